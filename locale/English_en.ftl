@@ -59,11 +59,14 @@ save_packfile = &Save PackFile
 save_packfile_as = Save PackFile &As…
 packfile_install = &Install
 packfile_uninstall = &Uninstall
+packfile_notes = Pack &Notes
 load_all_ca_packfiles = &Load All CA PackFiles
+load_ca_packfiles_filtered = Load CA PackFiles…
 preferences = &Preferences
 quit = &Quit
-open_recent = Open Recent
+open_recent = Recent Packs
 open_from_content = Open From Content
+open_from_workshop = Open From Workshop
 open_from_data = Open From Data
 change_packfile_type = &Change PackFile Type
 
@@ -103,9 +106,12 @@ view_toggle_dependencies_panel = Toggle Dependencies Window
 ## Game Selected Menu
 
 game_selected_launch_game = Launch Game Selected
+game_selected_launch_game_with_current_mod = Launch with Current Mod
+game_selected_restore_mod_list = Restore Mod List
 game_selected_open_game_data_folder = Open Game's Data Folder
 game_selected_open_game_assembly_kit_folder = Open Game's Assembly Kit Folder
 game_selected_open_config_folder = Open RPFM's Config Folder
+game_selected_schema_browser = Schema Browser
 
 ## Special Stuff
 
@@ -167,6 +173,7 @@ table_filter = Type here to filter the table. Works with Regex too!
 merge_tables = Merge Tables
 merge_tables_new_name = Write the name of the new file here.
 merge_tables_delete_option = Delete original tables
+merge_tables_upgrade_option = Update tables to their newest version before merging
 
 ## External FileDialog
 
@@ -182,7 +189,9 @@ tt_packfile_save_packfile = Save the changes made in the currently open PackFile
 tt_packfile_save_packfile_as = Save the currently open PackFile as a new PackFile, instead of overwriting the original one.
 tt_packfile_install = Copy the currently selected PackFile into the data folder of the GameSelected.
 tt_packfile_uninstall = Removes the currently selected PackFile from the data folder of the GameSelected.
+tt_packfile_notes = Opens the notes of the currently open PackFile, with a preview of their Markdown. Notes are saved within the PackFile.
 tt_packfile_load_all_ca_packfiles = Try to load every PackedFile from every vanilla PackFile of the selected game into RPFM at the same time, using lazy-loading to load the PackedFiles. Keep in mind that if you try to save it, your PC may die.
+tt_packfile_load_ca_packfiles_filtered = Choose which vanilla PackFiles of the selected game to load into RPFM as one, instead of loading all of them. The selection is remembered for each game.
 tt_packfile_preferences = Open the Preferences/Settings dialog.
 tt_packfile_quit = Exit the Program.
 
@@ -193,10 +202,10 @@ tt_change_packfile_type_mod = Changes the PackFile's Type to Mod. You should use
 tt_change_packfile_type_movie = Changes the PackFile's Type to Movie. You should use this for mods that'll always be active, and will not show up in the Mod Manager.
 tt_change_packfile_type_other = Changes the PackFile's Type to Other. This is for PackFiles without write support, so you should never use it.
 
-tt_change_packfile_type_data_is_encrypted = If checked, the data of the PackedFiles in this PackFile is encrypted. Saving this kind of PackFiles is NOT SUPPORTED.
+tt_change_packfile_type_data_is_encrypted = If checked, the data of the PackedFiles in this PackFile is encrypted. Saving this kind of PackFiles is NOT SUPPORTED: they are saved decrypted, with this flag removed.
 tt_change_packfile_type_index_includes_timestamp = If checked, the PackedFile Index of this PackFile includes the 'Last Modified' date of every PackedFile. Note that PackFiles with this enabled WILL NOT SHOW UP as mods in the official launcher.
-tt_change_packfile_type_index_is_encrypted = If checked, the PackedFile Index of this PackFile is encrypted. Saving this kind of PackFiles is NOT SUPPORTED.
-tt_change_packfile_type_header_is_extended = If checked, the header of this PackFile is extended by 20 bytes. Only seen in Arena PackFiles with encryption. Saving this kind of PackFiles is NOT SUPPORTED.
+tt_change_packfile_type_index_is_encrypted = If checked, the PackedFile Index of this PackFile is encrypted. Saving this kind of PackFiles is NOT SUPPORTED: they are saved decrypted, with this flag removed.
+tt_change_packfile_type_header_is_extended = If checked, the header of this PackFile is extended by 20 bytes. Only seen in Arena PackFiles with encryption. Saving this kind of PackFiles is NOT SUPPORTED: they are saved decrypted, with this flag removed.
 tt_change_packfile_type_data_is_compressed = If checked, the data of each PackedFile in the open PackFile will be compressed on save. If you want to decompress a PackFile, disable this, then save it.
 
 ## MyMod menu tips
@@ -204,15 +213,18 @@ tt_change_packfile_type_data_is_compressed = If checked, the data of each Packed
 tt_mymod_new = Open the dialog to create a new MyMod.
 tt_mymod_delete_selected = Delete the currently selected MyMod.
 
-tt_mymod_import = Move all of the contents of the MyMod folder into the .pack file. If any files have been removed in the MyMod folder, they will be deleted in the .pack file.
-tt_mymod_export = Move all of the contents from the .pack file into the MyMod folder. If any files have been removed from the .pack, they will be deleted in the MyMod folder.
+tt_mymod_import = Move all of the contents of the MyMod folder into the .pack file. If any files have been removed in the MyMod folder, they will be deleted in the .pack file. Folders mapped by the MyMod Export Rules of the PackFile Settings are moved back to their folder in the .pack file.
+tt_mymod_export = Move all of the contents from the .pack file into the MyMod folder, applying the MyMod Export Rules of the PackFile Settings. If any files have been removed from the .pack, they will be deleted in the MyMod folders of the rules marked as mirror.
 
 ## GameSelected menu tips
 
 tt_game_selected_launch_game = Tries to launch the currently selected game on steam.
+tt_game_selected_launch_game_with_current_mod = Tries to launch the currently selected game on steam, loading the installed copy of the open Pack and its dependencies. The previous mod list of the game is backed up.
+tt_game_selected_restore_mod_list = Restores the mod list the currently selected game had before launching it with the current mod.
 tt_game_selected_open_game_data_folder = Tries to open the currently selected game's Data folder (if exists) in the default file manager.
 tt_game_selected_open_game_assembly_kit_folder = Tries to open the currently selected game's Assembly Kit folder (if exists) in the default file manager.
 tt_game_selected_open_config_folder = Tries to open RPFM's config folder, where the config/schemas/ctd reports are.
+tt_game_selected_schema_browser = Opens a read-only view of the definitions of all the tables in the schema of the game selected.
 
 tt_game_selected_warhammer_3 = Sets 'TW:Warhammer 3' as 'Game Selected'.
 tt_game_selected_troy = Sets 'TW:Troy' as 'Game Selected'.
@@ -297,13 +309,15 @@ treeview_collapse_all = &Collapse All
 
 tt_context_menu_add_file = Add one or more files to the currently open PackFile. Existing files are not overwritten!
 tt_context_menu_add_folder = Add a folder to the currently open PackFile. Existing files are not overwritten!
-tt_context_menu_add_from_packfile = Add files from another PackFile to the currently open PackFile. Existing files are not overwritten!
+tt_context_menu_add_from_packfile = Add files from another PackFile to the currently open PackFile. If any of the files already exist, you will be asked whether to overwrite them.
+tt_context_menu_add_from_archive = Add the contents of a zip archive to the selected folder of the currently open PackFile.
 tt_context_menu_check_tables = Check all the DB Tables of the currently open PackFile for dependency errors.
 tt_context_menu_new_folder = Open the dialog to create an empty folder. Due to how the PackFiles are done, these are NOT KEPT ON SAVING if they stay empty.
 tt_context_menu_new_packed_file_anim_pack = Open the dialog to create an AnimPack.
 tt_context_menu_new_packed_file_db = Open the dialog to create a DB Table (used by the game for… most of the things).
 tt_context_menu_new_packed_file_loc = Open the dialog to create a Loc File (used by the game to store the texts you see in game) in the selected folder.
 tt_context_menu_new_packed_file_text = Open the dialog to create a Plain Text File. It accepts different extensions, like '.xml', '.lua', '.txt',….
+tt_context_menu_new_from_template = Open the dialog to create a set of files from one of the templates in the templates folder.
 tt_context_menu_new_queek_packed_file = Open the dialog to create a Packedfile based on the context. For example, if you launch this in /text, it'll create a loc PackedFile.
 tt_context_menu_mass_import_tsv = Import a bunch of TSV files at the same time. It automatically checks if they are DB Tables, Locs or invalid TSVs, and imports them all at once. Existing files will be overwritten!
 tt_context_menu_mass_export_tsv = Export every DB Table and Loc PackedFile from this PackFile as TSV files at the same time. Existing files will be overwritten!
@@ -360,6 +374,8 @@ context_menu_add_files = Add File/s
 context_menu_add_folder = Add &Folder
 context_menu_add_folders = Add Folder/s
 context_menu_add_from_packfile = Add from &PackFile
+context_menu_add_from_archive = Add from A&rchive
+context_menu_select_archive = Select Archive
 context_menu_select_packfile = Select PackFile
 context_menu_extract_packfile = Extract PackFile
 
@@ -368,17 +384,24 @@ context_menu_new_packed_file_anim_pack = Create AnimPack
 context_menu_new_packed_file_db = Create DB
 context_menu_new_packed_file_loc = Create Loc
 context_menu_new_packed_file_text = Create Text
+context_menu_new_from_template = New from Template
 context_menu_new_queek_packed_file = New Quick File
 
 context_menu_mass_import_tsv = Mass-Import TSV
 context_menu_mass_export_tsv = Mass-Export TSV
 context_menu_mass_export_tsv_folder = Select destination folder
 context_menu_move = Rename/Move
+context_menu_rename_by_pattern = Rename by &Pattern
+context_menu_cut = Cu&t
+context_menu_copy = &Copy
+context_menu_paste = Pa&ste
+context_menu_duplicate_table = Duplicate &Table
 context_menu_delete = Delete
 context_menu_extract = Extract
 
 context_menu_open_decoder = &Open with Decoder
 context_menu_open_dependency_manager = Open &Dependency Manager
+context_menu_open_dependencies_editor = Open Dependencies &Editor…
 context_menu_open_containing_folder = Open &Containing Folder
 context_menu_open_with_external_program = Open with &External Program
 context_menu_open_notes = Open &Notes
@@ -524,6 +547,7 @@ external_current_path = Current path for edition:
 stop_watching = Stop watching the file
 open_folder = Open folder in file manager
 
+open_pack_load_warnings = <p>The Pack has been opened, but it's damaged. These problems were found while reading it:</p><ul><li>{"{"}{"}"}</li></ul><p>Saving the Pack will fix them.</p>
 game_selected_changed_on_opening = Game Selected changed to {"{"}{"}"}, as the PackFile you opened is not compatible with the game you had selected.
 
 ### Extra stuff I don't remember where it goes.
@@ -531,6 +555,8 @@ game_selected_changed_on_opening = Game Selected changed to {"{"}{"}"}, as the P
 rpfm_title = Rusted PackFile Manager
 delete_mymod_0 = <p>You are about to delete this <i>'MyMod'</i> from your disk.</p><p>There is no way to recover it after that.</p><p>Are you sure?</p>
 delete_mymod_1 = <p>There are some changes yet to be saved.</p><p>Are you sure?</p>
+close_unsaved_open_files = <p>There are some changes yet to be saved. The following open files have been edited since the Pack was last saved:</p><ul>{"{"}{"}"}</ul><p>Are you sure?</p>
+close_waiting_for_background_thread = Waiting for the current operation to finish before closing. Cancel to keep RPFM open.
 
 api_response_success_new_stable_update = <h4>New major stable update found: {"{"}{"}"}</h4> <p>Please, make sure to save your work in progress before hitting 'Update', or you may lose it.</p>
 api_response_success_new_beta_update = <h4>New beta update found: {"{"}{"}"}</h4><p>Please, make sure to save your work in progress before hitting 'Update', or you may lose it.</p>
@@ -566,6 +592,7 @@ settings_font_title = Font Settings
 
 title_success = Success!
 title_error = Error!
+title_warning = Warning!
 
 rename_move_instructions = <p>It's easy peasy:</p>
     <ul>
@@ -584,7 +611,6 @@ rename_move_instructions = <p>It's easy peasy:</p>
         </ul>
     </ul>
 
-update_table_success = Table updated from version '{"{"}{"}"}' to version '{"{"}{"}"}'.
 no_errors_detected = No errors detected.
 original_data = Original Data: '{"{"}{"}"}'
 column_tooltip_1 = This column is a reference to:
@@ -631,6 +657,7 @@ context_menu_resize_columns = Resize Columns
 context_menu_undo = &Undo
 context_menu_redo = &Redo
 context_menu_cascade_edition = Rename References
+context_menu_deep_clone = Deep Clone
 
 header_column = <b><i>Column Name</i></b>
 header_hidden = <b><i>Hidden</i></b>
@@ -668,12 +695,16 @@ error_autosave_non_editable = This PackFile cannot be autosaved.
 settings_ui_table_use_old_column_order_label = Use Old Column Order (Keys first):
 
 context_menu_paste_as_new_row = Paste as New Row
+paste_add_rows_are_you_sure = What you are pasting doesn't fit in the table. Do you want to add new rows at the end of the table for the rest?
+paste_invalid_values = {"{"}{"}"} values couldn't be pasted because they are not valid for their columns. Check the details for the full list.
+paste_invalid_value = Row {"{"}{"}"}, column "{"{"}{"}"}": "{"{"}{"}"}" is not a valid {"{"}{"}"} value.
 
 gen_loc_diagnostics = Diagnostics
 diagnostics_button_check_packfile = Check PackFile
 diagnostics_button_check_current_packed_file = Check Open PackedFiles Only
 diagnostics_button_error = Error
 diagnostics_button_warning = Warning
+diagnostics_button_notice = Notice
 diagnostics_button_info = Info
 diagnostics_button_only_current_packed_file = Open PackedFiles Only
 
@@ -746,8 +777,18 @@ pfs_diagnostics_files_to_ignore_description_label =
 
 pfs_import_files_to_ignore_label = <h3>Files to Ignore when Importing</h3>
 pfs_import_files_to_ignore_description_label = <p>The files on this list will be ignored when importing from a MyMod folder. Only for MyMods. Paths are relative, the glory of the empire is absolute.</p>
+pfs_compression_files_to_ignore_label = <h3>Files to Never Compress</h3>
+pfs_compression_files_to_ignore_description_label = <p>The files on this list will always be saved uncompressed, even if compression is enabled for this PackFile. One entry per line: entries starting with a dot (like <code>.dds</code>) are extensions, anything else is a path prefix (like <code>movies/</code>). Lines starting with <code>#</code> are ignored.</p>
+pfs_mymod_export_rules_label = <h3>MyMod Export Rules</h3>
+pfs_mymod_export_rules_description_label = <p>Rules to map folders of this PackFile to folders of its MyMod folder when exporting, and back when importing. Only for MyMods. One rule per line, with the format <code>pack_folder;disk_folder;flags</code>. Flags are optional and comma-separated: <code>tsv</code> exports the tables under the rule as TSV, and <code>mirror</code> deletes files in the disk folder that are no longer in the PackFile (hidden files, like <code>.git</code>, are kept). Files not under any rule are exported as usual. Lines starting with <code>#</code> are ignored. Example: <code>db;tables;tsv,mirror</code>.</p>
+pfs_translation_report_keys_to_ignore_label = <h3>Keys to Ignore in Translation Reports</h3>
+pfs_translation_report_keys_to_ignore_description_label = <p>Loc keys that should never be reported as missing or untranslated in the Translation Report, like keys that are meant to be the same in every language. One key per line. Lines starting with <code>#</code> are ignored.</p>
 pfs_disable_autosaves_label = <h3>Disable Autosaves for this PackFile</h3>
 pfs_disable_autosaves_description_label = <p></p>
+pfs_generate_manifest_on_save_label = <h3>Generate Manifest on Save</h3>
+pfs_generate_manifest_on_save_description_label = <p>If enabled, every time this PackFile is saved a <i>packname.manifest.json</i> file is generated next to it, with the PFH version, timestamp, and the size and sha256 of every file in the PackFile. Useful to let server admins verify the PackFiles of their players.</p>
+pfs_pad_pack_to_bytes_label = <h3>Pad PackFile to Size (in bytes)</h3>
+pfs_pad_pack_to_bytes_description_label = <p>If bigger than 0, every time this PackFile is saved, if it's smaller than this size, a zero-filled <i>rpfm_padding.bin</i> file is added at the end of it to reach this size. Useful for launchers that mis-handle small PackFiles. 0 disables it.</p>
 
 instructions_ca_vp8 = It's simple, the video can have 2 formats: CAMV (used by the game) and IVF (reproducible on a media player with VP8 codecs).
   To export a video, convert it to IVF and extract it.
@@ -815,21 +856,22 @@ next_tab = Previous Tab
 settings_debug_clear_autosave_folder = Clear autosave folder
 settings_debug_clear_schema_folder = Clear schema folder
 settings_debug_clear_layout_settings = Clear layout settings
-tt_settings_debug_clear_autosave_folder = Use this to clear the entire autosave folder, either to clear space on your disk, or to apply the changes to autosave amount, if any.
+tt_settings_debug_clear_autosave_folder = Use this to clear the entire autosave folder, to clear space on your disk.
 tt_settings_debug_clear_schema_folder = Use this to clear the entire schema folder. Just in case the updater fails.
 tt_settings_debug_clear_layout_settings = Use this to clear the layout special settings and restore the UI to its initial state.
 
-autosaves_cleared = Autosave folder deleted. It'll be regenerated the next time you start the program.
+autosaves_cleared = Autosave folder deleted.
 schemas_cleared = Schemas folder deleted. Please, remember to re-download the schemas to be able to open tables.
 dependencies_cache_cleared = Dependencies folder deleted.
 
-settings_autosave_amount = Autosave Amount (min 1)
-tt_settings_autosave_amount = Sets the amount of autosaves RPFM is allowed to use. If you reduce this number, you need to hit "Clear Autosave Folder" to delete the extra autosaves. Keep in mind this resets the entire autosave folder.
+settings_autosave_amount = Autosaves per Pack (min 1)
+tt_settings_autosave_amount = Sets the amount of autosaves RPFM keeps for each Pack. Once there are more autosaves than this, the oldest ones get deleted.
 
 restart_button = Restart
 error_not_booted_from_launcher = This window of RPFM has not been launched from the "rpfm.exe" file, but directly from the "rpfm_ui.exe" file. Since version 2.3.102, you should launch it from "rpfm.exe" (or equivalent) to support certain features regarding the update system.
 
 install_success = PackFile successfully installed.
+install_show_in_data_folder = Show in data folder
 uninstall_success = PackFile successfully uninstalled.
 
 outdated_table_explanation = Tables have an internal version number that changes whenever CA does an update to said table that changes its structure.
@@ -903,6 +945,10 @@ invalid_dependency_pack_file_name_explanation = One of the PackFile Names in the
     - A PackFile name containing an space.
 
 pfs_button_apply = Apply Settings
+pfs_header_label = <h3>Pack Header</h3>
+pfs_header_game_version = Game Version:
+pfs_header_build_number = Build Number:
+pfs_header_authoring_tool = Authoring Tool:
 cascade_edition_dialog = Rename References
 template_load_final_message = And with that, the template is done. Make sure to follow the steps here in case the template needs them.
 is_required = Is Required
@@ -921,13 +967,25 @@ context_menu_go_to_definition = Go To Definition
 source_data_for_field_not_found = The source of the selected data could not be found.
 context_menu_go_to_loc = Go To Loc Entry:  {"{"}{"}"}
 loc_key_not_found = The loc entry couldn't be found.
+context_menu_open_related_loc_entries = Open Related Loc Entries
+context_menu_open_defining_table_row = Open Defining Table Row
+context_menu_diff_against_dependencies = Diff Against Dependencies
+table_diff_show_only_changed = Show only rows changed against vanilla/parent files
+table_diff_removed_rows = Rows removed from vanilla/parent files: {"{"}{"}"}
+context_menu_edit_row_annotation = Edit Row Note
+row_annotation_dialog = Edit Row Note
+row_annotation_explanation = Note for the {"{"}{"}"} selected rows. It's stored in the Pack Settings and shown as a pin in the row header. Leave it empty to remove it.
+related_loc_entries_not_found_create = There are no loc entries for the selected row. Do you want to create them in the open Pack?
 table_filter_show_blank_cells = Show Blank Cells
+table_filter_typed = On numeric columns you can also use >, >=, <, <= and = followed by a number, or a range like 10..20. On colour columns, an RRGGBB colour matches exactly that colour.
 special_stuff_rescue_packfile = Rescue PackFile
 are_you_sure_rescue_packfile = Are you sure you want to do this? This is a dangerous option that should never be used unless the dev or RPFM tells you to specifically use it.
     So again, are you sure you want to use this?
 
 filter_group = Group
 are_you_sure_delete = Are you sure you want to delete the selected PackedFiles?
+container_undo_nothing = There is nothing to undo in the Pack.
+container_redo_nothing = There is nothing to redo in the Pack.
 label_invalid_loc_key = Invalid Loc Key:
 info_title = Info
 category_title = Category {"{"}{"}"}
@@ -1078,6 +1136,8 @@ context_menu_import = Import
 dependencies_asskit_files = Assembly Kit Files
 dependencies_game_files = Game Files
 dependencies_parent_files = Parent Files
+dependencies_pending = Loading the dependencies in the background. This will be available once they finish loading.
+dependencies_ready = Dependencies loaded.
 import_from_dependencies = Import from Dependencies
 global_search_search_source = Search Source
 global_search_source_packfile = Packfile
@@ -1117,6 +1177,7 @@ tools_unit_multiplayer = Multiplayer
 tools_unit_extra_data = Extra Data
 copy_unit = Copy Unit
 generate_dependencies_cache_in_progress_message = Generating Dependencies Cache... this may take a while.
+optimize_packfile_in_progress_message = Optimizing PackFile... this may take a while.
 copy_unit_instructions = <p>Write the new unit's key in the input field, and hit accept. Also, note:</p>
     <ul>
         <li>Existing unit keys are not valid.</li>
@@ -1126,6 +1187,9 @@ copy_unit_instructions = <p>Write the new unit's key in the input field, and hit
 copy_unit_new_unit_name = Unit Key
 settings_disable_file_previews = Disable PackedFile Previews
 tt_settings_disable_file_previews_tip = Check this to make RPFM always open PackedFiles as non-preview, so they'll not get closed when opening another PackedFile.
+settings_restore_tab_sessions = Restore Open Files on Pack Open
+tt_settings_restore_tab_sessions_tip = Check this to make RPFM remember which files you had open in each Pack when closing it, and reopen them the next time you open that same Pack. Uncheck it if you prefer to start with no files open.
+tab_session_restore_failed = Failed to restore {"{"}{"}"} of the previously open files: {"{"}{"}"}.
 variant_editor_title = Variant Editor
 variants_variant_filename = Variant Mesh FileName
 variants_mesh_editor_title = Variant Mesh Editor
@@ -1161,6 +1225,7 @@ new_colour_variant_instructions = <p>Write the new colour variant key in the inp
 new_colour_variant_name = Colour Variant Key
 
 line_counter = Rows On Filter / On Table: {"{"}{"}"} / {"{"}{"}"}
+column_description = {"{"}{"}"}: {"{"}{"}"}
 new_tip_user = User:
 new_tip_tip = Message:
 new_tip_path = Path:
@@ -1197,9 +1262,28 @@ import_schema_patch = Import Schema Patch
 import_schema_patch_title = Import Schema Patch
 import_schema_patch_button = Import Patch
 import_schema_patch_success = Patch imported correctly.
+export_schema_patches = Export Schema Patches
+export_schema_patches_title = Export Schema Patches
+export_schema_patches_copy = Copy to Clipboard
+export_schema_patches_save = Save to File
+export_schema_patches_none = There are no local schema patches to export.
+release_memory = Release Memory
+release_memory_success = Released approximately {} MB of memory.
 label_value_cannot_be_empty = Value Cannot be Empty:
 value_cannot_be_empty_explanation = The value of this column cannot be empty. This basically means your game may crash if you leave a value of this column empty.
     If you think this is a false positive, feel free to submit a schema patch to fix it.
+label_missing_loc_data = Missing Loc Data:
+missing_loc_data_explanation = This row has a localised field without a loc entry, neither in this Pack nor in its dependencies. The game will show an empty text or the loc key instead. You can use "Generate Loc Data" to create the missing entries.
+label_non_standard_loc_layout = Non-Standard Loc Layout:
+non_standard_loc_layout_explanation = This Loc file was written by a tool using a non-standard layout, with extra bytes after each row. RPFM keeps those bytes when saving the file, but the game may not read it correctly. Recreate it with a standard tool if the game shows broken text.
+label_identical_to_vanilla = Identical to Vanilla:
+identical_to_vanilla_explanation = This file is exactly the same as the one with the same path in the vanilla files or in the parent mods. It does nothing other than increasing the size of your Pack,
+    and it may override changes other mods make to the same file. You can delete all these files at once with the "Delete Files Identical to Vanilla" action of this panel's context menu.
+diagnostics_context_menu_delete_identical_to_vanilla = Delete Files Identical to Vanilla
+are_you_sure_delete_identical_to_vanilla = This will delete from the Pack all the files reported as identical to their vanilla/parent counterparts. Are you sure?
+label_dependency_loaded_after_pack = Dependency Loads After Pack:
+dependency_loaded_after_pack_explanation = Packs are loaded in alphabetical order, and this dependency's name sorts after the name of your Pack. That means it loads after your Pack, so your Pack cannot overwrite its files.
+    If you want your changes to take priority, rename your Pack so it sorts before its dependencies (for example, starting it with "!").
 
 context_menu_patch_column = Patch Column Definition
 new_schema_patch_dialog = Schema Patcher
@@ -1222,6 +1306,7 @@ reference_search_column_number = Column Index
 reference_search_row_number = Row Index
 
 view_toggle_references_panel = Toggle References Window
+view_toggle_modified_column = Show Modified Date of Files
 
 tt_settings_debug_clear_dependencies_cache_folder = Use this to clear the dependencies cache folder. In case you don't want RPFM to eat a lot of RAM.
 settings_debug_clear_dependencies_cache_folder = Clear dependencies cache folder
@@ -1249,6 +1334,12 @@ new_mymod_gitignore_same_as_files_ignored_on_import = Same as files ignored on i
 mymod_error_spaces_on_name = Error: Mod name cannot contain spaces.
 new_mymod_pack_import_ignore_contents_placeholder = Relative paths, one per line.
 new_mymod_gitignore_contents_placeholder = Paths ignored by git, one per line.
+new_mymod_skeleton = Folder Structure
+new_mymod_skeleton_in_pack = Add it to the Pack too
+new_mymod_skeleton_data = DB and Loc Folders
+new_mymod_skeleton_campaign_scripts = Campaign Scripts
+new_mymod_skeleton_battle_scripts = Battle Scripts
+new_mymod_skeleton_frontend_scripts = Frontend Scripts
 global_search_search_placeholder = Search
 global_search_replace_placeholder = Replace
 
@@ -1257,3 +1348,357 @@ discord_link = Open "The Modding Den" discord channel
 open_manual = Open RPFM Manual
 patreon_link = Support me on Patreon
 reload_style_sheet = Reload StyleSheets
+
+special_stuff_verify_integrity = Verify Integrity
+verify_integrity_title = Verify Integrity Report
+verify_integrity_success = No problems found in the open Pack.
+verify_integrity_decoding_failed = Files that failed to decode
+verify_integrity_size_mismatch = Files with size mismatches
+verify_integrity_vanilla_path_collision = Files colliding with vanilla files due to their path casing
+verify_integrity_skipped_encrypted = Encrypted files (skipped)
+
+optimizer_remove_itm = Remove table entries unchanged from the vanilla/parent files (ITM)
+optimizer_remove_itnr = Remove table entries unchanged from the default row (ITNR)
+optimizer_remove_duplicates = Remove duplicated table entries (this also sorts the tables)
+optimizer_remove_empty_files = Remove tables left empty after the optimization
+optimizer_remove_map_xml = Remove useless xml files from map folders
+optimizer_remove_overridden_loc_entries = Remove loc entries overridden by other loc files of this Pack that load later
+optimize_packfile_nothing_to_optimize = There is nothing to optimize in this PackFile with the selected options.
+optimizer_report_title = Optimizer Report
+optimizer_report_explanation = <p>The optimizer is going to change or remove the following {"{"}{"}"} files. Hit Apply to optimize the PackFile.</p>
+optimizer_report_column_path = Path
+optimizer_report_column_duplicated = Duplicated Rows
+optimizer_report_column_itm = ITM Rows
+optimizer_report_column_itnr = ITNR Rows
+optimizer_report_column_overridden = Overridden Loc Rows
+optimizer_report_column_removed = File
+optimizer_report_removed = Removed
+optimizer_report_apply = Apply
+text_encoding = Encoding:
+special_stuff_compare_packs = Compare Packs
+compare_packs_select = Select the Pack to compare against
+compare_packs_title = Pack Comparison
+compare_packs_only_in_open = Only in the open Pack
+compare_packs_only_in_other = Only in the other Pack
+compare_packs_different = Different
+compare_packs_column_path = Path
+compare_packs_column_added = Added Rows
+compare_packs_column_removed = Removed Rows
+compare_packs_column_changed = Changed Rows
+compare_packs_column_duplicated_keys = Duplicated Keys
+special_stuff_sign_pack = Sign Pack
+special_stuff_verify_pack_signature = Verify Pack Signature
+sign_pack_select = Select the Pack to sign
+sign_pack_success = Pack signed. The signature has been saved to:<br><br><i>{"{"}{"}"}</i><br><br>Share it along with the Pack. To verify it, others will need your public key, which is at:<br><br><i>{"{"}{"}"}</i>
+verify_pack_signature_select = Select the Pack to verify. Its signature must be next to it, as a .sig file with the same name
+verify_pack_signature_select_public_key = Select the public key of whoever signed the Pack
+verify_pack_signature_success = The Pack matches its signature. Nothing has been altered since it was signed.
+special_stuff_translation = Translation
+special_stuff_translation_export_po = Export Locs to PO
+special_stuff_translation_import_po = Import Locs from PO
+translation_export_po_title = Export Locs to PO
+translation_export_po_language = Language of the translation (es, fr, de,...). Optional.
+translation_export_po_accept = Export
+translation_export_po_select = Select where to save the PO file
+translation_export_po_success = All the Loc files of the Pack have been exported to the PO file.
+translation_import_po_select = Select the PO file to import
+translation_import_po_success = PO file imported. Entries updated: {"{"}{"}"}. New entries: {"{"}{"}"}.<br><br>New entries have been added to a Loc named after the PO file, under text/db.
+special_stuff_translation_report = Translation Report
+tt_special_stuff_translation_report = Compares the Loc files of each language in the Pack, listing the keys each language is missing, and the keys with the same text as the base language, which are probably untranslated.
+translation_report_title = Translation Report
+translation_report_detection = Get the language of each Loc from:
+translation_report_detection_file_suffix = The end of its name (text/db/my_mod_en.loc)
+translation_report_detection_folder = Its folder (text/local_en/my_mod.loc)
+translation_report_base_language = Base language:
+translation_report_accept = Generate Report
+translation_report_explanation = {"{"}{"}"} different keys found between {"{"}{"}"} languages, compared against the "{"{"}{"}"}" language. Loc files whose language couldn't be found, or that couldn't be decoded: {"{"}{"}"}.<br><br>Keys in the "Keys to Ignore in Translation Reports" setting of the Pack are not reported.
+translation_report_column_language = Language
+translation_report_column_files = Files
+translation_report_column_keys = Keys
+translation_report_column_missing = Missing
+translation_report_column_untranslated = Untranslated
+translation_report_column_key = Key
+translation_report_column_issue = Issue
+translation_report_column_base_text = Base Text
+translation_report_issue_missing = Missing
+translation_report_issue_untranslated = Untranslated
+translation_report_export_tsv = Export to TSV
+global_search_source_all = Everything
+global_search_read_only_match = This file is read-only, either because it's from a dependency or because it's within another file. Its matches cannot be replaced.
+settings_autosave_size_warning_threshold = Autosave Size Warning (MB)
+tt_settings_autosave_size_warning_threshold = If an autosaved Pack is bigger than this, RPFM logs a warning about it, as autosaving big Packs can take a while. Set it to 0 to disable the warning.
+packfile_restore_autosave = Restore Autosave
+restore_autosave_explanation = Select the autosave of this Pack you want to open:
+restore_autosave_none = There are no autosaves of this Pack yet.
+missing_loc_data_target = Target Loc:
+missing_loc_data_template = Value Template ({"{"}key{"}"} gets replaced with the key):
+missing_loc_data_include_parent_keys = Also generate entries already defined in the parent mods
+missing_loc_data_generated = Generated {"{"}{"}"} missing loc entries.
+update_tables_success = {"{"}{"}"} tables updated to their newest version.
+update_tables_nothing_to_update = There are no tables that can be updated to a newer version in the selection.
+update_tables_confirm = <p>The following tables will be updated to the newest version in the schema. This may be newer than the version the installed game uses:</p><ul>{"{"}{"}"}</ul><p>The following tables will be skipped, as there is no newer version of them:</p><ul>{"{"}{"}"}</ul><p>Are you sure?</p>
+merge_tables_conflicts = <p>{"{"}{"}"} keys have rows with different data across the tables to merge:</p><ul>{"{"}{"}"}</ul><p>If you continue, all these rows will be kept in the merged table. Are you sure?</p>
+rename_by_pattern_title = Rename by Pattern
+rename_by_pattern_instructions = <p>Write a regex pattern and its replacement. The pattern is applied to the full path of every selected file, and of every file within the selected folders. The replacement can use capture groups, like <i>$1</i> or <i>${"{"}name{"}"}</i>.</p><p>If any of the new paths already exists, nothing will be renamed.</p>
+rename_by_pattern_pattern = Pattern, like unit_x_(.*)\.xml
+rename_by_pattern_replacement = Replacement, like unit_y_$1.xml
+rename_by_pattern_case_insensitive = Case Insensitive
+rename_by_pattern_no_matches = None of the selected files matched the pattern.
+duplicate_table_title = Duplicate Table
+duplicate_table_instructions = <p>Write the name for the copy of <i>{"{"}{"}"}</i>. You can also write a full path, but copies of DB tables must stay in the folder of their table, and copies of Loc files must stay in the <i>text</i> folder.</p>
+extraction_format_title = Extract
+extraction_format_instructions = Choose the format in which tables will be extracted. Any other file is extracted as it is in the Pack.
+extraction_format_raw = Binary (as they are in the Pack)
+extraction_format_tsv = TSV (DB and Loc tables)
+extraction_format_ass_kit_xml = Assembly Kit XML (DB tables)
+load_ca_packfiles_filtered_title = Load CA PackFiles
+load_ca_packfiles_filtered_explanation = Select the vanilla PackFiles you want to load. They'll be loaded together as one read-only PackFile.
+image_export_png = Export as PNG…
+image_export_png_title = Export Image as PNG
+image_export_png_error = The image couldn't be exported to <i>{"{"}{"}"}</i>.
+image_metadata = {"{"}{"}"}x{"{"}{"}"} px, {"{"}{"}"} bytes.
+image_metadata_fourcc = {"{"}{"}"}x{"{"}{"}"} px, {"{"}{"}"} bytes, {"{"}{"}"} format.
+image_metadata_size_only = {"{"}{"}"} bytes.
+image_metadata_dds = {"{"}{"}"}x{"{"}{"}"} px, {"{"}{"}"} bytes, {"{"}{"}"} format, {"{"}{"}"} mipmaps, {"{"}{"}"} textures.
+hex_view_goto = Go To
+hex_view_goto_placeholder = Offset (decimal, or hex starting with 0x)
+hex_view_search_placeholder = Search
+hex_view_search_bytes = Byte Sequence
+hex_view_find_next = Find Next
+hex_view_size = Size: {"{"}{"}"} bytes.
+hex_view_invalid_offset = Invalid offset.
+hex_view_invalid_byte_sequence = Invalid byte sequence. It must be written in hex, like "DE AD BE EF".
+hex_view_not_found = No matches found.
+save_views_error = <p>The following files couldn't be saved from their views:</p><pre>{"{"}{"}"}</pre>
+add_files_overwrite_confirm = <p>The following files already exist in the PackFile and will be overwritten:</p><ul>{"{"}{"}"}</ul><p>Are you sure?</p>
+dependencies_editor_title = Dependencies Editor
+dependencies_editor_explanation = <p>These are the Packs your Pack depends on, in the order they're listed in your Pack. Packs found in the data or MyMod folders show where they are. Those not found are marked with a warning icon.</p>
+dependencies_editor_column_pack = Pack
+dependencies_editor_column_location = Location
+dependencies_editor_not_found = Not found in the data or MyMod folders.
+dependencies_editor_move_up = Move Up
+dependencies_editor_move_down = Move Down
+dependencies_editor_remove = Remove
+dependencies_editor_add = Add
+dependencies_editor_add_placeholder = Pick a Pack from the data folder, or write its name
+dependencies_editor_save = Save
+
+export_ivf = Export as IVF
+import_ivf = Import from IVF
+export_ivf_title = Export video as IVF
+import_ivf_title = Select an IVF video to import
+export_ivf_success = Video exported. You can play it with any media player with VP8 support, like VLC.
+
+operation_cancelling = Cancelling... the operation will stop as soon as possible.
+global_search_in_progress_message = Searching... this may take a while.
+diagnostics_in_progress_message = Checking the Pack for problems...
+esf_search = Search
+esf_search_placeholder = Search nodes by name or value...
+esf_search_no_matches = No matches found.
+esf_bookmark_add = Bookmark Node
+esf_bookmark_remove = Remove Bookmark
+esf_bookmarks_tooltip = Bookmarked nodes of this file. Select one to go to it.
+pfs_esf_bookmarks_label = <h3>ESF Bookmarks</h3>
+pfs_esf_bookmarks_description_label = <p>Bookmarked nodes of the ESF files of this PackFile. One bookmark per line, with the format <code>file_path;node_indexes;node_names</code>. You can add and remove them from the ESF editor.</p>
+deep_clone_dialog = Deep Clone
+deep_clone_instructions = <p>This clones the selected row into a new key, along with all the rows of other tables that use its key as part of their own keys, and its loc entries. Tables referencing it only in non-key columns are not cloned.</p>
+deep_clone_key = Key to clone:
+deep_clone_new_key = New key:
+deep_clone_include_vanilla = Also clone rows from vanilla and parent files
+deep_clone_preview_dialog = Deep Clone Preview
+deep_clone_preview_explanation = <p>The following {"{"}{"}"} rows will be added to your Pack. Are you sure?</p>
+deep_clone_success = Deep clone finished: {"{"}{"}"} rows added across {"{"}{"}"} files.
+cascade_edition_preview_dialog = Rename References Preview
+cascade_edition_preview_explanation = <p>The following {"{"}{"}"} cells across {"{"}{"}"} files will be changed. Are you sure?</p>
+context_menu_label = Label
+context_menu_label_remove = Remove Label
+packedfile_filter_label = Filter by label
+packedfile_filter_label_none = Any (filter by name)
+pfs_path_labels_label = <h3>File and Folder Labels</h3>
+pfs_path_labels_description_label = <p>Labels assigned to files and folders of this PackFile. One label per line, with the format <code>path;label</code>. Folder paths end with <code>/</code>. You can assign and remove them from the contextual menu of the PackFile Contents panel.</p>
+pfs_path_label_definitions_label = <h3>Available Labels</h3>
+pfs_path_label_definitions_description_label = <p>Labels that can be assigned to the files and folders of this PackFile. One label per line, with the format <code>name;colour</code>, where colour is a hex colour like <code>#4caf50</code>. Lines starting with <code>#</code> are ignored.</p>
+context_menu_column_presets = Column Presets
+context_menu_column_presets_switch = Switch To
+context_menu_column_presets_save = Save Layout As Preset
+context_menu_column_presets_delete = Delete Current Preset
+context_menu_column_presets_reset = Reset To Schema Order
+context_menu_column_presets_export = Export Presets
+context_menu_column_presets_import = Import Presets
+column_presets_save_title = Save Column Preset
+column_presets_save_placeholder = Name of the preset. Presets with the same name are replaced.
+column_presets_export_title = Export Column Presets
+column_presets_import_title = Import Column Presets
+column_presets_import_success = Column presets imported. They'll be applied the next time you open the tables they're for, or when you switch to them.
+special_stuff_refresh_dependencies_cache = Refresh Dependencies Cache
+refresh_dependencies_cache_in_progress_message = Refreshing Dependencies Cache... if the game files changed, it'll be regenerated, which may take a while.
+refresh_dependencies_cache_up_to_date = Dependencies Cache refreshed. Nothing changed since it was loaded.
+refresh_dependencies_cache_success = Dependencies Cache refreshed. Sources reloaded ({"{"}{"}"}): {"{"}{"}"}.
+error_kind_schema_missing = <p>There is no schema for the game selected. You can download the latest schemas now, or later from <i>About/Check Schema Updates</i>.</p>
+error_kind_schema_missing_button = Check Schema Updates
+error_kind_dependencies_cache_outdated = <p>The dependencies cache for the game selected is missing or was generated by an older version of RPFM. You need to regenerate it.</p>
+error_kind_dependencies_cache_outdated_button = Generate Dependencies Cache
+error_kind_file_locked = <p>The file is being used by another program. If the game or another instance of RPFM is open, close it and try again.</p>
+recent_packs_show_all_games = Show Packs from All Games
+recent_packs_tooltip = {"{"}{"}"}
+    Right-click to pin it or remove it from the list.
+recent_packs_missing = {"{"}{"}"} no longer exists.
+    Right-click to remove it from the list.
+recent_packs_pin = Pin
+recent_packs_unpin = Unpin
+recent_packs_remove = Remove from List
+settings_recent_packs_limit = Recent Packs per Game (min 1)
+tt_settings_recent_packs_limit = Sets the amount of recent Packs RPFM remembers for each game. Pinned Packs don't count for this limit, and are never removed from the list automatically.
+settings_container_undo_memory_cap = Undo History Memory Limit (MB)
+tt_settings_container_undo_memory_cap = Sets how much memory RPFM can use to keep deleted or replaced files, so deleting, renaming or adding files in the Pack can be undone. Older operations are forgotten when the limit is reached, and files bigger than the limit are not kept at all, so their deletion cannot be undone.
+settings_container_undo_clear_on_save = Clear Undo History on Save
+tt_settings_container_undo_clear_on_save = Check this to make RPFM forget the deleted, renamed or added files it can undo every time the Pack is saved, freeing the memory they use.
+settings_memory_ceiling = Memory Ceiling (MB)
+tt_settings_memory_ceiling = If RPFM uses more memory than this after a heavy operation (like opening Packs, searching or checking diagnostics), it releases the cached data it can load again from disk. 0 disables it. You can also release memory manually from the Debug menu.
+settings_watch_open_pack = Watch the Open Pack for Changes
+tt_settings_watch_open_pack = Check this to make RPFM notice when the open Pack is changed on disk by another program, so it can be reloaded. If the Pack is in a folder that cannot be watched, like some network drives, this does nothing.
+close = Close
+packfile_statistics = Pack Statistics
+tt_packfile_statistics = Shows statistics about the contents of the open Pack: files and size by type, largest files, and rows of each DB and Loc table.
+pack_statistics_summary = Summary
+pack_statistics_total = Total
+pack_statistics_db_tables = DB Tables
+pack_statistics_loc_files = Loc Files
+pack_statistics_undecoded_files = Undecoded Files
+pack_statistics_types = Files by Type
+pack_statistics_largest_files = Largest Files
+pack_statistics_tables = DB Tables by Name
+pack_statistics_column_name = Name
+pack_statistics_column_files = Files
+pack_statistics_column_rows = Rows/Keys
+pack_statistics_column_size = Size
+pack_statistics_copy_markdown = Copy as Markdown
+packfile_check_conflicts = Check Conflicts with Other Packs
+tt_packfile_check_conflicts = Checks what files of the open Pack are also in the Packs you select, and which one the game will use, following the game's load order. If a schema is loaded, conflicting DB and Loc files also show the rows that collide between them.
+pack_conflicts_explanation = Found {"{"}{"}"} files in more than one Pack, between {"{"}{"}"} Packs. Load order, from the one that takes priority to the one that loses against all the others: {"{"}{"}"}.
+pack_conflicts_group_by = Group by:
+pack_conflicts_group_by_pack = Pack
+pack_conflicts_group_by_folder = Folder
+pack_conflicts_unreadable_packs = Unreadable Packs
+pack_conflicts_column_path = Path/Key
+pack_conflicts_column_winner = Winner
+pack_conflicts_column_losers = Overwritten
+pack_conflicts_column_rows = Conflicting Rows
+pack_conflicts_export_text = Export as Text
+packfile_row_annotations = Row Notes
+tt_packfile_row_annotations = Shows all the notes left on table rows of the open Pack. Double-click a note to jump to its row. Notes whose row no longer exists are marked as orphaned, so you can fix or remove them.
+row_annotations_explanation = Notes left on table rows of the open Pack. Double-click a note to jump to its row.
+row_annotations_column_path = Path
+row_annotations_column_key = Row Key
+row_annotations_column_note = Note
+row_annotations_column_status = Status
+row_annotations_status_found = Found
+row_annotations_status_orphaned = Orphaned
+row_annotations_remove = Remove Selected
+row_annotations_row_not_found = The row of the note couldn't be found. It may be hidden by the table filters.
+label_invalid_path_list = Invalid Path List:
+path_list_path_not_found_explanation = This path is not in the Pack nor in its dependencies. Path lists like texture arrays with wrong paths can crash the game.
+path_list_too_long_explanation = This file references more paths than the game supports. Texture arrays over the limit can crash the game.
+label_invalid_texture = Invalid Texture:
+texture_format_not_supported_explanation = This texture uses a format this game cannot read. It may show up black, glitched or crash the game. Re-export it with an older format, like BC3/DXT5.
+texture_dimensions_not_multiple_of_4_explanation = Block-compressed textures are stored in blocks of 4x4 pixels. If their dimensions are not multiples of 4 the game may fail to read them.
+texture_dimensions_not_power_of_two_explanation = Textures with mipmaps whose dimensions are not powers of two may show up glitched at some distances.
+texture_mipmap_count_invalid_explanation = This texture claims to have more mipmaps than its dimensions allow. Its header is probably damaged.
+texture_mipmaps_missing_explanation = The game expects textures in this folder to have mipmaps. Without them, they may look noisy or glitched when scaled down.
+path_list_problems = The game may crash with this file. Problems found:
+label_lua_syntax_error = Lua Syntax Error:
+lua_syntax_error_explanation = This script has a syntax error. The game will fail to load it, and everything that depends on it will not work.
+lua_syntax_problems = The file has been saved, but the game will fail to load it. Syntax errors found:
+label_invalid_variant_mesh = Invalid VariantMesh:
+variant_mesh_path_not_found_explanation = This VariantMeshDefinition references a file that is not in the Pack nor in its dependencies. Units using it may be invisible in game.
+variant_mesh_parse_error_explanation = This VariantMeshDefinition is not valid XML. The game will fail to load it, and units using it may be invisible in game.
+text_validate_references = Validate References
+variant_mesh_problems = Problems found in the references of this file:
+variant_mesh_no_problems = All the references of this file have been found.
+label_invalid_speedtree = Invalid SpeedTree:
+speedtree_texture_not_found_explanation = This SpeedTree references a texture that is not in the Pack nor in its dependencies. The tree may show up without textures in game.
+rescue_report_title = Rescue Report
+rescue_report_explanation = The PackFile has been rescued and saved. This is what has been done to it. A copy of this report has been saved in: {"{"}{"}"}
+rescue_report_copy = Copy Report
+text_search_search_pattern = Search Pattern:
+text_search_replace_pattern = Replace Pattern:
+text_search_search_placeholder = Type here what you want to search.
+text_search_replace_placeholder = If you want to replace the searched text with something, type the replacement here.
+text_search_prev_match = Prev. Match
+text_search_next_match = Next Match
+text_search_replace_current = Replace Current
+text_search_replace_all = Replace All
+text_search_case_sensitive = Case Sensitive
+text_search_whole_word = Whole Words
+text_search_regex = Regex
+text_search_close = Close
+text_search_matches = {"{"}{"}"} matches
+text_search_no_matches = No matches
+text_search_invalid_regex = Invalid regex
+text_search_replaced = Replaced {"{"}{"}"} matches
+diagnostics_context_menu_ignore_for_file = Ignore Diagnostic for this File
+diagnostics_context_menu_ignore_for_column = Ignore Diagnostic for this Column
+pfs_diagnostics_ignore_rules_label = <h3>Diagnostics Ignore Rules</h3>
+pfs_diagnostics_ignore_rules_description_label = <p>Diagnostics ignored for the files of this PackFile. Each rule applies to the files whose path starts with its path. If a diagnostic is set, only that diagnostic is ignored, and if columns are set (comma-separated), only the diagnostics on those columns are ignored. You can add new rules from the contextual menu of the Diagnostics panel.</p>
+pfs_diagnostics_ignore_rules_path = Path
+pfs_diagnostics_ignore_rules_diagnostic = Diagnostic
+pfs_diagnostics_ignore_rules_columns = Columns
+pfs_diagnostics_ignore_rules_remove = Remove Selected Rules
+operations_title = Operations
+view_toggle_operations_panel = Toggle Operations Window
+operations_filter_info = All
+operations_filter_warning = Warnings and Errors
+operations_filter_error = Errors Only
+operations_copy_all = Copy All
+operations_time = Time
+operations_level = Level
+operations_message = Message
+operations_level_info = Info
+operations_level_warning = Warning
+operations_level_error = Error
+file_info_title = File Info
+view_toggle_file_info_panel = Toggle File Info Window
+file_info_path = Path:
+file_info_file_type = File Type:
+file_info_timestamp = Last Modified:
+file_info_data_state = Data State:
+file_info_compressed = Compressed:
+file_info_stored_size = Size on Disk:
+file_info_data_size = Size in Memory:
+file_info_source = Source:
+file_info_data_state_on_disk = Not loaded (lazy-loaded from disk)
+file_info_data_state_cached = Loaded, not decoded
+file_info_data_state_decoded = Loaded and decoded
+file_info_source_dependencies = Dependencies
+file_info_source_memory = In memory only
+file_info_unknown = Unknown
+file_info_yes = Yes
+file_info_no = No
+file_info_no_file_selected = No file selected.
+context_menu_open_pack_in_side_pane = Open Pack in &Side Pane
+context_menu_paste_from_side_pane = &Paste from Side Pane
+extra_pack_copy = &Copy
+extra_pack_add_to_pack = &Add to Pack
+extra_pack_pane_close = Close the side pane.
+add_from_extra_pack_conflicts = <p>The following files already exist in the PackFile:</p><ul>{"{"}{"}"}</ul><p>Do you want to overwrite them, or skip them and add only the new files?</p>
+add_from_extra_pack_overwrite = Overwrite
+add_from_extra_pack_skip = Skip Existing
+launch_game_with_current_mod_install = <p>The open Pack is not installed in the game's data folder, so the game cannot load it.</p><p>Do you want to install it now?</p>
+restore_mod_list_success = Mod list restored.
+schema_diff_title = Schema Changes
+schema_diff_only_open_pack = Only show tables in the open Pack
+schema_diff_export = Export as Markdown
+schema_browser_title = Schema Browser
+schema_browser_filter = Filter by table or field name...
+schema_browser_versions = Versions: {"{"}{"}"}
+schema_browser_field = Field
+schema_browser_type = Type
+schema_browser_key = Key
+schema_browser_reference = Reference
+schema_browser_description = Description
+template_template = Template:
+template_parameter_required = Required
+template_none_found = There are no templates in the templates folder.
+template_collisions = <p>The following files already exist in the Pack:</p><ul>{"{"}{"}"}</ul><p>Do you want to overwrite them?</p>
+pack_changed_on_disk = <p>The open Pack has been changed on disk by another program:</p><p>{"{"}{"}"}</p><p>Do you want to reload it?</p>
+pack_changed_on_disk_banner = The open Pack has been changed on disk, but it has unsaved changes. <a href="reload">Reload it</a> (losing the changes) or <a href="dismiss">dismiss</a> this warning. Saving it will overwrite the changes on disk.
@@ -13,6 +13,7 @@
 use anyhow::{anyhow, Result};
 
 use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
 
 use rpfm_extensions::dependencies::Dependencies;
 use rpfm_lib::integrations::log::*;
@@ -32,7 +33,7 @@ pub fn generate(config: &Config, pak_path: &Path, game_path: &Path, assembly_kit
     match &config.game {
         Some(game_info) => {
 
-            let mut dependencies = Dependencies::generate_dependencies_cache(game_info, game_path, assembly_kit_path)?;
+            let mut dependencies = Dependencies::generate_dependencies_cache(game_info, game_path, assembly_kit_path, &|_, _, _| {}, &AtomicBool::new(false))?;
             dependencies.save(pak_path)?;
 
             if config.verbose {
@@ -17,6 +17,7 @@ use std::collections::BTreeMap;
 use std::io::{BufReader, BufWriter};
 use std::fs::File;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
 
 use rpfm_extensions::dependencies::Dependencies;
 use rpfm_extensions::diagnostics::Diagnostics;
@@ -280,7 +281,7 @@ pub fn diagnose(config: &Config, game_path: &Path, pak_path: &Path, schema_path:
 
             // Trigger a diagnostics check.
             let mut diagnostics = Diagnostics::default();
-            diagnostics.check(&pack, &mut dependencies, game_info, game_path, &[], &schema);
+            diagnostics.check(&pack, &mut dependencies, game_info, game_path, &[], &schema, &AtomicBool::new(false))?;
 
             if config.verbose {
                 info!("Diagnosed problems in the following Packs:");
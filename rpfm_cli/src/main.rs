@@ -44,6 +44,9 @@ fn main() {
         warn!("Logging initialization has failed. No logs will be saved.");
     }
 
+    // Register the decoders for file types not supported by the lib.
+    rpfm_extensions::custom_files::register_all();
+
     // Build the Config struct to remember the current configuration when processing stuff.
     let config = Config::new(&cli.game, cli.verbose);
 
@@ -0,0 +1,161 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for cascade editions of DB values.
+
+use rpfm_lib::files::loc::Loc;
+use rpfm_lib::games::pfh_version::PFHVersion;
+use rpfm_lib::schema::{Definition, Field, FieldType};
+
+use crate::test_helpers::{field, insert_table, string};
+
+use super::*;
+
+/// This function creates a Schema with a small reference graph, including a cycle and a second-level reference.
+fn schema() -> Schema {
+    let mut schema = Schema::default();
+
+    let mut definition = Definition::new(1);
+    let mut cost = Field::default();
+    cost.set_name("cost".to_owned());
+    cost.set_field_type(FieldType::I32);
+    definition.set_fields(vec![field("unit", true, Some(("unit_stats", "unit"))), cost]);
+    definition.set_localised_fields(vec![field("onscreen_name", false, None), field("description", false, None)]);
+    schema.add_definition("main_units_tables", &definition);
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(vec![field("unit", true, Some(("main_units", "unit"))), field("stat", false, None)]);
+    schema.add_definition("unit_stats_tables", &definition);
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(vec![field("faction", true, None), field("unit", false, Some(("main_units", "unit")))]);
+    schema.add_definition("faction_units_tables", &definition);
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(vec![field("id", true, None), field("faction_unit", false, Some(("faction_units", "unit")))]);
+    schema.add_definition("faction_unit_upgrades_tables", &definition);
+
+    schema
+}
+
+/// This function adds a Loc with the provided keys to the Pack.
+fn insert_loc(pack: &mut Pack, keys: &[&str]) {
+    let mut loc = Loc::new(false);
+    let rows = keys.iter().map(|key| {
+        let mut row = loc.new_row();
+        row[0] = DecodedData::StringU16(key.to_string());
+        row[1] = DecodedData::StringU16("Text".to_owned());
+        row
+    }).collect::<Vec<_>>();
+    loc.set_data(&rows).unwrap();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, "text/db/test.loc")).unwrap();
+}
+
+/// This function returns the value of the cell at the provided path, row and column.
+fn cell(pack: &Pack, path: &str, row: usize, column: usize) -> String {
+    match pack.files().get(path).unwrap().decoded().unwrap() {
+        RFileDecoded::DB(table) => table.data(&None).unwrap()[row][column].data_to_string().to_string(),
+        RFileDecoded::Loc(table) => table.data(&None).unwrap()[row][column].data_to_string().to_string(),
+        _ => panic!("Not a table."),
+    }
+}
+
+/// This function creates a Pack with references to the key `old_unit` in all the tables of the test schema.
+fn test_pack(schema: &Schema) -> Pack {
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    insert_table(&mut pack, schema, "main_units_tables", &[vec![string("old_unit"), DecodedData::I32(500)]]);
+    insert_table(&mut pack, schema, "unit_stats_tables", &[vec![string("old_unit"), string("stats")], vec![string("other_unit"), string("stats")]]);
+    insert_table(&mut pack, schema, "faction_units_tables", &[vec![string("faction"), string("old_unit")], vec![string("old_unit"), string("other_unit")]]);
+    insert_table(&mut pack, schema, "faction_unit_upgrades_tables", &[vec![string("upgrade"), string("old_unit")]]);
+    insert_loc(&mut pack, &["main_units_onscreen_name_old_unit", "main_units_description_old_unit", "main_units_onscreen_name_other_unit"]);
+    pack
+}
+
+#[test]
+fn test_cascade_edition() {
+    let schema = schema();
+    let mut pack = test_pack(&schema);
+
+    let changes = vec![("unit".to_owned(), "old_unit".to_owned(), "new_unit".to_owned())];
+    let cascade_edition = CascadeEdition::new(&pack, &schema, "main_units_tables", &changes).unwrap();
+    assert_eq!(cascade_edition.edits(), &vec![
+        CascadeEdit::new("db/faction_unit_upgrades_tables/test", 0, 1, "faction_unit", "old_unit", "new_unit"),
+        CascadeEdit::new("db/faction_units_tables/test", 0, 1, "unit", "old_unit", "new_unit"),
+        CascadeEdit::new("db/unit_stats_tables/test", 0, 0, "unit", "old_unit", "new_unit"),
+        CascadeEdit::new("text/db/test.loc", 0, 0, "key", "main_units_onscreen_name_old_unit", "main_units_onscreen_name_new_unit"),
+        CascadeEdit::new("text/db/test.loc", 1, 0, "key", "main_units_description_old_unit", "main_units_description_new_unit"),
+    ]);
+    assert_eq!(cascade_edition.files_count(), 4);
+
+    // Building the edition must not touch the Pack.
+    assert_eq!(cell(&pack, "db/unit_stats_tables/test", 0, 0), "old_unit");
+
+    let edited_paths = cascade_edition.apply(&mut pack, &schema).unwrap();
+    assert_eq!(edited_paths.len(), 4);
+    assert!(edited_paths.contains(&ContainerPath::File("text/db/test.loc".to_owned())));
+
+    assert_eq!(cell(&pack, "db/main_units_tables/test", 0, 0), "old_unit");
+    assert_eq!(cell(&pack, "db/unit_stats_tables/test", 0, 0), "new_unit");
+    assert_eq!(cell(&pack, "db/unit_stats_tables/test", 1, 0), "other_unit");
+    assert_eq!(cell(&pack, "db/faction_units_tables/test", 0, 1), "new_unit");
+    assert_eq!(cell(&pack, "db/faction_units_tables/test", 1, 0), "old_unit");
+    assert_eq!(cell(&pack, "db/faction_unit_upgrades_tables/test", 0, 1), "new_unit");
+    assert_eq!(cell(&pack, "text/db/test.loc", 0, 0), "main_units_onscreen_name_new_unit");
+    assert_eq!(cell(&pack, "text/db/test.loc", 2, 0), "main_units_onscreen_name_other_unit");
+
+    // Non-key columns and unchanged values have nothing to update.
+    let changes = vec![("cost".to_owned(), "500".to_owned(), "600".to_owned()), ("unit".to_owned(), "new_unit".to_owned(), "new_unit".to_owned())];
+    assert!(CascadeEdition::new(&pack, &schema, "main_units_tables", &changes).unwrap().edits().is_empty());
+}
+
+#[test]
+fn test_cascade_edition_is_transactional() {
+    let schema = schema();
+    let changes = vec![("unit".to_owned(), "old_unit".to_owned(), "new_unit".to_owned())];
+
+    // Tables that fail to decode must stop the edition before it starts, naming the file.
+    let mut pack = test_pack(&schema);
+    pack.insert(RFile::new_from_vec(&[1, 2, 3], FileType::DB, 0, "db/unit_stats_tables/broken")).unwrap();
+    match CascadeEdition::new(&pack, &schema, "main_units_tables", &changes) {
+        Err(RLibError::CascadeEditionFailed(path, _)) => assert_eq!(path, "db/unit_stats_tables/broken"),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    // If a file changed between the preview and the edition, no file is edited.
+    let mut pack = test_pack(&schema);
+    let cascade_edition = CascadeEdition::new(&pack, &schema, "main_units_tables", &changes).unwrap();
+    insert_table(&mut pack, &schema, "unit_stats_tables", &[vec![string("other_unit"), string("stats")]]);
+    match cascade_edition.apply(&mut pack, &schema) {
+        Err(RLibError::CascadeEditionFailed(path, _)) => assert_eq!(path, "db/unit_stats_tables/test"),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    assert_eq!(cell(&pack, "db/faction_unit_upgrades_tables/test", 0, 1), "old_unit");
+    assert_eq!(cell(&pack, "db/faction_units_tables/test", 0, 1), "old_unit");
+    assert_eq!(cell(&pack, "text/db/test.loc", 0, 0), "main_units_onscreen_name_old_unit");
+}
+
+#[test]
+fn test_cascade_edition_rekeys_row_annotations() {
+    let schema = schema();
+    let mut pack = test_pack(&schema);
+    pack.settings_mut().set_row_annotation("db/unit_stats_tables/test", "old_unit", Some("Revisit these stats"));
+    pack.settings_mut().set_row_annotation("db/faction_units_tables/test", "faction", Some("Not a changed key"));
+    pack.settings_mut().set_row_annotation("text/db/test.loc", "main_units_description_old_unit", Some("Typo"));
+
+    let changes = vec![("unit".to_owned(), "old_unit".to_owned(), "new_unit".to_owned())];
+    let cascade_edition = CascadeEdition::new(&pack, &schema, "main_units_tables", &changes).unwrap();
+    cascade_edition.apply(&mut pack, &schema).unwrap();
+
+    let annotations = pack.settings().row_annotations();
+    assert_eq!(annotations["db/unit_stats_tables/test"].keys().collect::<Vec<_>>(), vec!["new_unit"]);
+    assert_eq!(annotations["db/faction_units_tables/test"].keys().collect::<Vec<_>>(), vec!["faction"]);
+    assert_eq!(annotations["text/db/test.loc"]["main_units_description_new_unit"], "Typo");
+}
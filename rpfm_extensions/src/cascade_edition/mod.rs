@@ -0,0 +1,330 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the [CascadeEdition] struct, used to update all the references to a value of a DB column when the value changes.
+//!
+//! Starting from a column of a table, the reference graph of the schema is walked backwards: every column referencing the edited
+//! column gets its cells with the old value updated, and then the same is done for the columns referencing those ones. If the edited
+//! column is the only key of its table, the Loc keys of its localised fields are updated too.
+
+use getset::Getters;
+
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+use rpfm_lib::error::{RLibError, Result};
+use rpfm_lib::files::{Container, ContainerPath, DecodeableExtraData, FileType, pack::Pack, RFile, RFileDecoded, table::DecodedData};
+use rpfm_lib::schema::Schema;
+
+#[cfg(test)] mod cascade_edition_test;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the cells a cascade edition is going to change.
+///
+/// Build it with [CascadeEdition::new], check its edits, and write them to the Pack with [CascadeEdition::apply].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct CascadeEdition {
+
+    /// Cells to change, sorted by path, row and column.
+    edits: Vec<CascadeEdit>,
+}
+
+/// This struct represents a cell to be changed by a cascade edition.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Getters)]
+#[getset(get = "pub")]
+pub struct CascadeEdit {
+
+    /// Path of the file the cell is in.
+    path: String,
+
+    /// Row of the cell.
+    row: usize,
+
+    /// Column of the cell.
+    column: usize,
+
+    /// Name of the column of the cell.
+    column_name: String,
+
+    /// Value of the cell before the edition.
+    value_before: String,
+
+    /// Value of the cell after the edition.
+    value_after: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl CascadeEdition {
+
+    /// This function finds all the cells of the provided Pack that need to change when the values of the provided table change.
+    ///
+    /// Each change is a column name, the old value and the new value. The edited table itself is not included, only the files referencing it.
+    /// This doesn't modify the Pack. If any of the files to check fails to decode, this fails with an error naming the file.
+    pub fn new(pack: &Pack, schema: &Schema, table_name: &str, changes: &[(String, String, String)]) -> Result<Self> {
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.set_schema(Some(schema));
+        let extra_data = Some(extra_data);
+
+        let mut edits = BTreeSet::new();
+        let mut decoded_files: BTreeMap<String, RFile> = BTreeMap::new();
+        for (column_name, value_before, value_after) in changes {
+            if value_before == value_after {
+                continue;
+            }
+
+            for (ref_table_name, ref_column_names) in referencing_columns(schema, table_name, column_name) {
+                let mut files = pack.files_by_path(&ContainerPath::Folder(format!("db/{}", ref_table_name)), true);
+                files.sort_by(|x, y| x.path_in_container_raw().cmp(y.path_in_container_raw()));
+
+                for file in files {
+                    let path = file.path_in_container_raw().to_owned();
+                    if !decoded_files.contains_key(&path) {
+                        decoded_files.insert(path.to_owned(), decoded_copy(file, &extra_data)?);
+                    }
+
+                    let file = &decoded_files[&path];
+
+                    if let Ok(RFileDecoded::DB(table)) = file.decoded() {
+                        let fields = table.definition().fields_processed();
+                        let data = table.data(&None)?;
+                        for ref_column_name in &ref_column_names {
+                            if let Some(column) = fields.iter().position(|field| field.name() == ref_column_name) {
+                                for (row, cells) in data.iter().enumerate() {
+                                    if cells[column].data_to_string() == value_before.as_str() {
+                                        edits.insert(CascadeEdit::new(&path, row, column, ref_column_name, value_before, value_after));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Loc keys of localised fields are built from the key of the table, so we can only update them for single-key tables.
+            if let Some(loc_fields) = localised_fields_for_key(schema, table_name, column_name) {
+                let short_table_name = table_name.strip_suffix("_tables").unwrap_or(table_name);
+                let mut files = pack.files_by_type(&[FileType::Loc]);
+                files.sort_by(|x, y| x.path_in_container_raw().cmp(y.path_in_container_raw()));
+
+                for file in files {
+                    let path = file.path_in_container_raw().to_owned();
+                    if !decoded_files.contains_key(&path) {
+                        decoded_files.insert(path.to_owned(), decoded_copy(file, &extra_data)?);
+                    }
+
+                    let file = &decoded_files[&path];
+
+                    if let Ok(RFileDecoded::Loc(table)) = file.decoded() {
+                        let data = table.data(&None)?;
+                        for loc_field in &loc_fields {
+                            let key_before = format!("{}_{}_{}", short_table_name, loc_field, value_before);
+                            let key_after = format!("{}_{}_{}", short_table_name, loc_field, value_after);
+                            for (row, cells) in data.iter().enumerate() {
+                                if cells[0].data_to_string() == key_before {
+                                    edits.insert(CascadeEdit::new(&path, row, 0, "key", &key_before, &key_after));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            edits: edits.into_iter().collect(),
+        })
+    }
+
+    /// This function writes the edits of this cascade edition to the provided Pack.
+    ///
+    /// All the edited files are prepared before touching the Pack, so if any of them fails to decode, or no longer has the expected
+    /// value in one of the cells to edit, nothing is written and the error names the file. It returns the paths of the edited files.
+    ///
+    /// Row annotations of the edited rows follow their rows if the edition changes their keys.
+    pub fn apply(&self, pack: &mut Pack, schema: &Schema) -> Result<Vec<ContainerPath>> {
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.set_schema(Some(schema));
+        let extra_data = Some(extra_data);
+
+        let mut edits_by_path: BTreeMap<&str, Vec<&CascadeEdit>> = BTreeMap::new();
+        for edit in &self.edits {
+            edits_by_path.entry(edit.path()).or_default().push(edit);
+        }
+
+        let mut edited_files = Vec::with_capacity(edits_by_path.len());
+        let mut rekeyed_rows = vec![];
+        for (path, edits) in edits_by_path {
+            let file = pack.files().get(path).ok_or_else(|| RLibError::CascadeEditionFailed(path.to_owned(), "the file is no longer in the Pack.".to_owned()))?;
+            let mut file = decoded_copy(file, &extra_data)?;
+            let has_annotations = pack.settings().row_annotations().contains_key(path);
+            let keys_before = if has_annotations { row_keys(&file)? } else { vec![] };
+
+            edit_file(&mut file, &edits).map_err(|error| match error {
+                RLibError::CascadeEditionFailed(..) => error,
+                _ => RLibError::CascadeEditionFailed(path.to_owned(), error.to_string()),
+            })?;
+
+            // Edits can change the keys of rows, so keep track of them to move their annotations.
+            if has_annotations {
+                let changes = keys_before.into_iter()
+                    .zip(row_keys(&file)?)
+                    .filter_map(|(key_before, key_after)| match (key_before, key_after) {
+                        (Some(key_before), Some(key_after)) if key_before != key_after => Some((key_before, key_after)),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                rekeyed_rows.push((path.to_owned(), changes));
+            }
+
+            edited_files.push(file);
+        }
+
+        // Only once every file is ready we replace them in the Pack, so a failure can't leave it half-edited.
+        let mut edited_paths = Vec::with_capacity(edited_files.len());
+        for file in edited_files {
+            let path = file.path_in_container_raw().to_owned();
+            if let Some(old_file) = pack.files_mut().get_mut(&path) {
+                *old_file = file;
+                edited_paths.push(ContainerPath::File(path));
+            }
+        }
+
+        for (path, changes) in rekeyed_rows {
+            pack.settings_mut().rekey_row_annotations(&path, &changes);
+        }
+
+        Ok(edited_paths)
+    }
+
+    /// This function returns the amount of different files this cascade edition edits.
+    pub fn files_count(&self) -> usize {
+        self.edits.iter().map(|edit| edit.path()).collect::<HashSet<_>>().len()
+    }
+}
+
+impl CascadeEdit {
+
+    /// This function creates a new edit for the cell at the provided path, row and column.
+    pub fn new(path: &str, row: usize, column: usize, column_name: &str, value_before: &str, value_after: &str) -> Self {
+        Self {
+            path: path.to_owned(),
+            row,
+            column,
+            column_name: column_name.to_owned(),
+            value_before: value_before.to_owned(),
+            value_after: value_after.to_owned(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------//
+//                             Utility functions
+//-------------------------------------------------------------------------------//
+
+/// This function returns all the columns that need to change when the provided column changes, grouped by table.
+///
+/// That's the columns referencing it, and the columns referencing those, and so on. The provided column is not included.
+fn referencing_columns(schema: &Schema, table_name: &str, column_name: &str) -> BTreeMap<String, BTreeSet<String>> {
+    let mut columns: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut visited = HashSet::from([(table_name.to_owned(), column_name.to_owned())]);
+    let mut pending = VecDeque::from([(table_name.to_owned(), column_name.to_owned())]);
+
+    // Reference graphs can have cycles, so each table/column is only walked once.
+    while let Some((table_name, column_name)) = pending.pop_front() {
+        let short_table_name = table_name.strip_suffix("_tables").unwrap_or(&table_name);
+        for (ref_table_name, definitions) in schema.definitions() {
+            for field in definitions.iter().flat_map(|definition| definition.fields_processed()) {
+                let is_reference = field.is_reference().as_ref().map(|(table, column)| table == short_table_name && *column == column_name).unwrap_or(false);
+                if is_reference && visited.insert((ref_table_name.to_owned(), field.name().to_owned())) {
+                    columns.entry(ref_table_name.to_owned()).or_default().insert(field.name().to_owned());
+                    pending.push_back((ref_table_name.to_owned(), field.name().to_owned()));
+                }
+            }
+        }
+    }
+
+    columns
+}
+
+/// This function returns the names of the localised fields of the provided table, if the provided column is its only key.
+///
+/// The newest definition of the table in the schema is used.
+fn localised_fields_for_key(schema: &Schema, table_name: &str, column_name: &str) -> Option<Vec<String>> {
+    let definition = schema.definitions_by_table_name(table_name)?.iter().max_by_key(|definition| definition.version())?;
+    let fields = definition.fields_processed();
+    let keys = fields.iter().filter(|field| field.is_key()).collect::<Vec<_>>();
+    let is_single_key = keys.len() == 1 && keys[0].name() == column_name;
+    if !is_single_key || definition.localised_fields().is_empty() {
+        return None;
+    }
+
+    Some(definition.localised_fields().iter().map(|field| field.name().to_owned()).collect())
+}
+
+/// This function returns a decoded copy of the provided file, so we can read or edit it without touching the Pack.
+fn decoded_copy(file: &RFile, extra_data: &Option<DecodeableExtraData>) -> Result<RFile> {
+    let mut file = file.clone();
+    file.decode(extra_data, true, false).map_err(|error| RLibError::CascadeEditionFailed(file.path_in_container_raw().to_owned(), error.to_string()))?;
+    Ok(file)
+}
+
+/// This function returns the keys of all the rows of the provided decoded file.
+fn row_keys(file: &RFile) -> Result<Vec<Option<String>>> {
+    match file.decoded()? {
+        RFileDecoded::DB(table) => table.table().row_keys(),
+        RFileDecoded::Loc(table) => table.table().row_keys(),
+        _ => Ok(vec![]),
+    }
+}
+
+/// This function performs the provided edits over the provided decoded file.
+fn edit_file(file: &mut RFile, edits: &[&CascadeEdit]) -> Result<()> {
+    let file_type = file.file_type();
+    match file.decoded_mut()? {
+        RFileDecoded::DB(table) => {
+            let fields = table.definition().fields_processed();
+            let data = table.data_mut()?;
+            for edit in edits {
+                let field = fields.get(edit.column).ok_or(RLibError::CascadeEditionFailed(edit.path.to_owned(), "the table structure has changed.".to_owned()))?;
+                let cell = cell_mut(data, edit)?;
+                *cell = DecodedData::new_from_type_and_string(field.field_type(), &edit.value_after)?;
+            }
+        }
+
+        RFileDecoded::Loc(table) => {
+            let mut data = table.data(&None)?.to_vec();
+            for edit in edits {
+                let cell = cell_mut(&mut data, edit)?;
+                *cell = DecodedData::StringU16(edit.value_after.to_owned());
+            }
+
+            table.set_data(&data)?;
+        }
+
+        _ => return Err(RLibError::DecodedDataDoesNotMatchFileType(FileType::DB, file_type)),
+    }
+
+    Ok(())
+}
+
+/// This function returns the cell of the provided edit, making sure it still has the value the edit expects.
+fn cell_mut<'a>(data: &'a mut [Vec<DecodedData>], edit: &CascadeEdit) -> Result<&'a mut DecodedData> {
+    match data.get_mut(edit.row).and_then(|row| row.get_mut(edit.column)) {
+        Some(cell) if cell.data_to_string() == edit.value_before => Ok(cell),
+        _ => Err(RLibError::CascadeEditionFailed(edit.path.to_owned(), format!("the cell at row {}, column \"{}\" no longer has the value \"{}\".", edit.row + 1, edit.column_name, edit.value_before))),
+    }
+}
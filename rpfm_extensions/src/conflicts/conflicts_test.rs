@@ -0,0 +1,104 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the conflict checker.
+
+use std::fs::{create_dir_all, remove_dir_all};
+
+use rpfm_lib::files::{db::DB, table::DecodedData};
+use rpfm_lib::games::{pfh_file_type::PFHFileType, pfh_version::PFHVersion};
+
+use crate::test_helpers::units_schema;
+
+use super::*;
+
+fn pack(name: &str, pfh_file_type: PFHFileType, schema: &Schema, rows: &[(&str, i32)], extra_file: &str) -> Pack {
+    let mut pack = Pack::new_with_name_and_version(name, PFHVersion::PFH5);
+    pack.set_pfh_file_type(pfh_file_type);
+
+    let definition = schema.definitions_by_table_name("units_tables").unwrap()[0].clone();
+    let mut table = DB::new(&definition, None, "units_tables", false);
+    let rows = rows.iter().map(|(key, value)| vec![DecodedData::StringU8(key.to_string()), DecodedData::I32(*value)]).collect::<Vec<_>>();
+    table.set_data(None, &rows).unwrap();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::DB(table), 0, "db/units_tables/data")).unwrap();
+    pack.insert(RFile::new_from_vec(name.as_bytes(), FileType::Text, 0, "text/shared.txt")).unwrap();
+    pack.insert(RFile::new_from_vec(name.as_bytes(), FileType::Text, 0, extra_file)).unwrap();
+    pack
+}
+
+#[test]
+fn test_pack_conflicts() {
+    let folder = std::env::temp_dir().join("rpfm_test_pack_conflicts");
+    let _ = remove_dir_all(&folder);
+    create_dir_all(&folder).unwrap();
+
+    let schema = units_schema();
+    let paths = [
+        ("!first.pack", PFHFileType::Mod, vec![("unit_a", 1), ("unit_b", 2)], "text/first.txt"),
+        ("z_last.pack", PFHFileType::Mod, vec![("unit_a", 1), ("unit_b", 3)], "text/only_last.txt"),
+        ("movie.pack", PFHFileType::Movie, vec![("unit_c", 1)], "text/first.txt"),
+    ].into_iter()
+        .map(|(name, pfh_file_type, rows, extra_file)| {
+            let path = folder.join(name);
+            pack(name, pfh_file_type, &schema, &rows, extra_file).save(Some(&path)).unwrap();
+            path
+        })
+        .collect::<Vec<_>>();
+
+    // The open Pack is also on the list of paths, and its unsaved version must be the one used.
+    let open_path = folder.join("my_mod.pack");
+    let mut open_pack = pack(&open_path.to_string_lossy(), PFHFileType::Mod, &schema, &[("unit_b", 2)], "text/my_mod.txt");
+    open_pack.save(Some(&open_path)).unwrap();
+    open_pack.insert(RFile::new_from_vec(b"unsaved", FileType::Text, 0, "text/only_last.txt")).unwrap();
+
+    let mut pack_paths = paths.to_vec();
+    pack_paths.push(open_path);
+    pack_paths.push(folder.join("missing.pack"));
+
+    let conflicts = PackConflicts::check(&open_pack, &pack_paths, Some(&schema));
+    assert_eq!(conflicts.load_order(), &vec!["movie.pack".to_owned(), "!first.pack".to_owned(), "my_mod.pack".to_owned(), "z_last.pack".to_owned()]);
+    assert_eq!(conflicts.unreadable_packs().len(), 1);
+    assert!(conflicts.unreadable_packs()[0].0.ends_with("missing.pack"));
+
+    let paths = conflicts.conflicts().iter().map(|conflict| conflict.path().as_str()).collect::<Vec<_>>();
+    assert_eq!(paths, vec!["db/units_tables/data", "text/first.txt", "text/only_last.txt", "text/shared.txt"]);
+
+    let shared = &conflicts.conflicts()[3];
+    assert_eq!(shared.winner(), "movie.pack");
+    assert_eq!(shared.losers(), &vec!["!first.pack".to_owned(), "my_mod.pack".to_owned(), "z_last.pack".to_owned()]);
+    assert!(shared.row_conflicts().is_none());
+
+    let only_last = &conflicts.conflicts()[2];
+    assert_eq!(only_last.winner(), "my_mod.pack");
+    assert_eq!(only_last.losers(), &vec!["z_last.pack".to_owned()]);
+
+    // Only rows with the same key and different data are row conflicts.
+    let table = &conflicts.conflicts()[0];
+    let row_conflicts = table.row_conflicts().as_ref().unwrap();
+    assert_eq!(row_conflicts.len(), 1);
+    assert_eq!(row_conflicts[0].key(), "unit_b");
+    assert_eq!(row_conflicts[0].rows().iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(), vec!["!first.pack", "my_mod.pack", "z_last.pack"]);
+
+    // Without schema, tables are not refined.
+    let conflicts_no_schema = PackConflicts::check(&open_pack, &pack_paths, None);
+    assert!(conflicts_no_schema.conflicts()[0].row_conflicts().is_none());
+
+    let by_pack = conflicts.conflicts_by_pack();
+    assert_eq!(by_pack["movie.pack"].len(), 3);
+    assert_eq!(by_pack["my_mod.pack"].len(), 3);
+    assert_eq!(by_pack["z_last.pack"].len(), 3);
+
+    let text = conflicts.to_text();
+    assert!(text.starts_with("Load Order:\n1. movie.pack\n"));
+    assert!(text.contains("text/only_last.txt\n    Winner: my_mod.pack\n    Overwritten: z_last.pack\n"));
+    assert!(text.contains("        unit_b (!first.pack, my_mod.pack, z_last.pack)\n"));
+
+    remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,210 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to find the files that collide between multiple Packs, simulating the load order of the game.
+
+The game loads Packs by type, and the files of a type take priority over the files of the types loaded before it
+(`Movie` over `Mod`, `Mod` over `Patch`,...). Within the same type, the first Pack by name wins, which is why people
+prefix their Packs with `!` to make them take priority over the rest.
+
+Only the indexes of the Packs are read, so this can be used against a lot of Packs without loading all their data in memory.
+The data of DB and Loc files is only loaded when refining their conflicts to row level.
+!*/
+
+use getset::Getters;
+use rayon::prelude::*;
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use rpfm_lib::files::{Container, DecodeableExtraData, FileType, pack::Pack, RFile, RFileDecoded, table::{MergeConflict, Table}};
+use rpfm_lib::schema::Schema;
+
+#[cfg(test)] mod conflicts_test;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the files that collide between multiple Packs.
+#[derive(Clone, Debug, Default, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct PackConflicts {
+
+    /// Names of the Packs checked, in load order. On conflicts, a Pack wins over all the Packs after it.
+    load_order: Vec<String>,
+
+    /// Files present in more than one Pack, sorted by path.
+    conflicts: Vec<FileConflict>,
+
+    /// Paths of the Packs that couldn't be read, with the reason why.
+    unreadable_packs: Vec<(String, String)>,
+}
+
+/// This struct contains the Packs containing a file with the same path.
+#[derive(Clone, Debug, Default, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct FileConflict {
+
+    /// Path of the file.
+    path: String,
+
+    /// Name of the Pack whose file is the one used by the game.
+    winner: String,
+
+    /// Names of the Packs whose file is overwritten by the winner's one, in load order.
+    losers: Vec<String>,
+
+    /// Rows with the same key but different data between the copies of the file, if they're DB or Loc files.
+    ///
+    /// The path of each row is the name of the Pack it comes from. This is `None` if the files are not tables,
+    /// if there is no schema to decode them, or if they couldn't be decoded with the same definition.
+    row_conflicts: Option<Vec<MergeConflict>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl PackConflicts {
+
+    /// This function checks for conflicts between the provided Pack and the Packs at the provided paths.
+    ///
+    /// If one of the paths points to the provided Pack, the provided Pack is used instead of the one on disk,
+    /// so unsaved changes are taken into account. If a schema is provided, conflicts between DB and Loc files are refined to row level.
+    pub fn check(pack: &Pack, pack_paths: &[PathBuf], schema: Option<&Schema>) -> Self {
+        let pack_path = PathBuf::from(pack.disk_file_path());
+        let (packs, unreadable_packs): (Vec<_>, Vec<_>) = pack_paths.par_iter()
+            .filter(|path| pack.disk_file_path().is_empty() || **path != pack_path)
+            .map(|path| Pack::read_and_merge(&[path.to_path_buf()], true, false).map_err(|error| (path.to_string_lossy().to_string(), error.to_string())))
+            .partition(|result| result.is_ok());
+
+        let packs = packs.into_iter().flatten().collect::<Vec<_>>();
+        let mut unreadable_packs = unreadable_packs.into_iter().filter_map(|result| result.err()).collect::<Vec<_>>();
+        unreadable_packs.sort();
+
+        let mut sorted_packs = packs.iter().chain(std::iter::once(pack)).collect::<Vec<_>>();
+        sort_by_load_order(&mut sorted_packs);
+
+        let load_order = sorted_packs.iter().map(|pack| pack.disk_file_name()).collect::<Vec<_>>();
+
+        // Group the files by path, keeping the load order within each path.
+        let mut files_by_path: BTreeMap<&str, Vec<(&str, &RFile)>> = BTreeMap::new();
+        for (pack, name) in sorted_packs.iter().zip(load_order.iter()) {
+            for (path, file) in pack.files() {
+                files_by_path.entry(path).or_default().push((name, file));
+            }
+        }
+
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.set_schema(schema);
+        let extra_data = Some(extra_data);
+
+        let conflicts = files_by_path.into_par_iter()
+            .filter(|(_, files)| files.len() > 1)
+            .map(|(path, files)| FileConflict {
+                path: path.to_owned(),
+                winner: files[0].0.to_owned(),
+                losers: files[1..].iter().map(|(name, _)| name.to_string()).collect(),
+                row_conflicts: if schema.is_some() { row_conflicts(&files, &extra_data) } else { None },
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            load_order,
+            conflicts,
+            unreadable_packs,
+        }
+    }
+
+    /// This function returns the conflicts each Pack is part of, by Pack name, either as a winner or as a loser.
+    pub fn conflicts_by_pack(&self) -> BTreeMap<&str, Vec<&FileConflict>> {
+        let mut conflicts: BTreeMap<&str, Vec<&FileConflict>> = BTreeMap::new();
+        for conflict in &self.conflicts {
+            conflicts.entry(&conflict.winner).or_default().push(conflict);
+            for loser in &conflict.losers {
+                conflicts.entry(loser).or_default().push(conflict);
+            }
+        }
+
+        conflicts
+    }
+
+    /// This function returns the conflicts formatted as plain text, ready to be saved to a file.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+
+        // Writing to a String never fails, so we can ignore the results here.
+        let _ = writeln!(text, "Load Order:");
+        for (index, name) in self.load_order.iter().enumerate() {
+            let _ = writeln!(text, "{}. {}", index + 1, name);
+        }
+
+        let _ = writeln!(text, "\nConflicts: {}", self.conflicts.len());
+        for conflict in &self.conflicts {
+            let _ = writeln!(text, "\n{}", conflict.path);
+            let _ = writeln!(text, "    Winner: {}", conflict.winner);
+            let _ = writeln!(text, "    Overwritten: {}", conflict.losers.join(", "));
+
+            if let Some(row_conflicts) = &conflict.row_conflicts {
+                let _ = writeln!(text, "    Conflicting Rows: {}", row_conflicts.len());
+                for row_conflict in row_conflicts {
+                    let packs = row_conflict.rows().iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+                    let _ = writeln!(text, "        {} ({})", row_conflict.key(), packs.join(", "));
+                }
+            }
+        }
+
+        if !self.unreadable_packs.is_empty() {
+            let _ = writeln!(text, "\nUnreadable Packs:");
+            for (path, error) in &self.unreadable_packs {
+                let _ = writeln!(text, "{}: {}", path, error);
+            }
+        }
+
+        text
+    }
+}
+
+/// This function sorts the provided Packs in the order the game loads them, with the Packs that take priority first.
+pub fn sort_by_load_order(packs: &mut [&Pack]) {
+    packs.sort_by_key(|pack| (Reverse(pack.pfh_file_type().value()), pack.disk_file_name()));
+}
+
+/// This function decodes copies of the provided files and returns the rows with the same key but different data between them.
+///
+/// Returns `None` if any of the files is not a DB/Loc file, fails to decode, or has a different definition than the rest.
+fn row_conflicts(files: &[(&str, &RFile)], extra_data: &Option<DecodeableExtraData>) -> Option<Vec<MergeConflict>> {
+    if files.iter().any(|(_, file)| !matches!(file.file_type(), FileType::DB | FileType::Loc)) {
+        return None;
+    }
+
+    let decoded = files.iter()
+        .map(|(name, file)| {
+            let mut file = (*file).clone();
+            match file.decode(extra_data, false, true).ok()?? {
+                RFileDecoded::DB(table) => Some((name.to_string(), table.table().clone())),
+                RFileDecoded::Loc(table) => Some((name.to_string(), table.table().clone())),
+                _ => None,
+            }
+        })
+        .collect::<Option<Vec<(String, Table)>>>()?;
+
+    let definition = decoded[0].1.definition();
+    if decoded.iter().any(|(_, table)| table.definition() != definition || table.table_name() != decoded[0].1.table_name()) {
+        return None;
+    }
+
+    let tables = decoded.iter().map(|(name, table)| (name.as_str(), table)).collect::<Vec<_>>();
+    Table::merge_conflicts(&tables).ok()
+}
@@ -0,0 +1,44 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the decoders of file types not supported natively by the lib.
+
+use rpfm_lib::files::{FileType, RFile, RFileDecoded, table::DecodedData};
+
+use super::*;
+
+#[test]
+fn test_key_values() {
+    register_all();
+
+    let data = b"name = Karl Franz\n\nfaction=wh_main_emp_empire\n";
+    let mut file = RFile::new_from_vec(data, FileType::Unknown, 0, "script/test.key_values");
+    file.guess_file_type().unwrap();
+    assert_eq!(file.file_type(), FileType::Custom);
+
+    match file.decode(&None, true, true).unwrap().unwrap() {
+        RFileDecoded::Custom(custom) => {
+            assert_eq!(custom.decoder(), key_values::NAME);
+            assert_eq!(custom.fields(), &vec![
+                ("name".to_owned(), DecodedData::StringU8("Karl Franz".to_owned())),
+                ("faction".to_owned(), DecodedData::StringU8("wh_main_emp_empire".to_owned())),
+            ]);
+        }
+        _ => panic!("Not decoded with the registered decoder."),
+    }
+
+    let encoded = file.encode(&None, false, false, true).unwrap().unwrap();
+    assert_eq!(encoded, b"name=Karl Franz\nfaction=wh_main_emp_empire\n".to_vec());
+
+    // Malformed files fail to decode, instead of being silently treated as unknown.
+    let mut file = RFile::new_from_vec(b"name", FileType::Unknown, 0, "script/broken.key_values");
+    file.guess_file_type().unwrap();
+    assert!(file.decode(&None, false, true).is_err());
+}
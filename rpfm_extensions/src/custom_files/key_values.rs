@@ -0,0 +1,44 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This is an example decoder for a trivial format: UTF-8 text files with one `key=value` pair per line.
+//!
+//! It's here to serve as reference for decoders of other formats. Each pair is decoded as a
+//! `StringU8` field named after its key. Empty lines are ignored.
+
+use rpfm_lib::error::{RLibError, Result};
+use rpfm_lib::files::table::DecodedData;
+
+/// Name of the decoder.
+pub const NAME: &str = "key_values";
+
+/// Extensions of the files supported by this decoder.
+pub const EXTENSIONS: &[&str] = &[".key_values"];
+
+/// This function decodes the provided data into a list of fields.
+pub fn decode(data: &[u8]) -> Result<Vec<(String, DecodedData)>> {
+    let text = String::from_utf8(data.to_vec())?;
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| match line.split_once('=') {
+            Some((key, value)) => Ok((key.trim().to_owned(), DecodedData::StringU8(value.trim().to_owned()))),
+            None => Err(RLibError::CustomDecodingError(format!("line {} is not a key=value pair.", index + 1))),
+        })
+        .collect()
+}
+
+/// This function encodes the provided list of fields back into data.
+pub fn encode(fields: &[(String, DecodedData)]) -> Result<Vec<u8>> {
+    Ok(fields.iter()
+        .map(|(key, value)| format!("{}={}\n", key, value.data_to_string()))
+        .collect::<String>()
+        .into_bytes())
+}
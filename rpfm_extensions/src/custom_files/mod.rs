@@ -0,0 +1,26 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the decoders for file types the lib doesn't support natively.
+//!
+//! Each decoder is registered in the lib's [custom decoder registry](rpfm_lib::files::custom) by [register_all],
+//! which needs to be called at startup. To add support for a new format, add a submodule with its
+//! [CustomDecoder] and register it there.
+
+use rpfm_lib::files::custom::{self, CustomDecoder};
+
+pub mod key_values;
+
+#[cfg(test)] mod custom_files_test;
+
+/// This function registers all the decoders of this module.
+pub fn register_all() {
+    custom::register(CustomDecoder::new(key_values::NAME, key_values::EXTENSIONS, key_values::decode, key_values::encode));
+}
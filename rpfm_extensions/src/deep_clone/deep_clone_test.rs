@@ -0,0 +1,96 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for deep cloning DB rows.
+
+use rpfm_lib::games::pfh_version::PFHVersion;
+use rpfm_lib::schema::FieldType;
+
+use crate::test_helpers::{field, insert_table, string};
+
+use super::*;
+
+/// This function creates a Schema with a small reference graph, including a cycle and a non-key reference.
+fn schema() -> Schema {
+    let mut schema = Schema::default();
+
+    let mut definition = Definition::new(1);
+    let mut cost = Field::default();
+    cost.set_name("cost".to_owned());
+    cost.set_field_type(FieldType::I32);
+    definition.set_fields(vec![field("unit", true, Some(("unit_stats", "unit"))), cost]);
+    definition.set_localised_fields(vec![field("onscreen_name", false, None)]);
+    schema.add_definition("main_units_tables", &definition);
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(vec![field("unit", true, Some(("main_units", "unit"))), field("group", true, None)]);
+    schema.add_definition("units_to_groupings_tables", &definition);
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(vec![field("unit", true, Some(("main_units", "unit"))), field("stat", false, None)]);
+    schema.add_definition("unit_stats_tables", &definition);
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(vec![field("faction", true, None), field("unit", false, Some(("main_units", "unit")))]);
+    schema.add_definition("faction_units_tables", &definition);
+
+    schema
+}
+
+#[test]
+fn test_deep_clone() {
+    let schema = schema();
+    let dependencies = Dependencies::default();
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+
+    insert_table(&mut pack, &schema, "main_units_tables", &[
+        vec![string("old_unit"), DecodedData::I32(500)],
+        vec![string("other_unit"), DecodedData::I32(100)],
+    ]);
+    insert_table(&mut pack, &schema, "units_to_groupings_tables", &[
+        vec![string("old_unit"), string("group_a")],
+        vec![string("old_unit"), string("group_b")],
+        vec![string("other_unit"), string("group_a")],
+    ]);
+    insert_table(&mut pack, &schema, "unit_stats_tables", &[vec![string("old_unit"), string("stats")]]);
+    insert_table(&mut pack, &schema, "faction_units_tables", &[vec![string("faction"), string("old_unit")]]);
+
+    let mut loc = Loc::new(false);
+    let mut row = loc.new_row();
+    row[0] = DecodedData::StringU16("main_units_onscreen_name_old_unit".to_owned());
+    row[1] = DecodedData::StringU16("Old Unit".to_owned());
+    loc.set_data(&[row]).unwrap();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, "text/db/test.loc")).unwrap();
+
+    let deep_clone = DeepClone::new(&mut pack, &dependencies, &schema, "main_units_tables", "old_unit", "new_unit", false).unwrap();
+    let rows = deep_clone.rows().iter().map(|row| (row.path().to_owned(), row.row().iter().map(|x| x.data_to_string().to_string()).collect::<Vec<_>>())).collect::<Vec<_>>();
+    assert_eq!(rows, vec![
+        ("db/main_units_tables/test".to_owned(), vec!["new_unit".to_owned(), "500".to_owned()]),
+        ("text/db/test.loc".to_owned(), vec!["main_units_onscreen_name_new_unit".to_owned(), "Old Unit".to_owned(), "false".to_owned()]),
+        ("db/unit_stats_tables/test".to_owned(), vec!["new_unit".to_owned(), "stats".to_owned()]),
+        ("db/units_to_groupings_tables/test".to_owned(), vec!["new_unit".to_owned(), "group_a".to_owned()]),
+        ("db/units_to_groupings_tables/test".to_owned(), vec!["new_unit".to_owned(), "group_b".to_owned()]),
+    ]);
+
+    let (modified_paths, added_paths) = deep_clone.apply(&mut pack, &schema).unwrap();
+    assert_eq!(modified_paths.len(), 4);
+    assert!(added_paths.is_empty());
+
+    if let Ok(RFileDecoded::DB(table)) = pack.files().get("db/units_to_groupings_tables/test").unwrap().decoded() {
+        assert_eq!(table.data(&None).unwrap().len(), 5);
+    } else {
+        panic!("Missing table.");
+    }
+
+    // Once cloned, the new key cannot be cloned over again.
+    assert!(DeepClone::new(&mut pack, &dependencies, &schema, "main_units_tables", "old_unit", "new_unit", false).is_err());
+    assert!(DeepClone::new(&mut pack, &dependencies, &schema, "main_units_tables", "missing_unit", "another_unit", false).is_err());
+    assert!(DeepClone::new(&mut pack, &dependencies, &schema, "main_units_tables", "old_unit", "old_unit", false).is_err());
+}
@@ -0,0 +1,367 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the [DeepClone] struct, used to clone a DB row along with the rows of other tables that depend on it.
+//!
+//! Starting from a column of a table, the reference graph of the schema is walked backwards: every table with a key column
+//! referencing a column being cloned gets its rows with the original key cloned with the new key, and then the same is done
+//! for the tables referencing that key column. Loc entries of localised fields are cloned too.
+
+use getset::Getters;
+
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+use rpfm_lib::error::{RLibError, Result};
+use rpfm_lib::files::{Container, ContainerPath, DecodeableExtraData, db::DB, FileType, loc::Loc, pack::Pack, RFile, RFileDecoded, table::{DecodedData, Table}};
+use rpfm_lib::schema::{Definition, DefinitionPatch, Field, Schema};
+
+use crate::dependencies::Dependencies;
+
+#[cfg(test)] mod deep_clone_test;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the rows a deep clone of a key is going to create.
+///
+/// Build it with [DeepClone::new], check its rows, and add them to the Pack with [DeepClone::apply].
+#[derive(Clone, Debug, Default, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct DeepClone {
+
+    /// Rows to create, in the order they were found while walking the reference graph.
+    rows: Vec<ClonedRow>,
+
+    /// Table name and definition of the DB files to create, for tables not yet in the Pack.
+    new_tables: BTreeMap<String, (String, Definition)>,
+}
+
+/// This struct represents a row to be created by a deep clone.
+#[derive(Clone, Debug, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct ClonedRow {
+
+    /// Path of the file the row is going to be added to.
+    path: String,
+
+    /// Name of the table the row is going to be added to. For Loc rows, this is the table of the localised field.
+    table_name: String,
+
+    /// Name of the column which value has been replaced with the new key.
+    column_name: String,
+
+    /// The new row, already adapted to the definition of the file it's going to be added to.
+    row: Vec<DecodedData>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl DeepClone {
+
+    /// This function finds all the rows that need to be created to clone the row with the provided key of the provided table.
+    ///
+    /// The key is searched in the first key column of the table. If `include_vanilla` is true, rows from the vanilla and parent files
+    /// are cloned too. Otherwise, only the rows of the provided Pack are cloned.
+    pub fn new(pack: &mut Pack, dependencies: &Dependencies, schema: &Schema, table_name: &str, key: &str, new_key: &str, include_vanilla: bool) -> Result<Self> {
+        if new_key.is_empty() || key == new_key {
+            return Err(RLibError::DeepCloneInvalidNewKey);
+        }
+
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.set_schema(Some(schema));
+        let extra_data = Some(extra_data);
+
+        let definition = table_definition(dependencies, schema, table_name).ok_or(RLibError::DecodingDBNoDefinitionsFound)?;
+        let fields = definition.fields_processed();
+        let key_column = match fields.iter().find(|field| field.is_key()).or_else(|| fields.first()) {
+            Some(field) => field.name().to_owned(),
+            None => return Err(RLibError::DecodingDBNoDefinitionsFound),
+        };
+
+        if matching_rows(pack, dependencies, &extra_data, table_name, &key_column, key, include_vanilla).is_empty() {
+            return Err(RLibError::DeepCloneKeyNotFound(key.to_owned(), table_name.to_owned()));
+        }
+
+        if !matching_rows(pack, dependencies, &extra_data, table_name, &key_column, new_key, include_vanilla).is_empty() {
+            return Err(RLibError::DeepCloneKeyAlreadyExists(new_key.to_owned(), table_name.to_owned()));
+        }
+
+        let mut deep_clone = Self::default();
+        let mut visited = HashSet::new();
+        let mut pending = VecDeque::from([(table_name.to_owned(), key_column)]);
+
+        // Reference graphs can have cycles, so each table/column is only walked once.
+        while let Some((table_name, column_name)) = pending.pop_front() {
+            if !visited.insert((table_name.to_owned(), column_name.to_owned())) {
+                continue;
+            }
+
+            let tables = matching_rows(pack, dependencies, &extra_data, &table_name, &column_name, key, include_vanilla);
+            if !tables.is_empty() {
+                let (path, target_definition) = deep_clone.target_table(pack, dependencies, schema, &table_name, &tables, new_key)?;
+                let target_fields = target_definition.fields_processed();
+                let target_patches = schema.patches_for_table(&table_name);
+                if let Some(target_column) = target_fields.iter().position(|field| field.name() == column_name) {
+                    for (definition, rows) in &tables {
+                        let fields = definition.fields_processed();
+                        for row in rows {
+                            let mut row = adapt_row(row, definition, &fields, &target_definition, &target_fields, target_patches);
+                            row[target_column] = DecodedData::new_from_type_and_string(target_fields[target_column].field_type(), new_key)?;
+                            deep_clone.push_row(ClonedRow {
+                                path: path.to_owned(),
+                                table_name: table_name.to_owned(),
+                                column_name: column_name.to_owned(),
+                                row,
+                            });
+                        }
+                    }
+
+                    // Loc keys of localised fields are built from the key of the table, so we can only clone them for single-key tables.
+                    let is_single_key = target_fields.iter().filter(|field| field.is_key()).count() == 1 && target_fields[target_column].is_key();
+                    if is_single_key {
+                        deep_clone.clone_loc_entries(pack, dependencies, &table_name, &target_definition, key, new_key, include_vanilla)?;
+                    }
+                }
+            }
+
+            pending.extend(referencing_key_columns(schema, &table_name, &column_name));
+        }
+
+        Ok(deep_clone)
+    }
+
+    /// This function adds the rows of this deep clone to the provided Pack, creating the files that don't exist yet.
+    ///
+    /// It returns the paths of the modified files and the paths of the added files.
+    pub fn apply(&self, pack: &mut Pack, schema: &Schema) -> Result<(Vec<ContainerPath>, Vec<ContainerPath>)> {
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.set_schema(Some(schema));
+        let extra_data = Some(extra_data);
+
+        let mut rows_by_path: BTreeMap<&str, Vec<Vec<DecodedData>>> = BTreeMap::new();
+        for row in &self.rows {
+            rows_by_path.entry(row.path()).or_default().push(row.row().to_vec());
+        }
+
+        let mut modified_paths = vec![];
+        let mut added_paths = vec![];
+        for (path, rows) in rows_by_path {
+            match pack.files_mut().get_mut(path) {
+                Some(file) => {
+                    file.decode(&extra_data, true, false)?;
+                    let file_type = file.file_type();
+                    match file.decoded_mut()? {
+                        RFileDecoded::DB(table) => table.data_mut()?.extend(rows),
+                        RFileDecoded::Loc(table) => {
+                            let mut data = table.data(&None)?.to_vec();
+                            data.extend(rows);
+                            table.set_data(&data)?;
+                        }
+                        _ => return Err(RLibError::DecodedDataDoesNotMatchFileType(FileType::DB, file_type)),
+                    }
+
+                    modified_paths.push(ContainerPath::File(path.to_owned()));
+                }
+
+                None => {
+                    let decoded = match self.new_tables.get(path) {
+                        Some((table_name, definition)) => {
+                            let mut table = DB::new(definition, schema.patches_for_table(table_name), table_name, false);
+                            table.set_data(None, &rows)?;
+                            RFileDecoded::DB(table)
+                        }
+                        None => {
+                            let mut table = Loc::new(false);
+                            table.set_data(&rows)?;
+                            RFileDecoded::Loc(table)
+                        }
+                    };
+
+                    pack.insert(RFile::new_from_decoded(&decoded, 0, path))?;
+                    added_paths.push(ContainerPath::File(path.to_owned()));
+                }
+            }
+        }
+
+        Ok((modified_paths, added_paths))
+    }
+
+    /// This function returns the path and definition of the table where the cloned rows of the provided table should go.
+    ///
+    /// That's the first table of its kind in the Pack or, if there's none, a new table named after the new key.
+    fn target_table(&mut self, pack: &Pack, dependencies: &Dependencies, schema: &Schema, table_name: &str, tables: &[(Definition, Vec<Vec<DecodedData>>)], new_key: &str) -> Result<(String, Definition)> {
+        let mut files = pack.files_by_path(&ContainerPath::Folder(format!("db/{}", table_name)), true);
+        files.sort_by(|x, y| x.path_in_container_raw().cmp(y.path_in_container_raw()));
+        for file in files {
+            if let Ok(RFileDecoded::DB(table)) = file.decoded() {
+                return Ok((file.path_in_container_raw().to_owned(), table.definition().clone()));
+            }
+        }
+
+        let path = format!("db/{}/{}", table_name, new_key);
+        if let Some((_, definition)) = self.new_tables.get(&path) {
+            return Ok((path, definition.clone()));
+        }
+
+        // New tables use the definition of the vanilla table, or the newest one we have if the table is not in vanilla.
+        let definition = match table_definition(dependencies, schema, table_name) {
+            Some(definition) if dependencies.db_version(table_name).is_some() => definition,
+            _ => tables.iter()
+                .map(|(definition, _)| definition)
+                .max_by_key(|definition| definition.version())
+                .cloned()
+                .ok_or(RLibError::DecodingDBNoDefinitionsFound)?,
+        };
+
+        self.new_tables.insert(path.to_owned(), (table_name.to_owned(), definition.clone()));
+        Ok((path, definition))
+    }
+
+    /// This function clones the loc entries of the localised fields of the provided table.
+    #[allow(clippy::too_many_arguments)]
+    fn clone_loc_entries(&mut self, pack: &mut Pack, dependencies: &Dependencies, table_name: &str, definition: &Definition, key: &str, new_key: &str, include_vanilla: bool) -> Result<()> {
+        if definition.localised_fields().is_empty() {
+            return Ok(());
+        }
+
+        let short_table_name = table_name.strip_suffix("_tables").unwrap_or(table_name);
+        let mut loc_paths = pack.files_by_type(&[FileType::Loc]).iter().map(|file| file.path_in_container_raw().to_owned()).collect::<Vec<_>>();
+        loc_paths.sort();
+
+        // Decode the Locs of the Pack beforehand, so we can search them later without needing mutable access.
+        for path in &loc_paths {
+            if let Some(file) = pack.files_mut().get_mut(path) {
+                let _ = file.decode(&None, true, false);
+            }
+        }
+
+        let target_path = loc_paths.first().cloned().unwrap_or_else(|| format!("text/db/{}.loc", new_key));
+        let mut loc_files = loc_paths.iter().filter_map(|path| pack.files().get(path)).collect::<Vec<_>>();
+        if include_vanilla {
+            let mut dependency_locs = dependencies.loc_data(true, true).unwrap_or_default();
+
+            // Later locs override earlier ones, so search them from the last one.
+            dependency_locs.reverse();
+            loc_files.extend(dependency_locs);
+        }
+
+        for field in definition.localised_fields() {
+            let loc_key = format!("{}_{}_{}", short_table_name, field.name(), key);
+            let row = loc_files.iter()
+                .filter_map(|file| if let Ok(RFileDecoded::Loc(table)) = file.decoded() { Some(table) } else { None })
+                .find_map(|table| table.data(&None).ok()?.iter().find(|row| row[0].data_to_string() == loc_key).cloned());
+
+            if let Some(mut row) = row {
+                row[0] = DecodedData::StringU16(format!("{}_{}_{}", short_table_name, field.name(), new_key));
+                self.push_row(ClonedRow {
+                    path: target_path.to_owned(),
+                    table_name: table_name.to_owned(),
+                    column_name: field.name().to_owned(),
+                    row,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function adds a row to the list of rows to create, unless there's already an identical row going to the same file.
+    fn push_row(&mut self, row: ClonedRow) {
+        if !self.rows.iter().any(|x| x.path == row.path && x.row == row.row) {
+            self.rows.push(row);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------//
+//                             Utility functions
+//-------------------------------------------------------------------------------//
+
+/// This function returns the definition to use for the provided table: the one of the vanilla table if we have it, or the newest one in the schema.
+pub(crate) fn table_definition(dependencies: &Dependencies, schema: &Schema, table_name: &str) -> Option<Definition> {
+    if let Some(version) = dependencies.db_version(table_name) {
+        if let Some(definition) = schema.definition_by_name_and_version(table_name, version) {
+            return Some(definition.clone());
+        }
+    }
+
+    schema.definitions_by_table_name(table_name)?.iter().max_by_key(|definition| definition.version()).cloned()
+}
+
+/// This function returns, for each instance of the provided table, its definition and the rows with the provided value in the provided column.
+///
+/// Instances without matching rows are not returned. Tables of the Pack that fail to decode are ignored.
+fn matching_rows(pack: &mut Pack, dependencies: &Dependencies, extra_data: &Option<DecodeableExtraData>, table_name: &str, column_name: &str, value: &str, include_vanilla: bool) -> Vec<(Definition, Vec<Vec<DecodedData>>)> {
+    let mut files = pack.files_by_path_mut(&ContainerPath::Folder(format!("db/{}", table_name)), true);
+    files.sort_by(|x, y| x.path_in_container_raw().cmp(y.path_in_container_raw()));
+    for file in files {
+        let _ = file.decode(extra_data, true, false);
+    }
+
+    let mut files = pack.files_by_path(&ContainerPath::Folder(format!("db/{}", table_name)), true);
+    files.sort_by(|x, y| x.path_in_container_raw().cmp(y.path_in_container_raw()));
+    if include_vanilla {
+        files.extend(dependencies.db_data(table_name, true, true).unwrap_or_default());
+    }
+
+    files.iter()
+        .filter_map(|file| if let Ok(RFileDecoded::DB(table)) = file.decoded() { Some(table) } else { None })
+        .filter_map(|table| {
+            let column = table.definition().fields_processed().iter().position(|field| field.name() == column_name)?;
+            let rows = table.data(&None).ok()?
+                .iter()
+                .filter(|row| row[column].data_to_string() == value)
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if rows.is_empty() {
+                None
+            } else {
+                Some((table.definition().clone(), rows))
+            }
+        })
+        .collect()
+}
+
+/// This function returns the key columns of all the tables that reference the provided table/column, as (table_name, column_name).
+fn referencing_key_columns(schema: &Schema, table_name: &str, column_name: &str) -> BTreeSet<(String, String)> {
+    let short_table_name = table_name.strip_suffix("_tables").unwrap_or(table_name);
+    schema.definitions().iter()
+        .flat_map(|(ref_table_name, definitions)| definitions.iter()
+            .flat_map(|definition| definition.fields_processed())
+            .filter(|field| field.is_key() && field.is_reference().as_ref().map(|(table, column)| table == short_table_name && column == column_name).unwrap_or(false))
+            .map(|field| (ref_table_name.to_owned(), field.name().to_owned()))
+            .collect::<Vec<_>>()
+        )
+        .collect()
+}
+
+/// This function adapts a row to a different definition of the same table, matching columns by name.
+///
+/// Columns missing in the original definition, or which data cannot be converted, get their default value.
+fn adapt_row(row: &[DecodedData], definition: &Definition, fields: &[Field], target_definition: &Definition, target_fields: &[Field], target_patches: Option<&DefinitionPatch>) -> Vec<DecodedData> {
+    if definition == target_definition {
+        return row.to_vec();
+    }
+
+    let mut new_row = Table::new_row(target_definition, target_patches);
+    for (index, target_field) in target_fields.iter().enumerate() {
+        if let Some(column) = fields.iter().position(|field| field.name() == target_field.name()) {
+            if let Ok(data) = row[column].convert_between_types(target_field.field_type()) {
+                new_row[index] = data;
+            }
+        }
+    }
+
+    new_row
+}
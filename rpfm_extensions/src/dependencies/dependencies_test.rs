@@ -0,0 +1,184 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the dependencies cache.
+
+use std::fs::{create_dir_all, remove_dir_all, remove_file, write, OpenOptions};
+
+use rpfm_lib::games::pfh_version::PFHVersion;
+
+use super::*;
+
+#[test]
+fn test_dependencies_source() {
+    let folder = std::env::temp_dir().join("rpfm_test_dependencies_source");
+    let _ = remove_dir_all(&folder);
+    create_dir_all(&folder).unwrap();
+
+    let path = folder.join("data.pack");
+    write(&path, [0; 16]).unwrap();
+
+    let source = DependenciesSource::from_path(&path).unwrap();
+    assert_eq!(*source.size(), 16);
+    assert!(!source.is_outdated());
+
+    // Changing the size of the file must be detected, even if the modification date doesn't change.
+    OpenOptions::new().append(true).open(&path).unwrap().write_all(&[0; 4]).unwrap();
+    assert!(source.is_outdated());
+    assert!(!DependenciesSource::from_path(&path).unwrap().is_outdated());
+
+    remove_file(&path).unwrap();
+    assert!(source.is_outdated());
+    assert!(DependenciesSource::from_path(&path).is_err());
+
+    remove_dir_all(&folder).unwrap();
+}
+
+#[test]
+fn test_previous_parent_data() {
+    let pack = |name: &str, paths: &[&str]| ParentPack {
+        source: DependenciesSource { path: PathBuf::from(name), size: 16, last_modified: 1_600_000_000 },
+        dependencies: vec![],
+        paths: paths.iter().map(|path| path.to_string()).collect(),
+    };
+
+    let files = ["text/a.txt", "text/b.txt", "text/c.txt"].iter()
+        .map(|path| (path.to_string(), RFile::new_from_vec(path.as_bytes(), FileType::Text, 0, path)))
+        .collect();
+
+    let mut previous = PreviousParentData {
+        packs: vec![pack("a.pack", &["text/a.txt", "text/b.txt"]), pack("b.pack", &["text/b.txt", "text/c.txt"])],
+        files,
+    };
+
+    // Packs with files overwritten by a later Pack need to be read again.
+    assert!(previous.take(&pack("a.pack", &[]).source).is_none());
+
+    let (parent_pack, files) = previous.take(&pack("b.pack", &[]).source).unwrap();
+    assert_eq!(parent_pack.paths, files.keys().cloned().collect::<HashSet<_>>());
+    assert_eq!(files.len(), 2);
+
+    // Packs that changed on disk are not reused.
+    let mut changed = pack("b.pack", &[]).source;
+    changed.size = 20;
+    assert!(previous.take(&changed).is_none());
+}
+
+#[test]
+fn test_load_version_mismatch() {
+    let folder = std::env::temp_dir().join("rpfm_test_dependencies_version");
+    let _ = remove_dir_all(&folder);
+    let path = folder.join("cache.pak2");
+
+    let mut dependencies = Dependencies {
+        build_date: 1_700_000_000,
+        vanilla_sources: vec![DependenciesSource {
+            path: PathBuf::from("data.pack"),
+            size: 16,
+            last_modified: 1_600_000_000,
+        }],
+        ..Default::default()
+    };
+    dependencies.save(&path).unwrap();
+
+    let loaded = Dependencies::load(&path, &None).unwrap();
+    assert_eq!(loaded.build_date, dependencies.build_date);
+    assert_eq!(loaded.vanilla_sources, dependencies.vanilla_sources);
+
+    // Caches from before the version was stored start with the build date.
+    let old_data = bincode::serialize(&(&dependencies.build_date, &Vec::<RFile>::new())).unwrap();
+    write(path.with_extension("pak1"), old_data).unwrap();
+    assert!(matches!(Dependencies::load(&path, &None), Err(RLibError::DependenciesCacheVersionMismatch(_, CACHE_VERSION))));
+
+    // Corrupted caches must fail to load too.
+    write(path.with_extension("pak1"), [1, 0, 0, 0, 1]).unwrap();
+    assert!(Dependencies::load(&path, &None).is_err());
+
+    remove_dir_all(&folder).unwrap();
+}
+
+/// This function creates a table with a single StringU8 column and the provided keys.
+fn land_units(keys: &[&str]) -> DB {
+    let mut field = Field::default();
+    field.set_name("key".to_owned());
+    field.set_is_key(true);
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(vec![field]);
+
+    let mut table = DB::new(&definition, None, "land_units_tables", false);
+    let rows = keys.iter().map(|key| vec![DecodedData::StringU8(key.to_string())]).collect::<Vec<_>>();
+    table.set_data(None, &rows).unwrap();
+    table
+}
+
+#[test]
+fn test_db_reference_data_cached() {
+    let mut dependencies = Dependencies::default();
+    let path = "db/land_units_tables/data__".to_owned();
+    dependencies.vanilla_files.insert(path.to_owned(), RFile::new_from_decoded(&RFileDecoded::DB(land_units(&["vanilla_unit"])), 0, &path));
+    dependencies.vanilla_tables.insert("land_units_tables".to_owned(), vec![path]);
+
+    let mut unit = Field::default();
+    unit.set_name("unit".to_owned());
+    unit.set_is_reference(Some(("land_units".to_owned(), "key".to_owned())));
+    let mut definition = Definition::new(3);
+    definition.set_fields(vec![Field::default(), unit]);
+
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    let references = dependencies.db_reference_data_cached(&pack, "main_units_tables", &definition);
+    assert_eq!(references.keys().collect::<Vec<_>>(), vec![&1]);
+    assert!(references[&1].data().contains_key("vanilla_unit"));
+
+    // Columns without references don't count for the stats.
+    assert_eq!((*dependencies.reference_cache().hits(), *dependencies.reference_cache().misses()), (0, 1));
+
+    // Changes in the local Pack must show up, even if the column is cached.
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::DB(land_units(&["local_unit"])), 0, "db/land_units_tables/mod")).unwrap();
+    let references = dependencies.db_reference_data_cached(&pack, "main_units_tables", &definition);
+    assert!(references[&1].data().contains_key("vanilla_unit"));
+    assert!(references[&1].data().contains_key("local_unit"));
+    assert_eq!((*dependencies.reference_cache().hits(), *dependencies.reference_cache().misses()), (1, 1));
+
+    // Other versions of the same table are cached separately.
+    definition.set_version(4);
+    dependencies.db_reference_data_cached(&pack, "main_units_tables", &definition);
+    assert_eq!(dependencies.reference_cache().len(), 2);
+}
+
+#[test]
+fn test_reference_cache_lru() {
+    let references = |rows: usize| {
+        let mut references = TableReferences::default();
+        references.data_mut().extend((0..rows).map(|row| (row.to_string(), String::new())));
+        references
+    };
+
+    let key = |column: i32| ("main_units_tables".to_owned(), 1, column);
+    let mut cache = ReferenceCache::new(10);
+    cache.insert(key(0), references(4));
+    cache.insert(key(1), references(4));
+    assert!(cache.get(&key(0)).is_some());
+
+    // Going over the limit removes the least recently used column.
+    cache.insert(key(2), references(4));
+    assert_eq!(*cache.rows(), 8);
+    assert!(cache.get(&key(1)).is_none());
+    assert!(cache.get(&key(0)).is_some());
+
+    // A column bigger than the limit is kept on its own.
+    cache.insert(key(3), references(20));
+    assert_eq!(cache.len(), 1);
+    assert_eq!(*cache.rows(), 20);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!((*cache.hits(), *cache.misses()), (2, 1));
+}
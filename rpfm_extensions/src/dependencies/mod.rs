@@ -18,14 +18,27 @@ use std::collections::{HashMap, HashSet};
 use std::fs::{DirBuilder, File};
 use std::io::{BufReader, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::thread::{JoinHandle, spawn};
 
 use rpfm_lib::error::{Result, RLibError};
-use rpfm_lib::files::{Container, ContainerPath, db::DB, DecodeableExtraData, FileType, pack::Pack, RFile, RFileDecoded};
+use rpfm_lib::files::{Container, ContainerPath, db::DB, DecodeableExtraData, FileType, pack::Pack, RFile, RFileDecoded, table::DecodedData};
 use rpfm_lib::games::GameInfo;
 use rpfm_lib::integrations::assembly_kit::table_data::RawTable;
-use rpfm_lib::schema::{Definition, Schema};
-use rpfm_lib::utils::{current_time, last_modified_time_from_files, starts_with_case_insensitive};
+use rpfm_lib::schema::{Definition, Field, Schema};
+use rpfm_lib::utils::{current_time, last_modified_time_from_file, last_modified_time_from_files, starts_with_case_insensitive};
+
+use self::reference_cache::ReferenceCache;
+
+pub mod reference_cache;
+
+#[cfg(test)] mod dependencies_test;
+
+/// Amount of files processed between progress reports when generating the dependencies cache.
+const PROGRESS_REPORT_INTERVAL: u32 = 1000;
+
+/// Version of the format of the dependencies cache files. Caches with a different version need to be regenerated.
+const CACHE_VERSION: u32 = 1;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -39,14 +52,20 @@ use rpfm_lib::utils::{current_time, last_modified_time_from_files, starts_with_c
 ///     - vanilla_files.
 ///     - vanilla_tables.
 ///     - vanilla_locs.
+///     - vanilla_sources.
 /// - Then, we have the ones that gets regenerated on rebuild:
 ///     - parent_files.
 ///     - parent_tables.
 ///     - parent_locs.
 ///     - local_tables_references.
+///     - reference_cache.
+/// - The files of the parent Packs are only kept in parent_files. parent_packs keeps what's needed to know which files came from each Pack,
+///   so a refresh only needs to re-read the ones that changed on disk.
 ///
 /// - Then, on runtime, we add decoded table's reference data to this one, so we don't need to recalculate it again.
 ///     - local_tables_references,
+///     - reference_cache,
+///     - cached_loc_keys,
 #[derive(Default, Debug, Clone, Getters, Serialize, Deserialize)]
 #[getset(get = "pub")]
 pub struct Dependencies {
@@ -54,6 +73,9 @@ pub struct Dependencies {
     /// Date of the generation of this dependencies cache. For checking if it needs an update.
     build_date: u64,
 
+    /// Game Packs used to generate this dependencies cache, in load order.
+    vanilla_sources: Vec<DependenciesSource>,
+
     /// Data to quickly load CA dependencies from disk.
     vanilla_files: HashMap<String, RFile>,
 
@@ -96,6 +118,58 @@ pub struct Dependencies {
 
     /// DB Files only available on the assembly kit. Usable only for references. Do not use them as the base for new tables.
     asskit_only_db_tables: HashMap<String, DB>,
+
+    /// Cached reference data of the vanilla, parent and assembly kit tables, by column.
+    ///
+    /// This is for runtime caching, and it must not be serialized to disk.
+    #[serde(skip_serializing, skip_deserializing)]
+    reference_cache: ReferenceCache,
+
+    /// Cached keys of the vanilla and parent locs.
+    ///
+    /// This is for runtime caching, and it must not be serialized to disk.
+    #[serde(skip_serializing, skip_deserializing)]
+    #[getset(skip)]
+    cached_loc_keys: Option<HashSet<String>>,
+
+    /// Parent Packs read on the last rebuild, in load order. Used to avoid re-reading Packs that didn't change.
+    ///
+    /// Not serialized, regenerated from parent Packs on rebuild.
+    #[serde(skip_serializing, skip_deserializing)]
+    #[getset(skip)]
+    parent_packs: Vec<ParentPack>,
+}
+
+/// This holds the metadata of a Pack on disk used as source for the dependencies, to know if it changed since we read it.
+#[derive(Eq, PartialEq, Clone, Default, Debug, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct DependenciesSource {
+
+    /// Path of the Pack on disk.
+    path: PathBuf,
+
+    /// Size of the Pack, in bytes.
+    size: u64,
+
+    /// Last modification date of the Pack.
+    last_modified: u64,
+}
+
+/// This holds the data of a parent Pack, as it was read from disk.
+///
+/// Its files are kept in the parent files of the dependencies, this only keeps their paths.
+#[derive(Clone, Default, Debug)]
+struct ParentPack {
+    source: DependenciesSource,
+    dependencies: Vec<String>,
+    paths: HashSet<String>,
+}
+
+/// This holds the parent data from before a rebuild, so the files of the parent Packs that didn't change can be reused.
+#[derive(Default)]
+struct PreviousParentData {
+    packs: Vec<ParentPack>,
+    files: HashMap<String, RFile>,
 }
 
 /// This holds the reference data for a table's column.
@@ -135,22 +209,66 @@ impl Dependencies {
         // If we only want to reload the parent mods, not the full dependencies, we can skip this section.
         if let Some(file_path) = file_path {
 
-            // First, clear the current data, so we're not left with broken data afterwards if the next operations fail.
-            *self = Self::default();
+            // First, clear the current vanilla data, so we're not left with broken data afterwards if the next operations fail.
+            self.replace_vanilla_data(Self::default());
 
             // Try to load the binary file and check if it's even valid.
             let stored_data = Self::load(file_path, schema)?;
             if !stored_data.needs_updating(game_info, game_path)? {
-                *self = stored_data;
+                self.replace_vanilla_data(stored_data);
             }
         }
 
+        self.rebuild_parent_data(schema, parent_pack_names, game_info, game_path)?;
+        Ok(())
+    }
+
+    /// This function refreshes the dependencies cache, re-reading only the Packs that changed on disk since they were read.
+    ///
+    /// The vanilla data is only loaded from the cache at `file_path` if it's not already loaded. If the game Packs changed
+    /// since the cache was generated, or the cache cannot be loaded, [RLibError::DependenciesCacheNotGeneratedorOutOfDate] is returned
+    /// and only the vanilla part of the cache needs to be regenerated. Any other error comes from the parent Packs.
+    ///
+    /// It returns the paths of the sources that were read from disk.
+    pub fn refresh(&mut self, schema: &Option<Schema>, parent_pack_names: &[String], file_path: &Path, game_info: &GameInfo, game_path: &Path) -> Result<Vec<PathBuf>> {
+        let mut refreshed = vec![];
+
+        if self.vanilla_sources.is_empty() {
+            let stored_data = Self::load(file_path, schema).map_err(|_| RLibError::DependenciesCacheNotGeneratedorOutOfDate)?;
+            self.replace_vanilla_data(stored_data);
+            refreshed.push(file_path.to_path_buf());
+        }
+
+        if self.vanilla_sources_changed(game_info, game_path).unwrap_or(true) {
+            return Err(RLibError::DependenciesCacheNotGeneratedorOutOfDate);
+        }
+
+        refreshed.append(&mut self.rebuild_parent_data(schema, parent_pack_names, game_info, game_path)?);
+        Ok(refreshed)
+    }
+
+    /// This function replaces the vanilla data of this cache with the one of the provided cache, keeping the current parent data.
+    fn replace_vanilla_data(&mut self, mut vanilla_data: Self) {
+        vanilla_data.parent_packs = std::mem::take(&mut self.parent_packs);
+        vanilla_data.parent_files = std::mem::take(&mut self.parent_files);
+        *self = vanilla_data;
+    }
+
+    /// This function rebuilds the data of the parent Packs, re-reading only the Packs that changed on disk since they were read.
+    ///
+    /// It returns the paths of the Packs that were read from disk.
+    fn rebuild_parent_data(&mut self, schema: &Option<Schema>, parent_pack_names: &[String], game_info: &GameInfo, game_path: &Path) -> Result<Vec<PathBuf>> {
+
         // Clear the table's cached data, to ensure it gets rebuild properly when needed.
         self.local_tables_references.clear();
+        self.reference_cache.clear();
+        self.cached_loc_keys = None;
+        self.parent_tables.clear();
+        self.parent_locs.clear();
 
         // Preload parent mods of the currently loaded Pack.
-        self.load_parent_packs(parent_pack_names, game_info, game_path)?;
-        self.parent_files.par_iter_mut().map(|(_, file)| file.guess_file_type()).collect::<Result<()>>()?;
+        let refreshed = self.load_parent_packs(parent_pack_names, game_info, game_path)?;
+        self.parent_files.par_iter_mut().map(|(_, file)| file.guess_file_type_for_game(game_info)).collect::<Result<()>>()?;
 
         // Then build the table/loc lists, for easy access.
         self.parent_files.iter()
@@ -194,6 +312,7 @@ impl Dependencies {
         }).flatten().collect::<HashSet<String>>();
 
         // Only decode the tables if we passed a schema. If not, it's responsability of the user to decode them later.
+        // Tables reused from Packs that didn't change are already decoded.
         if let Some(schema) = schema {
             let mut decode_extra_data = DecodeableExtraData::default();
             decode_extra_data.set_schema(Some(schema));
@@ -203,25 +322,49 @@ impl Dependencies {
                 self.parent_files.remove(path).map(|file| (path.to_owned(), file))
             }).collect::<Vec<_>>();
 
-            files.par_iter_mut().for_each(|(_, file)| {
+            files.par_iter_mut().filter(|(_, file)| file.decoded().is_err()).for_each(|(_, file)| {
                 let _ = file.decode(&extra_data, true, false);
             });
 
             self.parent_files.par_extend(files);
         }
 
-        Ok(())
+        Ok(refreshed)
     }
 
     /// This function generates the dependencies cache for the game provided and returns it.
-    pub fn generate_dependencies_cache(game_info: &GameInfo, game_path: &Path, asskit_path: &Option<PathBuf>) -> Result<Self> {
+    ///
+    /// The `progress` closure receives the current step, the total amount of steps (0 if unknown) and a description of the current step.
+    ///
+    /// If `cancel` is set while generating the cache, the generation is stopped as soon as possible and [RLibError::OperationCancelled] is returned.
+    pub fn generate_dependencies_cache(game_info: &GameInfo, game_path: &Path, asskit_path: &Option<PathBuf>, progress: &(dyn Fn(u32, u32, &str) + Sync), cancel: &AtomicBool) -> Result<Self> {
         let mut cache = Self::default();
         cache.build_date = current_time()?;
+        cache.vanilla_sources = game_info.ca_packs_paths(game_path)?.iter()
+            .map(|path| DependenciesSource::from_path(path))
+            .collect::<Result<Vec<_>>>()?;
+
+        progress(0, 0, "Reading game files.");
         cache.vanilla_files = Pack::read_and_merge_ca_packs(game_info, game_path)?.files().clone();
+        if cancel.load(Ordering::Relaxed) {
+            return Err(RLibError::OperationCancelled);
+        }
 
+        // Only report every few files, as reporting every single one of them slows the whole process down.
+        let total = cache.vanilla_files.len() as u32;
+        let processed = AtomicU32::new(0);
         let cacheable = cache.vanilla_files.par_iter_mut()
             .filter_map(|(_, file)| {
-                let _ = file.guess_file_type();
+                if cancel.load(Ordering::Relaxed) {
+                    return None;
+                }
+
+                let _ = file.guess_file_type_for_game(game_info);
+
+                let current = processed.fetch_add(1, Ordering::Relaxed) + 1;
+                if current % PROGRESS_REPORT_INTERVAL == 0 || current == total {
+                    progress(current, total, "Processing game files.");
+                }
 
                 match file.file_type() {
                     FileType::DB |
@@ -231,6 +374,10 @@ impl Dependencies {
             })
             .collect::<Vec<&mut RFile>>();
 
+        if cancel.load(Ordering::Relaxed) {
+            return Err(RLibError::OperationCancelled);
+        }
+
         cacheable.iter()
             .for_each(|file| {
                 match file.file_type() {
@@ -271,6 +418,7 @@ impl Dependencies {
 
         // This one can fail, leaving the dependencies with only game data.
         if let Some(path) = asskit_path {
+            progress(0, 0, "Processing Assembly Kit tables.");
             let _ = cache.generate_asskit_only_db_tables(path, game_info.raw_db_version());
         }
 
@@ -320,32 +468,37 @@ impl Dependencies {
     /// This function builds the local db references data for the table with the definition you pass to, and returns it.
     pub fn generate_references(&self, definition: &Definition) -> HashMap<i32, TableReferences> {
         definition.fields_processed().into_iter().enumerate().filter_map(|(column, field)| {
-            if let Some((ref ref_table, ref ref_column)) = field.is_reference() {
-                if !ref_table.is_empty() && !ref_column.is_empty() {
-                    let ref_table = format!("{}_tables", ref_table);
+            self.generate_column_references(&field).map(|references| (column as i32, references))
+        }).collect::<HashMap<_, _>>()
+    }
 
-                    // Get his lookup data if it has it.
-                    let lookup_data = if let Some(ref data) = field.lookup() { data.to_vec() } else { Vec::with_capacity(0) };
-                    let mut references = TableReferences::default();
-                    *references.field_name_mut() = field.name().to_owned();
+    /// This function builds the vanilla/parent references data for the provided field, if it references another table.
+    fn generate_column_references(&self, field: &Field) -> Option<TableReferences> {
+        if let Some((ref ref_table, ref ref_column)) = field.is_reference() {
+            if !ref_table.is_empty() && !ref_column.is_empty() {
+                let ref_table = format!("{}_tables", ref_table);
 
-                    let fake_found = self.db_reference_data_from_asskit_tables(&mut references, (&ref_table, ref_column, &lookup_data));
-                    let real_found = self.db_reference_data_from_from_vanilla_and_modded_tables(&mut references, (&ref_table, ref_column, &lookup_data));
+                // Get his lookup data if it has it.
+                let lookup_data = if let Some(ref data) = field.lookup() { data.to_vec() } else { Vec::with_capacity(0) };
+                let mut references = TableReferences::default();
+                *references.field_name_mut() = field.name().to_owned();
 
-                    if fake_found && real_found.is_none() {
-                        references.referenced_table_is_ak_only = true;
-                    }
+                let fake_found = self.db_reference_data_from_asskit_tables(&mut references, (&ref_table, ref_column, &lookup_data));
+                let real_found = self.db_reference_data_from_from_vanilla_and_modded_tables(&mut references, (&ref_table, ref_column, &lookup_data));
 
-                    if let Some(ref_definition) = real_found {
-                        if ref_definition.localised_fields().iter().any(|x| x.name() == ref_column) {
-                            references.referenced_column_is_localised = true;
-                        }
+                if fake_found && real_found.is_none() {
+                    references.referenced_table_is_ak_only = true;
+                }
+
+                if let Some(ref_definition) = real_found {
+                    if ref_definition.localised_fields().iter().any(|x| x.name() == ref_column) {
+                        references.referenced_column_is_localised = true;
                     }
+                }
 
-                    Some((column as i32, references))
-                } else { None }
+                Some(references)
             } else { None }
-        }).collect::<HashMap<_, _>>()
+        } else { None }
     }
 
     /// This function tries to load dependencies from the path provided.
@@ -355,12 +508,18 @@ impl Dependencies {
         // Because bincode is not multithreaded and, while reading 3 medium files is slower than a big one,
         // deserializing 3 medium files in 3 separate threads is way faster than 1 big file in 1 thread.
         let mut file_path_1 = file_path.to_path_buf();
-        let handle_1: JoinHandle<Result<(u64, Vec<RFile>)>> = spawn(move || {
+        let handle_1: JoinHandle<Result<(u32, u64, Vec<RFile>)>> = spawn(move || {
             file_path_1.set_extension("pak1");
             let mut file = BufReader::new(File::open(&file_path_1)?);
             let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
             file.read_to_end(&mut data)?;
 
+            // Check the version before deserializing the rest, as caches from other versions cannot be deserialized.
+            let version: u32 = bincode::deserialize(&data)?;
+            if version != CACHE_VERSION {
+                return Err(RLibError::DependenciesCacheVersionMismatch(version, CACHE_VERSION));
+            }
+
             // Never deserialize directly from the file. It's bloody slow!!!
             bincode::deserialize(&data).map_err(From::from)
         });
@@ -377,7 +536,7 @@ impl Dependencies {
         });
 
         let mut file_path_3 = file_path.to_path_buf();
-        let handle_3: JoinHandle<Result<(HashMap<String, Vec<String>>, HashSet<String>, HashSet<String>, HashMap<String, DB>)>> = spawn(move || {
+        let handle_3: JoinHandle<Result<(HashMap<String, Vec<String>>, HashSet<String>, HashSet<String>, HashMap<String, DB>, Vec<DependenciesSource>)>> = spawn(move || {
             file_path_3.set_extension("pak3");
             let mut file = BufReader::new(File::open(&file_path_3)?);
             let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
@@ -388,22 +547,25 @@ impl Dependencies {
         });
 
         // Get the thread's data in reverse, as 1 and 2 are actually the slower to process.
+        // The first one goes first anyway, as it's the one checking the version of the cache.
         let mut dependencies = Self::default();
-        let data_3 = handle_3.join().unwrap()?;
-        let data_2 = handle_2.join().unwrap()?;
-        let data_1 = handle_1.join().unwrap()?;
+        let data_1 = handle_1.join().unwrap();
+        let data_3 = handle_3.join().unwrap();
+        let data_2 = handle_2.join().unwrap();
+        let (data_1, data_2, data_3) = (data_1?, data_2?, data_3?);
 
         // The vanilla file list is stored in a Vec format instead of a hashmap, because a vec can be splited,
         // and that list is more than 100mb long in some games. Here we turn it back to HashMap and merge it.
-        let mut vanilla_files: HashMap<_,_> = data_1.1.into_par_iter().map(|file| (file.path_in_container_raw().to_owned(), file)).collect();
+        let mut vanilla_files: HashMap<_,_> = data_1.2.into_par_iter().map(|file| (file.path_in_container_raw().to_owned(), file)).collect();
         vanilla_files.par_extend(data_2.into_par_iter().map(|file| (file.path_in_container_raw().to_owned(), file)));
 
-        dependencies.build_date = data_1.0;
+        dependencies.build_date = data_1.1;
         dependencies.vanilla_files = vanilla_files;
         dependencies.vanilla_tables = data_3.0;
         dependencies.vanilla_locs = data_3.1;
         dependencies.vanilla_folders = data_3.2;
         dependencies.asskit_only_db_tables = data_3.3;
+        dependencies.vanilla_sources = data_3.4;
 
         // Only decode the tables if we passed a schema. If not, it's responsability of the user to decode them later.
         if let Some(schema) = schema {
@@ -450,9 +612,9 @@ impl Dependencies {
         let vanilla_files_2 = vanilla_files_1.split_off(self.vanilla_files.len() / 2);
 
         // Never serialize directly into the file. It's bloody slow!!!
-        let serialized_1: Vec<u8> = bincode::serialize(&(&self.build_date, &vanilla_files_1))?;
+        let serialized_1: Vec<u8> = bincode::serialize(&(&CACHE_VERSION, &self.build_date, &vanilla_files_1))?;
         let serialized_2: Vec<u8> = bincode::serialize(&vanilla_files_2)?;
-        let serialized_3: Vec<u8> = bincode::serialize(&(&self.vanilla_tables, &self.vanilla_locs, &self.vanilla_folders, &self.asskit_only_db_tables))?;
+        let serialized_3: Vec<u8> = bincode::serialize(&(&self.vanilla_tables, &self.vanilla_locs, &self.vanilla_folders, &self.asskit_only_db_tables, &self.vanilla_sources))?;
 
         file_1.write_all(&serialized_1).map_err(RLibError::from)?;
         file_2.write_all(&serialized_2).map_err(RLibError::from)?;
@@ -466,25 +628,56 @@ impl Dependencies {
         Ok(last_date > self.build_date)
     }
 
+    /// This function checks if the game Packs changed since the dependencies cache was generated.
+    ///
+    /// Unlike [needs_updating](Self::needs_updating), this also detects Packs being added, removed, or replaced by older ones.
+    pub fn vanilla_sources_changed(&self, game_info: &GameInfo, game_path: &Path) -> Result<bool> {
+        let ca_paths = game_info.ca_packs_paths(game_path)?;
+        if ca_paths.len() != self.vanilla_sources.len() {
+            return Ok(true);
+        }
+
+        Ok(ca_paths.iter().zip(self.vanilla_sources.iter()).any(|(path, source)| source.path() != path || source.is_outdated()))
+    }
+
+    /// This function returns the sources of the parent files currently loaded.
+    pub fn parent_sources(&self) -> Vec<&DependenciesSource> {
+        let mut sources = self.parent_packs.iter().map(|pack| &pack.source).collect::<Vec<_>>();
+        sources.sort_by(|a, b| a.path.cmp(&b.path));
+        sources
+    }
+
 
     /// This function loads all the parent [Packs](rpfm_lib::files::pack::Pack) provided as `parent_pack_names` as dependencies,
     /// taking care of also loading all dependencies of all of them, if they're not already loaded.
-    fn load_parent_packs(&mut self, parent_pack_names: &[String], game_info: &GameInfo, game_path: &Path) -> Result<()> {
+    ///
+    /// Packs that didn't change on disk since the last time they were read are not read again. It returns the paths of the Packs that were read.
+    fn load_parent_packs(&mut self, parent_pack_names: &[String], game_info: &GameInfo, game_path: &Path) -> Result<Vec<PathBuf>> {
         let data_packs_paths = game_info.ca_packs_paths(game_path)?;
         let content_packs_paths = game_info.content_packs_paths(game_path);
         let mut loaded_packfiles = vec![];
+        let mut refreshed = vec![];
 
-        parent_pack_names.iter().for_each(|pack_name| self.load_parent_pack(pack_name, &mut loaded_packfiles, &data_packs_paths, &content_packs_paths));
+        // Packs that are no longer parents are forgotten, along with their files.
+        let mut previous = PreviousParentData {
+            packs: std::mem::take(&mut self.parent_packs),
+            files: std::mem::take(&mut self.parent_files),
+        };
 
-        Ok(())
+        parent_pack_names.iter().for_each(|pack_name| self.load_parent_pack(pack_name, &mut loaded_packfiles, &mut refreshed, &mut previous, &data_packs_paths, &content_packs_paths));
+
+        Ok(refreshed)
     }
 
     /// This function loads a parent [Pack](rpfm_lib::files::pack::Pack) as a dependency,
     /// taking care of also loading all dependencies of it, if they're not already loaded.
+    #[allow(clippy::too_many_arguments)]
     fn load_parent_pack(
         &mut self,
         pack_name: &str,
         already_loaded: &mut Vec<String>,
+        refreshed: &mut Vec<PathBuf>,
+        previous: &mut PreviousParentData,
         data_paths: &[PathBuf],
         external_path: &Option<Vec<PathBuf>>,
     ) {
@@ -492,32 +685,46 @@ impl Dependencies {
         if !already_loaded.contains(&pack_name.to_owned()) {
 
             // First, if the game has an external path (not in /data) for Packs, we load the external Packs.
-            if let Some(ref paths) = external_path {
-                if let Some(path) = paths.iter().find(|x| x.file_name().unwrap().to_string_lossy() == pack_name) {
-                    if let Ok(pack) = Pack::read_and_merge(&[path.to_path_buf()], true, false) {
-                        already_loaded.push(pack_name.to_owned());
-                        pack.dependencies().iter().for_each(|pack_name| self.load_parent_pack(pack_name, already_loaded, data_paths, external_path));
-                        self.parent_files.extend(pack.files().clone());
-                    }
-                }
-            }
-
             // Then we load the Packs from /data, so they take priority over the other ones when overwriting.
-            if let Some(path) = data_paths.iter().find(|x| x.file_name().unwrap().to_string_lossy() == pack_name) {
-                if let Ok(pack) = Pack::read_and_merge(&[path.to_path_buf()], true, false) {
+            let external_pack_path = external_path.as_ref().and_then(|paths| paths.iter().find(|x| x.file_name().unwrap().to_string_lossy() == pack_name));
+            let data_pack_path = data_paths.iter().find(|x| x.file_name().unwrap().to_string_lossy() == pack_name);
+
+            for path in external_pack_path.into_iter().chain(data_pack_path) {
+                if let Some((pack, files)) = Self::parent_pack(path, refreshed, previous) {
                     already_loaded.push(pack_name.to_owned());
-                    pack.dependencies().iter().for_each(|pack_name| self.load_parent_pack(pack_name, already_loaded, data_paths, external_path));
-                    self.parent_files.extend(pack.files().clone());
+                    pack.dependencies.iter().for_each(|pack_name| self.load_parent_pack(pack_name, already_loaded, refreshed, previous, data_paths, external_path));
+                    self.parent_files.extend(files);
+                    self.parent_packs.push(pack);
                 }
             }
         }
     }
 
+    /// This function returns the parent Pack at the provided path with its files, reading it from disk only if it changed since the last time it was read.
+    fn parent_pack(path: &Path, refreshed: &mut Vec<PathBuf>, previous: &mut PreviousParentData) -> Option<(ParentPack, HashMap<String, RFile>)> {
+        let source = DependenciesSource::from_path(path).ok()?;
+        if let Some(pack) = previous.take(&source) {
+            return Some(pack);
+        }
+
+        let mut pack = Pack::read_and_merge(&[path.to_path_buf()], true, false).ok()?;
+        let files = std::mem::take(pack.files_mut());
+        let parent_pack = ParentPack {
+            source,
+            dependencies: pack.dependencies().to_vec(),
+            paths: files.keys().cloned().collect(),
+        };
+
+        refreshed.push(path.to_path_buf());
+        Some((parent_pack, files))
+    }
+
     /// Function to force-decode all tables/locs in the dependencies.
     ///
     /// Many operations require them to be decoded, so if you did not decoded them on load, make sure to call this to decode them after load.
     pub fn decode_tables(&mut self, schema: &Option<Schema>) {
         if let Some(schema) = schema {
+            self.cached_loc_keys = None;
 
             let mut decode_extra_data = DecodeableExtraData::default();
             decode_extra_data.set_schema(Some(schema));
@@ -578,6 +785,8 @@ impl Dependencies {
 
     /// This function returns a mutable reference to a specific file from the cache, if exists.
     pub fn file_mut(&mut self, file_path: &str, include_vanilla: bool, include_parent: bool) -> Result<&mut RFile> {
+        self.cached_loc_keys = None;
+
         if include_parent {
             if let Some(file) = self.parent_files.get_mut(file_path) {
                 return Ok(file);
@@ -666,6 +875,8 @@ impl Dependencies {
 
     /// This function returns a mutable reference to all files of the specified FileTypes from the cache, if any, along with their path.
     pub fn files_by_types_mut(&mut self, file_types: &[FileType], include_vanilla: bool, include_parent: bool) -> HashMap<String, &mut RFile> {
+        self.cached_loc_keys = None;
+
         let mut files = HashMap::new();
 
         // Vanilla first, so if parent files are found, they overwrite vanilla files.
@@ -826,6 +1037,39 @@ impl Dependencies {
             None => panic!("To be fixed: If you see this, you forgot to call generate_local_db_references before this."),
         };
 
+        Self::extend_with_local_references(&mut vanilla_references, pack, definition);
+        vanilla_references
+    }
+
+    /// This function returns the reference/lookup data of all relevant columns of a DB Table, using the reference cache.
+    ///
+    /// Unlike [Self::db_reference_data], this doesn't need the runtime references to be populated before calling it.
+    /// Only the vanilla, parent and assembly kit data is cached. The data from the provided Pack is read on every call,
+    /// so changes to the referenced tables in the Pack are always reflected. The cache is cleared when the dependencies are rebuilt.
+    pub fn db_reference_data_cached(&mut self, pack: &Pack, table_name: &str, definition: &Definition) -> HashMap<i32, TableReferences> {
+        let mut references = HashMap::new();
+        for (column, field) in definition.fields_processed().iter().enumerate() {
+
+            // Skip columns that don't reference anything, so they don't count as misses.
+            if !matches!(field.is_reference(), Some((ref_table, ref_column)) if !ref_table.is_empty() && !ref_column.is_empty()) {
+                continue;
+            }
+
+            let key = (table_name.to_owned(), *definition.version(), column as i32);
+            if let Some(cached_data) = self.reference_cache.get(&key) {
+                references.insert(column as i32, cached_data.clone());
+            } else if let Some(column_references) = self.generate_column_references(field) {
+                self.reference_cache.insert(key, column_references.clone());
+                references.insert(column as i32, column_references);
+            }
+        }
+
+        Self::extend_with_local_references(&mut references, pack, definition);
+        references
+    }
+
+    /// This function adds the reference/lookup data from the provided Pack to the provided vanilla/parent reference data.
+    fn extend_with_local_references(vanilla_references: &mut HashMap<i32, TableReferences>, pack: &Pack, definition: &Definition) {
         let local_references = definition.fields_processed().into_par_iter().enumerate().filter_map(|(column, field)| {
             if let Some((ref ref_table, ref ref_column)) = field.is_reference() {
                 if !ref_table.is_empty() && !ref_column.is_empty() {
@@ -847,8 +1091,6 @@ impl Dependencies {
                 value.data.extend(local_value.data.iter().map(|(k, v)| (k.clone(), v.clone())));
             }
         );
-
-        vanilla_references
     }
 
     /// This function returns the reference/lookup data of all relevant columns of a DB Table from the vanilla/parent data.
@@ -1032,6 +1274,33 @@ impl Dependencies {
         None
     }
 
+    /// This function returns all the keys in the vanilla/parent locs from the cache, according to the params you pass it.
+    pub fn loc_keys(&self, include_vanilla: bool, include_parent: bool) -> Result<HashSet<String>> {
+        Ok(self.loc_data(include_vanilla, include_parent)?
+            .par_iter()
+            .filter_map(|file| if let Ok(RFileDecoded::Loc(table)) = file.decoded() {
+                table.data(&None).ok().map(|data| data.iter()
+                    .filter_map(|row| if let DecodedData::StringU16(key) = &row[0] { Some(key.to_owned()) } else { None })
+                    .collect::<Vec<_>>())
+            } else { None })
+            .flatten()
+            .collect())
+    }
+
+    /// This function caches all the keys in the vanilla and parent locs, so they're only collected once until the cache changes.
+    ///
+    /// Use [Dependencies::cached_loc_keys] to get them.
+    pub fn cache_loc_keys(&mut self) {
+        if self.cached_loc_keys.is_none() {
+            self.cached_loc_keys = Some(self.loc_keys(true, true).unwrap_or_default());
+        }
+    }
+
+    /// This function returns all the keys in the vanilla and parent locs, if they have been cached with [Dependencies::cache_loc_keys].
+    pub fn cached_loc_keys(&self) -> Option<&HashSet<String>> {
+        self.cached_loc_keys.as_ref()
+    }
+
     //-----------------------------------//
     // Utility functions.
     //-----------------------------------//
@@ -1138,6 +1407,8 @@ impl Dependencies {
     /// This function updates a DB Table to its latest valid version, being the latest valid version the one in the vanilla files.
     ///
     /// It returns both, old and new versions, or an error.
+    ///
+    /// To update tables to the newest version in the schema instead, use [Pack::update_tables](rpfm_lib::files::pack::Pack::update_tables).
     pub fn update_db(&mut self, rfile: &mut RFileDecoded) -> Result<(i32, i32)> {
         match rfile {
             RFileDecoded::DB(data) => {
@@ -1161,5 +1432,81 @@ impl Dependencies {
             _ => Err(RLibError::DecodingDBNotADBTable),
         }
     }
+
+    /// This function releases as much memory as possible without losing data, returning the amount of bytes released (estimated).
+    ///
+    /// The reference caches are cleared, and the files that can be lazy-loaded again are unloaded from memory.
+    /// DB and Loc tables are kept decoded, as most operations expect them to be decoded.
+    pub fn release_memory(&mut self) -> u64 {
+        let mut released = self.local_tables_references.values()
+            .flat_map(|references| references.values())
+            .map(|references| references.memory_usage())
+            .sum::<u64>();
+
+        released += self.reference_cache.memory_usage();
+        released += self.cached_loc_keys.take()
+            .map(|keys| keys.iter().map(|key| (key.capacity() + std::mem::size_of::<String>()) as u64).sum::<u64>())
+            .unwrap_or_default();
+
+        self.local_tables_references.clear();
+        self.local_tables_references.shrink_to_fit();
+        self.reference_cache.clear();
+
+        let unloadable = |(_, file): &(&String, &mut RFile)| !matches!(file.file_type(), FileType::DB | FileType::Loc);
+        released += self.vanilla_files.iter_mut().filter(unloadable).map(|(_, file)| file.unload()).sum::<u64>();
+        released += self.parent_files.iter_mut().filter(unloadable).map(|(_, file)| file.unload()).sum::<u64>();
+
+        released
+    }
+}
+
+impl PreviousParentData {
+
+    /// This function takes the provided Pack and its files out of the previous data, if the Pack didn't change on disk.
+    ///
+    /// If any of its files were overwritten by a Pack loaded after it, its version of them is no longer available, so None is returned and the Pack must be read again.
+    fn take(&mut self, source: &DependenciesSource) -> Option<(ParentPack, HashMap<String, RFile>)> {
+        let index = self.packs.iter().position(|pack| pack.source == *source)?;
+        let (pack, later_packs) = self.packs[index..].split_first()?;
+        if pack.paths.iter().any(|path| later_packs.iter().any(|later_pack| later_pack.paths.contains(path))) {
+            return None;
+        }
+
+        let pack = std::mem::take(&mut self.packs[index]);
+        let files = pack.paths.iter().filter_map(|path| self.files.remove_entry(path)).collect::<HashMap<_, _>>();
+        if files.len() != pack.paths.len() {
+            return None;
+        }
+
+        Some((pack, files))
+    }
+}
+
+impl TableReferences {
+
+    /// This function returns an estimation of the memory used by the reference data, in bytes.
+    pub(crate) fn memory_usage(&self) -> u64 {
+        self.data.iter().map(|(key, value)| (key.len() + value.len()) as u64).sum()
+    }
 }
 
+impl DependenciesSource {
+
+    /// This function gets the metadata of the Pack at the provided path.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let file = File::open(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            size: file.metadata()?.len(),
+            last_modified: last_modified_time_from_file(&file)?,
+        })
+    }
+
+    /// This function checks if the Pack changed on disk since we got its metadata, or if it no longer exists.
+    pub fn is_outdated(&self) -> bool {
+        match Self::from_path(&self.path) {
+            Ok(source) => source != *self,
+            Err(_) => true,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the cache used to avoid recalculating the reference data of the same table columns over and over.
+
+use getset::Getters;
+use lru::LruCache;
+
+use super::TableReferences;
+
+/// Default maximum amount of reference entries kept in the cache, between all its columns.
+const DEFAULT_MAX_ROWS: usize = 500_000;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This is the key of a column in the cache: table name, definition version and column index.
+pub type ReferenceCacheKey = (String, i32, i32);
+
+/// This struct is a LRU cache of the reference data of table columns.
+///
+/// The cache is bounded by the amount of reference entries between all its columns.
+/// Once it goes over that amount, the least recently used columns are removed from it.
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ReferenceCache {
+
+    /// Cached reference data, by column.
+    #[getset(skip)]
+    cache: LruCache<ReferenceCacheKey, TableReferences>,
+
+    /// Amount of reference entries between all the cached columns.
+    rows: usize,
+
+    /// Maximum amount of reference entries to keep in the cache.
+    max_rows: usize,
+
+    /// Amount of lookups that found their column in the cache.
+    hits: u64,
+
+    /// Amount of lookups that didn't find their column in the cache.
+    misses: u64,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl Default for ReferenceCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ROWS)
+    }
+}
+
+impl ReferenceCache {
+
+    /// This function creates a new empty cache, which will keep at most `max_rows` reference entries.
+    pub fn new(max_rows: usize) -> Self {
+        Self {
+            cache: LruCache::unbounded(),
+            rows: 0,
+            max_rows,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// This function returns the cached reference data of a column, if any, marking it as recently used.
+    pub fn get(&mut self, key: &ReferenceCacheKey) -> Option<&TableReferences> {
+        match self.cache.get(key) {
+            Some(references) => {
+                self.hits += 1;
+                Some(references)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// This function adds the reference data of a column to the cache, removing the least recently used columns if needed.
+    ///
+    /// The column we just added is never removed, even if on its own it goes over the limit.
+    pub fn insert(&mut self, key: ReferenceCacheKey, references: TableReferences) {
+        self.rows += references.data().len();
+        if let Some(old_references) = self.cache.put(key, references) {
+            self.rows -= old_references.data().len();
+        }
+
+        while self.rows > self.max_rows && self.cache.len() > 1 {
+            match self.cache.pop_lru() {
+                Some((_, references)) => self.rows -= references.data().len(),
+                None => break,
+            }
+        }
+    }
+
+    /// This function removes all the cached columns. Hit and miss counts are kept.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.rows = 0;
+    }
+
+    /// This function returns an estimation of the memory used by the cached reference data, in bytes.
+    pub fn memory_usage(&self) -> u64 {
+        self.cache.iter().map(|(_, references)| references.memory_usage()).sum()
+    }
+
+    /// This function returns the amount of columns in the cache.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// This function returns if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
@@ -46,7 +46,8 @@ pub struct DependencyDiagnosticReport {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum DependencyDiagnosticReportType {
-    InvalidDependencyPackName(String)
+    InvalidDependencyPackName(String),
+    DependencyLoadedAfterPack(String),
 }
 
 //-------------------------------------------------------------------------------//
@@ -75,12 +76,14 @@ impl DiagnosticReport for DependencyDiagnosticReport {
     fn message(&self) -> String {
         match &self.report_type {
             DependencyDiagnosticReportType::InvalidDependencyPackName(pack_name) => format!("Invalid dependency Pack name: {}", pack_name),
+            DependencyDiagnosticReportType::DependencyLoadedAfterPack(pack_name) => format!("Dependency Pack loads after this Pack: {}", pack_name),
         }
     }
 
     fn level(&self) -> DiagnosticLevel {
         match self.report_type {
             DependencyDiagnosticReportType::InvalidDependencyPackName(_) => DiagnosticLevel::Error,
+            DependencyDiagnosticReportType::DependencyLoadedAfterPack(_) => DiagnosticLevel::Warning,
         }
     }
 }
@@ -89,6 +92,7 @@ impl Display for DependencyDiagnosticReportType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         Display::fmt(match self {
             Self::InvalidDependencyPackName(_) => "InvalidPackName",
+            Self::DependencyLoadedAfterPack(_) => "DependencyLoadedAfterPack",
         }, f)
     }
 }
@@ -0,0 +1,208 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the diagnostics.
+
+use std::io::{Cursor, Write};
+
+use rpfm_lib::binary::WriteBytes;
+use rpfm_lib::files::Decodeable;
+use rpfm_lib::games::supported_games::{KEY_SHOGUN_2, KEY_WARHAMMER_3, SupportedGames};
+
+use super::*;
+
+fn messages(results: &[FileDiagnosticReport]) -> Vec<String> {
+    results.iter().map(|result| result.message()).collect()
+}
+
+#[test]
+fn test_check_path_list() {
+    let games = SupportedGames::default();
+    let game_info = games.game(KEY_WARHAMMER_3).unwrap();
+    let dependencies = Dependencies::default();
+    let local_paths = ["terrain/tiles/a.dds".to_owned(), "ui/atlas.dds".to_owned()].into_iter().collect::<HashSet<_>>();
+
+    // Paths are compared case-insensitively, with backslashes normalized.
+    let mut text = Text::default();
+    text.set_contents("# Comment\nTerrain\\Tiles\\A.dds\nterrain/tiles/missing.dds\n".to_owned());
+    let results = Diagnostics::check_path_list(&text, PathListFormat::TextureArray, &local_paths, &dependencies, game_info);
+    assert_eq!(messages(&results), vec!["Path not found in line 3: terrain/tiles/missing.dds.".to_owned()]);
+    assert!(matches!(results[0].level(), DiagnosticLevel::Error));
+
+    let limit = game_info.max_texture_array_textures().unwrap();
+    text.set_contents("terrain/tiles/a.dds\n".repeat(limit + 1));
+    let results = Diagnostics::check_path_list(&text, PathListFormat::TextureArray, &local_paths, &dependencies, game_info);
+    assert!(matches!(results[..], [ref result] if matches!(result.report_type(), FileDiagnosticReportType::PathListTooLong(count, max) if *count == limit + 1 && *max == limit)));
+
+    // Games without a known limit only check the paths.
+    let results = Diagnostics::check_path_list(&text, PathListFormat::TextureArray, &local_paths, &dependencies, games.game(KEY_SHOGUN_2).unwrap());
+    assert!(results.is_empty());
+
+    // Tai files only reference the atlas of each frame.
+    text.set_contents("frame.tga\t\tui\\atlas.dds, 0, 2D, 0.0, 0.0, 0.0, 1.0, 1.0\nframe_2.tga\t\tui/other.dds, 0, 2D, 0.0, 0.0, 0.0, 1.0, 1.0\n".to_owned());
+    let results = Diagnostics::check_path_list(&text, PathListFormat::Tai, &local_paths, &dependencies, game_info);
+    assert_eq!(messages(&results), vec!["Path not found in line 2: ui/other.dds.".to_owned()]);
+}
+
+#[test]
+fn test_check_variant_mesh_definition() {
+    let dependencies = Dependencies::default();
+    let local_paths = ["variantmeshes/wh_variantmodels/hu1/emp/body_01.rigid_model_v2".to_owned()].into_iter().collect::<HashSet<_>>();
+
+    let mut text = Text::default();
+    text.set_contents("<VARIANT_MESH>\n    <SLOT name=\"body\">\n        <VARIANT_MESH model=\"VariantMeshes\\wh_variantmodels\\hu1\\emp\\Body_01.rigid_model_v2\"/>\n        <VARIANT_MESH model=\"variantmeshes/wh_variantmodels/hu1/emp/typo.rigid_model_v2\"/>\n    </SLOT>\n</VARIANT_MESH>\n".to_owned());
+    let results = Diagnostics::check_variant_mesh_definition(&text, &local_paths, &dependencies);
+    assert_eq!(messages(&results), vec!["Referenced file not found in line 4: variantmeshes/wh_variantmodels/hu1/emp/typo.rigid_model_v2.".to_owned()]);
+
+    // Malformed files are reported once, with the position of the error.
+    text.set_contents("<variant_mesh>\n    <slot name=\"body\">\n</variant_mesh>\n".to_owned());
+    let results = Diagnostics::check_variant_mesh_definition(&text, &local_paths, &dependencies);
+    assert!(matches!(results[..], [ref result] if matches!(result.report_type(), FileDiagnosticReportType::VariantMeshParseError(3, _, _))));
+}
+
+#[test]
+fn test_check_speedtree() {
+    let dependencies = Dependencies::default();
+    let local_paths = ["variantmeshes/trees/oak_bark.dds".to_owned()].into_iter().collect::<HashSet<_>>();
+
+    let mut data = vec![];
+    data.write_u32(12).unwrap();
+    data.write_string_u8("__IdvSpt_02_").unwrap();
+    data.write_u32(4).unwrap();
+    data.write_all(&[0; 24]).unwrap();
+    data.write_u32(1).unwrap();
+    data.write_u32(2).unwrap();
+    data.write_sized_string_u8("Oak_Bark.dds").unwrap();
+    data.write_sized_string_u8("variantmeshes\\textures\\missing.dds").unwrap();
+
+    // Textures without folder are relative to the SpeedTree.
+    let speedtree = SpeedTree::decode(&mut Cursor::new(data), &None).unwrap();
+    let results = Diagnostics::check_speedtree(&speedtree, "variantmeshes/trees/oak.spt", &local_paths, &dependencies);
+    assert_eq!(messages(&results), vec!["Referenced texture not found: variantmeshes/textures/missing.dds.".to_owned()]);
+    assert!(matches!(results[0].level(), DiagnosticLevel::Error));
+}
+
+#[test]
+fn test_ignore_data_for_file() {
+    let file = RFile::new_from_vec(&[], FileType::DB, 0, "db/units_tables/mod");
+    let columns = ["key".to_owned()];
+
+    let (fields, diagnostics, diagnostics_for_fields) = Diagnostics::ignore_data_for_file(&file, &[]).unwrap();
+    assert!(fields.is_empty() && diagnostics.is_empty() && diagnostics_for_fields.is_empty());
+
+    let rules = [
+        DiagnosticIgnoreRule::new("db/units_tables", None, &["category".to_owned()]),
+        DiagnosticIgnoreRule::new("db/units_tables/mod", Some("OutdatedTable"), &[]),
+        DiagnosticIgnoreRule::new("db/units_tables", Some("InvalidReference"), &columns),
+        DiagnosticIgnoreRule::new("db/land_units_tables", None, &[]),
+    ];
+    let (fields, diagnostics, diagnostics_for_fields) = Diagnostics::ignore_data_for_file(&file, &rules).unwrap();
+    assert_eq!(fields, vec!["category".to_owned()]);
+    assert!(diagnostics.contains("OutdatedTable"));
+    assert_eq!(diagnostics_for_fields["key"], vec!["InvalidReference".to_owned()]);
+    assert!(Diagnostics::ignore_diagnostic(&[], Some("key"), Some("InvalidReference"), &fields, &diagnostics, &diagnostics_for_fields));
+    assert!(!Diagnostics::ignore_diagnostic(&[], Some("key"), Some("EmptyKeyField"), &fields, &diagnostics, &diagnostics_for_fields));
+
+    // Rules without diagnostic nor columns ignore the entire file.
+    assert!(Diagnostics::ignore_data_for_file(&file, &[DiagnosticIgnoreRule::new("db/units_tables", None, &[])]).is_none());
+}
+
+/// This function builds a DDS header with the provided dimensions, mipmaps and fourcc, and parses it.
+fn dds_header(width: u32, height: u32, mipmap_count: u32, fourcc: &[u8; 4], dxgi_format: Option<u32>) -> DDSHeader {
+    use std::io::Cursor;
+    use rpfm_lib::files::{Decodeable, image::Image};
+
+    let mut dds = vec![0; 128];
+    dds[0..4].copy_from_slice(b"DDS ");
+    dds[8..12].copy_from_slice(&0x21007u32.to_le_bytes());
+    dds[12..16].copy_from_slice(&height.to_le_bytes());
+    dds[16..20].copy_from_slice(&width.to_le_bytes());
+    dds[28..32].copy_from_slice(&mipmap_count.to_le_bytes());
+    dds[80..84].copy_from_slice(&4u32.to_le_bytes());
+    dds[84..88].copy_from_slice(fourcc);
+    if let Some(dxgi_format) = dxgi_format {
+        dds.extend_from_slice(&dxgi_format.to_le_bytes());
+        dds.extend_from_slice(&[3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    Image::decode(&mut Cursor::new(dds), &None).unwrap().dds_header().unwrap()
+}
+
+#[test]
+fn test_check_texture() {
+    use rpfm_lib::games::supported_games::KEY_WARHAMMER;
+
+    let games = SupportedGames::default();
+    let warhammer_3 = games.game(KEY_WARHAMMER_3).unwrap();
+    let warhammer = games.game(KEY_WARHAMMER).unwrap();
+
+    // Valid textures.
+    assert!(Diagnostics::check_texture(&dds_header(256, 256, 9, b"DXT5", None), "variantmeshes/a.dds", warhammer).is_empty());
+    assert!(Diagnostics::check_texture(&dds_header(256, 256, 9, b"DX10", Some(98)), "variantmeshes/a.dds", warhammer_3).is_empty());
+
+    // BC7 is only supported since Warhammer 2.
+    let results = Diagnostics::check_texture(&dds_header(256, 256, 9, b"DX10", Some(98)), "variantmeshes/a.dds", warhammer);
+    assert!(matches!(results[..], [ref result] if matches!(result.report_type(), FileDiagnosticReportType::TextureFormatNotSupported(format) if format == "BC7")));
+    assert!(matches!(results[0].level(), DiagnosticLevel::Error));
+
+    // Block-compressed textures need dimensions multiple of 4, and textures with mipmaps need powers of two.
+    let results = Diagnostics::check_texture(&dds_header(100, 30, 2, b"DXT1", None), "variantmeshes/a.dds", warhammer_3);
+    assert!(matches!(results[..], [ref a, ref b] if
+        matches!(a.report_type(), FileDiagnosticReportType::TextureDimensionsNotMultipleOf4(100, 30)) &&
+        matches!(b.report_type(), FileDiagnosticReportType::TextureDimensionsNotPowerOfTwo(100, 30))));
+
+    // Mipmap counts must fit the dimensions.
+    let results = Diagnostics::check_texture(&dds_header(16, 16, 9, b"DXT5", None), "variantmeshes/a.dds", warhammer_3);
+    assert!(matches!(results[..], [ref result] if matches!(result.report_type(), FileDiagnosticReportType::TextureMipmapCountInvalid(9, 5))));
+
+    // UI textures need mipmaps.
+    let results = Diagnostics::check_texture(&dds_header(64, 64, 1, b"DXT5", None), "ui/skins/a.dds", warhammer_3);
+    assert!(matches!(results[..], [ref result] if matches!(result.report_type(), FileDiagnosticReportType::TextureMipmapsMissing)));
+    assert!(Diagnostics::check_texture(&dds_header(64, 64, 1, b"DXT5", None), "variantmeshes/a.dds", warhammer_3).is_empty());
+
+    // Only textures in texture folders are checked.
+    assert!(warhammer_3.texture_constraints().is_texture("UI/Skins/a.dds"));
+    assert!(!warhammer_3.texture_constraints().is_texture("ui/skins/a.png"));
+    assert!(!warhammer_3.texture_constraints().is_texture("text/a.dds"));
+}
+
+#[test]
+#[cfg(feature = "support_lua_check")]
+fn test_check_lua_script() {
+    let mut text = Text::default();
+    text.set_contents("local function a()\n    out(\"a\")\nend\n".to_owned());
+    assert!(Diagnostics::check_lua_script(&text).is_empty());
+
+    // A stray end is reported where it is.
+    text.set_contents("local function a()\n    out(\"a\")\nend\nend\n".to_owned());
+    let results = Diagnostics::check_lua_script(&text);
+    assert!(matches!(results.first().map(|result| result.report_type()), Some(FileDiagnosticReportType::LuaSyntaxError(4, 1, _))));
+    assert!(matches!(results[0].level(), DiagnosticLevel::Error));
+}
+
+#[test]
+fn test_is_identical_to() {
+    let dependency_file = RFile::new_from_vec(b"Data.", FileType::Text, 0, "text/test.txt");
+    let same = RFile::new_from_vec(b"Data.", FileType::Text, 0, "text/test.txt");
+    let different = RFile::new_from_vec(b"Other data.", FileType::Text, 0, "text/test.txt");
+    assert!(Diagnostics::is_identical_to(&same, &dependency_file));
+    assert!(!Diagnostics::is_identical_to(&different, &dependency_file));
+
+    // Decoded files are compared with their current contents, and left decoded.
+    let mut decoded = same.clone();
+    decoded.decode(&None, true, false).unwrap();
+    assert!(Diagnostics::is_identical_to(&decoded, &dependency_file));
+    assert!(matches!(decoded.decoded(), Ok(RFileDecoded::Text(_))));
+
+    if let Ok(RFileDecoded::Text(text)) = decoded.decoded_mut() {
+        text.set_contents("Edited.".to_owned());
+    }
+    assert!(!Diagnostics::is_identical_to(&decoded, &dependency_file));
+}
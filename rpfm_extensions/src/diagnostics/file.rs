@@ -0,0 +1,159 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module with the structs and functions specific for generic `File` diagnostics.
+
+use getset::{Getters, MutGetters};
+use serde_derive::{Serialize, Deserialize};
+
+use std::{fmt, fmt::Display};
+
+use rpfm_lib::files::ContainerPath;
+
+use crate::diagnostics::DiagnosticReport;
+use super::DiagnosticLevel;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the results of a File diagnostic.
+#[derive(Debug, Clone, Default, Getters, MutGetters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub")]
+pub struct FileDiagnostic {
+    path: String,
+    results: Vec<FileDiagnosticReport>
+}
+
+/// This struct defines an individual file diagnostic result.
+#[derive(Debug, Clone, Getters, MutGetters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub")]
+pub struct FileDiagnosticReport {
+    report_type: FileDiagnosticReportType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FileDiagnosticReportType {
+    IdenticalToVanilla,
+
+    /// A path in a path list file not found in the Pack or its dependencies. Contains the line number and the path.
+    PathListPathNotFound(usize, String),
+
+    /// A path list file with more paths than the game supports. Contains the amount of paths and the limit.
+    PathListTooLong(usize, usize),
+
+    /// A texture in a format the game cannot read. Contains the format.
+    TextureFormatNotSupported(String),
+
+    /// A block-compressed texture with dimensions that are not multiples of 4. Contains the width and height.
+    TextureDimensionsNotMultipleOf4(u32, u32),
+
+    /// A texture with mipmaps and dimensions that are not powers of two. Contains the width and height.
+    TextureDimensionsNotPowerOfTwo(u32, u32),
+
+    /// A texture with more mipmaps than its dimensions allow. Contains the amount of mipmaps and the maximum.
+    TextureMipmapCountInvalid(u32, u32),
+
+    /// A texture without mipmaps in a folder where the game expects them.
+    TextureMipmapsMissing,
+
+    /// A syntax error in a Lua script. Contains the line, the column and the error message.
+    LuaSyntaxError(usize, usize, String),
+
+    /// A path referenced by a VariantMeshDefinition not found in the Pack or its dependencies. Contains the line number and the path.
+    VariantMeshPathNotFound(usize, String),
+
+    /// A VariantMeshDefinition that cannot be parsed. Contains the line, the column and the error message.
+    VariantMeshParseError(usize, usize, String),
+
+    /// A texture referenced by a SpeedTree not found in the Pack or its dependencies. Contains the path of the texture.
+    SpeedTreeTextureNotFound(String),
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl FileDiagnostic {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_owned(),
+            results: vec![],
+        }
+    }
+
+    /// This function returns the [ContainerPath] of the file this diagnostic is about.
+    pub fn container_path(&self) -> ContainerPath {
+        ContainerPath::File(self.path.to_owned())
+    }
+}
+
+impl FileDiagnosticReport {
+    pub fn new(report_type: FileDiagnosticReportType) -> Self {
+        Self {
+            report_type
+        }
+    }
+}
+
+impl DiagnosticReport for FileDiagnosticReport {
+    fn message(&self) -> String {
+        match &self.report_type {
+            FileDiagnosticReportType::IdenticalToVanilla => "File identical to its vanilla/parent counterpart.".to_owned(),
+            FileDiagnosticReportType::PathListPathNotFound(line, path) => format!("Path not found in line {line}: {path}."),
+            FileDiagnosticReportType::PathListTooLong(count, limit) => format!("The file has {count} paths, but the game only supports {limit}."),
+            FileDiagnosticReportType::TextureFormatNotSupported(format) => format!("The texture uses the {format} format, which this game cannot read."),
+            FileDiagnosticReportType::TextureDimensionsNotMultipleOf4(width, height) => format!("The texture is block-compressed, but its dimensions ({width}x{height}) are not multiples of 4."),
+            FileDiagnosticReportType::TextureDimensionsNotPowerOfTwo(width, height) => format!("The texture has mipmaps, but its dimensions ({width}x{height}) are not powers of two."),
+            FileDiagnosticReportType::TextureMipmapCountInvalid(count, max) => format!("The texture has {count} mipmaps, but its dimensions only allow {max}."),
+            FileDiagnosticReportType::TextureMipmapsMissing => "The texture has no mipmaps, but the game expects them for textures in this folder.".to_owned(),
+            FileDiagnosticReportType::LuaSyntaxError(line, column, message) => format!("Syntax error in line {line}, column {column}: {message}"),
+            FileDiagnosticReportType::VariantMeshPathNotFound(line, path) => format!("Referenced file not found in line {line}: {path}."),
+            FileDiagnosticReportType::VariantMeshParseError(line, column, message) => format!("Invalid XML in line {line}, column {column}: {message}"),
+            FileDiagnosticReportType::SpeedTreeTextureNotFound(path) => format!("Referenced texture not found: {path}."),
+        }
+    }
+
+    fn level(&self) -> DiagnosticLevel {
+        match self.report_type {
+            FileDiagnosticReportType::IdenticalToVanilla |
+            FileDiagnosticReportType::TextureDimensionsNotPowerOfTwo(_, _) |
+            FileDiagnosticReportType::TextureMipmapsMissing => DiagnosticLevel::Warning,
+            FileDiagnosticReportType::PathListPathNotFound(_, _) |
+            FileDiagnosticReportType::PathListTooLong(_, _) |
+            FileDiagnosticReportType::TextureFormatNotSupported(_) |
+            FileDiagnosticReportType::TextureDimensionsNotMultipleOf4(_, _) |
+            FileDiagnosticReportType::TextureMipmapCountInvalid(_, _) |
+            FileDiagnosticReportType::LuaSyntaxError(_, _, _) |
+            FileDiagnosticReportType::VariantMeshPathNotFound(_, _) |
+            FileDiagnosticReportType::VariantMeshParseError(_, _, _) |
+            FileDiagnosticReportType::SpeedTreeTextureNotFound(_) => DiagnosticLevel::Error,
+        }
+    }
+}
+
+impl Display for FileDiagnosticReportType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(match self {
+            Self::IdenticalToVanilla => "IdenticalToVanilla",
+            Self::PathListPathNotFound(_, _) => "PathListPathNotFound",
+            Self::PathListTooLong(_, _) => "PathListTooLong",
+            Self::TextureFormatNotSupported(_) => "TextureFormatNotSupported",
+            Self::TextureDimensionsNotMultipleOf4(_, _) => "TextureDimensionsNotMultipleOf4",
+            Self::TextureDimensionsNotPowerOfTwo(_, _) => "TextureDimensionsNotPowerOfTwo",
+            Self::TextureMipmapCountInvalid(_, _) => "TextureMipmapCountInvalid",
+            Self::TextureMipmapsMissing => "TextureMipmapsMissing",
+            Self::LuaSyntaxError(_, _, _) => "LuaSyntaxError",
+            Self::VariantMeshPathNotFound(_, _) => "VariantMeshPathNotFound",
+            Self::VariantMeshParseError(_, _, _) => "VariantMeshParseError",
+            Self::SpeedTreeTextureNotFound(_) => "SpeedTreeTextureNotFound",
+        }, f)
+    }
+}
@@ -30,9 +30,10 @@ use std::path::Path;
 use std::{fmt, fmt::Display};
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{self, AtomicBool};
 
-use rpfm_lib::error::Result;
-use rpfm_lib::files::{ContainerPath, Container, FileType, pack::Pack, RFile, RFileDecoded, table::DecodedData};
+use rpfm_lib::error::{RLibError, Result};
+use rpfm_lib::files::{ContainerPath, Container, EncodeableExtraData, FileType, image::DDSHeader, pack::{DiagnosticIgnoreRule, Pack}, RFile, RFileDecoded, speedtree::SpeedTree, table::DecodedData, text::{PathListFormat, Text}};
 use rpfm_lib::games::{GameInfo, VanillaDBTableNameLogic};
 use rpfm_lib::schema::{FieldType, Schema};
 
@@ -41,15 +42,19 @@ use crate::dependencies::{Dependencies, TableReferences};
 use self::anim_fragment::{AnimFragmentDiagnostic, AnimFragmentDiagnosticReport, AnimFragmentDiagnosticReportType};
 use self::config::{ConfigDiagnostic, ConfigDiagnosticReport, ConfigDiagnosticReportType};
 use self::dependency::{DependencyDiagnostic, DependencyDiagnosticReport, DependencyDiagnosticReportType};
+use self::file::{FileDiagnostic, FileDiagnosticReport, FileDiagnosticReportType};
 use self::pack::{PackDiagnostic, PackDiagnosticReport, PackDiagnosticReportType};
 use self::table::{TableDiagnostic, TableDiagnosticReport, TableDiagnosticReportType};
 
 pub mod anim_fragment;
 pub mod config;
 pub mod dependency;
+pub mod file;
 pub mod pack;
 pub mod table;
 
+#[cfg(test)] mod diagnostics_test;
+
 //-------------------------------------------------------------------------------//
 //                              Trait definitions
 //-------------------------------------------------------------------------------//
@@ -102,6 +107,7 @@ pub enum DiagnosticType {
     DB(TableDiagnostic),
     Loc(TableDiagnostic),
     Pack(PackDiagnostic),
+    File(FileDiagnostic),
 }
 
 /// This enum defines the possible level of a diagnostic.
@@ -109,6 +115,9 @@ pub enum DiagnosticType {
 pub enum DiagnosticLevel {
     #[default]
     Info,
+
+    /// For issues that don't break anything, but show up in-game, like missing loc data.
+    Notice,
     Warning,
     Error,
 }
@@ -132,6 +141,7 @@ impl DiagnosticType {
             Self::Pack(_) => "",
             Self::Dependency(diag) => diag.path(),
             Self::Config(_) => "",
+            Self::File(diag) => diag.path(),
         }
     }
 }
@@ -139,7 +149,10 @@ impl DiagnosticType {
 impl Diagnostics {
 
     /// This function performs a search over the parts of a `PackFile` you specify it, storing his results.
-    pub fn check(&mut self, pack: &Pack, dependencies: &mut Dependencies, game_info: &GameInfo, game_path: &Path, paths_to_check: &[ContainerPath], schema: &Schema) {
+    ///
+    /// If `cancel` is set while checking, the check is stopped as soon as possible and [RLibError::OperationCancelled] is returned.
+    #[allow(clippy::too_many_arguments)]
+    pub fn check(&mut self, pack: &Pack, dependencies: &mut Dependencies, game_info: &GameInfo, game_path: &Path, paths_to_check: &[ContainerPath], schema: &Schema, cancel: &AtomicBool) -> Result<()> {
 
         // Clear the diagnostics first if we're doing a full check, or only the config ones and the ones for the path to update if we're doing a partial check.
         if paths_to_check.is_empty() {
@@ -172,11 +185,21 @@ impl Diagnostics {
             // If we have one of the blocking diagnostics, report it and return.
             self.results.push(diagnostics);
             if is_diagnostic_blocking {
-                return;
+                return Ok(());
             }
         }
 
-        let files_to_ignore = pack.settings().diagnostics_files_to_ignore();
+        let ignore_rules = pack.settings().diagnostics_ignore_rules();
+
+        // Loc keys are only needed to find missing loc data, and getting them is not cheap, so only get them if needed.
+        // The ones from the dependencies are cached, so only the ones from the Pack are collected on each check.
+        let check_loc_keys = !self.diagnostics_ignored.iter().any(|x| x == "MissingLocData");
+        let local_loc_keys = if check_loc_keys {
+            dependencies.cache_loc_keys();
+            Self::local_loc_keys(pack)
+        } else {
+            HashSet::new()
+        };
 
         // Logic here: we want to process the tables on batches containing all the tables of the same type, so we can check duplicates in different tables.
         // To do that, we have to sort/split the file list, the process that.
@@ -229,6 +252,8 @@ impl Diagnostics {
         let table_names = files_split.iter().filter(|(key, _)| **key != "anim_fragments" && **key != "locs").map(|(key, _)| key.to_string()).collect::<Vec<_>>();
         dependencies.generate_local_db_references(pack, &table_names);
 
+        let loc_keys = [dependencies.cached_loc_keys(), Some(&local_loc_keys)].into_iter().flatten().collect::<Vec<_>>();
+
         // Process the files in batches.
         self.results.append(&mut files_split.par_iter().filter_map(|(_, files)| {
 
@@ -236,7 +261,11 @@ impl Diagnostics {
             let mut table_references = HashMap::new();
 
             for file in files {
-                let (ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields) = Self::ignore_data_for_file(file, &files_to_ignore)?;
+                if cancel.load(atomic::Ordering::Relaxed) {
+                    return None;
+                }
+
+                let (ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields) = Self::ignore_data_for_file(file, ignore_rules)?;
 
                 let diagnostic = match file.file_type() {
                     FileType::AnimFragment => Self::check_anim_fragment(
@@ -272,6 +301,7 @@ impl Diagnostics {
                             &local_file_path_list,
                             &local_folder_path_list,
                             &table_references,
+                            &loc_keys,
                         )
                     },
                     FileType::Loc => Self::check_loc(file, &self.diagnostics_ignored, &ignored_fields, &ignored_diagnostics, &ignored_diagnostics_for_fields),
@@ -286,7 +316,41 @@ impl Diagnostics {
             Some(diagnostics)
         }).flatten().collect());
 
-        if let Some(diagnostics) = Self::check_dependency_manager(pack) {
+        if cancel.load(atomic::Ordering::Relaxed) {
+            return Err(RLibError::OperationCancelled);
+        }
+
+        // Generic file checks. Tables are skipped, as the optimizer takes care of their vanilla data row by row.
+        let files = if paths_to_check.is_empty() {
+            pack.files().values().collect::<Vec<_>>()
+        } else {
+            pack.files_by_paths(paths_to_check, false)
+        };
+
+        // Path lists, VariantMeshDefinitions and SpeedTrees are checked case-insensitively against the Pack, so lowercase its paths once here, and only if we have files to check.
+        let local_paths_lowercase = if files.iter().any(|file| Self::path_list_format(file).is_some() || Self::is_variant_mesh_definition(file) || file.file_type() == FileType::SpeedTree) {
+            pack.paths_raw().into_iter().map(|path| path.to_lowercase()).collect::<HashSet<_>>()
+        } else {
+            HashSet::new()
+        };
+
+        self.results.append(&mut files.par_iter()
+            .filter(|file| file.file_type() != FileType::DB && file.file_type() != FileType::Loc)
+            .filter_map(|file| {
+                if cancel.load(atomic::Ordering::Relaxed) {
+                    return None;
+                }
+
+                let (ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields) = Self::ignore_data_for_file(file, ignore_rules)?;
+                Self::check_file(file, dependencies, game_info, &local_paths_lowercase, &self.diagnostics_ignored, &ignored_fields, &ignored_diagnostics, &ignored_diagnostics_for_fields)
+            })
+            .collect());
+
+        if cancel.load(atomic::Ordering::Relaxed) {
+            return Err(RLibError::OperationCancelled);
+        }
+
+        if let Some(diagnostics) = Self::check_dependency_manager(pack, &self.diagnostics_ignored) {
             self.results_mut().push(diagnostics);
         }
 
@@ -305,6 +369,8 @@ impl Diagnostics {
                 Ordering::Equal
             }
         });
+
+        Ok(())
     }
 
     /// This function takes care of checking the db tables of your mod for errors.
@@ -319,7 +385,8 @@ impl Diagnostics {
         schema: &Schema,
         local_path_list: &HashSet<&str>,
         local_folder_list: &HashSet<String>,
-        dependency_data: &HashMap<i32, TableReferences>
+        dependency_data: &HashMap<i32, TableReferences>,
+        loc_keys: &[&HashSet<String>],
     ) ->Option<DiagnosticType> {
         if let Ok(RFileDecoded::DB(table)) = file.decoded() {
             let mut diagnostic = TableDiagnostic::new(file.path_in_container_raw());
@@ -570,6 +637,36 @@ impl Diagnostics {
                 }
             }
 
+            // Check that every localised field of every row has its loc data, either in the Pack or in its dependencies.
+            let localised_fields = table.definition().localised_fields();
+            if !localised_fields.is_empty() {
+                let table_name = table.table_name_without_tables();
+
+                // Get the keys, which may be concatenated. We get them IN THE ORDER THEY ARE IN THE BINARY FILE.
+                let key_field_positions = table.definition().fields().iter()
+                    .filter(|field| field.is_key())
+                    .filter_map(|field| fields_processed.iter().position(|field_processed| field_processed.name() == field.name()))
+                    .collect::<Vec<usize>>();
+
+                for (row, cells) in table_data.iter().enumerate() {
+                    let key = key_field_positions.iter().map(|column| cells[*column].data_to_string()).join("");
+                    if key.is_empty() {
+                        continue;
+                    }
+
+                    for localised_field in localised_fields {
+                        if !Self::ignore_diagnostic(global_ignored_diagnostics, Some(localised_field.name()), Some("MissingLocData"), ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields) {
+                            let loc_key = format!("{}_{}_{}", table_name, localised_field.name(), key);
+                            if !loc_keys.iter().any(|keys| keys.contains(&loc_key)) {
+                                let cells_affected = key_field_positions.iter().map(|column| (row as i32, *column as i32)).collect::<Vec<(i32, i32)>>();
+                                let result = TableDiagnosticReport::new(TableDiagnosticReportType::MissingLocData(loc_key), &cells_affected);
+                                diagnostic.results_mut().push(result);
+                            }
+                        }
+                    }
+                }
+            }
+
             // Checks that only need to be done once per table.
             if !Self::ignore_diagnostic(global_ignored_diagnostics, None, Some("NoReferenceTableFound"), ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields) {
                 for column in &columns_without_reference_table {
@@ -677,6 +774,12 @@ impl Diagnostics {
         if let Ok(RFileDecoded::Loc(table)) = file.decoded() {
             let mut diagnostic = TableDiagnostic::new(file.path_in_container_raw());
 
+            // Check if the file was written by a tool using a non-standard layout, as the game may not read it correctly.
+            if !Self::ignore_diagnostic(global_ignored_diagnostics, None, Some("NonStandardLocLayout"), ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields) && table.is_non_standard() {
+                let result = TableDiagnosticReport::new(TableDiagnosticReportType::NonStandardLocLayout, &[]);
+                diagnostic.results_mut().push(result);
+            }
+
             // Check all the columns with reference data.
             let mut keys: HashMap<String, Vec<(i32, i32)>> = HashMap::new();
             let fields = table.definition().fields_processed();
@@ -755,6 +858,199 @@ impl Diagnostics {
         } else { None }
     }
 
+    /// This function checks if a file has the same data as the one it overwrites.
+    ///
+    /// Hashes are only used to discard different files quickly. As the quick fix for identical files deletes them,
+    /// files with matching hashes are compared byte by byte. Decoded files, like the ones open in the UI, cannot be hashed,
+    /// so their data is encoded from a copy and compared directly.
+    fn is_identical_to(file: &RFile, dependency_file: &RFile) -> bool {
+        let hashes_match = match (file.data_hash(), dependency_file.data_hash()) {
+            (Ok(local_hash), Ok(dependency_hash)) => local_hash == dependency_hash,
+            _ => true,
+        };
+
+        hashes_match && matches!((Self::encoded_data(file), Self::encoded_data(dependency_file)), (Some(local_data), Some(dependency_data)) if local_data == dependency_data)
+    }
+
+    /// This function returns the encoded data of a file, without changing the state of the file.
+    fn encoded_data(file: &RFile) -> Option<Vec<u8>> {
+        file.clone().encode(&Some(EncodeableExtraData::default()), false, false, true).ok().flatten()
+    }
+
+    /// This function takes care of checking generic files of your mod for errors.
+    #[allow(clippy::too_many_arguments)]
+    fn check_file(
+        file: &RFile,
+        dependencies: &Dependencies,
+        game_info: &GameInfo,
+        local_paths_lowercase: &HashSet<String>,
+        global_ignored_diagnostics: &[String],
+        ignored_fields: &[String],
+        ignored_diagnostics: &HashSet<String>,
+        ignored_diagnostics_for_fields: &HashMap<String, Vec<String>>,
+    ) -> Option<DiagnosticType> {
+        let mut diagnostic = FileDiagnostic::new(file.path_in_container_raw());
+
+        // Check if the file is the same as the one it overwrites.
+        // The parent file goes first, so files identical to vanilla but overwriting a parent file are not reported.
+        if !Self::ignore_diagnostic(global_ignored_diagnostics, None, Some("IdenticalToVanilla"), ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields) {
+            if let Ok(dependency_file) = dependencies.file(file.path_in_container_raw(), true, true, false) {
+                if Self::is_identical_to(file, dependency_file) {
+                    let result = FileDiagnosticReport::new(FileDiagnosticReportType::IdenticalToVanilla);
+                    diagnostic.results_mut().push(result);
+                }
+            }
+        }
+
+        // Check the paths referenced by path lists. The file is decoded from a copy, so we don't leave it decoded in the Pack.
+        if let Some(format) = Self::path_list_format(file) {
+            if let Ok(Some(RFileDecoded::Text(text))) = file.clone().decode(&None, false, true) {
+                diagnostic.results_mut().extend(Self::check_path_list(&text, format, local_paths_lowercase, dependencies, game_info)
+                    .into_iter()
+                    .filter(|result| !Self::ignore_diagnostic(global_ignored_diagnostics, None, Some(&result.report_type().to_string()), ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields)));
+            }
+        }
+
+        // Check the paths referenced by VariantMeshDefinitions.
+        if Self::is_variant_mesh_definition(file) {
+            if let Ok(Some(RFileDecoded::Text(text))) = file.clone().decode(&None, false, true) {
+                diagnostic.results_mut().extend(Self::check_variant_mesh_definition(&text, local_paths_lowercase, dependencies)
+                    .into_iter()
+                    .filter(|result| !Self::ignore_diagnostic(global_ignored_diagnostics, None, Some(&result.report_type().to_string()), ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields)));
+            }
+        }
+
+        // Check the textures referenced by SpeedTrees.
+        if file.file_type() == FileType::SpeedTree {
+            if let Ok(Some(RFileDecoded::SpeedTree(speedtree))) = file.clone().decode(&None, false, true) {
+                diagnostic.results_mut().extend(Self::check_speedtree(&speedtree, file.path_in_container_raw(), local_paths_lowercase, dependencies)
+                    .into_iter()
+                    .filter(|result| !Self::ignore_diagnostic(global_ignored_diagnostics, None, Some(&result.report_type().to_string()), ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields)));
+            }
+        }
+
+        // Check the textures against the constraints of the game. Only the header is needed, but we need to decode a copy to get it.
+        if file.file_type() == FileType::Image && game_info.texture_constraints().is_texture(file.path_in_container_raw()) {
+            if let Ok(Some(RFileDecoded::Image(image))) = file.clone().decode(&None, false, true) {
+                if let Some(header) = image.dds_header() {
+                    diagnostic.results_mut().extend(Self::check_texture(&header, file.path_in_container_raw(), game_info)
+                        .into_iter()
+                        .filter(|result| !Self::ignore_diagnostic(global_ignored_diagnostics, None, Some(&result.report_type().to_string()), ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields)));
+                }
+            }
+        }
+
+        // Check the syntax of Lua scripts.
+        #[cfg(feature = "support_lua_check")]
+        if file.file_type() == FileType::Text && file.path_in_container_raw().to_lowercase().ends_with(".lua") && !Self::ignore_diagnostic(global_ignored_diagnostics, None, Some("LuaSyntaxError"), ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields) {
+            if let Ok(Some(RFileDecoded::Text(text))) = file.clone().decode(&None, false, true) {
+                diagnostic.results_mut().extend(Self::check_lua_script(&text));
+            }
+        }
+
+        if !diagnostic.results().is_empty() {
+            Some(DiagnosticType::File(diagnostic))
+        } else { None }
+    }
+
+    /// This function checks the header of a DDS texture against the texture constraints of the game, returning a report for each problem found.
+    pub fn check_texture(header: &DDSHeader, path: &str, game_info: &GameInfo) -> Vec<FileDiagnosticReport> {
+        let constraints = game_info.texture_constraints();
+        let mut results = vec![];
+        let (width, height) = (*header.width(), *header.height());
+
+        let format = header.format();
+        if format == "BC7" && !constraints.supports_bc7() {
+            results.push(FileDiagnosticReport::new(FileDiagnosticReportType::TextureFormatNotSupported(format)));
+        }
+
+        if header.is_block_compressed() && (width % 4 != 0 || height % 4 != 0) {
+            results.push(FileDiagnosticReport::new(FileDiagnosticReportType::TextureDimensionsNotMultipleOf4(width, height)));
+        }
+
+        if *header.mipmap_count() > 1 && (!width.is_power_of_two() || !height.is_power_of_two()) {
+            results.push(FileDiagnosticReport::new(FileDiagnosticReportType::TextureDimensionsNotPowerOfTwo(width, height)));
+        }
+
+        if *header.mipmap_count() > header.max_mipmap_count() {
+            results.push(FileDiagnosticReport::new(FileDiagnosticReportType::TextureMipmapCountInvalid(*header.mipmap_count(), header.max_mipmap_count())));
+        }
+
+        if *header.mipmap_count() == 1 && constraints.requires_mipmaps(path) {
+            results.push(FileDiagnosticReport::new(FileDiagnosticReportType::TextureMipmapsMissing));
+        }
+
+        results
+    }
+
+    /// This function checks the syntax of a Lua script, returning a report for each syntax error found.
+    #[cfg(feature = "support_lua_check")]
+    pub fn check_lua_script(text: &Text) -> Vec<FileDiagnosticReport> {
+        crate::lua::check_syntax(text.contents())
+            .into_iter()
+            .map(|error| FileDiagnosticReport::new(FileDiagnosticReportType::LuaSyntaxError(*error.line(), *error.column(), error.message().to_owned())))
+            .collect()
+    }
+
+    /// This function checks the paths referenced by a path list file, returning a report for each problem found.
+    ///
+    /// Paths are searched case-insensitively, first in the provided lowercased paths of the Pack, then in the dependencies.
+    pub fn check_path_list(text: &Text, format: PathListFormat, local_paths_lowercase: &HashSet<String>, dependencies: &Dependencies, game_info: &GameInfo) -> Vec<FileDiagnosticReport> {
+        let paths = text.path_list(format);
+        let mut results = paths.iter()
+            .filter(|(_, path)| !local_paths_lowercase.contains(&path.to_lowercase()) && !dependencies.file_exists(path, true, true, true))
+            .map(|(line, path)| FileDiagnosticReport::new(FileDiagnosticReportType::PathListPathNotFound(*line, path.to_owned())))
+            .collect::<Vec<_>>();
+
+        if format == PathListFormat::TextureArray {
+            if let Some(limit) = game_info.max_texture_array_textures() {
+                if paths.len() > limit {
+                    results.push(FileDiagnosticReport::new(FileDiagnosticReportType::PathListTooLong(paths.len(), limit)));
+                }
+            }
+        }
+
+        results
+    }
+
+    /// This function checks the paths referenced by a VariantMeshDefinition, returning a report for each problem found.
+    ///
+    /// Paths are searched case-insensitively, first in the provided lowercased paths of the Pack, then in the dependencies.
+    /// If the file cannot be parsed, only the parsing error is reported.
+    pub fn check_variant_mesh_definition(text: &Text, local_paths_lowercase: &HashSet<String>, dependencies: &Dependencies) -> Vec<FileDiagnosticReport> {
+        match crate::variant_mesh::references(text.contents()) {
+            Ok(references) => references.iter()
+                .filter(|reference| !local_paths_lowercase.contains(&reference.path().to_lowercase()) && !dependencies.file_exists(reference.path(), true, true, true))
+                .map(|reference| FileDiagnosticReport::new(FileDiagnosticReportType::VariantMeshPathNotFound(*reference.line(), reference.path().to_owned())))
+                .collect(),
+            Err(error) => vec![FileDiagnosticReport::new(FileDiagnosticReportType::VariantMeshParseError(*error.line(), *error.column(), error.message().to_owned()))],
+        }
+    }
+
+    /// This function checks the textures referenced by a SpeedTree, returning a report for each texture not found.
+    ///
+    /// Paths are searched case-insensitively, first in the provided lowercased paths of the Pack, then in the dependencies.
+    pub fn check_speedtree(speedtree: &SpeedTree, path: &str, local_paths_lowercase: &HashSet<String>, dependencies: &Dependencies) -> Vec<FileDiagnosticReport> {
+        speedtree.texture_paths(path).into_iter()
+            .filter(|texture| !local_paths_lowercase.contains(&texture.to_lowercase()) && !dependencies.file_exists(texture, true, true, true))
+            .map(|texture| FileDiagnosticReport::new(FileDiagnosticReportType::SpeedTreeTextureNotFound(texture)))
+            .collect()
+    }
+
+    /// This function returns if the provided file is a VariantMeshDefinition.
+    fn is_variant_mesh_definition(file: &RFile) -> bool {
+        file.file_type() == FileType::Text && file.path_in_container_raw().to_lowercase().ends_with(".variantmeshdefinition")
+    }
+
+    /// This function returns the path list format of the provided file, if it's a path list.
+    fn path_list_format(file: &RFile) -> Option<PathListFormat> {
+        if file.file_type() == FileType::Text {
+            PathListFormat::from_path(file.path_in_container_raw())
+        } else {
+            None
+        }
+    }
+
     /// This function takes care of checking for PackFile-Related for errors.
     fn check_pack(pack: &Pack) -> Option<DiagnosticType> {
         let mut diagnostic = PackDiagnostic::default();
@@ -771,13 +1067,21 @@ impl Diagnostics {
     }
 
     /// This function takes care of checking for errors in the Dependency Manager.
-    fn check_dependency_manager(pack: &Pack) ->Option<DiagnosticType> {
+    fn check_dependency_manager(pack: &Pack, global_ignored_diagnostics: &[String]) ->Option<DiagnosticType> {
         let mut diagnostic = DependencyDiagnostic::default();
-        for (index, pack) in pack.dependencies().iter().enumerate() {
+        let pack_name = pack.disk_file_name();
+        for (index, dependency) in pack.dependencies().iter().enumerate() {
 
             // TODO: Make it so this also checks if the PackFile actually exists,
-            if pack.is_empty() || !pack.ends_with(".pack") || pack.contains(' ') {
-                let result = DependencyDiagnosticReport::new(DependencyDiagnosticReportType::InvalidDependencyPackName(pack.to_string()), &[(index as i32, 0)]);
+            if dependency.is_empty() || !dependency.ends_with(".pack") || dependency.contains(' ') {
+                let result = DependencyDiagnosticReport::new(DependencyDiagnosticReportType::InvalidDependencyPackName(dependency.to_string()), &[(index as i32, 0)]);
+                diagnostic.results_mut().push(result);
+            }
+
+            // Packs load in alphabetical order, so a dependency sorting after us loads too late to be overwritten by us.
+            // Unsaved packs have no name yet, so we can't check them.
+            else if !pack_name.is_empty() && *dependency > pack_name && !global_ignored_diagnostics.iter().any(|x| x == "DependencyLoadedAfterPack") {
+                let result = DependencyDiagnosticReport::new(DependencyDiagnosticReportType::DependencyLoadedAfterPack(dependency.to_string()), &[(index as i32, 0)]);
                 diagnostic.results_mut().push(result);
             }
         }
@@ -826,8 +1130,11 @@ impl Diagnostics {
     fn ignore_diagnostic(global_ignored_diagnostics: &[String], field_name: Option<&str>, diagnostic: Option<&str>, ignored_fields: &[String], ignored_diagnostics: &HashSet<String>, ignored_diagnostics_for_fields: &HashMap<String, Vec<String>>) -> bool {
         let mut ignore_diagnostic = false;
 
+        // If the diagnostic is globally ignored, ignore it.
         if let Some(diagnostic) = diagnostic {
-            return global_ignored_diagnostics.iter().any(|x| x == diagnostic);
+            if global_ignored_diagnostics.iter().any(|x| x == diagnostic) {
+                return true;
+            }
         }
 
         // If we have a field, and it's in the ignored list, ignore it.
@@ -836,8 +1143,10 @@ impl Diagnostics {
         }
 
         // If we have a diagnostic, and it's in the ignored list, ignore it.
-        else if let Some(diagnostic) = diagnostic {
-            ignore_diagnostic = ignored_diagnostics.get(diagnostic).is_some();
+        if !ignore_diagnostic {
+            if let Some(diagnostic) = diagnostic {
+                ignore_diagnostic = ignored_diagnostics.get(diagnostic).is_some();
+            }
         }
 
         // If we have not yet being ignored, check for specific diagnostics for specific fields.
@@ -854,46 +1163,44 @@ impl Diagnostics {
         ignore_diagnostic
     }
 
-    /// Ignore entire tables if their path starts with the one we have (so we can do mass ignores) and we didn't specified a field to ignore.
-    fn ignore_data_for_file(file: &RFile, files_to_ignore: &Option<Vec<(String, Vec<String>, Vec<String>)>>) -> Option<(Vec<String>, HashSet<String>, HashMap<String, Vec<String>>)> {
+    /// This function returns what to ignore of the provided file, according to the provided ignore rules.
+    ///
+    /// Returns None if the entire file has to be ignored.
+    fn ignore_data_for_file(file: &RFile, ignore_rules: &[DiagnosticIgnoreRule]) -> Option<(Vec<String>, HashSet<String>, HashMap<String, Vec<String>>)> {
         let mut ignored_fields = vec![];
         let mut ignored_diagnostics = HashSet::new();
         let mut ignored_diagnostics_for_fields: HashMap<String, Vec<String>> = HashMap::new();
-        if let Some(ref files_to_ignore) = files_to_ignore {
-            for (path_to_ignore, fields, diags_to_ignore) in files_to_ignore {
 
-                // If the rule doesn't affect this PackedFile, ignore it.
-                if !path_to_ignore.is_empty() && file.path_in_container_raw().starts_with(path_to_ignore) {
+        for rule in ignore_rules.iter().filter(|rule| rule.affects_path(file.path_in_container_raw())) {
+            match rule.diagnostic() {
 
-                    // If we don't have either fields or diags specified, we ignore the entire file.
-                    if fields.is_empty() && diags_to_ignore.is_empty() {
-                        return None;
-                    }
-
-                    // If we have both, fields and diags, disable only those diags for those fields.
-                    if !fields.is_empty() && !diags_to_ignore.is_empty() {
-                        for field in fields {
-                            match ignored_diagnostics_for_fields.get_mut(field) {
-                                Some(diagnostics) => diagnostics.append(&mut diags_to_ignore.to_vec()),
-                                None => { ignored_diagnostics_for_fields.insert(field.to_owned(), diags_to_ignore.to_vec()); },
-                            }
-                        }
-                    }
-
-                    // Otherwise, check if we only have fields or diags, and put them separately.
-                    else if !fields.is_empty() {
-                        ignored_fields.append(&mut fields.to_vec());
-                    }
+                // If we don't have either fields or diags specified, we ignore the entire file.
+                None if rule.columns().is_empty() => return None,
+                None => ignored_fields.extend_from_slice(rule.columns()),
+                Some(diagnostic) if rule.columns().is_empty() => { ignored_diagnostics.insert(diagnostic.to_owned()); },
 
-                    else if !diags_to_ignore.is_empty() {
-                        ignored_diagnostics.extend(diags_to_ignore.to_vec());
-                    }
-                }
+                // If we have both, fields and diags, disable only those diags for those fields.
+                Some(diagnostic) => for column in rule.columns() {
+                    ignored_diagnostics_for_fields.entry(column.to_owned()).or_default().push(diagnostic.to_owned());
+                },
             }
         }
+
         Some((ignored_fields, ignored_diagnostics, ignored_diagnostics_for_fields))
     }
 
+    /// This function returns all the loc keys in the locs of the provided Pack.
+    fn local_loc_keys(pack: &Pack) -> HashSet<String> {
+        pack.files_by_type(&[FileType::Loc]).par_iter()
+            .filter_map(|file| if let Ok(RFileDecoded::Loc(table)) = file.decoded() {
+                table.data(&None).ok().map(|data| data.iter()
+                    .filter_map(|row| if let DecodedData::StringU16(key) = &row[0] { Some(key.to_owned()) } else { None })
+                    .collect::<Vec<_>>())
+            } else { None })
+            .flatten()
+            .collect()
+    }
+
     /// This function is used to check if a table is outdated or not.
     fn is_table_outdated(table_name: &str, table_version: i32, dependencies: &Dependencies) -> bool {
         if let Ok(vanilla_dbs) = dependencies.db_data(table_name, true, false) {
@@ -918,6 +1225,20 @@ impl Diagnostics {
     pub fn json(&self) -> Result<String> {
         serde_json::to_string_pretty(self).map_err(From::from)
     }
+
+    /// This function returns if any of the results of the last check is an error.
+    pub fn has_errors(&self) -> bool {
+        let is_error = |level: DiagnosticLevel| matches!(level, DiagnosticLevel::Error);
+        self.results.iter().any(|diagnostic| match diagnostic {
+            DiagnosticType::AnimFragment(diag) => diag.results().iter().any(|result| is_error(result.level())),
+            DiagnosticType::Config(diag) => diag.results().iter().any(|result| is_error(result.level())),
+            DiagnosticType::Dependency(diag) => diag.results().iter().any(|result| is_error(result.level())),
+            DiagnosticType::DB(diag) |
+            DiagnosticType::Loc(diag) => diag.results().iter().any(|result| is_error(result.level())),
+            DiagnosticType::Pack(diag) => diag.results().iter().any(|result| is_error(result.level())),
+            DiagnosticType::File(diag) => diag.results().iter().any(|result| is_error(result.level())),
+        })
+    }
 }
 
 impl Display for DiagnosticType {
@@ -929,6 +1250,7 @@ impl Display for DiagnosticType {
             Self::Loc(_) => "Loc",
             Self::Pack(_) => "Packfile",
             Self::Dependency(_) => "DependencyManager",
+            Self::File(_) => "File",
         }, f)
     }
 }
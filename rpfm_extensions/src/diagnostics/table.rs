@@ -63,6 +63,8 @@ pub enum TableDiagnosticReportType {
     FieldWithPathNotFound(Vec<String>),
     BannedTable,
     ValueCannotBeEmpty(String),
+    MissingLocData(String),
+    NonStandardLocLayout,
 }
 
 //-------------------------------------------------------------------------------//
@@ -108,6 +110,8 @@ impl DiagnosticReport for TableDiagnosticReport {
             TableDiagnosticReportType::FieldWithPathNotFound(paths) => format!("Path not found: {}.", paths.iter().join(" || ")),
             TableDiagnosticReportType::BannedTable => "Banned table.".to_owned(),
             TableDiagnosticReportType::ValueCannotBeEmpty(field_name) => format!("Empty value for column \"{}\".", field_name),
+            TableDiagnosticReportType::MissingLocData(loc_key) => format!("Missing loc data for key \"{}\".", loc_key),
+            TableDiagnosticReportType::NonStandardLocLayout => "Loc file with non-standard layout.".to_owned(),
         }
     }
 
@@ -131,6 +135,8 @@ impl DiagnosticReport for TableDiagnosticReport {
             TableDiagnosticReportType::FieldWithPathNotFound(_) => DiagnosticLevel::Warning,
             TableDiagnosticReportType::BannedTable => DiagnosticLevel::Error,
             TableDiagnosticReportType::ValueCannotBeEmpty(_) => DiagnosticLevel::Error,
+            TableDiagnosticReportType::MissingLocData(_) => DiagnosticLevel::Notice,
+            TableDiagnosticReportType::NonStandardLocLayout => DiagnosticLevel::Warning,
         }
     }
 }
@@ -156,6 +162,8 @@ impl Display for TableDiagnosticReportType {
             Self::FieldWithPathNotFound(_) => "FieldWithPathNotFound",
             Self::BannedTable => "BannedTable",
             Self::ValueCannotBeEmpty(_) => "ValueCannotBeEmpty",
+            Self::MissingLocData(_) => "MissingLocData",
+            Self::NonStandardLocLayout => "NonStandardLocLayout",
         }, f)
     }
 }
@@ -0,0 +1,187 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the container journal.
+
+use rpfm_lib::files::FileType;
+
+use super::*;
+
+fn file(path: &str, data: &[u8]) -> RFile {
+    RFile::new_from_vec(data, FileType::Unknown, 0, path)
+}
+
+fn pack(files: &[(&str, &[u8])]) -> Pack {
+    let mut pack = Pack::default();
+    for (path, data) in files {
+        pack.insert(file(path, data)).unwrap();
+    }
+    pack
+}
+
+fn data(pack: &Pack, path: &str) -> Vec<u8> {
+    pack.files().get(path).unwrap().cached().unwrap().to_vec()
+}
+
+#[test]
+fn test_undo_redo_removal() {
+    let mut pack = pack(&[("script/a.lua", b"a"), ("script/b.lua", b"b"), ("text/c.txt", b"c")]);
+    let mut journal = ContainerJournal::new(1024);
+
+    let paths = vec![ContainerPath::Folder("script".to_owned())];
+    let removed = ContainerJournal::snapshot(&pack, &paths);
+    pack.remove(&paths[0]);
+    assert!(journal.push_removal(removed).is_empty());
+    assert_eq!(journal.memory_usage(), 2);
+
+    let changes = journal.undo(&mut pack).unwrap();
+    assert_eq!(changes.added().len(), 2);
+    assert_eq!(data(&pack, "script/a.lua"), b"a");
+    assert_eq!(data(&pack, "script/b.lua"), b"b");
+    assert!(!journal.can_undo());
+    assert!(journal.can_redo());
+
+    let changes = journal.redo(&mut pack).unwrap();
+    assert_eq!(changes.removed().len(), 2);
+    assert_eq!(pack.files().len(), 1);
+    assert!(journal.can_undo());
+    assert!(journal.redo(&mut pack).is_none());
+}
+
+#[test]
+fn test_undo_redo_addition_with_replacement() {
+    let mut pack = pack(&[("text/a.txt", b"old")]);
+    let mut journal = ContainerJournal::new(1024);
+
+    let paths = vec![ContainerPath::File("text/a.txt".to_owned()), ContainerPath::File("text/b.txt".to_owned())];
+    let previous = ContainerJournal::snapshot(&pack, &paths);
+    pack.insert(file("text/a.txt", b"new")).unwrap();
+    pack.insert(file("text/b.txt", b"b")).unwrap();
+    journal.push_addition(&paths, previous);
+
+    let changes = journal.undo(&mut pack).unwrap();
+    assert_eq!(changes.removed(), &vec![ContainerPath::File("text/b.txt".to_owned())]);
+    assert_eq!(changes.modified(), &vec![ContainerPath::File("text/a.txt".to_owned())]);
+    assert_eq!(data(&pack, "text/a.txt"), b"old");
+    assert!(!pack.has_file("text/b.txt"));
+
+    journal.redo(&mut pack).unwrap();
+    assert_eq!(data(&pack, "text/a.txt"), b"new");
+    assert_eq!(data(&pack, "text/b.txt"), b"b");
+}
+
+#[test]
+fn test_undo_redo_moves() {
+    let mut pack = pack(&[("text/a.txt", b"a"), ("text/b.txt", b"b")]);
+    let mut journal = ContainerJournal::new(1024);
+
+    // Rename a over b, replacing it.
+    let renames = vec![(ContainerPath::File("text/a.txt".to_owned()), ContainerPath::File("text/b.txt".to_owned()))];
+    let previous = ContainerJournal::snapshot(&pack, &[renames[0].1.clone()]);
+    let moved = pack.move_paths(&renames).unwrap();
+    journal.push_moves(&moved, previous);
+    assert_eq!(pack.files().len(), 1);
+
+    let changes = journal.undo(&mut pack).unwrap();
+    assert_eq!(changes.moved().len(), 1);
+    assert_eq!(data(&pack, "text/a.txt"), b"a");
+    assert_eq!(data(&pack, "text/b.txt"), b"b");
+
+    journal.redo(&mut pack).unwrap();
+    assert_eq!(pack.files().len(), 1);
+    assert_eq!(data(&pack, "text/b.txt"), b"a");
+
+    journal.undo(&mut pack).unwrap();
+    assert_eq!(data(&pack, "text/a.txt"), b"a");
+    assert_eq!(data(&pack, "text/b.txt"), b"b");
+}
+
+#[test]
+fn test_new_operation_clears_redo() {
+    let mut pack = pack(&[("text/a.txt", b"a"), ("text/b.txt", b"b")]);
+    let mut journal = ContainerJournal::new(1024);
+
+    let paths = vec![ContainerPath::File("text/a.txt".to_owned())];
+    let removed = ContainerJournal::snapshot(&pack, &paths);
+    pack.remove(&paths[0]);
+    journal.push_removal(removed);
+    journal.undo(&mut pack).unwrap();
+    assert!(journal.can_redo());
+
+    let paths = vec![ContainerPath::File("text/b.txt".to_owned())];
+    let removed = ContainerJournal::snapshot(&pack, &paths);
+    pack.remove(&paths[0]);
+    journal.push_removal(removed);
+    assert!(!journal.can_redo());
+    assert_eq!(journal.undo_stack().len(), 1);
+}
+
+#[test]
+fn test_memory_cap() {
+    let mut pack = pack(&[("text/big.txt", &[0; 64]), ("text/a.txt", &[0; 8]), ("text/b.txt", &[0; 8])]);
+    let mut journal = ContainerJournal::new(10);
+
+    // Files bigger than the cap are not kept.
+    let paths = vec![ContainerPath::File("text/big.txt".to_owned())];
+    let removed = ContainerJournal::snapshot(&pack, &paths);
+    pack.remove(&paths[0]);
+    assert_eq!(journal.push_removal(removed), vec!["text/big.txt".to_owned()]);
+    assert!(!journal.can_undo());
+
+    // Older operations are forgotten when the cap is reached.
+    for path in ["text/a.txt", "text/b.txt"] {
+        let paths = vec![ContainerPath::File(path.to_owned())];
+        let removed = ContainerJournal::snapshot(&pack, &paths);
+        pack.remove(&paths[0]);
+        assert!(journal.push_removal(removed).is_empty());
+    }
+
+    assert_eq!(journal.undo_stack().len(), 1);
+    assert_eq!(journal.memory_usage(), 8);
+
+    journal.undo(&mut pack).unwrap();
+    assert!(pack.has_file("text/b.txt"));
+    assert!(!pack.has_file("text/a.txt"));
+
+    // Redoing with no memory left removes the file again, but it can no longer be restored.
+    journal.set_memory_cap(0);
+    let changes = journal.redo(&mut pack).unwrap();
+    assert_eq!(changes.not_kept(), &vec!["text/b.txt".to_owned()]);
+    assert!(!pack.has_file("text/b.txt"));
+    assert!(!journal.can_undo());
+}
+
+#[test]
+fn test_memory_cap_entry_too_big() {
+    let mut pack = pack(&[("text/a.txt", &[0; 8]), ("text/b.txt", &[0; 8]), ("text/c.txt", &[0; 4])]);
+    let mut journal = ContainerJournal::new(10);
+
+    let paths = vec![ContainerPath::File("text/c.txt".to_owned())];
+    let removed = ContainerJournal::snapshot(&pack, &paths);
+    pack.remove(&paths[0]);
+    assert!(journal.push_removal(removed).is_empty());
+
+    // Each file fits within the cap, but both together don't, so the operation cannot be undone.
+    let paths = vec![ContainerPath::File("text/a.txt".to_owned()), ContainerPath::File("text/b.txt".to_owned())];
+    let removed = ContainerJournal::snapshot(&pack, &paths);
+    for path in &paths {
+        pack.remove(path);
+    }
+
+    let mut not_kept = journal.push_removal(removed);
+    not_kept.sort();
+    assert_eq!(not_kept, vec!["text/a.txt".to_owned(), "text/b.txt".to_owned()]);
+
+    // The operation before it is not forgotten to make room for it.
+    assert_eq!(journal.undo_stack().len(), 1);
+    journal.undo(&mut pack).unwrap();
+    assert!(pack.has_file("text/c.txt"));
+    assert!(!pack.has_file("text/a.txt"));
+}
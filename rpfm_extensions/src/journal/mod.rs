@@ -0,0 +1,345 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the [ContainerJournal] struct, used to undo and redo operations over the structure of a Container.
+//!
+//! Only operations that add, remove or move files are journaled. Changes to the contents of a file are not.
+//! Removed files are kept in memory, encoded, so they can be restored later. To avoid the journal eating all the memory
+//! available, the amount of memory it can use is capped: files of an operation that don't fit within the cap are not kept,
+//! and older operations are forgotten once the cap is reached.
+
+use getset::Getters;
+
+use std::collections::{HashSet, VecDeque};
+
+use rpfm_lib::files::{Container, ContainerPath, pack::Pack, RFile};
+
+#[cfg(test)] mod journal_test;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct keeps track of the operations done over the structure of a Container, so they can be undone and redone.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct ContainerJournal {
+
+    /// Operations that can be undone, from oldest to newest.
+    undo_stack: VecDeque<JournalEntry>,
+
+    /// Operations that can be redone, from oldest to newest undone.
+    redo_stack: VecDeque<JournalEntry>,
+
+    /// Max amount of bytes the files kept by the journal can use.
+    memory_cap: usize,
+}
+
+/// This struct represents an operation over the structure of a Container, in a way it can be reverted.
+#[derive(Clone, Debug, Default)]
+pub struct JournalEntry {
+
+    /// Paths of the files added by the operation.
+    added: Vec<String>,
+
+    /// Old and new paths of the files moved by the operation, in the order they were moved.
+    moved: Vec<(String, String)>,
+
+    /// Files removed or replaced by the operation, encoded.
+    removed: Vec<RFile>,
+}
+
+/// This struct contains the changes done to a Container when undoing or redoing an operation.
+#[derive(Clone, Debug, Default, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct JournalChanges {
+
+    /// Paths of the files no longer in the Container.
+    removed: Vec<ContainerPath>,
+
+    /// Paths of the files that were not in the Container before.
+    added: Vec<ContainerPath>,
+
+    /// Paths of the files replaced with other files with the same path.
+    modified: Vec<ContainerPath>,
+
+    /// Old and new paths of the files moved within the Container.
+    moved: Vec<(ContainerPath, ContainerPath)>,
+
+    /// Paths of the removed files that were too big to keep, so this change cannot be reverted for them.
+    not_kept: Vec<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl ContainerJournal {
+
+    /// This function creates a new empty journal, which files can use up to `memory_cap` bytes.
+    pub fn new(memory_cap: usize) -> Self {
+        Self {
+            memory_cap,
+            ..Default::default()
+        }
+    }
+
+    /// This function changes the max amount of bytes the journal can use, forgetting the oldest operations if needed.
+    pub fn set_memory_cap(&mut self, memory_cap: usize) {
+        self.memory_cap = memory_cap;
+        self.enforce_memory_cap();
+    }
+
+    /// This function returns the amount of bytes used by the files kept in the journal.
+    pub fn memory_usage(&self) -> usize {
+        self.undo_stack.iter()
+            .chain(self.redo_stack.iter())
+            .map(|entry| entry.memory_usage())
+            .sum()
+    }
+
+    /// This function returns if there is any operation to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// This function returns if there is any operation to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// This function forgets all the operations in the journal.
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+
+    /// This function returns copies of the files under the provided paths, to pass them to the `push_` functions after an operation.
+    ///
+    /// Take them right before the operation, so the journal can restore any file the operation replaces.
+    pub fn snapshot(pack: &Pack, paths: &[ContainerPath]) -> Vec<RFile> {
+        pack.files_by_paths(paths, false).into_iter().cloned().collect()
+    }
+
+    /// This function records an operation that removed files from the Container.
+    ///
+    /// `removed` must be the files as they were before removing them. Returns the paths of the files too big to keep.
+    pub fn push_removal(&mut self, removed: Vec<RFile>) -> Vec<String> {
+        let mut entry = JournalEntry::default();
+        let mut not_kept = vec![];
+        for file in removed {
+            self.keep(file, &mut entry.removed, &mut not_kept);
+        }
+
+        self.push(entry, &mut not_kept);
+        not_kept
+    }
+
+    /// This function records an operation that added files to the Container.
+    ///
+    /// `previous` must be a [snapshot](Self::snapshot) taken before the operation. Only the files replaced by it are kept.
+    /// Returns the paths of the replaced files too big to keep.
+    pub fn push_addition(&mut self, added: &[ContainerPath], previous: Vec<RFile>) -> Vec<String> {
+        let mut entry = JournalEntry::default();
+        let mut not_kept = vec![];
+        entry.added = added.iter()
+            .filter_map(|path| match path {
+                ContainerPath::File(path) => Some(path.to_owned()),
+                ContainerPath::Folder(_) => None,
+            })
+            .collect();
+
+        let added = entry.added.iter().map(|path| path.as_str()).collect::<HashSet<_>>();
+        let replaced = previous.into_iter().filter(|file| added.contains(file.path_in_container_raw())).collect::<Vec<_>>();
+        for file in replaced {
+            self.keep(file, &mut entry.removed, &mut not_kept);
+        }
+
+        self.push(entry, &mut not_kept);
+        not_kept
+    }
+
+    /// This function records an operation that moved files within the Container.
+    ///
+    /// `moved` must contain the old and new paths of each moved file, as returned by the move functions of [Container].
+    /// `previous` must be a [snapshot](Self::snapshot) of the destination paths taken before the operation, or empty if the
+    /// operation cannot replace files. Returns the paths of the replaced files too big to keep.
+    pub fn push_moves(&mut self, moved: &[(ContainerPath, ContainerPath)], previous: Vec<RFile>) -> Vec<String> {
+        let mut entry = JournalEntry::default();
+        let mut not_kept = vec![];
+        entry.moved = moved.iter()
+            .filter_map(|(old_path, new_path)| match (old_path, new_path) {
+                (ContainerPath::File(old_path), ContainerPath::File(new_path)) => Some((old_path.to_owned(), new_path.to_owned())),
+                _ => None,
+            })
+            .collect();
+
+        // Files that were moved away are not replaced, even if another file took their place.
+        let old_paths = entry.moved.iter().map(|(old_path, _)| old_path.as_str()).collect::<HashSet<_>>();
+        let new_paths = entry.moved.iter().map(|(_, new_path)| new_path.as_str()).collect::<HashSet<_>>();
+        let replaced = previous.into_iter()
+            .filter(|file| new_paths.contains(file.path_in_container_raw()) && !old_paths.contains(file.path_in_container_raw()))
+            .collect::<Vec<_>>();
+
+        for file in replaced {
+            self.keep(file, &mut entry.removed, &mut not_kept);
+        }
+
+        self.push(entry, &mut not_kept);
+        not_kept
+    }
+
+    /// This function undoes the last operation done, returning what changed in the Container.
+    ///
+    /// Returns None if there was nothing to undo.
+    pub fn undo(&mut self, pack: &mut Pack) -> Option<JournalChanges> {
+        let entry = self.undo_stack.pop_back()?;
+        let (mut inverse, mut changes) = self.revert(pack, entry);
+        self.fit_in_memory_cap(&mut inverse, &mut changes.not_kept);
+        if !inverse.is_empty() {
+            self.redo_stack.push_back(inverse);
+        }
+
+        self.enforce_memory_cap();
+        Some(changes)
+    }
+
+    /// This function redoes the last operation undone, returning what changed in the Container.
+    ///
+    /// Returns None if there was nothing to redo.
+    pub fn redo(&mut self, pack: &mut Pack) -> Option<JournalChanges> {
+        let entry = self.redo_stack.pop_back()?;
+        let (mut inverse, mut changes) = self.revert(pack, entry);
+        self.fit_in_memory_cap(&mut inverse, &mut changes.not_kept);
+        if !inverse.is_empty() {
+            self.undo_stack.push_back(inverse);
+        }
+
+        self.enforce_memory_cap();
+        Some(changes)
+    }
+
+    /// This function adds a new operation to the journal. Doing a new operation means the undone ones can no longer be redone.
+    ///
+    /// If the files kept by the operation don't fit within the memory cap, their paths are added to `not_kept`.
+    fn push(&mut self, mut entry: JournalEntry, not_kept: &mut Vec<String>) {
+        self.fit_in_memory_cap(&mut entry, not_kept);
+        if !entry.is_empty() {
+            self.redo_stack.clear();
+            self.undo_stack.push_back(entry);
+            self.enforce_memory_cap();
+        }
+    }
+
+    /// This function reverts the provided operation, returning the operation that reverts it back, and what changed in the Container.
+    ///
+    /// Steps that cannot be reverted, like moving back a file that is no longer there, are skipped.
+    fn revert(&self, pack: &mut Pack, entry: JournalEntry) -> (JournalEntry, JournalChanges) {
+        let mut inverse = JournalEntry::default();
+        let mut changes = JournalChanges::default();
+
+        // Take out the added files, keeping them so they can be added back.
+        for path in &entry.added {
+            if let Some(file) = pack.files_mut().remove(path) {
+                changes.removed.push(ContainerPath::File(path.to_owned()));
+                self.keep(file, &mut inverse.removed, &mut changes.not_kept);
+            }
+        }
+
+        // Move back the moved files, in reverse order, so chained moves end up where they started.
+        for (old_path, new_path) in entry.moved.iter().rev() {
+            if let Some(mut file) = pack.files_mut().remove(new_path) {
+                if let Some(previous) = pack.files_mut().remove(old_path) {
+                    changes.removed.push(ContainerPath::File(old_path.to_owned()));
+                    self.keep(previous, &mut inverse.removed, &mut changes.not_kept);
+                }
+
+                file.set_path_in_container_raw(old_path);
+                pack.files_mut().insert(old_path.to_owned(), file);
+                inverse.moved.push((new_path.to_owned(), old_path.to_owned()));
+                changes.moved.push((ContainerPath::File(new_path.to_owned()), ContainerPath::File(old_path.to_owned())));
+            }
+        }
+
+        // Put back the removed files. If something took their place, keep it.
+        for file in entry.removed {
+            let path = file.path_in_container_raw().to_owned();
+            if let Some(previous) = pack.files_mut().remove(&path) {
+                changes.removed.push(ContainerPath::File(path.to_owned()));
+                self.keep(previous, &mut inverse.removed, &mut changes.not_kept);
+            }
+
+            pack.files_mut().insert(path.to_owned(), file);
+            inverse.added.push(path.to_owned());
+            changes.added.push(ContainerPath::File(path));
+        }
+
+        // Files that were taken out and put back are just modified.
+        let removed = changes.removed.iter().cloned().collect::<HashSet<_>>();
+        changes.modified = changes.added.iter().filter(|path| removed.contains(path)).cloned().collect();
+        let modified = changes.modified.iter().cloned().collect::<HashSet<_>>();
+        changes.added.retain(|path| !modified.contains(path));
+        changes.removed.retain(|path| !modified.contains(path));
+
+        (inverse, changes)
+    }
+
+    /// This function loads and encodes the provided file so it can be kept in the journal, adding it to `kept` if it fits within the memory cap.
+    ///
+    /// If it doesn't, or it cannot be encoded, its path is added to `not_kept` instead.
+    fn keep(&self, mut file: RFile, kept: &mut Vec<RFile>, not_kept: &mut Vec<String>) {
+        let fits = file.load().is_ok() &&
+            file.encode(&None, true, true, false).is_ok() &&
+            file.cached().map(|data| data.len() <= self.memory_cap).unwrap_or(false);
+
+        if fits {
+            kept.push(file);
+        } else {
+            not_kept.push(file.path_in_container_raw().to_owned());
+        }
+    }
+
+    /// This function drops the files kept by the provided operation if, together, they don't fit within the memory cap.
+    ///
+    /// Otherwise, making room for them would forget the operation itself. Their paths are added to `not_kept`.
+    fn fit_in_memory_cap(&self, entry: &mut JournalEntry, not_kept: &mut Vec<String>) {
+        if entry.memory_usage() > self.memory_cap {
+            not_kept.extend(entry.removed.drain(..).map(|file| file.path_in_container_raw().to_owned()));
+        }
+    }
+
+    /// This function forgets the oldest operations until the kept files fit within the memory cap.
+    ///
+    /// Operations to undo are forgotten first, then the ones to redo, starting with the ones furthest from being redone.
+    fn enforce_memory_cap(&mut self) {
+        let mut memory_usage = self.memory_usage();
+        while memory_usage > self.memory_cap {
+            match self.undo_stack.pop_front().or_else(|| self.redo_stack.pop_front()) {
+                Some(entry) => memory_usage -= entry.memory_usage(),
+                None => break,
+            }
+        }
+    }
+}
+
+impl JournalEntry {
+
+    /// This function returns if the operation did nothing.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.moved.is_empty() && self.removed.is_empty()
+    }
+
+    /// This function returns the amount of bytes used by the files kept in this operation.
+    pub fn memory_usage(&self) -> usize {
+        self.removed.iter()
+            .map(|file| file.cached().map(|data| data.len()).unwrap_or(0))
+            .sum()
+    }
+}
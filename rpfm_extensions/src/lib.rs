@@ -10,7 +10,20 @@
 
 //! This crate contains certain functionality extensions that, for one reason or another, didn't fit in the main RPFM lib crate.
 
+pub mod cascade_edition;
+pub mod conflicts;
+pub mod custom_files;
+pub mod deep_clone;
 pub mod dependencies;
 pub mod diagnostics;
+pub mod journal;
+#[cfg(feature = "support_lua_check")] pub mod lua;
 pub mod optimizer;
 pub mod search;
+pub mod statistics;
+pub mod table_diff;
+pub mod template;
+pub mod translation;
+pub mod variant_mesh;
+
+#[cfg(test)] mod test_helpers;
@@ -0,0 +1,52 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the Lua syntax check.
+
+use super::*;
+
+#[test]
+fn test_check_syntax_valid() {
+    let code = "\
+local function greet(name)
+    out(\"Hello, \" .. name)
+end
+
+core:add_listener(\"test\", \"FactionTurnStart\", true, function(context) greet(\"there\") end, true)
+";
+
+    assert!(check_syntax(code).is_empty());
+}
+
+#[test]
+fn test_check_syntax_stray_end() {
+    let code = "\
+local function greet(name)
+    out(name)
+end
+end
+";
+
+    let errors = check_syntax(code);
+    assert!(!errors.is_empty());
+    assert_eq!(*errors[0].line(), 4);
+    assert_eq!(*errors[0].column(), 1);
+    assert!(!errors[0].message().is_empty());
+}
+
+#[test]
+fn test_check_syntax_unclosed_block() {
+    let code = "\
+if true then
+    out(\"missing end\")
+";
+
+    assert!(!check_syntax(code).is_empty());
+}
@@ -0,0 +1,71 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the code to check the syntax of Lua scripts.
+//!
+//! Only the syntax is checked. Scripts are fully parsed, but nothing is resolved or executed.
+
+use getset::Getters;
+use serde_derive::{Serialize, Deserialize};
+
+use std::fmt::{Display, Formatter};
+
+#[cfg(test)] mod lua_test;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains a syntax error found in a Lua script.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct LuaSyntaxError {
+
+    /// Line of the error, starting at 1.
+    line: usize,
+
+    /// Column of the error, starting at 1.
+    column: usize,
+
+    /// Message explaining the error.
+    message: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl Display for LuaSyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// This function checks the syntax of the provided Lua script, returning the errors found in it, in the order they appear.
+pub fn check_syntax(code: &str) -> Vec<LuaSyntaxError> {
+    match full_moon::parse(code) {
+        Ok(_) => vec![],
+        Err(errors) => {
+            let mut errors = errors.iter()
+                .map(|error| {
+                    let (start, _) = error.range();
+                    LuaSyntaxError {
+                        line: start.line(),
+                        column: start.character(),
+                        message: error.error_message().to_string(),
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            errors.sort_by_key(|error| (error.line, error.column));
+            errors
+        }
+    }
+}
@@ -10,17 +10,81 @@
 
 //! This module contains the [Optimizable] and [OptimizableContainer] trait.
 
+use getset::{Getters, Setters};
 use rayon::prelude::*;
 
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{self, AtomicBool};
 
 use rpfm_lib::error::{RLibError, Result};
-use rpfm_lib::files::{Container, ContainerPath, DecodeableExtraData, db::DB, FileType, loc::Loc, pack::Pack, RFileDecoded, table::DecodedData};
+use rpfm_lib::files::{Container, ContainerPath, DecodeableExtraData, db::DB, FileType, loc::Loc, pack::Pack, RFile, RFileDecoded, table::DecodedData};
 use rpfm_lib::schema::Schema;
 
 use crate::dependencies::Dependencies;
 
+#[cfg(test)] mod optimizer_test;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the toggles to enable/disable each of the optimizations the optimizer can perform.
+///
+/// By default, all of them are enabled.
+#[derive(Clone, Copy, Debug, Getters, Setters)]
+#[getset(get = "pub", set = "pub")]
+pub struct OptimizerOptions {
+
+    /// Remove table rows identical to the vanilla/parent ones.
+    remove_itm: bool,
+
+    /// Remove table rows identical to a new, empty row.
+    remove_itnr: bool,
+
+    /// Remove duplicated table rows. This also sorts the tables.
+    remove_duplicates: bool,
+
+    /// Remove tables left empty after the rest of the optimizations.
+    remove_empty_files: bool,
+
+    /// Remove XML files left by Terry in map folders.
+    remove_map_xml: bool,
+
+    /// Remove Loc entries overridden by Loc files loaded later within the same Pack.
+    remove_overridden_loc_entries: bool,
+}
+
+/// This struct contains what the optimizer would do to a [Container](rpfm_lib::files::Container), without actually doing it.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct OptimizerReport {
+
+    /// Files the optimizer would change or remove, by path.
+    files: BTreeMap<String, OptimizerFileReport>,
+}
+
+/// This struct contains what the optimizer does (or would do) to a specific file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct OptimizerFileReport {
+
+    /// Amount of duplicated rows removed.
+    duplicated_rows: usize,
+
+    /// Amount of ITM (Identical To Master) rows removed.
+    itm_rows: usize,
+
+    /// Amount of ITNR (Identical To New Row) rows removed.
+    itnr_rows: usize,
+
+    /// Amount of Loc rows removed because Loc files loaded later within the same Pack override them.
+    overridden_rows: usize,
+
+    /// If the file gets removed.
+    removed: bool,
+}
+
 //-------------------------------------------------------------------------------//
 //                             Trait definitions
 //-------------------------------------------------------------------------------//
@@ -31,7 +95,7 @@ pub trait Optimizable {
     /// This function optimizes the provided struct to reduce its size and improve compatibility.
     ///
     /// It returns if the struct has been left in an state where it can be safetly deleted.
-    fn optimize(&mut self, dependencies: &mut Dependencies) -> bool;
+    fn optimize(&mut self, dependencies: &mut Dependencies, options: &OptimizerOptions) -> bool;
 }
 
 /// This trait marks a [Container](rpfm_lib::files::Container) as an `Optimizable` container, meaning it can be cleaned up to reduce size and improve compatibility.
@@ -40,31 +104,52 @@ pub trait OptimizableContainer: Container {
     /// This function optimizes the provided [Container](rpfm_lib::files::Container) to reduce its size and improve compatibility.
     ///
     /// It returns the list of files that has been safetly deleted during the optimization process.
-    fn optimize(&mut self, dependencies: &mut Dependencies, schema: &Schema, optimize_datacored_tables: bool) -> Result<HashSet<String>>;
+    ///
+    /// The `progress` closure receives the current step, the total amount of steps and a description of the current step.
+    ///
+    /// If `cancel` is set while optimizing, the optimization is stopped before removing any file and [RLibError::OperationCancelled] is returned.
+    fn optimize(&mut self, dependencies: &mut Dependencies, schema: &Schema, optimize_datacored_tables: bool, options: &OptimizerOptions, progress: &(dyn Fn(u32, u32, &str) + Sync), cancel: &AtomicBool) -> Result<HashSet<String>>;
+
+    /// This function returns what [optimize](Self::optimize) would do to the provided [Container](rpfm_lib::files::Container) with the same arguments, without changing it.
+    fn optimize_report(&self, dependencies: &Dependencies, schema: &Schema, optimize_datacored_tables: bool, options: &OptimizerOptions) -> Result<OptimizerReport>;
 }
 
 //-------------------------------------------------------------------------------//
 //                           Trait implementations
 //-------------------------------------------------------------------------------//
 
+impl Default for OptimizerOptions {
+    fn default() -> Self {
+        Self {
+            remove_itm: true,
+            remove_itnr: true,
+            remove_duplicates: true,
+            remove_empty_files: true,
+            remove_map_xml: true,
+            remove_overridden_loc_entries: true,
+        }
+    }
+}
+
 impl OptimizableContainer for Pack {
 
     /// This function optimizes the provided [Pack](rpfm_lib::files::pack::Pack) file in order to make it smaller and more compatible.
     ///
-    /// Specifically, it performs the following optimizations:
+    /// Specifically, it performs the following optimizations, if enabled in the provided [OptimizerOptions]:
     ///
     /// - DB/Loc tables (except if the table has the same name as his vanilla/parent counterpart and `optimize_datacored_tables` is false):
     ///     - Removal of duplicated entries.
     ///     - Removal of ITM (Identical To Master) entries.
     ///     - Removal of ITNR (Identical To New Row) entries.
     ///     - Removal of empty tables.
+    /// - Removal of XML files in map folders.
+    /// - Removal of Loc entries overridden by other Loc files of the same Pack that load after them.
     ///
-    /// NOTE: due to a consequence of the optimization, all tables are also sorted by their first key.
+    /// NOTE: due to a consequence of the duplicate removal, all tables are also sorted by their first key.
     ///
     /// Not yet working:
-    /// - Remove XML files in map folders.
     /// - Remove files identical to Parent/Vanilla files (if is identical to vanilla, but a parent mod overwrites it, it ignores it).
-    fn optimize(&mut self, dependencies: &mut Dependencies, schema: &Schema, optimize_datacored_tables: bool) -> Result<HashSet<String>> {
+    fn optimize(&mut self, dependencies: &mut Dependencies, schema: &Schema, optimize_datacored_tables: bool, options: &OptimizerOptions, progress: &(dyn Fn(u32, u32, &str) + Sync), cancel: &AtomicBool) -> Result<HashSet<String>> {
 
         // We can only optimize if we have vanilla data available.
         if !dependencies.is_vanilla_data_loaded(true) {
@@ -90,56 +175,67 @@ impl OptimizableContainer for Pack {
         }).collect());
         */
 
+        // Terry leaves a bunch of xml files in the map folders that the game doesn't use.
+        if options.remove_map_xml {
+            files_to_delete.extend(self.map_xml_paths());
+        }
+
         let mut extra_data = DecodeableExtraData::default();
         extra_data.set_schema(Some(schema));
         let extra_data = Some(extra_data);
 
         // Then, do a second pass, this time over the decodeable files that we can optimize.
-        files_to_delete.extend(self.files_mut().iter_mut().filter_map(|(path, rfile)| {
+        let total = self.files().len() as u32;
+        // Stop at the first file after a cancellation, instead of going through the rest of them.
+        files_to_delete.extend(self.files_mut().iter_mut().enumerate().take_while(|_| !cancel.load(atomic::Ordering::Relaxed)).filter_map(|(index, (path, rfile))| {
+            progress(index as u32 + 1, total, path);
 
             // Only check it if it's not already marked for deletion.
-            if files_to_delete.get(path).is_none() {
-
-                match rfile.file_type() {
-                    FileType::DB => {
+            if !files_to_delete.contains(path) && is_optimizable(rfile, path, dependencies, optimize_datacored_tables) {
+                if let Ok(Some(mut decoded)) = rfile.decode(&extra_data, false, true) {
+                    if let Some((entries, file_report)) = optimized_rows(&decoded, dependencies, options) {
+                        if file_report.removed {
+                            return Some(path.to_owned());
+                        }
 
-                        // Unless we specifically wanted to, ignore the same-name-as-vanilla-or-parent files,
-                        // as those are probably intended to overwrite vanilla files, not to be optimized.
-                        if optimize_datacored_tables || !dependencies.file_exists(path, true, true, true) {
-                            if let Ok(Some(RFileDecoded::DB(mut db))) = rfile.decode(&extra_data, false, true) {
-                                if db.optimize(dependencies) {
-                                    return Some(path.to_owned());
-                                }
-                            }
+                        if set_rows(&mut decoded, &entries).is_ok() {
+                            let _ = rfile.set_decoded(decoded);
                         }
                     }
+                }
+            }
 
-                    FileType::Loc => {
+            None
+        }).collect::<Vec<String>>());
+
+        if cancel.load(atomic::Ordering::Relaxed) {
+            return Err(RLibError::OperationCancelled);
+        }
+
+        // Then, do a pass over all the locs together, removing entries overwritten by locs that load later.
+        if options.remove_overridden_loc_entries {
+            let mut keys_loaded_later = HashSet::new();
+            for path in loc_paths_by_load_order(self, &files_to_delete).iter().rev() {
+                if let Some(rfile) = self.files_mut().get_mut(path) {
+                    if rfile.decode(&extra_data, true, false).is_err() {
+                        continue;
+                    }
 
-                        // Same as with tables, don't optimize them if they're overwriting.
-                        if optimize_datacored_tables || !dependencies.file_exists(path, true, true, true) {
-                            if let Ok(Some(RFileDecoded::Loc(mut loc))) = rfile.decode(&extra_data, false, true) {
-                                if loc.optimize(dependencies) {
-                                    return Some(path.to_owned());
+                    if let Ok(RFileDecoded::Loc(loc)) = rfile.decoded_mut() {
+                        if let Ok(entries) = loc.data(&None) {
+                            let mut entries = entries.to_vec();
+                            if remove_overridden_loc_rows(&mut entries, &mut keys_loaded_later) > 0 {
+                                if entries.is_empty() && options.remove_empty_files {
+                                    files_to_delete.insert(path.to_owned());
+                                } else {
+                                    let _ = loc.set_data(&entries);
                                 }
                             }
                         }
                     }
-
-                    /*
-                    PackedFileType::Text(text_type) => {
-                        if !path.is_empty() && path.starts_with(&Self::get_terry_map_path()) && text_type == TextType::Xml {
-                            return Some(path.to_vec());
-                        }
-                    }*/
-
-                    // Ignore the rest.
-                    _ => {}
                 }
             }
-
-            None
-        }).collect::<Vec<String>>());
+        }
 
         // Delete all the files marked for deletion.
         files_to_delete.iter().for_each(|x| { self.remove(&ContainerPath::File(x.to_owned())); });
@@ -147,103 +243,103 @@ impl OptimizableContainer for Pack {
         // Return the deleted files, so the caller can know what got removed.
         Ok(files_to_delete)
     }
+
+    /// This function returns what [optimize](OptimizableContainer::optimize) would do to the provided [Pack](rpfm_lib::files::pack::Pack), without changing it.
+    ///
+    /// Only files the optimizer would change or remove are included in the report.
+    fn optimize_report(&self, dependencies: &Dependencies, schema: &Schema, optimize_datacored_tables: bool, options: &OptimizerOptions) -> Result<OptimizerReport> {
+
+        // We can only optimize if we have vanilla data available.
+        if !dependencies.is_vanilla_data_loaded(true) {
+            return Err(RLibError::DependenciesCacheNotGeneratedorOutOfDate);
+        }
+
+        let mut report = OptimizerReport::default();
+        if options.remove_map_xml {
+            for path in self.map_xml_paths() {
+                report.files.insert(path, OptimizerFileReport { removed: true, ..Default::default() });
+            }
+        }
+
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.set_schema(Some(schema));
+        let extra_data = Some(extra_data);
+
+        // Keep the rows the locs would be left with, so the overridden entries pass sees the same data the real optimization would see.
+        let mut loc_rows = HashMap::new();
+        for (path, rfile) in self.files() {
+            if report.files.contains_key(path) || !is_optimizable(rfile, path, dependencies, optimize_datacored_tables) {
+                continue;
+            }
+
+            // Decode a copy of the file, so we don't change the one in the Pack.
+            if let Ok(Some(decoded)) = rfile.clone().decode(&extra_data, false, true) {
+                if let Some((entries, file_report)) = optimized_rows(&decoded, dependencies, options) {
+                    if !file_report.removed && rfile.file_type() == FileType::Loc {
+                        loc_rows.insert(path.to_owned(), entries);
+                    }
+
+                    if file_report.removed || file_report.removed_rows() > 0 {
+                        report.files.insert(path.to_owned(), file_report);
+                    }
+                }
+            }
+        }
+
+        if options.remove_overridden_loc_entries {
+            let files_to_delete = report.files.iter()
+                .filter(|(_, file_report)| file_report.removed)
+                .map(|(path, _)| path.to_owned())
+                .collect::<HashSet<_>>();
+
+            let mut keys_loaded_later = HashSet::new();
+            for path in loc_paths_by_load_order(self, &files_to_delete).iter().rev() {
+                let mut entries = match loc_rows.remove(path) {
+                    Some(entries) => entries,
+                    None => match self.files().get(path).map(|rfile| rfile.clone().decode(&extra_data, false, true)) {
+                        Some(Ok(Some(RFileDecoded::Loc(loc)))) => match loc.data(&None) {
+                            Ok(entries) => entries.to_vec(),
+                            Err(_) => continue,
+                        },
+                        _ => continue,
+                    }
+                };
+
+                let overridden_rows = remove_overridden_loc_rows(&mut entries, &mut keys_loaded_later);
+                if overridden_rows > 0 {
+                    let file_report = report.files.entry(path.to_owned()).or_default();
+                    file_report.overridden_rows = overridden_rows;
+                    file_report.removed = entries.is_empty() && options.remove_empty_files;
+                }
+            }
+        }
+
+        Ok(report)
+    }
 }
 
 impl Optimizable for DB {
 
     /// This function optimizes the provided [DB](rpfm_lib::files::db::DB) file in order to make it smaller and more compatible.
     ///
-    /// Specifically, it performs the following optimizations:
+    /// Specifically, it performs the following optimizations, if enabled in the provided [OptimizerOptions]:
     ///
     /// - Removal of duplicated entries.
     /// - Removal of ITM (Identical To Master) entries.
     /// - Removal of ITNR (Identical To New Row) entries.
     ///
     /// It returns if the DB is empty, meaning it can be safetly deleted.
-    fn optimize(&mut self, dependencies: &mut Dependencies) -> bool {
-        match self.data(&None) {
-            Ok(entries) => {
-
-                // Get a manipulable copy of all the entries, so we can optimize it.
-                let mut entries = entries.to_vec();
-                let definition = self.definition();
-                let first_key = definition.fields_processed_sorted(true).iter().position(|x| x.is_key()).unwrap_or(0);
-
-                match dependencies.db_data(self.table_name(), true, true) {
-                    Ok(mut vanilla_tables) => {
-
-                        // First, merge all vanilla and parent db fragments into a single HashSet.
-                        let vanilla_table = vanilla_tables.iter_mut()
-                            .filter_map(|file| {
-                                if let Ok(RFileDecoded::DB(table)) = file.decoded() {
-                                    table.data(&None).ok().map(|x| x.to_vec())
-                                } else { None }
-                            })
-                            .flatten()
-                            .map(|x| {
-
-                                // We map all floats here to string representations of floats, so we can actually compare them reliably.
-                                let json = x.iter().map(|data|
-                                    if let DecodedData::F32(value) = data {
-                                        DecodedData::StringU8(format!("{:.4}", value))
-                                    } else {
-                                        data.to_owned()
-                                    }
-                                ).collect::<Vec<DecodedData>>();
-                                serde_json::to_string(&json).unwrap()
-                            })
-                            .collect::<HashSet<String>>();
-
-                        // Remove ITM and ITNR entries.
-                        let new_row = self.new_row().iter().map(|data|
-                            if let DecodedData::F32(value) = data {
-                                DecodedData::StringU8(format!("{:.4}", value))
-                            } else {
-                                data.to_owned()
-                            }
-                        ).collect::<Vec<DecodedData>>();
-
-                        entries.retain(|entry| {
-                            let entry_json = entry.iter().map(|data|
-                                if let DecodedData::F32(value) = data {
-                                    DecodedData::StringU8(format!("{:.4}", value))
-                                } else {
-                                    data.to_owned()
-                                }
-                            ).collect::<Vec<DecodedData>>();
-                            !vanilla_table.contains(&serde_json::to_string(&entry_json).unwrap()) && entry != &new_row
-                        });
-
-                        // Sort the table so it can be dedup. Sorting floats is a pain in the ass.
-                        entries.par_sort_by(|a, b| {
-                            let ordering = if let DecodedData::F32(x) = a[first_key] {
-                                if let DecodedData::F32(y) = b[first_key] {
-                                    if float_eq::float_eq!(x, y, abs <= 0.0001) {
-                                        Some(Ordering::Equal)
-                                    } else { None }
-                                } else { None }
-                            } else { None };
-
-                            match ordering {
-                                Some(ordering) => ordering,
-                                None => a[first_key].data_to_string().partial_cmp(&b[first_key].data_to_string()).unwrap_or(Ordering::Equal)
-                            }
-                        });
-
-                        entries.dedup();
-
-                        // Then we overwrite the entries and return if the table is empty or now, so we can optimize it further at the Container level.
-                        //
-                        // NOTE: This may fail, but in that case the table will not be left empty, which we check in the next line.
-                        let _ = self.set_data(None, &entries);
-                        self.data(&None).unwrap().is_empty()
-                    }
-                    Err(_) => false,
-                }
+    fn optimize(&mut self, dependencies: &mut Dependencies, options: &OptimizerOptions) -> bool {
+        match db_optimized_rows(self, dependencies, options) {
+            Some((entries, _)) => {
+
+                // Then we overwrite the entries and return if the table is empty or now, so we can optimize it further at the Container level.
+                //
+                // NOTE: This may fail, but in that case the table will not be left empty, which we check in the next line.
+                let _ = self.set_data(None, &entries);
+                self.data(&None).unwrap().is_empty()
             }
-
-            // We don't optimize sql-backed data.
-            Err(_) => false,
+            None => false,
         }
     }
 }
@@ -252,63 +348,213 @@ impl Optimizable for Loc {
 
     /// This function optimizes the provided [Loc](rpfm_lib::files::loc::Loc) file in order to make it smaller and more compatible.
     ///
-    /// Specifically, it performs the following optimizations:
+    /// Specifically, it performs the following optimizations, if enabled in the provided [OptimizerOptions]:
     ///
     /// - Removal of duplicated entries.
     /// - Removal of ITM (Identical To Master) entries.
     /// - Removal of ITNR (Identical To New Row) entries.
     ///
     /// It returns if the Loc is empty, meaning it can be safetly deleted.
-    fn optimize(&mut self, dependencies: &mut Dependencies) -> bool {
-        match self.data(&None) {
-            Ok(entries) => {
-
-                // Get a manipulable copy of all the entries, so we can optimize it.
-                let mut entries = entries.to_vec();
-                match dependencies.loc_data(true, true) {
-                    Ok(mut vanilla_tables) => {
-
-                        // First, merge all vanilla and parent locs into a single HashMap<key, value>. We don't care about the third column.
-                        let vanilla_table = vanilla_tables.iter_mut()
-                            .filter_map(|file| {
-                                if let Ok(RFileDecoded::Loc(table)) = file.decoded() {
-                                    table.data(&None).ok().map(|x| x.to_vec())
-                                } else { None }
-                            })
-                            .flat_map(|data| data.iter()
-                                .map(|data| (data[0].data_to_string().to_string(), data[1].data_to_string().to_string()))
-                                .collect::<Vec<(String, String)>>())
-                            .collect::<HashMap<String, String>>();
-
-                        // Remove ITM and ITNR entries.
-                        let new_row = self.new_row();
-                        entries.retain(|entry| {
-                            if entry == &new_row {
-                                return false;
-                            }
+    fn optimize(&mut self, dependencies: &mut Dependencies, options: &OptimizerOptions) -> bool {
+        match loc_optimized_rows(self, dependencies, options) {
+            Some((entries, _)) => {
+
+                // Then we overwrite the entries and return if the table is empty or now, so we can optimize it further at the Container level.
+                //
+                // NOTE: This may fail, but in that case the table will not be left empty, which we check in the next line.
+                let _ = self.set_data(&entries);
+                self.data(&None).unwrap().is_empty()
+            }
+            None => false,
+        }
+    }
+}
 
-                            match vanilla_table.get(&*entry[0].data_to_string()) {
-                                Some(vanilla_value) => &*entry[1].data_to_string() != vanilla_value,
-                                None => true
-                            }
-                        });
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
 
-                        // Sort the table so it can be dedup.
-                        entries.par_sort_by(|a, b| a[0].data_to_string().partial_cmp(&b[0].data_to_string()).unwrap_or(Ordering::Equal));
-                        entries.dedup();
+impl OptimizerFileReport {
 
-                        // Then we overwrite the entries and return if the table is empty or now, so we can optimize it further at the Container level.
-                        //
-                        // NOTE: This may fail, but in that case the table will not be left empty, which we check in the next line.
-                        let _ = self.set_data(&entries);
-                        self.data(&None).unwrap().is_empty()
-                    }
-                    Err(_) => false,
-                }
-            }
+    /// This function returns the total amount of rows removed from the file.
+    pub fn removed_rows(&self) -> usize {
+        self.duplicated_rows + self.itm_rows + self.itnr_rows + self.overridden_rows
+    }
+}
+
+/// This function returns the paths of the locs of the provided Pack not marked for deletion, in load order.
+///
+/// Locs load in alphabetical order, and the last one loaded wins.
+fn loc_paths_by_load_order(pack: &Pack, files_to_delete: &HashSet<String>) -> Vec<String> {
+    let mut loc_paths = pack.files_by_type(&[FileType::Loc])
+        .iter()
+        .map(|file| file.path_in_container_raw().to_owned())
+        .filter(|path| !files_to_delete.contains(path))
+        .collect::<Vec<_>>();
+
+    loc_paths.sort();
+    loc_paths
+}
+
+/// This function returns if the provided file is a DB/Loc file the optimizer should try to optimize.
+///
+/// Unless we specifically wanted to, ignore the same-name-as-vanilla-or-parent files,
+/// as those are probably intended to overwrite vanilla files, not to be optimized.
+fn is_optimizable(rfile: &RFile, path: &str, dependencies: &Dependencies, optimize_datacored_tables: bool) -> bool {
+    matches!(rfile.file_type(), FileType::DB | FileType::Loc) &&
+        (optimize_datacored_tables || !dependencies.file_exists(path, true, true, true))
+}
+
+/// This function returns the rows the provided decoded DB/Loc file is left with after optimizing it, along with what got removed from it.
+///
+/// It returns None if the file cannot be optimized.
+fn optimized_rows(decoded: &RFileDecoded, dependencies: &Dependencies, options: &OptimizerOptions) -> Option<(Vec<Vec<DecodedData>>, OptimizerFileReport)> {
+    let (entries, mut file_report) = match decoded {
+        RFileDecoded::DB(db) => db_optimized_rows(db, dependencies, options)?,
+        RFileDecoded::Loc(loc) => loc_optimized_rows(loc, dependencies, options)?,
+        _ => return None,
+    };
+
+    file_report.removed = entries.is_empty() && options.remove_empty_files;
+    Some((entries, file_report))
+}
+
+/// This function replaces the rows of the provided decoded DB/Loc file.
+fn set_rows(decoded: &mut RFileDecoded, entries: &[Vec<DecodedData>]) -> Result<()> {
+    match decoded {
+        RFileDecoded::DB(db) => db.set_data(None, entries),
+        RFileDecoded::Loc(loc) => loc.set_data(entries),
+        _ => Ok(()),
+    }
+}
+
+/// This function returns the rows the provided DB is left with after optimizing it, along with what got removed from it.
+///
+/// It returns None for sql-backed tables, or if we couldn't get the vanilla/parent data of the table.
+fn db_optimized_rows(db: &DB, dependencies: &Dependencies, options: &OptimizerOptions) -> Option<(Vec<Vec<DecodedData>>, OptimizerFileReport)> {
+
+    // We don't optimize sql-backed data.
+    let entries = db.data(&None).ok()?.to_vec();
+    let vanilla_tables = dependencies.db_data(db.table_name(), true, true).ok()?;
+
+    // First, merge all vanilla and parent db fragments into a single HashSet.
+    let vanilla_table = vanilla_tables.iter()
+        .filter_map(|file| {
+            if let Ok(RFileDecoded::DB(table)) = file.decoded() {
+                table.data(&None).ok().map(|x| x.to_vec())
+            } else { None }
+        })
+        .flatten()
+        .map(|row| itm_comparable_row(&row))
+        .collect::<HashSet<String>>();
+
+    let definition = db.definition();
+    let first_key = definition.fields_processed_sorted(true).iter().position(|x| x.is_key()).unwrap_or(0);
+
+    // The new row gets its floats mapped like the vanilla rows.
+    let new_row = db.new_row().iter().map(itm_comparable_cell).collect::<Vec<DecodedData>>();
+
+    // Sorting floats is a pain in the ass.
+    Some(remove_rows(entries, &new_row, options, |entry| vanilla_table.contains(&itm_comparable_row(entry)), |a, b| {
+        let ordering = if let DecodedData::F32(x) = a[first_key] {
+            if let DecodedData::F32(y) = b[first_key] {
+                if float_eq::float_eq!(x, y, abs <= 0.0001) {
+                    Some(Ordering::Equal)
+                } else { None }
+            } else { None }
+        } else { None };
+
+        match ordering {
+            Some(ordering) => ordering,
+            None => a[first_key].data_to_string().partial_cmp(&b[first_key].data_to_string()).unwrap_or(Ordering::Equal)
+        }
+    }))
+}
+
+/// This function returns the rows the provided Loc is left with after optimizing it, along with what got removed from it.
+///
+/// It returns None for sql-backed tables, or if we couldn't get the vanilla/parent locs.
+fn loc_optimized_rows(loc: &Loc, dependencies: &Dependencies, options: &OptimizerOptions) -> Option<(Vec<Vec<DecodedData>>, OptimizerFileReport)> {
+
+    // We don't optimize sql-backed data.
+    let entries = loc.data(&None).ok()?.to_vec();
+    let vanilla_tables = dependencies.loc_data(true, true).ok()?;
+
+    // First, merge all vanilla and parent locs into a single HashMap<key, value>. We don't care about the third column.
+    let vanilla_table = vanilla_tables.iter()
+        .filter_map(|file| {
+            if let Ok(RFileDecoded::Loc(table)) = file.decoded() {
+                table.data(&None).ok().map(|x| x.to_vec())
+            } else { None }
+        })
+        .flat_map(|data| data.iter()
+            .map(|data| (data[0].data_to_string().to_string(), data[1].data_to_string().to_string()))
+            .collect::<Vec<(String, String)>>())
+        .collect::<HashMap<String, String>>();
+
+    Some(remove_rows(entries, &loc.new_row(), options, |entry| match vanilla_table.get(&*entry[0].data_to_string()) {
+        Some(vanilla_value) => &*entry[1].data_to_string() == vanilla_value,
+        None => false
+    }, |a, b| a[0].data_to_string().partial_cmp(&b[0].data_to_string()).unwrap_or(Ordering::Equal)))
+}
 
-            // We don't optimize sql-backed data.
-            Err(_) => false,
+/// This function removes the ITM, ITNR and duplicated rows from the provided rows, according to the provided options.
+///
+/// It returns the rows left, along with the amount of rows removed by each optimization.
+fn remove_rows<I, S>(mut entries: Vec<Vec<DecodedData>>, new_row: &[DecodedData], options: &OptimizerOptions, is_itm: I, sort: S) -> (Vec<Vec<DecodedData>>, OptimizerFileReport)
+where
+    I: Fn(&[DecodedData]) -> bool,
+    S: Fn(&Vec<DecodedData>, &Vec<DecodedData>) -> Ordering + Sync,
+{
+    let mut file_report = OptimizerFileReport::default();
+
+    // Remove ITNR and ITM entries.
+    entries.retain(|entry| {
+        if options.remove_itnr && entry == new_row {
+            file_report.itnr_rows += 1;
+            false
+        } else if options.remove_itm && is_itm(entry) {
+            file_report.itm_rows += 1;
+            false
+        } else {
+            true
         }
+    });
+
+    // Sort the table so it can be dedup.
+    if options.remove_duplicates {
+        let len = entries.len();
+        entries.par_sort_by(sort);
+        entries.dedup();
+        file_report.duplicated_rows = len - entries.len();
+    }
+
+    (entries, file_report)
+}
+
+/// This function removes from the provided Loc rows the ones whose key is loaded later, then marks the keys of the rows left as loaded.
+///
+/// It returns the amount of rows removed.
+fn remove_overridden_loc_rows(entries: &mut Vec<Vec<DecodedData>>, keys_loaded_later: &mut HashSet<String>) -> usize {
+    let len = entries.len();
+    entries.retain(|entry| !keys_loaded_later.contains(&*entry[0].data_to_string()));
+    keys_loaded_later.extend(entries.iter().map(|entry| entry[0].data_to_string().to_string()));
+    len - entries.len()
+}
+
+/// This function returns a representation of the provided row we can reliably compare against vanilla rows.
+pub(crate) fn itm_comparable_row(row: &[DecodedData]) -> String {
+    let json = row.iter().map(itm_comparable_cell).collect::<Vec<DecodedData>>();
+    serde_json::to_string(&json).unwrap()
+}
+
+/// This function returns a representation of the provided cell we can reliably compare against vanilla cells.
+///
+/// We map all floats here to string representations of floats, so we can actually compare them reliably.
+pub(crate) fn itm_comparable_cell(data: &DecodedData) -> DecodedData {
+    if let DecodedData::F32(value) = data {
+        DecodedData::StringU8(format!("{:.4}", value))
+    } else {
+        data.to_owned()
     }
 }
@@ -0,0 +1,265 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the optimizer.
+
+use rpfm_lib::games::pfh_version::PFHVersion;
+
+use crate::test_helpers::{loc_file, units_schema};
+
+use super::*;
+
+fn db_file(schema: &Schema, path: &str, rows: &[(&str, i32)]) -> RFile {
+    let definition = schema.definitions_by_table_name("units_tables").unwrap()[0].clone();
+    let mut table = DB::new(&definition, None, "units_tables", false);
+    let rows = rows.iter().map(|(key, value)| vec![DecodedData::StringU8(key.to_string()), DecodedData::I32(*value)]).collect::<Vec<_>>();
+    table.set_data(None, &rows).unwrap();
+    RFile::new_from_decoded(&RFileDecoded::DB(table), 0, path)
+}
+
+/// This function creates a Dependencies cache with the provided files as vanilla files.
+///
+/// The cache has no public way to be built without the game files, so we build it through its serialized form.
+fn dependencies(schema: &Schema, vanilla_files: Vec<RFile>) -> Dependencies {
+    let mut dependencies = serde_json::to_value(Dependencies::default()).unwrap();
+    for file in &vanilla_files {
+        let path = file.path_in_container_raw().to_owned();
+        match file.file_type() {
+            FileType::DB => dependencies["vanilla_tables"]["units_tables"] = serde_json::json!([path]),
+            FileType::Loc => dependencies["vanilla_locs"] = serde_json::json!([path]),
+            _ => {}
+        }
+
+        dependencies["vanilla_files"][path] = serde_json::to_value(file).unwrap();
+    }
+
+    // The optimizer requires the assembly kit data to be loaded.
+    let definition = schema.definitions_by_table_name("units_tables").unwrap()[0].clone();
+    dependencies["asskit_only_db_tables"]["units_tables"] = serde_json::to_value(DB::new(&definition, None, "units_tables", false)).unwrap();
+
+    serde_json::from_value(dependencies).unwrap()
+}
+
+#[test]
+fn test_optimize_report() {
+    let schema = units_schema();
+    let mut dependencies = dependencies(&schema, vec![
+        db_file(&schema, "db/units_tables/data__", &[("vanilla_a", 1), ("vanilla_b", 2)]),
+        loc_file("text/db/vanilla.loc", &[("loc_vanilla", "Vanilla text")]),
+    ]);
+
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    let files = vec![
+
+        // One ITM row, one ITNR row and one duplicated row.
+        db_file(&schema, "db/units_tables/mod", &[("vanilla_a", 1), ("mod_a", 5), ("mod_a", 5), ("", 0), ("vanilla_b", 3)]),
+
+        // Only ITM rows, so it gets removed.
+        db_file(&schema, "db/units_tables/itm_only", &[("vanilla_b", 2)]),
+
+        // Same name as the vanilla table, so it's not optimized.
+        db_file(&schema, "db/units_tables/data__", &[("vanilla_a", 1)]),
+
+        // Nothing to optimize.
+        db_file(&schema, "db/units_tables/clean", &[("mod_b", 1)]),
+
+        // Loaded first, so the shared key is overridden. Once overridden, it's left empty.
+        loc_file("text/db/0.loc", &[("shared", "0")]),
+
+        // One ITM row, and one row overridden by b.loc.
+        loc_file("text/db/a.loc", &[("loc_vanilla", "Vanilla text"), ("shared", "A"), ("a_only", "A")]),
+
+        // One duplicated row.
+        loc_file("text/db/b.loc", &[("shared", "B"), ("b_only", "B"), ("b_only", "B")]),
+    ];
+
+    for file in files {
+        pack.insert(file).unwrap();
+    }
+
+    let mut xml = RFile::new_from_vec(b"<root/>", FileType::Unknown, 0, "prefabs/test_prefab.xml");
+    xml.guess_file_type().unwrap();
+    pack.insert(xml).unwrap();
+
+    let options = OptimizerOptions::default();
+    let report = pack.optimize_report(&dependencies, &schema, false, &options).unwrap();
+    let files = report.files();
+    assert_eq!(files.keys().collect::<Vec<_>>(), vec![
+        "db/units_tables/itm_only",
+        "db/units_tables/mod",
+        "prefabs/test_prefab.xml",
+        "text/db/0.loc",
+        "text/db/a.loc",
+        "text/db/b.loc",
+    ]);
+
+    assert_eq!(files["db/units_tables/mod"], OptimizerFileReport { duplicated_rows: 1, itm_rows: 1, itnr_rows: 1, overridden_rows: 0, removed: false });
+    assert_eq!(files["db/units_tables/itm_only"], OptimizerFileReport { itm_rows: 1, removed: true, ..Default::default() });
+    assert_eq!(files["prefabs/test_prefab.xml"], OptimizerFileReport { removed: true, ..Default::default() });
+    assert_eq!(files["text/db/0.loc"], OptimizerFileReport { overridden_rows: 1, removed: true, ..Default::default() });
+    assert_eq!(files["text/db/a.loc"], OptimizerFileReport { itm_rows: 1, overridden_rows: 1, ..Default::default() });
+    assert_eq!(files["text/db/b.loc"], OptimizerFileReport { duplicated_rows: 1, ..Default::default() });
+
+    // The report must not change the Pack.
+    let rows_before = pack.files().iter().map(|(path, file)| (path.to_owned(), row_count(file))).collect::<HashMap<_, _>>();
+    assert_eq!(rows_before["db/units_tables/mod"], 5);
+
+    // The real optimization must do exactly what the report said.
+    let deleted = pack.optimize(&mut dependencies, &schema, false, &options, &|_, _, _| {}, &AtomicBool::new(false)).unwrap();
+    let mut deleted = deleted.into_iter().collect::<Vec<_>>();
+    deleted.sort();
+    assert_eq!(deleted, files.iter().filter(|(_, file)| *file.removed()).map(|(path, _)| path.to_owned()).collect::<Vec<_>>());
+
+    for (path, rows) in &rows_before {
+        let expected = match files.get(path) {
+            Some(file_report) if *file_report.removed() => continue,
+            Some(file_report) => rows - file_report.removed_rows(),
+            None => *rows,
+        };
+
+        assert_eq!(row_count(pack.files().get(path).unwrap()), expected, "{}", path);
+    }
+}
+
+#[test]
+fn test_optimize_sorts_tables() {
+    let schema = units_schema();
+    let mut dependencies = dependencies(&schema, vec![db_file(&schema, "db/units_tables/data__", &[("vanilla_a", 1)])]);
+
+    // Nothing to remove, but the table still gets sorted by its first key.
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    pack.insert(db_file(&schema, "db/units_tables/clean", &[("mod_c", 1), ("mod_a", 2), ("mod_b", 3)])).unwrap();
+
+    let options = OptimizerOptions::default();
+    let deleted = pack.optimize(&mut dependencies, &schema, false, &options, &|_, _, _| {}, &AtomicBool::new(false)).unwrap();
+    assert!(deleted.is_empty());
+
+    match pack.files().get("db/units_tables/clean").unwrap().decoded().unwrap() {
+        RFileDecoded::DB(table) => {
+            let keys = table.data(&None).unwrap().iter().map(|row| row[0].data_to_string().to_string()).collect::<Vec<_>>();
+            assert_eq!(keys, vec!["mod_a", "mod_b", "mod_c"]);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_optimize_cancelled() {
+    let schema = units_schema();
+    let mut dependencies = dependencies(&schema, vec![db_file(&schema, "db/units_tables/data__", &[("vanilla_a", 1)])]);
+
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    pack.insert(db_file(&schema, "db/units_tables/itm_only", &[("vanilla_a", 1)])).unwrap();
+    pack.insert(db_file(&schema, "db/units_tables/clean", &[("mod_a", 1)])).unwrap();
+
+    // Once cancelled, no more files are processed, and none are removed.
+    let processed = std::sync::atomic::AtomicU32::new(0);
+    let progress = |_, _, _: &str| { processed.fetch_add(1, atomic::Ordering::Relaxed); };
+    let result = pack.optimize(&mut dependencies, &schema, false, &OptimizerOptions::default(), &progress, &AtomicBool::new(true));
+    assert!(matches!(result, Err(RLibError::OperationCancelled)));
+    assert_eq!(processed.load(atomic::Ordering::Relaxed), 0);
+    assert_eq!(pack.files().len(), 2);
+}
+
+#[test]
+fn test_optimize_map_xml() {
+    let schema = units_schema();
+    let mut dependencies = dependencies(&schema, vec![db_file(&schema, "db/units_tables/data__", &[("vanilla_a", 1)])]);
+    let paths = [
+        "terrain/tiles/battle/_assembly_kit/test_map/test_map.xml",
+        "prefabs/test_prefab.xml",
+        "ui/templates/test.xml",
+    ];
+
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    for path in paths {
+        let mut file = RFile::new_from_vec(b"<root/>", FileType::Unknown, 0, path);
+        file.guess_file_type().unwrap();
+        pack.insert(file).unwrap();
+    }
+
+    // With the option disabled, the map xml files are left alone.
+    let mut options = OptimizerOptions::default();
+    options.set_remove_map_xml(false);
+    let deleted = pack.optimize(&mut dependencies, &schema, false, &options, &|_, _, _| {}, &AtomicBool::new(false)).unwrap();
+    assert!(deleted.is_empty());
+    assert_eq!(pack.files().len(), 3);
+
+    // Otherwise, only the xml files in map folders are removed.
+    let options = OptimizerOptions::default();
+    let deleted = pack.optimize(&mut dependencies, &schema, false, &options, &|_, _, _| {}, &AtomicBool::new(false)).unwrap();
+    let mut deleted = deleted.into_iter().collect::<Vec<_>>();
+    deleted.sort();
+    assert_eq!(deleted, vec![paths[1], paths[0]]);
+    assert_eq!(pack.files().keys().collect::<Vec<_>>(), vec![paths[2]]);
+}
+
+#[test]
+fn test_optimize_overridden_loc_entries() {
+    let schema = units_schema();
+    let mut dependencies = dependencies(&schema, vec![loc_file("text/db/vanilla.loc", &[("loc_vanilla", "Vanilla text")])]);
+    let locs = || vec![
+
+        // Loaded first, so all its entries are overridden.
+        loc_file("text/db/aaa.loc", &[("shared", "aaa")]),
+        loc_file("text/db/foo.loc", &[("shared", "foo"), ("foo_only", "foo")]),
+
+        // Loaded last, so it wins.
+        loc_file("text/db/zzz_foo.loc", &[("shared", "zzz")]),
+    ];
+
+    // With the option disabled, the locs are left alone.
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    locs().into_iter().for_each(|file| { pack.insert(file).unwrap(); });
+
+    let mut options = OptimizerOptions::default();
+    options.set_remove_overridden_loc_entries(false);
+    let deleted = pack.optimize(&mut dependencies, &schema, false, &options, &|_, _, _| {}, &AtomicBool::new(false)).unwrap();
+    assert!(deleted.is_empty());
+    assert_eq!(row_count(pack.files().get("text/db/aaa.loc").unwrap()), 1);
+    assert_eq!(row_count(pack.files().get("text/db/foo.loc").unwrap()), 2);
+
+    // Otherwise, the overridden entries are removed, and the locs left empty with them.
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    locs().into_iter().for_each(|file| { pack.insert(file).unwrap(); });
+
+    let options = OptimizerOptions::default();
+    let deleted = pack.optimize(&mut dependencies, &schema, false, &options, &|_, _, _| {}, &AtomicBool::new(false)).unwrap();
+    assert_eq!(deleted, HashSet::from(["text/db/aaa.loc".to_owned()]));
+    assert!(pack.files().get("text/db/aaa.loc").is_none());
+
+    let foo = pack.files_mut().get_mut("text/db/foo.loc").unwrap();
+    match foo.decoded().unwrap() {
+        RFileDecoded::Loc(loc) => assert_eq!(loc.data(&None).unwrap()[0][0].data_to_string(), "foo_only"),
+        _ => unreachable!(),
+    }
+
+    assert_eq!(row_count(foo), 1);
+    assert_eq!(row_count(pack.files().get("text/db/zzz_foo.loc").unwrap()), 1);
+
+    // Unless we don't want to remove empty files.
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    locs().into_iter().for_each(|file| { pack.insert(file).unwrap(); });
+
+    let mut options = OptimizerOptions::default();
+    options.set_remove_empty_files(false);
+    let deleted = pack.optimize(&mut dependencies, &schema, false, &options, &|_, _, _| {}, &AtomicBool::new(false)).unwrap();
+    assert!(deleted.is_empty());
+    assert_eq!(row_count(pack.files().get("text/db/aaa.loc").unwrap()), 0);
+}
+
+/// This function returns the amount of rows of the provided DB/Loc file, or 0 for any other file.
+fn row_count(file: &RFile) -> usize {
+    match file.decoded() {
+        Ok(RFileDecoded::DB(table)) => table.data(&None).unwrap().len(),
+        Ok(RFileDecoded::Loc(table)) => table.data(&None).unwrap().len(),
+        _ => 0,
+    }
+}
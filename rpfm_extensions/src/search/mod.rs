@@ -17,7 +17,11 @@ This module contains the code needed to get a `GlobalSearch` over an entire `Pac
 use regex::{RegexBuilder, Regex};
 use rayon::prelude::*;
 
-use rpfm_lib::files::{Container, ContainerPath};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use rpfm_lib::error::{RLibError, Result};
+use rpfm_lib::files::{animpack::AnimPack, Container, ContainerPath, DecodeableExtraData};
 use rpfm_lib::files::{FileType, pack::Pack, RFileDecoded};
 use rpfm_lib::games::{GameInfo, VanillaDBTableNameLogic};
 use rpfm_lib::schema::Schema;
@@ -120,14 +124,22 @@ pub enum MatchHolder {
 }
 
 /// This enum is specifies the source where the search should be performed.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// It's also used to mark the source each match comes from, so we know what matches can be replaced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SearchSource {
     Pack,
     ParentFiles,
     GameFiles,
     AssKitFiles,
+
+    /// All the previous sources together.
+    All,
 }
 
+/// Matches of a search over a single source, for DB, Loc and Text files, in that order.
+type SourceMatches = (Vec<TableMatches>, Vec<TableMatches>, Vec<TextMatches>);
+
 //---------------------------------------------------------------p----------------//
 //                             Implementations
 //-------------------------------------------------------------------------------//
@@ -157,10 +169,12 @@ impl Default for GlobalSearch {
 impl GlobalSearch {
 
     /// This function performs a search over the parts of a `PackFile` you specify it, storing his results.
-    pub fn search(&mut self, game_info: &GameInfo, schema: &Schema, pack: &mut Pack, dependencies: &mut Dependencies, update_paths: &[ContainerPath]) {
+    ///
+    /// If `cancel` is set while searching, the search is stopped as soon as possible and [RLibError::OperationCancelled] is returned.
+    pub fn search(&mut self, game_info: &GameInfo, schema: &Schema, pack: &mut Pack, dependencies: &mut Dependencies, update_paths: &[ContainerPath], cancel: &AtomicBool) -> Result<()> {
 
         // Don't do anything if we have no pattern to search.
-        if self.pattern.is_empty() { return }
+        if self.pattern.is_empty() { return Ok(()) }
 
         // If we want to use regex and the pattern is invalid, don't search.
         let matching_mode = if self.use_regex {
@@ -171,18 +185,21 @@ impl GlobalSearch {
         } else { MatchingMode::Pattern };
 
         // If we're updating, make sure to dedup and get the raw paths of each file to update.
-        let update_paths = if !update_paths.is_empty() && self.source == SearchSource::Pack {
+        let update_paths = if !update_paths.is_empty() && (self.source == SearchSource::Pack || self.source == SearchSource::All) {
             let container_paths = ContainerPath::dedup(update_paths);
             let raw_paths = container_paths.par_iter()
                 .map(|container_path| pack.paths_raw_from_container_path(container_path))
                 .flatten()
-                .collect::<Vec<_>>();
+                .collect::<HashSet<_>>();
 
-            for path in raw_paths {
-                self.matches_db.retain(|x| x.path() != &path);
-                self.matches_loc.retain(|x| x.path() != &path);
-                self.matches_text.retain(|x| x.path() != &path);
-            }
+            // Matches within AnimPacks are removed if the AnimPack has been updated.
+            let is_updated = |source: &SearchSource, path: &String, container_path: &Option<String>| {
+                *source == SearchSource::Pack && (raw_paths.contains(path) || container_path.as_ref().map(|path| raw_paths.contains(path)).unwrap_or(false))
+            };
+
+            self.matches_db.retain(|x| !is_updated(x.source(), x.path(), x.container_path()));
+            self.matches_loc.retain(|x| !is_updated(x.source(), x.path(), x.container_path()));
+            self.matches_text.retain(|x| !is_updated(x.source(), x.path(), x.container_path()));
 
             container_paths
         }
@@ -204,20 +221,59 @@ impl GlobalSearch {
             self.pattern = self.pattern.to_lowercase();
         }
 
-        match self.source {
+        // Updates only affect the open Pack, as the dependencies cannot be edited.
+        let sources = if !update_paths.is_empty() {
+            vec![SearchSource::Pack]
+        } else if self.source == SearchSource::All {
+            vec![SearchSource::Pack, SearchSource::ParentFiles, SearchSource::GameFiles, SearchSource::AssKitFiles]
+        } else {
+            vec![self.source]
+        };
+
+        for source in sources {
+            let (matches_db, matches_loc, matches_text) = self.search_source(source, game_info, schema, pack, dependencies, &update_paths, &matching_mode, cancel);
+            if cancel.load(Ordering::Relaxed) {
+                self.pattern = pattern;
+                return Err(RLibError::OperationCancelled);
+            }
+
+            self.matches_db.extend(matches_db);
+            self.matches_loc.extend(matches_loc);
+            self.matches_text.extend(matches_text);
+        }
+
+        // Schema searches are a bit independant from the rest, so they're done after the full search.
+        if self.search_on_schema {
+            self.matches_schema = schema.search("", &self.pattern, self.case_sensitive, &matching_mode);
+        }
+
+        self.pattern = pattern;
+        Ok(())
+    }
+
+    /// This function performs the search over a single source, returning the matches for DB, Loc and Text files, in that order.
+    #[allow(clippy::too_many_arguments)]
+    fn search_source(&self, source: SearchSource, game_info: &GameInfo, schema: &Schema, pack: &mut Pack, dependencies: &mut Dependencies, update_paths: &[ContainerPath], matching_mode: &MatchingMode, cancel: &AtomicBool) -> SourceMatches {
+        let (mut matches_db, mut matches_loc, mut matches_text) = (vec![], vec![], vec![]);
+
+        match source {
             SearchSource::Pack => {
 
                 if self.search_on_dbs {
                     let files = if !update_paths.is_empty() {
-                        pack.files_by_type_and_paths(&[FileType::DB], &update_paths, false)
+                        pack.files_by_type_and_paths(&[FileType::DB], update_paths, false)
                     } else {
                         pack.files_by_type(&[FileType::DB])
                     };
 
-                    self.matches_db = files.par_iter()
+                    matches_db = files.par_iter()
                         .filter_map(|file| {
+                            if cancel.load(Ordering::Relaxed) {
+                                return None;
+                            }
+
                             if let Ok(RFileDecoded::DB(table)) = file.decoded() {
-                                let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, &matching_mode);
+                                let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, matching_mode);
                                 if !result.matches().is_empty() {
                                     Some(result)
                                 } else {
@@ -232,15 +288,19 @@ impl GlobalSearch {
 
                 if self.search_on_locs {
                     let files = if !update_paths.is_empty() {
-                        pack.files_by_type_and_paths(&[FileType::Loc], &update_paths, false)
+                        pack.files_by_type_and_paths(&[FileType::Loc], update_paths, false)
                     } else {
                         pack.files_by_type(&[FileType::Loc])
                     };
 
-                    self.matches_loc = files.par_iter()
+                    matches_loc = files.par_iter()
                         .filter_map(|file| {
+                            if cancel.load(Ordering::Relaxed) {
+                                return None;
+                            }
+
                             if let Ok(RFileDecoded::Loc(table)) = file.decoded() {
-                                let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, &matching_mode);
+                                let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, matching_mode);
                                 if !result.matches().is_empty() {
                                     Some(result)
                                 } else {
@@ -255,15 +315,19 @@ impl GlobalSearch {
 
                 if self.search_on_texts {
                     let mut files = if !update_paths.is_empty() {
-                        pack.files_by_type_and_paths_mut(&[FileType::Text], &update_paths, false)
+                        pack.files_by_type_and_paths_mut(&[FileType::Text], update_paths, false)
                     } else {
                         pack.files_by_type_mut(&[FileType::Text])
                     };
 
-                    self.matches_text = files.par_iter_mut()
+                    matches_text = files.par_iter_mut()
                         .filter_map(|file| {
+                            if cancel.load(Ordering::Relaxed) {
+                                return None;
+                            }
+
                             if let Ok(RFileDecoded::Text(table)) = file.decode(&None, false, true).transpose().unwrap() {
-                                let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, &matching_mode);
+                                let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, matching_mode);
                                 if !result.matches().is_empty() {
                                     Some(result)
                                 } else {
@@ -275,15 +339,47 @@ impl GlobalSearch {
                         }
                     ).collect();
                 }
+
+                // Files within AnimPacks are searched too, using their path within the AnimPack prepended with the AnimPack's path.
+                let mut files = if !update_paths.is_empty() {
+                    pack.files_by_type_and_paths_mut(&[FileType::AnimPack], update_paths, false)
+                } else {
+                    pack.files_by_type_mut(&[FileType::AnimPack])
+                };
+
+                let animpack_matches = files.par_iter_mut()
+                    .filter_map(|file| {
+                        if cancel.load(Ordering::Relaxed) {
+                            return None;
+                        }
+
+                        let path = file.path_in_container_raw().to_owned();
+                        if let Ok(Some(RFileDecoded::AnimPack(mut animpack))) = file.decode(&None, false, true) {
+                            Some(self.search_animpack(&mut animpack, &path, schema, matching_mode))
+                        } else {
+                            None
+                        }
+                    }
+                ).collect::<Vec<_>>();
+
+                for (animpack_matches_db, animpack_matches_loc, animpack_matches_text) in animpack_matches {
+                    matches_db.extend(animpack_matches_db);
+                    matches_loc.extend(animpack_matches_loc);
+                    matches_text.extend(animpack_matches_text);
+                }
             }
             SearchSource::ParentFiles => {
 
                 if self.search_on_dbs {
                     if let Ok(files) = dependencies.db_and_loc_data(true, false, false, true) {
-                        self.matches_db = files.par_iter()
+                        matches_db = files.par_iter()
                             .filter_map(|file| {
+                                if cancel.load(Ordering::Relaxed) {
+                                    return None;
+                                }
+
                                 if let Ok(RFileDecoded::DB(table)) = file.decoded() {
-                                    let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, &matching_mode);
+                                    let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, matching_mode);
                                     if !result.matches().is_empty() {
                                         Some(result)
                                     } else {
@@ -299,10 +395,14 @@ impl GlobalSearch {
 
                 if self.search_on_locs {
                     if let Ok(files) = dependencies.db_and_loc_data(false, true, false, true) {
-                        self.matches_loc = files.par_iter()
+                        matches_loc = files.par_iter()
                             .filter_map(|file| {
+                                if cancel.load(Ordering::Relaxed) {
+                                    return None;
+                                }
+
                                 if let Ok(RFileDecoded::Loc(table)) = file.decoded() {
-                                    let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, &matching_mode);
+                                    let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, matching_mode);
                                     if !result.matches().is_empty() {
                                         Some(result)
                                     } else {
@@ -318,10 +418,14 @@ impl GlobalSearch {
 
                 if self.search_on_texts {
                     let mut files = dependencies.files_by_types_mut(&[FileType::Text], false, true);
-                    self.matches_text = files.par_iter_mut()
+                    matches_text = files.par_iter_mut()
                         .filter_map(|(path, file)| {
+                            if cancel.load(Ordering::Relaxed) {
+                                return None;
+                            }
+
                             if let Ok(RFileDecoded::Text(text)) = file.decode(&None, false, true).transpose().unwrap() {
-                                let result = text.search(path, &self.pattern, self.case_sensitive, &matching_mode);
+                                let result = text.search(path, &self.pattern, self.case_sensitive, matching_mode);
                                 if !result.matches().is_empty() {
                                     Some(result)
                                 } else {
@@ -338,10 +442,14 @@ impl GlobalSearch {
 
                 if self.search_on_dbs {
                     if let Ok(files) = dependencies.db_and_loc_data(true, false, true, false) {
-                        self.matches_db = files.par_iter()
+                        matches_db = files.par_iter()
                             .filter_map(|file| {
+                                if cancel.load(Ordering::Relaxed) {
+                                    return None;
+                                }
+
                                 if let Ok(RFileDecoded::DB(table)) = file.decoded() {
-                                    let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, &matching_mode);
+                                    let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, matching_mode);
                                     if !result.matches().is_empty() {
                                         Some(result)
                                     } else {
@@ -357,10 +465,14 @@ impl GlobalSearch {
 
                 if self.search_on_locs {
                     if let Ok(files) = dependencies.db_and_loc_data(false, true, true, false) {
-                        self.matches_loc = files.par_iter()
+                        matches_loc = files.par_iter()
                             .filter_map(|file| {
+                                if cancel.load(Ordering::Relaxed) {
+                                    return None;
+                                }
+
                                 if let Ok(RFileDecoded::Loc(table)) = file.decoded() {
-                                    let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, &matching_mode);
+                                    let result = table.search(file.path_in_container_raw(), &self.pattern, self.case_sensitive, matching_mode);
                                     if !result.matches().is_empty() {
                                         Some(result)
                                     } else {
@@ -376,10 +488,14 @@ impl GlobalSearch {
 
                 if self.search_on_texts {
                     let mut files = dependencies.files_by_types_mut(&[FileType::Text], true, false);
-                    self.matches_text = files.par_iter_mut()
+                    matches_text = files.par_iter_mut()
                         .filter_map(|(path, file)| {
+                            if cancel.load(Ordering::Relaxed) {
+                                return None;
+                            }
+
                             if let Ok(RFileDecoded::Text(text)) = file.decode(&None, false, true).transpose().unwrap() {
-                                let result = text.search(path, &self.pattern, self.case_sensitive, &matching_mode);
+                                let result = text.search(path, &self.pattern, self.case_sensitive, matching_mode);
                                 if !result.matches().is_empty() {
                                     Some(result)
                                 } else {
@@ -396,16 +512,20 @@ impl GlobalSearch {
             // Asskit files are only tables.
             SearchSource::AssKitFiles => {
                 if self.search_on_dbs {
-                    self.matches_db = dependencies.asskit_only_db_tables()
+                    matches_db = dependencies.asskit_only_db_tables()
                         .par_iter()
                         .filter_map(|(table_name, table)| {
+                            if cancel.load(Ordering::Relaxed) {
+                                return None;
+                            }
+
                             let file_name = match game_info.vanilla_db_table_name_logic() {
                                 VanillaDBTableNameLogic::FolderName => table_name.to_owned(),
                                 VanillaDBTableNameLogic::DefaultName(ref default_name) => default_name.to_owned()
                             };
 
                             let path = format!("db/{}/{}", table_name, file_name);
-                            let result = table.search(&path, &self.pattern, self.case_sensitive, &matching_mode);
+                            let result = table.search(&path, &self.pattern, self.case_sensitive, matching_mode);
                             if !result.matches().is_empty() {
                                 Some(result)
                             } else {
@@ -415,14 +535,67 @@ impl GlobalSearch {
                     ).collect();
                 }
             },
+
+            // All is a combination of the rest of the sources, so it's never passed here.
+            SearchSource::All => unreachable!(),
         }
 
-        // Schema searches are a bit independant from the rest, so they're done after the full search.
-        if self.search_on_schema {
-            self.matches_schema = schema.search("", &self.pattern, self.case_sensitive, &matching_mode);
+        // Tag the matches with the source they come from, so they can be opened from the right place.
+        matches_db.iter_mut().for_each(|matches| *matches.source_mut() = source);
+        matches_loc.iter_mut().for_each(|matches| *matches.source_mut() = source);
+        matches_text.iter_mut().for_each(|matches| *matches.source_mut() = source);
+
+        (matches_db, matches_loc, matches_text)
+    }
+
+    /// This function searches the files within an AnimPack, returning the matches for DB, Loc and Text files, in that order.
+    ///
+    /// The paths of the matches are composed by the path of the AnimPack followed by the path of the file within it.
+    fn search_animpack(&self, animpack: &mut AnimPack, animpack_path: &str, schema: &Schema, matching_mode: &MatchingMode) -> SourceMatches {
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.set_schema(Some(schema));
+        let extra_data = Some(extra_data);
+
+        let (mut matches_db, mut matches_loc, mut matches_text) = (vec![], vec![], vec![]);
+        let matches = animpack.files_mut()
+            .par_iter_mut()
+            .filter_map(|(path, file)| {
+                let _ = file.guess_file_type();
+                let file_type = file.file_type();
+                if (file_type == FileType::DB && !self.search_on_dbs) ||
+                    (file_type == FileType::Loc && !self.search_on_locs) ||
+                    (file_type == FileType::Text && !self.search_on_texts) {
+                    return None;
+                }
+
+                let path = format!("{}/{}", animpack_path, path);
+                match file.decode(&extra_data, false, true) {
+                    Ok(Some(RFileDecoded::DB(table))) => Some((Some(table.search(&path, &self.pattern, self.case_sensitive, matching_mode)), None, None)),
+                    Ok(Some(RFileDecoded::Loc(table))) => Some((None, Some(table.search(&path, &self.pattern, self.case_sensitive, matching_mode)), None)),
+                    Ok(Some(RFileDecoded::Text(text))) => Some((None, None, Some(text.search(&path, &self.pattern, self.case_sensitive, matching_mode)))),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for (db, loc, text) in matches {
+            if let Some(mut db) = db.filter(|x| !x.matches().is_empty()) {
+                *db.container_path_mut() = Some(animpack_path.to_owned());
+                matches_db.push(db);
+            }
+
+            if let Some(mut loc) = loc.filter(|x| !x.matches().is_empty()) {
+                *loc.container_path_mut() = Some(animpack_path.to_owned());
+                matches_loc.push(loc);
+            }
+
+            if let Some(mut text) = text.filter(|x| !x.matches().is_empty()) {
+                *text.container_path_mut() = Some(animpack_path.to_owned());
+                matches_text.push(text);
+            }
         }
 
-        self.pattern = pattern;
+        (matches_db, matches_loc, matches_text)
     }
 
     /// This function clears the Global Search result's data, and reset the UI for it.
@@ -440,7 +613,7 @@ impl GlobalSearch {
         if self.pattern.is_empty() { return edited_paths }
 
         // This is only useful for Packs, not for dependencies.
-        if self.source != SearchSource::Pack { return edited_paths }
+        if self.source != SearchSource::Pack && self.source != SearchSource::All { return edited_paths }
 
         // If we want to use regex and the pattern is invalid, use normal pattern instead of Regex.
         let matching_mode = if self.use_regex {
@@ -450,9 +623,11 @@ impl GlobalSearch {
             else { MatchingMode::Pattern }
         } else { MatchingMode::Pattern };
 
-        // Just replace all the provided matches, one by one.
+        // Just replace all the provided matches, one by one. Read-only matches are skipped.
         for match_file in matches {
             match match_file {
+                MatchHolder::Table(search_matches) if search_matches.is_read_only() => continue,
+                MatchHolder::Text(search_matches) if search_matches.is_read_only() => continue,
                 MatchHolder::Table(search_matches) => {
                     let container_path = ContainerPath::File(search_matches.path().to_string());
                     let mut file = pack.files_by_path_mut(&container_path, false);
@@ -507,8 +682,8 @@ impl GlobalSearch {
             }
         }
 
-        // Update the current search over the edited files.
-        self.search(game_info, schema, pack, dependencies, &edited_paths);
+        // Update the current search over the edited files. Replaces cannot be cancelled, so this cannot fail.
+        let _ = self.search(game_info, schema, pack, dependencies, &edited_paths, &AtomicBool::new(false));
 
         // Return the changed paths.
         edited_paths
@@ -20,7 +20,7 @@ use regex::Regex;
 use rpfm_lib::files::{db::DB, loc::Loc, table::DecodedData};
 use rpfm_lib::schema::Field;
 
-use super::{MatchingMode, Replaceable, Searchable};
+use super::{MatchingMode, Replaceable, SearchSource, Searchable};
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -34,6 +34,12 @@ pub struct TableMatches {
     /// The path of the table.
     path: String,
 
+    /// The source the table comes from.
+    source: SearchSource,
+
+    /// If the table is within another file (like an AnimPack), the path of said file.
+    container_path: Option<String>,
+
     /// The list of matches within a table.
     matches: Vec<TableMatch>,
 }
@@ -142,10 +148,17 @@ impl TableMatches {
     pub fn new(path: &str) -> Self {
         Self {
             path: path.to_owned(),
+            source: SearchSource::Pack,
+            container_path: None,
             matches: vec![],
         }
     }
 
+    /// This function returns if the matches cannot be replaced, because they're from a dependency or from a file within another file.
+    pub fn is_read_only(&self) -> bool {
+        self.source != SearchSource::Pack || self.container_path.is_some()
+    }
+
     /// This function check if the provided `&str` matches our search.
     fn match_decoded_data(
         &mut self,
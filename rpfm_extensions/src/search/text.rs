@@ -19,7 +19,7 @@ use itertools::Itertools;
 
 use rpfm_lib::files::text::Text;
 
-use super::{MatchingMode, Replaceable, Searchable};
+use super::{MatchingMode, Replaceable, SearchSource, Searchable};
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -33,6 +33,12 @@ pub struct TextMatches {
     /// The path of the file.
     path: String,
 
+    /// The source the file comes from.
+    source: SearchSource,
+
+    /// If the file is within another file (like an AnimPack), the path of said file.
+    container_path: Option<String>,
+
     /// The list of matches within the file.
     matches: Vec<TextMatch>,
 }
@@ -140,9 +146,16 @@ impl TextMatches {
     pub fn new(path: &str) -> Self {
         Self {
             path: path.to_owned(),
+            source: SearchSource::Pack,
+            container_path: None,
             matches: vec![],
         }
     }
+
+    /// This function returns if the matches cannot be replaced, because they're from a dependency or from a file within another file.
+    pub fn is_read_only(&self) -> bool {
+        self.source != SearchSource::Pack || self.container_path.is_some()
+    }
 }
 
 impl TextMatch {
@@ -0,0 +1,231 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the code to get statistics about the contents of a [Pack].
+
+use getset::Getters;
+use rayon::prelude::*;
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use rpfm_lib::files::{DecodeableExtraData, FileType, pack::Pack, RFile, RFileDecoded};
+use rpfm_lib::schema::Schema;
+
+#[cfg(test)] mod statistics_test;
+
+/// Amount of files reported in the list of largest files.
+const LARGEST_FILES_COUNT: usize = 10;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains statistics about the contents of a [Pack].
+///
+/// All sizes are uncompressed sizes, in bytes.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct PackStatistics {
+
+    /// Amount of files in the Pack.
+    total_files: usize,
+
+    /// Size of all the files in the Pack.
+    total_size: u64,
+
+    /// Amount of files and size of them, by file type.
+    types: BTreeMap<FileType, CountAndSize>,
+
+    /// Path and size of the largest files in the Pack, from the largest one.
+    largest_files: Vec<(String, u64)>,
+
+    /// Amount of files and rows of them, by table name.
+    tables: BTreeMap<String, TableStatistics>,
+
+    /// Amount of Loc files in the Pack.
+    loc_files: usize,
+
+    /// Amount of Loc entries between all the Loc files in the Pack.
+    loc_keys: usize,
+
+    /// Path and size of the DB and Loc files that couldn't be decoded, sorted by path.
+    undecoded_files: Vec<(String, u64)>,
+}
+
+/// This struct contains the amount of files of a group and the size of them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct CountAndSize {
+    files: usize,
+    size: u64,
+}
+
+/// This struct contains the amount of files of a table and the rows between all of them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct TableStatistics {
+    files: usize,
+    rows: usize,
+}
+
+/// Statistics of a single file, before being merged into the [PackStatistics].
+enum FileStatistics {
+    DB(String, usize),
+    Loc(usize),
+    Undecoded,
+    Other,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl PackStatistics {
+
+    /// This function gets the statistics of the provided [Pack].
+    ///
+    /// Files are processed in parallel. DB and Loc files are decoded with the provided schema, if any, to count their rows.
+    /// The files in the Pack are not changed: each one is encoded and decoded from a copy, which is dropped once processed.
+    ///
+    /// Files that fail to decode (or to load) don't fail the whole process. They're just reported as undecoded files.
+    pub fn from_pack(pack: &Pack, schema: Option<&Schema>) -> Self {
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.set_schema(schema);
+        let extra_data = Some(extra_data);
+
+        let files = pack.files().par_iter()
+            .map(|(path, rfile)| {
+                let file_type = rfile.file_type();
+                let mut rfile = rfile.clone();
+                let size = match rfile.encode(&None, true, true, true) {
+                    Ok(Some(data)) => data.len() as u64,
+                    _ => return (path.to_owned(), file_type, 0, FileStatistics::Undecoded),
+                };
+
+                let statistics = match file_type {
+                    FileType::DB | FileType::Loc => file_statistics(&mut rfile, &extra_data),
+                    _ => FileStatistics::Other,
+                };
+
+                (path.to_owned(), file_type, size, statistics)
+            })
+            .collect::<Vec<_>>();
+
+        let mut statistics = Self::default();
+        for (path, file_type, size, file_statistics) in files {
+            statistics.total_files += 1;
+            statistics.total_size += size;
+
+            let type_statistics = statistics.types.entry(file_type).or_default();
+            type_statistics.files += 1;
+            type_statistics.size += size;
+
+            match file_statistics {
+                FileStatistics::DB(table_name, rows) => {
+                    let table_statistics = statistics.tables.entry(table_name).or_default();
+                    table_statistics.files += 1;
+                    table_statistics.rows += rows;
+                }
+                FileStatistics::Loc(rows) => {
+                    statistics.loc_files += 1;
+                    statistics.loc_keys += rows;
+                }
+                FileStatistics::Undecoded => statistics.undecoded_files.push((path.to_owned(), size)),
+                FileStatistics::Other => {}
+            }
+
+            statistics.largest_files.push((path, size));
+        }
+
+        // Ties are sorted by path, so the result doesn't depend on the order the Pack returns its files.
+        statistics.largest_files.sort_by(|(path_a, size_a), (path_b, size_b)| size_b.cmp(size_a).then_with(|| path_a.cmp(path_b)));
+        statistics.largest_files.truncate(LARGEST_FILES_COUNT);
+        statistics.undecoded_files.sort();
+        statistics
+    }
+
+    /// This function returns the size of all the files that couldn't be decoded.
+    pub fn undecoded_size(&self) -> u64 {
+        self.undecoded_files.iter().map(|(_, size)| size).sum()
+    }
+
+    /// This function returns the statistics formatted as Markdown, ready to be pasted in a mod page.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+
+        // Writing to a String never fails, so we can ignore the results here.
+        let _ = writeln!(markdown, "## Pack Statistics\n");
+        let _ = writeln!(markdown, "- Files: {}", self.total_files);
+        let _ = writeln!(markdown, "- Size: {}", format_size(self.total_size));
+        let _ = writeln!(markdown, "- DB Tables: {} ({} rows)", self.tables.values().map(|table| table.files).sum::<usize>(), self.tables.values().map(|table| table.rows).sum::<usize>());
+        let _ = writeln!(markdown, "- Loc Files: {} ({} keys)", self.loc_files, self.loc_keys);
+        let _ = writeln!(markdown, "- Undecoded Files: {} ({})", self.undecoded_files.len(), format_size(self.undecoded_size()));
+
+        let _ = writeln!(markdown, "\n### Files by Type\n");
+        let _ = writeln!(markdown, "| Type | Files | Size |");
+        let _ = writeln!(markdown, "| --- | ---: | ---: |");
+        for (file_type, type_statistics) in &self.types {
+            let _ = writeln!(markdown, "| {} | {} | {} |", file_type, type_statistics.files, format_size(type_statistics.size));
+        }
+
+        let _ = writeln!(markdown, "\n### Largest Files\n");
+        let _ = writeln!(markdown, "| Path | Size |");
+        let _ = writeln!(markdown, "| --- | ---: |");
+        for (path, size) in &self.largest_files {
+            let _ = writeln!(markdown, "| `{}` | {} |", path, format_size(*size));
+        }
+
+        if !self.tables.is_empty() {
+            let _ = writeln!(markdown, "\n### DB Tables\n");
+            let _ = writeln!(markdown, "| Table | Files | Rows |");
+            let _ = writeln!(markdown, "| --- | ---: | ---: |");
+            for (table_name, table_statistics) in &self.tables {
+                let _ = writeln!(markdown, "| {} | {} | {} |", table_name, table_statistics.files, table_statistics.rows);
+            }
+        }
+
+        markdown
+    }
+}
+
+/// This function decodes a copy of a DB or Loc file to get its rows.
+fn file_statistics(rfile: &mut RFile, extra_data: &Option<DecodeableExtraData>) -> FileStatistics {
+    match rfile.decode(extra_data, false, true) {
+        Ok(Some(RFileDecoded::DB(table))) => match table.len(None) {
+            Ok(rows) => FileStatistics::DB(table.table_name().to_owned(), rows),
+            Err(_) => FileStatistics::Undecoded,
+        },
+        Ok(Some(RFileDecoded::Loc(loc))) => FileStatistics::Loc(loc.len()),
+        _ => FileStatistics::Undecoded,
+    }
+}
+
+/// This function formats a size in bytes into a human-readable string.
+pub fn format_size(size: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if size < 1024 {
+        return format!("{size} B");
+    }
+
+    let mut size = size as f64;
+    let mut unit = "B";
+    for next_unit in UNITS {
+        if size < 1024.0 {
+            break;
+        }
+
+        size /= 1024.0;
+        unit = next_unit;
+    }
+
+    format!("{size:.2} {unit}")
+}
@@ -0,0 +1,83 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the Pack statistics.
+
+use rpfm_lib::files::{Container, db::DB, loc::Loc, table::DecodedData};
+use rpfm_lib::games::pfh_version::PFHVersion;
+
+use crate::test_helpers::units_schema;
+
+use super::*;
+
+fn pack(schema: &Schema) -> Pack {
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+
+    let definition = schema.definitions_by_table_name("units_tables").unwrap()[0].clone();
+    for (path, rows) in [("db/units_tables/a", 3), ("db/units_tables/b", 2)] {
+        let mut table = DB::new(&definition, None, "units_tables", false);
+        let rows = (0..rows).map(|row| vec![DecodedData::StringU8(format!("key_{row}")), DecodedData::I32(row)]).collect::<Vec<_>>();
+        table.set_data(None, &rows).unwrap();
+        pack.insert(RFile::new_from_decoded(&RFileDecoded::DB(table), 0, path)).unwrap();
+    }
+
+    let mut loc = Loc::new(false);
+    let mut row = loc.new_row();
+    row[0] = DecodedData::StringU16("key".to_owned());
+    loc.set_data(&[row.clone(), row]).unwrap();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, "text/db/a.loc")).unwrap();
+
+    // A table with no definition in the schema, and a file with broken data.
+    pack.insert(RFile::new_from_vec(&[1, 2, 3], FileType::DB, 0, "db/missing_tables/a")).unwrap();
+    pack.insert(RFile::new_from_vec(&[0; 2048], FileType::Unknown, 0, "big.bin")).unwrap();
+    pack
+}
+
+#[test]
+fn test_statistics() {
+    let schema = units_schema();
+    let pack = pack(&schema);
+    let statistics = PackStatistics::from_pack(&pack, Some(&schema));
+
+    assert_eq!(*statistics.total_files(), 5);
+    assert_eq!(*statistics.total_size(), statistics.types().values().map(|types| *types.size()).sum::<u64>());
+    assert_eq!(*statistics.types()[&FileType::DB].files(), 3);
+    assert_eq!(statistics.tables()["units_tables"], TableStatistics { files: 2, rows: 5 });
+    assert_eq!(*statistics.loc_files(), 1);
+    assert_eq!(*statistics.loc_keys(), 2);
+
+    // Broken files only count for the size stats.
+    assert_eq!(statistics.undecoded_files(), &vec![("db/missing_tables/a".to_owned(), 3)]);
+    assert!(!statistics.tables().contains_key("missing_tables"));
+
+    assert_eq!(statistics.largest_files()[0], ("big.bin".to_owned(), 2048));
+    assert_eq!(statistics.largest_files().len(), 5);
+
+    // The files in the Pack must not be changed.
+    assert!(pack.file("db/units_tables/a").unwrap().decoded().is_ok());
+    assert!(pack.file("db/missing_tables/a").unwrap().cached().is_ok());
+
+    let markdown = statistics.to_markdown();
+    assert!(markdown.contains("| units_tables | 2 | 5 |"));
+    assert!(markdown.contains("| `big.bin` | 2.00 KiB |"));
+
+    // Without schema, no table can be decoded.
+    let statistics = PackStatistics::from_pack(&pack, None);
+    assert!(statistics.tables().is_empty());
+    assert_eq!(statistics.undecoded_files().len(), 3);
+}
+
+#[test]
+fn test_format_size() {
+    assert_eq!(format_size(0), "0 B");
+    assert_eq!(format_size(1023), "1023 B");
+    assert_eq!(format_size(1536), "1.50 KiB");
+    assert_eq!(format_size(5 * 1024 * 1024), "5.00 MiB");
+}
@@ -0,0 +1,183 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to diff DB and Loc tables against their vanilla and parent counterparts.
+
+Rows are matched by their key columns (or by all their columns, if the table has no keys), and compared
+using the same float-tolerant comparison the optimizer uses, so rows don't show up as modified just due to float precision.
+!*/
+
+use getset::Getters;
+
+use std::collections::{HashMap, HashSet};
+
+use rpfm_lib::error::{RLibError, Result};
+use rpfm_lib::files::{RFile, RFileDecoded, table::DecodedData};
+use rpfm_lib::schema::Field;
+
+use crate::dependencies::Dependencies;
+use crate::optimizer::{itm_comparable_cell, itm_comparable_row};
+
+#[cfg(test)] mod table_diff_test;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the differences between a DB/Loc table and the vanilla and parent data of the same table.
+#[derive(Clone, Debug, Default, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct TableDataDiff {
+
+    /// Status of each row of the table, in the same order as the rows of the table.
+    statuses: Vec<RowDiffStatus>,
+
+    /// Rows of the vanilla/parent files with the same path as the table that are not in the table, in the table's column layout.
+    ///
+    /// As these files are overwritten by the table, these rows are effectively removed by it.
+    removed_rows: Vec<Vec<DecodedData>>,
+}
+
+/// This enum represents the status of a row when compared against the vanilla and parent data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RowDiffStatus {
+
+    /// The row is also in the vanilla/parent data, with the same values.
+    Unchanged,
+
+    /// There is no row with the same key in the vanilla/parent data.
+    Added,
+
+    /// There is a row with the same key in the vanilla/parent data, but with different values in the columns provided.
+    Modified(Vec<usize>),
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl TableDataDiff {
+
+    /// This function diffs the provided DB/Loc file against the vanilla and parent data of the same table.
+    ///
+    /// Columns are matched by name, so tables with a different version than the vanilla ones can be diffed too.
+    /// If a row's key is in more than one vanilla/parent row, the row is only reported as modified if it doesn't match any of them,
+    /// and its changed columns are the ones that differ from the one the game uses: the first one found in the parent files, then in the vanilla files.
+    pub fn new(file: &RFile, dependencies: &Dependencies) -> Result<Self> {
+        let path = file.path_in_container_raw();
+        // Parent files go first, as their rows take priority over the vanilla ones.
+        let (fields, rows, new_row, vanilla_files) = match file.decoded()? {
+            RFileDecoded::DB(table) => {
+                let mut vanilla_files = dependencies.db_data(table.table_name(), false, true)?;
+                vanilla_files.append(&mut dependencies.db_data(table.table_name(), true, false)?);
+                (table.definition().fields_processed(), table.data(&None)?, table.new_row(), vanilla_files)
+            }
+            RFileDecoded::Loc(table) => {
+                let mut vanilla_files = dependencies.loc_data(false, true)?;
+                vanilla_files.append(&mut dependencies.loc_data(true, false)?);
+                (table.definition().fields_processed(), table.data(&None)?, table.new_row(), vanilla_files)
+            }
+            _ => return Err(RLibError::TableDiffNotATable(path.to_owned())),
+        };
+
+        let mut key_columns = fields.iter().enumerate().filter_map(|(index, field)| if field.is_key() { Some(index) } else { None }).collect::<Vec<_>>();
+        if key_columns.is_empty() {
+            key_columns = (0..fields.len()).collect();
+        }
+
+        // Get all the vanilla/parent rows in our column layout, grouped by key, and in load order.
+        let mut vanilla_rows: HashMap<String, Vec<Vec<DecodedData>>> = HashMap::new();
+        let mut overwritten_rows = vec![];
+        for vanilla_file in vanilla_files {
+            let (vanilla_fields, vanilla_data) = match vanilla_file.decoded() {
+                Ok(RFileDecoded::DB(table)) => (table.definition().fields_processed(), table.data(&None)?),
+                Ok(RFileDecoded::Loc(table)) => (table.definition().fields_processed(), table.data(&None)?),
+                _ => continue,
+            };
+
+            let column_map = fields.iter()
+                .map(|field| vanilla_fields.iter().position(|vanilla_field| vanilla_field.name() == field.name()))
+                .collect::<Vec<_>>();
+
+            let overwritten = vanilla_file.path_in_container_raw() == path;
+            for vanilla_row in vanilla_data.iter() {
+                let row = map_row(vanilla_row, &column_map, &fields, &new_row);
+                let key = row_key(&row, &key_columns);
+
+                if overwritten {
+                    overwritten_rows.push((key.clone(), row.clone()));
+                }
+
+                vanilla_rows.entry(key).or_default().push(row);
+            }
+        }
+
+        let mut keys = HashSet::new();
+        let statuses = rows.iter()
+            .map(|row| {
+                let key = row_key(row, &key_columns);
+                let status = match vanilla_rows.get(&key) {
+                    Some(vanilla_rows) => {
+                        let comparable_row = itm_comparable_row(row);
+                        if vanilla_rows.iter().any(|vanilla_row| itm_comparable_row(vanilla_row) == comparable_row) {
+                            RowDiffStatus::Unchanged
+                        } else {
+                            let changed_columns = row.iter()
+                                .zip(vanilla_rows[0].iter())
+                                .enumerate()
+                                .filter_map(|(index, (cell, vanilla_cell))| if itm_comparable_cell(cell) != itm_comparable_cell(vanilla_cell) { Some(index) } else { None })
+                                .collect();
+                            RowDiffStatus::Modified(changed_columns)
+                        }
+                    }
+                    None => RowDiffStatus::Added,
+                };
+
+                keys.insert(key);
+                status
+            })
+            .collect();
+
+        let removed_rows = overwritten_rows.into_iter()
+            .filter_map(|(key, row)| if keys.contains(&key) { None } else { Some(row) })
+            .collect();
+
+        Ok(Self {
+            statuses,
+            removed_rows,
+        })
+    }
+}
+
+/// This function maps a row to the provided fields, using the provided source column for each field.
+///
+/// Fields without source column get the values of the provided default row.
+fn map_row(row: &[DecodedData], column_map: &[Option<usize>], fields: &[Field], default_row: &[DecodedData]) -> Vec<DecodedData> {
+    column_map.iter()
+        .zip(fields.iter())
+        .zip(default_row.iter())
+        .map(|((column, field), default)| match column {
+            Some(column) => {
+                if row[*column].is_field_type_correct(field.field_type()) {
+                    row[*column].to_owned()
+                } else {
+                    row[*column].convert_between_types(field.field_type()).unwrap_or_else(|_| default.to_owned())
+                }
+            }
+            None => default.to_owned(),
+        })
+        .collect()
+}
+
+/// This function returns a representation of the key of the provided row we can reliably compare against other keys.
+fn row_key(row: &[DecodedData], key_columns: &[usize]) -> String {
+    itm_comparable_row(&key_columns.iter().map(|index| row[*index].to_owned()).collect::<Vec<_>>())
+}
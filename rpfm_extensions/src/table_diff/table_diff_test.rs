@@ -0,0 +1,110 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for diffing tables against their dependencies.
+
+use rpfm_lib::files::{db::DB, FileType};
+use rpfm_lib::schema::{Definition, FieldType};
+
+use crate::test_helpers::loc_file;
+
+use super::*;
+
+/// This function creates a definition with a key string column, an integer column and a float column.
+fn definition() -> Definition {
+    let mut key = Field::default();
+    key.set_name("key".to_owned());
+    key.set_is_key(true);
+
+    let mut value = Field::default();
+    value.set_name("value".to_owned());
+    value.set_field_type(FieldType::I32);
+
+    let mut ratio = Field::default();
+    ratio.set_name("ratio".to_owned());
+    ratio.set_field_type(FieldType::F32);
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(vec![key, value, ratio]);
+    definition
+}
+
+fn db_file(path: &str, rows: &[(&str, i32, f32)]) -> RFile {
+    let mut table = DB::new(&definition(), None, "units_tables", false);
+    let rows = rows.iter().map(|(key, value, ratio)| vec![DecodedData::StringU8(key.to_string()), DecodedData::I32(*value), DecodedData::F32(*ratio)]).collect::<Vec<_>>();
+    table.set_data(None, &rows).unwrap();
+    RFile::new_from_decoded(&RFileDecoded::DB(table), 0, path)
+}
+
+/// This function creates a Dependencies cache with the provided files as vanilla files.
+///
+/// The cache has no public way to be built without the game files, so we build it through its serialized form.
+fn dependencies(vanilla_files: Vec<RFile>) -> Dependencies {
+    let mut dependencies = serde_json::to_value(Dependencies::default()).unwrap();
+    let mut tables = vec![];
+    let mut locs = vec![];
+    for file in &vanilla_files {
+        let path = file.path_in_container_raw().to_owned();
+        match file.file_type() {
+            FileType::DB => tables.push(path.to_owned()),
+            FileType::Loc => locs.push(path.to_owned()),
+            _ => {}
+        }
+
+        dependencies["vanilla_files"][path] = serde_json::to_value(file).unwrap();
+    }
+
+    dependencies["vanilla_tables"]["units_tables"] = serde_json::json!(tables);
+    dependencies["vanilla_locs"] = serde_json::json!(locs);
+    serde_json::from_value(dependencies).unwrap()
+}
+
+#[test]
+fn test_table_diff_db() {
+    let dependencies = dependencies(vec![
+        db_file("db/units_tables/data__", &[("unchanged", 1, 0.1), ("modified", 2, 0.5), ("removed", 3, 1.0)]),
+        db_file("db/units_tables/other", &[("other", 4, 2.0)]),
+    ]);
+
+    // Same path as a vanilla table, so rows missing from it are removed.
+    let file = db_file("db/units_tables/data__", &[("unchanged", 1, 0.10000001), ("modified", 2, 0.75), ("added", 5, 0.0), ("other", 5, 3.0)]);
+    let diff = TableDataDiff::new(&file, &dependencies).unwrap();
+    assert_eq!(diff.statuses(), &vec![
+        RowDiffStatus::Unchanged,
+        RowDiffStatus::Modified(vec![2]),
+        RowDiffStatus::Added,
+        RowDiffStatus::Modified(vec![1, 2]),
+    ]);
+
+    assert_eq!(diff.removed_rows(), &vec![vec![DecodedData::StringU8("removed".to_owned()), DecodedData::I32(3), DecodedData::F32(1.0)]]);
+
+    // Different path, so it only adds or overrides rows.
+    let file = db_file("db/units_tables/mod", &[("other", 4, 2.0)]);
+    let diff = TableDataDiff::new(&file, &dependencies).unwrap();
+    assert_eq!(diff.statuses(), &vec![RowDiffStatus::Unchanged]);
+    assert!(diff.removed_rows().is_empty());
+}
+
+#[test]
+fn test_table_diff_loc() {
+    let dependencies = dependencies(vec![
+        loc_file("text/db/vanilla.loc", &[("a", "A"), ("b", "B"), ("c", "C")]),
+    ]);
+
+    let file = loc_file("text/db/vanilla.loc", &[("a", "A"), ("b", "Changed"), ("d", "D")]);
+    let diff = TableDataDiff::new(&file, &dependencies).unwrap();
+    assert_eq!(diff.statuses(), &vec![RowDiffStatus::Unchanged, RowDiffStatus::Modified(vec![1]), RowDiffStatus::Added]);
+    assert_eq!(diff.removed_rows().len(), 1);
+    assert_eq!(diff.removed_rows()[0][0], DecodedData::StringU16("c".to_owned()));
+
+    // Non-table files cannot be diffed.
+    let file = RFile::new_from_vec(b"text", FileType::Text, 0, "text/a.txt");
+    assert!(TableDataDiff::new(&file, &dependencies).is_err());
+}
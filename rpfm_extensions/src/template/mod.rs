@@ -0,0 +1,295 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the [Template] struct, used to create a set of related files in a Pack from a few parameters.
+//!
+//! Templates are JSON files describing DB tables (with their rows), Loc files and Text files. Every string in them can contain
+//! `{parameter}` placeholders, which get replaced with the values provided for the parameters declared by the template.
+
+use getset::Getters;
+use serde_derive::{Serialize, Deserialize};
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{DirBuilder, File};
+use std::io::BufReader;
+use std::path::Path;
+
+use rpfm_lib::error::{RLibError, Result};
+use rpfm_lib::files::{Container, ContainerPath, db::DB, loc::Loc, pack::Pack, RFile, RFileDecoded, table::DecodedData, text::{self, Text}};
+use rpfm_lib::schema::Schema;
+
+use crate::deep_clone::table_definition;
+use crate::dependencies::Dependencies;
+
+#[cfg(test)] mod template_test;
+
+/// Name of the file of the template shipped with the program.
+pub const EXAMPLE_TEMPLATE_NAME: &str = "ancillary.json";
+
+/// Contents of the template shipped with the program, so users have something to start from.
+pub const EXAMPLE_TEMPLATE: &str = include_str!("ancillary.json");
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct represents a template, a set of files to be created from a few parameters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct Template {
+
+    /// Name of the template, as shown to the user.
+    name: String,
+
+    /// Explanation of what the template creates.
+    description: String,
+
+    /// Parameters the user has to provide to instantiate the template.
+    parameters: Vec<TemplateParameter>,
+
+    /// DB tables to create.
+    tables: Vec<TemplateTable>,
+
+    /// Loc files to create.
+    locs: Vec<TemplateLoc>,
+
+    /// Text files to create.
+    texts: Vec<TemplateText>,
+}
+
+/// This struct represents a parameter of a template.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct TemplateParameter {
+
+    /// Key of the parameter. Use it as `{key}` in the template to get its value.
+    key: String,
+
+    /// Name of the parameter, as shown to the user.
+    name: String,
+
+    /// Explanation of what the parameter is for.
+    description: String,
+
+    /// Value to use if the user doesn't provide one. Parameters without default value are mandatory.
+    default_value: Option<String>,
+}
+
+/// This struct represents a DB table created by a template.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct TemplateTable {
+
+    /// Name of the table, like `units_tables`.
+    table_name: String,
+
+    /// Name of the file within the folder of the table.
+    file_name: String,
+
+    /// Rows of the table, as column name/value pairs. Columns not provided get their default value.
+    rows: Vec<BTreeMap<String, String>>,
+}
+
+/// This struct represents a Loc file created by a template.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct TemplateLoc {
+
+    /// Path of the file within the Pack.
+    path: String,
+
+    /// Entries of the file.
+    entries: Vec<TemplateLocEntry>,
+}
+
+/// This struct represents an entry of a Loc file created by a template.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct TemplateLocEntry {
+    key: String,
+    text: String,
+    tooltip: bool,
+}
+
+/// This struct represents a Text file created by a template.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct TemplateText {
+
+    /// Path of the file within the Pack.
+    path: String,
+
+    /// Contents of the file.
+    contents: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl Template {
+
+    /// This function loads a template from the provided JSON file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let file = BufReader::new(File::open(path)?);
+        serde_json::from_reader(file).map_err(From::from)
+    }
+
+    /// This function loads all the templates in the provided folder, by the name of their file without extension.
+    ///
+    /// Files that are not valid templates are ignored. If the folder doesn't exist, it's created and the example template is written to it.
+    pub fn templates(folder: &Path) -> Result<BTreeMap<String, Self>> {
+        if !folder.is_dir() {
+            DirBuilder::new().recursive(true).create(folder)?;
+            std::fs::write(folder.join(EXAMPLE_TEMPLATE_NAME), EXAMPLE_TEMPLATE)?;
+        }
+
+        let mut templates = BTreeMap::new();
+        for entry in std::fs::read_dir(folder)? {
+            let path = entry?.path();
+            if path.is_file() && path.extension().map(|extension| extension == "json").unwrap_or(false) {
+                if let (Some(key), Ok(template)) = (path.file_stem(), Self::load(&path)) {
+                    templates.insert(key.to_string_lossy().to_string(), template);
+                }
+            }
+        }
+
+        Ok(templates)
+    }
+
+    /// This function returns the values to use for each parameter of the template, using the default values for the parameters not provided.
+    ///
+    /// Empty values count as not provided. Values for parameters not declared by the template are ignored.
+    pub fn parameter_values(&self, values: &HashMap<String, String>) -> Result<HashMap<String, String>> {
+        self.parameters.iter()
+            .map(|parameter| {
+                let value = values.get(&parameter.key)
+                    .filter(|value| !value.is_empty())
+                    .or(parameter.default_value.as_ref())
+                    .ok_or_else(|| RLibError::TemplateParameterMissing(parameter.key.to_owned()))?;
+
+                Ok((parameter.key.to_owned(), value.to_owned()))
+            })
+            .collect()
+    }
+
+    /// This function replaces the `{key}` placeholders of the provided text with the values of their parameters.
+    ///
+    /// Placeholders of parameters not in the provided values are left untouched.
+    pub fn substitute(text: &str, values: &HashMap<String, String>) -> String {
+        let mut text = text.to_owned();
+        for (key, value) in values {
+            text = text.replace(&format!("{{{}}}", key), value);
+        }
+
+        text
+    }
+
+    /// This function returns the paths of the files this template creates with the provided values.
+    pub fn paths(&self, values: &HashMap<String, String>) -> Result<Vec<String>> {
+        let values = self.parameter_values(values)?;
+        let mut paths = self.tables.iter().map(|table| format!("db/{}/{}", table.table_name, Self::substitute(&table.file_name, &values))).collect::<Vec<_>>();
+        paths.extend(self.locs.iter().map(|loc| Self::substitute(&loc.path, &values)));
+        paths.extend(self.texts.iter().map(|text| Self::substitute(&text.path, &values)));
+        Ok(paths)
+    }
+
+    /// This function returns the paths of the files this template creates with the provided values which already exist in the Pack.
+    pub fn collisions(&self, pack: &Pack, values: &HashMap<String, String>) -> Result<Vec<String>> {
+        Ok(self.paths(values)?.into_iter().filter(|path| pack.has_file(path)).collect())
+    }
+
+    /// This function creates the files of this template in the provided Pack, replacing any existing file with the same path.
+    ///
+    /// All files are built before touching the Pack, so if any of them fails, the Pack is left untouched. It returns the paths of the added files.
+    pub fn apply(&self, pack: &mut Pack, dependencies: &Dependencies, schema: &Schema, values: &HashMap<String, String>) -> Result<Vec<ContainerPath>> {
+        let values = self.parameter_values(values)?;
+        let mut files: BTreeMap<String, RFileDecoded> = BTreeMap::new();
+
+        for template_table in &self.tables {
+            let path = format!("db/{}/{}", template_table.table_name, Self::substitute(&template_table.file_name, &values));
+            let definition = table_definition(dependencies, schema, &template_table.table_name).ok_or(RLibError::DecodingDBNoDefinitionsFound)?;
+            let patches = schema.patches_for_table(&template_table.table_name);
+            let fields = definition.fields_processed();
+
+            let mut table = DB::new(&definition, patches, &template_table.table_name, false);
+            let rows = template_table.rows.iter()
+                .map(|template_row| {
+                    let mut row = table.new_row();
+                    for (column_name, value) in template_row {
+                        let column = fields.iter().position(|field| field.name() == column_name)
+                            .ok_or_else(|| RLibError::TemplateColumnNotFound(template_table.table_name.to_owned(), column_name.to_owned()))?;
+
+                        let value = Self::substitute(value, &values);
+                        row[column] = DecodedData::new_from_type_and_string(fields[column].field_type(), &value)
+                            .map_err(|_| RLibError::TemplateInvalidValue(template_table.table_name.to_owned(), column_name.to_owned(), value))?;
+                    }
+
+                    Ok(row)
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            table.set_data(None, &rows)?;
+            insert_unique(&mut files, path, RFileDecoded::DB(table))?;
+        }
+
+        for template_loc in &self.locs {
+            let mut table = Loc::new(false);
+            let rows = template_loc.entries.iter()
+                .map(|entry| vec![
+                    DecodedData::StringU16(Self::substitute(&entry.key, &values)),
+                    DecodedData::StringU16(Self::substitute(&entry.text, &values)),
+                    DecodedData::Boolean(entry.tooltip),
+                ])
+                .collect::<Vec<_>>();
+
+            table.set_data(&rows)?;
+            insert_unique(&mut files, Self::substitute(&template_loc.path, &values), RFileDecoded::Loc(table))?;
+        }
+
+        for template_text in &self.texts {
+            let path = Self::substitute(&template_text.path, &values);
+            let mut file = Text::default();
+            if let Some((_, format)) = text::EXTENSIONS.iter().find(|(extension, _)| path.ends_with(extension)) {
+                file.set_format(*format);
+            }
+
+            file.set_contents(Self::substitute(&template_text.contents, &values));
+            insert_unique(&mut files, path, RFileDecoded::Text(file))?;
+        }
+
+        let mut added_paths = vec![];
+        for (path, decoded) in &files {
+            added_paths.extend(pack.insert(RFile::new_from_decoded(decoded, 0, path))?);
+        }
+
+        Ok(added_paths)
+    }
+}
+
+//-------------------------------------------------------------------------------//
+//                             Utility functions
+//-------------------------------------------------------------------------------//
+
+/// This function adds a file to the list of files to create, failing if there was already a file with the same path.
+fn insert_unique(files: &mut BTreeMap<String, RFileDecoded>, path: String, decoded: RFileDecoded) -> Result<()> {
+    if files.contains_key(&path) {
+        return Err(RLibError::TemplateDuplicatedPath(path));
+    }
+
+    files.insert(path, decoded);
+    Ok(())
+}
@@ -0,0 +1,122 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for templates.
+
+use rpfm_lib::games::pfh_version::PFHVersion;
+use rpfm_lib::schema::{Definition, Field, FieldType};
+
+use super::*;
+
+/// This function creates a Schema with the tables used by the example template.
+fn schema() -> Schema {
+    let mut schema = Schema::default();
+
+    let mut key = Field::default();
+    key.set_name("key".to_owned());
+    key.set_is_key(true);
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(vec![key]);
+    schema.add_definition("ancillaries_tables", &definition);
+
+    let fields = ["ancillary", "effect", "effect_scope", "value"].iter().map(|name| {
+        let mut field = Field::default();
+        field.set_name(name.to_string());
+        if *name == "value" {
+            field.set_field_type(FieldType::F32);
+        }
+        field
+    }).collect();
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(fields);
+    schema.add_definition("ancillary_to_effects_tables", &definition);
+
+    schema
+}
+
+fn values(values: &[(&str, &str)]) -> HashMap<String, String> {
+    values.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+}
+
+#[test]
+fn test_template_substitution() {
+    let template: Template = serde_json::from_str(EXAMPLE_TEMPLATE).unwrap();
+
+    // Missing mandatory parameters must fail, and empty values count as missing.
+    assert!(template.parameter_values(&values(&[("key", "my_anc")])).is_err());
+    assert!(template.parameter_values(&values(&[("key", ""), ("name", "Sword"), ("effect", "my_effect")])).is_err());
+
+    let values = template.parameter_values(&values(&[("key", "my_anc"), ("name", "Sword"), ("effect", "my_effect"), ("value", ""), ("unknown", "x")])).unwrap();
+    assert_eq!(values.get("value").unwrap(), "1");
+    assert_eq!(values.get("effect_scope").unwrap(), "character_to_character_own");
+    assert!(!values.contains_key("unknown"));
+
+    assert_eq!(Template::substitute("{key}_{name}_{key}", &values), "my_anc_Sword_my_anc");
+    assert_eq!(Template::substitute("{not_a_parameter}", &values), "{not_a_parameter}");
+
+    assert_eq!(template.paths(&values).unwrap(), vec![
+        "db/ancillaries_tables/my_anc".to_owned(),
+        "db/ancillary_to_effects_tables/my_anc".to_owned(),
+        "text/db/my_anc.loc".to_owned(),
+    ]);
+}
+
+#[test]
+fn test_template_apply() {
+    let schema = schema();
+    let dependencies = Dependencies::default();
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    let template: Template = serde_json::from_str(EXAMPLE_TEMPLATE).unwrap();
+
+    // Invalid values must not leave half the files in the Pack.
+    let bad_values = values(&[("key", "my_anc"), ("name", "Sword"), ("effect", "my_effect"), ("value", "not_a_number")]);
+    assert!(template.apply(&mut pack, &dependencies, &schema, &bad_values).is_err());
+    assert!(pack.files().is_empty());
+
+    let values = values(&[("key", "my_anc"), ("name", "Sword"), ("effect", "my_effect"), ("value", "2.5")]);
+    assert!(template.collisions(&pack, &values).unwrap().is_empty());
+
+    let added_paths = template.apply(&mut pack, &dependencies, &schema, &values).unwrap();
+    assert_eq!(added_paths.len(), 3);
+    assert_eq!(template.collisions(&pack, &values).unwrap().len(), 3);
+
+    let file = pack.files().get("db/ancillary_to_effects_tables/my_anc").unwrap();
+    if let Ok(RFileDecoded::DB(table)) = file.decoded() {
+        assert_eq!(table.data(&None).unwrap().to_vec(), vec![vec![
+            DecodedData::StringU8("my_anc".to_owned()),
+            DecodedData::StringU8("my_effect".to_owned()),
+            DecodedData::StringU8("character_to_character_own".to_owned()),
+            DecodedData::F32(2.5),
+        ]]);
+    } else {
+        panic!("The table was not created.");
+    }
+
+    let file = pack.files().get("text/db/my_anc.loc").unwrap();
+    if let Ok(RFileDecoded::Loc(table)) = file.decoded() {
+        let data = table.data(&None).unwrap();
+        assert_eq!(data[0][0].data_to_string(), "ancillaries_onscreen_name_my_anc");
+        assert_eq!(data[0][1].data_to_string(), "Sword");
+    } else {
+        panic!("The loc was not created.");
+    }
+
+    // Applying it again replaces the existing files.
+    let values = self::values(&[("key", "my_anc"), ("name", "Axe"), ("effect", "my_effect")]);
+    template.apply(&mut pack, &dependencies, &schema, &values).unwrap();
+    assert_eq!(pack.files().len(), 3);
+
+    // Unknown columns must fail.
+    let mut template = template;
+    template.tables[0].rows[0].insert("not_a_column".to_owned(), "x".to_owned());
+    assert!(matches!(template.apply(&mut pack, &dependencies, &schema, &values), Err(RLibError::TemplateColumnNotFound(_, _))));
+}
@@ -0,0 +1,79 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing helpers shared by the tests of this crate.
+
+use rpfm_lib::files::{Container, db::DB, loc::Loc, pack::Pack, RFile, RFileDecoded, table::DecodedData};
+use rpfm_lib::games::pfh_version::PFHVersion;
+use rpfm_lib::schema::{Definition, Field, FieldType, Schema};
+
+/// This function creates a Schema with a single table, `units_tables`, with a key string column and an integer column.
+pub fn units_schema() -> Schema {
+    let mut key = Field::default();
+    key.set_name("key".to_owned());
+    key.set_is_key(true);
+
+    let mut value = Field::default();
+    value.set_name("value".to_owned());
+    value.set_field_type(FieldType::I32);
+
+    let mut definition = Definition::new(1);
+    definition.set_fields(vec![key, value]);
+
+    let mut schema = Schema::default();
+    schema.add_definition("units_tables", &definition);
+    schema
+}
+
+/// This function creates a StringU8 field, optionally referencing another table.
+pub fn field(name: &str, is_key: bool, reference: Option<(&str, &str)>) -> Field {
+    let mut field = Field::default();
+    field.set_name(name.to_owned());
+    field.set_is_key(is_key);
+    field.set_is_reference(reference.map(|(table, column)| (table.to_owned(), column.to_owned())));
+    field
+}
+
+/// This function adds a table with the provided rows to the Pack, at `db/{table_name}/test`.
+pub fn insert_table(pack: &mut Pack, schema: &Schema, table_name: &str, rows: &[Vec<DecodedData>]) {
+    let definition = schema.definitions_by_table_name(table_name).unwrap()[0].clone();
+    let mut table = DB::new(&definition, None, table_name, false);
+    table.set_data(None, rows).unwrap();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::DB(table), 0, &format!("db/{}/test", table_name))).unwrap();
+}
+
+/// This function creates a StringU8 cell with the provided value.
+pub fn string(value: &str) -> DecodedData {
+    DecodedData::StringU8(value.to_owned())
+}
+
+/// This function creates a Loc file at the provided path, containing the provided key/text pairs.
+pub fn loc_file(path: &str, rows: &[(&str, &str)]) -> RFile {
+    let mut loc = Loc::new(false);
+    let empty_row = loc.new_row();
+    let rows = rows.iter().map(|(key, text)| {
+        let mut row = empty_row.clone();
+        row[0] = DecodedData::StringU16(key.to_string());
+        row[1] = DecodedData::StringU16(text.to_string());
+        row
+    }).collect::<Vec<_>>();
+    loc.set_data(&rows).unwrap();
+    RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, path)
+}
+
+/// This function creates a Pack with a Loc at each one of the provided paths, containing the provided key/text pairs.
+pub fn pack_with_locs(locs: &[(&str, &[(&str, &str)])]) -> Pack {
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    for (path, rows) in locs {
+        pack.insert(loc_file(path, rows)).unwrap();
+    }
+
+    pack
+}
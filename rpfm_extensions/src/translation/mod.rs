@@ -0,0 +1,425 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the [Po] struct, used to exchange the Loc files of a Pack with translators using gettext's PO format.
+//!
+//! Each Loc entry is exported as a PO entry, with the loc key as `msgid`, the text as `msgstr`, and the path of the Loc file
+//! it comes from as a reference comment. Plural forms are not supported, as Loc files don't have them.
+
+use getset::{Getters, MutGetters, Setters};
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use rpfm_lib::error::{RLibError, Result};
+use rpfm_lib::files::{Container, ContainerPath, FileType, loc::Loc, pack::Pack, RFile, RFileDecoded, table::DecodedData};
+
+pub mod report;
+
+#[cfg(test)] mod report_test;
+#[cfg(test)] mod translation_test;
+
+/// Start of the header entry of the PO files we generate, so gettext tools know they're in UTF-8.
+const PO_HEADER: &str = "msgid \"\"\nmsgstr \"\"\n\"Content-Type: text/plain; charset=UTF-8\\n\"\n\"Content-Transfer-Encoding: 8bit\\n\"\n";
+
+/// Field of the header entry with the language of the translation.
+const PO_HEADER_LANGUAGE: &str = "Language:";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct represents the entries of a PO file.
+#[derive(Clone, Debug, Default, PartialEq, Getters, MutGetters, Setters)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct Po {
+
+    /// The language of the translation, as written in the header entry. Empty if unknown.
+    language: String,
+
+    /// The entries of the PO file, in the order they're in the file. The header entry is not included.
+    entries: Vec<PoEntry>,
+}
+
+/// This struct represents a single entry of a PO file.
+#[derive(Clone, Debug, Default, PartialEq, Getters, MutGetters)]
+#[getset(get = "pub", get_mut = "pub")]
+pub struct PoEntry {
+
+    /// The loc key of the entry, used as `msgid`.
+    key: String,
+
+    /// The text of the entry, used as `msgstr`.
+    text: String,
+
+    /// Paths of the Loc files containing the entry, written as reference comments.
+    references: Vec<String>,
+}
+
+/// Keywords (`msgctxt`, `msgid` and `msgstr`) we can find at the start of a non-comment line of a PO file.
+#[derive(Clone, Copy, PartialEq)]
+enum Keyword {
+    Context,
+    Id,
+    Str,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl Po {
+
+    /// This function builds a [Po] with the entries of all the Loc files in the provided Pack.
+    ///
+    /// Locs load in alphabetical order and the last one loaded wins, so if a key is in more than one Loc, its text is
+    /// taken from the last one, and all of them are kept as references.
+    pub fn from_pack(pack: &mut Pack) -> Result<Self> {
+        let mut paths = pack.files_by_type(&[FileType::Loc])
+            .iter()
+            .map(|file| file.path_in_container_raw().to_owned())
+            .collect::<Vec<_>>();
+
+        if paths.is_empty() {
+            return Err(RLibError::PoNoLocsToExport);
+        }
+
+        paths.sort();
+
+        let mut po = Self::default();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for path in &paths {
+            if let Some(RFileDecoded::Loc(loc)) = decoded_loc(pack, path)? {
+                for row in loc.data(&None)?.iter() {
+                    let key = row[0].data_to_string().to_string();
+                    let text = row[1].data_to_string().to_string();
+
+                    match positions.get(&key) {
+                        Some(position) => {
+                            let entry = &mut po.entries[*position];
+                            entry.text = text;
+                            if !entry.references.contains(path) {
+                                entry.references.push(path.to_owned());
+                            }
+                        }
+                        None => {
+                            positions.insert(key.to_owned(), po.entries.len());
+                            po.entries.push(PoEntry {
+                                key,
+                                text,
+                                references: vec![path.to_owned()],
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(po)
+    }
+
+    /// This function updates the Loc files of the provided Pack with the texts of this [Po].
+    ///
+    /// Keys found in the Pack's Loc files are updated in place, in every Loc containing them. Keys not found in any of them
+    /// are added to the Loc at `new_loc_path`, which is created if it doesn't exist. Entries with an empty `msgstr` are considered
+    /// untranslated, so they're ignored.
+    ///
+    /// It returns the amount of entries updated, the amount of entries added, and the paths of the Loc files modified and added.
+    pub fn import_to_pack(&self, pack: &mut Pack, new_loc_path: &str) -> Result<(usize, usize, Vec<ContainerPath>, Vec<ContainerPath>)> {
+        let texts = self.entries.iter()
+            .filter(|entry| !entry.text.is_empty())
+            .map(|entry| (entry.key.as_str(), entry.text.as_str()))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut paths = pack.files_by_type(&[FileType::Loc])
+            .iter()
+            .map(|file| file.path_in_container_raw().to_owned())
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        let mut updated = 0;
+        let mut keys_found = HashSet::new();
+        let mut modified_paths = vec![];
+        for path in &paths {
+            if let Some(RFileDecoded::Loc(loc)) = decoded_loc(pack, path)? {
+                let mut data = loc.data(&None)?.to_vec();
+                let mut modified = false;
+                for row in data.iter_mut() {
+                    let key = row[0].data_to_string().to_string();
+                    if let Some(text) = texts.get(key.as_str()) {
+                        if row[1].data_to_string() != *text {
+                            row[1] = DecodedData::StringU16(text.to_string());
+                            updated += 1;
+                            modified = true;
+                        }
+
+                        keys_found.insert(key);
+                    }
+                }
+
+                if modified {
+                    loc.set_data(&data)?;
+                    modified_paths.push(ContainerPath::File(path.to_owned()));
+                }
+            }
+        }
+
+        // Keys we don't have in any Loc go to the new one.
+        let empty_row = Loc::new(false).new_row();
+        let mut new_rows = texts.iter()
+            .filter(|(key, _)| !keys_found.contains(**key))
+            .map(|(key, text)| {
+                let mut row = empty_row.clone();
+                row[0] = DecodedData::StringU16(key.to_string());
+                row[1] = DecodedData::StringU16(text.to_string());
+                row
+            })
+            .collect::<Vec<_>>();
+
+        let added = new_rows.len();
+        let mut added_paths = vec![];
+        if added > 0 {
+            match decoded_loc(pack, new_loc_path)? {
+                Some(RFileDecoded::Loc(loc)) => {
+                    let mut data = loc.data(&None)?.to_vec();
+                    data.append(&mut new_rows);
+                    loc.set_data(&data)?;
+                    modified_paths.push(ContainerPath::File(new_loc_path.to_owned()));
+                }
+                Some(_) => return Err(RLibError::DecodingLocNotALocTable),
+                None => {
+                    let mut loc = Loc::new(false);
+                    loc.set_data(&new_rows)?;
+                    let file = RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, new_loc_path);
+                    added_paths.extend(pack.insert(file)?);
+                }
+            }
+        }
+
+        Ok((updated, added, modified_paths, added_paths))
+    }
+
+    /// This function parses the contents of a PO file.
+    ///
+    /// Translator comments, flags, contexts and obsolete entries are accepted, but ignored.
+    pub fn from_po_str(data: &str) -> Result<Self> {
+        let mut po = Self::default();
+        let mut references = vec![];
+        let mut key: Option<String> = None;
+        let mut text: Option<String> = None;
+        let mut last_keyword = None;
+
+        for (index, line) in data.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+
+            // Anything other than the continuation of the msgstr ends the current entry, if it's complete.
+            if !line.starts_with('"') && text.is_some() {
+                po.push_entry(key.take(), text.take(), &mut references, line_number)?;
+                last_keyword = None;
+            }
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(comment) = line.strip_prefix('#') {
+                if let Some(refs) = comment.strip_prefix(':') {
+                    references.extend(refs.split_whitespace().map(|reference| reference.to_owned()));
+                }
+                continue;
+            }
+
+            if line.starts_with('"') {
+                let value = parse_string(line, line_number)?;
+                match last_keyword {
+                    Some(Keyword::Context) => {},
+                    Some(Keyword::Id) => key.get_or_insert_with(String::new).push_str(&value),
+                    Some(Keyword::Str) => text.get_or_insert_with(String::new).push_str(&value),
+                    None => return Err(RLibError::PoParseError(line_number, "string without a keyword before it.".to_owned())),
+                }
+                continue;
+            }
+
+            let (keyword, value) = line.split_once(char::is_whitespace)
+                .ok_or_else(|| RLibError::PoParseError(line_number, format!("unexpected line \"{}\".", line)))?;
+            let value = parse_string(value.trim(), line_number)?;
+
+            match keyword {
+                "msgctxt" => last_keyword = Some(Keyword::Context),
+                "msgid" => {
+                    if key.is_some() {
+                        return Err(RLibError::PoParseError(line_number, "msgid without a msgstr before it.".to_owned()));
+                    }
+
+                    key = Some(value);
+                    last_keyword = Some(Keyword::Id);
+                }
+                "msgstr" => {
+                    if key.is_none() {
+                        return Err(RLibError::PoParseError(line_number, "msgstr without a msgid before it.".to_owned()));
+                    }
+
+                    text = Some(value);
+                    last_keyword = Some(Keyword::Str);
+                }
+                "msgid_plural" => return Err(RLibError::PoParseError(line_number, "plural forms are not supported.".to_owned())),
+                _ if keyword.starts_with("msgstr[") => return Err(RLibError::PoParseError(line_number, "plural forms are not supported.".to_owned())),
+                _ => return Err(RLibError::PoParseError(line_number, format!("unknown keyword \"{}\".", keyword))),
+            }
+        }
+
+        if text.is_some() {
+            po.push_entry(key, text, &mut references, data.lines().count())?;
+        } else if key.is_some() {
+            return Err(RLibError::PoParseError(data.lines().count(), "msgid without a msgstr after it.".to_owned()));
+        }
+
+        Ok(po)
+    }
+
+    /// This function returns this [Po] serialized as the contents of a PO file.
+    pub fn to_po_string(&self) -> String {
+        let mut data = PO_HEADER.to_owned();
+        if !self.language.is_empty() {
+            data.push_str(&format!("{}\n", escape_string(&format!("{} {}\n", PO_HEADER_LANGUAGE, self.language))));
+        }
+
+        for entry in &self.entries {
+            data.push('\n');
+            for reference in &entry.references {
+                data.push_str(&format!("#: {}\n", reference));
+            }
+
+            data.push_str(&format!("msgid {}\n", escape_string(&entry.key)));
+            data.push_str(&format!("msgstr {}\n", escape_string(&entry.text)));
+        }
+
+        data
+    }
+
+    /// This function adds a parsed entry to the list of entries. If it's the header entry, we only take the language from it.
+    fn push_entry(&mut self, key: Option<String>, text: Option<String>, references: &mut Vec<String>, line_number: usize) -> Result<()> {
+        let key = key.ok_or_else(|| RLibError::PoParseError(line_number, "msgstr without a msgid before it.".to_owned()))?;
+        let text = text.unwrap_or_default();
+        let references = std::mem::take(references);
+
+        if key.is_empty() {
+            if let Some(language) = text.lines().find_map(|line| line.strip_prefix(PO_HEADER_LANGUAGE)) {
+                self.language = language.trim().to_owned();
+            }
+        } else {
+            self.entries.push(PoEntry {
+                key,
+                text,
+                references,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl PoEntry {
+
+    /// This function creates a new entry with the provided key, text and references.
+    pub fn new(key: &str, text: &str, references: &[String]) -> Self {
+        Self {
+            key: key.to_owned(),
+            text: text.to_owned(),
+            references: references.to_vec(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------//
+//                                  Functions
+//-------------------------------------------------------------------------------//
+
+/// This function returns the Loc at the provided path of the Pack, decoded, or None if there is no file at that path.
+fn decoded_loc<'a>(pack: &'a mut Pack, path: &str) -> Result<Option<&'a mut RFileDecoded>> {
+    match pack.files_mut().get_mut(path) {
+        Some(file) => {
+            if file.file_type() != FileType::Loc {
+                return Err(RLibError::DecodingLocNotALocTable);
+            }
+
+            file.decode(&None, true, false)?;
+            Ok(Some(file.decoded_mut()?))
+        }
+        None => Ok(None),
+    }
+}
+
+/// This function escapes a string following the PO spec, and returns it between quotes.
+///
+/// Strings with line jumps are split in multiple lines after each line jump, like gettext tools do.
+pub fn escape_string(string: &str) -> String {
+    let mut lines = vec![String::new()];
+    for character in string.chars() {
+        let line = lines.last_mut().unwrap();
+        match character {
+            '\\' => line.push_str("\\\\"),
+            '"' => line.push_str("\\\""),
+            '\t' => line.push_str("\\t"),
+            '\r' => line.push_str("\\r"),
+            '\n' => {
+                line.push_str("\\n");
+                lines.push(String::new());
+            }
+            _ => line.push(character),
+        }
+    }
+
+    // Remove the empty line left by a line jump at the end.
+    if lines.len() > 1 && lines.last().map_or(false, |line| line.is_empty()) {
+        lines.pop();
+    }
+
+    if lines.len() == 1 {
+        format!("\"{}\"", lines[0])
+    } else {
+        let mut escaped = "\"\"".to_owned();
+        for line in &lines {
+            escaped.push_str(&format!("\n\"{}\"", line));
+        }
+        escaped
+    }
+}
+
+/// This function parses a quoted string of a PO file, unescaping it.
+fn parse_string(string: &str, line_number: usize) -> Result<String> {
+    let inner = string.strip_prefix('"')
+        .and_then(|string| string.strip_suffix('"'))
+        .ok_or_else(|| RLibError::PoParseError(line_number, format!("string not between quotes: {}.", string)))?;
+
+    let mut unescaped = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(character) = chars.next() {
+        match character {
+            '\\' => match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some('t') => unescaped.push('\t'),
+                Some('r') => unescaped.push('\r'),
+                Some('a') => unescaped.push('\u{07}'),
+                Some('b') => unescaped.push('\u{08}'),
+                Some('f') => unescaped.push('\u{0C}'),
+                Some('v') => unescaped.push('\u{0B}'),
+                Some('"') => unescaped.push('"'),
+                Some('\\') => unescaped.push('\\'),
+                Some(other) => return Err(RLibError::PoParseError(line_number, format!("invalid escape sequence \"\\{}\".", other))),
+                None => return Err(RLibError::PoParseError(line_number, "string ending with an unfinished escape sequence.".to_owned())),
+            },
+            '"' => return Err(RLibError::PoParseError(line_number, "unescaped quote inside a string.".to_owned())),
+            _ => unescaped.push(character),
+        }
+    }
+
+    Ok(unescaped)
+}
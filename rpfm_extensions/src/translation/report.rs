@@ -0,0 +1,250 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the [TranslationReport] struct, used to check how complete the translations of the Loc files of a Pack are.
+//!
+//! The Loc files are grouped by language, using the [LanguageDetection] provided. Then the keys of all languages are merged,
+//! and each language is checked for keys it lacks, and for keys with the same text as the base language, which are probably untranslated.
+
+use getset::Getters;
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::Write;
+
+use rpfm_lib::error::Result;
+use rpfm_lib::files::{Container, FileType, pack::Pack, RFile, RFileDecoded};
+
+/// Prefix of the folders containing the Loc files of each language, when using [LanguageDetection::Folder].
+const LANGUAGE_FOLDER_PREFIX: &str = "local_";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This enum represents the ways we can get the language of a Loc file from its path.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LanguageDetection {
+
+    /// The language is the last part of the file name, after the last `_`, like in `text/db/my_mod_en.loc`.
+    #[default]
+    FileSuffix,
+
+    /// The language is the end of a `local_xx` folder containing the file, like in `text/local_en/my_mod.loc`.
+    Folder,
+}
+
+/// This struct contains the result of comparing the translations of the Loc files of a Pack.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct TranslationReport {
+
+    /// Language the other languages are compared against to find untranslated keys.
+    base_language: String,
+
+    /// Amount of files and keys of each language, and problems found in them.
+    languages: BTreeMap<String, LanguageSummary>,
+
+    /// Amount of different keys between all languages.
+    total_keys: usize,
+
+    /// Problems found, sorted by language and key.
+    entries: Vec<TranslationReportEntry>,
+
+    /// Loc files not included in the report, either because we couldn't get their language, or because they couldn't be decoded.
+    skipped_files: Vec<String>,
+}
+
+/// This struct contains the amount of files and keys of a language, and the amount of problems found in them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct LanguageSummary {
+    files: usize,
+    keys: usize,
+    missing: usize,
+    untranslated: usize,
+}
+
+/// This struct represents a problem with a key in a specific language.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct TranslationReportEntry {
+
+    /// Language with the problem.
+    language: String,
+
+    /// Key with the problem.
+    key: String,
+
+    /// The problem itself.
+    issue: TranslationIssue,
+
+    /// Text of the key in the base language, if it has it.
+    base_text: String,
+}
+
+/// This enum represents the problems a key can have in a language.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TranslationIssue {
+
+    /// The key is in other languages, but not in this one.
+    Missing,
+
+    /// The key has the same text as in the base language.
+    Untranslated,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl TranslationReport {
+
+    /// This function compares the translations of the Loc files of the provided Pack.
+    ///
+    /// Languages are compared case-insensitively, and reported in lowercase. Keys in `keys_to_ignore` are not reported,
+    /// and keys with an empty text in the base language are never reported as untranslated.
+    ///
+    /// Locs load in alphabetical order and the last one loaded wins, so if a key is in more than one Loc of the same language,
+    /// its text is taken from the last one.
+    pub fn from_pack(pack: &Pack, detection: LanguageDetection, base_language: &str, keys_to_ignore: &HashSet<String>) -> Result<Self> {
+        let mut files = pack.files_by_type(&[FileType::Loc]);
+        files.sort_by(|a, b| a.path_in_container_raw().cmp(b.path_in_container_raw()));
+
+        let mut report = Self {
+            base_language: base_language.to_lowercase(),
+            ..Default::default()
+        };
+
+        let mut texts: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+        for file in files {
+            let path = file.path_in_container_raw();
+            let language = match detection.language(path) {
+                Some(language) => language,
+                None => {
+                    report.skipped_files.push(path.to_owned());
+                    continue;
+                }
+            };
+
+            let rows = match loc_rows(file) {
+                Ok(rows) => rows,
+                Err(_) => {
+                    report.skipped_files.push(path.to_owned());
+                    continue;
+                }
+            };
+
+            let language_texts = texts.entry(language.to_owned()).or_default();
+            language_texts.extend(rows.into_iter().filter(|(key, _)| !keys_to_ignore.contains(key)));
+            report.languages.entry(language).or_default().files += 1;
+        }
+
+        let all_keys = texts.values().flat_map(|language_texts| language_texts.keys()).collect::<BTreeSet<_>>();
+        report.total_keys = all_keys.len();
+
+        let empty = BTreeMap::new();
+        let base_texts = texts.get(&report.base_language).unwrap_or(&empty);
+        for (language, language_texts) in &texts {
+            let summary = report.languages.entry(language.to_owned()).or_default();
+            summary.keys = language_texts.len();
+
+            for key in &all_keys {
+                let base_text = base_texts.get(*key).cloned().unwrap_or_default();
+                let issue = match language_texts.get(*key) {
+                    None => TranslationIssue::Missing,
+                    Some(text) if *language != report.base_language && !base_text.is_empty() && *text == base_text => TranslationIssue::Untranslated,
+                    Some(_) => continue,
+                };
+
+                match issue {
+                    TranslationIssue::Missing => summary.missing += 1,
+                    TranslationIssue::Untranslated => summary.untranslated += 1,
+                }
+
+                report.entries.push(TranslationReportEntry {
+                    language: language.to_owned(),
+                    key: key.to_string(),
+                    issue,
+                    base_text,
+                });
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// This function returns the problems of the report as TSV, with a header line.
+    ///
+    /// Tabs and line jumps in the texts are escaped, so each problem takes exactly one line.
+    pub fn to_tsv(&self) -> String {
+        let mut tsv = String::from("language\tkey\tissue\tbase_text\n");
+
+        // Writing to a String never fails, so we can ignore the results here.
+        for entry in &self.entries {
+            let _ = writeln!(tsv, "{}\t{}\t{}\t{}", entry.language, escape_tsv(&entry.key), entry.issue, escape_tsv(&entry.base_text));
+        }
+
+        tsv
+    }
+}
+
+impl LanguageDetection {
+
+    /// This function returns the language of the Loc file at the provided path, in lowercase, if it can be found.
+    pub fn language(&self, path: &str) -> Option<String> {
+        let path = path.to_lowercase();
+        match self {
+            Self::FileSuffix => {
+                let file_name = path.rsplit('/').next()?;
+                let name = file_name.strip_suffix(".loc").unwrap_or(file_name);
+                let (_, language) = name.rsplit_once('_')?;
+                if language.is_empty() {
+                    None
+                } else {
+                    Some(language.to_owned())
+                }
+            },
+            Self::Folder => path.split('/')
+                .rev()
+                .skip(1)
+                .find_map(|folder| folder.strip_prefix(LANGUAGE_FOLDER_PREFIX))
+                .filter(|language| !language.is_empty())
+                .map(|language| language.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for TranslationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Missing => write!(f, "missing"),
+            Self::Untranslated => write!(f, "untranslated"),
+        }
+    }
+}
+
+/// This function returns the key/text pairs of the provided Loc file, decoding a copy of it if it's not yet decoded.
+fn loc_rows(file: &RFile) -> Result<Vec<(String, String)>> {
+    let mut file = file.clone();
+    file.decode(&None, true, false)?;
+
+    match file.decoded()? {
+        RFileDecoded::Loc(loc) => Ok(loc.data(&None)?
+            .iter()
+            .map(|row| (row[0].data_to_string().to_string(), row[1].data_to_string().to_string()))
+            .collect()),
+        _ => Ok(vec![]),
+    }
+}
+
+/// This function escapes tabs and line jumps in the provided string, so it fits in a single TSV cell.
+fn escape_tsv(string: &str) -> String {
+    string.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
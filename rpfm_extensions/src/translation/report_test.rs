@@ -0,0 +1,102 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the translation completeness reports.
+
+use std::collections::HashSet;
+
+use crate::test_helpers::pack_with_locs;
+
+use super::report::*;
+
+#[test]
+fn test_language_detection() {
+    assert_eq!(LanguageDetection::FileSuffix.language("text/db/my_mod_EN.loc"), Some("en".to_owned()));
+    assert_eq!(LanguageDetection::FileSuffix.language("text/db/my_mod_fr"), Some("fr".to_owned()));
+    assert_eq!(LanguageDetection::FileSuffix.language("text/db_en/mymod.loc"), None);
+    assert_eq!(LanguageDetection::FileSuffix.language("text/db/my_mod_.loc"), None);
+
+    assert_eq!(LanguageDetection::Folder.language("text/local_DE/my_mod.loc"), Some("de".to_owned()));
+    assert_eq!(LanguageDetection::Folder.language("text/local_es/db/my_mod.loc"), Some("es".to_owned()));
+    assert_eq!(LanguageDetection::Folder.language("text/db/local_en.loc"), None);
+    assert_eq!(LanguageDetection::Folder.language("text/local_/my_mod.loc"), None);
+}
+
+#[test]
+fn test_translation_report() {
+    let pack = pack_with_locs(&[
+        ("text/db/units_en.loc", &[("unit_name", "Swordsmen"), ("unit_desc", "Good at fighting"), ("empty_key", ""), ("debug_key", "Debug")]),
+        ("text/db/units_fr.loc", &[("unit_name", "Epéistes"), ("unit_desc", "Good at fighting"), ("empty_key", "")]),
+        ("text/db/extra_fr.loc", &[("fr_only_key", "Seulement")]),
+        ("text/db/units_de.loc", &[("unit_name", "Swordsmen"), ("debug_key", "Debug")]),
+        ("text/db/untagged.loc", &[("unit_name", "Swordsmen")]),
+    ]);
+
+    let mut keys_to_ignore = HashSet::new();
+    keys_to_ignore.insert("debug_key".to_owned());
+
+    let report = TranslationReport::from_pack(&pack, LanguageDetection::FileSuffix, "EN", &keys_to_ignore).unwrap();
+    assert_eq!(report.base_language(), "en");
+    assert_eq!(*report.total_keys(), 4);
+    assert_eq!(report.skipped_files(), &vec!["text/db/untagged.loc".to_owned()]);
+
+    let en = report.languages().get("en").unwrap();
+    assert_eq!((*en.files(), *en.keys(), *en.missing(), *en.untranslated()), (1, 3, 1, 0));
+
+    let fr = report.languages().get("fr").unwrap();
+    assert_eq!((*fr.files(), *fr.keys(), *fr.missing(), *fr.untranslated()), (2, 4, 0, 1));
+
+    let de = report.languages().get("de").unwrap();
+    assert_eq!((*de.files(), *de.keys(), *de.missing(), *de.untranslated()), (1, 1, 3, 1));
+
+    let issues = report.entries().iter()
+        .map(|entry| (entry.language().as_str(), entry.key().as_str(), *entry.issue()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(issues, vec![
+        ("de", "empty_key", TranslationIssue::Missing),
+        ("de", "fr_only_key", TranslationIssue::Missing),
+        ("de", "unit_desc", TranslationIssue::Missing),
+        ("de", "unit_name", TranslationIssue::Untranslated),
+        ("en", "fr_only_key", TranslationIssue::Missing),
+        ("fr", "unit_desc", TranslationIssue::Untranslated),
+    ]);
+}
+
+#[test]
+fn test_translation_report_folder_detection() {
+    let pack = pack_with_locs(&[
+        ("text/local_en/units.loc", &[("unit_name", "Swordsmen")]),
+        ("text/local_en/units_override.loc", &[("unit_name", "Greatswords")]),
+        ("text/local_es/units.loc", &[("unit_name", "Greatswords")]),
+        ("text/db/units_en.loc", &[("unit_name", "Swordsmen")]),
+    ]);
+
+    let report = TranslationReport::from_pack(&pack, LanguageDetection::Folder, "en", &HashSet::new()).unwrap();
+    assert_eq!(report.languages().len(), 2);
+    assert_eq!(*report.languages().get("en").unwrap().files(), 2);
+    assert_eq!(report.skipped_files(), &vec!["text/db/units_en.loc".to_owned()]);
+
+    // The last loc loaded wins, so the text compared is the one from the override.
+    assert_eq!(report.entries().len(), 1);
+    assert_eq!(report.entries()[0].base_text(), "Greatswords");
+    assert_eq!(*report.entries()[0].issue(), TranslationIssue::Untranslated);
+}
+
+#[test]
+fn test_translation_report_to_tsv() {
+    let pack = pack_with_locs(&[
+        ("text/db/units_en.loc", &[("unit_name", "Sword\tsmen\nof \\Reikland")]),
+        ("text/db/units_fr.loc", &[]),
+    ]);
+
+    let report = TranslationReport::from_pack(&pack, LanguageDetection::FileSuffix, "en", &HashSet::new()).unwrap();
+    assert_eq!(report.to_tsv(), "language\tkey\tissue\tbase_text\nfr\tunit_name\tmissing\tSword\\tsmen\\nof \\\\Reikland\n");
+}
@@ -0,0 +1,131 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for exporting/importing Loc files to/from PO files.
+
+use rpfm_lib::files::pack::Pack;
+use rpfm_lib::games::pfh_version::PFHVersion;
+
+use crate::test_helpers::pack_with_locs;
+
+use super::*;
+
+/// This function returns the key/text pairs of the Loc at the provided path.
+fn loc_entries(pack: &mut Pack, path: &str) -> Vec<(String, String)> {
+    match decoded_loc(pack, path).unwrap() {
+        Some(RFileDecoded::Loc(loc)) => loc.data(&None).unwrap().iter().map(|row| (row[0].data_to_string().to_string(), row[1].data_to_string().to_string())).collect(),
+        _ => panic!("No loc at {}", path),
+    }
+}
+
+#[test]
+fn test_escape_string() {
+    assert_eq!(escape_string("Karl Franz"), "\"Karl Franz\"");
+    assert_eq!(escape_string("The \"Emperor\"\tC:\\Altdorf"), "\"The \\\"Emperor\\\"\\tC:\\\\Altdorf\"");
+    assert_eq!(escape_string("First line\nSecond line"), "\"\"\n\"First line\\n\"\n\"Second line\"");
+    assert_eq!(escape_string("Ends with a line jump\n"), "\"Ends with a line jump\\n\"");
+    assert_eq!(parse_string("\"\\a\\b\\f\\v\\r\"", 1).unwrap(), "\u{07}\u{08}\u{0C}\u{0B}\r");
+    assert!(parse_string("\"Invalid \\q escape\"", 1).is_err());
+    assert!(parse_string("\"Unescaped \" quote\"", 1).is_err());
+    assert!(parse_string("\"Unfinished escape\\\"", 1).is_err());
+    assert!(parse_string("No quotes", 1).is_err());
+}
+
+#[test]
+fn test_po_round_trip() {
+    let mut po = Po::default();
+    po.set_language("es".to_owned());
+    po.entries_mut().push(PoEntry::new("simple_key", "Simple text", &["text/db/a.loc".to_owned()]));
+    po.entries_mut().push(PoEntry::new("quotes_key", "He said \"For the Emperor!\"", &["text/db/a.loc".to_owned(), "text/db/b.loc".to_owned()]));
+    po.entries_mut().push(PoEntry::new("lines_key", "Line one\nLine two\r\n\tIndented \\ backslash\n", &[]));
+    po.entries_mut().push(PoEntry::new("untranslated_key", "", &[]));
+
+    let data = po.to_po_string();
+    assert!(data.starts_with("msgid \"\"\nmsgstr \"\"\n"));
+    assert!(data.contains("\"Language: es\\n\"\n"));
+    assert!(data.contains("#: text/db/a.loc\n#: text/db/b.loc\nmsgid \"quotes_key\"\nmsgstr \"He said \\\"For the Emperor!\\\"\"\n"));
+
+    let decoded = Po::from_po_str(&data).unwrap();
+    assert_eq!(decoded, po);
+}
+
+#[test]
+fn test_po_parse_gettext_style() {
+    let data = r#"# Translator comment
+msgid ""
+msgstr ""
+"Language: es\n"
+
+#. Extracted comment
+#: text/db/a.loc
+#, fuzzy
+msgctxt "context"
+msgid "split_"
+"key"
+msgstr ""
+"Texto "
+"partido"
+
+#~ msgid "obsolete_key"
+#~ msgstr "Obsoleto"
+msgid "last_key"
+msgstr "Último"
+"#;
+
+    let po = Po::from_po_str(data).unwrap();
+    assert_eq!(po.language(), "es");
+    assert_eq!(po.entries().len(), 2);
+    assert_eq!(po.entries()[0], PoEntry::new("split_key", "Texto partido", &["text/db/a.loc".to_owned()]));
+    assert_eq!(po.entries()[1], PoEntry::new("last_key", "Último", &[]));
+
+    assert!(Po::from_po_str("msgid \"key\"\nmsgid_plural \"keys\"\nmsgstr[0] \"\"\n").is_err());
+    assert!(Po::from_po_str("msgstr \"text\"\n").is_err());
+    assert!(Po::from_po_str("msgid \"key\"\n").is_err());
+    assert!(Po::from_po_str("msgid \"key\"\nmsgstr \"text\"\nwhatever\n").is_err());
+}
+
+#[test]
+fn test_po_pack_export_import() {
+    let mut pack = pack_with_locs(&[
+        ("text/db/b.loc", &[("shared_key", "Later text"), ("b_key", "B text")]),
+        ("text/db/a.loc", &[("shared_key", "Earlier text"), ("a_key", "A \"quoted\"\ntext")]),
+    ]);
+
+    let po = Po::from_pack(&mut pack).unwrap();
+    assert_eq!(po.entries(), &vec![
+        PoEntry::new("shared_key", "Later text", &["text/db/a.loc".to_owned(), "text/db/b.loc".to_owned()]),
+        PoEntry::new("a_key", "A \"quoted\"\ntext", &["text/db/a.loc".to_owned()]),
+        PoEntry::new("b_key", "B text", &["text/db/b.loc".to_owned()]),
+    ]);
+
+    // Translate it, going through the PO format.
+    let mut translated = Po::from_po_str(&po.to_po_string()).unwrap();
+    translated.entries_mut()[0].text_mut().push_str(" (ES)");
+    translated.entries_mut()[2].text_mut().clear();
+    translated.entries_mut().push(PoEntry::new("new_key", "Nuevo", &[]));
+
+    let (updated, added, modified_paths, added_paths) = translated.import_to_pack(&mut pack, "text/db/translation.loc").unwrap();
+    assert_eq!(updated, 2);
+    assert_eq!(added, 1);
+    assert_eq!(modified_paths.len(), 2);
+    assert_eq!(added_paths, vec![ContainerPath::File("text/db/translation.loc".to_owned())]);
+
+    assert_eq!(loc_entries(&mut pack, "text/db/a.loc"), vec![
+        ("shared_key".to_owned(), "Later text (ES)".to_owned()),
+        ("a_key".to_owned(), "A \"quoted\"\ntext".to_owned()),
+    ]);
+    assert_eq!(loc_entries(&mut pack, "text/db/b.loc"), vec![
+        ("shared_key".to_owned(), "Later text (ES)".to_owned()),
+        ("b_key".to_owned(), "B text".to_owned()),
+    ]);
+    assert_eq!(loc_entries(&mut pack, "text/db/translation.loc"), vec![("new_key".to_owned(), "Nuevo".to_owned())]);
+
+    assert!(Po::from_pack(&mut Pack::new_with_version(PFHVersion::PFH5)).is_err());
+}
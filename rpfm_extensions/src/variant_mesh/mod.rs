@@ -0,0 +1,128 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the code to extract the asset paths referenced by VariantMeshDefinitions.
+//!
+//! VariantMeshDefinitions are namespace-less XML files, referencing rigidmodels, textures and other
+//! VariantMeshDefinitions through attributes. Any attribute with a value that looks like a file path
+//! (a path with folders and a file extension) is considered a reference.
+//!
+//! Older games use uppercase element names (`VARIANT_MESH`) while newer games use lowercase ones
+//! (`variant_mesh`), so element names are compared case-insensitively.
+
+use getset::Getters;
+use serde_derive::{Serialize, Deserialize};
+use xml::common::Position;
+use xml::reader::{EventReader, XmlEvent};
+
+use std::fmt::{Display, Formatter};
+
+#[cfg(test)] mod variant_mesh_test;
+
+/// Name of the root element of a VariantMeshDefinition, in lowercase.
+const ROOT_ELEMENT: &str = "variant_mesh";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains an asset path referenced by a VariantMeshDefinition.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct VariantMeshReference {
+
+    /// Line of the element referencing the path, starting at 1.
+    line: usize,
+
+    /// Attribute containing the path.
+    attribute: String,
+
+    /// Referenced path, with forward slashes and without leading slashes.
+    path: String,
+}
+
+/// This struct contains the error that stopped a VariantMeshDefinition from being parsed.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct VariantMeshParseError {
+
+    /// Line of the error, starting at 1.
+    line: usize,
+
+    /// Column of the error, starting at 1.
+    column: usize,
+
+    /// Message explaining the error.
+    message: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl Display for VariantMeshParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// This function returns all the asset paths referenced by the provided VariantMeshDefinition, in the order they appear.
+///
+/// If the file is not valid XML, or its root element is not a `VARIANT_MESH`, only the first error found is returned.
+pub fn references(contents: &str) -> Result<Vec<VariantMeshReference>, VariantMeshParseError> {
+    let mut reader = EventReader::from_str(contents);
+    let mut references = vec![];
+    let mut is_root = true;
+
+    loop {
+        match reader.next() {
+            Ok(XmlEvent::StartElement { name, attributes, .. }) => {
+                let position = reader.position();
+                if is_root && !name.local_name.eq_ignore_ascii_case(ROOT_ELEMENT) {
+                    return Err(VariantMeshParseError {
+                        line: position.row as usize + 1,
+                        column: position.column as usize + 1,
+                        message: format!("The root element is \"{}\", but it should be \"VARIANT_MESH\".", name.local_name),
+                    });
+                }
+
+                is_root = false;
+                references.extend(attributes.iter()
+                    .filter_map(|attribute| asset_path(&attribute.value).map(|path| VariantMeshReference {
+                        line: position.row as usize + 1,
+                        attribute: attribute.name.local_name.to_owned(),
+                        path,
+                    }))
+                );
+            }
+            Ok(XmlEvent::EndDocument) => return Ok(references),
+            Ok(_) => {}
+            Err(error) => {
+                let position = error.position();
+                return Err(VariantMeshParseError {
+                    line: position.row as usize + 1,
+                    column: position.column as usize + 1,
+                    message: error.msg().to_owned(),
+                });
+            }
+        }
+    }
+}
+
+/// This function returns the provided attribute value as a normalised path, if it looks like a path to a file.
+fn asset_path(value: &str) -> Option<String> {
+    let path = value.trim().replace('\\', "/");
+    let path = path.trim_start_matches('/');
+
+    match path.rsplit_once('/') {
+        Some((folder, file_name)) if !folder.is_empty() && file_name.contains('.') && !file_name.ends_with('.') => Some(path.to_owned()),
+        _ => None,
+    }
+}
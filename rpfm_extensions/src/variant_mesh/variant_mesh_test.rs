@@ -0,0 +1,56 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the VariantMeshDefinition reference extraction.
+
+use super::*;
+
+#[test]
+fn test_references() {
+    let legacy = r#"<?xml version="1.0"?>
+<VARIANT_MESH>
+    <SLOT name="body" probability="100">
+        <VARIANT_MESH model="VariantMeshes\wh_variantmodels\hu1\emp\body_01.rigid_model_v2" mask_image="variantmeshes/wh_variantmodels/hu1/emp/tex/body_mask.dds" weight="1"/>
+    </SLOT>
+    <SLOT name="head">
+        <VARIANT_MESH definition="/variantmeshes/variantmeshdefinitions/emp_head.variantmeshdefinition"/>
+        <VARIANT_MESH model="none" name="head_01.rigid_model_v2"/>
+    </SLOT>
+</VARIANT_MESH>
+"#;
+
+    assert_eq!(references(legacy).unwrap(), vec![
+        VariantMeshReference { line: 4, attribute: "model".to_owned(), path: "VariantMeshes/wh_variantmodels/hu1/emp/body_01.rigid_model_v2".to_owned() },
+        VariantMeshReference { line: 4, attribute: "mask_image".to_owned(), path: "variantmeshes/wh_variantmodels/hu1/emp/tex/body_mask.dds".to_owned() },
+        VariantMeshReference { line: 7, attribute: "definition".to_owned(), path: "variantmeshes/variantmeshdefinitions/emp_head.variantmeshdefinition".to_owned() },
+    ]);
+
+    // Newer games use lowercase element names.
+    let lowercase = r#"<variant_mesh>
+    <slot name="body">
+        <variant_mesh model="variantmeshes/wh_variantmodels/hu1/emp/body_01.rigid_model_v2"/>
+    </slot>
+</variant_mesh>"#;
+
+    assert_eq!(references(lowercase).unwrap().len(), 1);
+}
+
+#[test]
+fn test_references_errors() {
+    let malformed = "<VARIANT_MESH>\n    <SLOT name=\"body\">\n    </VARIANT_MESH>\n";
+    let error = references(malformed).unwrap_err();
+    assert_eq!(*error.line(), 3);
+    assert!(error.column() > &1);
+
+    let wrong_root = "<SLOT name=\"body\"/>";
+    let error = references(wrong_root).unwrap_err();
+    assert_eq!(*error.line(), 1);
+    assert_eq!(*error.column(), 1);
+}
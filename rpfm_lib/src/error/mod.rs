@@ -82,6 +82,18 @@ pub enum RLibError {
     #[error("Launch commands for game \"{0}\", install type \"{1}\" are not currently supported.")]
     GameInstallLaunchNotSupported(String, String),
 
+    #[error("Loading mods through a mod list is not currently supported for game \"{0}\".")]
+    GameModListNotSupported(String),
+
+    #[error("The folder where \"{0}\" stores its mod list could not be found. Launch the game at least once, and if this keeps happening, your installation may not be supported.")]
+    GameModListFolderNotFound(String),
+
+    #[error("\"{0}\" can only load mods from its data folder, but the following Pack is not there: {1}.")]
+    GameModListPackNotInData(String, String),
+
+    #[error("There is no backup of the mod list of \"{0}\" to restore.")]
+    GameModListBackupNotFound(String),
+
     #[error("Error trying to convert the following value to a bool: {0}.")]
     ParseBoolError(String),
 
@@ -98,18 +110,31 @@ pub enum RLibError {
     #[error("The indexes of the Pack are incomplete, unsupported or damaged")]
     PackIndexesNotComplete,
 
+    #[error("The file \"{path}\" of the Pack's index points to data out of the Pack: {size} bytes at offset {offset}.")]
+    PackIndexEntryOutOfBounds {
+        path: String,
+        offset: u64,
+        size: u64,
+    },
+
     #[error("Unknown PFH File Type: {0}")]
     UnknownPFHFileType(String),
 
     #[error("Unknown PFH Version: {0}")]
     UnknownPFHVersion(String),
 
+    #[error("The header of this {0} Pack has flags that are not valid for it: {1}")]
+    PackFlagsNotSupported(String, String),
+
     #[error("Unknown ESF Signature: {0}")]
     UnknownESFSignature(String),
 
     #[error("This is either not a Text File, or a Text File using an unsupported encoding")]
     DecodingTextUnsupportedEncodingOrNotATextFile,
 
+    #[error("The character '{1}' in line {2} cannot be represented in the {0:?} encoding.")]
+    EncodingTextUnrepresentableCharacter(crate::files::text::Encoding, char, usize),
+
     #[error("This file has an unknown/unsupported version: {0}.")]
     DecodingAnimsTableUnknownVersion(i32),
 
@@ -119,6 +144,30 @@ pub enum RLibError {
     #[error("Incorrect/Unknown Frame size.")]
     DecodingCAVP8IncorrectOrUnknownFrameSize,
 
+    #[error("Unsupported ca_vp8 version {0}.")]
+    CAVP8UnsupportedVersion(u16),
+
+    #[error("The provided file is not an IVF video.")]
+    DecodingIVFNotAnIVFFile,
+
+    #[error("Unsupported IVF codec: {0}. Only VP8 (VP80) videos can be imported.")]
+    DecodingIVFUnsupportedCodec(String),
+
+    #[error("The IVF video has no frames.")]
+    DecodingIVFNoFrames,
+
+    #[error("The header of the frame {0} is out of bounds. The frame table is probably corrupted or the file is truncated.")]
+    DecodingIVFFrameHeaderOutOfBounds(u32),
+
+    #[error("The frame {0} reports a size of {1} bytes, but there are only {2} bytes left in the file. The frame table is probably corrupted or the file is truncated.")]
+    DecodingIVFFrameOutOfBounds(u32, u32, u64),
+
+    #[error("The frame {0} is empty.")]
+    DecodingIVFEmptyFrame(u32),
+
+    #[error("The first frame of the video is not a key frame.")]
+    DecodingIVFFirstFrameNotKeyFrame,
+
     #[error("Unsupported signature: {0:#X}{1:#X}.")]
     DecodingESFUnsupportedSignature(u8, u8),
 
@@ -152,6 +201,12 @@ pub enum RLibError {
     #[error("Missing or invalid extra data provided: \"{0}\"")]
     DecodingMissingExtraDataField(String),
 
+    #[error("There is no decoder registered for the file \"{0}\".")]
+    CustomDecoderNotFound(String),
+
+    #[error("Error while decoding a file with a registered decoder: {0}")]
+    CustomDecodingError(String),
+
     #[error("Error while trying to save a row from a table: We expected a row with \"{0}\" fields, but we got a row with \"{1}\" fields instead.")]
     TableRowWrongFieldCount(usize, usize),
 
@@ -173,9 +228,18 @@ pub enum RLibError {
     #[error("This is either not a Matched Combat Table, or it's a Matched Combat Table but it's corrupted.")]
     DecodingMatchedCombatNotAMatchedCombatTable,
 
+    #[error("This Matched Combat file has an unsupported version: {0}.")]
+    DecodingMatchedCombatVersionNotSupported(i32),
+
+    #[error("This AnimFragment file has an unsupported version: {0}.")]
+    DecodingAnimFragmentVersionNotSupported(i32),
+
     #[error("This is either not an Unit Variant, or it's an Unit Variant but it's corrupted.")]
     DecodingUnitVariantNotAUnitVariant,
 
+    #[error("This is either not a SpeedTree model, or it's a SpeedTree model but its header is corrupted.")]
+    DecodingSpeedTreeNotASpeedTree,
+
     #[error("This file's reported size is '{0}' bytes, but we expected it to be '{1}' bytes. This means that the definition of the table is incorrect (only on tables, it's usually this), the decoding logic in RPFM is broken for this file, or this file is corrupted.")]
     DecodingMismatchSizeError(usize, usize),
 
@@ -203,15 +267,33 @@ pub enum RLibError {
     #[error("The following file hasn't yet been decoded: {0}.")]
     FileNotDecoded(String),
 
+    #[error("The following file is already loaded to memory, so its data as stored on disk is not available: {0}.")]
+    FileNotOnDisk(String),
+
     #[error("The following file hasn't yet been cached: {0}.")]
     FileNotCached(String),
 
+    #[error("The following file has already been decoded, so its raw data is not available without encoding it first: {0}.")]
+    FileAlreadyDecoded(String),
+
     #[error("Operation not allowed: reserved file detected.")]
     ReservedFiles,
 
     #[error("Operation not allowed: destiny is blank for your file.")]
     EmptyDestiny,
 
+    #[error("Operation not allowed: the following paths already exist, or more than one file would end up on them: {}.", .0.join(", "))]
+    RenamePathsCollide(Vec<String>),
+
+    #[error("Operation not allowed: the folder {0} cannot be moved into itself.")]
+    MoveFolderIntoItself(String),
+
+    #[error("The file {0} cannot be duplicated as a table. Only DB and Loc tables can.")]
+    DuplicateTableNotATable(String),
+
+    #[error("The path \"{1}\" is not valid for a copy of \"{0}\". Copies of DB tables must stay in the folder of their table, and copies of Loc files must stay in the \"text\" folder.")]
+    DuplicateTableInvalidPath(String, String),
+
     #[error("No Packs provided.")]
     NoPacksProvided,
 
@@ -227,6 +309,9 @@ pub enum RLibError {
     #[error("The file with the path {0} hasn't been found in the dependencies cache.")]
     DependenciesCacheFileNotFound(String),
 
+    #[error("The dependencies cache was generated by another version of RPFM (cache version {0}, expected {1}), and it needs regenerating.")]
+    DependenciesCacheVersionMismatch(u32, u32),
+
     #[error("This table already has the newer definition available.")]
     NoDefinitionUpdateAvailable,
 
@@ -251,6 +336,9 @@ pub enum RLibError {
     #[error("This TSV file has an error in the row {0}, field {1} (both starting at 0). Please, check it and make sure the value in that field is a valid value for that column.")]
     ImportTSVIncorrectRow(usize, usize),
 
+    #[error("This TSV file has an error in the line {0}, column {1} (both starting at 1). Please, check it and make sure the value in that field is a valid value for that column.")]
+    ImportTSVIncorrectLine(usize, usize),
+
     #[error("This TSV file either belongs to another table, to a localisation File, it's broken or it's incompatible with RPFM.")]
     ImportTSVWrongTypeTable,
 
@@ -260,6 +348,9 @@ pub enum RLibError {
     #[error("This TSV file has an invalid or missing file path value at line 1.")]
     ImportTSVInvalidOrMissingPath,
 
+    #[error("The file {0} cannot be exported as an Assembly Kit table. Only DB tables can.")]
+    FileNotAssemblyKitCompatible(String),
+
     #[error("You need to pass more than one file to merge.")]
     RFileMergeOnlyOneFileProvided,
 
@@ -269,6 +360,9 @@ pub enum RLibError {
     #[error("Merging tables with different table names is not supported.")]
     RFileMergeTablesDifferentNames,
 
+    #[error("Merging tables with different definitions is not supported. The following tables don't share table name and version with {0}: {1}.")]
+    RFileMergeTablesDifferentDefinitions(String, String),
+
     #[error("Merging files of type {0} is not supported.")]
     RFileMergeNotSupportedForType(String),
 
@@ -278,6 +372,63 @@ pub enum RLibError {
     #[error("There are not files in this Pack that could be patched/deleted.")]
     PatchSiegeAINoPatchableFiles,
 
+    #[error("The signing key at {0} is not a valid ed25519 private key.")]
+    SigningInvalidPrivateKey(String),
+
+    #[error("The public key at {0} is not a valid ed25519 public key.")]
+    SigningInvalidPublicKey(String),
+
+    #[error("The signature file is not a valid Pack signature.")]
+    SigningInvalidSignature,
+
+    #[error("The signature doesn't match the provided public key. Either the Pack was signed with another key, or the signature file has been altered.")]
+    SigningSignatureMismatch,
+
+    #[error("The signature is valid, but the Pack has been altered since it was signed. Files added, removed or modified:\n{0}")]
+    SigningPackAltered(String),
+
+    #[error("The operation has been cancelled.")]
+    OperationCancelled,
+
+    #[error("There are no Loc files in this Pack to export.")]
+    PoNoLocsToExport,
+
+    #[error("Error parsing the PO file at line {0}: {1}")]
+    PoParseError(usize, String),
+
+    #[error("The new key cannot be empty, nor equal to the key being cloned.")]
+    DeepCloneInvalidNewKey,
+
+    #[error("There is no row with the key \"{0}\" in the table \"{1}\".")]
+    DeepCloneKeyNotFound(String, String),
+
+    #[error("There is already a row with the key \"{0}\" in the table \"{1}\".")]
+    DeepCloneKeyAlreadyExists(String, String),
+
+    #[error("The references couldn't be updated because of the file \"{0}\", so no file has been edited: {1}")]
+    CascadeEditionFailed(String, String),
+
+    #[error("The template parameter \"{0}\" has no value.")]
+    TemplateParameterMissing(String),
+
+    #[error("The table \"{0}\" doesn't have a column named \"{1}\".")]
+    TemplateColumnNotFound(String, String),
+
+    #[error("The value \"{2}\" is not valid for the column \"{1}\" of the table \"{0}\".")]
+    TemplateInvalidValue(String, String, String),
+
+    #[error("The template creates the file \"{0}\" more than once.")]
+    TemplateDuplicatedPath(String),
+
+    #[error("The Game Selected doesn't have a folder for downloaded mods, or it hasn't been found.")]
+    DownloadedModsFolderNotFound,
+
+    #[error("Error parsing the KeyValues file: {0}")]
+    ParseKeyValuesError(String),
+
+    #[error("The file {0} cannot be diffed against its dependencies. Only DB and Loc tables can.")]
+    TableDiffNotATable(String),
+
     /// Represents all other cases of `std::io::Error`.
     #[error(transparent)]
     IOError(#[from] std::io::Error),
@@ -303,6 +454,10 @@ pub enum RLibError {
     #[error(transparent)]
     JsonError(#[from] serde_json::Error),
 
+    /// Represents all other cases of `regex::Error`.
+    #[error(transparent)]
+    RegexError(#[from] regex::Error),
+
     /// Represents all other cases of `std::array::TryFromSliceError`.
     #[error(transparent)]
     TryFromSliceError(#[from] std::array::TryFromSliceError),
@@ -13,24 +13,25 @@
 use std::io::{BufReader, BufWriter, Write};
 use std::fs::File;
 
-use crate::binary::ReadBytes;
+use crate::binary::{ReadBytes, WriteBytes};
+use crate::error::RLibError;
 use crate::files::*;
+use crate::files::table::DecodedData;
+use crate::games::{GameInfo, supported_games::{KEY_WARHAMMER_2, KEY_WARHAMMER_3, SupportedGames}};
 
-use super::AnimFragment;
+use super::{AnimFragment, VERSION_WARHAMMER_2, VERSION_WARHAMMER_3};
 
-#[test]
-fn test_encode_anim_fragment_frg() {
-    let path_1 = "../test_files/test_decode_anim_fragment.frg";
-    let path_2 = "../test_files/test_encode_anim_fragment.frg";
+/// This function decodes the file at `path_1` with the provided game, then encodes it to `path_2` and checks both are equal.
+fn test_encode_anim_fragment(path_1: &str, path_2: &str, game_info: Option<&GameInfo>) -> AnimFragment {
     let mut reader = BufReader::new(File::open(path_1).unwrap());
 
     let mut decodeable_extra_data = DecodeableExtraData::default();
     decodeable_extra_data.file_name = Some("test_decode_anim_fragment.frg");
+    decodeable_extra_data.game_info = game_info;
 
     let data_len = reader.len().unwrap();
     let before = reader.read_slice(data_len as usize, true).unwrap();
     let mut data = AnimFragment::decode(&mut reader, &Some(decodeable_extra_data)).unwrap();
-    dbg!(&data);
 
     let mut after = vec![];
     data.encode(&mut after, &None).unwrap();
@@ -39,4 +40,73 @@ fn test_encode_anim_fragment_frg() {
     writer.write_all(&after).unwrap();
 
     assert_eq!(before, after);
+    data
+}
+
+#[test]
+fn test_encode_anim_fragment_wh2() {
+    let games = SupportedGames::default();
+    let data = test_encode_anim_fragment(
+        "../test_files/test_decode_anim_fragment_wh2.frg",
+        "../test_files/test_encode_anim_fragment_wh2.frg",
+        games.game(KEY_WARHAMMER_2)
+    );
+
+    assert_eq!(*data.definition().version(), VERSION_WARHAMMER_2);
+    assert_eq!(data.skeleton_1(), "humanoid01");
+    assert_eq!(*data.max_id(), 12);
+    assert_eq!(data.data().unwrap().len(), 2);
+}
+
+#[test]
+fn test_encode_anim_fragment_wh3() {
+    let games = SupportedGames::default();
+    let data = test_encode_anim_fragment(
+        "../test_files/test_decode_anim_fragment_wh3.frg",
+        "../test_files/test_encode_anim_fragment_wh3.frg",
+        games.game(KEY_WARHAMMER_3)
+    );
+
+    assert_eq!(*data.definition().version(), VERSION_WARHAMMER_3);
+    assert_eq!(data.name(), "hu1_swsh");
+    assert_eq!(data.skeleton_name(), "humanoid01");
+    assert_eq!(data.data().unwrap()[1][4], DecodedData::Boolean(true));
+}
+
+#[test]
+fn test_edit_anim_fragment_wh3() {
+    let games = SupportedGames::default();
+    let mut decodeable_extra_data = DecodeableExtraData::default();
+    decodeable_extra_data.file_name = Some("test_decode_anim_fragment.frg");
+    decodeable_extra_data.game_info = games.game(KEY_WARHAMMER_3);
+    let decodeable_extra_data = Some(decodeable_extra_data);
+
+    let mut reader = BufReader::new(File::open("../test_files/test_decode_anim_fragment_wh3.frg").unwrap());
+    let mut data = AnimFragment::decode(&mut reader, &decodeable_extra_data).unwrap();
+
+    let mut rows = data.data().unwrap().to_vec();
+    rows[0][2] = DecodedData::F32(0.5);
+    data.set_data(&rows).unwrap();
+
+    // The edited data must be saved with the same layout, so it can be read again.
+    let mut after = vec![];
+    data.encode(&mut after, &None).unwrap();
+
+    let mut reader = std::io::Cursor::new(after);
+    let data_2 = AnimFragment::decode(&mut reader, &decodeable_extra_data).unwrap();
+    assert_eq!(data, data_2);
+}
+
+#[test]
+fn test_decode_anim_fragment_unsupported_version() {
+    let games = SupportedGames::default();
+    let mut decodeable_extra_data = DecodeableExtraData::default();
+    decodeable_extra_data.file_name = Some("test_decode_anim_fragment.frg");
+    decodeable_extra_data.game_info = games.game(KEY_WARHAMMER_3);
+
+    let mut data = vec![];
+    data.write_i32(99).unwrap();
+    let mut reader = std::io::Cursor::new(data);
+
+    assert!(matches!(AnimFragment::decode(&mut reader, &Some(decodeable_extra_data)), Err(RLibError::DecodingAnimFragmentVersionNotSupported(99))));
 }
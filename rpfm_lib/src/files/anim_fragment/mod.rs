@@ -8,31 +8,43 @@
 // https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
-//! Matched Combat files are tables containing data about matched animations between units.
+//! AnimFragment files are tables containing the animations a skeleton can use for each animation slot.
 //!
-//! They're used for string translation in all Total War games since Empire. One thing to take into account
-//! when you're using a language other than english is that in all games up to Troy, the game will only load
-//! the main `localisation.loc` file. It'll not load individual loc files.
+//! Their layout depends on the game: Warhammer 2 fragments have no version number, while Warhammer 3
+//! fragments start with one. Because of that, the game the file is for must be provided to decode them.
 //!
-//! # Loc Structure
+//! # AnimFragment Structure (Warhammer 2)
 //!
 //! ## Header
 //!
-//! | Bytes | Type     | Data                                           |
-//! | ----- | -------- | ---------------------------------------------- |
-//! | 2     | [u16]    | Byteorder mark. Always 0xFF0xFE.               |
-//! | 3     | StringU8 | FileType String. Always LOC.                   |
-//! | 1     | [u8]     | Unknown, always 0. Maybe part of the fileType? |
-//! | 4     | [u32]    | Version of the table. Always 1.                |
-//! | 4     | [u32]    | Amount of entries on the table.                |
+//! | Bytes | Type           | Data                                |
+//! | ----- | -------------- | ----------------------------------- |
+//! | *     | Sized StringU8 | Skeleton 1.                         |
+//! | *     | Sized StringU8 | Skeleton 2.                         |
+//! | 4     | [i32]          | Min Id.                             |
+//! | 4     | [i32]          | Max Id.                             |
+//! | 1     | [bool]         | Unknown.                            |
+//! | 4     | [u32]          | Amount of entries on the table.     |
+//!
+//! # AnimFragment Structure (Warhammer 3)
+//!
+//! ## Header
+//!
+//! | Bytes | Type           | Data                                |
+//! | ----- | -------------- | ----------------------------------- |
+//! | 4     | [i32]          | Version of the table. Always 4.     |
+//! | 4     | [i32]          | Subversion of the table.            |
+//! | *     | Sized StringU8 | Name.                               |
+//! | *     | Sized StringU8 | Mount table name.                   |
+//! | *     | Sized StringU8 | Unmount table name.                 |
+//! | *     | Sized StringU8 | Skeleton name.                      |
+//! | *     | Sized StringU8 | Locomotion graph.                   |
+//! | *     | Sized StringU8 | Unknown.                            |
+//! | 4     | [u32]          | Amount of entries on the table.     |
 //!
 //! ## Data
 //!
-//! | Bytes | Type            | Data              |
-//! | ----- | --------------- | ----------------- |
-//! | *     | Sized StringU16 | Localisation key. |
-//! | *     | Sized StringU16 | Localised string. |
-//! | 1     | [bool]          | Unknown.          |
+//! The entries of both versions are described by the definitions returned by [AnimFragment::new_definition].
 
 use getset::{Getters, Setters};
 use serde_derive::{Serialize, Deserialize};
@@ -43,64 +55,79 @@ use std::collections::{BTreeMap, HashMap};
 use crate::binary::{ReadBytes, WriteBytes};
 use crate::error::{RLibError, Result};
 use crate::files::{DecodeableExtraData, Decodeable, EncodeableExtraData, Encodeable, table::{DecodedData, Table}};
+use crate::games::supported_games::KEY_WARHAMMER_3;
 use crate::schema::*;
 use crate::utils::check_size_mismatch;
 
-/// Size of the header of an AnimFragment PackedFile.
-pub const HEADER_SIZE: usize = 0;
-
 /// Base path of an animation table. This is an special type of bin, stored only in this folder.
 pub const BASE_PATH: &str = "animations";
 
 /// Extension of AnimFragment PackedFiles.
 pub const EXTENSIONS: [&str; 2] = [".frg", ".bin"];
 
-//#[cfg(test)] mod anim_fragment_test;
+/// Version used for the Warhammer 2 layout, which doesn't store its version in the file.
+pub const VERSION_WARHAMMER_2: i32 = 2;
+
+/// Version used for the Warhammer 3 layout.
+pub const VERSION_WARHAMMER_3: i32 = 4;
+
+#[cfg(test)] mod anim_fragment_test;
 
 //---------------------------------------------------------------------------//
 //                              Enum & Structs
 //---------------------------------------------------------------------------//
 
-/// This stores the data of a decoded matched combat file in memory.
+/// This stores the data of a decoded AnimFragment file in memory.
+///
+/// Which header fields are used depends on the version of the table's definition.
 #[derive(PartialEq, Clone, Debug, Getters, Setters, Serialize, Deserialize)]
 #[getset(get = "pub", set = "pub")]
 pub struct AnimFragment {
 
+    // Warhammer 2 header fields.
     skeleton_1: String,
     skeleton_2: String,
     min_id: i32,
     max_id: i32,
     unknown_bool: bool,
 
+    // Warhammer 3 header fields.
+    #[serde(default)]
+    subversion: i32,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    mount_table_name: String,
+    #[serde(default)]
+    unmount_table_name: String,
+    #[serde(default)]
+    skeleton_name: String,
+    #[serde(default)]
+    locomotion_graph: String,
+    #[serde(default)]
+    unknown_string: String,
+
     /// The table's data, containing all the stuff needed to decode/encode it.
     table: Table,
 }
 
 //---------------------------------------------------------------------------//
-//                      Implementation of MatchedCombat
+//                      Implementation of AnimFragment
 //---------------------------------------------------------------------------//
 
-/// Implementation of `MatchedCombat`.
+/// Implementation of `AnimFragment`.
 impl AnimFragment {
 
-    /// This function creates a new empty `MatchedCombat`.
+    /// This function creates a new empty `AnimFragment`.
     pub fn new(definition: &Definition) -> Self {
-        Self {
-            skeleton_1: String::new(),
-            skeleton_2: String::new(),
-            min_id: 0,
-            max_id: 0,
-            unknown_bool: false,
-            table: Table::new(definition, None, "", false),
-        }
+        Self::from(Table::new(definition, None, "", false))
     }
 
-    /// This function returns the definition of a Loc table.
-    pub(crate) fn new_definition(version: i32) -> Definition {
-        dbg!(version);
+    /// This function returns the definition of an AnimFragment table for the provided version.
+    pub(crate) fn new_definition(version: i32) -> Result<Definition> {
         let mut definition = Definition::new(version);
         let fields = match version {
-            2 => vec![
+            VERSION_WARHAMMER_2 => vec![
                 Field::new("id".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
                 Field::new("slot".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
                 Field::new("file_name".to_owned(), FieldType::StringU8, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
@@ -114,70 +141,80 @@ impl AnimFragment {
                 Field::new("unknown_4".to_owned(), FieldType::StringU8, false, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
                 Field::new("unknown_5".to_owned(), FieldType::Boolean, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
             ],
-            0 => vec![
-                Field::new("id".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("slot".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("file_name".to_owned(), FieldType::StringU8, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("metadata".to_owned(), FieldType::StringU8, true, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("metadata_sound".to_owned(), FieldType::StringU8, false, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("skeleton_type".to_owned(), FieldType::StringU8, false, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("blend_in_time".to_owned(), FieldType::F32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("selection_weight".to_owned(), FieldType::F32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("unknown_3".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("weapon_bone".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("unknown_4".to_owned(), FieldType::StringU8, false, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                Field::new("unknown_5".to_owned(), FieldType::Boolean, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-            ],
-            _ => todo!(),
+            VERSION_WARHAMMER_3 => {
+                let mut subdefinition = Definition::new(-1);
+                let subfields = vec![
+                    Field::new("file_path".to_owned(), FieldType::StringU8, false, None, true, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+                    Field::new("meta_file_path".to_owned(), FieldType::StringU8, false, None, true, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+                    Field::new("snd_file_path".to_owned(), FieldType::StringU8, false, None, true, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+                ];
+                subdefinition.set_fields(subfields);
+
+                vec![
+                    Field::new("animation_id".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+                    Field::new("blend_in_time".to_owned(), FieldType::F32, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+                    Field::new("selection_weight".to_owned(), FieldType::F32, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+                    Field::new("weapon_bone".to_owned(), FieldType::I32, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+                    Field::new("single_frame_variant".to_owned(), FieldType::Boolean, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+                    Field::new("anim_refs".to_owned(), FieldType::SequenceU32(Box::new(subdefinition)), false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+                ]
+            },
+            _ => return Err(RLibError::DecodingAnimFragmentVersionNotSupported(version)),
         };
 
         definition.set_fields(fields);
-        definition
+        Ok(definition)
     }
 
-    /// This function returns a reference of the definition used by the Loc table.
+    /// This function returns a reference of the definition used by the AnimFragment table.
     pub fn definition(&self) -> &Definition {
         self.table.definition()
     }
 
-    /// This function returns a reference to the entries of this Loc table.
+    /// This function returns a reference to the entries of this AnimFragment table.
     pub fn data(&self) -> Result<Cow<[Vec<DecodedData>]>> {
         self.table.data(&None)
     }
 
-    /*
-
-    /// This function replaces the definition of this table with the one provided.
-    ///
-    /// This updates the table's data to follow the format marked by the new definition, so you can use it to *update* the version of your table.
-    pub fn set_definition(&mut self, new_definition: &Definition) {
-        self.table.set_definition(new_definition);
-    }
-
     /// This function replaces the data of this table with the one provided.
     ///
     /// This can (and will) fail if the data is not of the format defined by the definition of the table.
-    pub fn set_table_data(&mut self, data: &[Vec<DecodedData>]) -> Result<()> {
-        self.table.set_table_data(data)
+    pub fn set_data(&mut self, data: &[Vec<DecodedData>]) -> Result<()> {
+        self.table.set_data(None, data)
     }
-    */
-    /// This function tries to read the header of a Matched Combat file from a reader.
-    pub fn read_header<R: ReadBytes>(data: &mut R) -> Result<(String, String, i32, i32, bool, u32)> {
 
-        // A valid Loc PackedFile has at least 14 bytes. This ensures they exists before anything else.
-        if data.len()? < HEADER_SIZE as u64 {
-            return Err(RLibError::DecodingMatchedCombatNotAMatchedCombatTable)
+    /// This function returns the version of the layout this AnimFragment uses, based on the game it's for.
+    ///
+    /// Only Warhammer 3 fragments store their version, so for any other game (or if no game is provided) we assume the Warhammer 2 layout.
+    fn read_version<R: ReadBytes>(data: &mut R, extra_data: &DecodeableExtraData) -> Result<i32> {
+        match extra_data.game_info {
+            Some(game_info) if game_info.game_key_name() == KEY_WARHAMMER_3 => data.read_i32(),
+            _ => Ok(VERSION_WARHAMMER_2),
         }
+    }
 
-        let skeleton_1 = data.read_sized_string_u8()?;
-        let skeleton_2 = data.read_sized_string_u8()?;
+    /// This function reads the header of a Warhammer 2 AnimFragment, returning the amount of entries in the table.
+    fn read_header_v2<R: ReadBytes>(&mut self, data: &mut R) -> Result<u32> {
+        self.skeleton_1 = data.read_sized_string_u8()?;
+        self.skeleton_2 = data.read_sized_string_u8()?;
+        self.min_id = data.read_i32()?;
+        self.max_id = data.read_i32()?;
+        self.unknown_bool = data.read_bool()?;
 
-        let min_id = data.read_i32()?;
-        let max_id = data.read_i32()?;
-        let unknown_bool = data.read_bool()?;
-        let entry_count = data.read_u32()?;
+        data.read_u32()
+    }
 
-        Ok((skeleton_1, skeleton_2, min_id, max_id, unknown_bool, entry_count))
+    /// This function reads the header of a Warhammer 3 AnimFragment (after the version), returning the amount of entries in the table.
+    fn read_header_v4<R: ReadBytes>(&mut self, data: &mut R) -> Result<u32> {
+        self.subversion = data.read_i32()?;
+        self.name = data.read_sized_string_u8()?;
+        self.mount_table_name = data.read_sized_string_u8()?;
+        self.unmount_table_name = data.read_sized_string_u8()?;
+        self.skeleton_name = data.read_sized_string_u8()?;
+        self.locomotion_graph = data.read_sized_string_u8()?;
+        self.unknown_string = data.read_sized_string_u8()?;
+
+        data.read_u32()
     }
 }
 
@@ -187,32 +224,49 @@ impl Decodeable for AnimFragment {
         let extra_data = extra_data.as_ref().ok_or(RLibError::DecodingMissingExtraData)?;
         let file_name = extra_data.file_name.ok_or_else(|| RLibError::DecodingMissingExtraDataField("file_name".to_string()))?;
 
-        let (skeleton_1, skeleton_2, min_id, max_id, unknown_bool, entry_count) = Self::read_header(data)?;
-        let definition = Self::new_definition(0);
-        let table = Table::decode(&None, data, &definition, &HashMap::new(), Some(entry_count), false, file_name)?;
-        dbg!(&table);
+        let version = Self::read_version(data, extra_data)?;
+        let definition = Self::new_definition(version)?;
+        let mut fragment = Self::new(&definition);
+
+        let entry_count = match version {
+            VERSION_WARHAMMER_3 => fragment.read_header_v4(data)?,
+            _ => fragment.read_header_v2(data)?,
+        };
+
+        fragment.table = Table::decode(&None, data, &definition, &HashMap::new(), Some(entry_count), false, file_name)?;
+
         // If we are not in the last byte, it means we didn't parse the entire file, which means this file is corrupt.
         check_size_mismatch(data.stream_position()? as usize, data.len()? as usize)?;
 
-        Ok(Self {
-            skeleton_1,
-            skeleton_2,
-            min_id,
-            max_id,
-            unknown_bool,
-            table,
-        })
+        Ok(fragment)
     }
 }
 
 impl Encodeable for AnimFragment {
 
     fn encode<W: WriteBytes>(&mut self, buffer: &mut W, _extra_data: &Option<EncodeableExtraData>) -> Result<()> {
-        buffer.write_sized_string_u8(&self.skeleton_1)?;
-        buffer.write_sized_string_u8(&self.skeleton_2)?;
-        buffer.write_i32(self.min_id)?;
-        buffer.write_i32(self.max_id)?;
-        buffer.write_bool(self.unknown_bool)?;
+        match *self.table.definition().version() {
+            VERSION_WARHAMMER_2 => {
+                buffer.write_sized_string_u8(&self.skeleton_1)?;
+                buffer.write_sized_string_u8(&self.skeleton_2)?;
+                buffer.write_i32(self.min_id)?;
+                buffer.write_i32(self.max_id)?;
+                buffer.write_bool(self.unknown_bool)?;
+            }
+
+            VERSION_WARHAMMER_3 => {
+                buffer.write_i32(VERSION_WARHAMMER_3)?;
+                buffer.write_i32(self.subversion)?;
+                buffer.write_sized_string_u8(&self.name)?;
+                buffer.write_sized_string_u8(&self.mount_table_name)?;
+                buffer.write_sized_string_u8(&self.unmount_table_name)?;
+                buffer.write_sized_string_u8(&self.skeleton_name)?;
+                buffer.write_sized_string_u8(&self.locomotion_graph)?;
+                buffer.write_sized_string_u8(&self.unknown_string)?;
+            }
+
+            version => return Err(RLibError::DecodingAnimFragmentVersionNotSupported(version)),
+        }
 
         buffer.write_u32(self.table.len(None)? as u32)?;
 
@@ -229,6 +283,13 @@ impl From<Table> for AnimFragment {
             min_id: 0,
             max_id: 0,
             unknown_bool: false,
+            subversion: 0,
+            name: String::new(),
+            mount_table_name: String::new(),
+            unmount_table_name: String::new(),
+            skeleton_name: String::new(),
+            locomotion_graph: String::new(),
+            unknown_string: String::new(),
             table,
         }
     }
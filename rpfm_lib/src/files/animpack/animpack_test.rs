@@ -12,6 +12,7 @@
 
 use std::io::{BufReader, BufWriter, Write};
 use std::fs::File;
+use std::path::PathBuf;
 
 use crate::binary::ReadBytes;
 use crate::files::*;
@@ -42,3 +43,134 @@ fn test_encode_animpack() {
     assert_eq!(before, after);
 }
 
+
+#[test]
+fn test_encode_animpack_deterministic() {
+    let paths = ["animations/b.bin", "Animations/A.bin", "animations/a.bin", "animations/c/d.bin", "animations/B.frg"];
+
+    let mut data = AnimPack::default();
+    for (index, path) in paths.iter().enumerate() {
+        data.insert(RFile::new_from_vec(&vec![index as u8; index + 1], FileType::Unknown, 0, path)).unwrap();
+    }
+
+    let mut before = vec![];
+    data.encode(&mut before, &None).unwrap();
+
+    // Re-insert the files in reverse order, so the internal map doesn't keep the same layout.
+    let mut data_reversed = AnimPack::default();
+    for (index, path) in paths.iter().enumerate().rev() {
+        data_reversed.insert(RFile::new_from_vec(&vec![index as u8; index + 1], FileType::Unknown, 0, path)).unwrap();
+    }
+
+    let mut after = vec![];
+    data_reversed.encode(&mut after, &None).unwrap();
+    assert_eq!(before, after);
+
+    let mut after_twice = vec![];
+    data.encode(&mut after_twice, &None).unwrap();
+    assert_eq!(before, after_twice);
+
+    // Check the count header and the per-file lengths still match the data.
+    let mut reader = std::io::Cursor::new(before);
+    assert_eq!(reader.read_u32().unwrap(), paths.len() as u32);
+
+    let mut decoded_paths = vec![];
+    for _ in 0..paths.len() {
+        let path = reader.read_sized_string_u8().unwrap();
+        let index = paths.iter().position(|x| *x == path).unwrap();
+        let len = reader.read_u32().unwrap();
+        assert_eq!(len as usize, index + 1);
+        assert_eq!(reader.read_slice(len as usize, false).unwrap(), vec![index as u8; index + 1]);
+        decoded_paths.push(path);
+    }
+
+    assert_eq!(reader.len().unwrap(), reader.position());
+    assert_eq!(decoded_paths, vec!["Animations/A.bin", "animations/a.bin", "animations/b.bin", "animations/B.frg", "animations/c/d.bin"]);
+}
+
+/// Builds an AnimPack with nested paths, including folders whose names are prefixes of other folders.
+fn animpack_with_nested_paths() -> AnimPack {
+    let paths = [
+        "animations/battle/humanoid01/attack.frg",
+        "animations/battle/humanoid01/idle.frg",
+        "animations/battle/humanoid01b/idle.frg",
+        "animations/campaign/walk.frg",
+        "animations/tables.bin",
+    ];
+
+    let mut data = AnimPack::default();
+    for path in paths {
+        data.insert(RFile::new_from_vec(path.as_bytes(), FileType::Unknown, 0, path)).unwrap();
+    }
+
+    data
+}
+
+#[test]
+fn test_animpack_files_by_path_prefix() {
+    let data = animpack_with_nested_paths();
+
+    let mut paths = data.files_by_path_prefix("animations/battle/humanoid01", false).iter().map(|file| file.path_in_container_raw().to_owned()).collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, vec!["animations/battle/humanoid01/attack.frg", "animations/battle/humanoid01/idle.frg"]);
+
+    assert_eq!(data.files_by_path_prefix("Animations/Battle/", true).len(), 3);
+    assert_eq!(data.files_by_path_prefix("Animations/Battle/", false).len(), 0);
+    assert_eq!(data.files_by_path_prefix("animations/tables.bin", false).len(), 0);
+    assert_eq!(data.files_by_path_prefix("", false).len(), 5);
+}
+
+#[test]
+fn test_animpack_extract_paths() {
+    let mut data = animpack_with_nested_paths();
+    let destination_path = PathBuf::from("../test_files/test_extract_animpack");
+    if destination_path.is_dir() {
+        std::fs::remove_dir_all(&destination_path).unwrap();
+    }
+
+    // Overlapping selections should only extract each file once, and not touch sibling folders with similar names.
+    let paths = vec![
+        ContainerPath::Folder("animations/battle/humanoid01".to_owned()),
+        ContainerPath::File("animations/battle/humanoid01/idle.frg".to_owned()),
+        ContainerPath::File("animations/tables.bin".to_owned()),
+    ];
+
+    assert_eq!(ContainerPath::dedup(&paths), vec![
+        ContainerPath::File("animations/tables.bin".to_owned()),
+        ContainerPath::Folder("animations/battle/humanoid01".to_owned()),
+    ]);
+
+    data.extract_paths(&paths, &destination_path, true, &None).unwrap();
+
+    assert_eq!(std::fs::read(destination_path.join("animations/battle/humanoid01/attack.frg")).unwrap(), b"animations/battle/humanoid01/attack.frg");
+    assert_eq!(std::fs::read(destination_path.join("animations/battle/humanoid01/idle.frg")).unwrap(), b"animations/battle/humanoid01/idle.frg");
+    assert_eq!(std::fs::read(destination_path.join("animations/tables.bin")).unwrap(), b"animations/tables.bin");
+    assert!(!destination_path.join("animations/battle/humanoid01b").exists());
+    assert!(!destination_path.join("animations/campaign").exists());
+
+    std::fs::remove_dir_all(&destination_path).unwrap();
+}
+
+#[test]
+fn test_animpack_remove_folder() {
+    let mut data = animpack_with_nested_paths();
+
+    let mut removed = data.remove(&ContainerPath::Folder("animations/battle/humanoid01".to_owned()));
+    removed.sort();
+    assert_eq!(removed, vec![
+        ContainerPath::File("animations/battle/humanoid01/attack.frg".to_owned()),
+        ContainerPath::File("animations/battle/humanoid01/idle.frg".to_owned()),
+    ]);
+
+    let mut paths = data.paths_raw();
+    paths.sort();
+    assert_eq!(paths, vec!["animations/battle/humanoid01b/idle.frg", "animations/campaign/walk.frg", "animations/tables.bin"]);
+
+    // Make sure the removal survives a save.
+    let mut encoded = vec![];
+    data.encode(&mut encoded, &None).unwrap();
+    let mut extra_data = DecodeableExtraData::default();
+    extra_data.data_size = encoded.len() as u64;
+    let decoded = AnimPack::decode(&mut std::io::Cursor::new(encoded), &Some(extra_data)).unwrap();
+    assert_eq!(decoded.files().len(), 3);
+}
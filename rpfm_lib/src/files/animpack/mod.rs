@@ -185,6 +185,7 @@ impl Decodeable for AnimPack {
                     timestamp: None,
                     file_type: FileType::AnimPack,
                     data: RFileInnerData::Cached(data),
+                    loaded_from: None,
                 };
 
                 anim_pack.files.insert(path_in_container, file);
@@ -230,8 +231,10 @@ impl Encodeable for AnimPack {
     fn encode<W: WriteBytes>(&mut self, buffer: &mut W, extra_data: &Option<EncodeableExtraData>) -> Result<()> {
         buffer.write_u32(self.files.len() as u32)?;
 
+        // Files are sorted like in Packs, so encoding the same AnimPack always results in the same bytes.
+        // Paths only differing in casing are sorted by their original casing, so their order doesn't depend on the HashMap.
         let mut sorted_files = self.files.iter_mut().collect::<Vec<(&String, &mut RFile)>>();
-        sorted_files.sort_unstable_by_key(|(path, _)| path.to_lowercase());
+        sorted_files.sort_unstable_by(|(path_a, _), (path_b, _)| path_a.to_lowercase().cmp(&path_b.to_lowercase()).then_with(|| path_a.cmp(path_b)));
 
         for (path, file) in sorted_files {
             buffer.write_sized_string_u8(path)?;
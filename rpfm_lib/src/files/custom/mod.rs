@@ -0,0 +1,161 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This is a module to read/write files with decoders registered at runtime.
+//!
+//! It allows other crates to add support for file formats this lib doesn't know about, without
+//! having to touch the lib itself. To do it, register a [CustomDecoder] with [register] at startup.
+//!
+//! Registered decoders are only used for files not detected as any of the built-in types, so they
+//! cannot replace the built-in decoders. Files detected this way are of type [FileType::Custom],
+//! and they decode into a [Custom] struct, containing the decoded data as a list of named fields.
+//!
+//! [FileType::Custom]: crate::files::FileType::Custom
+
+use getset::*;
+use lazy_static::lazy_static;
+use serde_derive::{Serialize, Deserialize};
+
+use std::fmt;
+use std::sync::{PoisonError, RwLock};
+
+use crate::binary::{ReadBytes, WriteBytes};
+use crate::error::{RLibError, Result};
+use crate::files::{DecodeableExtraData, Decodeable, EncodeableExtraData, Encodeable, table::DecodedData};
+
+lazy_static! {
+
+    /// Decoders registered for file types this lib doesn't support natively.
+    static ref DECODERS: RwLock<Vec<CustomDecoder>> = RwLock::new(vec![]);
+}
+
+/// Function to decode the raw data of a custom file into a list of named fields.
+pub type CustomDecodeFn = fn(&[u8]) -> Result<Vec<(String, DecodedData)>>;
+
+/// Function to encode a list of named fields back into the raw data of a custom file.
+pub type CustomEncodeFn = fn(&[(String, DecodedData)]) -> Result<Vec<u8>>;
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct contains a file decoded by a registered [CustomDecoder].
+#[derive(Clone, Debug, PartialEq, Getters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", set = "pub")]
+pub struct Custom {
+
+    /// Name of the decoder used to decode this file. It's also used to encode it back.
+    decoder: String,
+
+    /// Decoded fields of the file, in the order the decoder returned them.
+    fields: Vec<(String, DecodedData)>,
+}
+
+/// This struct represents a decoder/encoder pair for a file type not supported by the lib.
+#[derive(Clone, Copy, Getters)]
+#[getset(get = "pub")]
+pub struct CustomDecoder {
+
+    /// Unique name of the decoder.
+    name: &'static str,
+
+    /// Extensions of the files this decoder supports, in lowercase and with the dot (e.g. `.fx_fragment`).
+    extensions: &'static [&'static str],
+
+    /// Function to decode the files.
+    decode: CustomDecodeFn,
+
+    /// Function to encode the files.
+    encode: CustomEncodeFn,
+}
+
+//---------------------------------------------------------------------------//
+//                              Implementations
+//---------------------------------------------------------------------------//
+
+impl CustomDecoder {
+
+    /// This function creates a new decoder, ready to be registered.
+    pub fn new(name: &'static str, extensions: &'static [&'static str], decode: CustomDecodeFn, encode: CustomEncodeFn) -> Self {
+        Self {
+            name,
+            extensions,
+            decode,
+            encode,
+        }
+    }
+
+    /// This function returns if this decoder supports the file on the provided path.
+    pub fn supports(&self, path: &str) -> bool {
+        let path = path.to_lowercase();
+        self.extensions.iter().any(|extension| path.ends_with(extension))
+    }
+}
+
+impl fmt::Debug for CustomDecoder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CustomDecoder")
+            .field("name", &self.name)
+            .field("extensions", &self.extensions)
+            .finish()
+    }
+}
+
+/// This function registers the provided decoder, so files with its extensions are detected and decoded with it.
+///
+/// If there was already a decoder with the same name, it's replaced.
+pub fn register(decoder: CustomDecoder) {
+    let mut decoders = DECODERS.write().unwrap_or_else(PoisonError::into_inner);
+    decoders.retain(|registered| registered.name != decoder.name);
+    decoders.push(decoder);
+}
+
+/// This function unregisters the decoder with the provided name, returning if there was one.
+pub fn unregister(name: &str) -> bool {
+    let mut decoders = DECODERS.write().unwrap_or_else(PoisonError::into_inner);
+    let len = decoders.len();
+    decoders.retain(|registered| registered.name != name);
+    len != decoders.len()
+}
+
+/// This function returns the registered decoder for the file on the provided path, if any.
+pub fn decoder_for_path(path: &str) -> Option<CustomDecoder> {
+    DECODERS.read().unwrap_or_else(PoisonError::into_inner).iter().find(|decoder| decoder.supports(path)).copied()
+}
+
+/// This function returns the registered decoder with the provided name, if any.
+pub fn decoder_by_name(name: &str) -> Option<CustomDecoder> {
+    DECODERS.read().unwrap_or_else(PoisonError::into_inner).iter().find(|decoder| decoder.name == name).copied()
+}
+
+impl Decodeable for Custom {
+
+    /// The decoder is chosen by the file name provided in the extra data.
+    fn decode<R: ReadBytes>(data: &mut R, extra_data: &Option<DecodeableExtraData>) -> Result<Self> {
+        let extra_data = extra_data.as_ref().ok_or(RLibError::DecodingMissingExtraData)?;
+        let file_name = extra_data.file_name.ok_or_else(|| RLibError::DecodingMissingExtraDataField("file_name".to_owned()))?;
+        let decoder = decoder_for_path(file_name).ok_or_else(|| RLibError::CustomDecoderNotFound(file_name.to_owned()))?;
+
+        let len = data.len()?;
+        let data = data.read_slice(len as usize, false)?;
+        Ok(Self {
+            decoder: decoder.name.to_owned(),
+            fields: (decoder.decode)(&data)?,
+        })
+    }
+}
+
+impl Encodeable for Custom {
+
+    fn encode<W: WriteBytes>(&mut self, buffer: &mut W, _extra_data: &Option<EncodeableExtraData>) -> Result<()> {
+        let decoder = decoder_by_name(&self.decoder).ok_or_else(|| RLibError::CustomDecoderNotFound(self.decoder.to_owned()))?;
+        buffer.write_all(&(decoder.encode)(&self.fields)?).map_err(From::from)
+    }
+}
@@ -52,9 +52,9 @@ use std::io::SeekFrom;
 
 use crate::binary::{ReadBytes, WriteBytes};
 use crate::error::{RLibError, Result};
-use crate::files::{Container, ContainerPath, DecodeableExtraData, Decodeable, EncodeableExtraData, Encodeable, table::{DecodedData, Table}, pack::Pack, RFileDecoded};
-#[cfg(test)] use crate::schema::FieldType;
-use crate::schema::{Definition, DefinitionPatch, Field, Schema};
+use crate::files::{DecodeableExtraData, Decodeable, EncodeableExtraData, Encodeable, table::{DecodedData, Table}};
+#[cfg(test)] use crate::schema::{Field, FieldType};
+use crate::schema::{Definition, DefinitionPatch, Schema};
 use crate::utils::check_size_mismatch;
 
 /// If this sequence is found, the DB Table has a GUID after it.
@@ -353,130 +353,16 @@ impl DB {
         self.table.set_definition(new_definition);
     }
 
+    /// This function replaces the definition patches of this table with the ones provided.
+    pub fn set_patches(&mut self, definition_patch: &DefinitionPatch) {
+        self.table.set_patches(definition_patch);
+    }
+
     /// This function updates the current table to a new definition.
     pub fn update(&mut self, new_definition: &Definition) {
         self.set_definition(new_definition)
     }
 
-    /// This function performs a cascade update of DB/Loc values across an entire Pack, making sure
-    /// all references to the edited value are updated accordingly.
-    ///
-    /// It returns the list of ContainerPath where said reference has been found and updated.
-    pub fn cascade_edition(pack: &mut Pack, schema: &Option<Schema>, table_name: &str, field: &Field, definition: &Definition, value_before: &str, value_after: &str) -> Vec<ContainerPath> {
-        let mut edited_paths = vec![];
-
-        // If we're not changing anything, don't bother performing an edition.
-        if value_before == value_after {
-            return vec![];
-        }
-
-        // Get the tables/rows that need to be edited.
-        let fields_processed = definition.fields_processed();
-        let fields_localised = definition.localised_fields();
-        let referenced_tables = Table::tables_and_columns_referencing_our_own(schema, table_name, field.name(), &fields_processed, fields_localised);
-
-        // This little boy is the one that contains the list of precalculated tables to edit.
-        // Get the ones for the column we're currently editing, then get all the tables we need to edit.
-        if let Some((ref_table_data, has_loc_fields)) = referenced_tables {
-            let container_paths = ref_table_data.keys().map(|ref_table_name| ContainerPath::Folder("db/".to_owned() + ref_table_name)).collect::<Vec<_>>();
-            let mut files = pack.files_by_paths_mut(&container_paths, true);
-
-            for file in files.iter_mut() {
-                let path = file.path_in_container();
-                if let Ok(RFileDecoded::DB(table)) = file.decoded_mut() {
-                    let fields_processed = table.definition().fields_processed();
-                    let table_name = table.table_name().to_owned();
-
-                    // Optimization.
-                    let table_data = table.data_mut().unwrap();
-
-                    // Find the column to edit within the table.
-                    for ref_column_name in &ref_table_data[&table_name] {
-                        if let Some(column) = fields_processed.iter().position(|x| x.name() == ref_column_name) {
-
-                            // Then, only if the data has changed, go through all the rows and perform the edits.
-                            for row in table_data.iter_mut() {
-                                if let Some(field_data) = row.get_mut(column) {
-                                    match field_data {
-                                        DecodedData::StringU8(field_data) |
-                                        DecodedData::StringU16(field_data) |
-                                        DecodedData::OptionalStringU8(field_data) |
-                                        DecodedData::OptionalStringU16(field_data) => {
-
-                                            // Only edit exact matches.
-                                            if field_data == value_before {
-                                                *field_data = value_after.to_owned();
-
-                                                if !edited_paths.contains(&path) {
-                                                    edited_paths.push(path.clone());
-                                                }
-                                            }
-                                        }
-                                        _ => continue
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-            //}
-            /*
-            // Now, with locs. First, this is done only if our definition has localised fields and we edited the "key" field of our table.
-            if let Some(field) = self.edited_table_definition().fields_processed().get(*column as usize) {
-                if field.is_key() || field.name().to_lowercase() == "key" {
-                    if !self.edited_table_definition().localised_fields().is_empty() {
-
-                        for packed_file in pack.get_ref_mut_packed_files_by_type(FileType::Loc, false) {
-                            let path = packed_file.get_path().to_vec();
-                            if let RFileDecoded::Loc(table) = packed_file.get_ref_mut_decoded() {
-                                let mut table_data = table.data(&None).unwrap();
-                                for row in &mut table_data {
-                                    for (old_data, new_data) in data_changes {
-
-                                        // Same as with the tables, but here the column is always 0 and the entry structure is:
-                                        // "tablenamewithout_tables"_"localisedcolumnname"_"editedkey".
-                                        for loc_field in self.edited_table_definition().localised_fields() {
-                                            let short_table_name = if self.edited_table_name().ends_with("_tables") {
-                                                self.edited_table_name().split_at(self.edited_table_name().len() - 7).0
-                                            } else { self.edited_table_name() };
-
-                                            let old_localised_key = format!("{}_{}_{}", short_table_name, loc_field.name(), &old_data);
-                                            let new_localised_key = format!("{}_{}_{}", short_table_name, loc_field.name(), &new_data);
-
-                                            if old_localised_key != new_localised_key {
-                                                match &mut row[0] {
-                                                    DecodedData::StringU8(field_data) |
-                                                    DecodedData::StringU16(field_data) |
-                                                    DecodedData::OptionalStringU8(field_data) |
-                                                    DecodedData::OptionalStringU16(field_data) => {
-                                                        if *field_data == old_localised_key {
-                                                            *field_data = new_localised_key;
-
-                                                            if !edited_paths.contains(&path) {
-                                                                edited_paths.push(path.to_vec());
-                                                            }
-                                                        }
-                                                    }
-                                                    _ => continue
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-
-                                let _ = table.set_data(&table_data);
-
-                            }
-                        }
-                    }
-                }
-            }*/
-        }
-
-        edited_paths
-    }
-
     /// This function merges the data of a few DB tables into a new DB table.
     ///
     /// The metadata used (definition, patches) is taken from the first table on the list.
@@ -519,7 +405,7 @@ impl DB {
 
     /// This function imports a TSV file into a decoded table.
     pub fn tsv_export(&self, writer: &mut Writer<File>, table_path: &str) -> Result<()> {
-        self.table.tsv_export(writer, table_path)
+        self.table.tsv_export(writer, table_path, true)
     }
 }
 
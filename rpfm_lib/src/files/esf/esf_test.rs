@@ -0,0 +1,53 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for searching the nodes of ESF files.
+
+use super::*;
+
+/// This function creates a record node with the provided name, flags and blocks.
+fn record(name: &str, record_flags: RecordNodeFlags, children: Vec<Vec<NodeType>>) -> NodeType {
+    let mut node = RecordNode::default();
+    node.set_name(name.to_owned());
+    node.set_record_flags(record_flags | RecordNodeFlags::IS_RECORD_NODE);
+    node.set_children(children);
+    NodeType::Record(node)
+}
+
+#[test]
+fn test_search_nodes() {
+    let faction = |key: &str| vec![
+        NodeType::Ascii(key.to_owned()),
+        record("FACTION", RecordNodeFlags::empty(), vec![vec![NodeType::Utf16(format!("{}_name", key)), NodeType::U16(7)]]),
+    ];
+
+    let mut esf = ESF::default();
+    esf.set_root_node(record("CAMPAIGN_SAVE_GAME", RecordNodeFlags::empty(), vec![vec![
+        NodeType::U32(U32Node { value: 12, optimized: false }),
+        record("CAMPAIGN_ENV", RecordNodeFlags::empty(), vec![vec![NodeType::F32(F32Node { value: 1.5, optimized: false })]]),
+        record("FACTION_ARRAY", RecordNodeFlags::HAS_NESTED_BLOCKS, vec![faction("wh_main_emp_empire"), faction("wh_main_dwf_dwarfs")]),
+    ]]));
+
+    // Names are matched case-insensitively.
+    let matches = esf.search_nodes("faction_array");
+    assert_eq!(matches, vec![ESFNodePath::new(vec![1], vec!["CAMPAIGN_SAVE_GAME".to_owned(), "FACTION_ARRAY".to_owned()])]);
+    assert_eq!(matches[0].to_string(), "CAMPAIGN_SAVE_GAME > FACTION_ARRAY");
+
+    // Values point to the node holding them: the record node, or its block if it has nested blocks.
+    let matches = esf.search_nodes("DWF_DWARFS");
+    assert_eq!(matches.iter().map(|x| x.indexes().to_vec()).collect::<Vec<_>>(), vec![vec![1, 1], vec![1, 1, 0]]);
+    assert_eq!(matches[1].to_string(), "CAMPAIGN_SAVE_GAME > FACTION_ARRAY > wh_main_dwf_dwarfs > FACTION");
+
+    assert_eq!(esf.search_nodes("1.5")[0].indexes(), &[0]);
+    assert_eq!(esf.search_nodes("12")[0].indexes(), &[] as &[usize]);
+    assert_eq!(esf.search_nodes("faction").len(), 3);
+    assert!(esf.search_nodes("not_there").is_empty());
+    assert!(esf.search_nodes("").is_empty());
+}
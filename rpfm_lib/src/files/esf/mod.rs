@@ -38,6 +38,8 @@ pub const SIGNATURE_CFAB: &[u8; 4] = &[0xCF, 0xAB, 0x00, 0x00];
 
 pub mod caab;
 
+#[cfg(test)] mod esf_test;
+
 //---------------------------------------------------------------------------//
 //                              Markers, from ESFEdit
 //---------------------------------------------------------------------------//
@@ -310,6 +312,21 @@ pub struct RecordNode {
     children: Vec<Vec<NodeType>>
 }
 
+/// This struct represents the path to a node of the tree of an ESF, as shown in the ESF views.
+///
+/// The root node is not part of the indexes, as there can only be one. For each node after it, the index is its position
+/// between the record nodes of its parent. Record nodes with nested blocks have an extra step for the block, between them and their children.
+#[derive(Getters, Eq, PartialEq, Clone, Default, Debug, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct ESFNodePath {
+
+    /// Position of each node of the path within its parent, without the root node.
+    indexes: Vec<usize>,
+
+    /// Names of each node of the path, including the root node.
+    names: Vec<String>,
+}
+
 //---------------------------------------------------------------------------//
 //                           Implementation of ESF
 //---------------------------------------------------------------------------//
@@ -326,6 +343,109 @@ impl ESF {
             root_node: NodeType::Invalid,
         }
     }
+
+    /// This function returns the paths of the nodes whose name or values contain the provided text. Comparisons are case-insensitive.
+    ///
+    /// Values are stored in record nodes, or in their blocks if they have nested blocks, so the paths returned always point to one of those.
+    pub fn search_nodes(&self, query: &str) -> Vec<ESFNodePath> {
+        let mut matches = vec![];
+        if query.is_empty() {
+            return matches;
+        }
+
+        if let NodeType::Record(node) = &self.root_node {
+            let query = query.to_lowercase();
+            let path = ESFNodePath::new(vec![], vec![node.name().to_owned()]);
+
+            // The root node has all its record nodes directly under it, even if it has nested blocks.
+            if node.name().to_lowercase().contains(&query) || node.children().first().map_or(false, |values| values.iter().any(|value| value.value_matches(&query))) {
+                matches.push(path.clone());
+            }
+
+            for (index, child) in node.children().iter().flat_map(|block| RecordNode::records(block)).enumerate() {
+                child.search_nodes(&query, &path.child(index, child.name()), &mut matches);
+            }
+        }
+
+        matches
+    }
+}
+
+/// Implementation of `ESFNodePath`.
+impl ESFNodePath {
+
+    /// This function creates a new path from its indexes and names.
+    pub fn new(indexes: Vec<usize>, names: Vec<String>) -> Self {
+        Self {
+            indexes,
+            names,
+        }
+    }
+
+    /// This function returns the path of the child of this path's node at the provided index.
+    fn child(&self, index: usize, name: &str) -> Self {
+        let mut path = self.clone();
+        path.indexes.push(index);
+        path.names.push(name.to_owned());
+        path
+    }
+}
+
+/// Implementation of `RecordNode`.
+impl RecordNode {
+
+    /// This function returns the name of the provided block of this node, as shown in the ESF views.
+    ///
+    /// Blocks made of a key and a record node use the key as name. The rest use the name of this node followed by the index of the block.
+    pub fn block_name(&self, index: usize) -> String {
+        match self.children.get(index) {
+            Some(block) if block.len() == 2 => match &block[0] {
+                NodeType::Ascii(key) => key.to_owned(),
+                _ => format!("{}_{}", self.name, index),
+            },
+            _ => format!("{}_{}", self.name, index),
+        }
+    }
+
+    /// This function adds to the provided list the paths of this node, its blocks and its children matching the provided lowercase query.
+    fn search_nodes(&self, query: &str, path: &ESFNodePath, matches: &mut Vec<ESFNodePath>) {
+        if self.record_flags.contains(RecordNodeFlags::HAS_NESTED_BLOCKS) {
+            if self.name.to_lowercase().contains(query) {
+                matches.push(path.clone());
+            }
+
+            for (block_index, block) in self.children.iter().enumerate() {
+                let block_name = self.block_name(block_index);
+                let block_path = path.child(block_index, &block_name);
+                if block_name.to_lowercase().contains(query) || block.iter().any(|value| value.value_matches(query)) {
+                    matches.push(block_path.clone());
+                }
+
+                for (index, child) in Self::records(block).enumerate() {
+                    child.search_nodes(query, &block_path.child(index, child.name()), matches);
+                }
+            }
+        }
+
+        else if let Some(block) = self.children.first() {
+            if self.name.to_lowercase().contains(query) || block.iter().any(|value| value.value_matches(query)) {
+                matches.push(path.clone());
+            }
+
+            for (index, child) in Self::records(block).enumerate() {
+                child.search_nodes(query, &path.child(index, child.name()), matches);
+            }
+        }
+
+        else if self.name.to_lowercase().contains(query) {
+            matches.push(path.clone());
+        }
+    }
+
+    /// This function returns the record nodes of the provided block.
+    fn records(block: &[NodeType]) -> impl Iterator<Item = &RecordNode> {
+        block.iter().filter_map(|child| if let NodeType::Record(child) = child { Some(child) } else { None })
+    }
 }
 
 /// Implementation of `NodeType`.
@@ -346,6 +466,65 @@ impl NodeType {
             _ => self.clone()
         }
     }
+
+    /// This function checks if the value of this node contains the provided lowercase query.
+    ///
+    /// Record nodes have no value, so they never match. Arrays match if any of their values match.
+    fn value_matches(&self, query: &str) -> bool {
+        let matches = |value: &dyn Display| value.to_string().to_lowercase().contains(query);
+        let matches_2d = |value: &Coordinates2DNode| matches(&format!("{} {}", value.x, value.y));
+        let matches_3d = |value: &Coordinates3DNode| matches(&format!("{} {} {}", value.x, value.y, value.z));
+
+        match self {
+            Self::Invalid | Self::Record(_) => false,
+
+            Self::Bool(node) => matches(&node.value),
+            Self::I8(value) => matches(value),
+            Self::I16(value) => matches(value),
+            Self::I32(node) => matches(&node.value),
+            Self::I64(value) => matches(value),
+            Self::U8(value) => matches(value),
+            Self::U16(value) => matches(value),
+            Self::U32(node) => matches(&node.value),
+            Self::U64(value) => matches(value),
+            Self::F32(node) => matches(&node.value),
+            Self::F64(value) => matches(value),
+            Self::Coord2d(value) => matches_2d(value),
+            Self::Coord3d(value) => matches_3d(value),
+            Self::Utf16(value) => matches(value),
+            Self::Ascii(value) => matches(value),
+            Self::Angle(value) => matches(value),
+
+            Self::Unknown21(value) => matches(value),
+            Self::Unknown23(value) => matches(value),
+            Self::Unknown25(value) => matches(value),
+            Self::Unknown26(values) => values.iter().any(|value| matches(value)),
+
+            Self::BoolArray(values) => values.iter().any(|value| matches(value)),
+            Self::I8Array(values) => values.iter().any(|value| matches(value)),
+            Self::I16Array(values) => values.iter().any(|value| matches(value)),
+            Self::I32Array(node) => node.value.iter().any(|value| matches(value)),
+            Self::I64Array(values) => values.iter().any(|value| matches(value)),
+            Self::U8Array(values) => values.iter().any(|value| matches(value)),
+            Self::U16Array(values) => values.iter().any(|value| matches(value)),
+            Self::U32Array(node) => node.value.iter().any(|value| matches(value)),
+            Self::U64Array(values) => values.iter().any(|value| matches(value)),
+            Self::F32Array(values) => values.iter().any(|value| matches(value)),
+            Self::F64Array(values) => values.iter().any(|value| matches(value)),
+            Self::Coord2dArray(values) => values.iter().any(matches_2d),
+            Self::Coord3dArray(values) => values.iter().any(matches_3d),
+            Self::Utf16Array(values) => values.iter().any(|value| matches(value)),
+            Self::AsciiArray(values) => values.iter().any(|value| matches(value)),
+            Self::AngleArray(values) => values.iter().any(|value| matches(value)),
+        }
+    }
+}
+
+/// Display implementation for `ESFNodePath`.
+impl Display for ESFNodePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.names.join(" > "), f)
+    }
 }
 
 /// Default implementation for `ESF`.
@@ -0,0 +1,120 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for reading the headers of Images.
+
+use std::io::Cursor;
+
+use crate::files::*;
+
+use super::*;
+
+#[test]
+fn test_image_header() {
+
+    // DDS, compressed with DXT5.
+    let mut dds = vec![0; 128];
+    dds[0..4].copy_from_slice(b"DDS ");
+    dds[12..16].copy_from_slice(&256u32.to_le_bytes());
+    dds[16..20].copy_from_slice(&512u32.to_le_bytes());
+    dds[80..84].copy_from_slice(&DDS_PIXEL_FORMAT_FOURCC.to_le_bytes());
+    dds[84..88].copy_from_slice(b"DXT5");
+
+    let header = Image::decode(&mut Cursor::new(dds.to_vec()), &None).unwrap().header().unwrap();
+    assert_eq!(*header.width(), 512);
+    assert_eq!(*header.height(), 256);
+    assert_eq!(header.fourcc().as_deref(), Some("DXT5"));
+
+    // Uncompressed DDS files have no fourcc.
+    dds[80..84].copy_from_slice(&0x41u32.to_le_bytes());
+    let header = Image::decode(&mut Cursor::new(dds), &None).unwrap().header().unwrap();
+    assert_eq!(*header.fourcc(), None);
+
+    // PNG.
+    let mut png = SIGNATURE_PNG.to_vec();
+    png.extend_from_slice(&13u32.to_be_bytes());
+    png.extend_from_slice(b"IHDR");
+    png.extend_from_slice(&64u32.to_be_bytes());
+    png.extend_from_slice(&32u32.to_be_bytes());
+
+    let header = Image::decode(&mut Cursor::new(png), &None).unwrap().header().unwrap();
+    assert_eq!((*header.width(), *header.height()), (64, 32));
+    assert_eq!(*header.fourcc(), None);
+
+    // TGA, uncompressed true-color.
+    let mut tga = vec![0; 18];
+    tga[2] = 2;
+    tga[12..14].copy_from_slice(&16u16.to_le_bytes());
+    tga[14..16].copy_from_slice(&8u16.to_le_bytes());
+
+    let header = Image::decode(&mut Cursor::new(tga), &None).unwrap().header().unwrap();
+    assert_eq!((*header.width(), *header.height()), (16, 8));
+
+    // Anything else is not supported.
+    assert!(Image::decode(&mut Cursor::new(b"GIF89a".to_vec()), &None).unwrap().header().is_none());
+}
+
+#[test]
+fn test_dds_header() {
+
+    // DXT1 with mipmaps.
+    let mut dds = vec![0; 128];
+    dds[0..4].copy_from_slice(b"DDS ");
+    dds[8..12].copy_from_slice(&(0x1007 | DDS_FLAG_MIPMAP_COUNT).to_le_bytes());
+    dds[12..16].copy_from_slice(&64u32.to_le_bytes());
+    dds[16..20].copy_from_slice(&128u32.to_le_bytes());
+    dds[28..32].copy_from_slice(&8u32.to_le_bytes());
+    dds[80..84].copy_from_slice(&DDS_PIXEL_FORMAT_FOURCC.to_le_bytes());
+    dds[84..88].copy_from_slice(b"DXT1");
+
+    let header = Image::decode(&mut Cursor::new(dds.to_vec()), &None).unwrap().dds_header().unwrap();
+    assert_eq!((*header.width(), *header.height(), *header.depth()), (128, 64, 1));
+    assert_eq!(*header.mipmap_count(), 8);
+    assert_eq!(header.max_mipmap_count(), 8);
+    assert_eq!(*header.array_size(), 1);
+    assert_eq!(header.format(), "BC1");
+    assert!(header.is_block_compressed());
+
+    // The mipmap count is ignored if its flag is not set.
+    dds[8..12].copy_from_slice(&0x1007u32.to_le_bytes());
+    let header = Image::decode(&mut Cursor::new(dds.to_vec()), &None).unwrap().dds_header().unwrap();
+    assert_eq!(*header.mipmap_count(), 1);
+
+    // BC7 texture array, with DX10 extended header.
+    dds[84..88].copy_from_slice(b"DX10");
+    dds.extend_from_slice(&98u32.to_le_bytes());
+    dds.extend_from_slice(&3u32.to_le_bytes());
+    dds.extend_from_slice(&0u32.to_le_bytes());
+    dds.extend_from_slice(&6u32.to_le_bytes());
+    dds.extend_from_slice(&0u32.to_le_bytes());
+
+    let header = Image::decode(&mut Cursor::new(dds.to_vec()), &None).unwrap().dds_header().unwrap();
+    assert_eq!(*header.dxgi_format(), Some(98));
+    assert_eq!(*header.array_size(), 6);
+    assert_eq!(header.format(), "BC7");
+
+    // Truncated DX10 headers are broken headers.
+    dds.truncate(140);
+    assert!(Image::decode(&mut Cursor::new(dds), &None).unwrap().dds_header().is_none());
+
+    // Uncompressed.
+    let mut dds = vec![0; 128];
+    dds[0..4].copy_from_slice(b"DDS ");
+    dds[80..84].copy_from_slice(&0x41u32.to_le_bytes());
+    dds[88..92].copy_from_slice(&32u32.to_le_bytes());
+    let header = Image::decode(&mut Cursor::new(dds), &None).unwrap().dds_header().unwrap();
+    assert_eq!(header.format(), "Uncompressed 32bpp");
+    assert!(!header.is_block_compressed());
+
+    // Non-DDS images have no DDS header.
+    let mut tga = vec![0; 18];
+    tga[2] = 2;
+    assert!(Image::decode(&mut Cursor::new(tga), &None).unwrap().dds_header().is_none());
+}
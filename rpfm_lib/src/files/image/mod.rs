@@ -38,6 +38,39 @@ pub const EXTENSIONS: [&str; 6] = [
     ".gif"
 ];
 
+/// Signature of DDS files.
+const SIGNATURE_DDS: &[u8; 4] = b"DDS ";
+
+/// Signature of PNG files.
+const SIGNATURE_PNG: &[u8; 8] = b"\x89PNG\r\n\x1a\n";
+
+/// Flag in the DDS pixel format marking the format as compressed and identified by a fourcc.
+const DDS_PIXEL_FORMAT_FOURCC: u32 = 0x4;
+
+/// Flag in the DDS header marking the mipmap count as valid.
+const DDS_FLAG_MIPMAP_COUNT: u32 = 0x20000;
+
+/// Flag in the DDS header marking the depth as valid.
+const DDS_FLAG_DEPTH: u32 = 0x800000;
+
+/// FourCC of DDS files with the DX10 extended header after the normal one.
+const DDS_FOURCC_DX10: &str = "DX10";
+
+/// FourCCs of the block-compressed formats of DDS files without DX10 extended header, with their format name.
+const DDS_BLOCK_COMPRESSED_FOURCCS: [(&str, &str); 9] = [
+    ("DXT1", "BC1"),
+    ("DXT2", "BC2"),
+    ("DXT3", "BC2"),
+    ("DXT4", "BC3"),
+    ("DXT5", "BC3"),
+    ("ATI1", "BC4"),
+    ("BC4U", "BC4"),
+    ("ATI2", "BC5"),
+    ("BC5U", "BC5"),
+];
+
+#[cfg(test)] mod image_test;
+
 //---------------------------------------------------------------------------//
 //                              Enum & Structs
 //---------------------------------------------------------------------------//
@@ -49,10 +82,185 @@ pub struct Image {
     data: Vec<u8>,
 }
 
+/// This holds the basic metadata of an Image, as read from its header.
+#[derive(Default, PartialEq, Eq, Clone, Debug, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct ImageHeader {
+
+    /// Width of the image, in pixels.
+    width: u32,
+
+    /// Height of the image, in pixels.
+    height: u32,
+
+    /// FourCC of the pixel format. Only for compressed DDS images.
+    fourcc: Option<String>,
+}
+
+/// This holds the full header of a DDS Image, including the DX10 extended header, if present.
+#[derive(Default, PartialEq, Eq, Clone, Debug, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct DDSHeader {
+
+    /// Width of the image, in pixels.
+    width: u32,
+
+    /// Height of the image, in pixels.
+    height: u32,
+
+    /// Depth of the image, for volume textures. 1 for anything else.
+    depth: u32,
+
+    /// Amount of mipmaps, including the main image. 1 if the image has no mipmaps.
+    mipmap_count: u32,
+
+    /// FourCC of the pixel format. Only for compressed images.
+    fourcc: Option<String>,
+
+    /// Bits per pixel. Only for uncompressed images.
+    bit_count: u32,
+
+    /// DXGI format of the image. Only for images with the DX10 extended header.
+    dxgi_format: Option<u32>,
+
+    /// Amount of textures in the image, for texture arrays. 1 for anything else.
+    array_size: u32,
+}
+
 //---------------------------------------------------------------------------//
 //                           Implementation of Image
 //---------------------------------------------------------------------------//
 
+impl Image {
+
+    /// This function parses the header of the image to get its basic metadata.
+    ///
+    /// Only DDS, PNG and TGA images are supported. For any other image, or for broken headers, it returns None.
+    pub fn header(&self) -> Option<ImageHeader> {
+        let data = &self.data;
+
+        // DDS: the full header is parsed separately.
+        if data.starts_with(SIGNATURE_DDS) {
+            self.dds_header().map(|header| ImageHeader {
+                width: header.width,
+                height: header.height,
+                fourcc: header.fourcc,
+            })
+        }
+
+        // PNG: 8 bytes signature, then the IHDR chunk, with its big endian width and height.
+        else if data.len() >= 24 && data.starts_with(SIGNATURE_PNG) && &data[12..16] == b"IHDR" {
+            let width = u32::from_be_bytes(data[16..20].try_into().ok()?);
+            let height = u32::from_be_bytes(data[20..24].try_into().ok()?);
+
+            Some(ImageHeader { width, height, fourcc: None })
+        }
+
+        // TGA: no signature, so we only accept it if the image type is one of the valid ones.
+        else if data.len() >= 18 && matches!(data[2], 1 | 2 | 3 | 9 | 10 | 11) {
+            let width = u16::from_le_bytes(data[12..14].try_into().ok()?);
+            let height = u16::from_le_bytes(data[14..16].try_into().ok()?);
+
+            Some(ImageHeader { width: width as u32, height: height as u32, fourcc: None })
+        }
+
+        else {
+            None
+        }
+    }
+
+    /// This function parses the header of a DDS image, including its DX10 extended header if present.
+    ///
+    /// For non-DDS images, or for broken headers, it returns None.
+    pub fn dds_header(&self) -> Option<DDSHeader> {
+        let data = &self.data;
+
+        // 4 bytes signature, then a 124 bytes header, with the pixel format starting at byte 76 of it.
+        if data.len() < 128 || !data.starts_with(SIGNATURE_DDS) {
+            return None;
+        }
+
+        let read_u32 = |offset: usize| data.get(offset..offset + 4).and_then(|bytes| bytes.try_into().ok()).map(u32::from_le_bytes);
+
+        let flags = read_u32(8)?;
+        let height = read_u32(12)?;
+        let width = read_u32(16)?;
+        let depth = if flags & DDS_FLAG_DEPTH != 0 { read_u32(24)?.max(1) } else { 1 };
+        let mipmap_count = if flags & DDS_FLAG_MIPMAP_COUNT != 0 { read_u32(28)?.max(1) } else { 1 };
+
+        let pixel_format_flags = read_u32(80)?;
+        let fourcc = if pixel_format_flags & DDS_PIXEL_FORMAT_FOURCC != 0 {
+            Some(String::from_utf8_lossy(&data[84..88]).trim_end_matches('\0').to_owned())
+        } else {
+            None
+        };
+        let bit_count = if fourcc.is_none() { read_u32(88)? } else { 0 };
+
+        // The DX10 extended header goes right after the normal one, and it's 20 bytes long.
+        let (dxgi_format, array_size) = if fourcc.as_deref() == Some(DDS_FOURCC_DX10) {
+            (Some(read_u32(128)?), read_u32(140)?.max(1))
+        } else {
+            (None, 1)
+        };
+
+        Some(DDSHeader {
+            width,
+            height,
+            depth,
+            mipmap_count,
+            fourcc,
+            bit_count,
+            dxgi_format,
+            array_size,
+        })
+    }
+}
+
+impl DDSHeader {
+
+    /// This function returns the name of the format of the image, like `BC1` or `B8G8R8A8_UNORM`.
+    ///
+    /// Formats we don't know are returned as their fourcc or DXGI code.
+    pub fn format(&self) -> String {
+        match self.dxgi_format {
+            Some(dxgi_format) => match dxgi_format {
+                2 => "R32G32B32A32_FLOAT".to_owned(),
+                10 => "R16G16B16A16_FLOAT".to_owned(),
+                28 => "R8G8B8A8_UNORM".to_owned(),
+                29 => "R8G8B8A8_UNORM_SRGB".to_owned(),
+                61 => "R8_UNORM".to_owned(),
+                70..=72 => "BC1".to_owned(),
+                73..=75 => "BC2".to_owned(),
+                76..=78 => "BC3".to_owned(),
+                79..=81 => "BC4".to_owned(),
+                82..=84 => "BC5".to_owned(),
+                87 => "B8G8R8A8_UNORM".to_owned(),
+                91 => "B8G8R8A8_UNORM_SRGB".to_owned(),
+                94..=96 => "BC6H".to_owned(),
+                97..=99 => "BC7".to_owned(),
+                _ => format!("DXGI {}", dxgi_format),
+            },
+            None => match &self.fourcc {
+                Some(fourcc) => DDS_BLOCK_COMPRESSED_FOURCCS.iter()
+                    .find(|(known_fourcc, _)| known_fourcc == fourcc)
+                    .map(|(_, format)| format.to_string())
+                    .unwrap_or_else(|| fourcc.to_owned()),
+                None => format!("Uncompressed {}bpp", self.bit_count),
+            }
+        }
+    }
+
+    /// This function returns if the image uses a block-compressed (BCn) format.
+    pub fn is_block_compressed(&self) -> bool {
+        self.format().starts_with("BC")
+    }
+
+    /// This function returns the maximum amount of mipmaps, including the main image, an image with this header's dimensions can have.
+    pub fn max_mipmap_count(&self) -> u32 {
+        32 - self.width.max(self.height).max(1).leading_zeros()
+    }
+}
+
 impl Decodeable for Image {
 
     fn decode<R: ReadBytes>(data: &mut R, _extra_data: &Option<DecodeableExtraData>) -> Result<Self> {
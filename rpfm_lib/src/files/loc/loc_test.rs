@@ -64,3 +64,60 @@ fn test_encode_loc_sqlite() {
 
     assert_eq!(before, after);
 }
+
+#[test]
+fn test_encode_loc_non_standard() {
+    let path_1 = "../test_files/test_decode_non_standard.loc";
+    let path_2 = "../test_files/test_encode_non_standard.loc";
+    let mut reader = BufReader::new(File::open(path_1).unwrap());
+
+    let decodeable_extra_data = DecodeableExtraData::default();
+
+    let data_len = reader.len().unwrap();
+    let before = reader.read_slice(data_len as usize, true).unwrap();
+    let mut data = Loc::decode(&mut reader, &Some(decodeable_extra_data)).unwrap();
+
+    // This file uses a 4 bytes tooltip column, so the last 3 bytes of it are kept as padding.
+    assert!(data.is_non_standard());
+    assert_eq!(data.row_padding(), &vec![0, 0, 0]);
+
+    // The data must match the one from the standard file.
+    let mut reader = BufReader::new(File::open("../test_files/test_decode.loc").unwrap());
+    let standard = Loc::decode(&mut reader, &Some(DecodeableExtraData::default())).unwrap();
+    assert_eq!(data.data(&None).unwrap(), standard.data(&None).unwrap());
+
+    let mut after = vec![];
+    data.encode(&mut after, &None).unwrap();
+
+    let mut writer = BufWriter::new(File::create(path_2).unwrap());
+    writer.write_all(&after).unwrap();
+
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_encode_loc_non_standard_sqlite() {
+    let pool = crate::integrations::sqlite::init_database().unwrap();
+
+    let path_1 = "../test_files/test_decode_non_standard.loc";
+    let path_2 = "../test_files/test_encode_non_standard_sqlite.loc";
+    let mut reader = BufReader::new(File::open(path_1).unwrap());
+
+    let mut decodeable_extra_data = DecodeableExtraData::default();
+    decodeable_extra_data.pool = Some(&pool);
+
+    let data_len = reader.len().unwrap();
+    let before = reader.read_slice(data_len as usize, true).unwrap();
+    let mut data = Loc::decode(&mut reader, &Some(decodeable_extra_data)).unwrap();
+    assert!(data.is_non_standard());
+
+    let mut after = vec![];
+    let mut encodeable_extra_data = EncodeableExtraData::default();
+    encodeable_extra_data.pool = Some(&pool);
+    data.encode(&mut after, &Some(encodeable_extra_data)).unwrap();
+
+    let mut writer = BufWriter::new(File::create(path_2).unwrap());
+    writer.write_all(&after).unwrap();
+
+    assert_eq!(before, after);
+}
@@ -33,6 +33,9 @@
 //! | *     | Sized StringU16 | Localisation key. |
 //! | *     | Sized StringU16 | Localised string. |
 //! | 1     | [bool]          | Unknown.          |
+//!
+//! Some community tools write the third column as a wider field. For those files, RPFM reads the first byte of the field
+//! as the boolean, and keeps the rest of the field as row padding, so the file can be saved back with the same layout.
 
 use csv::{StringRecordsIter, Writer};
 use getset::{Getters, Setters};
@@ -44,6 +47,9 @@ use serde_derive::{Serialize, Deserialize};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
+use std::io::{Cursor, SeekFrom};
+
+#[cfg(feature = "integration_log")] use log::warn;
 
 use crate::binary::{ReadBytes, WriteBytes};
 use crate::error::{RLibError, Result};
@@ -70,6 +76,9 @@ pub const EXTENSION: &str = ".loc";
 /// Version used by Loc files. We've only seen version 1 so far, so we stick with that one.
 const VERSION: i32 = 1;
 
+/// Max amount of bytes we accept after the tooltip of each row when decoding non-standard Loc files.
+const MAX_ROW_PADDING: usize = 16;
+
 #[cfg(test)] mod loc_test;
 
 //---------------------------------------------------------------------------//
@@ -83,6 +92,10 @@ pub struct Loc {
 
     /// The table's data, containing all the stuff needed to decode/encode it.
     table: Table,
+
+    /// Bytes written after the tooltip of each row by tools using a non-standard layout. Empty for standard Loc files.
+    #[serde(default)]
+    row_padding: Vec<u8>,
 }
 
 //---------------------------------------------------------------------------//
@@ -98,6 +111,7 @@ impl Loc {
 
         Self {
             table: Table::new(&definition, None, TSV_NAME_LOC, use_sql_backend),
+            row_padding: vec![],
         }
     }
 
@@ -140,6 +154,11 @@ impl Loc {
         self.table().column_position_by_name(column_name)
     }
 
+    /// This function returns if this Loc uses the non-standard layout some tools write, with extra bytes after each row.
+    pub fn is_non_standard(&self) -> bool {
+        !self.row_padding.is_empty()
+    }
+
     /// This function returns the amount of entries in this Loc Table.
     pub fn len(&self) -> usize {
         self.table.len(None).unwrap()
@@ -176,6 +195,46 @@ impl Loc {
         Ok((version, entry_count))
     }
 
+    /// This function tries to read the rows of a Loc file written with a wider third column, starting after the header.
+    ///
+    /// It tries every padding size until one reads the entire file with the same padding for every row.
+    /// It returns the rows in the standard layout, ready to be decoded as a normal Loc table, and the padding.
+    fn read_non_standard_rows<R: ReadBytes>(data: &mut R, entry_count: u32) -> Option<(Vec<u8>, Vec<u8>)> {
+        let data_len = data.len().ok()?;
+
+        // Without rows there's no padding to detect.
+        if entry_count == 0 {
+            return None;
+        }
+
+        for padding_size in 1..=MAX_ROW_PADDING {
+            data.seek(SeekFrom::Start(HEADER_SIZE as u64)).ok()?;
+
+            let mut rows = vec![];
+            let mut row_padding = None;
+            let read = (0..entry_count).all(|_| {
+                let padding = Self::copy_row(data, &mut rows).and_then(|_| data.read_slice(padding_size, false));
+                match padding {
+                    Ok(padding) => *row_padding.get_or_insert_with(|| padding.to_vec()) == padding,
+                    Err(_) => false,
+                }
+            });
+
+            if read && data.stream_position().ok()? == data_len {
+                return row_padding.map(|row_padding| (rows, row_padding));
+            }
+        }
+
+        None
+    }
+
+    /// This function copies a row in the standard layout from the provided reader to the provided writer.
+    fn copy_row<R: ReadBytes, W: WriteBytes>(data: &mut R, buffer: &mut W) -> Result<()> {
+        buffer.write_sized_string_u16(&data.read_sized_string_u16()?)?;
+        buffer.write_sized_string_u16(&data.read_sized_string_u16()?)?;
+        buffer.write_bool(data.read_bool()?)
+    }
+
     /// This function merges the data of a few Loc tables into a new Loc table.
     pub(crate) fn merge(sources: &[&Self]) -> Result<Self> {
         let mut new_table = Self::new(false);
@@ -207,7 +266,7 @@ impl Loc {
 
     /// This function exports a decoded Loc file into a TSV file.
     pub fn tsv_export(&self, writer: &mut Writer<File>, table_path: &str) -> Result<()> {
-        self.table.tsv_export(writer, table_path)
+        self.table.tsv_export(writer, table_path, true)
     }
 }
 
@@ -221,14 +280,38 @@ impl Decodeable for Loc {
         let (_version, entry_count) = Self::read_header(data)?;
 
         let definition = Self::new_definition();
-        let table = Table::decode(&pool, data, &definition, &HashMap::new(), Some(entry_count), false, TSV_NAME_LOC)?;
-
-        // If we are not in the last byte, it means we didn't parse the entire file, which means this file is corrupt.
-        check_size_mismatch(data.stream_position()? as usize, data.len()? as usize)?;
-
-        Ok(Self {
-            table,
-        })
+        let strict = Table::decode(&pool, data, &definition, &HashMap::new(), Some(entry_count), false, TSV_NAME_LOC)
+            .and_then(|table| {
+
+                // If we are not in the last byte, it means we didn't parse the entire file, which means this file is corrupt.
+                check_size_mismatch(data.stream_position()? as usize, data.len()? as usize)?;
+                Ok(table)
+            });
+
+        match strict {
+            Ok(table) => Ok(Self {
+                table,
+                row_padding: vec![],
+            }),
+
+            // Some tools write the third column as a wider field. If that's the case, decode it with the padding they use.
+            Err(error) => match Self::read_non_standard_rows(data, entry_count) {
+                Some((rows, row_padding)) => {
+
+                    #[cfg(feature = "integration_log")] {
+                        warn!("Loc file {} uses a non-standard layout, with {} extra bytes per row. It'll be saved with the same layout.", extra_data.file_name.unwrap_or_default(), row_padding.len());
+                    }
+
+                    let table = Table::decode(&pool, &mut Cursor::new(rows), &definition, &HashMap::new(), Some(entry_count), false, TSV_NAME_LOC)?;
+
+                    Ok(Self {
+                        table,
+                        row_padding,
+                    })
+                }
+                None => Err(error),
+            }
+        }
     }
 }
 
@@ -243,7 +326,23 @@ impl Encodeable for Loc {
         buffer.write_i32(*self.table.definition().version())?;
         buffer.write_u32(self.table.len(pool)? as u32)?;
 
-        self.table.encode(buffer, &None, &pool)
+        if self.row_padding.is_empty() {
+            self.table.encode(buffer, &None, &pool)
+        }
+
+        // Non-standard Locs are encoded in the standard layout first, then the padding is added to each row.
+        else {
+            let mut rows = vec![];
+            self.table.encode(&mut rows, &None, &pool)?;
+
+            let mut rows = Cursor::new(rows);
+            for _ in 0..self.table.len(pool)? {
+                Self::copy_row(&mut rows, buffer)?;
+                buffer.write_all(&self.row_padding)?;
+            }
+
+            Ok(())
+        }
     }
 }
 
@@ -253,6 +352,7 @@ impl From<Table> for Loc {
         table.set_table_name(TSV_NAME_LOC.to_owned());
         Self {
             table,
+            row_padding: vec![],
         }
     }
 }
@@ -13,8 +13,11 @@
 use std::io::{BufReader, BufWriter, Write};
 use std::fs::File;
 
-use crate::binary::ReadBytes;
+use crate::binary::{ReadBytes, WriteBytes};
+use crate::error::RLibError;
 use crate::files::*;
+use crate::files::table::{DecodedData, Table};
+use crate::schema::FieldType;
 
 use super::MatchedCombat;
 
@@ -40,3 +43,87 @@ fn test_encode_matched_combat() {
 
     assert_eq!(before, after);
 }
+
+#[test]
+fn test_decode_matched_combat_unsupported_version() {
+    let data = vec![99, 0, 0, 0, 0, 0, 0, 0];
+    let mut reader = std::io::Cursor::new(data);
+
+    assert!(matches!(MatchedCombat::decode(&mut reader, &None), Err(RLibError::DecodingMatchedCombatVersionNotSupported(99))));
+}
+
+#[test]
+fn test_encode_matched_combat_v2() {
+    let mut before = vec![];
+    before.write_i32(2).unwrap();
+    before.write_u32(1).unwrap();
+
+    for unit_key in ["unit_1_key", "unit_2_key"] {
+        before.write_u32(1).unwrap();
+        before.write_i32(5).unwrap();
+        before.write_i32(1).unwrap();
+        before.write_i32(2).unwrap();
+        before.write_i32(3).unwrap();
+        before.write_sized_string_u8(unit_key).unwrap();
+    }
+
+    let mut reader = std::io::Cursor::new(before.to_vec());
+    let mut data = MatchedCombat::decode(&mut reader, &None).unwrap();
+    assert_eq!(data.definition().fields().len(), 10);
+
+    let mut after = vec![];
+    data.encode(&mut after, &None).unwrap();
+
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_edit_sequence_matched_combat_v3() {
+    let write_file = |sequence_len: usize| {
+        let mut data = vec![];
+        data.write_i32(3).unwrap();
+        data.write_u32(1).unwrap();
+
+        for unit in ["unit_1", "unit_2"] {
+            data.write_u32(sequence_len as u32).unwrap();
+            for value in 0..sequence_len {
+                data.write_i32(value as i32).unwrap();
+            }
+
+            data.write_i32(1).unwrap();
+            data.write_i32(2).unwrap();
+            data.write_i32(3).unwrap();
+            data.write_sized_string_u8(&format!("{}_key", unit)).unwrap();
+            data.write_sized_string_u8(&format!("{}_text", unit)).unwrap();
+        }
+        data
+    };
+
+    let before = write_file(1);
+    let mut reader = std::io::Cursor::new(before);
+    let mut data = MatchedCombat::decode(&mut reader, &None).unwrap();
+
+    // Add a row to the sequence of each unit.
+    let mut rows = data.data().unwrap().to_vec();
+    for column in [0, 6] {
+        let field = &data.definition().fields()[column];
+        let FieldType::SequenceU32(ref definition) = field.field_type() else { panic!("Wrong field type") };
+
+        let mut sequence = Table::from_sequence(&rows[0][column], definition).unwrap();
+        let mut sequence_rows = sequence.data(&None).unwrap().to_vec();
+        sequence_rows.push(vec![DecodedData::I32(1)]);
+        sequence.set_data(None, &sequence_rows).unwrap();
+
+        rows[0][column] = sequence.to_sequence(field.field_type()).unwrap();
+    }
+    data.set_data(&rows).unwrap();
+
+    let mut after = vec![];
+    data.encode(&mut after, &None).unwrap();
+    assert_eq!(after, write_file(2));
+
+    // The text fields of version 3 must survive the round trip.
+    let mut reader = std::io::Cursor::new(after);
+    let data = MatchedCombat::decode(&mut reader, &None).unwrap();
+    assert_eq!(data.data().unwrap()[0][11], DecodedData::StringU8("unit_2_text".to_owned()));
+}
@@ -55,7 +55,7 @@ pub const EXTENSION: &str = ".bin";
 /// Size of the header of a MatchedCombat PackedFile.
 pub const HEADER_SIZE: usize = 8;
 
-//#[cfg(test)] mod matched_combat_test;
+#[cfg(test)] mod matched_combat_test;
 
 //---------------------------------------------------------------------------//
 //                              Enum & Structs
@@ -84,70 +84,40 @@ impl MatchedCombat {
         }
     }
 
-    /// This function returns the definition of a Loc table.
-    pub(crate) fn new_definition(version: i32) -> Definition {
-        dbg!(version);
-        match version {
-
-            // Seen in 3k.
-            3 => {
-
-                let mut subdefinition = Definition::new(-1);
-                let subfields = vec![
-                    Field::new("uk_1".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                ];
-                subdefinition.set_fields(subfields);
-                let mut definition = Definition::new(version);
-                let fields = vec![
-                    Field::new("unit_1_uk_1".to_owned(), FieldType::SequenceU32(Box::new(subdefinition.clone())), true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_1_uk_2".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_1_uk_3".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_1_uk_4".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_1_key".to_owned(), FieldType::StringU8, true, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_1_text".to_owned(), FieldType::StringU8, false, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-
-                    Field::new("unit_2_uk_1".to_owned(), FieldType::SequenceU32(Box::new(subdefinition)), true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_2_uk_2".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_2_uk_3".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_2_uk_4".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_2_key".to_owned(), FieldType::StringU8, true, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_2_text".to_owned(), FieldType::StringU8, false, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                ];
-
-                definition.set_fields(fields);
-                definition
-            },
-
-            // Seen in wh3
-            1 => {
-
-                let mut subdefinition = Definition::new(-1);
-                let subfields = vec![
-                    Field::new("uk_1".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                ];
-                subdefinition.set_fields(subfields);
-                let mut definition = Definition::new(version);
-                let fields = vec![
-                    Field::new("unit_1_uk_1".to_owned(), FieldType::SequenceU32(Box::new(subdefinition.clone())), true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_1_uk_2".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_1_uk_3".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_1_uk_4".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_1_key".to_owned(), FieldType::StringU8, true, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_1_text".to_owned(), FieldType::StringU8, false, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-
-                    Field::new("unit_2_uk_1".to_owned(), FieldType::SequenceU32(Box::new(subdefinition)), true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_2_uk_2".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_2_uk_3".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_2_uk_4".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_2_key".to_owned(), FieldType::StringU8, true, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                    Field::new("unit_2_text".to_owned(), FieldType::StringU8, false, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
-                ];
-
-                definition.set_fields(fields);
-                definition
-            },
-            _ => todo!(),
+    /// This function returns the definition of a Matched Combat table for the provided version.
+    pub(crate) fn new_definition(version: i32) -> Result<Definition> {
+        let has_text_fields = match version {
+
+            // Seen in wh3 (1) and 3k (3).
+            1 | 3 => true,
+
+            // Seen in Troy. Same as version 1, but without the text fields.
+            2 => false,
+            _ => return Err(RLibError::DecodingMatchedCombatVersionNotSupported(version)),
+        };
+
+        let mut subdefinition = Definition::new(-1);
+        let subfields = vec![
+            Field::new("uk_1".to_owned(), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+        ];
+        subdefinition.set_fields(subfields);
+
+        let mut fields = vec![];
+        for unit in ["unit_1", "unit_2"] {
+            fields.push(Field::new(format!("{}_uk_1", unit), FieldType::SequenceU32(Box::new(subdefinition.clone())), true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None));
+            fields.push(Field::new(format!("{}_uk_2", unit), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None));
+            fields.push(Field::new(format!("{}_uk_3", unit), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None));
+            fields.push(Field::new(format!("{}_uk_4", unit), FieldType::I32, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None));
+            fields.push(Field::new(format!("{}_key", unit), FieldType::StringU8, true, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None));
+
+            if has_text_fields {
+                fields.push(Field::new(format!("{}_text", unit), FieldType::StringU8, false, Some("PLACEHOLDER".to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None));
+            }
         }
+
+        let mut definition = Definition::new(version);
+        definition.set_fields(fields);
+        Ok(definition)
     }
 
     /// This function returns a reference of the definition used by the Loc table.
@@ -160,8 +130,6 @@ impl MatchedCombat {
         self.table.data(&None)
     }
 
-    /*
-
     /// This function replaces the definition of this table with the one provided.
     ///
     /// This updates the table's data to follow the format marked by the new definition, so you can use it to *update* the version of your table.
@@ -172,10 +140,10 @@ impl MatchedCombat {
     /// This function replaces the data of this table with the one provided.
     ///
     /// This can (and will) fail if the data is not of the format defined by the definition of the table.
-    pub fn set_table_data(&mut self, data: &[Vec<DecodedData>]) -> Result<()> {
-        self.table.set_table_data(data)
+    pub fn set_data(&mut self, data: &[Vec<DecodedData>]) -> Result<()> {
+        self.table.set_data(None, data)
     }
-    */
+
     /// This function tries to read the header of a Matched Combat file from a reader.
     pub fn read_header<R: ReadBytes>(data: &mut R) -> Result<(i32, u32)> {
 
@@ -195,7 +163,7 @@ impl Decodeable for MatchedCombat {
 
     fn decode<R: ReadBytes>(data: &mut R, _extra_data: &Option<DecodeableExtraData>) -> Result<Self> {
         let (version, entry_count) = Self::read_header(data)?;
-        let definition = Self::new_definition(version);
+        let definition = Self::new_definition(version)?;
         let table = Table::decode(&None, data, &definition, &HashMap::new(), Some(entry_count), true, "matched_combat")?;
 
         // If we are not in the last byte, it means we didn't parse the entire file, which means this file is corrupt.
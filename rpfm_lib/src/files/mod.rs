@@ -25,6 +25,7 @@
 //! | [`Pack`]             | Yes                | Yes                |
 //! | [`PortraitSettings`] | No                 | No                 |
 //! | [`RigidModel`]       | No                 | No                 |
+//! | [`SpeedTree`]        | Limited            | Limited            |
 //! | [`Text`]             | Yes                | Yes                |
 //! | [`UIC`]              | No                 | No                 |
 //! | [`UnitVariant`]      | Yes                | Yes                |
@@ -33,12 +34,17 @@
 //! There is an additional type: [`Unknown`]. This type is used as a wildcard,
 //! so you can get the raw data of any non-supported file type and manipulate it yourself in a safe way.
 //!
+//! Support for other file types can be added from outside the lib by registering a decoder for them.
+//! Files detected this way are decoded as [`Custom`]. Check the [`custom`] module for more info.
+//!
 //! For more information about specific file types, including their binary format spec, please
 //! **check their respective documentation**.
 //!
 //! [`AnimFragment`]: crate::files::anim_fragment::AnimFragment
 //! [`AnimPack`]: crate::files::animpack::AnimPack
 //! [`AnimsTable`]: crate::files::anims_table::AnimsTable
+//! [`Custom`]: crate::files::custom::Custom
+//! [`custom`]: crate::files::custom
 //! [`DB`]: crate::files::db::DB
 //! [`ESF`]: crate::files::esf::ESF
 //! [`Image`]: crate::files::image::Image
@@ -47,6 +53,7 @@
 //! [`Pack`]: crate::files::pack::Pack
 //! [`PortraitSettings`]: crate::files::portrait_settings::PortraitSettings
 //! [`RigidModel`]: crate::files::rigidmodel::RigidModel
+//! [`SpeedTree`]: crate::files::speedtree::SpeedTree
 //! [`Text`]: crate::files::text::Text
 //! [`UIC`]: crate::files::uic::UIC
 //! [`UnitVariant`]: crate::files::unit_variant::UnitVariant
@@ -57,15 +64,17 @@
 #[cfg(feature = "integration_sqlite")] use r2d2::Pool;
 #[cfg(feature = "integration_sqlite")] use r2d2_sqlite::SqliteConnectionManager;
 
-use csv::{QuoteStyle, ReaderBuilder, WriterBuilder};
+use csv::{QuoteStyle, Reader, ReaderBuilder, Writer, WriterBuilder};
 use getset::*;
 use rayon::prelude::*;
+use regex::RegexBuilder;
 use serde_derive::{Serialize, Deserialize};
 
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, hash_map::DefaultHasher};
 use std::{fmt, fmt::{Debug, Display}};
 use std::fs::{DirBuilder, File};
+use std::hash::{Hash, Hasher};
 use std::io::{BufReader, Cursor, Read, Seek, SeekFrom, BufWriter, Write};
 use std::path::{Path, PathBuf};
 
@@ -73,7 +82,8 @@ use crate::binary::{ReadBytes, WriteBytes};
 use crate::compression::Decompressible;
 use crate::encryption::Decryptable;
 use crate::error::{Result, RLibError};
-use crate::games::pfh_version::PFHVersion;
+use crate::games::{GameInfo, pfh_version::PFHVersion};
+#[cfg(feature = "integration_assembly_kit")] use crate::integrations::assembly_kit::table_data::write_raw_table;
 use crate::{REGEX_DB, REGEX_PORTRAIT_SETTINGS};
 use crate::schema::{Schema, Definition};
 use crate::utils::*;
@@ -81,6 +91,7 @@ use crate::utils::*;
 use self::anim_fragment::AnimFragment;
 use self::animpack::AnimPack;
 use self::anims_table::AnimsTable;
+use self::custom::Custom;
 use self::db::DB;
 use self::esf::ESF;
 use self::image::Image;
@@ -89,6 +100,8 @@ use self::matched_combat::MatchedCombat;
 use self::pack::Pack;
 use self::portrait_settings::PortraitSettings;
 use self::rigidmodel::RigidModel;
+use self::speedtree::SpeedTree;
+use self::table::{MergeConflict, Table};
 use self::text::Text;
 use self::uic::UIC;
 use self::unit_variant::UnitVariant;
@@ -98,6 +111,7 @@ use self::video::Video;
 pub mod anim_fragment;
 pub mod animpack;
 pub mod anims_table;
+pub mod custom;
 pub mod db;
 pub mod esf;
 pub mod image;
@@ -106,6 +120,7 @@ pub mod matched_combat;
 pub mod pack;
 pub mod portrait_settings;
 pub mod rigidmodel;
+pub mod speedtree;
 pub mod table;
 pub mod text;
 pub mod uic;
@@ -113,6 +128,9 @@ pub mod unit_variant;
 pub mod unknown;
 pub mod video;
 
+/// Delimiters we try to detect when importing CSV files, in order of preference.
+pub const CSV_DELIMITERS: [u8; 3] = [b',', b';', b'\t'];
+
 #[cfg(test)] mod rfile_test;
 
 //---------------------------------------------------------------------------//
@@ -143,6 +161,12 @@ pub struct RFile {
     ///
     /// Internal only. Users should use the [`RFile`] methods instead of using this directly.
     data: RFileInnerData,
+
+    /// Where the data in memory was loaded from, if it hasn't been changed since then.
+    ///
+    /// Used to drop the data from memory and lazy-load it again later. Internal only.
+    #[serde(skip)]
+    loaded_from: Option<LoadedFrom>,
 }
 
 /// This enum contains the data of each [`RFile`].
@@ -161,6 +185,21 @@ enum RFileInnerData {
     OnDisk(OnDisk)
 }
 
+/// This struct represents where on disk the data in memory of an [`RFile`] was loaded from.
+///
+/// This is internal only.
+#[derive(Clone, Debug, PartialEq)]
+struct LoadedFrom {
+
+    /// Where the data is on disk.
+    source: OnDisk,
+
+    /// Size in bytes of the file that contains the data, when the data was loaded.
+    ///
+    /// The timestamp of the source is in seconds, so this is also used to check if the file has been manipulated since then.
+    file_size: u64,
+}
+
 /// This struct represents a file on disk, which data has not been loaded to memory yet.
 ///
 /// This may be a file directly on disk, or one inside another file (like inside a [Container]).
@@ -195,6 +234,21 @@ struct OnDisk {
     is_encrypted: Option<PFHVersion>,
 }
 
+/// This enum represents how much of the data of an [`RFile`] is currently in memory.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RFileDataState {
+
+    /// The data hasn't been loaded to memory yet. It'll be lazy-loaded when needed.
+    #[default]
+    OnDisk,
+
+    /// The data has been loaded to memory, but it hasn't been decoded.
+    Cached,
+
+    /// The data has been loaded to memory and decoded.
+    Decoded,
+}
+
 /// This enum allow us to store any kind of decoded file type on a common place.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum RFileDecoded {
@@ -203,6 +257,7 @@ pub enum RFileDecoded {
     AnimPack(AnimPack),
     AnimsTable(AnimsTable),
     CEO(ESF),
+    Custom(Custom),
     DB(DB),
     ESF(ESF),
     GroupFormations(Unknown),
@@ -213,6 +268,7 @@ pub enum RFileDecoded {
     PortraitSettings(PortraitSettings),
     RigidModel(RigidModel),
     Save(ESF),
+    SpeedTree(SpeedTree),
     Text(Text),
     UIC(UIC),
     UnitVariant(UnitVariant),
@@ -232,6 +288,7 @@ pub enum FileType {
     AnimPack,
     AnimsTable,
     CEO,
+    Custom,
     DB,
     ESF,
     GroupFormations,
@@ -242,6 +299,7 @@ pub enum FileType {
     PortraitSettings,
     RigidModel,
     Save,
+    SpeedTree,
     Text,
     UIC,
     UnitVariant,
@@ -264,6 +322,22 @@ pub enum ContainerPath {
     Folder(String),
 }
 
+/// This enum represents the formats in which DB and Loc files can be extracted from a [Container].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExtractionFormat {
+
+    /// Files are extracted as they are in the container.
+    #[default]
+    Raw,
+
+    /// DB and Loc files are extracted as TSV files.
+    Tsv,
+
+    /// DB files are extracted as Assembly Kit tables (xml). Everything else is extracted as it is.
+    #[cfg(feature = "integration_assembly_kit")]
+    AssKitXml,
+}
+
 /// This is a generic struct to easily pass additional data to a [Decodeable::decode] method.
 ///
 /// To know what you need to provide to each file type, please check their documentation.
@@ -320,6 +394,9 @@ pub struct DecodeableExtraData<'a> {
 
     /// Size of the data in a file, either on disk or in memory.
     data_size: u64,
+
+    /// Game the file is for. Used to apply game-specific format overrides.
+    game_info: Option<&'a GameInfo>,
 }
 
 /// This is a generic struct to easily pass additional data to a [Encodeable::encode] method.
@@ -391,6 +468,14 @@ pub trait Container {
     ///
     /// If a schema is provided, this function will try to extract any DB/Loc file as a TSV. If it fails to decode them, it'll extract them as binary files.
     fn extract(&mut self, container_path: ContainerPath, destination_path: &Path, keep_container_path_structure: bool, schema: &Option<Schema>) -> Result<()> {
+        let format = if schema.is_some() { ExtractionFormat::Tsv } else { ExtractionFormat::Raw };
+        self.extract_as(container_path, destination_path, keep_container_path_structure, schema, format)
+    }
+
+    /// This method works like [extract](Self::extract), but allows to choose the format in which DB/Loc files are extracted.
+    ///
+    /// Formats other than [ExtractionFormat::Raw] need a schema. Without one, or if a file fails to decode, it's extracted as a binary file.
+    fn extract_as(&mut self, container_path: ContainerPath, destination_path: &Path, keep_container_path_structure: bool, schema: &Option<Schema>, format: ExtractionFormat) -> Result<()> {
         match container_path {
             ContainerPath::File(mut container_path) => {
                 if container_path.starts_with('/') {
@@ -408,49 +493,7 @@ pub trait Container {
                 DirBuilder::new().recursive(true).create(&destination_folder)?;
 
                 let rfile = self.files_mut().get_mut(&container_path).ok_or_else(|| RLibError::FileNotFound(container_path.to_string()))?;
-
-                // If we want to extract as tsv and we got a db/loc, export to tsv.
-                if let Some(schema) = schema {
-                    if rfile.file_type() == FileType::DB || rfile.file_type() == FileType::Loc {
-                        let mut destination_path_tsv = destination_path.to_owned();
-
-                        // Make sure to NOT replace the extension if there is one, only append to it.
-                        match destination_path_tsv.extension() {
-                            Some(extension) => {
-                                let extension = format!("{}.tsv", extension.to_string_lossy());
-                                destination_path_tsv.set_extension(extension)
-                            },
-                            None => destination_path_tsv.set_extension("tsv"),
-                        };
-
-                        let result = rfile.tsv_export_to_path(&destination_path_tsv, schema);
-
-                        // If it fails to extract as tsv, extract as binary.
-                        if result.is_err() {
-
-                            #[cfg(feature = "integration_log")] {
-                                warn!("File with path {} failed to extract as TSV. Extracting it as binary.", rfile.path_in_container_raw());
-                            }
-
-                            let mut file = BufWriter::new(File::create(&destination_path)?);
-                            let data = rfile.encode(&None, false, false, true)?.unwrap();
-                            file.write_all(&data).map_err(From::from)
-                        } else {
-                            result
-                        }
-                    } else {
-                        let mut file = BufWriter::new(File::create(&destination_path)?);
-                        let data = rfile.encode(&None, false, false, true)?.unwrap();
-                        file.write_all(&data).map_err(From::from)
-                    }
-                }
-
-                // Otherwise, just write the binary data to disk.
-                else {
-                    let mut file = BufWriter::new(File::create(&destination_path)?);
-                    let data = rfile.encode(&None, false, false, true)?.unwrap();
-                    file.write_all(&data).map_err(From::from)
-                }
+                rfile.extract_to_path(&destination_path, schema, format)
             }
             ContainerPath::Folder(mut container_path) => {
                 if container_path.starts_with('/') {
@@ -470,47 +513,7 @@ pub trait Container {
                     destination_folder.pop();
                     DirBuilder::new().recursive(true).create(&destination_folder)?;
 
-                    // If we want to extract as tsv and we got a db/loc, export to tsv.
-                    if let Some(schema) = schema {
-                        if rfile.file_type() == FileType::DB || rfile.file_type() == FileType::Loc {
-                            let mut destination_path_tsv = destination_path.to_owned();
-
-                            // Make sure to NOT replace the extension if there is one, only append to it.
-                            match destination_path_tsv.extension() {
-                                Some(extension) => {
-                                    let extension = format!("{}.tsv", extension.to_string_lossy());
-                                    destination_path_tsv.set_extension(extension)
-                                },
-                                None => destination_path_tsv.set_extension("tsv"),
-                            };
-
-                            let result = rfile.tsv_export_to_path(&destination_path_tsv, schema);
-
-                            // If it fails to extract as tsv, extract as binary.
-                            if result.is_err() {
-
-                                #[cfg(feature = "integration_log")] {
-                                    warn!("File with path {} failed to extract as TSV. Extracting it as binary.", rfile.path_in_container_raw());
-                                }
-
-                                let mut file = BufWriter::new(File::create(&destination_path)?);
-                                let data = rfile.encode(&None, false, false, true)?.unwrap();
-                                file.write_all(&data)?;
-                            }
-                        } else {
-                            let mut file = BufWriter::new(File::create(&destination_path)?);
-                            let data = rfile.encode(&None, false, false, true)?.unwrap();
-                            file.write_all(&data)?;
-                        }
-                    }
-
-                    // Otherwise, just write the binary data to disk.
-                    else {
-                        let mut file = BufWriter::new(File::create(&destination_path)?);
-                        let data = rfile.encode(&None, false, false, true)?.unwrap();
-                        file.write_all(&data)?;
-                    }
-
+                    rfile.extract_to_path(&destination_path, schema, format)?;
                 }
 
                 // If we're extracting the whole container, also extract any relevant metadata file associated with it.
@@ -523,6 +526,18 @@ pub trait Container {
         }
     }
 
+    /// This method allow us to extract multiple [ContainerPath] from a Container to disk, in one go.
+    ///
+    /// Overlapping paths (like a folder and a file within it) are deduplicated before extracting, so each file is only extracted once.
+    /// The rest of the arguments work like in [extract](Self::extract).
+    fn extract_paths(&mut self, container_paths: &[ContainerPath], destination_path: &Path, keep_container_path_structure: bool, schema: &Option<Schema>) -> Result<()> {
+        for container_path in ContainerPath::dedup(container_paths) {
+            self.extract(container_path, destination_path, keep_container_path_structure, schema)?;
+        }
+
+        Ok(())
+    }
+
     /// This method allows us to extract the metadata associated to the provided container as `.json` files.
     ///
     /// Default implementation does nothing.
@@ -595,8 +610,11 @@ pub trait Container {
             }
         }?;
 
+        // Imported files are built from their data, so they need the timestamp of their source set manually.
         if !tsv_imported {
             rfile.set_path_in_container_raw(&container_path_folder);
+        } else {
+            rfile.set_timestamp(Some(last_modified_time_from_file(&File::open(source_path)?)?));
         }
 
         // Make sure to guess the file type before inserting it.
@@ -672,6 +690,8 @@ pub trait Container {
 
             if !tsv_imported {
                 rfile.set_path_in_container_raw(&file_container_path);
+            } else {
+                rfile.set_timestamp(Some(last_modified_time_from_file(&File::open(&file_path)?)?));
             }
 
             if let Some(path) = self.insert(rfile)? {
@@ -791,6 +811,17 @@ pub trait Container {
         self.files_mut().par_iter_mut().filter(|(_, file)| file_types.contains(&file.file_type)).map(|(_, file)| file).collect()
     }
 
+    /// This method drops from memory the data of the RFiles inside the provided Container that can be lazy-loaded again,
+    /// except the ones of the provided FileTypes.
+    ///
+    /// Check [RFile::unload] for more info. It returns the amount of bytes released.
+    fn unload_files(&mut self, skip_types: &[FileType]) -> u64 {
+        self.files_mut().par_iter_mut()
+            .filter(|(_, file)| !skip_types.contains(&file.file_type))
+            .map(|(_, file)| file.unload())
+            .sum()
+    }
+
     /// This method returns a reference to the RFiles inside the provided Container that match the provided [ContainerPath].
     ///
     /// An special situation is passing `ContainerPath::Folder("")`. This represents the root of the container,
@@ -818,12 +849,14 @@ pub trait Container {
                 }
 
                 // Otherwise, only get the files under our folder.
+                // Make sure to only pick folders, not files matching folder names or partial folder matches!
                 else {
+                    let path = format!("{}/", path.trim_end_matches('/'));
                     self.files().par_iter()
                         .filter_map(|(key, file)|
                             if case_insensitive {
-                                if starts_with_case_insensitive(key, path) { Some(file) } else { None }
-                            } else if key.starts_with(path) {
+                                if starts_with_case_insensitive(key, &path) { Some(file) } else { None }
+                            } else if key.starts_with(&path) {
                                 Some(file)
                             } else {
                                 None
@@ -861,12 +894,14 @@ pub trait Container {
                 }
 
                 // Otherwise, only get the files under our folder.
+                // Make sure to only pick folders, not files matching folder names or partial folder matches!
                 else {
+                    let path = format!("{}/", path.trim_end_matches('/'));
                     self.files_mut().par_iter_mut()
                         .filter_map(|(key, file)|
                             if case_insensitive {
-                                if starts_with_case_insensitive(key, path) { Some(file) } else { None }
-                            } else if key.starts_with(path) {
+                                if starts_with_case_insensitive(key, &path) { Some(file) } else { None }
+                            } else if key.starts_with(&path) {
                                 Some(file)
                             } else {
                                 None
@@ -877,6 +912,15 @@ pub trait Container {
         }
     }
 
+    /// This method returns a reference to the RFiles inside the provided Container that are within the provided folder.
+    ///
+    /// Only full folder names are matched, so `db/units` doesn't match `db/units_tables/x`. Leading and trailing slashes are ignored.
+    /// An empty prefix represents the root of the container, returning all RFiles within the container.
+    fn files_by_path_prefix(&self, prefix: &str, case_insensitive: bool) -> Vec<&RFile> {
+        let prefix = prefix.trim_start_matches('/').trim_end_matches('/');
+        self.files_by_path(&ContainerPath::Folder(prefix.to_owned()), case_insensitive)
+    }
+
     /// This method returns a reference to the RFiles inside the provided Container that match one of the provided [ContainerPath].
     fn files_by_paths(&self, paths: &[ContainerPath], case_insensitive: bool) -> Vec<&RFile> {
         paths.iter()
@@ -901,10 +945,13 @@ pub trait Container {
                             }
                         }
                         ContainerPath::Folder(path) => {
-                            if case_insensitive {
-                                starts_with_case_insensitive(file_path, path)
+                            let path = format!("{}/", path.trim_end_matches('/'));
+                            if path == "/" {
+                                true
+                            } else if case_insensitive {
+                                starts_with_case_insensitive(file_path, &path)
                             } else {
-                                file_path.starts_with(path)
+                                file_path.starts_with(&path)
                             }
                         }
                     }
@@ -1090,6 +1137,234 @@ pub trait Container {
         }
     }
 
+    /// This function allows you to rename multiple RFiles using a regex pattern and a replacement, which may use the pattern's capture groups.
+    ///
+    /// The pattern is applied to the full path of each RFile. Folders get the pattern applied to every RFile under them.
+    /// If any of the new paths collides with another path in the Container, nothing gets renamed.
+    ///
+    /// It returns a list with all the old and new [ContainerPath].
+    fn rename_paths_by_pattern(&mut self, paths: &[ContainerPath], pattern: &str, replacement: &str, case_insensitive: bool) -> Result<Vec<(ContainerPath, ContainerPath)>> {
+        let regex = RegexBuilder::new(pattern).case_insensitive(case_insensitive).build()?;
+
+        let mut renames = self.files_by_paths(paths, false)
+            .iter()
+            .filter_map(|file| {
+                let old_path = file.path_in_container_raw();
+                let new_path = regex.replace_all(old_path, replacement);
+                if new_path != old_path {
+                    Some((old_path.to_owned(), new_path.to_string()))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        renames.sort();
+        renames.dedup();
+
+        if renames.iter().any(|(_, new_path)| new_path.is_empty()) {
+            return Err(RLibError::EmptyDestiny);
+        }
+
+        // Check for collisions before renaming anything. Paths that are going to be renamed are free to take.
+        let old_paths = renames.iter().map(|(old_path, _)| old_path.as_str()).collect::<HashSet<_>>();
+        let mut new_paths = HashSet::new();
+        let mut collisions = renames.iter()
+            .filter(|(_, new_path)| !new_paths.insert(new_path.as_str()) || (self.files().contains_key(new_path) && !old_paths.contains(new_path.as_str())))
+            .map(|(_, new_path)| new_path.to_owned())
+            .collect::<Vec<_>>();
+
+        if !collisions.is_empty() {
+            collisions.sort();
+            collisions.dedup();
+            return Err(RLibError::RenamePathsCollide(collisions));
+        }
+
+        // Take all the files out first, so renames that take the old path of another renamed file do not overwrite it.
+        let mut moved = Vec::with_capacity(renames.len());
+        for (old_path, new_path) in &renames {
+            if let Some(mut file) = self.files_mut().remove(old_path) {
+                file.set_path_in_container_raw(new_path);
+                moved.push((old_path, file));
+            }
+        }
+
+        let mut renamed = Vec::with_capacity(moved.len());
+        for (old_path, file) in moved {
+            if let Some(new_path) = self.insert(file)? {
+                renamed.push((ContainerPath::File(old_path.to_owned()), new_path));
+            }
+        }
+
+        Ok(renamed)
+    }
+
+    /// This function returns the new paths the RFiles under the provided paths would have if they were moved or copied to the provided folder.
+    ///
+    /// Files keep their name, and folders are moved with all their contents. Paths already in the destination folder are ignored.
+    ///
+    /// It returns a list with all the old and new paths, sorted by old path.
+    fn paths_to_folder(&self, paths: &[ContainerPath], destination_folder: &str) -> Vec<(String, String)> {
+        let destination_folder = destination_folder.trim_matches('/');
+        let new_path = |name: &str| if destination_folder.is_empty() { name.to_owned() } else { format!("{}/{}", destination_folder, name) };
+
+        let mut mappings = paths.iter()
+            .flat_map(|path| match path {
+                ContainerPath::File(path) => {
+                    let name = path.rsplit('/').next().unwrap_or(path);
+                    vec![(path.to_owned(), new_path(name))]
+                },
+                ContainerPath::Folder(path) => {
+
+                    // Moving the root of the Container somewhere makes no sense.
+                    if path.is_empty() {
+                        return vec![];
+                    }
+
+                    let folder_name = path.rsplit('/').next().unwrap_or(path);
+                    let prefix = format!("{}/", path);
+                    self.files().keys()
+                        .filter_map(|file_path| file_path.strip_prefix(&prefix)
+                            .map(|relative_path| (file_path.to_owned(), new_path(&format!("{}/{}", folder_name, relative_path)))))
+                        .collect::<Vec<_>>()
+                },
+            })
+            .filter(|(old_path, new_path)| old_path != new_path)
+            .collect::<Vec<_>>();
+
+        mappings.sort();
+        mappings.dedup();
+        mappings
+    }
+
+    /// This function allows you to *move* multiple RFiles or folders of RFiles into another folder, keeping their names.
+    ///
+    /// If any of the new paths collides with another path in the Container, nothing gets moved.
+    ///
+    /// It returns a list with all the old and new [ContainerPath].
+    fn move_paths_to_folder(&mut self, paths: &[ContainerPath], destination_folder: &str) -> Result<Vec<(ContainerPath, ContainerPath)>> {
+        let destination_folder = destination_folder.trim_matches('/');
+        for path in paths {
+            if let ContainerPath::Folder(path) = path {
+                if destination_folder == path || destination_folder.starts_with(&format!("{}/", path)) {
+                    return Err(RLibError::MoveFolderIntoItself(path.to_owned()));
+                }
+            }
+        }
+
+        let moves = self.paths_to_folder(paths, destination_folder);
+
+        // Check for collisions before moving anything. Paths that are going to be moved are free to take.
+        let old_paths = moves.iter().map(|(old_path, _)| old_path.as_str()).collect::<HashSet<_>>();
+        let mut new_paths = HashSet::new();
+        let mut collisions = moves.iter()
+            .filter(|(_, new_path)| !new_paths.insert(new_path.as_str()) || (self.files().contains_key(new_path) && !old_paths.contains(new_path.as_str())))
+            .map(|(_, new_path)| new_path.to_owned())
+            .collect::<Vec<_>>();
+
+        if !collisions.is_empty() {
+            collisions.sort();
+            collisions.dedup();
+            return Err(RLibError::RenamePathsCollide(collisions));
+        }
+
+        let mut moved = Vec::with_capacity(moves.len());
+        for (old_path, new_path) in &moves {
+            if let Some(mut file) = self.files_mut().remove(old_path) {
+                file.set_path_in_container_raw(new_path);
+                moved.push((old_path, file));
+            }
+        }
+
+        let mut new_paths = Vec::with_capacity(moved.len());
+        for (old_path, file) in moved {
+            if let Some(new_path) = self.insert(file)? {
+                new_paths.push((ContainerPath::File(old_path.to_owned()), new_path));
+            }
+        }
+
+        Ok(new_paths)
+    }
+
+    /// This function allows you to *copy* multiple RFiles or folders of RFiles into another folder, keeping their names.
+    ///
+    /// As DB tables only need an unique name within their table folder, copies of DB tables that collide with
+    /// another path get a `_copy` suffix instead. If any other new path collides with another path in the Container, nothing gets copied.
+    ///
+    /// It returns a list with all the original and new [ContainerPath].
+    fn copy_paths_to_folder(&mut self, paths: &[ContainerPath], destination_folder: &str) -> Result<Vec<(ContainerPath, ContainerPath)>> {
+
+        // Copying a file into its own folder is a valid way of duplicating it.
+        let mut copies = paths.iter()
+            .filter_map(|path| match path {
+                ContainerPath::File(path) if path.rsplit_once('/').map(|(folder, _)| folder).unwrap_or("") == destination_folder.trim_matches('/') => Some((path.to_owned(), path.to_owned())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        copies.append(&mut self.paths_to_folder(paths, destination_folder));
+        copies.sort();
+        copies.dedup();
+
+        let mut new_paths = HashSet::new();
+        let mut collisions = vec![];
+        for (_, new_path) in copies.iter_mut() {
+            if self.files().contains_key(new_path) || new_paths.contains(new_path) {
+                if ContainerPath::File(new_path.to_owned()).db_table_name_from_path().is_some() {
+                    let base_path = new_path.to_owned();
+                    let mut index = 1;
+                    while self.files().contains_key(new_path) || new_paths.contains(new_path) {
+                        *new_path = if index == 1 { format!("{}_copy", base_path) } else { format!("{}_copy_{}", base_path, index) };
+                        index += 1;
+                    }
+                } else {
+                    collisions.push(new_path.to_owned());
+                }
+            }
+
+            new_paths.insert(new_path.to_owned());
+        }
+
+        if !collisions.is_empty() {
+            collisions.sort();
+            collisions.dedup();
+            return Err(RLibError::RenamePathsCollide(collisions));
+        }
+
+        let mut copied = Vec::with_capacity(copies.len());
+        for (old_path, new_path) in &copies {
+            if let Some(file) = self.files().get(old_path) {
+                let mut file = file.clone();
+                file.set_path_in_container_raw(new_path);
+                if let Some(new_path) = self.insert(file)? {
+                    copied.push((ContainerPath::File(old_path.to_owned()), new_path));
+                }
+            }
+        }
+
+        Ok(copied)
+    }
+
+    /// This function duplicates a DB or Loc table of the Container, giving the copy the provided name.
+    ///
+    /// The name can be either a file name or a full path. Check [ContainerPath::duplicate_table_path] for the rules the new path must follow.
+    /// The copy keeps the definition and version of the source table. It returns the [ContainerPath] of the copy.
+    fn duplicate_table(&mut self, source_path: &str, new_name: &str) -> Result<ContainerPath> {
+        let new_path = ContainerPath::File(source_path.to_owned()).duplicate_table_path(new_name)?;
+
+        let mut file = match self.files().get(source_path) {
+            Some(file) if matches!(file.file_type(), FileType::DB | FileType::Loc) => file.clone(),
+            Some(_) => return Err(RLibError::DuplicateTableNotATable(source_path.to_owned())),
+            None => return Err(RLibError::FileNotFound(source_path.to_owned())),
+        };
+
+        if self.files().contains_key(&new_path) {
+            return Err(RLibError::RenamePathsCollide(vec![new_path]));
+        }
+
+        file.set_path_in_container_raw(&new_path);
+        self.insert(file)?.ok_or(RLibError::ReservedFiles)
+    }
+
     /// This function removes all not-in-memory-already Files from the Container.
     ///
     /// Used for removing possibly corrupted RFiles from the Container in order to sanitize it.
@@ -1140,7 +1415,8 @@ impl RFile {
             path: path_in_container.to_owned(),
             timestamp: if file_timestamp == 0 { None } else { Some(file_timestamp) },
             file_type: FileType::Unknown,
-            data: RFileInnerData::OnDisk(on_disk)
+            data: RFileInnerData::OnDisk(on_disk),
+            loaded_from: None,
         };
 
         Ok(rfile)
@@ -1172,7 +1448,8 @@ impl RFile {
             path: path.to_owned(),
             timestamp: Some(on_disk.timestamp),
             file_type: FileType::Unknown,
-            data: RFileInnerData::OnDisk(on_disk)
+            data: RFileInnerData::OnDisk(on_disk),
+            loaded_from: None,
         };
 
         Ok(rfile)
@@ -1196,7 +1473,8 @@ impl RFile {
             path: path.to_owned(),
             timestamp: if timestamp == 0 { None } else { Some(timestamp) },
             file_type,
-            data: RFileInnerData::Cached(data.to_vec())
+            data: RFileInnerData::Cached(data.to_vec()),
+            loaded_from: None,
         }
     }
 
@@ -1208,7 +1486,8 @@ impl RFile {
             path: path.to_owned(),
             timestamp: if timestamp == 0 { None } else { Some(timestamp) },
             file_type: FileType::from(data),
-            data: RFileInnerData::Decoded(Box::new(data.clone()))
+            data: RFileInnerData::Decoded(Box::new(data.clone())),
+            loaded_from: None,
         }
     }
 
@@ -1226,6 +1505,7 @@ impl RFile {
     ///
     /// Useful for accessing preloaded data.
     pub fn cached_mut(&mut self) -> Result<&mut Vec<u8>> {
+        self.loaded_from = None;
         match self.data {
             RFileInnerData::Cached(ref mut data) => Ok(data),
             _ => Err(RLibError::FileNotCached(self.path_in_container_raw().to_string()))
@@ -1246,6 +1526,7 @@ impl RFile {
     ///
     /// Useful for accessing preloaded data.
     pub fn decoded_mut(&mut self) -> Result<&mut RFileDecoded> {
+        self.loaded_from = None;
         match self.data {
             RFileInnerData::Decoded(ref mut data) => Ok(data),
             _ => Err(RLibError::FileNotDecoded(self.path_in_container_raw().to_string()))
@@ -1255,6 +1536,7 @@ impl RFile {
     /// This function replace any data a RFile has with the provided raw data.
     pub fn set_cached(&mut self, data: &[u8]) {
         self.data = RFileInnerData::Cached(data.to_vec());
+        self.loaded_from = None;
     }
 
     /// This function allows to replace the inner decoded data of a RFile with another. It'll fail if the decoded data is not valid for the file's type.
@@ -1265,6 +1547,7 @@ impl RFile {
             (FileType::AnimPack, &RFileDecoded::AnimPack(_)) |
             (FileType::AnimsTable, &RFileDecoded::AnimsTable(_)) |
             (FileType::CEO, &RFileDecoded::CEO(_)) |
+            (FileType::Custom, &RFileDecoded::Custom(_)) |
             (FileType::DB, &RFileDecoded::DB(_)) |
             (FileType::ESF, &RFileDecoded::ESF(_)) |
             (FileType::GroupFormations, &RFileDecoded::GroupFormations(_)) |
@@ -1275,11 +1558,15 @@ impl RFile {
             (FileType::PortraitSettings, &RFileDecoded::PortraitSettings(_)) |
             (FileType::RigidModel, &RFileDecoded::RigidModel(_)) |
             (FileType::Save, &RFileDecoded::Save(_)) |
+            (FileType::SpeedTree, &RFileDecoded::SpeedTree(_)) |
             (FileType::Text, &RFileDecoded::Text(_)) |
             (FileType::UIC, &RFileDecoded::UIC(_)) |
             (FileType::UnitVariant, &RFileDecoded::UnitVariant(_)) |
             (FileType::Unknown, &RFileDecoded::Unknown(_)) |
-            (FileType::Video, &RFileDecoded::Video(_)) => self.data = RFileInnerData::Decoded(Box::new(decoded)),
+            (FileType::Video, &RFileDecoded::Video(_)) => {
+                self.data = RFileInnerData::Decoded(Box::new(decoded));
+                self.loaded_from = None;
+            }
             _ => return Err(RLibError::DecodedDataDoesNotMatchFileType(self.file_type(), From::from(&decoded)))
         }
 
@@ -1297,6 +1584,12 @@ impl RFile {
     /// NOTE: Passing `keep_in_cache` and `return_data` at false causes this function to decode the RFile and
     /// immediately drop the resulting data.
     pub fn decode(&mut self, extra_data: &Option<DecodeableExtraData>, keep_in_cache: bool, return_data: bool) -> Result<Option<RFileDecoded>> {
+
+        // Files detected before a decoder for them was registered are unknown, so check the registered decoders before decoding them as unknown.
+        if self.file_type == FileType::Unknown && !matches!(self.data, RFileInnerData::Decoded(_)) && custom::decoder_for_path(&self.path).is_some() {
+            self.file_type = FileType::Custom;
+        }
+
         let mut already_decoded = false;
         let decoded = match &self.data {
 
@@ -1331,6 +1624,7 @@ impl RFile {
                     FileType::AnimPack => RFileDecoded::AnimPack(AnimPack::decode(&mut data, &Some(extra_data))?),
                     FileType::AnimsTable => RFileDecoded::AnimsTable(AnimsTable::decode(&mut data, &Some(extra_data))?),
                     FileType::CEO => RFileDecoded::CEO(ESF::decode(&mut data, &Some(extra_data))?),
+                    FileType::Custom => RFileDecoded::Custom(Custom::decode(&mut data, &Some(extra_data))?),
                     FileType::DB => {
 
                         if extra_data.table_name.is_none() {
@@ -1347,6 +1641,7 @@ impl RFile {
                     FileType::PortraitSettings => RFileDecoded::PortraitSettings(PortraitSettings::decode(&mut data, &Some(extra_data))?),
                     FileType::RigidModel => RFileDecoded::RigidModel(RigidModel::decode(&mut data, &Some(extra_data))?),
                     FileType::Save => RFileDecoded::Save(ESF::decode(&mut data, &Some(extra_data))?),
+                    FileType::SpeedTree => RFileDecoded::SpeedTree(SpeedTree::decode(&mut data, &Some(extra_data))?),
                     FileType::Text => RFileDecoded::Text(Text::decode(&mut data, &Some(extra_data))?),
                     FileType::UIC => RFileDecoded::UIC(UIC::decode(&mut data, &Some(extra_data))?),
                     FileType::UnitVariant => RFileDecoded::UnitVariant(UnitVariant::decode(&mut data, &Some(extra_data))?),
@@ -1365,6 +1660,7 @@ impl RFile {
                     FileType::AnimsTable |
                     FileType::Video |
                     FileType::CEO |
+                    FileType::Custom |
                     FileType::DB |
                     FileType::ESF |
                     FileType::GroupFormations |
@@ -1374,6 +1670,7 @@ impl RFile {
                     FileType::PortraitSettings |
                     FileType::RigidModel |
                     FileType::Save |
+                    FileType::SpeedTree |
                     FileType::Text |
                     FileType::UIC |
                     FileType::UnitVariant |
@@ -1396,6 +1693,7 @@ impl RFile {
                             FileType::AnimFragment => RFileDecoded::AnimFragment(AnimFragment::decode(&mut data, &Some(extra_data))?),
                             FileType::AnimsTable => RFileDecoded::AnimsTable(AnimsTable::decode(&mut data, &Some(extra_data))?),
                             FileType::CEO => RFileDecoded::CEO(ESF::decode(&mut data, &Some(extra_data))?),
+                            FileType::Custom => RFileDecoded::Custom(Custom::decode(&mut data, &Some(extra_data))?),
                             FileType::DB => {
 
                                 if extra_data.table_name.is_none() {
@@ -1411,6 +1709,7 @@ impl RFile {
                             FileType::PortraitSettings => RFileDecoded::PortraitSettings(PortraitSettings::decode(&mut data, &Some(extra_data))?),
                             FileType::RigidModel => RFileDecoded::RigidModel(RigidModel::decode(&mut data, &Some(extra_data))?),
                             FileType::Save => RFileDecoded::Save(ESF::decode(&mut data, &Some(extra_data))?),
+                            FileType::SpeedTree => RFileDecoded::SpeedTree(SpeedTree::decode(&mut data, &Some(extra_data))?),
                             FileType::Text => RFileDecoded::Text(Text::decode(&mut data, &Some(extra_data))?),
                             FileType::UIC => RFileDecoded::UIC(UIC::decode(&mut data, &Some(extra_data))?),
                             FileType::UnitVariant => RFileDecoded::UnitVariant(UnitVariant::decode(&mut data, &Some(extra_data))?),
@@ -1426,12 +1725,12 @@ impl RFile {
                     FileType::Pack => {
 
                         // These two require extra data and may require lazy-loading.
-                        // For lazy-loading, disable it if we detect encryption.
+                        // For lazy-loading, disable it if we detect encryption, either in the container or in this file.
                         let mut extra_data = match extra_data {
                             Some(extra_data) => extra_data.clone(),
                             None => DecodeableExtraData::default(),
                         };
-                        extra_data.lazy_load = !extra_data.is_encrypted && extra_data.lazy_load;
+                        extra_data.lazy_load = !extra_data.is_encrypted && data.is_encrypted.is_none() && extra_data.lazy_load;
                         extra_data.file_name = self.file_name();
                         extra_data.data_size = data.size;
 
@@ -1466,6 +1765,12 @@ impl RFile {
         };
 
         // If we're returning data, clone it. If not, skip the clone.
+        if let RFileInnerData::OnDisk(data) = &self.data {
+            if keep_in_cache {
+                self.loaded_from = LoadedFrom::new(data);
+            }
+        }
+
         if !already_decoded && keep_in_cache && return_data {
             self.data = RFileInnerData::Decoded(Box::new(decoded.clone()));
         } else if !already_decoded && keep_in_cache && !return_data{
@@ -1502,6 +1807,7 @@ impl RFile {
                     RFileDecoded::AnimPack(data) => data.encode(&mut buffer, extra_data)?,
                     RFileDecoded::AnimsTable(data) => data.encode(&mut buffer, extra_data)?,
                     RFileDecoded::CEO(data) => data.encode(&mut buffer, extra_data)?,
+                    RFileDecoded::Custom(data) => data.encode(&mut buffer, extra_data)?,
                     RFileDecoded::DB(data) => data.encode(&mut buffer, extra_data)?,
                     RFileDecoded::ESF(data) => data.encode(&mut buffer, extra_data)?,
                     RFileDecoded::GroupFormations(data) => data.encode(&mut buffer, extra_data)?,
@@ -1512,6 +1818,7 @@ impl RFile {
                     RFileDecoded::PortraitSettings(data) => data.encode(&mut buffer, extra_data)?,
                     RFileDecoded::RigidModel(data) => data.encode(&mut buffer, extra_data)?,
                     RFileDecoded::Save(data) => data.encode(&mut buffer, extra_data)?,
+                    RFileDecoded::SpeedTree(data) => data.encode(&mut buffer, extra_data)?,
                     RFileDecoded::Text(data) => data.encode(&mut buffer, extra_data)?,
                     RFileDecoded::UIC(data) => data.encode(&mut buffer, extra_data)?,
                     RFileDecoded::UnitVariant(data) => data.encode(&mut buffer, extra_data)?,
@@ -1551,6 +1858,10 @@ impl RFile {
         // If the RFile was not even loaded.
         else if previously_undecoded {
             if move_undecoded_to_cache {
+                if let RFileInnerData::OnDisk(data) = &self.data {
+                    self.loaded_from = LoadedFrom::new(data);
+                }
+
                 if return_data {
                     self.data = RFileInnerData::Cached(encoded.to_vec());
                     Ok(Some(encoded))
@@ -1583,6 +1894,7 @@ impl RFile {
                 return Ok(())
             },
             RFileInnerData::OnDisk(data) => {
+                self.loaded_from = LoadedFrom::new(data);
                 data.read(data.is_compressed, data.is_encrypted)?
             },
         };
@@ -1597,11 +1909,98 @@ impl RFile {
         Ok(())
     }
 
+    /// This function drops the data of the RFile from memory, so it's lazy-loaded again the next time it's needed.
+    ///
+    /// Only RFiles which haven't been changed since they were loaded can be unloaded, and only if the file they were loaded from
+    /// hasn't changed on disk since then. Otherwise, the data is kept in memory.
+    ///
+    /// It returns the amount of bytes released. For decoded RFiles this is an estimation, based on the size of their data on disk.
+    pub fn unload(&mut self) -> u64 {
+        let size = match &self.data {
+            RFileInnerData::OnDisk(_) => return 0,
+            RFileInnerData::Cached(data) => data.len() as u64,
+            RFileInnerData::Decoded(_) => self.loaded_from.as_ref().map(|loaded_from| loaded_from.source.size).unwrap_or_default(),
+        };
+
+        // If the source changed on disk, the offsets we have are no longer valid, so keep the data in memory.
+        match self.loaded_from.take() {
+            Some(loaded_from) if !loaded_from.is_outdated() => {
+                self.data = RFileInnerData::OnDisk(loaded_from.source);
+                size
+            }
+            _ => 0,
+        }
+    }
+
+    /// This function forgets where the data of this RFile was loaded from, if it was loaded from the provided path.
+    ///
+    /// Use it when the file at that path is overwritten, so the RFile is not unloaded with outdated offsets.
+    pub(crate) fn forget_source(&mut self, path: &str) {
+        if self.loaded_from.as_ref().map(|loaded_from| loaded_from.source.path == path).unwrap_or(false) {
+            self.loaded_from = None;
+        }
+    }
+
+    /// This function returns the data of the RFile exactly as it's stored in its container, without decompressing or decrypting it.
+    ///
+    /// Only works with RFiles that have not been loaded to memory yet.
+    pub(crate) fn data_as_stored(&self) -> Result<Vec<u8>> {
+        match &self.data {
+            RFileInnerData::OnDisk(data) => data.read(false, None),
+            _ => Err(RLibError::FileNotOnDisk(self.path_in_container_raw().to_owned())),
+        }
+    }
+
+    /// This function shrinks the data range of a not-yet-loaded RFile, so it doesn't go past the end of its source file.
+    ///
+    /// Returns the old and new sizes if the range had to be shrunk. The data past the end is lost, so only use this to rescue broken Packs.
+    pub(crate) fn fit_data_to_source(&mut self) -> Result<Option<(u64, u64)>> {
+        match &mut self.data {
+            RFileInnerData::OnDisk(data) => {
+                let available = File::open(&data.path)?.len()?.saturating_sub(data.start);
+                if data.size > available {
+                    let old_size = data.size;
+                    data.size = available;
+                    Ok(Some((old_size, available)))
+                } else {
+                    Ok(None)
+                }
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// This function returns a hash of the raw data of this RFile, without decoding it.
+    ///
+    /// Data is hashed decompressed and decrypted, so the same file hashes the same no matter how it's stored.
+    /// Decoded RFiles are not supported, as they would need to be encoded first.
+    pub fn data_hash(&self) -> Result<u64> {
+        let mut hasher = DefaultHasher::new();
+        match &self.data {
+            RFileInnerData::OnDisk(data) => data.read(data.is_compressed, data.is_encrypted)?.hash(&mut hasher),
+            RFileInnerData::Cached(data) => data.hash(&mut hasher),
+            RFileInnerData::Decoded(_) => return Err(RLibError::FileAlreadyDecoded(self.path_in_container_raw().to_owned())),
+        }
+
+        Ok(hasher.finish())
+    }
+
     /// This function returns a copy of the `Last modified date` of this RFile, if any.
     pub fn timestamp(&self) -> Option<u64> {
         self.timestamp
     }
 
+    /// This function sets the `Last modified date` of this RFile.
+    pub fn set_timestamp(&mut self, timestamp: Option<u64>) {
+        self.timestamp = timestamp;
+    }
+
+    /// This function sets the `Last modified date` of this RFile to now, using the same clock used for the timestamp of Packs.
+    pub fn touch(&mut self) -> Result<()> {
+        self.timestamp = Some(current_time()?);
+        Ok(())
+    }
+
     /// This function returns a copy of the FileType of this RFile.
     pub fn file_type(&self) -> FileType {
         self.file_type
@@ -1649,6 +2048,85 @@ impl RFile {
         !matches!(self.file_type, FileType::DB | FileType::Loc)
     }
 
+    /// This function returns if the RFile's data is encrypted on disk or not.
+    ///
+    /// Only files that haven't been loaded to memory yet can be encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        match self.data {
+            RFileInnerData::OnDisk(ref data) => data.is_encrypted.is_some(),
+            _ => false,
+        }
+    }
+
+    /// This function returns if the RFile's data is compressed on disk or not.
+    ///
+    /// Only files that haven't been loaded to memory yet can be compressed.
+    pub fn is_compressed(&self) -> bool {
+        match self.data {
+            RFileInnerData::OnDisk(ref data) => data.is_compressed,
+            _ => false,
+        }
+    }
+
+    /// This function returns how much of the data of this RFile is currently in memory.
+    pub fn data_state(&self) -> RFileDataState {
+        match self.data {
+            RFileInnerData::Decoded(_) => RFileDataState::Decoded,
+            RFileInnerData::Cached(_) => RFileDataState::Cached,
+            RFileInnerData::OnDisk(_) => RFileDataState::OnDisk,
+        }
+    }
+
+    /// This function returns the path of the file on disk this RFile's data was read from, if any.
+    ///
+    /// Unlike [`container_name`](Self::container_name), this also works with files that have been loaded to memory, as long as they haven't been changed since.
+    pub fn source_path(&self) -> Option<&str> {
+        self.source().map(|source| &*source.path)
+    }
+
+    /// This function returns the size in bytes of this RFile's data as stored on disk, so compressed and encrypted if it's stored that way.
+    ///
+    /// Like [`source_path`](Self::source_path), it's only available for files not changed since they were read from disk.
+    pub fn stored_size(&self) -> Option<u64> {
+        self.source().map(|source| source.size)
+    }
+
+    /// This function returns if this RFile's data is stored compressed on disk.
+    ///
+    /// Unlike [`is_compressed`](Self::is_compressed), this also works with files that have been loaded to memory, as long as they haven't been changed since.
+    pub fn is_stored_compressed(&self) -> bool {
+        self.source().map(|source| source.is_compressed).unwrap_or(false)
+    }
+
+    /// This function returns the size in bytes of this RFile's data once loaded to memory, if it can be known without decompressing or encoding it.
+    ///
+    /// For decoded files, and for files that are compressed or encrypted on disk, this returns None.
+    pub fn data_size(&self) -> Option<u64> {
+        match self.data {
+            RFileInnerData::Cached(ref data) => Some(data.len() as u64),
+            RFileInnerData::OnDisk(ref data) if !data.is_compressed && data.is_encrypted.is_none() => Some(data.size),
+            _ => None,
+        }
+    }
+
+    /// This function returns where on disk this RFile's data is or was read from, if the data in memory hasn't been changed since.
+    fn source(&self) -> Option<&OnDisk> {
+        match self.data {
+            RFileInnerData::OnDisk(ref data) => Some(data),
+            _ => self.loaded_from.as_ref().map(|loaded_from| &loaded_from.source),
+        }
+    }
+
+    /// This function returns the name of the file on disk this RFile's data is on, if any.
+    ///
+    /// For files inside a Pack, this is the name of said Pack. Only files that haven't been loaded to memory yet have it.
+    pub fn container_name(&self) -> Option<&str> {
+        match self.data {
+            RFileInnerData::OnDisk(ref data) => data.path.rsplit(['/', '\\']).next(),
+            _ => None,
+        }
+    }
+
     /// This function guesses the [`FileType`] of the provided RFile and stores it on it for later queries.
     ///
     /// The way it works is: first it tries to guess it by extension (fast), then by full path (not as fast), then by data (slow and it may fail on lazy-loaded files).
@@ -1708,6 +2186,14 @@ impl RFile {
             self.file_type = FileType::Save;
         }
 
+        // If we have the data, check the header. Corrupted SpeedTrees are left as Unknown.
+        else if speedtree::EXTENSIONS.iter().any(|x| path.ends_with(x)) {
+            self.file_type = match self.cached() {
+                Ok(data) if !SpeedTree::is_speedtree(data) => FileType::Unknown,
+                _ => FileType::SpeedTree,
+            };
+        }
+
         // If that failed, try types that need to be in a specific path.
         else if matched_combat::BASE_PATHS.iter().any(|x| path.starts_with(*x)) && path.ends_with(matched_combat::EXTENSION) {
             self.file_type = FileType::MatchedCombat;
@@ -1731,6 +2217,11 @@ impl RFile {
             self.file_type = FileType::PortraitSettings;
         }
 
+        // If that failed, check if there's a registered decoder for it.
+        else if custom::decoder_for_path(&path).is_some() {
+            self.file_type = FileType::Custom;
+        }
+
         // If we reach this... we're clueless. Leave it unknown.
         else {
             self.file_type = FileType::Unknown;
@@ -1739,6 +2230,17 @@ impl RFile {
         Ok(())
     }
 
+    /// This function guesses the [`FileType`] of the provided RFile like [`RFile::guess_file_type`], but checking first the file type rules of the provided game.
+    pub fn guess_file_type_for_game(&mut self, game_info: &GameInfo) -> Result<()> {
+        match game_info.file_type_override(&self.path) {
+            Some(file_type_override) => {
+                self.file_type = *file_type_override.file_type();
+                Ok(())
+            }
+            None => self.guess_file_type(),
+        }
+    }
+
     /// This function allows to import a TSV file on the provided Path into a binary database file.
     ///
     /// It requires the path on disk of the TSV file and the Schema to use.
@@ -1746,14 +2248,98 @@ impl RFile {
 
         // We want the reader to have no quotes, tab as delimiter and custom headers, because otherwise
         // Excel, Libreoffice and all the programs that edit this kind of files break them on save.
-        let mut reader = ReaderBuilder::new()
+        let reader = ReaderBuilder::new()
             .delimiter(b'\t')
             .quoting(false)
             .has_headers(true)
             .flexible(true)
             .from_path(path)?;
 
-        // If we successfully load the TSV file into a reader, check the first line to get the column list and order.
+        Self::table_import_from_reader(reader, schema)
+    }
+
+    /// This function replaces the data of this RFile with the table in the TSV file on the provided Path.
+    ///
+    /// The TSV must contain the same table this RFile contains. Unlike with [RFile::tsv_import_from_path],
+    /// errors on rows are reported with the line and column of the TSV file, so they're easier to find in an editor.
+    pub fn tsv_import_to_self(&mut self, path: &Path, schema: &Schema) -> Result<()> {
+        let imported = Self::tsv_import_from_path(path, schema).map_err(|error| match error {
+
+            // Data rows start after the header line and the metadata line.
+            RLibError::ImportTSVIncorrectRow(row, column) => RLibError::ImportTSVIncorrectLine(row + 3, column + 1),
+            _ => error,
+        })?;
+
+        let decoded = match imported.data {
+            RFileInnerData::Decoded(decoded) => *decoded,
+            _ => return Err(RLibError::FileNotDecoded(imported.path)),
+        };
+
+        if let RFileDecoded::DB(ref table) = decoded {
+            if self.db_table_name_from_path() != Some(table.table_name()) {
+                return Err(RLibError::ImportTSVWrongTypeTable);
+            }
+        }
+
+        // This fails if we try to import a Loc into a DB or viceversa.
+        self.set_decoded(decoded).map_err(|_| RLibError::ImportTSVWrongTypeTable)
+    }
+
+    /// This function allows to import a CSV file on the provided Path into a binary database file.
+    ///
+    /// It requires the path on disk of the CSV file, the Schema to use, and the delimiter of the file.
+    /// If no delimiter is provided, it's guessed from the header line of the file.
+    pub fn csv_import_from_path(path: &Path, schema: &Schema, delimiter: Option<u8>) -> Result<Self> {
+        let delimiter = match delimiter {
+            Some(delimiter) => delimiter,
+            None => Self::csv_detect_delimiter(path)?,
+        };
+
+        // Unlike TSV, CSV files are expected to follow RFC-4180, so fields may be quoted.
+        let reader = ReaderBuilder::new()
+            .delimiter(delimiter)
+            .quoting(true)
+            .double_quote(true)
+            .has_headers(true)
+            .flexible(true)
+            .from_path(path)?;
+
+        Self::table_import_from_reader(reader, schema)
+    }
+
+    /// This function tries to guess the delimiter of a CSV file from its header line.
+    ///
+    /// Delimiters within quoted fields are ignored. If no known delimiter is found, comma is assumed.
+    pub fn csv_detect_delimiter(path: &Path) -> Result<u8> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut quoted = false;
+        let mut counts = CSV_DELIMITERS.map(|delimiter| (delimiter, 0));
+        let mut buffer = [0; 1];
+
+        while reader.read(&mut buffer)? == 1 {
+            match buffer[0] {
+                b'"' => quoted = !quoted,
+                b'\n' if !quoted => break,
+                byte if !quoted => if let Some((_, count)) = counts.iter_mut().find(|(delimiter, _)| *delimiter == byte) {
+                    *count += 1;
+                },
+                _ => {},
+            }
+        }
+
+        // On draw, the first delimiter in the list wins.
+        Ok(counts.iter()
+            .rev()
+            .max_by_key(|(_, count)| *count)
+            .filter(|(_, count)| *count > 0)
+            .map(|(delimiter, _)| *delimiter)
+            .unwrap_or(b','))
+    }
+
+    /// This function imports the table contained in the provided reader, as long as it contains a TSV/CSV table.
+    fn table_import_from_reader(mut reader: Reader<File>, schema: &Schema) -> Result<Self> {
+
+        // Check the first line to get the column list and order.
         let field_order = reader.headers()?
             .iter()
             .enumerate()
@@ -1826,13 +2412,122 @@ impl RFile {
 
         // We want the writer to have no quotes, tab as delimiter and custom headers, because otherwise
         // Excel, Libreoffice and all the programs that edit this kind of files break them on save.
-        let mut writer = WriterBuilder::new()
+        let writer = WriterBuilder::new()
             .delimiter(b'\t')
             .quote_style(QuoteStyle::Never)
             .has_headers(false)
             .flexible(true)
             .from_path(path)?;
 
+        self.table_export_to_writer(writer, path, schema, true)
+    }
+
+    /// This function allows to export a RFile into a CSV file on disk, using the provided delimiter.
+    ///
+    /// Fields are quoted following RFC-4180 when needed, so values with delimiters, quotes or line jumps survive a round-trip.
+    ///
+    /// Only supported for DB and Loc files.
+    pub fn csv_export_to_path(&mut self, path: &Path, schema: &Schema, delimiter: u8) -> Result<()> {
+
+        // Make sure the folder actually exists.
+        let mut folder_path = path.to_path_buf();
+        folder_path.pop();
+        DirBuilder::new().recursive(true).create(&folder_path)?;
+
+        let writer = WriterBuilder::new()
+            .delimiter(delimiter)
+            .quote_style(QuoteStyle::Necessary)
+            .double_quote(true)
+            .has_headers(false)
+            .flexible(true)
+            .from_path(path)?;
+
+        self.table_export_to_writer(writer, path, schema, false)
+    }
+
+    /// This function allows to export a RFile into an Assembly Kit table (xml) on disk.
+    ///
+    /// Only supported for DB files.
+    #[cfg(feature = "integration_assembly_kit")]
+    pub fn ak_xml_export_to_path(&mut self, path: &Path, schema: &Schema) -> Result<()> {
+
+        // Make sure the folder actually exists.
+        let mut folder_path = path.to_path_buf();
+        folder_path.pop();
+        DirBuilder::new().recursive(true).create(&folder_path)?;
+
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.set_schema(Some(schema));
+
+        let result = match self.decode(&Some(extra_data), false, true)? {
+            Some(RFileDecoded::DB(table)) => {
+                let mut writer = BufWriter::new(File::create(path)?);
+                write_raw_table(&table, &mut writer).and_then(|_| writer.flush().map_err(From::from))
+            }
+            _ => Err(RLibError::FileNotAssemblyKitCompatible(self.path_in_container_raw().to_owned())),
+        };
+
+        // If the export failed, delete the xml file.
+        if result.is_err() {
+            let _ = std::fs::remove_file(path);
+        }
+
+        result
+    }
+
+    /// This function extracts this RFile to the provided path on disk, using the provided format for DB/Loc files.
+    ///
+    /// If it fails to extract the file in that format, it's extracted as a binary file.
+    fn extract_to_path(&mut self, destination_path: &Path, schema: &Option<Schema>, format: ExtractionFormat) -> Result<()> {
+        if let Some(schema) = schema {
+            let result = match format {
+                ExtractionFormat::Tsv if self.file_type() == FileType::DB || self.file_type() == FileType::Loc => {
+                    Some(self.tsv_export_to_path(&Self::path_with_extra_extension(destination_path, "tsv"), schema))
+                }
+
+                #[cfg(feature = "integration_assembly_kit")]
+                ExtractionFormat::AssKitXml if self.file_type() == FileType::DB => {
+                    Some(self.ak_xml_export_to_path(&Self::path_with_extra_extension(destination_path, "xml"), schema))
+                }
+
+                _ => None,
+            };
+
+            match result {
+                Some(Ok(())) => return Ok(()),
+
+                #[cfg(feature = "integration_log")]
+                Some(Err(_)) => warn!("File with path {} failed to extract as {:?}. Extracting it as binary.", self.path_in_container_raw(), format),
+
+                _ => {},
+            }
+        }
+
+        let mut file = BufWriter::new(File::create(destination_path)?);
+        let data = self.encode(&None, false, false, true)?.unwrap();
+        file.write_all(&data).map_err(From::from)
+    }
+
+    /// This function returns the provided path with the provided extension appended to it.
+    ///
+    /// Any existing extension is NOT replaced, only appended to.
+    fn path_with_extra_extension(path: &Path, extra_extension: &str) -> PathBuf {
+        let mut path = path.to_owned();
+        match path.extension() {
+            Some(extension) => {
+                let extension = format!("{}.{}", extension.to_string_lossy(), extra_extension);
+                path.set_extension(extension)
+            },
+            None => path.set_extension(extra_extension),
+        };
+
+        path
+    }
+
+    /// This function exports this RFile through the provided writer, deleting the file at `path` if it fails.
+    ///
+    /// Line jumps and tabs in strings are only escaped if `escape_special_chars` is true, for files written without quotes.
+    fn table_export_to_writer(&mut self, mut writer: Writer<File>, path: &Path, schema: &Schema, escape_special_chars: bool) -> Result<()> {
         let mut extra_data = DecodeableExtraData::default();
         extra_data.set_schema(Some(schema));
 
@@ -1846,8 +2541,8 @@ impl RFile {
         }
 
         let file = match file?.unwrap() {
-            RFileDecoded::DB(table) => table.tsv_export(&mut writer, self.path_in_container_raw()),
-            RFileDecoded::Loc(table) => table.tsv_export(&mut writer, self.path_in_container_raw()),
+            RFileDecoded::DB(table) => table.table().tsv_export(&mut writer, self.path_in_container_raw(), escape_special_chars),
+            RFileDecoded::Loc(table) => table.table().tsv_export(&mut writer, self.path_in_container_raw(), escape_special_chars),
             _ => unimplemented!()
         };
 
@@ -1859,11 +2554,11 @@ impl RFile {
         file
     }
 
-    /// This function tries to merge multiple files into one.
+    /// This function checks if the provided files can be merged, returning their common type if they can.
     ///
-    /// All files must be of the same type and said type must support merging.
-    pub fn merge(sources: &[&Self], path: &str) -> Result<Self> {
-        if sources.len() == 1 {
+    /// All files must be decoded, of the same type, and said type must support merging. DB tables must also share table name and version.
+    fn merge_check(sources: &[&Self]) -> Result<FileType> {
+        if sources.len() < 2 {
             return Err(RLibError::RFileMergeOnlyOneFileProvided);
         }
 
@@ -1875,7 +2570,57 @@ impl RFile {
             return Err(RLibError::RFileMergeDifferentTypes);
         }
 
-        match file_types[0] {
+        if file_types[0] != FileType::DB && file_types[0] != FileType::Loc {
+            return Err(RLibError::RFileMergeNotSupportedForType(file_types[0].to_string()));
+        }
+
+        // Undecoded files would be silently left out of the merge, so we fail instead.
+        if let Some(file) = sources.iter().find(|file| file.decoded().is_err()) {
+            return Err(RLibError::FileNotDecoded(file.path_in_container_raw().to_string()));
+        }
+
+        if file_types[0] == FileType::DB {
+            let tables = sources.iter()
+                .filter_map(|file| if let Ok(RFileDecoded::DB(table)) = file.decoded() { Some((file.path_in_container_raw(), table)) } else { None })
+                .collect::<Vec<_>>();
+
+            let (first_path, first_table) = tables[0];
+            let mismatches = tables.iter()
+                .filter(|(_, table)| table.table_name() != first_table.table_name() || table.definition().version() != first_table.definition().version())
+                .map(|(path, _)| path.to_string())
+                .collect::<Vec<_>>();
+
+            if !mismatches.is_empty() {
+                return Err(RLibError::RFileMergeTablesDifferentDefinitions(first_path.to_owned(), mismatches.join(", ")));
+            }
+        }
+
+        Ok(file_types[0])
+    }
+
+    /// This function returns the rows with the same key values but different data across the provided files.
+    ///
+    /// The files are checked the same way [RFile::merge] checks them.
+    pub fn merge_conflicts(sources: &[&Self]) -> Result<Vec<MergeConflict>> {
+        Self::merge_check(sources)?;
+
+        let tables = sources.iter()
+            .filter_map(|file| match file.decoded() {
+                Ok(RFileDecoded::DB(table)) => Some((file.path_in_container_raw(), table.table())),
+                Ok(RFileDecoded::Loc(table)) => Some((file.path_in_container_raw(), table.table())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        Table::merge_conflicts(&tables)
+    }
+
+    /// This function tries to merge multiple files into one.
+    ///
+    /// All files must be decoded, of the same type, and said type must support merging. DB tables must also share table name and version.
+    pub fn merge(sources: &[&Self], path: &str) -> Result<Self> {
+        let file_type = Self::merge_check(sources)?;
+        match file_type {
             FileType::DB => {
                 let files = sources.iter().filter_map(|file| if let Ok(RFileDecoded::DB(table)) = file.decoded() { Some(table) } else { None }).collect::<Vec<_>>();
                 let data = RFileDecoded::DB(DB::merge(&files)?);
@@ -1886,7 +2631,7 @@ impl RFile {
                 let data = RFileDecoded::Loc(Loc::merge(&files)?);
                 Ok(Self::new_from_decoded(&data, current_time()?, path))
             },
-            _ => Err(RLibError::RFileMergeNotSupportedForType(file_types[0].to_string())),
+            _ => Err(RLibError::RFileMergeNotSupportedForType(file_type.to_string())),
         }
     }
 
@@ -1906,6 +2651,31 @@ impl RFile {
     }
 }
 
+impl LoadedFrom {
+
+    /// This function returns where the data of an RFile is being loaded from, along with the current size of the file containing it.
+    ///
+    /// If we cannot get said size, it returns None, as we would not be able to tell if the file changed later.
+    fn new(source: &OnDisk) -> Option<Self> {
+        std::fs::metadata(&source.path).ok().map(|metadata| Self {
+            source: source.clone(),
+            file_size: metadata.len(),
+        })
+    }
+
+    /// This function checks if the file containing the data changed on disk since the data was loaded, or if it no longer exists.
+    fn is_outdated(&self) -> bool {
+        match File::open(&self.source.path) {
+            Ok(file) => {
+                let timestamp = last_modified_time_from_file(&file).ok();
+                let file_size = file.metadata().ok().map(|metadata| metadata.len());
+                timestamp != Some(self.source.timestamp) || file_size != Some(self.file_size)
+            }
+            Err(_) => true,
+        }
+    }
+}
+
 impl OnDisk {
 
     /// This function tries to read and return the raw data of an RFile.
@@ -1995,6 +2765,47 @@ impl ContainerPath {
         }
     }
 
+    /// This function returns the path a copy of this DB or Loc table would have with the provided name.
+    ///
+    /// The name can be either a file name, or a full path. Copies of DB tables must stay in the folder of their table,
+    /// and copies of Loc files must stay in the `text` folder and end in `.loc`. If the name doesn't end in `.loc`, it's added for Loc files.
+    pub fn duplicate_table_path(&self, new_name: &str) -> Result<String> {
+        let source_path = match self {
+            ContainerPath::File(path) => path,
+            ContainerPath::Folder(path) => return Err(RLibError::DuplicateTableNotATable(path.to_owned())),
+        };
+
+        let is_loc = source_path.to_lowercase().starts_with("text/") && source_path.to_lowercase().ends_with(".loc");
+        if self.db_table_name_from_path().is_none() && !is_loc {
+            return Err(RLibError::DuplicateTableNotATable(source_path.to_owned()));
+        }
+
+        let new_name = new_name.trim().trim_matches('/');
+        let mut new_path = if new_name.contains('/') {
+            new_name.to_owned()
+        } else {
+            format!("{}/{}", self.parent_path(), new_name)
+        };
+
+        if is_loc && !new_path.to_lowercase().ends_with(".loc") {
+            new_path.push_str(".loc");
+        }
+
+        let new_container_path = ContainerPath::File(new_path.to_owned());
+        let file_name = new_path.rsplit('/').next().unwrap_or_default();
+        let is_valid = if is_loc {
+            new_path.to_lowercase().starts_with("text/") && file_name.len() > 4
+        } else {
+            !file_name.is_empty() && new_container_path.parent_path() == self.parent_path()
+        };
+
+        if is_valid {
+            Ok(new_path)
+        } else {
+            Err(RLibError::DuplicateTableInvalidPath(source_path.to_owned(), new_path))
+        }
+    }
+
     /// This function returns the path of the parent folder of the provided [ContainerPath].
     ///
     /// If the provided [ContainerPath] corresponds to a Container root, the path returned will be the current one.
@@ -2026,7 +2837,7 @@ impl ContainerPath {
 
         // If we don't have the root of the container, second optimization: check if we have at least one folder.
         // If not, we just need to dedup the file list.
-        if paths.par_iter().any(|item| matches!(item, ContainerPath::Folder(_))) {
+        if !paths.par_iter().any(|item| matches!(item, ContainerPath::Folder(_))) {
             let mut paths = paths.to_vec();
             paths.sort();
             paths.dedup();
@@ -2044,7 +2855,7 @@ impl ContainerPath {
                         .any(|item_type| {
 
                         // If the other one is a folder that contains it, dont add it.
-                        item_type.is_folder() && path_to_add.starts_with(item_type.path_raw())
+                        item_type.is_folder() && path_to_add.starts_with(&format!("{}/", item_type.path_raw()))
                     })
                 }
 
@@ -2054,8 +2865,7 @@ impl ContainerPath {
                         .any(|item_type| {
 
                         // If the other one is a folder that contains it, dont add it.
-                        let path = item_type.path_raw();
-                        item_type.is_folder() && path_to_add.starts_with(path) && path_to_add.len() > path.len()
+                        item_type.is_folder() && path_to_add.starts_with(&format!("{}/", item_type.path_raw()))
                     })
                 }
             }
@@ -2099,6 +2909,7 @@ impl Display for FileType {
             FileType::AnimsTable => write!(f, "AnimsTable"),
             FileType::Video => write!(f, "Video"),
             FileType::CEO => write!(f, "CEO"),
+            FileType::Custom => write!(f, "Custom"),
             FileType::DB => write!(f, "DB Table"),
             FileType::ESF => write!(f, "ESF"),
             FileType::GroupFormations => write!(f, "Group Formations"),
@@ -2109,6 +2920,7 @@ impl Display for FileType {
             FileType::PortraitSettings => write!(f, "Portrait Settings"),
             FileType::RigidModel => write!(f, "RigidModel"),
             FileType::Save => write!(f, "Save"),
+            FileType::SpeedTree => write!(f, "SpeedTree"),
             FileType::Text => write!(f, "Text"),
             FileType::UIC => write!(f, "UI Component"),
             FileType::UnitVariant => write!(f, "Unit Variant"),
@@ -2127,6 +2939,7 @@ impl From<&str> for FileType {
             "AnimsTable" => FileType::AnimsTable,
             "Video" => FileType::Video,
             "CEO" => FileType::CEO,
+            "Custom" => FileType::Custom,
             "DB" => FileType::DB,
             "ESF" => FileType::ESF,
             "GroupFormations" => FileType::GroupFormations,
@@ -2137,6 +2950,7 @@ impl From<&str> for FileType {
             "PortraitSettings" => FileType::PortraitSettings,
             "RigidModel" => FileType::RigidModel,
             "Save" => FileType::Save,
+            "SpeedTree" => FileType::SpeedTree,
             "Text" => FileType::Text,
             "UIC" => FileType::UIC,
             "UnitVariant" => FileType::UnitVariant,
@@ -2155,6 +2969,7 @@ impl From<FileType> for String {
             FileType::AnimsTable => "AnimsTable",
             FileType::Video => "Video",
             FileType::CEO => "CEO",
+            FileType::Custom => "Custom",
             FileType::DB => "DB",
             FileType::ESF => "ESF",
             FileType::GroupFormations => "GroupFormations",
@@ -2165,6 +2980,7 @@ impl From<FileType> for String {
             FileType::PortraitSettings => "PortraitSettings",
             FileType::RigidModel => "RigidModel",
             FileType::Save => "Save",
+            FileType::SpeedTree => "SpeedTree",
             FileType::Text => "Text",
             FileType::UIC => "UIC",
             FileType::UnitVariant => "UnitVariant",
@@ -2181,6 +2997,7 @@ impl From<&RFileDecoded> for FileType {
             RFileDecoded::AnimPack(_) => Self::AnimPack,
             RFileDecoded::AnimsTable(_) => Self::AnimsTable,
             RFileDecoded::CEO(_) => Self::CEO,
+            RFileDecoded::Custom(_) => Self::Custom,
             RFileDecoded::DB(_) => Self::DB,
             RFileDecoded::ESF(_) => Self::ESF,
             RFileDecoded::GroupFormations(_) => Self::GroupFormations,
@@ -2191,6 +3008,7 @@ impl From<&RFileDecoded> for FileType {
             RFileDecoded::PortraitSettings(_) => Self::PortraitSettings,
             RFileDecoded::RigidModel(_) => Self::RigidModel,
             RFileDecoded::Save(_) => Self::Save,
+            RFileDecoded::SpeedTree(_) => Self::SpeedTree,
             RFileDecoded::Text(_) => Self::Text,
             RFileDecoded::UIC(_) => Self::UIC,
             RFileDecoded::UnitVariant(_) => Self::UnitVariant,
@@ -15,10 +15,12 @@ use getset::*;
 use rayon::prelude::*;
 use serde_derive::{Serialize, Deserialize};
 use serde_json::{from_slice, to_string_pretty};
+use sha2::{Digest, Sha256};
 use itertools::Itertools;
 
 use std::collections::{BTreeMap, HashMap, HashSet};
-use std::fs::File;
+use std::fmt::{self, Display};
+use std::fs::{File, remove_file};
 use std::io::{BufReader, BufWriter, Cursor, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -26,9 +28,12 @@ use std::str::FromStr;
 use crate::binary::{ReadBytes, WriteBytes};
 use crate::compression::Compressible;
 use crate::error::{RLibError, Result};
-use crate::files::{Container, ContainerPath, Decodeable, DecodeableExtraData, Encodeable, EncodeableExtraData, FileType, Loc, RFile, RFileDecoded, table::DecodedData};
+use crate::files::{Container, ContainerPath, Decodeable, DecodeableExtraData, Encodeable, EncodeableExtraData, esf::ESFNodePath, FileType, Loc, RFile, RFileDecoded, table::DecodedData, text::{self, TextFormat}};
 use crate::games::{GameInfo, pfh_file_type::PFHFileType, pfh_version::PFHVersion};
-use crate::utils::{current_time, last_modified_time_from_file};
+use crate::schema::{Definition, Schema};
+use crate::utils::{current_time, files_from_subdir, last_modified_time_from_file};
+
+#[cfg(feature = "integration_log")] use log::info;
 
 #[cfg(test)]
 mod pack_test;
@@ -37,12 +42,21 @@ mod pack_versions;
 /// Extension used by Packs.
 pub const EXTENSION: &str = ".pack";
 
+/// Extension used by the manifests generated for Packs.
+pub const MANIFEST_EXTENSION: &str = ".manifest.json";
+
+/// Extension used by the reports generated when rescuing Packs.
+pub const RESCUE_REPORT_EXTENSION: &str = ".rescue_report.txt";
+
 /// Special Preamble/Id prefixing steam workshop files, for some reason.
 const MFH_PREAMBLE: &str = "MFH"; // Weird format of some packs downloaded from Steam.
 
 /// Path where Terry-generated map files end up.
 const TERRY_MAP_PATH: &str = "terrain/tiles/battle/_assembly_kit";
 
+/// Paths where Terry-generated map files may end up, including the old `prefabs` layout.
+pub const TERRY_MAP_PATHS: [&str; 2] = [TERRY_MAP_PATH, "prefabs"];
+
 /// This one is the name of the main BMD data file used by maps exported from Terry.
 const DEFAULT_BMD_DATA: &str = "bmd_data.bin";
 
@@ -57,20 +71,33 @@ pub const RESERVED_NAME_SETTINGS: &str = "settings.rpfm_reserved";
 pub const RESERVED_NAME_SETTINGS_EXTRACTED: &str = "settings.rpfm_reserved.json";
 pub const RESERVED_NAME_NOTES: &str = "notes.rpfm_reserved";
 pub const RESERVED_NAME_NOTES_EXTRACTED: &str = "notes.rpfm_reserved.md";
+pub const RESERVED_NAME_PADDING: &str = "rpfm_padding.bin";
 
 /// This is the list of ***Reserved File Names***. They're file names used by RPFM for special purposes.
-pub const RESERVED_RFILE_NAMES: [&str; 3] = [RESERVED_NAME_EXTRA_PACKFILE, RESERVED_NAME_SETTINGS, RESERVED_NAME_NOTES];
+pub const RESERVED_RFILE_NAMES: [&str; 4] = [RESERVED_NAME_EXTRA_PACKFILE, RESERVED_NAME_SETTINGS, RESERVED_NAME_NOTES, RESERVED_NAME_PADDING];
+
+/// Numeric setting with the minimum size, in bytes, Packs are padded to on save. 0 disables the padding.
+pub const PADDING_SIZE_SETTING: &str = "pad_pack_to_bytes";
+
+/// Text setting where older versions stored the diagnostics ignore rules. We still write it on save, so they keep working with them.
+pub const LEGACY_DIAGNOSTICS_IGNORE_SETTING: &str = "diagnostics_files_to_ignore";
+
+/// Labels available for files and folders of Packs without a `path_label_definitions` setting, with the format of said setting.
+pub const DEFAULT_PATH_LABEL_DEFINITIONS: &str = "Done;#4caf50\nNeeds Review;#ff9800\nWork in Progress;#2196f3";
 
 const AUTHORING_TOOL_CA: &str = "CA_TOOL";
 const AUTHORING_TOOL_RPFM: &str = "RPFM";
 const AUTHORING_TOOL_SIZE: u32 = 8;
+const SUBHEADER_VERSION: u32 = 1;
+const SUBHEADER_EXTRA_DATA_SIZE: usize = 256;
 
 bitflags! {
 
     /// This represents the bitmasks a Pack can have applied to his type.
     ///
     /// Keep in mind that this lib supports decoding Packs with any of these flags enabled,
-    /// but it only supports enconding for the `HAS_INDEX_WITH_TIMESTAMPS` flag.
+    /// but it only supports enconding for the `HAS_INDEX_WITH_TIMESTAMPS` flag. Packs with
+    /// any of the other flags are saved decrypted and without extended header, with said flags removed.
     #[derive(Serialize, Deserialize)]
     pub struct PFHFlags: u32 {
 
@@ -88,6 +115,20 @@ bitflags! {
     }
 }
 
+/// Flags we can decode, but not encode. They're removed from the header of a Pack when saving it.
+const NOT_ENCODEABLE_FLAGS: PFHFlags = PFHFlags::HAS_ENCRYPTED_DATA.union(PFHFlags::HAS_ENCRYPTED_INDEX).union(PFHFlags::HAS_EXTENDED_HEADER);
+
+/// List of all the flags a Pack header can have, in the order they should be shown to the user.
+pub const PFH_FLAGS: [PFHFlags; 4] = [
+    PFHFlags::HAS_EXTENDED_HEADER,
+    PFHFlags::HAS_INDEX_WITH_TIMESTAMPS,
+    PFHFlags::HAS_ENCRYPTED_INDEX,
+    PFHFlags::HAS_ENCRYPTED_DATA,
+];
+
+/// Result of checking/updating the tables of a Pack: path, old and new version of each updatable table, and paths of the tables with no update available.
+pub type TableUpdates = (Vec<(String, i32, i32)>, Vec<String>);
+
 //---------------------------------------------------------------------------//
 //                              Enum & Structs
 //---------------------------------------------------------------------------//
@@ -163,6 +204,10 @@ pub struct Pack {
 
     /// Settings stored in the Pack itself, to be able to share them between installations.
     settings: PackSettings,
+
+    /// Warnings about problems found in the Pack while reading it, like duplicated paths in its index. Not saved.
+    #[serde(skip)]
+    load_warnings: Vec<String>,
 }
 
 /// Header of a Pack, containing all the header-related info of said Pack.
@@ -211,6 +256,9 @@ pub struct PackHeader {
     /// The timestamp of the last time the Pack was saved.
     internal_timestamp: u64,
 
+    /// Version of the subheader. Only used since PFH6.
+    subheader_version: u32,
+
     /// Game version this Pack is intended for. This usually triggers the "outdated mod" warning in the launcher if it doesn't match the current exe version.
     game_version: u32,
 
@@ -220,7 +268,7 @@ pub struct PackHeader {
     /// Tool that created the Pack. Max 8 characters, 00-padded.
     authoring_tool: String,
 
-    /// Extra subheader data, in case it's used in the future.
+    /// Extra subheader data, in case it's used in the future. Always 256 bytes long when saved.
     extra_subheader_data: Vec<u8>,
 }
 
@@ -243,6 +291,165 @@ pub struct PackSettings {
 
     /// For integer values.
     settings_number: BTreeMap<String, i32>,
+
+    /// Rules to ignore diagnostics of files of the Pack.
+    #[serde(default)]
+    diagnostics_ignore_rules: Vec<DiagnosticIgnoreRule>,
+
+    /// Notes attached to rows of tables of the Pack, as `path -> row key -> note`. Row keys are the ones returned by [Table::row_key](crate::files::table::Table::row_key).
+    #[serde(default)]
+    row_annotations: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// This struct holds a rule to ignore diagnostics of the files of a Pack, as stored in [PackSettings].
+///
+/// Depending on what the rule contains, it ignores:
+/// - No diagnostic and no columns: the entire file.
+/// - Diagnostic and no columns: that diagnostic in the entire file.
+/// - Columns and no diagnostic: all the diagnostics of those columns.
+/// - Diagnostic and columns: that diagnostic in those columns.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct DiagnosticIgnoreRule {
+
+    /// Path prefix of the files affected by the rule. Rules with an empty path don't affect any file.
+    path: String,
+
+    /// Name of the diagnostic to ignore, like `InvalidReference`.
+    diagnostic: Option<String>,
+
+    /// Names of the table columns affected by the rule.
+    columns: Vec<String>,
+}
+
+/// This struct holds the differences between two Packs, as returned by [Pack::diff].
+#[derive(Clone, Debug, PartialEq, Eq, Default, Getters)]
+#[getset(get = "pub")]
+pub struct PackDiff {
+
+    /// Files only present in the Pack we called [Pack::diff] on.
+    only_in_self: Vec<ContainerPath>,
+
+    /// Files only present in the other Pack.
+    only_in_other: Vec<ContainerPath>,
+
+    /// Files present in both Packs, but with different contents.
+    different: Vec<ContainerPath>,
+
+    /// Row-level differences of the DB and Loc files present in both Packs, by path.
+    table_diffs: BTreeMap<String, TableDiff>,
+}
+
+/// This struct holds the amount of rows that differ between two versions of the same table.
+///
+/// Rows are matched by their key columns (or by all their columns if the table has no keys). Rows sharing a key are matched
+/// in the order they appear in each table. Added rows are the ones only present in the Pack we called [Pack::diff] on.
+/// Removed rows are the ones only present in the other Pack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct TableDiff {
+    added: usize,
+    removed: usize,
+    changed: usize,
+
+    /// Amount of keys used by more than one row, in any of both tables.
+    #[serde(default)]
+    duplicated_keys: usize,
+}
+
+/// This struct holds the manifest of a Pack saved to disk, as returned by [Pack::manifest].
+///
+/// It's meant to be distributed along the Pack, so third parties can verify their copy of the Pack is the same.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, MutGetters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub")]
+pub struct PackManifest {
+
+    /// PFH Version of the Pack.
+    pfh_version: PFHVersion,
+
+    /// Timestamp written in the header of the Pack.
+    timestamp: u64,
+
+    /// Files in the Pack, sorted by path.
+    files: Vec<PackManifestEntry>,
+}
+
+/// This struct holds what was done to a Pack when rescuing it, as returned by [Pack::rescue].
+#[derive(Clone, Debug, PartialEq, Eq, Default, Getters)]
+#[getset(get = "pub")]
+pub struct PackRescueReport {
+
+    /// Files removed from the Pack, with the reason they were removed. Sorted by path.
+    dropped_files: Vec<(String, RescueDropReason)>,
+
+    /// Files kept in the Pack that may still be broken, with the reason they're suspicious. Sorted by path.
+    suspicious_files: Vec<(String, RescueSuspiciousReason)>,
+
+    /// Flags removed from the header of the Pack, because they cannot be saved.
+    removed_flags: PFHFlags,
+}
+
+/// This enum represents the reasons a file can be removed from a Pack when rescuing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RescueDropReason {
+
+    /// The data of the file couldn't be read, decompressed or decrypted. Contains the error.
+    Unreadable(String),
+
+    /// The data of the file is completely out of the bounds of the Pack.
+    OutOfBounds,
+
+    /// The file has no data.
+    ZeroSize,
+
+    /// Another file has the same path if we ignore the case. Contains the path of the file that was kept.
+    DuplicatePath(String),
+}
+
+/// This enum represents the reasons a file kept in a Pack when rescuing it can be suspicious.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RescueSuspiciousReason {
+
+    /// The data of the file went past the end of the Pack, and it was truncated. Contains the old and new sizes.
+    Truncated(u64, u64),
+
+    /// The data of the file can be read, but not decoded. Contains the error.
+    Undecodeable(String),
+}
+
+/// This struct holds the data of a single file of a [PackManifest].
+#[derive(Clone, Debug, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct PackManifestEntry {
+
+    /// Path of the file within the Pack.
+    path: String,
+
+    /// Size of the file's data, as stored in the Pack.
+    size: u64,
+
+    /// SHA-256 hash of the file's data, as stored in the Pack.
+    sha256: String,
+}
+
+/// This struct holds a single rule of the `mymod_export_rules` setting, as returned by [PackSettings::mymod_export_rules].
+///
+/// Each rule maps a folder within the Pack to a folder within the MyMod's assets folder.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Getters)]
+#[getset(get = "pub")]
+pub struct MyModExportRule {
+
+    /// Folder within the Pack, without leading or trailing slashes. Empty means the root of the Pack.
+    pack_folder: String,
+
+    /// Folder within the assets folder, without leading or trailing slashes. Empty means the root of the assets folder.
+    disk_folder: String,
+
+    /// If DB and Loc files under this rule should be exported as TSV.
+    tables_as_tsv: bool,
+
+    /// If files in the disk folder that are not in the Pack should be deleted on export.
+    mirror: bool,
 }
 
 //---------------------------------------------------------------------------//
@@ -499,6 +706,9 @@ impl Pack {
             pack.settings = PackSettings::load(data)?;
         }
 
+        // The padding file is generated on save, so we don't need to keep it.
+        pack.files.remove(RESERVED_NAME_PADDING);
+
         // If at this point we have not reached the end of the Pack, there is something wrong with it.
         // NOTE: Arena Packs have extra data at the end. If we detect one of those Packs, take that into account.
         if pack.header.pfh_version == PFHVersion::PFH5 && pack.header.bitmask.contains(PFHFlags::HAS_EXTENDED_HEADER) {
@@ -518,6 +728,52 @@ impl Pack {
         Ok(pack)
     }
 
+    /// This function checks the sizes of the indexes declared in the header of a Pack, before reading them.
+    ///
+    /// It returns the capacity to reserve for the files in the index.
+    pub(crate) fn check_indexes_size(&mut self, data_pos: u64, data_len: u64, indexes_size: u64, files_count: u32, files_index_size: u32) -> Result<usize> {
+        if data_pos + indexes_size > data_len {
+            return Err(RLibError::PackIndexesNotComplete);
+        }
+
+        if files_count == 0 && files_index_size > 0 {
+            self.load_warnings.push(format!("The Pack has no files, but its file index is {} bytes long. The file index has been ignored.", files_index_size));
+        }
+
+        // Each entry of the file index takes at least 2 bytes, so don't trust bigger counts when reserving memory.
+        Ok(files_count.min(files_index_size / 2) as usize)
+    }
+
+    /// This function checks that an entry of the file index points to data within the Pack.
+    pub(crate) fn check_index_entry_bounds(path: &str, data_pos: u64, size: u32, data_len: u64) -> Result<()> {
+        if data_pos + u64::from(size) > data_len {
+            return Err(RLibError::PackIndexEntryOutOfBounds {
+                path: path.to_owned(),
+                offset: data_pos,
+                size: u64::from(size),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// This function adds a file read from the file index to the Pack.
+    ///
+    /// If the path was already in the index, even with a different casing, the last entry is kept and a warning is added to the load warnings.
+    pub(crate) fn insert_index_entry(&mut self, file: RFile, index_paths: &mut HashMap<String, String>) -> Result<()> {
+        let path = file.path_in_container_raw().to_owned();
+        if let Some(old_path) = index_paths.insert(path.to_lowercase(), path.to_owned()) {
+            self.files.remove(&old_path);
+            self.load_warnings.push(if old_path == path {
+                format!("The file \"{}\" is more than once in the index. Only the last one has been kept.", path)
+            } else {
+                format!("The files \"{}\" and \"{}\" only differ in casing. Only the last one, \"{}\", has been kept.", old_path, path, path)
+            });
+        }
+
+        self.insert(file).map(|_| ())
+    }
+
     /// This function writes a `Pack` into the provided buffer.
     fn write<W: WriteBytes>(&mut self, buffer: &mut W, extra_data: &Option<EncodeableExtraData>) -> Result<()> {
         let test_mode = if let Some(extra_data) = extra_data {
@@ -540,6 +796,7 @@ impl Pack {
                 }
 
                 // Saving Pack settings.
+                self.settings.update_legacy_diagnostics_ignore_setting();
                 let mut data = vec![];
                 data.write_all(to_string_pretty(&self.settings)?.as_bytes())?;
                 let file = RFile::new_from_vec(&data, FileType::Text, 0, RESERVED_NAME_SETTINGS);
@@ -547,15 +804,27 @@ impl Pack {
             }
         }
 
-        match self.header.pfh_version {
-            PFHVersion::PFH6 => self.write_pfh6(buffer, extra_data)?,
-            PFHVersion::PFH5 => self.write_pfh5(buffer, extra_data)?,
-            PFHVersion::PFH4 => self.write_pfh4(buffer, extra_data)?,
-            PFHVersion::PFH3 => self.write_pfh3(buffer, extra_data)?,
-            PFHVersion::PFH2 => self.write_pfh2(buffer, extra_data)?,
-            PFHVersion::PFH0 => self.write_pfh0(buffer, extra_data)?,
+        // We can read encrypted Packs, but not write them. Their files are decrypted when loaded,
+        // so we write them decrypted and remove the flags that no longer apply.
+        if self.header.bitmask.intersects(NOT_ENCODEABLE_FLAGS) {
+
+            #[cfg(feature = "integration_log")] {
+                info!("Pack {} has flags we cannot encode ({:?}). Saving it decrypted and without them.", self.disk_file_path, self.header.bitmask & NOT_ENCODEABLE_FLAGS);
+            }
+
+            self.header.bitmask.remove(NOT_ENCODEABLE_FLAGS);
         }
 
+        // Any other flag the PFH version doesn't know about would result in a Pack the game cannot read.
+        let unsupported_flags = self.header.bitmask - Self::supported_pfh_flags(self.header.pfh_version);
+        if !unsupported_flags.is_empty() {
+            return Err(RLibError::PackFlagsNotSupported(self.header.pfh_version.to_string(), format!("{:?}", unsupported_flags)));
+        }
+
+        // The padding file, if needed, is added by the writer of each PFH version, once it knows the size of the rest of the Pack.
+        self.files.remove(RESERVED_NAME_PADDING);
+        self.write_pfh_version(buffer, extra_data)?;
+
         // Remove again the reserved Files.
         self.remove(&ContainerPath::File(RESERVED_NAME_NOTES.to_owned()));
         self.remove(&ContainerPath::File(RESERVED_NAME_SETTINGS.to_owned()));
@@ -568,12 +837,90 @@ impl Pack {
     //                        Convenience functions
     //-----------------------------------------------------------------------//
 
+    /// This function guesses again the types of all the files in this Pack, checking first the file type rules of the provided game.
+    ///
+    /// Use it when the Pack was read without knowing the game it's for, or when said game changes.
+    pub fn guess_file_types_for_game(&mut self, game_info: &GameInfo) -> Result<()> {
+        self.files.par_iter_mut().map(|(_, file)| file.guess_file_type_for_game(game_info)).collect()
+    }
+
+    /// This function writes the Pack into the provided buffer, using the writer of its PFH version.
+    fn write_pfh_version<W: WriteBytes>(&mut self, buffer: &mut W, extra_data: &Option<EncodeableExtraData>) -> Result<()> {
+        match self.header.pfh_version {
+            PFHVersion::PFH6 => self.write_pfh6(buffer, extra_data),
+            PFHVersion::PFH5 => self.write_pfh5(buffer, extra_data),
+            PFHVersion::PFH4 => self.write_pfh4(buffer, extra_data),
+            PFHVersion::PFH3 => self.write_pfh3(buffer, extra_data),
+            PFHVersion::PFH2 => self.write_pfh2(buffer, extra_data),
+            PFHVersion::PFH0 => self.write_pfh0(buffer, extra_data),
+        }
+    }
+
+    /// This function returns the flags a Pack header of the provided PFH version can have.
+    fn supported_pfh_flags(pfh_version: PFHVersion) -> PFHFlags {
+        match pfh_version {
+            PFHVersion::PFH6 => PFHFlags::HAS_INDEX_WITH_TIMESTAMPS | PFHFlags::HAS_ENCRYPTED_INDEX | PFHFlags::HAS_ENCRYPTED_DATA,
+            PFHVersion::PFH5 |
+            PFHVersion::PFH4 => PFHFlags::all(),
+            PFHVersion::PFH3 |
+            PFHVersion::PFH2 => PFHFlags::HAS_INDEX_WITH_TIMESTAMPS,
+            PFHVersion::PFH0 => PFHFlags::empty(),
+        }
+    }
+
+    /// This function returns the flags that cannot be enabled in the header of a Pack with the provided PFH version and type
+    /// for the provided game, along with the reason why. Flags not in the returned list can be toggled freely.
+    ///
+    /// Flags that can be read but not saved are always returned, as they're removed from the header when saving.
+    pub fn pfh_flags_restrictions(game: &GameInfo, pfh_version: PFHVersion, pfh_file_type: PFHFileType) -> Vec<(PFHFlags, String)> {
+        let game_pfh_version = game.pfh_version_by_file_type(pfh_file_type);
+        PFH_FLAGS.iter()
+            .filter_map(|flag| {
+                if !Self::supported_pfh_flags(pfh_version).contains(*flag) {
+                    Some((*flag, format!("{} Packs do not support this flag.", pfh_version)))
+                } else if !Self::supported_pfh_flags(game_pfh_version).contains(*flag) {
+                    Some((*flag, format!("{} uses {} for {} Packs, which do not support this flag.", game.display_name(), game_pfh_version, pfh_file_type)))
+                } else if NOT_ENCODEABLE_FLAGS.contains(*flag) {
+                    Some((*flag, "This flag can be read, but not saved. It's removed from the header when saving the Pack.".to_owned()))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// This function checks if the provided flags are valid in the header of a Pack with the provided PFH version and type for the provided game.
+    ///
+    /// Flags that can be read but not saved are considered valid, as they're removed from the header when saving.
+    pub fn validate_pfh_flags(game: &GameInfo, pfh_version: PFHVersion, pfh_file_type: PFHFileType, flags: PFHFlags) -> Result<()> {
+        let invalid_flags = Self::pfh_flags_restrictions(game, pfh_version, pfh_file_type).into_iter()
+            .filter(|(flag, _)| flags.contains(*flag) && !NOT_ENCODEABLE_FLAGS.contains(*flag))
+            .map(|(flag, reason)| format!("{:?}: {}", flag, reason))
+            .collect::<Vec<_>>();
+
+        if invalid_flags.is_empty() {
+            Ok(())
+        } else {
+            Err(RLibError::PackFlagsNotSupported(pfh_version.to_string(), invalid_flags.join(" ")))
+        }
+    }
+
     /// This function reads and returns all CA Packs for the provided game merged as one, for easy manipulation.
     ///
     /// This needs a [GameInfo] to get the Packs from, and a game path to search the Packs on.
     pub fn read_and_merge_ca_packs(game: &GameInfo, game_path: &Path) -> Result<Self> {
         let paths = game.ca_packs_paths(game_path)?;
-        Self::read_and_merge(&paths, true, true)
+        Self::read_and_merge_ca_packs_from_paths(&paths)
+    }
+
+    /// This function reads and returns only the provided CA Packs merged as one, for when loading all of them is overkill.
+    ///
+    /// The resulting Pack is marked as `Release` and keeps the compression flag if any of its files was compressed, like the full load.
+    pub fn read_and_merge_ca_packs_from_paths(pack_paths: &[PathBuf]) -> Result<Self> {
+        let mut pack = Self::read_and_merge(pack_paths, true, true)?;
+        pack.set_pfh_file_type(PFHFileType::Release);
+        pack.compress = pack.files.values().any(|file| file.is_compressed());
+        Ok(pack)
     }
 
     /// Convenience function to open multiple Packs as one, taking care of overwriting files when needed.
@@ -625,7 +972,10 @@ impl Pack {
                 } else { true }
             })
             .for_each(|pack| {
-                pack_new.files_mut().extend(pack.files().clone())
+                pack_new.files_mut().extend(pack.files().clone());
+
+                let pack_name = pack.disk_file_name();
+                pack_new.load_warnings.extend(pack.load_warnings.iter().map(|warning| format!("{}: {}", pack_name, warning)));
             });
 
         // Fix the dependencies of the merged pack.
@@ -644,6 +994,108 @@ impl Pack {
         Ok(pack_new)
     }
 
+    /// This function generates the manifest of the Pack saved at the provided path.
+    ///
+    /// Files are hashed exactly as they're stored in the Pack, so compressed files are hashed compressed.
+    /// RPFM's reserved files (notes, settings,...) are not part of the manifest.
+    pub fn manifest(pack_path: &Path) -> Result<PackManifest> {
+        let pack = Self::read_and_merge(&[pack_path.to_path_buf()], true, false)?;
+        let mut files = pack.files.par_iter()
+            .map(|(path, file)| {
+                let data = file.data_as_stored()?;
+                Ok(PackManifestEntry {
+                    path: path.to_owned(),
+                    size: data.len() as u64,
+                    sha256: format!("{:x}", Sha256::digest(&data)),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(PackManifest {
+            pfh_version: pack.pfh_version(),
+            timestamp: pack.internal_timestamp(),
+            files,
+        })
+    }
+
+    /// This function tries to sanitize a broken Pack so it can be saved, returning a report of what was done.
+    ///
+    /// Files whose data goes past the end of the Pack are truncated instead of removed. Files that cannot be read, empty files,
+    /// and files with the same path as others if we ignore the case are removed. Files that can be read but not decoded with
+    /// the provided extra data are kept, but reported as suspicious. Files are loaded to memory in the process.
+    ///
+    /// BE CAREFUL WITH USING THIS. IT MAY (PROBABLY WILL) CAUSE DATA LOSSES.
+    pub fn rescue(&mut self, extra_data: &Option<DecodeableExtraData>) -> PackRescueReport {
+        let mut report = PackRescueReport {
+            removed_flags: self.header.bitmask & NOT_ENCODEABLE_FLAGS,
+            ..Default::default()
+        };
+
+        // The game cannot tell apart paths with different case, so we only keep the first path of each group.
+        let mut paths = self.files.keys().cloned().collect::<Vec<_>>();
+        paths.sort();
+
+        let mut kept_paths: HashMap<String, String> = HashMap::new();
+        for path in paths {
+            match kept_paths.get(&path.to_lowercase()) {
+                Some(kept_path) => {
+                    self.files.remove(&path);
+                    report.dropped_files.push((path, RescueDropReason::DuplicatePath(kept_path.to_owned())));
+                }
+                None => {
+                    kept_paths.insert(path.to_lowercase(), path);
+                }
+            }
+        }
+
+        let results = self.files.par_iter_mut()
+            .map(|(path, file)| {
+                let mut suspicious = vec![];
+                match file.fit_data_to_source() {
+                    Ok(Some((_, 0))) => return (path.to_owned(), Some(RescueDropReason::OutOfBounds), suspicious),
+                    Ok(Some((old_size, new_size))) => suspicious.push(RescueSuspiciousReason::Truncated(old_size, new_size)),
+                    Ok(None) => {},
+                    Err(error) => return (path.to_owned(), Some(RescueDropReason::Unreadable(error.to_string())), suspicious),
+                }
+
+                if let Err(error) = file.load() {
+                    return (path.to_owned(), Some(RescueDropReason::Unreadable(error.to_string())), suspicious);
+                }
+
+                if let Ok(data) = file.cached() {
+                    if data.is_empty() {
+                        return (path.to_owned(), Some(RescueDropReason::ZeroSize), suspicious);
+                    }
+
+                    // Decode a copy, so we don't keep every file decoded in memory.
+                    if file.file_type() != FileType::Unknown {
+                        if let Err(error) = file.clone().decode(extra_data, false, true) {
+                            suspicious.push(RescueSuspiciousReason::Undecodeable(error.to_string()));
+                        }
+                    }
+                }
+
+                (path.to_owned(), None, suspicious)
+            })
+            .collect::<Vec<_>>();
+
+        for (path, drop_reason, suspicious) in results {
+            match drop_reason {
+                Some(drop_reason) => {
+                    self.files.remove(&path);
+                    report.dropped_files.push((path, drop_reason));
+                }
+                None => report.suspicious_files.extend(suspicious.into_iter().map(|reason| (path.to_owned(), reason))),
+            }
+        }
+
+        report.dropped_files.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+        report.suspicious_files.sort_by(|(path_a, _), (path_b, _)| path_a.cmp(path_b));
+        report
+    }
+
     /// Convenience function to easily save a Pack to disk.
     ///
     /// If a path is provided, the Pack will be saved to that path. Otherwise, it'll use whatever path it had set before.
@@ -653,7 +1105,9 @@ impl Pack {
         }
 
         // Before truncating the file, make sure we loaded everything to memory.
+        // Files loaded from the file we're about to overwrite can no longer be unloaded, as their offsets will be wrong.
         self.files.iter_mut().try_for_each(|(_, file)| file.load())?;
+        self.files.values_mut().for_each(|file| file.forget_source(&self.disk_file_path));
 
         let mut file = BufWriter::new(File::create(&self.disk_file_path)?);
         let extra_data = EncodeableExtraData::default();
@@ -685,6 +1139,11 @@ impl Pack {
         *self.header.internal_timestamp()
     }
 
+    /// This function returns the version of the subheader of the Pack.
+    pub fn subheader_version(&self) -> u32 {
+        *self.header.subheader_version()
+    }
+
     /// This function returns the Game version this Pack is intended for.
     pub fn game_version(&self) -> u32 {
         *self.header.game_version()
@@ -753,7 +1212,14 @@ impl Pack {
         self.header.set_authoring_tool(authoring_tool.to_string());
     }
 
+    /// This function sets the version of the subheader of the Pack.
+    pub fn set_subheader_version(&mut self, subheader_version: u32) {
+        self.header.set_subheader_version(subheader_version);
+    }
+
     /// This function sets the Extra Subheader Data of the Pack.
+    ///
+    /// When saving, the data is cropped or padded with zeros to fit the 256 bytes the subheader has for it.
     pub fn set_extra_subheader_data(&mut self, extra_subheader_data: &[u8]) {
         self.header.set_extra_subheader_data(extra_subheader_data.to_vec());
     }
@@ -778,27 +1244,32 @@ impl Pack {
         matches!(self.header.pfh_version, PFHVersion::PFH6 | PFHVersion::PFH5)
     }
 
-    /// This function is used to generate all loc entries missing from a Pack into a missing.loc file.
-    pub fn generate_missing_loc_data(&mut self) -> Result<Option<ContainerPath>> {
-
-        let db_tables = self.files_by_type(&[FileType::DB]);
-        let loc_tables = self.files_by_type(&[FileType::Loc]);
-        let mut missing_trads_file = Loc::new(false);
+    /// This function is used to generate all loc entries missing from a Pack into the provided loc file.
+    ///
+    /// The loc keys are built from the localised fields of each DB table of the Pack, using the table's keys.
+    /// Keys already in any loc of the Pack or in `existing_keys` are not generated again.
+    ///
+    /// `{key}` in the `value_template` gets replaced with the key of each new entry.
+    ///
+    /// It returns the amount of entries generated, and the path of the loc file, if it was changed.
+    pub fn generate_missing_loc_data(&mut self, loc_path: &str, value_template: &str, existing_keys: &HashSet<String>) -> Result<(usize, Option<ContainerPath>)> {
 
-        db_tables.iter().for_each(|rfile| {
-            if let RFileDecoded::DB(table) = rfile.decoded().unwrap() {
-                let definition = table.definition();
-                let fields_processed = definition.fields_processed();
-                let localised_fields = definition.localised_fields();
-                if !localised_fields.is_empty() && fields_processed.iter().filter(|x| x.is_key()).count() > 1 {
-                    println!("{}, keys: {}", table.table_name_without_tables(), fields_processed.iter().filter(|x| x.is_key()).count());
-                }
+        // Make sure the target loc, if exists, is decoded, so we can add our stuff to it later.
+        if let Some(file) = self.files.get_mut(loc_path) {
+            if file.file_type() != FileType::Loc {
+                return Err(RLibError::DecodingLocNotALocTable);
             }
-        });
 
+            file.decode(&None, true, false)?;
+        }
+
+        let mut db_tables = self.files_by_type(&[FileType::DB]);
+        db_tables.sort_by(|a, b| a.path_in_container_raw().cmp(b.path_in_container_raw()));
+
+        let loc_tables = self.files_by_type(&[FileType::Loc]);
         let loc_keys_from_memory = loc_tables.par_iter().filter_map(|rfile| {
-            if let RFileDecoded::Loc(table) = rfile.decoded().unwrap() {
-                Some(table.data(&None).unwrap().iter().filter_map(|x| {
+            if let Ok(RFileDecoded::Loc(table)) = rfile.decoded() {
+                Some(table.data(&None).ok()?.iter().filter_map(|x| {
                     if let DecodedData::StringU16(data) = &x[0] {
                         Some(data.to_owned())
                     } else {
@@ -808,51 +1279,200 @@ impl Pack {
             } else { None }
         }).flatten().collect::<HashSet<String>>();
 
-        let missing_trads_file_table_data = db_tables.par_iter().filter_map(|rfile| {
-            if let RFileDecoded::DB(table) = rfile.decoded().unwrap() {
+        let missing_loc_keys = db_tables.par_iter().filter_map(|rfile| {
+            if let Ok(RFileDecoded::DB(table)) = rfile.decoded() {
                 let definition = table.definition();
                 let loc_fields = definition.localised_fields();
                 let processed_fields = definition.fields_processed();
                 if !loc_fields.is_empty() {
-                    let table_data = table.data(&None).unwrap();
+                    let table_data = table.data(&None).ok()?;
                     let table_name = table.table_name_without_tables();
 
                     // Get the keys, which may be concatenated. We get them IN THE ORDER THEY ARE IN THE BINARY FILE.
                     let key_field_names = definition.fields().iter().filter_map(|field| if field.is_key() { Some(field.name()) } else { None }).collect::<Vec<&str>>();
                     let key_field_positions = key_field_names.iter().filter_map(|name| processed_fields.iter().position(|field| field.name() == *name)).collect::<Vec<usize>>();
 
-                    let mut new_rows = vec![];
-
+                    let mut keys = vec![];
                     for row in table_data.iter() {
+                        let key = key_field_positions.iter().map(|pos| row[*pos].data_to_string()).join("");
                         for loc_field in loc_fields {
-                            let key = key_field_positions.iter().map(|pos| row[*pos].data_to_string()).join("");
                             let loc_key = format!("{}_{}_{}", table_name, loc_field.name(), key);
-
-                            if loc_keys_from_memory.get(&*loc_key).is_none() {
-                                let mut new_row = missing_trads_file.new_row();
-                                new_row[0] = DecodedData::StringU16(loc_key);
-                                new_row[1] = DecodedData::StringU16("PLACEHOLDER".to_owned());
-                                new_rows.push(new_row);
+                            if !loc_keys_from_memory.contains(&loc_key) && !existing_keys.contains(&loc_key) {
+                                keys.push(loc_key);
                             }
                         }
                     }
 
-                    return Some(new_rows)
+                    return Some(keys)
                 }
             }
             None
-        }).flatten().collect::<Vec<Vec<DecodedData>>>();
+        }).flatten().collect::<Vec<String>>();
 
-        // Save the missing translations to a missing_locs.loc file.
-        let _ = missing_trads_file.set_data(&missing_trads_file_table_data);
-        if !missing_trads_file_table_data.is_empty() {
-            let packed_file = RFile::new_from_decoded(&RFileDecoded::Loc(missing_trads_file), 0,  "text/missing_locs.loc");
-            Ok(self.insert(packed_file)?)
-        } else {
-            Ok(None)
+        // Different tables may generate the same key, so make sure we only add each one once.
+        let mut keys_added = HashSet::new();
+        let missing_loc_keys = missing_loc_keys.into_iter()
+            .filter(|key| keys_added.insert(key.to_owned()))
+            .collect::<Vec<_>>();
+
+        self.add_loc_entries(loc_path, &missing_loc_keys, value_template)
+    }
+
+    /// This function adds entries with the provided keys to the end of the loc file at the provided path, creating it if it doesn't exist.
+    ///
+    /// `{key}` in the `value_template` gets replaced with the key of each new entry.
+    ///
+    /// It returns the amount of entries added, and the path of the loc file, if it was changed.
+    pub fn add_loc_entries(&mut self, loc_path: &str, keys: &[String], value_template: &str) -> Result<(usize, Option<ContainerPath>)> {
+        if keys.is_empty() {
+            return Ok((0, None));
+        }
+
+        // Make sure the target loc, if exists, is decoded, so we can add our stuff to it.
+        if let Some(file) = self.files.get_mut(loc_path) {
+            if file.file_type() != FileType::Loc {
+                return Err(RLibError::DecodingLocNotALocTable);
+            }
+
+            file.decode(&None, true, false)?;
+        }
+
+        let empty_row = Loc::new(false).new_row();
+        let mut new_rows = keys.iter()
+            .map(|key| {
+                let mut new_row = empty_row.clone();
+                new_row[0] = DecodedData::StringU16(key.to_owned());
+                new_row[1] = DecodedData::StringU16(value_template.replace("{key}", key));
+                new_row
+            })
+            .collect::<Vec<_>>();
+
+        let amount = new_rows.len();
+
+        // Add the new entries to the end of the loc, or create it if it doesn't exist.
+        match self.files.get_mut(loc_path) {
+            Some(file) => {
+                if let RFileDecoded::Loc(loc) = file.decoded_mut()? {
+                    let mut data = loc.data(&None)?.to_vec();
+                    data.append(&mut new_rows);
+                    loc.set_data(&data)?;
+                }
+
+                Ok((amount, Some(ContainerPath::File(loc_path.to_owned()))))
+            }
+            None => {
+                let mut loc = Loc::new(false);
+                loc.set_data(&new_rows)?;
+                let file = RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, loc_path);
+                Ok((amount, self.insert(file)?))
+            }
         }
     }
 
+    /// This function returns the DB tables under the provided path that have a newer definition in the provided schema.
+    ///
+    /// It returns the path, current version and newest version of each table that can be updated,
+    /// and the paths of the tables that cannot be updated because there is no newer definition for them.
+    pub fn outdated_tables(&self, schema: &Schema, path: &ContainerPath) -> TableUpdates {
+        let mut files = self.files_by_path(path, false);
+        files.retain(|file| file.file_type() == FileType::DB);
+        files.sort_by(|a, b| a.path_in_container_raw().cmp(b.path_in_container_raw()));
+
+        let mut outdated = vec![];
+        let mut skipped = vec![];
+        for file in files {
+            let path = file.path_in_container_raw().to_owned();
+            if let Ok(RFileDecoded::DB(table)) = file.decoded() {
+                let old_version = *table.definition().version();
+                let newest_version = schema.definitions_by_table_name(table.table_name())
+                    .and_then(|definitions| definitions.iter().map(|definition| *definition.version()).max());
+
+                match newest_version {
+                    Some(new_version) if new_version > old_version => outdated.push((path, old_version, new_version)),
+                    _ => skipped.push(path),
+                }
+            } else {
+                skipped.push(path);
+            }
+        }
+
+        (outdated, skipped)
+    }
+
+    /// This function updates the DB tables under the provided path to the newest definition they have in the provided schema.
+    ///
+    /// Columns in both definitions keep their data, new columns get their default value, and removed columns are dropped.
+    /// Columns renamed through the schema patches keep their data too.
+    ///
+    /// It returns the same data as [Pack::outdated_tables], with the first list containing the tables that got updated.
+    pub fn update_tables(&mut self, schema: &Schema, path: &ContainerPath) -> Result<TableUpdates> {
+        let (outdated, skipped) = self.outdated_tables(schema, path);
+        for (path, _, new_version) in &outdated {
+            if let Some(file) = self.files.get_mut(path) {
+                if let RFileDecoded::DB(table) = file.decoded_mut()? {
+                    let table_name = table.table_name().to_owned();
+                    if let Some(definition) = schema.definition_by_name_and_version(&table_name, *new_version) {
+                        table.set_patches(&schema.patches_for_table(&table_name).cloned().unwrap_or_default());
+                        table.set_definition(definition);
+                    }
+                }
+            }
+        }
+
+        Ok((outdated, skipped))
+    }
+
+    /// This function returns the `(path, row key)` of the row annotations of the Pack that no longer point to a row.
+    ///
+    /// That's the annotations of files no longer in the Pack, or whose row key is not in their file. Files that fail to decode
+    /// are not checked, so a missing schema doesn't turn all their annotations into orphans.
+    pub fn orphaned_row_annotations(&mut self, extra_data: &Option<DecodeableExtraData>) -> HashSet<(String, String)> {
+        let mut orphaned = HashSet::new();
+        let paths = self.settings.row_annotations().keys().cloned().collect::<Vec<_>>();
+        for path in paths {
+            let row_keys = match self.files.get_mut(&path) {
+                Some(file) => match file.decode(extra_data, false, true) {
+                    Ok(Some(RFileDecoded::DB(table))) => table.table().row_keys(),
+                    Ok(Some(RFileDecoded::Loc(table))) => table.table().row_keys(),
+                    _ => continue,
+                },
+                None => Ok(vec![]),
+            };
+
+            if let Ok(row_keys) = row_keys {
+                let row_keys = row_keys.into_iter().flatten().collect::<HashSet<_>>();
+                orphaned.extend(self.settings.row_annotations()[&path].keys()
+                    .filter(|key| !row_keys.contains(*key))
+                    .map(|key| (path.to_owned(), key.to_owned())));
+            }
+        }
+
+        orphaned
+    }
+
+    /// This function returns the paths of all the XML files within the map folders of the Pack.
+    ///
+    /// These files are a byproduct of how Terry exports maps, and the game doesn't use them.
+    pub fn map_xml_paths(&self) -> Vec<String> {
+        self.files.iter()
+            .filter(|(_, file)| file.file_type() == FileType::Text)
+            .filter_map(|(path, _)| {
+                let path_lower = path.to_lowercase();
+                let is_map_file = TERRY_MAP_PATHS.iter().any(|map_path| path_lower.starts_with(&format!("{}/", map_path)));
+                let is_xml = text::EXTENSIONS.iter()
+                    .find_map(|(extension, format)| if path_lower.ends_with(extension) { Some(format) } else { None })
+                    .map(|format| *format == TextFormat::Xml)
+                    .unwrap_or(false);
+
+                if is_map_file && is_xml {
+                    Some(path.to_owned())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
     /// This function is used to patch Warhammer I & II Siege map packs so their AI actually works.
     ///
     /// This also removes the useless xml files left by Terry in the Pack.
@@ -948,13 +1568,260 @@ impl Pack {
             Ok((format!("{} files patched.\n{} files deleted.", files_patched, files_to_delete.len()), files_to_delete))
         }
     }
+
+    /// This function extracts all the files of the Pack to the provided folder, applying the provided [MyModExportRule]s to their paths.
+    ///
+    /// Files not under any rule keep their path and, if a schema is provided, DB and Loc files are extracted as TSV. Files under a rule
+    /// are only extracted as TSV if the rule asks for it. For rules with the `mirror` flag, files in their disk folder that are not in
+    /// the Pack are deleted, except hidden files and folders (like `.git`).
+    ///
+    /// Returns the paths of the files that failed to extract.
+    pub fn extract_with_export_rules(&mut self, destination_path: &Path, rules: &[MyModExportRule], schema: &Option<Schema>) -> Result<Vec<String>> {
+        let no_schema = None;
+        let mut failed_paths = vec![];
+        let mut extracted_paths = HashSet::new();
+
+        let mut paths = self.files.keys().cloned().collect::<Vec<String>>();
+        paths.sort();
+
+        for path in paths {
+            let rule = MyModExportRule::rule_for_pack_path(rules, &path);
+            let (disk_path, schema) = match rule {
+                Some(rule) => (rule.disk_path(&path), if rule.tables_as_tsv { schema } else { &no_schema }),
+                None => (path.to_owned(), schema),
+            };
+
+            // Tables fall back to binary if they fail to extract as TSV, so we keep both names.
+            let file_path = destination_path.join(disk_path);
+            extracted_paths.insert(PathBuf::from(format!("{}.tsv", file_path.to_string_lossy())));
+            extracted_paths.insert(file_path.to_owned());
+
+            if self.extract(ContainerPath::File(path.to_owned()), &file_path, false, schema).is_err() {
+                failed_paths.push(path);
+            }
+        }
+
+        for rule in rules.iter().filter(|rule| rule.mirror) {
+            let folder_path = destination_path.join(&rule.disk_folder);
+            if folder_path.is_dir() {
+                for file_path in files_from_subdir(&folder_path, true)? {
+                    let is_hidden = file_path.strip_prefix(&folder_path)
+                        .map(|path| path.components().any(|component| component.as_os_str().to_string_lossy().starts_with('.')))
+                        .unwrap_or(true);
+
+                    if !is_hidden && !extracted_paths.contains(&file_path) {
+                        remove_file(&file_path)?;
+                    }
+                }
+            }
+        }
+
+        Ok(failed_paths)
+    }
+
+    /// This function compares this Pack against another one, returning the files that differ between them.
+    ///
+    /// DB and Loc files present in both Packs are compared by their decoded data if the provided schema can decode them,
+    /// so changes in their headers (like a different GUID) are not reported. The rest of the files are compared byte by byte.
+    pub fn diff(&mut self, other: &mut Pack, schema: Option<&Schema>) -> Result<PackDiff> {
+        let mut diff = PackDiff::default();
+
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.set_schema(schema);
+        let extra_data = Some(extra_data);
+
+        diff.only_in_other = other.files.keys()
+            .filter(|path| !self.files.contains_key(*path))
+            .map(|path| ContainerPath::File(path.to_owned()))
+            .collect();
+
+        for (path, file) in self.files.iter_mut() {
+            match other.files.get_mut(path) {
+                Some(other_file) => {
+                    if matches!(file.file_type(), FileType::DB | FileType::Loc) && file.file_type() == other_file.file_type() {
+                        let data = file.decode(&extra_data, false, true);
+                        let other_data = other_file.decode(&extra_data, false, true);
+
+                        if let (Ok(Some(data)), Ok(Some(other_data))) = (data, other_data) {
+                            if let Some(table_diff) = Self::table_diff(&data, &other_data)? {
+                                diff.different.push(ContainerPath::File(path.to_owned()));
+                                diff.table_diffs.insert(path.to_owned(), table_diff);
+                            }
+
+                            continue;
+                        }
+                    }
+
+                    let data = file.encode(&None, false, false, true)?;
+                    let other_data = other_file.encode(&None, false, false, true)?;
+                    if data != other_data {
+                        diff.different.push(ContainerPath::File(path.to_owned()));
+                    }
+                }
+                None => diff.only_in_self.push(ContainerPath::File(path.to_owned())),
+            }
+        }
+
+        diff.only_in_self.sort();
+        diff.only_in_other.sort();
+        diff.different.sort();
+
+        Ok(diff)
+    }
+
+    /// This function returns the row-level differences between two decoded tables, or None if they're equal.
+    fn table_diff(data: &RFileDecoded, other_data: &RFileDecoded) -> Result<Option<TableDiff>> {
+        let (definition, rows, other_definition, other_rows) = match (data, other_data) {
+            (RFileDecoded::DB(data), RFileDecoded::DB(other_data)) => (data.definition(), data.data(&None)?, other_data.definition(), other_data.data(&None)?),
+            (RFileDecoded::Loc(data), RFileDecoded::Loc(other_data)) => (data.definition(), data.data(&None)?, other_data.definition(), other_data.data(&None)?),
+            _ => return Ok(None),
+        };
+
+        let rows = Self::table_rows_by_key(definition, &rows);
+        let other_rows = Self::table_rows_by_key(other_definition, &other_rows);
+
+        let mut table_diff = TableDiff::default();
+        let no_rows = vec![];
+        let keys = rows.keys().chain(other_rows.keys()).collect::<HashSet<_>>();
+        for key in keys {
+            let key_rows = rows.get(key).unwrap_or(&no_rows);
+            let other_key_rows = other_rows.get(key).unwrap_or(&no_rows);
+            if key_rows.len() > 1 || other_key_rows.len() > 1 {
+                table_diff.duplicated_keys += 1;
+            }
+
+            for index in 0..key_rows.len().max(other_key_rows.len()) {
+                match (key_rows.get(index), other_key_rows.get(index)) {
+                    (Some(row), Some(other_row)) => if row != other_row {
+                        table_diff.changed += 1;
+                    },
+                    (Some(_), None) => table_diff.added += 1,
+                    (None, Some(_)) => table_diff.removed += 1,
+                    (None, None) => {},
+                }
+            }
+        }
+
+        if table_diff == TableDiff::default() && definition == other_definition {
+            Ok(None)
+        } else {
+            Ok(Some(table_diff))
+        }
+    }
+
+    /// This function returns the rows of a table grouped by the contents of their key columns, in the order they appear in the table.
+    fn table_rows_by_key<'a>(definition: &Definition, rows: &'a [Vec<DecodedData>]) -> HashMap<Vec<String>, Vec<&'a Vec<DecodedData>>> {
+        let fields = definition.fields_processed();
+        let key_columns = fields.iter()
+            .enumerate()
+            .filter_map(|(index, field)| if field.is_key() { Some(index) } else { None })
+            .collect::<Vec<_>>();
+
+        let mut rows_by_key: HashMap<Vec<String>, Vec<&'a Vec<DecodedData>>> = HashMap::new();
+        for row in rows {
+            let key = if key_columns.is_empty() {
+                row.iter().map(|data| data.data_to_string().to_string()).collect()
+            } else {
+                key_columns.iter().filter_map(|column| row.get(*column)).map(|data| data.data_to_string().to_string()).collect()
+            };
+
+            rows_by_key.entry(key).or_default().push(row);
+        }
+
+        rows_by_key
+    }
+}
+
+impl PackManifest {
+
+    /// This function returns the path of the manifest that corresponds to the Pack at the provided path.
+    pub fn path_for_pack(pack_path: &Path) -> PathBuf {
+        let file_name = pack_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        let pack_name = file_name.strip_suffix(EXTENSION).unwrap_or(&file_name);
+        pack_path.with_file_name(format!("{}{}", pack_name, MANIFEST_EXTENSION))
+    }
+
+    /// This function saves the manifest as json next to the Pack at the provided path, returning the path of the manifest.
+    pub fn save(&self, pack_path: &Path) -> Result<PathBuf> {
+        let path = Self::path_for_pack(pack_path);
+        let mut file = BufWriter::new(File::create(&path)?);
+        file.write_all(to_string_pretty(self)?.as_bytes())?;
+        Ok(path)
+    }
+}
+
+impl PackRescueReport {
+
+    /// This function returns the path of the report that corresponds to the Pack at the provided path.
+    pub fn path_for_pack(pack_path: &Path) -> PathBuf {
+        let file_name = pack_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        let pack_name = file_name.strip_suffix(EXTENSION).unwrap_or(&file_name);
+        pack_path.with_file_name(format!("{}{}", pack_name, RESCUE_REPORT_EXTENSION))
+    }
+
+    /// This function saves the report as text next to the Pack at the provided path, returning the path of the report.
+    pub fn save(&self, pack_path: &Path) -> Result<PathBuf> {
+        let path = Self::path_for_pack(pack_path);
+        let mut file = BufWriter::new(File::create(&path)?);
+        file.write_all(self.to_string().as_bytes())?;
+        Ok(path)
+    }
+}
+
+impl Display for PackRescueReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Dropped files: {}", self.dropped_files.len())?;
+        for (path, reason) in &self.dropped_files {
+            writeln!(f, "  - {}: {}", path, reason)?;
+        }
+
+        writeln!(f, "\nSuspicious files: {}", self.suspicious_files.len())?;
+        for (path, reason) in &self.suspicious_files {
+            writeln!(f, "  - {}: {}", path, reason)?;
+        }
+
+        if self.removed_flags.is_empty() {
+            writeln!(f, "\nHeader flags: unchanged.")
+        } else {
+            writeln!(f, "\nHeader flags: removed {:?}.", self.removed_flags)
+        }
+    }
+}
+
+impl Display for RescueDropReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unreadable(error) => write!(f, "data couldn't be read ({})", error),
+            Self::OutOfBounds => write!(f, "data is out of the bounds of the Pack"),
+            Self::ZeroSize => write!(f, "file is empty"),
+            Self::DuplicatePath(kept_path) => write!(f, "duplicate of {} if we ignore the case", kept_path),
+        }
+    }
+}
+
+impl Display for RescueSuspiciousReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Truncated(old_size, new_size) => write!(f, "data truncated from {} to {} bytes", old_size, new_size),
+            Self::Undecodeable(error) => write!(f, "data couldn't be decoded ({})", error),
+        }
+    }
 }
 
 impl PackSettings {
 
     /// This function tries to load the settings from the current Pack and return them.
+    ///
+    /// If the settings have no diagnostics ignore rules, they're imported from the legacy setting, if any.
     pub fn load(data: &[u8]) -> Result<Self> {
-        from_slice(data).map_err(From::from)
+        let mut settings: Self = from_slice(data)?;
+        if settings.diagnostics_ignore_rules.is_empty() {
+            if let Some(legacy_rules) = settings.settings_text.get(LEGACY_DIAGNOSTICS_IGNORE_SETTING) {
+                settings.diagnostics_ignore_rules = DiagnosticIgnoreRule::from_legacy(legacy_rules);
+            }
+        }
+
+        Ok(settings)
     }
 
     /// This function returns the provided string setting, if found.
@@ -1005,29 +1872,441 @@ impl PackSettings {
         self.settings_number.insert(key.to_owned(), value);
     }
 
-    // TODO: Move this to rpfm_extensions.
-    pub fn diagnostics_files_to_ignore(&self) -> Option<Vec<(String, Vec<String>, Vec<String>)>> {
-        self.settings_text.get("diagnostics_files_to_ignore").map(|files_to_ignore| {
-            let files = files_to_ignore.split('\n').collect::<Vec<&str>>();
-
-            // Ignore commented out rows.
-            files.iter().filter_map(|x| {
-                if !x.starts_with('#') {
-                    let path = x.splitn(3, ';').collect::<Vec<&str>>();
-                    if path.len() == 3 {
-                        Some((path[0].to_string(), path[1].split(',').filter_map(|y| if !y.is_empty() { Some(y.to_owned()) } else { None }).collect::<Vec<String>>(), path[2].split(',').filter_map(|y| if !y.is_empty() { Some(y.to_owned()) } else { None }).collect::<Vec<String>>()))
-                    } else if path.len() == 2 {
-                        Some((path[0].to_string(), path[1].split(',').filter_map(|y| if !y.is_empty() { Some(y.to_owned()) } else { None }).collect::<Vec<String>>(), vec![]))
-                    } else if path.len() == 1 {
-                        Some((path[0].to_string(), vec![], vec![]))
-                    } else {
-                        None
+    /// This function returns if the file at the provided path should be left uncompressed on save,
+    /// according to the `compression_files_to_ignore` setting.
+    ///
+    /// Each line of the setting is either an extension (if it starts with a dot) or a path prefix.
+    /// Lines starting with `#` are ignored. Comparisons are case-insensitive.
+    ///
+    /// The padding file is never compressed, as that would defeat its purpose.
+    pub fn is_compression_excluded(&self, path: &str) -> bool {
+        if path == RESERVED_NAME_PADDING {
+            return true;
+        }
+
+        match self.settings_text.get("compression_files_to_ignore") {
+            Some(files_to_ignore) => {
+                let path = path.replace('\\', "/").to_lowercase();
+                files_to_ignore.lines()
+                    .map(|x| x.trim())
+                    .filter(|x| !x.is_empty() && !x.starts_with('#'))
+                    .any(|x| {
+                        let x = x.replace('\\', "/").to_lowercase();
+                        if x.starts_with('.') {
+                            path.ends_with(&x)
+                        } else {
+                            path.starts_with(&x)
+                        }
+                    })
+            }
+            None => false,
+        }
+    }
+
+    /// This function returns the minimum size, in bytes, the Pack has to be padded to on save, from the `pad_pack_to_bytes` setting.
+    ///
+    /// If the setting is missing or not positive, the Pack is not padded.
+    pub fn pad_to_size(&self) -> Option<u64> {
+        self.settings_number.get(PADDING_SIZE_SETTING)
+            .filter(|size| **size > 0)
+            .map(|size| *size as u64)
+    }
+
+    /// This function returns the Loc keys the translation reports should ignore, from the `translation_report_keys_to_ignore` setting.
+    ///
+    /// Each line of the setting is a key. Lines starting with `#` are ignored.
+    pub fn translation_report_keys_to_ignore(&self) -> HashSet<String> {
+        match self.settings_text.get("translation_report_keys_to_ignore") {
+            Some(keys) => keys.lines()
+                .map(|x| x.trim())
+                .filter(|x| !x.is_empty() && !x.starts_with('#'))
+                .map(|x| x.to_owned())
+                .collect(),
+            None => HashSet::new(),
+        }
+    }
+
+    /// This function returns the rules of the `mymod_export_rules` setting.
+    ///
+    /// Each line of the setting is a rule with the format `pack_folder;disk_folder;flags`, where flags is an optional comma-separated
+    /// list of `tsv` (export DB and Loc files as TSV) and `mirror` (delete files in the disk folder that are not in the Pack).
+    /// Lines starting with `#` are ignored, and so are rules with folders trying to escape their base folder with `..`.
+    pub fn mymod_export_rules(&self) -> Vec<MyModExportRule> {
+        match self.settings_text.get("mymod_export_rules") {
+            Some(rules) => rules.lines()
+                .map(|x| x.trim())
+                .filter(|x| !x.is_empty() && !x.starts_with('#'))
+                .filter_map(|x| {
+                    let rule = x.splitn(3, ';').collect::<Vec<&str>>();
+                    if rule.len() < 2 {
+                        return None;
                     }
-                } else {
+
+                    let pack_folder = rule[0].trim().replace('\\', "/").trim_matches('/').to_owned();
+                    let disk_folder = rule[1].trim().replace('\\', "/").trim_matches('/').to_owned();
+                    if pack_folder.split('/').any(|x| x == "..") || disk_folder.split('/').any(|x| x == "..") || disk_folder.contains(':') {
+                        return None;
+                    }
+
+                    let flags = rule.get(2).map(|x| x.split(',').map(|y| y.trim().to_lowercase()).collect::<Vec<String>>()).unwrap_or_default();
+                    Some(MyModExportRule {
+                        pack_folder,
+                        disk_folder,
+                        tables_as_tsv: flags.iter().any(|x| x == "tsv"),
+                        mirror: flags.iter().any(|x| x == "mirror"),
+                    })
+                })
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// This function returns the ESF node bookmarks of the file at the provided path, from the `esf_bookmarks` setting.
+    ///
+    /// Each line of the setting is a bookmark with the format `file_path;indexes;names`, where indexes is a comma-separated list of
+    /// the indexes of the path, and names is the list of names of the path separated by ` > `. Invalid lines are ignored.
+    pub fn esf_bookmarks(&self, path: &str) -> Vec<ESFNodePath> {
+        match self.settings_text.get("esf_bookmarks") {
+            Some(bookmarks) => bookmarks.lines()
+                .filter_map(|x| {
+                    let bookmark = x.trim().splitn(3, ';').collect::<Vec<&str>>();
+                    if bookmark.len() != 3 || bookmark[0] != path {
+                        return None;
+                    }
+
+                    let indexes = bookmark[1].split(',')
+                        .filter(|y| !y.is_empty())
+                        .map(|y| y.trim().parse::<usize>())
+                        .collect::<std::result::Result<Vec<usize>, _>>()
+                        .ok()?;
+
+                    let names = bookmark[2].split(" > ").map(|y| y.to_owned()).collect::<Vec<String>>();
+                    Some(ESFNodePath::new(indexes, names))
+                })
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// This function replaces the ESF node bookmarks of the file at the provided path in the `esf_bookmarks` setting.
+    ///
+    /// Bookmarks of other files are kept untouched.
+    pub fn set_esf_bookmarks(&mut self, path: &str, bookmarks: &[ESFNodePath]) {
+        let prefix = format!("{};", path);
+        let mut lines = self.settings_text.get("esf_bookmarks")
+            .map(|x| x.lines()
+                .filter(|y| !y.trim().starts_with(&prefix))
+                .map(|y| y.to_owned())
+                .collect::<Vec<String>>()
+            ).unwrap_or_default();
+
+        lines.extend(bookmarks.iter().map(|bookmark| format!("{}{};{}", prefix, bookmark.indexes().iter().map(|x| x.to_string()).join(","), bookmark)));
+        self.settings_text.insert("esf_bookmarks".to_owned(), lines.join("\n"));
+    }
+
+    /// This function returns the labels available for files and folders, as `(name, colour)`, from the `path_label_definitions` setting.
+    ///
+    /// Each line of the setting is a label with the format `name;colour`, where colour is a hex colour like `#4caf50`.
+    /// Lines starting with `#` and lines without colour are ignored. If the setting is missing, [DEFAULT_PATH_LABEL_DEFINITIONS] is used.
+    pub fn path_label_definitions(&self) -> Vec<(String, String)> {
+        let definitions = self.settings_text.get("path_label_definitions").map(|x| x.as_str()).unwrap_or(DEFAULT_PATH_LABEL_DEFINITIONS);
+        definitions.lines()
+            .map(|x| x.trim())
+            .filter(|x| !x.is_empty() && !x.starts_with('#'))
+            .filter_map(|x| {
+                let (name, colour) = x.rsplit_once(';')?;
+                let (name, colour) = (name.trim(), colour.trim());
+                if name.is_empty() || colour.is_empty() {
                     None
+                } else {
+                    Some((name.to_owned(), colour.to_owned()))
                 }
-            }).collect::<Vec<(String, Vec<String>, Vec<String>)>>()
-        })
+            })
+            .collect()
+    }
+
+    /// This function returns the labels assigned to files and folders of the Pack, from the `path_labels` setting.
+    ///
+    /// Each line of the setting is a label with the format `path;label`. Folder paths end with `/`. Invalid lines are ignored.
+    pub fn path_labels(&self) -> HashMap<ContainerPath, String> {
+        match self.settings_text.get("path_labels") {
+            Some(labels) => labels.lines()
+                .filter_map(|x| {
+                    let (path, label) = x.trim().rsplit_once(';')?;
+                    if path.is_empty() || label.is_empty() {
+                        return None;
+                    }
+
+                    let path = match path.strip_suffix('/') {
+                        Some(path) => ContainerPath::Folder(path.to_owned()),
+                        None => ContainerPath::File(path.to_owned()),
+                    };
+
+                    Some((path, label.to_owned()))
+                })
+                .collect(),
+            None => HashMap::new(),
+        }
+    }
+
+    /// This function sets the label of the provided path in the `path_labels` setting. Passing `None` removes its label.
+    pub fn set_path_label(&mut self, path: &ContainerPath, label: Option<&str>) {
+        let key = Self::path_label_key(path);
+        let mut lines = self.path_label_lines()
+            .into_iter()
+            .filter(|(path, _)| *path != key)
+            .collect::<Vec<_>>();
+
+        if let Some(label) = label {
+            lines.push((key, label.to_owned()));
+        }
+
+        self.set_path_label_lines(&lines);
+    }
+
+    /// This function updates the `path_labels` setting so the labels follow the files and folders moved from one path to another.
+    ///
+    /// Moving a folder moves the labels of everything within it too.
+    pub fn move_path_labels(&mut self, moved_paths: &[(ContainerPath, ContainerPath)]) {
+        if !self.settings_text.contains_key("path_labels") {
+            return;
+        }
+
+        let moved_keys = moved_paths.iter()
+            .map(|(source, destination)| (Self::path_label_key(source), Self::path_label_key(destination)))
+            .collect::<Vec<_>>();
+
+        let lines = self.path_label_lines()
+            .into_iter()
+            .map(|(path, label)| {
+                let path = moved_keys.iter()
+                    .find_map(|(source, destination)| {
+                        if source.ends_with('/') {
+                            path.strip_prefix(source).map(|x| format!("{}{}", destination, x))
+                        } else if path == *source {
+                            Some(destination.to_owned())
+                        } else {
+                            None
+                        }
+                    })
+                    .unwrap_or(path);
+                (path, label)
+            })
+            .collect::<Vec<_>>();
+
+        self.set_path_label_lines(&lines);
+    }
+
+    /// This function returns the key used in the `path_labels` setting for the provided path.
+    fn path_label_key(path: &ContainerPath) -> String {
+        match path {
+            ContainerPath::File(path) => path.to_owned(),
+            ContainerPath::Folder(path) => format!("{}/", path),
+        }
+    }
+
+    /// This function returns the valid `(path, label)` lines of the `path_labels` setting.
+    fn path_label_lines(&self) -> Vec<(String, String)> {
+        self.settings_text.get("path_labels")
+            .map(|x| x.lines()
+                .filter_map(|y| y.trim().rsplit_once(';'))
+                .filter(|(path, label)| !path.is_empty() && !label.is_empty())
+                .map(|(path, label)| (path.to_owned(), label.to_owned()))
+                .collect()
+            ).unwrap_or_default()
+    }
+
+    /// This function replaces the `path_labels` setting with the provided `(path, label)` lines.
+    fn set_path_label_lines(&mut self, lines: &[(String, String)]) {
+        let labels = lines.iter().map(|(path, label)| format!("{};{}", path, label)).join("\n");
+        self.settings_text.insert("path_labels".to_owned(), labels);
+    }
+
+    /// This function sets the note of the row with the provided key in the file at the provided path. Passing `None` or an empty note removes it.
+    pub fn set_row_annotation(&mut self, path: &str, key: &str, note: Option<&str>) {
+        match note.filter(|note| !note.trim().is_empty()) {
+            Some(note) => {
+                self.row_annotations.entry(path.to_owned()).or_default().insert(key.to_owned(), note.to_owned());
+            }
+            None => {
+                if let Some(notes) = self.row_annotations.get_mut(path) {
+                    notes.remove(key);
+                    if notes.is_empty() {
+                        self.row_annotations.remove(path);
+                    }
+                }
+            }
+        }
+    }
+
+    /// This function moves the notes of the rows of the file at the provided path from their old keys to their new ones.
+    ///
+    /// Each change is an old key and a new key. If a row with a note gets the key of another row with a note, both notes are kept, joined.
+    pub fn rekey_row_annotations(&mut self, path: &str, changes: &[(String, String)]) {
+        let notes = match self.row_annotations.get_mut(path) {
+            Some(notes) => notes,
+            None => return,
+        };
+
+        // Take out all the notes first, so changes swapping keys don't overwrite each other.
+        let moved = changes.iter()
+            .filter(|(key_before, key_after)| key_before != key_after)
+            .filter_map(|(key_before, key_after)| notes.remove(key_before).map(|note| (key_after, note)))
+            .collect::<Vec<_>>();
+
+        for (key, note) in moved {
+            match notes.get_mut(key) {
+                Some(existing_note) => *existing_note = format!("{}\n{}", existing_note, note),
+                None => { notes.insert(key.to_owned(), note); }
+            }
+        }
+    }
+
+    /// This function updates the row annotations so they follow the files moved from one path to another.
+    ///
+    /// Moving a folder moves the annotations of every file within it too.
+    pub fn move_row_annotations(&mut self, moved_paths: &[(ContainerPath, ContainerPath)]) {
+        if self.row_annotations.is_empty() {
+            return;
+        }
+
+        let mut row_annotations = BTreeMap::new();
+        for (path, notes) in std::mem::take(&mut self.row_annotations) {
+            let path = moved_paths.iter()
+                .find_map(|(source, destination)| match (source, destination) {
+                    (ContainerPath::Folder(source), ContainerPath::Folder(destination)) => path.strip_prefix(&format!("{}/", source))
+                        .map(|x| format!("{}/{}", destination, x)),
+                    (ContainerPath::File(source), ContainerPath::File(destination)) if *source == path => Some(destination.to_owned()),
+                    _ => None,
+                })
+                .unwrap_or(path);
+
+            row_annotations.entry(path).or_insert_with(BTreeMap::new).extend(notes);
+        }
+
+        self.row_annotations = row_annotations;
+    }
+
+    /// This function adds a rule to the diagnostics ignore rules, unless an identical one already exists.
+    ///
+    /// Returns if the rule was added.
+    pub fn add_diagnostics_ignore_rule(&mut self, rule: DiagnosticIgnoreRule) -> bool {
+        if self.diagnostics_ignore_rules.contains(&rule) {
+            false
+        } else {
+            self.diagnostics_ignore_rules.push(rule);
+            true
+        }
+    }
+
+    /// This function writes the diagnostics ignore rules to the legacy setting, so older versions can still use them.
+    ///
+    /// If there are no rules, the legacy setting is removed.
+    pub(crate) fn update_legacy_diagnostics_ignore_setting(&mut self) {
+        if self.diagnostics_ignore_rules.is_empty() {
+            self.settings_text.remove(LEGACY_DIAGNOSTICS_IGNORE_SETTING);
+        } else {
+            self.settings_text.insert(LEGACY_DIAGNOSTICS_IGNORE_SETTING.to_owned(), DiagnosticIgnoreRule::to_legacy(&self.diagnostics_ignore_rules));
+        }
+    }
+}
+
+impl DiagnosticIgnoreRule {
+
+    /// This function creates a new rule. Columns are only kept if they're not empty.
+    pub fn new(path: &str, diagnostic: Option<&str>, columns: &[String]) -> Self {
+        Self {
+            path: path.to_owned(),
+            diagnostic: diagnostic.map(|x| x.to_owned()),
+            columns: columns.iter().filter(|x| !x.is_empty()).cloned().collect(),
+        }
+    }
+
+    /// This function returns if the rule affects the file at the provided path.
+    pub fn affects_path(&self, path: &str) -> bool {
+        !self.path.is_empty() && path.starts_with(&self.path)
+    }
+
+    /// This function parses the rules from the legacy `diagnostics_files_to_ignore` setting.
+    ///
+    /// Each line of the setting is a rule with the format `path;columns;diagnostics`, where columns and diagnostics are optional comma-separated lists.
+    /// Lines starting with `#` are ignored. Lines with multiple diagnostics are split into one rule per diagnostic.
+    pub fn from_legacy(text: &str) -> Vec<Self> {
+        text.lines()
+            .filter(|x| !x.is_empty() && !x.starts_with('#'))
+            .flat_map(|x| {
+                let rule = x.splitn(3, ';').collect::<Vec<&str>>();
+                let list = |index: usize| rule.get(index)
+                    .map(|y| y.split(',').filter(|z| !z.is_empty()).map(|z| z.to_owned()).collect::<Vec<String>>())
+                    .unwrap_or_default();
+
+                let columns = list(1);
+                let diagnostics = list(2);
+                if diagnostics.is_empty() {
+                    vec![Self::new(rule[0], None, &columns)]
+                } else {
+                    diagnostics.iter().map(|diagnostic| Self::new(rule[0], Some(diagnostic), &columns)).collect()
+                }
+            })
+            .collect()
+    }
+
+    /// This function formats the provided rules with the format of the legacy `diagnostics_files_to_ignore` setting.
+    pub fn to_legacy(rules: &[Self]) -> String {
+        rules.iter()
+            .map(|rule| match rule.diagnostic {
+                Some(ref diagnostic) => format!("{};{};{}", rule.path, rule.columns.join(","), diagnostic),
+                None if !rule.columns.is_empty() => format!("{};{}", rule.path, rule.columns.join(",")),
+                None => rule.path.to_owned(),
+            })
+            .join("\n")
+    }
+}
+
+impl MyModExportRule {
+
+    /// This function returns the rule with the most specific Pack folder containing the provided Pack path, if any.
+    pub fn rule_for_pack_path<'a>(rules: &'a [Self], path: &str) -> Option<&'a Self> {
+        rules.iter()
+            .filter(|rule| Self::folder_contains(&rule.pack_folder, path))
+            .max_by_key(|rule| rule.pack_folder.len())
+    }
+
+    /// This function returns the rule with the most specific disk folder containing the provided path, if any.
+    ///
+    /// The path must be relative to the assets folder, using `/` as separator.
+    pub fn rule_for_disk_path<'a>(rules: &'a [Self], path: &str) -> Option<&'a Self> {
+        rules.iter()
+            .filter(|rule| Self::folder_contains(&rule.disk_folder, path))
+            .max_by_key(|rule| rule.disk_folder.len())
+    }
+
+    /// This function maps a path within this rule's Pack folder to its path relative to the assets folder.
+    pub fn disk_path(&self, pack_path: &str) -> String {
+        Self::replace_folder(pack_path, &self.pack_folder, &self.disk_folder)
+    }
+
+    /// This function maps a path relative to the assets folder within this rule's disk folder to its path within the Pack.
+    pub fn pack_path(&self, disk_path: &str) -> String {
+        Self::replace_folder(disk_path, &self.disk_folder, &self.pack_folder)
+    }
+
+    /// This function checks if the provided path is within the provided folder. Comparisons are case-insensitive.
+    fn folder_contains(folder: &str, path: &str) -> bool {
+        folder.is_empty() || (
+            path.as_bytes().get(folder.len()) == Some(&b'/') &&
+            path[..folder.len()].eq_ignore_ascii_case(folder)
+        )
+    }
+
+    /// This function replaces the `from` folder at the start of the provided path with the `to` folder.
+    fn replace_folder(path: &str, from: &str, to: &str) -> String {
+        let path = if from.is_empty() { path } else { &path[from.len() + 1..] };
+        if to.is_empty() {
+            path.to_owned()
+        } else {
+            format!("{}/{}", to, path)
+        }
     }
 }
 
@@ -1038,10 +2317,11 @@ impl Default for PackHeader {
             pfh_file_type: Default::default(),
             bitmask: Default::default(),
             internal_timestamp: Default::default(),
+            subheader_version: SUBHEADER_VERSION,
             game_version: Default::default(),
             build_number: Default::default(),
             authoring_tool: AUTHORING_TOOL_RPFM.to_owned(),
-            extra_subheader_data: Default::default(),
+            extra_subheader_data: vec![0; SUBHEADER_EXTRA_DATA_SIZE],
         }
     }
 }
@@ -14,7 +14,9 @@ use std::io::{BufReader, BufWriter};
 use std::fs::File;
 
 use crate::files::*;
-use super::Pack;
+use crate::files::table::DecodedData;
+use crate::files::esf::ESFNodePath;
+use super::{DiagnosticIgnoreRule, LEGACY_DIAGNOSTICS_IGNORE_SETTING, MyModExportRule, Pack, PackSettings, PFHFlags};
 
 #[test]
 fn test_decode_pfh6() {
@@ -276,3 +278,1050 @@ fn test_encode_pfh0() {
     assert_eq!(data_pack_1, data_pack_2);
 }
 
+
+#[test]
+fn test_map_xml_paths() {
+    let mut pack = Pack::default();
+    let paths = [
+        "terrain/tiles/battle/_assembly_kit/test_map/test_map.xml",
+        "prefabs/test_prefab.xml",
+        "terrain/tiles/battle/_assembly_kit/test_map/bmd_data.bin",
+        "ui/templates/test.xml",
+    ];
+
+    for path in paths {
+        let mut file = RFile::new_from_vec(b"<root/>", FileType::Unknown, 0, path);
+        file.guess_file_type().unwrap();
+        pack.insert(file).unwrap();
+    }
+
+    let mut map_xml_paths = pack.map_xml_paths();
+    map_xml_paths.sort();
+
+    assert_eq!(map_xml_paths, vec![
+        "prefabs/test_prefab.xml".to_owned(),
+        "terrain/tiles/battle/_assembly_kit/test_map/test_map.xml".to_owned(),
+    ]);
+}
+
+#[test]
+fn test_diff() {
+    let mut loc = Loc::new(false);
+    let mut rows = vec![];
+    for (key, text) in [("key_1", "Text 1"), ("key_2", "Text 2"), ("key_3", "Text 3")] {
+        let mut row = loc.new_row();
+        row[0] = DecodedData::StringU16(key.to_owned());
+        row[1] = DecodedData::StringU16(text.to_owned());
+        rows.push(row);
+    }
+
+    loc.set_data(&rows).unwrap();
+    let other_loc = loc.clone();
+
+    // Change one row, remove another one and add a new one.
+    rows[0][1] = DecodedData::StringU16("Text 1 changed".to_owned());
+    rows[2][0] = DecodedData::StringU16("key_4".to_owned());
+    loc.set_data(&rows).unwrap();
+
+    let mut pack = Pack::default();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, "text/db/test.loc")).unwrap();
+    pack.insert(RFile::new_from_vec(b"same", FileType::Unknown, 0, "same.bin")).unwrap();
+    pack.insert(RFile::new_from_vec(b"data", FileType::Unknown, 0, "different.bin")).unwrap();
+    pack.insert(RFile::new_from_vec(b"data", FileType::Unknown, 0, "only_self.bin")).unwrap();
+
+    let mut other = Pack::default();
+    other.insert(RFile::new_from_decoded(&RFileDecoded::Loc(other_loc.clone()), 0, "text/db/test.loc")).unwrap();
+    other.insert(RFile::new_from_vec(b"same", FileType::Unknown, 0, "same.bin")).unwrap();
+    other.insert(RFile::new_from_vec(b"other data", FileType::Unknown, 0, "different.bin")).unwrap();
+    other.insert(RFile::new_from_vec(b"data", FileType::Unknown, 0, "only_other.bin")).unwrap();
+
+    let diff = pack.diff(&mut other, None).unwrap();
+    assert_eq!(diff.only_in_self(), &vec![ContainerPath::File("only_self.bin".to_owned())]);
+    assert_eq!(diff.only_in_other(), &vec![ContainerPath::File("only_other.bin".to_owned())]);
+    assert_eq!(diff.different(), &vec![ContainerPath::File("different.bin".to_owned()), ContainerPath::File("text/db/test.loc".to_owned())]);
+
+    let table_diff = diff.table_diffs().get("text/db/test.loc").unwrap();
+    assert_eq!(*table_diff.added(), 1);
+    assert_eq!(*table_diff.removed(), 1);
+    assert_eq!(*table_diff.changed(), 1);
+
+    // Equal tables must not be reported.
+    let mut other_pack = Pack::default();
+    other_pack.insert(RFile::new_from_decoded(&RFileDecoded::Loc(other_loc.clone()), 0, "text/db/test.loc")).unwrap();
+    let mut other_pack_2 = Pack::default();
+    other_pack_2.insert(RFile::new_from_decoded(&RFileDecoded::Loc(other_loc), 0, "text/db/test.loc")).unwrap();
+    assert!(other_pack.diff(&mut other_pack_2, None).unwrap().different().is_empty());
+
+    // Rows sharing a key are all compared, instead of collapsing into one.
+    let loc_with_rows = |texts: &[&str]| {
+        let mut loc = Loc::new(false);
+        let rows = texts.iter().map(|text| {
+            let mut row = loc.new_row();
+            row[0] = DecodedData::StringU16("key_1".to_owned());
+            row[1] = DecodedData::StringU16(text.to_string());
+            row
+        }).collect::<Vec<_>>();
+
+        loc.set_data(&rows).unwrap();
+        let mut pack = Pack::default();
+        pack.insert(RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, "text/db/test.loc")).unwrap();
+        pack
+    };
+
+    let mut pack = loc_with_rows(&["Text 1", "Text 2", "Text 3"]);
+    let mut other = loc_with_rows(&["Text 1", "Text 2 changed"]);
+    let diff = pack.diff(&mut other, None).unwrap();
+    let table_diff = diff.table_diffs().get("text/db/test.loc").unwrap();
+    assert_eq!(*table_diff.added(), 1);
+    assert_eq!(*table_diff.removed(), 0);
+    assert_eq!(*table_diff.changed(), 1);
+    assert_eq!(*table_diff.duplicated_keys(), 1);
+}
+
+#[test]
+fn test_generate_missing_loc_data() {
+    use std::collections::{BTreeMap, HashSet};
+    use crate::schema::{Definition, Field, FieldType};
+
+    let key_field = Field::new("key".to_owned(), FieldType::StringU8, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None);
+    let loc_field = Field::new("name".to_owned(), FieldType::StringU8, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None);
+    let definition = Definition::new_with_fields(1, &[key_field], &[loc_field]);
+
+    // Two tables, with one key repeated between them.
+    let mut pack = Pack::default();
+    for (table_file, keys) in [("db/units_tables/table_1", vec!["unit_a", "unit_b", "unit_c"]), ("db/units_tables/table_2", vec!["unit_c"])] {
+        let mut table = db::DB::new(&definition, None, "units_tables", false);
+        let rows = keys.iter().map(|key| vec![DecodedData::StringU8(key.to_string())]).collect::<Vec<_>>();
+        table.set_data(None, &rows).unwrap();
+        pack.insert(RFile::new_from_decoded(&RFileDecoded::DB(table), 0, table_file)).unwrap();
+    }
+
+    let mut loc = Loc::new(false);
+    let mut row = loc.new_row();
+    row[0] = DecodedData::StringU16("units_name_unit_a".to_owned());
+    row[1] = DecodedData::StringU16("Unit A".to_owned());
+    loc.set_data(&[row]).unwrap();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, "text/db/units.loc")).unwrap();
+
+    // Keys from the target loc and from the dependencies must be skipped.
+    let existing_keys = HashSet::from(["units_name_unit_b".to_owned()]);
+    let (amount, path) = pack.generate_missing_loc_data("text/db/units.loc", "PLACEHOLDER: {key}", &existing_keys).unwrap();
+    assert_eq!(amount, 1);
+    assert_eq!(path, Some(ContainerPath::File("text/db/units.loc".to_owned())));
+
+    if let RFileDecoded::Loc(loc) = pack.files().get("text/db/units.loc").unwrap().decoded().unwrap() {
+        let data = loc.data(&None).unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[1][0], DecodedData::StringU16("units_name_unit_c".to_owned()));
+        assert_eq!(data[1][1], DecodedData::StringU16("PLACEHOLDER: units_name_unit_c".to_owned()));
+    } else {
+        panic!("Target file is not a loc.");
+    }
+
+    // Running it again must not duplicate anything.
+    let (amount, path) = pack.generate_missing_loc_data("text/db/units.loc", "PLACEHOLDER: {key}", &existing_keys).unwrap();
+    assert_eq!(amount, 0);
+    assert_eq!(path, None);
+
+    // Locs that do not exist yet get created.
+    let (amount, path) = pack.generate_missing_loc_data("text/db/new.loc", "{key}", &HashSet::new()).unwrap();
+    assert_eq!(amount, 1);
+    assert_eq!(path, Some(ContainerPath::File("text/db/new.loc".to_owned())));
+
+    // Non-loc files cannot be used as target.
+    assert!(pack.generate_missing_loc_data("db/units_tables/table_1", "{key}", &HashSet::new()).is_err());
+}
+
+#[test]
+fn test_add_loc_entries() {
+    let mut pack = Pack::default();
+    let mut loc = Loc::new(false);
+    let mut row = loc.new_row();
+    row[0] = DecodedData::StringU16("units_name_unit_a".to_owned());
+    loc.set_data(&[row]).unwrap();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, "text/db/units.loc")).unwrap();
+
+    // Entries go at the end of the loc, in the order they were provided.
+    let keys = vec!["units_name_unit_c".to_owned(), "units_name_unit_b".to_owned()];
+    assert_eq!(pack.add_loc_entries("text/db/units.loc", &keys, "").unwrap(), (2, Some(ContainerPath::File("text/db/units.loc".to_owned()))));
+    if let RFileDecoded::Loc(loc) = pack.files().get("text/db/units.loc").unwrap().decoded().unwrap() {
+        let data = loc.data(&None).unwrap();
+        assert_eq!(data.iter().map(|row| row[0].data_to_string().to_string()).collect::<Vec<_>>(), vec!["units_name_unit_a", "units_name_unit_c", "units_name_unit_b"]);
+        assert_eq!(data[1][1], DecodedData::StringU16(String::new()));
+    } else {
+        panic!("Target file is not a loc.");
+    }
+
+    // No keys, no changes.
+    assert_eq!(pack.add_loc_entries("text/db/other.loc", &[], "").unwrap(), (0, None));
+    assert!(!pack.files().contains_key("text/db/other.loc"));
+}
+
+#[test]
+fn test_update_tables() {
+    use std::collections::{BTreeMap, HashMap};
+    use crate::schema::{Definition, Field, FieldType, Schema};
+
+    let field = |name: &str, field_type: FieldType, is_key: bool, default_value: Option<&str>| {
+        Field::new(name.to_owned(), field_type, is_key, default_value.map(|x| x.to_owned()), false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None)
+    };
+
+    let definition_v1 = Definition::new_with_fields(1, &[
+        field("key", FieldType::StringU8, true, None),
+        field("name", FieldType::StringU8, false, None),
+        field("removed", FieldType::I32, false, None),
+    ], &[]);
+
+    let definition_v2 = Definition::new_with_fields(2, &[
+        field("key", FieldType::StringU8, true, None),
+        field("display_name", FieldType::StringU8, false, None),
+        field("added", FieldType::I32, false, Some("5")),
+    ], &[]);
+
+    // The "name" column has been renamed to "display_name" in the newer version.
+    let mut schema = Schema::default();
+    schema.add_definition("units_tables", &definition_v1);
+    schema.add_definition("units_tables", &definition_v2);
+    schema.add_definition("other_tables", &definition_v1);
+    schema.add_patch(HashMap::from([("units_tables".to_owned(), HashMap::from([
+        ("display_name".to_owned(), HashMap::from([("old_name".to_owned(), "name".to_owned())]))
+    ]))]));
+
+    let mut pack = Pack::default();
+    for (path, table_name) in [("db/units_tables/test", "units_tables"), ("db/other_tables/test", "other_tables")] {
+        let mut table = db::DB::new(&definition_v1, None, table_name, false);
+        table.set_data(None, &[vec![
+            DecodedData::StringU8("unit_a".to_owned()),
+            DecodedData::StringU8("Unit A".to_owned()),
+            DecodedData::I32(1),
+        ]]).unwrap();
+        pack.insert(RFile::new_from_decoded(&RFileDecoded::DB(table), 0, path)).unwrap();
+    }
+
+    let db_folder = ContainerPath::Folder("db".to_owned());
+    let expected_outdated = vec![("db/units_tables/test".to_owned(), 1, 2)];
+    let expected_skipped = vec!["db/other_tables/test".to_owned()];
+    assert_eq!(pack.outdated_tables(&schema, &db_folder), (expected_outdated.clone(), expected_skipped.clone()));
+    assert_eq!(pack.update_tables(&schema, &db_folder).unwrap(), (expected_outdated, expected_skipped));
+
+    if let RFileDecoded::DB(table) = pack.files().get("db/units_tables/test").unwrap().decoded().unwrap() {
+        assert_eq!(*table.definition().version(), 2);
+        assert_eq!(table.data(&None).unwrap().to_vec(), vec![vec![
+            DecodedData::StringU8("unit_a".to_owned()),
+            DecodedData::StringU8("Unit A".to_owned()),
+            DecodedData::I32(5),
+        ]]);
+    } else {
+        panic!("Updated file is not a DB table.");
+    }
+
+    // Once updated, there should be nothing left to update.
+    assert!(pack.outdated_tables(&schema, &ContainerPath::File("db/units_tables/test".to_owned())).0.is_empty());
+}
+
+#[test]
+fn test_manifest() {
+    use std::path::PathBuf;
+    use crate::games::pfh_version::PFHVersion;
+
+    let path = PathBuf::from("../test_files/test_manifest.pack");
+    let mut pack = Pack::new_with_name_and_version("test_manifest.pack", PFHVersion::PFH5);
+    pack.insert(RFile::new_from_vec(b"Test data.", FileType::Text, 0, "text/test.txt")).unwrap();
+    pack.insert(RFile::new_from_vec(&[1, 2, 3, 4], FileType::Unknown, 0, "a/test.bin")).unwrap();
+
+    pack.save(Some(&path)).unwrap();
+    let pack_data = std::fs::read(&path).unwrap();
+    let mut manifest_1 = Pack::manifest(&path).unwrap();
+    let manifest_path = manifest_1.save(&path).unwrap();
+
+    // Saving the manifest must not touch the Pack.
+    assert_eq!(manifest_path, PathBuf::from("../test_files/test_manifest.manifest.json"));
+    assert_eq!(std::fs::read(&path).unwrap(), pack_data);
+
+    pack.save(None).unwrap();
+    let mut manifest_2 = Pack::manifest(&path).unwrap();
+
+    assert_eq!(*manifest_1.pfh_version(), PFHVersion::PFH5);
+    assert_eq!(manifest_1.files().iter().map(|file| file.path().as_str()).collect::<Vec<_>>(), vec!["a/test.bin", "text/test.txt"]);
+    assert_eq!(*manifest_1.files()[0].size(), 4);
+    assert_eq!(manifest_1.files()[0].sha256(), "9f64a747e1b97f131fabb6b447296c9b6f0201e79fb3c5356e6c77e89b6a806a");
+
+    // Only the timestamp may change between saves.
+    *manifest_1.timestamp_mut() = 0;
+    *manifest_2.timestamp_mut() = 0;
+    assert_eq!(manifest_1, manifest_2);
+}
+
+#[test]
+fn test_rename_paths_by_pattern() {
+    let mut pack = Pack::default();
+    for path in ["variantmeshes/unit_x_1.xml", "variantmeshes/Unit_X_2.xml", "variantmeshes/sub/unit_x_3.xml", "variantmeshes/unit_y_4.xml", "other/unit_x_5.xml"] {
+        pack.insert(RFile::new_from_vec(b"<root/>", FileType::Unknown, 0, path)).unwrap();
+    }
+
+    // Folders apply the pattern to every file under them, and capture groups can be used in the replacement.
+    let folder = [ContainerPath::Folder("variantmeshes".to_owned())];
+    let mut renamed = pack.rename_paths_by_pattern(&folder, r"unit_x_(\d+)", "unit_y_${1}_new", true).unwrap();
+    renamed.sort();
+
+    assert_eq!(renamed, vec![
+        (ContainerPath::File("variantmeshes/Unit_X_2.xml".to_owned()), ContainerPath::File("variantmeshes/unit_y_2_new.xml".to_owned())),
+        (ContainerPath::File("variantmeshes/sub/unit_x_3.xml".to_owned()), ContainerPath::File("variantmeshes/sub/unit_y_3_new.xml".to_owned())),
+        (ContainerPath::File("variantmeshes/unit_x_1.xml".to_owned()), ContainerPath::File("variantmeshes/unit_y_1_new.xml".to_owned())),
+    ]);
+    assert!(pack.files().contains_key("other/unit_x_5.xml"));
+
+    // Renames that collide with existing paths, or with each other, are rejected without renaming anything.
+    let files = [ContainerPath::File("variantmeshes/unit_y_1_new.xml".to_owned()), ContainerPath::File("variantmeshes/unit_y_2_new.xml".to_owned())];
+    match pack.rename_paths_by_pattern(&files, r"unit_y_\d_new", "unit_y_4", false) {
+        Err(RLibError::RenamePathsCollide(collisions)) => assert_eq!(collisions, vec!["variantmeshes/unit_y_4.xml".to_owned()]),
+        _ => panic!("Colliding rename didn't fail as expected."),
+    }
+    assert!(pack.files().contains_key("variantmeshes/unit_y_1_new.xml"));
+    assert!(pack.files().contains_key("variantmeshes/unit_y_2_new.xml"));
+
+    // Invalid patterns must fail too.
+    assert!(pack.rename_paths_by_pattern(&folder, "unit_(", "", false).is_err());
+}
+
+#[test]
+fn test_move_and_copy_paths_to_folder() {
+    let mut pack = Pack::default();
+    for path in ["variantmeshes/unit_1.xml", "variantmeshes/sub/unit_2.xml", "text/readme.txt", "other/readme.txt", "db/units_tables/my_units"] {
+        pack.insert(RFile::new_from_vec(b"data", FileType::Unknown, 0, path)).unwrap();
+    }
+
+    // Folders are moved with all their contents, keeping their name.
+    let mut moved = pack.move_paths_to_folder(&[ContainerPath::Folder("variantmeshes".to_owned())], "assets").unwrap();
+    moved.sort();
+    assert_eq!(moved, vec![
+        (ContainerPath::File("variantmeshes/sub/unit_2.xml".to_owned()), ContainerPath::File("assets/variantmeshes/sub/unit_2.xml".to_owned())),
+        (ContainerPath::File("variantmeshes/unit_1.xml".to_owned()), ContainerPath::File("assets/variantmeshes/unit_1.xml".to_owned())),
+    ]);
+    assert!(!pack.has_folder("variantmeshes"));
+    assert!(matches!(pack.move_paths_to_folder(&[ContainerPath::Folder("assets".to_owned())], "assets/variantmeshes"), Err(RLibError::MoveFolderIntoItself(_))));
+
+    // Collisions are rejected without moving anything.
+    match pack.move_paths_to_folder(&[ContainerPath::File("text/readme.txt".to_owned())], "other") {
+        Err(RLibError::RenamePathsCollide(collisions)) => assert_eq!(collisions, vec!["other/readme.txt".to_owned()]),
+        _ => panic!("Colliding move didn't fail as expected."),
+    }
+    assert!(pack.files().contains_key("text/readme.txt"));
+    assert!(pack.copy_paths_to_folder(&[ContainerPath::File("text/readme.txt".to_owned())], "other").is_err());
+
+    // Copies keep the original files, and DB tables get a suffix instead of colliding.
+    let copied = pack.copy_paths_to_folder(&[ContainerPath::File("assets/variantmeshes/unit_1.xml".to_owned())], "").unwrap();
+    assert_eq!(copied, vec![(ContainerPath::File("assets/variantmeshes/unit_1.xml".to_owned()), ContainerPath::File("unit_1.xml".to_owned()))]);
+    assert!(pack.files().contains_key("assets/variantmeshes/unit_1.xml"));
+
+    let table = [ContainerPath::File("db/units_tables/my_units".to_owned())];
+    let copied = pack.copy_paths_to_folder(&table, "db/units_tables").unwrap();
+    assert_eq!(copied[0].1, ContainerPath::File("db/units_tables/my_units_copy".to_owned()));
+    let copied = pack.copy_paths_to_folder(&table, "db/units_tables").unwrap();
+    assert_eq!(copied[0].1, ContainerPath::File("db/units_tables/my_units_copy_2".to_owned()));
+}
+
+#[test]
+fn test_duplicate_table() {
+    let mut pack = Pack::default();
+    pack.insert(RFile::new_from_vec(b"data", FileType::DB, 0, "db/units_tables/my_units")).unwrap();
+    pack.insert(RFile::new_from_vec(b"data", FileType::Loc, 0, "text/db/my_units.loc")).unwrap();
+    pack.insert(RFile::new_from_vec(b"data", FileType::Text, 0, "text/readme.txt")).unwrap();
+
+    // Names and full paths are both valid, as long as they stay in the same table folder.
+    let table = ContainerPath::File("db/units_tables/my_units".to_owned());
+    assert_eq!(table.duplicate_table_path("my_units_2").unwrap(), "db/units_tables/my_units_2");
+    assert_eq!(table.duplicate_table_path("db/units_tables/my_units_2").unwrap(), "db/units_tables/my_units_2");
+    assert!(matches!(table.duplicate_table_path("db/land_units_tables/my_units_2"), Err(RLibError::DuplicateTableInvalidPath(_, _))));
+    assert!(matches!(table.duplicate_table_path("db/units_tables/sub/my_units_2"), Err(RLibError::DuplicateTableInvalidPath(_, _))));
+    assert!(table.duplicate_table_path("  ").is_err());
+
+    // Loc files can go anywhere within the text folder, and always end in .loc.
+    let loc = ContainerPath::File("text/db/my_units.loc".to_owned());
+    assert_eq!(loc.duplicate_table_path("my_units_2").unwrap(), "text/db/my_units_2.loc");
+    assert_eq!(loc.duplicate_table_path("text/my_units_2.loc").unwrap(), "text/my_units_2.loc");
+    assert!(loc.duplicate_table_path("db/my_units_2.loc").is_err());
+    assert!(ContainerPath::File("text/readme.txt".to_owned()).duplicate_table_path("readme_2.txt").is_err());
+
+    assert_eq!(pack.duplicate_table("db/units_tables/my_units", "my_units_2").unwrap(), ContainerPath::File("db/units_tables/my_units_2".to_owned()));
+    assert_eq!(pack.file("db/units_tables/my_units_2").unwrap().file_type(), FileType::DB);
+    assert!(pack.files().contains_key("db/units_tables/my_units"));
+    assert!(matches!(pack.duplicate_table("db/units_tables/my_units", "my_units_2"), Err(RLibError::RenamePathsCollide(_))));
+    assert!(matches!(pack.duplicate_table("db/units_tables/missing", "missing_2"), Err(RLibError::FileNotFound(_))));
+
+    assert_eq!(pack.duplicate_table("text/db/my_units.loc", "my_units_2").unwrap(), ContainerPath::File("text/db/my_units_2.loc".to_owned()));
+}
+
+#[test]
+fn test_pfh_flags_restrictions() {
+    use crate::games::{pfh_file_type::PFHFileType, pfh_version::PFHVersion, supported_games::*};
+
+    let games = SupportedGames::default();
+    let restricted = |game: &str, pfh_version: PFHVersion| Pack::pfh_flags_restrictions(games.game(game).unwrap(), pfh_version, PFHFileType::Mod)
+        .into_iter()
+        .fold(PFHFlags::empty(), |flags, (flag, _)| flags | flag);
+
+    // Flags we can read but not save are always restricted. Timestamps are only valid if both the Pack and the game's PFH version support them.
+    let not_encodeable = PFHFlags::HAS_EXTENDED_HEADER | PFHFlags::HAS_ENCRYPTED_INDEX | PFHFlags::HAS_ENCRYPTED_DATA;
+    assert_eq!(restricted(KEY_TROY, PFHVersion::PFH6), not_encodeable);
+    assert_eq!(restricted(KEY_WARHAMMER_2, PFHVersion::PFH5), not_encodeable);
+    assert_eq!(restricted(KEY_ATTILA, PFHVersion::PFH4), not_encodeable);
+    assert_eq!(restricted(KEY_EMPIRE, PFHVersion::PFH4), PFHFlags::all());
+
+    // Restrictions come with a reason.
+    let restrictions = Pack::pfh_flags_restrictions(games.game(KEY_TROY).unwrap(), PFHVersion::PFH6, PFHFileType::Mod);
+    assert!(restrictions.iter().any(|(flag, reason)| *flag == PFHFlags::HAS_EXTENDED_HEADER && reason.contains("PFH6")));
+
+    // Validation ignores the flags that get removed on save, but not the ones the version doesn't support.
+    for (game, pfh_version) in [(KEY_TROY, PFHVersion::PFH6), (KEY_WARHAMMER_2, PFHVersion::PFH5), (KEY_ATTILA, PFHVersion::PFH4)] {
+        let game = games.game(game).unwrap();
+        assert!(Pack::validate_pfh_flags(game, pfh_version, PFHFileType::Mod, PFHFlags::HAS_INDEX_WITH_TIMESTAMPS).is_ok());
+        assert!(Pack::validate_pfh_flags(game, pfh_version, PFHFileType::Mod, PFHFlags::HAS_ENCRYPTED_DATA).is_ok());
+    }
+
+    assert!(Pack::validate_pfh_flags(games.game(KEY_TROY).unwrap(), PFHVersion::PFH5, PFHFileType::Mod, PFHFlags::HAS_EXTENDED_HEADER).is_ok());
+    assert!(matches!(Pack::validate_pfh_flags(games.game(KEY_EMPIRE).unwrap(), PFHVersion::PFH5, PFHFileType::Mod, PFHFlags::HAS_INDEX_WITH_TIMESTAMPS), Err(RLibError::PackFlagsNotSupported(_, _))));
+
+    // Saving with flags the PFH version doesn't support must fail.
+    let mut pack = Pack::new_with_name_and_version("test_flags.pack", PFHVersion::PFH0);
+    pack.set_bitmask(PFHFlags::HAS_INDEX_WITH_TIMESTAMPS);
+    assert!(matches!(pack.encode(&mut vec![], &None), Err(RLibError::PackFlagsNotSupported(_, _))));
+}
+
+#[test]
+fn test_read_and_merge_ca_packs_from_paths() {
+    use std::path::PathBuf;
+    use crate::games::pfh_file_type::PFHFileType;
+    use crate::games::pfh_version::PFHVersion;
+
+    let path_1 = PathBuf::from("../test_files/test_merge_ca_1.pack");
+    let path_2 = PathBuf::from("../test_files/test_merge_ca_2.pack");
+
+    let mut pack_1 = Pack::new_with_name_and_version("test_merge_ca_1.pack", PFHVersion::PFH5);
+    pack_1.set_pfh_file_type(PFHFileType::Release);
+    pack_1.insert(RFile::new_from_vec(b"Data 1.", FileType::Text, 0, "text/test_1.txt")).unwrap();
+    pack_1.insert(RFile::new_from_vec(b"Data 1.", FileType::Text, 0, "text/shared.txt")).unwrap();
+    pack_1.save(Some(&path_1)).unwrap();
+
+    let mut pack_2 = Pack::new_with_name_and_version("test_merge_ca_2.pack", PFHVersion::PFH5);
+    pack_2.set_pfh_file_type(PFHFileType::Release);
+    pack_2.insert(RFile::new_from_vec(b"Data 2.", FileType::Text, 0, "text/test_2.txt")).unwrap();
+    pack_2.insert(RFile::new_from_vec(b"Data 2.", FileType::Text, 0, "text/shared.txt")).unwrap();
+    pack_2.save(Some(&path_2)).unwrap();
+
+    let pack = Pack::read_and_merge_ca_packs_from_paths(&[path_2.to_owned(), path_1.to_owned()]).unwrap();
+    assert_eq!(pack.pfh_file_type(), PFHFileType::Release);
+    assert!(!pack.compress());
+    assert_eq!(pack.files().len(), 3);
+
+    // Each file must remember the Pack it came from, with the last Pack by name winning on conflicts.
+    assert_eq!(pack.file("text/test_1.txt").unwrap().container_name(), Some("test_merge_ca_1.pack"));
+    assert_eq!(pack.file("text/test_2.txt").unwrap().container_name(), Some("test_merge_ca_2.pack"));
+    assert_eq!(pack.file("text/shared.txt").unwrap().container_name(), Some("test_merge_ca_2.pack"));
+
+    assert!(Pack::read_and_merge_ca_packs_from_paths(&[]).is_err());
+}
+
+#[test]
+fn test_is_compression_excluded() {
+    let mut settings = PackSettings::default();
+    assert!(!settings.is_compression_excluded("ui/skins/default/image.dds"));
+
+    settings.set_setting_text("compression_files_to_ignore", "# Comment\n.DDS\n.ca_vp8\n\nmovies\\intro\n");
+    assert!(settings.is_compression_excluded("ui/skins/default/image.dds"));
+    assert!(settings.is_compression_excluded("movies/ui/video.ca_vp8"));
+    assert!(settings.is_compression_excluded("movies/intro/anything.txt"));
+    assert!(!settings.is_compression_excluded("movies/outro/anything.txt"));
+    assert!(!settings.is_compression_excluded("db/units_tables/data"));
+    assert!(!settings.is_compression_excluded("# Comment"));
+}
+
+#[test]
+fn test_translation_report_keys_to_ignore() {
+    let mut settings = PackSettings::default();
+    assert!(settings.translation_report_keys_to_ignore().is_empty());
+
+    settings.set_setting_text("translation_report_keys_to_ignore", "# Comment\nunits_name_debug\n\n  campaign_localised_strings_test  \n");
+    let keys = settings.translation_report_keys_to_ignore();
+    assert_eq!(keys.len(), 2);
+    assert!(keys.contains("units_name_debug"));
+    assert!(keys.contains("campaign_localised_strings_test"));
+}
+
+#[test]
+fn test_diagnostics_ignore_rules() {
+    let legacy = "# Comment\ndb/units_tables\ndb/land_units_tables;key,category\ndb/factions_tables/mod;;OutdatedTable,EmptyRow\ntext/db;key;InvalidLocKey";
+    let mut settings = PackSettings::default();
+    settings.set_setting_text(LEGACY_DIAGNOSTICS_IGNORE_SETTING, legacy);
+
+    // Legacy rules are only imported when loading settings without rules.
+    let mut settings = PackSettings::load(serde_json::to_string(&settings).unwrap().as_bytes()).unwrap();
+    assert_eq!(settings.diagnostics_ignore_rules(), &vec![
+        DiagnosticIgnoreRule::new("db/units_tables", None, &[]),
+        DiagnosticIgnoreRule::new("db/land_units_tables", None, &["key".to_owned(), "category".to_owned()]),
+        DiagnosticIgnoreRule::new("db/factions_tables/mod", Some("OutdatedTable"), &[]),
+        DiagnosticIgnoreRule::new("db/factions_tables/mod", Some("EmptyRow"), &[]),
+        DiagnosticIgnoreRule::new("text/db", Some("InvalidLocKey"), &["key".to_owned()]),
+    ]);
+
+    assert!(settings.diagnostics_ignore_rules()[0].affects_path("db/units_tables/data"));
+    assert!(!settings.diagnostics_ignore_rules()[0].affects_path("db/land_units_tables/data"));
+    assert!(!DiagnosticIgnoreRule::new("", None, &[]).affects_path("db/units_tables/data"));
+
+    assert!(!settings.add_diagnostics_ignore_rule(DiagnosticIgnoreRule::new("db/units_tables", None, &[])));
+    assert!(settings.add_diagnostics_ignore_rule(DiagnosticIgnoreRule::new("db/units_tables", Some("EmptyRow"), &[String::new()])));
+
+    // The legacy setting is rewritten from the rules, and reading it back gives the same rules.
+    settings.update_legacy_diagnostics_ignore_setting();
+    let legacy = settings.setting_text(LEGACY_DIAGNOSTICS_IGNORE_SETTING).unwrap().to_owned();
+    assert_eq!(legacy.lines().next(), Some("db/units_tables"));
+    assert_eq!(&DiagnosticIgnoreRule::from_legacy(&legacy), settings.diagnostics_ignore_rules());
+
+    let settings = PackSettings::load(serde_json::to_string(&settings).unwrap().as_bytes()).unwrap();
+    assert_eq!(settings.diagnostics_ignore_rules().len(), 6);
+
+    let mut settings = PackSettings::default();
+    settings.set_setting_text(LEGACY_DIAGNOSTICS_IGNORE_SETTING, "db/units_tables");
+    settings.update_legacy_diagnostics_ignore_setting();
+    assert!(settings.setting_text(LEGACY_DIAGNOSTICS_IGNORE_SETTING).is_none());
+}
+
+#[test]
+fn test_notes_are_not_files() {
+    use crate::games::pfh_version::PFHVersion;
+    use super::RESERVED_NAME_NOTES;
+
+    let path = "../test_files/test_notes.pack";
+    let notes = "# Changelog\n\n- Fixed `land_units_tables` typo.\n";
+
+    let mut pack = Pack::new_with_name_and_version("test_notes.pack", PFHVersion::PFH5);
+    pack.insert(RFile::new_from_vec(b"Data.", FileType::Text, 0, "text/test.txt")).unwrap();
+    pack.set_notes(notes.to_owned());
+    pack.save(Some(&std::path::PathBuf::from(path))).unwrap();
+
+    let mut reader = BufReader::new(File::open(path).unwrap());
+    let mut decodeable_extra_data = DecodeableExtraData::default();
+    decodeable_extra_data.disk_file_path = Some(path);
+    decodeable_extra_data.data_size = reader.len().unwrap();
+    decodeable_extra_data.timestamp = last_modified_time_from_file(reader.get_ref()).unwrap();
+
+    // Notes must survive a save, but never show up as a file, so nothing that iterates files (optimizer, diagnostics,...) sees them.
+    let pack = Pack::decode(&mut reader, &Some(decodeable_extra_data)).unwrap();
+    assert_eq!(pack.notes(), notes);
+    assert_eq!(pack.files().len(), 1);
+    assert!(pack.file(RESERVED_NAME_NOTES).is_none());
+}
+
+#[test]
+fn test_padding() {
+    use crate::games::pfh_version::PFHVersion;
+    use super::{PADDING_SIZE_SETTING, RESERVED_NAME_PADDING};
+
+    // Compressing the padding file would make it useless.
+    assert!(PackSettings::default().is_compression_excluded(RESERVED_NAME_PADDING));
+
+    let target_size = 4096;
+    for (pfh_version, bitmask) in [(PFHVersion::PFH5, PFHFlags::empty()), (PFHVersion::PFH4, PFHFlags::HAS_INDEX_WITH_TIMESTAMPS), (PFHVersion::PFH2, PFHFlags::HAS_INDEX_WITH_TIMESTAMPS), (PFHVersion::PFH0, PFHFlags::empty())] {
+        let mut pack = Pack::new_with_version(pfh_version);
+        pack.set_bitmask(bitmask);
+        pack.insert(RFile::new_from_vec(b"Data.", FileType::Text, 0, "text/test.txt")).unwrap();
+        pack.settings_mut().set_setting_number(PADDING_SIZE_SETTING, target_size);
+
+        let mut data = vec![];
+        pack.encode(&mut data, &None).unwrap();
+        assert_eq!(data.len(), target_size as usize);
+        assert!(pack.file(RESERVED_NAME_PADDING).is_none());
+
+        // The padding file goes after the reserved files in the index, even if its name sorts before them.
+        let index_pos = |name: &str| data.windows(name.len()).position(|x| x == name.as_bytes()).unwrap();
+        assert!(index_pos(RESERVED_NAME_PADDING) > index_pos("settings.rpfm_reserved"));
+
+        // The padding file is not loaded as a file, and its setting survives the save.
+        let path = std::path::PathBuf::from("../test_files/test_padding.pack");
+        std::fs::write(&path, &data).unwrap();
+        let mut pack = Pack::read_and_merge(&[path], true, false).unwrap();
+        assert_eq!(pack.files().len(), 1);
+        assert_eq!(pack.settings().pad_to_size(), Some(target_size as u64));
+
+        // Saving again resizes the padding file to get the same size.
+        pack.insert(RFile::new_from_vec(&[1; 512], FileType::Unknown, 0, "zzz/file.bin")).unwrap();
+        let mut data = vec![];
+        pack.encode(&mut data, &None).unwrap();
+        assert_eq!(data.len(), target_size as usize);
+
+        // And if the Pack is already big enough, no padding file is added.
+        pack.insert(RFile::new_from_vec(&[1; 8192], FileType::Unknown, 0, "zzz/file.bin")).unwrap();
+        let mut data = vec![];
+        pack.encode(&mut data, &None).unwrap();
+        assert!(data.len() > target_size as usize);
+        assert!(!data.windows(RESERVED_NAME_PADDING.len()).any(|x| x == RESERVED_NAME_PADDING.as_bytes()));
+
+        // Same if the Pack is smaller, but the entry of the padding file would make it overshoot.
+        let target_size = data.len() as i32 - 8192 + 4;
+        pack.insert(RFile::new_from_vec(&[1; 1], FileType::Unknown, 0, "zzz/file.bin")).unwrap();
+        pack.settings_mut().set_setting_number(PADDING_SIZE_SETTING, target_size);
+        let mut data = vec![];
+        pack.encode(&mut data, &None).unwrap();
+        assert!(data.len() <= target_size as usize);
+        assert!(!data.windows(RESERVED_NAME_PADDING.len()).any(|x| x == RESERVED_NAME_PADDING.as_bytes()));
+    }
+}
+
+#[test]
+fn test_mymod_export_rules() {
+    let mut settings = PackSettings::default();
+    assert!(settings.mymod_export_rules().is_empty());
+
+    settings.set_setting_text("mymod_export_rules", "# Comment\nscript/;lua_src/\ndb;tables;TSV, mirror\n\nno_disk_folder\ntext;../outside;mirror\nscript/campaign;lua_src/campaign_scripts");
+    let rules = settings.mymod_export_rules();
+    assert_eq!(rules.len(), 3);
+    assert_eq!(rules[0].pack_folder(), "script");
+    assert_eq!(rules[0].disk_folder(), "lua_src");
+    assert!(!rules[0].tables_as_tsv());
+    assert!(!rules[0].mirror());
+    assert!(rules[1].tables_as_tsv());
+    assert!(rules[1].mirror());
+
+    // The most specific folder wins.
+    let rule = MyModExportRule::rule_for_pack_path(&rules, "script/campaign/mod/test.lua").unwrap();
+    assert_eq!(rule.disk_path("script/campaign/mod/test.lua"), "lua_src/campaign_scripts/mod/test.lua");
+    let rule = MyModExportRule::rule_for_pack_path(&rules, "Script/battle/test.lua").unwrap();
+    assert_eq!(rule.disk_path("Script/battle/test.lua"), "lua_src/battle/test.lua");
+    assert!(MyModExportRule::rule_for_pack_path(&rules, "scripts/test.lua").is_none());
+    assert!(MyModExportRule::rule_for_pack_path(&rules, "text/db/test.loc").is_none());
+
+    // And the inverse.
+    let rule = MyModExportRule::rule_for_disk_path(&rules, "tables/units_tables/data.tsv").unwrap();
+    assert_eq!(rule.pack_path("tables/units_tables/data.tsv"), "db/units_tables/data.tsv");
+    assert!(MyModExportRule::rule_for_disk_path(&rules, "db/units_tables/data").is_none());
+}
+
+#[test]
+fn test_extract_with_export_rules() {
+    use std::fs::{create_dir_all, read, remove_dir_all, write};
+    use crate::games::pfh_version::PFHVersion;
+
+    let path = std::env::temp_dir().join("rpfm_test_extract_with_export_rules");
+    let _ = remove_dir_all(&path);
+    create_dir_all(path.join("tables/.git")).unwrap();
+    create_dir_all(path.join("lua_src")).unwrap();
+    write(path.join("tables/removed_table"), b"Removed.").unwrap();
+    write(path.join("tables/.git/config"), b"Hidden.").unwrap();
+    write(path.join("lua_src/removed.lua"), b"Not mirrored.").unwrap();
+
+    let mut pack = Pack::new_with_name_and_version("test_export_rules.pack", PFHVersion::PFH5);
+    pack.insert(RFile::new_from_vec(b"Script.", FileType::Text, 0, "script/test.lua")).unwrap();
+    pack.insert(RFile::new_from_vec(b"Table.", FileType::Text, 0, "db/units_tables/data.txt")).unwrap();
+    pack.insert(RFile::new_from_vec(b"Text.", FileType::Text, 0, "text/test.txt")).unwrap();
+
+    let mut settings = PackSettings::default();
+    settings.set_setting_text("mymod_export_rules", "script;lua_src\ndb;tables;tsv,mirror");
+    let failed_paths = pack.extract_with_export_rules(&path, &settings.mymod_export_rules(), &None).unwrap();
+    assert!(failed_paths.is_empty());
+
+    assert_eq!(read(path.join("lua_src/test.lua")).unwrap(), b"Script.");
+    assert_eq!(read(path.join("tables/units_tables/data.txt")).unwrap(), b"Table.");
+    assert_eq!(read(path.join("text/test.txt")).unwrap(), b"Text.");
+
+    // Only mirrored rules delete files, and never hidden ones.
+    assert!(!path.join("tables/removed_table").exists());
+    assert!(path.join("tables/.git/config").exists());
+    assert!(path.join("lua_src/removed.lua").exists());
+
+    remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn test_esf_bookmarks() {
+    let mut settings = PackSettings::default();
+    settings.set_setting_text("esf_bookmarks", "campaigns/main/startpos.esf;0,1;CAMPAIGN_SAVE_GAME > FACTION_ARRAY\nbroken line\nother.esf;x;ROOT");
+
+    let bookmark = ESFNodePath::new(vec![1, 1, 0], vec!["CAMPAIGN_SAVE_GAME".to_owned(), "FACTION_ARRAY".to_owned(), "wh_main_dwf_dwarfs".to_owned(), "FACTION".to_owned()]);
+    let mut bookmarks = settings.esf_bookmarks("campaigns/main/startpos.esf");
+    assert_eq!(bookmarks, vec![ESFNodePath::new(vec![0, 1], vec!["CAMPAIGN_SAVE_GAME".to_owned(), "FACTION_ARRAY".to_owned()])]);
+    assert!(settings.esf_bookmarks("other.esf").is_empty());
+
+    // Setting the bookmarks of a file must not touch the bookmarks of other files.
+    bookmarks.push(bookmark.clone());
+    settings.set_esf_bookmarks("campaigns/main/startpos.esf", &bookmarks);
+    assert_eq!(settings.esf_bookmarks("campaigns/main/startpos.esf"), bookmarks);
+    assert!(settings.setting_text("esf_bookmarks").unwrap().contains("other.esf;x;ROOT"));
+
+    settings.set_esf_bookmarks("campaigns/main/startpos.esf", &[]);
+    assert!(settings.esf_bookmarks("campaigns/main/startpos.esf").is_empty());
+}
+
+#[test]
+fn test_path_labels() {
+    let mut settings = PackSettings::default();
+    assert_eq!(settings.path_label_definitions().len(), 3);
+
+    settings.set_setting_text("path_label_definitions", "Done;#00ff00\n# Commented;#000000\nNo colour\nReview; #ffff00");
+    assert_eq!(settings.path_label_definitions(), vec![("Done".to_owned(), "#00ff00".to_owned()), ("Review".to_owned(), "#ffff00".to_owned())]);
+
+    settings.set_path_label(&ContainerPath::Folder("db/units_tables".to_owned()), Some("Done"));
+    settings.set_path_label(&ContainerPath::File("db/units_tables/mod".to_owned()), Some("Review"));
+    settings.set_path_label(&ContainerPath::File("text/db/mod.loc".to_owned()), Some("Review"));
+    settings.set_path_label(&ContainerPath::File("text/db/mod.loc".to_owned()), Some("Done"));
+
+    let labels = settings.path_labels();
+    assert_eq!(labels.len(), 3);
+    assert_eq!(labels.get(&ContainerPath::Folder("db/units_tables".to_owned())), Some(&"Done".to_owned()));
+    assert_eq!(labels.get(&ContainerPath::File("text/db/mod.loc".to_owned())), Some(&"Done".to_owned()));
+
+    // Labels must follow their paths when moved, including the ones inside moved folders.
+    settings.move_path_labels(&[
+        (ContainerPath::Folder("db/units_tables".to_owned()), ContainerPath::Folder("db/main_units_tables".to_owned())),
+        (ContainerPath::File("text/db/mod.loc".to_owned()), ContainerPath::File("text/db/renamed.loc".to_owned())),
+    ]);
+
+    let labels = settings.path_labels();
+    assert_eq!(labels.get(&ContainerPath::Folder("db/main_units_tables".to_owned())), Some(&"Done".to_owned()));
+    assert_eq!(labels.get(&ContainerPath::File("db/main_units_tables/mod".to_owned())), Some(&"Review".to_owned()));
+    assert_eq!(labels.get(&ContainerPath::File("text/db/renamed.loc".to_owned())), Some(&"Done".to_owned()));
+    assert!(!labels.contains_key(&ContainerPath::File("text/db/mod.loc".to_owned())));
+
+    settings.set_path_label(&ContainerPath::File("text/db/renamed.loc".to_owned()), None);
+    assert_eq!(settings.path_labels().len(), 2);
+}
+
+#[test]
+fn test_row_annotations() {
+    let mut settings = PackSettings::default();
+    settings.set_row_annotation("text/db/mod.loc", "key_1", Some("Revisit this text"));
+    settings.set_row_annotation("text/db/mod.loc", "key_2", Some("Too long"));
+    settings.set_row_annotation("text/db/mod.loc", "key_3", Some(" "));
+    settings.set_row_annotation("db/units_tables/mod", "unit_1", Some("Revisit this unit's cost"));
+    assert_eq!(settings.row_annotations()["text/db/mod.loc"].len(), 2);
+
+    // Swapping keys must not overwrite notes, and notes moved to a key with a note must be kept.
+    settings.rekey_row_annotations("text/db/mod.loc", &[("key_1".to_owned(), "key_2".to_owned()), ("key_2".to_owned(), "key_1".to_owned())]);
+    assert_eq!(settings.row_annotations()["text/db/mod.loc"]["key_1"], "Too long");
+    assert_eq!(settings.row_annotations()["text/db/mod.loc"]["key_2"], "Revisit this text");
+
+    settings.rekey_row_annotations("text/db/mod.loc", &[("key_1".to_owned(), "key_2".to_owned())]);
+    assert_eq!(settings.row_annotations()["text/db/mod.loc"].len(), 1);
+    assert_eq!(settings.row_annotations()["text/db/mod.loc"]["key_2"], "Revisit this text\nToo long");
+
+    // Annotations must follow their files when moved, including the ones inside moved folders.
+    settings.move_row_annotations(&[
+        (ContainerPath::Folder("db/units_tables".to_owned()), ContainerPath::Folder("db/main_units_tables".to_owned())),
+        (ContainerPath::File("text/db/mod.loc".to_owned()), ContainerPath::File("text/db/renamed.loc".to_owned())),
+    ]);
+    assert!(settings.row_annotations().contains_key("db/main_units_tables/mod"));
+    assert!(settings.row_annotations().contains_key("text/db/renamed.loc"));
+    assert_eq!(settings.row_annotations().len(), 2);
+
+    // Removing the last note of a file must remove the file too.
+    settings.set_row_annotation("db/main_units_tables/mod", "unit_1", None);
+    assert!(!settings.row_annotations().contains_key("db/main_units_tables/mod"));
+
+    let mut loc = Loc::new(false);
+    let mut row = loc.new_row();
+    row[0] = DecodedData::StringU16("key_2".to_owned());
+    loc.set_data(&[row]).unwrap();
+
+    let mut pack = Pack::default();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, "text/db/renamed.loc")).unwrap();
+    pack.settings_mut().set_row_annotation("text/db/renamed.loc", "key_2", Some("Still here"));
+    pack.settings_mut().set_row_annotation("text/db/renamed.loc", "key_5", Some("Row deleted"));
+    pack.settings_mut().set_row_annotation("text/db/deleted.loc", "key_1", Some("File deleted"));
+
+    let orphaned = pack.orphaned_row_annotations(&None);
+    assert_eq!(orphaned.len(), 2);
+    assert!(orphaned.contains(&("text/db/renamed.loc".to_owned(), "key_5".to_owned())));
+    assert!(orphaned.contains(&("text/db/deleted.loc".to_owned(), "key_1".to_owned())));
+}
+
+/// This function builds an Arena-like Pack, with extended header and encrypted data.
+///
+/// Encryption is a XOR, so encrypting is the same as decrypting.
+fn encrypted_pack(files: &[(&str, &[u8])]) -> Vec<u8> {
+    use crate::encryption::Decryptable;
+    use crate::games::pfh_file_type::PFHFileType;
+    use super::PFHFlags;
+
+    let padded_len = |len: usize| if len % 8 == 0 { len } else { len + 8 - (len % 8) };
+
+    let mut index = vec![];
+    let mut data = vec![];
+    for (path, file_data) in files {
+        index.write_u32(file_data.len() as u32).unwrap();
+        index.write_bool(false).unwrap();
+        index.write_string_u8_0terminated(&path.replace('/', "\\")).unwrap();
+
+        let mut encrypted = std::io::Cursor::new(file_data.to_vec()).decrypt(false).unwrap();
+        encrypted.resize(padded_len(encrypted.len()), 0);
+        data.extend_from_slice(&encrypted);
+    }
+
+    let mut pack = vec![];
+    pack.write_string_u8("PFH5").unwrap();
+    pack.write_u32((PFHFlags::HAS_ENCRYPTED_DATA | PFHFlags::HAS_EXTENDED_HEADER).bits() | PFHFileType::Mod.value()).unwrap();
+    pack.write_u32(0).unwrap();
+    pack.write_u32(0).unwrap();
+    pack.write_u32(files.len() as u32).unwrap();
+    pack.write_u32(index.len() as u32).unwrap();
+    pack.write_u32(0).unwrap();
+    pack.extend_from_slice(&[0; 20]);
+    pack.extend_from_slice(&index);
+
+    // The data starts in a multiple of 8, and Arena Packs have 256 extra bytes at the end.
+    pack.resize(padded_len(pack.len()), 0);
+    pack.extend_from_slice(&data);
+    pack.extend_from_slice(&[0; 256]);
+    pack
+}
+
+#[test]
+fn test_decode_encrypted_data() {
+    use std::path::PathBuf;
+    use super::PFHFlags;
+
+    let files: [(&str, &[u8]); 2] = [
+        ("text/test.txt", b"Encrypted text data."),
+        ("a/test.bin", &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13]),
+    ];
+
+    let path = PathBuf::from("../test_files/test_encrypted.pack");
+    std::fs::write(&path, encrypted_pack(&files)).unwrap();
+
+    // Lazy-loaded files must be decrypted when read.
+    let mut pack = Pack::read_and_merge(&[path], true, false).unwrap();
+    assert!(pack.bitmask().contains(PFHFlags::HAS_ENCRYPTED_DATA));
+    for (path, data) in &files {
+        let file = pack.file_mut(path).unwrap();
+        assert!(file.is_encrypted());
+        assert_eq!(file.encode(&None, false, false, true).unwrap().unwrap(), data.to_vec());
+    }
+
+    // Saving it writes it decrypted, without the flags we cannot encode.
+    let path = PathBuf::from("../test_files/test_encrypted_save.pack");
+    pack.save(Some(&path)).unwrap();
+
+    let mut pack = Pack::read_and_merge(&[path], true, false).unwrap();
+    assert!(!pack.bitmask().intersects(PFHFlags::HAS_ENCRYPTED_DATA | PFHFlags::HAS_ENCRYPTED_INDEX | PFHFlags::HAS_EXTENDED_HEADER));
+    for (path, data) in &files {
+        let file = pack.file_mut(path).unwrap();
+        assert!(!file.is_encrypted());
+        assert_eq!(file.encode(&None, false, false, true).unwrap().unwrap(), data.to_vec());
+    }
+}
+
+#[test]
+fn test_rescue() {
+    use crate::games::pfh_version::PFHVersion;
+    use super::{PackRescueReport, RescueDropReason, RescueSuspiciousReason};
+
+    let mut pack = Pack::new_with_version(PFHVersion::PFH5);
+    pack.insert(RFile::new_from_vec(b"Data.", FileType::Text, 0, "text/test.txt")).unwrap();
+    pack.insert(RFile::new_from_vec(b"Duplicated data.", FileType::Text, 0, "text/TEST.txt")).unwrap();
+    pack.insert(RFile::new_from_vec(&[], FileType::Text, 0, "text/empty.txt")).unwrap();
+    pack.insert(RFile::new_from_vec(&[1, 2, 3], FileType::DB, 0, "db/missing_tables/broken")).unwrap();
+    pack.insert(RFile::new_from_vec(&[0; 16], FileType::Unknown, 0, "z.bin")).unwrap();
+
+    let mut encodeable_extra_data = EncodeableExtraData::default();
+    encodeable_extra_data.test_mode = true;
+    let mut data = vec![];
+    pack.encode(&mut data, &Some(encodeable_extra_data)).unwrap();
+
+    // Cut the end of the last file, but make the Pack think it's complete, like when reading a partially-written Pack.
+    let path = "../test_files/test_rescue.pack";
+    let data_size = data.len() as u64;
+    data.truncate(data.len() - 8);
+    std::fs::write(path, &data).unwrap();
+
+    let reader = File::open(path).unwrap();
+    let mut decodeable_extra_data = DecodeableExtraData::default();
+    decodeable_extra_data.disk_file_path = Some(path);
+    decodeable_extra_data.data_size = data_size;
+    decodeable_extra_data.timestamp = last_modified_time_from_file(&reader).unwrap();
+    decodeable_extra_data.lazy_load = true;
+
+    // Paths that only differ in casing are already deduplicated when reading the Pack.
+    let mut pack = Pack::decode(&mut BufReader::new(reader), &Some(decodeable_extra_data)).unwrap();
+    assert_eq!(pack.load_warnings().len(), 1);
+    let duplicated_path = if pack.file("text/TEST.txt").is_some() { "text/TEST.txt" } else { "text/test.txt" };
+
+    let report = pack.rescue(&None);
+    assert_eq!(report.dropped_files(), &vec![
+        ("text/empty.txt".to_owned(), RescueDropReason::ZeroSize),
+    ]);
+
+    assert_eq!(report.suspicious_files().len(), 2);
+    assert!(matches!(&report.suspicious_files()[0], (path, RescueSuspiciousReason::Undecodeable(_)) if path == "db/missing_tables/broken"));
+    assert_eq!(report.suspicious_files()[1], ("z.bin".to_owned(), RescueSuspiciousReason::Truncated(16, 8)));
+    assert!(report.removed_flags().is_empty());
+
+    let mut paths = pack.files().keys().cloned().collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, vec!["db/missing_tables/broken".to_owned(), duplicated_path.to_owned(), "z.bin".to_owned()]);
+    assert_eq!(pack.file("z.bin").unwrap().cached().unwrap().len(), 8);
+
+    let text = report.to_string();
+    assert!(text.contains("  - z.bin: data truncated from 16 to 8 bytes"));
+    assert!(text.contains("Header flags: unchanged."));
+    assert_eq!(PackRescueReport::path_for_pack(std::path::Path::new("a/test.pack")), std::path::PathBuf::from("a/test.rescue_report.txt"));
+}
+
+/// This function builds a Mod Pack with the provided file index values, so we can test how damaged indexes are handled.
+///
+/// If `files_index_size` is None, the real size of the index is used.
+fn malformed_pack(version: &str, files_count: u32, files_index_size: Option<u32>, entries: &[(u32, &str)], data: &[u8]) -> Vec<u8> {
+    use crate::games::pfh_file_type::PFHFileType;
+
+    let mut index = vec![];
+    for (size, path) in entries {
+        index.write_u32(*size).unwrap();
+        if version != "PFH4" {
+            index.write_bool(false).unwrap();
+        }
+        index.write_string_u8_0terminated(path).unwrap();
+    }
+
+    let mut pack = vec![];
+    pack.write_string_u8(version).unwrap();
+    pack.write_u32(PFHFileType::Mod.value()).unwrap();
+    pack.write_u32(0).unwrap();
+    pack.write_u32(0).unwrap();
+    pack.write_u32(files_count).unwrap();
+    pack.write_u32(files_index_size.unwrap_or(index.len() as u32)).unwrap();
+    pack.write_u32(0).unwrap();
+    pack.extend_from_slice(&index);
+    pack.extend_from_slice(data);
+    pack
+}
+
+/// This function saves a malformed Pack to the test files and reads it back, loading all its files.
+fn read_malformed_pack(name: &str, data: Vec<u8>) -> crate::error::Result<Pack> {
+    let path = std::path::PathBuf::from(format!("../test_files/test_malformed_{}.pack", name));
+    std::fs::write(&path, data).unwrap();
+    Pack::read_and_merge(&[path], false, false)
+}
+
+#[test]
+fn test_decode_malformed_indexes() {
+    use crate::error::RLibError;
+
+    // Files past the end of the data section must fail with the file's info, not when loading the file later.
+    let data = malformed_pack("PFH4", 2, None, &[(4, "text/a.txt"), (100, "text/b.txt")], b"AAAABBBB");
+    match read_malformed_pack("out_of_bounds", data) {
+        Err(RLibError::PackIndexEntryOutOfBounds { path, offset, size }) => {
+            assert_eq!(path, "text/b.txt");
+            assert_eq!(offset, 62);
+            assert_eq!(size, 100);
+        }
+        result => panic!("Unexpected result: {:?}", result.map(|pack| pack.files().len())),
+    }
+
+    let data = malformed_pack("PFH5", 1, None, &[(u32::MAX, "text/a.txt")], b"AAAA");
+    assert!(matches!(read_malformed_pack("size_overflow", data), Err(RLibError::PackIndexEntryOutOfBounds { .. })));
+
+    // Indexes bigger than the Pack must fail before trying to read them.
+    let data = malformed_pack("PFH5", 1, Some(u32::MAX), &[(4, "text/a.txt")], b"AAAA");
+    assert!(matches!(read_malformed_pack("index_too_big", data), Err(RLibError::PackIndexesNotComplete)));
+
+    // Absurd file counts must fail when the index runs out, not when reserving memory for them.
+    let data = malformed_pack("PFH4", u32::MAX, None, &[(4, "text/a.txt")], b"AAAA");
+    assert!(read_malformed_pack("files_count_too_big", data).is_err());
+
+    // No files with an index is weird, but readable.
+    let data = malformed_pack("PFH5", 0, None, &[(0, "text/a.txt")], b"");
+    let pack = read_malformed_pack("no_files", data).unwrap();
+    assert!(pack.files().is_empty());
+    assert_eq!(pack.load_warnings().len(), 1);
+
+    // Duplicated paths keep the last one, with a warning.
+    let data = malformed_pack("PFH5", 3, None, &[(4, "text/test.txt"), (4, "text/TEST.txt"), (4, "text/other.txt")], b"AAAABBBBCCCC");
+    let mut pack = read_malformed_pack("duplicated_casing", data).unwrap();
+    assert_eq!(pack.files().len(), 2);
+    assert_eq!(pack.load_warnings().len(), 1);
+    assert!(pack.file("text/test.txt").is_none());
+    assert_eq!(pack.file_mut("text/TEST.txt").unwrap().encode(&None, false, false, true).unwrap().unwrap(), b"BBBB".to_vec());
+
+    let data = malformed_pack("PFH4", 2, None, &[(4, "text/test.txt"), (4, "text/test.txt")], b"AAAABBBB");
+    let pack = read_malformed_pack("duplicated", data).unwrap();
+    assert_eq!(pack.files().len(), 1);
+    assert_eq!(pack.load_warnings().len(), 1);
+}
+
+#[test]
+fn test_encode_pfh6_keeps_subheader() {
+    let mut data = std::fs::read("../test_files/PFH6_test.pack").unwrap();
+
+    // Subheader with values different from the ones we use by default: version, game version, build number, authoring tool and extra data.
+    data[32..36].copy_from_slice(&2u32.to_le_bytes());
+    data[36..40].copy_from_slice(&0x0105_0203u32.to_le_bytes());
+    data[40..44].copy_from_slice(&12345u32.to_le_bytes());
+    data[44..52].copy_from_slice(b"CA_TOOL\0");
+    data[52..56].copy_from_slice(&[1, 2, 3, 4]);
+    data[304..308].copy_from_slice(&[5, 6, 7, 8]);
+
+    let path = std::path::PathBuf::from("../test_files/PFH6_test_subheader.pack");
+    std::fs::write(&path, &data).unwrap();
+    let mut pack = Pack::read_and_merge(&[path], false, false).unwrap();
+    assert_eq!(pack.subheader_version(), 2);
+    assert_eq!(pack.game_version(), 0x0105_0203);
+    assert_eq!(pack.build_number(), 12345);
+    assert_eq!(pack.authoring_tool(), "CA_TOOL");
+    assert_eq!(pack.extra_subheader_data(), &data[52..308]);
+
+    // Outside test mode the timestamp and the reserved files change, but the rest of the header must be written back as it was read.
+    let mut encoded = vec![];
+    pack.encode(&mut encoded, &None).unwrap();
+    assert_eq!(encoded[..8], data[..8]);
+    assert_eq!(encoded[28..308], data[28..308]);
+
+    // Changed fields are written, and the extra data is kept at its fixed size.
+    pack.set_authoring_tool("RPFM");
+    pack.set_extra_subheader_data(&[9; 4]);
+    let mut encoded = vec![];
+    pack.encode(&mut encoded, &None).unwrap();
+    assert_eq!(&encoded[44..52], b"RPFM\0\0\0\0");
+    assert_eq!(encoded[52..56], [9; 4]);
+    assert!(encoded[56..308].iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn test_file_timestamps_round_trip() {
+    use crate::games::pfh_version::PFHVersion;
+
+    let folder = std::env::temp_dir().join("rpfm_test_file_timestamps");
+    let _ = std::fs::remove_dir_all(&folder);
+    std::fs::create_dir_all(folder.join("text")).unwrap();
+    std::fs::write(folder.join("text/from_disk.txt"), b"From disk.").unwrap();
+    let disk_timestamp = last_modified_time_from_file(&File::open(folder.join("text/from_disk.txt")).unwrap()).unwrap();
+
+    for (pfh_version, file_name) in [(PFHVersion::PFH5, "test_timestamps_pfh5.pack"), (PFHVersion::PFH3, "test_timestamps_pfh3.pack")] {
+        let path = folder.with_file_name(file_name).to_string_lossy().to_string();
+        let mut pack = Pack::new_with_name_and_version(file_name, pfh_version);
+        pack.set_bitmask(PFHFlags::HAS_INDEX_WITH_TIMESTAMPS);
+
+        // Files added from disk keep the last modified date of their source, and edited files get the current date.
+        pack.insert_folder(&folder, "", &None, &None).unwrap();
+        pack.insert(RFile::new_from_vec(b"Old.", FileType::Text, 1_600_000_000, "text/old.txt")).unwrap();
+        pack.insert(RFile::new_from_vec(b"Edited.", FileType::Text, 1_600_000_000, "text/edited.txt")).unwrap();
+        pack.file_mut("text/edited.txt").unwrap().touch().unwrap();
+
+        assert_eq!(pack.file("text/from_disk.txt").unwrap().timestamp(), Some(disk_timestamp));
+        let edited_timestamp = pack.file("text/edited.txt").unwrap().timestamp().unwrap();
+        assert!(edited_timestamp >= disk_timestamp);
+
+        pack.save(Some(&std::path::PathBuf::from(&path))).unwrap();
+
+        let mut reader = BufReader::new(File::open(&path).unwrap());
+        let mut decodeable_extra_data = DecodeableExtraData::default();
+        decodeable_extra_data.disk_file_path = Some(&path);
+        decodeable_extra_data.data_size = reader.len().unwrap();
+        decodeable_extra_data.timestamp = last_modified_time_from_file(reader.get_ref()).unwrap();
+
+        let pack = Pack::decode(&mut reader, &Some(decodeable_extra_data)).unwrap();
+        assert_eq!(pack.file("text/from_disk.txt").unwrap().timestamp(), Some(disk_timestamp));
+        assert_eq!(pack.file("text/old.txt").unwrap().timestamp(), Some(1_600_000_000));
+        assert_eq!(pack.file("text/edited.txt").unwrap().timestamp(), Some(edited_timestamp));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    let _ = std::fs::remove_dir_all(&folder);
+}
@@ -8,12 +8,16 @@
 // https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
+use crate::binary::WriteBytes;
+use crate::error::{RLibError, Result};
+use crate::files::pack::*;
+use crate::games::pfh_version::PFHVersion;
+
 // These consts are used for dealing with Time-related operations.
 const WINDOWS_TICK: u64 = 10_000_000;
 const SEC_TO_UNIX_EPOCH: u64 = 11_644_473_600;
 
 const SUBHEADER_MARK: u32 = 0x12345678;
-const SUBHEADER_VERSION: u32 = 1;
 
 mod pfh6;
 mod pfh5;
@@ -21,3 +25,61 @@ mod pfh4;
 mod pfh3;
 mod pfh2;
 mod pfh0;
+
+impl Pack {
+
+    /// This function adds the padding file at the end of the file index and data of a Pack being written, if the Pack needs it.
+    ///
+    /// The final size of the Pack is calculated from its already built parts. The padding file's own entry in the index
+    /// counts towards the target size, so if the Pack is too close to it to fit said entry, no padding file is added.
+    fn add_padding(&self, header: &mut [u8], dependencies_index: &[u8], files_index: &mut Vec<u8>, files_data: &mut Vec<u8>) -> Result<()> {
+        let target_size = match self.settings.pad_to_size() {
+            Some(target_size) => target_size,
+            None => return Ok(()),
+        };
+
+        // Build the index entry first with an empty size, as we need its length to know the size of the padding.
+        let mut file_index_entry = vec![];
+        file_index_entry.write_u32(0)?;
+
+        if self.header.bitmask.contains(PFHFlags::HAS_INDEX_WITH_TIMESTAMPS) {
+            match self.header.pfh_version {
+                PFHVersion::PFH6 |
+                PFHVersion::PFH5 |
+                PFHVersion::PFH4 => file_index_entry.write_u32(0)?,
+                PFHVersion::PFH3 |
+                PFHVersion::PFH2 => file_index_entry.write_u64(SEC_TO_UNIX_EPOCH * WINDOWS_TICK)?,
+                PFHVersion::PFH0 => {},
+            }
+        }
+
+        // The padding file is never compressed.
+        if matches!(self.header.pfh_version, PFHVersion::PFH6 | PFHVersion::PFH5) {
+            file_index_entry.write_bool(false)?;
+        }
+
+        file_index_entry.write_string_u8_0terminated(RESERVED_NAME_PADDING)?;
+
+        let padded_len = (header.len() + dependencies_index.len() + files_index.len() + file_index_entry.len() + files_data.len()) as u64;
+        if padded_len > target_size {
+            return Ok(());
+        }
+
+        // Error on padding too big for the Pack.
+        let padding_len = (target_size - padded_len) as usize;
+        if padding_len > u32::MAX as usize {
+            return Err(RLibError::DataTooBigForContainer("Pack".to_owned(), u32::MAX as u64, padding_len, RESERVED_NAME_PADDING.to_owned()));
+        }
+
+        file_index_entry[0..4].copy_from_slice(&(padding_len as u32).to_le_bytes());
+        files_index.extend_from_slice(&file_index_entry);
+        files_data.resize(files_data.len() + padding_len, 0);
+
+        // The amount of files and the length of the file index are always in the same place of the header, so just update them there.
+        let files_count = u32::from_le_bytes([header[16], header[17], header[18], header[19]]) + 1;
+        header[16..20].copy_from_slice(&files_count.to_le_bytes());
+        header[20..24].copy_from_slice(&(files_index.len() as u32).to_le_bytes());
+
+        Ok(())
+    }
+}
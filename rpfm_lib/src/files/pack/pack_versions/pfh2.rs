@@ -121,8 +121,8 @@ impl Pack {
             .into_par_iter()
             .unzip();
 
-        let files_index = files_index.into_par_iter().flatten().collect::<Vec<_>>();
-        let files_data = files_data.into_par_iter().flatten().collect::<Vec<_>>();
+        let mut files_index = files_index.into_par_iter().flatten().collect::<Vec<_>>();
+        let mut files_data = files_data.into_par_iter().flatten().collect::<Vec<_>>();
 
         // Build the dependencies index on memory. This one is never big, so no need of par_iter.
         let mut dependencies_index = vec![];
@@ -146,6 +146,9 @@ impl Pack {
 
         header.write_u64((self.header.internal_timestamp + SEC_TO_UNIX_EPOCH) * WINDOWS_TICK)?;
 
+        // Add the padding file last, as it needs to know the size of everything else.
+        self.add_padding(&mut header, &dependencies_index, &mut files_index, &mut files_data)?;
+
         // Finally, write everything in one go.
         buffer.write_all(&header)?;
         buffer.write_all(&dependencies_index)?;
@@ -33,11 +33,10 @@ impl Pack {
         let files_index_size = data.read_u32()?;
 
         self.header.internal_timestamp = u64::from(data.read_u32()?);
-        self.files = HashMap::with_capacity(files_count as usize);
 
         // The rest of the header data depends on certain flags. Check them to see what parts of the header
         // are left to read.
-        let extra_header_size = {
+        let extra_header_size: u32 = {
             if (self.header.bitmask.contains(PFHFlags::HAS_EXTENDED_HEADER) && data_len < 44) ||
                 (!self.header.bitmask.contains(PFHFlags::HAS_EXTENDED_HEADER) && data_len < 24) {
                 return Err(RLibError::PackHeaderNotComplete);
@@ -48,7 +47,11 @@ impl Pack {
 
         // Optimization: we only really need the header of the Pack, not the data, and reads, if performed from disk, are expensive.
         // So we get all the data from the header to the end of the indexes to memory and put it in a buffer, so we can read it faster.
-        let indexes_size = extra_header_size + packs_index_size + files_index_size;
+        // Make sure the indexes actually fit in the Pack before reading them, as damaged Packs may report absurd sizes.
+        let indexes_size = u64::from(extra_header_size) + u64::from(packs_index_size) + u64::from(files_index_size);
+        let capacity = self.check_indexes_size(data.stream_position()? - extra_data.disk_file_offset, data_len, indexes_size, files_count, files_index_size)?;
+        self.files = HashMap::with_capacity(capacity);
+
         let buffer_data = data.read_slice(indexes_size as usize, false)?;
         let mut buffer_mem = BufReader::new(Cursor::new(buffer_data));
 
@@ -71,6 +74,7 @@ impl Pack {
         };
 
         // Get the Files in the Pack.
+        let mut index_paths = HashMap::with_capacity(capacity);
         for files_to_read in (0..files_count).rev() {
 
             // Get his size. If it's encrypted, decrypt it first.
@@ -94,9 +98,12 @@ impl Pack {
                 buffer_mem.read_string_u8_0terminated()?
             }.replace('\\', "/");
 
+            // Make sure the file's data is actually in the Pack, so we don't end up with lazy-loaded files that fail later.
+            Self::check_index_entry_bounds(&path, data_pos, size, data_len)?;
+
             // Build the File as a LazyLoaded file by default.
             let file = RFile::new_from_container(self, size as u64, false, files_are_encrypted, data_pos, timestamp, &path)?;
-            self.insert(file)?;
+            self.insert_index_entry(file, &mut index_paths)?;
 
             data_pos += u64::from(size);
         }
@@ -152,8 +159,8 @@ impl Pack {
             .into_par_iter()
             .unzip();
 
-        let files_index = files_index.into_par_iter().flatten().collect::<Vec<_>>();
-        let files_data = files_data.into_par_iter().flatten().collect::<Vec<_>>();
+        let mut files_index = files_index.into_par_iter().flatten().collect::<Vec<_>>();
+        let mut files_data = files_data.into_par_iter().flatten().collect::<Vec<_>>();
 
         // Build the dependencies index on memory. This one is never big, so no need of par_iter.
         let mut dependencies_index = vec![];
@@ -177,6 +184,9 @@ impl Pack {
 
         header.write_u32(self.header.internal_timestamp as u32)?;
 
+        // Add the padding file last, as it needs to know the size of everything else.
+        self.add_padding(&mut header, &dependencies_index, &mut files_index, &mut files_data)?;
+
         // Finally, write everything in one go.
         buffer.write_all(&header)?;
         buffer.write_all(&dependencies_index)?;
@@ -35,14 +35,17 @@ impl Pack {
         let files_index_size = data.read_u32()?;
 
         self.header.internal_timestamp = u64::from(data.read_u32()?);
-        self.files = HashMap::with_capacity(files_count as usize);
 
         // The rest of the header data is 280 bytes.
-        let extra_header_size = 280;
+        let extra_header_size: u32 = 280;
 
         // Optimization: we only really need the header of the Pack, not the data, and reads, if performed from disk, are expensive.
         // So we get all the data from the header to the end of the indexes to memory and put it in a buffer, so we can read it faster.
-        let indexes_size = extra_header_size + packs_index_size + files_index_size;
+        // Make sure the indexes actually fit in the Pack before reading them, as damaged Packs may report absurd sizes.
+        let indexes_size = u64::from(extra_header_size) + u64::from(packs_index_size) + u64::from(files_index_size);
+        let capacity = self.check_indexes_size(data.stream_position()? - extra_data.disk_file_offset, data_len, indexes_size, files_count, files_index_size)?;
+        self.files = HashMap::with_capacity(capacity);
+
         let buffer_data = data.read_slice(indexes_size as usize, false)?;
         let mut buffer_mem = BufReader::new(Cursor::new(buffer_data));
 
@@ -57,12 +60,12 @@ impl Pack {
         if subheader_marker != SUBHEADER_MARK {
             return Err(RLibError::PackSubHeaderMissing)
         }
-        let _subheader_version = buffer_mem.read_u32()?;
+        self.header.subheader_version = buffer_mem.read_u32()?;
 
         self.header.game_version = buffer_mem.read_u32()?;
         self.header.build_number = buffer_mem.read_u32()?;
         self.header.authoring_tool = buffer_mem.read_string_u8_0padded(AUTHORING_TOOL_SIZE as usize)?;
-        self.header.extra_subheader_data = buffer_mem.read_slice(256, false)?;
+        self.header.extra_subheader_data = buffer_mem.read_slice(SUBHEADER_EXTRA_DATA_SIZE, false)?;
 
         // Check that the position of the data we want to get is actually valid.
         let mut data_pos = data.stream_position()? - extra_data.disk_file_offset;
@@ -83,6 +86,7 @@ impl Pack {
         };
 
         // Get the Files in the Pack.
+        let mut index_paths = HashMap::with_capacity(capacity);
         for files_to_read in (0..files_count).rev() {
 
             // Get his size. If it's encrypted, decrypt it first.
@@ -109,9 +113,12 @@ impl Pack {
                 buffer_mem.read_string_u8_0terminated()?
             }.replace('\\', "/");
 
+            // Make sure the file's data is actually in the Pack, so we don't end up with lazy-loaded files that fail later.
+            Self::check_index_entry_bounds(&path, data_pos, size, data_len)?;
+
             // Build the File as a LazyLoaded file by default.
             let file = RFile::new_from_container(self, size as u64, is_compressed, files_are_encrypted, data_pos, timestamp, &path)?;
-            self.insert(file)?;
+            self.insert_index_entry(file, &mut index_paths)?;
 
             // Then we move our data position.
             data_pos += u64::from(size);
@@ -142,7 +149,9 @@ impl Pack {
                 // This unwrap is actually safe.
                 let mut data = file.encode(extra_data, false, false, true)?.unwrap();
 
-                if self.compress && file.is_compressible() {
+                // Files excluded from compression in the Pack Settings are always written uncompressed.
+                let compress = self.compress && file.is_compressible() && !self.settings.is_compression_excluded(path);
+                if compress {
                     if let Some(sevenzip_exe_path) = sevenzip_exe_path {
                         data = data.compress(sevenzip_exe_path)?;
                     }
@@ -168,15 +177,15 @@ impl Pack {
                     file_index_entry.write_u32(file.timestamp().unwrap_or(0) as u32)?;
                 }
 
-                file_index_entry.write_bool(self.compress && file.is_compressible())?;
+                file_index_entry.write_bool(compress)?;
                 file_index_entry.write_string_u8_0terminated(&path.replace('/', "\\"))?;
                 Ok((file_index_entry, data))
             }).collect::<Result<Vec<(Vec<u8>, Vec<u8>)>>>()?
             .into_par_iter()
             .unzip();
 
-        let files_index = files_index.into_par_iter().flatten().collect::<Vec<_>>();
-        let files_data = files_data.into_par_iter().flatten().collect::<Vec<_>>();
+        let mut files_index = files_index.into_par_iter().flatten().collect::<Vec<_>>();
+        let mut files_data = files_data.into_par_iter().flatten().collect::<Vec<_>>();
 
         // Build the dependencies index on memory. This one is never big, so no need of par_iter.
         let mut dependencies_index = vec![];
@@ -200,11 +209,18 @@ impl Pack {
 
         header.write_u32(self.header.internal_timestamp as u32)?;
         header.write_u32(SUBHEADER_MARK)?;
-        header.write_u32(SUBHEADER_VERSION)?;
+        header.write_u32(self.header.subheader_version)?;
         header.write_u32(self.header.game_version)?;
         header.write_u32(self.header.build_number)?;
-        header.write_string_u8_0padded(&self.header.authoring_tool, 8, false)?;
-        header.write_all(&self.header.extra_subheader_data)?;
+        header.write_string_u8_0padded(&self.header.authoring_tool, AUTHORING_TOOL_SIZE as usize, false)?;
+
+        // The subheader has a fixed size, so make sure the extra data fills exactly its space, even if it was changed.
+        let mut extra_subheader_data = self.header.extra_subheader_data.to_vec();
+        extra_subheader_data.resize(SUBHEADER_EXTRA_DATA_SIZE, 0);
+        header.write_all(&extra_subheader_data)?;
+
+        // Add the padding file last, as it needs to know the size of everything else.
+        self.add_padding(&mut header, &dependencies_index, &mut files_index, &mut files_data)?;
 
         // Finally, write everything in one go.
         buffer.write_all(&header)?;
@@ -15,6 +15,8 @@ use std::fs::File;
 
 use crate::binary::ReadBytes;
 use crate::files::*;
+use crate::files::table::DecodedData;
+use crate::games::supported_games::{KEY_WARHAMMER_2, KEY_WARHAMMER_3, SupportedGames};
 
 #[test]
 fn test_encode_rfile() {
@@ -54,3 +56,387 @@ fn test_encode_rfile() {
     }
 
 }
+
+#[test]
+fn test_csv_loc_round_trip() {
+    let schema = Schema::default();
+    let text = "Value with \"quotes\", commas,\ttabs\nand line jumps.";
+
+    let mut loc = Loc::new(false);
+    let mut row = loc.new_row();
+    row[0] = DecodedData::StringU16("test_key".to_owned());
+    row[1] = DecodedData::StringU16(text.to_owned());
+    row[2] = DecodedData::Boolean(true);
+    loc.set_data(&[row]).unwrap();
+
+    let mut rfile = RFile::new_from_decoded(&RFileDecoded::Loc(loc.clone()), 0, "text/db/test_csv.loc");
+
+    for (delimiter, file_name) in [(b',', "test_encode_comma.csv"), (b';', "test_encode_semicolon.csv")] {
+        let path = PathBuf::from("../test_files").join(file_name);
+        rfile.csv_export_to_path(&path, &schema, delimiter).unwrap();
+        assert_eq!(RFile::csv_detect_delimiter(&path).unwrap(), delimiter);
+
+        let imported = RFile::csv_import_from_path(&path, &schema, None).unwrap();
+        assert_eq!(imported.path_in_container_raw(), "text/db/test_csv.loc");
+
+        match imported.decoded().unwrap() {
+            RFileDecoded::Loc(imported) => assert_eq!(imported.data(&None).unwrap(), loc.data(&None).unwrap()),
+            _ => panic!("Incorrect file type"),
+        }
+    }
+}
+
+#[test]
+fn test_tsv_loc_round_trip() {
+    let schema = Schema::default();
+    let text = "Value with \"quotes\", commas,\ttabs\nand line jumps.";
+
+    let mut loc = Loc::new(false);
+    let mut row = loc.new_row();
+    row[0] = DecodedData::StringU16("test_key".to_owned());
+    row[1] = DecodedData::StringU16(text.to_owned());
+    row[2] = DecodedData::Boolean(true);
+    loc.set_data(&[row]).unwrap();
+
+    let mut before = vec![];
+    loc.encode(&mut before, &None).unwrap();
+
+    let path = PathBuf::from("../test_files/test_encode_round_trip.tsv");
+    let mut rfile = RFile::new_from_decoded(&RFileDecoded::Loc(loc), 0, "text/db/test_tsv.loc");
+    rfile.tsv_export_to_path(&path, &schema).unwrap();
+
+    // Line jumps and tabs are escaped in the TSV, so the file must still have just the header, metadata and row lines.
+    assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 3);
+
+    let mut imported = RFile::tsv_import_from_path(&path, &schema).unwrap();
+    let mut after = vec![];
+    match imported.decoded_mut().unwrap() {
+        RFileDecoded::Loc(imported) => imported.encode(&mut after, &None).unwrap(),
+        _ => panic!("Incorrect file type"),
+    }
+
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_merge_tables() {
+    use std::collections::BTreeMap;
+    use crate::schema::{Definition, Field, FieldType};
+
+    let fields = [
+        Field::new("key".to_owned(), FieldType::StringU8, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+        Field::new("value".to_owned(), FieldType::I32, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+    ];
+    let definition_v1 = Definition::new_with_fields(1, &fields, &[]);
+    let definition_v2 = Definition::new_with_fields(2, &fields, &[]);
+
+    let table_file = |definition: &Definition, path: &str, rows: &[(&str, i32)]| {
+        let mut table = DB::new(definition, None, "units_tables", false);
+        let rows = rows.iter().map(|(key, value)| vec![DecodedData::StringU8(key.to_string()), DecodedData::I32(*value)]).collect::<Vec<_>>();
+        table.set_data(None, &rows).unwrap();
+        RFile::new_from_decoded(&RFileDecoded::DB(table), 0, path)
+    };
+
+    let file_1 = table_file(&definition_v1, "db/units_tables/a", &[("unit_a", 1), ("unit_b", 2)]);
+    let file_2 = table_file(&definition_v1, "db/units_tables/b", &[("unit_b", 3), ("unit_c", 4), ("unit_a", 1)]);
+    let file_3 = table_file(&definition_v2, "db/units_tables/c", &[("unit_d", 5)]);
+
+    // Identical rows are not conflicts, but rows with the same key and different data are.
+    let conflicts = RFile::merge_conflicts(&[&file_1, &file_2]).unwrap();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].key(), "unit_b");
+    assert_eq!(conflicts[0].rows().iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>(), vec!["db/units_tables/a", "db/units_tables/b"]);
+
+    let merged = RFile::merge(&[&file_1, &file_2], "db/units_tables/merged").unwrap();
+    match merged.decoded().unwrap() {
+        RFileDecoded::DB(table) => assert_eq!(table.data(&None).unwrap().len(), 5),
+        _ => panic!("Incorrect file type"),
+    }
+
+    // Tables with different versions cannot be merged, and the error must point to the mismatching ones.
+    match RFile::merge(&[&file_1, &file_2, &file_3], "db/units_tables/merged") {
+        Err(RLibError::RFileMergeTablesDifferentDefinitions(first, mismatches)) => {
+            assert_eq!(first, "db/units_tables/a");
+            assert_eq!(mismatches, "db/units_tables/c");
+        }
+        _ => panic!("Merge of tables with different versions didn't fail as expected."),
+    }
+    assert!(RFile::merge_conflicts(&[&file_1, &file_3]).is_err());
+}
+
+#[test]
+fn test_tsv_import_to_self() {
+    use std::collections::BTreeMap;
+    use std::fs::{read_to_string, write};
+    use crate::schema::{Definition, Field, FieldType};
+
+    let fields = [
+        Field::new("key".to_owned(), FieldType::StringU8, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+        Field::new("value".to_owned(), FieldType::I32, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+    ];
+    let definition = Definition::new_with_fields(1, &fields, &[]);
+    let mut schema = Schema::default();
+    schema.add_definition("units_tables", &definition);
+
+    let mut table = DB::new(&definition, None, "units_tables", false);
+    table.set_data(None, &[vec![DecodedData::StringU8("unit_a".to_owned()), DecodedData::I32(1)]]).unwrap();
+    let mut rfile = RFile::new_from_decoded(&RFileDecoded::DB(table), 0, "db/units_tables/test");
+
+    let path = PathBuf::from("../test_files/test_tsv_import_to_self.tsv");
+    rfile.tsv_export_to_path(&path, &schema).unwrap();
+
+    // Edit the file like an external program would, and get the edited data back.
+    let tsv = read_to_string(&path).unwrap();
+    write(&path, tsv.replace("unit_a\t1", "unit_a\t5")).unwrap();
+    rfile.tsv_import_to_self(&path, &schema).unwrap();
+    match rfile.decoded().unwrap() {
+        RFileDecoded::DB(table) => assert_eq!(table.data(&None).unwrap()[0][1], DecodedData::I32(5)),
+        _ => panic!("Incorrect file type"),
+    }
+
+    // Invalid values must be reported with their line and column in the TSV.
+    write(&path, tsv.replace("unit_a\t1", "unit_a\tfive")).unwrap();
+    match rfile.tsv_import_to_self(&path, &schema) {
+        Err(RLibError::ImportTSVIncorrectLine(line, column)) => assert_eq!((line, column), (3, 2)),
+        _ => panic!("Import of an invalid TSV didn't fail as expected."),
+    }
+
+    // Tables from a different table cannot be imported.
+    let mut other = RFile::new_from_decoded(&RFileDecoded::DB(DB::new(&definition, None, "land_units_tables", false)), 0, "db/land_units_tables/test");
+    write(&path, &tsv).unwrap();
+    assert!(matches!(other.tsv_import_to_self(&path, &schema), Err(RLibError::ImportTSVWrongTypeTable)));
+}
+
+#[test]
+fn test_data_hash() {
+    let rfile_1 = RFile::new_from_vec(b"Data.", FileType::Text, 0, "text/test_1.txt");
+    let rfile_2 = RFile::new_from_vec(b"Data.", FileType::Text, 0, "text/test_2.txt");
+    let rfile_3 = RFile::new_from_vec(b"Other data.", FileType::Text, 0, "text/test_1.txt");
+
+    // Only the data matters, not the path.
+    assert_eq!(rfile_1.data_hash().unwrap(), rfile_2.data_hash().unwrap());
+    assert_ne!(rfile_1.data_hash().unwrap(), rfile_3.data_hash().unwrap());
+
+    let mut decoded = RFile::new_from_vec(b"Data.", FileType::Text, 0, "text/test_1.txt");
+    decoded.decode(&None, true, false).unwrap();
+    assert!(matches!(decoded.data_hash(), Err(RLibError::FileAlreadyDecoded(_))));
+}
+
+#[test]
+fn test_unload() {
+    use std::time::{Duration, SystemTime};
+
+    let folder = std::env::temp_dir().join("rpfm_test_unload");
+    let _ = std::fs::remove_dir_all(&folder);
+    std::fs::create_dir_all(&folder).unwrap();
+    let path = folder.join("test.txt");
+    std::fs::write(&path, b"Data.").unwrap();
+
+    // Unchanged files go back to disk.
+    let mut rfile = RFile::new_from_file_path(&path).unwrap();
+    rfile.file_type = FileType::Text;
+    rfile.load().unwrap();
+    assert_eq!(rfile.unload(), 5);
+    assert!(rfile.cached().is_err());
+    assert_eq!(rfile.unload(), 0);
+
+    rfile.decode(&None, true, false).unwrap();
+    assert_eq!(rfile.unload(), 5);
+    assert!(rfile.decoded().is_err());
+
+    // Changed files stay in memory.
+    rfile.load().unwrap();
+    rfile.cached_mut().unwrap().push(b'!');
+    assert_eq!(rfile.unload(), 0);
+    assert_eq!(rfile.cached().unwrap(), b"Data.!");
+
+    // And so do files whose source changed on disk since they were loaded.
+    let mut rfile = RFile::new_from_file_path(&path).unwrap();
+    rfile.load().unwrap();
+    File::options().write(true).open(&path).unwrap().set_modified(SystemTime::now() + Duration::from_secs(10)).unwrap();
+    assert_eq!(rfile.unload(), 0);
+    assert_eq!(rfile.cached().unwrap(), b"Data.");
+
+    // Even if they keep their old modification date.
+    let mut rfile = RFile::new_from_file_path(&path).unwrap();
+    rfile.load().unwrap();
+    let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+    std::fs::write(&path, b"New Data.").unwrap();
+    File::options().write(true).open(&path).unwrap().set_modified(modified).unwrap();
+    assert_eq!(rfile.unload(), 0);
+    assert_eq!(rfile.cached().unwrap(), b"Data.");
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+#[test]
+fn test_data_state() {
+    let folder = std::env::temp_dir().join("rpfm_test_data_state");
+    let _ = std::fs::remove_dir_all(&folder);
+    std::fs::create_dir_all(&folder).unwrap();
+    let path = folder.join("test.txt");
+    std::fs::write(&path, b"Data.").unwrap();
+
+    let mut rfile = RFile::new_from_file_path(&path).unwrap();
+    rfile.file_type = FileType::Text;
+    assert_eq!(rfile.data_state(), RFileDataState::OnDisk);
+    assert_eq!(rfile.source_path(), Some(&*path.to_string_lossy()));
+    assert_eq!(rfile.stored_size(), Some(5));
+    assert_eq!(rfile.data_size(), Some(5));
+    assert!(!rfile.is_stored_compressed());
+
+    // Loaded files still know where they came from.
+    rfile.load().unwrap();
+    assert_eq!(rfile.data_state(), RFileDataState::Cached);
+    assert_eq!(rfile.stored_size(), Some(5));
+    assert!(rfile.container_name().is_none());
+    assert!(rfile.source_path().is_some());
+
+    // Decoded files cannot know their size without encoding them.
+    rfile.decode(&None, true, false).unwrap();
+    assert_eq!(rfile.data_state(), RFileDataState::Decoded);
+    assert_eq!(rfile.data_size(), None);
+    assert_eq!(rfile.stored_size(), Some(5));
+
+    // And once changed, they're no longer tied to their source.
+    rfile.decoded_mut().unwrap();
+    assert!(rfile.source_path().is_none());
+    assert!(rfile.stored_size().is_none());
+
+    std::fs::remove_dir_all(&folder).unwrap();
+}
+
+#[cfg(feature = "integration_assembly_kit")]
+#[test]
+fn test_extract_as_ak_xml() {
+    use std::collections::BTreeMap;
+    use std::fs::read_to_string;
+    use crate::binary::WriteBytes;
+    use crate::files::pack::Pack;
+    use crate::schema::{Definition, Field, FieldType};
+
+    let sequence_fields = [
+        Field::new("name".to_owned(), FieldType::StringU8, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+        Field::new("amount".to_owned(), FieldType::I32, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+    ];
+    let sequence_definition = Definition::new_with_fields(0, &sequence_fields, &[]);
+
+    let fields = [
+        Field::new("key".to_owned(), FieldType::StringU8, true, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+        Field::new("is_enabled".to_owned(), FieldType::Boolean, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+        Field::new("speed".to_owned(), FieldType::F32, false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+        Field::new("costs".to_owned(), FieldType::SequenceU32(Box::new(sequence_definition)), false, None, false, None, None, None, String::new(), 0, 0, BTreeMap::new(), None),
+    ];
+    let definition = Definition::new_with_fields(1, &fields, &[]);
+    let mut schema = Schema::default();
+    schema.add_definition("units_tables", &definition);
+
+    let mut costs = vec![];
+    costs.write_u32(2).unwrap();
+    costs.write_sized_string_u8("gold").unwrap();
+    costs.write_i32(100).unwrap();
+    costs.write_sized_string_u8("food").unwrap();
+    costs.write_i32(-5).unwrap();
+
+    let mut table = DB::new(&definition, None, "units_tables", false);
+    table.set_data(None, &[
+        vec![DecodedData::StringU8("unit_a".to_owned()), DecodedData::Boolean(true), DecodedData::F32(1.5), DecodedData::SequenceU32(costs)],
+        vec![DecodedData::StringU8("Tom & <Jerry>".to_owned()), DecodedData::Boolean(false), DecodedData::F32(0.123456), DecodedData::SequenceU32(vec![])],
+    ]).unwrap();
+
+    let mut pack = Pack::default();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::DB(table), 0, "db/units_tables/test")).unwrap();
+    pack.insert(RFile::new_from_decoded(&RFileDecoded::Text(Default::default()), 0, "text/readme.txt")).unwrap();
+
+    let path = PathBuf::from("../test_files/test_extract_as_ak_xml");
+    pack.extract_as(ContainerPath::Folder(String::new()), &path, true, &Some(schema), ExtractionFormat::AssKitXml).unwrap();
+
+    let expected = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\r\n\
+        <dataroot>\r\n\
+        <units>\r\n\
+        <key>unit_a</key>\r\n\
+        <is_enabled>true</is_enabled>\r\n\
+        <speed>1.5</speed>\r\n\
+        <costs>\r\n\
+        <costs_entry>\r\n<name>gold</name>\r\n<amount>100</amount>\r\n</costs_entry>\r\n\
+        <costs_entry>\r\n<name>food</name>\r\n<amount>-5</amount>\r\n</costs_entry>\r\n\
+        </costs>\r\n\
+        </units>\r\n\
+        <units>\r\n\
+        <key>Tom &amp; &lt;Jerry&gt;</key>\r\n\
+        <is_enabled>false</is_enabled>\r\n\
+        <speed>0.123456</speed>\r\n\
+        <costs>\r\n\
+        </costs>\r\n\
+        </units>\r\n\
+        </dataroot>\r\n";
+
+    // Tables are written as xml next to where their binary file would be. Anything else is extracted as it is.
+    assert_eq!(read_to_string(path.join("db/units_tables/test.xml")).unwrap(), expected);
+    assert!(!path.join("db/units_tables/test").exists());
+    assert!(path.join("text/readme.txt").is_file());
+}
+
+#[test]
+fn test_guess_file_type_for_game() {
+    let games = SupportedGames::default();
+    let warhammer_3 = games.game(KEY_WARHAMMER_3).unwrap();
+    let warhammer_2 = games.game(KEY_WARHAMMER_2).unwrap();
+
+    let mut rfile = RFile::new_from_vec(&[], FileType::Unknown, 0, "shaders/Test.HLSL");
+    rfile.guess_file_type().unwrap();
+    assert_eq!(rfile.file_type, FileType::Unknown);
+
+    rfile.guess_file_type_for_game(warhammer_3).unwrap();
+    assert_eq!(rfile.file_type, FileType::Text);
+
+    // Games without rules for a file must fall back to the generic detection.
+    rfile.guess_file_type_for_game(warhammer_2).unwrap();
+    assert_eq!(rfile.file_type, FileType::Unknown);
+
+    let mut rfile = RFile::new_from_vec(&[], FileType::Unknown, 0, "variantmeshes/wh_variantmodels/test.wsmodel");
+    rfile.guess_file_type_for_game(warhammer_3).unwrap();
+    assert_eq!(rfile.file_type, FileType::Text);
+}
+
+#[test]
+fn test_custom_decoder_registry() {
+    fn decode(data: &[u8]) -> Result<Vec<(String, DecodedData)>> {
+        Ok(vec![("len".to_owned(), DecodedData::I32(data.len() as i32))])
+    }
+
+    fn encode(fields: &[(String, DecodedData)]) -> Result<Vec<u8>> {
+        match fields.first() {
+            Some((_, DecodedData::I32(len))) => Ok(vec![0; *len as usize]),
+            _ => Err(RLibError::CustomDecodingError("missing len field".to_owned())),
+        }
+    }
+
+    let path = "test/file.test_custom_registry";
+    let mut rfile = RFile::new_from_vec(&[0; 4], FileType::Unknown, 0, path);
+    rfile.guess_file_type().unwrap();
+    assert_eq!(rfile.file_type, FileType::Unknown);
+
+    custom::register(custom::CustomDecoder::new("test_custom_registry", &[".test_custom_registry"], decode, encode));
+    rfile.guess_file_type().unwrap();
+    assert_eq!(rfile.file_type, FileType::Custom);
+
+    // Files already flagged as unknown must be decoded with the registered decoder too.
+    let mut rfile = RFile::new_from_vec(&[0; 4], FileType::Unknown, 0, path);
+    match rfile.decode(&None, true, true).unwrap().unwrap() {
+        RFileDecoded::Custom(data) => {
+            assert_eq!(data.decoder(), "test_custom_registry");
+            assert_eq!(data.fields(), &vec![("len".to_owned(), DecodedData::I32(4))]);
+        }
+        _ => panic!("Not decoded with the registered decoder."),
+    }
+    assert_eq!(rfile.file_type, FileType::Custom);
+    assert_eq!(rfile.encode(&None, false, false, true).unwrap().unwrap(), vec![0; 4]);
+
+    assert!(custom::unregister("test_custom_registry"));
+    assert!(!custom::unregister("test_custom_registry"));
+
+    let mut rfile = RFile::new_from_vec(&[0; 4], FileType::Unknown, 0, path);
+    rfile.guess_file_type().unwrap();
+    assert_eq!(rfile.file_type, FileType::Unknown);
+}
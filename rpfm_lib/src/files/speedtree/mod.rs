@@ -0,0 +1,152 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This is a module to read SpeedTree files.
+//!
+//! SpeedTree files (`.spt` and `.sps`) contain the models of trees and other vegetation in older games.
+//! Only their header is decoded, to identify them and to know which textures they use. The rest of the file
+//! (geometry, wind, collisions,...) is kept as it is, so these files are read-only.
+//!
+//! # SpeedTree Structure
+//!
+//! ## Header
+//!
+//! | Bytes | Type                  | Data                                                      |
+//! | ----- | --------------------- | --------------------------------------------------------- |
+//! | 4     | [u32]                 | Length of the signature. Always 12.                       |
+//! | 12    | StringU8              | Signature of the file: `__IdvSpt_02_`.                    |
+//! | 4     | [u32]                 | Version of the file.                                      |
+//! | 12    | 3 [f32]               | Minimum point of the bounding box (X, Y, Z).              |
+//! | 12    | 3 [f32]               | Maximum point of the bounding box (X, Y, Z).              |
+//! | 4     | [u32]                 | Amount of levels of detail.                               |
+//! | 4     | [u32]                 | Amount of textures.                                       |
+//! | *     | Sized StringU8 List   | Paths of the textures used by the model.                  |
+//!
+//! ## Data
+//!
+//! | Bytes | Type     | Data                                                |
+//! | ----- | -------- | --------------------------------------------------- |
+//! | *     | &\[[u8]\] | Rest of the model. Not decoded, kept as it is.     |
+
+use getset::*;
+use serde_derive::{Serialize, Deserialize};
+
+use std::io::Cursor;
+
+use crate::binary::{ReadBytes, WriteBytes};
+use crate::error::{RLibError, Result};
+use crate::files::{DecodeableExtraData, Decodeable, EncodeableExtraData, Encodeable};
+
+/// Signature/Magic Numbers/Whatever of a SpeedTree.
+const SIGNATURE: &str = "__IdvSpt_02_";
+
+/// Extensions used by SpeedTree files.
+pub const EXTENSIONS: [&str; 2] = [".spt", ".sps"];
+
+#[cfg(test)] mod speedtree_test;
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This holds the header of a SpeedTree file, and its raw data.
+#[derive(PartialEq, Clone, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct SpeedTree {
+
+    /// Version of the SpeedTree.
+    version: u32,
+
+    /// Minimum point of the bounding box of the model.
+    bounding_box_min: [f32; 3],
+
+    /// Maximum point of the bounding box of the model.
+    bounding_box_max: [f32; 3],
+
+    /// Amount of levels of detail of the model.
+    lod_count: u32,
+
+    /// Paths of the textures used by the model, as they're in the file.
+    textures: Vec<String>,
+
+    /// Raw data of the whole file, so we can write it back as it was.
+    #[getset(skip)]
+    #[serde(skip)]
+    data: Vec<u8>,
+}
+
+//---------------------------------------------------------------------------//
+//                      Implementation of SpeedTree
+//---------------------------------------------------------------------------//
+
+/// Implementation of `SpeedTree`.
+impl SpeedTree {
+
+    /// This function returns if the provided data starts with a valid SpeedTree header.
+    pub fn is_speedtree(data: &[u8]) -> bool {
+        Self::decode(&mut Cursor::new(data), &None).is_ok()
+    }
+
+    /// This function returns the paths of the textures used by the model, relative to the root of the Pack.
+    ///
+    /// Textures without folder are relative to the folder of the SpeedTree file, whose path is the one provided.
+    pub fn texture_paths(&self, path: &str) -> Vec<String> {
+        let folder = path.rsplit_once('/').map(|(folder, _)| folder).unwrap_or_default();
+        self.textures.iter()
+            .map(|texture| texture.replace('\\', "/"))
+            .map(|texture| if texture.contains('/') || folder.is_empty() {
+                texture
+            } else {
+                format!("{}/{}", folder, texture)
+            })
+            .collect()
+    }
+}
+
+impl Decodeable for SpeedTree {
+
+    fn decode<R: ReadBytes>(data: &mut R, _extra_data: &Option<DecodeableExtraData>) -> Result<Self> {
+        let len = data.len()?;
+        let raw_data = data.read_slice(len as usize, false)?;
+        let mut header = Cursor::new(&raw_data);
+
+        if header.read_u32().ok() != Some(SIGNATURE.len() as u32) || header.read_string_u8(SIGNATURE.len()).ok().as_deref() != Some(SIGNATURE) {
+            return Err(RLibError::DecodingSpeedTreeNotASpeedTree);
+        }
+
+        let version = header.read_u32()?;
+        let bounding_box_min = [header.read_f32()?, header.read_f32()?, header.read_f32()?];
+        let bounding_box_max = [header.read_f32()?, header.read_f32()?, header.read_f32()?];
+        let lod_count = header.read_u32()?;
+
+        // Do not preallocate the textures, as a corrupted count can be anything.
+        let textures_count = header.read_u32()?;
+        let mut textures = vec![];
+        for _ in 0..textures_count {
+            textures.push(header.read_sized_string_u8()?);
+        }
+
+        Ok(Self {
+            version,
+            bounding_box_min,
+            bounding_box_max,
+            lod_count,
+            textures,
+            data: raw_data,
+        })
+    }
+}
+
+impl Encodeable for SpeedTree {
+
+    fn encode<W: WriteBytes>(&mut self, buffer: &mut W, _extra_data: &Option<EncodeableExtraData>) -> Result<()> {
+        buffer.write_all(&self.data).map_err(From::from)
+    }
+}
@@ -0,0 +1,90 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for decoding/encoding `SpeedTree` files.
+
+use std::io::Cursor;
+
+use crate::binary::WriteBytes;
+use crate::files::*;
+
+use super::{SIGNATURE, SpeedTree};
+
+/// This function builds a small SpeedTree with a couple of textures and some trailing data.
+fn speedtree_data() -> Vec<u8> {
+    let mut data = vec![];
+    data.write_u32(SIGNATURE.len() as u32).unwrap();
+    data.write_string_u8(SIGNATURE).unwrap();
+    data.write_u32(4).unwrap();
+    for value in [-1.0, 0.0, -2.5, 1.0, 12.0, 2.5] {
+        data.write_f32(value).unwrap();
+    }
+    data.write_u32(3).unwrap();
+    data.write_u32(2).unwrap();
+    data.write_sized_string_u8("oak_bark.dds").unwrap();
+    data.write_sized_string_u8("variantmeshes\\textures\\oak_leaves.dds").unwrap();
+    data.write_all(&[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+    data
+}
+
+#[test]
+fn test_encode_speedtree() {
+    let before = speedtree_data();
+    let mut data = SpeedTree::decode(&mut Cursor::new(&before), &None).unwrap();
+
+    assert_eq!(data.version(), &4);
+    assert_eq!(data.bounding_box_min(), &[-1.0, 0.0, -2.5]);
+    assert_eq!(data.bounding_box_max(), &[1.0, 12.0, 2.5]);
+    assert_eq!(data.lod_count(), &3);
+    assert_eq!(data.textures().len(), 2);
+
+    let mut after = vec![];
+    data.encode(&mut after, &None).unwrap();
+
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_speedtree_texture_paths() {
+    let data = SpeedTree::decode(&mut Cursor::new(speedtree_data()), &None).unwrap();
+    let paths = data.texture_paths("variantmeshes/trees/oak.spt");
+
+    assert_eq!(paths, vec![
+        "variantmeshes/trees/oak_bark.dds".to_owned(),
+        "variantmeshes/textures/oak_leaves.dds".to_owned(),
+    ]);
+}
+
+#[test]
+fn test_decode_speedtree_corrupted() {
+    let mut data = speedtree_data();
+    data[4] = b'X';
+    assert!(SpeedTree::decode(&mut Cursor::new(&data), &None).is_err());
+    assert!(!SpeedTree::is_speedtree(&data));
+
+    let mut data = speedtree_data();
+    data.truncate(40);
+    assert!(SpeedTree::decode(&mut Cursor::new(&data), &None).is_err());
+
+    assert!(SpeedTree::is_speedtree(&speedtree_data()));
+}
+
+#[test]
+fn test_guess_speedtree_file_type() {
+    let mut file = RFile::new_from_vec(&speedtree_data(), FileType::Unknown, 0, "variantmeshes/trees/oak.spt");
+    file.guess_file_type().unwrap();
+    assert_eq!(file.file_type(), FileType::SpeedTree);
+
+    let mut data = speedtree_data();
+    data[4] = b'X';
+    let mut file = RFile::new_from_vec(&data, FileType::Unknown, 0, "variantmeshes/trees/oak.sps");
+    file.guess_file_type().unwrap();
+    assert_eq!(file.file_type(), FileType::Unknown);
+}
@@ -24,16 +24,19 @@ use serde_derive::{Serialize, Deserialize};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
-use std::io::SeekFrom;
+use std::io::{Cursor, SeekFrom};
 
 use crate::error::{RLibError, Result};
 use crate::binary::{ReadBytes, WriteBytes};
 use crate::schema::*;
-use crate::utils::parse_str_as_bool;
+use crate::utils::{check_size_mismatch, parse_str_as_bool};
 
 mod local;
 #[cfg(feature = "integration_sqlite")] mod sqlite;
 
+/// Separator used between the values of the key columns of a row when joined into a single row key.
+pub const ROW_KEY_SEPARATOR: &str = "| |";
+
 //---------------------------------------------------------------------------//
 //                              Enum & Structs
 //---------------------------------------------------------------------------//
@@ -74,6 +77,18 @@ pub struct SQLData {
     table_unique_id: u64,
 }
 
+/// This struct contains a group of rows found across multiple tables that share the same key values, but not the same data.
+#[derive(Clone, Debug, PartialEq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct MergeConflict {
+
+    /// Values of the key columns of the conflicting rows.
+    key: String,
+
+    /// Path of the table each conflicting row comes from, and the row itself.
+    rows: Vec<(String, Vec<DecodedData>)>,
+}
+
 /// This enum is used to store different types of data in a unified way. Used, for example, to store the data from each field in a DB Table.
 ///
 /// NOTE: `Sequence` it's a recursive type. A Sequence/List means you got a repeated sequence of fields
@@ -457,6 +472,11 @@ impl Table {
         &self.definition_patch
     }
 
+    /// This function replaces the definition patches of this Table with the ones provided.
+    pub fn set_patches(&mut self, definition_patch: &DefinitionPatch) {
+        self.definition_patch = definition_patch.clone();
+    }
+
     /// This function returns a mutable reference to the data of the table.
     ///
     /// Note that using this makes you responsible of keeping the structure of the table "valid".
@@ -479,19 +499,26 @@ impl Table {
 
         // It's simple: we compare both schemas, and get the original and final positions of each column.
         // If a column is new, his original position is -1. If has been removed, his final position is -1.
+        // Columns renamed through the schema patches are treated as the same column.
         let mut positions: Vec<(i32, i32)> = vec![];
         let new_fields_processed = new_definition.fields_processed();
         let old_fields_processed = self.definition.fields_processed();
 
         for (new_pos, new_field) in new_fields_processed.iter().enumerate() {
-            if let Some(old_pos) = old_fields_processed.iter().position(|x| x.name() == new_field.name()) {
+            let old_pos = old_fields_processed.iter().position(|x| x.name() == new_field.name())
+                .or_else(|| {
+                    let old_name = new_field.schema_patch_old_name(Some(&self.definition_patch))?;
+                    old_fields_processed.iter().position(|x| x.name() == old_name)
+                });
+
+            if let Some(old_pos) = old_pos {
                 positions.push((old_pos as i32, new_pos as i32))
             } else { positions.push((-1, new_pos as i32)); }
         }
 
         // Then, for each field in the old definition, check if exists in the new one.
-        for (old_pos, old_field) in old_fields_processed.iter().enumerate() {
-            if !new_fields_processed.iter().any(|x| x.name() == old_field.name()) { positions.push((old_pos as i32, -1)); }
+        for (old_pos, _) in old_fields_processed.iter().enumerate() {
+            if !positions.iter().any(|(x, _)| *x == old_pos as i32) { positions.push((old_pos as i32, -1)); }
         }
 
         // We sort the columns by their destination.
@@ -533,6 +560,7 @@ impl Table {
 
         // Then, we finally replace our definition and our data.
         self.definition = new_definition.clone();
+        self.table_data = TableData::Local(new_entries);
     }
 
     /// This function replaces the data of this table with the one provided.
@@ -584,6 +612,48 @@ impl Table {
         }
     }
 
+    /// This function decodes the data of a sequence field into a table with the provided definition.
+    pub fn from_sequence(data: &DecodedData, definition: &Definition) -> Result<Self> {
+        let (blob, is_u16) = match data {
+            DecodedData::SequenceU16(blob) => (blob, true),
+            DecodedData::SequenceU32(blob) => (blob, false),
+            _ => return Err(RLibError::EncodingTableWrongFieldType(FieldType::from(data).to_string(), "Sequence".to_owned())),
+        };
+
+        // Empty sequences may have no counter at all.
+        let entries = if blob.is_empty() {
+            vec![]
+        } else {
+            let mut data = Cursor::new(blob);
+            let entry_count = if is_u16 { data.read_u16()? as u32 } else { data.read_u32()? };
+            let entries = Self::decode_table(&mut data, definition, Some(entry_count), false)?;
+            check_size_mismatch(data.position() as usize, blob.len())?;
+            entries
+        };
+
+        let mut table = Self::new(definition, None, "", false);
+        table.set_data(None, &entries)?;
+        Ok(table)
+    }
+
+    /// This function encodes this table as the data of a sequence field of the provided type, entry count included.
+    pub fn to_sequence(&self, field_type: &FieldType) -> Result<DecodedData> {
+        let mut data = vec![];
+        match field_type {
+            FieldType::SequenceU16(_) => {
+                data.write_u16(self.len(None)? as u16)?;
+                self.encode(&mut data, &None, &None)?;
+                Ok(DecodedData::SequenceU16(data))
+            }
+            FieldType::SequenceU32(_) => {
+                data.write_u32(self.len(None)? as u32)?;
+                self.encode(&mut data, &None, &None)?;
+                Ok(DecodedData::SequenceU32(data))
+            }
+            _ => Err(RLibError::EncodingTableWrongFieldType("Sequence".to_owned(), field_type.to_string())),
+        }
+    }
+
     pub(crate) fn decode_table<R: ReadBytes>(data: &mut R, definition: &Definition, entry_count: Option<u32>, return_incomplete: bool) -> Result<Vec<Vec<DecodedData>>> {
 
         // If we received an entry count, it's the root table. If not, it's a nested one.
@@ -1261,6 +1331,43 @@ impl Table {
         }
     }
 
+    /// This function returns the key of the provided row, made by joining the values of its key columns with [ROW_KEY_SEPARATOR].
+    ///
+    /// Returns `None` if the definition has no key columns.
+    pub fn row_key(definition: &Definition, row: &[DecodedData]) -> Option<String> {
+        let key_positions = definition.fields_processed()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, field)| if field.is_key() { Some(index) } else { None })
+            .collect::<Vec<_>>();
+
+        Self::row_key_by_positions(&key_positions, row)
+    }
+
+    /// This function returns the keys of all the rows of this table, as returned by [Table::row_key].
+    pub fn row_keys(&self) -> Result<Vec<Option<String>>> {
+        let key_positions = self.definition().fields_processed()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, field)| if field.is_key() { Some(index) } else { None })
+            .collect::<Vec<_>>();
+
+        Ok(self.data(&None)?.iter().map(|row| Self::row_key_by_positions(&key_positions, row)).collect())
+    }
+
+    /// This function returns the key of the provided row, using the values at the provided positions.
+    fn row_key_by_positions(key_positions: &[usize], row: &[DecodedData]) -> Option<String> {
+        if key_positions.is_empty() {
+            None
+        } else {
+            Some(key_positions.iter()
+                .filter_map(|position| row.get(*position))
+                .map(|data| data.data_to_string())
+                .collect::<Vec<_>>()
+                .join(ROW_KEY_SEPARATOR))
+        }
+    }
+
     /// This function tries to find all rows with the provided data, if they exists in this table.
     pub fn rows_containing_data(&self, column_name: &str, data: &str) -> Option<(usize, Vec<usize>)> {
         let mut row_indexes = vec![];
@@ -1281,6 +1388,41 @@ impl Table {
         }
     }
 
+    /// This function returns the rows across the provided tables that share the same key values, but not the same data.
+    ///
+    /// Key columns are taken from the definition of the first table. Tables without key columns never conflict.
+    pub fn merge_conflicts(sources: &[(&str, &Self)]) -> Result<Vec<MergeConflict>> {
+        let key_positions = match sources.first() {
+            Some((_, table)) => table.definition().fields_processed()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, field)| if field.is_key() { Some(index) } else { None })
+                .collect::<Vec<_>>(),
+            None => return Ok(vec![]),
+        };
+
+        if key_positions.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut rows_by_key: BTreeMap<String, Vec<(String, Vec<DecodedData>)>> = BTreeMap::new();
+        for (path, table) in sources {
+            for row in table.data(&None)?.iter() {
+                let key = key_positions.iter()
+                    .map(|position| row[*position].data_to_string())
+                    .collect::<Vec<_>>()
+                    .join("|");
+
+                rows_by_key.entry(key).or_default().push((path.to_string(), row.to_vec()));
+            }
+        }
+
+        Ok(rows_by_key.into_iter()
+            .filter(|(_, rows)| rows.iter().any(|(_, row)| row != &rows[0].1))
+            .map(|(key, rows)| MergeConflict { key, rows })
+            .collect())
+    }
+
     //----------------------------------------------------------------//
     // TSV Functions for tables.
     //----------------------------------------------------------------//
@@ -1338,7 +1480,9 @@ impl Table {
     }
 
     /// This function exports the provided data to a TSV file.
-    pub(crate) fn tsv_export(&self, writer: &mut Writer<File>, table_path: &str) -> Result<()> {
+    ///
+    /// If `escape_special_chars` is true, line jumps and tabs in strings are escaped, for files written without quotes.
+    pub(crate) fn tsv_export(&self, writer: &mut Writer<File>, table_path: &str, escape_special_chars: bool) -> Result<()> {
 
         let fields_processed = self.definition().fields_processed();
         let fields_sorted = self.definition().fields_processed_sorted(true);
@@ -1351,11 +1495,21 @@ impl Table {
         writer.serialize(fields_sorted_properly.iter().map(|(_, field)| field.name()).collect::<Vec<&str>>())?;
         writer.serialize(metadata)?;
 
-        // Then we serialize each entry in the DB Table.
+        // Then we serialize each entry in the DB Table. Files written without quotes need line jumps and tabs in strings
+        // escaped, as the ones set without escaping (like the ones edited by hand) break the file otherwise.
         let entries = self.data(&None)?;
         for entry in &*entries {
             let sorted_entry = fields_sorted_properly.iter()
-                .map(|(index, _)| entry[*index].data_to_string())
+                .map(|(index, _)| {
+                    let data = entry[*index].data_to_string();
+                    if escape_special_chars && data.contains(['\n', '\t']) {
+                        let mut data = data.into_owned();
+                        Self::escape_special_chars(&mut data);
+                        Cow::from(data)
+                    } else {
+                        data
+                    }
+                })
                 .collect::<Vec<Cow<str>>>();
             writer.serialize(sorted_entry)?;
         }
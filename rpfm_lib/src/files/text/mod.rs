@@ -12,8 +12,10 @@
 //!
 //! Text files are any kind of plain-text files, really. Encodings supported by this lib are:
 //! - `ISO-8859-15`
+//! - `Windows-1252`
 //! - `UTF-8`
 //! - `UTF-16` (LittleEndian)
+//! - `UTF-16` (BigEndian)
 //!
 //! Also, the module automatically tries to guess the language of a Text file, so programs
 //! can query the guess language format and apply extended functionality.
@@ -47,7 +49,7 @@
 //! | `.lighting`              | `Xml`    |                                             |
 //! | `.lua`                   | `Lua`    | LUA Script file.                            |
 //! | `.material`              | `Xml`    |                                             |
-//! | `.tai`                   | `Plain`  |                                             |
+//! | `.tai`                   | `Plain`  | Texture atlas index.                        |
 //! | `.technique`             | `Xml`    |                                             |
 //! | `.texture_array`         | `Plain`  | List of Campaign Map textures.              |
 //! | `.tsv`                   | `Plain`  | Normal TSV file.                            |
@@ -59,6 +61,7 @@
 //! | `.xml.shader`            | `Xml`    | Shader setup metadata.                      |
 //! | `.xml.material`          | `Xml`    |                                             |
 
+use encoding_rs::{ISO_8859_15, UTF_16BE, WINDOWS_1252};
 use getset::*;
 use serde_derive::{Serialize, Deserialize};
 
@@ -76,6 +79,9 @@ const BOM_UTF_8: [u8;3] = [0xEF,0xBB,0xBF];
 /// UTF-16 BOM (Byte Order Mark), Little Endian.
 const BOM_UTF_16_LE: [u8;2] = [0xFF,0xFE];
 
+/// UTF-16 BOM (Byte Order Mark), Big Endian.
+const BOM_UTF_16_BE: [u8;2] = [0xFE,0xFF];
+
 /// List of extensions we recognize as `Text` files, with their respective known format.
 pub const EXTENSIONS: [(&str, TextFormat); 35] = [
     (".battle_speech_camera", TextFormat::Plain),
@@ -143,6 +149,8 @@ pub enum Encoding {
     Utf8,
     Utf8Bom,
     Utf16Le,
+    Utf16Be,
+    Windows1252,
 }
 
 /// This enum represents the formats we know.
@@ -159,6 +167,17 @@ pub enum TextFormat {
     Xml,
 }
 
+/// This enum represents the text formats consisting of a list of references to other files, one per line.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum PathListFormat {
+
+    /// Texture atlas index. Each line is a frame, which references the atlas texture containing it.
+    Tai,
+
+    /// Texture array. Each line is the path of a texture of the array.
+    TextureArray,
+}
+
 //---------------------------------------------------------------------------//
 //                           Implementation of Text
 //---------------------------------------------------------------------------//
@@ -181,8 +200,89 @@ impl Default for TextFormat {
     }
 }
 
+impl PathListFormat {
+
+    /// This function returns the path list format of the file with the provided path, if it's a path list.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let path = path.to_lowercase();
+        if path.ends_with(".tai") {
+            Some(Self::Tai)
+        } else if path.ends_with(".texture_array") {
+            Some(Self::TextureArray)
+        } else {
+            None
+        }
+    }
+}
+
 impl Text {
 
+    /// This function changes the encoding of the file, making sure its contents can be represented in the new encoding.
+    ///
+    /// If they can't, it returns an error with the first character that cannot be represented, and the line it is in.
+    pub fn set_encoding_checked(&mut self, encoding: Encoding) -> Result<()> {
+        self.check_representable(encoding)?;
+        self.encoding = encoding;
+        Ok(())
+    }
+
+    /// This function checks if the contents of the file can be represented in the provided encoding.
+    ///
+    /// If they can't, it returns an error with the first character that cannot be represented, and the line it is in.
+    fn check_representable(&self, encoding: Encoding) -> Result<()> {
+        let encoder = match encoding {
+            Encoding::Iso8859_1 => Some(ISO_8859_15),
+            Encoding::Windows1252 => Some(WINDOWS_1252),
+
+            // Unicode encodings can represent anything we can have in a String.
+            Encoding::Utf8 |
+            Encoding::Utf8Bom |
+            Encoding::Utf16Le |
+            Encoding::Utf16Be => None,
+        };
+
+        if let Some(encoder) = encoder {
+            if encoder.encode(&self.contents).2 {
+                let mut buffer = [0; 4];
+                for (index, line) in self.contents.lines().enumerate() {
+                    if let Some(character) = line.chars().find(|character| encoder.encode(character.encode_utf8(&mut buffer)).2) {
+                        return Err(RLibError::EncodingTextUnrepresentableCharacter(encoding, character, index + 1));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the paths referenced by this file, if it's a path list with the provided format.
+    ///
+    /// Each path comes with the number of the line it's in, starting at 1. Empty lines and comments (lines starting with `#` or `//`)
+    /// are skipped, and backslashes are replaced with forward slashes, so the paths can be compared with the ones in a Pack.
+    pub fn path_list(&self, format: PathListFormat) -> Vec<(usize, String)> {
+        self.contents.lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+                    return None;
+                }
+
+                let path = match format {
+
+                    // Tai lines are "frame_name<tabs>atlas_path, atlas_index, type, offsets...". We only care about the atlas.
+                    PathListFormat::Tai => line.split_once(char::is_whitespace)
+                        .and_then(|(_, data)| data.split(',').next())
+                        .map(|path| path.trim())
+                        .unwrap_or_default(),
+                    PathListFormat::TextureArray => line,
+                };
+
+                Some((index + 1, path.replace('\\', "/")))
+            })
+            .collect()
+    }
+
     pub fn detect_encoding<R: ReadBytes>(data: &mut R) -> Result<Encoding> {
         let len = data.len()?;
 
@@ -195,6 +295,17 @@ impl Text {
             data.seek(SeekFrom::Start(2))?;
             return Ok(Encoding::Utf16Le)
         }
+        else if len > 1 && data.read_slice(2, true)? == BOM_UTF_16_BE {
+
+            // UTF-16 data always comes in pairs of bytes. If we have an odd amount, this is not a valid UTF-16 file.
+            if len % 2 != 0 {
+                data.seek(SeekFrom::Start(0))?;
+                return Err(RLibError::DecodingTextUnsupportedEncodingOrNotATextFile);
+            }
+
+            data.seek(SeekFrom::Start(2))?;
+            return Ok(Encoding::Utf16Be)
+        }
 
         // If no BOM is found, we assume UTF-8 if it decodes properly.
         else {
@@ -204,7 +315,14 @@ impl Text {
                 return Ok(Encoding::Utf8)
             }
 
+            // Windows-1252 and ISO-8859-1 only differ in the 0x80-0x9F range: control characters in ISO,
+            // printable characters (quotes, euro sign,...) in Windows-1252. If any of these is used, it's Windows-1252.
             data.seek(SeekFrom::Start(0))?;
+            let raw_data = data.read_slice(len as usize, true)?;
+            if raw_data.iter().any(|byte| (0x80..=0x9F).contains(byte)) {
+                return Ok(Encoding::Windows1252)
+            }
+
             let iso_8859_1_string = data.read_string_u8_iso_8859_15(len as usize);
             if iso_8859_1_string.is_ok() {
                 data.seek(SeekFrom::Start(0))?;
@@ -237,15 +355,33 @@ impl Decodeable for Text {
                 let curr_pos = data.stream_position()?;
                 data.read_string_u16((len - curr_pos) as usize)
                     .map_err(|_| RLibError::DecodingTextUnsupportedEncodingOrNotATextFile)?
-            }
+            },
+            Encoding::Utf16Be => {
+                let curr_pos = data.stream_position()?;
+                let raw_data = data.read_slice((len - curr_pos) as usize, false)?;
+                let (contents, had_errors) = UTF_16BE.decode_without_bom_handling(&raw_data);
+                if had_errors {
+                    return Err(RLibError::DecodingTextUnsupportedEncodingOrNotATextFile);
+                }
+
+                contents.to_string()
+            },
+            Encoding::Windows1252 => {
+                let raw_data = data.read_slice(len as usize, false)?;
+                WINDOWS_1252.decode_without_bom_handling(&raw_data).0.to_string()
+            },
         };
 
-        // Try to get the format of the file.
+        // Try to get the format of the file. Game-specific formats take priority over the generic ones.
         let format = match extra_data {
             Some(extra_data) => match extra_data.file_name {
                 Some(file_name) => {
-                    match EXTENSIONS.iter().find_map(|(extension, format)| if file_name.ends_with(extension) { Some(format) } else { None }) {
-                        Some(format) => *format,
+                    let game_format = extra_data.game_info
+                        .and_then(|game_info| game_info.file_type_override(file_name))
+                        .and_then(|file_type_override| *file_type_override.text_format());
+
+                    match game_format.or_else(|| EXTENSIONS.iter().find_map(|(extension, format)| if file_name.ends_with(extension) { Some(*format) } else { None })) {
+                        Some(format) => format,
                         None => TextFormat::Plain,
                     }
                 }
@@ -279,6 +415,17 @@ impl Encodeable for Text {
                 buffer.write_all(&BOM_UTF_16_LE)?;
                 buffer.write_string_u16(&self.contents)
             },
+            Encoding::Utf16Be => {
+                buffer.write_all(&BOM_UTF_16_BE)?;
+                let data = self.contents.encode_utf16().flat_map(|character| character.to_be_bytes()).collect::<Vec<_>>();
+                buffer.write_all(&data).map_err(From::from)
+            },
+            // The encoder replaces characters it cannot represent with numeric character references, so check them first.
+            Encoding::Windows1252 => {
+                self.check_representable(Encoding::Windows1252)?;
+                let (data, _, _) = WINDOWS_1252.encode(&self.contents);
+                buffer.write_all(&data).map_err(From::from)
+            },
         }
     }
 }
@@ -14,9 +14,11 @@ use std::io::{BufReader, BufWriter, Write};
 use std::fs::File;
 
 use crate::binary::ReadBytes;
+use crate::error::RLibError;
 use crate::files::*;
+use crate::games::supported_games::{KEY_WARHAMMER_2, KEY_WARHAMMER_3, SupportedGames};
 
-use super::Text;
+use super::{Encoding, PathListFormat, Text, TextFormat};
 
 #[test]
 fn test_encode_iso_8859_1() {
@@ -105,3 +107,115 @@ fn test_encode_utf16_le() {
 
     assert_eq!(before, after);
 }
+
+#[test]
+fn test_encode_utf16_be() {
+    let path_1 = "../test_files/test_decode_utf16_be.html";
+    let path_2 = "../test_files/test_encode_utf16_be.html";
+    let mut reader = BufReader::new(File::open(path_1).unwrap());
+
+    let mut decodeable_extra_data = DecodeableExtraData::default();
+    decodeable_extra_data.file_name = Some("test_decode_utf16_be.html");
+
+    let data_len = reader.len().unwrap();
+    let before = reader.read_slice(data_len as usize, true).unwrap();
+    let mut data = Text::decode(&mut reader, &Some(decodeable_extra_data)).unwrap();
+    assert_eq!(data.encoding(), &Encoding::Utf16Be);
+
+    let mut after = vec![];
+    data.encode(&mut after, &None).unwrap();
+
+    let mut writer = BufWriter::new(File::create(path_2).unwrap());
+    writer.write_all(&after).unwrap();
+
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_encode_windows_1252() {
+    let path_1 = "../test_files/test_decode_windows_1252.html";
+    let path_2 = "../test_files/test_encode_windows_1252.html";
+    let mut reader = BufReader::new(File::open(path_1).unwrap());
+
+    let mut decodeable_extra_data = DecodeableExtraData::default();
+    decodeable_extra_data.file_name = Some("test_decode_windows_1252.html");
+
+    let data_len = reader.len().unwrap();
+    let before = reader.read_slice(data_len as usize, true).unwrap();
+    let mut data = Text::decode(&mut reader, &Some(decodeable_extra_data)).unwrap();
+    assert_eq!(data.encoding(), &Encoding::Windows1252);
+
+    let mut after = vec![];
+    data.encode(&mut after, &None).unwrap();
+
+    let mut writer = BufWriter::new(File::create(path_2).unwrap());
+    writer.write_all(&after).unwrap();
+
+    assert_eq!(before, after);
+
+    // Characters the encoding cannot represent must fail, not be silently replaced.
+    data.set_contents("First line\nSecond line with Ω".to_owned());
+    assert!(matches!(data.encode(&mut vec![], &None), Err(RLibError::EncodingTextUnrepresentableCharacter(Encoding::Windows1252, 'Ω', 2))));
+}
+
+#[test]
+fn test_decode_utf16_be_odd_length() {
+    let data = vec![0xFE, 0xFF, 0x00, 0x54, 0x00];
+    let mut reader = std::io::Cursor::new(data);
+
+    assert!(matches!(Text::decode(&mut reader, &None), Err(RLibError::DecodingTextUnsupportedEncodingOrNotATextFile)));
+}
+
+#[test]
+fn test_set_encoding_checked() {
+    let mut data = Text::default();
+    data.set_contents("First line\nSecond line with ñ\nThird line with Ω".to_owned());
+
+    assert!(data.set_encoding_checked(Encoding::Utf16Be).is_ok());
+    assert_eq!(data.encoding(), &Encoding::Utf16Be);
+
+    assert!(matches!(data.set_encoding_checked(Encoding::Iso8859_1), Err(RLibError::EncodingTextUnrepresentableCharacter(Encoding::Iso8859_1, 'Ω', 3))));
+    assert_eq!(data.encoding(), &Encoding::Utf16Be);
+
+    data.set_contents("First line\nSecond line with ñ".to_owned());
+    assert!(data.set_encoding_checked(Encoding::Iso8859_1).is_ok());
+    assert_eq!(data.encoding(), &Encoding::Iso8859_1);
+}
+
+#[test]
+fn test_decode_game_format() {
+    let games = SupportedGames::default();
+    let decode = |file_name, game| {
+        let mut extra_data = DecodeableExtraData::default();
+        extra_data.file_name = Some(file_name);
+        extra_data.game_info = games.game(game);
+
+        let mut reader = std::io::Cursor::new(b"<material/>".to_vec());
+        *Text::decode(&mut reader, &Some(extra_data)).unwrap().format()
+    };
+
+    assert_eq!(decode("test.material", KEY_WARHAMMER_3), TextFormat::Cpp);
+    assert_eq!(decode("test.material", KEY_WARHAMMER_2), TextFormat::Xml);
+    assert_eq!(decode("test.xml.material", KEY_WARHAMMER_3), TextFormat::Xml);
+    assert_eq!(decode("test.twui.xml", KEY_WARHAMMER_3), TextFormat::Xml);
+}
+
+#[test]
+fn test_path_list() {
+    assert_eq!(PathListFormat::from_path("terrain/Campaign.TEXTURE_ARRAY"), Some(PathListFormat::TextureArray));
+    assert_eq!(PathListFormat::from_path("ui/skins/atlas.tai"), Some(PathListFormat::Tai));
+    assert_eq!(PathListFormat::from_path("script/test.lua"), None);
+
+    let mut data = Text::default();
+    data.set_contents("# Comment\r\nterrain\\tiles\\a.dds\n\n  // Another comment\nterrain/tiles/b.dds  \n".to_owned());
+    assert_eq!(data.path_list(PathListFormat::TextureArray), vec![
+        (2, "terrain/tiles/a.dds".to_owned()),
+        (5, "terrain/tiles/b.dds".to_owned()),
+    ]);
+
+    data.set_contents("# atlas\nframe_1.tga\t\tui\\atlas.dds, 0, 2D, 0.000000, 0.000000, 0.0, 0.500000, 0.500000\nbroken_frame\n".to_owned());
+    assert_eq!(data.path_list(PathListFormat::Tai), vec![
+        (2, "ui/atlas.dds".to_owned()),
+        (3, String::new()),
+    ]);
+}
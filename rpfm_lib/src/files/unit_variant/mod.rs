@@ -46,6 +46,7 @@
 //! | -------------------- | ------------------------------------ | ------------------------------------------------------ |
 //! | 528 * Category Count | [Category](#category-structure) List | List of categories in the UnitVariant.                 |
 //! | 1026 * Variant Count | [Variant](#variant-structure) List   | List of variants in the categories of the UnitVariant. |
+//! | *                    | 00-Padding                           | Optional. Kept as it is to not break round-trips.      |
 //!
 //! ### Category Structure
 //!
@@ -71,6 +72,8 @@
 use getset::*;
 use serde_derive::{Serialize, Deserialize};
 
+use std::io::SeekFrom;
+
 use crate::error::{RLibError, Result};
 use crate::binary::{ReadBytes, WriteBytes};
 use crate::files::{DecodeableExtraData, Decodeable, EncodeableExtraData, Encodeable};
@@ -103,6 +106,9 @@ pub struct UnitVariant {
 
     /// Variant categories.
     categories: Vec<Category>,
+
+    /// Amount of 00 bytes after the last variant, if any.
+    padding: u32,
 }
 
 /// This holds a variant category.
@@ -205,6 +211,14 @@ impl Decodeable for UnitVariant {
             }
         }
 
+        // Some files are padded with zeros at the end. Keep track of the padding so we can write it back.
+        let remaining_size = data.len()? - data.stream_position()?;
+        let remaining_data = data.read_slice(remaining_size as usize, true)?;
+        let padding = if remaining_data.iter().all(|byte| *byte == 0) {
+            data.seek(SeekFrom::End(0))?;
+            remaining_data.len() as u32
+        } else { 0 };
+
         // Trigger an error if there's left data on the source.
         check_size_mismatch(data.stream_position()? as usize, data.len()? as usize)?;
 
@@ -212,7 +226,8 @@ impl Decodeable for UnitVariant {
         Ok(Self {
             version,
             unknown_1,
-            categories
+            categories,
+            padding,
         })
     }
 }
@@ -252,6 +267,7 @@ impl Encodeable for UnitVariant {
 
         buffer.write_all(&encoded_categories)?;
         buffer.write_all(&encoded_variants)?;
+        buffer.write_all(&vec![0; self.padding as usize])?;
 
         Ok(())
     }
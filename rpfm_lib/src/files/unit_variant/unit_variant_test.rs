@@ -16,7 +16,7 @@ use std::fs::File;
 use crate::binary::ReadBytes;
 use crate::files::*;
 
-use super::UnitVariant;
+use super::{Category, UnitVariant};
 
 #[test]
 fn test_encode_unit_variant_v1() {
@@ -61,3 +61,46 @@ fn test_encode_unit_variant_v2() {
 
     assert_eq!(before, after);
 }
+
+#[test]
+fn test_encode_unit_variant_padding() {
+    let path = "../test_files/test_decode_v2.unit_variant";
+    let mut before = std::fs::read(path).unwrap();
+    before.extend_from_slice(&[0; 6]);
+
+    let mut reader = std::io::Cursor::new(before.to_vec());
+    let mut data = UnitVariant::decode(&mut reader, &None).unwrap();
+    assert_eq!(data.padding, 6);
+
+    let mut after = vec![];
+    data.encode(&mut after, &None).unwrap();
+    assert_eq!(before, after);
+
+    // Data we don't know about is not padding.
+    before.push(1);
+    let mut reader = std::io::Cursor::new(before);
+    assert!(UnitVariant::decode(&mut reader, &None).is_err());
+}
+
+#[test]
+fn test_encode_unit_variant_edited() {
+    let path = "../test_files/test_decode_v1.unit_variant";
+    let mut reader = BufReader::new(File::open(path).unwrap());
+    let mut data = UnitVariant::decode(&mut reader, &None).unwrap();
+
+    // Add a variant to the first category and a new category, so the indexes of the following ones change.
+    let variant = data.categories[0].variants[0].clone();
+    data.categories[0].variants.push(variant.clone());
+    data.categories.push(Category {
+        name: "new_category".to_owned(),
+        id: 999,
+        variants: vec![variant],
+    });
+
+    let mut encoded = vec![];
+    data.encode(&mut encoded, &None).unwrap();
+
+    let mut reader = std::io::Cursor::new(encoded);
+    let decoded = UnitVariant::decode(&mut reader, &None).unwrap();
+    assert_eq!(data, decoded);
+}
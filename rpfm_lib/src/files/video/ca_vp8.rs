@@ -20,6 +20,10 @@ use super::*;
 
 const HEADER_LENGTH_CAVP8_V0: u16 = 40;
 const HEADER_LENGTH_CAVP8_V1: u16 = 41;
+const HEADER_LENGTH_CAVP8_V2: u16 = 45;
+
+/// Bit of the flags of a frame in CaVp8 v2 files that marks it as a key frame.
+const KEY_FRAME_FLAG_V2: u32 = 1;
 
 //---------------------------------------------------------------------------//
 //                              Implementation
@@ -34,6 +38,10 @@ impl Video {
         let format = SupportedFormats::CaVp8;
 
         let version = data.read_u16()?;
+        if version > 2 {
+            return Err(RLibError::CAVP8UnsupportedVersion(version));
+        }
+
         let mut header_len = data.read_u16()?;
         let codec_four_cc = data.read_string_u8(4)?;
         let width = data.read_u16()?;
@@ -45,10 +53,15 @@ impl Video {
         let _num_frames_plus_1 = data.read_u32()?;
         let _largest_frame_size = data.read_u32()?;
 
-        if version == 1 {
-            let _unknown_1 = data.read_u8()?;
-        } else if version == 0 {
+        let mut audio_tracks = 0;
+        if version == 0 {
             num_frames += 1;
+        } else {
+            let _unknown_1 = data.read_u8()?;
+
+            if version == 2 {
+                audio_tracks = data.read_u32()?;
+            }
         }
 
         // Check the header has been read correctly. Fun fact about the header len: it seems is wrong on v0 files.
@@ -63,6 +76,23 @@ impl Video {
         let frame_data_len = offset_frame_table as u64 - data.stream_position()?;
         let frame_data = data.read_slice(frame_data_len as usize, false)?;
 
+        if version == 2 {
+            let (frame_table, audio_offsets) = Self::read_frame_table_cavp8_v2(data, num_frames, audio_tracks, header_len as u32, offset_frame_table)?;
+
+            return Ok(Self {
+                format,
+                version,
+                codec_four_cc,
+                width,
+                height,
+                num_frames,
+                framerate: 1_000f32 / ms_per_frame,
+                frame_table,
+                frame_data,
+                audio_offsets,
+            })
+        }
+
         // Brace yourself, wonky workaround incoming!
         // There are some files that, for unknown reasons, have 13 bytes instead of 9 in the frame table.
         // I have no freaking idea what's the logic behind 9/13 bytes, so we go with the ghetto solution:
@@ -86,6 +116,7 @@ impl Video {
                 offset: frame_offset,
                 size: frame_size,
                 is_key_frame: frame_is_key_frame,
+                flags: 0,
             };
 
             frame_offset += frame.size;
@@ -115,14 +146,60 @@ impl Video {
             framerate: 1_000f32 / ms_per_frame,
             frame_table,
             frame_data,
+            audio_offsets: vec![],
         })
     }
 
+    /// This function reads the frame table of a CaVp8 v2 file, returning the frames and the audio offsets of each frame.
+    ///
+    /// Frames in v2 files are not always one after another, so their offsets are kept relative to the start of the frame data.
+    fn read_frame_table_cavp8_v2<R: ReadBytes>(data: &mut R, num_frames: u32, audio_tracks: u32, header_len: u32, offset_frame_table: u32) -> Result<(Vec<Frame>, Vec<Vec<u32>>)> {
+        let mut frame_table = Vec::with_capacity(num_frames as usize);
+        let mut audio_offsets = Vec::with_capacity(num_frames as usize);
+
+        for _ in 0..num_frames {
+            let frame_offset_real = data.read_u32()?;
+            let frame_size = data.read_u32()?;
+            let frame_flags = data.read_u32()?;
+
+            let mut frame_audio_offsets = Vec::with_capacity(audio_tracks as usize);
+            for _ in 0..audio_tracks {
+                frame_audio_offsets.push(data.read_u32()?);
+            }
+
+            if frame_offset_real < header_len || frame_offset_real as u64 + frame_size as u64 > offset_frame_table as u64 {
+                return Err(RLibError::DecodingCAVP8IncorrectOrUnknownFrameSize);
+            }
+
+            frame_table.push(Frame {
+                offset: frame_offset_real - header_len,
+                size: frame_size,
+                is_key_frame: frame_flags & KEY_FRAME_FLAG_V2 != 0,
+                flags: frame_flags & !KEY_FRAME_FLAG_V2,
+            });
+
+            // Videos without audio have no audio offsets at all, same as the ones from other versions.
+            if audio_tracks > 0 {
+                audio_offsets.push(frame_audio_offsets);
+            }
+        }
+
+        // Check we decoded the full file correctly.
+        check_size_mismatch(data.stream_position()? as usize, data.len()? as usize)?;
+
+        Ok((frame_table, audio_offsets))
+    }
+
     /// This function writes a `CaVp8` into a buffer in the `CaVp8` format.
     pub(crate) fn save_cavp8<W: WriteBytes>(&self, buffer: &mut W) -> Result<()> {
 
-        let header_lenght = if self.version == 0 { HEADER_LENGTH_CAVP8_V0 } else { HEADER_LENGTH_CAVP8_V1 };
-        let header_lenght_broken = if self.version == 0 { HEADER_LENGTH_CAVP8_V0 - 8 } else { HEADER_LENGTH_CAVP8_V1 };
+        let header_lenght = match self.version {
+            0 => HEADER_LENGTH_CAVP8_V0,
+            1 => HEADER_LENGTH_CAVP8_V1,
+            2 => HEADER_LENGTH_CAVP8_V2,
+            _ => return Err(RLibError::CAVP8UnsupportedVersion(self.version)),
+        };
+        let header_lenght_broken = if self.version == 0 { HEADER_LENGTH_CAVP8_V0 - 8 } else { header_lenght };
 
         buffer.write_string_u8(SIGNATURE_CAVP8)?;
         buffer.write_u16(self.version)?;
@@ -145,14 +222,36 @@ impl Video {
         buffer.write_u32(self.num_frames)?;
         buffer.write_u32(self.frame_table.iter().map(|x| x.size).max().unwrap())?;
 
-        // Final header byte, only in version 1.
-        if self.version == 1 {
+        // Final header byte, only in versions 1 and 2.
+        if self.version != 0 {
             buffer.write_u8(0)?; // _unknown_1: no idea.
         }
 
+        // Audio tracks are only supported in version 2.
+        let audio_tracks = self.audio_tracks();
+        if self.version == 2 {
+            buffer.write_u32(audio_tracks as u32)?;
+        }
+
         // Frame data and table.
         buffer.write_all(&self.frame_data)?;
 
+        if self.version == 2 {
+            for (index, frame) in self.frame_table.iter().enumerate() {
+                buffer.write_u32(header_lenght as u32 + frame.offset)?;
+                buffer.write_u32(frame.size)?;
+                buffer.write_u32(if frame.is_key_frame { frame.flags | KEY_FRAME_FLAG_V2 } else { frame.flags })?;
+
+                if audio_tracks > 0 {
+                    for offset in &self.audio_offsets[index] {
+                        buffer.write_u32(*offset)?;
+                    }
+                }
+            }
+
+            return Ok(());
+        }
+
         let mut offset = header_lenght as u32;
         for frame in &self.frame_table {
             buffer.write_u32(offset)?;
@@ -163,4 +262,14 @@ impl Video {
 
         Ok(())
     }
+
+    /// This function returns the amount of audio tracks of the video.
+    ///
+    /// If the audio offsets don't match the frame table (for example, after importing an IVF file), the video is considered to have no audio.
+    fn audio_tracks(&self) -> usize {
+        match self.audio_offsets.first() {
+            Some(offsets) if self.audio_offsets.len() == self.frame_table.len() && self.audio_offsets.iter().all(|x| x.len() == offsets.len()) => offsets.len(),
+            _ => 0,
+        }
+    }
 }
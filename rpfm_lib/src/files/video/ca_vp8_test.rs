@@ -14,6 +14,7 @@ use std::io::{BufReader, BufWriter, Write};
 use std::fs::File;
 
 use crate::binary::ReadBytes;
+use crate::error::RLibError;
 use crate::files::*;
 
 use super::Video;
@@ -89,3 +90,56 @@ fn test_encode_ca_vp8_v0() {
 
     assert_eq!(before, after);
 }
+
+#[test]
+fn test_decode_ca_vp8_v2() {
+    let path = "../test_files/ca_vp8_v2_decode.ca_vp8";
+    let mut reader = BufReader::new(File::open(path).unwrap());
+
+    let data = Video::decode(&mut reader, &None).unwrap();
+    assert_eq!(data.version(), &2);
+    assert_eq!(data.num_frames(), &12);
+    assert_eq!(data.frame_table().len(), 12);
+    assert_eq!(data.audio_offsets().len(), 12);
+    assert!(data.audio_offsets().iter().all(|offsets| offsets.len() == 2));
+
+    // The key frame bit is not kept in the flags, but the unknown ones are.
+    assert!(data.frame_table()[0].is_key_frame());
+    assert_eq!(data.frame_table()[0].flags(), &0x10);
+    assert!(!data.frame_table()[5].is_key_frame());
+    assert_eq!(data.frame_table()[5].flags(), &0x100);
+
+    // Audio data is between frames, so frames are not one after another.
+    let first = data.frame_table()[0];
+    assert!(data.frame_table()[1].offset() > &(first.offset() + first.size()));
+}
+
+#[test]
+fn test_encode_ca_vp8_v2() {
+    let path_1 = "../test_files/ca_vp8_v2_decode.ca_vp8";
+    let path_2 = "../test_files/ca_vp8_v2_encode.ca_vp8";
+    let mut reader = BufReader::new(File::open(path_1).unwrap());
+
+    let data_len = reader.len().unwrap();
+    let before = reader.read_slice(data_len as usize, true).unwrap();
+    let mut data = Video::decode(&mut reader, &None).unwrap();
+
+    let mut after = vec![];
+    data.encode(&mut after, &None).unwrap();
+
+    let mut file_out = BufWriter::new(File::create(path_2).unwrap());
+    file_out.write_all(&after).unwrap();
+
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_ca_vp8_unsupported_version() {
+    let mut data = std::fs::read("../test_files/ca_vp8_v2_decode.ca_vp8").unwrap();
+    data[4..6].copy_from_slice(&3u16.to_le_bytes());
+    assert!(matches!(Video::decode(&mut std::io::Cursor::new(data), &None), Err(RLibError::CAVP8UnsupportedVersion(3))));
+
+    let mut video = Video::decode(&mut BufReader::new(File::open("../test_files/ca_vp8_v1_decode.ca_vp8").unwrap()), &None).unwrap();
+    video.set_version(7);
+    assert!(matches!(video.encode(&mut vec![], &None), Err(RLibError::CAVP8UnsupportedVersion(7))));
+}
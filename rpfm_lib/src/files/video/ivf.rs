@@ -15,13 +15,16 @@
 use fraction::GenericFraction;
 
 use crate::binary::{ReadBytes, WriteBytes};
-use crate::error::Result;
+use crate::error::{RLibError, Result};
 use crate::utils::*;
 
 use super::*;
 
 const HEADER_LENGTH_IVF: u16 = 32;
 
+/// Length of the header of each frame in IVF format: size (u32) + timestamp (u64).
+const HEADER_LENGTH_FRAME_IVF: u64 = 12;
+
 /// Key frame marker of a frame in IVF format.
 const KEY_FRAME_MARKER: &[u8; 3] = &[0x9D, 0x01, 0x2A];
 
@@ -50,21 +53,39 @@ impl Video {
         // Check we decoded the header correctly.
         check_size_mismatch(data.stream_position()? as usize, header_len as usize)?;
 
+        let data_len = data.len()?;
         let mut frame_table = Vec::with_capacity(num_frames as usize);
         let mut frame_data = vec![];
         let mut frame_offset = 0;
 
-        for _ in 0..num_frames {
+        for index in 0..num_frames {
+
+            // Validate the frame against the remaining data before reading it,
+            // so a corrupted frame table gives a meaningful error instead of a generic one.
+            if data.stream_position()? + HEADER_LENGTH_FRAME_IVF > data_len {
+                return Err(RLibError::DecodingIVFFrameHeaderOutOfBounds(index));
+            }
+
             let size = data.read_u32()?;
             let _timestamp = data.read_u64()?;
 
+            let remaining = data_len - data.stream_position()?;
+            if size as u64 > remaining {
+                return Err(RLibError::DecodingIVFFrameOutOfBounds(index, size, remaining));
+            }
+
+            if size == 0 {
+                return Err(RLibError::DecodingIVFEmptyFrame(index));
+            }
+
             let frame_raw_data = data.read_slice(size as usize, false)?;
-            let is_key_frame = &frame_raw_data[3..6] == KEY_FRAME_MARKER;
+            let is_key_frame = frame_raw_data.get(3..6).map_or(false, |marker| marker == KEY_FRAME_MARKER);
 
             let frame = Frame {
                 offset: frame_offset,
                 size,
                 is_key_frame,
+                flags: 0,
             };
 
             frame_data.extend_from_slice(&frame_raw_data);
@@ -74,7 +95,6 @@ impl Video {
         }
 
         // Check we decoded the full file correctly.
-        let data_len = data.len()?;
         check_size_mismatch(data.stream_position()? as usize, data_len as usize)?;
 
         Ok(Self {
@@ -87,6 +107,7 @@ impl Video {
             framerate: timebase_denominator as f32 / timebase_numerator as f32,
             frame_table,
             frame_data,
+            audio_offsets: vec![],
         })
     }
 
@@ -108,13 +129,12 @@ impl Video {
         buffer.write_u32(self.num_frames)?;
         buffer.write_u32(0)?;
 
-        let mut offset = 0;
+        // Use the offsets of the frames, as CaVp8 v2 files may have audio data between frames.
         for (index, frame) in self.frame_table.iter().enumerate() {
-            let frame_data = &self.frame_data[offset..(offset + frame.size as usize)];
+            let frame_data = &self.frame_data[frame.offset as usize..(frame.offset + frame.size) as usize];
             buffer.write_u32(frame_data.len() as u32)?;
             buffer.write_u64(index as u64)?;
             buffer.write_all(frame_data)?;
-            offset += frame.size as usize;
         }
 
         Ok(())
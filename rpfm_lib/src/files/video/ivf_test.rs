@@ -13,7 +13,7 @@
 use std::io::{BufReader, BufWriter, Write};
 use std::fs::File;
 
-use crate::binary::ReadBytes;
+use crate::binary::{ReadBytes, WriteBytes};
 use crate::files::*;
 
 use super::*;
@@ -77,3 +77,161 @@ fn test_decode_ca_vp8_v0_to_ivf_and_back() {
 
     assert_eq!(before, after);
 }
+
+/// This function builds a small VP8 IVF file in memory with the provided frames.
+fn small_ivf(frames: &[&[u8]]) -> Vec<u8> {
+    let mut ivf = vec![];
+    ivf.write_string_u8("DKIF").unwrap();
+    ivf.write_u16(0).unwrap();
+    ivf.write_u16(32).unwrap();
+    ivf.write_string_u8("VP80").unwrap();
+    ivf.write_u16(64).unwrap();
+    ivf.write_u16(48).unwrap();
+    ivf.write_u32(25).unwrap();
+    ivf.write_u32(1).unwrap();
+    ivf.write_u32(frames.len() as u32).unwrap();
+    ivf.write_u32(0).unwrap();
+
+    for (index, frame) in frames.iter().enumerate() {
+        ivf.write_u32(frame.len() as u32).unwrap();
+        ivf.write_u64(index as u64).unwrap();
+        ivf.write_all(frame).unwrap();
+    }
+
+    ivf
+}
+
+const KEY_FRAME: &[u8] = &[0x50, 0x02, 0x00, 0x9D, 0x01, 0x2A, 0x40, 0x00, 0x30, 0x00];
+const INTER_FRAME_1: &[u8] = &[0x31, 0x01, 0x00, 0x11, 0x22];
+const INTER_FRAME_2: &[u8] = &[0x31, 0x02, 0x00, 0x33, 0x44, 0x55, 0x66];
+
+fn base_video(path: &str) -> Video {
+    let mut reader = BufReader::new(File::open(path).unwrap());
+    Video::decode(&mut reader, &None).unwrap()
+}
+
+#[test]
+fn test_export_and_import_ivf_ca_vp8_v0() {
+    let mut video = base_video("../test_files/ca_vp8_v0_decode.ca_vp8");
+    let before = video.clone();
+
+    let mut ivf = vec![];
+    video.export_ivf(&mut ivf).unwrap();
+    video.import_ivf(&mut Cursor::new(ivf)).unwrap();
+
+    assert_eq!(before, video);
+}
+
+#[test]
+fn test_export_and_import_ivf_ca_vp8_v1() {
+    let mut video = base_video("../test_files/ca_vp8_v1_decode.ca_vp8");
+    let before = video.clone();
+
+    let mut ivf = vec![];
+    video.export_ivf(&mut ivf).unwrap();
+    video.import_ivf(&mut Cursor::new(ivf)).unwrap();
+
+    assert_eq!(before, video);
+}
+
+#[test]
+fn test_export_and_import_ivf_ca_vp8_v2() {
+    let mut video = base_video("../test_files/ca_vp8_v2_decode.ca_vp8");
+    let before = video.clone();
+
+    let mut ivf = vec![];
+    video.export_ivf(&mut ivf).unwrap();
+    video.import_ivf(&mut Cursor::new(ivf)).unwrap();
+
+    // IVF files have no audio, so only the frames themselves survive the trip.
+    assert_eq!(video.version(), &2);
+    assert!(video.audio_offsets().is_empty());
+    assert_eq!(video.frame_table().len(), before.frame_table().len());
+    for (frame, frame_before) in video.frame_table().iter().zip(before.frame_table()) {
+        assert_eq!(frame.size(), frame_before.size());
+        assert_eq!(frame.is_key_frame(), frame_before.is_key_frame());
+
+        let data = &video.frame_data()[*frame.offset() as usize..(frame.offset() + frame.size()) as usize];
+        let data_before = &before.frame_data()[*frame_before.offset() as usize..(frame_before.offset() + frame_before.size()) as usize];
+        assert_eq!(data, data_before);
+    }
+
+    // And it can still be saved as a v2 file, without audio.
+    let mut ca_vp8 = vec![];
+    video.encode(&mut ca_vp8, &None).unwrap();
+    assert_eq!(Video::decode(&mut Cursor::new(ca_vp8), &None).unwrap(), video);
+}
+
+#[test]
+fn test_import_small_ivf_to_ca_vp8_and_back() {
+    let ivf = small_ivf(&[KEY_FRAME, INTER_FRAME_1, INTER_FRAME_2]);
+
+    let mut video = base_video("../test_files/ca_vp8_v0_decode.ca_vp8");
+    video.import_ivf(&mut Cursor::new(ivf.to_vec())).unwrap();
+
+    assert_eq!(video.format(), &SupportedFormats::CaVp8);
+    assert_eq!(video.version(), &0);
+    assert_eq!(video.num_frames(), &3);
+    assert_eq!(video.width(), &64);
+    assert_eq!(video.height(), &48);
+    assert!(video.frame_table()[0].is_key_frame());
+    assert!(!video.frame_table()[1].is_key_frame());
+    assert_eq!(video.frame_table()[2].offset(), &((KEY_FRAME.len() + INTER_FRAME_1.len()) as u32));
+
+    // Save it as CaVp8, decode it again, and export it. It should give us back the same IVF.
+    let mut ca_vp8 = vec![];
+    video.encode(&mut ca_vp8, &None).unwrap();
+
+    let decoded = Video::decode(&mut Cursor::new(ca_vp8), &None).unwrap();
+    assert_eq!(decoded, video);
+
+    let mut after = vec![];
+    decoded.export_ivf(&mut after).unwrap();
+
+    assert_eq!(ivf, after);
+}
+
+#[test]
+fn test_import_ivf_errors() {
+    let mut video = base_video("../test_files/ca_vp8_v1_decode.ca_vp8");
+    let before = video.clone();
+
+    // Not an IVF file.
+    assert!(matches!(video.import_ivf(&mut Cursor::new(b"CAMV0000".to_vec())), Err(RLibError::DecodingIVFNotAnIVFFile)));
+    assert!(matches!(video.import_ivf(&mut Cursor::new(vec![])), Err(RLibError::DecodingIVFNotAnIVFFile)));
+
+    // No frames.
+    let ivf = small_ivf(&[]);
+    assert!(matches!(video.import_ivf(&mut Cursor::new(ivf)), Err(RLibError::DecodingIVFNoFrames)));
+
+    // First frame is not a key frame.
+    let ivf = small_ivf(&[INTER_FRAME_1, KEY_FRAME]);
+    assert!(matches!(video.import_ivf(&mut Cursor::new(ivf)), Err(RLibError::DecodingIVFFirstFrameNotKeyFrame)));
+
+    // Empty frame.
+    let ivf = small_ivf(&[KEY_FRAME, &[]]);
+    assert!(matches!(video.import_ivf(&mut Cursor::new(ivf)), Err(RLibError::DecodingIVFEmptyFrame(1))));
+
+    // Truncated file, cutting the last frame in half.
+    let mut ivf = small_ivf(&[KEY_FRAME, INTER_FRAME_2]);
+    ivf.truncate(ivf.len() - 3);
+    assert!(matches!(video.import_ivf(&mut Cursor::new(ivf)), Err(RLibError::DecodingIVFFrameOutOfBounds(1, 7, 4))));
+
+    // Header reporting more frames than the file has.
+    let mut ivf = small_ivf(&[KEY_FRAME, INTER_FRAME_1]);
+    ivf[24..28].copy_from_slice(&3u32.to_le_bytes());
+    assert!(matches!(video.import_ivf(&mut Cursor::new(ivf)), Err(RLibError::DecodingIVFFrameHeaderOutOfBounds(2))));
+
+    // Frame with a size bigger than the file.
+    let mut ivf = small_ivf(&[KEY_FRAME, INTER_FRAME_1]);
+    ivf[32..36].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(matches!(video.import_ivf(&mut Cursor::new(ivf)), Err(RLibError::DecodingIVFFrameOutOfBounds(0, u32::MAX, _))));
+
+    // Unsupported codec.
+    let mut ivf = small_ivf(&[KEY_FRAME]);
+    ivf[8..12].copy_from_slice(b"VP90");
+    assert!(matches!(video.import_ivf(&mut Cursor::new(ivf)), Err(RLibError::DecodingIVFUnsupportedCodec(_))));
+
+    // Failed imports must not touch the video.
+    assert_eq!(before, video);
+}
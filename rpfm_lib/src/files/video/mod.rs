@@ -11,15 +11,34 @@
 //! CA_VP8 are a custom version of a VP8 video by CA. These files contain only video data, no audio.
 //!
 //! Within this module are functions to convert these files into IVF files, readable by tools such
-//! FFMpeg, VLC or MPV.
+//! FFMpeg, VLC or MPV, and to import IVF files back into CaVp8 ones.
 //!
 //! These files can usually be found under the movies folder, with the extension `.ca_vp8`. This format
 //! is versioned through a `version` number in the file's header. This lib supports has support for reading
-//! and writing the versions 0 and 1.
+//! and writing the versions 0, 1 and 2. Other versions return a [`RLibError::CAVP8UnsupportedVersion`] error.
 //!
 //! # CA_VP8 Structure
 //!
 //! ## Header
+//! ### V2
+//!
+//! | Bytes | Type     | Data                       |
+//! | ----- | -------- | -------------------------- |
+//! | 4     | StringU8 | Signature of the file.     |
+//! | 2     | [u16]    | Version of the file.       |
+//! | 2     | [u16]    | Length of the header.      |
+//! | 4     | StringU8 | FourCC of the video.       |
+//! | 2     | [u16]    | Width of the video.        |
+//! | 2     | [u16]    | Heigth of the video.       |
+//! | 4     | [f32]    | Milliseconds per frame.    |
+//! | 4     | [u32]    | Unknown.                   |
+//! | 4     | [u32]    | Number of frames.          |
+//! | 4     | [u32]    | Offset of the frame table. |
+//! | 4     | [u32]    | Number of frames.          |
+//! | 4     | [u32]    | Largest frame.             |
+//! | 1     | [u8]     | Unknown value.             |
+//! | 4     | [u32]    | Number of audio tracks.    |
+//!
 //! ### V1
 //!
 //! | Bytes | Type     | Data                       |
@@ -57,16 +76,24 @@
 //!
 //! ## Frames Data
 //!
-//! This is valid for versions 0 and 1.
-//!
 //! | Bytes                                | Type                                         | Data                                                           |
 //! | ------------------------------------ | -------------------------------------------- | -------------------------------------------------------------- |
 //! | Frame table's offset - header length | &\[[u8]\]                                    | Frames data, concatenated.                                     |
 //! | Until the end of the file            | &\[[Frame Table Entry](#frame-table-entry)\] | List of entries with each frame metadata (position, size,...). |
 //!
+//! In version 2, the frames data may also contain the data of the audio tracks, so frames are not always one after another.
+//!
 //! ## Frame Table Entry
+//! ### V2
 //!
-//! This is valid for versions 0 and 1.
+//! | Bytes              | Type         | Data                                                                      |
+//! | ------------------ | ------------ | ------------------------------------------------------------------------- |
+//! | 4                  | [u32]        | Offset of the frame from the start of the file.                           |
+//! | 4                  | [u32]        | Size in bytes of the frame's data.                                        |
+//! | 4                  | [u32]        | Flags of the frame. The first bit marks key frames, the rest are unknown. |
+//! | 4 * Audio tracks   | &\[[u32]\]   | Offset of the audio data of each audio track for the frame.               |
+//!
+//! ### V0 and V1
 //!
 //! | Bytes      | Type      | Data                                             |
 //! | ---------- | --------- | ------------------------------------------------ |
@@ -141,6 +168,11 @@ pub struct Video {
 
     /// Raw frame data of the video.
     frame_data: Vec<u8>,
+
+    /// Offsets of the audio data of each audio track, for each frame of the frame table.
+    ///
+    /// Only used by CaVp8 v2. The audio data itself is kept within the frame data.
+    audio_offsets: Vec<Vec<u32>>,
 }
 
 /// This struct contains the information needed to locate an specific frame from a video within the raw frame data.
@@ -156,6 +188,9 @@ pub struct Frame {
 
     /// If the frame is a key frame.
     is_key_frame: bool,
+
+    /// Flags of the frame, except the key frame one. Only used by CaVp8 v2.
+    flags: u32,
 }
 
 /// This enum contains the list of formats this lib supports.
@@ -174,6 +209,48 @@ pub enum SupportedFormats {
 //                              Implementation
 //---------------------------------------------------------------------------//
 
+impl Video {
+
+    /// This function writes the video into the provided buffer as a standard VP8 IVF file,
+    /// playable by tools like VLC, MPV or FFMpeg.
+    ///
+    /// This doesn't change the format the video is saved with inside the Pack.
+    pub fn export_ivf<W: WriteBytes>(&self, buffer: &mut W) -> Result<()> {
+        self.save_ivf(buffer)
+    }
+
+    /// This function replaces the video's contents with the ones of the provided IVF file.
+    ///
+    /// The format and version of the current video are kept, so the imported video is saved in the same
+    /// flavor of CaVp8 (or IVF) the original one was using. The frame table of the IVF file is fully
+    /// validated before replacing anything, so a corrupted file returns an error and leaves the video untouched.
+    ///
+    /// IVF files have no audio, so any audio track the video had is removed.
+    pub fn import_ivf<R: ReadBytes>(&mut self, data: &mut R) -> Result<()> {
+        if data.read_string_u8(4).ok().as_deref() != Some(SIGNATURE_IVF) {
+            return Err(RLibError::DecodingIVFNotAnIVFFile);
+        }
+
+        let mut video = Self::read_ivf(data)?;
+        if video.codec_four_cc != "VP80" {
+            return Err(RLibError::DecodingIVFUnsupportedCodec(video.codec_four_cc));
+        }
+
+        match video.frame_table.first() {
+            Some(frame) => if !frame.is_key_frame {
+                return Err(RLibError::DecodingIVFFirstFrameNotKeyFrame);
+            }
+            None => return Err(RLibError::DecodingIVFNoFrames),
+        }
+
+        video.format = self.format;
+        video.version = self.version;
+        *self = video;
+
+        Ok(())
+    }
+}
+
 impl Decodeable for Video {
 
     fn decode<R: ReadBytes>(data: &mut R, _extra_data: &Option<DecodeableExtraData>) -> Result<Self> {
@@ -0,0 +1,161 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the game-specific logic of `GameInfo`.
+
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+use crate::binary::ReadBytes;
+use crate::error::RLibError;
+
+use super::ModListFormat;
+use super::supported_games::*;
+
+#[test]
+fn test_mod_list_data_user_script() {
+    let games = SupportedGames::default();
+    let game = games.game(KEY_WARHAMMER_2).unwrap();
+    let data_path = Path::new("/games/warhammer_2/data");
+    let mod_paths = vec![
+        data_path.join("my_mod.pack"),
+        data_path.join("my_mod_dependency.pack"),
+    ];
+
+    let data = game.mod_list_data(data_path, &mod_paths).unwrap();
+    assert_eq!(String::from_utf8(data).unwrap(), "mod \"my_mod.pack\";\nmod \"my_mod_dependency.pack\";\n");
+}
+
+#[test]
+fn test_mod_list_data_user_script_utf16() {
+    let games = SupportedGames::default();
+    let game = games.game(KEY_EMPIRE).unwrap();
+    let data_path = Path::new("/games/empire/data");
+    let mod_paths = vec![data_path.join("my_mod.pack")];
+
+    let data = game.mod_list_data(data_path, &mod_paths).unwrap();
+    assert_eq!(&data[..2], &[0xFF, 0xFE]);
+
+    let mut contents = Cursor::new(&data[2..]);
+    assert_eq!(contents.read_string_u16(data.len() - 2).unwrap(), "mod \"my_mod.pack\";\n");
+}
+
+#[test]
+fn test_mod_list_data_user_script_outside_data() {
+    let games = SupportedGames::default();
+    let game = games.game(KEY_ROME_2).unwrap();
+    let data_path = Path::new("/games/rome_2/data");
+    let mod_paths = vec![PathBuf::from("/workshop/content/214950/12345/my_mod.pack")];
+
+    assert!(matches!(game.mod_list_data(data_path, &mod_paths), Err(RLibError::GameModListPackNotInData(_, _))));
+}
+
+#[test]
+fn test_mod_list_data_used_mods() {
+    let games = SupportedGames::default();
+    let game = games.game(KEY_WARHAMMER_3).unwrap();
+    let data_path = Path::new("/games/warhammer_3/data");
+    let mod_paths = vec![
+        data_path.join("my_mod.pack"),
+        PathBuf::from("/workshop/content/1142710/12345/dependency_a.pack"),
+        PathBuf::from("/workshop/content/1142710/12345/dependency_b.pack"),
+        PathBuf::from("/workshop/content/1142710/67890/dependency_c.pack"),
+    ];
+
+    let data = game.mod_list_data(data_path, &mod_paths).unwrap();
+    assert_eq!(String::from_utf8(data).unwrap(), "\
+        add_working_directory \"/workshop/content/1142710/12345\";\n\
+        add_working_directory \"/workshop/content/1142710/67890\";\n\
+        mod \"my_mod.pack\";\n\
+        mod \"dependency_a.pack\";\n\
+        mod \"dependency_b.pack\";\n\
+        mod \"dependency_c.pack\";\n"
+    );
+}
+
+#[test]
+fn test_mod_list_data_unsupported() {
+    let games = SupportedGames::default();
+    let game = games.game(KEY_ARENA).unwrap();
+    let data_path = Path::new("/games/arena/data");
+    let mod_paths = vec![data_path.join("my_mod.pack")];
+
+    assert_eq!(game.mod_list_format(), &ModListFormat::Unsupported);
+    assert!(matches!(game.mod_list_data(data_path, &mod_paths), Err(RLibError::GameModListNotSupported(_))));
+}
+
+#[test]
+fn test_mod_list_format_per_game() {
+    let games = SupportedGames::default();
+    for key in [KEY_WARHAMMER_3, KEY_TROY, KEY_THREE_KINGDOMS] {
+        assert!(matches!(games.game(key).unwrap().mod_list_format(), ModListFormat::UsedMods { .. }), "{}", key);
+    }
+
+    for key in [KEY_WARHAMMER_2, KEY_WARHAMMER, KEY_THRONES_OF_BRITANNIA, KEY_ATTILA, KEY_ROME_2, KEY_SHOGUN_2] {
+        assert!(matches!(games.game(key).unwrap().mod_list_format(), ModListFormat::UserScript { utf16: false, .. }), "{}", key);
+    }
+
+    for key in [KEY_NAPOLEON, KEY_EMPIRE] {
+        assert!(matches!(games.game(key).unwrap().mod_list_format(), ModListFormat::UserScript { utf16: true, .. }), "{}", key);
+    }
+}
+
+#[test]
+fn test_mymod_skeleton_layout_per_game() {
+    let games = SupportedGames::default();
+    let all_components = |key: &str| games.game(key).unwrap().mymod_skeleton().iter().map(|component| component.key().to_owned()).collect::<Vec<_>>();
+
+    let (folders, files) = games.game(KEY_WARHAMMER_3).unwrap().mymod_skeleton_layout("my_mod", &all_components(KEY_WARHAMMER_3));
+    assert_eq!(folders, vec!["db", "script/battle/mod", "script/campaign/mod", "script/frontend/mod", "text/db"]);
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].0, "script/campaign/mod/my_mod.lua");
+    assert!(files[0].1.contains("my_mod: campaign script loaded."));
+
+    for key in [KEY_TROY, KEY_THREE_KINGDOMS] {
+        let (folders, files) = games.game(key).unwrap().mymod_skeleton_layout("my_mod", &all_components(key));
+        assert_eq!(folders, vec!["db", "script/campaign/mod", "text/db"], "{}", key);
+        assert_eq!(files.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>(), vec!["script/campaign/mod/my_mod.lua"], "{}", key);
+    }
+
+    for key in [KEY_WARHAMMER, KEY_ATTILA, KEY_EMPIRE] {
+        let (folders, files) = games.game(key).unwrap().mymod_skeleton_layout("my_mod", &all_components(key));
+        assert_eq!(folders, vec!["db", "text/db"], "{}", key);
+        assert!(files.is_empty(), "{}", key);
+    }
+
+    // Only the chosen components are generated, and unknown ones are ignored.
+    let (folders, files) = games.game(KEY_WARHAMMER_2).unwrap().mymod_skeleton_layout("my_mod", &["battle_scripts".to_owned(), "unknown".to_owned()]);
+    assert_eq!(folders, vec!["script/battle/mod"]);
+    assert!(files.is_empty());
+}
+
+#[test]
+fn test_create_mymod_skeleton() {
+    let path = std::env::temp_dir().join("rpfm_test_create_mymod_skeleton");
+    let _ = std::fs::remove_dir_all(&path);
+
+    let games = SupportedGames::default();
+    let game = games.game(KEY_WARHAMMER_3).unwrap();
+    let components = vec!["data".to_owned(), "campaign_scripts".to_owned()];
+    game.create_mymod_skeleton(&path, "my_mod", &components).unwrap();
+
+    assert!(path.join("db").is_dir());
+    assert!(path.join("text/db").is_dir());
+    assert!(!path.join("script/battle/mod").exists());
+
+    // Existing files must not be overwritten.
+    let script_path = path.join("script/campaign/mod/my_mod.lua");
+    assert!(std::fs::read_to_string(&script_path).unwrap().starts_with("-- Campaign script of my_mod."));
+    std::fs::write(&script_path, "-- Edited.").unwrap();
+    game.create_mymod_skeleton(&path, "my_mod", &components).unwrap();
+    assert_eq!(std::fs::read_to_string(&script_path).unwrap(), "-- Edited.");
+
+    std::fs::remove_dir_all(&path).unwrap();
+}
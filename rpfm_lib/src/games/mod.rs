@@ -17,13 +17,15 @@ Module that contains the GameInfo definition and stuff related with it.
 use std::collections::HashMap;
 use std::{fmt, fmt::Display};
 use std::fs::{DirBuilder, File};
-use std::io::{BufReader, Read};
+use std::io::{BufReader, Read, Write};
 use std::path::{Path, PathBuf};
 
 use getset::*;
 #[cfg(feature = "integration_log")] use log::warn;
 
+use crate::binary::WriteBytes;
 use crate::error::{RLibError, Result};
+use crate::files::{FileType, text::TextFormat};
 use crate::utils::*;
 
 use self::supported_games::KEY_TROY;
@@ -35,6 +37,7 @@ pub mod supported_games;
 pub mod manifest;
 pub mod pfh_file_type;
 pub mod pfh_version;
+pub mod workshop;
 
 const BRAZILIAN: &str = "br";
 const SIMPLIFIED_CHINESE: &str = "cn";
@@ -55,6 +58,14 @@ pub const LUA_REPO: &str = "https://github.com/chadvandy/tw_autogen";
 pub const LUA_REMOTE: &str = "origin";
 pub const LUA_BRANCH: &str = "main";
 
+/// Extension added to the mod list of a game when we back it up before replacing it.
+pub const MOD_LIST_BACKUP_EXTENSION: &str = "rpfm_backup";
+
+/// UTF-16 BOM (Byte Order Mark), Little Endian.
+const BOM_UTF_16_LE: [u8;2] = [0xFF,0xFE];
+
+#[cfg(test)] mod games_test;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -107,7 +118,74 @@ pub struct GameInfo {
     tool_vars: HashMap<String, String>,
 
     /// Subfolder under Lua Autogen's folder where the files for this game are, if it's supported.
-    lua_autogen_folder: Option<String>
+    lua_autogen_folder: Option<String>,
+
+    /// Game-specific rules for detecting file types. They're checked in order, before the generic detection.
+    file_type_overrides: Vec<FileTypeOverride>,
+
+    /// Maximum amount of textures a `.texture_array` file can reference in this game, if the game uses them.
+    max_texture_array_textures: Option<usize>,
+
+    /// Constraints the textures of this game must follow for the game to read them properly.
+    texture_constraints: TextureConstraints,
+
+    /// How to tell the game which mods to load when it starts.
+    mod_list_format: ModListFormat,
+
+    /// Components of the folder structure new MyMods for this game start with.
+    mymod_skeleton: Vec<MyModSkeletonComponent>,
+}
+
+/// This struct contains a game-specific rule to detect the type of a file by the end of its path.
+///
+/// These rules allow supporting file types only used by some games, or treating a file type differently on specific games.
+#[derive(Getters, Clone, Debug)]
+#[getset(get = "pub")]
+pub struct FileTypeOverride {
+
+    /// End of the path of the files this rule applies to, in lowercase. For example: `.twui.xml`.
+    extension: String,
+
+    /// Type of the files matching this rule.
+    file_type: FileType,
+
+    /// Format of the files matching this rule, if they're text files. If None, the generic format detection is used.
+    text_format: Option<TextFormat>,
+
+    /// Name of the icon used to display the files matching this rule in an UI, if they have their own icon.
+    icon: Option<String>,
+}
+
+/// This struct contains the constraints the DDS textures of a game must follow for the game to read them properly.
+#[derive(Getters, Clone, Debug)]
+#[getset(get = "pub")]
+pub struct TextureConstraints {
+
+    /// If the game can read BC7-compressed textures.
+    supports_bc7: bool,
+
+    /// Folders with the textures of the game, in lowercase and ending in `/`. Only textures in them are checked.
+    texture_folders: Vec<String>,
+
+    /// Folders whose textures must have mipmaps, in lowercase and ending in `/`.
+    mipmapped_folders: Vec<String>,
+}
+
+/// This struct contains a component of the folder structure new MyMods for a game start with, like the folder for campaign scripts.
+///
+/// In both folders and files, `{mod_name}` is replaced with the name of the MyMod.
+#[derive(Getters, Clone, Debug)]
+#[getset(get = "pub")]
+pub struct MyModSkeletonComponent {
+
+    /// Key of the component, used to choose which components to create. For example: `campaign_scripts`.
+    key: String,
+
+    /// Folders of the component, relative to the root of the MyMod and without trailing `/`.
+    folders: Vec<String>,
+
+    /// Starter files of the component, as `(path, contents)`. Paths are relative to the root of the MyMod.
+    files: Vec<(String, String)>,
 }
 
 /// This enum holds the info about each game approach at naming db tables.
@@ -121,6 +199,30 @@ pub enum VanillaDBTableNameLogic {
     DefaultName(String),
 }
 
+/// This enum represents the different ways games read the list of mods they have to load on start.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModListFormat {
+
+    /// The game has no way we know of to be told which mods to load.
+    Unsupported,
+
+    /// The game reads a script from `%APPDATA%/The Creative Assembly/{config_folder}/scripts/{file_name}`,
+    /// with a `mod "name.pack";` line per mod. The script can only load Packs from the data folder.
+    UserScript {
+        config_folder: String,
+        file_name: String,
+
+        /// If the script has to be encoded in UTF-16 LE, with BOM. Otherwise, it's UTF-8.
+        utf16: bool,
+    },
+
+    /// The game reads a file from its own folder, with an `add_working_directory "path";` line per folder
+    /// containing mods outside the data folder, followed by a `mod "name.pack";` line per mod.
+    UsedMods {
+        file_name: String,
+    },
+}
+
 /// This enum represents the different installations of games the game support.
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum InstallType {
@@ -183,6 +285,42 @@ impl Display for InstallType {
     }
 }
 
+impl TextureConstraints {
+
+    /// This function creates the texture constraints of a game. All games use the same texture folders.
+    pub(crate) fn new(supports_bc7: bool) -> Self {
+        Self {
+            supports_bc7,
+            texture_folders: vec!["models/".to_owned(), "terrain/".to_owned(), "ui/".to_owned(), "variantmeshes/".to_owned()],
+            mipmapped_folders: vec!["ui/".to_owned()],
+        }
+    }
+
+    /// This function returns if the file in the provided path is a texture these constraints apply to.
+    pub fn is_texture(&self, path: &str) -> bool {
+        let path = path.to_lowercase();
+        path.ends_with(".dds") && self.texture_folders.iter().any(|folder| path.starts_with(folder))
+    }
+
+    /// This function returns if the texture in the provided path must have mipmaps.
+    pub fn requires_mipmaps(&self, path: &str) -> bool {
+        let path = path.to_lowercase();
+        self.mipmapped_folders.iter().any(|folder| path.starts_with(folder))
+    }
+}
+
+impl MyModSkeletonComponent {
+
+    /// This function creates a component of the MyMod skeleton of a game.
+    pub(crate) fn new(key: &str, folders: &[&str], files: &[(&str, &str)]) -> Self {
+        Self {
+            key: key.to_owned(),
+            folders: folders.iter().map(|folder| folder.to_string()).collect(),
+            files: files.iter().map(|(path, contents)| (path.to_string(), contents.to_string())).collect(),
+        }
+    }
+}
+
 /// Implementation of GameInfo.
 impl GameInfo {
 
@@ -263,6 +401,85 @@ impl GameInfo {
         self.lua_autogen_folder.as_deref()
     }
 
+    /// This function returns this Game's file type detection rules.
+    pub fn file_type_overrides(&self) -> &[FileTypeOverride] {
+        &self.file_type_overrides
+    }
+
+    /// This function returns the maximum amount of textures a `.texture_array` file can reference in this Game, if it uses them.
+    pub fn max_texture_array_textures(&self) -> Option<usize> {
+        self.max_texture_array_textures
+    }
+
+    /// This function returns the constraints the textures of this Game must follow.
+    pub fn texture_constraints(&self) -> &TextureConstraints {
+        &self.texture_constraints
+    }
+
+    /// This function returns how this Game reads the list of mods it has to load.
+    pub fn mod_list_format(&self) -> &ModListFormat {
+        &self.mod_list_format
+    }
+
+    /// This function returns the components of the folder structure new MyMods for this Game start with.
+    pub fn mymod_skeleton(&self) -> &[MyModSkeletonComponent] {
+        &self.mymod_skeleton
+    }
+
+    /// This function returns the folders and starter files of the provided components of this Game's MyMod skeleton, for a MyMod with the provided name.
+    ///
+    /// Keys not matching any component of this Game are ignored. Folders are returned sorted, and files are returned as `(path, contents)`.
+    pub fn mymod_skeleton_layout(&self, mod_name: &str, components: &[String]) -> (Vec<String>, Vec<(String, String)>) {
+        let components = self.mymod_skeleton.iter()
+            .filter(|component| components.contains(&component.key))
+            .collect::<Vec<_>>();
+
+        let mut folders = components.iter()
+            .flat_map(|component| component.folders.iter().map(|folder| folder.replace("{mod_name}", mod_name)))
+            .collect::<Vec<_>>();
+        folders.sort();
+        folders.dedup();
+
+        let files = components.iter()
+            .flat_map(|component| component.files.iter().map(|(path, contents)| (path.replace("{mod_name}", mod_name), contents.replace("{mod_name}", mod_name))))
+            .collect();
+
+        (folders, files)
+    }
+
+    /// This function creates the provided components of this Game's MyMod skeleton in the provided folder.
+    ///
+    /// Starter files that already exist are not overwritten.
+    pub fn create_mymod_skeleton(&self, path: &Path, mod_name: &str, components: &[String]) -> Result<()> {
+        let (folders, files) = self.mymod_skeleton_layout(mod_name, components);
+        for folder in &folders {
+            DirBuilder::new().recursive(true).create(path.join(folder))?;
+        }
+
+        for (file_path, contents) in &files {
+            let file_path = path.join(file_path);
+            if !file_path.exists() {
+                if let Some(parent) = file_path.parent() {
+                    DirBuilder::new().recursive(true).create(parent)?;
+                }
+
+                File::create(&file_path)?.write_all(contents.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the first file type detection rule of this Game matching the provided path, if any.
+    pub fn file_type_override(&self, path: &str) -> Option<&FileTypeOverride> {
+        if self.file_type_overrides.is_empty() {
+            return None;
+        }
+
+        let path = path.to_lowercase();
+        self.file_type_overrides.iter().find(|file_type_override| path.ends_with(&file_type_override.extension))
+    }
+
     //---------------------------------------------------------------------------//
     // Advanced getters.
     //---------------------------------------------------------------------------//
@@ -336,8 +553,8 @@ impl GameInfo {
         Ok(game_path.join(install_data.local_mods_path()))
     }
 
-    /// This function gets the `/mods` path or equivalent of the game selected, if said game it's configured in the settings.
-    pub fn content_packs_paths(&self, game_path: &Path) -> Option<Vec<PathBuf>> {
+    /// This function gets the folder where downloaded mods are stored for the game selected, if said game it's configured in the settings and supports downloaded mods.
+    pub fn content_path(&self, game_path: &Path) -> Option<PathBuf> {
         let install_type = self.install_type(game_path).ok()?;
         let install_data = self.install_data.get(&install_type)?;
         let downloaded_mods_path = install_data.downloaded_mods_path();
@@ -347,7 +564,12 @@ impl GameInfo {
             return None;
         }
 
-        let path = std::fs::canonicalize(game_path.join(downloaded_mods_path)).ok()?;
+        std::fs::canonicalize(game_path.join(downloaded_mods_path)).ok()
+    }
+
+    /// This function gets the `/mods` path or equivalent of the game selected, if said game it's configured in the settings.
+    pub fn content_packs_paths(&self, game_path: &Path) -> Option<Vec<PathBuf>> {
+        let path = self.content_path(game_path)?;
         let mut paths = vec![];
 
         for path in files_from_subdir(&path, true).ok()?.iter() {
@@ -526,6 +748,109 @@ impl GameInfo {
         Some(executable_path)
     }
 
+    /// This function returns the path of the file this Game reads its list of mods from.
+    pub fn mod_list_path(&self, game_path: &Path) -> Result<PathBuf> {
+        match &self.mod_list_format {
+            ModListFormat::Unsupported => Err(RLibError::GameModListNotSupported(self.display_name.to_string())),
+            ModListFormat::UserScript { config_folder, file_name, .. } => {
+                let app_data = std::env::var_os("APPDATA").ok_or_else(|| RLibError::GameModListFolderNotFound(self.display_name.to_string()))?;
+                Ok(PathBuf::from(app_data).join("The Creative Assembly").join(config_folder).join("scripts").join(file_name))
+            }
+            ModListFormat::UsedMods { file_name } => Ok(game_path.join(file_name)),
+        }
+    }
+
+    /// This function generates the contents of the mod list of this Game, telling it to load the provided Packs.
+    ///
+    /// The Packs are loaded in the order provided, with the first one having the highest priority.
+    /// Packs outside the data folder are only supported in games using a `UsedMods` file.
+    pub fn mod_list_data(&self, data_path: &Path, mod_paths: &[PathBuf]) -> Result<Vec<u8>> {
+        let mut lines = vec![];
+        let mut mod_lines = vec![];
+
+        for mod_path in mod_paths {
+            let mod_name = mod_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            let mod_folder = mod_path.parent().unwrap_or_else(|| Path::new(""));
+
+            // Packs in the data folder are loaded by name. Packs elsewhere need their folder to be registered as a working directory.
+            if mod_folder != data_path {
+                match self.mod_list_format {
+                    ModListFormat::UsedMods { .. } => {
+                        let line = format!("add_working_directory \"{}\";", mod_folder.to_string_lossy().replace('\\', "/"));
+                        if !lines.contains(&line) {
+                            lines.push(line);
+                        }
+                    }
+                    _ => return Err(RLibError::GameModListPackNotInData(self.display_name.to_string(), mod_path.to_string_lossy().to_string())),
+                }
+            }
+
+            mod_lines.push(format!("mod \"{}\";", mod_name));
+        }
+
+        lines.append(&mut mod_lines);
+        let mut contents = lines.join("\n");
+        contents.push('\n');
+
+        match self.mod_list_format {
+            ModListFormat::Unsupported => Err(RLibError::GameModListNotSupported(self.display_name.to_string())),
+            ModListFormat::UserScript { utf16: true, .. } => {
+                let mut data = BOM_UTF_16_LE.to_vec();
+                data.write_string_u16(&contents)?;
+                Ok(data)
+            }
+            ModListFormat::UserScript { utf16: false, .. } |
+            ModListFormat::UsedMods { .. } => Ok(contents.into_bytes()),
+        }
+    }
+
+    /// This function writes the mod list of this Game, telling it to load the provided Packs, and returns its path.
+    ///
+    /// The first time the mod list is replaced, the existing one is backed up next to it, so it can be restored with `restore_mod_list`.
+    /// If there was no mod list, an empty backup is created instead.
+    pub fn write_mod_list(&self, game_path: &Path, mod_paths: &[PathBuf]) -> Result<PathBuf> {
+        let data_path = self.data_path(game_path)?;
+        let data = self.mod_list_data(&data_path, mod_paths)?;
+
+        let path = self.mod_list_path(game_path)?;
+        if let Some(parent) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent)?;
+        }
+
+        let backup_path = Self::mod_list_backup_path(&path);
+        if !backup_path.is_file() {
+            if path.is_file() {
+                std::fs::copy(&path, &backup_path)?;
+            } else {
+                File::create(&backup_path)?;
+            }
+        }
+
+        let mut file = File::create(&path)?;
+        file.write_all(&data)?;
+        Ok(path)
+    }
+
+    /// This function restores the mod list of this Game to the one it had before we first replaced it.
+    pub fn restore_mod_list(&self, game_path: &Path) -> Result<()> {
+        let path = self.mod_list_path(game_path)?;
+        let backup_path = Self::mod_list_backup_path(&path);
+        if !backup_path.is_file() {
+            return Err(RLibError::GameModListBackupNotFound(self.display_name.to_string()));
+        }
+
+        std::fs::rename(backup_path, path)?;
+        Ok(())
+    }
+
+    /// This function returns the path of the backup of the provided mod list.
+    fn mod_list_backup_path(path: &Path) -> PathBuf {
+        let mut backup_path = path.as_os_str().to_owned();
+        backup_path.push(".");
+        backup_path.push(MOD_LIST_BACKUP_EXTENSION);
+        PathBuf::from(backup_path)
+    }
+
     /// Check if a specific file is banned.
     pub fn is_file_banned(&self, path: &str) -> bool {
         let path = path.to_lowercase();
@@ -17,7 +17,9 @@ You should have no business here, except for supporting a new game.
 
 use std::collections::HashMap;
 
-use super::{GameInfo, InstallData, InstallType, pfh_file_type::PFHFileType, pfh_version::PFHVersion, VanillaDBTableNameLogic};
+use crate::files::{FileType, text::TextFormat};
+
+use super::{FileTypeOverride, GameInfo, InstallData, InstallType, ModListFormat, MyModSkeletonComponent, pfh_file_type::PFHFileType, pfh_version::PFHVersion, TextureConstraints, VanillaDBTableNameLogic};
 
 // Display Name for all the Supported Games.
 pub const DISPLAY_NAME_WARHAMMER_3: &str = "Warhammer 3";
@@ -47,6 +49,16 @@ pub const KEY_NAPOLEON: &str = "napoleon";
 pub const KEY_EMPIRE: &str = "empire";
 pub const KEY_ARENA: &str = "arena";
 
+/// Maximum amount of textures in a texture array in DirectX 11 games. The game can't load texture arrays bigger than this.
+const MAX_TEXTURE_ARRAY_TEXTURES_DX11: usize = 2048;
+
+/// Starter script for games that load automatically the campaign scripts of mods on their `script/campaign/mod` folder.
+const MYMOD_CAMPAIGN_SCRIPT: &str = "-- Campaign script of {mod_name}. The game loads every script in this folder when a campaign starts.
+cm:add_first_tick_callback(function()
+    out(\"{mod_name}: campaign script loaded.\");
+end);
+";
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -145,6 +157,40 @@ impl Default for SupportedGames {
                 vars
             },
             lua_autogen_folder: Some("wh3".to_owned()),
+            file_type_overrides: vec![
+                FileTypeOverride {
+                    extension: ".twui.xml".to_owned(),
+                    file_type: FileType::Text,
+                    text_format: Some(TextFormat::Xml),
+                    icon: Some("application-x-designer".to_owned()),
+                },
+
+                // This one needs to be before the .material one, or these files will be detected as shaders.
+                FileTypeOverride {
+                    extension: ".xml.material".to_owned(),
+                    file_type: FileType::Text,
+                    text_format: Some(TextFormat::Xml),
+                    icon: None,
+                },
+                FileTypeOverride {
+                    extension: ".material".to_owned(),
+                    file_type: FileType::Text,
+                    text_format: Some(TextFormat::Cpp),
+                    icon: None,
+                },
+                FileTypeOverride {
+                    extension: ".hlsl".to_owned(),
+                    file_type: FileType::Text,
+                    text_format: Some(TextFormat::Cpp),
+                    icon: None,
+                },
+            ],
+            max_texture_array_textures: Some(MAX_TEXTURE_ARRAY_TEXTURES_DX11),
+            texture_constraints: TextureConstraints::new(true),
+            mod_list_format: ModListFormat::UsedMods {
+                file_name: "used_mods.txt".to_owned(),
+            },
+            mymod_skeleton: vec![mymod_skeleton_data(), mymod_skeleton_campaign_scripts(), mymod_skeleton_scripts("battle_scripts", "script/battle/mod"), mymod_skeleton_scripts("frontend_scripts", "script/frontend/mod")],
         });
 
         // Troy
@@ -219,6 +265,13 @@ impl Default for SupportedGames {
                 vars
             },
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: Some(MAX_TEXTURE_ARRAY_TEXTURES_DX11),
+            texture_constraints: TextureConstraints::new(true),
+            mod_list_format: ModListFormat::UsedMods {
+                file_name: "used_mods.txt".to_owned(),
+            },
+            mymod_skeleton: vec![mymod_skeleton_data(), mymod_skeleton_campaign_scripts()],
         });
 
         // Three Kingdoms
@@ -395,6 +448,13 @@ impl Default for SupportedGames {
                 vars
             },
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: Some(MAX_TEXTURE_ARRAY_TEXTURES_DX11),
+            texture_constraints: TextureConstraints::new(true),
+            mod_list_format: ModListFormat::UsedMods {
+                file_name: "used_mods.txt".to_owned(),
+            },
+            mymod_skeleton: vec![mymod_skeleton_data(), mymod_skeleton_campaign_scripts()],
         });
         // Warhammer 2
         game_list.insert(KEY_WARHAMMER_2, GameInfo {
@@ -806,6 +866,15 @@ impl Default for SupportedGames {
                 vars
             },
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: Some(MAX_TEXTURE_ARRAY_TEXTURES_DX11),
+            texture_constraints: TextureConstraints::new(true),
+            mod_list_format: ModListFormat::UserScript {
+                config_folder: "Warhammer2".to_owned(),
+                file_name: "user.script.txt".to_owned(),
+                utf16: false,
+            },
+            mymod_skeleton: vec![mymod_skeleton_data(), mymod_skeleton_campaign_scripts(), mymod_skeleton_scripts("battle_scripts", "script/battle/mod"), mymod_skeleton_scripts("frontend_scripts", "script/frontend/mod")],
         });
 
         // Warhammer
@@ -960,6 +1029,15 @@ impl Default for SupportedGames {
                 vars
             },
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: Some(MAX_TEXTURE_ARRAY_TEXTURES_DX11),
+            texture_constraints: TextureConstraints::new(false),
+            mod_list_format: ModListFormat::UserScript {
+                config_folder: "Warhammer".to_owned(),
+                file_name: "user.script.txt".to_owned(),
+                utf16: false,
+            },
+            mymod_skeleton: vec![mymod_skeleton_data()],
         });
 
         // Thrones of Britannia
@@ -1062,6 +1140,15 @@ impl Default for SupportedGames {
                 vars
             },
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: Some(MAX_TEXTURE_ARRAY_TEXTURES_DX11),
+            texture_constraints: TextureConstraints::new(false),
+            mod_list_format: ModListFormat::UserScript {
+                config_folder: "ThronesOfBritannia".to_owned(),
+                file_name: "user.script.txt".to_owned(),
+                utf16: false,
+            },
+            mymod_skeleton: vec![mymod_skeleton_data()],
         });
 
         // Attila
@@ -1135,6 +1222,15 @@ impl Default for SupportedGames {
                 vars
             },
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: Some(MAX_TEXTURE_ARRAY_TEXTURES_DX11),
+            texture_constraints: TextureConstraints::new(false),
+            mod_list_format: ModListFormat::UserScript {
+                config_folder: "Attila".to_owned(),
+                file_name: "user.script.txt".to_owned(),
+                utf16: false,
+            },
+            mymod_skeleton: vec![mymod_skeleton_data()],
         });
 
         // Rome 2
@@ -1197,6 +1293,15 @@ impl Default for SupportedGames {
                 vars
             },
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: Some(MAX_TEXTURE_ARRAY_TEXTURES_DX11),
+            texture_constraints: TextureConstraints::new(false),
+            mod_list_format: ModListFormat::UserScript {
+                config_folder: "Rome2".to_owned(),
+                file_name: "user.script.txt".to_owned(),
+                utf16: false,
+            },
+            mymod_skeleton: vec![mymod_skeleton_data()],
         });
 
         // Shogun 2
@@ -1271,6 +1376,15 @@ impl Default for SupportedGames {
             },
             tool_vars: HashMap::new(),
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: None,
+            texture_constraints: TextureConstraints::new(false),
+            mod_list_format: ModListFormat::UserScript {
+                config_folder: "Shogun2".to_owned(),
+                file_name: "user.script.txt".to_owned(),
+                utf16: false,
+            },
+            mymod_skeleton: vec![mymod_skeleton_data()],
         });
 
         // Napoleon
@@ -1357,6 +1471,15 @@ impl Default for SupportedGames {
             },
             tool_vars: HashMap::new(),
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: None,
+            texture_constraints: TextureConstraints::new(false),
+            mod_list_format: ModListFormat::UserScript {
+                config_folder: "Napoleon".to_owned(),
+                file_name: "user.napoleon_script.txt".to_owned(),
+                utf16: true,
+            },
+            mymod_skeleton: vec![mymod_skeleton_data()],
         });
 
         // Empire
@@ -1519,6 +1642,15 @@ impl Default for SupportedGames {
             },
             tool_vars: HashMap::new(),
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: None,
+            texture_constraints: TextureConstraints::new(false),
+            mod_list_format: ModListFormat::UserScript {
+                config_folder: "Empire".to_owned(),
+                file_name: "user.empire_script.txt".to_owned(),
+                utf16: true,
+            },
+            mymod_skeleton: vec![mymod_skeleton_data()],
         });
 
         // NOTE: There are things that depend on the order of this list, and this game must ALWAYS be the last one.
@@ -1561,6 +1693,11 @@ impl Default for SupportedGames {
             },
             tool_vars: HashMap::new(),
             lua_autogen_folder: None,
+            file_type_overrides: vec![],
+            max_texture_array_textures: None,
+            texture_constraints: TextureConstraints::new(false),
+            mod_list_format: ModListFormat::Unsupported,
+            mymod_skeleton: vec![],
         });
 
         let order_list = vec![
@@ -1613,3 +1750,18 @@ impl SupportedGames {
         &self.order
     }
 }
+
+/// This function returns the component of the MyMod skeleton with the folders for DB tables and Loc files, common to all games.
+fn mymod_skeleton_data() -> MyModSkeletonComponent {
+    MyModSkeletonComponent::new("data", &["db", "text/db"], &[])
+}
+
+/// This function returns the component of the MyMod skeleton with the campaign scripts folder and a starter script, for games that load them automatically.
+fn mymod_skeleton_campaign_scripts() -> MyModSkeletonComponent {
+    MyModSkeletonComponent::new("campaign_scripts", &["script/campaign/mod"], &[("script/campaign/mod/{mod_name}.lua", MYMOD_CAMPAIGN_SCRIPT)])
+}
+
+/// This function returns a component of the MyMod skeleton with just a scripts folder.
+fn mymod_skeleton_scripts(key: &str, folder: &str) -> MyModSkeletonComponent {
+    MyModSkeletonComponent::new(key, &[folder], &[])
+}
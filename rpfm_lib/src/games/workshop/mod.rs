@@ -0,0 +1,203 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to find the Packs of the Steam Workshop items a game is subscribed to.
+
+Steam stores each subscribed item in `steamapps/workshop/content/<appid>/<item id>/`, and keeps
+the info about them in `steamapps/workshop/appworkshop_<appid>.acf`, a KeyValues text file.
+!*/
+
+use getset::*;
+
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+use crate::error::{RLibError, Result};
+use crate::utils::files_from_subdir;
+use super::GameInfo;
+
+#[cfg(test)]
+mod workshop_test;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct represents a Pack from a subscribed Steam Workshop item.
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct WorkshopPack {
+
+    /// Id of the Workshop item.
+    id: String,
+
+    /// Title of the Workshop item, if we could find it in the local Steam files.
+    title: Option<String>,
+
+    /// Path of the Pack on disk.
+    path: PathBuf,
+}
+
+/// This enum represents a value of a KeyValues (acf/vdf) file.
+#[derive(Clone, Debug, PartialEq)]
+enum KeyValue {
+    String(String),
+    Map(Vec<(String, KeyValue)>),
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl WorkshopPack {
+
+    /// This function returns the Packs of the Workshop items the provided game is subscribed to, sorted by title and id.
+    ///
+    /// Steam doesn't always store the titles of the items locally. If we can't find one, or the acf file is missing or broken, the title is left empty.
+    pub fn read_from_game_path(game: &GameInfo, game_path: &Path) -> Result<Vec<Self>> {
+        let content_path = game.content_path(game_path).ok_or(RLibError::DownloadedModsFolderNotFound)?;
+        Self::read_from_content_path(&content_path)
+    }
+
+    /// This function returns the Packs of the Workshop items in the provided `steamapps/workshop/content/<appid>` folder, sorted by title and id.
+    fn read_from_content_path(content_path: &Path) -> Result<Vec<Self>> {
+        let app_id = content_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+
+        let titles = content_path.parent()
+            .and_then(|path| path.parent())
+            .and_then(|path| read_to_string(path.join(format!("appworkshop_{app_id}.acf"))).ok())
+            .map(|data| acf_titles(&data))
+            .unwrap_or_default();
+
+        let mut packs = vec![];
+        for item_path in content_path.read_dir()?.flatten().map(|entry| entry.path()).filter(|path| path.is_dir()) {
+            let id = item_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            if id.is_empty() || !id.chars().all(|char| char.is_ascii_digit()) {
+                continue;
+            }
+
+            for path in files_from_subdir(&item_path, false)? {
+                if path.extension().map(|extension| extension == "pack").unwrap_or(false) {
+                    packs.push(Self {
+                        id: id.to_owned(),
+                        title: titles.get(&id).cloned(),
+                        path,
+                    });
+                }
+            }
+        }
+
+        packs.sort_by(|a, b| a.display_title().to_lowercase().cmp(&b.display_title().to_lowercase()).then_with(|| a.path.cmp(&b.path)));
+        Ok(packs)
+    }
+
+    /// This function returns the title of the item, or its id if we don't know its title.
+    pub fn display_title(&self) -> &str {
+        self.title.as_deref().unwrap_or(&self.id)
+    }
+}
+
+/// This function returns the titles of the items in the provided acf file, by item id.
+///
+/// Missing titles are skipped, and broken files return no titles at all.
+fn acf_titles(data: &str) -> HashMap<String, String> {
+    let mut titles = HashMap::new();
+    if let Ok(KeyValue::Map(root)) = parse_key_values(data) {
+        for (_, app) in &root {
+            if let KeyValue::Map(sections) = app {
+                for (_, section) in sections {
+                    if let KeyValue::Map(items) = section {
+                        for (id, item) in items {
+                            if let KeyValue::Map(values) = item {
+                                let title = values.iter().find_map(|(key, value)| match value {
+                                    KeyValue::String(value) if key.eq_ignore_ascii_case("title") && !value.trim().is_empty() => Some(value.trim().to_owned()),
+                                    _ => None,
+                                });
+
+                                if let Some(title) = title {
+                                    titles.insert(id.to_owned(), title);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    titles
+}
+
+/// This function parses a KeyValues text file into a map with its root keys.
+fn parse_key_values(data: &str) -> Result<KeyValue> {
+    let mut chars = data.chars().peekable();
+    let mut stack: Vec<(String, Vec<(String, KeyValue)>)> = vec![(String::new(), vec![])];
+    let mut key: Option<String> = None;
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('n') => string.push('\n'),
+                            Some('t') => string.push('\t'),
+                            Some(char) => string.push(char),
+                            None => return Err(RLibError::ParseKeyValuesError("Unfinished string.".to_owned())),
+                        }
+                        Some(char) => string.push(char),
+                        None => return Err(RLibError::ParseKeyValuesError("Unfinished string.".to_owned())),
+                    }
+                }
+
+                match key.take() {
+                    Some(key) => stack.last_mut().unwrap().1.push((key, KeyValue::String(string))),
+                    None => key = Some(string),
+                }
+            }
+
+            '{' => match key.take() {
+                Some(key) => stack.push((key, vec![])),
+                None => return Err(RLibError::ParseKeyValuesError("Map without key.".to_owned())),
+            }
+
+            '}' => {
+                if key.is_some() || stack.len() < 2 {
+                    return Err(RLibError::ParseKeyValuesError("Unexpected end of map.".to_owned()));
+                }
+
+                let (key, values) = stack.pop().unwrap();
+                stack.last_mut().unwrap().1.push((key, KeyValue::Map(values)));
+            }
+
+            // Comments go until the end of the line.
+            '/' if chars.peek() == Some(&'/') => {
+                for char in chars.by_ref() {
+                    if char == '\n' {
+                        break;
+                    }
+                }
+            }
+
+            char if char.is_whitespace() => {},
+            char => return Err(RLibError::ParseKeyValuesError(format!("Unexpected character: {char}."))),
+        }
+    }
+
+    if key.is_some() || stack.len() != 1 {
+        return Err(RLibError::ParseKeyValuesError("Unexpected end of file.".to_owned()));
+    }
+
+    Ok(KeyValue::Map(stack.pop().unwrap().1))
+}
@@ -0,0 +1,90 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for finding the Packs of Steam Workshop items.
+
+use std::fs::{create_dir_all, remove_dir_all, write};
+
+use super::*;
+
+const ACF: &str = r#""AppWorkshop"
+{
+	"appid"		"1142710"
+	"SizeOnDisk"		"1024"
+	"WorkshopItemsInstalled"
+	{
+		"2789857593"
+		{
+			"size"		"512"
+			"timeupdated"		"1666000000"
+			"manifest"		"123456789"
+		}
+		"2790000000"
+		{
+			"size"		"512"
+			"title"		"Better \"Quoted\" Mod"
+		}
+	}
+	// Steam writes details of the items here too.
+	"WorkshopItemDetails"
+	{
+		"2789857593"
+		{
+			"manifest"		"123456789"
+			"title"		"Some Mod"
+		}
+	}
+}
+"#;
+
+#[test]
+fn test_acf_titles() {
+    let titles = acf_titles(ACF);
+    assert_eq!(titles.len(), 2);
+    assert_eq!(titles.get("2789857593").unwrap(), "Some Mod");
+    assert_eq!(titles.get("2790000000").unwrap(), "Better \"Quoted\" Mod");
+
+    // Broken files must not break anything, just return no titles.
+    assert!(acf_titles(&ACF[..ACF.len() / 2]).is_empty());
+    assert!(acf_titles("garbage { \"").is_empty());
+    assert!(acf_titles("").is_empty());
+}
+
+#[test]
+fn test_read_from_content_path() {
+    let workshop_path = std::env::temp_dir().join("rpfm_test_workshop");
+    let _ = remove_dir_all(&workshop_path);
+
+    let content_path = workshop_path.join("content").join("1142710");
+    create_dir_all(content_path.join("2789857593")).unwrap();
+    create_dir_all(content_path.join("1000")).unwrap();
+    create_dir_all(content_path.join("not_an_item")).unwrap();
+    write(content_path.join("2789857593").join("some_mod.pack"), b"").unwrap();
+    write(content_path.join("2789857593").join("some_mod.png"), b"").unwrap();
+    write(content_path.join("1000").join("another_mod.pack"), b"").unwrap();
+    write(content_path.join("not_an_item").join("ignored.pack"), b"").unwrap();
+
+    // Without acf we only have ids.
+    let packs = WorkshopPack::read_from_content_path(&content_path).unwrap();
+    assert_eq!(packs.iter().map(|pack| (pack.display_title(), pack.path().file_name().unwrap().to_str().unwrap())).collect::<Vec<_>>(), vec![
+        ("1000", "another_mod.pack"),
+        ("2789857593", "some_mod.pack"),
+    ]);
+
+    // With it, we have titles for the ones in it.
+    write(workshop_path.join("appworkshop_1142710.acf"), ACF).unwrap();
+    let packs = WorkshopPack::read_from_content_path(&content_path).unwrap();
+    assert_eq!(packs.iter().map(|pack| (pack.id().as_str(), pack.display_title())).collect::<Vec<_>>(), vec![
+        ("1000", "1000"),
+        ("2789857593", "Some Mod"),
+    ]);
+
+    remove_dir_all(&workshop_path).unwrap();
+}
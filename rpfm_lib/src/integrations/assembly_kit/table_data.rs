@@ -11,7 +11,8 @@
 /*!
 Module with all the code to interact with the Assembly Kit's DB Files.
 
-This module contains all the code needed to parse Assembly Kit's DB files to a format we can understand.
+This module contains all the code needed to parse Assembly Kit's DB files to a format we can understand,
+and to write our DB files back in the same format.
 !*/
 
 use rayon::prelude::*;
@@ -20,12 +21,13 @@ use serde_derive::Deserialize;
 use serde_xml_rs::from_reader;
 
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{BufReader, Cursor, Read, Write};
 use std::path::Path;
 
+use crate::binary::ReadBytes;
 use crate::error::{Result, RLibError};
 use crate::files::{db::DB, table::{DecodedData, Table}};
-use crate::schema::FieldType;
+use crate::schema::{Field, FieldType};
 
 use super::table_definition::RawDefinition;
 
@@ -215,3 +217,76 @@ impl TryFrom<&RawTable> for Table {
         Ok(table)
     }
 }
+
+//---------------------------------------------------------------------------//
+// Functions to write DB Files as Assembly Kit DB Files.
+//---------------------------------------------------------------------------//
+
+/// This function writes the provided DB to the provided writer, as a Raw Assembly Kit Table.
+///
+/// Rows are named after the table (without the `_tables` suffix) and fields after their name in the schema.
+/// The Assembly Kit doesn't use sequences, so we write each of their rows as a nested `<{field_name}_entry>` element.
+///
+/// Export timestamps and uuids are not written, so exporting the same table twice always gives the same file.
+pub fn write_raw_table<W: Write>(db: &DB, writer: &mut W) -> Result<()> {
+    let row_name = db.table_name().strip_suffix("_tables").unwrap_or(db.table_name());
+    let fields = db.definition().fields_processed();
+
+    writer.write_all(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\r\n<dataroot>\r\n")?;
+    for row in db.data(&None)?.iter() {
+        write_raw_row(writer, row_name, &fields, row)?;
+    }
+
+    writer.write_all(b"</dataroot>\r\n")?;
+    Ok(())
+}
+
+/// This function writes a row of a Raw Assembly Kit Table, with its sequences nested within their fields.
+fn write_raw_row<W: Write>(writer: &mut W, row_name: &str, fields: &[Field], row: &[DecodedData]) -> Result<()> {
+    write!(writer, "<{}>\r\n", row_name)?;
+
+    for (field, data) in fields.iter().zip(row.iter()) {
+        let name = field.name();
+        match (field.field_type(), data) {
+            (FieldType::SequenceU16(definition), DecodedData::SequenceU16(data)) |
+            (FieldType::SequenceU32(definition), DecodedData::SequenceU32(data)) => {
+
+                // Empty sequences may not even have the entry count.
+                let mut data = Cursor::new(data);
+                let rows = if data.get_ref().is_empty() {
+                    vec![]
+                } else if let FieldType::SequenceU16(_) = field.field_type() {
+                    let entry_count = data.read_u16()?;
+                    Table::decode_table(&mut data, definition, Some(entry_count as u32), false)?
+                } else {
+                    Table::decode_table(&mut data, definition, None, false)?
+                };
+
+                let nested_row_name = format!("{}_entry", name);
+                let nested_fields = definition.fields_processed();
+
+                write!(writer, "<{}>\r\n", name)?;
+                for row in &rows {
+                    write_raw_row(writer, &nested_row_name, &nested_fields, row)?;
+                }
+                write!(writer, "</{}>\r\n", name)?;
+            }
+
+            // Floats are written with full precision, so exporting them doesn't round their values.
+            (_, DecodedData::F32(data)) => write!(writer, "<{0}>{1}</{0}>\r\n", name, data)?,
+            (_, DecodedData::F64(data)) => write!(writer, "<{0}>{1}</{0}>\r\n", name, data)?,
+            _ => write!(writer, "<{0}>{1}</{0}>\r\n", name, escape_xml(&data.data_to_string()))?,
+        }
+    }
+
+    write!(writer, "</{}>\r\n", row_name)?;
+    Ok(())
+}
+
+/// This function escapes the characters XML cares about.
+fn escape_xml(data: &str) -> String {
+    data.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -20,7 +20,7 @@ Otherwise, none of them will work.
 !*/
 
 use backtrace::Backtrace;
-pub use log::{error, info, warn};
+pub use log::{debug, error, info, warn};
 pub use sentry::{ClientInitGuard, Envelope, integrations::log::SentryLogger, protocol::*};
 use serde_derive::Serialize;
 use simplelog::{ColorChoice, CombinedLogger, LevelFilter, SharedLogger, TermLogger, TerminalMode};
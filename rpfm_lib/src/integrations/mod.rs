@@ -17,10 +17,15 @@
 //!   `integration_git` to be enabled.
 //! - **Log**: enables logging and automatic upload crash reports. Requires the
 //!   feature `integration_log` to be enabled.
+//! - **Signing**: enables signing Packs and verifying their signatures. Requires the
+//!   feature `integration_signing` to be enabled.
 //!
 //! Each integration is opt-in, so you can ignore them unless you really want to use them.
 
 #[cfg(feature = "integration_assembly_kit")] pub mod assembly_kit;
 #[cfg(feature = "integration_git")] pub mod git;
 #[cfg(feature = "integration_log")] pub mod log;
+#[cfg(feature = "integration_signing")] pub mod signing;
 #[cfg(feature = "integration_sqlite")] pub mod sqlite;
+
+#[cfg(all(test, feature = "integration_signing"))] mod signing_test;
@@ -0,0 +1,184 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! This module contains the code to sign Packs and verify their signatures, using ed25519 keys.
+//!
+//! Signatures are not made over the Pack file itself, but over the list of paths of its files and the SHA-256 hash
+//! of their data, sorted by path. That way, re-saving a Pack without changes (which updates its timestamp, and may
+//! change the order or the compression of its files) doesn't break its signature.
+//!
+//! The signature is saved next to the Pack, in a `.sig` file with the same name as the Pack. As it contains the list of
+//! hashes that has been signed, if a Pack fails to verify we can also tell which files have been altered.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use getset::Getters;
+use rand::rngs::OsRng;
+use serde_derive::{Serialize, Deserialize};
+use serde_json::{from_slice, to_string_pretty};
+use sha2::{Digest, Sha256};
+
+use std::collections::BTreeMap;
+use std::fs::{DirBuilder, OpenOptions, read, read_to_string, write};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::{RLibError, Result};
+use crate::files::pack::Pack;
+
+/// Extension of the signature files. It's appended to the full name of the Pack.
+pub const SIGNATURE_EXTENSION: &str = ".sig";
+
+/// Header of the signed data, so a signature of a Pack cannot be passed as a valid signature of anything else.
+const SIGNED_DATA_HEADER: &[u8] = b"RPFM Pack Signature V1\n";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct holds the signature of a Pack, as saved in its `.sig` file.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct PackSignature {
+
+    /// Public key of the key used to sign the Pack, as a hex string.
+    public_key: String,
+
+    /// Signature of the list of files, as a hex string.
+    signature: String,
+
+    /// Paths of the files in the Pack, with the SHA-256 hash of their data, sorted by path.
+    files: BTreeMap<String, String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl PackSignature {
+
+    /// This function returns the path of the signature file of the Pack at the provided path.
+    pub fn path_for_pack(pack_path: &Path) -> PathBuf {
+        let mut path = pack_path.as_os_str().to_owned();
+        path.push(SIGNATURE_EXTENSION);
+        PathBuf::from(path)
+    }
+
+    /// This function signs the provided Pack with the provided key.
+    pub fn sign(pack: &mut Pack, key: &SigningKey) -> Result<Self> {
+        let files = Self::files_from_pack(pack)?;
+        let signature = key.sign(&Self::signed_data(&files));
+
+        Ok(Self {
+            public_key: hex::encode(key.verifying_key().as_bytes()),
+            signature: hex::encode(signature.to_bytes()),
+            files,
+        })
+    }
+
+    /// This function checks that this signature has been made by the provided public key, and that it matches the provided Pack.
+    ///
+    /// If the Pack has been altered after being signed, the error contains the list of added, removed or modified files.
+    pub fn verify(&self, pack: &mut Pack, public_key: &VerifyingKey) -> Result<()> {
+        let signature = hex::decode(&self.signature).ok()
+            .and_then(|signature| <[u8; 64]>::try_from(signature).ok())
+            .map(|signature| Signature::from_bytes(&signature))
+            .ok_or(RLibError::SigningInvalidSignature)?;
+
+        public_key.verify(&Self::signed_data(&self.files), &signature).map_err(|_| RLibError::SigningSignatureMismatch)?;
+
+        // Once we know the list of files is legit, check it against the Pack.
+        let files = Self::files_from_pack(pack)?;
+        let mut altered_paths = files.iter()
+            .filter(|(path, hash)| self.files.get(*path) != Some(hash))
+            .map(|(path, _)| path.to_owned())
+            .collect::<Vec<_>>();
+
+        altered_paths.extend(self.files.keys().filter(|path| !files.contains_key(*path)).cloned());
+        altered_paths.sort();
+
+        if altered_paths.is_empty() {
+            Ok(())
+        } else {
+            Err(RLibError::SigningPackAltered(altered_paths.join("\n")))
+        }
+    }
+
+    /// This function loads a signature from the provided path.
+    pub fn load(path: &Path) -> Result<Self> {
+        from_slice(&read(path)?).map_err(|_| RLibError::SigningInvalidSignature)
+    }
+
+    /// This function saves the signature next to the Pack at the provided path, returning the path of the signature file.
+    pub fn save(&self, pack_path: &Path) -> Result<PathBuf> {
+        let path = Self::path_for_pack(pack_path);
+        write(&path, to_string_pretty(&self)?)?;
+        Ok(path)
+    }
+
+    /// This function returns the paths of the files of the provided Pack, with the SHA-256 hash of their data.
+    fn files_from_pack(pack: &mut Pack) -> Result<BTreeMap<String, String>> {
+        pack.files_mut()
+            .iter_mut()
+            .map(|(path, file)| Ok((path.to_owned(), format!("{:x}", Sha256::digest(file.encode(&None, false, false, true)?.unwrap_or_default())))))
+            .collect()
+    }
+
+    /// This function returns the data that actually gets signed for the provided list of files.
+    fn signed_data(files: &BTreeMap<String, String>) -> Vec<u8> {
+        let mut data = SIGNED_DATA_HEADER.to_vec();
+        for (path, hash) in files {
+            data.extend_from_slice(&(path.len() as u32).to_le_bytes());
+            data.extend_from_slice(path.as_bytes());
+            data.extend_from_slice(hash.as_bytes());
+        }
+
+        data
+    }
+}
+
+/// This function returns the signing key stored at the provided path.
+///
+/// If there is no key there yet, a new one is generated and saved, along with its public key (in the same path,
+/// with the `.pub` extension) so it can be shared with whoever needs to verify the Packs signed with it.
+pub fn signing_key(path: &Path) -> Result<SigningKey> {
+    if path.is_file() {
+        hex::decode(read_to_string(path)?.trim()).ok()
+            .and_then(|key| <[u8; 32]>::try_from(key).ok())
+            .map(|key| SigningKey::from_bytes(&key))
+            .ok_or_else(|| RLibError::SigningInvalidPrivateKey(path.to_string_lossy().to_string()))
+    } else {
+        if let Some(parent) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent)?;
+        }
+
+        let key = SigningKey::generate(&mut OsRng);
+
+        // The private key must only be readable by its owner.
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)] {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        options.open(path)?.write_all(hex::encode(key.as_bytes()).as_bytes())?;
+        write(path.with_extension("pub"), hex::encode(key.verifying_key().as_bytes()))?;
+        Ok(key)
+    }
+}
+
+/// This function returns the public key stored, as a hex string, at the provided path.
+pub fn public_key(path: &Path) -> Result<VerifyingKey> {
+    hex::decode(read_to_string(path)?.trim()).ok()
+        .and_then(|key| <[u8; 32]>::try_from(key).ok())
+        .and_then(|key| VerifyingKey::from_bytes(&key).ok())
+        .ok_or_else(|| RLibError::SigningInvalidPublicKey(path.to_string_lossy().to_string()))
+}
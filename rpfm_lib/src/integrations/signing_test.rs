@@ -0,0 +1,74 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for signing Packs and verifying their signatures.
+
+use std::fs::{create_dir_all, remove_dir_all};
+use std::path::PathBuf;
+
+use crate::error::RLibError;
+use crate::files::*;
+use crate::files::pack::Pack;
+use crate::games::pfh_version::PFHVersion;
+
+use super::signing::{PackSignature, public_key, signing_key};
+
+#[test]
+fn test_sign_and_verify_pack() {
+    let folder = std::env::temp_dir().join("rpfm_test_sign_and_verify_pack");
+    let _ = remove_dir_all(&folder);
+    create_dir_all(&folder).unwrap();
+
+    // Keys are generated on first use, and reused after that.
+    let key_path = folder.join("config/pack_signing.key");
+    let key = signing_key(&key_path).unwrap();
+    assert_eq!(signing_key(&key_path).unwrap(), key);
+    let verifying_key = public_key(&key_path.with_extension("pub")).unwrap();
+    assert_eq!(verifying_key, key.verifying_key());
+
+    #[cfg(unix)] {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(std::fs::metadata(&key_path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+
+    let pack_path = folder.join("test_signing.pack");
+    let mut pack = Pack::new_with_name_and_version("test_signing.pack", PFHVersion::PFH5);
+    pack.insert(RFile::new_from_vec(b"Data 1.", FileType::Text, 0, "text/test_1.txt")).unwrap();
+    pack.insert(RFile::new_from_vec(b"Data 2.", FileType::Text, 0, "text/test_2.txt")).unwrap();
+    pack.save(Some(&pack_path)).unwrap();
+
+    let signature = PackSignature::sign(&mut pack, &key).unwrap();
+    let signature_path = signature.save(&pack_path).unwrap();
+    assert_eq!(signature_path, PathBuf::from(format!("{}.sig", pack_path.to_string_lossy())));
+
+    // Re-saving the Pack, with a different timestamp, must not break the signature.
+    let mut pack = Pack::read_and_merge(&[pack_path.to_path_buf()], true, false).unwrap();
+    pack.set_internal_timestamp(1);
+    pack.save(None).unwrap();
+
+    let mut pack = Pack::read_and_merge(&[pack_path.to_path_buf()], true, false).unwrap();
+    let signature = PackSignature::load(&signature_path).unwrap();
+    signature.verify(&mut pack, &verifying_key).unwrap();
+
+    // Other keys must fail.
+    let other_key = signing_key(&folder.join("other.key")).unwrap();
+    assert!(matches!(signature.verify(&mut pack, &other_key.verifying_key()), Err(RLibError::SigningSignatureMismatch)));
+
+    // And altered Packs must report what changed.
+    pack.insert(RFile::new_from_vec(b"Data 3.", FileType::Text, 0, "text/test_3.txt")).unwrap();
+    pack.insert(RFile::new_from_vec(b"Altered.", FileType::Text, 0, "text/test_1.txt")).unwrap();
+    pack.remove(&ContainerPath::File("text/test_2.txt".to_owned()));
+    match signature.verify(&mut pack, &verifying_key) {
+        Err(RLibError::SigningPackAltered(paths)) => assert_eq!(paths, "text/test_1.txt\ntext/test_2.txt\ntext/test_3.txt"),
+        _ => panic!("Altered Pack verified correctly."),
+    }
+
+    remove_dir_all(&folder).unwrap();
+}
@@ -0,0 +1,258 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to compare two versions of a Schema.
+
+Only the newest definition of each table is compared, as that's the one used to create and update tables.
+Fields are matched by name. Fields that only exist in one of the definitions are considered renamed if they're
+in the same position and have the same type in both definitions, and added or removed otherwise.
+!*/
+
+use getset::Getters;
+use serde_derive::{Serialize, Deserialize};
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use super::{Definition, Field, FieldType, Schema};
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct contains the differences between two versions of a Schema, by table name.
+///
+/// Tables without changes are not included.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct SchemaDiff {
+    tables: BTreeMap<String, TableDiff>,
+}
+
+/// This struct contains the differences between the newest definitions of a table in two versions of a Schema.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct TableDiff {
+
+    /// Version of the newest definition of the table in the old Schema. None if the table was not in it.
+    old_version: Option<i32>,
+
+    /// Version of the newest definition of the table in the new Schema. None if the table is not in it.
+    new_version: Option<i32>,
+
+    /// Changes in the fields of the table, in the order of the new definition. Removed fields go last.
+    changes: Vec<FieldChange>,
+}
+
+/// This enum represents a change in a field of a table between two versions of a Schema.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FieldChange {
+    Added(String),
+    Removed(String),
+    Renamed {
+        old_name: String,
+        new_name: String,
+    },
+    TypeChanged {
+        name: String,
+        old_type: FieldType,
+        new_type: FieldType,
+    },
+    KeyChanged {
+        name: String,
+        is_key: bool,
+    },
+    ReferenceChanged {
+        name: String,
+        old_reference: Option<(String, String)>,
+        new_reference: Option<(String, String)>,
+    },
+}
+
+//---------------------------------------------------------------------------//
+//                       Enum & Structs Implementations
+//---------------------------------------------------------------------------//
+
+impl SchemaDiff {
+
+    /// This function compares the newest definition of each table in the provided Schemas.
+    pub fn new(old: &Schema, new: &Schema) -> Self {
+        let mut tables = BTreeMap::new();
+
+        for (table_name, new_definitions) in new.definitions() {
+            let old_definition = old.definitions().get(table_name).and_then(|definitions| newest_definition(definitions));
+            let new_definition = newest_definition(new_definitions);
+            let diff = TableDiff::new(old_definition, new_definition);
+            if !diff.is_empty() {
+                tables.insert(table_name.to_owned(), diff);
+            }
+        }
+
+        // Tables removed from the new schema.
+        for (table_name, old_definitions) in old.definitions() {
+            if !new.definitions().contains_key(table_name) {
+                let diff = TableDiff::new(newest_definition(old_definitions), None);
+                if !diff.is_empty() {
+                    tables.insert(table_name.to_owned(), diff);
+                }
+            }
+        }
+
+        Self {
+            tables,
+        }
+    }
+
+    /// This function returns a copy of this diff, only with the provided tables.
+    pub fn filtered(&self, table_names: &[String]) -> Self {
+        Self {
+            tables: self.tables.iter()
+                .filter(|(table_name, _)| table_names.contains(table_name))
+                .map(|(table_name, diff)| (table_name.to_owned(), diff.clone()))
+                .collect(),
+        }
+    }
+
+    /// This function returns if there are no changes in this diff.
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// This function returns this diff as a Markdown document.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::from("# Schema Changes\n");
+        if self.tables.is_empty() {
+            markdown.push_str("\nNo changes.\n");
+            return markdown;
+        }
+
+        for (table_name, diff) in &self.tables {
+            let _ = writeln!(markdown, "\n## {}\n", table_name);
+
+            match (diff.old_version, diff.new_version) {
+                (None, Some(new_version)) => { let _ = writeln!(markdown, "- Table added, with version {}.", new_version); },
+                (Some(old_version), None) => { let _ = writeln!(markdown, "- Table removed. Last version was {}.", old_version); },
+                (Some(old_version), Some(new_version)) if old_version != new_version => { let _ = writeln!(markdown, "- Version changed from {} to {}.", old_version, new_version); },
+                _ => {},
+            }
+
+            for change in &diff.changes {
+                let _ = match change {
+                    FieldChange::Added(name) => writeln!(markdown, "- Field added: `{}`.", name),
+                    FieldChange::Removed(name) => writeln!(markdown, "- Field removed: `{}`.", name),
+                    FieldChange::Renamed { old_name, new_name } => writeln!(markdown, "- Field renamed: `{}` to `{}`.", old_name, new_name),
+                    FieldChange::TypeChanged { name, old_type, new_type } => writeln!(markdown, "- Field `{}` changed its type from {} to {}.", name, old_type, new_type),
+                    FieldChange::KeyChanged { name, is_key } => if *is_key {
+                        writeln!(markdown, "- Field `{}` is now a key.", name)
+                    } else {
+                        writeln!(markdown, "- Field `{}` is no longer a key.", name)
+                    },
+                    FieldChange::ReferenceChanged { name, old_reference, new_reference } => writeln!(markdown, "- Field `{}` changed its reference from {} to {}.", name, reference_to_string(old_reference), reference_to_string(new_reference)),
+                };
+            }
+        }
+
+        markdown
+    }
+}
+
+impl TableDiff {
+
+    /// This function compares two definitions of the same table. If one of them is None, the table is considered added or removed.
+    fn new(old: Option<&Definition>, new: Option<&Definition>) -> Self {
+        let mut changes = vec![];
+
+        if let (Some(old), Some(new)) = (old, new) {
+            let old_fields = old.fields().iter().map(|field| (field.name(), field)).collect::<HashMap<_, _>>();
+            let new_fields = new.fields().iter().map(|field| (field.name(), field)).collect::<HashMap<_, _>>();
+            let mut renamed_old_fields = vec![];
+
+            for (index, new_field) in new.fields().iter().enumerate() {
+                match old_fields.get(new_field.name()) {
+                    Some(old_field) => changes.append(&mut Self::field_changes(new_field.name(), old_field, new_field)),
+
+                    // If the field at the same position in the old definition is gone from the new one and has the same type, consider it renamed.
+                    None => match old.fields().get(index) {
+                        Some(old_field) if !new_fields.contains_key(old_field.name()) && old_field.field_type() == new_field.field_type() => {
+                            renamed_old_fields.push(old_field.name());
+                            changes.push(FieldChange::Renamed {
+                                old_name: old_field.name().to_owned(),
+                                new_name: new_field.name().to_owned(),
+                            });
+                            changes.append(&mut Self::field_changes(new_field.name(), old_field, new_field));
+                        }
+                        _ => changes.push(FieldChange::Added(new_field.name().to_owned())),
+                    }
+                }
+            }
+
+            for old_field in old.fields() {
+                if !new_fields.contains_key(old_field.name()) && !renamed_old_fields.contains(&old_field.name()) {
+                    changes.push(FieldChange::Removed(old_field.name().to_owned()));
+                }
+            }
+        }
+
+        Self {
+            old_version: old.map(|definition| *definition.version()),
+            new_version: new.map(|definition| *definition.version()),
+            changes,
+        }
+    }
+
+    /// This function returns if there are no changes between both definitions.
+    pub fn is_empty(&self) -> bool {
+        self.old_version == self.new_version && self.changes.is_empty()
+    }
+
+    /// This function returns the changes between two versions of the same field, other than its name.
+    fn field_changes(name: &str, old: &Field, new: &Field) -> Vec<FieldChange> {
+        let mut changes = vec![];
+
+        if old.field_type() != new.field_type() {
+            changes.push(FieldChange::TypeChanged {
+                name: name.to_owned(),
+                old_type: old.field_type().clone(),
+                new_type: new.field_type().clone(),
+            });
+        }
+
+        if old.is_key() != new.is_key() {
+            changes.push(FieldChange::KeyChanged {
+                name: name.to_owned(),
+                is_key: new.is_key(),
+            });
+        }
+
+        if old.is_reference() != new.is_reference() {
+            changes.push(FieldChange::ReferenceChanged {
+                name: name.to_owned(),
+                old_reference: old.is_reference().clone(),
+                new_reference: new.is_reference().clone(),
+            });
+        }
+
+        changes
+    }
+}
+
+/// This function returns the definition with the highest version from the provided ones.
+fn newest_definition(definitions: &[Definition]) -> Option<&Definition> {
+    definitions.iter().max_by_key(|definition| *definition.version())
+}
+
+/// This function returns a reference in a human-readable format.
+fn reference_to_string(reference: &Option<(String, String)>) -> String {
+    match reference {
+        Some((table, column)) => format!("`{}/{}`", table, column),
+        None => "nothing".to_owned(),
+    }
+}
@@ -0,0 +1,88 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for comparing Schemas.
+
+use super::*;
+
+fn field(name: &str, field_type: FieldType) -> Field {
+    let mut field = Field::default();
+    field.set_name(name.to_owned());
+    field.set_field_type(field_type);
+    field
+}
+
+#[test]
+fn test_schema_diff() {
+    let mut key = field("key", FieldType::StringU8);
+    key.set_is_key(true);
+    let mut unit = field("unit", FieldType::StringU8);
+    unit.set_is_reference(Some(("main_units_tables".to_owned(), "unit".to_owned())));
+
+    let mut old = Schema::default();
+    old.add_definition("units_tables", &Definition::new_with_fields(1, &[key.clone(), field("cost", FieldType::I32), field("old_flag", FieldType::Boolean)], &[]));
+    old.add_definition("unchanged_tables", &Definition::new_with_fields(3, &[key.clone()], &[]));
+    old.add_definition("removed_tables", &Definition::new_with_fields(2, &[key.clone()], &[]));
+    old.add_definition("retyped_tables", &Definition::new_with_fields(1, &[key.clone(), unit.clone()], &[]));
+
+    let mut unit_new = field("unit", FieldType::StringU16);
+    unit_new.set_is_reference(Some(("land_units_tables".to_owned(), "key".to_owned())));
+    let mut key_new = key.clone();
+    key_new.set_is_key(false);
+
+    let mut new = Schema::default();
+    new.add_definition("units_tables", &Definition::new_with_fields(1, &[key.clone(), field("cost", FieldType::I32), field("old_flag", FieldType::Boolean)], &[]));
+    new.add_definition("units_tables", &Definition::new_with_fields(2, &[key.clone(), field("upkeep", FieldType::I32), field("new_flag", FieldType::Boolean), field("extra", FieldType::F32)], &[]));
+    new.add_definition("unchanged_tables", &Definition::new_with_fields(3, &[key.clone()], &[]));
+    new.add_definition("added_tables", &Definition::new_with_fields(1, &[key.clone()], &[]));
+    new.add_definition("retyped_tables", &Definition::new_with_fields(1, &[key_new, unit_new], &[]));
+
+    let diff = SchemaDiff::new(&old, &new);
+    assert_eq!(diff.tables().keys().collect::<Vec<_>>(), vec!["added_tables", "removed_tables", "retyped_tables", "units_tables"]);
+
+    let units = &diff.tables()["units_tables"];
+    assert_eq!(*units.old_version(), Some(1));
+    assert_eq!(*units.new_version(), Some(2));
+    assert_eq!(units.changes(), &vec![
+        FieldChange::Renamed { old_name: "cost".to_owned(), new_name: "upkeep".to_owned() },
+        FieldChange::Renamed { old_name: "old_flag".to_owned(), new_name: "new_flag".to_owned() },
+        FieldChange::Added("extra".to_owned()),
+    ]);
+
+    let added = &diff.tables()["added_tables"];
+    assert_eq!((*added.old_version(), *added.new_version()), (None, Some(1)));
+    assert!(added.changes().is_empty());
+
+    let removed = &diff.tables()["removed_tables"];
+    assert_eq!((*removed.old_version(), *removed.new_version()), (Some(2), None));
+
+    let retyped = &diff.tables()["retyped_tables"];
+    assert_eq!(retyped.changes(), &vec![
+        FieldChange::KeyChanged { name: "key".to_owned(), is_key: false },
+        FieldChange::TypeChanged { name: "unit".to_owned(), old_type: FieldType::StringU8, new_type: FieldType::StringU16 },
+        FieldChange::ReferenceChanged {
+            name: "unit".to_owned(),
+            old_reference: Some(("main_units_tables".to_owned(), "unit".to_owned())),
+            new_reference: Some(("land_units_tables".to_owned(), "key".to_owned())),
+        },
+    ]);
+
+    // Filtering keeps only the requested tables.
+    let filtered = diff.filtered(&["units_tables".to_owned(), "unchanged_tables".to_owned()]);
+    assert_eq!(filtered.tables().keys().collect::<Vec<_>>(), vec!["units_tables"]);
+
+    let markdown = filtered.to_markdown();
+    assert!(markdown.contains("## units_tables"));
+    assert!(markdown.contains("- Version changed from 1 to 2."));
+    assert!(markdown.contains("- Field renamed: `cost` to `upkeep`."));
+    assert!(markdown.contains("- Field added: `extra`."));
+
+    assert!(SchemaDiff::new(&new, &new).is_empty());
+}
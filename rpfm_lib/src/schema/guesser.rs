@@ -0,0 +1,423 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to guess definitions for DB Tables we don't have a definition for.
+
+The guesser tries every possible size for the first row of the table, and for each one brute-forces sequences of
+fields over the first and second rows at the same time, discarding any field whose value doesn't look plausible for
+its type (booleans that are not 0 or 1, strings with control characters, floats that are really integers,...).
+Every sequence that fills the first row is then tested against the entire table, and only the ones that decode
+all the rows and consume all the data are considered valid.
+
+The search is limited in both, amount of fields and amount of steps, so it doesn't take forever on big tables.
+!*/
+
+use getset::Getters;
+use serde_derive::{Serialize, Deserialize};
+
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use crate::files::table::{DecodedData, Table};
+
+use super::{Definition, Field, FieldType};
+
+/// Field types the guesser tries by default, in order of preference.
+pub const GUESSABLE_FIELD_TYPES: &[FieldType] = &[
+    FieldType::StringU8,
+    FieldType::OptionalStringU8,
+    FieldType::I32,
+    FieldType::F32,
+    FieldType::Boolean,
+    FieldType::I64,
+    FieldType::StringU16,
+];
+
+/// Maximum amount of fields a guessed definition can have.
+const MAX_FIELDS: usize = 15;
+
+/// Maximum amount of sequences of fields the guesser tries before giving up.
+const MAX_STEPS: usize = 2_000_000;
+
+/// Maximum amount of valid definitions the guesser collects before giving up.
+const MAX_CANDIDATES: usize = 100;
+
+/// Maximum length of a string for it to be considered plausible.
+const MAX_STRING_LENGTH: usize = 4096;
+
+/// Amount of rows decoded for the preview of each guessed definition.
+const PREVIEW_ROWS: u32 = 5;
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct represents a definition guessed from the data of a table, with a preview of the first rows decoded with it.
+#[derive(Clone, Debug, PartialEq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct GuessedDefinition {
+
+    /// The guessed definition.
+    definition: Definition,
+
+    /// The first rows of the table, decoded with the guessed definition.
+    preview: Vec<Vec<DecodedData>>,
+}
+
+/// Internal state of the search.
+struct Guesser<'a> {
+    data: &'a [u8],
+    entry_count: usize,
+    field_types: &'a [FieldType],
+    steps: usize,
+    candidates: Vec<Vec<FieldType>>,
+
+    /// Positions of the first and second rows from which no valid definition has been found, with the least amount of fields
+    /// we had when we reached them. Reaching them again with the same or more fields cannot find anything new.
+    dead_states: HashMap<(usize, usize), usize>,
+}
+
+//---------------------------------------------------------------------------//
+//                       Enum & Structs Implementations
+//---------------------------------------------------------------------------//
+
+impl<'a> Guesser<'a> {
+
+    /// This function tries every possible size for the first row, searching the sequences of fields that can decode the entire table.
+    fn search(&mut self) {
+
+        // Every row needs at least one byte, so the first one cannot be bigger than this.
+        // If there are more rows than bytes, the entry count is corrupted and there's nothing to guess.
+        let max_row_size = match (self.data.len() + 1).checked_sub(self.entry_count) {
+            Some(max_row_size) => max_row_size,
+            None => return,
+        };
+        for row_size in 1..=max_row_size {
+            if self.is_search_over() {
+                break;
+            }
+
+            // With only one row, the row needs to take the entire table.
+            if self.entry_count == 1 && row_size != self.data.len() {
+                continue;
+            }
+
+            self.dead_states.clear();
+            self.search_fields(&mut vec![], 0, row_size, row_size);
+        }
+    }
+
+    /// This function tries to extend the provided sequence of fields with each one of the field types we have, decoding the first and second rows
+    /// at the same time, until the first row ends exactly at `row_size`. Then the sequence is checked against the entire table.
+    ///
+    /// `pos_first` and `pos_second` are the positions where the first and second rows end with the current sequence.
+    fn search_fields(&mut self, fields: &mut Vec<FieldType>, pos_first: usize, pos_second: usize, row_size: usize) {
+        if self.is_search_over() {
+            return;
+        }
+
+        self.steps += 1;
+
+        if pos_first == row_size {
+            if self.decodes_table(fields) {
+                self.candidates.push(fields.to_vec());
+            }
+            return;
+        }
+
+        // Different sequences of fields often end up in the same positions (like four booleans and an i32 reading zeros),
+        // so if we already got here without finding anything, don't bother.
+        let state = (pos_first, pos_second);
+        if fields.len() >= MAX_FIELDS || self.dead_states.get(&state).map_or(false, |len| *len <= fields.len()) {
+            return;
+        }
+
+        let candidates = self.candidates.len();
+        for field_type in self.field_types {
+            let new_pos_first = match read_field(self.data, pos_first, field_type) {
+                Some(new_pos_first) if new_pos_first <= row_size => new_pos_first,
+                _ => continue,
+            };
+
+            let new_pos_second = if self.entry_count > 1 {
+                match read_field(self.data, pos_second, field_type) {
+                    Some(new_pos_second) => new_pos_second,
+                    None => continue,
+                }
+            } else {
+                pos_second
+            };
+
+            fields.push(field_type.clone());
+            self.search_fields(fields, new_pos_first, new_pos_second, row_size);
+            fields.pop();
+        }
+
+        if self.candidates.len() == candidates && !self.is_search_over() {
+            self.dead_states.insert(state, fields.len());
+        }
+    }
+
+    /// This function checks if we have already done enough searching.
+    fn is_search_over(&self) -> bool {
+        self.steps >= MAX_STEPS || self.candidates.len() >= MAX_CANDIDATES
+    }
+
+    /// This function checks if the provided sequence of fields decodes all the rows of the table, consuming all the data.
+    fn decodes_table(&self, fields: &[FieldType]) -> bool {
+
+        // If all the fields have a fixed size, we can discard most sequences without decoding anything.
+        let row_size = fields.iter().map(fixed_size).sum::<Option<usize>>();
+        if row_size.map_or(false, |row_size| row_size * self.entry_count != self.data.len()) {
+            return false;
+        }
+
+        let mut pos = 0;
+        for _ in 0..self.entry_count {
+            for field_type in fields {
+                match read_field(self.data, pos, field_type) {
+                    Some(new_pos) => pos = new_pos,
+                    None => return false,
+                }
+            }
+        }
+
+        pos == self.data.len()
+    }
+
+    /// This function returns the amount of columns that have the same value in all rows when decoded with the provided sequence of fields.
+    ///
+    /// Columns like that are usually the result of splitting a field wrongly, like reading the zeros of a number as an empty string.
+    fn constant_columns(&self, fields: &[FieldType]) -> usize {
+        let mut first_row = vec![];
+        let mut constant = vec![true; fields.len()];
+        let mut pos = 0;
+
+        for row in 0..self.entry_count {
+            for (column, field_type) in fields.iter().enumerate() {
+                let new_pos = match read_field(self.data, pos, field_type) {
+                    Some(new_pos) => new_pos,
+                    None => return fields.len(),
+                };
+
+                let value = &self.data[pos..new_pos];
+                if row == 0 {
+                    first_row.push(value);
+                } else if first_row[column] != value {
+                    constant[column] = false;
+                }
+
+                pos = new_pos;
+            }
+        }
+
+        // With only one row, we cannot tell.
+        if self.entry_count == 1 {
+            0
+        } else {
+            constant.iter().filter(|x| **x).count()
+        }
+    }
+}
+
+//---------------------------------------------------------------------------//
+//                              Functions
+//---------------------------------------------------------------------------//
+
+/// This function guesses the possible definitions of a DB Table from its data, ranked from most to least likely.
+///
+/// The data must not include the header of the table. Definitions are ranked by amount of fields, as less fields means
+/// less chances of having split a field in two, then by amount of columns with the same value in all rows, and then by
+/// the order of the provided field types.
+///
+/// Only the first `max_results` definitions are returned. If none is found, an empty list is returned.
+pub fn guess_definitions(data: &[u8], version: i32, entry_count: u32, field_types: &[FieldType], max_results: usize) -> Vec<GuessedDefinition> {
+    if entry_count == 0 || data.is_empty() {
+        return vec![];
+    }
+
+    let mut guesser = Guesser {
+        data,
+        entry_count: entry_count as usize,
+        field_types,
+        steps: 0,
+        candidates: vec![],
+        dead_states: HashMap::new(),
+    };
+
+    guesser.search();
+
+    // Sort is stable, so equally ranked candidates keep the order in which they were found.
+    let mut candidates = guesser.candidates.iter()
+        .map(|fields| (fields.len(), guesser.constant_columns(fields), fields))
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|(len, constant_columns, _)| (*len, *constant_columns));
+
+    candidates.iter()
+        .take(max_results)
+        .filter_map(|(_, _, field_types)| {
+            let fields = field_types.iter()
+                .enumerate()
+                .map(|(index, field_type)| {
+                    let mut field = Field::default();
+                    field.set_name(format!("unknown_{}", index));
+                    field.set_field_type(field_type.clone());
+                    field
+                })
+                .collect::<Vec<_>>();
+
+            let definition = Definition::new_with_fields(version, &fields, &[]);
+            let preview = Table::decode_table(&mut Cursor::new(data), &definition, Some(entry_count.min(PREVIEW_ROWS)), false).ok()?;
+            Some(GuessedDefinition {
+                definition,
+                preview,
+            })
+        })
+        .collect()
+}
+
+/// This function returns the size of the provided field type, if it always has the same size.
+fn fixed_size(field_type: &FieldType) -> Option<usize> {
+    match field_type {
+        FieldType::Boolean => Some(1),
+        FieldType::I16 => Some(2),
+        FieldType::I32 | FieldType::F32 | FieldType::ColourRGB => Some(4),
+        FieldType::I64 | FieldType::F64 => Some(8),
+        _ => None,
+    }
+}
+
+/// This function tries to read a field of the provided type at the provided position, returning the position after it
+/// if the field has been read and its value looks plausible for its type.
+fn read_field(data: &[u8], pos: usize, field_type: &FieldType) -> Option<usize> {
+    match field_type {
+        FieldType::Boolean => match data.get(pos)? {
+            0 | 1 => Some(pos + 1),
+            _ => None,
+        },
+
+        FieldType::I16 => read_bytes::<2>(data, pos).map(|_| pos + 2),
+
+        FieldType::I32 => {
+            let bytes = read_bytes::<4>(data, pos)?;
+
+            // Big integers that are also valid floats are probably floats.
+            let value = i32::from_le_bytes(bytes);
+            if value.unsigned_abs() >= 1 << 24 && is_plausible_f32(f32::from_le_bytes(bytes)) {
+                None
+            } else {
+                Some(pos + 4)
+            }
+        }
+
+        // Only accept i64 values that fit in an i32, as otherwise it's usually two fields.
+        FieldType::I64 => {
+            let value = i64::from_le_bytes(read_bytes::<8>(data, pos)?);
+            if i32::try_from(value).is_ok() {
+                Some(pos + 8)
+            } else {
+                None
+            }
+        }
+
+        FieldType::F32 => {
+            if is_plausible_f32(f32::from_le_bytes(read_bytes::<4>(data, pos)?)) {
+                Some(pos + 4)
+            } else {
+                None
+            }
+        }
+
+        FieldType::F64 => {
+            let value = f64::from_le_bytes(read_bytes::<8>(data, pos)?);
+            if value == 0.0 || (value.is_finite() && (1e-5..1e12).contains(&value.abs())) {
+                Some(pos + 8)
+            } else {
+                None
+            }
+        }
+
+        FieldType::ColourRGB => read_bytes::<4>(data, pos).map(|_| pos + 4),
+
+        FieldType::StringU8 => {
+            let size = u16::from_le_bytes(read_bytes::<2>(data, pos)?) as usize;
+            let end = pos + 2 + size;
+            if size > MAX_STRING_LENGTH {
+                return None;
+            }
+
+            let string = std::str::from_utf8(data.get(pos + 2..end)?).ok()?;
+            if is_plausible_string(string.chars()) {
+                Some(end)
+            } else {
+                None
+            }
+        }
+
+        FieldType::StringU16 => {
+            let size = u16::from_le_bytes(read_bytes::<2>(data, pos)?) as usize;
+            let end = pos + 2 + size * 2;
+            if size > MAX_STRING_LENGTH {
+                return None;
+            }
+
+            let chars = data.get(pos + 2..end)?
+                .chunks_exact(2)
+                .map(|x| u16::from_le_bytes([x[0], x[1]]))
+                .collect::<Vec<_>>();
+
+            let string = String::from_utf16(&chars).ok()?;
+            if is_plausible_string(string.chars()) {
+                Some(end)
+            } else {
+                None
+            }
+        }
+
+        FieldType::OptionalStringU8 |
+        FieldType::OptionalStringU16 |
+        FieldType::OptionalI16 |
+        FieldType::OptionalI32 |
+        FieldType::OptionalI64 => {
+            let inner_type = match field_type {
+                FieldType::OptionalStringU8 => FieldType::StringU8,
+                FieldType::OptionalStringU16 => FieldType::StringU16,
+                FieldType::OptionalI16 => FieldType::I16,
+                FieldType::OptionalI32 => FieldType::I32,
+                _ => FieldType::I64,
+            };
+
+            match data.get(pos)? {
+                0 => Some(pos + 1),
+                1 => read_field(data, pos + 1, &inner_type),
+                _ => None,
+            }
+        }
+
+        // Sequences are too complex to guess.
+        FieldType::SequenceU16(_) | FieldType::SequenceU32(_) => None,
+    }
+}
+
+/// This function returns the `N` bytes at the provided position, if there are enough bytes.
+fn read_bytes<const N: usize>(data: &[u8], pos: usize) -> Option<[u8; N]> {
+    data.get(pos..pos + N)?.try_into().ok()
+}
+
+/// This function checks if a float looks like a real value and not like the bytes of something else.
+fn is_plausible_f32(value: f32) -> bool {
+    value == 0.0 || (value.is_finite() && (1e-5..1e7).contains(&value.abs()))
+}
+
+/// This function checks if a string looks like real text, meaning it has no control characters other than line jumps and tabs.
+fn is_plausible_string<I: Iterator<Item = char>>(mut chars: I) -> bool {
+    chars.all(|character| !character.is_control() || character == '\n' || character == '\r' || character == '\t')
+}
@@ -0,0 +1,60 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for guessing definitions of DB Tables.
+
+use crate::binary::WriteBytes;
+use crate::files::table::DecodedData;
+
+use super::*;
+
+#[test]
+fn test_guess_definitions() {
+    let rows = [
+        ("wh_main_emp_karl_franz", 1500, 0.75, true),
+        ("wh_main_dwf_thorgrim", 250, 12.5, false),
+        ("wh_main_grn_grimgor", -3, 0.0, true),
+    ];
+
+    let mut data = vec![];
+    for (key, cost, multiplier, enabled) in &rows {
+        data.write_sized_string_u8(key).unwrap();
+        data.write_i32(*cost).unwrap();
+        data.write_f32(*multiplier).unwrap();
+        data.write_bool(*enabled).unwrap();
+    }
+
+    let guesses = guess_definitions(&data, 2, rows.len() as u32, GUESSABLE_FIELD_TYPES, 10);
+    assert!(!guesses.is_empty());
+
+    let best = &guesses[0];
+    let field_types = best.definition().fields().iter().map(|field| field.field_type().clone()).collect::<Vec<_>>();
+    assert_eq!(field_types, vec![FieldType::StringU8, FieldType::I32, FieldType::F32, FieldType::Boolean]);
+    assert_eq!(*best.definition().version(), 2);
+    assert_eq!(best.preview().len(), 3);
+    assert_eq!(best.preview()[1][0], DecodedData::StringU8("wh_main_dwf_thorgrim".to_owned()));
+    assert_eq!(best.preview()[2][1], DecodedData::I32(-3));
+
+    // Data that cannot be split in the amount of rows provided has no valid definitions.
+    assert!(guess_definitions(&data[..data.len() - 1], 2, rows.len() as u32, GUESSABLE_FIELD_TYPES, 10).is_empty());
+    assert!(guess_definitions(&data, 2, 0, GUESSABLE_FIELD_TYPES, 10).is_empty());
+}
+
+
+#[test]
+fn test_guess_definitions_corrupted_entry_count() {
+    let mut data = vec![];
+    data.write_i32(1500).unwrap();
+    data.write_bool(true).unwrap();
+
+    // More rows than bytes can only come from a corrupted header.
+    assert!(guess_definitions(&data, 2, data.len() as u32 + 2, GUESSABLE_FIELD_TYPES, 10).is_empty());
+    assert!(guess_definitions(&data, 2, u32::MAX, GUESSABLE_FIELD_TYPES, 10).is_empty());
+}
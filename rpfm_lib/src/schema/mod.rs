@@ -90,6 +90,20 @@ use getset::*;
 use crate::error::Result;
 use crate::files::table::DecodedData;
 
+pub use self::diff::{FieldChange, SchemaDiff, TableDiff};
+pub use self::guesser::{guess_definitions, GuessedDefinition, GUESSABLE_FIELD_TYPES};
+pub use self::overview::{FieldOverview, TableOverview};
+pub use self::patch::{export_patches_to_json, merge_patches, stamp_patches, PATCH_TIMESTAMP_KEY};
+
+mod diff;
+#[cfg(test)] mod diff_test;
+mod guesser;
+#[cfg(test)] mod guesser_test;
+mod overview;
+#[cfg(test)] mod overview_test;
+mod patch;
+#[cfg(test)] mod patch_test;
+
 // Legacy Schemas, to keep backwards compatibility during updates.
 pub(crate) mod v4;
 
@@ -239,24 +253,10 @@ impl Schema {
     }
 
     /// This function adds a list of patches into the currently loaded schema.
+    ///
+    /// Conflicting values are taken from the newest patch. Check [merge_patches] for more info.
     pub fn add_patch(&mut self, patches: HashMap<String, DefinitionPatch>) {
-        patches.iter().for_each(|(table_name, column_patch)| {
-            match self.patches.get_mut(table_name) {
-                Some(column_patch_current) => {
-                    column_patch.iter().for_each(|(column_name, patch)| {
-                        match column_patch_current.get_mut(column_name) {
-                            Some(patch_current) => patch_current.extend(patch.clone()),
-                            None => {
-                                column_patch_current.insert(column_name.to_owned(), patch.clone());
-                            }
-                        }
-                    });
-                }
-                None => {
-                    self.patches.insert(table_name.to_owned(), column_patch.clone());
-                }
-            }
-        });
+        merge_patches(&mut self.patches, &patches);
     }
 
     /// This function adds a definition for a table into the currently loaded schema.
@@ -922,6 +922,13 @@ impl Field {
         false
     }
 
+    /// Getter for the `old_name` field for schema patches.
+    ///
+    /// This is the name the column had in older versions of the table, so its data can be kept when updating the table.
+    pub fn schema_patch_old_name(&self, schema_patches: Option<&DefinitionPatch>) -> Option<String> {
+        schema_patches?.get(self.name())?.get("old_name").cloned()
+    }
+
     /// Getter for the `explanation` field for schema patches.
     pub fn schema_patch_explanation(&self, schema_patches: Option<&DefinitionPatch>) -> String {
         if let Some(schema_patches) = schema_patches {
@@ -935,6 +942,16 @@ impl Field {
         String::new()
     }
 
+    /// This function returns the description of the field, followed by the explanation from its schema patch, if any.
+    pub fn description_with_patches(&self, schema_patches: Option<&DefinitionPatch>) -> String {
+        let explanation = self.schema_patch_explanation(schema_patches);
+        match (self.description.is_empty(), explanation.is_empty()) {
+            (_, true) => self.description.to_owned(),
+            (true, false) => explanation,
+            (false, false) => format!("{}\n\n{}", self.description, explanation),
+        }
+    }
+
     /// This function maps our field to a String ready to be used in a SQL `CREATE TABLE` command.
     #[cfg(feature = "integration_sqlite")]
     pub fn map_to_sql_string(&self, schema_patches: Option<&DefinitionPatch>) -> String {
@@ -0,0 +1,103 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to get a summary of the tables in a Schema, for browsing it.
+
+Only the newest definition of each table is summarized, with its fields processed the same way they're shown in table views,
+and with the explanations from the schema patches added to their descriptions.
+!*/
+
+use getset::Getters;
+use serde_derive::{Serialize, Deserialize};
+
+use super::Schema;
+
+//---------------------------------------------------------------------------//
+//                              Enum & Structs
+//---------------------------------------------------------------------------//
+
+/// This struct contains a summary of the definitions of a table.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct TableOverview {
+
+    /// Name of the table, like `units_tables`.
+    table_name: String,
+
+    /// Versions of the table in the Schema, from newest to oldest.
+    versions: Vec<i32>,
+
+    /// Fields of the newest definition of the table.
+    fields: Vec<FieldOverview>,
+}
+
+/// This struct contains a summary of a field of a table.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct FieldOverview {
+    name: String,
+    field_type: String,
+    is_key: bool,
+    description: String,
+
+    /// `Some(referenced_table, referenced_column)` if the field is referencing another table/column.
+    reference: Option<(String, String)>,
+}
+
+//---------------------------------------------------------------------------//
+//                       Enum & Structs Implementations
+//---------------------------------------------------------------------------//
+
+impl Schema {
+
+    /// This function returns a summary of every table in the Schema, sorted by table name.
+    pub fn overview(&self) -> Vec<TableOverview> {
+        let mut tables = self.definitions().iter()
+            .filter_map(|(table_name, definitions)| {
+                let newest = definitions.iter().max_by_key(|definition| *definition.version())?;
+                let patches = self.patches_for_table(table_name);
+
+                let mut versions = definitions.iter().map(|definition| *definition.version()).collect::<Vec<_>>();
+                versions.sort_unstable_by(|a, b| b.cmp(a));
+
+                let fields = newest.fields_processed().iter()
+                    .map(|field| FieldOverview {
+                        name: field.name().to_owned(),
+                        field_type: field.field_type().to_string(),
+                        is_key: field.is_key(),
+                        description: field.description_with_patches(patches),
+                        reference: field.is_reference().clone(),
+                    })
+                    .collect();
+
+                Some(TableOverview {
+                    table_name: table_name.to_owned(),
+                    versions,
+                    fields,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        tables.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+        tables
+    }
+}
+
+impl TableOverview {
+
+    /// This function returns if the name of the table or the name of any of its fields contains the provided text, ignoring case.
+    ///
+    /// An empty text matches every table.
+    pub fn matches(&self, text: &str) -> bool {
+        let text = text.to_lowercase();
+        self.table_name.to_lowercase().contains(&text) || self.fields.iter().any(|field| field.name.to_lowercase().contains(&text))
+    }
+}
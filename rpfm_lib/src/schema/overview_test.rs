@@ -0,0 +1,57 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the summary of Schemas.
+
+use super::*;
+
+fn field(name: &str, field_type: FieldType, description: &str) -> Field {
+    let mut field = Field::default();
+    field.set_name(name.to_owned());
+    field.set_field_type(field_type);
+    field.set_description(description.to_owned());
+    field
+}
+
+#[test]
+fn test_schema_overview() {
+    let mut key = field("key", FieldType::StringU8, "Key of the unit.");
+    key.set_is_key(true);
+    let mut unit = field("unit", FieldType::StringU8, "");
+    unit.set_is_reference(Some(("land_units_tables".to_owned(), "key".to_owned())));
+
+    let mut schema = Schema::default();
+    schema.add_definition("units_tables", &Definition::new_with_fields(1, &[key.clone()], &[]));
+    schema.add_definition("units_tables", &Definition::new_with_fields(3, &[key.clone(), field("cost", FieldType::I32, "")], &[]));
+    schema.add_definition("main_units_tables", &Definition::new_with_fields(2, &[key.clone(), unit], &[]));
+
+    let patch = HashMap::from([("explanation".to_owned(), "Gold.".to_owned())]);
+    schema.add_patch(HashMap::from([("units_tables".to_owned(), HashMap::from([("cost".to_owned(), patch.clone()), ("key".to_owned(), patch)]))]));
+
+    let overview = schema.overview();
+    assert_eq!(overview.iter().map(|table| table.table_name().as_str()).collect::<Vec<_>>(), vec!["main_units_tables", "units_tables"]);
+
+    let units = &overview[1];
+    assert_eq!(units.versions(), &vec![3, 1]);
+    assert_eq!(units.fields().len(), 2);
+    assert!(*units.fields()[0].is_key());
+    assert_eq!(units.fields()[0].description(), "Key of the unit.\n\nGold.");
+    assert_eq!(units.fields()[1].description(), "Gold.");
+    assert_eq!(units.fields()[1].field_type(), "I32");
+
+    let main_units = &overview[0];
+    assert_eq!(main_units.fields()[1].reference(), &Some(("land_units_tables".to_owned(), "key".to_owned())));
+
+    assert!(units.matches(""));
+    assert!(units.matches("UNITS_"));
+    assert!(units.matches("cos"));
+    assert!(!units.matches("land_units"));
+    assert!(!main_units.matches("cost"));
+}
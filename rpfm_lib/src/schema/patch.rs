@@ -0,0 +1,76 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to merge and share Schema patches.
+
+Each column patch can have a timestamp, stored under the [PATCH_TIMESTAMP_KEY] key. When merging two patches
+for the same column, the values of the newest one win. Patches without timestamp are considered the oldest ones.
+!*/
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::error::Result;
+
+use super::DefinitionPatch;
+
+/// Key of the timestamp of a column patch, in seconds since the Unix epoch.
+pub const PATCH_TIMESTAMP_KEY: &str = "timestamp";
+
+//---------------------------------------------------------------------------//
+//                              Functions
+//---------------------------------------------------------------------------//
+
+/// This function merges the provided patches into the base ones.
+///
+/// Conflicting values for the same column are taken from the patch with the newest timestamp. On equal timestamps, the provided patches win.
+pub fn merge_patches(base: &mut HashMap<String, DefinitionPatch>, patches: &HashMap<String, DefinitionPatch>) {
+    for (table_name, column_patches) in patches {
+        let base_column_patches = base.entry(table_name.to_owned()).or_default();
+        for (column_name, patch) in column_patches {
+            match base_column_patches.get_mut(column_name) {
+                Some(base_patch) => {
+                    if patch_timestamp(patch) >= patch_timestamp(base_patch) {
+                        base_patch.extend(patch.clone());
+                    } else {
+                        patch.iter().for_each(|(key, value)| { base_patch.entry(key.to_owned()).or_insert_with(|| value.to_owned()); });
+                    }
+                }
+                None => { base_column_patches.insert(column_name.to_owned(), patch.clone()); }
+            }
+        }
+    }
+}
+
+/// This function sets the provided timestamp on all the column patches that don't have one.
+pub fn stamp_patches(patches: &mut HashMap<String, DefinitionPatch>, timestamp: u64) {
+    patches.values_mut()
+        .flat_map(|column_patches| column_patches.values_mut())
+        .for_each(|patch| { patch.entry(PATCH_TIMESTAMP_KEY.to_owned()).or_insert_with(|| timestamp.to_string()); });
+}
+
+/// This function exports the patches of the provided tables to a JSON string, in the same format the patch importer uses.
+///
+/// If no tables are provided, all the patches are exported. Tables and columns are sorted, so the output is stable.
+pub fn export_patches_to_json(patches: &HashMap<String, DefinitionPatch>, table_names: &[String]) -> Result<String> {
+    let patches = patches.iter()
+        .filter(|(table_name, _)| table_names.is_empty() || table_names.contains(table_name))
+        .map(|(table_name, column_patches)| (table_name, column_patches.iter()
+            .map(|(column_name, patch)| (column_name, patch.iter().collect::<BTreeMap<_, _>>()))
+            .collect::<BTreeMap<_, _>>()))
+        .collect::<BTreeMap<_, _>>();
+
+    serde_json::to_string_pretty(&patches).map_err(From::from)
+}
+
+/// This function returns the timestamp of a column patch, or 0 if it doesn't have a valid one.
+fn patch_timestamp(patch: &HashMap<String, String>) -> u64 {
+    patch.get(PATCH_TIMESTAMP_KEY).and_then(|timestamp| timestamp.parse().ok()).unwrap_or(0)
+}
@@ -0,0 +1,83 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for merging and sharing Schema patches.
+
+use super::*;
+
+fn patches(table_name: &str, column_name: &str, values: &[(&str, &str)]) -> HashMap<String, DefinitionPatch> {
+    let patch = values.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect::<HashMap<_, _>>();
+    HashMap::from([(table_name.to_owned(), HashMap::from([(column_name.to_owned(), patch)]))])
+}
+
+#[test]
+fn test_merge_patches_newer_wins() {
+    let older = patches("units_tables", "cost", &[("default_value", "100"), ("explanation", "Old."), ("timestamp", "10")]);
+    let newer = patches("units_tables", "cost", &[("default_value", "200"), ("timestamp", "20")]);
+
+    // The result must be the same no matter the order the patches are merged in.
+    let mut base = older.clone();
+    merge_patches(&mut base, &newer);
+    let mut base_reversed = newer.clone();
+    merge_patches(&mut base_reversed, &older);
+    assert_eq!(base, base_reversed);
+
+    let patch = &base["units_tables"]["cost"];
+    assert_eq!(patch["default_value"], "200");
+    assert_eq!(patch["explanation"], "Old.");
+    assert_eq!(patch[PATCH_TIMESTAMP_KEY], "20");
+
+    // Patches without timestamp lose against timestamped ones.
+    merge_patches(&mut base, &patches("units_tables", "cost", &[("default_value", "300")]));
+    assert_eq!(base["units_tables"]["cost"]["default_value"], "200");
+
+    // And on equal timestamps, the merged patch wins.
+    merge_patches(&mut base, &patches("units_tables", "cost", &[("default_value", "400"), ("timestamp", "20")]));
+    assert_eq!(base["units_tables"]["cost"]["default_value"], "400");
+}
+
+#[test]
+fn test_stamp_patches() {
+    let mut base = patches("units_tables", "cost", &[("default_value", "100")]);
+    base.extend(patches("land_units_tables", "key", &[("explanation", "Key."), ("timestamp", "5")]));
+    stamp_patches(&mut base, 50);
+
+    assert_eq!(base["units_tables"]["cost"][PATCH_TIMESTAMP_KEY], "50");
+    assert_eq!(base["land_units_tables"]["key"][PATCH_TIMESTAMP_KEY], "5");
+}
+
+#[test]
+fn test_export_patches_round_trip() {
+    let mut schema = Schema::default();
+    schema.add_patch(patches("units_tables", "cost", &[("default_value", "100"), ("timestamp", "10")]));
+    schema.add_patch(patches("units_tables", "key", &[("not_empty", "true")]));
+    schema.add_patch(patches("land_units_tables", "key", &[("explanation", "Key.")]));
+
+    // Empty means everything.
+    let json = export_patches_to_json(schema.patches(), &[]).unwrap();
+    assert_eq!(json, export_patches_to_json(schema.patches(), &[]).unwrap());
+    let imported: HashMap<String, DefinitionPatch> = serde_json::from_str(&json).unwrap();
+    assert_eq!(&imported, schema.patches());
+
+    let json = export_patches_to_json(schema.patches(), &["units_tables".to_owned()]).unwrap();
+    let imported: HashMap<String, DefinitionPatch> = serde_json::from_str(&json).unwrap();
+    assert_eq!(imported.len(), 1);
+    assert_eq!(imported["units_tables"], schema.patches()["units_tables"]);
+
+    // Tables and columns are sorted in the output.
+    let json = export_patches_to_json(schema.patches(), &[]).unwrap();
+    let units = json.find("\"units_tables\"").unwrap();
+    assert!(json.find("\"land_units_tables\"").unwrap() < units);
+    assert!(json[units..].find("\"cost\"").unwrap() < json[units..].find("\"key\"").unwrap());
+
+    let mut other = Schema::default();
+    other.add_patch(imported);
+    assert_eq!(other.patches()["units_tables"], schema.patches()["units_tables"]);
+}
@@ -2,15 +2,24 @@
 #define PACKED_FILE_MODEL_H
 
 #include "qt_subclasses_global.h"
+#include <QLineEdit>
+#include <QMimeData>
 #include <QStandardItemModel>
 #include <QStringListModel>
+#include <QUrl>
 
 extern "C" QStandardItemModel* new_packed_file_model();
+extern "C" QLineEdit* enable_external_drops(QStandardItemModel* model, QWidget* parent);
 
 class PackedFileModel : public QStandardItemModel {
     Q_OBJECT
 public:
     Qt::ItemFlags flags(const QModelIndex &index) const;
+    QStringList mimeTypes() const;
+    bool canDropMimeData(const QMimeData *data, Qt::DropAction action, int row, int column, const QModelIndex &parent) const;
+    bool dropMimeData(const QMimeData *data, Qt::DropAction action, int row, int column, const QModelIndex &parent);
+
+    QLineEdit* dropNotifier = nullptr;
 };
 
 #endif // PACKED_FILE_MODEL_H
@@ -14,7 +14,12 @@ extern "C" void trigger_tableview_filter(
     QStringList patterns = QStringList(),
     QList<int> case_sensitive = QList<int>(),
     QList<int> show_blank_cells = QList<int>(),
-    QList<int> match_groups_per_column = QList<int>()
+    QList<int> match_groups_per_column = QList<int>(),
+    QList<int> filter_types = QList<int>()
+);
+extern "C" void set_tableview_filter_column_types(
+    QSortFilterProxyModel *filter = nullptr,
+    QList<int> column_types = QList<int>()
 );
 
 class QTableViewSortFilterProxyModel : public QSortFilterProxyModel
@@ -22,11 +27,22 @@ class QTableViewSortFilterProxyModel : public QSortFilterProxyModel
     Q_OBJECT
 
 public:
+
+    // Types of comparisons the filter can do. These must be kept in sync with the FilterType enum on the Rust side.
+    enum FilterType {
+        Text = 0,
+        Integer = 1,
+        Float = 2,
+        Colour = 3,
+    };
+
     QList<int> columns;
     QStringList patterns;
     QList<int> case_sensitive;
     QList<int> show_blank_cells;
     QList<int> match_groups_per_column;
+    QList<int> filter_types;
+    QList<int> column_types;
 
     explicit QTableViewSortFilterProxyModel(QObject *parent = nullptr);
     bool filterAcceptsRow(int source_row, const QModelIndex & source_parent) const;
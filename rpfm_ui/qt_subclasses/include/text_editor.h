@@ -11,7 +11,10 @@
 #include <KTextEditor/Editor>
 #include <KTextEditor/View>
 #endif
+#include <QAction>
 #include <QLineEdit>
+#include <QMenu>
+#include <QRegularExpression>
 
 // This one is needed for the save fix.
 #include <KActionCollection>
@@ -20,6 +23,8 @@ extern "C" QWidget* new_text_editor(QWidget* parent = nullptr);
 
 extern "C" QString* get_text(QWidget* parent = nullptr);
 
+extern "C" void add_text_editor_context_menu_action(QWidget* view = nullptr, QAction* action = nullptr);
+
 extern "C" void set_text(QWidget* view = nullptr, QString* text = nullptr, QString* highlighting_mode = nullptr);
 
 extern "C" void open_text_editor_config(QWidget* parent);
@@ -28,4 +33,14 @@ extern "C" QLineEdit* get_text_changed_dummy_widget(QWidget* view = nullptr);
 
 extern "C" void scroll_to_row(QWidget* view = nullptr, int row_number = 0);
 
+extern "C" int cursor_row(QWidget* view = nullptr);
+
+extern "C" int text_search_count(QWidget* view = nullptr, QString* pattern = nullptr, int flags = 0);
+
+extern "C" bool text_search_find(QWidget* view = nullptr, QString* pattern = nullptr, int flags = 0, bool backwards = false, bool incremental = false);
+
+extern "C" bool text_search_replace(QWidget* view = nullptr, QString* pattern = nullptr, QString* replacement = nullptr, int flags = 0);
+
+extern "C" int text_search_replace_all(QWidget* view = nullptr, QString* pattern = nullptr, QString* replacement = nullptr, int flags = 0);
+
 #endif // TEXT_EDITOR_H
@@ -21,7 +21,9 @@ extern "C" void shortcut_collection_init(QWidget* parent, QList<QObject*>* short
     new_action(pack_menu_actions, "save_pack_as", "Save Pack As", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString("Ctrl+Shift+S"), "document-save-as");
     new_action(pack_menu_actions, "install_pack", "Install Pack", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString("Ctrl+Shift+I"), "format-align-vertical-top");
     new_action(pack_menu_actions, "uninstall_pack", "Uninstall Pack", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString("Ctrl+Shift+U"), "format-align-vertical-bottom");
+    new_action(pack_menu_actions, "pack_notes", "Pack Notes", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "view-pim-notes");
     new_action(pack_menu_actions, "load_all_ca_packs", "Load All CA Packs", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString("Ctrl+G"), "dialog-object-properties");
+    new_action(pack_menu_actions, "load_ca_packs_filtered", "Load CA Packs...", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString("Ctrl+Shift+G"), "dialog-object-properties");
     new_action(pack_menu_actions, "settings", "Settings", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString("Ctrl+P"), "settings-configure");
     new_action(pack_menu_actions, "quit", "Quit", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "gtk-quit");
     pack_menu_actions->readSettings();
@@ -44,15 +46,19 @@ extern "C" void shortcut_collection_init(QWidget* parent, QList<QObject*>* short
     new_action(view_menu_actions, "diagnostics_panel", "Diagnostics Panel", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "builder-view-left-pane-symbolic");
     new_action(view_menu_actions, "dependencies_panel", "Dependencies Panel", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "builder-view-left-pane-symbolic");
     new_action(view_menu_actions, "references_panel", "References Panel", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "builder-view-left-pane-symbolic");
+    new_action(view_menu_actions, "modified_column", "Modified Column", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "view-calendar");
     view_menu_actions->readSettings();
 
     // Game Selected Menu actions.
     KActionCollection* game_selected_menu_actions = new KActionCollection(parent, "game_selected_menu");
     game_selected_menu_actions->setComponentDisplayName("Game Selected Menu");
     new_action(game_selected_menu_actions, "launch_game", "Launch Game", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "media-playback-start-symbolic");
+    new_action(game_selected_menu_actions, "launch_game_with_current_mod", "Launch Game With Current Mod", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "media-playback-start-symbolic");
+    new_action(game_selected_menu_actions, "restore_mod_list", "Restore Mod List", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "edit-undo");
     new_action(game_selected_menu_actions, "open_game_data_folder", "Open Game Data Folder", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "document-open-folder");
     new_action(game_selected_menu_actions, "open_game_ak_folder", "Open Game Assembly Kit Folder", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "document-open-folder");
     new_action(game_selected_menu_actions, "open_rpfm_config_folder", "Open RPFM Config Folder", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "document-open-folder");
+    new_action(game_selected_menu_actions, "schema_browser", "Schema Browser", Qt::ShortcutContext::ApplicationShortcut, QKeySequence::listFromString(""), "view-list-details");
     game_selected_menu_actions->readSettings();
 
     // Special Stuff Menu actions.
@@ -96,21 +102,33 @@ extern "C" void shortcut_collection_init(QWidget* parent, QList<QObject*>* short
     new_action(pack_tree_actions, "add_file", "Add File", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+A"), "document-new-symbolic");
     new_action(pack_tree_actions, "add_folder", "Add Folder", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+Shift+A"), "tab-new-symbolic");
     new_action(pack_tree_actions, "add_from_pack", "Add From Pack", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+Alt+A"), "labplot-workbook-new");
+    new_action(pack_tree_actions, "add_from_archive", "Add From Archive", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "archive-extract");
+    new_action(pack_tree_actions, "open_pack_in_side_pane", "Open Pack In Side Pane", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "view-split-left-right");
+    new_action(pack_tree_actions, "paste_from_side_pane", "Paste From Side Pane", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+V"), "edit-paste");
     new_action(pack_tree_actions, "new_folder", "New Folder", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+F"), "albumfolder-new");
     new_action(pack_tree_actions, "new_animpack", "New AnimPack", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "document-new");
     new_action(pack_tree_actions, "new_db", "New DB", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+D"), "document-new");
     new_action(pack_tree_actions, "new_loc", "New Loc", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+L"), "document-new");
     new_action(pack_tree_actions, "new_text", "New Text", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+T"), "document-new");
+    new_action(pack_tree_actions, "new_from_template", "New from Template", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "document-new-from-template");
     new_action(pack_tree_actions, "new_quick_file", "New Quick File", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+Q"), "document-new");
     new_action(pack_tree_actions, "merge_files", "Merge Files", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+M"), "merge");
     new_action(pack_tree_actions, "update_files", "Update Tables", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "edit-text-frame-update");
     new_action(pack_tree_actions, "generate_missing_loc_data", "Generate Missing Loc Data", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "languages");
     new_action(pack_tree_actions, "delete", "Delete", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Del"), "entry-delete");
+    new_action(pack_tree_actions, "undo", "Undo", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+Z"), "edit-undo-symbolic");
+    new_action(pack_tree_actions, "redo", "Redo", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+Shift+Z"), "edit-redo-symbolic");
     new_action(pack_tree_actions, "extract", "Extract", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+E"), "archive-extract");
+    new_action(pack_tree_actions, "cut", "Cut", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+X"), "edit-cut");
+    new_action(pack_tree_actions, "copy", "Copy", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+C"), "edit-copy");
+    new_action(pack_tree_actions, "paste", "Paste", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+Shift+V"), "edit-paste");
+    new_action(pack_tree_actions, "duplicate_table", "Duplicate Table", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+Shift+D"), "edit-copy");
     new_action(pack_tree_actions, "rename", "Rename", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+R"), "edit-move");
+    new_action(pack_tree_actions, "rename_by_pattern", "Rename by Pattern", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+Shift+R"), "edit-find-replace");
     new_action(pack_tree_actions, "copy_path", "Copy Path", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "edit-copy-path");
     new_action(pack_tree_actions, "open_in_decoder", "Open In Decoder", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+J"), "document-edit-decrypt");
     new_action(pack_tree_actions, "open_dependency_manager", "Open Dependency Manager", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "dblatex");
+    new_action(pack_tree_actions, "open_dependencies_editor", "Open Dependencies Editor", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "dblatex");
     new_action(pack_tree_actions, "open_in_external_program", "Open In External Program", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+K"), "quickopen-function");
     new_action(pack_tree_actions, "open_containing_folder", "Open Containing Folder", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "document-open");
     new_action(pack_tree_actions, "open_pack_settings", "Open Pack Settings", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "settings-configure");
@@ -143,6 +161,8 @@ extern "C" void shortcut_collection_init(QWidget* parent, QList<QObject*>* short
     secondary_pack_tree_actions->setComponentDisplayName("Pack Tree Context Menu");
     new_action(secondary_pack_tree_actions, "expand", "Expand", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl++"), "expand-all-symbolic");
     new_action(secondary_pack_tree_actions, "collapse", "Collapse", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+-"), "collapse-all-symbolic");
+    new_action(secondary_pack_tree_actions, "copy", "Copy", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+C"), "edit-copy");
+    new_action(secondary_pack_tree_actions, "add_to_pack", "Add To Pack", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "document-import");
     secondary_pack_tree_actions->readSettings();
 
     // Table Editor actions.
@@ -172,6 +192,7 @@ extern "C" void shortcut_collection_init(QWidget* parent, QList<QObject*>* short
     new_action(table_editor_actions, "smart_delete", "Smart Delete", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Del"), "edit-delete-shred");
     new_action(table_editor_actions, "resize_columns", "Resize Columns", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "resizecol");
     new_action(table_editor_actions, "rename_references", "Rename References", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "tool_references");
+    new_action(table_editor_actions, "deep_clone", "Deep Clone", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "edit-copy");
     new_action(table_editor_actions, "patch_columns", "Patch Columns", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "edit-table-insert-column-right");
     new_action(table_editor_actions, "find_references", "Find References", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "folder-saved-search-symbolic");
     new_action(table_editor_actions, "go_to_definition", "Go To Definition", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString(""), "go-jump-definition");
@@ -189,11 +210,23 @@ extern "C" void shortcut_collection_init(QWidget* parent, QList<QObject*>* short
     new_action(decoder_actions, "load_definition", "Load Definition", Qt::ShortcutContext::WidgetShortcut, QKeySequence::listFromString("Ctrl+L"), "kt-set-max-upload-speed");
     decoder_actions->readSettings();
 
+    // Text Search actions.
+    KActionCollection* text_search_actions = new KActionCollection(parent, "text_search");
+    text_search_actions->setComponentDisplayName("Text Search");
+    new_action(text_search_actions, "find", "Find", Qt::ShortcutContext::WidgetWithChildrenShortcut, QKeySequence::listFromString("Ctrl+F"), "edit-find");
+    new_action(text_search_actions, "find_next", "Find Next", Qt::ShortcutContext::WidgetWithChildrenShortcut, QKeySequence::listFromString("F3"), "go-down-search");
+    new_action(text_search_actions, "find_previous", "Find Previous", Qt::ShortcutContext::WidgetWithChildrenShortcut, QKeySequence::listFromString("Shift+F3"), "go-up-search");
+    text_search_actions->readSettings();
+
     // Text Editor actions.
     KTextEditor::Editor *editor = KTextEditor::Editor::instance();
     KTextEditor::Document *doc = editor->createDocument(nullptr);
     KTextEditor::View *view = doc->createView(nullptr);
     KActionCollection* text_editor_actions = view->actionCollection();
+    text_editor_actions->removeAction(text_editor_actions->action("edit_find"));
+    text_editor_actions->removeAction(text_editor_actions->action("edit_find_next"));
+    text_editor_actions->removeAction(text_editor_actions->action("edit_find_prev"));
+    text_editor_actions->removeAction(text_editor_actions->action("edit_replace"));
     text_editor_actions->readSettings();
 
     // Add all the actions to our list.
@@ -210,6 +243,7 @@ extern "C" void shortcut_collection_init(QWidget* parent, QList<QObject*>* short
     shortcuts->append(dynamic_cast<QObject*>(secondary_pack_tree_actions));
     shortcuts->append(dynamic_cast<QObject*>(table_editor_actions));
     shortcuts->append(dynamic_cast<QObject*>(decoder_actions));
+    shortcuts->append(dynamic_cast<QObject*>(text_search_actions));
     shortcuts->append(dynamic_cast<QObject*>(text_editor_actions));
 }
 
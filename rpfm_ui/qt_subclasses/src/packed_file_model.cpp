@@ -5,6 +5,21 @@ extern "C" QStandardItemModel* new_packed_file_model() {
     return dynamic_cast<QStandardItemModel*>(new PackedFileModel());
 }
 
+// Function to allow dropping files from outside the program into a PackedFileModel.
+//
+// Dropped files are notified through the textChanged signal of the returned dummy widget, with the folder
+// they were dropped into in the first line, and the paths of the dropped files and folders in the next ones.
+extern "C" QLineEdit* enable_external_drops(QStandardItemModel* model, QWidget* parent) {
+    PackedFileModel* packed_file_model = dynamic_cast<PackedFileModel*>(model);
+
+    QLineEdit* dummy = new QLineEdit(parent);
+    dummy->setObjectName("DropDummy");
+    dummy->setVisible(false);
+
+    packed_file_model->dropNotifier = dummy;
+    return dummy;
+}
+
 // Function to check if an item can be drag or drop into.
 //
 // TODO: Expand this to ensure only unique items can be drop into folders, so we don't have duplicate names in the same folder.
@@ -33,8 +48,72 @@ Qt::ItemFlags PackedFileModel::flags(const QModelIndex &index) const {
         }
     }
 
-    // In case of invalid index, do not allow anything.
+    // In case of invalid index, only allow drops from outside, which go to the root of the PackFile.
+    else if (dropNotifier != nullptr) {
+        return Qt::ItemIsDropEnabled | defaultFlags;
+    }
+
+    // Otherwise, do not allow anything.
     else {
         return defaultFlags;
     }
 }
+
+// Function to add the mime type of files dropped from outside to the ones we accept.
+QStringList PackedFileModel::mimeTypes() const {
+    QStringList types = QStandardItemModel::mimeTypes();
+    types << "text/uri-list";
+    return types;
+}
+
+// Function to check if what's being dragged from outside can be dropped. Only local files and folders are allowed.
+bool PackedFileModel::canDropMimeData(const QMimeData *data, Qt::DropAction action, int row, int column, const QModelIndex &parent) const {
+    if (data->hasUrls()) {
+        if (dropNotifier == nullptr) {
+            return false;
+        }
+
+        for (const QUrl &url : data->urls()) {
+            if (!url.isLocalFile()) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    return QStandardItemModel::canDropMimeData(data, action, row, column, parent);
+}
+
+// Function to handle files dropped from outside. We don't touch the model here, we just notify the drop.
+bool PackedFileModel::dropMimeData(const QMimeData *data, Qt::DropAction action, int row, int column, const QModelIndex &parent) {
+    if (data->hasUrls()) {
+        if (!canDropMimeData(data, action, row, column, parent)) {
+            return false;
+        }
+
+        // Files cannot be dropped into, so if we got one, we use its folder.
+        QModelIndex folder = parent;
+        if (folder.isValid() && itemFromIndex(folder)->data(20).toInt() == 1) {
+            folder = folder.parent();
+        }
+
+        // Get the path of the folder within the PackFile, skipping the PackFile item itself.
+        QStringList folder_path;
+        while (folder.isValid() && folder.parent().isValid()) {
+            folder_path.prepend(folder.data().toString());
+            folder = folder.parent();
+        }
+
+        QStringList payload;
+        payload << folder_path.join("/");
+        for (const QUrl &url : data->urls()) {
+            payload << url.toLocalFile();
+        }
+
+        emit dropNotifier->textChanged(payload.join("\n"));
+        return true;
+    }
+
+    return QStandardItemModel::dropMimeData(data, action, row, column, parent);
+}
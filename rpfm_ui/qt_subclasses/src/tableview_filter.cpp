@@ -18,7 +18,8 @@ extern "C" void trigger_tableview_filter(
     QStringList patterns,
     QList<int> case_sensitive,
     QList<int> show_blank_cells,
-    QList<int> match_groups_per_column
+    QList<int> match_groups_per_column,
+    QList<int> filter_types
 ) {
     QTableViewSortFilterProxyModel* filter2 = static_cast<QTableViewSortFilterProxyModel*>(filter);
     filter2->columns = columns;
@@ -26,9 +27,78 @@ extern "C" void trigger_tableview_filter(
     filter2->case_sensitive = case_sensitive;
     filter2->show_blank_cells = show_blank_cells;
     filter2->match_groups_per_column = match_groups_per_column;
+    filter2->filter_types = filter_types;
     filter2->setFilterKeyColumn(0);
 }
 
+// Function to tell the filter the type of each column, so it can sort them properly.
+extern "C" void set_tableview_filter_column_types(QSortFilterProxyModel* filter, QList<int> column_types) {
+    QTableViewSortFilterProxyModel* filter2 = static_cast<QTableViewSortFilterProxyModel*>(filter);
+    filter2->column_types = column_types;
+    filter2->invalidate();
+}
+
+// Function to check if a value is within a range. The range is in the format "[a,b]", using "(" and ")" for exclusive ends, and empty ends for unbounded ones.
+template <typename T>
+static bool isInRange(T value, const QString &range, T (*parse)(const QString &, bool *)) {
+    if (range.length() < 3) {
+        return false;
+    }
+
+    QStringList bounds = range.mid(1, range.length() - 2).split(',');
+    if (bounds.count() != 2) {
+        return false;
+    }
+
+    bool ok = true;
+    if (!bounds.at(0).isEmpty()) {
+        T start = parse(bounds.at(0), &ok);
+        if (!ok || value < start || (range.startsWith('(') && value == start)) {
+            return false;
+        }
+    }
+
+    if (!bounds.at(1).isEmpty()) {
+        T end = parse(bounds.at(1), &ok);
+        if (!ok || value > end || (range.endsWith(')') && value == end)) {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+static qlonglong parseLongLong(const QString &text, bool *ok) { return text.toLongLong(ok); }
+static float parseFloat(const QString &text, bool *ok) { return text.toFloat(ok); }
+static double parseDouble(const QString &text, bool *ok) { return text.toDouble(ok); }
+
+// Function to check if a cell matches a typed pattern.
+static bool matchesTypedPattern(const QVariant &data, const QString &pattern, int filter_type) {
+    switch (filter_type) {
+        case QTableViewSortFilterProxyModel::Integer:
+            return isInRange<qlonglong>(data.toLongLong(), pattern, parseLongLong);
+
+        // F32 values are compared as floats, so what the user types matches what the cell shows.
+        case QTableViewSortFilterProxyModel::Float:
+            if (data.userType() == QMetaType::Float) {
+                return isInRange<float>(data.toFloat(), pattern, parseFloat);
+            } else {
+                return isInRange<double>(data.toDouble(), pattern, parseDouble);
+            }
+
+        case QTableViewSortFilterProxyModel::Colour: {
+            bool ok_data = false;
+            bool ok_pattern = false;
+            uint colour = data.toString().toUInt(&ok_data, 16);
+            uint colour_pattern = pattern.toUInt(&ok_pattern, 16);
+            return ok_data && ok_pattern && colour == colour_pattern;
+        }
+
+        default:
+            return false;
+    }
+}
+
 // Constructor of QTableViewSortFilterProxyModel.
 QTableViewSortFilterProxyModel::QTableViewSortFilterProxyModel(QObject *parent): QSortFilterProxyModel(parent) {}
 
@@ -66,6 +136,7 @@ bool QTableViewSortFilterProxyModel::filterAcceptsRow(int source_row, const QMod
             QString const pattern = patterns.at(match);
             Qt::CaseSensitivity case_sensitivity = static_cast<Qt::CaseSensitivity>(case_sensitive.at(match));
             bool show_blank_cells_in_column = show_blank_cells.at(match) == 1 ? true: false;
+            int filter_type = filter_types.count() > match ? filter_types.at(match) : QTableViewSortFilterProxyModel::Text;
 
             QRegularExpression::PatternOptions options = QRegularExpression::PatternOptions();
             if (case_sensitivity == Qt::CaseSensitivity::CaseInsensitive) {
@@ -95,6 +166,14 @@ bool QTableViewSortFilterProxyModel::filterAcceptsRow(int source_row, const QMod
                     continue;
                 }
 
+                // Numeric and colour matches.
+                else if (filter_type != QTableViewSortFilterProxyModel::Text) {
+                    if (!matchesTypedPattern(currntIndex.data(2), pattern, filter_type)) {
+                        is_group_valid = false;
+                        break;
+                    }
+                }
+
                 // Text matches.
                 else if (regex.isValid()) {
                     QRegularExpressionMatch match = regex.match(currntIndex.data(2).toString());
@@ -134,7 +213,18 @@ bool QTableViewSortFilterProxyModel::lessThan(const QModelIndex &left, const QMo
         } else {
             return true;
         }
-    } else {
-        return QSortFilterProxyModel::lessThan(left, right);
+    }
+
+    // Numeric and colour columns are sorted by their value, not by their text.
+    int column_type = column_types.count() > left.column() ? column_types.at(left.column()) : QTableViewSortFilterProxyModel::Text;
+    switch (column_type) {
+        case QTableViewSortFilterProxyModel::Integer:
+            return left.data(2).toLongLong() < right.data(2).toLongLong();
+        case QTableViewSortFilterProxyModel::Float:
+            return left.data(2).toDouble() < right.data(2).toDouble();
+        case QTableViewSortFilterProxyModel::Colour:
+            return left.data(2).toString().toUInt(nullptr, 16) < right.data(2).toString().toUInt(nullptr, 16);
+        default:
+            return QSortFilterProxyModel::lessThan(left, right);
     }
 }
@@ -14,6 +14,12 @@ extern "C" QWidget* new_text_editor(QWidget* parent) {
     actions->removeAction(actions->action("file_save"));
     actions->removeAction(actions->action("file_save_as"));
 
+    // Remove the search actions too, as we use our own search bar, and their shortcuts collide with the ones from it.
+    actions->removeAction(actions->action("edit_find"));
+    actions->removeAction(actions->action("edit_find_next"));
+    actions->removeAction(actions->action("edit_find_prev"));
+    actions->removeAction(actions->action("edit_replace"));
+
     QLineEdit* dummy = new QLineEdit(view);
     dummy->setObjectName("Dummy");
     dummy->setVisible(false);
@@ -33,6 +39,20 @@ extern "C" QString* get_text(QWidget* view) {
     return text;
 }
 
+// Function to add an action at the end of the context menu of the text editor.
+extern "C" void add_text_editor_context_menu_action(QWidget* view, QAction* action) {
+
+    KTextEditor::View* doc_view = dynamic_cast<KTextEditor::View*>(view);
+    QMenu* menu = doc_view->contextMenu();
+    if (menu == nullptr) {
+        menu = doc_view->defaultContextMenu(new QMenu(view));
+        doc_view->setContextMenu(menu);
+    }
+
+    menu->addSeparator();
+    menu->addAction(action);
+}
+
 // Function to set the current text of the text editor.
 extern "C" void set_text(QWidget* view, QString* text, QString* highlighting_mode) {
 
@@ -89,3 +109,188 @@ extern "C" int cursor_row(QWidget* view) {
     KTextEditor::View* doc_view = dynamic_cast<KTextEditor::View*>(view);
     return doc_view->cursorPosition().line();
 }
+
+// Flags for the search functions. Keep them in sync with the ones in the Rust side.
+const int SEARCH_CASE_SENSITIVE = 1;
+const int SEARCH_WHOLE_WORDS = 2;
+const int SEARCH_REGEX = 4;
+
+// Function to turn our search flags into the pattern and options the document expects.
+//
+// The document doesn't support whole words with regexes, so in that case we wrap the pattern with word boundaries.
+KTextEditor::SearchOptions search_options(QString* pattern, int flags, bool backwards) {
+    KTextEditor::SearchOptions options = KTextEditor::Default;
+    if (!(flags & SEARCH_CASE_SENSITIVE)) {
+        options |= KTextEditor::CaseInsensitive;
+    }
+
+    if (flags & SEARCH_REGEX) {
+        options |= KTextEditor::Regex;
+        if (flags & SEARCH_WHOLE_WORDS) {
+            *pattern = QString("\\b(?:%1)\\b").arg(*pattern);
+        }
+    } else if (flags & SEARCH_WHOLE_WORDS) {
+        options |= KTextEditor::WholeWords;
+    }
+
+    if (backwards) {
+        options |= KTextEditor::Backwards;
+    }
+
+    return options;
+}
+
+// Function to check if a search pattern can be used. Only invalid regexes fail this check.
+bool search_pattern_is_valid(QString pattern, int flags) {
+    return !pattern.isEmpty() && (!(flags & SEARCH_REGEX) || QRegularExpression(pattern).isValid());
+}
+
+// Function to find all the matches of a pattern in the document, from the start to the end of it.
+QVector<KTextEditor::Range> search_all(KTextEditor::Document* doc, QString pattern, int flags) {
+    QVector<KTextEditor::Range> matches;
+    KTextEditor::SearchOptions options = search_options(&pattern, flags, false);
+    KTextEditor::Cursor start = KTextEditor::Cursor::start();
+
+    while (start.isValid() && start < doc->documentEnd()) {
+        KTextEditor::Range match = doc->searchText(KTextEditor::Range(start, doc->documentEnd()), pattern, options).first();
+        if (!match.isValid()) {
+            break;
+        }
+
+        matches.append(match);
+
+        // Empty matches (like a regex for the start of the line) need to move the cursor manually, or we'll loop forever.
+        if (match.isEmpty()) {
+            if (match.end().column() < doc->lineLength(match.end().line())) {
+                start = KTextEditor::Cursor(match.end().line(), match.end().column() + 1);
+            } else {
+                start = KTextEditor::Cursor(match.end().line() + 1, 0);
+            }
+        } else {
+            start = match.end();
+        }
+    }
+
+    return matches;
+}
+
+// Function to get the text a match has to be replaced with, expanding the captures of the regex if needed.
+QString search_replacement(KTextEditor::Document* doc, KTextEditor::Range match, QString pattern, QString replacement, int flags) {
+    if (!(flags & SEARCH_REGEX)) {
+        return replacement;
+    }
+
+    if (flags & SEARCH_WHOLE_WORDS) {
+        pattern = QString("\\b(?:%1)\\b").arg(pattern);
+    }
+
+    QRegularExpression::PatternOptions options = QRegularExpression::NoPatternOption;
+    if (!(flags & SEARCH_CASE_SENSITIVE)) {
+        options |= QRegularExpression::CaseInsensitiveOption;
+    }
+
+    QString text = doc->text(match);
+    return text.replace(QRegularExpression(pattern, options), replacement);
+}
+
+// Function to count the matches of a pattern in the text editor. Returns -1 if the pattern is an invalid regex.
+extern "C" int text_search_count(QWidget* view, QString* pattern, int flags) {
+    if (pattern->isEmpty()) {
+        return 0;
+    }
+
+    if (!search_pattern_is_valid(*pattern, flags)) {
+        return -1;
+    }
+
+    KTextEditor::View* doc_view = dynamic_cast<KTextEditor::View*>(view);
+    return search_all(doc_view->document(), *pattern, flags).count();
+}
+
+// Function to select the next (or previous) match of a pattern in the text editor, wrapping around the document if needed.
+//
+// Incremental searches start from the start of the current selection, so the current match is kept while the pattern keeps matching it.
+extern "C" bool text_search_find(QWidget* view, QString* pattern, int flags, bool backwards, bool incremental) {
+    if (!search_pattern_is_valid(*pattern, flags)) {
+        return false;
+    }
+
+    KTextEditor::View* doc_view = dynamic_cast<KTextEditor::View*>(view);
+    KTextEditor::Document* doc = doc_view->document();
+
+    QString search_pattern = *pattern;
+    KTextEditor::SearchOptions options = search_options(&search_pattern, flags, backwards);
+
+    KTextEditor::Cursor from = doc_view->cursorPosition();
+    if (doc_view->selection() && (backwards || incremental)) {
+        from = doc_view->selectionRange().start();
+    }
+
+    KTextEditor::Range range = backwards ? KTextEditor::Range(KTextEditor::Cursor::start(), from) : KTextEditor::Range(from, doc->documentEnd());
+    KTextEditor::Range match = doc->searchText(range, search_pattern, options).first();
+
+    // If we didn't find anything, try again from the other end of the document.
+    if (!match.isValid()) {
+        range = backwards ? KTextEditor::Range(from, doc->documentEnd()) : KTextEditor::Range(KTextEditor::Cursor::start(), from);
+        match = doc->searchText(range, search_pattern, options).first();
+    }
+
+    if (!match.isValid()) {
+        return false;
+    }
+
+    doc_view->setCursorPosition(match.end());
+    doc_view->setSelection(match);
+    return true;
+}
+
+// Function to replace the currently selected match of a pattern in the text editor, and select the next one.
+//
+// If the selection is not a match, it just selects the next match. Returns if something was replaced.
+extern "C" bool text_search_replace(QWidget* view, QString* pattern, QString* replacement, int flags) {
+    if (!search_pattern_is_valid(*pattern, flags)) {
+        return false;
+    }
+
+    KTextEditor::View* doc_view = dynamic_cast<KTextEditor::View*>(view);
+    KTextEditor::Document* doc = doc_view->document();
+
+    bool replaced = false;
+    if (doc_view->selection()) {
+        KTextEditor::Range selection = doc_view->selectionRange();
+        QString search_pattern = *pattern;
+        KTextEditor::SearchOptions options = search_options(&search_pattern, flags, false);
+        KTextEditor::Range match = doc->searchText(selection, search_pattern, options).first();
+
+        if (match.isValid() && match == selection) {
+            QString text = search_replacement(doc, match, *pattern, *replacement, flags);
+            doc->replaceText(match, text);
+            replaced = true;
+        }
+    }
+
+    text_search_find(view, pattern, flags, false, false);
+    return replaced;
+}
+
+// Function to replace all the matches of a pattern in the text editor. Returns the amount of replaced matches.
+//
+// All the replacements are done in a single transaction, so they can be undone in one step.
+extern "C" int text_search_replace_all(QWidget* view, QString* pattern, QString* replacement, int flags) {
+    if (!search_pattern_is_valid(*pattern, flags)) {
+        return 0;
+    }
+
+    KTextEditor::View* doc_view = dynamic_cast<KTextEditor::View*>(view);
+    KTextEditor::Document* doc = doc_view->document();
+    QVector<KTextEditor::Range> matches = search_all(doc, *pattern, flags);
+
+    // Replace them from the end, so the ranges of the pending matches are not moved by the previous replacements.
+    KTextEditor::Document::EditingTransaction transaction(doc);
+    for (int i = matches.count() - 1; i >= 0; --i) {
+        QString text = search_replacement(doc, matches[i], *pattern, *replacement, flags);
+        doc->replaceText(matches[i], text);
+    }
+
+    return matches.count();
+}
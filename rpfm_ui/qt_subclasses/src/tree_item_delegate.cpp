@@ -88,6 +88,19 @@ void QTreeItemDelegate::paint(QPainter *painter, const QStyleOptionViewItem &opt
                 painter->drawLine(QLineF(option.rect.x() + option.rect.width() - (lineWidth / 2), option.rect.y() + (lineWidth / 2), option.rect.x() + option.rect.width() - (lineWidth / 2), option.rect.y() + option.rect.height() - (lineWidth / 4)));
             }
 
+            // Labelled items get a dot with the colour of their label, left of the status mark.
+            QString labelColour = item->data(25).toString();
+            if (!labelColour.isEmpty()) {
+                QColor colour = QColor(labelColour);
+                if (colour.isValid()) {
+                    int diameter = 8;
+                    painter->setRenderHint(QPainter::Antialiasing, true);
+                    painter->setPen(Qt::NoPen);
+                    painter->setBrush(colour);
+                    painter->drawEllipse(QRectF(option.rect.x() + option.rect.width() - diameter - 6, option.rect.y() + (option.rect.height() - diameter) / 2.0, diameter, diameter));
+                }
+            }
+
             // Remember to restore the painter so we can reuse it for other cells.
             painter->restore();
         }
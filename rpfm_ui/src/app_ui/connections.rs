@@ -28,14 +28,22 @@ pub unsafe fn set_connections(app_ui: &Rc<AppUI>, slots: &AppUISlots) {
     // `PackFile` menu connections.
     //-----------------------------------------------//
     app_ui.menu_bar_packfile.about_to_show().connect(&slots.packfile_open_menu);
+    app_ui.packfile_open_recent.custom_context_menu_requested().connect(&slots.packfile_open_recent_context_menu);
+    app_ui.packfile_open_recent_show_all_games.triggered().connect(&slots.packfile_open_recent_show_all_games);
 
     app_ui.packfile_new_packfile.triggered().connect(&slots.packfile_new_packfile);
     app_ui.packfile_open_packfile.triggered().connect(&slots.packfile_open_packfile);
     app_ui.packfile_save_packfile.triggered().connect(&slots.packfile_save_packfile);
     app_ui.packfile_save_packfile_as.triggered().connect(&slots.packfile_save_packfile_as);
+    app_ui.packfile_restore_autosave.triggered().connect(&slots.packfile_restore_autosave);
     app_ui.packfile_install.triggered().connect(&slots.packfile_install);
     app_ui.packfile_uninstall.triggered().connect(&slots.packfile_uninstall);
+    app_ui.packfile_notes.triggered().connect(&slots.packfile_notes);
+    app_ui.packfile_statistics.triggered().connect(&slots.packfile_statistics);
+    app_ui.packfile_check_conflicts.triggered().connect(&slots.packfile_check_conflicts);
+    app_ui.packfile_row_annotations.triggered().connect(&slots.packfile_row_annotations);
     app_ui.packfile_load_all_ca_packfiles.triggered().connect(&slots.packfile_load_all_ca_packfiles);
+    app_ui.packfile_load_ca_packfiles_filtered.triggered().connect(&slots.packfile_load_ca_packfiles_filtered);
 
     app_ui.change_packfile_type_boot.triggered().connect(&slots.packfile_change_packfile_type);
     app_ui.change_packfile_type_release.triggered().connect(&slots.packfile_change_packfile_type);
@@ -69,15 +77,19 @@ pub unsafe fn set_connections(app_ui: &Rc<AppUI>, slots: &AppUISlots) {
     app_ui.view_toggle_diagnostics_panel.toggled().connect(&slots.view_toggle_diagnostics_panel);
     app_ui.view_toggle_dependencies_panel.toggled().connect(&slots.view_toggle_dependencies_panel);
     app_ui.view_toggle_references_panel.toggled().connect(&slots.view_toggle_references_panel);
+    app_ui.view_toggle_modified_column.toggled().connect(&slots.view_toggle_modified_column);
 
     //-----------------------------------------------//
     // `Game Selected` menu connections.
     //-----------------------------------------------//
     app_ui.game_selected_launch_game.triggered().connect(&slots.game_selected_launch_game);
+    app_ui.game_selected_launch_game_with_current_mod.triggered().connect(&slots.game_selected_launch_game_with_current_mod);
+    app_ui.game_selected_restore_mod_list.triggered().connect(&slots.game_selected_restore_mod_list);
 
     app_ui.game_selected_open_game_data_folder.triggered().connect(&slots.game_selected_open_game_data_folder);
     app_ui.game_selected_open_game_assembly_kit_folder.triggered().connect(&slots.game_selected_open_game_assembly_kit_folder);
     app_ui.game_selected_open_config_folder.triggered().connect(&slots.game_selected_open_config_folder);
+    app_ui.game_selected_schema_browser.triggered().connect(&slots.game_selected_schema_browser);
 
     app_ui.game_selected_warhammer_3.triggered().connect(&slots.change_game_selected);
     app_ui.game_selected_troy.triggered().connect(&slots.change_game_selected);
@@ -122,7 +134,15 @@ pub unsafe fn set_connections(app_ui: &Rc<AppUI>, slots: &AppUISlots) {
     app_ui.special_stuff_nap_generate_dependencies_cache.triggered().connect(&slots.special_stuff_generate_dependencies_cache);
     app_ui.special_stuff_emp_generate_dependencies_cache.triggered().connect(&slots.special_stuff_generate_dependencies_cache);
 
+    app_ui.special_stuff_refresh_dependencies_cache.triggered().connect(&slots.special_stuff_refresh_dependencies_cache);
     app_ui.special_stuff_rescue_packfile.triggered().connect(&slots.special_stuff_rescue_packfile);
+    app_ui.special_stuff_verify_integrity.triggered().connect(&slots.special_stuff_verify_integrity);
+    app_ui.special_stuff_compare_packs.triggered().connect(&slots.special_stuff_compare_packs);
+    app_ui.special_stuff_sign_pack.triggered().connect(&slots.special_stuff_sign_pack);
+    app_ui.special_stuff_verify_pack_signature.triggered().connect(&slots.special_stuff_verify_pack_signature);
+    app_ui.special_stuff_translation_export_po.triggered().connect(&slots.special_stuff_translation_export_po);
+    app_ui.special_stuff_translation_import_po.triggered().connect(&slots.special_stuff_translation_import_po);
+    app_ui.special_stuff_translation_report.triggered().connect(&slots.special_stuff_translation_report);
 
     //-----------------------------------------------//
     // `Tools` menu connections.
@@ -145,6 +165,8 @@ pub unsafe fn set_connections(app_ui: &Rc<AppUI>, slots: &AppUISlots) {
     //-----------------------------------------------//
     app_ui.debug_update_current_schema_from_asskit.triggered().connect(&slots.debug_update_current_schema_from_asskit);
     app_ui.debug_import_schema_patch.triggered().connect(&slots.debug_import_schema_patch);
+    app_ui.debug_export_schema_patches.triggered().connect(&slots.debug_export_schema_patches);
+    app_ui.debug_release_memory.triggered().connect(&slots.debug_release_memory);
     app_ui.debug_reload_style_sheet.triggered().connect(&slots.debug_reload_style_sheet);
 
     //-----------------------------------------------//
@@ -158,6 +180,9 @@ pub unsafe fn set_connections(app_ui: &Rc<AppUI>, slots: &AppUISlots) {
     // `Generic` connections.
     //-----------------------------------------------//
     app_ui.timer_backup_autosave.timeout().connect(&slots.pack_file_backup_autosave);
+    app_ui.timer_restore_tab_session.timeout().connect(&slots.pack_file_restore_tab_session);
+    app_ui.timer_pending_dependencies.timeout().connect(&slots.pending_dependencies_check);
+    app_ui.pack_changed_on_disk_banner.link_activated().connect(&slots.pack_changed_on_disk_banner_link);
 
     app_ui.tab_bar_packed_file.custom_context_menu_requested().connect(&slots.tab_bar_packed_file_context_menu_show);
     app_ui.tab_bar_packed_file_close.triggered().connect(&slots.tab_bar_packed_file_close);
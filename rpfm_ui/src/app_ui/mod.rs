@@ -22,39 +22,51 @@ use qt_widgets::QComboBox;
 use qt_widgets::QDialog;
 use qt_widgets::QFileDialog;
 use qt_widgets::QGridLayout;
+use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
 use qt_widgets::QMainWindow;
 use qt_widgets::QMenu;
 use qt_widgets::{q_message_box, QMessageBox};
+use qt_widgets::q_header_view::ResizeMode;
 use qt_widgets::QPushButton;
 use qt_widgets::QTabWidget;
+use qt_widgets::QTextBrowser;
+use qt_widgets::QTextEdit;
 use qt_widgets::QTreeView;
 use qt_widgets::QWidget;
 
+use qt_gui::QGuiApplication;
 use qt_gui::QIcon;
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
+use qt_gui::SlotOfQStandardItem;
 
 use qt_core::QTimer;
+use qt_core::CheckState;
 use qt_core::ContextMenuPolicy;
 use qt_core::QBox;
 use qt_core::QFlags;
 use qt_core::QListOfQObject;
+use qt_core::QModelIndex;
 use qt_core::QPtr;
-use qt_core::QStringList;
 use qt_core::QRegExp;
-use qt_core::{SlotOfBool, SlotOfQString};
+use qt_core::{SlotNoArgs, SlotOfBool, SlotOfInt, SlotOfQModelIndex, SlotOfQModelIndexQModelIndex, SlotOfQString};
 use qt_core::QSortFilterProxyModel;
 use qt_core::QString;
 use qt_core::QVariant;
+use qt_core::{Orientation, WidgetAttribute};
 
 use cpp_core::CppBox;
+use cpp_core::Ptr;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use crossbeam::channel::Receiver;
 use getset::Getters;
 use self_update::cargo_crate_version;
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env::current_exe;
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
@@ -62,35 +74,42 @@ use std::process::{Command as SystemCommand, exit};
 use std::rc::Rc;
 use std::sync::{atomic::Ordering, RwLock};
 
-use rpfm_lib::files::{animpack, ContainerPath, FileType, loc, text, pack::*, text::TextFormat};
+use rpfm_extensions::conflicts::{FileConflict, PackConflicts};
+use rpfm_extensions::optimizer::{OptimizerOptions, OptimizerReport};
+use rpfm_extensions::statistics::{format_size, PackStatistics};
+use rpfm_extensions::translation::report::{LanguageDetection, TranslationIssue, TranslationReport};
+
+use rpfm_lib::files::{animpack, ContainerPath, Encodeable, FileType, loc, RFileDecoded, text, pack::*, text::TextFormat};
 use rpfm_lib::games::{pfh_file_type::*, pfh_version::*, supported_games::*};
 use rpfm_lib::integrations::{git::*, log::*};
+use rpfm_lib::schema::{SchemaDiff, TableOverview};
 use rpfm_lib::utils::*;
 
 use crate::ASSETS_PATH;
 use crate::backend::*;
 use crate::CENTRAL_COMMAND;
-use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::communications::{CentralCommand, Command, ErrorKind, ErrorWithKind, Response, THREADS_COMMUNICATION_ERROR};
 use crate::dependencies_ui::DependenciesUI;
 use crate::diagnostics_ui::DiagnosticsUI;
 use crate::ffi::*;
 use crate::FIRST_GAME_CHANGE_DONE;
-use crate::GAME_SELECTED;
+use crate::locks::{read_game_selected, read_schema};
 use crate::global_search_ui::GlobalSearchUI;
-use crate::locale::{qtr, qtre, tre};
+use crate::locale::{qtr, qtre, tr, tre};
 use crate::pack_tree::{BuildData, icons::IconType, new_pack_file_tooltip, PackTree, TreeViewOperation};
-use crate::packedfile_views::{anim_fragment::*, animpack::*, video::*, DataSource, decoder::*, dependencies_manager::*, esf::*, external::*, image::*, PackedFileView, packfile::PackFileExtraView, packfile_settings::*, SpecialView, table::*, text::*, unit_variant::*};
+use crate::packedfile_views::{anim_fragment::*, animpack::*, video::*, DataSource, decoder::*, dependencies_manager::*, esf::*, external::*, hex::*, image::*, PackedFileView, packfile::PackFileExtraView, packfile_settings::*, SpecialView, speedtree::*, table::*, text::*, unit_variant::*};
+use crate::packedfile_views::text::markdown::markdown_to_html;
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::references_ui::ReferencesUI;
 use crate::RPFM_PATH;
-use crate::SCHEMA;
 use crate::settings_ui::backend::*;
+use crate::settings_ui::recent_packs::RecentPacks;
 use crate::STATUS_BAR;
 use crate::SUPPORTED_GAMES;
 use crate::TREEVIEW_ICONS;
 use crate::UI_STATE;
 use crate::ui::GameSelectedIcons;
-use crate::ui_state::OperationalMode;
+use crate::ui_state::{OperationalMode, tab_session::TabSession};
 use crate::updater::{APIResponse, CHANGELOG_FILE};
 use crate::utils::*;
 
@@ -104,6 +123,12 @@ pub mod connections;
 pub mod slots;
 pub mod tips;
 
+/// Separator used to store the selection of the "Load CA PackFiles..." dialog in the settings.
+const CA_PACKS_SELECTION_SEPARATOR: &str = "|";
+
+/// Role used to mark the orphaned notes in the "Row Notes" dialog.
+const ROW_ANNOTATION_IS_ORPHANED: i32 = 40;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -146,11 +171,19 @@ pub struct AppUI {
     packfile_save_packfile_as: QPtr<QAction>,
     packfile_install: QPtr<QAction>,
     packfile_uninstall: QPtr<QAction>,
+    packfile_notes: QPtr<QAction>,
+    packfile_statistics: QPtr<QAction>,
+    packfile_check_conflicts: QPtr<QAction>,
+    packfile_row_annotations: QPtr<QAction>,
     packfile_open_recent: QBox<QMenu>,
+    packfile_open_recent_show_all_games: QBox<QAction>,
     packfile_open_from_content: QBox<QMenu>,
+    packfile_open_from_workshop: QBox<QMenu>,
     packfile_open_from_data: QBox<QMenu>,
     packfile_open_from_autosave: QBox<QMenu>,
+    packfile_restore_autosave: QPtr<QAction>,
     packfile_load_all_ca_packfiles: QPtr<QAction>,
+    packfile_load_ca_packfiles_filtered: QPtr<QAction>,
     packfile_preferences: QPtr<QAction>,
     packfile_quit: QPtr<QAction>,
 
@@ -202,15 +235,19 @@ pub struct AppUI {
     view_toggle_diagnostics_panel: QPtr<QAction>,
     view_toggle_dependencies_panel: QPtr<QAction>,
     view_toggle_references_panel: QPtr<QAction>,
+    view_toggle_modified_column: QPtr<QAction>,
 
     //-------------------------------------------------------------------------------//
     // `Game Selected` menu.
     //-------------------------------------------------------------------------------//
     game_selected_launch_game: QPtr<QAction>,
+    game_selected_launch_game_with_current_mod: QPtr<QAction>,
+    game_selected_restore_mod_list: QPtr<QAction>,
 
     game_selected_open_game_data_folder: QPtr<QAction>,
     game_selected_open_game_assembly_kit_folder: QPtr<QAction>,
     game_selected_open_config_folder: QPtr<QAction>,
+    game_selected_schema_browser: QPtr<QAction>,
 
     game_selected_warhammer_3: QPtr<QAction>,
     game_selected_troy: QPtr<QAction>,
@@ -278,7 +315,17 @@ pub struct AppUI {
     special_stuff_emp_optimize_packfile: QPtr<QAction>,
 
     // Common operations.
+    special_stuff_refresh_dependencies_cache: QPtr<QAction>,
     special_stuff_rescue_packfile: QPtr<QAction>,
+    special_stuff_verify_integrity: QPtr<QAction>,
+    special_stuff_compare_packs: QPtr<QAction>,
+    special_stuff_sign_pack: QPtr<QAction>,
+    special_stuff_verify_pack_signature: QPtr<QAction>,
+
+    // Translation actions.
+    special_stuff_translation_export_po: QPtr<QAction>,
+    special_stuff_translation_import_po: QPtr<QAction>,
+    special_stuff_translation_report: QPtr<QAction>,
 
     //-------------------------------------------------------------------------------//
     // `Tools` menu.
@@ -301,12 +348,18 @@ pub struct AppUI {
     //-------------------------------------------------------------------------------//
     debug_update_current_schema_from_asskit: QPtr<QAction>,
     debug_import_schema_patch: QPtr<QAction>,
+    debug_export_schema_patches: QPtr<QAction>,
+    debug_release_memory: QPtr<QAction>,
     debug_reload_style_sheet: QPtr<QAction>,
 
     //-------------------------------------------------------------------------------//
     // Extra stuff
     //-------------------------------------------------------------------------------//
     timer_backup_autosave: QBox<QTimer>,
+    timer_restore_tab_session: QBox<QTimer>,
+    timer_pending_dependencies: QBox<QTimer>,
+
+    pack_changed_on_disk_banner: QBox<QLabel>,
 
     tab_bar_packed_file_context_menu: QBox<QMenu>,
     tab_bar_packed_file_close: QPtr<QAction>,
@@ -350,7 +403,7 @@ impl AppUI {
     pub unsafe fn new() -> Self {
 
         // Initialize and configure the main window.
-        let main_window = new_q_main_window_custom_safe(are_you_sure);
+        let main_window = new_q_main_window_custom_safe(are_you_sure_to_close);
         let widget = QWidget::new_1a(&main_window);
         let layout = create_grid_layout(widget.static_upcast());
         main_window.set_central_widget(&widget);
@@ -365,7 +418,15 @@ impl AppUI {
         tab_bar_packed_file.set_movable(true);
         tab_bar_packed_file.set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
         status_bar.set_size_grip_enabled(false);
-        layout.add_widget_5a(&tab_bar_packed_file, 0, 0, 1, 1);
+
+        // Banner to warn about the open Pack being changed on disk while it has unsaved changes. Hidden until then.
+        let pack_changed_on_disk_banner = QLabel::from_q_string_q_widget(&qtr("pack_changed_on_disk_banner"), &widget);
+        pack_changed_on_disk_banner.set_word_wrap(true);
+        pack_changed_on_disk_banner.set_style_sheet(&QString::from_std_str("QLabel { background-color: #e5a50a; color: black; padding: 4px; }"));
+        pack_changed_on_disk_banner.hide();
+
+        layout.add_widget_5a(&pack_changed_on_disk_banner, 0, 0, 1, 1);
+        layout.add_widget_5a(&tab_bar_packed_file, 1, 0, 1, 1);
 
         let github_button = QPushButton::from_q_widget(&status_bar);
         github_button.set_flat(true);
@@ -451,23 +512,44 @@ impl AppUI {
         let packfile_save_packfile_as = add_action_to_menu(&menu_bar_packfile, shortcuts.as_ref(), "pack_menu", "save_pack_as", "save_packfile_as", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
         let packfile_install = add_action_to_menu(&menu_bar_packfile, shortcuts.as_ref(), "pack_menu", "install_pack", "packfile_install", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
         let packfile_uninstall = add_action_to_menu(&menu_bar_packfile, shortcuts.as_ref(), "pack_menu", "uninstall_pack", "packfile_uninstall", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
+        let packfile_notes = add_action_to_menu(&menu_bar_packfile, shortcuts.as_ref(), "pack_menu", "pack_notes", "packfile_notes", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
 
         let packfile_open_recent = QMenu::from_q_string_q_widget(&qtr("open_recent"), &menu_bar_packfile);
+        packfile_open_recent.set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
+        packfile_open_recent.set_tool_tips_visible(true);
+
+        // This one is owned by the main window, so it survives the submenu being cleared.
+        let packfile_open_recent_show_all_games = QAction::from_q_string_q_object(&qtr("recent_packs_show_all_games"), &main_window);
+        packfile_open_recent_show_all_games.set_checkable(true);
         let packfile_open_from_content = QMenu::from_q_string_q_widget(&qtr("open_from_content"), &menu_bar_packfile);
+        let packfile_open_from_workshop = QMenu::from_q_string_q_widget(&qtr("open_from_workshop"), &menu_bar_packfile);
         let packfile_open_from_data = QMenu::from_q_string_q_widget(&qtr("open_from_data"), &menu_bar_packfile);
         let packfile_open_from_autosave = QMenu::from_q_string_q_widget(&qtr("open_from_autosave"), &menu_bar_packfile);
         let packfile_change_packfile_type = QMenu::from_q_string_q_widget(&qtr("change_packfile_type"), &menu_bar_packfile);
 
         let packfile_load_all_ca_packfiles = add_action_to_menu(&menu_bar_packfile, shortcuts.as_ref(), "pack_menu", "load_all_ca_packs", "load_all_ca_packfiles", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
+        let packfile_load_ca_packfiles_filtered = add_action_to_menu(&menu_bar_packfile, shortcuts.as_ref(), "pack_menu", "load_ca_packs_filtered", "load_ca_packfiles_filtered", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
         let packfile_preferences = add_action_to_menu(&menu_bar_packfile, shortcuts.as_ref(), "pack_menu", "settings", "preferences", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
         let packfile_quit = add_action_to_menu(&menu_bar_packfile, shortcuts.as_ref(), "pack_menu", "quit", "quit", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
 
         // Add the "Open..." submenus. These needs to be here because they have to be inserted in specific positions of the menu.
         menu_bar_packfile.insert_menu(&packfile_load_all_ca_packfiles, &packfile_open_recent);
         menu_bar_packfile.insert_menu(&packfile_load_all_ca_packfiles, &packfile_open_from_content);
+        menu_bar_packfile.insert_menu(&packfile_load_all_ca_packfiles, &packfile_open_from_workshop);
         menu_bar_packfile.insert_menu(&packfile_load_all_ca_packfiles, &packfile_open_from_data);
         menu_bar_packfile.insert_menu(&packfile_load_all_ca_packfiles, &packfile_open_from_autosave);
 
+        let packfile_restore_autosave = menu_bar_packfile.add_action_q_string(&qtr("packfile_restore_autosave"));
+        menu_bar_packfile.insert_action(&packfile_load_all_ca_packfiles, &packfile_restore_autosave);
+
+        let packfile_statistics = menu_bar_packfile.add_action_q_string(&qtr("packfile_statistics"));
+        menu_bar_packfile.insert_action(packfile_open_recent.menu_action(), &packfile_statistics);
+
+        let packfile_check_conflicts = menu_bar_packfile.add_action_q_string(&qtr("packfile_check_conflicts"));
+        menu_bar_packfile.insert_action(packfile_open_recent.menu_action(), &packfile_check_conflicts);
+        let packfile_row_annotations = menu_bar_packfile.add_action_q_string(&qtr("packfile_row_annotations"));
+        menu_bar_packfile.insert_action(packfile_open_recent.menu_action(), &packfile_row_annotations);
+
         menu_bar_packfile.insert_separator(packfile_open_recent.menu_action());
         menu_bar_packfile.insert_separator(&packfile_preferences);
         menu_bar_packfile.insert_menu(&packfile_preferences, &packfile_change_packfile_type);
@@ -518,6 +600,7 @@ impl AppUI {
         packfile_change_packfile_type.insert_separator(&change_packfile_type_other);
         packfile_change_packfile_type.insert_separator(&change_packfile_type_header_is_extended);
         packfile_change_packfile_type.insert_separator(&change_packfile_type_data_is_compressed);
+        packfile_change_packfile_type.set_tool_tips_visible(true);
 
         //-----------------------------------------------//
         // `MyMod` Menu.
@@ -569,20 +652,26 @@ impl AppUI {
         let view_toggle_diagnostics_panel = add_action_to_menu(&menu_bar_view, shortcuts.as_ref(), "view_menu", "diagnostics_panel", "view_toggle_diagnostics_panel", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
         let view_toggle_dependencies_panel = add_action_to_menu(&menu_bar_view, shortcuts.as_ref(), "view_menu", "dependencies_panel", "view_toggle_dependencies_panel", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
         let view_toggle_references_panel = add_action_to_menu(&menu_bar_view, shortcuts.as_ref(), "view_menu", "references_panel", "view_toggle_references_panel", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
+        let view_toggle_modified_column = add_action_to_menu(&menu_bar_view, shortcuts.as_ref(), "view_menu", "modified_column", "view_toggle_modified_column", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
 
         view_toggle_packfile_contents.set_checkable(true);
         view_toggle_global_search_panel.set_checkable(true);
         view_toggle_diagnostics_panel.set_checkable(true);
         view_toggle_dependencies_panel.set_checkable(true);
         view_toggle_references_panel.set_checkable(true);
+        view_toggle_modified_column.set_checkable(true);
+        view_toggle_modified_column.set_checked(setting_bool("packfile_treeview_show_modified_column"));
 
         //-----------------------------------------------//
         // `Game Selected` Menu.
         //-----------------------------------------------//
         let game_selected_launch_game = add_action_to_menu(&menu_bar_game_selected, shortcuts.as_ref(), "game_selected_menu", "launch_game", "game_selected_launch_game", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
+        let game_selected_launch_game_with_current_mod = add_action_to_menu(&menu_bar_game_selected, shortcuts.as_ref(), "game_selected_menu", "launch_game_with_current_mod", "game_selected_launch_game_with_current_mod", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
+        let game_selected_restore_mod_list = add_action_to_menu(&menu_bar_game_selected, shortcuts.as_ref(), "game_selected_menu", "restore_mod_list", "game_selected_restore_mod_list", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
         let game_selected_open_game_data_folder = add_action_to_menu(&menu_bar_game_selected, shortcuts.as_ref(), "game_selected_menu", "open_game_data_folder", "game_selected_open_game_data_folder", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
         let game_selected_open_game_assembly_kit_folder = add_action_to_menu(&menu_bar_game_selected, shortcuts.as_ref(), "game_selected_menu", "open_game_ak_folder", "game_selected_open_game_assembly_kit_folder", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
         let game_selected_open_config_folder = add_action_to_menu(&menu_bar_game_selected, shortcuts.as_ref(), "game_selected_menu", "open_rpfm_config_folder", "game_selected_open_config_folder", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
+        let game_selected_schema_browser = add_action_to_menu(&menu_bar_game_selected, shortcuts.as_ref(), "game_selected_menu", "schema_browser", "game_selected_schema_browser", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
 
         let game_selected_warhammer_3 = menu_bar_game_selected.add_action_q_string(&QString::from_std_str(DISPLAY_NAME_WARHAMMER_3));
         let game_selected_troy = menu_bar_game_selected.add_action_q_string(&QString::from_std_str(DISPLAY_NAME_TROY));
@@ -656,7 +745,16 @@ impl AppUI {
         let menu_shogun_2 = menu_bar_special_stuff.add_menu_q_string(&QString::from_std_str(DISPLAY_NAME_SHOGUN_2));
         let menu_napoleon = menu_bar_special_stuff.add_menu_q_string(&QString::from_std_str(DISPLAY_NAME_NAPOLEON));
         let menu_empire = menu_bar_special_stuff.add_menu_q_string(&QString::from_std_str(DISPLAY_NAME_EMPIRE));
+        let special_stuff_refresh_dependencies_cache = menu_bar_special_stuff.add_action_q_string(&qtr("special_stuff_refresh_dependencies_cache"));
         let special_stuff_rescue_packfile = menu_bar_special_stuff.add_action_q_string(&qtr("special_stuff_rescue_packfile"));
+        let special_stuff_verify_integrity = menu_bar_special_stuff.add_action_q_string(&qtr("special_stuff_verify_integrity"));
+        let special_stuff_compare_packs = menu_bar_special_stuff.add_action_q_string(&qtr("special_stuff_compare_packs"));
+        let special_stuff_sign_pack = menu_bar_special_stuff.add_action_q_string(&qtr("special_stuff_sign_pack"));
+        let special_stuff_verify_pack_signature = menu_bar_special_stuff.add_action_q_string(&qtr("special_stuff_verify_pack_signature"));
+        let menu_translation = menu_bar_special_stuff.add_menu_q_string(&qtr("special_stuff_translation"));
+        let special_stuff_translation_export_po = menu_translation.add_action_q_string(&qtr("special_stuff_translation_export_po"));
+        let special_stuff_translation_import_po = menu_translation.add_action_q_string(&qtr("special_stuff_translation_import_po"));
+        let special_stuff_translation_report = menu_translation.add_action_q_string(&qtr("special_stuff_translation_report"));
 
         // Populate the `Special Stuff` submenus.
         let special_stuff_wh3_generate_dependencies_cache = add_action_to_menu(&menu_warhammer_3, shortcuts.as_ref(), "special_stuff_menu", "generate_dependencies_cache", "special_stuff_generate_dependencies_cache", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
@@ -684,7 +782,7 @@ impl AppUI {
         let special_stuff_emp_generate_dependencies_cache = add_action_to_menu(&menu_empire, shortcuts.as_ref(), "special_stuff_menu", "generate_dependencies_cache", "special_stuff_generate_dependencies_cache", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
         let special_stuff_emp_optimize_packfile = add_action_to_menu(&menu_empire, shortcuts.as_ref(), "special_stuff_menu", "optimize_pack", "special_stuff_optimize_packfile", Some(main_window.static_upcast::<qt_widgets::QWidget>()));
 
-        menu_bar_special_stuff.insert_separator(&special_stuff_rescue_packfile);
+        menu_bar_special_stuff.insert_separator(&special_stuff_refresh_dependencies_cache);
 
         //-----------------------------------------------//
         // `Tools` Menu.
@@ -714,6 +812,8 @@ impl AppUI {
         // Populate the `Debug` menu.
         let debug_update_current_schema_from_asskit = menu_bar_debug.add_action_q_string(&qtr("update_current_schema_from_asskit"));
         let debug_import_schema_patch = menu_bar_debug.add_action_q_string(&qtr("import_schema_patch"));
+        let debug_export_schema_patches = menu_bar_debug.add_action_q_string(&qtr("export_schema_patches"));
+        let debug_release_memory = menu_bar_debug.add_action_q_string(&qtr("release_memory"));
         let debug_reload_style_sheet = menu_bar_debug.add_action_q_string(&qtr("reload_style_sheet"));
 
         //-------------------------------------------------------------------------------//
@@ -722,6 +822,15 @@ impl AppUI {
         let timer_backup_autosave = QTimer::new_1a(&main_window);
         timer_backup_autosave.set_single_shot(true);
 
+        // This one is used to restore the open files of a Pack once the Pack is fully open.
+        let timer_restore_tab_session = QTimer::new_1a(&main_window);
+        timer_restore_tab_session.set_single_shot(true);
+        timer_restore_tab_session.set_interval(0);
+
+        // This one is used to check if the dependencies finished rebuilding in the background.
+        let timer_pending_dependencies = QTimer::new_1a(&main_window);
+        timer_pending_dependencies.set_interval(100);
+
         // Create ***Da monsta***.
         AppUI {
 
@@ -758,11 +867,19 @@ impl AppUI {
             packfile_save_packfile_as,
             packfile_install,
             packfile_uninstall,
+            packfile_notes,
+            packfile_statistics,
+            packfile_check_conflicts,
+            packfile_row_annotations,
             packfile_open_recent,
+            packfile_open_recent_show_all_games,
             packfile_open_from_content,
+            packfile_open_from_workshop,
             packfile_open_from_data,
             packfile_open_from_autosave,
+            packfile_restore_autosave,
             packfile_load_all_ca_packfiles,
+            packfile_load_ca_packfiles_filtered,
             packfile_preferences,
             packfile_quit,
 
@@ -814,15 +931,19 @@ impl AppUI {
             view_toggle_diagnostics_panel,
             view_toggle_dependencies_panel,
             view_toggle_references_panel,
+            view_toggle_modified_column,
 
             //-------------------------------------------------------------------------------//
             // "Game Selected" menu.
             //-------------------------------------------------------------------------------//
             game_selected_launch_game,
+            game_selected_launch_game_with_current_mod,
+            game_selected_restore_mod_list,
 
             game_selected_open_game_data_folder,
             game_selected_open_game_assembly_kit_folder,
             game_selected_open_config_folder,
+            game_selected_schema_browser,
 
             game_selected_warhammer_3,
             game_selected_troy,
@@ -890,7 +1011,16 @@ impl AppUI {
             special_stuff_emp_optimize_packfile,
 
             // Common operations.
+            special_stuff_refresh_dependencies_cache,
             special_stuff_rescue_packfile,
+            special_stuff_verify_integrity,
+            special_stuff_compare_packs,
+            special_stuff_sign_pack,
+            special_stuff_verify_pack_signature,
+
+            special_stuff_translation_export_po,
+            special_stuff_translation_import_po,
+            special_stuff_translation_report,
 
             //-------------------------------------------------------------------------------//
             // "Tools" menu.
@@ -913,12 +1043,18 @@ impl AppUI {
             //-------------------------------------------------------------------------------//
             debug_update_current_schema_from_asskit,
             debug_import_schema_patch,
+            debug_export_schema_patches,
+            debug_release_memory,
             debug_reload_style_sheet,
 
             //-------------------------------------------------------------------------------//
             // "Extra stuff" menu.
             //-------------------------------------------------------------------------------//
             timer_backup_autosave,
+            timer_restore_tab_session,
+            timer_pending_dependencies,
+
+            pack_changed_on_disk_banner,
 
             tab_bar_packed_file_context_menu,
             tab_bar_packed_file_close,
@@ -1017,6 +1153,63 @@ impl AppUI {
         ).exec() == 3
     }
 
+    /// This function shows a dialog with the provided error.
+    ///
+    /// Errors with a known kind are shown with `show_error_kind_dialog`, so we can help the user fix them.
+    pub unsafe fn show_error_dialog(app_ui: &Rc<Self>, error: &anyhow::Error) {
+        match error.downcast_ref::<ErrorWithKind>() {
+            Some(error) => Self::show_error_kind_dialog(app_ui, *error.kind(), error.message()),
+            None => show_dialog(&app_ui.main_window, error, false),
+        }
+    }
+
+    /// This function shows a dialog with the provided error, offering a way to fix it depending on its kind.
+    pub unsafe fn show_error_kind_dialog(app_ui: &Rc<Self>, kind: ErrorKind, message: &str) {
+        let (hint, button_text, action) = match kind {
+            ErrorKind::SchemaMissing => ("error_kind_schema_missing", "error_kind_schema_missing_button", Some(&app_ui.about_check_schema_updates)),
+            ErrorKind::DependenciesCacheOutdated => ("error_kind_dependencies_cache_outdated", "error_kind_dependencies_cache_outdated_button", app_ui.generate_dependencies_cache_action()),
+            ErrorKind::FileLocked => ("error_kind_file_locked", "", None),
+            ErrorKind::Io |
+            ErrorKind::Generic => return show_dialog(&app_ui.main_window, message, false),
+        };
+
+        let dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+            q_message_box::Icon::Critical,
+            &qtr("title_error"),
+            &QString::from_std_str(format!("<p>{}</p>{}", message, tr(hint))),
+            QFlags::from(q_message_box::StandardButton::Close),
+            &app_ui.main_window,
+        );
+
+        // Only offer the fix if we have a way to trigger it.
+        if let Some(action) = action {
+            dialog.add_button_q_string_button_role(&qtr(button_text), q_message_box::ButtonRole::AcceptRole);
+            if dialog.exec() == 0 {
+                action.trigger();
+            }
+        } else {
+            dialog.exec();
+        }
+    }
+
+    /// This function returns the "Generate Dependencies Cache" action of the Game Selected, if it has one.
+    fn generate_dependencies_cache_action(&self) -> Option<&QPtr<QAction>> {
+        match &*read_game_selected().game_key_name() {
+            KEY_WARHAMMER_3 => Some(&self.special_stuff_wh3_generate_dependencies_cache),
+            KEY_TROY => Some(&self.special_stuff_troy_generate_dependencies_cache),
+            KEY_THREE_KINGDOMS => Some(&self.special_stuff_three_k_generate_dependencies_cache),
+            KEY_WARHAMMER_2 => Some(&self.special_stuff_wh2_generate_dependencies_cache),
+            KEY_WARHAMMER => Some(&self.special_stuff_wh_generate_dependencies_cache),
+            KEY_THRONES_OF_BRITANNIA => Some(&self.special_stuff_tob_generate_dependencies_cache),
+            KEY_ATTILA => Some(&self.special_stuff_att_generate_dependencies_cache),
+            KEY_ROME_2 => Some(&self.special_stuff_rom2_generate_dependencies_cache),
+            KEY_SHOGUN_2 => Some(&self.special_stuff_sho2_generate_dependencies_cache),
+            KEY_NAPOLEON => Some(&self.special_stuff_nap_generate_dependencies_cache),
+            KEY_EMPIRE => Some(&self.special_stuff_emp_generate_dependencies_cache),
+            _ => None,
+        }
+    }
+
     /// This function updates the backend of all open PackedFiles with their view's data.
     #[must_use = "If one of those mysterious save errors happen here and we don't use the result, we may be losing the new changes to a file."]
     pub unsafe fn back_to_back_end_all(
@@ -1024,10 +1217,33 @@ impl AppUI {
         pack_file_contents_ui: &Rc<PackFileContentsUI>,
     ) -> Result<()> {
 
+        // Collect the data of all views first, so we can save all of them to the backend in one go.
+        // A broken view shouldn't stop the rest from being saved, so errors are collected and returned at the end.
+        let mut errors = vec![];
+        let mut files = vec![];
         for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
-            packed_file_view.save(app_ui, pack_file_contents_ui)?;
+            match packed_file_view.save_data(app_ui, pack_file_contents_ui) {
+                Ok(Some(data)) => files.push((packed_file_view.get_path(), data)),
+                Ok(None) => {},
+                Err(error) => errors.push(format!("{}: {}", packed_file_view.get_path(), error)),
+            }
+        }
+
+        if !files.is_empty() {
+            let receiver = CENTRAL_COMMAND.send_background(Command::SavePackedFilesFromViewBatch(files));
+            let response = CENTRAL_COMMAND.recv_try(&receiver);
+            match response {
+                Response::VecStringOptionString(results) => errors.extend(results.into_iter()
+                    .filter_map(|(path, error)| error.map(|error| format!("{}: {}", path, error)))),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(tre("save_views_error", &[&errors.join("\n")])))
         }
-        Ok(())
     }
 
     /// This function deletes all the widgets corresponding to opened PackedFiles.
@@ -1135,6 +1351,88 @@ impl AppUI {
         did_it_worked
     }
 
+    /// This function loads the CA Packs requested by the provided command as one read-only Pack, and sets all the stuff needed for it.
+    ///
+    /// It expects either a `Command::LoadAllCAPackFiles` or a `Command::LoadCAPackFilesFiltered`.
+    pub unsafe fn load_ca_packs(
+        app_ui: &Rc<Self>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        global_search_ui: &Rc<GlobalSearchUI>,
+        command: Command,
+    ) {
+
+        // Reset the autosave timer.
+        let timer = setting_int("autosave_interval");
+        if timer > 0 {
+            app_ui.timer_backup_autosave.set_interval(timer * 60 * 1000);
+            app_ui.timer_backup_autosave.start_0a();
+        }
+
+        // Tell the Background Thread to create a new PackFile with the data of one or more from the disk.
+        app_ui.toggle_main_window(false);
+
+        // Destroy whatever it's in the PackedFile's views and clear the global search UI.
+        Self::save_tab_session(app_ui);
+        GlobalSearchUI::clear(global_search_ui);
+        let _ = Self::purge_them_all(app_ui, pack_file_contents_ui, false);
+
+        let receiver = CENTRAL_COMMAND.send_background(command);
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+
+            // If it's success....
+            Response::ContainerInfo(ui_data) => {
+
+                // Set this PackFile always to type `Release`.
+                app_ui.change_packfile_type_release.set_checked(true);
+
+                // Disable all of these.
+                app_ui.change_packfile_type_data_is_encrypted.set_checked(false);
+                app_ui.change_packfile_type_index_includes_timestamp.set_checked(false);
+                app_ui.change_packfile_type_index_is_encrypted.set_checked(false);
+                app_ui.change_packfile_type_header_is_extended.set_checked(false);
+
+                // Set the compression level correctly, because otherwise we may fuckup some files.
+                app_ui.change_packfile_type_data_is_compressed.set_checked(*ui_data.compress());
+
+                // Update the TreeView.
+                let mut build_data = BuildData::new();
+                build_data.editable = true;
+                pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Build(build_data), DataSource::PackFile);
+
+                match &*read_game_selected().game_key_name() {
+                    KEY_WARHAMMER_3 => app_ui.game_selected_warhammer_3.trigger(),
+                    KEY_TROY => app_ui.game_selected_troy.trigger(),
+                    KEY_THREE_KINGDOMS => app_ui.game_selected_three_kingdoms.trigger(),
+                    KEY_WARHAMMER_2 => app_ui.game_selected_warhammer_2.trigger(),
+                    KEY_WARHAMMER => app_ui.game_selected_warhammer.trigger(),
+                    KEY_THRONES_OF_BRITANNIA => app_ui.game_selected_thrones_of_britannia.trigger(),
+                    KEY_ATTILA => app_ui.game_selected_attila.trigger(),
+                    KEY_ROME_2 => app_ui.game_selected_rome_2.trigger(),
+                    KEY_SHOGUN_2 => app_ui.game_selected_shogun_2.trigger(),
+                    KEY_NAPOLEON => app_ui.game_selected_napoleon.trigger(),
+                    KEY_EMPIRE => app_ui.game_selected_empire.trigger(),
+                    KEY_ARENA => app_ui.game_selected_arena.trigger(),
+                    _ => unreachable!(),
+                }
+
+                UI_STATE.set_operational_mode(app_ui, None);
+                UI_STATE.set_is_modified(false, app_ui, pack_file_contents_ui);
+            }
+
+            // If we got an error...
+            Response::Error(error) => {
+                show_dialog(&app_ui.main_window, error, false);
+            }
+
+            // In ANY other situation, it's a message problem.
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        // Always reenable the Main Window.
+        app_ui.toggle_main_window(true);
+    }
+
     /// This function opens the PackFile at the provided Path, and sets all the stuff needed, depending on the situation.
     ///
     /// NOTE: The `game_folder` is for when using this function with *MyMods*. If you're opening a normal mod, pass it empty.
@@ -1147,35 +1445,13 @@ impl AppUI {
     ) -> Result<()> {
 
         // Destroy whatever it's in the PackedFile's view, to avoid data corruption. We don't care about this result.
+        Self::save_tab_session(app_ui);
         let _ = Self::purge_them_all(app_ui, pack_file_contents_ui, false);
 
         // Tell the Background Thread to create a new PackFile with the data of one or more from the disk.
         app_ui.toggle_main_window(false);
         let receiver = CENTRAL_COMMAND.send_background(Command::OpenPackFiles(pack_file_paths.to_vec()));
 
-        // If it's only one packfile, store it in the recent file list.
-        if pack_file_paths.len() == 1 {
-            let q_settings = settings();
-
-            let paths = if q_settings.contains(&QString::from_std_str("recentFileList")) {
-                q_settings.value_1a(&QString::from_std_str("recentFileList")).to_string_list()
-            } else {
-                QStringList::new()
-            };
-
-            let pos = paths.index_of_1a(&QString::from_std_str(pack_file_paths[0].to_str().unwrap()));
-            if pos != -1 {
-                paths.remove_at(pos);
-            }
-
-            paths.prepend(&QString::from_std_str(pack_file_paths[0].to_str().unwrap()));
-
-            while paths.count_0a() > 10 {
-                paths.remove_last();
-            }
-            q_settings.set_value(&QString::from_std_str("recentFileList"), &QVariant::from_q_string_list(&paths));
-        }
-
         let timer = setting_int("autosave_interval");
         if timer > 0 {
             app_ui.timer_backup_autosave.set_interval(timer * 60 * 1000);
@@ -1246,7 +1522,7 @@ impl AppUI {
                     UI_STATE.set_operational_mode(app_ui, None);
 
                     // Depending on the Id, choose one game or another.
-                    let game_selected = GAME_SELECTED.read().unwrap().game_key_name();
+                    let game_selected = read_game_selected().game_key_name();
                     match ui_data.pfh_version() {
 
                         // PFH6 is for Troy and maybe WH3.
@@ -1329,8 +1605,33 @@ impl AppUI {
                 UI_STATE.set_is_modified(false, app_ui, pack_file_contents_ui);
                 pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Clean, DataSource::PackFile);
 
+                // If it's only one Pack, store it in the recent Packs list, under the game we ended up opening it with.
+                if pack_file_paths.len() == 1 {
+                    let mut recent_packs = RecentPacks::load().unwrap_or_default();
+                    recent_packs.add(&pack_file_paths[0], &read_game_selected().game_key_name(), setting_int("recent_packs_limit").max(1) as usize);
+                    if let Err(error) = recent_packs.save() {
+                        error!("Failed to save the list of recent Packs: {}", error);
+                    }
+                }
+
                 // Re-enable the Main Window.
                 app_ui.toggle_main_window(true);
+
+                // If the Pack was damaged and we had to work around it while reading it, let the user know.
+                if !ui_data.load_warnings().is_empty() {
+                    QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+                        q_message_box::Icon::Warning,
+                        &qtr("title_warning"),
+                        &qtre("open_pack_load_warnings", &[&ui_data.load_warnings().join("</li><li>")]),
+                        QFlags::from(q_message_box::StandardButton::Ok),
+                        &app_ui.main_window,
+                    ).exec();
+                }
+
+                // Reopen the files that were open the last time this Pack was closed, once we're done here.
+                if pack_file_paths.len() == 1 && setting_bool("restore_tab_sessions") {
+                    app_ui.timer_restore_tab_session.start_0a();
+                }
             }
 
             // If we got an error...
@@ -1339,6 +1640,11 @@ impl AppUI {
                 return Err(error)
             }
 
+            Response::ErrorKind(kind, message) => {
+                app_ui.toggle_main_window(true);
+                return Err(From::from(ErrorWithKind::new(kind, message)))
+            }
+
             // In ANY other situation, it's a message problem.
             _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
         }
@@ -1347,6 +1653,99 @@ impl AppUI {
         Ok(())
     }
 
+    /// This function stores the list of file views open in the current Pack, so they can be restored the next time it's open.
+    ///
+    /// Call it before closing the views of a Pack.
+    pub unsafe fn save_tab_session(app_ui: &Rc<Self>) {
+        if !setting_bool("restore_tab_sessions") {
+            return;
+        }
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetPackFilePath);
+        let response = CentralCommand::recv(&receiver);
+        let pack_path = if let Response::PathBuf(pack_path) = response { pack_path } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) };
+
+        // New Packs and Packs not yet saved have no path to store their session under.
+        if !pack_path.is_file() {
+            return;
+        }
+
+        let session = UI_STATE.get_tab_session(app_ui);
+        if let Err(error) = session.save(&pack_path) {
+            error!("Failed to save the open files of {}: {}", pack_path.to_string_lossy(), error);
+        }
+    }
+
+    /// This function reopens the file views that were open the last time the current Pack was closed.
+    ///
+    /// This is done on a best-effort basis: files that no longer exist are skipped, and files that fail to open are reported in the status bar.
+    pub unsafe fn restore_tab_session(
+        app_ui: &Rc<Self>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        global_search_ui: &Rc<GlobalSearchUI>,
+        diagnostics_ui: &Rc<DiagnosticsUI>,
+        dependencies_ui: &Rc<DependenciesUI>,
+        references_ui: &Rc<ReferencesUI>,
+    ) {
+        if !setting_bool("restore_tab_sessions") {
+            return;
+        }
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetPackFilePath);
+        let response = CentralCommand::recv(&receiver);
+        let pack_path = if let Response::PathBuf(pack_path) = response { pack_path } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) };
+        if !pack_path.is_file() {
+            return;
+        }
+
+        let session = match TabSession::load(&pack_path) {
+            Ok(Some(session)) => session,
+            Ok(None) => return,
+            Err(error) => return error!("Failed to load the open files of {}: {}", pack_path.to_string_lossy(), error),
+        };
+
+        let mut failed = vec![];
+        let mut active_tab = None;
+        for (index, tab) in session.tabs().iter().enumerate() {
+            let path = tab.path();
+            let data_source = *tab.data_source();
+
+            // Skip files deleted from the Pack since the session was saved.
+            if data_source == DataSource::PackFile {
+                let receiver = CENTRAL_COMMAND.send_background(Command::PackedFileExists(path.to_owned()));
+                let response = CentralCommand::recv(&receiver);
+                let exists = if let Response::Bool(data) = response { data } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response); };
+                if !exists {
+                    continue;
+                }
+            }
+
+            // Decode the file before opening it, so decoding errors end up in the status bar instead of in a dialog per file.
+            let receiver = CENTRAL_COMMAND.send_background(Command::DecodePackedFile(path.to_owned(), data_source));
+            if let Response::Error(error) = CentralCommand::recv(&receiver) {
+                info!("Failed to restore {}: {}", path, error);
+                failed.push(path.to_owned());
+                continue;
+            }
+
+            Self::open_packedfile(app_ui, pack_file_contents_ui, global_search_ui, diagnostics_ui, dependencies_ui, references_ui, Some(path.to_owned()), *tab.is_preview(), false, data_source);
+
+            match UI_STATE.get_open_packedfiles().iter().find(|view| *view.get_ref_path() == *path && view.get_data_source() == data_source) {
+                Some(view) => if *session.active_tab() == Some(index) {
+                    active_tab = Some(view.get_mut_widget().as_ptr());
+                },
+                None => failed.push(path.to_owned()),
+            }
+        }
+
+        if let Some(widget) = active_tab {
+            app_ui.tab_bar_packed_file.set_current_widget(widget);
+        }
+
+        if !failed.is_empty() {
+            log_to_status_bar(&tre("tab_session_restore_failed", &[&failed.len().to_string(), &failed.join(", ")]));
+        }
+    }
 
     /// This function is used to save the currently open `PackFile` to disk.
     ///
@@ -1388,7 +1787,7 @@ impl AppUI {
 
             // In case we have a default path for the Game Selected and that path is valid,
             // we use his data folder as base path for saving our PackFile.
-            else if let Ok(ref path) = GAME_SELECTED.read().unwrap().local_mods_path(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name())) {
+            else if let Ok(ref path) = read_game_selected().local_mods_path(&setting_path(&read_game_selected().game_key_name())) {
                 if path.is_dir() { file_dialog.set_directory_q_string(&QString::from_std_str(path.to_string_lossy().as_ref())); }
             }
 
@@ -1409,6 +1808,7 @@ impl AppUI {
                         UI_STATE.set_is_modified(false, app_ui, pack_file_contents_ui);
                     }
                     Response::Error(error) => result = Err(error),
+                    Response::ErrorKind(kind, message) => result = Err(From::from(ErrorWithKind::new(kind, message))),
 
                     // In ANY other situation, it's a message problem.
                     _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
@@ -1427,6 +1827,7 @@ impl AppUI {
                     UI_STATE.set_is_modified(false, app_ui, pack_file_contents_ui);
                 }
                 Response::Error(error) => result = Err(error),
+                Response::ErrorKind(kind, message) => result = Err(From::from(ErrorWithKind::new(kind, message))),
 
                 // In ANY other situation, it's a message problem.
                 _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
@@ -1451,15 +1852,20 @@ impl AppUI {
     pub unsafe fn enable_packfile_actions(app_ui: &Rc<Self>, pack_path: &Path, enable: bool) {
 
         // If the game is Arena, no matter what we're doing, these ones ALWAYS have to be disabled.
-        let game_selected = GAME_SELECTED.read().unwrap().game_key_name();
+        let game_selected = read_game_selected().game_key_name();
         if game_selected == KEY_ARENA {
 
             // Disable the actions that allow to create and save PackFiles.
             app_ui.packfile_new_packfile.set_enabled(false);
             app_ui.packfile_save_packfile.set_enabled(false);
             app_ui.packfile_save_packfile_as.set_enabled(false);
+            app_ui.packfile_restore_autosave.set_enabled(false);
             app_ui.packfile_install.set_enabled(false);
             app_ui.packfile_uninstall.set_enabled(false);
+            app_ui.packfile_notes.set_enabled(false);
+            app_ui.packfile_statistics.set_enabled(false);
+            app_ui.packfile_check_conflicts.set_enabled(false);
+            app_ui.packfile_row_annotations.set_enabled(false);
 
             // This one too, though we had to deal with it specially later on.
             app_ui.mymod_new.set_enabled(false);
@@ -1472,16 +1878,21 @@ impl AppUI {
             app_ui.packfile_new_packfile.set_enabled(true);
             app_ui.packfile_save_packfile.set_enabled(enable);
             app_ui.packfile_save_packfile_as.set_enabled(enable);
+            app_ui.packfile_restore_autosave.set_enabled(enable);
+            app_ui.packfile_notes.set_enabled(enable);
+            app_ui.packfile_statistics.set_enabled(enable);
+            app_ui.packfile_check_conflicts.set_enabled(enable);
+            app_ui.packfile_row_annotations.set_enabled(enable);
 
             // Ensure it's a file and it's not in data before proceeding.
             let enable_install = if !pack_path.is_file() { false }
-            else if let Ok(game_data_path) = GAME_SELECTED.read().unwrap().local_mods_path(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name())) {
+            else if let Ok(game_data_path) = read_game_selected().local_mods_path(&setting_path(&read_game_selected().game_key_name())) {
                 game_data_path.is_dir() && !pack_path.starts_with(&game_data_path)
             } else { false };
             app_ui.packfile_install.set_enabled(enable_install);
 
             let enable_uninstall = if !pack_path.is_file() { false }
-            else if let Ok(mut game_data_path) = GAME_SELECTED.read().unwrap().local_mods_path(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name())) {
+            else if let Ok(mut game_data_path) = read_game_selected().local_mods_path(&setting_path(&read_game_selected().game_key_name())) {
                 if !game_data_path.is_dir() || pack_path.starts_with(&game_data_path) { false }
                 else {
                     game_data_path.push(pack_path.file_name().unwrap().to_string_lossy().to_string());
@@ -1499,8 +1910,16 @@ impl AppUI {
         // These actions are common, no matter what game we have.
         app_ui.change_packfile_type_group.set_enabled(enable);
         app_ui.change_packfile_type_index_includes_timestamp.set_enabled(enable);
+        if enable {
+            Self::update_pfh_flags_actions(app_ui);
+        }
 
         app_ui.special_stuff_rescue_packfile.set_enabled(enable);
+        app_ui.special_stuff_verify_integrity.set_enabled(enable);
+        app_ui.special_stuff_compare_packs.set_enabled(enable);
+        app_ui.special_stuff_translation_export_po.set_enabled(enable);
+        app_ui.special_stuff_translation_import_po.set_enabled(enable);
+        app_ui.special_stuff_translation_report.set_enabled(enable);
 
         // If we are enabling...
         if enable {
@@ -1661,6 +2080,9 @@ impl AppUI {
                 app_ui.game_selected_open_game_assembly_kit_folder.set_enabled(false);
             },
         }
+
+        // Actions that need the dependencies stay disabled until they finish rebuilding.
+        Self::update_dependencies_pending_actions(app_ui);
     }
 
     /// This function takes care of recreating the dynamic submenus under `PackFile` menu.
@@ -1672,8 +2094,8 @@ impl AppUI {
     ) {
 
         // First, we clear both menus, so we can rebuild them properly.
-        app_ui.packfile_open_recent.clear();
         app_ui.packfile_open_from_content.clear();
+        app_ui.packfile_open_from_workshop.clear();
         app_ui.packfile_open_from_data.clear();
         app_ui.packfile_open_from_autosave.clear();
 
@@ -1682,51 +2104,10 @@ impl AppUI {
         //---------------------------------------------------------------------------------------//
 
         // Recent PackFiles.
-        let q_settings = settings();
-        if q_settings.contains(&QString::from_std_str("recentFileList")) {
-            let paths = q_settings.value_1a(&QString::from_std_str("recentFileList")).to_string_list();
-
-            for index in 0..paths.count_0a() {
-                let path_str = paths.at(index).to_std_string();
-
-                // That means our file is a valid PackFile and it needs to be added to the menu.
-                let path = PathBuf::from(&path_str);
-                if path.is_file() {
-                    let mod_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
-                    let open_mod_action = app_ui.packfile_open_recent.add_action_q_string(&QString::from_std_str(mod_name));
-
-                    // Create the slot for that action.
-                    let slot_open_mod = SlotOfBool::new(&open_mod_action, clone!(
-                        app_ui,
-                        pack_file_contents_ui,
-                        global_search_ui,
-                        diagnostics_ui,
-                        path => move |_| {
-                        if Self::are_you_sure(&app_ui, false) {
-                            if let Err(error) = Self::open_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &[path.to_path_buf()], "") {
-                                return show_dialog(&app_ui.main_window, error, false);
-                            }
-
-                            if setting_bool("diagnostics_trigger_on_open") {
-
-                                // Disable the top menus before triggering the check. Otherwise, we may end up in a crash.
-                                app_ui.menu_bar_packfile.set_enabled(false);
-
-                                DiagnosticsUI::check(&app_ui, &diagnostics_ui);
-
-                                app_ui.menu_bar_packfile.set_enabled(true);
-                            }
-                        }
-                    }));
-
-                    // Connect the slot and store it.
-                    open_mod_action.triggered().connect(&slot_open_mod);
-                }
-            }
-        }
+        Self::build_recent_packs_submenu(app_ui, pack_file_contents_ui, global_search_ui, diagnostics_ui);
 
         // Get the path of every PackFile in the content folder (if the game's path it's configured) and make an action for each one of them.
-        let mut content_paths = GAME_SELECTED.read().unwrap().content_packs_paths(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name()));
+        let mut content_paths = read_game_selected().content_packs_paths(&setting_path(&read_game_selected().game_key_name()));
         if let Some(ref mut paths) = content_paths {
             paths.sort_unstable_by_key(|x| x.file_name().unwrap().to_string_lossy().as_ref().to_owned());
             for path in paths {
@@ -1744,7 +2125,7 @@ impl AppUI {
                     path => move |_| {
                     if Self::are_you_sure(&app_ui, false) {
                         if let Err(error) = Self::open_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &[path.to_path_buf()], "") {
-                            return show_dialog(&app_ui.main_window, error, false);
+                            return Self::show_error_dialog(app_ui, &error);
                         }
 
                         if setting_bool("diagnostics_trigger_on_open") {
@@ -1752,7 +2133,7 @@ impl AppUI {
                             // Disable the top menus before triggering the check. Otherwise, we may end up in a crash.
                             app_ui.menu_bar_packfile.set_enabled(false);
 
-                            DiagnosticsUI::check(&app_ui, &diagnostics_ui);
+                            DiagnosticsUI::check_when_ready(&app_ui, &diagnostics_ui);
 
                             app_ui.menu_bar_packfile.set_enabled(true);
                         }
@@ -1764,8 +2145,50 @@ impl AppUI {
             }
         }
 
+        // Get the Packs of every Workshop item we're subscribed to (if the game's path it's configured) and make an action for each one of them.
+        // The scan is done in the background, as the acf files can be big.
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetWorkshopPacks);
+        let response = CentralCommand::recv(&receiver);
+        let workshop_packs = match response {
+            Response::VecStringStringPathBuf(packs) => packs,
+            Response::Error(_) => vec![],
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        for (_, title, path) in workshop_packs {
+            let pack_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
+            let open_mod_action = app_ui.packfile_open_from_workshop.add_action_q_string(&QString::from_std_str(format!("{} ({})", title, pack_name)));
+
+            // Create the slot for that action.
+            let slot_open_mod = SlotOfBool::new(&open_mod_action, clone!(
+                app_ui,
+                pack_file_contents_ui,
+                global_search_ui,
+                diagnostics_ui,
+                path => move |_| {
+                if Self::are_you_sure(&app_ui, false) {
+                    if let Err(error) = Self::open_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &[path.to_path_buf()], "") {
+                        return Self::show_error_dialog(app_ui, &error);
+                    }
+
+                    if setting_bool("diagnostics_trigger_on_open") {
+
+                        // Disable the top menus before triggering the check. Otherwise, we may end up in a crash.
+                        app_ui.menu_bar_packfile.set_enabled(false);
+
+                        DiagnosticsUI::check_when_ready(&app_ui, &diagnostics_ui);
+
+                        app_ui.menu_bar_packfile.set_enabled(true);
+                    }
+                }
+            }));
+
+            // Connect the slot and store it.
+            open_mod_action.triggered().connect(&slot_open_mod);
+        }
+
         // Get the path of every PackFile in the data folder (if the game's path it's configured) and make an action for each one of them.
-        let mut data_paths = GAME_SELECTED.read().unwrap().data_packs_paths(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name()));
+        let mut data_paths = read_game_selected().data_packs_paths(&setting_path(&read_game_selected().game_key_name()));
         if let Some(ref mut paths) = data_paths {
             paths.sort_unstable_by_key(|x| x.file_name().unwrap().to_string_lossy().as_ref().to_owned());
             for path in paths {
@@ -1783,7 +2206,7 @@ impl AppUI {
                     path => move |_| {
                     if Self::are_you_sure(&app_ui, false) {
                         if let Err(error) = Self::open_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &[path.to_path_buf()], "") {
-                            return show_dialog(&app_ui.main_window, error, false);
+                            return Self::show_error_dialog(app_ui, &error);
                         }
 
                         if setting_bool("diagnostics_trigger_on_open") {
@@ -1791,7 +2214,7 @@ impl AppUI {
                             // Disable the top menus before triggering the check. Otherwise, we may end up in a crash.
                             app_ui.menu_bar_packfile.set_enabled(false);
 
-                            DiagnosticsUI::check(&app_ui, &diagnostics_ui);
+                            DiagnosticsUI::check_when_ready(&app_ui, &diagnostics_ui);
 
                             app_ui.menu_bar_packfile.set_enabled(true);
                         }
@@ -1803,14 +2226,21 @@ impl AppUI {
             }
         }
 
-        // Get the path of every PackFile in the autosave folder, sorted by modification date, and make an action for each one of them.
+        // Get the path of every PackFile in the autosave folders, sorted by date, and make an action for each one of them.
         if let Ok(autosave_paths) = backup_autosave_path() {
-            let autosave_paths = files_in_folder_from_newest_to_oldest(&autosave_paths);
+            let autosave_paths = files_from_subdir(&autosave_paths, true).map(|mut paths| {
+                paths.retain(|path| path.extension().map(|extension| extension == "pack").unwrap_or(false));
+                paths.sort_by(|a, b| b.file_name().cmp(&a.file_name()));
+                paths
+            });
+
             if let Ok(ref paths) = autosave_paths {
                 for path in paths {
 
-                    // That means our file is a valid PackFile and it needs to be added to the menu.
-                    let mod_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
+                    // Autosaves are stored in a folder named after their Pack, so show both names.
+                    let pack_name = path.parent().and_then(|parent| parent.file_name()).unwrap_or_default().to_string_lossy().to_string();
+                    let autosave_name = path.file_stem().unwrap_or_default().to_string_lossy().replace('T', " ");
+                    let mod_name = format!("{} - {}", pack_name, autosave_name);
                     let open_mod_action = app_ui.packfile_open_from_autosave.add_action_q_string(&QString::from_std_str(mod_name));
 
                     // Create the slot for that action.
@@ -1822,7 +2252,7 @@ impl AppUI {
                         path => move |_| {
                         if Self::are_you_sure(&app_ui, false) {
                             if let Err(error) = Self::open_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &[path.to_path_buf()], "") {
-                                return show_dialog(&app_ui.main_window, error, false);
+                                return Self::show_error_dialog(app_ui, &error);
                             }
 
                             if setting_bool("diagnostics_trigger_on_open") {
@@ -1830,7 +2260,7 @@ impl AppUI {
                                 // Disable the top menus before triggering the check. Otherwise, we may end up in a crash.
                                 app_ui.menu_bar_packfile.set_enabled(false);
 
-                                DiagnosticsUI::check(&app_ui, &diagnostics_ui);
+                                DiagnosticsUI::check_when_ready(&app_ui, &diagnostics_ui);
 
                                 app_ui.menu_bar_packfile.set_enabled(true);
                             }
@@ -1844,12 +2274,97 @@ impl AppUI {
         }
 
         // Only if the submenu has items, we enable it.
-        app_ui.packfile_open_recent.menu_action().set_visible(!app_ui.packfile_open_recent.actions().is_empty());
         app_ui.packfile_open_from_content.menu_action().set_visible(!app_ui.packfile_open_from_content.actions().is_empty());
+        app_ui.packfile_open_from_workshop.menu_action().set_visible(!app_ui.packfile_open_from_workshop.actions().is_empty());
         app_ui.packfile_open_from_data.menu_action().set_visible(!app_ui.packfile_open_from_data.actions().is_empty());
         app_ui.packfile_open_from_autosave.menu_action().set_visible(!app_ui.packfile_open_from_autosave.actions().is_empty());
     }
 
+    /// This function takes care of recreating the `Recent Packs` submenu under `PackFile` menu.
+    ///
+    /// Packs that no longer exist are shown disabled, so they can still be removed from the list through the context menu.
+    pub unsafe fn build_recent_packs_submenu(
+        app_ui: &Rc<Self>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        global_search_ui: &Rc<GlobalSearchUI>,
+        diagnostics_ui: &Rc<DiagnosticsUI>,
+    ) {
+        app_ui.packfile_open_recent.clear();
+
+        let recent_packs = RecentPacks::load().unwrap_or_default();
+        let show_all_games = setting_bool("recent_packs_show_all_games");
+        let game_selected = read_game_selected().game_key_name();
+        let packs = recent_packs.visible(if show_all_games { None } else { Some(game_selected.as_str()) });
+
+        let pin_icon = QIcon::from_theme_1a(&QString::from_std_str("pin"));
+        for (index, pack) in packs.iter().enumerate() {
+
+            // Keep the pinned Packs separated from the rest.
+            if index > 0 && !pack.pinned() && *packs[index - 1].pinned() {
+                app_ui.packfile_open_recent.add_separator();
+            }
+
+            let path = pack.path().to_path_buf();
+            let mut pack_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_else(|| path.to_string_lossy().to_string());
+            if show_all_games {
+                if let Some(game) = SUPPORTED_GAMES.game(pack.game()) {
+                    pack_name = format!("{} ({})", pack_name, game.display_name());
+                }
+            }
+
+            let open_mod_action = app_ui.packfile_open_recent.add_action_q_string(&QString::from_std_str(pack_name));
+            open_mod_action.set_data(&QVariant::from_q_string(&QString::from_std_str(path.to_string_lossy())));
+            if *pack.pinned() {
+                open_mod_action.set_icon(&pin_icon);
+            }
+
+            if !path.is_file() {
+                open_mod_action.set_enabled(false);
+                open_mod_action.set_tool_tip(&QString::from_std_str(tre("recent_packs_missing", &[&path.to_string_lossy()])));
+                continue;
+            }
+
+            open_mod_action.set_tool_tip(&QString::from_std_str(tre("recent_packs_tooltip", &[&path.to_string_lossy()])));
+
+            // Create the slot for that action.
+            let slot_open_mod = SlotOfBool::new(&open_mod_action, clone!(
+                app_ui,
+                pack_file_contents_ui,
+                global_search_ui,
+                diagnostics_ui,
+                path => move |_| {
+                if Self::are_you_sure(&app_ui, false) {
+                    if let Err(error) = Self::open_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &[path.to_path_buf()], "") {
+                        return Self::show_error_dialog(app_ui, &error);
+                    }
+
+                    if setting_bool("diagnostics_trigger_on_open") {
+
+                        // Disable the top menus before triggering the check. Otherwise, we may end up in a crash.
+                        app_ui.menu_bar_packfile.set_enabled(false);
+
+                        DiagnosticsUI::check_when_ready(&app_ui, &diagnostics_ui);
+
+                        app_ui.menu_bar_packfile.set_enabled(true);
+                    }
+                }
+            }));
+
+            // Connect the slot and store it.
+            open_mod_action.triggered().connect(&slot_open_mod);
+        }
+
+        if !packs.is_empty() {
+            app_ui.packfile_open_recent.add_separator();
+        }
+
+        app_ui.packfile_open_recent_show_all_games.set_checked(show_all_games);
+        app_ui.packfile_open_recent.add_action(&app_ui.packfile_open_recent_show_all_games);
+
+        // Only if we have Packs in the list, from any game, we enable it.
+        app_ui.packfile_open_recent.menu_action().set_visible(!recent_packs.packs().is_empty());
+    }
+
     /// This function takes care of the re-creation of the `MyMod` list for each game.
     pub unsafe fn build_open_mymod_submenus(
         app_ui: &Rc<Self>,
@@ -1927,7 +2442,7 @@ impl AppUI {
                                         game_folder_name => move |_| {
                                         if Self::are_you_sure(&app_ui, false) {
                                             if let Err(error) = Self::open_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &[pack_file.to_path_buf()], &game_folder_name) {
-                                                return show_dialog(&app_ui.main_window, error, false);
+                                                return Self::show_error_dialog(app_ui, &error);
                                             }
 
                                             if setting_bool("diagnostics_trigger_on_open") {
@@ -1935,7 +2450,7 @@ impl AppUI {
                                                 // Disable the top menus before triggering the check. Otherwise, we may end up in a crash.
                                                 app_ui.menu_bar_mymod.set_enabled(false);
 
-                                                DiagnosticsUI::check(&app_ui, &diagnostics_ui);
+                                                DiagnosticsUI::check_when_ready(&app_ui, &diagnostics_ui);
 
                                                 app_ui.menu_bar_mymod.set_enabled(true);
                                             }
@@ -2119,9 +2634,13 @@ impl AppUI {
 
             let response = CENTRAL_COMMAND.recv_try(&receiver);
             match response {
-                Response::Success => {
+                Response::SchemaDiffVecString(diff, table_names) => {
                     dialog.set_text(&qtr("schema_update_success"));
                     close_button.set_enabled(true);
+
+                    if !diff.is_empty() {
+                        Self::schema_diff_dialog(app_ui, &diff, &table_names);
+                    }
                 },
                 Response::Error(error) => {
                     dialog.set_text(&QString::from_std_str(error.to_string()));
@@ -2342,7 +2861,8 @@ impl AppUI {
                             } else { return }
                         }
 
-                        DataSource::ExternalFile => unimplemented!(),
+                        DataSource::ExternalFile |
+                        DataSource::ComparedPackFile => unimplemented!(),
                     }
                 }
             };
@@ -2397,7 +2917,7 @@ impl AppUI {
 
                 // Any table banned or from out of our PackFile should not be editable.
                 if let DataSource::PackFile = data_source {
-                    if GAME_SELECTED.read().unwrap().is_file_banned(path) {
+                    if read_game_selected().is_file_banned(path) {
                         tab.set_is_read_only(true);
                     } else {
                         tab.set_is_read_only(false);
@@ -2551,8 +3071,8 @@ impl AppUI {
                         }
 
                         // If the file is a Image PackedFile, ignore failures while opening.
-                        Response::ImageRFileInfo(data, file_info) => {
-                            match PackedFileImageView::new_view(&mut tab, &data) {
+                        Response::ImageRFileInfo(data, header, file_info) => {
+                            match PackedFileImageView::new_view(&mut tab, &data, &header) {
                                 Ok(_) => {
 
                                     // Add the file to the 'Currently open' list and make it visible.
@@ -2647,11 +3167,61 @@ impl AppUI {
                                         Err(error) => return show_dialog(&app_ui.main_window, error, false),
                                     }
                                 }
-                                _ => {},
-                            }
-                        }
 
-                        // If the file is a RigidModel PackedFile...
+                                FileType::SpeedTree => {
+                                    match PackedFileSpeedTreeView::new_view(&mut tab, data) {
+                                        Ok(_) => {
+
+                                            // Add the file to the 'Currently open' list and make it visible.
+                                            app_ui.tab_bar_packed_file.add_tab_3a(tab.get_mut_widget(), icon, &QString::from_std_str(""));
+                                            app_ui.tab_bar_packed_file.set_current_widget(tab.get_mut_widget());
+
+                                            // Fix the tips view.
+                                            let layout = tab.get_mut_widget().layout().static_downcast::<QGridLayout>();
+                                            layout.add_widget_5a(tab.get_tips_widget(), 0, 99, layout.row_count(), 1);
+
+                                            let mut open_list = UI_STATE.set_open_packedfiles();
+                                            open_list.push(tab);
+
+                                            if data_source == DataSource::PackFile {
+                                                pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateTooltip(vec![file_info;1]), data_source);
+                                            }
+                                        }
+                                        Err(error) => return show_dialog(&app_ui.main_window, error, false),
+                                    }
+                                }
+
+                                // Files decoded by registered decoders have no specific view, so we show them in the hex view.
+                                FileType::Custom => {
+                                    let mut buffer = vec![];
+                                    if let RFileDecoded::Custom(mut data) = data {
+                                        if let Err(error) = data.encode(&mut buffer, &None) {
+                                            return show_dialog(&app_ui.main_window, error, false);
+                                        }
+                                    }
+
+                                    PackedFileHexView::new_view(&mut tab, buffer);
+
+                                    // Add the file to the 'Currently open' list and make it visible.
+                                    app_ui.tab_bar_packed_file.add_tab_3a(tab.get_mut_widget(), icon, &QString::from_std_str(""));
+                                    app_ui.tab_bar_packed_file.set_current_widget(tab.get_mut_widget());
+
+                                    // Fix the tips view.
+                                    let layout = tab.get_mut_widget().layout().static_downcast::<QGridLayout>();
+                                    layout.add_widget_5a(tab.get_tips_widget(), 0, 99, layout.row_count(), 1);
+
+                                    let mut open_list = UI_STATE.set_open_packedfiles();
+                                    open_list.push(tab);
+
+                                    if data_source == DataSource::PackFile {
+                                        pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateTooltip(vec![file_info;1]), data_source);
+                                    }
+                                }
+                                _ => {},
+                            }
+                        }
+
+                        // If the file is a RigidModel PackedFile...
                         #[cfg(feature = "support_rigidmodel")]
                         Response::RigidModelRFileInfo(data, file_info) => {
                             if setting_bool("enable_rigidmodel_editor") {
@@ -2680,38 +3250,46 @@ impl AppUI {
 
                         // If the file is a Text PackedFile...
                         Response::TextRFileInfo(data, file_info) => {
-                            PackedFileTextView::new_view(&mut tab, app_ui, pack_file_contents_ui, &data);
+                            match PackedFileTextView::new_view(&mut tab, app_ui, pack_file_contents_ui, &data) {
+                                Ok(_) => {
 
-                            // Add the file to the 'Currently open' list and make it visible.
-                            app_ui.tab_bar_packed_file.add_tab_3a(tab.get_mut_widget(), icon, &QString::from_std_str(""));
-                            app_ui.tab_bar_packed_file.set_current_widget(tab.get_mut_widget());
+                                    // Add the file to the 'Currently open' list and make it visible.
+                                    app_ui.tab_bar_packed_file.add_tab_3a(tab.get_mut_widget(), icon, &QString::from_std_str(""));
+                                    app_ui.tab_bar_packed_file.set_current_widget(tab.get_mut_widget());
 
-                            // Fix the tips view.
-                            let layout = tab.get_mut_widget().layout().static_downcast::<QGridLayout>();
-                            layout.add_widget_5a(tab.get_tips_widget(), 0, 99, layout.row_count(), 1);
+                                    // Fix the tips view.
+                                    let layout = tab.get_mut_widget().layout().static_downcast::<QGridLayout>();
+                                    layout.add_widget_5a(tab.get_tips_widget(), 0, 99, layout.row_count(), 1);
 
-                            let mut open_list = UI_STATE.set_open_packedfiles();
-                            open_list.push(tab);
+                                    let mut open_list = UI_STATE.set_open_packedfiles();
+                                    open_list.push(tab);
 
-                            if data_source == DataSource::PackFile {
-                                pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateTooltip(vec![file_info;1]), data_source);
+                                    if data_source == DataSource::PackFile {
+                                        pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateTooltip(vec![file_info;1]), data_source);
+                                    }
+                                },
+                                Err(error) => return show_dialog(&app_ui.main_window, error, false),
                             }
                         }
 
                         // If the file is the notes...
                         Response::Text(data) => {
-                            PackedFileTextView::new_view(&mut tab, app_ui, pack_file_contents_ui, &data);
+                            match PackedFileTextView::new_view(&mut tab, app_ui, pack_file_contents_ui, &data) {
+                                Ok(_) => {
 
-                            // Add the file to the 'Currently open' list and make it visible.
-                            app_ui.tab_bar_packed_file.add_tab_3a(tab.get_mut_widget(), icon, &QString::from_std_str(""));
-                            app_ui.tab_bar_packed_file.set_current_widget(tab.get_mut_widget());
+                                    // Add the file to the 'Currently open' list and make it visible.
+                                    app_ui.tab_bar_packed_file.add_tab_3a(tab.get_mut_widget(), icon, &QString::from_std_str(""));
+                                    app_ui.tab_bar_packed_file.set_current_widget(tab.get_mut_widget());
 
-                            // Fix the tips view.
-                            let layout = tab.get_mut_widget().layout().static_downcast::<QGridLayout>();
-                            layout.add_widget_5a(tab.get_tips_widget(), 0, 99, layout.row_count(), 1);
+                                    // Fix the tips view.
+                                    let layout = tab.get_mut_widget().layout().static_downcast::<QGridLayout>();
+                                    layout.add_widget_5a(tab.get_tips_widget(), 0, 99, layout.row_count(), 1);
 
-                            let mut open_list = UI_STATE.set_open_packedfiles();
-                            open_list.push(tab);
+                                    let mut open_list = UI_STATE.set_open_packedfiles();
+                                    open_list.push(tab);
+                                },
+                                Err(error) => return show_dialog(&app_ui.main_window, error, false),
+                            }
                         }
 
                         // If the file is a CA_VP8 PackedFile...
@@ -2733,6 +3311,35 @@ impl AppUI {
                             }
                         }
 
+                        // If we don't know how to decode the file, or we failed to decode it, fallback to showing its raw data.
+                        Response::Unknown |
+                        Response::Error(_) if data_source == DataSource::PackFile => {
+                            if let Response::Error(error) = response {
+                                log_to_status_bar(&error.to_string());
+                            }
+
+                            let receiver = CENTRAL_COMMAND.send_background(Command::GetPackedFileRawData(path.to_owned()));
+                            let response = CentralCommand::recv(&receiver);
+                            match response {
+                                Response::VecU8(data) => {
+                                    PackedFileHexView::new_view(&mut tab, data);
+
+                                    // Add the file to the 'Currently open' list and make it visible.
+                                    app_ui.tab_bar_packed_file.add_tab_3a(tab.get_mut_widget(), icon, &QString::from_std_str(""));
+                                    app_ui.tab_bar_packed_file.set_current_widget(tab.get_mut_widget());
+
+                                    // Fix the tips view.
+                                    let layout = tab.get_mut_widget().layout().static_downcast::<QGridLayout>();
+                                    layout.add_widget_5a(tab.get_tips_widget(), 0, 99, layout.row_count(), 1);
+
+                                    let mut open_list = UI_STATE.set_open_packedfiles();
+                                    open_list.push(tab);
+                                }
+                                Response::Error(error) => return show_dialog(&app_ui.main_window, error, false),
+                                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                            }
+                        }
+
                         Response::Unknown => {},
                         Response::Error(error) => return show_dialog(&app_ui.main_window, error, false),
                         _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
@@ -2845,7 +3452,7 @@ impl AppUI {
                 SpecialView::Decoder(ref path) => {
 
                     // If we don't have an schema, don't even try it.
-                    if SCHEMA.read().unwrap().is_none() {
+                    if read_schema().is_none() {
                         return show_dialog(&app_ui.main_window, "No schema found. You need one to open the decoder.", false);
                     }
 
@@ -2965,7 +3572,7 @@ impl AppUI {
         // DB Files require the dependencies cache to be generated, and the schemas to be downloaded.
         if file_type == FileType::DB {
 
-            if SCHEMA.read().unwrap().is_none() {
+            if read_schema().is_none() {
                 return show_dialog(&app_ui.main_window, "There is no Schema for the Game Selected.", false);
             }
 
@@ -3003,7 +3610,8 @@ impl AppUI {
                                 if !name.ends_with(loc::EXTENSION) { name.push_str(loc::EXTENSION); }
                             }
                             if let FileType::Text = file_type {
-                                if !text::EXTENSIONS.iter().any(|(x, _)| name.ends_with(x)) {
+                                let is_game_text_file = read_game_selected().file_type_override(&name).map_or(false, |file_type_override| *file_type_override.file_type() == FileType::Text);
+                                if !is_game_text_file && !text::EXTENSIONS.iter().any(|(x, _)| name.ends_with(x)) {
                                     name.push_str(".txt");
                                 }
                             }
@@ -3011,8 +3619,9 @@ impl AppUI {
                     }
 
                     if let NewPackedFile::Text(ref mut name, ref mut text_type) = new_packed_file {
-                        if let Some((_, text_type_real)) = text::EXTENSIONS.iter().find(|(x, _)| name.ends_with(x)) {
-                            *text_type = *text_type_real
+                        let game_text_type = read_game_selected().file_type_override(name).and_then(|file_type_override| *file_type_override.text_format());
+                        if let Some(text_type_real) = game_text_type.or_else(|| text::EXTENSIONS.iter().find_map(|(x, text_type)| if name.ends_with(x) { Some(*text_type) } else { None })) {
+                            *text_type = text_type_real
                         }
                     }
 
@@ -3202,6 +3811,68 @@ impl AppUI {
         else { None }
     }
 
+    /// This function creates the dialog to choose the language of a PO file.
+    ///
+    /// It returns the language, or None if the dialog is canceled or closed.
+    pub unsafe fn po_language_dialog(app_ui: &Rc<Self>) -> Option<String> {
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("translation_export_po_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(400, 20);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+
+        let language_line_edit = QLineEdit::new();
+        language_line_edit.set_placeholder_text(&qtr("translation_export_po_language"));
+        let accept_button = QPushButton::from_q_string(&qtr("translation_export_po_accept"));
+
+        main_grid.add_widget_5a(&language_line_edit, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 0, 1, 1, 1);
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 { Some(language_line_edit.text().to_std_string().trim().to_owned()) }
+        else { None }
+    }
+
+    /// This function creates the dialog to choose how to generate a translation report.
+    ///
+    /// It returns how to get the language of each Loc and the base language, or None if the dialog is canceled or closed.
+    pub unsafe fn translation_report_options_dialog(app_ui: &Rc<Self>) -> Option<(LanguageDetection, String)> {
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("translation_report_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(400, 20);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+
+        let detection_label = QLabel::from_q_string_q_widget(&qtr("translation_report_detection"), &dialog);
+        let detection_combobox = QComboBox::new_1a(&dialog);
+        detection_combobox.add_item_q_string(&qtr("translation_report_detection_file_suffix"));
+        detection_combobox.add_item_q_string(&qtr("translation_report_detection_folder"));
+
+        let base_language_label = QLabel::from_q_string_q_widget(&qtr("translation_report_base_language"), &dialog);
+        let base_language_line_edit = QLineEdit::from_q_string_q_widget(&QString::from_std_str("en"), &dialog);
+        let accept_button = QPushButton::from_q_string(&qtr("translation_report_accept"));
+
+        main_grid.add_widget_5a(&detection_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&detection_combobox, 0, 1, 1, 1);
+        main_grid.add_widget_5a(&base_language_label, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&base_language_line_edit, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 2, 0, 1, 2);
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 {
+            let detection = match detection_combobox.current_index() {
+                1 => LanguageDetection::Folder,
+                _ => LanguageDetection::FileSuffix,
+            };
+
+            let base_language = base_language_line_edit.text().to_std_string().trim().to_owned();
+            if base_language.is_empty() { None }
+            else { Some((detection, base_language)) }
+        } else { None }
+    }
+
     /// This function creates all the "New PackedFile" dialogs.
     ///
     /// It returns the type/name of the new file, or None if the dialog is canceled or closed.
@@ -3350,7 +4021,7 @@ impl AppUI {
     }
 
     /// This function creates the entire "Merge Tables" dialog. It returns the stuff set in it.
-    pub unsafe fn merge_tables_dialog(app_ui: &Rc<Self>) -> Option<(String, bool)> {
+    pub unsafe fn merge_tables_dialog(app_ui: &Rc<Self>) -> Option<(String, bool, bool)> {
 
         let dialog = QDialog::new_1a(&app_ui.main_window);
         dialog.set_window_title(&qtr("packedfile_merge_tables"));
@@ -3376,11 +4047,13 @@ impl AppUI {
         name_line_edit.set_text(&QString::from_std_str(packfile_name));
 
         let delete_source_tables = QCheckBox::from_q_string(&qtr("merge_tables_delete_option"));
+        let upgrade_before_merge = QCheckBox::from_q_string(&qtr("merge_tables_upgrade_option"));
 
         let accept_button = QPushButton::from_q_string(&qtr("gen_loc_accept"));
         main_grid.add_widget_5a(&name_line_edit, 0, 0, 1, 1);
         main_grid.add_widget_5a(&delete_source_tables, 1, 0, 1, 1);
-        main_grid.add_widget_5a(&accept_button, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&upgrade_before_merge, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 3, 0, 1, 1);
 
         // What happens when we hit the "Search" button.
         accept_button.released().connect(dialog.slot_accept());
@@ -3389,7 +4062,8 @@ impl AppUI {
         if dialog.exec() == 1 {
             let text = name_line_edit.text().to_std_string();
             let delete_source_tables = delete_source_tables.is_checked();
-            if !text.is_empty() { Some((text, delete_source_tables)) }
+            let upgrade_before_merge = upgrade_before_merge.is_checked();
+            if !text.is_empty() { Some((text, delete_source_tables, upgrade_before_merge)) }
             else { None }
         }
 
@@ -3397,122 +4071,1326 @@ impl AppUI {
         else { None }
     }
 
-    /// Update the PackedFileView names, to ensure we have no collisions.
-    pub unsafe fn update_views_names(&self) {
+    /// This function creates the entire "Optimize PackFile" dialog. It returns the optimizations to perform, or None if it was cancelled.
+    pub unsafe fn optimizer_options_dialog(app_ui: &Rc<Self>) -> Option<OptimizerOptions> {
 
-        // We also have to check for colliding packedfile names, so we can use their full path instead.
-        let mut names = HashMap::new();
-        let open_packedfiles = UI_STATE.get_open_packedfiles();
-        for packed_file_view in open_packedfiles.iter() {
-            let widget = packed_file_view.get_mut_widget();
-            if self.tab_bar_packed_file.index_of(widget) != -1 {
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("special_stuff_optimize_packfile"));
+        dialog.set_modal(true);
 
-                // Reserved PackedFiles should have special names.
-                let path = packed_file_view.get_ref_path();
-                let path_split = path.split('/').collect::<Vec<_>>();
-                if *path == RESERVED_NAME_NOTES {
-                    names.insert("Notes".to_owned(), 1);
-                } else if let Some(name) = path_split.last() {
-                    match names.get_mut(*name) {
-                        Some(name) => *name += 1,
-                        None => { names.insert(name.to_string(), 1); },
-                    }
-                }
-            }
-        }
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtr("optimize_packfile_are_you_sure"), &dialog);
+        explanation_label.set_word_wrap(true);
+
+        let remove_itm = QCheckBox::from_q_string_q_widget(&qtr("optimizer_remove_itm"), &dialog);
+        let remove_itnr = QCheckBox::from_q_string_q_widget(&qtr("optimizer_remove_itnr"), &dialog);
+        let remove_duplicates = QCheckBox::from_q_string_q_widget(&qtr("optimizer_remove_duplicates"), &dialog);
+        let remove_empty_files = QCheckBox::from_q_string_q_widget(&qtr("optimizer_remove_empty_files"), &dialog);
+        let remove_map_xml = QCheckBox::from_q_string_q_widget(&qtr("optimizer_remove_map_xml"), &dialog);
+        let remove_overridden_loc_entries = QCheckBox::from_q_string_q_widget(&qtr("optimizer_remove_overridden_loc_entries"), &dialog);
+
+        let options = OptimizerOptions::default();
+        remove_itm.set_checked(*options.remove_itm());
+        remove_itnr.set_checked(*options.remove_itnr());
+        remove_duplicates.set_checked(*options.remove_duplicates());
+        remove_empty_files.set_checked(*options.remove_empty_files());
+        remove_map_xml.set_checked(*options.remove_map_xml());
+        remove_overridden_loc_entries.set_checked(*options.remove_overridden_loc_entries());
 
-        for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
-            let widget = packed_file_view.get_mut_widget();
-            let path = packed_file_view.get_ref_path();
-            let path_split = path.split('/').collect::<Vec<_>>();
-            let widget_name = if *path == RESERVED_NAME_NOTES {
-                "Notes".to_owned()
-            } else if let Some(widget_name) = path_split.last() {
-                widget_name.to_string()
-            } else {
-                "".to_owned()
-            };
+        let accept_button = QPushButton::from_q_string(&qtr("gen_loc_accept"));
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&remove_itm, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&remove_itnr, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&remove_duplicates, 3, 0, 1, 1);
+        main_grid.add_widget_5a(&remove_empty_files, 4, 0, 1, 1);
+        main_grid.add_widget_5a(&remove_map_xml, 5, 0, 1, 1);
+        main_grid.add_widget_5a(&remove_overridden_loc_entries, 6, 0, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 7, 0, 1, 1);
 
-            if let Some(count) = names.get(&widget_name) {
-                let mut name = String::new();
-                match packed_file_view.get_data_source() {
-                    DataSource::PackFile => {},
-                    DataSource::ParentFiles => name.push_str("Parent"),
-                    DataSource::GameFiles => name.push_str("Game"),
-                    DataSource::AssKitFiles => name.push_str("AssKit"),
-                    DataSource::ExternalFile => name.push_str("External"),
-                }
+        accept_button.released().connect(dialog.slot_accept());
 
-                if !name.is_empty() {
-                    if packed_file_view.get_is_read_only() {
-                        name.push_str("-RO:");
-                    } else  {
-                        name.push(':');
-                    }
-                }
+        if dialog.exec() == 1 {
+            let mut options = OptimizerOptions::default();
+            options.set_remove_itm(remove_itm.is_checked());
+            options.set_remove_itnr(remove_itnr.is_checked());
+            options.set_remove_duplicates(remove_duplicates.is_checked());
+            options.set_remove_empty_files(remove_empty_files.is_checked());
+            options.set_remove_map_xml(remove_map_xml.is_checked());
+            options.set_remove_overridden_loc_entries(remove_overridden_loc_entries.is_checked());
+            Some(options)
+        } else { None }
+    }
 
-                if count > &1 {
-                    name.push_str(&path);
-                } else {
-                    name.push_str(&widget_name.to_owned());
-                };
+    /// This function creates the "Optimizer Report" dialog, showing what the optimizer is going to do to each file. It returns if the optimization should be applied.
+    pub unsafe fn optimizer_report_dialog(app_ui: &Rc<Self>, report: &OptimizerReport) -> bool {
 
-                if packed_file_view.get_is_preview() {
-                    name.push_str(" (Preview)");
-                }
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("optimizer_report_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(900, 500);
 
-                let index = self.tab_bar_packed_file.index_of(widget);
-                self.tab_bar_packed_file.set_tab_text(index, &QString::from_std_str(&name));
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtre("optimizer_report_explanation", &[&report.files().len().to_string()]), &dialog);
+        explanation_label.set_word_wrap(true);
+
+        let tree_view = QTreeView::new_1a(&dialog);
+        let model = QStandardItemModel::new_1a(&tree_view);
+        tree_view.set_model(&model);
+        tree_view.set_root_is_decorated(false);
+        tree_view.set_sorting_enabled(true);
+
+        for (path, file_report) in report.files() {
+            let qlist = QListOfQStandardItem::new();
+            let path_item = QStandardItem::from_q_string(&QString::from_std_str(path));
+            path_item.set_editable(false);
+            qlist.append_q_standard_item(&path_item.into_ptr().as_mut_raw_ptr());
+
+            for count in [file_report.duplicated_rows(), file_report.itm_rows(), file_report.itnr_rows(), file_report.overridden_rows()] {
+                let item = QStandardItem::from_q_string(&QString::from_std_str(count.to_string()));
+                item.set_editable(false);
+                qlist.append_q_standard_item(&item.into_ptr().as_mut_raw_ptr());
+            }
+
+            let removed_item = QStandardItem::new();
+            removed_item.set_editable(false);
+            if *file_report.removed() {
+                removed_item.set_text(&qtr("optimizer_report_removed"));
+                removed_item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("edit-delete")));
             }
+            qlist.append_q_standard_item(&removed_item.into_ptr().as_mut_raw_ptr());
+
+            model.append_row_q_list_of_q_standard_item(qlist.as_ref());
         }
+
+        model.set_header_data_3a(0, Orientation::Horizontal, &QVariant::from_q_string(&qtr("optimizer_report_column_path")));
+        model.set_header_data_3a(1, Orientation::Horizontal, &QVariant::from_q_string(&qtr("optimizer_report_column_duplicated")));
+        model.set_header_data_3a(2, Orientation::Horizontal, &QVariant::from_q_string(&qtr("optimizer_report_column_itm")));
+        model.set_header_data_3a(3, Orientation::Horizontal, &QVariant::from_q_string(&qtr("optimizer_report_column_itnr")));
+        model.set_header_data_3a(4, Orientation::Horizontal, &QVariant::from_q_string(&qtr("optimizer_report_column_overridden")));
+        model.set_header_data_3a(5, Orientation::Horizontal, &QVariant::from_q_string(&qtr("optimizer_report_column_removed")));
+        tree_view.header().resize_sections(ResizeMode::ResizeToContents);
+
+        let cancel_button = QPushButton::from_q_string(&qtr("cancel"));
+        let apply_button = QPushButton::from_q_string(&qtr("optimizer_report_apply"));
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&tree_view, 1, 0, 1, 2);
+        main_grid.add_widget_5a(&cancel_button, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&apply_button, 2, 1, 1, 1);
+
+        cancel_button.released().connect(dialog.slot_close());
+        apply_button.released().connect(dialog.slot_accept());
+
+        dialog.exec() == 1
     }
 
-    /// This function hides all the provided packedfile views.
-    pub unsafe fn packed_file_view_hide(
-        app_ui: &Rc<AppUI>,
-        pack_file_contents_ui: &Rc<PackFileContentsUI>,
-        indexes: &[i32]
-    ) {
+    /// This function creates the "Pack Statistics" dialog, showing the provided statistics grouped in a tree.
+    pub unsafe fn pack_statistics_dialog(app_ui: &Rc<Self>, statistics: &PackStatistics) {
 
-        let mut indexes = indexes.to_vec();
-        indexes.sort_unstable();
-        indexes.dedup();
-        indexes.reverse();
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("packfile_statistics"));
+        dialog.set_modal(true);
+        dialog.resize_2a(800, 600);
 
-        // PackFile and Decoder Views must be deleted on close, so get them apart if we find one.
-        let mut purge_on_delete = vec![];
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let tree_view = QTreeView::new_1a(&dialog);
+        let model = QStandardItemModel::new_1a(&tree_view);
+        tree_view.set_model(&model);
+
+        // Each row has a name, and optionally an amount of files, an amount of rows and a size.
+        let new_row = |parent: Ptr<QStandardItem>, name: &str, files: Option<usize>, rows: Option<usize>, size: Option<u64>| {
+            let qlist = QListOfQStandardItem::new();
+            let values = [
+                name.to_owned(),
+                files.map(|files| files.to_string()).unwrap_or_default(),
+                rows.map(|rows| rows.to_string()).unwrap_or_default(),
+                size.map(format_size).unwrap_or_default(),
+            ];
+
+            for value in values {
+                let item = QStandardItem::from_q_string(&QString::from_std_str(value));
+                item.set_editable(false);
+                qlist.append_q_standard_item(&item.into_ptr().as_mut_raw_ptr());
+            }
 
-        for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
-            let widget = packed_file_view.get_mut_widget();
-            let index_widget = app_ui.tab_bar_packed_file.index_of(widget);
-            if indexes.contains(&index_widget) {
-                let path = packed_file_view.get_ref_path();
-                if !path.is_empty() {
-                    if path.starts_with(RESERVED_NAME_EXTRA_PACKFILE) {
-                        purge_on_delete.push(path.to_owned());
+            parent.append_row_q_list_of_q_standard_item(qlist.as_ref());
+        };
 
-                        let path_split = path.split('/').collect::<Vec<_>>();
-                        let path = path_split[1..].join("/");
-                        let _ = CENTRAL_COMMAND.send_background(Command::RemovePackFileExtra(PathBuf::from(&path)));
-                    }
-                    else if path.ends_with(DECODER_EXTENSION) {
-                        purge_on_delete.push(path.to_owned());
-                    }
-                }
-            }
-        }
+        let new_group = |name: &str| {
+            let item = QStandardItem::from_q_string(&qtr(name));
+            item.set_editable(false);
+            let item = item.into_ptr();
+            model.append_row_q_standard_item(item);
+            item
+        };
 
-        indexes.iter().for_each(|x| app_ui.tab_bar_packed_file.remove_tab(*x));
+        let summary = new_group("pack_statistics_summary");
+        new_row(summary, &tr("pack_statistics_total"), Some(*statistics.total_files()), None, Some(*statistics.total_size()));
+        new_row(summary, &tr("pack_statistics_db_tables"), Some(statistics.tables().values().map(|table| table.files()).sum()), Some(statistics.tables().values().map(|table| table.rows()).sum()), None);
+        new_row(summary, &tr("pack_statistics_loc_files"), Some(*statistics.loc_files()), Some(*statistics.loc_keys()), None);
+        new_row(summary, &tr("pack_statistics_undecoded_files"), Some(statistics.undecoded_files().len()), None, Some(statistics.undecoded_size()));
 
-        // This is for cleaning up open PackFiles.
-        purge_on_delete.iter().for_each(|x| { let _ = Self::purge_that_one_specifically(app_ui, pack_file_contents_ui, x, DataSource::ExternalFile, false); });
+        let types = new_group("pack_statistics_types");
+        for (file_type, type_statistics) in statistics.types() {
+            new_row(types, &file_type.to_string(), Some(*type_statistics.files()), None, Some(*type_statistics.size()));
+        }
 
-        // And this is for cleaning decoders.
-        purge_on_delete.iter().for_each(|x| { let _ = Self::purge_that_one_specifically(app_ui, pack_file_contents_ui, x, DataSource::PackFile, false); });
+        let largest_files = new_group("pack_statistics_largest_files");
+        for (path, size) in statistics.largest_files() {
+            new_row(largest_files, path, None, None, Some(*size));
+        }
 
-        // Update the background icon.
-        GameSelectedIcons::set_game_selected_icon(app_ui);
-    }
+        let tables = new_group("pack_statistics_tables");
+        for (table_name, table_statistics) in statistics.tables() {
+            new_row(tables, table_name, Some(*table_statistics.files()), Some(*table_statistics.rows()), None);
+        }
+
+        let undecoded_files = new_group("pack_statistics_undecoded_files");
+        for (path, size) in statistics.undecoded_files() {
+            new_row(undecoded_files, path, None, None, Some(*size));
+        }
+
+        model.set_header_data_3a(0, Orientation::Horizontal, &QVariant::from_q_string(&qtr("pack_statistics_column_name")));
+        model.set_header_data_3a(1, Orientation::Horizontal, &QVariant::from_q_string(&qtr("pack_statistics_column_files")));
+        model.set_header_data_3a(2, Orientation::Horizontal, &QVariant::from_q_string(&qtr("pack_statistics_column_rows")));
+        model.set_header_data_3a(3, Orientation::Horizontal, &QVariant::from_q_string(&qtr("pack_statistics_column_size")));
+        tree_view.expand_to_depth(0);
+        tree_view.header().resize_sections(ResizeMode::ResizeToContents);
+
+        let copy_button = QPushButton::from_q_string(&qtr("pack_statistics_copy_markdown"));
+        let close_button = QPushButton::from_q_string(&qtr("close"));
+        main_grid.add_widget_5a(&tree_view, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&copy_button, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&close_button, 1, 1, 1, 1);
+
+        let markdown = statistics.to_markdown();
+        let copy_slot = SlotNoArgs::new(&dialog, move || {
+            QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(&markdown));
+        });
+
+        copy_button.released().connect(&copy_slot);
+        close_button.released().connect(dialog.slot_close());
+
+        dialog.exec();
+    }
+
+    /// This function creates the "Check Conflicts with Other Packs" dialog, showing the provided conflicts grouped by Pack or by folder.
+    pub unsafe fn pack_conflicts_dialog(app_ui: &Rc<Self>, conflicts: &PackConflicts) {
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("packfile_check_conflicts"));
+        dialog.set_modal(true);
+        dialog.resize_2a(900, 700);
+
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtre("pack_conflicts_explanation", &[
+            &conflicts.conflicts().len().to_string(),
+            &conflicts.load_order().len().to_string(),
+            &conflicts.load_order().join(", "),
+        ]), &dialog);
+        explanation_label.set_word_wrap(true);
+
+        let group_by_label = QLabel::from_q_string_q_widget(&qtr("pack_conflicts_group_by"), &dialog);
+        let group_by_combo = QComboBox::new_1a(&dialog);
+        group_by_combo.add_item_q_string(&qtr("pack_conflicts_group_by_pack"));
+        group_by_combo.add_item_q_string(&qtr("pack_conflicts_group_by_folder"));
+
+        let tree_view = QTreeView::new_1a(&dialog);
+        let model = QStandardItemModel::new_1a(&tree_view);
+        tree_view.set_model(&model);
+
+        let export_button = QPushButton::from_q_string(&qtr("pack_conflicts_export_text"));
+        let close_button = QPushButton::from_q_string(&qtr("close"));
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&group_by_label, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&group_by_combo, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&tree_view, 2, 0, 1, 2);
+        main_grid.add_widget_5a(&export_button, 3, 0, 1, 1);
+        main_grid.add_widget_5a(&close_button, 3, 1, 1, 1);
+
+        let tree_view_ptr = tree_view.as_ptr();
+        let model_ptr = model.as_ptr();
+        Self::load_pack_conflicts_to_view(tree_view_ptr, model_ptr, conflicts, true);
+
+        let conflicts_to_group = conflicts.clone();
+        let group_by_slot = SlotOfInt::new(&dialog, move |index| {
+            Self::load_pack_conflicts_to_view(tree_view_ptr, model_ptr, &conflicts_to_group, index == 0);
+        });
+
+        let text = conflicts.to_text();
+        let dialog_ptr = dialog.as_ptr();
+        let export_slot = SlotNoArgs::new(&dialog, move || {
+            let file_dialog = QFileDialog::from_q_widget_q_string(dialog_ptr, &qtr("pack_conflicts_export_text"));
+            file_dialog.set_accept_mode(qt_widgets::q_file_dialog::AcceptMode::AcceptSave);
+            file_dialog.set_name_filter(&QString::from_std_str("Text Files (*.txt)"));
+            file_dialog.set_confirm_overwrite(true);
+            file_dialog.set_default_suffix(&QString::from_std_str("txt"));
+
+            if file_dialog.exec() == 1 {
+                let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                if let Err(error) = std::fs::write(&path, &text) {
+                    show_dialog(dialog_ptr, error, false);
+                }
+            }
+        });
+
+        group_by_combo.current_index_changed().connect(&group_by_slot);
+        export_button.released().connect(&export_slot);
+        close_button.released().connect(dialog.slot_close());
+
+        dialog.exec();
+    }
+
+    /// This function loads the provided conflicts to the view of the conflicts dialog, grouped by Pack or by folder.
+    ///
+    /// Each conflict shows its conflicting rows as children, if it has any.
+    unsafe fn load_pack_conflicts_to_view(tree_view: Ptr<QTreeView>, model: Ptr<QStandardItemModel>, conflicts: &PackConflicts, by_pack: bool) {
+        model.clear();
+
+        let new_item = |text: &str| {
+            let item = QStandardItem::from_q_string(&QString::from_std_str(text));
+            item.set_editable(false);
+            item.into_ptr()
+        };
+
+        let new_conflict_row = |parent: Ptr<QStandardItem>, conflict: &FileConflict| {
+            let path = new_item(conflict.path());
+            let qlist = QListOfQStandardItem::new();
+            qlist.append_q_standard_item(&path.as_mut_raw_ptr());
+            qlist.append_q_standard_item(&new_item(conflict.winner()).as_mut_raw_ptr());
+            qlist.append_q_standard_item(&new_item(&conflict.losers().join(", ")).as_mut_raw_ptr());
+            qlist.append_q_standard_item(&new_item(&conflict.row_conflicts().as_ref().map(|rows| rows.len().to_string()).unwrap_or_default()).as_mut_raw_ptr());
+            parent.append_row_q_list_of_q_standard_item(qlist.as_ref());
+
+            if let Some(row_conflicts) = conflict.row_conflicts() {
+                for row_conflict in row_conflicts {
+                    let qlist = QListOfQStandardItem::new();
+                    qlist.append_q_standard_item(&new_item(row_conflict.key()).as_mut_raw_ptr());
+                    qlist.append_q_standard_item(&new_item(row_conflict.rows().first().map(|(name, _)| name.as_str()).unwrap_or_default()).as_mut_raw_ptr());
+                    qlist.append_q_standard_item(&new_item(&row_conflict.rows().iter().skip(1).map(|(name, _)| name.as_str()).collect::<Vec<_>>().join(", ")).as_mut_raw_ptr());
+                    path.append_row_q_list_of_q_standard_item(qlist.as_ref());
+                }
+            }
+        };
+
+        let root = model.invisible_root_item();
+        if by_pack {
+
+            // Packs are shown in load order, not by name, so it's easy to see which ones win.
+            let conflicts_by_pack = conflicts.conflicts_by_pack();
+            for (index, pack_name) in conflicts.load_order().iter().enumerate() {
+                if let Some(pack_conflicts) = conflicts_by_pack.get(pack_name.as_str()) {
+                    let group = new_item(&format!("{}. {}", index + 1, pack_name));
+                    root.append_row_q_standard_item(group);
+
+                    for conflict in pack_conflicts {
+                        new_conflict_row(group, conflict);
+                    }
+                }
+            }
+        } else {
+            let mut conflicts_by_folder: BTreeMap<&str, Vec<&FileConflict>> = BTreeMap::new();
+            for conflict in conflicts.conflicts() {
+                let folder = conflict.path().rsplit_once('/').map(|(folder, _)| folder).unwrap_or_default();
+                conflicts_by_folder.entry(folder).or_default().push(conflict);
+            }
+
+            for (folder, folder_conflicts) in &conflicts_by_folder {
+                let group = new_item(folder);
+                root.append_row_q_standard_item(group);
+
+                for conflict in folder_conflicts {
+                    new_conflict_row(group, conflict);
+                }
+            }
+        }
+
+        if !conflicts.unreadable_packs().is_empty() {
+            let group = new_item(&tr("pack_conflicts_unreadable_packs"));
+            root.append_row_q_standard_item(group);
+
+            for (path, error) in conflicts.unreadable_packs() {
+                let qlist = QListOfQStandardItem::new();
+                qlist.append_q_standard_item(&new_item(path).as_mut_raw_ptr());
+                qlist.append_q_standard_item(&new_item(error).as_mut_raw_ptr());
+                group.append_row_q_list_of_q_standard_item(qlist.as_ref());
+            }
+        }
+
+        model.set_header_data_3a(0, Orientation::Horizontal, &QVariant::from_q_string(&qtr("pack_conflicts_column_path")));
+        model.set_header_data_3a(1, Orientation::Horizontal, &QVariant::from_q_string(&qtr("pack_conflicts_column_winner")));
+        model.set_header_data_3a(2, Orientation::Horizontal, &QVariant::from_q_string(&qtr("pack_conflicts_column_losers")));
+        model.set_header_data_3a(3, Orientation::Horizontal, &QVariant::from_q_string(&qtr("pack_conflicts_column_rows")));
+        tree_view.expand_to_depth(0);
+        tree_view.header().resize_sections(ResizeMode::ResizeToContents);
+    }
+
+    /// This function creates the "Row Notes" dialog, showing all the row notes of the open Pack.
+    ///
+    /// Notes whose rows cannot be found are shown as orphaned, so they can be fixed or removed manually.
+    /// If the user double-clicks a note with a row, this returns its path and row key so we can jump to it.
+    pub unsafe fn row_annotations_dialog(app_ui: &Rc<Self>, pack_file_contents_ui: &Rc<PackFileContentsUI>, annotations: &[(String, String, String, bool)]) -> Option<(String, String)> {
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("packfile_row_annotations"));
+        dialog.set_modal(true);
+        dialog.resize_2a(900, 600);
+
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtr("row_annotations_explanation"), &dialog);
+        explanation_label.set_word_wrap(true);
+
+        let tree_view = QTreeView::new_1a(&dialog);
+        let model = QStandardItemModel::new_1a(&tree_view);
+        tree_view.set_model(&model);
+        tree_view.set_root_is_decorated(false);
+        tree_view.set_sorting_enabled(true);
+        tree_view.set_selection_mode(qt_widgets::q_abstract_item_view::SelectionMode::ExtendedSelection);
+
+        let new_item = |text: &str| {
+            let item = QStandardItem::from_q_string(&QString::from_std_str(text));
+            item.set_editable(false);
+            item.into_ptr()
+        };
+
+        for (path, key, note, is_orphaned) in annotations {
+            let status = if *is_orphaned { tr("row_annotations_status_orphaned") } else { tr("row_annotations_status_found") };
+            let qlist = QListOfQStandardItem::new();
+            qlist.append_q_standard_item(&new_item(path).as_mut_raw_ptr());
+            qlist.append_q_standard_item(&new_item(key).as_mut_raw_ptr());
+            qlist.append_q_standard_item(&new_item(note).as_mut_raw_ptr());
+            qlist.append_q_standard_item(&new_item(&status).as_mut_raw_ptr());
+
+            // Keep the orphaned flag in the first item, so we don't depend on the translated text.
+            model.append_row_q_list_of_q_standard_item(qlist.as_ref());
+            model.item_1a(model.row_count_0a() - 1).set_data_2a(&QVariant::from_bool(*is_orphaned), ROW_ANNOTATION_IS_ORPHANED);
+        }
+
+        model.set_header_data_3a(0, Orientation::Horizontal, &QVariant::from_q_string(&qtr("row_annotations_column_path")));
+        model.set_header_data_3a(1, Orientation::Horizontal, &QVariant::from_q_string(&qtr("row_annotations_column_key")));
+        model.set_header_data_3a(2, Orientation::Horizontal, &QVariant::from_q_string(&qtr("row_annotations_column_note")));
+        model.set_header_data_3a(3, Orientation::Horizontal, &QVariant::from_q_string(&qtr("row_annotations_column_status")));
+        tree_view.header().resize_sections(ResizeMode::ResizeToContents);
+
+        let remove_button = QPushButton::from_q_string(&qtr("row_annotations_remove"));
+        let close_button = QPushButton::from_q_string(&qtr("close"));
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&tree_view, 1, 0, 1, 2);
+        main_grid.add_widget_5a(&remove_button, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&close_button, 2, 1, 1, 1);
+
+        let model_ptr = model.as_ptr();
+        let tree_view_ptr = tree_view.as_ptr();
+        let remove_slot = SlotNoArgs::new(&dialog, clone!(
+            app_ui,
+            pack_file_contents_ui => move || {
+                let mut rows = (0..tree_view_ptr.selection_model().selected_rows_0a().count_0a())
+                    .map(|index| tree_view_ptr.selection_model().selected_rows_0a().at(index).row())
+                    .collect::<Vec<_>>();
+
+                if rows.is_empty() {
+                    return;
+                }
+
+                // Remove them from the bottom, so the rows we still have to remove don't move.
+                rows.sort_unstable();
+                for row in rows.iter().rev() {
+                    let path = model_ptr.item_2a(*row, 0).text().to_std_string();
+                    let key = model_ptr.item_2a(*row, 1).text().to_std_string();
+                    let _ = CENTRAL_COMMAND.send_background(Command::SetRowAnnotation(path, key, None));
+                    model_ptr.remove_row_1a(*row);
+                }
+
+                UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
+            }
+        ));
+
+        let selected_row = Rc::new(RefCell::new(None));
+        let dialog_ptr = dialog.as_ptr();
+        let double_click_slot = SlotOfQModelIndex::new(&dialog, clone!(
+            selected_row => move |index| {
+                let item = model_ptr.item_2a(index.row(), 0);
+                if !item.data_1a(ROW_ANNOTATION_IS_ORPHANED).to_bool() {
+                    let key = model_ptr.item_2a(index.row(), 1).text().to_std_string();
+                    *selected_row.borrow_mut() = Some((item.text().to_std_string(), key));
+                    dialog_ptr.accept();
+                }
+            }
+        ));
+
+        remove_button.released().connect(&remove_slot);
+        tree_view.double_clicked().connect(&double_click_slot);
+        close_button.released().connect(dialog.slot_close());
+
+        dialog.exec();
+        selected_row.take()
+    }
+
+    /// This function creates the "Translation Report" dialog, showing the keys each language is missing or has untranslated.
+    pub unsafe fn translation_report_dialog(app_ui: &Rc<Self>, report: &TranslationReport) {
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("translation_report_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(900, 700);
+
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtre("translation_report_explanation", &[
+            &report.total_keys().to_string(),
+            &report.languages().len().to_string(),
+            report.base_language(),
+            &report.skipped_files().len().to_string(),
+        ]), &dialog);
+        explanation_label.set_word_wrap(true);
+
+        // Numbers are stored as numbers, so sorting by them works as expected.
+        let new_text_item = |text: &str| {
+            let item = QStandardItem::from_q_string(&QString::from_std_str(text));
+            item.set_editable(false);
+            item.into_ptr().as_mut_raw_ptr()
+        };
+
+        let new_number_item = |number: usize| {
+            let item = QStandardItem::new();
+            item.set_data_2a(&QVariant::from_int(number as i32), 2);
+            item.set_editable(false);
+            item.into_ptr().as_mut_raw_ptr()
+        };
+
+        let languages_tree_view = QTreeView::new_1a(&dialog);
+        let languages_model = QStandardItemModel::new_1a(&languages_tree_view);
+        languages_tree_view.set_model(&languages_model);
+        languages_tree_view.set_root_is_decorated(false);
+        languages_tree_view.set_sorting_enabled(true);
+
+        for (language, summary) in report.languages() {
+            let qlist = QListOfQStandardItem::new();
+            qlist.append_q_standard_item(&new_text_item(language));
+            for number in [summary.files(), summary.keys(), summary.missing(), summary.untranslated()] {
+                qlist.append_q_standard_item(&new_number_item(*number));
+            }
+
+            languages_model.append_row_q_list_of_q_standard_item(qlist.as_ref());
+        }
+
+        languages_model.set_header_data_3a(0, Orientation::Horizontal, &QVariant::from_q_string(&qtr("translation_report_column_language")));
+        languages_model.set_header_data_3a(1, Orientation::Horizontal, &QVariant::from_q_string(&qtr("translation_report_column_files")));
+        languages_model.set_header_data_3a(2, Orientation::Horizontal, &QVariant::from_q_string(&qtr("translation_report_column_keys")));
+        languages_model.set_header_data_3a(3, Orientation::Horizontal, &QVariant::from_q_string(&qtr("translation_report_column_missing")));
+        languages_model.set_header_data_3a(4, Orientation::Horizontal, &QVariant::from_q_string(&qtr("translation_report_column_untranslated")));
+        languages_tree_view.header().resize_sections(ResizeMode::ResizeToContents);
+
+        let entries_tree_view = QTreeView::new_1a(&dialog);
+        let entries_model = QStandardItemModel::new_1a(&entries_tree_view);
+        entries_tree_view.set_model(&entries_model);
+        entries_tree_view.set_root_is_decorated(false);
+        entries_tree_view.set_sorting_enabled(true);
+
+        for entry in report.entries() {
+            let issue = match entry.issue() {
+                TranslationIssue::Missing => tr("translation_report_issue_missing"),
+                TranslationIssue::Untranslated => tr("translation_report_issue_untranslated"),
+            };
+
+            let qlist = QListOfQStandardItem::new();
+            qlist.append_q_standard_item(&new_text_item(entry.language()));
+            qlist.append_q_standard_item(&new_text_item(entry.key()));
+            qlist.append_q_standard_item(&new_text_item(&issue));
+            qlist.append_q_standard_item(&new_text_item(entry.base_text()));
+            entries_model.append_row_q_list_of_q_standard_item(qlist.as_ref());
+        }
+
+        entries_model.set_header_data_3a(0, Orientation::Horizontal, &QVariant::from_q_string(&qtr("translation_report_column_language")));
+        entries_model.set_header_data_3a(1, Orientation::Horizontal, &QVariant::from_q_string(&qtr("translation_report_column_key")));
+        entries_model.set_header_data_3a(2, Orientation::Horizontal, &QVariant::from_q_string(&qtr("translation_report_column_issue")));
+        entries_model.set_header_data_3a(3, Orientation::Horizontal, &QVariant::from_q_string(&qtr("translation_report_column_base_text")));
+        entries_tree_view.header().resize_sections(ResizeMode::ResizeToContents);
+
+        let export_button = QPushButton::from_q_string(&qtr("translation_report_export_tsv"));
+        let close_button = QPushButton::from_q_string(&qtr("close"));
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&languages_tree_view, 1, 0, 1, 2);
+        main_grid.add_widget_5a(&entries_tree_view, 2, 0, 1, 2);
+        main_grid.add_widget_5a(&export_button, 3, 0, 1, 1);
+        main_grid.add_widget_5a(&close_button, 3, 1, 1, 1);
+        main_grid.set_row_stretch(2, 3);
+
+        let tsv = report.to_tsv();
+        let dialog_ptr = dialog.as_ptr();
+        let export_slot = SlotNoArgs::new(&dialog, move || {
+            let file_dialog = QFileDialog::from_q_widget_q_string(dialog_ptr, &qtr("translation_report_export_tsv"));
+            file_dialog.set_accept_mode(qt_widgets::q_file_dialog::AcceptMode::AcceptSave);
+            file_dialog.set_name_filter(&QString::from_std_str("TSV Files (*.tsv)"));
+            file_dialog.set_confirm_overwrite(true);
+            file_dialog.set_default_suffix(&QString::from_std_str("tsv"));
+
+            if file_dialog.exec() == 1 {
+                let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                if let Err(error) = std::fs::write(&path, &tsv) {
+                    show_dialog(dialog_ptr, error, false);
+                }
+            }
+        });
+
+        export_button.released().connect(&export_slot);
+        close_button.released().connect(dialog.slot_close());
+
+        dialog.exec();
+    }
+
+    /// This function creates the dialog showing what was done to a Pack when rescuing it.
+    pub unsafe fn rescue_report_dialog(app_ui: &Rc<Self>, report: &PackRescueReport, report_path: &Path) {
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("rescue_report_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(800, 600);
+
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtre("rescue_report_explanation", &[&report_path.to_string_lossy()]), &dialog);
+        explanation_label.set_word_wrap(true);
+
+        let report = report.to_string();
+        let report_text_edit = QTextEdit::from_q_widget(&dialog);
+        report_text_edit.set_read_only(true);
+        report_text_edit.set_plain_text(&QString::from_std_str(&report));
+
+        let copy_button = QPushButton::from_q_string(&qtr("rescue_report_copy"));
+        let close_button = QPushButton::from_q_string(&qtr("close"));
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&report_text_edit, 1, 0, 1, 2);
+        main_grid.add_widget_5a(&copy_button, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&close_button, 2, 1, 1, 1);
+
+        let copy_slot = SlotNoArgs::new(&dialog, move || {
+            QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(&report));
+        });
+
+        copy_button.released().connect(&copy_slot);
+        close_button.released().connect(dialog.slot_close());
+
+        dialog.exec();
+    }
+
+    /// This function creates the dialog showing the changes in the definitions of the tables after a schema update.
+    ///
+    /// By default it only shows the tables in the open Pack.
+    pub unsafe fn schema_diff_dialog(app_ui: &Rc<Self>, diff: &SchemaDiff, table_names: &[String]) {
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("schema_diff_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(800, 600);
+
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let only_open_pack_checkbox = QCheckBox::from_q_string_q_widget(&qtr("schema_diff_only_open_pack"), &dialog);
+        only_open_pack_checkbox.set_checked(true);
+
+        let report_text_browser = QTextBrowser::new_1a(&dialog);
+        let export_button = QPushButton::from_q_string(&qtr("schema_diff_export"));
+        let close_button = QPushButton::from_q_string(&qtr("close"));
+        main_grid.add_widget_5a(&only_open_pack_checkbox, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&report_text_browser, 1, 0, 1, 2);
+        main_grid.add_widget_5a(&export_button, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&close_button, 2, 1, 1, 1);
+
+        let diff = diff.clone();
+        let filtered_diff = diff.filtered(table_names);
+        let markdown = move |only_open_pack: bool| if only_open_pack { filtered_diff.to_markdown() } else { diff.to_markdown() };
+        report_text_browser.set_html(&QString::from_std_str(markdown_to_html(&markdown(true))));
+
+        let report_text_browser_ptr = report_text_browser.as_ptr();
+        let filter_slot = SlotOfBool::new(&dialog, clone!(markdown => move |only_open_pack| {
+            report_text_browser_ptr.set_html(&QString::from_std_str(markdown_to_html(&markdown(only_open_pack))));
+        }));
+
+        let dialog_ptr = dialog.as_ptr();
+        let only_open_pack_checkbox_ptr = only_open_pack_checkbox.as_ptr();
+        let export_slot = SlotNoArgs::new(&dialog, move || {
+            let file_dialog = QFileDialog::from_q_widget_q_string(dialog_ptr, &qtr("schema_diff_export"));
+            file_dialog.set_accept_mode(qt_widgets::q_file_dialog::AcceptMode::AcceptSave);
+            file_dialog.set_name_filter(&QString::from_std_str("Markdown (*.md)"));
+            file_dialog.set_confirm_overwrite(true);
+            file_dialog.set_default_suffix(&QString::from_std_str("md"));
+
+            if file_dialog.exec() == 1 {
+                let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                if let Err(error) = std::fs::write(&path, markdown(only_open_pack_checkbox_ptr.is_checked())) {
+                    show_dialog(dialog_ptr, error, false);
+                }
+            }
+        });
+
+        only_open_pack_checkbox.toggled().connect(&filter_slot);
+        export_button.released().connect(&export_slot);
+        close_button.released().connect(dialog.slot_close());
+
+        dialog.exec();
+    }
+
+    /// This function creates the "Export Schema Patches" dialog, to share the local patches of the selected tables as JSON.
+    ///
+    /// The exported JSON is the same the "Import Schema Patch" dialog accepts.
+    pub unsafe fn export_schema_patches_dialog(app_ui: &Rc<Self>, table_names: &[String]) {
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("export_schema_patches_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(1000, 600);
+
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let tree_view = QTreeView::new_1a(&dialog);
+        let model = QStandardItemModel::new_1a(&tree_view);
+        tree_view.set_model(&model);
+        tree_view.set_root_is_decorated(false);
+        tree_view.set_header_hidden(true);
+
+        for table_name in table_names {
+            let item = QStandardItem::from_q_string(&QString::from_std_str(table_name));
+            item.set_editable(false);
+            item.set_checkable(true);
+            item.set_check_state(CheckState::Checked);
+            model.append_row_q_standard_item(item.into_ptr());
+        }
+
+        let preview_text_edit = QTextEdit::from_q_widget(&dialog);
+        preview_text_edit.set_read_only(true);
+        preview_text_edit.set_line_wrap_mode(qt_widgets::q_text_edit::LineWrapMode::NoWrap);
+
+        let copy_button = QPushButton::from_q_string(&qtr("export_schema_patches_copy"));
+        let save_button = QPushButton::from_q_string(&qtr("export_schema_patches_save"));
+        let close_button = QPushButton::from_q_string(&qtr("close"));
+        main_grid.add_widget_5a(&tree_view, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&preview_text_edit, 0, 1, 1, 2);
+        main_grid.add_widget_5a(&copy_button, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&save_button, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&close_button, 1, 2, 1, 1);
+        main_grid.set_column_stretch(1, 10);
+
+        // The preview is regenerated every time the selection changes. An empty selection means nothing to export.
+        let model_ptr = model.as_ptr();
+        let dialog_ptr = dialog.as_ptr();
+        let preview_text_edit_ptr = preview_text_edit.as_ptr();
+        let copy_button_ptr = copy_button.as_ptr();
+        let save_button_ptr = save_button.as_ptr();
+        let table_names = table_names.to_vec();
+        let update_preview = move || {
+            let selected = table_names.iter()
+                .enumerate()
+                .filter(|(index, _)| model_ptr.item_1a(*index as i32).check_state() == CheckState::Checked)
+                .map(|(_, table_name)| table_name.to_owned())
+                .collect::<Vec<_>>();
+
+            let json = if selected.is_empty() {
+                String::new()
+            } else {
+                let receiver = CENTRAL_COMMAND.send_background(Command::ExportSchemaPatches(selected));
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::String(json) => json,
+                    Response::Error(error) => {
+                        show_dialog(dialog_ptr, error, false);
+                        String::new()
+                    }
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+            };
+
+            preview_text_edit_ptr.set_plain_text(&QString::from_std_str(&json));
+            copy_button_ptr.set_enabled(!json.is_empty());
+            save_button_ptr.set_enabled(!json.is_empty());
+        };
+        update_preview();
+
+        let selection_slot = SlotOfQStandardItem::new(&dialog, move |_| update_preview());
+
+        let copy_slot = SlotNoArgs::new(&dialog, move || {
+            QGuiApplication::clipboard().set_text_1a(&preview_text_edit_ptr.to_plain_text());
+        });
+
+        let save_slot = SlotNoArgs::new(&dialog, move || {
+            let file_dialog = QFileDialog::from_q_widget_q_string(dialog_ptr, &qtr("export_schema_patches_save"));
+            file_dialog.set_accept_mode(qt_widgets::q_file_dialog::AcceptMode::AcceptSave);
+            file_dialog.set_name_filter(&QString::from_std_str("JSON Files (*.json)"));
+            file_dialog.set_confirm_overwrite(true);
+            file_dialog.set_default_suffix(&QString::from_std_str("json"));
+
+            if file_dialog.exec() == 1 {
+                let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                if let Err(error) = std::fs::write(&path, preview_text_edit_ptr.to_plain_text().to_std_string()) {
+                    show_dialog(dialog_ptr, error, false);
+                }
+            }
+        });
+
+        model.item_changed().connect(&selection_slot);
+        copy_button.released().connect(&copy_slot);
+        save_button.released().connect(&save_slot);
+        close_button.released().connect(dialog.slot_close());
+
+        dialog.exec();
+    }
+
+    /// This function creates the "Schema Browser" dialog, a read-only view of the definitions of all the tables in the schema.
+    ///
+    /// Tables can be filtered by their name or the name of any of their fields. The details of a table are only built when it's selected.
+    pub unsafe fn schema_browser_dialog(app_ui: &Rc<Self>, tables: Vec<TableOverview>) {
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("schema_browser_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(1000, 600);
+
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let filter_line_edit = QLineEdit::from_q_widget(&dialog);
+        filter_line_edit.set_placeholder_text(&qtr("schema_browser_filter"));
+        filter_line_edit.set_clear_button_enabled(true);
+
+        let tree_view = QTreeView::new_1a(&dialog);
+        let model = QStandardItemModel::new_1a(&tree_view);
+        tree_view.set_model(&model);
+        tree_view.set_root_is_decorated(false);
+        tree_view.set_header_hidden(true);
+
+        for table in &tables {
+            let item = QStandardItem::from_q_string(&QString::from_std_str(table.table_name()));
+            item.set_editable(false);
+            model.append_row_q_standard_item(item.into_ptr());
+        }
+
+        let details_text_browser = QTextBrowser::new_1a(&dialog);
+        let close_button = QPushButton::from_q_string(&qtr("close"));
+        main_grid.add_widget_5a(&filter_line_edit, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&tree_view, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&details_text_browser, 0, 1, 2, 1);
+        main_grid.add_widget_5a(&close_button, 2, 1, 1, 1);
+        main_grid.set_column_stretch(1, 10);
+
+        let tables = Rc::new(tables);
+        let tree_view_ptr = tree_view.as_ptr();
+        let filter_slot = SlotOfQString::new(&dialog, clone!(tables => move |text| {
+            let text = text.to_std_string();
+            for (row, table) in tables.iter().enumerate() {
+                tree_view_ptr.set_row_hidden(row as i32, &QModelIndex::new(), !table.matches(&text));
+            }
+        }));
+
+        let details_text_browser_ptr = details_text_browser.as_ptr();
+        let details_slot = SlotOfQModelIndexQModelIndex::new(&dialog, clone!(tables => move |current, _| {
+            let html = match tables.get(current.row() as usize) {
+                Some(table) if current.is_valid() => {
+                    let escape = |text: &str| QString::from_std_str(text).to_html_escaped().to_std_string();
+                    let versions = table.versions().iter().map(|version| version.to_string()).collect::<Vec<_>>().join(", ");
+                    let mut html = format!("<h2>{}</h2><p>{}</p><table border=\"1\" cellpadding=\"4\" cellspacing=\"0\"><tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+                        escape(table.table_name()),
+                        tre("schema_browser_versions", &[&versions]),
+                        tr("schema_browser_field"),
+                        tr("schema_browser_type"),
+                        tr("schema_browser_key"),
+                        tr("schema_browser_reference"),
+                        tr("schema_browser_description"),
+                    );
+
+                    for field in table.fields() {
+                        let reference = field.reference().as_ref().map(|(table, column)| format!("{}/{}", table, column)).unwrap_or_default();
+                        html.push_str(&format!("<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                            escape(field.name()),
+                            field.field_type(),
+                            if *field.is_key() { "✓" } else { "" },
+                            escape(&reference),
+                            escape(field.description()).replace('\n', "<br/>"),
+                        ));
+                    }
+
+                    html.push_str("</table>");
+                    html
+                }
+                _ => String::new(),
+            };
+
+            details_text_browser_ptr.set_html(&QString::from_std_str(html));
+        }));
+
+        filter_line_edit.text_changed().connect(&filter_slot);
+        tree_view.selection_model().current_changed().connect(&details_slot);
+        close_button.released().connect(dialog.slot_close());
+
+        dialog.exec();
+    }
+
+    /// This function creates the entire "Restore Autosave" dialog. It returns the path of the autosave to restore, or None if it was cancelled.
+    pub unsafe fn restore_autosave_dialog(app_ui: &Rc<Self>, autosaves: &[PathBuf]) -> Option<PathBuf> {
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("packfile_restore_autosave"));
+        dialog.set_modal(true);
+
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtr("restore_autosave_explanation"), &dialog);
+        explanation_label.set_word_wrap(true);
+
+        // Autosaves are named after their date, so we just need to show their names.
+        let autosaves_combobox = QComboBox::new_1a(&dialog);
+        for autosave in autosaves {
+            let name = autosave.file_stem().map(|stem| stem.to_string_lossy().replace('T', " ")).unwrap_or_default();
+            autosaves_combobox.add_item_q_string(&QString::from_std_str(name));
+        }
+
+        let accept_button = QPushButton::from_q_string(&qtr("gen_loc_accept"));
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&autosaves_combobox, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 2, 0, 1, 1);
+
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 {
+            let index = autosaves_combobox.current_index();
+            if index >= 0 { autosaves.get(index as usize).cloned() }
+            else { None }
+        } else { None }
+    }
+
+    /// This function creates the "Load CA PackFiles..." dialog. It returns the paths of the CA Packs to load, or None if it was cancelled.
+    ///
+    /// The selection is remembered per game, so next time the dialog opens with the same Packs checked.
+    pub unsafe fn load_ca_packs_filtered_dialog(app_ui: &Rc<Self>) -> Option<Vec<PathBuf>> {
+        let game_key = read_game_selected().game_key_name();
+        let ca_packs = match read_game_selected().ca_packs_paths(&setting_path(&game_key)) {
+            Ok(ca_packs) => ca_packs,
+            Err(error) => {
+                show_dialog(&app_ui.main_window, error, false);
+                return None;
+            }
+        };
+
+        // The selection is stored as a list of Pack names. If we have none stored, we check everything.
+        let setting_key = format!("ca_packs_selection_{game_key}");
+        let selection = setting_string(&setting_key);
+        let selection = selection.split(CA_PACKS_SELECTION_SEPARATOR).filter(|name| !name.is_empty()).collect::<Vec<_>>();
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("load_ca_packfiles_filtered_title"));
+        dialog.set_modal(true);
+
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtr("load_ca_packfiles_filtered_explanation"), &dialog);
+        explanation_label.set_word_wrap(true);
+
+        let tree_view = QTreeView::new_1a(&dialog);
+        let model = QStandardItemModel::new_1a(&tree_view);
+        tree_view.set_model(&model);
+        tree_view.set_root_is_decorated(false);
+        tree_view.set_header_hidden(true);
+
+        for path in &ca_packs {
+            let name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            let item = QStandardItem::from_q_string(&QString::from_std_str(&name));
+            item.set_editable(false);
+            item.set_checkable(true);
+            item.set_check_state(if selection.is_empty() || selection.contains(&name.as_str()) { CheckState::Checked } else { CheckState::Unchecked });
+            model.append_row_q_standard_item(item.into_ptr());
+        }
+
+        let accept_button = QPushButton::from_q_string(&qtr("gen_loc_accept"));
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&tree_view, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 2, 0, 1, 1);
+
+        accept_button.released().connect(dialog.slot_accept());
+        dialog.resize_2a(400, 600);
+
+        if dialog.exec() == 1 {
+            let paths = ca_packs.iter()
+                .enumerate()
+                .filter(|(index, _)| model.item_1a(*index as i32).check_state() == CheckState::Checked)
+                .map(|(_, path)| path.to_owned())
+                .collect::<Vec<_>>();
+
+            if paths.is_empty() {
+                return None;
+            }
+
+            let names = paths.iter()
+                .filter_map(|path| path.file_name().map(|name| name.to_string_lossy().to_string()))
+                .collect::<Vec<_>>();
+            set_setting_string(&setting_key, &names.join(CA_PACKS_SELECTION_SEPARATOR));
+
+            Some(paths)
+        } else { None }
+    }
+
+    /// This function creates the "Dependencies Editor" dialog, to edit the list of Packs the open Pack depends on.
+    ///
+    /// Each dependency shows if we found it in the data or MyMod folders. Accepting the dialog saves the list and marks the Pack as modified.
+    pub unsafe fn dependencies_editor_dialog(app_ui: &Rc<Self>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
+
+        // If the Dependency Manager is open, save it and close it, so it doesn't overwrite our changes later.
+        if let Err(error) = Self::purge_that_one_specifically(app_ui, pack_file_contents_ui, RESERVED_NAME_DEPENDENCIES_MANAGER, DataSource::PackFile, true) {
+            return show_dialog(&app_ui.main_window, error, false);
+        }
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetDependencyPackFilesList);
+        let response = CentralCommand::recv(&receiver);
+        let dependencies = if let Response::VecStringOptionPathBuf(data) = response { data } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response); };
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("dependencies_editor_title"));
+        dialog.set_modal(true);
+
+        // Create the main Grid.
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtr("dependencies_editor_explanation"), &dialog);
+        explanation_label.set_word_wrap(true);
+
+        let tree_view = QTreeView::new_1a(&dialog);
+        let model = QStandardItemModel::new_1a(&tree_view);
+        tree_view.set_model(&model);
+        tree_view.set_root_is_decorated(false);
+        model.set_column_count(2);
+
+        for (name, path) in &dependencies {
+            model.append_row_q_list_of_q_standard_item(Self::dependencies_editor_row(name, path).as_ref());
+        }
+
+        model.set_header_data_3a(0, Orientation::Horizontal, &QVariant::from_q_string(&qtr("dependencies_editor_column_pack")));
+        model.set_header_data_3a(1, Orientation::Horizontal, &QVariant::from_q_string(&qtr("dependencies_editor_column_location")));
+        tree_view.header().resize_sections(ResizeMode::ResizeToContents);
+
+        let move_up_button = QPushButton::from_q_string_q_widget(&qtr("dependencies_editor_move_up"), &dialog);
+        let move_down_button = QPushButton::from_q_string_q_widget(&qtr("dependencies_editor_move_down"), &dialog);
+        let remove_button = QPushButton::from_q_string_q_widget(&qtr("dependencies_editor_remove"), &dialog);
+
+        // The picker lists the Packs in the data folder, but it's editable so Packs from elsewhere can be added by name.
+        let packs_combobox = QComboBox::new_1a(&dialog);
+        packs_combobox.set_editable(true);
+        let game_selected = read_game_selected();
+        if let Some(paths) = game_selected.data_packs_paths(&setting_path(&game_selected.game_key_name())) {
+            for path in &paths {
+                if let Some(name) = path.file_name() {
+                    packs_combobox.add_item_q_string(&QString::from_std_str(name.to_string_lossy()));
+                }
+            }
+        }
+        drop(game_selected);
+
+        packs_combobox.set_current_index(-1);
+        packs_combobox.line_edit().set_placeholder_text(&qtr("dependencies_editor_add_placeholder"));
+        let add_button = QPushButton::from_q_string_q_widget(&qtr("dependencies_editor_add"), &dialog);
+        let accept_button = QPushButton::from_q_string_q_widget(&qtr("dependencies_editor_save"), &dialog);
+
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&tree_view, 1, 0, 4, 1);
+        main_grid.add_widget_5a(&move_up_button, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&move_down_button, 2, 1, 1, 1);
+        main_grid.add_widget_5a(&remove_button, 3, 1, 1, 1);
+        main_grid.add_widget_5a(&packs_combobox, 5, 0, 1, 1);
+        main_grid.add_widget_5a(&add_button, 5, 1, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 6, 0, 1, 2);
+        main_grid.set_row_stretch(4, 10);
+
+        let tree_view_ptr = tree_view.as_ptr();
+        let model_ptr = model.as_ptr();
+        let packs_combobox_ptr = packs_combobox.as_ptr();
+
+        // What happens when we add a Pack. Duplicates are ignored.
+        let slot_add = SlotNoArgs::new(&dialog, move || {
+            let name = packs_combobox_ptr.current_text().to_std_string().trim().to_owned();
+            if name.is_empty() || (0..model_ptr.row_count_0a()).any(|row| model_ptr.item_1a(row).text().to_std_string() == name) {
+                return;
+            }
+
+            model_ptr.append_row_q_list_of_q_standard_item(Self::dependencies_editor_row(&name, &dependency_pack_path(&name)).as_ref());
+            tree_view_ptr.set_current_index(&model_ptr.index_2a(model_ptr.row_count_0a() - 1, 0));
+            packs_combobox_ptr.set_current_index(-1);
+        });
+
+        let slot_remove = SlotNoArgs::new(&dialog, move || {
+            let row = tree_view_ptr.current_index().row();
+            if row >= 0 {
+                model_ptr.remove_row_1a(row);
+            }
+        });
+
+        let slot_move_up = SlotNoArgs::new(&dialog, move || {
+            let row = tree_view_ptr.current_index().row();
+            if row > 0 {
+                let items = model_ptr.take_row(row);
+                model_ptr.insert_row_int_q_list_of_q_standard_item(row - 1, &items);
+                tree_view_ptr.set_current_index(&model_ptr.index_2a(row - 1, 0));
+            }
+        });
+
+        let slot_move_down = SlotNoArgs::new(&dialog, move || {
+            let row = tree_view_ptr.current_index().row();
+            if row >= 0 && row < model_ptr.row_count_0a() - 1 {
+                let items = model_ptr.take_row(row);
+                model_ptr.insert_row_int_q_list_of_q_standard_item(row + 1, &items);
+                tree_view_ptr.set_current_index(&model_ptr.index_2a(row + 1, 0));
+            }
+        });
+
+        add_button.released().connect(&slot_add);
+        packs_combobox.line_edit().return_pressed().connect(&slot_add);
+        remove_button.released().connect(&slot_remove);
+        move_up_button.released().connect(&slot_move_up);
+        move_down_button.released().connect(&slot_move_down);
+        accept_button.released().connect(dialog.slot_accept());
+        dialog.resize_2a(700, 500);
+
+        if dialog.exec() == 1 {
+            let entries = (0..model.row_count_0a())
+                .map(|row| model.item_1a(row).text().to_std_string())
+                .collect::<Vec<_>>();
+
+            if entries.iter().ne(dependencies.iter().map(|(name, _)| name)) {
+                let _ = CENTRAL_COMMAND.send_background(Command::SetDependencyPackFilesList(entries));
+
+                // The dependencies are not a file, so we have to mark the Pack as modified manually.
+                pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::MarkAlwaysModified(vec![ContainerPath::Folder(String::new())]), DataSource::PackFile);
+                UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+            }
+        }
+    }
+
+    /// This function builds the row of a dependency for the "Dependencies Editor" dialog, marking if we found the Pack or not.
+    unsafe fn dependencies_editor_row(name: &str, path: &Option<PathBuf>) -> CppBox<QListOfQStandardItem> {
+        let qlist = QListOfQStandardItem::new();
+        let name_item = QStandardItem::from_q_string(&QString::from_std_str(name));
+        let location_item = match path {
+            Some(path) => {
+                name_item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("dialog-ok")));
+                QStandardItem::from_q_string(&QString::from_std_str(path.to_string_lossy()))
+            }
+            None => {
+                name_item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("dialog-warning")));
+                QStandardItem::from_q_string(&qtr("dependencies_editor_not_found"))
+            }
+        };
+
+        name_item.set_editable(false);
+        location_item.set_editable(false);
+        qlist.append_q_standard_item(&name_item.into_ptr().as_mut_raw_ptr());
+        qlist.append_q_standard_item(&location_item.into_ptr().as_mut_raw_ptr());
+        qlist
+    }
+
+    /// This function creates the "Compare Packs" dialog, showing the results of comparing the open Pack against another one.
+    ///
+    /// The dialog is not modal, so the files in it can be opened by double-clicking them while the dialog stays open.
+    pub unsafe fn compare_packs_dialog(
+        app_ui: &Rc<Self>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        global_search_ui: &Rc<GlobalSearchUI>,
+        diagnostics_ui: &Rc<DiagnosticsUI>,
+        dependencies_ui: &Rc<DependenciesUI>,
+        references_ui: &Rc<ReferencesUI>,
+        diff: &PackDiff,
+    ) {
+
+        let dialog = QDialog::new_1a(&app_ui.main_window);
+        dialog.set_window_title(&qtr("compare_packs_title"));
+        dialog.set_attribute_1a(WidgetAttribute::WADeleteOnClose);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let tab_widget = QTabWidget::new_1a(&dialog);
+        main_grid.add_widget_5a(&tab_widget, 0, 0, 1, 1);
+
+        let tabs = [
+            ("compare_packs_only_in_open", diff.only_in_self(), vec![DataSource::PackFile]),
+            ("compare_packs_only_in_other", diff.only_in_other(), vec![DataSource::ComparedPackFile]),
+            ("compare_packs_different", diff.different(), vec![DataSource::PackFile, DataSource::ComparedPackFile]),
+        ];
+
+        for (title, paths, data_sources) in tabs {
+            let tree_view = QTreeView::new_1a(&tab_widget);
+            let model = QStandardItemModel::new_1a(&tree_view);
+            tree_view.set_model(&model);
+            tree_view.set_root_is_decorated(false);
+            tree_view.set_sorting_enabled(true);
+
+            for path in paths {
+                let qlist = QListOfQStandardItem::new();
+                let path_item = QStandardItem::from_q_string(&QString::from_std_str(path.path_raw()));
+                path_item.set_editable(false);
+                qlist.append_q_standard_item(&path_item.into_ptr().as_mut_raw_ptr());
+
+                // Tables also get a summary of what rows changed between both Packs.
+                if let Some(table_diff) = diff.table_diffs().get(path.path_raw()) {
+                    for count in [table_diff.added(), table_diff.removed(), table_diff.changed(), table_diff.duplicated_keys()] {
+                        let item = QStandardItem::from_q_string(&QString::from_std_str(count.to_string()));
+                        item.set_editable(false);
+                        qlist.append_q_standard_item(&item.into_ptr().as_mut_raw_ptr());
+                    }
+                }
+
+                model.append_row_q_list_of_q_standard_item(qlist.as_ref());
+            }
+
+            model.set_header_data_3a(0, Orientation::Horizontal, &QVariant::from_q_string(&qtr("compare_packs_column_path")));
+            if data_sources.len() > 1 {
+                model.set_header_data_3a(1, Orientation::Horizontal, &QVariant::from_q_string(&qtr("compare_packs_column_added")));
+                model.set_header_data_3a(2, Orientation::Horizontal, &QVariant::from_q_string(&qtr("compare_packs_column_removed")));
+                model.set_header_data_3a(3, Orientation::Horizontal, &QVariant::from_q_string(&qtr("compare_packs_column_changed")));
+                model.set_header_data_3a(4, Orientation::Horizontal, &QVariant::from_q_string(&qtr("compare_packs_column_duplicated_keys")));
+            }
+
+            tree_view.header().resize_sections(ResizeMode::ResizeToContents);
+
+            // Double-clicking a file opens it from every side of the comparison it's in.
+            let open_file = SlotOfQModelIndex::new(&tree_view, clone!(
+                app_ui,
+                pack_file_contents_ui,
+                global_search_ui,
+                diagnostics_ui,
+                dependencies_ui,
+                references_ui => move |model_index| {
+                    let path = model_index.sibling_at_column(0).data_0a().to_string().to_std_string();
+                    for data_source in &data_sources {
+                        AppUI::open_packedfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui, Some(path.to_owned()), false, false, *data_source);
+                    }
+                }
+            ));
+            tree_view.double_clicked().connect(&open_file);
+
+            tab_widget.add_tab_2a(&tree_view, &QString::from_std_str(format!("{} ({})", tr(title), paths.len())));
+        }
+
+        dialog.resize_2a(800, 600);
+        dialog.show();
+    }
+
+    /// Update the PackedFileView names, to ensure we have no collisions.
+    pub unsafe fn update_views_names(&self) {
+
+        // We also have to check for colliding packedfile names, so we can use their full path instead.
+        let mut names = HashMap::new();
+        let open_packedfiles = UI_STATE.get_open_packedfiles();
+        for packed_file_view in open_packedfiles.iter() {
+            let widget = packed_file_view.get_mut_widget();
+            if self.tab_bar_packed_file.index_of(widget) != -1 {
+
+                // Reserved PackedFiles should have special names.
+                let path = packed_file_view.get_ref_path();
+                let path_split = path.split('/').collect::<Vec<_>>();
+                if *path == RESERVED_NAME_NOTES {
+                    names.insert("Notes".to_owned(), 1);
+                } else if let Some(name) = path_split.last() {
+                    match names.get_mut(*name) {
+                        Some(name) => *name += 1,
+                        None => { names.insert(name.to_string(), 1); },
+                    }
+                }
+            }
+        }
+
+        for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
+            let widget = packed_file_view.get_mut_widget();
+            let path = packed_file_view.get_ref_path();
+            let path_split = path.split('/').collect::<Vec<_>>();
+            let widget_name = if *path == RESERVED_NAME_NOTES {
+                "Notes".to_owned()
+            } else if let Some(widget_name) = path_split.last() {
+                widget_name.to_string()
+            } else {
+                "".to_owned()
+            };
+
+            if let Some(count) = names.get(&widget_name) {
+                let mut name = String::new();
+                match packed_file_view.get_data_source() {
+                    DataSource::PackFile => {},
+                    DataSource::ParentFiles => name.push_str("Parent"),
+                    DataSource::GameFiles => name.push_str("Game"),
+                    DataSource::AssKitFiles => name.push_str("AssKit"),
+                    DataSource::ExternalFile => name.push_str("External"),
+                    DataSource::ComparedPackFile => name.push_str("Compared"),
+                }
+
+                if !name.is_empty() {
+                    if packed_file_view.get_is_read_only() {
+                        name.push_str("-RO:");
+                    } else  {
+                        name.push(':');
+                    }
+                }
+
+                if count > &1 {
+                    name.push_str(&path);
+                } else {
+                    name.push_str(&widget_name.to_owned());
+                };
+
+                if packed_file_view.get_is_preview() {
+                    name.push_str(" (Preview)");
+                }
+
+                let index = self.tab_bar_packed_file.index_of(widget);
+                self.tab_bar_packed_file.set_tab_text(index, &QString::from_std_str(&name));
+            }
+        }
+    }
+
+    /// This function hides all the provided packedfile views.
+    pub unsafe fn packed_file_view_hide(
+        app_ui: &Rc<AppUI>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        indexes: &[i32]
+    ) {
+
+        let mut indexes = indexes.to_vec();
+        indexes.sort_unstable();
+        indexes.dedup();
+        indexes.reverse();
+
+        // PackFile and Decoder Views must be deleted on close, so get them apart if we find one.
+        let mut purge_on_delete = vec![];
+
+        for packed_file_view in UI_STATE.get_open_packedfiles().iter() {
+            let widget = packed_file_view.get_mut_widget();
+            let index_widget = app_ui.tab_bar_packed_file.index_of(widget);
+            if indexes.contains(&index_widget) {
+                let path = packed_file_view.get_ref_path();
+                if !path.is_empty() {
+                    if path.starts_with(RESERVED_NAME_EXTRA_PACKFILE) {
+                        purge_on_delete.push(path.to_owned());
+
+                        // Don't free the Pack if the side pane is still using it.
+                        let path_split = path.split('/').collect::<Vec<_>>();
+                        let path = PathBuf::from(path_split[1..].join("/"));
+                        let in_side_pane = pack_file_contents_ui.extra_pack_pane().read().unwrap().as_ref().map(|view| *view.pack_file_path().read().unwrap() == path).unwrap_or(false);
+                        if !in_side_pane {
+                            let _ = CENTRAL_COMMAND.send_background(Command::RemovePackFileExtra(path));
+                        }
+                    }
+                    else if path.ends_with(DECODER_EXTENSION) {
+                        purge_on_delete.push(path.to_owned());
+                    }
+                }
+            }
+        }
+
+        indexes.iter().for_each(|x| app_ui.tab_bar_packed_file.remove_tab(*x));
+
+        // This is for cleaning up open PackFiles.
+        purge_on_delete.iter().for_each(|x| { let _ = Self::purge_that_one_specifically(app_ui, pack_file_contents_ui, x, DataSource::ExternalFile, false); });
+
+        // And this is for cleaning decoders.
+        purge_on_delete.iter().for_each(|x| { let _ = Self::purge_that_one_specifically(app_ui, pack_file_contents_ui, x, DataSource::PackFile, false); });
+
+        // Update the background icon.
+        GameSelectedIcons::set_game_selected_icon(app_ui);
+    }
 
     /// Function to change the game selected, changing schemas, dependencies, and all related stuff as needed.
     pub unsafe fn change_game_selected(
@@ -3538,7 +5416,7 @@ impl AppUI {
         let _ = AppUI::purge_them_all(app_ui, pack_file_contents_ui, true);
 
         // If the game changed or we're initializing the program, change the game selected.
-        if new_game_selected != GAME_SELECTED.read().unwrap().game_key_name() || !FIRST_GAME_CHANGE_DONE.load(Ordering::SeqCst) {
+        if new_game_selected != read_game_selected().game_key_name() || !FIRST_GAME_CHANGE_DONE.load(Ordering::SeqCst) {
 
             // Disable the main window if it's not yet disabled so we can avoid certain issues.
             app_ui.toggle_main_window(false);
@@ -3584,30 +5462,8 @@ impl AppUI {
                 CENTRAL_COMMAND.send_background(Command::RebuildDependencies(true))
             };
 
-            let response = CENTRAL_COMMAND.recv_try(&receiver);
-            match response {
-                Response::DependenciesInfo(response) => {
-                    let mut parent_build_data = BuildData::new();
-                    parent_build_data.data = Some((ContainerInfo::default(), response.parent_packed_files().to_vec()));
-                    dependencies_ui.dependencies_tree_view().update_treeview(true, TreeViewOperation::Build(parent_build_data), DataSource::ParentFiles);
-
-                    // While the backend returns the data of the entire dependencies, game and asskit data only change on game change, so we don't need to
-                    // rebuild them the game didn't change.
-                    if game_changed {
-
-                        // NOTE: We're MOVING, not copying nor referencing the RFileInfo. This info is big and moving it makes it faster.
-                        let mut game_build_data = BuildData::new();
-                        game_build_data.data = Some((ContainerInfo::default(), response.vanilla_packed_files));
-
-                        let mut asskit_build_data = BuildData::new();
-                        asskit_build_data.data = Some((ContainerInfo::default(), response.asskit_tables));
-                        dependencies_ui.dependencies_tree_view().update_treeview(true, TreeViewOperation::Build(game_build_data), DataSource::GameFiles);
-                        dependencies_ui.dependencies_tree_view().update_treeview(true, TreeViewOperation::Build(asskit_build_data), DataSource::AssKitFiles);
-                    }
-                }
-                Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
-                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
-            }
+            // Don't wait for the dependencies here. They're finished in the background while the UI is usable.
+            Self::wait_for_dependencies(app_ui, dependencies_ui, receiver, game_changed);
         }
 
         // Reenable the main window once everything is reloaded, regardless of if we disabled it here or not.
@@ -3627,6 +5483,215 @@ impl AppUI {
         }
     }
 
+    /// This function marks the dependencies as pending until the provided receiver gets the result of their rebuild.
+    ///
+    /// While pending, the actions and views that need the dependencies are disabled. The result is checked periodically
+    /// by [AppUI::check_pending_dependencies], so the UI can be used in the meantime.
+    pub unsafe fn wait_for_dependencies(app_ui: &Rc<Self>, dependencies_ui: &Rc<DependenciesUI>, receiver: Receiver<Response>, game_changed: bool) {
+
+        // If another rebuild was pending, this one replaces it. Keep its game change, or we'll not rebuild the vanilla trees.
+        let game_changed = game_changed || UI_STATE.get_pending_dependencies().map(|(_, game_changed)| game_changed).unwrap_or(false);
+        UI_STATE.set_pending_dependencies(Some((receiver, game_changed)));
+
+        dependencies_ui.dependencies_tree_view().set_enabled(false);
+        dependencies_ui.dependencies_tree_view().set_tool_tip(&qtr("dependencies_pending"));
+        Self::update_dependencies_pending_actions(app_ui);
+        log_to_status_bar(&tr("dependencies_pending"));
+
+        app_ui.timer_pending_dependencies.start_0a();
+    }
+
+    /// This function handles the open Pack being changed on disk by another program.
+    ///
+    /// If the Pack has no unsaved changes, the user is asked if it should be reloaded. Otherwise, we just show a warning banner,
+    /// so the user can decide what to do without being interrupted.
+    pub unsafe fn pack_changed_on_disk(
+        app_ui: &Rc<Self>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        global_search_ui: &Rc<GlobalSearchUI>,
+        diagnostics_ui: &Rc<DiagnosticsUI>,
+        path: &Path,
+    ) {
+
+        // Ignore changes to Packs we closed, or Packs deleted from disk without a replacement.
+        if !setting_bool("watch_open_pack") || !path.is_file() {
+            return;
+        }
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetPackFilePath);
+        let response = CentralCommand::recv(&receiver);
+        let open_path = if let Response::PathBuf(open_path) = response { open_path } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) };
+        if open_path != path {
+            return;
+        }
+
+        if UI_STATE.get_is_modified() {
+            app_ui.pack_changed_on_disk_banner.show();
+        } else {
+            let dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+                q_message_box::Icon::Question,
+                &qtr("rpfm_title"),
+                &qtre("pack_changed_on_disk", &[&path.to_string_lossy()]),
+                q_message_box::StandardButton::Yes | q_message_box::StandardButton::No,
+                &app_ui.main_window,
+            );
+
+            if dialog.exec() == q_message_box::StandardButton::Yes.to_int() {
+                Self::reload_packfile(app_ui, pack_file_contents_ui, global_search_ui, diagnostics_ui);
+            }
+        }
+    }
+
+    /// This function reloads the open Pack from disk, through the same path used to open it, discarding any unsaved change.
+    pub unsafe fn reload_packfile(
+        app_ui: &Rc<Self>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        global_search_ui: &Rc<GlobalSearchUI>,
+        diagnostics_ui: &Rc<DiagnosticsUI>,
+    ) {
+        app_ui.pack_changed_on_disk_banner.hide();
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetPackFilePath);
+        let response = CentralCommand::recv(&receiver);
+        let path = if let Response::PathBuf(path) = response { path } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) };
+
+        // Keep the MyMod mode if we're reloading a MyMod.
+        let game_folder = match UI_STATE.get_operational_mode() {
+            OperationalMode::MyMod(game_folder, _) => game_folder,
+            OperationalMode::Normal => String::new(),
+        };
+
+        if let Err(error) = Self::open_packfile(app_ui, pack_file_contents_ui, global_search_ui, &[path], &game_folder) {
+            return Self::show_error_dialog(app_ui, &error);
+        }
+
+        if setting_bool("diagnostics_trigger_on_open") {
+            DiagnosticsUI::check_when_ready(app_ui, diagnostics_ui);
+        }
+    }
+
+    /// This function checks if the pending dependencies finished rebuilding and, if they did, updates the UI with them.
+    ///
+    /// Diagnostics checks queued while the dependencies were pending are triggered here.
+    pub unsafe fn check_pending_dependencies(
+        app_ui: &Rc<Self>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        diagnostics_ui: &Rc<DiagnosticsUI>,
+        dependencies_ui: &Rc<DependenciesUI>,
+    ) {
+        let (receiver, game_changed) = match UI_STATE.get_pending_dependencies() {
+            Some(pending_dependencies) => pending_dependencies,
+            None => return app_ui.timer_pending_dependencies.stop(),
+        };
+
+        let response = match receiver.try_recv() {
+            Ok(response) => response,
+            Err(error) => if error.is_disconnected() {
+                panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, error)
+            } else {
+                return
+            }
+        };
+
+        app_ui.timer_pending_dependencies.stop();
+        UI_STATE.set_pending_dependencies(None);
+
+        match response {
+            Response::DependenciesInfo(response) => {
+                let mut parent_build_data = BuildData::new();
+                parent_build_data.data = Some((ContainerInfo::default(), response.parent_packed_files().to_vec()));
+                dependencies_ui.dependencies_tree_view().update_treeview(true, TreeViewOperation::Build(parent_build_data), DataSource::ParentFiles);
+
+                // While the backend returns the data of the entire dependencies, game and asskit data only change on game change, so we don't need to
+                // rebuild them the game didn't change.
+                if game_changed {
+
+                    // NOTE: We're MOVING, not copying nor referencing the RFileInfo. This info is big and moving it makes it faster.
+                    let mut game_build_data = BuildData::new();
+                    game_build_data.data = Some((ContainerInfo::default(), response.vanilla_packed_files));
+
+                    let mut asskit_build_data = BuildData::new();
+                    asskit_build_data.data = Some((ContainerInfo::default(), response.asskit_tables));
+                    dependencies_ui.dependencies_tree_view().update_treeview(true, TreeViewOperation::Build(game_build_data), DataSource::GameFiles);
+                    dependencies_ui.dependencies_tree_view().update_treeview(true, TreeViewOperation::Build(asskit_build_data), DataSource::AssKitFiles);
+                }
+            }
+            Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+            Response::ErrorKind(kind, message) => Self::show_error_kind_dialog(app_ui, kind, &message),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        dependencies_ui.dependencies_tree_view().set_enabled(true);
+        dependencies_ui.dependencies_tree_view().set_tool_tip(&QString::new());
+
+        // Restore the actions that were waiting for the dependencies.
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetPackFilePath);
+        let response = CentralCommand::recv(&receiver);
+        let pack_path = if let Response::PathBuf(pack_path) = response { pack_path } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) };
+        Self::enable_packfile_actions(app_ui, &pack_path, pack_file_contents_ui.packfile_contents_tree_model().row_count_0a() != 0);
+        log_to_status_bar(&tr("dependencies_ready"));
+
+        if UI_STATE.take_diagnostics_queued() {
+            DiagnosticsUI::check(app_ui, diagnostics_ui);
+        }
+    }
+
+    /// This function disables the actions that need the dependencies while they're being rebuilt, explaining why in their tooltip.
+    ///
+    /// It only disables them. Once the dependencies are ready, [AppUI::enable_packfile_actions] restores them.
+    pub unsafe fn update_dependencies_pending_actions(app_ui: &Rc<Self>) {
+        let pending = UI_STATE.get_dependencies_pending();
+        let tooltip = if pending { qtr("dependencies_pending") } else { QString::new() };
+        let actions = [
+            &app_ui.special_stuff_wh3_optimize_packfile,
+            &app_ui.special_stuff_troy_optimize_packfile,
+            &app_ui.special_stuff_three_k_optimize_packfile,
+            &app_ui.special_stuff_wh2_optimize_packfile,
+            &app_ui.special_stuff_wh_optimize_packfile,
+            &app_ui.special_stuff_tob_optimize_packfile,
+            &app_ui.special_stuff_att_optimize_packfile,
+            &app_ui.special_stuff_rom2_optimize_packfile,
+            &app_ui.special_stuff_sho2_optimize_packfile,
+            &app_ui.special_stuff_nap_optimize_packfile,
+            &app_ui.special_stuff_emp_optimize_packfile,
+        ];
+
+        for action in actions {
+            if pending {
+                action.set_enabled(false);
+            }
+            action.set_tool_tip(&tooltip);
+        }
+    }
+
+    /// This function enables or disables the header flags of the `Change PackFile Type` menu, depending on what is valid
+    /// for the version and type of the open PackFile in the `Game Selected`. Disabled flags get a tooltip explaining why.
+    pub unsafe fn update_pfh_flags_actions(app_ui: &Rc<Self>) {
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetPFHFlagsRestrictions);
+        let response = CentralCommand::recv(&receiver);
+        let restrictions = if let Response::VecPFHFlagsString(restrictions) = response { restrictions } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) };
+
+        let flag_actions = [
+            (PFHFlags::HAS_EXTENDED_HEADER, &app_ui.change_packfile_type_header_is_extended),
+            (PFHFlags::HAS_INDEX_WITH_TIMESTAMPS, &app_ui.change_packfile_type_index_includes_timestamp),
+            (PFHFlags::HAS_ENCRYPTED_INDEX, &app_ui.change_packfile_type_index_is_encrypted),
+            (PFHFlags::HAS_ENCRYPTED_DATA, &app_ui.change_packfile_type_data_is_encrypted),
+        ];
+
+        for (flag, action) in flag_actions {
+            match restrictions.iter().find(|(restricted_flag, _)| *restricted_flag == flag) {
+                Some((_, reason)) => {
+                    action.set_enabled(false);
+                    action.set_tool_tip(&QString::from_std_str(reason));
+                }
+                None => {
+                    action.set_enabled(true);
+                    action.set_tool_tip(&QString::new());
+                }
+            }
+        }
+    }
+
     /// This function creates a new PackFile and setups the UI for it.
     pub unsafe fn new_packfile(
         app_ui: &Rc<Self>,
@@ -3653,6 +5718,7 @@ impl AppUI {
         }
 
         // Close any open PackedFile and clear the global search panel.
+        Self::save_tab_session(app_ui);
         let _ = AppUI::purge_them_all(app_ui,  pack_file_contents_ui, false);
         GlobalSearchUI::clear(global_search_ui);
         diagnostics_ui.diagnostics_table_model().clear();
@@ -3683,16 +5749,7 @@ impl AppUI {
 
         // Force a dependency rebuild.
         let receiver = CENTRAL_COMMAND.send_background(Command::RebuildDependencies(true));
-        let response = CENTRAL_COMMAND.recv_try(&receiver);
-        match response {
-            Response::DependenciesInfo(response) => {
-                let mut parent_build_data = BuildData::new();
-                parent_build_data.data = Some((ContainerInfo::default(), response.parent_packed_files().to_vec()));
-                dependencies_ui.dependencies_tree_view().update_treeview(true, TreeViewOperation::Build(parent_build_data), DataSource::ParentFiles);
-            }
-            Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
-            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
-        }
+        Self::wait_for_dependencies(app_ui, dependencies_ui, receiver, false);
 
         // Re-enable the Main Window.
         if !window_was_disabled {
@@ -3735,13 +5792,6 @@ impl AppUI {
                         }
                     };
 
-                    // Check if the files are in the Assets Folder. All are in the same folder, so we can just check the first one.
-                    let mut paths_packedfile: Vec<ContainerPath> = vec![];
-                    for path in &paths {
-                        let filtered_path = path.strip_prefix(&assets_folder).unwrap();
-                        paths_packedfile.push(ContainerPath::File(filtered_path.to_string_lossy().to_string()));
-                    }
-
                     let receiver = CENTRAL_COMMAND.send_background(Command::GetPackSettings);
                     let response = CentralCommand::recv(&receiver);
                     let settings = match response {
@@ -3749,6 +5799,20 @@ impl AppUI {
                         _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
                     };
 
+                    // Check if the files are in the Assets Folder. All are in the same folder, so we can just check the first one.
+                    // Files exported through an export rule are moved back to their folder in the PackFile.
+                    let rules = settings.mymod_export_rules();
+                    let mut paths_packedfile: Vec<ContainerPath> = vec![];
+                    for path in &paths {
+                        let filtered_path = path.strip_prefix(&assets_folder).unwrap().to_string_lossy().replace('\\', "/");
+                        let packed_file_path = match MyModExportRule::rule_for_disk_path(&rules, &filtered_path) {
+                            Some(rule) => rule.pack_path(&filtered_path),
+                            None => filtered_path,
+                        };
+
+                        paths_packedfile.push(ContainerPath::File(packed_file_path));
+                    }
+
                     let files_to_ignore = settings.setting_text("import_files_to_ignore").map(|files_to_ignore| {
                         if files_to_ignore.is_empty() { vec![] } else {
                             files_to_ignore.split('\n')
@@ -3771,11 +5835,54 @@ impl AppUI {
     }
 
     /// This function is used to perform MyḾod exports.
+    ///
+    /// The whole PackFile is exported, applying the `mymod_export_rules` setting of the PackFile.
     pub unsafe fn export_mymod(
         app_ui: &Rc<Self>,
         pack_file_contents_ui: &Rc<PackFileContentsUI>,
-        paths_to_extract: Option<Vec<ContainerPath>>
     ) {
-        PackFileContentsUI::extract_packed_files(app_ui, pack_file_contents_ui, paths_to_extract, true)
+        match UI_STATE.get_operational_mode() {
+
+            // If we have a "MyMod" selected...
+            OperationalMode::MyMod(ref game_folder_name, ref mod_name) => {
+                let mymods_base_path = setting_path("mymods_base_path");
+                if mymods_base_path.is_dir() {
+
+                    // We get the assets folder of our mod (without .pack extension). This mess removes the .pack.
+                    let mut mod_name = mod_name.to_owned();
+                    mod_name.pop();
+                    mod_name.pop();
+                    mod_name.pop();
+                    mod_name.pop();
+                    mod_name.pop();
+
+                    let mut assets_folder = mymods_base_path;
+                    assets_folder.push(game_folder_name);
+                    assets_folder.push(&mod_name);
+
+                    // We have to save our data from cache to the backend before exporting it. Otherwise we would export outdated data.
+                    if let Err(error) = UI_STATE.get_open_packedfiles()
+                        .iter()
+                        .filter(|x| x.get_data_source() == DataSource::PackFile)
+                        .try_for_each(|packed_file| packed_file.save(app_ui, pack_file_contents_ui)) {
+                        return show_dialog(&app_ui.main_window, error, false);
+                    }
+
+                    app_ui.toggle_main_window(false);
+                    let receiver = CENTRAL_COMMAND.send_background(Command::ExportMyMod(assets_folder));
+                    let response = CENTRAL_COMMAND.recv_try(&receiver);
+                    match response {
+                        Response::String(result) => show_dialog(&app_ui.main_window, result, true),
+                        Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                    app_ui.toggle_main_window(true);
+                }
+
+                // If there is no MyMod path configured, report it.
+                else { show_dialog(&app_ui.main_window, "MyMod path not configured. Go to <i>'PackFile/Preferences'</i> and configure it.", false) }
+            }
+            OperationalMode::Normal => show_dialog(&app_ui.main_window, "This action is only available for MyMods.", false),
+        }
     }
 }
@@ -13,10 +13,12 @@ Module with all the code related to the main `AppUISlot`.
 !*/
 
 use qt_widgets::QApplication;
+use qt_widgets::QAbstractButton;
 use qt_widgets::QAction;
 use qt_widgets::QDialog;
 use qt_widgets::{QFileDialog, q_file_dialog::FileMode};
 use qt_widgets::QGridLayout;
+use qt_widgets::QMenu;
 use qt_widgets::{QMessageBox, q_message_box};
 use qt_widgets::QPushButton;
 use qt_widgets::QTextEdit;
@@ -27,30 +29,30 @@ use qt_gui::QDesktopServices;
 use qt_gui::QFont;
 
 use qt_core::QBox;
-use qt_core::{SlotOfBool, SlotOfInt, SlotNoArgs};
 use qt_core::QFlags;
+use qt_core::q_item_selection_model::SelectionFlag;
+use qt_core::{SlotOfBool, SlotOfInt, SlotOfQString, SlotNoArgs};
 use qt_core::QPtr;
 use qt_core::QString;
 use qt_core::QUrl;
-use qt_core::WidgetAttribute;
 
 use std::collections::BTreeMap;
 use std::fs::{copy, remove_file, remove_dir_all};
 use std::path::PathBuf;
 use std::rc::Rc;
 
-use rpfm_lib::files::ContainerPath;
+use rpfm_lib::files::{ContainerPath, pack::RESERVED_NAME_NOTES};
 use rpfm_lib::games::{pfh_file_type::PFHFileType, supported_games::*};
 use rpfm_lib::integrations::log::*;
 
 use crate::app_ui::AppUI;
 use crate::backend::*;
 use crate::CENTRAL_COMMAND;
-use crate::communications::{CentralCommand, THREADS_COMMUNICATION_ERROR, Command, Response};
+use crate::communications::{CentralCommand, THREADS_COMMUNICATION_ERROR, Command, FolderTarget, Response};
 use crate::dependencies_ui::DependenciesUI;
 use crate::diagnostics_ui::DiagnosticsUI;
 use crate::DISCORD_URL;
-use crate::GAME_SELECTED;
+use crate::locks::read_game_selected;
 use crate::GITHUB_URL;
 use crate::global_search_ui::GlobalSearchUI;
 use crate::locale::{qtr, tr, tre};
@@ -61,7 +63,7 @@ use crate::packedfile_views::{DataSource, View, ViewType};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::PATREON_URL;
 use crate::references_ui::ReferencesUI;
-use crate::settings_ui::{backend::*, SettingsUI};
+use crate::settings_ui::{backend::*, recent_packs::RecentPacks, SettingsUI};
 use crate::tools::{faction_painter::ToolFactionPainter, unit_editor::ToolUnitEditor};
 use crate::ui::GameSelectedIcons;
 use crate::{ui_state::OperationalMode, UI_STATE};
@@ -83,13 +85,21 @@ pub struct AppUISlots {
     // `PackFile` menu slots.
     //-----------------------------------------------//
     pub packfile_open_menu: QBox<SlotNoArgs>,
+    pub packfile_open_recent_context_menu: QBox<SlotOfQPoint>,
+    pub packfile_open_recent_show_all_games: QBox<SlotOfBool>,
     pub packfile_new_packfile: QBox<SlotOfBool>,
     pub packfile_open_packfile: QBox<SlotOfBool>,
     pub packfile_save_packfile: QBox<SlotOfBool>,
     pub packfile_save_packfile_as: QBox<SlotOfBool>,
+    pub packfile_restore_autosave: QBox<SlotOfBool>,
     pub packfile_install: QBox<SlotOfBool>,
     pub packfile_uninstall: QBox<SlotOfBool>,
+    pub packfile_notes: QBox<SlotOfBool>,
+    pub packfile_statistics: QBox<SlotOfBool>,
+    pub packfile_check_conflicts: QBox<SlotOfBool>,
+    pub packfile_row_annotations: QBox<SlotOfBool>,
     pub packfile_load_all_ca_packfiles: QBox<SlotOfBool>,
+    pub packfile_load_ca_packfiles_filtered: QBox<SlotOfBool>,
     pub packfile_change_packfile_type: QBox<SlotOfBool>,
     pub packfile_index_includes_timestamp: QBox<SlotOfBool>,
     pub packfile_data_is_compressed: QBox<SlotOfBool>,
@@ -115,14 +125,18 @@ pub struct AppUISlots {
     pub view_toggle_diagnostics_panel: QBox<SlotOfBool>,
     pub view_toggle_dependencies_panel: QBox<SlotOfBool>,
     pub view_toggle_references_panel: QBox<SlotOfBool>,
+    pub view_toggle_modified_column: QBox<SlotOfBool>,
 
     //-----------------------------------------------//
     // `Game Selected` menu slots.
     //-----------------------------------------------//
     pub game_selected_launch_game: QBox<SlotOfBool>,
+    pub game_selected_launch_game_with_current_mod: QBox<SlotOfBool>,
+    pub game_selected_restore_mod_list: QBox<SlotOfBool>,
     pub game_selected_open_game_data_folder: QBox<SlotOfBool>,
     pub game_selected_open_game_assembly_kit_folder: QBox<SlotOfBool>,
     pub game_selected_open_config_folder: QBox<SlotOfBool>,
+    pub game_selected_schema_browser: QBox<SlotOfBool>,
     pub change_game_selected: QBox<SlotOfBool>,
 
     //-----------------------------------------------//
@@ -131,7 +145,15 @@ pub struct AppUISlots {
     pub special_stuff_generate_dependencies_cache: QBox<SlotOfBool>,
     pub special_stuff_optimize_packfile: QBox<SlotOfBool>,
     pub special_stuff_patch_siege_ai: QBox<SlotOfBool>,
+    pub special_stuff_refresh_dependencies_cache: QBox<SlotOfBool>,
     pub special_stuff_rescue_packfile: QBox<SlotOfBool>,
+    pub special_stuff_verify_integrity: QBox<SlotOfBool>,
+    pub special_stuff_compare_packs: QBox<SlotOfBool>,
+    pub special_stuff_sign_pack: QBox<SlotOfBool>,
+    pub special_stuff_verify_pack_signature: QBox<SlotOfBool>,
+    pub special_stuff_translation_export_po: QBox<SlotOfBool>,
+    pub special_stuff_translation_import_po: QBox<SlotOfBool>,
+    pub special_stuff_translation_report: QBox<SlotOfBool>,
 
     //-----------------------------------------------//
     // `Tools` menu slots.
@@ -154,6 +176,8 @@ pub struct AppUISlots {
     //-----------------------------------------------//
     pub debug_update_current_schema_from_asskit: QBox<SlotOfBool>,
     pub debug_import_schema_patch: QBox<SlotNoArgs>,
+    pub debug_export_schema_patches: QBox<SlotNoArgs>,
+    pub debug_release_memory: QBox<SlotNoArgs>,
     pub debug_reload_style_sheet: QBox<SlotNoArgs>,
 
     //-----------------------------------------------//
@@ -167,6 +191,9 @@ pub struct AppUISlots {
     // `Generic` slots.
     //-----------------------------------------------//
     pub pack_file_backup_autosave: QBox<SlotNoArgs>,
+    pub pack_file_restore_tab_session: QBox<SlotNoArgs>,
+    pub pending_dependencies_check: QBox<SlotNoArgs>,
+    pub pack_changed_on_disk_banner_link: QBox<SlotOfQString>,
 
     pub tab_bar_packed_file_context_menu_show: QBox<SlotOfQPoint>,
     pub tab_bar_packed_file_close: QBox<SlotNoArgs>,
@@ -221,6 +248,56 @@ impl AppUISlots {
             }
         ));
 
+        // Slot to pin, unpin or remove the Pack under the cursor from the "Recent Packs" submenu.
+        let packfile_open_recent_context_menu = SlotOfQPoint::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui,
+            diagnostics_ui => move |point| {
+
+                // Separators and the "Show all games" action have no path, so skip them.
+                let action = app_ui.packfile_open_recent.action_at(point);
+                if action.is_null() || action.data().is_null() {
+                    return;
+                }
+
+                let path = PathBuf::from(action.data().to_string().to_std_string());
+                let mut recent_packs = RecentPacks::load().unwrap_or_default();
+                let pinned = recent_packs.packs().iter().any(|pack| *pack.path() == path && *pack.pinned());
+
+                let menu = QMenu::new();
+                let pin = menu.add_action_q_string(&qtr(if pinned { "recent_packs_unpin" } else { "recent_packs_pin" }));
+                let remove = menu.add_action_q_string(&qtr("recent_packs_remove"));
+                let selected = menu.exec_1a_mut(&app_ui.packfile_open_recent.map_to_global(point));
+                if selected.is_null() {
+                    return;
+                }
+
+                if selected.as_raw_ptr() == pin.as_raw_ptr() {
+                    recent_packs.set_pinned(&path, !pinned);
+                } else if selected.as_raw_ptr() == remove.as_raw_ptr() {
+                    recent_packs.remove(&path);
+                }
+
+                if let Err(error) = recent_packs.save() {
+                    return show_dialog(&app_ui.main_window, error, false);
+                }
+
+                AppUI::build_recent_packs_submenu(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui);
+            }
+        ));
+
+        // Slot to toggle between showing the recent Packs of all games or only the ones of the Game Selected.
+        let packfile_open_recent_show_all_games = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui,
+            diagnostics_ui => move |state| {
+                set_setting_bool("recent_packs_show_all_games", state);
+                AppUI::build_recent_packs_submenu(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui);
+            }
+        ));
+
         // What happens when we trigger the "New PackFile" action.
         let packfile_new_packfile = SlotOfBool::new(&app_ui.main_window, clone!(
             app_ui,
@@ -268,11 +345,11 @@ impl AppUISlots {
 
                         // Try to open it, and report it case of error.
                         if let Err(error) = AppUI::open_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &paths, "") {
-                            return show_dialog(&app_ui.main_window, error, false);
+                            return AppUI::show_error_dialog(&app_ui, &error);
                         }
 
                         if setting_bool("diagnostics_trigger_on_open") {
-                            DiagnosticsUI::check(&app_ui, &diagnostics_ui);
+                            DiagnosticsUI::check_when_ready(&app_ui, &diagnostics_ui);
                         }
                     }
                 }
@@ -285,7 +362,7 @@ impl AppUISlots {
             pack_file_contents_ui => move |_| {
                 info!("Triggering `Save PackFile` By Slot");
                 if let Err(error) = AppUI::save_packfile(&app_ui, &pack_file_contents_ui, false) {
-                    show_dialog(&app_ui.main_window, error, false);
+                    AppUI::show_error_dialog(&app_ui, &error);
                 }
             }
         ));
@@ -296,7 +373,41 @@ impl AppUISlots {
             pack_file_contents_ui => move |_| {
                 info!("Triggering `Save PackFile As` By Slot");
                 if let Err(error) = AppUI::save_packfile(&app_ui, &pack_file_contents_ui, true) {
-                    show_dialog(&app_ui.main_window, error, false);
+                    AppUI::show_error_dialog(&app_ui, &error);
+                }
+            }
+        ));
+
+        // What happens when we trigger the "Restore Autosave" action.
+        let packfile_restore_autosave = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            diagnostics_ui,
+            global_search_ui => move |_| {
+                info!("Triggering `Restore Autosave` By Slot");
+
+                let receiver = CENTRAL_COMMAND.send_background(Command::ListAutosaves);
+                let response = CentralCommand::recv(&receiver);
+                let autosaves = match response {
+                    Response::VecPathBuf(autosaves) => autosaves,
+                    Response::Error(error) => return show_dialog(&app_ui.main_window, error, false),
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                };
+
+                if autosaves.is_empty() {
+                    return show_dialog(&app_ui.main_window, tr("restore_autosave_none"), false);
+                }
+
+                if let Some(path) = AppUI::restore_autosave_dialog(&app_ui, &autosaves) {
+                    if AppUI::are_you_sure(&app_ui, false) {
+                        if let Err(error) = AppUI::open_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &[path], "") {
+                            return AppUI::show_error_dialog(&app_ui, &error);
+                        }
+
+                        if setting_bool("diagnostics_trigger_on_open") {
+                            DiagnosticsUI::check_when_ready(&app_ui, &diagnostics_ui);
+                        }
+                    }
                 }
             }
         ));
@@ -309,7 +420,7 @@ impl AppUISlots {
 
                 // Save before installing, to ensure we always have the latest data on install.
                 if let Err(error) = AppUI::save_packfile(&app_ui, &pack_file_contents_ui, false) {
-                    return show_dialog(&app_ui.main_window, error, false);
+                    return AppUI::show_error_dialog(&app_ui, &error);
                 }
 
                 // Get the current path of the PackFile.
@@ -324,7 +435,7 @@ impl AppUISlots {
                     return show_dialog(&app_ui.main_window, "Pack to install not found on disk.", false);
                 }
 
-                if let Ok(mut game_local_mods_path) = GAME_SELECTED.read().unwrap().local_mods_path(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name())) {
+                if let Ok(mut game_local_mods_path) = read_game_selected().local_mods_path(&setting_path(&read_game_selected().game_key_name())) {
                     if !game_local_mods_path.is_dir() {
                         return show_dialog(&app_ui.main_window, "Game Path not configured. Go to <i>'PackFile/Preferences'</i> and configure it.", false);
                     }
@@ -337,7 +448,7 @@ impl AppUISlots {
                         game_local_mods_path.push(mod_name);
 
                         // Check if the PackFile is not a CA one before installing.
-                        let ca_paths = match GAME_SELECTED.read().unwrap().ca_packs_paths(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name())) {
+                        let ca_paths = match read_game_selected().ca_packs_paths(&setting_path(&read_game_selected().game_key_name())) {
                             Ok(paths) => paths,
                             Err(_) => return show_dialog(&app_ui.main_window, "You can't do that to a CA PackFile, you monster!", false),
                         };
@@ -362,6 +473,28 @@ impl AppUISlots {
 
                         // Enable the uninstall button.
                         app_ui.packfile_uninstall.set_enabled(true);
+
+                        // Offer to show the installed Pack, in case the user wants to check it.
+                        let dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+                            q_message_box::Icon::Information,
+                            &qtr("title_success"),
+                            &qtr("install_success"),
+                            QFlags::from(q_message_box::StandardButton::Ok),
+                            &app_ui.main_window,
+                        );
+
+                        let show_button = dialog.add_button_q_string_button_role(&qtr("install_show_in_data_folder"), q_message_box::ButtonRole::ActionRole);
+                        dialog.exec();
+
+                        if dialog.clicked_button().as_raw_ptr() == show_button.static_upcast::<QAbstractButton>().as_raw_ptr() {
+                            let receiver = CENTRAL_COMMAND.send_background(Command::OpenContainingFolder(Some(FolderTarget::InstalledLocation)));
+                            let response = CentralCommand::recv(&receiver);
+                            match response {
+                                Response::Success => {}
+                                Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                            }
+                        }
                     }
                 }
             }
@@ -382,7 +515,7 @@ impl AppUISlots {
                     return show_dialog(&app_ui.main_window, "Pack to install not found on disk.", false);
                 }
 
-                if let Ok(mut game_local_mods_path) = GAME_SELECTED.read().unwrap().local_mods_path(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name())) {
+                if let Ok(mut game_local_mods_path) = read_game_selected().local_mods_path(&setting_path(&read_game_selected().game_key_name())) {
                     if !game_local_mods_path.is_dir() {
                         return show_dialog(&app_ui.main_window, "Game Path not configured. Go to <i>'PackFile/Preferences'</i> and configure it.", false);
                     }
@@ -394,7 +527,7 @@ impl AppUISlots {
                     if let Some(ref mod_name) = pack_path.file_name() {
                         game_local_mods_path.push(mod_name);
 
-                        let ca_paths = match GAME_SELECTED.read().unwrap().ca_packs_paths(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name())) {
+                        let ca_paths = match read_game_selected().ca_packs_paths(&setting_path(&read_game_selected().game_key_name())) {
                             Ok(paths) => paths,
                             Err(_) => return show_dialog(&app_ui.main_window, "You can't do that to a CA PackFile, you monster!", false),
                         };
@@ -417,86 +550,163 @@ impl AppUISlots {
             }
         ));
 
-        // What happens when we trigger the "Load All CA PackFiles" action.
-        let packfile_load_all_ca_packfiles = SlotOfBool::new(&app_ui.main_window, clone!(
+        // This slot is used for the "Pack Notes" action.
+        let packfile_notes = SlotOfBool::new(&app_ui.main_window, clone!(
             app_ui,
             pack_file_contents_ui,
-            global_search_ui => move |_| {
+            global_search_ui,
+            diagnostics_ui,
+            dependencies_ui,
+            references_ui => move |_| {
+                info!("Triggering `Pack Notes` By Slot");
+                AppUI::open_packedfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui, Some(RESERVED_NAME_NOTES.to_owned()), false, false, DataSource::PackFile);
+            }
+        ));
 
-            // Check first if there has been changes in the PackFile. If we accept, just take all the PackFiles in the data folder
-            // and open them all together, skipping mods.
-            if AppUI::are_you_sure(&app_ui, false) {
-                info!("Triggering `Load all CA PackFiles` By Slot");
+        // What happens when we trigger the "Pack Statistics" action.
+        let packfile_statistics = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Pack Statistics` By Slot");
 
-                // Reset the autosave timer.
-                let timer = setting_int("autosave_interval");
-                if timer > 0 {
-                    app_ui.timer_backup_autosave.set_interval(timer * 60 * 1000);
-                    app_ui.timer_backup_autosave.start_0a();
+                // The open files need to be in the backend for the statistics to be accurate.
+                if let Err(error) = AppUI::back_to_back_end_all(&app_ui, &pack_file_contents_ui) {
+                    return show_dialog(&app_ui.main_window, error, false);
                 }
 
-                // Tell the Background Thread to create a new PackFile with the data of one or more from the disk.
-                app_ui.toggle_main_window(false);
-
-                // Destroy whatever it's in the PackedFile's views and clear the global search UI.
-                GlobalSearchUI::clear(&global_search_ui);
-                let _ = AppUI::purge_them_all(&app_ui, &pack_file_contents_ui, false);
-
-                let receiver = CENTRAL_COMMAND.send_background(Command::LoadAllCAPackFiles);
+                let receiver = CENTRAL_COMMAND.send_background(Command::GetPackStatistics);
                 let response = CENTRAL_COMMAND.recv_try(&receiver);
                 match response {
+                    Response::PackStatistics(statistics) => AppUI::pack_statistics_dialog(&app_ui, &statistics),
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+            }
+        ));
 
-                    // If it's success....
-                    Response::ContainerInfo(ui_data) => {
-
-                        // Set this PackFile always to type `Release`.
-                        app_ui.change_packfile_type_release.set_checked(true);
-
-                        // Disable all of these.
-                        app_ui.change_packfile_type_data_is_encrypted.set_checked(false);
-                        app_ui.change_packfile_type_index_includes_timestamp.set_checked(false);
-                        app_ui.change_packfile_type_index_is_encrypted.set_checked(false);
-                        app_ui.change_packfile_type_header_is_extended.set_checked(false);
-
-                        // Set the compression level correctly, because otherwise we may fuckup some files.
-                        app_ui.change_packfile_type_data_is_compressed.set_checked(*ui_data.compress());
-
-                        // Update the TreeView.
-                        let mut build_data = BuildData::new();
-                        build_data.editable = true;
-                        pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Build(build_data), DataSource::PackFile);
-
-                        match &*GAME_SELECTED.read().unwrap().game_key_name() {
-                            KEY_WARHAMMER_3 => app_ui.game_selected_warhammer_3.trigger(),
-                            KEY_TROY => app_ui.game_selected_troy.trigger(),
-                            KEY_THREE_KINGDOMS => app_ui.game_selected_three_kingdoms.trigger(),
-                            KEY_WARHAMMER_2 => app_ui.game_selected_warhammer_2.trigger(),
-                            KEY_WARHAMMER => app_ui.game_selected_warhammer.trigger(),
-                            KEY_THRONES_OF_BRITANNIA => app_ui.game_selected_thrones_of_britannia.trigger(),
-                            KEY_ATTILA => app_ui.game_selected_attila.trigger(),
-                            KEY_ROME_2 => app_ui.game_selected_rome_2.trigger(),
-                            KEY_SHOGUN_2 => app_ui.game_selected_shogun_2.trigger(),
-                            KEY_NAPOLEON => app_ui.game_selected_napoleon.trigger(),
-                            KEY_EMPIRE => app_ui.game_selected_empire.trigger(),
-                            KEY_ARENA => app_ui.game_selected_arena.trigger(),
-                            _ => unreachable!(),
-                        }
+        // What happens when we trigger the "Check Conflicts with Other Packs" action.
+        let packfile_check_conflicts = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Check Conflicts with Other Packs` By Slot");
 
-                        UI_STATE.set_operational_mode(&app_ui, None);
-                        UI_STATE.set_is_modified(false, &app_ui, &pack_file_contents_ui);
+                let file_dialog = QFileDialog::from_q_widget_q_string(
+                    &app_ui.main_window,
+                    &qtr("packfile_check_conflicts"),
+                );
+                file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
+                file_dialog.set_file_mode(FileMode::ExistingFiles);
+
+                // Start in the data folder of the game, as that's where most of the Packs to check against will be.
+                let game = read_game_selected();
+                if let Ok(data_path) = game.data_path(&setting_path(&game.game_key_name())) {
+                    file_dialog.set_directory_q_string(&QString::from_std_str(data_path.to_string_lossy()));
+                }
+
+                if file_dialog.exec() == 1 {
+                    let mut paths = vec![];
+                    for index in 0..file_dialog.selected_files().count_0a() {
+                        paths.push(PathBuf::from(file_dialog.selected_files().at(index).to_std_string()));
                     }
 
-                    // If we got an error...
-                    Response::Error(error) => {
-                        show_dialog(&app_ui.main_window, error, false);
+                    // Unsaved changes need to be in the backend for the conflicts to be accurate.
+                    if let Err(error) = AppUI::back_to_back_end_all(&app_ui, &pack_file_contents_ui) {
+                        return show_dialog(&app_ui.main_window, error, false);
                     }
 
-                    // In ANY other situation, it's a message problem.
+                    let receiver = CENTRAL_COMMAND.send_background(Command::CheckConflicts(paths));
+                    let response = CENTRAL_COMMAND.recv_try(&receiver);
+                    match response {
+                        Response::PackConflicts(conflicts) => AppUI::pack_conflicts_dialog(&app_ui, &conflicts),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+            }
+        ));
+
+        // What happens when we trigger the "Row Notes" action.
+        let packfile_row_annotations = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui,
+            diagnostics_ui,
+            dependencies_ui,
+            references_ui => move |_| {
+                info!("Triggering `Row Notes` By Slot");
+
+                // Unsaved changes need to be in the backend to know which notes are orphaned.
+                if let Err(error) = AppUI::back_to_back_end_all(&app_ui, &pack_file_contents_ui) {
+                    return show_dialog(&app_ui.main_window, error, false);
+                }
+
+                let receiver = CENTRAL_COMMAND.send_background(Command::GetAllRowAnnotations);
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                let annotations = match response {
+                    Response::VecStringStringStringBool(annotations) => annotations,
                     _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                };
+
+                let selected_row = AppUI::row_annotations_dialog(&app_ui, &pack_file_contents_ui, &annotations);
+
+                // Notes may have been removed in the dialog, so refresh the pins of the open tables.
+                for packed_file_view in UI_STATE.get_open_packedfiles().iter().filter(|x| x.get_data_source() == DataSource::PackFile) {
+                    if let ViewType::Internal(View::Table(view)) = packed_file_view.get_view() {
+                        view.get_ref_table().update_row_annotations();
+                    }
                 }
 
-                // Always reenable the Main Window.
-                app_ui.toggle_main_window(true);
+                if let Some((path, key)) = selected_row {
+                    let tree_index = pack_file_contents_ui.packfile_contents_tree_view().expand_treeview_to_item(&path, DataSource::PackFile);
+                    if let Some(ref tree_index) = tree_index {
+                        if tree_index.is_valid() {
+                            UI_STATE.set_packfile_contents_read_only(true);
+                            pack_file_contents_ui.packfile_contents_tree_view().scroll_to_1a(tree_index.as_ref().unwrap());
+                            pack_file_contents_ui.packfile_contents_tree_view().selection_model().select_q_model_index_q_flags_selection_flag(tree_index.as_ref().unwrap(), QFlags::from(SelectionFlag::ClearAndSelect));
+                            UI_STATE.set_packfile_contents_read_only(false);
+                        }
+                    }
+
+                    AppUI::open_packedfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui, Some(path.to_owned()), false, false, DataSource::PackFile);
+
+                    let found = UI_STATE.get_open_packedfiles().iter()
+                        .filter(|x| x.get_data_source() == DataSource::PackFile)
+                        .find(|x| *x.get_ref_path() == path)
+                        .map(|packed_file_view| match packed_file_view.get_view() {
+                            ViewType::Internal(View::Table(view)) => view.get_ref_table().select_row_by_key(&key),
+                            _ => false,
+                        })
+                        .unwrap_or(false);
+
+                    if !found {
+                        log_to_status_bar(&tr("row_annotations_row_not_found"));
+                    }
+                }
+            }
+        ));
+
+        // What happens when we trigger the "Load All CA PackFiles" action.
+        let packfile_load_all_ca_packfiles = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui => move |_| {
+
+            // Check first if there has been changes in the PackFile. If we accept, just take all the PackFiles in the data folder
+            // and open them all together, skipping mods.
+            if AppUI::are_you_sure(&app_ui, false) {
+                info!("Triggering `Load all CA PackFiles` By Slot");
+                AppUI::load_ca_packs(&app_ui, &pack_file_contents_ui, &global_search_ui, Command::LoadAllCAPackFiles);
+            }
+        }));
+
+        // What happens when we trigger the "Load CA PackFiles..." action.
+        let packfile_load_ca_packfiles_filtered = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui => move |_| {
+            if AppUI::are_you_sure(&app_ui, false) {
+                info!("Triggering `Load CA PackFiles...` By Slot");
+                if let Some(paths) = AppUI::load_ca_packs_filtered_dialog(&app_ui) {
+                    AppUI::load_ca_packs(&app_ui, &pack_file_contents_ui, &global_search_ui, Command::LoadCAPackFilesFiltered(paths));
+                }
             }
         }));
 
@@ -520,6 +730,9 @@ impl AppUISlots {
                 // Send the type to the Background Thread, and update the UI.
                 let _ = CENTRAL_COMMAND.send_background(Command::SetPackFileType(packfile_type));
                 UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
+
+                // Different types may use different PFH versions, so the valid flags may have changed.
+                AppUI::update_pfh_flags_actions(&app_ui);
             }
         ));
 
@@ -529,6 +742,7 @@ impl AppUISlots {
             pack_file_contents_ui =>  move |_| {
                 let state = app_ui.change_packfile_type_index_includes_timestamp.is_checked();
                 let _ = CENTRAL_COMMAND.send_background(Command::ChangeIndexIncludesTimestamp(state));
+                pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateHasTimestamps(state), DataSource::PackFile);
                 UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
             }
         ));
@@ -551,7 +765,7 @@ impl AppUISlots {
             global_search_ui => move |_| {
                 info!("Triggering `Preferences Dialog` By Slot");
 
-                let game_key = GAME_SELECTED.read().unwrap().game_key_name();
+                let game_key = read_game_selected().game_key_name();
                 let mymod_path_old = setting_path(MYMOD_BASE_PATH);
                 let game_path_old = setting_path(&game_key);
                 let ak_path_old = setting_path(&format!("{}_assembly_kit", game_key));
@@ -653,9 +867,12 @@ impl AppUISlots {
                 // Trigger the `New MyMod` Dialog, and get the result.
                 match MyModUI::new(&app_ui) {
                     Ok(dialog) => {
-                        if let Some((mod_name, mod_game, sublime_support, vscode_support, paths_ignore_on_import, git_support)) = dialog {
+                        if let Some((mod_name, mod_game, sublime_support, vscode_support, paths_ignore_on_import, git_support, skeleton_components, skeleton_in_pack)) = dialog {
                             let full_mod_name = format!("{}.pack", mod_name);
 
+                            // Remember the open files of the current Pack before the game change closes them.
+                            AppUI::save_tab_session(&app_ui);
+
                             // Change the Game Selected to match the one we chose for the new "MyMod".
                             // NOTE: Arena should not be on this list.
                             match &*mod_game {
@@ -677,7 +894,7 @@ impl AppUISlots {
                             app_ui.toggle_main_window(false);
 
                             // Initialize the folder structure of the MyMod.
-                            let receiver = CENTRAL_COMMAND.send_background(Command::InitializeMyModFolder(mod_name, mod_game, sublime_support, vscode_support, git_support));
+                            let receiver = CENTRAL_COMMAND.send_background(Command::InitializeMyModFolder(mod_name.clone(), mod_game, sublime_support, vscode_support, git_support, skeleton_components.clone()));
                             let response = CENTRAL_COMMAND.recv_try(&receiver);
                             match response {
                                 Response::PathBuf(mymod_pack_path) => {
@@ -699,6 +916,18 @@ impl AppUISlots {
 
                                     let _ = CENTRAL_COMMAND.send_background(Command::NewPackFile);
                                     let _ = CENTRAL_COMMAND.send_background(Command::SetPackSettings(pack_settings));
+
+                                    // Add the starter files of the folder structure to the Pack before saving it, and remember the empty folders to show them.
+                                    let mut empty_folders = vec![];
+                                    if skeleton_in_pack && !skeleton_components.is_empty() {
+                                        let receiver = CENTRAL_COMMAND.send_background(Command::AddMyModSkeletonToPack(mod_name, skeleton_components));
+                                        let response = CENTRAL_COMMAND.recv_try(&receiver);
+                                        match response {
+                                            Response::VecContainerPath(folders) => empty_folders = folders,
+                                            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                                        }
+                                    }
+
                                     let receiver = CENTRAL_COMMAND.send_background(Command::SavePackFileAs(mymod_pack_path.clone()));
                                     let response = CENTRAL_COMMAND.recv_try(&receiver);
                                     match response {
@@ -707,6 +936,10 @@ impl AppUISlots {
                                             let mut build_data = BuildData::new();
                                             build_data.editable = true;
                                             pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Build(build_data), DataSource::PackFile);
+                                            if !empty_folders.is_empty() {
+                                                pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Add(empty_folders), DataSource::PackFile);
+                                            }
+
                                             let packfile_item = pack_file_contents_ui.packfile_contents_tree_model().item_1a(0);
                                             packfile_item.set_tool_tip(&QString::from_std_str(new_pack_file_tooltip(&pack_file_info)));
                                             packfile_item.set_text(&QString::from_std_str(full_mod_name));
@@ -733,6 +966,11 @@ impl AppUISlots {
                                             show_dialog(&app_ui.main_window, error, false);
                                         }
 
+                                        Response::ErrorKind(kind, message) => {
+                                            app_ui.toggle_main_window(true);
+                                            AppUI::show_error_kind_dialog(&app_ui, kind, &message);
+                                        }
+
                                         // In ANY other situation, it's a message problem.
                                         _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
                                     }
@@ -842,7 +1080,7 @@ impl AppUISlots {
             app_ui,
             pack_file_contents_ui => move |_| {
             info!("Triggering `Export MyMod` By Slot");
-            AppUI::export_mymod(&app_ui, &pack_file_contents_ui, Some(vec![ContainerPath::Folder("".to_owned())]));
+            AppUI::export_mymod(&app_ui, &pack_file_contents_ui);
         }));
 
         //-----------------------------------------------//
@@ -897,6 +1135,12 @@ impl AppUISlots {
                 else { references_ui.references_dock_widget().show();}
         }));
 
+        let view_toggle_modified_column = SlotOfBool::new(&app_ui.main_window, clone!(
+            pack_file_contents_ui => move |state| {
+                set_setting_bool("packfile_treeview_show_modified_column", state);
+                pack_file_contents_ui.packfile_contents_tree_view().update_modified_column(true);
+        }));
+
         //-----------------------------------------------//
         // `Game Selected` menu logic.
         //-----------------------------------------------//
@@ -904,16 +1148,73 @@ impl AppUISlots {
         // What happens when we trigger the "Launch Game" action.
         let game_selected_launch_game = SlotOfBool::new(&app_ui.main_window, clone!(
             app_ui => move |_| {
-            match GAME_SELECTED.read().unwrap().game_launch_command(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name())) {
+            match read_game_selected().game_launch_command(&setting_path(&read_game_selected().game_key_name())) {
                 Ok(command) => { let _ = open::that(command); },
                 _ => show_dialog(&app_ui.main_window, "The currently selected game cannot be launched from Steam.", false),
             }
         }));
 
+        // What happens when we trigger the "Launch with Current Mod" action.
+        let game_selected_launch_game_with_current_mod = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui => move |_| {
+            info!("Triggering `Launch with Current Mod` By Slot");
+
+            let game_path = setting_path(&read_game_selected().game_key_name());
+            let launch_command = match read_game_selected().game_launch_command(&game_path) {
+                Ok(command) => command,
+                _ => return show_dialog(&app_ui.main_window, "The currently selected game cannot be launched from Steam.", false),
+            };
+
+            let receiver = CENTRAL_COMMAND.send_background(Command::GetPackFilePath);
+            let response = CentralCommand::recv(&receiver);
+            let pack_path = if let Response::PathBuf(pack_path) = response { pack_path } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response) };
+            if !pack_path.is_file() {
+                return show_dialog(&app_ui.main_window, "The Pack is not saved on disk. Save it and install it before launching the game with it.", false);
+            }
+
+            let local_mods_path = match read_game_selected().local_mods_path(&game_path) {
+                Ok(path) if path.is_dir() => path,
+                _ => return show_dialog(&app_ui.main_window, "Game Path not configured. Go to <i>'PackFile/Preferences'</i> and configure it.", false),
+            };
+
+            // The game can only load the Pack if it's installed, so offer to install it if it isn't.
+            let is_installed = || pack_path.starts_with(&local_mods_path) || pack_path.file_name().map(|name| local_mods_path.join(name).is_file()).unwrap_or(false);
+            if !is_installed() {
+                if !AppUI::are_you_sure_edition(&app_ui, "launch_game_with_current_mod_install") {
+                    return;
+                }
+
+                app_ui.packfile_install.trigger();
+                if !is_installed() {
+                    return;
+                }
+            }
+
+            let receiver = CENTRAL_COMMAND.send_background(Command::WriteModListForOpenPack);
+            let response = CentralCommand::recv(&receiver);
+            match response {
+                Response::PathBuf(_) => { let _ = open::that(launch_command); },
+                Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }));
+
+        // What happens when we trigger the "Restore Mod List" action.
+        let game_selected_restore_mod_list = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui => move |_| {
+            info!("Triggering `Restore Mod List` By Slot");
+
+            let game_path = setting_path(&read_game_selected().game_key_name());
+            match read_game_selected().restore_mod_list(&game_path) {
+                Ok(_) => log_to_status_bar(&tr("restore_mod_list_success")),
+                Err(error) => show_dialog(&app_ui.main_window, error, false),
+            }
+        }));
+
         // What happens when we trigger the "Open Game's Data Folder" action.
         let game_selected_open_game_data_folder = SlotOfBool::new(&app_ui.main_window, clone!(
             app_ui => move |_| {
-            if let Ok(path) = GAME_SELECTED.read().unwrap().data_path(&setting_path(&GAME_SELECTED.read().unwrap().game_key_name())) {
+            if let Ok(path) = read_game_selected().data_path(&setting_path(&read_game_selected().game_key_name())) {
                 let _ = open::that(path);
             } else {
                 show_dialog(&app_ui.main_window, "Game Path not configured. Go to <i>'PackFile/Preferences'</i> and configure it.", false);
@@ -923,7 +1224,7 @@ impl AppUISlots {
         // What happens when we trigger the "Open Game's Assembly Kit Folder" action.
         let game_selected_open_game_assembly_kit_folder = SlotOfBool::new(&app_ui.main_window, clone!(
             app_ui => move |_| {
-            let path = setting_path(&format!("{}_assembly_kit", GAME_SELECTED.read().unwrap().game_key_name()));
+            let path = setting_path(&format!("{}_assembly_kit", read_game_selected().game_key_name()));
             if path.is_dir() {
                 let _ = open::that(&path);
             } else {
@@ -941,6 +1242,20 @@ impl AppUISlots {
             }
         }));
 
+        // What happens when we trigger the "Schema Browser" action.
+        let game_selected_schema_browser = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui => move |_| {
+            info!("Triggering `Schema Browser` By Slot");
+
+            let receiver = CENTRAL_COMMAND.send_background(Command::GetSchemaOverview);
+            let response = CENTRAL_COMMAND.recv_try(&receiver);
+            match response {
+                Response::VecTableOverview(tables) => AppUI::schema_browser_dialog(&app_ui, tables),
+                Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }));
+
         // What happens when we trigger the "Change Game Selected" action.
         //
         // NOTE: NEVER EVER AGAIN SHALL YOU TRIGGER HERE A REBUILD OF THE GAME-SPECIFIC SLOTS!!!!!!!!!!
@@ -964,28 +1279,20 @@ impl AppUISlots {
                 if AppUI::are_you_sure_edition(&app_ui, "generate_dependencies_cache_are_you_sure") {
                     info!("Triggering `Generate Dependencies Cache` By Slot");
 
-                    if !setting_path(&format!("{}_assembly_kit", GAME_SELECTED.read().unwrap().game_key_name())).is_dir() {
+                    if !setting_path(&format!("{}_assembly_kit", read_game_selected().game_key_name())).is_dir() {
                         show_dialog(&app_ui.main_window, tr("generate_dependencies_cache_warn"), true);
                     }
 
                     // If there is no problem, ere we go.
                     app_ui.toggle_main_window(false);
 
-                    let wait_dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
-                        q_message_box::Icon::Information,
-                        &qtr("rpfm_title"),
-                        &qtr("generate_dependencies_cache_in_progress_message"),
-                        QFlags::from(0),
-                        &app_ui.main_window,
-                    );
+                    let wait_text = tr("generate_dependencies_cache_in_progress_message");
+                    let (receiver, cancel) = CENTRAL_COMMAND.send_background_cancellable(Command::GenerateDependenciesCache);
+                    let wait_dialog = new_cancellable_progress_dialog(&app_ui.main_window, &wait_text, &cancel);
 
-                    wait_dialog.set_attribute_1a(WidgetAttribute::WADeleteOnClose);
-                    wait_dialog.set_modal(true);
-                    wait_dialog.set_standard_buttons(QFlags::from(0));
-                    wait_dialog.show();
-
-                    let receiver = CENTRAL_COMMAND.send_background(Command::GenerateDependenciesCache);
-                    let response = CENTRAL_COMMAND.recv_try(&receiver);
+                    let response = CENTRAL_COMMAND.recv_try_with_progress(&receiver, |current, total, description| {
+                        update_progress_dialog(&wait_dialog, &wait_text, current, total, description);
+                    });
 
                     match response {
                         Response::DependenciesInfo(response) => {
@@ -1005,10 +1312,15 @@ impl AppUISlots {
                             wait_dialog.done(1);
                             show_dialog(&app_ui.main_window, tr("generate_dependency_cache_success"), true)
                         },
+                        Response::Cancelled => wait_dialog.done(1),
                         Response::Error(error) => {
                             wait_dialog.done(1);
                             show_dialog(&app_ui.main_window, error, false);
                         },
+                        Response::ErrorKind(kind, message) => {
+                            wait_dialog.done(1);
+                            AppUI::show_error_kind_dialog(&app_ui, kind, &message);
+                        },
                         _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
                     }
 
@@ -1017,15 +1329,96 @@ impl AppUISlots {
             }
         ));
 
+        // What happens when we trigger the "Refresh Dependencies Cache" action.
+        let special_stuff_refresh_dependencies_cache = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            dependencies_ui => move |_| {
+                info!("Triggering `Refresh Dependencies Cache` By Slot");
+                app_ui.toggle_main_window(false);
+
+                // If the cache cannot be refreshed, it's fully regenerated, so we need the same progress dialog as when generating it.
+                let wait_text = tr("refresh_dependencies_cache_in_progress_message");
+                let (receiver, cancel) = CENTRAL_COMMAND.send_background_cancellable(Command::RefreshDependenciesCache);
+                let wait_dialog = new_cancellable_progress_dialog(&app_ui.main_window, &wait_text, &cancel);
+
+                let response = CENTRAL_COMMAND.recv_try_with_progress(&receiver, |current, total, description| {
+                    update_progress_dialog(&wait_dialog, &wait_text, current, total, description);
+                });
+
+                match response {
+                    Response::DependenciesInfoVecPathBuf(response, refreshed) => {
+                        let mut parent_build_data = BuildData::new();
+                        parent_build_data.data = Some((ContainerInfo::default(), response.parent_packed_files().to_vec()));
+
+                        let mut game_build_data = BuildData::new();
+                        game_build_data.data = Some((ContainerInfo::default(), response.vanilla_packed_files().to_vec()));
+
+                        let mut asskit_build_data = BuildData::new();
+                        asskit_build_data.data = Some((ContainerInfo::default(), response.asskit_tables().to_vec()));
+
+                        dependencies_ui.dependencies_tree_view().update_treeview(true, TreeViewOperation::Build(parent_build_data), DataSource::ParentFiles);
+                        dependencies_ui.dependencies_tree_view().update_treeview(true, TreeViewOperation::Build(game_build_data), DataSource::GameFiles);
+                        dependencies_ui.dependencies_tree_view().update_treeview(true, TreeViewOperation::Build(asskit_build_data), DataSource::AssKitFiles);
+
+                        wait_dialog.done(1);
+
+                        if refreshed.is_empty() {
+                            log_to_status_bar(&tr("refresh_dependencies_cache_up_to_date"));
+                        } else {
+                            let names = refreshed.iter()
+                                .map(|path| path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default())
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            log_to_status_bar(&tre("refresh_dependencies_cache_success", &[&refreshed.len().to_string(), &names]));
+                        }
+                    },
+                    Response::Cancelled => wait_dialog.done(1),
+                    Response::Error(error) => {
+                        wait_dialog.done(1);
+                        show_dialog(&app_ui.main_window, error, false);
+                    },
+                    Response::ErrorKind(kind, message) => {
+                        wait_dialog.done(1);
+                        AppUI::show_error_kind_dialog(&app_ui, kind, &message);
+                    },
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+
+                app_ui.toggle_main_window(true);
+            }
+        ));
+
         // What happens when we trigger the "Optimize PackFile" action.
         let special_stuff_optimize_packfile = SlotOfBool::new(&app_ui.main_window, clone!(
             app_ui,
             pack_file_contents_ui,
             global_search_ui => move |_| {
 
-                if AppUI::are_you_sure_edition(&app_ui, "optimize_packfile_are_you_sure") {
+                if let Some(options) = AppUI::optimizer_options_dialog(&app_ui) {
                     info!("Triggering `Optimize PackFile` By Slot");
 
+                    // Before optimizing, show what the optimization is going to do. The open files need to be in the backend for the report to be accurate.
+                    if let Err(error) = AppUI::back_to_back_end_all(&app_ui, &pack_file_contents_ui) {
+                        return show_dialog(&app_ui.main_window, error, false);
+                    }
+
+                    let receiver = CENTRAL_COMMAND.send_background(Command::OptimizePackFileReport(options));
+                    let response = CENTRAL_COMMAND.recv_try(&receiver);
+                    match response {
+                        Response::OptimizerReport(report) => {
+                            if report.files().is_empty() {
+                                return show_dialog(&app_ui.main_window, tr("optimize_packfile_nothing_to_optimize"), true);
+                            }
+
+                            if !AppUI::optimizer_report_dialog(&app_ui, &report) {
+                                return;
+                            }
+                        }
+                        Response::Error(error) => return show_dialog(&app_ui.main_window, error, false),
+                        Response::ErrorKind(kind, message) => return AppUI::show_error_kind_dialog(&app_ui, kind, &message),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+
                     // If there is no problem, ere we go.
                     app_ui.toggle_main_window(false);
 
@@ -1035,8 +1428,15 @@ impl AppUISlots {
 
                     GlobalSearchUI::clear(&global_search_ui);
 
-                    let receiver = CENTRAL_COMMAND.send_background(Command::OptimizePackFile);
-                    let response = CENTRAL_COMMAND.recv_try(&receiver);
+                    let wait_text = tr("optimize_packfile_in_progress_message");
+                    let (receiver, cancel) = CENTRAL_COMMAND.send_background_cancellable(|cancel| Command::OptimizePackFile(options, cancel));
+                    let wait_dialog = new_cancellable_progress_dialog(&app_ui.main_window, &wait_text, &cancel);
+
+                    let response = CENTRAL_COMMAND.recv_try_with_progress(&receiver, |current, total, description| {
+                        update_progress_dialog(&wait_dialog, &wait_text, current, total, description);
+                    });
+                    wait_dialog.close();
+
                     match response {
                         Response::HashSetString(response) => {
                             let response = response.iter().map(|x| ContainerPath::File(x.to_owned())).collect::<Vec<ContainerPath>>();
@@ -1044,7 +1444,9 @@ impl AppUISlots {
                             pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Delete(response), DataSource::PackFile);
                             show_dialog(&app_ui.main_window, tr("optimize_packfile_success"), true);
                         }
+                        Response::Cancelled => {},
                         Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                        Response::ErrorKind(kind, message) => AppUI::show_error_kind_dialog(&app_ui, kind, &message),
                         _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
                     }
 
@@ -1119,7 +1521,7 @@ impl AppUISlots {
                         let receiver = CENTRAL_COMMAND.send_background(Command::CleanAndSavePackFileAs(path));
                         let response = CENTRAL_COMMAND.recv_try(&receiver);
                         match response {
-                            Response::ContainerInfo(pack_file_info) => {
+                            Response::ContainerInfoPackRescueReportPathBuf(pack_file_info, report, report_path) => {
                                 let mut build_data = BuildData::new();
                                 build_data.editable = true;
                                 pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Build(build_data), DataSource::PackFile);
@@ -1131,8 +1533,11 @@ impl AppUISlots {
 
                                 UI_STATE.set_operational_mode(&app_ui, None);
                                 UI_STATE.set_is_modified(false, &app_ui, &pack_file_contents_ui);
+
+                                AppUI::rescue_report_dialog(&app_ui, &report, &report_path);
                             }
                             Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                            Response::ErrorKind(kind, message) => AppUI::show_error_kind_dialog(&app_ui, kind, &message),
 
                             // In ANY other situation, it's a message problem.
                             _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
@@ -1145,6 +1550,297 @@ impl AppUISlots {
             }
         ));
 
+        // What happens when we trigger the "Verify Integrity" action.
+        let special_stuff_verify_integrity = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Verify Integrity` By Slot");
+
+                app_ui.toggle_main_window(false);
+
+                // Make sure the backend has the latest version of the open files before checking them.
+                if let Err(error) = AppUI::back_to_back_end_all(&app_ui, &pack_file_contents_ui) {
+                    app_ui.toggle_main_window(true);
+                    return show_dialog(&app_ui.main_window, error, false);
+                }
+
+                let receiver = CENTRAL_COMMAND.send_background(Command::VerifyPackIntegrity);
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::BTreeMapStringVecString(report) => {
+                        if report.is_empty() {
+                            show_dialog(&app_ui.main_window, tr("verify_integrity_success"), true);
+                        } else {
+                            let dialog = QDialog::new_1a(&app_ui.main_window);
+                            dialog.set_window_title(&qtr("verify_integrity_title"));
+                            dialog.set_modal(true);
+
+                            // Group the broken paths by the reason they failed.
+                            let mut text = String::new();
+                            for (reason, paths) in &report {
+                                text.push_str(&format!("<h3>{} ({})</h3><ul>", tr(reason), paths.len()));
+                                for path in paths {
+                                    text.push_str(&format!("<li>{}</li>", path));
+                                }
+                                text.push_str("</ul>");
+                            }
+
+                            let main_grid = create_grid_layout(dialog.static_upcast());
+                            let report_text_edit = QTextEdit::from_q_widget(&dialog);
+                            report_text_edit.set_read_only(true);
+                            report_text_edit.set_html(&QString::from_std_str(text));
+                            main_grid.add_widget_5a(&report_text_edit, 0, 0, 1, 1);
+
+                            dialog.resize_2a(800, 600);
+                            dialog.exec();
+                        }
+                    }
+                    Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+
+                app_ui.toggle_main_window(true);
+            }
+        ));
+
+        // What happens when we trigger the "Compare Packs" action.
+        let special_stuff_compare_packs = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui,
+            diagnostics_ui,
+            dependencies_ui,
+            references_ui => move |_| {
+                info!("Triggering `Compare Packs` By Slot");
+
+                let file_dialog = QFileDialog::from_q_widget_q_string(
+                    &app_ui.main_window,
+                    &qtr("compare_packs_select"),
+                );
+                file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
+                file_dialog.set_file_mode(FileMode::ExistingFile);
+
+                // Run it and expect a response (1 => Accept, 0 => Cancel).
+                if file_dialog.exec() == 1 {
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+
+                    app_ui.toggle_main_window(false);
+
+                    // Make sure the backend has the latest version of the open files before comparing them.
+                    if let Err(error) = AppUI::back_to_back_end_all(&app_ui, &pack_file_contents_ui) {
+                        app_ui.toggle_main_window(true);
+                        return show_dialog(&app_ui.main_window, error, false);
+                    }
+
+                    let receiver = CENTRAL_COMMAND.send_background(Command::ComparePacks(path));
+                    let response = CENTRAL_COMMAND.recv_try(&receiver);
+                    app_ui.toggle_main_window(true);
+
+                    match response {
+                        Response::PackDiff(diff) => AppUI::compare_packs_dialog(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui, &diff),
+                        Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+            }
+        ));
+
+        // What happens when we trigger the "Sign Pack" action.
+        let special_stuff_sign_pack = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui => move |_| {
+                info!("Triggering `Sign Pack` By Slot");
+
+                let file_dialog = QFileDialog::from_q_widget_q_string(
+                    &app_ui.main_window,
+                    &qtr("sign_pack_select"),
+                );
+                file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
+                file_dialog.set_file_mode(FileMode::ExistingFile);
+
+                // Run it and expect a response (1 => Accept, 0 => Cancel).
+                if file_dialog.exec() == 1 {
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+
+                    app_ui.toggle_main_window(false);
+                    let receiver = CENTRAL_COMMAND.send_background(Command::SignPack(path));
+                    let response = CENTRAL_COMMAND.recv_try(&receiver);
+                    app_ui.toggle_main_window(true);
+
+                    match response {
+                        Response::PathBuf(signature_path) => {
+                            let public_key_path = pack_signing_key_path().map(|path| path.with_extension("pub").to_string_lossy().to_string()).unwrap_or_default();
+                            show_dialog(&app_ui.main_window, tre("sign_pack_success", &[&signature_path.to_string_lossy(), &public_key_path]), true);
+                        }
+                        Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+            }
+        ));
+
+        // What happens when we trigger the "Verify Pack Signature" action.
+        let special_stuff_verify_pack_signature = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui => move |_| {
+                info!("Triggering `Verify Pack Signature` By Slot");
+
+                let file_dialog = QFileDialog::from_q_widget_q_string(
+                    &app_ui.main_window,
+                    &qtr("verify_pack_signature_select"),
+                );
+                file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
+                file_dialog.set_file_mode(FileMode::ExistingFile);
+
+                // Run it and expect a response (1 => Accept, 0 => Cancel).
+                if file_dialog.exec() == 1 {
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+
+                    let file_dialog = QFileDialog::from_q_widget_q_string(
+                        &app_ui.main_window,
+                        &qtr("verify_pack_signature_select_public_key"),
+                    );
+                    file_dialog.set_name_filter(&QString::from_std_str("Public Keys (*.pub)"));
+                    file_dialog.set_file_mode(FileMode::ExistingFile);
+
+                    if file_dialog.exec() == 1 {
+                        let public_key_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+
+                        app_ui.toggle_main_window(false);
+                        let receiver = CENTRAL_COMMAND.send_background(Command::VerifyPackSignature(path, public_key_path));
+                        let response = CENTRAL_COMMAND.recv_try(&receiver);
+                        app_ui.toggle_main_window(true);
+
+                        match response {
+                            Response::Success => show_dialog(&app_ui.main_window, tr("verify_pack_signature_success"), true),
+                            Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                        }
+                    }
+                }
+            }
+        ));
+
+        // What happens when we trigger the "Export Locs to PO" action.
+        let special_stuff_translation_export_po = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Export Locs to PO` By Slot");
+
+                if let Some(language) = AppUI::po_language_dialog(&app_ui) {
+                    let file_dialog = QFileDialog::from_q_widget_q_string(
+                        &app_ui.main_window,
+                        &qtr("translation_export_po_select"),
+                    );
+                    file_dialog.set_accept_mode(qt_widgets::q_file_dialog::AcceptMode::AcceptSave);
+                    file_dialog.set_name_filter(&QString::from_std_str("PO Files (*.po)"));
+                    file_dialog.set_confirm_overwrite(true);
+                    file_dialog.set_default_suffix(&QString::from_std_str("po"));
+
+                    // Run it and expect a response (1 => Accept, 0 => Cancel).
+                    if file_dialog.exec() == 1 {
+                        let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+
+                        app_ui.toggle_main_window(false);
+
+                        // Make sure the backend has the latest version of the open files before exporting them.
+                        if let Err(error) = AppUI::back_to_back_end_all(&app_ui, &pack_file_contents_ui) {
+                            app_ui.toggle_main_window(true);
+                            return show_dialog(&app_ui.main_window, error, false);
+                        }
+
+                        let receiver = CENTRAL_COMMAND.send_background(Command::ExportLocsToPo(path, language));
+                        let response = CENTRAL_COMMAND.recv_try(&receiver);
+                        app_ui.toggle_main_window(true);
+
+                        match response {
+                            Response::Success => show_dialog(&app_ui.main_window, tr("translation_export_po_success"), true),
+                            Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                        }
+                    }
+                }
+            }
+        ));
+
+        // What happens when we trigger the "Import Locs from PO" action.
+        let special_stuff_translation_import_po = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Import Locs from PO` By Slot");
+
+                let file_dialog = QFileDialog::from_q_widget_q_string(
+                    &app_ui.main_window,
+                    &qtr("translation_import_po_select"),
+                );
+                file_dialog.set_name_filter(&QString::from_std_str("PO Files (*.po)"));
+                file_dialog.set_file_mode(FileMode::ExistingFile);
+
+                // Run it and expect a response (1 => Accept, 0 => Cancel).
+                if file_dialog.exec() == 1 {
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+
+                    app_ui.toggle_main_window(false);
+
+                    // Make sure the backend has the latest version of the open files, so we don't lose any edit when reloading them.
+                    if let Err(error) = AppUI::back_to_back_end_all(&app_ui, &pack_file_contents_ui) {
+                        app_ui.toggle_main_window(true);
+                        return show_dialog(&app_ui.main_window, error, false);
+                    }
+
+                    let receiver = CENTRAL_COMMAND.send_background(Command::ImportLocsFromPo(path));
+                    let response = CENTRAL_COMMAND.recv_try(&receiver);
+                    app_ui.toggle_main_window(true);
+
+                    match response {
+                        Response::UsizeUsizeVecContainerPathVecContainerPath(updated, added, modified_paths, added_paths) => {
+                            if !modified_paths.is_empty() || !added_paths.is_empty() {
+
+                                // If any of the modified locs is open, reload it so it shows the new texts.
+                                for path in &modified_paths {
+                                    if let Some(file_view) = UI_STATE.set_open_packedfiles().iter_mut().find(|x| *x.get_ref_path() == path.path_raw() && x.get_data_source() == DataSource::PackFile) {
+                                        if let Err(error) = file_view.reload(path.path_raw(), &pack_file_contents_ui) {
+                                            show_dialog(&app_ui.main_window, error, false);
+                                        }
+                                    }
+                                }
+
+                                pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Modify(modified_paths), DataSource::PackFile);
+                                pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Add(added_paths), DataSource::PackFile);
+                                UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
+                            }
+
+                            show_dialog(&app_ui.main_window, tre("translation_import_po_success", &[&updated.to_string(), &added.to_string()]), true);
+                        }
+                        Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+            }
+        ));
+
+        // What happens when we trigger the "Translation Report" action.
+        let special_stuff_translation_report = SlotOfBool::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Translation Report` By Slot");
+
+                if let Some((detection, base_language)) = AppUI::translation_report_options_dialog(&app_ui) {
+
+                    // The open files need to be in the backend for the report to be accurate.
+                    if let Err(error) = AppUI::back_to_back_end_all(&app_ui, &pack_file_contents_ui) {
+                        return show_dialog(&app_ui.main_window, error, false);
+                    }
+
+                    let receiver = CENTRAL_COMMAND.send_background(Command::GetTranslationReport(detection, base_language));
+                    let response = CENTRAL_COMMAND.recv_try(&receiver);
+                    match response {
+                        Response::TranslationReport(report) => AppUI::translation_report_dialog(&app_ui, &report),
+                        Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+            }
+        ));
+
         //-----------------------------------------------//
         // `Tools` menu logic.
         //-----------------------------------------------//
@@ -1355,6 +2051,37 @@ impl AppUISlots {
             }
         ));
 
+        let debug_export_schema_patches = SlotNoArgs::new(&app_ui.main_window, clone!(
+            app_ui => move || {
+                info!("Triggering `Export Schema Patches` By Slot");
+
+                let receiver = CENTRAL_COMMAND.send_background(Command::GetSchemaPatchedTables);
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::VecString(table_names) => if table_names.is_empty() {
+                        show_dialog(&app_ui.main_window, tr("export_schema_patches_none"), false);
+                    } else {
+                        AppUI::export_schema_patches_dialog(&app_ui, &table_names);
+                    }
+                    Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+            }
+        ));
+
+        let debug_release_memory = SlotNoArgs::new(&app_ui.main_window, clone!(
+            app_ui => move || {
+                info!("Triggering `Release Memory` By Slot");
+
+                let receiver = CENTRAL_COMMAND.send_background(Command::ReleaseMemory);
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::U64(released) => show_dialog(&app_ui.main_window, tre("release_memory_success", &[&(released / 1024 / 1024).to_string()]), true),
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+            }
+        ));
+
         let debug_reload_style_sheet = SlotNoArgs::new(&app_ui.main_window, clone!(
             app_ui => move || {
                 info!("Triggering `Reload StyleSheets` By Slot");
@@ -1435,8 +2162,12 @@ impl AppUISlots {
                 info!("Triggering `Autosave` By Slot");
 
                 if !setting_bool("disable_autosaves") {
-                    let _ = CENTRAL_COMMAND.send_background(Command::TriggerBackupAutosave);
-                    log_to_status_bar(&tr("autosaving"));
+
+                    // Only autosave if something changed since the last autosave.
+                    if UI_STATE.take_is_modified_since_autosave() {
+                        let _ = CENTRAL_COMMAND.send_background(Command::TriggerBackupAutosave);
+                        log_to_status_bar(&tr("autosaving"));
+                    }
 
                     // Reset the timer.
                     let timer = setting_int("autosave_interval");
@@ -1448,6 +2179,46 @@ impl AppUISlots {
             }
         ));
 
+        // Tab session restore slot.
+        let pack_file_restore_tab_session = SlotNoArgs::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui,
+            diagnostics_ui,
+            dependencies_ui,
+            references_ui => move || {
+                info!("Triggering `Restore Tab Session` By Slot");
+                AppUI::restore_tab_session(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui);
+            }
+        ));
+
+        // What happens when we check if the dependencies finished rebuilding in the background.
+        let pending_dependencies_check = SlotNoArgs::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            diagnostics_ui,
+            dependencies_ui => move || {
+                AppUI::check_pending_dependencies(&app_ui, &pack_file_contents_ui, &diagnostics_ui, &dependencies_ui);
+            }
+        ));
+
+        // What happens when we click one of the links of the "Pack changed on disk" banner.
+        let pack_changed_on_disk_banner_link = SlotOfQString::new(&app_ui.main_window, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui,
+            diagnostics_ui => move |link| {
+                if link.to_std_string() == "reload" {
+                    info!("Triggering `Reload Pack` By Slot");
+                    if AppUI::are_you_sure(&app_ui, false) {
+                        AppUI::reload_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui);
+                    }
+                } else {
+                    app_ui.pack_changed_on_disk_banner().hide();
+                }
+            }
+        ));
+
         // When we want to show the context menu.
         let tab_bar_packed_file_context_menu_show = SlotOfQPoint::new(&app_ui.main_window, clone!(
             app_ui => move |_| {
@@ -1618,13 +2389,21 @@ impl AppUISlots {
             // `PackFile` menu slots.
             //-----------------------------------------------//
             packfile_open_menu,
+            packfile_open_recent_context_menu,
+            packfile_open_recent_show_all_games,
             packfile_new_packfile,
             packfile_open_packfile,
             packfile_save_packfile,
             packfile_save_packfile_as,
+            packfile_restore_autosave,
             packfile_install,
             packfile_uninstall,
+            packfile_notes,
+            packfile_statistics,
+            packfile_check_conflicts,
+            packfile_row_annotations,
             packfile_load_all_ca_packfiles,
+            packfile_load_ca_packfiles_filtered,
             packfile_change_packfile_type,
             packfile_index_includes_timestamp,
             packfile_data_is_compressed,
@@ -1650,14 +2429,18 @@ impl AppUISlots {
             view_toggle_diagnostics_panel,
             view_toggle_dependencies_panel,
             view_toggle_references_panel,
+            view_toggle_modified_column,
 
             //-----------------------------------------------//
             // `Game Selected` menu slots.
             //-----------------------------------------------//
             game_selected_launch_game,
+            game_selected_launch_game_with_current_mod,
+            game_selected_restore_mod_list,
             game_selected_open_game_data_folder,
             game_selected_open_game_assembly_kit_folder,
             game_selected_open_config_folder,
+            game_selected_schema_browser,
             change_game_selected,
 
             //-----------------------------------------------//
@@ -1666,7 +2449,15 @@ impl AppUISlots {
             special_stuff_generate_dependencies_cache,
             special_stuff_optimize_packfile,
             special_stuff_patch_siege_ai,
+            special_stuff_refresh_dependencies_cache,
             special_stuff_rescue_packfile,
+            special_stuff_verify_integrity,
+            special_stuff_compare_packs,
+            special_stuff_sign_pack,
+            special_stuff_verify_pack_signature,
+            special_stuff_translation_export_po,
+            special_stuff_translation_import_po,
+            special_stuff_translation_report,
 
             //-----------------------------------------------//
             // `Tools` menu slots.
@@ -1689,6 +2480,8 @@ impl AppUISlots {
             //-----------------------------------------------//
             debug_update_current_schema_from_asskit,
             debug_import_schema_patch,
+            debug_export_schema_patches,
+            debug_release_memory,
             debug_reload_style_sheet,
 
             //-----------------------------------------------//
@@ -1702,6 +2495,9 @@ impl AppUISlots {
             // `Generic` slots.
             //-----------------------------------------------//
             pack_file_backup_autosave,
+            pack_file_restore_tab_session,
+            pending_dependencies_check,
+            pack_changed_on_disk_banner_link,
 
             tab_bar_packed_file_context_menu_show,
             tab_bar_packed_file_close,
@@ -29,7 +29,12 @@ pub unsafe fn set_tips(app_ui: &Rc<AppUI>) {
     app_ui.packfile_save_packfile_as.set_status_tip(&qtr("tt_packfile_save_packfile_as"));
     app_ui.packfile_install.set_status_tip(&qtr("tt_packfile_install"));
     app_ui.packfile_uninstall.set_status_tip(&qtr("tt_packfile_uninstall"));
+    app_ui.packfile_notes.set_status_tip(&qtr("tt_packfile_notes"));
+    app_ui.packfile_statistics.set_status_tip(&qtr("tt_packfile_statistics"));
+    app_ui.packfile_check_conflicts.set_status_tip(&qtr("tt_packfile_check_conflicts"));
+    app_ui.packfile_row_annotations.set_status_tip(&qtr("tt_packfile_row_annotations"));
     app_ui.packfile_load_all_ca_packfiles.set_status_tip(&qtr("tt_packfile_load_all_ca_packfiles"));
+    app_ui.packfile_load_ca_packfiles_filtered.set_status_tip(&qtr("tt_packfile_load_ca_packfiles_filtered"));
     app_ui.packfile_preferences.set_status_tip(&qtr("tt_packfile_preferences"));
     app_ui.packfile_quit.set_status_tip(&qtr("tt_packfile_quit"));
 
@@ -58,9 +63,12 @@ pub unsafe fn set_tips(app_ui: &Rc<AppUI>) {
     // `Game Selected` menu tips.
     //-----------------------------------------------//
     app_ui.game_selected_launch_game.set_status_tip(&qtr("tt_game_selected_launch_game"));
+    app_ui.game_selected_launch_game_with_current_mod.set_status_tip(&qtr("tt_game_selected_launch_game_with_current_mod"));
+    app_ui.game_selected_restore_mod_list.set_status_tip(&qtr("tt_game_selected_restore_mod_list"));
     app_ui.game_selected_open_game_data_folder.set_status_tip(&qtr("tt_game_selected_open_game_data_folder"));
     app_ui.game_selected_open_game_assembly_kit_folder.set_status_tip(&qtr("tt_game_selected_open_game_assembly_kit_folder"));
     app_ui.game_selected_open_config_folder.set_status_tip(&qtr("tt_game_selected_open_config_folder"));
+    app_ui.game_selected_schema_browser.set_status_tip(&qtr("tt_game_selected_schema_browser"));
 
     app_ui.game_selected_warhammer_3.set_status_tip(&qtr("tt_game_selected_warhammer_3"));
     app_ui.game_selected_troy.set_status_tip(&qtr("tt_game_selected_troy"));
@@ -106,6 +114,7 @@ pub unsafe fn set_tips(app_ui: &Rc<AppUI>) {
     app_ui.special_stuff_nap_optimize_packfile.set_status_tip(&optimize_packfile);
     app_ui.special_stuff_emp_generate_dependencies_cache.set_status_tip(&generate_dependencies_cache);
     app_ui.special_stuff_emp_optimize_packfile.set_status_tip(&optimize_packfile);
+    app_ui.special_stuff_translation_report.set_status_tip(&qtr("tt_special_stuff_translation_report"));
 
     //-----------------------------------------------//
     // `About` menu tips.
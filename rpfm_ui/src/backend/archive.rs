@@ -0,0 +1,129 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to extract compressed archives (zip and, if enabled, 7z) so their contents can be added to a Pack.
+!*/
+
+use anyhow::{anyhow, Result};
+
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, copy, Read};
+use std::path::Path;
+
+//-------------------------------------------------------------------------------//
+//                             Extra functions
+//-------------------------------------------------------------------------------//
+
+/// This function normalizes the path of an entry within an archive to a relative path using `/` as separator.
+///
+/// Returns `None` if the entry points to the root of the archive, and an error if it tries to escape it with `..` or uses an absolute path.
+pub fn normalize_archive_entry_path(entry_name: &str) -> Result<Option<String>> {
+    let entry_name = entry_name.replace('\\', "/");
+    let mut components = vec![];
+    for component in entry_name.split('/') {
+        match component {
+            "" | "." => continue,
+            ".." => return Err(anyhow!("The archive contains an entry trying to escape its folder: {}. Import aborted.", entry_name)),
+            _ => components.push(component),
+        }
+    }
+
+    // Windows drive letters (C:) would turn the path into an absolute one.
+    if components.first().map(|component| component.contains(':')).unwrap_or(false) {
+        return Err(anyhow!("The archive contains an entry with an absolute path: {}. Import aborted.", entry_name));
+    }
+
+    if components.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(components.join("/")))
+    }
+}
+
+/// This function extracts the archive at `source_path` into the `destination_path` folder.
+///
+/// Supported archives are zip and, if the `support_7z` feature is enabled, 7z. Directory entries and empty files are
+/// recreated as they are. If any entry has an invalid path, nothing is extracted and an error is returned.
+pub fn extract_archive(source_path: &Path, destination_path: &Path) -> Result<()> {
+    let extension = source_path.extension().map(|extension| extension.to_string_lossy().to_lowercase()).unwrap_or_default();
+    match &*extension {
+        "zip" => extract_zip(source_path, destination_path),
+
+        #[cfg(feature = "support_7z")]
+        "7z" => extract_7z(source_path, destination_path),
+
+        _ => Err(anyhow!("Unsupported archive format: {}.", source_path.to_string_lossy())),
+    }
+}
+
+/// This function extracts a zip archive into the provided folder.
+fn extract_zip(source_path: &Path, destination_path: &Path) -> Result<()> {
+    let mut archive = zip::ZipArchive::new(BufReader::new(File::open(source_path)?))?;
+
+    // Validate all the paths before extracting anything.
+    let entries = archive.file_names()
+        .map(|name| normalize_archive_entry_path(name).map(|path| (name.to_owned(), path)))
+        .collect::<Result<Vec<_>>>()?;
+
+    for (name, path) in entries {
+        if let Some(path) = path {
+            let mut entry = archive.by_name(&name)?;
+            let is_dir = entry.is_dir();
+            extract_entry(&mut entry, is_dir, &destination_path.join(path))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// This function extracts a 7z archive into the provided folder.
+#[cfg(feature = "support_7z")]
+fn extract_7z(source_path: &Path, destination_path: &Path) -> Result<()> {
+    let mut archive = sevenz_rust::SevenZReader::open(source_path, sevenz_rust::Password::empty())?;
+
+    // Validate all the paths before extracting anything.
+    for entry in &archive.archive().files {
+        normalize_archive_entry_path(entry.name())?;
+    }
+
+    let mut error = None;
+    archive.for_each_entries(|entry, reader| {
+        if let Ok(Some(path)) = normalize_archive_entry_path(entry.name()) {
+            if let Err(extract_error) = extract_entry(reader, entry.is_directory(), &destination_path.join(path)) {
+                error = Some(extract_error);
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    })?;
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// This function writes a single archive entry to disk, creating its parent folders if needed.
+fn extract_entry<R: Read + ?Sized>(reader: &mut R, is_dir: bool, path: &Path) -> Result<()> {
+    if is_dir {
+        DirBuilder::new().recursive(true).create(path)?;
+    } else {
+        if let Some(parent) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent)?;
+        }
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        copy(reader, &mut writer)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for extracting archives.
+
+use std::fs::read;
+use std::path::Path;
+
+use super::archive::{extract_archive, normalize_archive_entry_path};
+
+#[test]
+fn test_normalize_archive_entry_path() {
+    assert_eq!(normalize_archive_entry_path("db/units_tables/data").unwrap(), Some("db/units_tables/data".to_owned()));
+    assert_eq!(normalize_archive_entry_path("db\\units_tables\\data").unwrap(), Some("db/units_tables/data".to_owned()));
+    assert_eq!(normalize_archive_entry_path("/script//./campaign/").unwrap(), Some("script/campaign".to_owned()));
+    assert_eq!(normalize_archive_entry_path("./").unwrap(), None);
+
+    assert!(normalize_archive_entry_path("../data.pack").is_err());
+    assert!(normalize_archive_entry_path("script/..\\..\\data.pack").is_err());
+    assert!(normalize_archive_entry_path("C:\\Windows\\file.txt").is_err());
+
+    // Dots within names are fine.
+    assert_eq!(normalize_archive_entry_path("ui/..skins/file..png").unwrap(), Some("ui/..skins/file..png".to_owned()));
+}
+
+#[test]
+fn test_extract_archive_zip() {
+    let destination = tempfile::tempdir().unwrap();
+    extract_archive(Path::new("../test_files/archive_import.zip"), destination.path()).unwrap();
+
+    let path = destination.path();
+    assert!(path.join("assets/empty_folder").is_dir());
+    assert!(path.join("assets/empty.txt").is_file());
+    assert!(read(path.join("assets/empty.txt")).unwrap().is_empty());
+    assert_eq!(read(path.join("text/windows_separator.txt")).unwrap(), b"separator");
+    assert_eq!(read(path.join("script/campaign/mod/archive_import.lua")).unwrap(), b"out(\"archive import\")\n");
+}
+
+#[test]
+fn test_extract_archive_zip_traversal() {
+    let destination = tempfile::tempdir().unwrap();
+    let extract_path = destination.path().join("extracted");
+
+    assert!(extract_archive(Path::new("../test_files/archive_import_traversal.zip"), &extract_path).is_err());
+
+    // Nothing must be extracted, not even the valid entries.
+    assert!(!extract_path.join("fine.txt").exists());
+    assert!(!destination.path().join("escaped.txt").exists());
+}
+
+#[test]
+fn test_extract_archive_unsupported() {
+    let destination = tempfile::tempdir().unwrap();
+    assert!(extract_archive(Path::new("../test_files/archive_import.rar"), destination.path()).is_err());
+}
@@ -19,9 +19,16 @@ use rpfm_extensions::dependencies::Dependencies;
 use rpfm_extensions::search::{GlobalSearch, SearchSource};
 
 use rpfm_lib::games::{*, pfh_file_type::PFHFileType, pfh_version::PFHVersion};
-use rpfm_lib::files::{animpack::*, Container, db::*, FileType, pack::*, RFile, video::*};
+use rpfm_lib::files::{animpack::*, Container, db::*, EncodeableExtraData, FileType, pack::*, RFile, RFileDataState, video::*};
 
-use crate::GAME_SELECTED;
+use std::path::PathBuf;
+
+use crate::locks::read_game_selected;
+use crate::settings_ui::backend::*;
+
+pub mod archive;
+
+#[cfg(test)] mod archive_test;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -54,6 +61,9 @@ pub struct ContainerInfo {
 
     /// The timestamp of the last time the PackFile was saved.
     timestamp: u64,
+
+    /// Warnings about problems found in the PackFile while reading it.
+    load_warnings: Vec<String>,
 }
 
 /// This struct represents the detailed info about the `PackedFile` we can provide to whoever request it.
@@ -64,7 +74,7 @@ pub struct RFileInfo {
     /// This is the path of the `PackedFile`.
     path: String,
 
-    /// This is the name of the `PackFile` this file belongs to.
+    /// This is the name of the `PackFile` this file belongs to. Empty if the file is not on disk.
     packfile_name: String,
 
     /// This is the ***Last Modified*** time.
@@ -85,6 +95,39 @@ pub struct RFileInfo {
     //cached_type: String,
 }
 
+/// This struct contains all the metadata we can provide about a file, for the File Info panel.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct RFileDetailedInfo {
+
+    /// Path of the file within its container.
+    path: String,
+
+    /// Type of the file.
+    file_type: FileType,
+
+    /// ***Last Modified*** time of the file, if it has one.
+    timestamp: Option<u64>,
+
+    /// How much of the file's data is currently in memory.
+    data_state: RFileDataState,
+
+    /// If the file's data is stored compressed on disk.
+    is_compressed: bool,
+
+    /// Size in bytes of the file's data as stored on disk. None if the file only exists in memory.
+    stored_size: Option<u64>,
+
+    /// Size in bytes of the file's data once loaded and decompressed. None if it couldn't be calculated.
+    data_size: Option<u64>,
+
+    /// Path of the file on disk the data was read from. For files in a Pack, that Pack. None if the file only exists in memory.
+    source_path: Option<String>,
+
+    /// If the file comes from the dependencies instead of the open Pack.
+    is_from_dependencies: bool,
+}
+
 /// This struct represents the detailed info about the `PackedFile` we can provide to whoever request it.
 #[derive(Clone, Debug, Default, Getters)]
 #[getset(get = "pub")]
@@ -152,6 +195,7 @@ impl From<&Pack> for ContainerInfo {
             bitmask: *pack.header().bitmask(),
             timestamp: *pack.header().internal_timestamp(),
             compress: *pack.compress(),
+            load_warnings: pack.load_warnings().to_vec(),
         }
     }
 }
@@ -175,7 +219,7 @@ impl From<&RFile> for RFileInfo {
         //else { format!("{:?}", PackedFileType::from(rfile.get_ref_decoded())) };
         Self {
             path: rfile.path_in_container_raw().to_owned(),
-            packfile_name: rfile.file_name().unwrap().to_string(),
+            packfile_name: rfile.container_name().unwrap_or_default().to_owned(),
             timestamp: rfile.timestamp(),
             file_type: rfile.file_type(),
             //is_compressed: rfile.get_ref_raw().get_compression_state(),
@@ -186,6 +230,33 @@ impl From<&RFile> for RFileInfo {
     }
 }
 
+impl RFileDetailedInfo {
+
+    /// This function gets all the info we can about the provided file.
+    ///
+    /// If the size of the data cannot be known without loading it, it's calculated on a copy of the file, so the file itself is left as it is.
+    pub fn new(rfile: &RFile, is_from_dependencies: bool) -> Self {
+        let data_size = rfile.data_size().or_else(|| rfile.clone()
+            .encode(&Some(EncodeableExtraData::default()), false, false, true)
+            .ok()
+            .flatten()
+            .map(|data| data.len() as u64)
+        );
+
+        Self {
+            path: rfile.path_in_container_raw().to_owned(),
+            file_type: rfile.file_type(),
+            timestamp: rfile.timestamp(),
+            data_state: rfile.data_state(),
+            is_compressed: rfile.is_stored_compressed(),
+            stored_size: rfile.stored_size(),
+            data_size,
+            source_path: rfile.source_path().map(|path| path.to_owned()),
+            is_from_dependencies,
+        }
+    }
+}
+
 impl From<&Video> for VideoInfo {
     fn from(video: &Video) -> Self {
         Self {
@@ -202,7 +273,7 @@ impl From<&Video> for VideoInfo {
 
 impl From<&Dependencies> for DependenciesInfo {
     fn from(dependencies: &Dependencies) -> Self {
-        let table_name_logic = GAME_SELECTED.read().unwrap().vanilla_db_table_name_logic();
+        let table_name_logic = read_game_selected().vanilla_db_table_name_logic();
 
         let asskit_tables = dependencies.asskit_only_db_tables().iter().map(|(_, table)| {
             let table_name = match table_name_logic {
@@ -234,7 +305,7 @@ impl RFileInfo {
         if global_search.search_on_texts { types.push(FileType::Text); }
 
         // Only return info of stuff on the local Pack.
-        if global_search.source == SearchSource::Pack {
+        if global_search.source == SearchSource::Pack || global_search.source == SearchSource::All {
             pack.files_by_type(&types).iter().map(|x| From::from(*x)).collect()
         } else {
             vec![]
@@ -258,3 +329,30 @@ impl RFileInfo {
         }
     }
 }
+
+//-------------------------------------------------------------------------------//
+//                             Extra functions
+//-------------------------------------------------------------------------------//
+
+/// This function returns the path of the dependency Pack with the provided name, if it's either in the data folder
+/// or in the MyMod folder of the game selected.
+pub fn dependency_pack_path(pack_name: &str) -> Option<PathBuf> {
+    if pack_name.is_empty() {
+        return None;
+    }
+
+    let game_selected = read_game_selected();
+    let mut folders = vec![];
+    if let Ok(data_path) = game_selected.data_path(&setting_path(&game_selected.game_key_name())) {
+        folders.push(data_path);
+    }
+
+    let mymods_base_path = setting_path(MYMOD_BASE_PATH);
+    if mymods_base_path.is_dir() {
+        folders.push(mymods_base_path.join(game_selected.game_key_name()));
+    }
+
+    folders.iter()
+        .map(|folder| folder.join(pack_name))
+        .find(|path| path.is_file())
+}
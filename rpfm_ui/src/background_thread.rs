@@ -15,42 +15,56 @@ Basically, this does the heavy load of the program.
 !*/
 
 use anyhow::anyhow;
-use crossbeam::channel::Sender;
+use crossbeam::channel::{Sender, unbounded};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher, event::ModifyKind};
 use open::that;
 use rayon::prelude::*;
 
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::env::temp_dir;
-use std::fs::{DirBuilder, File};
+use std::fs::{DirBuilder, File, remove_dir_all};
 use std::io::{BufReader, BufWriter, Cursor, Read, Write};
-use std::path::PathBuf;
-use std::sync::{Arc, atomic::Ordering, RwLock};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, atomic::{AtomicBool, Ordering}, RwLock, TryLockError};
 use std::thread;
+use std::time::Instant;
+use time::OffsetDateTime;
 
+use rpfm_extensions::cascade_edition::CascadeEdition;
+use rpfm_extensions::conflicts::PackConflicts;
+use rpfm_extensions::deep_clone::DeepClone;
 use rpfm_extensions::dependencies::Dependencies;
-use rpfm_extensions::diagnostics::Diagnostics;
-use rpfm_extensions::optimizer::OptimizableContainer;
-
-use rpfm_lib::files::{animpack::AnimPack, Container, ContainerPath, db::DB, DecodeableExtraData, EncodeableExtraData, FileType, loc::Loc, pack::*, RFile, RFileDecoded, text::*};
-use rpfm_lib::games::{GameInfo, LUA_REPO, LUA_BRANCH, LUA_REMOTE, pfh_file_type::PFHFileType};
-use rpfm_lib::integrations::{assembly_kit::*, git::*, log::*};
+use rpfm_extensions::diagnostics::{DiagnosticReport, Diagnostics};
+use rpfm_extensions::journal::{ContainerJournal, JournalChanges};
+use rpfm_extensions::lua;
+use rpfm_extensions::optimizer::{OptimizableContainer, OptimizerOptions};
+use rpfm_extensions::statistics::PackStatistics;
+use rpfm_extensions::table_diff::TableDataDiff;
+use rpfm_extensions::template::Template;
+use rpfm_extensions::translation::{Po, report::TranslationReport};
+
+use rpfm_lib::error::RLibError;
+use rpfm_lib::files::{animpack::AnimPack, Container, ContainerPath, db::DB, DecodeableExtraData, Encodeable, EncodeableExtraData, FileType, loc::{self, Loc}, pack::*, RFile, RFileDecoded, text::*};
+use rpfm_lib::games::{GameInfo, LUA_REPO, LUA_BRANCH, LUA_REMOTE, pfh_file_type::PFHFileType, workshop::WorkshopPack};
+use rpfm_lib::integrations::{assembly_kit::*, git::*, log::*, signing::*};
 use rpfm_lib::schema::*;
 use rpfm_lib::tips::*;
 use rpfm_lib::utils::*;
 
 use crate::app_ui::NewPackedFile;
-use crate::{backend::*, SENTRY_GUARD};
+use crate::AUTOSAVE_DATE_FORMAT;
+use crate::backend::{*, archive};
+use crate::locks::{read_game_selected, read_schema, read_sentry_guard, replace_schema, write_game_selected, write_schema};
 use crate::CENTRAL_COMMAND;
-use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::communications::{CentralCommand, Command, ErrorKind, FolderTarget, NotificationLevel, Response, THREADS_COMMUNICATION_ERROR};
 use crate::FIRST_GAME_CHANGE_DONE;
-use crate::GAME_SELECTED;
 use crate::initialize_pack_settings;
 use crate::locale::tr;
 use crate::packedfile_views::DataSource;
 use crate::RPFM_PATH;
-use crate::SCHEMA;
 use crate::settings_ui::backend::*;
 use crate::SUPPORTED_GAMES;
+use crate::utils::{process_memory_usage, show_in_file_manager};
 
 /// This is the background loop that's going to be executed in a parallel thread to the UI. No UI or "Unsafe" stuff here.
 ///
@@ -67,8 +81,17 @@ pub fn background_loop() {
     let mut pack_file_decoded = Pack::default();
     let mut pack_files_decoded_extra = BTreeMap::new();
 
+    // PackFile the open one was last compared against, if any. Only the last one is kept, so comparing again releases the previous one.
+    let mut compared_pack: Option<Pack> = None;
+
+    // Journal of the operations done over the structure of the open PackFile, so they can be undone.
+    let mut container_journal = ContainerJournal::new(container_journal_memory_cap());
+
+    // Watcher over the open PackFile, with the path it's watching, to notice when other programs change it on disk.
+    let mut pack_watcher: Option<(PathBuf, RecommendedWatcher)> = None;
+
     // Preload the default game's dependencies.
-    let mut dependencies = Arc::new(RwLock::new(Dependencies::default()));
+    let dependencies = Arc::new(RwLock::new(Dependencies::default()));
 
     // Initalize background sentry guard. This should, in theory, register crashes on the background thread.
     let _sentry_guard = Logger::init(&error_path().unwrap_or_else(|_| PathBuf::from(".")), true, false);
@@ -85,22 +108,54 @@ pub fn background_loop() {
         // Wait until you get something through the channel. This hangs the thread until we got something,
         // so it doesn't use processing power until we send it a message.
         let (sender, response): (Sender<Response>, Command) = CENTRAL_COMMAND.recv_background();
+
+        // Mark the command as finished when we're done with it, no matter how we leave this iteration.
+        let _command_guard = BackgroundCommandGuard;
+
+        // Heavy commands can leave a lot of data in memory, so we check the memory ceiling after them.
+        // Dependency rebuilds are not here because they finish in their own thread, after the command is done.
+        let is_heavy_command = matches!(response,
+            Command::OpenPackFiles(..) |
+            Command::OpenPackExtra(..) |
+            Command::ComparePacks(..) |
+            Command::LoadAllCAPackFiles |
+            Command::LoadCAPackFilesFiltered(..) |
+            Command::GlobalSearch(..) |
+            Command::GlobalSearchReplaceAll(..) |
+            Command::DiagnosticsCheck(..)
+        );
+
+        // Our own saves are not external changes, so we stop watching the PackFile while we write it.
+        if matches!(response, Command::SavePackFile | Command::SavePackFileAs(_) | Command::CleanAndSavePackFileAs(_)) {
+            pack_watcher = None;
+        }
+
         match response {
 
             // Command to close the thread.
-            Command::Exit => return,
+            Command::Exit => {
+                external_temp_files_clean(&pack_file_decoded);
+                return
+            }
 
             // In case we want to reset the PackFile to his original state (dummy)...
-            Command::ResetPackFile => pack_file_decoded = Pack::default(),
+            Command::ResetPackFile => {
+                external_temp_files_clean(&pack_file_decoded);
+                pack_file_decoded = Pack::default();
+                compared_pack = None;
+                container_journal.clear();
+            }
 
             // In case we want to remove a Secondary Packfile from memory...
             Command::RemovePackFileExtra(path) => { pack_files_decoded_extra.remove(&path); },
 
             // In case we want to create a "New PackFile"...
             Command::NewPackFile => {
-                let game_selected = GAME_SELECTED.read().unwrap();
+                let game_selected = read_game_selected();
                 let pack_version = game_selected.pfh_version_by_file_type(PFHFileType::Mod);
+                external_temp_files_clean(&pack_file_decoded);
                 pack_file_decoded = Pack::new_with_name_and_version("unknown.pack", pack_version);
+                container_journal.clear();
                 pack_file_decoded.set_settings(initialize_pack_settings());
 
                 if let Some(version_number) = game_selected.game_version_number(&setting_path(&game_selected.game_key_name())) {
@@ -112,10 +167,18 @@ pub fn background_loop() {
             Command::OpenPackFiles(paths) => {
                 match Pack::read_and_merge(&paths, setting_bool("use_lazy_loading"), false) {
                     Ok(pack) => {
+                        external_temp_files_clean(&pack_file_decoded);
                         pack_file_decoded = pack;
+                        container_journal.clear();
+
+                        // Packs are read without knowing their game, so apply the game-specific file types here.
+                        let game = read_game_selected();
+                        if !game.file_type_overrides().is_empty() {
+                            let _ = pack_file_decoded.guess_file_types_for_game(&game);
+                        }
 
                         // Force decoding of table/locs, so they're in memory for the diagnostics to work.
-                        if let Some(ref schema) = *SCHEMA.read().unwrap() {
+                        if let Some(ref schema) = *read_schema() {
                             let mut decode_extra_data = DecodeableExtraData::default();
                             decode_extra_data.set_schema(Some(schema));
                             let extra_data = Some(decode_extra_data);
@@ -126,9 +189,17 @@ pub fn background_loop() {
                             });
                         }
 
+                        notify(NotificationLevel::Info, format!("Opened {} ({} files).", paths.iter().map(|path| path.to_string_lossy()).collect::<Vec<_>>().join(", "), pack_file_decoded.files().len()));
+                        for warning in pack_file_decoded.load_warnings() {
+                            warn!("{}", warning);
+                            notify(NotificationLevel::Warning, warning.to_owned());
+                        }
                         CentralCommand::send_back(&sender, Response::ContainerInfo(ContainerInfo::from(&pack_file_decoded)));
                     }
-                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    Err(error) => {
+                        notify(NotificationLevel::Error, format!("Failed to open {}: {}", paths.iter().map(|path| path.to_string_lossy()).collect::<Vec<_>>().join(", "), error));
+                        CentralCommand::send_back(&sender, Response::error_kind(error))
+                    }
                 }
             }
 
@@ -146,12 +217,112 @@ pub fn background_loop() {
                 }
             }
 
+            // In case we want to compare the open PackFile against another one...
+            // The Pack is always reloaded, so we don't compare against an outdated version of it.
+            Command::ComparePacks(path) => {
+                match Pack::read_and_merge(&[path.to_path_buf()], true, false) {
+                    Ok(mut pack) => match pack_file_decoded.diff(&mut pack, read_schema().as_ref()) {
+                        Ok(diff) => {
+                            compared_pack = Some(pack);
+                            CentralCommand::send_back(&sender, Response::PackDiff(diff));
+                        }
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
+            // In case we want to sign a Pack on disk...
+            // The Pack is read from disk, so we sign exactly what's going to be distributed.
+            Command::SignPack(path) => {
+                match pack_signing_key_path().and_then(|key_path| signing_key(&key_path).map_err(From::from)) {
+                    Ok(key) => match Pack::read_and_merge(&[path.to_path_buf()], true, false) {
+                        Ok(mut pack) => match PackSignature::sign(&mut pack, &key).and_then(|signature| signature.save(&path)) {
+                            Ok(signature_path) => CentralCommand::send_back(&sender, Response::PathBuf(signature_path)),
+                            Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                        }
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            // In case we want to export the Loc files of the open Pack for translators...
+            Command::ExportLocsToPo(path, language) => {
+                match Po::from_pack(&mut pack_file_decoded) {
+                    Ok(mut po) => {
+                        po.set_language(language);
+                        match std::fs::write(&path, po.to_po_string()) {
+                            Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                            Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                        }
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
+            // In case we want to import a translation into the Loc files of the open Pack...
+            // Keys not found in the Pack go to a new Loc named after the PO file.
+            Command::ImportLocsFromPo(path) => {
+                match std::fs::read_to_string(&path).map_err(From::from).and_then(|data| Po::from_po_str(&data)) {
+                    Ok(po) => {
+                        let new_loc_path = format!("text/db/{}{}", path.file_stem().unwrap_or_default().to_string_lossy(), loc::EXTENSION);
+                        match po.import_to_pack(&mut pack_file_decoded, &new_loc_path) {
+                            Ok((updated, added, modified_paths, added_paths)) => CentralCommand::send_back(&sender, Response::UsizeUsizeVecContainerPathVecContainerPath(updated, added, modified_paths, added_paths)),
+                            Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                        }
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
+            // In case we want to check how complete the translations of the open Pack are...
+            Command::GetTranslationReport(detection, base_language) => {
+                let keys_to_ignore = pack_file_decoded.settings().translation_report_keys_to_ignore();
+                match TranslationReport::from_pack(&pack_file_decoded, detection, &base_language, &keys_to_ignore) {
+                    Ok(report) => CentralCommand::send_back(&sender, Response::TranslationReport(report)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
+            // In case we want to verify a Pack on disk against its signature...
+            Command::VerifyPackSignature(path, public_key_path) => {
+                match public_key(&public_key_path) {
+                    Ok(public_key) => match PackSignature::load(&PackSignature::path_for_pack(&path)) {
+                        Ok(signature) => match Pack::read_and_merge(&[path.to_path_buf()], true, false) {
+                            Ok(mut pack) => match signature.verify(&mut pack, &public_key) {
+                                Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                                Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                            }
+                            Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                        }
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
             // In case we want to "Load All CA PackFiles"...
             Command::LoadAllCAPackFiles => {
-                let game_selected = GAME_SELECTED.read().unwrap();
+                let game_selected = read_game_selected();
                 match Pack::read_and_merge_ca_packs(&game_selected, &setting_path(&game_selected.game_key_name())) {
                     Ok(pack) => {
+                        external_temp_files_clean(&pack_file_decoded);
+                        pack_file_decoded = pack;
+                        container_journal.clear();
+                        CentralCommand::send_back(&sender, Response::ContainerInfo(ContainerInfo::from(&pack_file_decoded)));
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
+            // In case we want to load only some of the CA PackFiles...
+            Command::LoadCAPackFilesFiltered(paths) => {
+                match Pack::read_and_merge_ca_packs_from_paths(&paths) {
+                    Ok(pack) => {
+                        external_temp_files_clean(&pack_file_decoded);
                         pack_file_decoded = pack;
+                        container_journal.clear();
                         CentralCommand::send_back(&sender, Response::ContainerInfo(ContainerInfo::from(&pack_file_decoded)));
                     }
                     Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
@@ -161,25 +332,79 @@ pub fn background_loop() {
             // In case we want to "Save a PackFile"...
             Command::SavePackFile => {
                 match pack_file_decoded.save(None) {
-                    Ok(_) => CentralCommand::send_back(&sender, Response::ContainerInfo(From::from(&pack_file_decoded))),
-                    Err(error) => CentralCommand::send_back(&sender, Response::Error(anyhow!("Error while trying to save the currently open PackFile: {}", error))),
+                    Ok(_) => {
+                        if setting_bool("container_undo_clear_on_save") {
+                            container_journal.clear();
+                        }
+
+                        match save_manifest_if_enabled(&pack_file_decoded) {
+                            Ok(_) => {
+                                notify(NotificationLevel::Info, format!("Saved {}.", pack_file_decoded.disk_file_path()));
+                                CentralCommand::send_back(&sender, Response::ContainerInfo(From::from(&pack_file_decoded)))
+                            }
+                            Err(error) => {
+                                notify(NotificationLevel::Warning, format!("Saved {}, but its manifest couldn't be generated: {}", pack_file_decoded.disk_file_path(), error));
+                                CentralCommand::send_back(&sender, Response::ErrorKind(ErrorKind::from_error(error.as_ref()), format!("The PackFile has been saved, but its manifest couldn't be generated: {}", error)))
+                            }
+                        }
+                    }
+                    Err(error) => {
+                        notify(NotificationLevel::Error, format!("Failed to save {}: {}", pack_file_decoded.disk_file_path(), error));
+                        CentralCommand::send_back(&sender, Response::ErrorKind(ErrorKind::from_error(&error), format!("Error while trying to save the currently open PackFile: {}", error)))
+                    }
                 }
             }
 
             // In case we want to "Save a PackFile As"...
             Command::SavePackFileAs(path) => {
                 match pack_file_decoded.save(Some(&path)) {
-                    Ok(_) => CentralCommand::send_back(&sender, Response::ContainerInfo(From::from(&pack_file_decoded))),
-                    Err(error) => CentralCommand::send_back(&sender, Response::Error(anyhow!("Error while trying to save the currently open PackFile: {}", error))),
+                    Ok(_) => {
+                        if setting_bool("container_undo_clear_on_save") {
+                            container_journal.clear();
+                        }
+
+                        match save_manifest_if_enabled(&pack_file_decoded) {
+                            Ok(_) => {
+                                notify(NotificationLevel::Info, format!("Saved {}.", pack_file_decoded.disk_file_path()));
+                                CentralCommand::send_back(&sender, Response::ContainerInfo(From::from(&pack_file_decoded)))
+                            }
+                            Err(error) => {
+                                notify(NotificationLevel::Warning, format!("Saved {}, but its manifest couldn't be generated: {}", pack_file_decoded.disk_file_path(), error));
+                                CentralCommand::send_back(&sender, Response::ErrorKind(ErrorKind::from_error(error.as_ref()), format!("The PackFile has been saved, but its manifest couldn't be generated: {}", error)))
+                            }
+                        }
+                    }
+                    Err(error) => {
+                        notify(NotificationLevel::Error, format!("Failed to save {}: {}", pack_file_decoded.disk_file_path(), error));
+                        CentralCommand::send_back(&sender, Response::ErrorKind(ErrorKind::from_error(&error), format!("Error while trying to save the currently open PackFile: {}", error)))
+                    }
                 }
             }
 
             // If you want to perform a clean&save over a PackFile...
             Command::CleanAndSavePackFileAs(path) => {
-                pack_file_decoded.clean_undecoded();
+                let mut extra_data = DecodeableExtraData::default();
+                let schema = read_schema();
+                extra_data.set_schema(schema.as_ref());
+
+                let report = pack_file_decoded.rescue(&Some(extra_data));
                 match pack_file_decoded.save(Some(&path)) {
-                    Ok(_) => CentralCommand::send_back(&sender, Response::ContainerInfo(From::from(&pack_file_decoded))),
-                    Err(error) => CentralCommand::send_back(&sender, Response::Error(anyhow!("Error while trying to save the currently open PackFile: {}", error))),
+
+                    // The report is written before the manifest, so it's kept even if the manifest fails.
+                    Ok(_) => {
+                        if setting_bool("container_undo_clear_on_save") {
+                            container_journal.clear();
+                        }
+
+                        match report.save(&path) {
+                            Ok(report_path) => match save_manifest_if_enabled(&pack_file_decoded) {
+                                Ok(_) => CentralCommand::send_back(&sender, Response::ContainerInfoPackRescueReportPathBuf(From::from(&pack_file_decoded), report, report_path)),
+                                Err(error) => CentralCommand::send_back(&sender, Response::ErrorKind(ErrorKind::from_error(error.as_ref()), format!("The PackFile has been saved, but its manifest couldn't be generated: {}", error))),
+                            }
+                            Err(error) => CentralCommand::send_back(&sender, Response::ErrorKind(ErrorKind::from_error(&error), format!("The PackFile has been saved, but its rescue report couldn't be written: {}", error))),
+                        }
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::ErrorKind(ErrorKind::from_error(&error), format!("Error while trying to save the currently open PackFile: {}", error))),
                 }
             }
 
@@ -214,6 +439,16 @@ pub fn background_loop() {
                 ));
             }
 
+            // In case we want to get all the metadata of a file for the File Info panel.
+            Command::GetRFileDetailedInfo(path) => {
+                let info = match pack_file_decoded.files().get(&path) {
+                    Some(file) => Some(RFileDetailedInfo::new(file, false)),
+                    None => dependencies.read().unwrap().file(&path, true, true, false).ok().map(|file| RFileDetailedInfo::new(file, true)),
+                };
+
+                CentralCommand::send_back(&sender, Response::OptionRFileDetailedInfo(info));
+            }
+
             // In case we want to get the info of more than one PackedFiles from the TreeView.
             Command::GetPackedFilesInfo(paths) => {
                 let paths = paths.iter().map(|path| ContainerPath::File(path.to_owned())).collect::<Vec<_>>();
@@ -223,13 +458,17 @@ pub fn background_loop() {
             }
 
             // In case we want to launch a global search on a `PackFile`...
-            Command::GlobalSearch(mut global_search) => {
-                let game_selected = GAME_SELECTED.read().unwrap();
-                match *SCHEMA.read().unwrap() {
+            Command::GlobalSearch(mut global_search, cancel) => {
+                let game_selected = read_game_selected();
+                match *read_schema() {
                     Some(ref schema) => {
-                        global_search.search(&game_selected, schema, &mut pack_file_decoded, &mut dependencies.write().unwrap(), &[]);
-                        let packed_files_info = RFileInfo::info_from_global_search(&global_search, &pack_file_decoded);
-                        CentralCommand::send_back(&sender, Response::GlobalSearchVecRFileInfo(global_search, packed_files_info));
+                        match global_search.search(&game_selected, schema, &mut pack_file_decoded, &mut dependencies.write().unwrap(), &[], &cancel) {
+                            Ok(_) => {
+                                let packed_files_info = RFileInfo::info_from_global_search(&global_search, &pack_file_decoded);
+                                CentralCommand::send_back(&sender, Response::GlobalSearchVecRFileInfo(global_search, packed_files_info));
+                            }
+                            Err(_) => CentralCommand::send_back(&sender, Response::Cancelled),
+                        }
                     }
                     None => CentralCommand::send_back(&sender, Response::Error(anyhow!("Schema not found. Maybe you need to download it?"))),
                 }
@@ -237,40 +476,40 @@ pub fn background_loop() {
 
             Command::SetGameSelected(game_selected, rebuild_dependencies) => {
                 info!("Setting game selected.");
-                let game_changed = GAME_SELECTED.read().unwrap().game_key_name() != game_selected || !FIRST_GAME_CHANGE_DONE.load(Ordering::SeqCst);
-                *GAME_SELECTED.write().unwrap() = SUPPORTED_GAMES.game(&game_selected).unwrap();
-                let game = GAME_SELECTED.read().unwrap();
+                let game_changed = read_game_selected().game_key_name() != game_selected || !FIRST_GAME_CHANGE_DONE.load(Ordering::SeqCst);
+                *write_game_selected() = SUPPORTED_GAMES.game(&game_selected).unwrap();
+                let game = read_game_selected();
 
                 // Optimisation: If we know we need to rebuild the whole dependencies, load them in another thread
                 // while we load the schema. That way we can speed-up the entire game-switching process.
                 //
-                // While this is fast, the rust compiler doesn't like the fact that we're moving out the dependencies,
-                // then moving them back in an if, so we need two branches of code, depending on if rebuild is true or not.
+                // The rebuild keeps going after we're done here, so the UI can be used while it finishes.
+                // Their info is sent to the UI once they're fully loaded.
                 //
                 // Branch 1: dependencies rebuilt.
                 if rebuild_dependencies {
                 info!("Branch 1.");
                     let pack_dependencies = pack_file_decoded.dependencies().to_vec();
-                    let handle = thread::spawn(move || {
-                        let game_selected = GAME_SELECTED.read().unwrap();
+                    let (schema_sender, schema_receiver) = unbounded::<()>();
+                    let dependencies_sender = sender.clone();
+                    rebuild_dependencies_in_background(&dependencies, move |dependencies| {
+                        let game_selected = *read_game_selected();
                         let game_path = setting_path(&game_selected.game_key_name());
                         let file_path = dependencies_cache_path().unwrap().join(game_selected.dependencies_cache_file_name());
                         let file_path = if game_changed { Some(&*file_path) } else { None };
-                        let _ = dependencies.write().unwrap().rebuild(&None, &pack_dependencies, file_path, &game_selected, &game_path);
-                        dependencies
+                        let _ = dependencies.rebuild(&None, &pack_dependencies, file_path, game_selected, &game_path);
+
+                        // The tables can only be decoded once the schema of the new game is loaded.
+                        let _ = schema_receiver.recv();
+                        dependencies.decode_tables(&read_schema());
+
+                        info!("Sending dependencies info after game selected change.");
+                        let _ = dependencies_sender.send(Response::DependenciesInfo(DependenciesInfo::from(&*dependencies)));
                     });
 
                     // Load the new schemas.
                     load_schemas(&sender, &mut pack_file_decoded, &game);
-
-                    // Get the dependencies that were loading in parallel and send their info to the UI.
-                    dependencies = handle.join().unwrap();
-                    let dependencies_info = DependenciesInfo::from(&*dependencies.read().unwrap());
-                    info!("Sending dependencies info after game selected change.");
-                    CentralCommand::send_back(&sender, Response::DependenciesInfo(dependencies_info));
-
-                    // Decode the dependencies tables while the UI does its own thing.
-                    dependencies.write().unwrap().decode_tables(&SCHEMA.read().unwrap());
+                    let _ = schema_sender.send(());
                 }
 
                 // Branch 2: no dependecies rebuild.
@@ -289,40 +528,64 @@ pub fn background_loop() {
                     if let Some(version_number) = game.game_version_number(&setting_path(&game.game_key_name())) {
                         pack_file_decoded.set_game_version(version_number);
                     }
+
+                    if game_changed {
+                        let _ = pack_file_decoded.guess_file_types_for_game(&game);
+                    }
                 }
                 info!("Switching game selected done.");
             }
 
             // In case we want to generate the dependencies cache for our Game Selected...
-            Command::GenerateDependenciesCache => {
-                let game_selected = GAME_SELECTED.read().unwrap();
-                let game_path = setting_path(&game_selected.game_key_name());
-                let asskit_path = assembly_kit_path().ok();
-
-                if game_path.is_dir() {
-                    match Dependencies::generate_dependencies_cache(&game_selected, &game_path, &asskit_path) {
-                        Ok(mut cache) => {
-                            let dependencies_path = dependencies_cache_path().unwrap().join(game_selected.dependencies_cache_file_name());
-                            match cache.save(&dependencies_path) {
-                                Ok(_) => {
-                                    let _ = dependencies.write().unwrap().rebuild(&SCHEMA.read().unwrap(), pack_file_decoded.dependencies(), Some(&dependencies_path), &game_selected, &game_path);
-                                    let dependencies_info = DependenciesInfo::from(&*dependencies.read().unwrap());
-                                    CentralCommand::send_back(&sender, Response::DependenciesInfo(dependencies_info));
-                                },
-                                Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
-                            }
+            Command::GenerateDependenciesCache(cancel) => {
+                if generate_dependencies_cache(&sender, &dependencies, &pack_file_decoded, &cancel) {
+                    notify(NotificationLevel::Info, "Dependencies cache generated.");
+                    let dependencies_info = DependenciesInfo::from(&*dependencies.read().unwrap());
+                    CentralCommand::send_back(&sender, Response::DependenciesInfo(dependencies_info));
+                }
+            }
+
+            // In case we want to refresh the dependencies cache, only re-reading what changed on disk...
+            Command::RefreshDependenciesCache(cancel) => {
+                let refresh = {
+                    let game_selected = read_game_selected();
+                    let game_path = setting_path(&game_selected.game_key_name());
+                    let dependencies_path = dependencies_cache_path().unwrap().join(game_selected.dependencies_cache_file_name());
+                    dependencies.write().unwrap().refresh(&read_schema(), pack_file_decoded.dependencies(), &dependencies_path, &game_selected, &game_path)
+                };
+
+                match refresh {
+                    Ok(refreshed) => {
+                        notify(NotificationLevel::Info, format!("Dependencies cache refreshed ({} Packs re-read).", refreshed.len()));
+                        let dependencies_info = DependenciesInfo::from(&*dependencies.read().unwrap());
+                        CentralCommand::send_back(&sender, Response::DependenciesInfoVecPathBuf(dependencies_info, refreshed));
+                    }
+
+                    // If the vanilla cache is missing, outdated or broken, fall back to regenerating it.
+                    Err(RLibError::DependenciesCacheNotGeneratedorOutOfDate) => {
+                        info!("Dependencies cache cannot be refreshed, regenerating it.");
+                        if generate_dependencies_cache(&sender, &dependencies, &pack_file_decoded, &cancel) {
+                            notify(NotificationLevel::Info, "Dependencies cache couldn't be refreshed, so it has been regenerated.");
+                            let dependencies = dependencies.read().unwrap();
+                            let refreshed = dependencies.vanilla_sources().iter()
+                                .chain(dependencies.parent_sources())
+                                .map(|source| source.path().to_path_buf())
+                                .collect();
+
+                            let dependencies_info = DependenciesInfo::from(&*dependencies);
+                            CentralCommand::send_back(&sender, Response::DependenciesInfoVecPathBuf(dependencies_info, refreshed));
                         }
-                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                     }
-                } else {
-                    CentralCommand::send_back(&sender, Response::Error(anyhow!("Game Path not configured. Go to <i>'PackFile/Preferences'</i> and configure it.")));
+
+                    // Errors in the parent Packs don't need the vanilla cache to be regenerated.
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                 }
             }
 
             // In case we want to update the Schema for our Game Selected...
             Command::UpdateCurrentSchemaFromAssKit => {
-                if let Some(ref mut schema) = *SCHEMA.write().unwrap() {
-                    let game_selected = GAME_SELECTED.read().unwrap();
+                if let Some(ref mut schema) = *write_schema() {
+                    let game_selected = read_game_selected();
                     let asskit_path = setting_path(&format!("{}_assembly_kit", game_selected.game_key_name()));
                     let schema_path = schemas_path().unwrap().join(game_selected.schema_file_name());
 
@@ -356,14 +619,31 @@ pub fn background_loop() {
             }
 
             // In case we want to optimize our PackFile...
-            Command::OptimizePackFile => {
-                if let Some(ref schema) = *SCHEMA.read().unwrap() {
-                    match pack_file_decoded.optimize(&mut dependencies.write().unwrap(), schema, setting_bool("optimize_not_renamed_packedfiles")) {
-                        Ok(paths_to_delete) => CentralCommand::send_back(&sender, Response::HashSetString(paths_to_delete)),
-                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+            Command::OptimizePackFile(options, cancel) => {
+                if let Some(ref schema) = *read_schema() {
+                    let progress = |current, total, description: &str| CentralCommand::send_back(&sender, Response::Progress(current, total, description.to_owned()));
+                    match pack_file_decoded.optimize(&mut dependencies.write().unwrap(), schema, setting_bool("optimize_not_renamed_packedfiles"), &options, &progress, &cancel) {
+                        Ok(paths_to_delete) => {
+                            notify(NotificationLevel::Info, format!("Optimizer finished. {} files removed.", paths_to_delete.len()));
+                            CentralCommand::send_back(&sender, Response::HashSetString(paths_to_delete))
+                        }
+                        Err(RLibError::OperationCancelled) => CentralCommand::send_back(&sender, Response::Cancelled),
+                        Err(error) => CentralCommand::send_back(&sender, Response::error_kind(error)),
                     }
                 } else {
-                    CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected.")));
+                    CentralCommand::send_back(&sender, Response::ErrorKind(ErrorKind::SchemaMissing, "There is no Schema for the Game Selected.".to_owned()));
+                }
+            }
+
+            // In case we want to know what optimizing our PackFile would do...
+            Command::OptimizePackFileReport(options) => {
+                if let Some(ref schema) = *read_schema() {
+                    match pack_file_decoded.optimize_report(&dependencies.read().unwrap(), schema, setting_bool("optimize_not_renamed_packedfiles"), &options) {
+                        Ok(report) => CentralCommand::send_back(&sender, Response::OptimizerReport(report)),
+                        Err(error) => CentralCommand::send_back(&sender, Response::error_kind(error)),
+                    }
+                } else {
+                    CentralCommand::send_back(&sender, Response::ErrorKind(ErrorKind::SchemaMissing, "There is no Schema for the Game Selected.".to_owned()));
                 }
             }
 
@@ -385,21 +665,46 @@ pub fn background_loop() {
                 pack_file_decoded.set_bitmask(bitmask);
             },
 
+            // In case we want to know what header flags are not valid for the currently open PackFile...
+            Command::GetPFHFlagsRestrictions => {
+                let restrictions = Pack::pfh_flags_restrictions(&read_game_selected(), pack_file_decoded.pfh_version(), pack_file_decoded.pfh_file_type());
+                CentralCommand::send_back(&sender, Response::VecPFHFlagsString(restrictions));
+            },
+
             // In case we want to compress/decompress the PackedFiles of the currently open PackFile...
             Command::ChangeDataIsCompressed(state) => { pack_file_decoded.set_compress(state); },
 
             // In case we want to get the path of the currently open `PackFile`.
             Command::GetPackFilePath => CentralCommand::send_back(&sender, Response::PathBuf(PathBuf::from(pack_file_decoded.disk_file_path()))),
 
-            // In case we want to get the Dependency PackFiles of our PackFile...
-            Command::GetDependencyPackFilesList => CentralCommand::send_back(&sender, Response::VecString(pack_file_decoded.dependencies().to_vec())),
+            // In case we want the game to load our PackFile and its dependencies next time it starts...
+            Command::WriteModListForOpenPack => match write_mod_list(&pack_file_decoded) {
+                Ok(path) => {
+                    notify(NotificationLevel::Info, format!("Mod list written to {}.", path.to_string_lossy()));
+                    CentralCommand::send_back(&sender, Response::PathBuf(path));
+                }
+                Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+            },
+
+            // In case we want to get the Dependency PackFiles of our PackFile, and where they are on disk...
+            Command::GetDependencyPackFilesList => {
+                let packs = pack_file_decoded.dependencies().iter()
+                    .map(|pack_name| (pack_name.to_owned(), dependency_pack_path(pack_name)))
+                    .collect();
+                CentralCommand::send_back(&sender, Response::VecStringOptionPathBuf(packs));
+            },
 
             // In case we want to set the Dependency PackFiles of our PackFile...
             Command::SetDependencyPackFilesList(packs) => { pack_file_decoded.set_dependencies(packs); },
 
             // In case we want to check if there is a Dependency Database loaded...
+            // Dependencies still being rebuilt are reported as not loaded.
             Command::IsThereADependencyDatabase(include_asskit) => {
-                let are_dependencies_loaded = dependencies.read().unwrap().is_vanilla_data_loaded(include_asskit);
+                let are_dependencies_loaded = match dependencies.try_read() {
+                    Ok(dependencies) => dependencies.is_vanilla_data_loaded(include_asskit),
+                    Err(TryLockError::WouldBlock) => false,
+                    Err(TryLockError::Poisoned(error)) => panic!("{}", error),
+                };
                 CentralCommand::send_back(&sender, Response::Bool(are_dependencies_loaded))
             },
 
@@ -411,7 +716,7 @@ pub fn background_loop() {
                         RFileDecoded::AnimPack(file)
                     },
                     NewPackedFile::DB(_, table, version) => {
-                        if let Some(ref schema) = *SCHEMA.read().unwrap() {
+                        if let Some(ref schema) = *read_schema() {
                             match schema.definition_by_name_and_version(&table, version) {
                                 Some(definition) => {
                                     let patches = schema.patches_for_table(&table);
@@ -439,8 +744,13 @@ pub fn background_loop() {
                     },
                 };
                 let file = RFile::new_from_decoded(&decoded, 0, &path);
+                let previous = ContainerJournal::snapshot(&pack_file_decoded, &[ContainerPath::File(path.to_owned())]);
                 match pack_file_decoded.insert(file) {
-                    Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                    Ok(added_path) => {
+                        container_journal.set_memory_cap(container_journal_memory_cap());
+                        notify_not_kept_in_journal(&container_journal.push_addition(&added_path.into_iter().collect::<Vec<_>>(), previous));
+                        CentralCommand::send_back(&sender, Response::Success)
+                    }
                     Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                 }
             }
@@ -450,8 +760,12 @@ pub fn background_loop() {
                 let mut added_paths = vec![];
                 let mut it_broke = None;
 
+                // Adding files can replace existing ones, so keep the destinations in case we need to restore them.
+                container_journal.set_memory_cap(container_journal_memory_cap());
+                let previous = ContainerJournal::snapshot(&pack_file_decoded, &destination_paths);
+
                 let paths = source_paths.iter().zip(destination_paths.iter()).collect::<Vec<(&PathBuf, &ContainerPath)>>();
-                let schema = SCHEMA.read().unwrap();
+                let schema = read_schema();
                 for (source_path, destination_path) in paths {
 
                     // Skip ignored paths.
@@ -481,16 +795,104 @@ pub fn background_loop() {
                     }
                 }
 
+                // Files are inserted without knowing their game, so apply the game-specific file types here.
+                let game = read_game_selected();
+                pack_file_decoded.files_by_paths_mut(&added_paths, false).par_iter_mut().for_each(|file| {
+                    let _ = file.guess_file_type_for_game(&game);
+                });
+
+                notify_not_kept_in_journal(&container_journal.push_addition(&added_paths, previous));
                 if let Some(error) = it_broke {
+                    notify(NotificationLevel::Warning, format!("Added {} files, but some files couldn't be added: {}", added_paths.len(), error));
                     CentralCommand::send_back(&sender, Response::VecContainerPath(added_paths.to_vec()));
                     CentralCommand::send_back(&sender, Response::Error(From::from(error)));
                 } else {
+                    notify(NotificationLevel::Info, format!("Added {} files.", added_paths.len()));
                     CentralCommand::send_back(&sender, Response::VecContainerPath(added_paths.to_vec()));
                     CentralCommand::send_back(&sender, Response::Success);
                 }
 
                 // Force decoding of table/locs, so they're in memory for the diagnostics to work.
-                if let Some(ref schema) = *SCHEMA.read().unwrap() {
+                if let Some(ref schema) = *read_schema() {
+                    let mut decode_extra_data = DecodeableExtraData::default();
+                    decode_extra_data.set_schema(Some(schema));
+                    let extra_data = Some(decode_extra_data);
+
+                    pack_file_decoded.files_by_paths_mut(&added_paths, false).par_iter_mut().for_each(|x| {
+                        let _ = x.decode(&extra_data, true, false);
+                    });
+                }
+            }
+
+            // In case we want to add the contents of an archive to the PackFile...
+            Command::AddFilesFromArchive(archive_path, base_path, import_tsv) => {
+                let mut added_paths = vec![];
+                let mut it_broke = None;
+
+                // Extract the archive to a temporary folder first, so it gets fully validated before touching the Pack.
+                let extracted = tempfile::tempdir()
+                    .map_err(From::from)
+                    .and_then(|tmp_dir| archive::extract_archive(&archive_path, tmp_dir.path()).map(|_| tmp_dir));
+
+                match extracted {
+                    Ok(tmp_dir) => match files_from_subdir(tmp_dir.path(), true) {
+                        Ok(mut file_paths) => {
+                            file_paths.sort();
+
+                            // Respect the list of files the Pack is set to ignore on import.
+                            let files_to_ignore = pack_file_decoded.settings().setting_text("import_files_to_ignore").map(|files_to_ignore| {
+                                files_to_ignore.split('\n')
+                                    .filter(|x| !x.is_empty())
+                                    .map(|x| x.replace('\\', "/"))
+                                    .collect::<Vec<String>>()
+                            });
+
+                            let base_path = base_path.replace('\\', "/");
+                            let base_path = base_path.trim_matches('/');
+
+                            let schema = read_schema();
+                            let no_schema = None;
+                            let schema = if import_tsv { &*schema } else { &no_schema };
+
+                            for file_path in file_paths {
+                                let relative_path = match file_path.strip_prefix(tmp_dir.path()) {
+                                    Ok(path) => path.to_string_lossy().replace('\\', "/"),
+                                    Err(_) => continue,
+                                };
+
+                                let container_path = if base_path.is_empty() {
+                                    relative_path
+                                } else {
+                                    format!("{}/{}", base_path, relative_path)
+                                };
+
+                                if let Some(ref files_to_ignore) = files_to_ignore {
+                                    if files_to_ignore.iter().any(|x| container_path.starts_with(x)) {
+                                        continue;
+                                    }
+                                }
+
+                                match pack_file_decoded.insert_file(&file_path, &container_path, schema) {
+                                    Ok(path) => if let Some(path) = path {
+                                        added_paths.push(path);
+                                    },
+                                    Err(error) => it_broke = Some(anyhow!(error)),
+                                }
+                            }
+                        }
+                        Err(error) => it_broke = Some(anyhow!(error)),
+                    }
+                    Err(error) => it_broke = Some(error),
+                }
+
+                CentralCommand::send_back(&sender, Response::VecContainerPath(added_paths.to_vec()));
+                match it_broke {
+                    Some(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                    None => CentralCommand::send_back(&sender, Response::Success),
+                }
+
+                // Force decoding of table/locs, so they're in memory for the diagnostics to work.
+                if let Some(ref schema) = *read_schema() {
                     let mut decode_extra_data = DecodeableExtraData::default();
                     decode_extra_data.set_schema(Some(schema));
                     let extra_data = Some(decode_extra_data);
@@ -502,20 +904,45 @@ pub fn background_loop() {
             }
 
             // In case we want to move stuff from one PackFile to another...
-            Command::AddPackedFilesFromPackFile((pack_file_path, paths)) => {
+            Command::GetPackFileExtraConflicts((pack_file_path, paths)) => {
+                match pack_files_decoded_extra.get(&pack_file_path) {
+                    Some(pack) => {
+                        let paths = ContainerPath::dedup(&paths);
+                        let conflicts = pack.files_by_paths(&paths, false).iter()
+                            .filter(|file| pack_file_decoded.has_file(file.path_in_container_raw()))
+                            .map(|file| file.path_in_container())
+                            .collect::<Vec<_>>();
+
+                        CentralCommand::send_back(&sender, Response::VecContainerPath(conflicts));
+                    }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("Cannot find extra PackFile with path: {}", pack_file_path.to_string_lossy()))),
+                }
+            }
+
+            Command::AddPackedFilesFromPackFile((pack_file_path, paths, overwrite)) => {
                 match pack_files_decoded_extra.get(&pack_file_path) {
 
-                    // Try to add the PackedFile to the main PackFile.
+                    // Try to add the PackedFile to the main PackFile. Files keep their path, so folders keep their structure.
                     Some(pack) => {
+                        let paths = ContainerPath::dedup(&paths);
                         let files = pack.files_by_paths(&paths, false);
+                        let mut paths_added = vec![];
+
+                        container_journal.set_memory_cap(container_journal_memory_cap());
+                        let previous = ContainerJournal::snapshot(&pack_file_decoded, &paths);
                         for file in files {
-                            let _ = pack_file_decoded.insert(file.clone());
+                            if overwrite || !pack_file_decoded.has_file(file.path_in_container_raw()) {
+                                if let Ok(Some(path)) = pack_file_decoded.insert(file.clone()) {
+                                    paths_added.push(path);
+                                }
+                            }
                         }
 
-                        CentralCommand::send_back(&sender, Response::VecContainerPath(paths.to_vec()));
+                        notify_not_kept_in_journal(&container_journal.push_addition(&paths_added, previous));
+                        CentralCommand::send_back(&sender, Response::VecContainerPath(paths_added));
 
                         // Force decoding of table/locs, so they're in memory for the diagnostics to work.
-                        if let Some(ref schema) = *SCHEMA.read().unwrap() {
+                        if let Some(ref schema) = *read_schema() {
                             let mut decode_extra_data = DecodeableExtraData::default();
                             decode_extra_data.set_schema(Some(schema));
                             let extra_data = Some(decode_extra_data);
@@ -532,6 +959,7 @@ pub fn background_loop() {
 
             // In case we want to move stuff from our PackFile to an Animpack...
             Command::AddPackedFilesFromPackFileToAnimpack(anim_pack_path, paths) => {
+                let paths = ContainerPath::dedup(&paths);
                 let files = pack_file_decoded.files_by_paths(&paths, false).into_iter().cloned().collect::<Vec<RFile>>();
                 match pack_file_decoded.files_mut().get_mut(&anim_pack_path) {
                     Some(file) => {
@@ -544,11 +972,11 @@ pub fn background_loop() {
                         match file.decoded_mut() {
                             Ok(decoded) => match decoded {
                                 RFileDecoded::AnimPack(anim_pack) => {
-                                    for file in files {
-                                        let _ = anim_pack.insert(file);
-                                    }
+                                    let paths = files.into_iter()
+                                        .filter_map(|file| anim_pack.insert(file).ok().flatten())
+                                        .collect::<Vec<_>>();
 
-                                    CentralCommand::send_back(&sender, Response::VecContainerPath(paths.to_vec()));
+                                    CentralCommand::send_back(&sender, Response::VecContainerPath(paths));
                                 }
                                 _ => CentralCommand::send_back(&sender, Response::Error(anyhow!("We expected {} to be of type {} but found {}. This is either a bug or you did weird things with the game selected.", anim_pack_path, FileType::AnimPack, FileType::from(&*decoded)))),
                             }
@@ -561,13 +989,15 @@ pub fn background_loop() {
 
             // In case we want to move stuff from an Animpack to our PackFile...
             Command::AddPackedFilesFromAnimpack(data_source, anim_pack_path, paths) => {
+                let paths = ContainerPath::dedup(&paths);
                 let mut dependencies = dependencies.write().unwrap();
                 let anim_pack_file = match data_source {
                     DataSource::PackFile => pack_file_decoded.files_mut().get_mut(&anim_pack_path),
                     DataSource::GameFiles => dependencies.file_mut(&anim_pack_path, true, false).ok(),
                     DataSource::ParentFiles => dependencies.file_mut(&anim_pack_path, false, true).ok(),
                     DataSource::AssKitFiles |
-                    DataSource::ExternalFile => unreachable!(),
+                    DataSource::ExternalFile |
+                    DataSource::ComparedPackFile => unreachable!(),
                 };
 
                 let files = match anim_pack_file {
@@ -599,8 +1029,9 @@ pub fn background_loop() {
                 };
 
                 let paths = files.iter().map(|file| file.path_in_container()).collect::<Vec<_>>();
+                let game = read_game_selected();
                 for mut file in files {
-                    let _ = file.guess_file_type();
+                    let _ = file.guess_file_type_for_game(&game);
                     let _ = pack_file_decoded.insert(file);
                 }
 
@@ -620,7 +1051,7 @@ pub fn background_loop() {
                         match file.decoded_mut() {
                             Ok(decoded) => match decoded {
                                 RFileDecoded::AnimPack(anim_pack) => {
-                                    for path in paths {
+                                    for path in ContainerPath::dedup(&paths) {
                                         anim_pack.remove(&path);
                                     }
 
@@ -657,9 +1088,12 @@ pub fn background_loop() {
                                     let mut extra_data = DecodeableExtraData::default();
                                     extra_data.set_lazy_load(setting_bool("use_lazy_loading"));
 
-                                    let schema = SCHEMA.read().unwrap();
+                                    let schema = read_schema();
                                     extra_data.set_schema(schema.as_ref());
 
+                                    let game = read_game_selected();
+                                    extra_data.set_game_info(Some(*game));
+
                                     let result = file.decode(&Some(extra_data), true, true).transpose().unwrap();
 
                                     match result {
@@ -668,13 +1102,18 @@ pub fn background_loop() {
                                         Ok(RFileDecoded::AnimsTable(data)) => CentralCommand::send_back(&sender, Response::AnimsTableRFileInfo(data, From::from(&*file))),
                                         Ok(RFileDecoded::ESF(data)) => CentralCommand::send_back(&sender, Response::ESFRFileInfo(data, From::from(&*file))),
                                         Ok(RFileDecoded::DB(table)) => CentralCommand::send_back(&sender, Response::DBRFileInfo(table, From::from(&*file))),
-                                        Ok(RFileDecoded::Image(image)) => CentralCommand::send_back(&sender, Response::ImageRFileInfo(image, From::from(&*file))),
+                                        Ok(RFileDecoded::Image(image)) => {
+                                            let header = image.header();
+                                            CentralCommand::send_back(&sender, Response::ImageRFileInfo(image, header, From::from(&*file)))
+                                        }
                                         Ok(RFileDecoded::Loc(table)) => CentralCommand::send_back(&sender, Response::LocRFileInfo(table, From::from(&*file))),
                                         Ok(RFileDecoded::MatchedCombat(data)) => CentralCommand::send_back(&sender, Response::MatchedCombatRFileInfo(data, From::from(&*file))),
                                         #[cfg(feature = "support_rigidmodel")]Ok(RFileDecoded::RigidModel(rigid_model)) => CentralCommand::send_back(&sender, Response::RigidModelRFileInfo(rigid_model, From::from(&*file))),
                                         Ok(RFileDecoded::Text(text)) => CentralCommand::send_back(&sender, Response::TextRFileInfo(text, From::from(&*file))),
                                         Ok(RFileDecoded::UIC(uic)) => CentralCommand::send_back(&sender, Response::UICRFileInfo(uic, From::from(&*file))),
                                         Ok(RFileDecoded::UnitVariant(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
+                                        Ok(RFileDecoded::SpeedTree(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
+                                        Ok(RFileDecoded::Custom(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
                                         Ok(RFileDecoded::Video(data)) => CentralCommand::send_back(&sender, Response::VideoInfoRFileInfo(From::from(&data), From::from(&*file))),
                                         Ok(_) => CentralCommand::send_back(&sender, Response::Unknown),
                                         Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
@@ -691,9 +1130,12 @@ pub fn background_loop() {
                                 let mut extra_data = DecodeableExtraData::default();
                                 extra_data.set_lazy_load(setting_bool("use_lazy_loading"));
 
-                                let schema = SCHEMA.read().unwrap();
+                                let schema = read_schema();
                                 extra_data.set_schema(schema.as_ref());
 
+                                let game = read_game_selected();
+                                extra_data.set_game_info(Some(*game));
+
                                 let result = file.decode(&Some(extra_data), true, true).transpose().unwrap();
 
                                 match result {
@@ -702,13 +1144,18 @@ pub fn background_loop() {
                                     Ok(RFileDecoded::AnimsTable(data)) => CentralCommand::send_back(&sender, Response::AnimsTableRFileInfo(data, From::from(&*file))),
                                     Ok(RFileDecoded::ESF(data)) => CentralCommand::send_back(&sender, Response::ESFRFileInfo(data, From::from(&*file))),
                                     Ok(RFileDecoded::DB(table)) => CentralCommand::send_back(&sender, Response::DBRFileInfo(table, From::from(&*file))),
-                                    Ok(RFileDecoded::Image(image)) => CentralCommand::send_back(&sender, Response::ImageRFileInfo(image, From::from(&*file))),
+                                    Ok(RFileDecoded::Image(image)) => {
+                                        let header = image.header();
+                                        CentralCommand::send_back(&sender, Response::ImageRFileInfo(image, header, From::from(&*file)))
+                                    }
                                     Ok(RFileDecoded::Loc(table)) => CentralCommand::send_back(&sender, Response::LocRFileInfo(table, From::from(&*file))),
                                     Ok(RFileDecoded::MatchedCombat(data)) => CentralCommand::send_back(&sender, Response::MatchedCombatRFileInfo(data, From::from(&*file))),
                                     #[cfg(feature = "support_rigidmodel")]Ok(RFileDecoded::RigidModel(rigid_model)) => CentralCommand::send_back(&sender, Response::RigidModelRFileInfo(rigid_model, From::from(&*file))),
                                     Ok(RFileDecoded::Text(text)) => CentralCommand::send_back(&sender, Response::TextRFileInfo(text, From::from(&*file))),
                                     Ok(RFileDecoded::UIC(uic)) => CentralCommand::send_back(&sender, Response::UICRFileInfo(uic, From::from(&*file))),
                                     Ok(RFileDecoded::UnitVariant(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
+                                    Ok(RFileDecoded::SpeedTree(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
+                                    Ok(RFileDecoded::Custom(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
                                     Ok(RFileDecoded::Video(data)) => CentralCommand::send_back(&sender, Response::VideoInfoRFileInfo(From::from(&data), From::from(&*file))),
                                     Ok(_) => CentralCommand::send_back(&sender, Response::Unknown),
                                     Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
@@ -725,9 +1172,12 @@ pub fn background_loop() {
                                 let mut extra_data = DecodeableExtraData::default();
                                 extra_data.set_lazy_load(setting_bool("use_lazy_loading"));
 
-                                let schema = SCHEMA.read().unwrap();
+                                let schema = read_schema();
                                 extra_data.set_schema(schema.as_ref());
 
+                                let game = read_game_selected();
+                                extra_data.set_game_info(Some(*game));
+
                                 let result = file.decode(&Some(extra_data), true, true).transpose().unwrap();
 
                                 match result {
@@ -736,13 +1186,18 @@ pub fn background_loop() {
                                     Ok(RFileDecoded::AnimsTable(data)) => CentralCommand::send_back(&sender, Response::AnimsTableRFileInfo(data, From::from(&*file))),
                                     Ok(RFileDecoded::ESF(data)) => CentralCommand::send_back(&sender, Response::ESFRFileInfo(data, From::from(&*file))),
                                     Ok(RFileDecoded::DB(table)) => CentralCommand::send_back(&sender, Response::DBRFileInfo(table, From::from(&*file))),
-                                    Ok(RFileDecoded::Image(image)) => CentralCommand::send_back(&sender, Response::ImageRFileInfo(image, From::from(&*file))),
+                                    Ok(RFileDecoded::Image(image)) => {
+                                        let header = image.header();
+                                        CentralCommand::send_back(&sender, Response::ImageRFileInfo(image, header, From::from(&*file)))
+                                    }
                                     Ok(RFileDecoded::Loc(table)) => CentralCommand::send_back(&sender, Response::LocRFileInfo(table, From::from(&*file))),
                                     Ok(RFileDecoded::MatchedCombat(data)) => CentralCommand::send_back(&sender, Response::MatchedCombatRFileInfo(data, From::from(&*file))),
                                     #[cfg(feature = "support_rigidmodel")]Ok(RFileDecoded::RigidModel(rigid_model)) => CentralCommand::send_back(&sender, Response::RigidModelRFileInfo(rigid_model, From::from(&*file))),
                                     Ok(RFileDecoded::Text(text)) => CentralCommand::send_back(&sender, Response::TextRFileInfo(text, From::from(&*file))),
                                     Ok(RFileDecoded::UIC(uic)) => CentralCommand::send_back(&sender, Response::UICRFileInfo(uic, From::from(&*file))),
                                     Ok(RFileDecoded::UnitVariant(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
+                                    Ok(RFileDecoded::SpeedTree(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
+                                    Ok(RFileDecoded::Custom(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
                                     Ok(RFileDecoded::Video(data)) => CentralCommand::send_back(&sender, Response::VideoInfoRFileInfo(From::from(&data), From::from(&*file))),
                                     Ok(_) => CentralCommand::send_back(&sender, Response::Unknown),
                                     Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
@@ -764,35 +1219,136 @@ pub fn background_loop() {
                         }
                     }
 
+                    DataSource::ComparedPackFile => {
+                        let file = compared_pack.as_mut().and_then(|pack| pack.files_mut().get_mut(&path));
+
+                        match file {
+                            Some(file) => {
+                                let mut extra_data = DecodeableExtraData::default();
+                                extra_data.set_lazy_load(setting_bool("use_lazy_loading"));
+
+                                let schema = read_schema();
+                                extra_data.set_schema(schema.as_ref());
+
+                                let game = read_game_selected();
+                                extra_data.set_game_info(Some(*game));
+
+                                let result = file.decode(&Some(extra_data), true, true).transpose().unwrap();
+
+                                match result {
+                                    Ok(RFileDecoded::AnimFragment(data)) => CentralCommand::send_back(&sender, Response::AnimFragmentRFileInfo(data, From::from(&*file))),
+                                    Ok(RFileDecoded::AnimPack(data)) => CentralCommand::send_back(&sender, Response::AnimPackRFileInfo(From::from(&data), data.files().values().map(From::from).collect(), From::from(&*file))),
+                                    Ok(RFileDecoded::AnimsTable(data)) => CentralCommand::send_back(&sender, Response::AnimsTableRFileInfo(data, From::from(&*file))),
+                                    Ok(RFileDecoded::ESF(data)) => CentralCommand::send_back(&sender, Response::ESFRFileInfo(data, From::from(&*file))),
+                                    Ok(RFileDecoded::DB(table)) => CentralCommand::send_back(&sender, Response::DBRFileInfo(table, From::from(&*file))),
+                                    Ok(RFileDecoded::Image(image)) => {
+                                        let header = image.header();
+                                        CentralCommand::send_back(&sender, Response::ImageRFileInfo(image, header, From::from(&*file)))
+                                    }
+                                    Ok(RFileDecoded::Loc(table)) => CentralCommand::send_back(&sender, Response::LocRFileInfo(table, From::from(&*file))),
+                                    Ok(RFileDecoded::MatchedCombat(data)) => CentralCommand::send_back(&sender, Response::MatchedCombatRFileInfo(data, From::from(&*file))),
+                                    #[cfg(feature = "support_rigidmodel")]Ok(RFileDecoded::RigidModel(rigid_model)) => CentralCommand::send_back(&sender, Response::RigidModelRFileInfo(rigid_model, From::from(&*file))),
+                                    Ok(RFileDecoded::Text(text)) => CentralCommand::send_back(&sender, Response::TextRFileInfo(text, From::from(&*file))),
+                                    Ok(RFileDecoded::UIC(uic)) => CentralCommand::send_back(&sender, Response::UICRFileInfo(uic, From::from(&*file))),
+                                    Ok(RFileDecoded::UnitVariant(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
+                                    Ok(RFileDecoded::SpeedTree(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
+                                    Ok(RFileDecoded::Custom(_)) => CentralCommand::send_back(&sender, Response::RFileDecodedRFileInfo(result.unwrap(), From::from(&*file))),
+                                    Ok(RFileDecoded::Video(data)) => CentralCommand::send_back(&sender, Response::VideoInfoRFileInfo(From::from(&data), From::from(&*file))),
+                                    Ok(_) => CentralCommand::send_back(&sender, Response::Unknown),
+                                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                                }
+                            }
+                            None => CentralCommand::send_back(&sender, Response::Error(anyhow!("The file with the path {} hasn't been found on the compared Pack.", path))),
+                        }
+                    }
+
                     DataSource::ExternalFile => {}
                 }
             }
 
             // When we want to save a PackedFile from the view....
-            Command::SavePackedFileFromView(path, file_decoded) => {
+            Command::SavePackedFileFromView(path, mut file_decoded) => {
                 if path == RESERVED_NAME_NOTES {
                     if let RFileDecoded::Text(data) = file_decoded {
                         pack_file_decoded.set_notes(data.contents().to_owned());
                     }
                 }
                 else if let Some(file) = pack_file_decoded.files_mut().get_mut(&path) {
+                    keep_loc_layout(file, &mut file_decoded);
+
+                    // Lua scripts are saved even with syntax errors, but the errors are sent back so the view can show them.
+                    let lua_errors = match file_decoded {
+                        RFileDecoded::Text(ref text) if *text.format() == TextFormat::Lua => lua::check_syntax(text.contents()),
+                        _ => vec![],
+                    };
+
+                    // Views are saved even without changes, so only touch the file if its data actually changed.
+                    let changed = file.decoded().map(|decoded| decoded != &file_decoded).unwrap_or(true);
                     if let Err(error) = file.set_decoded(file_decoded) {
                         CentralCommand::send_back(&sender, Response::Error(From::from(error)));
+                        continue;
+                    }
+
+                    if changed {
+                        let _ = file.touch();
+                    }
+
+                    if !lua_errors.is_empty() {
+                        CentralCommand::send_back(&sender, Response::VecLuaSyntaxError(lua_errors));
+                        continue;
                     }
                 }
                 CentralCommand::send_back(&sender, Response::Success);
             }
 
+            // When we want to save multiple PackedFiles from their views in one go...
+            Command::SavePackedFilesFromViewBatch(files) => {
+                let results = files.into_iter()
+                    .map(|(path, mut file_decoded)| {
+                        let error = if path == RESERVED_NAME_NOTES {
+                            if let RFileDecoded::Text(data) = file_decoded {
+                                pack_file_decoded.set_notes(data.contents().to_owned());
+                            }
+                            None
+                        } else if let Some(file) = pack_file_decoded.files_mut().get_mut(&path) {
+                            keep_loc_layout(file, &mut file_decoded);
+                            let changed = file.decoded().map(|decoded| decoded != &file_decoded).unwrap_or(true);
+                            match file.set_decoded(file_decoded) {
+                                Ok(_) => {
+                                    if changed {
+                                        let _ = file.touch();
+                                    }
+                                    None
+                                }
+                                Err(error) => Some(error.to_string()),
+                            }
+                        } else {
+                            None
+                        };
+
+                        (path, error)
+                    })
+                    .collect();
+
+                CentralCommand::send_back(&sender, Response::VecStringOptionString(results));
+            }
+
             // In case we want to delete PackedFiles from a PackFile...
-            Command::DeletePackedFiles(paths) => CentralCommand::send_back(&sender, Response::VecContainerPath(paths.iter().flat_map(|path| pack_file_decoded.remove(path)).collect())),
+            Command::DeletePackedFiles(paths) => {
+                container_journal.set_memory_cap(container_journal_memory_cap());
+                let removed = ContainerJournal::snapshot(&pack_file_decoded, &paths);
+                let deleted_paths = paths.iter().flat_map(|path| pack_file_decoded.remove(path)).collect::<Vec<_>>();
+                notify_not_kept_in_journal(&container_journal.push_removal(removed));
+                notify(NotificationLevel::Info, format!("Deleted {} files. The undo history is using {} MB.", deleted_paths.len(), container_journal.memory_usage() / 1024 / 1024));
+                CentralCommand::send_back(&sender, Response::VecContainerPath(deleted_paths));
+            }
 
             // In case we want to extract PackedFiles from a PackFile...
-            Command::ExtractPackedFiles(container_paths, path, extract_tables_to_tsv) => {
-                let schema = SCHEMA.read().unwrap();
-                let schema = if extract_tables_to_tsv { &*schema } else { &None };
+            Command::ExtractPackedFiles(container_paths, path, extraction_format) => {
+                let schema = read_schema();
                 let mut errors = 0;
                 for container_path in container_paths {
-                    if pack_file_decoded.extract(container_path, &path, true, schema).is_err() {
+                    if pack_file_decoded.extract_as(container_path, &path, true, &schema, extraction_format).is_err() {
                         errors += 1;
                     }
                 }
@@ -804,15 +1360,106 @@ pub fn background_loop() {
                 }
             }
 
+            // In case we want to export the PackFile to its MyMod folder...
+            Command::ExportMyMod(path) => {
+                let rules = pack_file_decoded.settings().mymod_export_rules();
+                let schema = read_schema();
+                match pack_file_decoded.extract_with_export_rules(&path, &rules, &schema) {
+                    Ok(failed_paths) => if failed_paths.is_empty() {
+                        CentralCommand::send_back(&sender, Response::String(tr("files_extracted_success")));
+                    } else {
+                        CentralCommand::send_back(&sender, Response::Error(anyhow!("There were {} errors while extracting:\n{}", failed_paths.len(), failed_paths.join("\n"))));
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
             // In case we want to rename one or more files/folders...
             Command::RenamePackedFiles(renaming_data) => {
+                container_journal.set_memory_cap(container_journal_memory_cap());
+
+                // Renames can replace files, so keep the destinations in case we need to restore them.
+                let destination_paths = renaming_data.iter().map(|(_, destination_path)| destination_path.clone()).collect::<Vec<_>>();
+                let previous = ContainerJournal::snapshot(&pack_file_decoded, &destination_paths);
                 match pack_file_decoded.move_paths(&renaming_data) {
-                    Ok(data) => CentralCommand::send_back(&sender, Response::VecContainerPathContainerPath(data)),
+                    Ok(data) => {
+                        notify_not_kept_in_journal(&container_journal.push_moves(&data, previous));
+                        pack_file_decoded.settings_mut().move_path_labels(&renaming_data);
+                        pack_file_decoded.settings_mut().move_row_annotations(&renaming_data);
+                        CentralCommand::send_back(&sender, Response::VecContainerPathContainerPath(data));
+                    }
                     Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                 }
             }
 
-            // In case we want to know if a Folder exists, knowing his path...
+            // In case we want to rename one or more files/folders using a regex...
+            Command::RenamePackedFilesByPattern(paths, pattern, replacement, case_insensitive) => {
+                match pack_file_decoded.rename_paths_by_pattern(&paths, &pattern, &replacement, case_insensitive) {
+                    Ok(data) => {
+                        container_journal.push_moves(&data, vec![]);
+                        pack_file_decoded.settings_mut().move_path_labels(&data);
+                        pack_file_decoded.settings_mut().move_row_annotations(&data);
+                        CentralCommand::send_back(&sender, Response::VecContainerPathContainerPath(data));
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
+            // In case we want to move one or more files/folders into another folder...
+            Command::MoveFiles(paths, destination_folder) => {
+                match pack_file_decoded.move_paths_to_folder(&paths, &destination_folder) {
+                    Ok(data) => {
+                        container_journal.push_moves(&data, vec![]);
+                        pack_file_decoded.settings_mut().move_path_labels(&data);
+                        pack_file_decoded.settings_mut().move_row_annotations(&data);
+                        CentralCommand::send_back(&sender, Response::VecContainerPathContainerPath(data));
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
+            // In case we want to copy one or more files/folders into another folder...
+            Command::CopyFiles(paths, destination_folder) => {
+                match pack_file_decoded.copy_paths_to_folder(&paths, &destination_folder) {
+                    Ok(data) => {
+                        let added_paths = data.iter().map(|(_, new_path)| new_path.clone()).collect::<Vec<_>>();
+                        container_journal.push_addition(&added_paths, vec![]);
+                        CentralCommand::send_back(&sender, Response::VecContainerPathContainerPath(data))
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
+            // In case we want to duplicate a DB or Loc table...
+            Command::DuplicateTable(source_path, new_name) => {
+                match pack_file_decoded.duplicate_table(&source_path, &new_name) {
+                    Ok(new_path) => {
+                        container_journal.push_addition(&[new_path.clone()], vec![]);
+                        CentralCommand::send_back(&sender, Response::OptionRFileInfo(
+                            pack_file_decoded.files().get(new_path.path_raw()).map(From::from)
+                        ));
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
+            // In case we want to undo an operation over the structure of the PackFile...
+            Command::UndoContainerOp => {
+                container_journal.set_memory_cap(container_journal_memory_cap());
+                let changes = container_journal.undo(&mut pack_file_decoded);
+                notify_journal_changes(&container_journal, &changes);
+                CentralCommand::send_back(&sender, Response::OptionJournalChanges(changes));
+            }
+
+            // In case we want to redo an operation over the structure of the PackFile...
+            Command::RedoContainerOp => {
+                container_journal.set_memory_cap(container_journal_memory_cap());
+                let changes = container_journal.redo(&mut pack_file_decoded);
+                notify_journal_changes(&container_journal, &changes);
+                CentralCommand::send_back(&sender, Response::OptionJournalChanges(changes));
+            }
+
+            // In case we want to know if a Folder exists, knowing his path...
             Command::FolderExists(path) => {
                 CentralCommand::send_back(&sender, Response::Bool(pack_file_decoded.has_folder(&path)));
             }
@@ -838,7 +1485,7 @@ pub fn background_loop() {
             // In case we want to get the definition of an specific table from the dependency database...
             Command::GetTableDefinitionFromDependencyPackFile(table_name) => {
                 if dependencies.read().unwrap().is_vanilla_data_loaded(false) {
-                    if let Some(ref schema) = *SCHEMA.read().unwrap() {
+                    if let Some(ref schema) = *read_schema() {
                         if let Some(version) = dependencies.read().unwrap().db_version(&table_name) {
                             if let Some(definition) = schema.definition_by_name_and_version(&table_name, version) {
                                 CentralCommand::send_back(&sender, Response::Definition(definition.clone()));
@@ -849,8 +1496,49 @@ pub fn background_loop() {
             }
 
             // In case we want to merge DB or Loc Tables from a PackFile...
-            Command::MergeFiles(paths, merged_path, delete_source_files) => {
+            Command::MergeFiles(paths, merged_path, delete_source_files, upgrade_before_merge, ignore_conflicts) => {
+
+                // Make sure all the files are decoded, so none of them gets left out of the merge.
+                let schema = read_schema();
+                let mut extra_data = DecodeableExtraData::default();
+                extra_data.set_schema(schema.as_ref());
+                let extra_data = Some(extra_data);
+                pack_file_decoded.files_by_paths_mut(&paths, false).into_iter().for_each(|file| { let _ = file.decode(&extra_data, true, false); });
+
+                // If we have to, update the tables before merging them, so they all share the same definition.
+                let mut updated_paths = vec![];
+                if upgrade_before_merge {
+                    match *schema {
+                        Some(ref schema) => for path in &paths {
+                            match pack_file_decoded.update_tables(schema, path) {
+                                Ok((updated, _)) => updated_paths.extend(updated.into_iter().map(|(path, _, _)| ContainerPath::File(path))),
+                                Err(error) => {
+                                    CentralCommand::send_back(&sender, Response::Error(From::from(error)));
+                                    continue 'background_loop;
+                                }
+                            }
+                        }
+                        None => {
+                            CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected.")));
+                            continue;
+                        }
+                    }
+                }
+
                 let files_to_merge = pack_file_decoded.files_by_paths(&paths, false);
+                if !ignore_conflicts {
+                    match RFile::merge_conflicts(&files_to_merge) {
+                        Ok(conflicts) => if !conflicts.is_empty() {
+                            CentralCommand::send_back(&sender, Response::VecMergeConflictVecContainerPath(conflicts, updated_paths));
+                            continue;
+                        }
+                        Err(error) => {
+                            CentralCommand::send_back(&sender, Response::Error(From::from(error)));
+                            continue;
+                        }
+                    }
+                }
+
                 match RFile::merge(&files_to_merge, &merged_path) {
                     Ok(file) => {
                         let _ = pack_file_decoded.insert(file);
@@ -859,29 +1547,35 @@ pub fn background_loop() {
                             paths.iter().for_each(|path| { pack_file_decoded.remove(path); });
                         }
 
-                        CentralCommand::send_back(&sender, Response::String(merged_path.to_string()));
+                        CentralCommand::send_back(&sender, Response::StringVecContainerPath(merged_path.to_string(), updated_paths));
                     },
                     Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                 }
             }
 
+            // In case we want to know what tables can be updated...
+            Command::GetOutdatedTables(path) => {
+                match *read_schema() {
+                    Some(ref schema) => CentralCommand::send_back(&sender, Response::TableUpdates(pack_file_decoded.outdated_tables(schema, &path))),
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
+                }
+            }
+
             // In case we want to update a table...
             Command::UpdateTable(path) => {
-                let path = path.path_raw();
-                if let Some(rfile) = pack_file_decoded.file_mut(path) {
-                    if let Ok(decoded) = rfile.decoded_mut() {
-                        match dependencies.write().unwrap().update_db(decoded) {
-                            Ok((old_version, new_version)) => CentralCommand::send_back(&sender, Response::I32I32(old_version, new_version)),
-                            Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
-                        }
-                    } else { CentralCommand::send_back(&sender, Response::Error(anyhow!("File with the following path undecoded: {}", path))); }
-                } else { CentralCommand::send_back(&sender, Response::Error(anyhow!("File not found in the open Pack: {}", path))); }
+                match *read_schema() {
+                    Some(ref schema) => match pack_file_decoded.update_tables(schema, &path) {
+                        Ok(updates) => CentralCommand::send_back(&sender, Response::TableUpdates(updates)),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
+                }
             }
 
             // In case we want to replace all matches in a Global Search...
             Command::GlobalSearchReplaceMatches(mut global_search, matches) => {
-                let game_info = GAME_SELECTED.read().unwrap();
-                if let Some(ref schema) = *SCHEMA.read().unwrap() {
+                let game_info = read_game_selected();
+                if let Some(ref schema) = *read_schema() {
                     let paths = global_search.replace(&game_info, schema, &mut pack_file_decoded, &mut dependencies.write().unwrap(), &matches);
                     let files_info = paths.iter().flat_map(|path| pack_file_decoded.files_by_path(path, false).iter().map(|file| RFileInfo::from(*file)).collect::<Vec<RFileInfo>>()).collect();
 
@@ -893,8 +1587,8 @@ pub fn background_loop() {
 
             // In case we want to replace all matches in a Global Search...
             Command::GlobalSearchReplaceAll(mut global_search) => {
-                let game_info = GAME_SELECTED.read().unwrap();
-                if let Some(ref schema) = *SCHEMA.read().unwrap() {
+                let game_info = read_game_selected();
+                if let Some(ref schema) = *read_schema() {
                     let paths = global_search.replace_all(&game_info, schema, &mut pack_file_decoded, &mut dependencies.write().unwrap());
                     let files_info = paths.iter().flat_map(|path| pack_file_decoded.files_by_path(path, false).iter().map(|file| RFileInfo::from(*file)).collect::<Vec<RFileInfo>>()).collect();
 
@@ -905,11 +1599,21 @@ pub fn background_loop() {
             }
 
             // In case we want to get the reference data for a definition...
-            Command::GetReferenceDataFromDefinition(table_name, definition) => {
+            // Vanilla and parent data comes from the reference cache, and it's only calculated if it's not cached.
+            // If the dependencies are still being rebuilt, don't wait for them. Just return no references.
+            Command::GetReferenceDataCached(table_name, definition) => {
+                let reference_data = match dependencies.try_write() {
+                    Ok(mut dependencies) => {
+                        let reference_data = dependencies.db_reference_data_cached(&pack_file_decoded, &table_name, &definition);
+
+                        let cache = dependencies.reference_cache();
+                        debug!("Reference cache: {} hits, {} misses, {} columns ({} rows) cached.", cache.hits(), cache.misses(), cache.len(), cache.rows());
+                        reference_data
+                    }
+                    Err(TryLockError::WouldBlock) => HashMap::new(),
+                    Err(TryLockError::Poisoned(error)) => panic!("{}", error),
+                };
 
-                // TODO: move this to pack opening.
-                dependencies.write().unwrap().generate_local_definition_references(&table_name, &definition);
-                let reference_data = dependencies.read().unwrap().db_reference_data(&pack_file_decoded, &table_name, &definition);
                 CentralCommand::send_back(&sender, Response::HashMapI32TableReferences(reference_data));
             }
 
@@ -934,11 +1638,59 @@ pub fn background_loop() {
                 }
             },
 
+            // In case we want to export a ca_vp8 video as an IVF file...
+            Command::ExportCaVp8(internal_path, external_path) => {
+                match pack_file_decoded.files().get(&internal_path) {
+                    Some(rfile) => match rfile.decoded() {
+                        Ok(RFileDecoded::Video(video)) => {
+                            let mut data = vec![];
+                            match video.export_ivf(&mut data) {
+                                Ok(_) => match File::create(&external_path) {
+                                    Ok(file) => {
+                                        let mut writer = BufWriter::new(file);
+                                        match writer.write_all(&data) {
+                                            Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                                            Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                                        }
+                                    }
+                                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                                }
+                                Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                            }
+                        }
+                        Ok(_) => CentralCommand::send_back(&sender, Response::Error(anyhow!("The file {} is not a video.", internal_path))),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("File with the following path not found in the Pack: {}", internal_path))),
+                }
+            }
+
+            // In case we want to replace a ca_vp8 video with an IVF file...
+            Command::ImportCaVp8(internal_path, external_path) => {
+                match pack_file_decoded.files_mut().get_mut(&internal_path) {
+                    Some(rfile) => match rfile.decoded_mut() {
+                        Ok(RFileDecoded::Video(video)) => match File::open(&external_path) {
+                            Ok(file) => {
+                                let mut reader = BufReader::new(file);
+                                match video.import_ivf(&mut reader) {
+                                    Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                                }
+                            }
+                            Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                        }
+                        Ok(_) => CentralCommand::send_back(&sender, Response::Error(anyhow!("The file {} is not a video.", internal_path))),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("File with the following path not found in the Pack: {}", internal_path))),
+                }
+            }
+
             // In case we want to save an schema to disk...
             Command::SaveSchema(mut schema) => {
-                match schema.save(&schemas_path().unwrap().join(GAME_SELECTED.read().unwrap().schema_file_name())) {
+                match schema.save(&schemas_path().unwrap().join(read_game_selected().schema_file_name())) {
                     Ok(_) => {
-                        *SCHEMA.write().unwrap() = Some(schema);
+                        replace_schema(Some(schema));
                         CentralCommand::send_back(&sender, Response::Success);
                     },
                     Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
@@ -955,7 +1707,7 @@ pub fn background_loop() {
 
             // In case we want to export a PackedFile as a TSV file...
             Command::ExportTSV(internal_path, external_path) => {
-                let schema = SCHEMA.read().unwrap();
+                let schema = read_schema();
                 match &*schema {
                     Some(ref schema) => {
                         match pack_file_decoded.file_mut(&internal_path) {
@@ -970,10 +1722,37 @@ pub fn background_loop() {
                 }
             }
 
+            // In case we want to export all tables within a folder as TSV files...
+            Command::ExportTSVFolder(container_path, external_path) => {
+                let schema = read_schema();
+                match &*schema {
+                    Some(ref schema) => {
+                        let mut files = pack_file_decoded.files_by_type_and_paths_mut(&[FileType::DB, FileType::Loc], &[container_path], false);
+                        files.sort_by(|a, b| a.path_in_container_raw().cmp(b.path_in_container_raw()));
+
+                        let mut exported = vec![];
+                        let mut errors = vec![];
+                        for file in files {
+                            let mut path = external_path.join(file.path_in_container_raw());
+                            path.set_extension("tsv");
+                            let path = unique_path(path);
+
+                            match file.tsv_export_to_path(&path, schema) {
+                                Ok(_) => exported.push(file.path_in_container()),
+                                Err(error) => errors.push(format!("{}: {}", file.path_in_container_raw(), error)),
+                            }
+                        }
+
+                        CentralCommand::send_back(&sender, Response::VecContainerPathVecString(exported, errors));
+                    },
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
+                }
+            }
+
             // In case we want to import a TSV as a PackedFile...
             // TODO: This is... unreliable at best, can break stuff at worst. Replace the set_decoded with proper type checking.
             Command::ImportTSV(internal_path, external_path) => {
-                let schema = SCHEMA.read().unwrap();
+                let schema = read_schema();
                 match &*schema {
                     Some(ref schema) => {
                         match pack_file_decoded.file_mut(&internal_path) {
@@ -994,18 +1773,65 @@ pub fn background_loop() {
                 }
             }
 
-            // In case we want to open a PackFile's location in the file manager...
-            Command::OpenContainingFolder => {
+            // In case we want to export a PackedFile as a CSV file...
+            Command::ExportCSV(internal_path, external_path, delimiter) => {
+                let schema = read_schema();
+                match &*schema {
+                    Some(ref schema) => {
+                        match pack_file_decoded.file_mut(&internal_path) {
+                            Some(file) => match file.csv_export_to_path(&external_path, schema, delimiter) {
+                                Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                                Err(error) =>  CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                            }
+                            None => CentralCommand::send_back(&sender, Response::Error(anyhow!("File with the following path not found in the Pack: {}", internal_path))),
+                        }
+                    },
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
+                }
+            }
+
+            // In case we want to import a CSV as a PackedFile...
+            Command::ImportCSV(internal_path, external_path, delimiter) => {
+                let schema = read_schema();
+                match &*schema {
+                    Some(ref schema) => {
+                        match pack_file_decoded.file_mut(&internal_path) {
+                            Some(file) => {
+                                let result = RFile::csv_import_from_path(&external_path, schema, delimiter)
+                                    .and_then(|imported| imported.decoded().cloned())
+                                    .and_then(|decoded| file.set_decoded(decoded.clone()).map(|_| decoded));
 
-                // If the path exists, try to open it. If not, throw an error.
-                let mut path = PathBuf::from(pack_file_decoded.disk_file_path());
-                if path.exists() {
-                    path.pop();
-                    let _ = open::that(&path);
-                    CentralCommand::send_back(&sender, Response::Success);
+                                match result {
+                                    Ok(decoded) => CentralCommand::send_back(&sender, Response::RFileDecoded(decoded)),
+                                    Err(error) =>  CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                                }
+                            }
+                            None => CentralCommand::send_back(&sender, Response::Error(anyhow!("File with the following path not found in the Pack: {}", internal_path))),
+                        }
+                    },
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
                 }
-                else {
-                    CentralCommand::send_back(&sender, Response::Error(anyhow!("This Pack doesn't exists as a file in the disk.")));
+            }
+
+            // In case we want to open a PackFile's location (or any other path) in the file manager...
+            Command::OpenContainingFolder(target) => {
+                let path = match target.unwrap_or(FolderTarget::PackLocation) {
+                    FolderTarget::PackLocation => Ok(PathBuf::from(pack_file_decoded.disk_file_path())),
+                    FolderTarget::InstalledLocation => installed_pack_path(&pack_file_decoded),
+                    FolderTarget::ExtractedPath(path) => Ok(path),
+                };
+
+                // If the path exists, try to show it. If not, throw an error.
+                match path {
+                    Ok(path) => if path.exists() {
+                        match show_in_file_manager(&path) {
+                            Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                            Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                        }
+                    } else {
+                        CentralCommand::send_back(&sender, Response::Error(anyhow!("The following path doesn't exists in the disk: {}", path.to_string_lossy())));
+                    },
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
                 }
             },
 
@@ -1013,15 +1839,37 @@ pub fn background_loop() {
             Command::OpenPackedFileInExternalProgram(data_source, path) => {
                 match data_source {
                     DataSource::PackFile => {
-                        let folder = temp_dir().join(format!("rpfm_{}", pack_file_decoded.disk_file_name()));
-                        match pack_file_decoded.extract(path.clone(), &folder, true, &SCHEMA.read().unwrap()) {
-                            Ok(_) => {
+                        let folder = external_temp_folder(&pack_file_decoded);
+                        let mut extracted_path = folder.to_path_buf();
+                        if let ContainerPath::File(ref path) = path {
+                            extracted_path.push(path);
+                        }
 
-                                let mut extracted_path = folder.to_path_buf();
-                                if let ContainerPath::File(path) = path {
-                                    extracted_path.push(path);
-                                }
+                        // Tables are exported as TSV, so the external program can actually understand them.
+                        let table = match path {
+                            ContainerPath::File(ref path) => pack_file_decoded.file_mut(path).filter(|file| file.file_type() == FileType::DB || file.file_type() == FileType::Loc),
+                            ContainerPath::Folder(_) => None,
+                        };
+
+                        let result = match (table, &*read_schema()) {
+                            (Some(file), Some(schema)) => {
 
+                                // Make sure to NOT replace the extension if there is one, only append to it.
+                                match extracted_path.extension() {
+                                    Some(extension) => {
+                                        let extension = format!("{}.tsv", extension.to_string_lossy());
+                                        extracted_path.set_extension(extension)
+                                    },
+                                    None => extracted_path.set_extension("tsv"),
+                                };
+
+                                file.tsv_export_to_path(&extracted_path, schema)
+                            }
+                            _ => pack_file_decoded.extract(path.clone(), &folder, true, &None),
+                        };
+
+                        match result {
+                            Ok(_) => {
                                 let _ = that(&extracted_path);
                                 CentralCommand::send_back(&sender, Response::PathBuf(extracted_path));
                             }
@@ -1035,6 +1883,21 @@ pub fn background_loop() {
             // When we want to save a PackedFile from the external view....
             Command::SavePackedFileFromExternalView(path, external_path) => {
 
+                // Tables are edited as TSV, so they need to be imported back and validated against their definition.
+                let is_tsv = external_path.extension().map_or(false, |extension| extension == "tsv");
+                if is_tsv {
+                    if let Some(file) = pack_file_decoded.file_mut(&path).filter(|file| file.file_type() == FileType::DB || file.file_type() == FileType::Loc) {
+                        match &*read_schema() {
+                            Some(schema) => match file.tsv_import_to_self(&external_path, schema) {
+                                Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                                Err(error) => CentralCommand::send_back(&sender, Response::Error(anyhow!("Failed to import back the table {} edited externally due to the following error: {}", path, error))),
+                            }
+                            None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
+                        }
+                        continue;
+                    }
+                }
+
                 // We do it manually instead of using insert_file because insert_file replaces the file's metadata.
                 match File::open(external_path) {
                     Ok(file) => {
@@ -1046,7 +1909,7 @@ pub fn background_loop() {
                                     Some(file) => {
                                         file.set_cached(&data);
                                         if file.file_type() == FileType::DB || file.file_type() == FileType::Loc {
-                                            if let Some(ref schema) = *SCHEMA.read().unwrap() {
+                                            if let Some(ref schema) = *read_schema() {
                                                 let mut extra_data = DecodeableExtraData::default();
                                                 extra_data.set_schema(Some(schema));
                                                 let extra_data = Some(extra_data);
@@ -1073,16 +1936,30 @@ pub fn background_loop() {
                         let git_integration = GitIntegration::new(&local_path, SCHEMA_REPO, SCHEMA_BRANCH, SCHEMA_REMOTE);
                         match git_integration.update_repo() {
                             Ok(_) => {
-                                let game = GAME_SELECTED.read().unwrap();
+                                let game = read_game_selected();
                                 let schema_path = schemas_path().unwrap().join(game.schema_file_name());
 
                                 // Encode the decoded tables with the old schema, then re-decode them with the new one.
                                 let mut tables = pack_file_decoded.files_by_type_mut(&[FileType::DB]);
                                 tables.par_iter_mut().for_each(|x| { let _ = x.encode(&None, true, true, false); });
 
-                                *SCHEMA.write().unwrap() = Schema::load(&schema_path).ok();
+                                // Load the new schema before swapping it in one go, and keep the old one around so we can tell the user what changed.
+                                let new_schema = Schema::load(&schema_path).ok();
+                                let old_schema = replace_schema(new_schema);
+
+                                if let Some(ref schema) = *read_schema() {
+                                    let diff = match old_schema {
+                                        Some(ref old_schema) => SchemaDiff::new(old_schema, schema),
+                                        None => SchemaDiff::default(),
+                                    };
+
+                                    let mut table_names = tables.iter()
+                                        .filter_map(|file| file.db_table_name_from_path())
+                                        .map(|table_name| table_name.to_owned())
+                                        .collect::<Vec<_>>();
+                                    table_names.sort();
+                                    table_names.dedup();
 
-                                if let Some(ref schema) = *SCHEMA.read().unwrap() {
                                     let mut extra_data = DecodeableExtraData::default();
                                     extra_data.set_schema(Some(schema));
                                     let extra_data = Some(extra_data);
@@ -1096,12 +1973,12 @@ pub fn background_loop() {
                                         let game_path = setting_path(&game.game_key_name());
                                         let dependencies_file_path = dependencies_cache_path().unwrap().join(game.dependencies_cache_file_name());
 
-                                        match dependencies.write().unwrap().rebuild(&SCHEMA.read().unwrap(), pack_file_decoded.dependencies(), Some(&*dependencies_file_path), &game, &game_path) {
-                                            Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                                        match dependencies.write().unwrap().rebuild(&read_schema(), pack_file_decoded.dependencies(), Some(&*dependencies_file_path), &game, &game_path) {
+                                            Ok(_) => CentralCommand::send_back(&sender, Response::SchemaDiffVecString(diff, table_names)),
                                             Err(_) => CentralCommand::send_back(&sender, Response::Error(anyhow!("Schema updated, but dependencies cache rebuilding failed. You may need to regenerate it."))),
                                         }
                                     } else {
-                                        CentralCommand::send_back(&sender, Response::Success)
+                                        CentralCommand::send_back(&sender, Response::SchemaDiffVecString(diff, table_names))
                                     }
                                 } else {
                                     CentralCommand::send_back(&sender, Response::Success)
@@ -1150,34 +2027,46 @@ pub fn background_loop() {
                 }
             }
 
-            // When we want to update our program...
+            // When we want to autosave the open PackFile...
             Command::TriggerBackupAutosave => {
 
                 // Note: we no longer notify the UI of success or error to not hang it up.
-                if let Ok(Some(file)) = oldest_file_in_folder(&backup_autosave_path().unwrap()) {
-                    if pack_file_decoded.pfh_file_type() == PFHFileType::Mod {
-                        let _ = pack_file_decoded.clone().save(Some(&file));
+                if pack_file_decoded.pfh_file_type() == PFHFileType::Mod {
+                    if let Err(error) = autosave_pack(&pack_file_decoded) {
+                        error!("Autosave failed: {}", error);
+                        notify(NotificationLevel::Error, format!("Autosave failed: {}", error));
                     }
                 }
             }
 
+            // When we want to know what autosaves we have for the open PackFile...
+            Command::ListAutosaves => {
+                match pack_autosaves(&autosave_pack_name(&pack_file_decoded)) {
+                    Ok(autosaves) => CentralCommand::send_back(&sender, Response::VecPathBuf(autosaves)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
             // In case we want to perform a diagnostics check...
-            Command::DiagnosticsCheck(diagnostics_ignored) => {
+            Command::DiagnosticsCheck(diagnostics_ignored, cancel) => {
 
                 // Spawn a separate thread so the UI can keep working.
                 thread::spawn(clone!(
                     dependencies,
                     pack_file_decoded => move || {
-                    let game_selected = GAME_SELECTED.read().unwrap().clone();
+                    let game_selected = read_game_selected().clone();
                     let game_path = setting_path(&game_selected.game_key_name());
 
                     let mut diagnostics = Diagnostics::default();
                     *diagnostics.diagnostics_ignored_mut() = diagnostics_ignored;
 
-                    if let Some(schema) = &*SCHEMA.read().unwrap() {
+                    if let Some(schema) = &*read_schema() {
                         if pack_file_decoded.pfh_file_type() == PFHFileType::Mod ||
                             pack_file_decoded.pfh_file_type() == PFHFileType::Movie {
-                            diagnostics.check(&pack_file_decoded, &mut dependencies.write().unwrap(), &game_selected, &game_path, &[], schema);
+                            if diagnostics.check(&pack_file_decoded, &mut dependencies.write().unwrap(), &game_selected, &game_path, &[], schema, &cancel).is_err() {
+                                info!("Checking diagnostics: cancelled.");
+                                return CentralCommand::send_back(&sender, Response::Cancelled);
+                            }
                         }
 
                         info!("Checking diagnostics: done.");
@@ -1187,19 +2076,57 @@ pub fn background_loop() {
                 }));
             }
 
+            // In case we want to check the paths referenced by a path list file...
+            Command::CheckPathList(path) => {
+                let text = match pack_file_decoded.file(&path) {
+                    Some(file) => match file.clone().decode(&None, false, true) {
+                        Ok(Some(RFileDecoded::Text(text))) => Some(text),
+                        _ => None,
+                    },
+                    None => None,
+                };
+
+                let messages = match (text, PathListFormat::from_path(&path)) {
+                    (Some(text), Some(format)) => {
+                        let local_paths_lowercase = pack_file_decoded.paths_raw().into_iter().map(|path| path.to_lowercase()).collect::<HashSet<_>>();
+                        Diagnostics::check_path_list(&text, format, &local_paths_lowercase, &dependencies.read().unwrap(), *read_game_selected())
+                            .iter()
+                            .map(|result| result.message())
+                            .collect()
+                    }
+                    _ => vec![],
+                };
+
+                CentralCommand::send_back(&sender, Response::VecString(messages));
+            }
+
+            // In case we want to check the paths referenced by a VariantMeshDefinition...
+            Command::CheckVariantMeshDefinition(contents) => {
+                let mut text = Text::default();
+                text.set_contents(contents);
+
+                let local_paths_lowercase = pack_file_decoded.paths_raw().into_iter().map(|path| path.to_lowercase()).collect::<HashSet<_>>();
+                let messages = Diagnostics::check_variant_mesh_definition(&text, &local_paths_lowercase, &dependencies.read().unwrap())
+                    .iter()
+                    .map(|result| result.message())
+                    .collect();
+
+                CentralCommand::send_back(&sender, Response::VecString(messages));
+            }
+
             Command::DiagnosticsUpdate(mut diagnostics, path_types) => {
 
                 // Spawn a separate thread so the UI can keep working.
                 thread::spawn(clone!(
                     dependencies,
                     pack_file_decoded => move || {
-                    let game_selected = GAME_SELECTED.read().unwrap().clone();
+                    let game_selected = read_game_selected().clone();
                     let game_path = setting_path(&game_selected.game_key_name());
 
-                    if let Some(schema) = &*SCHEMA.read().unwrap() {
+                    if let Some(schema) = &*read_schema() {
                         if pack_file_decoded.pfh_file_type() == PFHFileType::Mod ||
                             pack_file_decoded.pfh_file_type() == PFHFileType::Movie {
-                            diagnostics.check(&pack_file_decoded, &mut dependencies.write().unwrap(), &game_selected, &game_path, &path_types, schema);
+                            let _ = diagnostics.check(&pack_file_decoded, &mut dependencies.write().unwrap(), &game_selected, &game_path, &path_types, schema, &AtomicBool::new(false));
                         }
 
                         info!("Checking diagnostics (update): done.");
@@ -1209,9 +2136,95 @@ pub fn background_loop() {
                 }));
             }
 
+            // In case we want to get statistics about the open PackFile...
+            Command::GetPackStatistics => {
+                let schema = read_schema();
+                let statistics = PackStatistics::from_pack(&pack_file_decoded, schema.as_ref());
+                CentralCommand::send_back(&sender, Response::PackStatistics(statistics));
+            }
+
+            // In case we want to check what files collide between the open PackFile and other Packs...
+            Command::CheckConflicts(paths) => {
+                let schema = read_schema();
+                let conflicts = PackConflicts::check(&pack_file_decoded, &paths, schema.as_ref());
+                CentralCommand::send_back(&sender, Response::PackConflicts(conflicts));
+            }
+
             // In case we want to get the open PackFile's Settings...
-            Command::GetPackSettings => CentralCommand::send_back(&sender, Response::PackSettings(pack_file_decoded.settings().clone())),
-            Command::SetPackSettings(settings) => { pack_file_decoded.set_settings(settings); }
+            Command::GetPackSettings => {
+
+                // Make sure settings added after the Pack was created are available too.
+                let mut settings = initialize_pack_settings();
+                settings.settings_text_mut().extend(pack_file_decoded.settings().settings_text().clone());
+                settings.settings_string_mut().extend(pack_file_decoded.settings().settings_string().clone());
+                settings.settings_bool_mut().extend(pack_file_decoded.settings().settings_bool().clone());
+                settings.settings_number_mut().extend(pack_file_decoded.settings().settings_number().clone());
+                settings.set_diagnostics_ignore_rules(pack_file_decoded.settings().diagnostics_ignore_rules().clone());
+
+                // The legacy diagnostics ignore rules are generated from the current ones on save, so they must not be edited.
+                settings.settings_text_mut().remove(LEGACY_DIAGNOSTICS_IGNORE_SETTING);
+                CentralCommand::send_back(&sender, Response::PackSettings(settings));
+            }
+            Command::SetPackSettings(mut settings) => {
+
+                // Row annotations are not editable from the settings view, so keep the ones we have.
+                settings.set_row_annotations(pack_file_decoded.settings().row_annotations().clone());
+                pack_file_decoded.set_settings(settings);
+            }
+
+            // In case we want to get the open PackFile's Header...
+            Command::GetPackHeader => CentralCommand::send_back(&sender, Response::PackHeader(pack_file_decoded.header().clone())),
+            Command::SetPackAuthoringTool(authoring_tool) => pack_file_decoded.set_authoring_tool(&authoring_tool),
+
+            // In case we want to ignore diagnostics of files of the open PackFile...
+            Command::AddDiagnosticsIgnoreRules(rules) => {
+                let mut extra_data = DecodeableExtraData::default();
+                let schema = read_schema();
+                extra_data.set_schema(schema.as_ref());
+                let extra_data = Some(extra_data);
+
+                // Diagnostics report columns by index, but rules use their names, so we need the definition of each table.
+                let rules_count = rules.len();
+                let mut new_rules = vec![];
+                for (path, diagnostic, columns) in rules {
+                    let column_names = if columns.is_empty() {
+                        Some(vec![])
+                    } else {
+                        let definition = match pack_file_decoded.file_mut(&path).map(|file| file.decode(&extra_data, false, true)) {
+                            Some(Ok(Some(RFileDecoded::DB(table)))) => Some(table.definition().clone()),
+                            Some(Ok(Some(RFileDecoded::Loc(table)))) => Some(table.definition().clone()),
+                            _ => None,
+                        };
+
+                        definition.map(|definition| {
+                            let fields = definition.fields_processed();
+                            columns.iter()
+                                .filter_map(|column| fields.get(*column as usize).map(|field| field.name().to_owned()))
+                                .collect::<Vec<_>>()
+                        })
+                    };
+
+                    match column_names {
+                        Some(column_names) => new_rules.push(DiagnosticIgnoreRule::new(&path, Some(&diagnostic), &column_names)),
+                        None => break,
+                    }
+                }
+
+                if new_rules.len() == rules_count {
+                    let mut settings = pack_file_decoded.settings().clone();
+                    new_rules.into_iter().for_each(|rule| { settings.add_diagnostics_ignore_rule(rule); });
+                    pack_file_decoded.set_settings(settings);
+                    CentralCommand::send_back(&sender, Response::Success);
+                } else {
+                    CentralCommand::send_back(&sender, Response::Error(anyhow!("The columns of the diagnostics couldn't be found, as their table couldn't be decoded.")));
+                }
+            }
+
+            Command::GetPackNotes => CentralCommand::send_back(&sender, Response::String(pack_file_decoded.notes().to_owned())),
+            Command::SetPackNotes(notes) => {
+                pack_file_decoded.set_notes(notes);
+                CentralCommand::send_back(&sender, Response::Success);
+            }
 
             Command::GetMissingDefinitions => {
 
@@ -1219,7 +2232,7 @@ pub fn background_loop() {
                 // a new patch lands and you want to know what tables you need to decode.
                 let mut counter = 0;
                 let mut table_list = String::new();
-                if let Some(ref schema) = *SCHEMA.read().unwrap() {
+                if let Some(ref schema) = *read_schema() {
                     let mut extra_data = DecodeableExtraData::default();
                     extra_data.set_schema(Some(schema));
                     let extra_data = Some(extra_data);
@@ -1250,156 +2263,241 @@ pub fn background_loop() {
 
             // Ignore errors for now.
             Command::RebuildDependencies(rebuild_only_current_mod_dependencies) => {
-                if SCHEMA.read().unwrap().is_some() {
-                    let game_selected = GAME_SELECTED.read().unwrap();
-                    let game_path = setting_path(&game_selected.game_key_name());
-                    let dependencies_file_path = dependencies_cache_path().unwrap().join(game_selected.dependencies_cache_file_name());
-                    let file_path = if !rebuild_only_current_mod_dependencies { Some(&*dependencies_file_path) } else { None };
+                if read_schema().is_some() {
+                    let pack_dependencies = pack_file_decoded.dependencies().to_vec();
+                    let dependencies_sender = sender.clone();
+                    rebuild_dependencies_in_background(&dependencies, move |dependencies| {
+                        let game_selected = *read_game_selected();
+                        let game_path = setting_path(&game_selected.game_key_name());
+                        let dependencies_file_path = dependencies_cache_path().unwrap().join(game_selected.dependencies_cache_file_name());
+                        let file_path = if !rebuild_only_current_mod_dependencies { Some(&*dependencies_file_path) } else { None };
 
-                    let _ = dependencies.write().unwrap().rebuild(&SCHEMA.read().unwrap(), pack_file_decoded.dependencies(), file_path, &game_selected, &game_path);
-                    let dependencies_info = DependenciesInfo::from(&*dependencies.read().unwrap());
-                    CentralCommand::send_back(&sender, Response::DependenciesInfo(dependencies_info));
+                        match dependencies.rebuild(&read_schema(), &pack_dependencies, file_path, game_selected, &game_path) {
+                            Ok(_) => notify(NotificationLevel::Info, "Dependencies rebuilt."),
+                            Err(error) => notify(NotificationLevel::Warning, format!("Dependencies rebuilt with errors: {}", error)),
+                        }
+
+                        let _ = dependencies_sender.send(Response::DependenciesInfo(DependenciesInfo::from(&*dependencies)));
+                    });
                 } else {
-                    CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected.")));
+                    CentralCommand::send_back(&sender, Response::ErrorKind(ErrorKind::SchemaMissing, "There is no Schema for the Game Selected.".to_owned()));
                 }
             },
 
-            Command::CascadeEdition(table_name, definition, changes) => {
-                let edited_paths = changes.iter().flat_map(|(field, value_before, value_after)| {
-                    DB::cascade_edition(&mut pack_file_decoded, &SCHEMA.read().unwrap(), &table_name, field, &definition, value_before, value_after)
-                }).collect::<Vec<_>>();
-
-                let packed_files_info = pack_file_decoded.files_by_paths(&edited_paths, false).into_par_iter().map(From::from).collect();
-                CentralCommand::send_back(&sender, Response::VecContainerPathVecRFileInfo(edited_paths, packed_files_info));
-            }
-
-            Command::GoToDefinition(ref_table, ref_column, ref_data) => {
-                let table_name = format!("{}_tables", ref_table);
-                let table_folder = format!("db/{}", table_name);
-                let packed_files = pack_file_decoded.files_by_path(&ContainerPath::Folder(table_folder.to_owned()), true);
-                let mut found = false;
-                for packed_file in &packed_files {
-                    if let Ok(RFileDecoded::DB(data)) = packed_file.decoded() {
-                        if let Some((column_index, row_index)) = data.table().rows_containing_data(&ref_column, &ref_data) {
-                            CentralCommand::send_back(&sender, Response::DataSourceStringUsizeUsize(DataSource::PackFile, packed_file.path_in_container_raw().to_owned(), column_index, row_index[0]));
-                            found = true;
-                            break;
-                        }
+            Command::CascadeEditionPreview(table_name, changes) => {
+                match *read_schema() {
+                    Some(ref schema) => match CascadeEdition::new(&pack_file_decoded, schema, &table_name, &changes) {
+                        Ok(cascade_edition) => CentralCommand::send_back(&sender, Response::CascadeEdition(cascade_edition)),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                     }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
                 }
+            }
 
-                if !found {
-                    if let Ok(packed_files) = dependencies.read().unwrap().db_data(&table_name, false, true) {
-                        for packed_file in &packed_files {
-                            if let Ok(RFileDecoded::DB(data)) = packed_file.decoded() {
-                                if let Some((column_index, row_index)) = data.table().rows_containing_data(&ref_column, &ref_data) {
-                                    CentralCommand::send_back(&sender, Response::DataSourceStringUsizeUsize(DataSource::ParentFiles, packed_file.path_in_container_raw().to_owned(), column_index, row_index[0]));
-                                    found = true;
-                                    break;
-                                }
+            // Cascade editions are calculated again, in case the Pack changed since the preview. If any file fails, none is edited.
+            Command::CascadeEdition(table_name, changes) => {
+                match *read_schema() {
+                    Some(ref schema) => match CascadeEdition::new(&pack_file_decoded, schema, &table_name, &changes) {
+                        Ok(cascade_edition) => match cascade_edition.apply(&mut pack_file_decoded, schema) {
+                            Ok(edited_paths) => {
+                                let packed_files_info = pack_file_decoded.files_by_paths(&edited_paths, false).into_par_iter().map(From::from).collect();
+                                CentralCommand::send_back(&sender, Response::VecCascadeEditVecRFileInfo(cascade_edition.edits().to_vec(), packed_files_info));
                             }
+                            Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                         }
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                     }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
                 }
+            }
 
-                if !found {
-                    if let Ok(packed_files) = dependencies.read().unwrap().db_data(&table_name, true, false) {
-                        for packed_file in &packed_files {
-                            if let Ok(RFileDecoded::DB(data)) = packed_file.decoded() {
-                                if let Some((column_index, row_index)) = data.table().rows_containing_data(&ref_column, &ref_data) {
-                                    CentralCommand::send_back(&sender, Response::DataSourceStringUsizeUsize(DataSource::GameFiles, packed_file.path_in_container_raw().to_owned(), column_index, row_index[0]));
-                                    found = true;
-                                    break;
+            Command::GoToDefinition(ref_table, ref_column, ref_data) => {
+                match definition_location(&pack_file_decoded, &dependencies.read().unwrap(), &ref_table, &ref_column, &ref_data) {
+                    Some((data_source, path, column_index, row_index)) => CentralCommand::send_back(&sender, Response::DataSourceStringUsizeUsize(data_source, path, column_index, row_index)),
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!(tr("source_data_for_field_not_found")))),
+                }
+            },
+
+            // When we want to know the row of the table where a loc key comes from...
+            Command::GetLocKeySourceRow(loc_key) => {
+                let dependencies = dependencies.read().unwrap();
+                let location = dependencies.loc_key_source(&loc_key)
+                    .and_then(|(ref_table, ref_column, ref_data)| definition_location(&pack_file_decoded, &dependencies, &ref_table, &ref_column, &ref_data))
+                    .map(|(data_source, path, _, row_index)| (data_source, path, row_index));
+
+                CentralCommand::send_back(&sender, Response::OptionDataSourceStringUsize(location));
+            },
+
+            // When we want to know where the provided loc keys are...
+            Command::GetLocEntries(loc_keys) => {
+                let dependencies = dependencies.read().unwrap();
+                let mut missing_keys = loc_keys;
+                let mut entries = vec![];
+
+                // Keys are searched in the Pack first, then in the parent files, then in the vanilla files.
+                // Only the first source where a key is found is used, as that's the one the game uses.
+                let mut local_locs = pack_file_decoded.files_by_type(&[FileType::Loc]);
+                local_locs.sort_by(|x, y| x.path_in_container_raw().cmp(y.path_in_container_raw()));
+
+                let sources = vec![
+                    (DataSource::PackFile, local_locs),
+                    (DataSource::ParentFiles, dependencies.loc_data(false, true).unwrap_or_default()),
+                    (DataSource::GameFiles, dependencies.loc_data(true, false).unwrap_or_default()),
+                ];
+
+                for (data_source, locs) in &sources {
+                    let mut found_keys = HashSet::new();
+                    for loc in locs {
+                        if let Ok(RFileDecoded::Loc(data)) = loc.decoded() {
+                            if let Ok(rows) = data.data(&None) {
+                                for (row_index, row) in rows.iter().enumerate() {
+                                    let key = row[0].data_to_string();
+                                    if missing_keys.iter().any(|missing_key| *missing_key == key) {
+                                        entries.push((*data_source, loc.path_in_container_raw().to_owned(), row_index));
+                                        found_keys.insert(key.to_string());
+                                    }
                                 }
                             }
                         }
                     }
-                }
 
-                if !found {
-                    let dependencies = dependencies.read().unwrap();
-                    let tables = dependencies.asskit_only_db_tables();
-                    for (table_name, table) in tables {
-                        if table.table_name() == table_name {
-                            if let Some((column_index, row_index)) = table.table().rows_containing_data(&ref_column, &ref_data) {
-                                let path = format!("{}/ak_data", &table_folder);
-                                CentralCommand::send_back(&sender, Response::DataSourceStringUsizeUsize(DataSource::AssKitFiles, path, column_index, row_index[0]));
-                                found = true;
-                                break;
-                            }
-                        }
+                    missing_keys.retain(|key| !found_keys.contains(key));
+                    if missing_keys.is_empty() {
+                        break;
                     }
                 }
 
-                if !found {
-                    CentralCommand::send_back(&sender, Response::Error(anyhow!(tr("source_data_for_field_not_found"))));
+                CentralCommand::send_back(&sender, Response::VecDataSourceStringUsizeVecString(entries, missing_keys));
+            },
+
+            // When we want to add entries for the provided loc keys to the open PackFile...
+            Command::AddLocEntries(loc_keys) => {
+
+                // New entries go to the first loc of the Pack. If there is none, we create one named after the Pack.
+                let mut loc_paths = pack_file_decoded.files_by_type(&[FileType::Loc]).iter().map(|file| file.path_in_container_raw().to_owned()).collect::<Vec<_>>();
+                loc_paths.sort();
+                let loc_path = match loc_paths.first() {
+                    Some(loc_path) => loc_path.to_owned(),
+                    None => {
+                        let pack_name = pack_file_decoded.disk_file_name();
+                        format!("text/db/{}.loc", pack_name.strip_suffix(".pack").unwrap_or(&pack_name))
+                    }
+                };
+
+                let loc_exists = !loc_paths.is_empty();
+                match pack_file_decoded.add_loc_entries(&loc_path, &loc_keys, "") {
+                    Ok((_, Some(path))) => if loc_exists {
+                        CentralCommand::send_back(&sender, Response::VecContainerPathVecContainerPath(vec![path], vec![]));
+                    } else {
+                        CentralCommand::send_back(&sender, Response::VecContainerPathVecContainerPath(vec![], vec![path]));
+                    },
+                    Ok((_, None)) => CentralCommand::send_back(&sender, Response::VecContainerPathVecContainerPath(vec![], vec![])),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                 }
             },
 
             Command::SearchReferences(reference_map, value) => {
                 let paths = reference_map.keys().map(|x| ContainerPath::Folder(format!("db/{}", x))).collect::<Vec<ContainerPath>>();
-                let files = pack_file_decoded.files_by_paths(&paths, true);
-
-                let mut references: Vec<(DataSource, String, String, usize, usize)> = vec![];
-
-                // Pass for local tables.
-                for (table_name, columns) in &reference_map {
-                    for file in &files {
-                        if file.db_table_name_from_path().unwrap() == table_name {
-                            if let Ok(RFileDecoded::DB(data)) = file.decoded() {
-                                for column_name in columns {
-                                    if let Some((column_index, row_indexes)) = data.table().rows_containing_data(column_name, &value) {
-                                        for row_index in &row_indexes {
-                                            references.push((DataSource::PackFile, file.path_in_container_raw().to_owned(), column_name.to_owned(), column_index, *row_index));
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+                let dependencies = dependencies.read().unwrap();
+
+                // Only the tables the schema says reference the column are checked: the local ones, then the parent ones, then the vanilla ones.
+                let mut local_tables = pack_file_decoded.files_by_paths(&paths, true);
+                local_tables.sort_by(|x, y| x.path_in_container_raw().cmp(y.path_in_container_raw()));
 
-                // Pass for parent tables.
-                for (table_name, columns) in &reference_map {
-                        if let Ok(tables) = dependencies.read().unwrap().db_data(table_name, false, true) {
-                        references.append(&mut tables.par_iter().map(|table| {
+                let sources = vec![
+                    (DataSource::PackFile, local_tables),
+                    (DataSource::ParentFiles, reference_map.keys().filter_map(|table_name| dependencies.db_data(table_name, false, true).ok()).flatten().collect::<Vec<_>>()),
+                    (DataSource::GameFiles, reference_map.keys().filter_map(|table_name| dependencies.db_data(table_name, true, false).ok()).flatten().collect::<Vec<_>>()),
+                ];
+
+                // Results keep the order of the sources, so they're grouped by source.
+                let references = sources.par_iter()
+                    .flat_map(|(data_source, tables)| tables.par_iter()
+                        .flat_map(|table| {
                             let mut references = vec![];
-                            if let Ok(RFileDecoded::DB(data)) = table.decoded() {
-                                for column_name in columns {
-                                    if let Some((column_index, row_indexes)) = data.table().rows_containing_data(column_name, &value) {
-                                        for row_index in &row_indexes {
-                                            references.push((DataSource::ParentFiles, table.path_in_container_raw().to_owned(), column_name.to_owned(), column_index, *row_index));
+                            if let Some(columns) = table.db_table_name_from_path().and_then(|table_name| reference_map.get(table_name)) {
+                                if let Ok(RFileDecoded::DB(data)) = table.decoded() {
+                                    for column_name in columns {
+                                        if let Some((column_index, row_indexes)) = data.table().rows_containing_data(column_name, &value) {
+                                            for row_index in &row_indexes {
+                                                references.push((*data_source, table.path_in_container_raw().to_owned(), column_name.to_owned(), column_index, *row_index));
+                                            }
                                         }
                                     }
                                 }
                             }
 
                             references
-                        }).flatten().collect());
+                        })
+                        .collect::<Vec<_>>()
+                    )
+                    .collect::<Vec<(DataSource, String, String, usize, usize)>>();
+
+                CentralCommand::send_back(&sender, Response::VecDataSourceStringStringUsizeUsize(references));
+            },
+
+            Command::DeepCloneKeyPreview(table_name, key, new_key, include_vanilla) => {
+                match *read_schema() {
+                    Some(ref schema) => match DeepClone::new(&mut pack_file_decoded, &dependencies.read().unwrap(), schema, &table_name, &key, &new_key, include_vanilla) {
+                        Ok(deep_clone) => CentralCommand::send_back(&sender, Response::DeepClone(deep_clone)),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                     }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
                 }
+            },
 
-                // Pass for vanilla tables.
-                for (table_name, columns) in &reference_map {
-                    if let Ok(tables) = dependencies.read().unwrap().db_data(table_name, true, false) {
-                        references.append(&mut tables.par_iter().map(|table| {
-                            let mut references = vec![];
-                            if let Ok(RFileDecoded::DB(data)) = table.decoded() {
-                                for column_name in columns {
-                                    if let Some((column_index, row_indexes)) = data.table().rows_containing_data(column_name, &value) {
-                                        for row_index in &row_indexes {
-                                            references.push((DataSource::GameFiles, table.path_in_container_raw().to_owned(), column_name.to_owned(), column_index, *row_index));
-                                        }
-                                    }
+            Command::DeepCloneKey(table_name, key, new_key, include_vanilla) => {
+                match *read_schema() {
+                    Some(ref schema) => match DeepClone::new(&mut pack_file_decoded, &dependencies.read().unwrap(), schema, &table_name, &key, &new_key, include_vanilla) {
+                        Ok(deep_clone) => match deep_clone.apply(&mut pack_file_decoded, schema) {
+                            Ok((modified_paths, added_paths)) => CentralCommand::send_back(&sender, Response::VecClonedRowVecContainerPathVecContainerPath(deep_clone.rows().to_vec(), modified_paths, added_paths)),
+                            Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                        }
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
+                }
+            },
+
+            Command::GetTemplateCollisions(name, values) => {
+                match templates_path().and_then(|path| Template::load(&path.join(format!("{}.json", name))).map_err(From::from)) {
+                    Ok(template) => match template.collisions(&pack_file_decoded, &values) {
+                        Ok(collisions) => CentralCommand::send_back(&sender, Response::VecString(collisions)),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            },
+
+            Command::ApplyTemplate(name, values) => {
+                match templates_path().and_then(|path| Template::load(&path.join(format!("{}.json", name))).map_err(From::from)) {
+                    Ok(template) => match *read_schema() {
+                        Some(ref schema) => {
+                            let paths = template.paths(&values).unwrap_or_default().into_iter().map(ContainerPath::File).collect::<Vec<_>>();
+                            let previous = ContainerJournal::snapshot(&pack_file_decoded, &paths);
+                            match template.apply(&mut pack_file_decoded, &dependencies.read().unwrap(), schema, &values) {
+                                Ok(added_paths) => {
+                                    container_journal.set_memory_cap(container_journal_memory_cap());
+                                    notify_not_kept_in_journal(&container_journal.push_addition(&added_paths, previous));
+                                    CentralCommand::send_back(&sender, Response::VecContainerPath(added_paths));
                                 }
+                                Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                             }
-
-                            references
-                        }).flatten().collect());
+                        }
+                        None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
                     }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
                 }
+            },
 
-                CentralCommand::send_back(&sender, Response::VecDataSourceStringStringUsizeUsize(references));
+            Command::GetTableDiff(path) => {
+                match pack_file_decoded.files().get(&path) {
+                    Some(file) => match TableDataDiff::new(file, &dependencies.read().unwrap()) {
+                        Ok(diff) => CentralCommand::send_back(&sender, Response::TableDataDiff(diff)),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("This PackedFile no longer exists in the PackFile."))),
+                }
             },
 
             Command::GoToLoc(loc_key) => {
@@ -1472,6 +2570,27 @@ pub fn background_loop() {
                 }
             },
 
+            // When we want to guess the definition of a table we cannot decode...
+            Command::GuessTableDefinition(path) => {
+                match pack_file_decoded.files_mut().get_mut(&path) {
+                    Some(ref mut rfile) => match rfile.load().and_then(|_| rfile.cached().map(|data| data.to_vec())) {
+                        Ok(data) => {
+                            let mut reader = Cursor::new(data);
+                            match DB::read_header(&mut reader) {
+                                Ok((version, _, _, entry_count)) => {
+                                    let position = reader.position() as usize;
+                                    let guesses = guess_definitions(&reader.get_ref()[position..], version, entry_count, GUESSABLE_FIELD_TYPES, 20);
+                                    CentralCommand::send_back(&sender, Response::VecGuessedDefinition(guesses));
+                                }
+                                Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                            }
+                        }
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("This PackedFile no longer exists in the PackFile."))),
+                }
+            }
+
             Command::ImportDependenciesToOpenPackFile(paths_by_data_source) => {
                 let mut added_paths = vec![];
 
@@ -1488,9 +2607,10 @@ pub fn background_loop() {
                         },
                     };
 
+                    let game = read_game_selected();
                     for file in files.into_values() {
                         let mut file = file.clone();
-                        let _ = file.guess_file_type();
+                        let _ = file.guess_file_type_for_game(&game);
                         if let Ok(Some(path)) = pack_file_decoded.insert(file) {
                             added_paths.push(path);
                         }
@@ -1563,7 +2683,7 @@ pub fn background_loop() {
             },
 
             Command::SavePackedFilesToPackFileAndClean(files) => {
-                let schema = SCHEMA.read().unwrap();
+                let schema = read_schema();
                 match &*schema {
                     Some(ref schema) => {
 
@@ -1581,7 +2701,7 @@ pub fn background_loop() {
                         added_paths.dedup();
 
                         // Then, optimize the PackFile. This should remove any non-edited rows/files.
-                        match pack_file_decoded.optimize(&mut dependencies.write().unwrap(), schema, false) {
+                        match pack_file_decoded.optimize(&mut dependencies.write().unwrap(), schema, false, &OptimizerOptions::default(), &|_, _, _| {}, &AtomicBool::new(false)) {
                             Ok(paths_to_delete) => CentralCommand::send_back(&sender, Response::VecContainerPathVecContainerPath(added_paths, paths_to_delete.into_iter()
                                 .map(ContainerPath::File)
                                 .collect())),
@@ -1625,15 +2745,21 @@ pub fn background_loop() {
                 let filename = "definitionpatch.json";
                 let data = serde_json::to_string_pretty(&patch).unwrap();
                 dbg!(&data);
-                match Logger::send_event(&SENTRY_GUARD.read().unwrap(), Level::Info, &format!("Schema patch for game: {}, table: {}", GAME_SELECTED.read().unwrap().display_name(), table_name), Some((filename, data.as_bytes()))) {
+                match Logger::send_event(&read_sentry_guard(), Level::Info, &format!("Schema patch for game: {}, table: {}", read_game_selected().display_name(), table_name), Some((filename, data.as_bytes()))) {
                     Ok(_) => CentralCommand::send_back(&sender, Response::Success),
                     Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                 }
             }
 
-            Command::ImportSchemaPatch(patch) => {
-                match *SCHEMA.write().unwrap() {
+            Command::ImportSchemaPatch(mut patch) => {
+                match *write_schema() {
                     Some(ref mut schema) => {
+
+                        // Patches without timestamp are considered made at the moment of importing them.
+                        if let Ok(timestamp) = current_time() {
+                            stamp_patches(&mut patch, timestamp);
+                        }
+
                         schema.add_patch(patch);
                         CentralCommand::send_back(&sender, Response::Success);
                     }
@@ -1641,15 +2767,60 @@ pub fn background_loop() {
                 }
             }
 
-            Command::GenerateMissingLocData => {
-                match pack_file_decoded.generate_missing_loc_data() {
-                    Ok(path) => CentralCommand::send_back(&sender, Response::OptionContainerPath(path)),
+            Command::ReleaseMemory => {
+                let released = release_memory(&mut pack_file_decoded, &mut pack_files_decoded_extra, &mut compared_pack, &dependencies);
+                CentralCommand::send_back(&sender, Response::U64(released));
+            }
+
+            Command::GetSchemaPatchedTables => {
+                match *read_schema() {
+                    Some(ref schema) => {
+                        let mut table_names = schema.patches().keys().cloned().collect::<Vec<_>>();
+                        table_names.sort();
+                        CentralCommand::send_back(&sender, Response::VecString(table_names));
+                    }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
+                }
+            }
+
+            Command::GetSchemaOverview => {
+                match *read_schema() {
+                    Some(ref schema) => CentralCommand::send_back(&sender, Response::VecTableOverview(schema.overview())),
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
+                }
+            }
+
+            Command::ExportSchemaPatches(table_names) => {
+                match *read_schema() {
+                    Some(ref schema) => match export_patches_to_json(schema.patches(), &table_names) {
+                        Ok(json) => CentralCommand::send_back(&sender, Response::String(json)),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected."))),
+                }
+            }
+
+            // When we want to generate the missing loc entries of the open PackFile...
+            Command::GenerateMissingLocData(loc_path, value_template, include_parent_keys) => {
+                let existing_keys = match dependencies.read().unwrap().loc_keys(true, !include_parent_keys) {
+                    Ok(keys) => keys,
+                    Err(error) => {
+                        CentralCommand::send_back(&sender, Response::Error(From::from(error)));
+                        continue;
+                    }
+                };
+
+                match pack_file_decoded.generate_missing_loc_data(&loc_path, &value_template, &existing_keys) {
+                    Ok((amount, path)) => {
+                        let info = path.and_then(|path| pack_file_decoded.file(path.path_raw()).map(RFileInfo::from));
+                        CentralCommand::send_back(&sender, Response::UsizeOptionRFileInfo(amount, info));
+                    }
                     Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
                 }
             }
 
             // Initialize the folder for a MyMod, including the folder structure it needs.
-            Command::InitializeMyModFolder(mod_name, mod_game, sublime_support, vscode_support, git_support)  => {
+            Command::InitializeMyModFolder(mod_name, mod_game, sublime_support, vscode_support, git_support, skeleton_components)  => {
                 let mut mymod_path = setting_path(MYMOD_BASE_PATH);
                 if !mymod_path.is_dir() {
                     CentralCommand::send_back(&sender, Response::Error(anyhow!("MyMod path is not configured. Configure it in the settings and try again.")));
@@ -1671,6 +2842,14 @@ pub fn background_loop() {
                     continue;
                 };
 
+                // Create the folder structure the game expects, if we chose any part of it.
+                if let Some(game) = SUPPORTED_GAMES.game(&mod_game) {
+                    if let Err(error) = game.create_mymod_skeleton(&mymod_path, &mod_name, &skeleton_components) {
+                        CentralCommand::send_back(&sender, Response::Error(anyhow!("Error while creating the MyMod's folder structure: {}.", error.to_string())));
+                        continue;
+                    }
+                }
+
                 // Create a repo inside the MyMod's folder.
                 if let Some(gitignore) = git_support {
                     let git_integration = GitIntegration::new(&mymod_path, "", "", "");
@@ -1687,7 +2866,7 @@ pub fn background_loop() {
 
                 // If the tw_autogen supports the game, create the vscode and sublime configs for lua mods.
                 if sublime_support || vscode_support {
-                    if let Ok(lua_autogen_folder) = lua_autogen_game_path(&GAME_SELECTED.read().unwrap()) {
+                    if let Ok(lua_autogen_folder) = lua_autogen_game_path(&read_game_selected()) {
                         let lua_autogen_folder = lua_autogen_folder.to_string_lossy().to_string().replace('\\', "/");
 
                         // VSCode support.
@@ -1771,9 +2950,183 @@ pub fn background_loop() {
                 CentralCommand::send_back(&sender, Response::PathBuf(mymod_path));
             }
 
+            // In case we want to add the starter files of the MyMod skeleton to a new MyMod Pack...
+            Command::AddMyModSkeletonToPack(mod_name, skeleton_components) => {
+                let (folders, files) = read_game_selected().mymod_skeleton_layout(&mod_name, &skeleton_components);
+                let timestamp = current_time().unwrap_or_default();
+                for (path, contents) in &files {
+                    let _ = pack_file_decoded.insert(RFile::new_from_vec(contents.as_bytes(), FileType::Text, timestamp, path));
+                }
+
+                // Packs cannot store empty folders, so we return them to keep them in the UI.
+                let empty_folders = folders.into_iter()
+                    .filter(|folder| !files.iter().any(|(path, _)| path.starts_with(&format!("{}/", folder))))
+                    .map(ContainerPath::Folder)
+                    .collect();
+
+                CentralCommand::send_back(&sender, Response::VecContainerPath(empty_folders));
+            }
+
+            // In case we want to check the files of the open Pack for corruption...
+            Command::VerifyPackIntegrity => {
+                if let Some(ref schema) = *read_schema() {
+                    let mut extra_data = DecodeableExtraData::default();
+                    extra_data.set_schema(Some(schema));
+                    let extra_data = Some(extra_data);
+
+                    // Lowercased vanilla paths, so we can find case-insensitive collisions without checking every file against every vanilla file.
+                    let dependencies = dependencies.read().unwrap();
+                    let vanilla_paths = dependencies.vanilla_files()
+                        .par_iter()
+                        .map(|(path, _)| (path.to_lowercase(), path.to_owned()))
+                        .collect::<HashMap<_, _>>();
+
+                    let failures = pack_file_decoded.files_mut()
+                        .par_iter_mut()
+                        .flat_map(|(path, file)| {
+                            let mut failures = vec![];
+
+                            // Exact matches are just overwrites. Only different casing is a problem.
+                            if let Some(vanilla_path) = vanilla_paths.get(&path.to_lowercase()) {
+                                if vanilla_path != path {
+                                    failures.push(("verify_integrity_vanilla_path_collision", path.to_owned()));
+                                }
+                            }
+
+                            // Encrypted files are skipped, and unknown files cannot be corrupt because there is nothing to decode in them.
+                            if file.is_encrypted() {
+                                failures.push(("verify_integrity_skipped_encrypted", path.to_owned()));
+                            } else if file.file_type() != FileType::Unknown {
+                                match file.decode(&extra_data, false, false) {
+                                    Ok(_) => {},
+                                    Err(RLibError::DecodingMismatchSizeError(_, _)) => failures.push(("verify_integrity_size_mismatch", path.to_owned())),
+                                    Err(_) => failures.push(("verify_integrity_decoding_failed", path.to_owned())),
+                                }
+                            }
+
+                            failures
+                        })
+                        .collect::<Vec<_>>();
+
+                    let mut report: BTreeMap<String, Vec<String>> = BTreeMap::new();
+                    for (reason, path) in failures {
+                        report.entry(reason.to_owned()).or_default().push(path);
+                    }
+
+                    report.values_mut().for_each(|paths| paths.sort());
+                    CentralCommand::send_back(&sender, Response::BTreeMapStringVecString(report));
+                } else {
+                    CentralCommand::send_back(&sender, Response::Error(anyhow!("There is no Schema for the Game Selected.")));
+                }
+            }
+
+            Command::SearchEsfNodes(path, query) => {
+                match pack_file_decoded.file_mut(&path) {
+                    Some(file) => match file.decode(&None, true, true).transpose().unwrap() {
+                        Ok(RFileDecoded::ESF(esf)) => CentralCommand::send_back(&sender, Response::VecESFNodePath(esf.search_nodes(&query))),
+                        Ok(_) => CentralCommand::send_back(&sender, Response::Error(anyhow!("The file with the following path is not an ESF file: {}", path))),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                    }
+                    None => CentralCommand::send_back(&sender, Response::Error(anyhow!("File with the following path not found in the Pack: {}", path))),
+                }
+            }
+
+            Command::GetEsfBookmarks(path) => CentralCommand::send_back(&sender, Response::VecESFNodePath(pack_file_decoded.settings().esf_bookmarks(&path))),
+            Command::SetEsfBookmarks(path, bookmarks) => pack_file_decoded.settings_mut().set_esf_bookmarks(&path, &bookmarks),
+            Command::GetPathLabels => CentralCommand::send_back(&sender, Response::VecStringStringHashMapContainerPathString(pack_file_decoded.settings().path_label_definitions(), pack_file_decoded.settings().path_labels())),
+            Command::SetPathLabel(path, label) => pack_file_decoded.settings_mut().set_path_label(&path, label.as_deref()),
+            Command::GetRowAnnotations(path) => CentralCommand::send_back(&sender, Response::BTreeMapStringString(pack_file_decoded.settings().row_annotations().get(&path).cloned().unwrap_or_default())),
+            Command::SetRowAnnotation(path, key, note) => pack_file_decoded.settings_mut().set_row_annotation(&path, &key, note.as_deref()),
+            Command::RekeyRowAnnotations(path, changes) => pack_file_decoded.settings_mut().rekey_row_annotations(&path, &changes),
+
+            // Annotations are never dropped, so the ones whose row no longer exists are returned marked as orphaned.
+            Command::GetAllRowAnnotations => {
+                let schema = read_schema();
+                let mut extra_data = DecodeableExtraData::default();
+                extra_data.set_schema(schema.as_ref());
+                let orphaned = pack_file_decoded.orphaned_row_annotations(&Some(extra_data));
+                let annotations = pack_file_decoded.settings().row_annotations().iter()
+                    .flat_map(|(path, notes)| notes.iter().map(|(key, note)| {
+                        let is_orphaned = orphaned.contains(&(path.to_owned(), key.to_owned()));
+                        (path.to_owned(), key.to_owned(), note.to_owned(), is_orphaned)
+                    }))
+                    .collect();
+
+                CentralCommand::send_back(&sender, Response::VecStringStringStringBool(annotations));
+            }
+
+            // In case we want to get the Packs from the Steam Workshop items we're subscribed to. Titles fall back to the item id if we can't find them.
+            Command::GetWorkshopPacks => {
+                let game_selected = read_game_selected();
+                match WorkshopPack::read_from_game_path(&game_selected, &setting_path(&game_selected.game_key_name())) {
+                    Ok(packs) => {
+                        let packs = packs.iter()
+                            .map(|pack| (pack.id().to_owned(), pack.display_title().to_owned(), pack.path().to_owned()))
+                            .collect();
+                        CentralCommand::send_back(&sender, Response::VecStringStringPathBuf(packs));
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+            }
+
             // These two belong to the network thread, not to this one!!!!
             Command::CheckUpdates | Command::CheckSchemaUpdates | Command::CheckMessageUpdates | Command::CheckLuaAutogenUpdates => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
         }
+
+        if is_heavy_command {
+            release_memory_over_ceiling(&mut pack_file_decoded, &mut pack_files_decoded_extra, &mut compared_pack, &dependencies);
+        }
+
+        update_pack_watcher(&mut pack_watcher, &pack_file_decoded);
+    }
+}
+
+/// Guard that marks the command being executed by the background thread as finished when dropped.
+struct BackgroundCommandGuard;
+
+impl Drop for BackgroundCommandGuard {
+    fn drop(&mut self) {
+        CENTRAL_COMMAND.finish_background_command();
+    }
+}
+
+/// This function generates the dependencies cache for the game selected, and loads it for the provided Pack.
+///
+/// If the generation fails or gets cancelled, the error is sent back and false is returned.
+fn generate_dependencies_cache(sender: &Sender<Response>, dependencies: &Arc<RwLock<Dependencies>>, pack: &Pack, cancel: &AtomicBool) -> bool {
+    let game_selected = read_game_selected();
+    let game_path = setting_path(&game_selected.game_key_name());
+    let asskit_path = assembly_kit_path().ok();
+
+    if !game_path.is_dir() {
+        CentralCommand::send_back(sender, Response::Error(anyhow!("Game Path not configured. Go to <i>'PackFile/Preferences'</i> and configure it.")));
+        return false;
+    }
+
+    let progress = |current, total, description: &str| CentralCommand::send_back(sender, Response::Progress(current, total, description.to_owned()));
+    match Dependencies::generate_dependencies_cache(&game_selected, &game_path, &asskit_path, &progress, cancel) {
+        Ok(mut cache) => {
+            progress(0, 0, "Saving dependencies cache.");
+            let dependencies_path = dependencies_cache_path().unwrap().join(game_selected.dependencies_cache_file_name());
+            match cache.save(&dependencies_path) {
+                Ok(_) => {
+                    let _ = dependencies.write().unwrap().rebuild(&read_schema(), pack.dependencies(), Some(&dependencies_path), &game_selected, &game_path);
+                    true
+                },
+                Err(error) => {
+                    CentralCommand::send_back(sender, Response::error_kind(error));
+                    false
+                }
+            }
+        }
+        Err(RLibError::OperationCancelled) => {
+            CentralCommand::send_back(sender, Response::Cancelled);
+            false
+        }
+        Err(error) => {
+            CentralCommand::send_back(sender, Response::error_kind(error));
+            false
+        }
     }
 }
 
@@ -1790,10 +3143,10 @@ fn load_schemas(sender: &Sender<Response>, pack: &mut Pack, game: &GameInfo) {
     // Load the new schema.
     let schema_path = schemas_path().unwrap().join(game.schema_file_name());
     let _ = Schema::update(&schema_path, &PathBuf::from("schemas/patches.ron"), &game.game_key_name());         // Quick fix so we can load old schemas. To be removed once 4.0 lands.
-    *SCHEMA.write().unwrap() = Schema::load(&schema_path).ok();
+    replace_schema(Schema::load(&schema_path).ok());
 
     // Redecode all the tables in the open file.
-    if let Some(ref schema) = *SCHEMA.read().unwrap() {
+    if let Some(ref schema) = *read_schema() {
         let mut extra_data = DecodeableExtraData::default();
         extra_data.set_schema(Some(schema));
         let extra_data = Some(extra_data);
@@ -1807,3 +3160,299 @@ fn load_schemas(sender: &Sender<Response>, pack: &mut Pack, game: &GameInfo) {
     info!("Sending success after game selected change.");
     CentralCommand::send_back(sender, Response::Success);
 }
+
+/// Function to rebuild the dependencies in another thread, so the background thread can keep answering the UI while they load.
+///
+/// It only returns once the other thread holds the dependencies lock, so commands received after this one never see outdated dependencies.
+/// Commands that must not wait for the rebuild to finish should use `try_read`/`try_write` on the dependencies.
+fn rebuild_dependencies_in_background<F>(dependencies: &Arc<RwLock<Dependencies>>, rebuild: F)
+    where F: FnOnce(&mut Dependencies) + Send + 'static {
+
+    let (locked_sender, locked_receiver) = unbounded::<()>();
+    let dependencies = dependencies.clone();
+    thread::spawn(move || {
+        let mut dependencies = dependencies.write().unwrap();
+        let _ = locked_sender.send(());
+        rebuild(&mut dependencies);
+    });
+
+    let _ = locked_receiver.recv();
+}
+
+/// Function to get a path that doesn't exist on disk yet, by appending a numeric suffix to the file name if the provided one already exists.
+fn unique_path(path: PathBuf) -> PathBuf {
+    if !path.exists() {
+        return path;
+    }
+
+    let stem = path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+    let extension = path.extension().map(|extension| extension.to_string_lossy().to_string());
+
+    let mut index = 1;
+    loop {
+        let file_name = match extension {
+            Some(ref extension) => format!("{}_{}.{}", stem, index, extension),
+            None => format!("{}_{}", stem, index),
+        };
+
+        let new_path = path.with_file_name(file_name);
+        if !new_path.exists() {
+            return new_path;
+        }
+
+        index += 1;
+    }
+}
+
+/// Function to get the temporary folder where files of the provided Pack are extracted to be opened in external programs.
+fn external_temp_folder(pack: &Pack) -> PathBuf {
+    temp_dir().join(format!("rpfm_{}", pack.disk_file_name()))
+}
+
+/// Function to remove the files extracted from the provided Pack to be opened in external programs, if any.
+fn external_temp_files_clean(pack: &Pack) {
+    let folder = external_temp_folder(pack);
+    if folder.is_dir() {
+        let _ = remove_dir_all(folder);
+    }
+}
+
+/// Function to get the name of the folder where the autosaves of the provided Pack go.
+///
+/// If the Pack is itself an autosave, this returns the folder it's in, so restored autosaves keep rotating in the same place.
+fn autosave_pack_name(pack: &Pack) -> String {
+    let path = PathBuf::from(pack.disk_file_path());
+    if let Ok(autosaves_path) = backup_autosave_path() {
+        if path.starts_with(&autosaves_path) {
+            if let Some(folder_name) = path.parent().and_then(|parent| parent.file_name()) {
+                return folder_name.to_string_lossy().to_string();
+            }
+        }
+    }
+
+    let name = pack.disk_file_name();
+    if name.is_empty() {
+        "unnamed.pack".to_owned()
+    } else {
+        name
+    }
+}
+
+/// Function to generate the manifest of the provided Pack next to it, if the Pack has it enabled in its settings.
+///
+/// The Pack is expected to have just been saved to disk.
+fn save_manifest_if_enabled(pack: &Pack) -> anyhow::Result<()> {
+    if let Some(true) = pack.settings().setting_bool("generate_manifest_on_save") {
+        let pack_path = PathBuf::from(pack.disk_file_path());
+        Pack::manifest(&pack_path)?.save(&pack_path)?;
+    }
+
+    Ok(())
+}
+
+/// Function to autosave the provided Pack into its autosave folder, rotating out the oldest autosaves.
+///
+/// The UI only triggers this if the Pack has been modified since the last autosave.
+fn autosave_pack(pack: &Pack) -> anyhow::Result<()> {
+    let start = Instant::now();
+
+    let mut pack = pack.clone();
+    pack.files_mut().iter_mut().try_for_each(|(_, file)| file.load())?;
+
+    let mut data = vec![];
+    pack.encode(&mut data, &Some(EncodeableExtraData::default()))?;
+
+    let folder = pack_autosave_path(&autosave_pack_name(&pack))?;
+    DirBuilder::new().recursive(true).create(&folder)?;
+
+    let file_name = format!("{}.pack", OffsetDateTime::now_utc().format(&AUTOSAVE_DATE_FORMAT)?);
+    let path = unique_path(folder.join(file_name));
+    let mut file = BufWriter::new(File::create(&path)?);
+    file.write_all(&data)?;
+    file.flush()?;
+
+    // Remove the oldest autosaves over the limit. Minimum 1.
+    let slots = setting_int("autosave_slots").max(1) as usize;
+    for old_autosave in pack_autosaves(&autosave_pack_name(&pack))?.iter().skip(slots) {
+        std::fs::remove_file(old_autosave)?;
+    }
+
+    let threshold = setting_int("autosave_size_warning_threshold");
+    if threshold > 0 && data.len() as u64 > threshold as u64 * 1024 * 1024 {
+        let message = format!("Autosave of {} took {:.2} seconds, as the Pack is {} MB. Consider increasing the autosave interval or disabling autosaves for this Pack.", path.to_string_lossy(), start.elapsed().as_secs_f64(), data.len() / 1024 / 1024);
+        warn!("{}", message);
+        notify(NotificationLevel::Warning, message);
+    } else {
+        notify(NotificationLevel::Info, format!("Autosaved to {}.", path.to_string_lossy()));
+    }
+
+    Ok(())
+}
+
+/// This function returns the path the provided Pack has when installed in the data folder of the game selected.
+fn installed_pack_path(pack: &Pack) -> anyhow::Result<PathBuf> {
+    let game_selected = read_game_selected();
+    let local_mods_path = game_selected.local_mods_path(&setting_path(&game_selected.game_key_name()))?;
+    let pack_name = PathBuf::from(pack.disk_file_path()).file_name().map(|name| name.to_owned()).ok_or_else(|| anyhow!("The Pack has not been saved to disk yet."))?;
+    Ok(local_mods_path.join(pack_name))
+}
+
+/// This function writes the mod list of the game selected, so it loads the installed copy of the provided Pack, followed by its dependencies.
+///
+/// Dependencies are searched in the data folder first, then in the downloaded mods folder.
+fn write_mod_list(pack: &Pack) -> anyhow::Result<PathBuf> {
+    let game_selected = read_game_selected();
+    let game_path = setting_path(&game_selected.game_key_name());
+    let data_path = game_selected.data_path(&game_path)?;
+    let local_mods_path = game_selected.local_mods_path(&game_path)?;
+    let content_paths = game_selected.content_packs_paths(&game_path).unwrap_or_default();
+
+    let pack_name = PathBuf::from(pack.disk_file_path()).file_name().map(|name| name.to_owned()).ok_or_else(|| anyhow!("The Pack has not been saved to disk yet."))?;
+    let mut mod_paths = vec![local_mods_path.join(pack_name)];
+
+    for dependency in pack.dependencies() {
+        let data_pack_path = data_path.join(dependency);
+        if data_pack_path.is_file() {
+            mod_paths.push(data_pack_path);
+        } else {
+            match content_paths.iter().find(|path| path.file_name().map(|name| name.to_string_lossy() == *dependency).unwrap_or(false)) {
+                Some(path) => mod_paths.push(path.to_path_buf()),
+                None => return Err(anyhow!("The dependency \"{}\" is not installed. Install or subscribe to it before launching the game with this Pack.", dependency)),
+            }
+        }
+    }
+
+    Ok(game_selected.write_mod_list(&game_path, &mod_paths)?)
+}
+
+/// Function to send a notification about something done in the background to the UI, outside of the response to the current command.
+fn notify<S: Into<String>>(level: NotificationLevel, message: S) {
+    CENTRAL_COMMAND.send_notification(Response::Notification(level, message.into()));
+}
+
+/// This function makes sure the provided watcher is watching the open PackFile, or nothing if it's not on disk or watching is disabled.
+fn update_pack_watcher(pack_watcher: &mut Option<(PathBuf, RecommendedWatcher)>, pack: &Pack) {
+    let path = PathBuf::from(pack.disk_file_path());
+    if !setting_bool("watch_open_pack") || !path.is_file() {
+        *pack_watcher = None;
+    } else if pack_watcher.as_ref().map(|(watched_path, _)| *watched_path != path).unwrap_or(true) {
+        *pack_watcher = watch_pack(&path).map(|watcher| (path, watcher));
+    }
+}
+
+/// This function creates a watcher that notifies the UI when the PackFile at the provided path is changed on disk.
+///
+/// The folder of the PackFile is watched instead of the file, so we still notice when programs replace the file instead of writing to it.
+/// If the folder cannot be watched, like it happens with some network drives, it returns `None`.
+fn watch_pack(path: &Path) -> Option<RecommendedWatcher> {
+    let disk_path = path.to_path_buf();
+    let pack_path = path.canonicalize().ok()?;
+    let folder = pack_path.parent()?.to_path_buf();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        if let Ok(event) = event {
+            let is_change = match event.kind {
+                EventKind::Create(_) | EventKind::Remove(_) => true,
+                EventKind::Modify(kind) => !matches!(kind, ModifyKind::Metadata(_)),
+                _ => false,
+            };
+
+            if is_change && event.paths.iter().any(|event_path| *event_path == pack_path) {
+                CENTRAL_COMMAND.send_notification(Response::PackFileChangedOnDisk(disk_path.to_path_buf()));
+            }
+        }
+    }).ok()?;
+
+    watcher.watch(&folder, RecursiveMode::NonRecursive).ok()?;
+    Some(watcher)
+}
+
+/// This function releases the memory used by data of the open Packs and the dependencies that can be loaded again from disk.
+///
+/// DB and Loc tables are kept decoded, as diagnostics and references expect them in memory. It returns the amount of bytes released (estimated).
+fn release_memory(pack: &mut Pack, packs_extra: &mut BTreeMap<PathBuf, Pack>, compared_pack: &mut Option<Pack>, dependencies: &Arc<RwLock<Dependencies>>) -> u64 {
+    let released = pack.unload_files(&[FileType::DB, FileType::Loc]) +
+        packs_extra.values_mut().chain(compared_pack.iter_mut()).map(|pack| pack.unload_files(&[FileType::DB, FileType::Loc])).sum::<u64>() +
+        dependencies.write().unwrap().release_memory();
+
+    info!("Released {} bytes of memory.", released);
+    released
+}
+
+/// This function releases memory if RPFM is using more than the memory ceiling set in the settings.
+fn release_memory_over_ceiling(pack: &mut Pack, packs_extra: &mut BTreeMap<PathBuf, Pack>, compared_pack: &mut Option<Pack>, dependencies: &Arc<RwLock<Dependencies>>) {
+    let ceiling = setting_int("memory_ceiling_mb").max(0) as u64 * 1024 * 1024;
+    if ceiling == 0 {
+        return;
+    }
+
+    if let Some(usage) = process_memory_usage() {
+        if usage > ceiling {
+            let released = release_memory(pack, packs_extra, compared_pack, dependencies);
+            notify(NotificationLevel::Info, format!("RPFM was using {} MB, over the memory ceiling. Released approximately {} MB.", usage / 1024 / 1024, released / 1024 / 1024));
+        }
+    }
+}
+
+/// This function returns the max amount of memory the container journal can use, in bytes.
+fn container_journal_memory_cap() -> usize {
+    setting_int("container_undo_memory_cap").max(0) as usize * 1024 * 1024
+}
+
+/// This function reports the memory used by the container journal after undoing or redoing an operation, warning about any file it couldn't keep.
+fn notify_journal_changes(container_journal: &ContainerJournal, changes: &Option<JournalChanges>) {
+    if let Some(changes) = changes {
+        notify_not_kept_in_journal(changes.not_kept());
+        notify(NotificationLevel::Info, format!("The undo history is using {} MB.", container_journal.memory_usage() / 1024 / 1024));
+    }
+}
+
+/// This function warns about the files the container journal couldn't keep, as the change that removed them cannot be undone for them.
+fn notify_not_kept_in_journal(paths: &[String]) {
+    if !paths.is_empty() {
+        notify(NotificationLevel::Warning, format!("The following files are too big to keep in the undo history, so their removal cannot be undone: {}", paths.join(", ")));
+    }
+}
+
+/// This function finds the first row containing the provided data in the provided column of a table.
+///
+/// Tables are searched in the Pack first, then in the parent files, then in the vanilla files, and then in the Assembly Kit-only tables.
+/// It returns the source, path, column and row of the data, if found.
+fn definition_location(pack: &Pack, dependencies: &Dependencies, ref_table: &str, ref_column: &str, ref_data: &str) -> Option<(DataSource, String, usize, usize)> {
+    let table_name = format!("{}_tables", ref_table);
+    let table_folder = format!("db/{}", table_name);
+
+    let sources = vec![
+        (DataSource::PackFile, pack.files_by_path(&ContainerPath::Folder(table_folder.to_owned()), true)),
+        (DataSource::ParentFiles, dependencies.db_data(&table_name, false, true).unwrap_or_default()),
+        (DataSource::GameFiles, dependencies.db_data(&table_name, true, false).unwrap_or_default()),
+    ];
+
+    for (data_source, files) in sources {
+        for file in &files {
+            if let Ok(RFileDecoded::DB(data)) = file.decoded() {
+                if let Some((column_index, row_index)) = data.table().rows_containing_data(ref_column, ref_data) {
+                    return Some((data_source, file.path_in_container_raw().to_owned(), column_index, row_index[0]));
+                }
+            }
+        }
+    }
+
+    for (table_name, table) in dependencies.asskit_only_db_tables() {
+        if table.table_name() == table_name {
+            if let Some((column_index, row_index)) = table.table().rows_containing_data(ref_column, ref_data) {
+                return Some((DataSource::AssKitFiles, format!("{}/ak_data", &table_folder), column_index, row_index[0]));
+            }
+        }
+    }
+
+    None
+}
+
+/// Function to keep the layout of non-standard Loc files when replacing them with data from a view, as views only know about the rows.
+fn keep_loc_layout(file: &RFile, file_decoded: &mut RFileDecoded) {
+    if let (Ok(RFileDecoded::Loc(old_loc)), RFileDecoded::Loc(new_loc)) = (file.decoded(), file_decoded) {
+        if old_loc.is_non_standard() && !new_loc.is_non_standard() {
+            new_loc.set_row_padding(old_loc.row_padding().to_vec());
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the error kinds reported by the background thread.
+
+use anyhow::anyhow;
+
+use std::io;
+
+use rpfm_lib::error::RLibError;
+
+use super::{CentralCommand, ErrorKind, NotificationLevel, Response};
+
+#[test]
+fn test_error_kind() {
+    assert_eq!(ErrorKind::from_error(&RLibError::DecodingDBNoDefinitionsFound), ErrorKind::SchemaMissing);
+    assert_eq!(ErrorKind::from_error(&RLibError::DependenciesCacheVersionMismatch(0, 1)), ErrorKind::DependenciesCacheOutdated);
+    assert_eq!(ErrorKind::from_error(&RLibError::IOError(io::Error::from(io::ErrorKind::NotFound))), ErrorKind::Io);
+    assert_eq!(ErrorKind::from_error(&RLibError::NoPacksProvided), ErrorKind::Generic);
+
+    // Errors with context must be detected by their cause.
+    let error = anyhow::Error::from(RLibError::DependenciesCacheNotGeneratedorOutOfDate).context("Failed to load the dependencies.");
+    assert_eq!(ErrorKind::from_error(error.as_ref()), ErrorKind::DependenciesCacheOutdated);
+
+    match Response::error_kind(anyhow!("Something failed.")) {
+        Response::ErrorKind(kind, message) => {
+            assert_eq!(kind, ErrorKind::Generic);
+            assert_eq!(message, "Something failed.");
+        }
+        _ => panic!("Incorrect response"),
+    }
+}
+
+#[test]
+fn test_notifications() {
+    let central_command = CentralCommand::<Response>::default();
+    assert!(central_command.recv_notifications().is_empty());
+
+    central_command.send_notification(Response::Notification(NotificationLevel::Info, "Opened test.pack.".to_owned()));
+    central_command.send_notification(Response::Notification(NotificationLevel::Error, "Failed to save test.pack.".to_owned()));
+
+    // Notifications must come in order, and be removed from the channel once received.
+    let notifications = central_command.recv_notifications();
+    assert_eq!(notifications.len(), 2);
+    assert!(matches!(notifications[0], Response::Notification(NotificationLevel::Info, _)));
+    assert!(matches!(notifications[1], Response::Notification(NotificationLevel::Error, _)));
+    assert!(central_command.recv_notifications().is_empty());
+}
+
+#[test]
+#[cfg(target_os = "windows")]
+fn test_error_kind_file_locked() {
+    assert_eq!(ErrorKind::from_error(&io::Error::from_raw_os_error(32)), ErrorKind::FileLocked);
+}
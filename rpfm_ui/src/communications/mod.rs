@@ -16,26 +16,43 @@ use qt_core::QEventLoop;
 
 use anyhow::Error;
 use crossbeam::channel::{Receiver, Sender, unbounded};
+use getset::Getters;
+use serde_derive::{Serialize, Deserialize};
 
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error as StdError;
 use std::fmt::Debug;
+use std::io;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
+use rpfm_extensions::cascade_edition::{CascadeEdit, CascadeEdition};
+use rpfm_extensions::conflicts::PackConflicts;
+use rpfm_extensions::deep_clone::{ClonedRow, DeepClone};
 use rpfm_extensions::dependencies::TableReferences;
 use rpfm_extensions::diagnostics::Diagnostics;
+use rpfm_extensions::journal::JournalChanges;
+use rpfm_extensions::lua::LuaSyntaxError;
+use rpfm_extensions::optimizer::{OptimizerOptions, OptimizerReport};
 use rpfm_extensions::search::{GlobalSearch, MatchHolder};
+use rpfm_extensions::statistics::PackStatistics;
+use rpfm_extensions::table_diff::TableDataDiff;
+use rpfm_extensions::translation::report::{LanguageDetection, TranslationReport};
 
-use rpfm_lib::files::{anim_fragment::AnimFragment, anims_table::AnimsTable, ContainerPath, video::SupportedFormats, db::DB, esf::ESF, image::Image, loc::Loc, matched_combat::MatchedCombat, pack::PackSettings, RFile, RFileDecoded, rigidmodel::RigidModel, text::Text, uic::UIC};
+use rpfm_lib::files::{anim_fragment::AnimFragment, anims_table::AnimsTable, ContainerPath, ExtractionFormat, video::SupportedFormats, db::DB, esf::{ESF, ESFNodePath}, image::{Image, ImageHeader}, loc::Loc, matched_combat::MatchedCombat, pack::{PackDiff, PackHeader, PackRescueReport, PackSettings, PFHFlags, TableUpdates}, RFile, RFileDecoded, rigidmodel::RigidModel, table::MergeConflict, text::Text, uic::UIC};
+use rpfm_lib::error::RLibError;
 use rpfm_lib::games::pfh_file_type::PFHFileType;
 use rpfm_lib::integrations::{git::GitResponse, log::info};
-use rpfm_lib::schema::{Definition, DefinitionPatch, Field, Schema};
+use rpfm_lib::schema::{Definition, DefinitionPatch, GuessedDefinition, Schema, SchemaDiff, TableOverview};
 
 use crate::app_ui::NewPackedFile;
 use crate::backend::*;
 use crate::packedfile_views::DataSource;
 use crate::updater::APIResponse;
 
+#[cfg(test)] mod communications_test;
+
 /// This const is the standard message in case of message communication error. If this happens, crash the program.
 pub const THREADS_COMMUNICATION_ERROR: &str = "Error in thread communication system. Response received: ";
 pub const THREADS_SENDER_ERROR: &str = "Error in thread communication system. Sender failed to send message.";
@@ -54,7 +71,14 @@ pub struct CentralCommand<T: Send + Sync + Debug> {
     receiver_background: Receiver<(Sender<T>, Command)>,
     receiver_network: Receiver<(Sender<T>, Command)>,
 
+    /// Channel for notifications not tied to any specific command, like the ones shown in the Operations panel.
+    sender_notifications: Sender<T>,
+    receiver_notifications: Receiver<T>,
+
     try_lock: AtomicBool,
+
+    /// Amount of commands sent to the background thread that it hasn't finished yet, including the one being executed.
+    background_commands: AtomicUsize,
 }
 
 /// This enum defines the commands (messages) you can send to the background thread in order to execute actions.
@@ -100,11 +124,14 @@ pub enum Command {
     /// This command is used to open all the CA PackFiles for the game selected as one.
     LoadAllCAPackFiles,
 
+    /// This command is used to open only the provided CA PackFiles for the game selected as one.
+    LoadCAPackFilesFiltered(Vec<PathBuf>),
+
     /// This command is used when we want to get the `RFileInfo` of one or more `PackedFiles`.
     GetPackedFilesInfo(Vec<String>),
 
-    /// This command is used when we want to perform a `Global Search`. It requires the search info.
-    GlobalSearch(GlobalSearch),
+    /// This command is used when we want to perform a `Global Search`. It requires the search info, and the cancellation token of the search.
+    GlobalSearch(GlobalSearch, Arc<AtomicBool>),
 
     /// This command is used when we want to change the `Game Selected`. It contains the name of the game to select, and if we should rebuild the dependencies.
     SetGameSelected(String, bool),
@@ -114,14 +141,27 @@ pub enum Command {
 
     /// This command is used when we want to generate the dependencies cache for a game. It contains the path of the
     /// source raw db files, the `Raw DB Version` of the currently selected game, and if we should has the files or not.
-    GenerateDependenciesCache,
+    ///
+    /// It contains the cancellation token of the generation.
+    GenerateDependenciesCache(Arc<AtomicBool>),
+
+    /// This command is used when we want to refresh the dependencies cache, only re-reading the Packs that changed on disk.
+    /// If the cache cannot be refreshed, it's regenerated.
+    ///
+    /// It contains the cancellation token of the generation.
+    RefreshDependenciesCache(Arc<AtomicBool>),
 
     /// This command is used when we want to update the currently loaded Schema with data from the game selected's Assembly Kit.
     /// It contains the path of the source files, if needed.
     UpdateCurrentSchemaFromAssKit,
 
-    /// This command is used when we want to trigger an optimization pass over the currently open `PackFile`.
-    OptimizePackFile,
+    /// This command is used when we want to trigger an optimization pass over the currently open `PackFile`. It contains the optimizations to perform,
+    /// and the cancellation token of the optimization.
+    OptimizePackFile(OptimizerOptions, Arc<AtomicBool>),
+
+    /// This command is used when we want to know what an optimization pass over the currently open `PackFile` would do, without doing it.
+    /// It contains the optimizations to perform.
+    OptimizePackFileReport(OptimizerOptions),
 
     /// This command is used to patch the SiegeAI of a Siege Map for warhammer games.
     PatchSiegeAI,
@@ -129,22 +169,35 @@ pub enum Command {
     /// This command is used when we want to change the `Index Includes Timestamp` flag in the currently open `PackFile`
     ChangeIndexIncludesTimestamp(bool),
 
+    /// This command is used when we want to know what header flags cannot be enabled in the currently open `PackFile` for the `Game Selected`, and why.
+    GetPFHFlagsRestrictions,
+
     /// This command is used when we want to change the `Data is Compressed` flag in the currently open `PackFile`
     ChangeDataIsCompressed(bool),
 
     /// This command is used when we want to know the current path of our currently open `PackFile`.
     GetPackFilePath,
 
+    /// This command is used when we want to write the mod list of the game selected, so it loads our installed `PackFile` and its dependencies.
+    ///
+    /// It returns the path of the mod list written.
+    WriteModListForOpenPack,
+
     /// This command is used when we want to get the info of the provided `PackedFile`.
     GetRFileInfo(String),
 
+    /// This command is used when we want to get all the metadata we can about the provided file, for the File Info panel.
+    ///
+    /// The file is searched in the open Pack first, then in the dependencies.
+    GetRFileDetailedInfo(String),
+
     /// This command is used when we want to check if there is an RPFM update available.
     CheckUpdates,
 
     /// This command is used when we want to check if there is an Schema update available.
     CheckSchemaUpdates,
 
-    /// This command is used when we want to update our schemas.
+    /// This command is used when we want to update our schemas. It returns the changes between the old and new schemas, and the tables in the open Pack.
     UpdateSchemas,
 
     /// This command is used when we want to know if there is a Dependency Database loaded in memory.
@@ -166,10 +219,20 @@ pub enum Command {
     DecodePackedFile(String, DataSource),
 
     // This command is used when we want to save an edited `PackedFile` back to the `PackFile`.
+    //
+    // Lua scripts are syntax-checked on save. The save is done even if there are errors, but the errors are returned instead of `Success`.
     SavePackedFileFromView(String, RFileDecoded),
 
-    // This command is used when we want to add a PackedFile from one PackFile into another.
-    AddPackedFilesFromPackFile((PathBuf, Vec<ContainerPath>)),
+    /// This command is used when we want to save multiple edited `PackedFiles` back to the `PackFile` in one go.
+    ///
+    /// It returns the list of paths, with the error that happened while saving each of them, if any.
+    SavePackedFilesFromViewBatch(Vec<(String, RFileDecoded)>),
+
+    // This command is used when we want to know which files of an extra PackFile already exist in our PackFile.
+    GetPackFileExtraConflicts((PathBuf, Vec<ContainerPath>)),
+
+    // This command is used when we want to add a PackedFile from one PackFile into another. The bool is for overwriting existing files.
+    AddPackedFilesFromPackFile((PathBuf, Vec<ContainerPath>, bool)),
 
     // This command is used when we want to add a PackedFile from our PackFile to an Animpack.
     AddPackedFilesFromPackFileToAnimpack(String, Vec<ContainerPath>),
@@ -183,12 +246,35 @@ pub enum Command {
     // This command is used when we want to delete one or more PackedFiles from a PackFile. It contains the ContainerPath of each PackedFile to delete.
     DeletePackedFiles(Vec<ContainerPath>),
 
-    // This command is used when we want to extract one or more PackedFiles from a PackFile. It contains the ContainerPaths to extract and the extraction path, and a bool to know if tables must be exported to tsv on extract or not.
-    ExtractPackedFiles(Vec<ContainerPath>, PathBuf, bool),
+    // This command is used when we want to extract one or more PackedFiles from a PackFile. It contains the ContainerPaths to extract, the extraction path, and the format in which tables must be extracted.
+    ExtractPackedFiles(Vec<ContainerPath>, PathBuf, ExtractionFormat),
+
+    /// This command is used when we want to export the whole PackFile to the assets folder of a MyMod. It contains the path of the assets folder.
+    /// The `mymod_export_rules` setting of the PackFile is applied to the exported paths.
+    ExportMyMod(PathBuf),
 
     // This command is used when we want to rename one or more PackedFiles in a PackFile. It contains a Vec with their original ContainerPath and their new name.
     RenamePackedFiles(Vec<(ContainerPath, ContainerPath)>),
 
+    /// This command is used when we want to rename one or more PackedFiles in a PackFile using a regex. It contains the ContainerPaths to rename,
+    /// the regex pattern, the replacement (which can use the pattern's capture groups), and if the pattern should be case-insensitive.
+    RenamePackedFilesByPattern(Vec<ContainerPath>, String, String, bool),
+
+    /// This command is used when we want to duplicate a DB or Loc table. It contains the path of the table, and the name (or path) for the copy.
+    DuplicateTable(String, String),
+
+    /// This command is used when we want to move one or more files or folders into another folder of the open PackFile. It contains the paths to move and the destination folder.
+    MoveFiles(Vec<ContainerPath>, String),
+
+    /// This command is used when we want to copy one or more files or folders into another folder of the open PackFile. It contains the paths to copy and the destination folder.
+    CopyFiles(Vec<ContainerPath>, String),
+
+    /// This command is used when we want to undo the last operation done over the structure of the open PackFile, like deleting, renaming or adding files.
+    UndoContainerOp,
+
+    /// This command is used when we want to redo the last operation undone over the structure of the open PackFile.
+    RedoContainerOp,
+
     /// This command is used when we want to know if a folder exists in the currently open PackFile.
     FolderExists(String),
 
@@ -205,12 +291,17 @@ pub enum Command {
     GetTableDefinitionFromDependencyPackFile(String),
 
     /// This command is used when we want to merge multiple compatible tables into one. The contents of this are as follows:
-    /// - Vec<Vec<String>>: List of paths to merge.
+    /// - Vec<ContainerPath>: List of paths to merge.
     /// - String: Path of the merged file.
     /// - Bool: Should we delete the source files after merging them?
-    MergeFiles(Vec<ContainerPath>, String, bool),
+    /// - Bool: Should we update the source tables to their newest version before merging them?
+    /// - Bool: Should we merge the tables even if they have conflicting rows?
+    MergeFiles(Vec<ContainerPath>, String, bool, bool, bool),
+
+    /// This command is used when we want to know which tables within the provided path can be updated to a newer version from the schema.
+    GetOutdatedTables(ContainerPath),
 
-    // This command is used when we want to update a table to a newer version.
+    /// This command is used when we want to update the tables within the provided path to the newest version in the schema.
     UpdateTable(ContainerPath),
 
     /// This command is used when we want to replace some specific matches in a Global Search.
@@ -223,11 +314,15 @@ pub enum Command {
     /// the list of paths to ignore, if any, and if any tsv found should be imported as tables.
     //AddPackedFilesFromFolder(Vec<(PathBuf, String)>, Option<Vec<PathBuf>>, bool),
 
-    /// This command is used to decode all tables referenced by columns in the provided definition and return their data.
-    /// It requires the table name, the definition of the table to get the reference data from and the list of PackedFiles to ignore.
-    GetReferenceDataFromDefinition(String, Definition),
+    /// This command is used when we want to add the contents of a zip (or 7z) archive to the Pack. It contains the path of the archive on disk,
+    /// the folder within the Pack to add its contents to, and if any tsv found should be imported as tables.
+    AddFilesFromArchive(PathBuf, String, bool),
 
-    /// This command is used to get the list of PackFiles that are marked as dependency of our PackFile.
+    /// This command is used to get the reference data of all columns in the provided definition that reference other tables.
+    /// It requires the table name and the definition of the table to get the reference data from. Vanilla and parent data is taken from the reference cache if possible.
+    GetReferenceDataCached(String, Definition),
+
+    /// This command is used to get the list of PackFiles that are marked as dependency of our PackFile, along with their path on disk, if they can be found.
     GetDependencyPackFilesList,
 
     /// This command is used to set the list of PackFiles that are marked as dependency of our PackFile.
@@ -242,6 +337,12 @@ pub enum Command {
     // This command is used to change the format of a ca_vp8 video packedfile. Requires the path of the PackedFile and the new format.
     SetVideoFormat(String, SupportedFormats),
 
+    /// This command is used to export a ca_vp8 video as a standard IVF file. Requires the internal and destination paths for the video.
+    ExportCaVp8(String, PathBuf),
+
+    /// This command is used to replace a ca_vp8 video with an IVF file from disk. Requires the internal path of the video and the path of the IVF file.
+    ImportCaVp8(String, PathBuf),
+
     // This command is used to save the provided schema to disk.
     SaveSchema(Schema),
 
@@ -251,16 +352,31 @@ pub enum Command {
     /// This command is used to export a table as TSV. Requires the internal and destination paths for the PackedFile.
     ExportTSV(String, PathBuf),
 
+    /// This command is used to export all the DB and Loc files within a folder of the open Pack to TSV files, keeping their folder structure.
+    ExportTSVFolder(ContainerPath, PathBuf),
+
     /// This command is used to import a TSV as a table. Requires the internal and destination paths for the PackedFile.
     ImportTSV(String, PathBuf),
 
-    /// This command is used to open in the defaul file manager the folder of the currently open PackFile.
-    OpenContainingFolder,
+    /// This command is used to export a table as CSV. Requires the internal and destination paths for the PackedFile, and the delimiter to use.
+    ExportCSV(String, PathBuf, u8),
+
+    /// This command is used to import a CSV as a table. Requires the internal and destination paths for the PackedFile, and the delimiter of the CSV, or None to guess it.
+    ImportCSV(String, PathBuf, Option<u8>),
+
+    /// This command is used to open in the default file manager the folder containing the provided target, selecting it if possible.
+    ///
+    /// If no target is provided, the currently open PackFile is used.
+    OpenContainingFolder(Option<FolderTarget>),
 
     /// This command is used to open a PackedFile on a external program. Requires the internal path of the PackedFile.
+    ///
+    /// DB and Loc files are exported as TSV before opening them.
     OpenPackedFileInExternalProgram(DataSource, ContainerPath),
 
     /// This command is used to save a PackedFile from an external program. Requires both, internal and external paths of the PackedFile.
+    ///
+    /// If the external file is a TSV, it's imported back into the table it was exported from.
     SavePackedFileFromExternalView(String, PathBuf),
 
     /// This command is used to update the program to the last version available, if possible.
@@ -269,26 +385,81 @@ pub enum Command {
     /// This command is used to trigger an autosave to a backup from time to time.
     TriggerBackupAutosave,
 
-    /// This command is used to trigger a full diagnostics check over the open PackFile.
-    DiagnosticsCheck(Vec<String>),
+    /// This command is used to get the paths of the available autosaves of the open PackFile, sorted from newest to oldest.
+    ListAutosaves,
+
+    /// This command is used to trigger a full diagnostics check over the open PackFile. It contains the ignored diagnostics, and the cancellation token of the check.
+    DiagnosticsCheck(Vec<String>, Arc<AtomicBool>),
 
     // This command is used to trigger a partial diagnostics check over the open PackFile.
     DiagnosticsUpdate(Diagnostics, Vec<ContainerPath>),
 
+    /// This command is used to check the paths referenced by a path list file (`.texture_array`, `.tai`,...) of the open PackFile. It contains its path.
+    CheckPathList(String),
+
+    /// This command is used to check the paths referenced by a VariantMeshDefinition against the open PackFile and its dependencies. It contains the contents of the file.
+    CheckVariantMeshDefinition(String),
+
     /// This command is used to get the settings of the currently open PackFile.
     GetPackSettings,
 
+    /// This command is used to get the header of the currently open PackFile.
+    GetPackHeader,
+
+    /// This command is used to set the authoring tool in the header of the currently open PackFile.
+    SetPackAuthoringTool(String),
+
+    /// This command is used to get statistics about the contents of the currently open PackFile.
+    GetPackStatistics,
+
+    /// This command is used to check what files of the currently open PackFile collide with the files of the Packs at the provided paths.
+    CheckConflicts(Vec<PathBuf>),
+
     // This command is used to set the settings of the currently open PackFile.
     SetPackSettings(PackSettings),
 
+    /// This command is used to add rules to ignore diagnostics to the settings of the currently open PackFile.
+    /// It contains the path of the file, the diagnostic and the indexes of the columns to ignore (empty for the entire file) of each rule.
+    AddDiagnosticsIgnoreRules(Vec<(String, String, Vec<i32>)>),
+
+    /// This command is used to get the notes of the currently open PackFile.
+    GetPackNotes,
+
+    /// This command is used to set the notes of the currently open PackFile. They're saved within the Pack on the next save.
+    SetPackNotes(String),
+
+    /// This command is used to compare the open Pack against the one at the provided path.
+    ComparePacks(PathBuf),
+
+    /// This command is used to sign the Pack at the provided path with the user's signing key, generating the key if needed.
+    /// It returns the path of the signature file.
+    SignPack(PathBuf),
+
+    /// This command is used to verify the Pack at the provided path against its signature file, using the public key at the second path.
+    VerifyPackSignature(PathBuf, PathBuf),
+
+    /// This command is used to export all the Loc files of the open Pack into a PO file at the provided path, for the provided language.
+    ExportLocsToPo(PathBuf, String),
+
+    /// This command is used to import the PO file at the provided path into the Loc files of the open Pack.
+    ImportLocsFromPo(PathBuf),
+
+    /// This command is used to compare the translations of the Loc files of the open Pack, getting each Loc's language the provided way,
+    /// and comparing the other languages against the provided one.
+    GetTranslationReport(LanguageDetection, String),
+
     /// This command is used to trigger the debug missing table definition's code.
     GetMissingDefinitions,
 
     /// This command is used to rebuild the dependencies of a PackFile. The bool is for rebuilding the whole dependencies, or just the mod-specific ones.
     RebuildDependencies(bool),
 
-    /// This command is used to trigger a cascade edition on all referenced data.
-    CascadeEdition(String, Definition, Vec<(Field, String, String)>),
+    /// This command is used to get the cells a cascade edition on all referenced data is going to change, without changing them.
+    /// It contains the table name, and the column name, old value and new value of each edited cell.
+    CascadeEditionPreview(String, Vec<(String, String, String)>),
+
+    /// This command is used to trigger a cascade edition on all referenced data. Same data as `CascadeEditionPreview`.
+    CascadeEdition(String, Vec<(String, String, String)>),
 
     /// This command is used for the Go To Definition feature. Contains table, column, and value to search.
     GoToDefinition(String, String, String),
@@ -299,9 +470,37 @@ pub enum Command {
     /// This command is used to get the loc file/column/row of a key. Contains the loc key to search.
     GoToLoc(String),
 
+    /// This command is used to get the source, path and row of the table row a loc key comes from. Contains the loc key to search.
+    GetLocKeySourceRow(String),
+
+    /// This command is used to get the source, path and row of the loc entries with the provided keys, and the keys not found.
+    GetLocEntries(Vec<String>),
+
+    /// This command is used to add empty entries with the provided keys to the first loc of the open PackFile, or to a new one if it has no locs.
+    AddLocEntries(Vec<String>),
+
     /// This command is used for the Find References feature. Contains list of table/columns to search, and value to search.
     SearchReferences(HashMap<String, Vec<String>>, String),
 
+    /// This command is used to get the rows a deep clone of a key would create, without creating them.
+    /// Contains the table, the key to clone, the new key, and if vanilla/parent rows should be cloned too.
+    DeepCloneKeyPreview(String, String, String, bool),
+
+    /// This command is used to deep clone a key into the open Pack, cloning with it the rows of other tables referencing it.
+    /// Contains the table, the key to clone, the new key, and if vanilla/parent rows should be cloned too.
+    DeepCloneKey(String, String, String, bool),
+
+    /// This command is used to get the paths of the files a template would overwrite in the open Pack.
+    /// Contains the name of the template, and the values of its parameters.
+    GetTemplateCollisions(String, HashMap<String, String>),
+
+    /// This command is used to create the files of a template in the open Pack, replacing existing files with the same paths.
+    /// Contains the name of the template, and the values of its parameters.
+    ApplyTemplate(String, HashMap<String, String>),
+
+    /// This command is used to diff the DB/Loc table at the provided path against the vanilla and parent data of the same table.
+    GetTableDiff(String),
+
     /// This command is used to get the type of a File.
     //GetFileType(String),
 
@@ -311,6 +510,9 @@ pub enum Command {
     /// This command is used to get the raw data of a PackedFile.
     GetPackedFileRawData(String),
 
+    /// This command is used to guess the possible definitions of the DB Table at the provided path, from its raw data.
+    GuessTableDefinition(String),
+
     /// This command is used to import files from the dependencies into out PackFile.
     ImportDependenciesToOpenPackFile(BTreeMap<DataSource, Vec<ContainerPath>>),
 
@@ -344,8 +546,24 @@ pub enum Command {
     /// This command is used to import a schema patch in the local schema patches.
     ImportSchemaPatch(HashMap<String, DefinitionPatch>),
 
-    /// This command is used to generate all missing loc entries for the currently open PackFile.
-    GenerateMissingLocData,
+    /// This command is used to release the memory used by data we can load again from disk.
+    ///
+    /// It returns the amount of bytes released (estimated).
+    ReleaseMemory,
+
+    /// This command is used to get the names of the tables with local schema patches.
+    GetSchemaPatchedTables,
+
+    /// This command is used to get a summary of all the tables in the schema of the game selected.
+    GetSchemaOverview,
+
+    /// This command is used to export the local schema patches of the provided tables as JSON. If no table is provided, all of them are exported.
+    ExportSchemaPatches(Vec<String>),
+
+    /// This command is used to generate all missing loc entries for the currently open PackFile into the provided loc.
+    ///
+    /// It requires the path of the loc, the template for the values of the new entries, and if we should also generate entries already in the parent mods.
+    GenerateMissingLocData(String, String, bool),
 
     /// This command is used to check for updates on the tw_autogen thing.
     CheckLuaAutogenUpdates,
@@ -353,8 +571,45 @@ pub enum Command {
     /// This command is used to update the tw_autogen thing.
     UpdateLuaAutogen,
 
-    /// This command is used to initialize a MyMod Folder.
-    InitializeMyModFolder(String, String, bool, bool, Option<String>),
+    /// This command is used to initialize a MyMod Folder. The last field contains the keys of the components of the game's MyMod skeleton to create in it.
+    InitializeMyModFolder(String, String, bool, bool, Option<String>, Vec<String>),
+
+    /// This command is used to add the starter files of the provided components of the game's MyMod skeleton to the open Pack.
+    /// It contains the name of the MyMod and the keys of the components, and returns the folders of the skeleton left empty.
+    AddMyModSkeletonToPack(String, Vec<String>),
+
+    /// This command is used to check all the files of the open Pack for corruption, grouping the broken ones by the reason they failed.
+    VerifyPackIntegrity,
+
+    /// This command is used to search the nodes of the ESF at the provided path whose name or value contains the provided text.
+    SearchEsfNodes(String, String),
+
+    /// This command is used to get the node bookmarks of the ESF at the provided path from the Pack Settings.
+    GetEsfBookmarks(String),
+
+    /// This command is used to replace the node bookmarks of the ESF at the provided path in the Pack Settings.
+    SetEsfBookmarks(String, Vec<ESFNodePath>),
+
+    /// This command is used to get the available labels and the labels assigned to files and folders from the Pack Settings.
+    GetPathLabels,
+
+    /// This command is used to set the label of a file or folder in the Pack Settings. `None` removes its label.
+    SetPathLabel(ContainerPath, Option<String>),
+
+    /// This command is used to get the notes of the rows of the table at the provided path from the Pack Settings, by row key.
+    GetRowAnnotations(String),
+
+    /// This command is used to get all the row notes of the Pack Settings, with their path, row key, and if their row no longer exists.
+    GetAllRowAnnotations,
+
+    /// This command is used to set the note of a row of a table in the Pack Settings. It contains the path, the row key and the note. `None` removes the note.
+    SetRowAnnotation(String, String, Option<String>),
+
+    /// This command is used to move the row notes of the table at the provided path from the old keys of its rows to the new ones.
+    RekeyRowAnnotations(String, Vec<(String, String)>),
+
+    /// This command is used to get the Packs of the Steam Workshop items the Game Selected is subscribed to.
+    GetWorkshopPacks,
 }
 
 /// This enum defines the responses (messages) you can send to the to the UI thread as result of a command.
@@ -369,15 +624,46 @@ pub enum Response {
     /// Generic response for situations that returned an error.
     Error(Error),
 
+    /// Response for situations that returned an error the UI may want to react to. It contains the kind of the error, and its message.
+    ///
+    /// Commands not yet migrated to this return `Response::Error` instead.
+    ErrorKind(ErrorKind, String),
+
+    /// Response to report the progress of a long command. It contains the current step, the total amount of steps, and a description of the current step.
+    ///
+    /// A total of 0 means the amount of steps is unknown. These are always followed by the final response of the command.
+    Progress(u32, u32, String),
+
+    /// Response to notify the UI of a significant action done in the background. It contains the level of the notification and its message.
+    ///
+    /// These are only sent through the notifications channel, never as a response to a command.
+    Notification(NotificationLevel, String),
+
+    /// Response to notify the UI that the open Pack has been changed on disk by another program. It contains the path of the Pack.
+    ///
+    /// Like `Notification`, this is only sent through the notifications channel.
+    PackFileChangedOnDisk(PathBuf),
+
+    /// Response for cancellable commands that have been stopped before finishing, due to their cancellation token being set.
+    ///
+    /// Commands that finish before noticing the token return their normal response instead.
+    Cancelled,
+
     /// Response to return (bool).
     Bool(bool),
 
     /// Response to return (i32).
     I32(i32),
 
+    /// Response to return (u64).
+    U64(u64),
+
     /// Response to return (PathBuf).
     PathBuf(PathBuf),
 
+    /// Response to return (Vec<PathBuf>).
+    VecPathBuf(Vec<PathBuf>),
+
     /// Response to return (String)
     String(String),
     OptionContainerPath(Option<ContainerPath>),
@@ -388,9 +674,15 @@ pub enum Response {
     // Response to return (ContainerInfo).
     ContainerInfo(ContainerInfo),
 
+    // Response to return (ContainerInfo, PackRescueReport, PathBuf).
+    ContainerInfoPackRescueReportPathBuf(ContainerInfo, PackRescueReport, PathBuf),
+
     // Response to return (Option<RFileInfo>).
     OptionRFileInfo(Option<RFileInfo>),
 
+    /// Response to return `Option<RFileDetailedInfo>`.
+    OptionRFileDetailedInfo(Option<RFileDetailedInfo>),
+
     // Response to return (Vec<Option<RFileInfo>>).
     VecRFileInfo(Vec<RFileInfo>),
 
@@ -430,8 +722,8 @@ pub enum Response {
     /// Response to return `(ESF, RFileInfo)`.
     ESFRFileInfo(ESF, RFileInfo),
 
-    /// Response to return `(Image, RFileInfo)`.
-    ImageRFileInfo(Image, RFileInfo),
+    /// Response to return `(Image, Option<ImageHeader>, RFileInfo)`.
+    ImageRFileInfo(Image, Option<ImageHeader>, RFileInfo),
 
     /// Response to return `(Text, RFileInfo)`.
     TextRFileInfo(Text, RFileInfo),
@@ -469,9 +761,33 @@ pub enum Response {
     /// Response to return `Vec<String>`.
     VecString(Vec<String>),
 
+    /// Response to return `Vec<TableOverview>`.
+    VecTableOverview(Vec<TableOverview>),
+
+    /// Response to return `Vec<LuaSyntaxError>`.
+    VecLuaSyntaxError(Vec<LuaSyntaxError>),
+
+    /// Response to return `Vec<(PFHFlags, String)>`.
+    VecPFHFlagsString(Vec<(PFHFlags, String)>),
+
+    /// Response to return `Vec<(String, Option<String>)>`.
+    VecStringOptionString(Vec<(String, Option<String>)>),
+
+    /// Response to return `Vec<(String, Option<PathBuf>)>`.
+    VecStringOptionPathBuf(Vec<(String, Option<PathBuf>)>),
+
     /// Response to return `(i32, i32)`.
     I32I32(i32, i32),
 
+    /// Response to return `TableUpdates`.
+    TableUpdates(TableUpdates),
+
+    /// Response to return `(Vec<MergeConflict>, Vec<ContainerPath>)`.
+    VecMergeConflictVecContainerPath(Vec<MergeConflict>, Vec<ContainerPath>),
+
+    /// Response to return `(usize, Option<RFileInfo>)`.
+    UsizeOptionRFileInfo(usize, Option<RFileInfo>),
+
     /// Response to return `BTreeMap<i32, DependencyData>`.
     HashMapI32TableReferences(HashMap<i32, TableReferences>),
 
@@ -484,12 +800,27 @@ pub enum Response {
     /// Response to return `PackFileSettings`.
     PackSettings(PackSettings),
 
+    /// Response to return `PackHeader`.
+    PackHeader(PackHeader),
+
+    /// Response to return `PackDiff`.
+    PackDiff(PackDiff),
+
     /// Response to return `Vec<Vec<String>>, Vec<RFileInfo>`.
     //VecVecStringVecRFileInfo(Vec<Vec<String>>, Vec<RFileInfo>),
 
     /// Response to return `DataSource, Vec<String>, usize, usize`.
     DataSourceStringUsizeUsize(DataSource, String, usize, usize),
 
+    /// Response to return `Option<JournalChanges>`.
+    OptionJournalChanges(Option<JournalChanges>),
+
+    /// Response to return `Option<(DataSource, String, usize)>`.
+    OptionDataSourceStringUsize(Option<(DataSource, String, usize)>),
+
+    /// Response to return `(Vec<(DataSource, String, usize)>, Vec<String>)`.
+    VecDataSourceStringUsizeVecString(Vec<(DataSource, String, usize)>, Vec<String>),
+
     /// Response to return `Vec<(DataSource, Vec<String>, String, usize, usize)>`.
     VecDataSourceStringStringUsizeUsize(Vec<(DataSource, String, String, usize, usize)>),
 
@@ -505,6 +836,9 @@ pub enum Response {
     /// Response to return `DependenciesInfo`.
     DependenciesInfo(DependenciesInfo),
 
+    /// Response to return `(DependenciesInfo, Vec<PathBuf>)`.
+    DependenciesInfoVecPathBuf(DependenciesInfo, Vec<PathBuf>),
+
     RFileDecoded(RFileDecoded),
 
     /// Response to return `HashMap<DataSource, HashMap<Vec<String>, PackedFile>>`.
@@ -517,8 +851,113 @@ pub enum Response {
     HashSetString(HashSet<String>),
     //StringHashSetString(String, HashSet<String>),
     StringVecContainerPath(String, Vec<ContainerPath>),
-    VecContainerPathVecRFileInfo(Vec<ContainerPath>, Vec<RFileInfo>),
-    VecContainerPathVecContainerPath(Vec<ContainerPath>, Vec<ContainerPath>)
+    VecCascadeEditVecRFileInfo(Vec<CascadeEdit>, Vec<RFileInfo>),
+    VecContainerPathVecContainerPath(Vec<ContainerPath>, Vec<ContainerPath>),
+
+    /// Response to return `(Vec<ContainerPath>, Vec<String>)`.
+    VecContainerPathVecString(Vec<ContainerPath>, Vec<String>),
+
+    /// Response to return `BTreeMap<String, Vec<String>>`.
+    BTreeMapStringVecString(BTreeMap<String, Vec<String>>),
+
+    /// Response to return `BTreeMap<String, String>`.
+    BTreeMapStringString(BTreeMap<String, String>),
+
+    /// Response to return `Vec<(String, String, String, bool)>`.
+    VecStringStringStringBool(Vec<(String, String, String, bool)>),
+
+    /// Response to return `Vec<ESFNodePath>`.
+    VecESFNodePath(Vec<ESFNodePath>),
+
+    /// Response to return `Vec<GuessedDefinition>`.
+    VecGuessedDefinition(Vec<GuessedDefinition>),
+
+    /// Response to return `(SchemaDiff, Vec<String>)`.
+    SchemaDiffVecString(SchemaDiff, Vec<String>),
+
+    /// Response to return `(usize, usize, Vec<ContainerPath>, Vec<ContainerPath>)`.
+    UsizeUsizeVecContainerPathVecContainerPath(usize, usize, Vec<ContainerPath>, Vec<ContainerPath>),
+
+    /// Response to return `DeepClone`.
+    DeepClone(DeepClone),
+
+    /// Response to return `CascadeEdition`.
+    CascadeEdition(CascadeEdition),
+
+    /// Response to return `TableDataDiff`.
+    TableDataDiff(TableDataDiff),
+
+    /// Response to return `(Vec<ClonedRow>, Vec<ContainerPath>, Vec<ContainerPath>)`.
+    VecClonedRowVecContainerPathVecContainerPath(Vec<ClonedRow>, Vec<ContainerPath>, Vec<ContainerPath>),
+
+    /// Response to return `(Vec<(String, String)>, HashMap<ContainerPath, String>)`.
+    VecStringStringHashMapContainerPathString(Vec<(String, String)>, HashMap<ContainerPath, String>),
+
+    /// Response to return `OptimizerReport`.
+    OptimizerReport(OptimizerReport),
+
+    /// Response to return `PackStatistics`.
+    PackStatistics(PackStatistics),
+
+    /// Response to return `PackConflicts`.
+    PackConflicts(PackConflicts),
+
+    /// Response to return `TranslationReport`.
+    TranslationReport(TranslationReport),
+
+    /// Response to return `Vec<(String, String, PathBuf)>`.
+    VecStringStringPathBuf(Vec<(String, String, PathBuf)>),
+}
+
+/// This enum defines what file `Command::OpenContainingFolder` has to show in the file manager.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FolderTarget {
+
+    /// The currently open PackFile, where it is on disk.
+    PackLocation,
+
+    /// The installed copy of the currently open PackFile, in the data folder of the Game Selected.
+    InstalledLocation,
+
+    /// Any file or folder on disk, like the ones extracted from the PackFile.
+    ExtractedPath(PathBuf),
+}
+
+/// This enum defines the levels of the notifications the background thread can send through `Response::Notification`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NotificationLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+/// This enum defines the kinds of errors the background thread can report through `Response::ErrorKind`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ErrorKind {
+
+    /// There is no schema for the Game Selected, or it lacks the definitions we need.
+    SchemaMissing,
+
+    /// The dependencies cache hasn't been generated, it's outdated, or it was generated by another version of RPFM.
+    DependenciesCacheOutdated,
+
+    /// A file cannot be accessed because another program, usually the game, has it open.
+    FileLocked,
+
+    /// Any other error while reading or writing files.
+    Io,
+
+    /// Any error we don't need to differentiate.
+    Generic,
+}
+
+/// This struct holds an error received through `Response::ErrorKind`, so it can be returned as an `anyhow::Error` without losing its kind.
+#[derive(Debug, Getters, thiserror::Error)]
+#[getset(get = "pub")]
+#[error("{message}")]
+pub struct ErrorWithKind {
+    kind: ErrorKind,
+    message: String,
 }
 
 //-------------------------------------------------------------------------------//
@@ -530,13 +969,17 @@ impl<T: Send + Sync + Debug> Default for CentralCommand<T> {
     fn default() -> Self {
         let (sender_background, receiver_background) = unbounded();
         let (sender_network, receiver_network) = unbounded();
+        let (sender_notifications, receiver_notifications) = unbounded();
         let try_lock = AtomicBool::new(false);
         Self {
             sender_background,
             sender_network,
             receiver_background,
             receiver_network,
+            sender_notifications,
+            receiver_notifications,
             try_lock,
+            background_commands: AtomicUsize::new(0),
         }
     }
 }
@@ -560,9 +1003,32 @@ impl<T: Send + Sync + Debug> CentralCommand<T> {
     ///
     /// It returns the receiver which will receive the answers for the command, if any.
     pub fn send_background(&self, data: Command) -> Receiver<T> {
+        self.background_commands.fetch_add(1, Ordering::SeqCst);
         Self::send(&self.sender_background, data)
     }
 
+    /// This function serves to send a cancellable message from the main thread to the background thread.
+    ///
+    /// The provided closure receives the cancellation token, to put it in the command. It returns the receiver which will
+    /// receive the answers for the command, and the token, which can be set to ask the background thread to stop the command.
+    pub fn send_background_cancellable<F: FnOnce(Arc<AtomicBool>) -> Command>(&self, data: F) -> (Receiver<T>, Arc<AtomicBool>) {
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.background_commands.fetch_add(1, Ordering::SeqCst);
+        (Self::send(&self.sender_background, data(cancel.clone())), cancel)
+    }
+
+    /// This function marks one of the commands sent to the background thread as finished.
+    ///
+    /// The background thread must call this once per command received, after it's done with it.
+    pub fn finish_background_command(&self) {
+        self.background_commands.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// This function returns if the background thread is executing a command, or has commands waiting to be executed.
+    pub fn is_background_busy(&self) -> bool {
+        self.background_commands.load(Ordering::SeqCst) > 0
+    }
+
     /// This function serves to send a message from the main thread to the network thread.
     ///
     /// It returns the receiver which will receive the answers for the command, if any.
@@ -577,6 +1043,22 @@ impl<T: Send + Sync + Debug> CentralCommand<T> {
         }
     }
 
+    /// This function serves to send a notification to the UI through the notifications channel.
+    ///
+    /// Unlike `send_back`, this doesn't need a command to answer to, so it can be used from anywhere.
+    pub fn send_notification(&self, data: T) {
+        if let Err(error) = self.sender_notifications.send(data) {
+            panic!("{}: {}", THREADS_SENDER_ERROR, error);
+        }
+    }
+
+    /// This function returns all the notifications waiting in the notifications channel.
+    ///
+    /// It never blocks, so it's safe to call it from the UI thread.
+    pub fn recv_notifications(&self) -> Vec<T> {
+        self.receiver_notifications.try_iter().collect()
+    }
+
     /// This functions serves to receive messages on the background thread.
     ///
     /// This function does only try once, and it locks the thread. Panics if the response fails.
@@ -646,3 +1128,80 @@ impl<T: Send + Sync + Debug> CentralCommand<T> {
         }
     }
 }
+
+/// Implementation of `CentralCommand` for the UI-Background communications.
+impl CentralCommand<Response> {
+
+    /// This functions serves to receive messages from a generated channel, reporting the progress of the command while waiting for its final response.
+    ///
+    /// It works like `recv_try`, but calls the provided closure for each `Response::Progress` received before the final response.
+    ///
+    /// NOTE: Beware of other events triggering when this keeps the UI enabled. It can lead to crashes.
+    pub fn recv_try_with_progress<F: FnMut(u32, u32, &str)>(&self, receiver: &Receiver<Response>, mut progress: F) -> Response {
+        loop {
+            match self.recv_try(receiver) {
+                Response::Progress(current, total, description) => progress(current, total, &description),
+                response => return response,
+            }
+        }
+    }
+}
+
+impl Response {
+
+    /// This function creates a `Response::ErrorKind` from the provided error, detecting its kind.
+    pub fn error_kind<E: Into<Error>>(error: E) -> Self {
+        let error = error.into();
+        Self::ErrorKind(ErrorKind::from_error(error.as_ref()), error.to_string())
+    }
+}
+
+impl ErrorKind {
+
+    /// This function returns the kind of the provided error, checking its causes if the error itself doesn't tell us.
+    pub fn from_error(error: &(dyn StdError + 'static)) -> Self {
+        let mut cause = Some(error);
+        while let Some(error) = cause {
+            if let Some(error) = error.downcast_ref::<RLibError>() {
+                match error {
+                    RLibError::DecodingDBNoDefinitionsFound |
+                    RLibError::DecodingDBNoDefinitionsFoundAndEmptyFile => return Self::SchemaMissing,
+                    RLibError::DependenciesCacheNotGeneratedorOutOfDate |
+                    RLibError::DependenciesCacheVersionMismatch(_, _) => return Self::DependenciesCacheOutdated,
+
+                    // IO errors are transparent, so their source is not the IO error itself.
+                    RLibError::IOError(error) => return Self::from_io_error(error),
+                    _ => {},
+                }
+            }
+
+            if let Some(error) = error.downcast_ref::<io::Error>() {
+                return Self::from_io_error(error);
+            }
+
+            cause = error.source();
+        }
+
+        Self::Generic
+    }
+
+    /// This function returns the kind of the provided IO error.
+    ///
+    /// Files open by the game are locked by Windows, so trying to write to them fails with a sharing or lock violation.
+    fn from_io_error(error: &io::Error) -> Self {
+        if cfg!(target_os = "windows") && matches!(error.raw_os_error(), Some(32) | Some(33)) {
+            Self::FileLocked
+        } else {
+            Self::Io
+        }
+    }
+}
+
+impl ErrorWithKind {
+    pub fn new(kind: ErrorKind, message: String) -> Self {
+        Self {
+            kind,
+            message,
+        }
+    }
+}
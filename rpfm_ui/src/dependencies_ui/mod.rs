@@ -114,6 +114,10 @@ impl DependenciesUI {
         let filter_autoexpand_matches_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_autoexpand_matches_button")?;
         let filter_case_sensitive_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_case_sensitive_button")?;
 
+        // Labels only exist for the open Pack, so there's nothing to filter by here.
+        let filter_label_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_label_button")?;
+        filter_label_button.set_visible(false);
+
         //-----------------------------------------------//
         // `PackFile Contents` DockWidget.
         //-----------------------------------------------//
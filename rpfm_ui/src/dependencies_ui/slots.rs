@@ -166,7 +166,8 @@ impl DependenciesUISlots {
                         DataSource::AssKitFiles => {
                             dependencies_ui.context_menu_import.set_enabled(false);
                         },
-                        DataSource::ExternalFile => {
+                        DataSource::ExternalFile |
+                        DataSource::ComparedPackFile => {
                             dependencies_ui.context_menu_import.set_enabled(false);
                         },
                     }
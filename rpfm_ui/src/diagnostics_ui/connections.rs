@@ -22,11 +22,16 @@ use super::{DiagnosticsUI, slots::DiagnosticsUISlots};
 /// to not pollute the other modules with a ton of connections.
 pub unsafe fn set_connections(ui: &DiagnosticsUI, slots: &DiagnosticsUISlots) {
     ui.diagnostics_table_view.double_clicked().connect(&slots.diagnostics_open_result);
+    ui.diagnostics_table_view.custom_context_menu_requested().connect(&slots.contextual_menu);
+    ui.context_menu_delete_identical_to_vanilla.triggered().connect(&slots.contextual_menu_delete_identical_to_vanilla);
+    ui.context_menu_ignore_for_file.triggered().connect(&slots.contextual_menu_ignore_for_file);
+    ui.context_menu_ignore_for_column.triggered().connect(&slots.contextual_menu_ignore_for_column);
 
     ui.diagnostics_button_check_packfile.released().connect(&slots.diagnostics_check_packfile);
     ui.diagnostics_button_check_current_packed_file.released().connect(&slots.diagnostics_check_currently_open_packed_file);
 
     ui.diagnostics_button_info.toggled().connect(&slots.toggle_filters);
+    ui.diagnostics_button_notice.toggled().connect(&slots.toggle_filters);
     ui.diagnostics_button_warning.toggled().connect(&slots.toggle_filters);
     ui.diagnostics_button_error.toggled().connect(&slots.toggle_filters);
     ui.diagnostics_button_only_current_packed_file.toggled().connect(&slots.toggle_filters);
@@ -58,4 +63,13 @@ pub unsafe fn set_connections(ui: &DiagnosticsUI, slots: &DiagnosticsUISlots) {
     ui.checkbox_incorrect_game_path.toggled().connect(&slots.toggle_filters);
     ui.checkbox_banned_table.toggled().connect(&slots.toggle_filters);
     ui.checkbox_value_cannot_be_empty.toggled().connect(&slots.toggle_filters);
+    ui.checkbox_missing_loc_data.toggled().connect(&slots.toggle_filters);
+    ui.checkbox_non_standard_loc_layout.toggled().connect(&slots.toggle_filters);
+    ui.checkbox_identical_to_vanilla.toggled().connect(&slots.toggle_filters);
+    ui.checkbox_dependency_loaded_after_pack.toggled().connect(&slots.toggle_filters);
+    ui.checkbox_invalid_path_list.toggled().connect(&slots.toggle_filters);
+    ui.checkbox_invalid_texture.toggled().connect(&slots.toggle_filters);
+    ui.checkbox_lua_syntax_error.toggled().connect(&slots.toggle_filters);
+    ui.checkbox_invalid_variant_mesh.toggled().connect(&slots.toggle_filters);
+    ui.checkbox_invalid_speedtree.toggled().connect(&slots.toggle_filters);
 }
@@ -13,11 +13,13 @@ Module with all the code related to the `DiagnosticsUI`.
 !*/
 
 use qt_widgets::q_abstract_item_view::ScrollHint;
+use qt_widgets::QAction;
 use qt_widgets::{QCheckBox, QVBoxLayout};
 use qt_widgets::QDockWidget;
 use qt_widgets::q_header_view::ResizeMode;
 use qt_widgets::QLabel;
 use qt_widgets::QMainWindow;
+use qt_widgets::QMenu;
 use qt_widgets::QScrollArea;
 use qt_widgets::QTableView;
 use qt_widgets::QToolButton;
@@ -29,7 +31,7 @@ use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 
-use qt_core::{CaseSensitivity, DockWidgetArea, Orientation, SortOrder};
+use qt_core::{CaseSensitivity, ContextMenuPolicy, DockWidgetArea, Orientation, SortOrder};
 use qt_core::QBox;
 use qt_core::QFlags;
 use qt_core::q_item_selection_model::SelectionFlag;
@@ -46,10 +48,11 @@ use cpp_core::Ptr;
 
 use anyhow::Result;
 use getset::Getters;
+use itertools::Itertools;
 
 use std::rc::Rc;
 
-use rpfm_extensions::diagnostics::{*, anim_fragment::*, config::*, dependency::*, pack::*, table::*};
+use rpfm_extensions::diagnostics::{*, anim_fragment::*, config::*, dependency::*, file::*, pack::*, table::*};
 
 use rpfm_lib::files::ContainerPath;
 use rpfm_lib::games::supported_games::*;
@@ -60,7 +63,7 @@ use crate::communications::{Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::CENTRAL_COMMAND;
 use crate::dependencies_ui::DependenciesUI;
 use crate::ffi::{new_tableview_filter_safe, trigger_tableview_filter_safe};
-use crate::GAME_SELECTED;
+use crate::locks::read_game_selected;
 use crate::global_search_ui::GlobalSearchUI;
 use crate::locale::{qtr, qtre, tr};
 use crate::pack_tree::*;
@@ -70,7 +73,7 @@ use crate::settings_ui::backend::*;
 use crate::UI_STATE;
 use crate::references_ui::ReferencesUI;
 use crate::utils::*;
-use crate::views::table::{ITEM_HAS_ERROR, ITEM_HAS_WARNING, ITEM_HAS_INFO};
+use crate::views::table::{filter::FilterType, ITEM_HAS_ERROR, ITEM_HAS_WARNING, ITEM_HAS_INFO};
 
 pub mod connections;
 pub mod slots;
@@ -94,6 +97,10 @@ pub struct DiagnosticsUI {
     diagnostics_table_view: QPtr<QTableView>,
     diagnostics_table_filter: QBox<QSortFilterProxyModel>,
     diagnostics_table_model: QBox<QStandardItemModel>,
+    diagnostics_table_view_context_menu: QBox<QMenu>,
+    context_menu_delete_identical_to_vanilla: QPtr<QAction>,
+    context_menu_ignore_for_file: QPtr<QAction>,
+    context_menu_ignore_for_column: QPtr<QAction>,
 
     //-------------------------------------------------------------------------------//
     // Filters section.
@@ -102,6 +109,7 @@ pub struct DiagnosticsUI {
     diagnostics_button_check_current_packed_file: QPtr<QToolButton>,
     diagnostics_button_error: QPtr<QToolButton>,
     diagnostics_button_warning: QPtr<QToolButton>,
+    diagnostics_button_notice: QPtr<QToolButton>,
     diagnostics_button_info: QPtr<QToolButton>,
     diagnostics_button_only_current_packed_file: QPtr<QToolButton>,
     diagnostics_button_show_more_filters: QPtr<QToolButton>,
@@ -132,6 +140,15 @@ pub struct DiagnosticsUI {
     checkbox_incorrect_game_path: QBox<QCheckBox>,
     checkbox_banned_table: QBox<QCheckBox>,
     checkbox_value_cannot_be_empty: QBox<QCheckBox>,
+    checkbox_missing_loc_data: QBox<QCheckBox>,
+    checkbox_non_standard_loc_layout: QBox<QCheckBox>,
+    checkbox_identical_to_vanilla: QBox<QCheckBox>,
+    checkbox_dependency_loaded_after_pack: QBox<QCheckBox>,
+    checkbox_invalid_path_list: QBox<QCheckBox>,
+    checkbox_invalid_texture: QBox<QCheckBox>,
+    checkbox_lua_syntax_error: QBox<QCheckBox>,
+    checkbox_invalid_variant_mesh: QBox<QCheckBox>,
+    checkbox_invalid_speedtree: QBox<QCheckBox>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -156,6 +173,7 @@ impl DiagnosticsUI {
         let diagnostics_button_check_current_packed_file: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "check_open_button")?;
         let diagnostics_button_error: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "error_button")?;
         let diagnostics_button_warning: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "warning_button")?;
+        let diagnostics_button_notice: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "notice_button")?;
         let diagnostics_button_info: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "info_button")?;
         let diagnostics_button_only_current_packed_file: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "only_open_button")?;
         let diagnostics_button_show_more_filters: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "more_filters_button")?;
@@ -164,6 +182,7 @@ impl DiagnosticsUI {
         diagnostics_button_check_current_packed_file.set_tool_tip(&qtr("diagnostics_button_check_current_packed_file"));
         diagnostics_button_error.set_tool_tip(&qtr("diagnostics_button_error"));
         diagnostics_button_warning.set_tool_tip(&qtr("diagnostics_button_warning"));
+        diagnostics_button_notice.set_tool_tip(&qtr("diagnostics_button_notice"));
         diagnostics_button_info.set_tool_tip(&qtr("diagnostics_button_info"));
         diagnostics_button_only_current_packed_file.set_tool_tip(&qtr("diagnostics_button_only_current_packed_file"));
         diagnostics_button_show_more_filters.set_tool_tip(&qtr("diagnostics_button_show_more_filters"));
@@ -194,6 +213,14 @@ impl DiagnosticsUI {
             background-color: {}
         }}", get_color_warning(), get_color_warning_pressed())));
 
+        diagnostics_button_notice.set_style_sheet(&QString::from_std_str(format!("
+        QPushButton {{
+            background-color: {}
+        }}
+        QPushButton::checked {{
+            background-color: {}
+        }}", get_color_warning(), get_color_warning_pressed())));
+
         diagnostics_button_error.set_style_sheet(&QString::from_std_str(format!("
         QPushButton {{
             background-color: {}
@@ -213,6 +240,14 @@ impl DiagnosticsUI {
             diagnostics_table_view.vertical_header().set_default_section_size(22);
         }
 
+        // Context menu with the automatic fixes for the diagnostics that support them.
+        diagnostics_table_view.set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
+        let diagnostics_table_view_context_menu = QMenu::from_q_widget(&diagnostics_dock_inner_widget);
+        let context_menu_delete_identical_to_vanilla = diagnostics_table_view_context_menu.add_action_q_string(&qtr("diagnostics_context_menu_delete_identical_to_vanilla"));
+        diagnostics_table_view_context_menu.add_separator();
+        let context_menu_ignore_for_file = diagnostics_table_view_context_menu.add_action_q_string(&qtr("diagnostics_context_menu_ignore_for_file"));
+        let context_menu_ignore_for_column = diagnostics_table_view_context_menu.add_action_q_string(&qtr("diagnostics_context_menu_ignore_for_column"));
+
         main_window.set_corner(qt_core::Corner::BottomLeftCorner, qt_core::DockWidgetArea::LeftDockWidgetArea);
         main_window.set_corner(qt_core::Corner::BottomRightCorner, qt_core::DockWidgetArea::RightDockWidgetArea);
 
@@ -249,6 +284,15 @@ impl DiagnosticsUI {
         let checkbox_incorrect_game_path = QCheckBox::from_q_string_q_widget(&qtr("label_incorrect_game_path"), &sidebar_scroll_area);
         let checkbox_banned_table = QCheckBox::from_q_string_q_widget(&qtr("label_banned_table"), &sidebar_scroll_area);
         let checkbox_value_cannot_be_empty = QCheckBox::from_q_string_q_widget(&qtr("label_value_cannot_be_empty"), &sidebar_scroll_area);
+        let checkbox_missing_loc_data = QCheckBox::from_q_string_q_widget(&qtr("label_missing_loc_data"), &sidebar_scroll_area);
+        let checkbox_non_standard_loc_layout = QCheckBox::from_q_string_q_widget(&qtr("label_non_standard_loc_layout"), &sidebar_scroll_area);
+        let checkbox_identical_to_vanilla = QCheckBox::from_q_string_q_widget(&qtr("label_identical_to_vanilla"), &sidebar_scroll_area);
+        let checkbox_dependency_loaded_after_pack = QCheckBox::from_q_string_q_widget(&qtr("label_dependency_loaded_after_pack"), &sidebar_scroll_area);
+        let checkbox_invalid_path_list = QCheckBox::from_q_string_q_widget(&qtr("label_invalid_path_list"), &sidebar_scroll_area);
+        let checkbox_invalid_texture = QCheckBox::from_q_string_q_widget(&qtr("label_invalid_texture"), &sidebar_scroll_area);
+        let checkbox_lua_syntax_error = QCheckBox::from_q_string_q_widget(&qtr("label_lua_syntax_error"), &sidebar_scroll_area);
+        let checkbox_invalid_variant_mesh = QCheckBox::from_q_string_q_widget(&qtr("label_invalid_variant_mesh"), &sidebar_scroll_area);
+        let checkbox_invalid_speedtree = QCheckBox::from_q_string_q_widget(&qtr("label_invalid_speedtree"), &sidebar_scroll_area);
 
         checkbox_all.set_checked(true);
         checkbox_outdated_table.set_checked(true);
@@ -275,6 +319,15 @@ impl DiagnosticsUI {
         checkbox_incorrect_game_path.set_checked(true);
         checkbox_banned_table.set_checked(true);
         checkbox_value_cannot_be_empty.set_checked(true);
+        checkbox_missing_loc_data.set_checked(true);
+        checkbox_non_standard_loc_layout.set_checked(true);
+        checkbox_identical_to_vanilla.set_checked(true);
+        checkbox_dependency_loaded_after_pack.set_checked(true);
+        checkbox_invalid_path_list.set_checked(true);
+        checkbox_invalid_texture.set_checked(true);
+        checkbox_lua_syntax_error.set_checked(true);
+        checkbox_invalid_variant_mesh.set_checked(true);
+        checkbox_invalid_speedtree.set_checked(true);
 
         sidebar_grid.add_widget_1a(&checkbox_all);
         sidebar_grid.add_widget_1a(&checkbox_outdated_table);
@@ -301,6 +354,15 @@ impl DiagnosticsUI {
         sidebar_grid.add_widget_1a(&checkbox_incorrect_game_path);
         sidebar_grid.add_widget_1a(&checkbox_banned_table);
         sidebar_grid.add_widget_1a(&checkbox_value_cannot_be_empty);
+        sidebar_grid.add_widget_1a(&checkbox_missing_loc_data);
+        sidebar_grid.add_widget_1a(&checkbox_non_standard_loc_layout);
+        sidebar_grid.add_widget_1a(&checkbox_identical_to_vanilla);
+        sidebar_grid.add_widget_1a(&checkbox_dependency_loaded_after_pack);
+        sidebar_grid.add_widget_1a(&checkbox_invalid_path_list);
+        sidebar_grid.add_widget_1a(&checkbox_invalid_texture);
+        sidebar_grid.add_widget_1a(&checkbox_lua_syntax_error);
+        sidebar_grid.add_widget_1a(&checkbox_invalid_variant_mesh);
+        sidebar_grid.add_widget_1a(&checkbox_invalid_speedtree);
 
         Ok(Self {
 
@@ -311,6 +373,10 @@ impl DiagnosticsUI {
             diagnostics_table_view,
             diagnostics_table_filter,
             diagnostics_table_model,
+            diagnostics_table_view_context_menu,
+            context_menu_delete_identical_to_vanilla,
+            context_menu_ignore_for_file,
+            context_menu_ignore_for_column,
 
             //-------------------------------------------------------------------------------//
             // Filters section.
@@ -319,6 +385,7 @@ impl DiagnosticsUI {
             diagnostics_button_check_current_packed_file,
             diagnostics_button_error,
             diagnostics_button_warning,
+            diagnostics_button_notice,
             diagnostics_button_info,
             diagnostics_button_only_current_packed_file,
             diagnostics_button_show_more_filters,
@@ -348,10 +415,30 @@ impl DiagnosticsUI {
             checkbox_field_with_path_not_found,
             checkbox_incorrect_game_path,
             checkbox_banned_table,
-            checkbox_value_cannot_be_empty
+            checkbox_value_cannot_be_empty,
+            checkbox_missing_loc_data,
+            checkbox_non_standard_loc_layout,
+            checkbox_identical_to_vanilla,
+            checkbox_dependency_loaded_after_pack,
+            checkbox_invalid_path_list,
+            checkbox_invalid_texture,
+            checkbox_lua_syntax_error,
+            checkbox_invalid_variant_mesh,
+            checkbox_invalid_speedtree,
         })
     }
 
+    /// This function checks the entire PackFile for errors once the dependencies are ready.
+    ///
+    /// If they're still being rebuilt, the check is queued until they finish.
+    pub unsafe fn check_when_ready(app_ui: &Rc<AppUI>, diagnostics_ui: &Rc<Self>) {
+        if UI_STATE.get_dependencies_pending() {
+            UI_STATE.set_diagnostics_queued(true);
+        } else {
+            Self::check(app_ui, diagnostics_ui);
+        }
+    }
+
     /// This function takes care of checking the entire PackFile for errors.
     pub unsafe fn check(app_ui: &Rc<AppUI>, diagnostics_ui: &Rc<Self>) {
 
@@ -363,17 +450,22 @@ impl DiagnosticsUI {
         app_ui.menu_bar_packfile().set_enabled(false);
         let diagnostics_ignored = diagnostics_ui.diagnostics_ignored();
         info!("Triggering check.");
-        let receiver = CENTRAL_COMMAND.send_background(Command::DiagnosticsCheck(diagnostics_ignored));
+        let (receiver, cancel) = CENTRAL_COMMAND.send_background_cancellable(|cancel| Command::DiagnosticsCheck(diagnostics_ignored, cancel));
+        let wait_dialog = new_cancellable_progress_dialog(&diagnostics_ui.diagnostics_dock_widget, tr("diagnostics_in_progress_message"), &cancel);
         let response = CENTRAL_COMMAND.recv_try(&receiver);
-        diagnostics_ui.diagnostics_table_model.clear();
+        wait_dialog.close();
 
         match response {
             Response::Diagnostics(diagnostics) => {
+                diagnostics_ui.diagnostics_table_model.clear();
                 Self::load_diagnostics_to_ui(app_ui, diagnostics_ui, diagnostics.results());
                 Self::filter(app_ui, diagnostics_ui);
                 Self::update_level_counts(diagnostics_ui, diagnostics.results());
                 UI_STATE.set_diagnostics(&diagnostics);
             }
+
+            // If cancelled, keep the results of the previous check.
+            Response::Cancelled => {},
             _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
         }
 
@@ -410,6 +502,110 @@ impl DiagnosticsUI {
         app_ui.menu_bar_packfile().set_enabled(true);
     }
 
+    /// This function deletes from the open Pack all the files reported as identical to their vanilla/parent counterparts.
+    pub unsafe fn delete_identical_to_vanilla(app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>, diagnostics_ui: &Rc<Self>) {
+        let mut diagnostics = UI_STATE.get_diagnostics();
+        let is_identical_to_vanilla = |diagnostic: &DiagnosticType| matches!(diagnostic, DiagnosticType::File(diag) if diag.results()
+            .iter()
+            .any(|result| matches!(result.report_type(), FileDiagnosticReportType::IdenticalToVanilla)));
+
+        let paths = diagnostics.results()
+            .iter()
+            .filter(|diagnostic| is_identical_to_vanilla(diagnostic))
+            .map(|diagnostic| ContainerPath::File(diagnostic.path().to_owned()))
+            .collect::<Vec<_>>();
+
+        if paths.is_empty() {
+            return;
+        }
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::DeletePackedFiles(paths));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::VecContainerPath(items) => {
+                pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Delete(items.to_vec()), DataSource::PackFile);
+                pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::MarkAlwaysModified(items.to_vec()), DataSource::PackFile);
+                UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+
+                // Remove all the deleted files from the cache.
+                for item in &items {
+                    if let ContainerPath::File(path) = item {
+                        let _ = AppUI::purge_that_one_specifically(app_ui, pack_file_contents_ui, path, DataSource::PackFile, false);
+                    }
+                }
+            }
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        // The deleted files no longer exist, so remove their diagnostics without triggering a full check.
+        diagnostics.results_mut().retain(|diagnostic| !is_identical_to_vanilla(diagnostic));
+        diagnostics_ui.diagnostics_table_model.clear();
+        Self::load_diagnostics_to_ui(app_ui, diagnostics_ui, diagnostics.results());
+        Self::filter(app_ui, diagnostics_ui);
+        Self::update_level_counts(diagnostics_ui, diagnostics.results());
+        UI_STATE.set_diagnostics(&diagnostics);
+    }
+
+    /// This function adds rules to ignore the selected diagnostics to the settings of the open Pack, then updates the diagnostics of their files.
+    ///
+    /// If `for_column` is true, the rules only ignore the diagnostics in the columns they were reported in. Diagnostics not tied
+    /// to a file (or to a column, if `for_column` is true) are skipped.
+    pub unsafe fn ignore_selected(app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>, diagnostics_ui: &Rc<Self>, for_column: bool) {
+        let indexes = diagnostics_ui.diagnostics_table_view.selection_model().selected_indexes();
+        let mut rows = (0..indexes.count_0a())
+            .map(|index| diagnostics_ui.diagnostics_table_filter.map_to_source(indexes.at(index)).row())
+            .collect::<Vec<_>>();
+        rows.sort_unstable();
+        rows.dedup();
+
+        let mut rules = vec![];
+        for row in rows {
+            let path = diagnostics_ui.diagnostics_table_model.item_2a(row, 3).text().to_std_string();
+            let diagnostic = diagnostics_ui.diagnostics_table_model.item_2a(row, 5).text().to_std_string();
+            if path.is_empty() {
+                continue;
+            }
+
+            let columns = if for_column {
+                let cells = diagnostics_ui.diagnostics_table_model.item_2a(row, 2).data_1a(2).to_string().to_std_string();
+                let mut columns = serde_json::from_str::<Vec<(i32, i32)>>(&cells)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(_, column)| column)
+                    .filter(|column| *column >= 0)
+                    .collect::<Vec<_>>();
+                columns.sort_unstable();
+                columns.dedup();
+
+                if columns.is_empty() {
+                    continue;
+                }
+
+                columns
+            } else {
+                vec![]
+            };
+
+            rules.push((path, diagnostic, columns));
+        }
+
+        if rules.is_empty() {
+            return;
+        }
+
+        let paths = rules.iter().map(|(path, _, _)| ContainerPath::File(path.to_owned())).collect::<Vec<_>>();
+        let receiver = CENTRAL_COMMAND.send_background(Command::AddDiagnosticsIgnoreRules(rules));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::Success => {
+                UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+                Self::check_on_path(app_ui, diagnostics_ui, paths);
+            }
+            Response::Error(error) => show_dialog(&app_ui.main_window, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
     /// This function takes care of loading the results of a diagnostic check into the table.
     unsafe fn load_diagnostics_to_ui(app_ui: &Rc<AppUI>, diagnostics_ui: &Rc<Self>, diagnostics: &[DiagnosticType]) {
 
@@ -440,6 +636,7 @@ impl DiagnosticsUI {
                             let (result_type, color) = match result.level() {
                                 DiagnosticLevel::Info => ("Info".to_owned(), get_color_info()),
                                 DiagnosticLevel::Warning => ("Warning".to_owned(), get_color_warning()),
+                                DiagnosticLevel::Notice => ("Notice".to_owned(), get_color_warning()),
                                 DiagnosticLevel::Error => ("Error".to_owned(), get_color_error()),
                             };
 
@@ -488,6 +685,7 @@ impl DiagnosticsUI {
                             let (result_type, color) = match result.level() {
                                 DiagnosticLevel::Info => ("Info".to_owned(), get_color_info()),
                                 DiagnosticLevel::Warning => ("Warning".to_owned(), get_color_warning()),
+                                DiagnosticLevel::Notice => ("Notice".to_owned(), get_color_warning()),
                                 DiagnosticLevel::Error => ("Error".to_owned(), get_color_error()),
                             };
 
@@ -536,6 +734,7 @@ impl DiagnosticsUI {
                             let (result_type, color) = match result.level() {
                                 DiagnosticLevel::Info => ("Info".to_owned(), get_color_info()),
                                 DiagnosticLevel::Warning => ("Warning".to_owned(), get_color_warning()),
+                                DiagnosticLevel::Notice => ("Notice".to_owned(), get_color_warning()),
                                 DiagnosticLevel::Error => ("Error".to_owned(), get_color_error()),
                             };
 
@@ -581,6 +780,7 @@ impl DiagnosticsUI {
                             let (result_type, color) = match result.level() {
                                 DiagnosticLevel::Info => ("Info".to_owned(), get_color_info()),
                                 DiagnosticLevel::Warning => ("Warning".to_owned(), get_color_warning()),
+                                DiagnosticLevel::Notice => ("Notice".to_owned(), get_color_warning()),
                                 DiagnosticLevel::Error => ("Error".to_owned(), get_color_error()),
                             };
 
@@ -614,6 +814,53 @@ impl DiagnosticsUI {
                             diagnostics_ui.diagnostics_table_model.append_row_q_list_of_q_standard_item(qlist_boi.as_ref());
                         }
                     }
+                    DiagnosticType::File(ref diagnostic) => {
+                        for result in diagnostic.results() {
+                            let qlist_boi = QListOfQStandardItem::new();
+
+                            // Create an empty row.
+                            let level = QStandardItem::new();
+                            let diag_type = QStandardItem::new();
+                            let fill1 = QStandardItem::new();
+                            let path = QStandardItem::new();
+                            let message = QStandardItem::new();
+                            let report_type = QStandardItem::new();
+                            let (result_type, color) = match result.level() {
+                                DiagnosticLevel::Info => ("Info".to_owned(), get_color_info()),
+                                DiagnosticLevel::Warning => ("Warning".to_owned(), get_color_warning()),
+                                DiagnosticLevel::Notice => ("Notice".to_owned(), get_color_warning()),
+                                DiagnosticLevel::Error => ("Error".to_owned(), get_color_error()),
+                            };
+
+                            level.set_background(&QBrush::from_q_color(&QColor::from_q_string(&QString::from_std_str(color))));
+                            level.set_text(&QString::from_std_str(result_type));
+                            diag_type.set_text(&QString::from_std_str(format!("{}", diagnostic_type)));
+                            path.set_text(&QString::from_std_str(diagnostic.path()));
+                            message.set_text(&QString::from_std_str(result.message()));
+                            report_type.set_text(&QString::from_std_str(format!("{}", result.report_type())));
+
+                            level.set_editable(false);
+                            diag_type.set_editable(false);
+                            fill1.set_editable(false);
+                            path.set_editable(false);
+                            message.set_editable(false);
+                            report_type.set_editable(false);
+
+                            // Set the tooltips to the diag type and description columns.
+                            Self::set_tooltips_file(&[&level, &path, &message], result.report_type());
+
+                            // Add an empty row to the list.
+                            qlist_boi.append_q_standard_item(&level.into_ptr().as_mut_raw_ptr());
+                            qlist_boi.append_q_standard_item(&diag_type.into_ptr().as_mut_raw_ptr());
+                            qlist_boi.append_q_standard_item(&fill1.into_ptr().as_mut_raw_ptr());
+                            qlist_boi.append_q_standard_item(&path.into_ptr().as_mut_raw_ptr());
+                            qlist_boi.append_q_standard_item(&message.into_ptr().as_mut_raw_ptr());
+                            qlist_boi.append_q_standard_item(&report_type.into_ptr().as_mut_raw_ptr());
+
+                            // Append the new row.
+                            diagnostics_ui.diagnostics_table_model.append_row_q_list_of_q_standard_item(qlist_boi.as_ref());
+                        }
+                    }
 
                     DiagnosticType::Config(ref diagnostic) => {
                         for result in diagnostic.results() {
@@ -629,6 +876,7 @@ impl DiagnosticsUI {
                             let (result_type, color) = match result.level() {
                                 DiagnosticLevel::Info => ("Info".to_owned(), get_color_info()),
                                 DiagnosticLevel::Warning => ("Warning".to_owned(), get_color_warning()),
+                                DiagnosticLevel::Notice => ("Notice".to_owned(), get_color_warning()),
                                 DiagnosticLevel::Error => ("Error".to_owned(), get_color_error()),
                             };
 
@@ -813,7 +1061,7 @@ impl DiagnosticsUI {
                     "DependenciesCacheNotGenerated" |
                     "DependenciesCacheOutdated" |
                     "DependenciesCacheCouldNotBeLoaded" => {
-                        match &*GAME_SELECTED.read().unwrap().game_key_name() {
+                        match &*read_game_selected().game_key_name() {
                             KEY_WARHAMMER_3 => app_ui.special_stuff_wh3_generate_dependencies_cache().trigger(),
                             KEY_TROY => app_ui.special_stuff_troy_generate_dependencies_cache().trigger(),
                             KEY_THREE_KINGDOMS => app_ui.special_stuff_three_k_generate_dependencies_cache().trigger(),
@@ -879,7 +1127,8 @@ impl DiagnosticsUI {
                                             if table_model_index.is_valid() {
                                                 match result.level() {
                                                     DiagnosticLevel::Error => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_ERROR),
-                                                    DiagnosticLevel::Warning => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
+                                                    DiagnosticLevel::Warning |
+                                                    DiagnosticLevel::Notice => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
                                                     DiagnosticLevel::Info => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_INFO),
                                                 }
                                             }
@@ -893,7 +1142,8 @@ impl DiagnosticsUI {
                                             if table_model_index.is_valid() {
                                                 match result.level() {
                                                     DiagnosticLevel::Error => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_ERROR),
-                                                    DiagnosticLevel::Warning => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
+                                                    DiagnosticLevel::Warning |
+                                                    DiagnosticLevel::Notice => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
                                                     DiagnosticLevel::Info => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_INFO),
                                                 }
                                             }
@@ -906,7 +1156,8 @@ impl DiagnosticsUI {
                                         if table_model_index.is_valid() {
                                             match result.level() {
                                                 DiagnosticLevel::Error => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_ERROR),
-                                                DiagnosticLevel::Warning => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
+                                                DiagnosticLevel::Warning |
+                                                DiagnosticLevel::Notice => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
                                                 DiagnosticLevel::Info => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_INFO),
                                             }
                                         }
@@ -930,7 +1181,8 @@ impl DiagnosticsUI {
                                             if table_model_index.is_valid() {
                                                 match result.level {
                                                     DiagnosticLevel::Error => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_ERROR),
-                                                    DiagnosticLevel::Warning => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
+                                                    DiagnosticLevel::Warning |
+                                                    DiagnosticLevel::Notice => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
                                                     DiagnosticLevel::Info => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_INFO),
                                                 }
                                             }
@@ -944,7 +1196,8 @@ impl DiagnosticsUI {
                                             if table_model_index.is_valid() {
                                                 match result.level {
                                                     DiagnosticLevel::Error => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_ERROR),
-                                                    DiagnosticLevel::Warning => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
+                                                    DiagnosticLevel::Warning |
+                                                    DiagnosticLevel::Notice => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
                                                     DiagnosticLevel::Info => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_INFO),
                                                 }
                                             }
@@ -957,7 +1210,8 @@ impl DiagnosticsUI {
                                         if table_model_index.is_valid() {
                                             match result.level {
                                                 DiagnosticLevel::Error => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_ERROR),
-                                                DiagnosticLevel::Warning => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
+                                                DiagnosticLevel::Warning |
+                                                DiagnosticLevel::Notice => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
                                                 DiagnosticLevel::Info => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_INFO),
                                             }
                                         }
@@ -979,7 +1233,8 @@ impl DiagnosticsUI {
                                             if table_model_index.is_valid() {
                                                 match result.level() {
                                                     DiagnosticLevel::Error => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_ERROR),
-                                                    DiagnosticLevel::Warning => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
+                                                    DiagnosticLevel::Warning |
+                                                    DiagnosticLevel::Notice => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
                                                     DiagnosticLevel::Info => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_INFO),
                                                 }
                                             }
@@ -993,7 +1248,8 @@ impl DiagnosticsUI {
                                             if table_model_index.is_valid() {
                                                 match result.level() {
                                                     DiagnosticLevel::Error => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_ERROR),
-                                                    DiagnosticLevel::Warning => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
+                                                    DiagnosticLevel::Warning |
+                                                    DiagnosticLevel::Notice => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
                                                     DiagnosticLevel::Info => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_INFO),
                                                 }
                                             }
@@ -1006,7 +1262,8 @@ impl DiagnosticsUI {
                                         if table_model_index.is_valid() {
                                             match result.level() {
                                                 DiagnosticLevel::Error => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_ERROR),
-                                                DiagnosticLevel::Warning => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
+                                                DiagnosticLevel::Warning |
+                                                DiagnosticLevel::Notice => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_WARNING),
                                                 DiagnosticLevel::Info => table_model_item.set_data_2a(&QVariant::from_bool(true), ITEM_HAS_INFO),
                                             }
                                         }
@@ -1113,22 +1370,18 @@ impl DiagnosticsUI {
         let mut patterns = vec![];
         let mut sensitivity = vec![];
 
-        let info_state = diagnostics_ui.diagnostics_button_info.is_checked();
-        let warning_state = diagnostics_ui.diagnostics_button_warning.is_checked();
-        let error_state = diagnostics_ui.diagnostics_button_error.is_checked();
-        let pattern_level = match (info_state, warning_state, error_state) {
-            (true, true, true) => "Info|Warning|Error",
-            (true, true, false) => "Info|Warning",
-            (true, false, true) => "Info|Error",
-            (false, true, true) => "Warning|Error",
-            (true, false, false) => "Info",
-            (false, false, true) => "Error",
-            (false, true, false) => "Warning",
-            (false, false, false) => "-1",
-        };
+        let pattern_level = [
+            (&diagnostics_ui.diagnostics_button_info, "Info"),
+            (&diagnostics_ui.diagnostics_button_notice, "Notice"),
+            (&diagnostics_ui.diagnostics_button_warning, "Warning"),
+            (&diagnostics_ui.diagnostics_button_error, "Error"),
+        ].iter()
+            .filter(|(button, _)| button.is_checked())
+            .map(|(_, level)| *level)
+            .join("|");
 
         columns.push(0);
-        patterns.push(QString::from_std_str(pattern_level).into_ptr());
+        patterns.push(QString::from_std_str(if pattern_level.is_empty() { "-1" } else { &pattern_level }).into_ptr());
         sensitivity.push(CaseSensitivity::CaseSensitive);
 
         // Check for currently open files filter.
@@ -1212,11 +1465,45 @@ impl DiagnosticsUI {
         if diagnostics_ui.checkbox_value_cannot_be_empty.is_checked() {
             diagnostic_type_pattern.push_str(&format!("{}|", TableDiagnosticReportType::ValueCannotBeEmpty(String::new())));
         }
+        if diagnostics_ui.checkbox_missing_loc_data.is_checked() {
+            diagnostic_type_pattern.push_str(&format!("{}|", TableDiagnosticReportType::MissingLocData(String::new())));
+        }
+        if diagnostics_ui.checkbox_non_standard_loc_layout.is_checked() {
+            diagnostic_type_pattern.push_str(&format!("{}|", TableDiagnosticReportType::NonStandardLocLayout));
+        }
+
+        if diagnostics_ui.checkbox_identical_to_vanilla.is_checked() {
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::IdenticalToVanilla));
+        }
+        if diagnostics_ui.checkbox_invalid_path_list.is_checked() {
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::PathListPathNotFound(0, String::new())));
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::PathListTooLong(0, 0)));
+        }
+        if diagnostics_ui.checkbox_invalid_texture.is_checked() {
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::TextureFormatNotSupported(String::new())));
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::TextureDimensionsNotMultipleOf4(0, 0)));
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::TextureDimensionsNotPowerOfTwo(0, 0)));
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::TextureMipmapCountInvalid(0, 0)));
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::TextureMipmapsMissing));
+        }
+        if diagnostics_ui.checkbox_lua_syntax_error.is_checked() {
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::LuaSyntaxError(0, 0, String::new())));
+        }
+        if diagnostics_ui.checkbox_invalid_variant_mesh.is_checked() {
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::VariantMeshPathNotFound(0, String::new())));
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::VariantMeshParseError(0, 0, String::new())));
+        }
+        if diagnostics_ui.checkbox_invalid_speedtree.is_checked() {
+            diagnostic_type_pattern.push_str(&format!("{}|", FileDiagnosticReportType::SpeedTreeTextureNotFound(String::new())));
+        }
 
 
         if diagnostics_ui.checkbox_invalid_dependency_packfile.is_checked() {
             diagnostic_type_pattern.push_str(&format!("{}|", DependencyDiagnosticReportType::InvalidDependencyPackName(String::new())));
         }
+        if diagnostics_ui.checkbox_dependency_loaded_after_pack.is_checked() {
+            diagnostic_type_pattern.push_str(&format!("{}|", DependencyDiagnosticReportType::DependencyLoadedAfterPack(String::new())));
+        }
 
         if diagnostics_ui.checkbox_dependencies_cache_not_generated.is_checked() {
             diagnostic_type_pattern.push_str(&format!("{}|", ConfigDiagnosticReportType::DependenciesCacheNotGenerated));
@@ -1246,89 +1533,49 @@ impl DiagnosticsUI {
         sensitivity.push(CaseSensitivity::CaseSensitive);
         let show_blank_lines = vec![false; sensitivity.len()];
         let match_groups = vec![0; sensitivity.len()];
+        let filter_types = vec![FilterType::Text as i32; sensitivity.len()];
 
         // Filter whatever it's in that column by the text we got.
-        trigger_tableview_filter_safe(&diagnostics_ui.diagnostics_table_filter, &columns, patterns, &sensitivity, &show_blank_lines, &match_groups);
+        trigger_tableview_filter_safe(&diagnostics_ui.diagnostics_table_filter, &columns, patterns, &sensitivity, &show_blank_lines, &match_groups, &filter_types);
     }
 
     pub unsafe fn update_level_counts(diagnostics_ui: &Rc<Self>, diagnostics: &[DiagnosticType]) {
-        let info = diagnostics.iter().map(|x|
-            match x {
-                DiagnosticType::AnimFragment(ref diag) => diag.results()
-                    .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Info))
-                    .count(),
-                DiagnosticType::DB(ref diag) |
-                DiagnosticType::Loc(ref diag) => diag.results()
-                    .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Info))
-                    .count(),
-                DiagnosticType::Pack(ref diag) => diag.results()
-                    .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Info))
-                    .count(),
-                 DiagnosticType::Dependency(ref diag) => diag.results()
-                    .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Info))
-                    .count(),
-                 DiagnosticType::Config(ref diag) => diag.results()
-                    .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Info))
-                    .count(),
-            }).sum::<usize>();
-
-        let warning = diagnostics.iter().map(|x|
+        let count = |is_level: fn(&DiagnosticLevel) -> bool| diagnostics.iter().map(|x|
             match x {
                 DiagnosticType::AnimFragment(ref diag) => diag.results()
                     .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Warning))
+                    .filter(|y| is_level(&y.level()))
                     .count(),
                 DiagnosticType::DB(ref diag) |
                 DiagnosticType::Loc(ref diag) => diag.results()
                     .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Warning))
+                    .filter(|y| is_level(&y.level()))
                     .count(),
                 DiagnosticType::Pack(ref diag) => diag.results()
                     .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Warning))
+                    .filter(|y| is_level(&y.level()))
                     .count(),
                 DiagnosticType::Dependency(ref diag) => diag.results()
                     .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Warning))
+                    .filter(|y| is_level(&y.level()))
                     .count(),
                 DiagnosticType::Config(ref diag) => diag.results()
                     .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Warning))
-                    .count(),
-            }).sum::<usize>();
-
-
-        let error = diagnostics.iter().map(|x|
-            match x {
-                DiagnosticType::AnimFragment(ref diag) => diag.results()
-                    .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Error))
-                    .count(),
-                DiagnosticType::DB(ref diag) |
-                DiagnosticType::Loc(ref diag) => diag.results()
-                    .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Error))
-                    .count(),
-                DiagnosticType::Pack(ref diag) => diag.results()
-                    .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Error))
+                    .filter(|y| is_level(&y.level()))
                     .count(),
-                DiagnosticType::Dependency(ref diag) => diag.results()
-                    .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Error))
-                    .count(),
-                DiagnosticType::Config(ref diag) => diag.results()
+                DiagnosticType::File(ref diag) => diag.results()
                     .iter()
-                    .filter(|y| matches!(y.level(), DiagnosticLevel::Error))
+                    .filter(|y| is_level(&y.level()))
                     .count(),
             }).sum::<usize>();
 
+        let info = count(|level| matches!(level, DiagnosticLevel::Info));
+        let notice = count(|level| matches!(level, DiagnosticLevel::Notice));
+        let warning = count(|level| matches!(level, DiagnosticLevel::Warning));
+        let error = count(|level| matches!(level, DiagnosticLevel::Error));
+
         diagnostics_ui.diagnostics_button_info.set_text(&QString::from_std_str(format!("{} ({})", tr("diagnostics_button_info"), info)));
+        diagnostics_ui.diagnostics_button_notice.set_text(&QString::from_std_str(format!("{} ({})", tr("diagnostics_button_notice"), notice)));
         diagnostics_ui.diagnostics_button_warning.set_text(&QString::from_std_str(format!("{} ({})", tr("diagnostics_button_warning"), warning)));
         diagnostics_ui.diagnostics_button_error.set_text(&QString::from_std_str(format!("{} ({})", tr("diagnostics_button_error"), error)));
     }
@@ -1363,6 +1610,8 @@ impl DiagnosticsUI {
             TableDiagnosticReportType::FieldWithPathNotFound(_) => qtr("field_with_path_not_found_explanation"),
             TableDiagnosticReportType::BannedTable => qtr("banned_table_explanation"),
             TableDiagnosticReportType::ValueCannotBeEmpty(_) => qtr("value_cannot_be_empty_explanation"),
+            TableDiagnosticReportType::MissingLocData(_) => qtr("missing_loc_data_explanation"),
+            TableDiagnosticReportType::NonStandardLocLayout => qtr("non_standard_loc_layout_explanation"),
         };
 
         for item in items {
@@ -1373,6 +1622,7 @@ impl DiagnosticsUI {
     pub unsafe fn set_tooltips_dependency_manager(items: &[&CppBox<QStandardItem>], report_type: &DependencyDiagnosticReportType) {
         let tool_tip = match report_type {
             DependencyDiagnosticReportType::InvalidDependencyPackName(_) => qtr("invalid_dependency_pack_file_name_explanation"),
+            DependencyDiagnosticReportType::DependencyLoadedAfterPack(_) => qtr("dependency_loaded_after_pack_explanation"),
         };
 
         for item in items {
@@ -1393,6 +1643,27 @@ impl DiagnosticsUI {
         }
     }
 
+    pub unsafe fn set_tooltips_file(items: &[&CppBox<QStandardItem>], report_type: &FileDiagnosticReportType) {
+        let tool_tip = match report_type {
+            FileDiagnosticReportType::IdenticalToVanilla => qtr("identical_to_vanilla_explanation"),
+            FileDiagnosticReportType::PathListPathNotFound(_, _) => qtr("path_list_path_not_found_explanation"),
+            FileDiagnosticReportType::PathListTooLong(_, _) => qtr("path_list_too_long_explanation"),
+            FileDiagnosticReportType::TextureFormatNotSupported(_) => qtr("texture_format_not_supported_explanation"),
+            FileDiagnosticReportType::TextureDimensionsNotMultipleOf4(_, _) => qtr("texture_dimensions_not_multiple_of_4_explanation"),
+            FileDiagnosticReportType::TextureDimensionsNotPowerOfTwo(_, _) => qtr("texture_dimensions_not_power_of_two_explanation"),
+            FileDiagnosticReportType::TextureMipmapCountInvalid(_, _) => qtr("texture_mipmap_count_invalid_explanation"),
+            FileDiagnosticReportType::TextureMipmapsMissing => qtr("texture_mipmaps_missing_explanation"),
+            FileDiagnosticReportType::LuaSyntaxError(_, _, _) => qtr("lua_syntax_error_explanation"),
+            FileDiagnosticReportType::VariantMeshPathNotFound(_, _) => qtr("variant_mesh_path_not_found_explanation"),
+            FileDiagnosticReportType::VariantMeshParseError(_, _, _) => qtr("variant_mesh_parse_error_explanation"),
+            FileDiagnosticReportType::SpeedTreeTextureNotFound(_) => qtr("speedtree_texture_not_found_explanation"),
+        };
+
+        for item in items {
+            item.set_tool_tip(&tool_tip);
+        }
+    }
+
     pub unsafe fn set_tooltips_packfile(items: &[&CppBox<QStandardItem>], report_type: &PackDiagnosticReportType) {
         let tool_tip = match report_type {
             PackDiagnosticReportType::InvalidPackName(_) => qtr("invalid_packfile_name_explanation"),
@@ -1460,10 +1731,44 @@ impl DiagnosticsUI {
         if !self.checkbox_value_cannot_be_empty.is_checked() {
             diagnostics_ignored.push(TableDiagnosticReportType::ValueCannotBeEmpty(String::new()).to_string());
         }
+        if !self.checkbox_missing_loc_data.is_checked() {
+            diagnostics_ignored.push(TableDiagnosticReportType::MissingLocData(String::new()).to_string());
+        }
+        if !self.checkbox_non_standard_loc_layout.is_checked() {
+            diagnostics_ignored.push(TableDiagnosticReportType::NonStandardLocLayout.to_string());
+        }
+
+        if !self.checkbox_identical_to_vanilla.is_checked() {
+            diagnostics_ignored.push(FileDiagnosticReportType::IdenticalToVanilla.to_string());
+        }
+        if !self.checkbox_invalid_path_list.is_checked() {
+            diagnostics_ignored.push(FileDiagnosticReportType::PathListPathNotFound(0, String::new()).to_string());
+            diagnostics_ignored.push(FileDiagnosticReportType::PathListTooLong(0, 0).to_string());
+        }
+        if !self.checkbox_invalid_texture.is_checked() {
+            diagnostics_ignored.push(FileDiagnosticReportType::TextureFormatNotSupported(String::new()).to_string());
+            diagnostics_ignored.push(FileDiagnosticReportType::TextureDimensionsNotMultipleOf4(0, 0).to_string());
+            diagnostics_ignored.push(FileDiagnosticReportType::TextureDimensionsNotPowerOfTwo(0, 0).to_string());
+            diagnostics_ignored.push(FileDiagnosticReportType::TextureMipmapCountInvalid(0, 0).to_string());
+            diagnostics_ignored.push(FileDiagnosticReportType::TextureMipmapsMissing.to_string());
+        }
+        if !self.checkbox_lua_syntax_error.is_checked() {
+            diagnostics_ignored.push(FileDiagnosticReportType::LuaSyntaxError(0, 0, String::new()).to_string());
+        }
+        if !self.checkbox_invalid_variant_mesh.is_checked() {
+            diagnostics_ignored.push(FileDiagnosticReportType::VariantMeshPathNotFound(0, String::new()).to_string());
+            diagnostics_ignored.push(FileDiagnosticReportType::VariantMeshParseError(0, 0, String::new()).to_string());
+        }
+        if !self.checkbox_invalid_speedtree.is_checked() {
+            diagnostics_ignored.push(FileDiagnosticReportType::SpeedTreeTextureNotFound(String::new()).to_string());
+        }
 
         if !self.checkbox_invalid_dependency_packfile.is_checked() {
             diagnostics_ignored.push(DependencyDiagnosticReportType::InvalidDependencyPackName(String::new()).to_string());
         }
+        if !self.checkbox_dependency_loaded_after_pack.is_checked() {
+            diagnostics_ignored.push(DependencyDiagnosticReportType::DependencyLoadedAfterPack(String::new()).to_string());
+        }
 
         if !self.checkbox_dependencies_cache_not_generated.is_checked() {
             diagnostics_ignored.push(ConfigDiagnosticReportType::DependenciesCacheNotGenerated.to_string());
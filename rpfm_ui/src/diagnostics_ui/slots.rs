@@ -15,7 +15,9 @@ Module with all the code related to the main `DiagnosticsUISlots`.
 use qt_core::QBox;
 use qt_core::QObject;
 use qt_core::QSignalBlocker;
-use qt_core::{SlotNoArgs, SlotOfBool, SlotOfQModelIndex};
+use qt_core::{SlotNoArgs, SlotOfBool, SlotOfQModelIndex, SlotOfQPoint};
+
+use qt_gui::QCursor;
 
 use rpfm_lib::integrations::log::*;
 
@@ -41,6 +43,10 @@ pub struct DiagnosticsUISlots {
     pub diagnostics_check_packfile: QBox<SlotNoArgs>,
     pub diagnostics_check_currently_open_packed_file: QBox<SlotNoArgs>,
     pub diagnostics_open_result: QBox<SlotOfQModelIndex>,
+    pub contextual_menu: QBox<SlotOfQPoint>,
+    pub contextual_menu_delete_identical_to_vanilla: QBox<SlotOfBool>,
+    pub contextual_menu_ignore_for_file: QBox<SlotOfBool>,
+    pub contextual_menu_ignore_for_column: QBox<SlotOfBool>,
     pub show_hide_extra_filters: QBox<SlotOfBool>,
     pub toggle_filters: QBox<SlotNoArgs>,
     pub toggle_filters_types: QBox<SlotNoArgs>,
@@ -102,6 +108,43 @@ impl DiagnosticsUISlots {
             }
         ));
 
+        let contextual_menu = SlotOfQPoint::new(&diagnostics_ui.diagnostics_dock_widget, clone!(
+            diagnostics_ui => move |_| {
+            diagnostics_ui.diagnostics_table_view_context_menu.exec_1a_mut(&QCursor::pos_0a());
+        }));
+
+        let contextual_menu_delete_identical_to_vanilla = SlotOfBool::new(&diagnostics_ui.diagnostics_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            diagnostics_ui => move |_| {
+                if AppUI::are_you_sure_edition(&app_ui, "are_you_sure_delete_identical_to_vanilla") {
+                    info!("Triggering `Delete Files Identical to Vanilla (Diag)` By Slot");
+
+                    app_ui.main_window().set_disabled(true);
+                    DiagnosticsUI::delete_identical_to_vanilla(&app_ui, &pack_file_contents_ui, &diagnostics_ui);
+                    app_ui.main_window().set_disabled(false);
+                }
+            }
+        ));
+
+        let contextual_menu_ignore_for_file = SlotOfBool::new(&diagnostics_ui.diagnostics_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            diagnostics_ui => move |_| {
+                info!("Triggering `Ignore Diagnostic For File (Diag)` By Slot");
+                DiagnosticsUI::ignore_selected(&app_ui, &pack_file_contents_ui, &diagnostics_ui, false);
+            }
+        ));
+
+        let contextual_menu_ignore_for_column = SlotOfBool::new(&diagnostics_ui.diagnostics_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            diagnostics_ui => move |_| {
+                info!("Triggering `Ignore Diagnostic For Column (Diag)` By Slot");
+                DiagnosticsUI::ignore_selected(&app_ui, &pack_file_contents_ui, &diagnostics_ui, true);
+            }
+        ));
+
         let show_hide_extra_filters = SlotOfBool::new(&diagnostics_ui.diagnostics_dock_widget, clone!(
             diagnostics_ui => move |state| {
                 if !state { diagnostics_ui.sidebar_scroll_area.hide(); }
@@ -144,6 +187,15 @@ impl DiagnosticsUISlots {
                 let _blocker_21 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_incorrect_game_path.static_upcast::<QObject>());
                 let _blocker_22 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_banned_table.static_upcast::<QObject>());
                 let _blocker_23 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_value_cannot_be_empty.static_upcast::<QObject>());
+                let _blocker_24 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_missing_loc_data.static_upcast::<QObject>());
+                let _blocker_25 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_identical_to_vanilla.static_upcast::<QObject>());
+                let _blocker_26 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_dependency_loaded_after_pack.static_upcast::<QObject>());
+                let _blocker_27 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_non_standard_loc_layout.static_upcast::<QObject>());
+                let _blocker_28 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_invalid_path_list.static_upcast::<QObject>());
+                let _blocker_29 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_invalid_texture.static_upcast::<QObject>());
+                let _blocker_30 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_lua_syntax_error.static_upcast::<QObject>());
+                let _blocker_31 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_invalid_variant_mesh.static_upcast::<QObject>());
+                let _blocker_32 = QSignalBlocker::from_q_object(diagnostics_ui.checkbox_invalid_speedtree.static_upcast::<QObject>());
 
                 diagnostics_ui.checkbox_outdated_table.toggle();
                 diagnostics_ui.checkbox_invalid_reference.toggle();
@@ -169,6 +221,15 @@ impl DiagnosticsUISlots {
                 diagnostics_ui.checkbox_incorrect_game_path.toggle();
                 diagnostics_ui.checkbox_banned_table.toggle();
                 diagnostics_ui.checkbox_value_cannot_be_empty.toggle();
+                diagnostics_ui.checkbox_missing_loc_data.toggle();
+                diagnostics_ui.checkbox_identical_to_vanilla.toggle();
+                diagnostics_ui.checkbox_dependency_loaded_after_pack.toggle();
+                diagnostics_ui.checkbox_non_standard_loc_layout.toggle();
+                diagnostics_ui.checkbox_invalid_path_list.toggle();
+                diagnostics_ui.checkbox_invalid_texture.toggle();
+                diagnostics_ui.checkbox_lua_syntax_error.toggle();
+                diagnostics_ui.checkbox_invalid_variant_mesh.toggle();
+                diagnostics_ui.checkbox_invalid_speedtree.toggle();
 
                 DiagnosticsUI::filter(&app_ui, &diagnostics_ui);
             }
@@ -179,6 +240,10 @@ impl DiagnosticsUISlots {
             diagnostics_check_packfile,
             diagnostics_check_currently_open_packed_file,
             diagnostics_open_result,
+            contextual_menu,
+            contextual_menu_delete_identical_to_vanilla,
+            contextual_menu_ignore_for_file,
+            contextual_menu_ignore_for_column,
             show_hide_extra_filters,
             toggle_filters,
             toggle_filters_types,
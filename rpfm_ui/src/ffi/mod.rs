@@ -42,6 +42,7 @@ use qt_core::QPtr;
 use qt_core::QTimer;
 use qt_core::QListOfInt;
 use qt_core::CaseSensitivity;
+use qt_core::QEventLoop;
 
 use cpp_core::CppBox;
 use cpp_core::Ptr;
@@ -51,9 +52,14 @@ use anyhow::{anyhow, Result};
 #[cfg(feature = "support_rigidmodel")]
 use rpfm_lib::integrations::log;
 
-use crate::locale::qtr;
+use std::thread;
+use std::time::Duration;
+
+use crate::CENTRAL_COMMAND;
+use crate::locale::{qtr, qtre, tr};
 use crate::settings_ui::backend::*;
 use crate::UI_STATE;
+use crate::utils::new_progress_dialog;
 
 //---------------------------------------------------------------------------//
 // Custom delegates stuff.
@@ -140,8 +146,10 @@ pub fn new_tableview_filter_safe(parent: QPtr<QObject>) ->  QBox<QSortFilterProx
 }
 
 // This function triggers the special filter used for the TableViews It has to be triggered here to work properly.
-extern "C" { fn trigger_tableview_filter(filter: *const QSortFilterProxyModel, columns: *const QListOfInt, patterns: *const QStringList, case_sensitive: *const QListOfInt, show_blank_cells: *const QListOfInt, match_groups: *const QListOfInt); }
-pub unsafe fn trigger_tableview_filter_safe(filter: &QSortFilterProxyModel, columns: &[i32], patterns: Vec<Ptr<QString>>, case_sensitive: &[CaseSensitivity], show_blank_cells: &[bool], match_groups: &[i32]) {
+//
+// The filter types are the values of `FilterType`, one per column.
+extern "C" { fn trigger_tableview_filter(filter: *const QSortFilterProxyModel, columns: *const QListOfInt, patterns: *const QStringList, case_sensitive: *const QListOfInt, show_blank_cells: *const QListOfInt, match_groups: *const QListOfInt, filter_types: *const QListOfInt); }
+pub unsafe fn trigger_tableview_filter_safe(filter: &QSortFilterProxyModel, columns: &[i32], patterns: Vec<Ptr<QString>>, case_sensitive: &[CaseSensitivity], show_blank_cells: &[bool], match_groups: &[i32], filter_types: &[i32]) {
     let columns_qlist = QListOfInt::new();
     columns.iter().for_each(|x| columns_qlist.append_int(x));
 
@@ -157,7 +165,19 @@ pub unsafe fn trigger_tableview_filter_safe(filter: &QSortFilterProxyModel, colu
     let match_groups_qlist = QListOfInt::new();
     match_groups.iter().for_each(|x| match_groups_qlist.append_int(x));
 
-    trigger_tableview_filter(filter, columns_qlist.into_ptr().as_raw_ptr(), patterns_qlist.into_ptr().as_raw_ptr(), case_sensitive_qlist.into_ptr().as_raw_ptr(), show_blank_cells_qlist.into_ptr().as_raw_ptr(), match_groups_qlist.into_ptr().as_raw_ptr());
+    let filter_types_qlist = QListOfInt::new();
+    filter_types.iter().for_each(|x| filter_types_qlist.append_int(x));
+
+    trigger_tableview_filter(filter, columns_qlist.into_ptr().as_raw_ptr(), patterns_qlist.into_ptr().as_raw_ptr(), case_sensitive_qlist.into_ptr().as_raw_ptr(), show_blank_cells_qlist.into_ptr().as_raw_ptr(), match_groups_qlist.into_ptr().as_raw_ptr(), filter_types_qlist.into_ptr().as_raw_ptr());
+}
+
+// This function sets the type of each column of a TableView's filter, so they're sorted by value instead of by text.
+extern "C" { fn set_tableview_filter_column_types(filter: *const QSortFilterProxyModel, column_types: *const QListOfInt); }
+pub unsafe fn set_tableview_filter_column_types_safe(filter: &QSortFilterProxyModel, column_types: &[i32]) {
+    let column_types_qlist = QListOfInt::new();
+    column_types.iter().for_each(|x| column_types_qlist.append_int(x));
+
+    set_tableview_filter_column_types(filter, column_types_qlist.into_ptr().as_raw_ptr());
 }
 
 
@@ -167,6 +187,14 @@ pub fn new_packed_file_model_safe() -> QBox<QStandardItemModel> {
     unsafe { QBox::from_raw(new_packed_file_model()) }
 }
 
+// This function allow us to accept files dropped from outside the program into a model created with `new_packed_file_model_safe`.
+//
+// Drops are notified through the `text_changed` signal of the returned widget: the first line is the folder they were dropped into, the rest are the dropped paths.
+extern "C" { fn enable_external_drops(model: *mut QStandardItemModel, parent: *mut QWidget) -> *mut QLineEdit; }
+pub fn enable_external_drops_safe(model: &Ptr<QStandardItemModel>, parent: &Ptr<QWidget>) -> Ptr<QLineEdit> {
+    unsafe { Ptr::from_raw(enable_external_drops(model.as_mut_raw_ptr(), parent.as_mut_raw_ptr())) }
+}
+
 // This function allow us to create a custom window.
 extern "C" { fn new_q_main_window_custom(are_you_sure: extern fn(*mut QMainWindow, bool) -> bool, is_dark_theme_enabled: bool) -> *mut QMainWindow; }
 pub fn new_q_main_window_custom_safe(are_you_sure: extern fn(*mut QMainWindow, bool) -> bool) -> QBox<QMainWindow> {
@@ -225,6 +253,12 @@ pub fn get_text_safe(document: &QBox<QWidget>) -> Ptr<QString> {
     unsafe { Ptr::from_raw(get_text(document.as_mut_raw_ptr())) }
 }
 
+// This function adds an action at the end of the context menu of the provided KTextEditor.
+extern "C" { fn add_text_editor_context_menu_action(view: *mut QWidget, action: *mut QAction); }
+pub fn add_text_editor_context_menu_action_safe(view: &Ptr<QWidget>, action: &Ptr<QAction>) {
+    unsafe { add_text_editor_context_menu_action(view.as_mut_raw_ptr(), action.as_mut_raw_ptr()) }
+}
+
 // This function allow us to set the text of the provided KTextEditor.
 extern "C" { fn set_text(document: *mut QWidget, string: *mut QString, highlighting_mode: *mut QString); }
 pub fn set_text_safe(document: &QPtr<QWidget>, string: &Ptr<QString>, highlighting_mode: &Ptr<QString>) {
@@ -255,6 +289,39 @@ pub fn cursor_row_safe<'a>(parent: &Ptr<QWidget>) -> u64 {
     unsafe { cursor_row(parent.as_mut_raw_ptr()) }
 }
 
+/// Search flag to make the search case sensitive.
+pub const TEXT_SEARCH_CASE_SENSITIVE: i32 = 1;
+
+/// Search flag to only match whole words.
+pub const TEXT_SEARCH_WHOLE_WORDS: i32 = 2;
+
+/// Search flag to use the pattern as a regex.
+pub const TEXT_SEARCH_REGEX: i32 = 4;
+
+// This function returns the amount of matches of a pattern in a KTextEditor, or -1 if the pattern is an invalid regex.
+extern "C" { fn text_search_count(view: *mut QWidget, pattern: *const QString, flags: i32) -> i32; }
+pub fn text_search_count_safe(view: &Ptr<QWidget>, pattern: &Ptr<QString>, flags: i32) -> i32 {
+    unsafe { text_search_count(view.as_mut_raw_ptr(), pattern.as_raw_ptr(), flags) }
+}
+
+// This function selects the next (or previous) match of a pattern in a KTextEditor. Returns if a match was found.
+extern "C" { fn text_search_find(view: *mut QWidget, pattern: *const QString, flags: i32, backwards: bool, incremental: bool) -> bool; }
+pub fn text_search_find_safe(view: &Ptr<QWidget>, pattern: &Ptr<QString>, flags: i32, backwards: bool, incremental: bool) -> bool {
+    unsafe { text_search_find(view.as_mut_raw_ptr(), pattern.as_raw_ptr(), flags, backwards, incremental) }
+}
+
+// This function replaces the selected match of a pattern in a KTextEditor and selects the next one. Returns if something was replaced.
+extern "C" { fn text_search_replace(view: *mut QWidget, pattern: *const QString, replacement: *const QString, flags: i32) -> bool; }
+pub fn text_search_replace_safe(view: &Ptr<QWidget>, pattern: &Ptr<QString>, replacement: &Ptr<QString>, flags: i32) -> bool {
+    unsafe { text_search_replace(view.as_mut_raw_ptr(), pattern.as_raw_ptr(), replacement.as_raw_ptr(), flags) }
+}
+
+// This function replaces all the matches of a pattern in a KTextEditor, in one undo step. Returns the amount of replaced matches.
+extern "C" { fn text_search_replace_all(view: *mut QWidget, pattern: *const QString, replacement: *const QString, flags: i32) -> i32; }
+pub fn text_search_replace_all_safe(view: &Ptr<QWidget>, pattern: &Ptr<QString>, replacement: &Ptr<QString>, flags: i32) -> i32 {
+    unsafe { text_search_replace_all(view.as_mut_raw_ptr(), pattern.as_raw_ptr(), replacement.as_raw_ptr(), flags) }
+}
+
 //---------------------------------------------------------------------------//
 // KColorCombo stuff.
 //---------------------------------------------------------------------------//
@@ -432,7 +499,7 @@ pub fn get_last_rigid_model_error(parent: &Ptr<QWidget>) -> Result<String> {
 // Special functions.
 //---------------------------------------------------------------------------//
 
-/// This function allow us to create a dialog when trying to close the main window.
+/// This function allow us to create a dialog when trying to do something that may result in unsaved data loss.
 pub extern fn are_you_sure(main_window: *mut QMainWindow, is_delete_my_mod: bool) -> bool {
     let title = qtr("rpfm_title");
     let message = if is_delete_my_mod { qtr("delete_mymod_0") }
@@ -452,3 +519,49 @@ pub extern fn are_you_sure(main_window: *mut QMainWindow, is_delete_my_mod: bool
         main_window,
     ).exec() == 3 }
 }
+
+/// This function allow us to create a dialog when trying to close the main window.
+///
+/// If the background thread is still working, we wait until it finishes before closing, so it's not killed mid-operation.
+/// Cancelling the wait cancels the close. If there are open files edited since the Pack was last saved, they're listed in the dialog.
+pub extern fn are_you_sure_to_close(main_window: *mut QMainWindow, _is_delete_my_mod: bool) -> bool {
+    unsafe {
+        if CENTRAL_COMMAND.is_background_busy() {
+            let dialog = new_progress_dialog(Ptr::from_raw(main_window).static_upcast::<QWidget>(), tr("close_waiting_for_background_thread"));
+            dialog.set_cancel_button_text(&qtr("cancel"));
+
+            let event_loop = QEventLoop::new_0a();
+            while CENTRAL_COMMAND.is_background_busy() && !dialog.was_canceled() {
+                event_loop.process_events_0a();
+                thread::sleep(Duration::from_millis(10));
+            }
+
+            let cancelled = dialog.was_canceled();
+            dialog.done(1);
+            if cancelled {
+                return false;
+            }
+        }
+    }
+
+    let unsaved_paths = UI_STATE.get_unsaved_open_packedfiles();
+    let message = if !unsaved_paths.is_empty() {
+        let paths = unsaved_paths.iter().map(|path| format!("<li>{}</li>", path)).collect::<String>();
+        qtre("close_unsaved_open_files", &[&paths])
+    }
+    else if UI_STATE.get_is_modified() { qtr("delete_mymod_1") }
+
+    // In any other situation... just return true and forget about the dialog.
+    else { return true };
+
+    // Create the dialog and run it (Yes => 3, No => 4).
+    unsafe { QMessageBox::from_2_q_string_icon3_int_q_widget(
+        &qtr("rpfm_title"),
+        &message,
+        q_message_box::Icon::Warning,
+        65536, // No
+        16384, // Yes
+        1, // By default, select yes.
+        main_window,
+    ).exec() == 3 }
+}
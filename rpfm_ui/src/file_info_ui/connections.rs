@@ -0,0 +1,37 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `FileInfoUI` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `FileInfoUI` and `FileInfoUISlots` structs.
+!*/
+
+use std::rc::Rc;
+
+use crate::dependencies_ui::DependenciesUI;
+use crate::packfile_contents_ui::PackFileContentsUI;
+
+use super::{FileInfoUI, slots::FileInfoUISlots};
+
+/// This function connects all the actions from the provided `FileInfoUI` with their slots in `FileInfoUISlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &FileInfoUI, slots: &FileInfoUISlots, pack_file_contents_ui: &Rc<PackFileContentsUI>, dependencies_ui: &Rc<DependenciesUI>) {
+    pack_file_contents_ui.packfile_contents_tree_view().selection_model().selection_changed().connect(&slots.select_from_pack_tree);
+    dependencies_ui.dependencies_tree_view().selection_model().selection_changed().connect(&slots.select_from_dependencies_tree);
+
+    // Editing or saving a file changes its status in the Pack TreeView, so use that to know when to refresh.
+    pack_file_contents_ui.packfile_contents_tree_model().data_changed().connect(&slots.request_refresh);
+
+    ui.refresh_timer.timeout().connect(&slots.refresh);
+    ui.file_info_dock_widget.visibility_changed().connect(&slots.visibility_changed);
+}
@@ -0,0 +1,261 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to the `FileInfoUI`.
+
+This panel shows all the metadata we have about the file selected in either the Pack or the Dependencies TreeView.
+!*/
+
+use qt_widgets::QDockWidget;
+use qt_widgets::QLabel;
+
+use qt_core::DockWidgetArea;
+use qt_core::QBox;
+use qt_core::QPtr;
+use qt_core::QString;
+use qt_core::QTimer;
+
+use anyhow::Result;
+use getset::Getters;
+use time::OffsetDateTime;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rpfm_lib::files::{ContainerPath, RFileDataState};
+
+use crate::app_ui::AppUI;
+use crate::backend::RFileDetailedInfo;
+use crate::CENTRAL_COMMAND;
+use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::dependencies_ui::DependenciesUI;
+use crate::FULL_DATE_FORMAT;
+use crate::locale::{qtr, tr};
+use crate::pack_tree::PackTree;
+use crate::packedfile_views::DataSource;
+use crate::packfile_contents_ui::PackFileContentsUI;
+use crate::utils::*;
+
+pub mod connections;
+pub mod slots;
+
+const VIEW_DEBUG: &str = "rpfm_ui/ui_templates/file_info_dock_widget.ui";
+const VIEW_RELEASE: &str = "ui/file_info_dock_widget.ui";
+
+/// Delay, in milliseconds, between a change in the selection or the files and the refresh of the panel.
+///
+/// Used to not ask the background thread for info again and again when a lot of changes happen at once, like when saving.
+const REFRESH_DELAY: i32 = 100;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the pointers we need to access the widgets in the File Info panel.
+#[derive(Getters)]
+#[getset(get = "pub")]
+pub struct FileInfoUI {
+
+    //-------------------------------------------------------------------------------//
+    // `File Info` Dock Widget.
+    //-------------------------------------------------------------------------------//
+    file_info_dock_widget: QPtr<QDockWidget>,
+    path_label: QPtr<QLabel>,
+    file_type_label: QPtr<QLabel>,
+    timestamp_label: QPtr<QLabel>,
+    data_state_label: QPtr<QLabel>,
+    compressed_label: QPtr<QLabel>,
+    stored_size_label: QPtr<QLabel>,
+    data_size_label: QPtr<QLabel>,
+    source_label: QPtr<QLabel>,
+    refresh_timer: QBox<QTimer>,
+
+    /// Path of the file the panel shows info about, if any.
+    selected_path: RefCell<Option<String>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl FileInfoUI {
+
+    /// This function creates an entire `FileInfoUI` struct.
+    pub unsafe fn new(app_ui: &Rc<AppUI>) -> Result<Self> {
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { VIEW_DEBUG } else { VIEW_RELEASE };
+        let main_widget = load_template(app_ui.main_window(), template_path)?;
+
+        let file_info_dock_widget: QPtr<QDockWidget> = main_widget.static_downcast();
+        let path_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "path_label")?;
+        let file_type_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "file_type_label")?;
+        let timestamp_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "timestamp_label")?;
+        let data_state_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "data_state_label")?;
+        let compressed_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "compressed_label")?;
+        let stored_size_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "stored_size_label")?;
+        let data_size_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "data_size_label")?;
+        let source_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "source_label")?;
+
+        for (name, key) in [
+            ("path_name_label", "file_info_path"),
+            ("file_type_name_label", "file_info_file_type"),
+            ("timestamp_name_label", "file_info_timestamp"),
+            ("data_state_name_label", "file_info_data_state"),
+            ("compressed_name_label", "file_info_compressed"),
+            ("stored_size_name_label", "file_info_stored_size"),
+            ("data_size_name_label", "file_info_data_size"),
+            ("source_name_label", "file_info_source"),
+        ] {
+            let label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), name)?;
+            label.set_text(&qtr(key));
+        }
+
+        app_ui.main_window().add_dock_widget_2a(DockWidgetArea::RightDockWidgetArea, file_info_dock_widget.as_ptr());
+        file_info_dock_widget.set_window_title(&qtr("file_info_title"));
+        file_info_dock_widget.set_object_name(&QString::from_std_str("file_info_dock"));
+
+        // The dock has no custom toggle action, so just use the one Qt provides.
+        let toggle_action = file_info_dock_widget.toggle_view_action();
+        toggle_action.set_text(&qtr("view_toggle_file_info_panel"));
+        app_ui.menu_bar_view().add_action(toggle_action.as_ptr());
+
+        let refresh_timer = QTimer::new_1a(&file_info_dock_widget);
+        refresh_timer.set_single_shot(true);
+        refresh_timer.set_interval(REFRESH_DELAY);
+
+        // Hide this widget by default.
+        file_info_dock_widget.hide();
+
+        let ui = Self {
+
+            //-------------------------------------------------------------------------------//
+            // `File Info` Dock Widget.
+            //-------------------------------------------------------------------------------//
+            file_info_dock_widget,
+            path_label,
+            file_type_label,
+            timestamp_label,
+            data_state_label,
+            compressed_label,
+            stored_size_label,
+            data_size_label,
+            source_label,
+            refresh_timer,
+            selected_path: RefCell::new(None),
+        };
+
+        ui.clear();
+        Ok(ui)
+    }
+
+    /// This function makes the panel follow the file selected in the Pack TreeView, if there's only one selected.
+    pub unsafe fn select_from_pack_tree(&self, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
+        let selected_items = pack_file_contents_ui.packfile_contents_tree_view().get_item_types_from_selection(true);
+        let path = match &*selected_items {
+            [ContainerPath::File(path)] => Some(path.to_owned()),
+            _ => None,
+        };
+
+        *self.selected_path.borrow_mut() = path;
+        self.request_refresh();
+    }
+
+    /// This function makes the panel follow the file selected in the Dependencies TreeView, if there's only one selected.
+    pub unsafe fn select_from_dependencies_tree(&self, dependencies_ui: &Rc<DependenciesUI>) {
+        let selected_items = dependencies_ui.dependencies_tree_view().get_item_types_and_data_source_from_selection(true);
+        let path = match &*selected_items {
+            [(ContainerPath::File(path), DataSource::GameFiles | DataSource::ParentFiles)] => Some(path.to_owned()),
+            _ => None,
+        };
+
+        *self.selected_path.borrow_mut() = path;
+        self.request_refresh();
+    }
+
+    /// This function schedules a refresh of the panel, so multiple changes in a row only trigger one.
+    pub unsafe fn request_refresh(&self) {
+        self.refresh_timer.start_0a();
+    }
+
+    /// This function reloads the info of the selected file from the background thread.
+    ///
+    /// If the panel is hidden this does nothing, as it's refreshed again when shown.
+    pub unsafe fn refresh(&self) {
+        if !self.file_info_dock_widget.is_visible() {
+            return;
+        }
+
+        let path = match &*self.selected_path.borrow() {
+            Some(path) => path.to_owned(),
+            None => return self.clear(),
+        };
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetRFileDetailedInfo(path));
+        let response = CentralCommand::recv_try(&receiver);
+        match response {
+            Response::OptionRFileDetailedInfo(Some(info)) => self.load_info(&info),
+            Response::OptionRFileDetailedInfo(None) => self.clear(),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function shows the provided info in the panel.
+    unsafe fn load_info(&self, info: &RFileDetailedInfo) {
+        let timestamp = info.timestamp()
+            .and_then(|timestamp| OffsetDateTime::from_unix_timestamp(timestamp as i64).ok())
+            .and_then(|date| date.format(&FULL_DATE_FORMAT).ok())
+            .unwrap_or_else(|| tr("file_info_unknown"));
+
+        let data_state = match info.data_state() {
+            RFileDataState::OnDisk => tr("file_info_data_state_on_disk"),
+            RFileDataState::Cached => tr("file_info_data_state_cached"),
+            RFileDataState::Decoded => tr("file_info_data_state_decoded"),
+        };
+
+        let source = match info.source_path() {
+            Some(source_path) if *info.is_from_dependencies() => format!("{} ({})", source_path, tr("file_info_source_dependencies")),
+            Some(source_path) => source_path.to_owned(),
+            None => tr("file_info_source_memory"),
+        };
+
+        self.path_label.set_text(&QString::from_std_str(info.path()));
+        self.file_type_label.set_text(&QString::from_std_str(info.file_type().to_string()));
+        self.timestamp_label.set_text(&QString::from_std_str(timestamp));
+        self.data_state_label.set_text(&QString::from_std_str(data_state));
+        self.compressed_label.set_text(&qtr(if *info.is_compressed() { "file_info_yes" } else { "file_info_no" }));
+        self.stored_size_label.set_text(&QString::from_std_str(Self::format_size(*info.stored_size())));
+        self.data_size_label.set_text(&QString::from_std_str(Self::format_size(*info.data_size())));
+        self.source_label.set_text(&QString::from_std_str(source));
+    }
+
+    /// This function clears the panel, for when there's no file to show info about.
+    unsafe fn clear(&self) {
+        self.path_label.set_text(&qtr("file_info_no_file_selected"));
+        self.file_type_label.clear();
+        self.timestamp_label.clear();
+        self.data_state_label.clear();
+        self.compressed_label.clear();
+        self.stored_size_label.clear();
+        self.data_size_label.clear();
+        self.source_label.clear();
+    }
+
+    /// This function formats the provided size in bytes for showing it in the panel.
+    fn format_size(size: Option<u64>) -> String {
+        match size {
+            Some(size) if size >= 1024 * 1024 => format!("{:.2} MiB ({} bytes)", size as f64 / (1024.0 * 1024.0), size),
+            Some(size) if size >= 1024 => format!("{:.2} KiB ({} bytes)", size as f64 / 1024.0, size),
+            Some(size) => format!("{} bytes", size),
+            None => tr("file_info_unknown"),
+        }
+    }
+}
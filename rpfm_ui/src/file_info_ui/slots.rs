@@ -0,0 +1,96 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to the main `FileInfoUISlots`.
+!*/
+
+use qt_core::QBox;
+use qt_core::{SlotNoArgs, SlotOfBool};
+
+use std::rc::Rc;
+
+use crate::dependencies_ui::DependenciesUI;
+use crate::packfile_contents_ui::PackFileContentsUI;
+
+use super::FileInfoUI;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of the file info panel.
+pub struct FileInfoUISlots {
+    pub select_from_pack_tree: QBox<SlotNoArgs>,
+    pub select_from_dependencies_tree: QBox<SlotNoArgs>,
+    pub request_refresh: QBox<SlotNoArgs>,
+    pub refresh: QBox<SlotNoArgs>,
+    pub visibility_changed: QBox<SlotOfBool>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `FileInfoUISlots`.
+impl FileInfoUISlots {
+
+    /// This function creates an entire `FileInfoUISlots` struct.
+    pub unsafe fn new(file_info_ui: &Rc<FileInfoUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>, dependencies_ui: &Rc<DependenciesUI>) -> Self {
+
+        // What happens when we select something in the Pack TreeView.
+        let select_from_pack_tree = SlotNoArgs::new(&file_info_ui.file_info_dock_widget, clone!(
+            file_info_ui,
+            pack_file_contents_ui => move || {
+                file_info_ui.select_from_pack_tree(&pack_file_contents_ui);
+            }
+        ));
+
+        // What happens when we select something in the Dependencies TreeView.
+        let select_from_dependencies_tree = SlotNoArgs::new(&file_info_ui.file_info_dock_widget, clone!(
+            file_info_ui,
+            dependencies_ui => move || {
+                file_info_ui.select_from_dependencies_tree(&dependencies_ui);
+            }
+        ));
+
+        // What happens when the files in the Pack change, like when they're edited or saved.
+        let request_refresh = SlotNoArgs::new(&file_info_ui.file_info_dock_widget, clone!(
+            file_info_ui => move || {
+                file_info_ui.request_refresh();
+            }
+        ));
+
+        // What happens when the refresh timer times out.
+        let refresh = SlotNoArgs::new(&file_info_ui.file_info_dock_widget, clone!(
+            file_info_ui => move || {
+                file_info_ui.refresh();
+            }
+        ));
+
+        // What happens when the panel is shown or hidden.
+        let visibility_changed = SlotOfBool::new(&file_info_ui.file_info_dock_widget, clone!(
+            file_info_ui => move |visible| {
+                if visible {
+                    file_info_ui.request_refresh();
+                }
+            }
+        ));
+
+        // And here... we return all the slots.
+        Self {
+            select_from_pack_tree,
+            select_from_dependencies_tree,
+            request_refresh,
+            refresh,
+            visibility_changed,
+        }
+    }
+}
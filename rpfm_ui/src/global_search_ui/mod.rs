@@ -61,7 +61,7 @@ use crate::communications::{CentralCommand, Command, Response};
 use crate::dependencies_ui::DependenciesUI;
 use crate::diagnostics_ui::DiagnosticsUI;
 use crate::ffi::{kline_edit_configure_safe, new_treeview_filter_safe, scroll_to_row_safe, trigger_treeview_filter_safe};
-use crate::locale::qtr;
+use crate::locale::{qtr, tr};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::pack_tree::{PackTree, TreeViewOperation};
 use crate::packedfile_views::{DataSource, View, ViewType};
@@ -102,6 +102,7 @@ pub struct GlobalSearchUI {
     search_source_parent: QPtr<QRadioButton>,
     search_source_game: QPtr<QRadioButton>,
     search_source_asskit: QPtr<QRadioButton>,
+    search_source_all: QPtr<QRadioButton>,
 
     search_on_all_checkbox: QPtr<QCheckBox>,
     search_on_dbs_checkbox: QPtr<QCheckBox>,
@@ -176,10 +177,12 @@ impl GlobalSearchUI {
         let search_source_parent: QPtr<QRadioButton> = find_widget(&main_widget.static_upcast(), "source_parent")?;
         let search_source_game: QPtr<QRadioButton> = find_widget(&main_widget.static_upcast(), "source_game")?;
         let search_source_asskit: QPtr<QRadioButton> = find_widget(&main_widget.static_upcast(), "source_asskit")?;
+        let search_source_all: QPtr<QRadioButton> = find_widget(&main_widget.static_upcast(), "source_all")?;
         search_source_packfile.set_text(&qtr("global_search_source_packfile"));
         search_source_parent.set_text(&qtr("global_search_source_parent"));
         search_source_game.set_text(&qtr("global_search_source_game"));
         search_source_asskit.set_text(&qtr("global_search_source_asskit"));
+        search_source_all.set_text(&qtr("global_search_source_all"));
 
         let search_source_group_box: QPtr<QGroupBox> = find_widget(&main_widget.static_upcast(), "search_source_groupbox")?;
         search_source_group_box.set_title(&qtr("global_search_search_source"));
@@ -263,6 +266,7 @@ impl GlobalSearchUI {
             search_source_parent,
             search_source_game,
             search_source_asskit,
+            search_source_all,
 
             search_on_all_checkbox,
             search_on_dbs_checkbox,
@@ -311,6 +315,8 @@ impl GlobalSearchUI {
             global_search.source = SearchSource::GameFiles;
         } else if self.search_source_asskit.is_checked() {
             global_search.source = SearchSource::AssKitFiles;
+        } else if self.search_source_all.is_checked() {
+            global_search.source = SearchSource::All;
         }
 
         if self.search_on_all_checkbox.is_checked() {
@@ -326,14 +332,18 @@ impl GlobalSearchUI {
             global_search.search_on_schema = self.search_on_schemas_checkbox.is_checked();
         }
 
-        let receiver = CENTRAL_COMMAND.send_background(Command::GlobalSearch(global_search));
+        let (receiver, cancel) = CENTRAL_COMMAND.send_background_cancellable(|cancel| Command::GlobalSearch(global_search, cancel));
+        let wait_dialog = new_cancellable_progress_dialog(&self.dock_widget, tr("global_search_in_progress_message"), &cancel);
 
         // While we wait for an answer, we need to clear the current results panels.
         self.matches_table_and_text_tree_model.clear();
         self.matches_schema_tree_model.clear();
 
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        wait_dialog.close();
+
         // Load the results to their respective models. Then, store the GlobalSearch for future checks.
-        match CentralCommand::recv(&receiver) {
+        match response {
             Response::GlobalSearchVecRFileInfo(global_search, packed_files_info) => {
                 self.load_table_matches_to_ui(&global_search.matches_db, FileType::DB);
                 self.load_table_matches_to_ui(&global_search.matches_loc, FileType::Loc);
@@ -351,6 +361,8 @@ impl GlobalSearchUI {
                 self.matches_table_and_text_tree_view.hide_column(3);
                 self.matches_table_and_text_tree_view.hide_column(4);
                 self.matches_table_and_text_tree_view.hide_column(5);
+                self.matches_table_and_text_tree_view.hide_column(6);
+                self.matches_table_and_text_tree_view.hide_column(7);
                 self.matches_table_and_text_tree_view.sort_by_column_2a(0, SortOrder::AscendingOrder);
                 self.matches_table_and_text_tree_view.header().resize_sections(ResizeMode::ResizeToContents);
 
@@ -363,9 +375,15 @@ impl GlobalSearchUI {
                     self.matches_tab_widget().set_current_index(1);
                 }
 
+                // Dependencies are read-only, so only allow replacing if the search included the open Pack.
+                let can_replace = global_search.source == SearchSource::Pack || global_search.source == SearchSource::All;
+                self.replace_button.set_enabled(can_replace);
+                self.replace_all_button.set_enabled(can_replace);
+
                 UI_STATE.set_global_search(&global_search);
                 pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateTooltip(packed_files_info), DataSource::PackFile);
             },
+            Response::Cancelled => {},
             Response::Error(error) => show_dialog(&self.dock_widget, error, false),
             _ => unimplemented!()
         }
@@ -384,7 +402,7 @@ impl GlobalSearchUI {
 
         let mut global_search = UI_STATE.get_global_search();
 
-        if global_search.source != SearchSource::Pack {
+        if global_search.source != SearchSource::Pack && global_search.source != SearchSource::All {
             return show_dialog(app_ui.main_window(), "The dependencies are read-only. You cannot do a Global Replace over them.", false);
         }
 
@@ -476,7 +494,7 @@ impl GlobalSearchUI {
 
         let mut global_search = UI_STATE.get_global_search();
 
-        if global_search.source != SearchSource::Pack {
+        if global_search.source != SearchSource::Pack && global_search.source != SearchSource::All {
             return show_dialog(app_ui.main_window(), "The dependencies are read-only. You cannot do a Global Replace over them.", false);
         }
 
@@ -560,59 +578,42 @@ impl GlobalSearchUI {
             gidhora.text().to_std_string()
         };
 
-        let global_search = UI_STATE.get_global_search();
-        let data_source = match global_search.source {
-            SearchSource::Pack => {
-                let tree_index = pack_file_contents_ui.packfile_contents_tree_view().expand_treeview_to_item(&path, DataSource::PackFile);
+        // Each file knows the source it comes from, and the file containing it if it's within another file.
+        let file_item = if is_match { gidhora.parent() } else { gidhora };
+        let data_source = DataSource::from(&*model.item_from_index(&file_item.index().sibling_at_column(6)).text().to_std_string());
+        let container_path = model.item_from_index(&file_item.index().sibling_at_column(7)).text().to_std_string();
 
-                // Manually select the open PackedFile, then open it. This means we can open PackedFiles nor in out filter.
-                UI_STATE.set_packfile_contents_read_only(true);
+        // Files within other files cannot be opened directly, so we open the file containing them instead.
+        let (path, is_match) = if container_path.is_empty() {
+            (path, is_match)
+        } else {
+            (container_path, false)
+        };
 
-                if let Some(ref tree_index) = tree_index {
-                    if tree_index.is_valid() {
-                        pack_file_contents_ui.packfile_contents_tree_view().scroll_to_1a(tree_index.as_ref().unwrap());
-                        pack_file_contents_ui.packfile_contents_tree_view().selection_model().select_q_model_index_q_flags_selection_flag(tree_index.as_ref().unwrap(), QFlags::from(SelectionFlag::ClearAndSelect));
-                    }
-                }
+        if data_source == DataSource::PackFile {
+            let tree_index = pack_file_contents_ui.packfile_contents_tree_view().expand_treeview_to_item(&path, DataSource::PackFile);
 
-                UI_STATE.set_packfile_contents_read_only(false);
-                DataSource::PackFile
-            },
+            // Manually select the open PackedFile, then open it. This means we can open PackedFiles nor in out filter.
+            UI_STATE.set_packfile_contents_read_only(true);
 
-            SearchSource::ParentFiles => {
-                let tree_index = dependencies_ui.dependencies_tree_view().expand_treeview_to_item(&path, DataSource::ParentFiles);
-                if let Some(ref tree_index) = tree_index {
-                    if tree_index.is_valid() {
-                        let _blocker = QSignalBlocker::from_q_object(dependencies_ui.dependencies_tree_view().static_upcast::<QObject>());
-                        dependencies_ui.dependencies_tree_view().scroll_to_1a(tree_index.as_ref().unwrap());
-                        dependencies_ui.dependencies_tree_view().selection_model().select_q_model_index_q_flags_selection_flag(tree_index.as_ref().unwrap(), QFlags::from(SelectionFlag::ClearAndSelect));
-                    }
+            if let Some(ref tree_index) = tree_index {
+                if tree_index.is_valid() {
+                    pack_file_contents_ui.packfile_contents_tree_view().scroll_to_1a(tree_index.as_ref().unwrap());
+                    pack_file_contents_ui.packfile_contents_tree_view().selection_model().select_q_model_index_q_flags_selection_flag(tree_index.as_ref().unwrap(), QFlags::from(SelectionFlag::ClearAndSelect));
                 }
-                DataSource::ParentFiles
-            },
-            SearchSource::GameFiles => {
-                let tree_index = dependencies_ui.dependencies_tree_view().expand_treeview_to_item(&path, DataSource::GameFiles);
-                if let Some(ref tree_index) = tree_index {
-                    if tree_index.is_valid() {
-                        let _blocker = QSignalBlocker::from_q_object(dependencies_ui.dependencies_tree_view().static_upcast::<QObject>());
-                        dependencies_ui.dependencies_tree_view().scroll_to_1a(tree_index.as_ref().unwrap());
-                        dependencies_ui.dependencies_tree_view().selection_model().select_q_model_index_q_flags_selection_flag(tree_index.as_ref().unwrap(), QFlags::from(SelectionFlag::ClearAndSelect));
-                    }
-                }
-                DataSource::GameFiles
-            },
-            SearchSource::AssKitFiles => {
-                let tree_index = dependencies_ui.dependencies_tree_view().expand_treeview_to_item(&path, DataSource::AssKitFiles);
-                if let Some(ref tree_index) = tree_index {
-                    if tree_index.is_valid() {
-                        let _blocker = QSignalBlocker::from_q_object(dependencies_ui.dependencies_tree_view().static_upcast::<QObject>());
-                        dependencies_ui.dependencies_tree_view().scroll_to_1a(tree_index.as_ref().unwrap());
-                        dependencies_ui.dependencies_tree_view().selection_model().select_q_model_index_q_flags_selection_flag(tree_index.as_ref().unwrap(), QFlags::from(SelectionFlag::ClearAndSelect));
-                    }
+            }
+
+            UI_STATE.set_packfile_contents_read_only(false);
+        } else {
+            let tree_index = dependencies_ui.dependencies_tree_view().expand_treeview_to_item(&path, data_source);
+            if let Some(ref tree_index) = tree_index {
+                if tree_index.is_valid() {
+                    let _blocker = QSignalBlocker::from_q_object(dependencies_ui.dependencies_tree_view().static_upcast::<QObject>());
+                    dependencies_ui.dependencies_tree_view().scroll_to_1a(tree_index.as_ref().unwrap());
+                    dependencies_ui.dependencies_tree_view().selection_model().select_q_model_index_q_flags_selection_flag(tree_index.as_ref().unwrap(), QFlags::from(SelectionFlag::ClearAndSelect));
                 }
-                DataSource::AssKitFiles
-            },
-        };
+            }
+        }
 
         AppUI::open_packedfile(app_ui, pack_file_contents_ui, global_search_ui, diagnostics_ui, dependencies_ui, references_ui, Some(path.to_owned()), false, false, data_source);
 
@@ -673,6 +674,8 @@ impl GlobalSearchUI {
                     let path = match_table.path();
                     let qlist_daddy = QListOfQStandardItem::new();
                     let file = QStandardItem::new();
+                    let source = QStandardItem::from_q_string(&QString::from_std_str(DataSource::from(match_table.source()).to_string()));
+                    let container_path = QStandardItem::from_q_string(&QString::from_std_str(match_table.container_path().as_deref().unwrap_or_default()));
                     let fill1 = QStandardItem::new();
                     let fill2 = QStandardItem::new();
                     let fill3 = QStandardItem::new();
@@ -681,7 +684,13 @@ impl GlobalSearchUI {
                     file.set_text(&QString::from_std_str(path));
                     TREEVIEW_ICONS.set_standard_item_icon(&file, Some(&file_type));
 
+                    if match_table.is_read_only() {
+                        file.set_tool_tip(&qtr("global_search_read_only_match"));
+                    }
+
                     file.set_editable(false);
+                    source.set_editable(false);
+                    container_path.set_editable(false);
                     fill1.set_editable(false);
                     fill2.set_editable(false);
                     fill3.set_editable(false);
@@ -727,6 +736,8 @@ impl GlobalSearchUI {
                     qlist_daddy.append_q_standard_item(&fill3.into_ptr().as_mut_raw_ptr());
                     qlist_daddy.append_q_standard_item(&fill4.into_ptr().as_mut_raw_ptr());
                     qlist_daddy.append_q_standard_item(&file_type_item.clone().as_mut_raw_ptr());
+                    qlist_daddy.append_q_standard_item(&source.into_ptr().as_mut_raw_ptr());
+                    qlist_daddy.append_q_standard_item(&container_path.into_ptr().as_mut_raw_ptr());
 
                     // Unlock the model before the last insertion.
                     if index == matches.len() - 1 {
@@ -759,6 +770,8 @@ impl GlobalSearchUI {
                     let path = match_text.path();
                     let qlist_daddy = QListOfQStandardItem::new();
                     let file = QStandardItem::new();
+                    let source = QStandardItem::from_q_string(&QString::from_std_str(DataSource::from(match_text.source()).to_string()));
+                    let container_path = QStandardItem::from_q_string(&QString::from_std_str(match_text.container_path().as_deref().unwrap_or_default()));
                     let fill1 = QStandardItem::new();
                     let fill2 = QStandardItem::new();
                     let fill3 = QStandardItem::new();
@@ -767,7 +780,13 @@ impl GlobalSearchUI {
                     file.set_text(&QString::from_std_str(path));
                     TREEVIEW_ICONS.set_standard_item_icon(&file, Some(&file_type));
 
+                    if match_text.is_read_only() {
+                        file.set_tool_tip(&qtr("global_search_read_only_match"));
+                    }
+
                     file.set_editable(false);
+                    source.set_editable(false);
+                    container_path.set_editable(false);
                     fill1.set_editable(false);
                     fill2.set_editable(false);
                     fill3.set_editable(false);
@@ -819,6 +838,8 @@ impl GlobalSearchUI {
                     qlist_daddy.append_q_standard_item(&fill3.into_ptr().as_mut_raw_ptr());
                     qlist_daddy.append_q_standard_item(&fill4.into_ptr().as_mut_raw_ptr());
                     qlist_daddy.append_q_standard_item(&file_type_item.clone().as_mut_raw_ptr());
+                    qlist_daddy.append_q_standard_item(&source.into_ptr().as_mut_raw_ptr());
+                    qlist_daddy.append_q_standard_item(&container_path.into_ptr().as_mut_raw_ptr());
 
                     // Unlock the model before the last insertion.
                     if index == matches.len() - 1 {
@@ -894,7 +915,16 @@ impl GlobalSearchUI {
         trigger_treeview_filter_safe(&model_filter, &pattern.as_ptr());
     }
 
+    /// This function returns if the provided file item of the matches tree is from a read-only source, or from a file within another file.
+    unsafe fn is_read_only_item(model: &QBox<QStandardItemModel>, item: Ptr<QStandardItem>) -> bool {
+        let data_source = DataSource::from(&*model.item_from_index(&item.index().sibling_at_column(6)).text().to_std_string());
+        let container_path = model.item_from_index(&item.index().sibling_at_column(7)).text();
+        data_source != DataSource::PackFile || !container_path.is_empty()
+    }
+
     /// Function to get all the selected matches in the visible selection.
+    ///
+    /// Matches from read-only sources are ignored.
     unsafe fn matches_from_selection(&self) -> Vec<MatchHolder> {
 
         let (model, tree_view) = match self.matches_tab_widget.current_index() {
@@ -922,6 +952,11 @@ impl GlobalSearchUI {
                     let file_type_index = parent.index().sibling_at_column(5);
                     let file_type = FileType::from(&*model.item_from_index(&file_type_index).text().to_std_string());
 
+                    // Read-only matches cannot be replaced, so don't even return them.
+                    if Self::is_read_only_item(model, parent) {
+                        continue;
+                    }
+
                     let column_name = parent.child_2a(item.row(), 1).text().to_std_string();
                     let column_number = parent.child_2a(item.row(), 3).text().to_std_string().parse().unwrap();
                     let row_number = parent.child_2a(item.row(), 2).text().to_std_string().parse::<i64>().unwrap() - 1;
@@ -972,6 +1007,10 @@ impl GlobalSearchUI {
                     let file_type_index = item.index().sibling_at_column(5);
                     let file_type = FileType::from(&*model.item_from_index(&file_type_index).text().to_std_string());
 
+                    if Self::is_read_only_item(model, item) {
+                        continue;
+                    }
+
                     // If it already exists, delete it, as the new one contains the entire set for it.
                     match file_type {
                         FileType::DB |
@@ -0,0 +1,52 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for parsing the arguments of the headless mode.
+
+use std::path::PathBuf;
+
+use super::{Headless, HeadlessOperation};
+
+fn parse(args: &[&str]) -> Option<anyhow::Result<Headless>> {
+    let args = std::iter::once("rpfm_ui").chain(args.iter().copied()).map(|arg| arg.to_owned()).collect::<Vec<_>>();
+    Headless::parse(&args)
+}
+
+#[test]
+fn test_parse_without_flags() {
+    assert!(parse(&[]).is_none());
+    assert!(parse(&["mod.pack", "other_mod.pack"]).is_none());
+}
+
+#[test]
+fn test_parse_operations() {
+    let headless = parse(&["--optimize", "mod.pack", "--out", "optimized.pack"]).unwrap().unwrap();
+    assert_eq!(headless.game, None);
+    assert_eq!(headless.operation, HeadlessOperation::Optimize { pack: PathBuf::from("mod.pack"), out: PathBuf::from("optimized.pack") });
+
+    let headless = parse(&["--export-tsv", "mod.pack", "tsv"]).unwrap().unwrap();
+    assert_eq!(headless.operation, HeadlessOperation::ExportTSV { pack: PathBuf::from("mod.pack"), folder: PathBuf::from("tsv") });
+
+    // Order doesn't matter.
+    let headless = parse(&["--json", "report.json", "--game", "warhammer_3", "--diagnose", "mod.pack"]).unwrap().unwrap();
+    assert_eq!(headless.game, Some("warhammer_3".to_owned()));
+    assert_eq!(headless.operation, HeadlessOperation::Diagnose { pack: PathBuf::from("mod.pack"), json: PathBuf::from("report.json") });
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert!(parse(&["--game", "warhammer_3"]).unwrap().is_err());
+    assert!(parse(&["--optimize", "mod.pack"]).unwrap().is_err());
+    assert!(parse(&["--diagnose", "mod.pack", "--out", "report.json"]).unwrap().is_err());
+    assert!(parse(&["--export-tsv", "mod.pack"]).unwrap().is_err());
+    assert!(parse(&["--optimize", "mod.pack", "--out", "a.pack", "--diagnose", "mod.pack", "--json", "report.json"]).unwrap().is_err());
+    assert!(parse(&["--diagnose", "mod.pack", "--json", "report.json", "--game", "not_a_game"]).unwrap().is_err());
+    assert!(parse(&["--unknown"]).unwrap().is_err());
+}
@@ -0,0 +1,248 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the headless mode of the UI binary.
+
+This allows scripting a few operations from the command line. They run on the background thread,
+like they do when triggered from the UI, but without creating the main window.
+!*/
+
+use anyhow::{anyhow, Result};
+use crossbeam::channel::Receiver;
+
+use std::path::PathBuf;
+use std::sync::{Arc, atomic::AtomicBool};
+
+use rpfm_extensions::optimizer::OptimizerOptions;
+
+use rpfm_lib::files::ContainerPath;
+use rpfm_lib::integrations::log::*;
+
+use crate::{CENTRAL_COMMAND, SUPPORTED_GAMES};
+use crate::locks::read_game_selected;
+use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICATION_ERROR};
+
+#[cfg(test)] mod headless_test;
+
+/// Exit code used when the operation finished without problems.
+pub const EXIT_CODE_SUCCESS: i32 = 0;
+
+/// Exit code used when the operation finished, but found errors. For example, a diagnostics check with errors.
+pub const EXIT_CODE_ERRORS_FOUND: i32 = 1;
+
+/// Exit code used when the operation failed.
+pub const EXIT_CODE_FAILED: i32 = 2;
+
+/// Exit code used when the arguments passed are not valid.
+pub const EXIT_CODE_INVALID_ARGUMENTS: i32 = 3;
+
+const USAGE: &str = "Usage:
+    rpfm_ui [--game <game>] --optimize <pack> --out <pack>
+    rpfm_ui [--game <game>] --export-tsv <pack> <folder>
+    rpfm_ui [--game <game>] --diagnose <pack> --json <file>
+
+If no game is provided, the default game from the settings is used.";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the operation requested from the command line, and the game it's for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Headless {
+
+    /// Key of the game to use. If None, we use the default game.
+    game: Option<String>,
+
+    /// Operation to perform.
+    operation: HeadlessOperation,
+}
+
+/// This enum contains the operations that can be performed without the UI.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HeadlessOperation {
+
+    /// Optimize a Pack, saving the optimized Pack in another path.
+    Optimize { pack: PathBuf, out: PathBuf },
+
+    /// Export all the tables of a Pack to TSV files in a folder.
+    ExportTSV { pack: PathBuf, folder: PathBuf },
+
+    /// Check a Pack for errors, writing the results as json to a file.
+    Diagnose { pack: PathBuf, json: PathBuf },
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl Headless {
+
+    /// This function parses the arguments RPFM was started with, skipping the executable path.
+    ///
+    /// It returns None if we have to start the UI normally, which is the case when no flags are provided.
+    pub fn parse(args: &[String]) -> Option<Result<Self>> {
+        if args.iter().skip(1).any(|arg| arg.starts_with("--")) {
+            Some(Self::parse_flags(args).map_err(|error| anyhow!("{}\n\n{}", error, USAGE)))
+        } else {
+            None
+        }
+    }
+
+    fn parse_flags(args: &[String]) -> Result<Self> {
+        let mut game = None;
+        let mut operation = None;
+        let mut out = None;
+        let mut json = None;
+
+        let mut args = args.iter().skip(1);
+        while let Some(arg) = args.next() {
+            if matches!(&**arg, "--optimize" | "--export-tsv" | "--diagnose") && operation.is_some() {
+                return Err(anyhow!("Only one operation can be performed at a time."));
+            }
+
+            let mut value = || args.next().cloned().ok_or_else(|| anyhow!("Missing value for {}.", arg));
+            match &**arg {
+                "--game" => game = Some(value()?),
+                "--optimize" => operation = Some(HeadlessOperation::Optimize { pack: PathBuf::from(value()?), out: PathBuf::new() }),
+                "--export-tsv" => operation = Some(HeadlessOperation::ExportTSV { pack: PathBuf::from(value()?), folder: PathBuf::from(value()?) }),
+                "--diagnose" => operation = Some(HeadlessOperation::Diagnose { pack: PathBuf::from(value()?), json: PathBuf::new() }),
+                "--out" => out = Some(PathBuf::from(value()?)),
+                "--json" => json = Some(PathBuf::from(value()?)),
+                _ => return Err(anyhow!("Unknown argument: {}.", arg)),
+            }
+        }
+
+        // The output paths depend on the operation, so we can only fill them once we know which one it is.
+        let mut operation = operation.ok_or_else(|| anyhow!("No operation provided."))?;
+        match operation {
+            HeadlessOperation::Optimize { out: ref mut path, .. } => *path = out.take().ok_or_else(|| anyhow!("Missing --out argument."))?,
+            HeadlessOperation::Diagnose { json: ref mut path, .. } => *path = json.take().ok_or_else(|| anyhow!("Missing --json argument."))?,
+            HeadlessOperation::ExportTSV { .. } => {},
+        }
+
+        if out.is_some() || json.is_some() {
+            return Err(anyhow!("--out is only valid with --optimize, and --json is only valid with --diagnose."));
+        }
+
+        if let Some(ref game) = game {
+            if SUPPORTED_GAMES.game(game).is_none() {
+                return Err(anyhow!("Unknown game: {}.", game));
+            }
+        }
+
+        Ok(Self {
+            game,
+            operation,
+        })
+    }
+
+    /// This function performs the requested operation, returning the exit code RPFM should exit with.
+    ///
+    /// The background thread must be running before calling this.
+    pub fn run(&self) -> i32 {
+        match self.run_operation() {
+            Ok(exit_code) => exit_code,
+            Err(error) => {
+                error!("{}", error);
+                eprintln!("{}", error);
+                EXIT_CODE_FAILED
+            }
+        }
+    }
+
+    fn run_operation(&self) -> Result<i32> {
+        let pack = match self.operation {
+            HeadlessOperation::Optimize { ref pack, .. } |
+            HeadlessOperation::ExportTSV { ref pack, .. } |
+            HeadlessOperation::Diagnose { ref pack, .. } => pack,
+        };
+
+        // Load the schema first, so the Pack's tables get decoded on open. Then, load the dependencies of the Pack.
+        let game = self.game.clone().unwrap_or_else(|| read_game_selected().game_key_name());
+        match Self::recv(&CENTRAL_COMMAND.send_background(Command::SetGameSelected(game, false))) {
+            Response::Success => {},
+            response => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        match Self::recv(&CENTRAL_COMMAND.send_background(Command::OpenPackFiles(vec![pack.to_path_buf()]))) {
+            Response::ContainerInfo(_) => {},
+            Response::Error(error) => return Err(error),
+            Response::ErrorKind(_, message) => return Err(anyhow!(message)),
+            response => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        match Self::recv(&CENTRAL_COMMAND.send_background(Command::RebuildDependencies(false))) {
+            Response::DependenciesInfo(_) => {},
+            Response::Error(error) => return Err(error),
+            Response::ErrorKind(_, message) => return Err(anyhow!(message)),
+            response => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        match self.operation {
+            HeadlessOperation::Optimize { ref out, .. } => {
+                let cancel = Arc::new(AtomicBool::new(false));
+                match Self::recv(&CENTRAL_COMMAND.send_background(Command::OptimizePackFile(OptimizerOptions::default(), cancel))) {
+                    Response::HashSetString(deleted) => println!("Optimized Pack. Files removed: {}.", deleted.len()),
+                    Response::Error(error) => return Err(error),
+                    Response::ErrorKind(_, message) => return Err(anyhow!(message)),
+                    response => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+
+                match Self::recv(&CENTRAL_COMMAND.send_background(Command::SavePackFileAs(out.to_path_buf()))) {
+                    Response::ContainerInfo(_) => Ok(EXIT_CODE_SUCCESS),
+                    Response::Error(error) => Err(error),
+                    Response::ErrorKind(_, message) => Err(anyhow!(message)),
+                    response => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+            }
+
+            HeadlessOperation::ExportTSV { ref folder, .. } => {
+                match Self::recv(&CENTRAL_COMMAND.send_background(Command::ExportTSVFolder(ContainerPath::Folder(String::new()), folder.to_path_buf()))) {
+                    Response::VecContainerPathVecString(exported, errors) => {
+                        println!("Exported {} tables.", exported.len());
+                        for error in &errors {
+                            eprintln!("{}", error);
+                        }
+
+                        Ok(if errors.is_empty() { EXIT_CODE_SUCCESS } else { EXIT_CODE_ERRORS_FOUND })
+                    }
+                    Response::Error(error) => Err(error),
+                    response => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+            }
+
+            HeadlessOperation::Diagnose { ref json, .. } => {
+                let cancel = Arc::new(AtomicBool::new(false));
+                match Self::recv(&CENTRAL_COMMAND.send_background(Command::DiagnosticsCheck(vec![], cancel))) {
+                    Response::Diagnostics(diagnostics) => {
+                        std::fs::write(json, diagnostics.json()?)?;
+                        Ok(if diagnostics.has_errors() { EXIT_CODE_ERRORS_FOUND } else { EXIT_CODE_SUCCESS })
+                    }
+                    Response::Error(error) => Err(error),
+                    response => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+            }
+        }
+    }
+
+    /// This function waits for the final response of a command, skipping the progress ones.
+    ///
+    /// Unlike `recv_try`, this doesn't need the UI event loop.
+    fn recv(receiver: &Receiver<Response>) -> Response {
+        loop {
+            match CentralCommand::recv(receiver) {
+                Response::Progress(_, _, _) => continue,
+                response => return response,
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the poisoning-resistant lock accessors.
+
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+use super::{read_lock, replace_lock, write_lock};
+
+/// This function returns a lock poisoned by a thread that panicked while writing to it.
+fn poisoned_lock() -> Arc<RwLock<Vec<u32>>> {
+    let lock = Arc::new(RwLock::new(vec![1, 2]));
+    let lock_thread = lock.clone();
+    let result = thread::spawn(move || {
+        let mut guard = lock_thread.write().unwrap();
+        guard.push(3);
+        panic!("Panic while holding the lock.");
+    }).join();
+
+    assert!(result.is_err());
+    assert!(lock.is_poisoned());
+    lock
+}
+
+#[test]
+fn test_read_lock() {
+    let lock = RwLock::new(5);
+    assert_eq!(*read_lock(&lock, "test"), 5);
+
+    // The value written before the panic must be kept.
+    let lock = poisoned_lock();
+    assert_eq!(*read_lock(&lock, "test"), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_write_lock() {
+    let lock = poisoned_lock();
+    write_lock(&lock, "test").push(4);
+    write_lock(&lock, "test").push(5);
+    assert_eq!(*read_lock(&lock, "test"), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_replace_lock() {
+    let lock = RwLock::new(Some("old".to_owned()));
+    assert_eq!(replace_lock(&lock, "test", Some("new".to_owned())), Some("old".to_owned()));
+    assert_eq!(*read_lock(&lock, "test"), Some("new".to_owned()));
+
+    let lock = poisoned_lock();
+    assert_eq!(replace_lock(&lock, "test", vec![]), vec![1, 2, 3]);
+    assert!(read_lock(&lock, "test").is_empty());
+}
@@ -0,0 +1,96 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the accessors to the global locks shared between threads.
+
+If a thread panics while holding one of these locks, the lock gets poisoned, and any other thread trying to `unwrap` it later
+panics too, turning a single failure into a crash of the entire program. The accessors in this module recover the guard of
+poisoned locks instead, logging it, so the rest of the program can keep working.
+!*/
+
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use rpfm_lib::games::GameInfo;
+use rpfm_lib::integrations::log::{ClientInitGuard, warn};
+use rpfm_lib::schema::Schema;
+
+use crate::GAME_SELECTED;
+use crate::SCHEMA;
+use crate::SENTRY_GUARD;
+
+#[cfg(test)] mod locks_test;
+
+//-------------------------------------------------------------------------------//
+//                             Generic accessors
+//-------------------------------------------------------------------------------//
+
+/// This function locks the provided lock for reading, recovering the guard if the lock is poisoned.
+///
+/// The name is only used to know which lock was poisoned in the logs.
+pub fn read_lock<'a, T>(lock: &'a RwLock<T>, name: &str) -> RwLockReadGuard<'a, T> {
+    lock.read().unwrap_or_else(|error| {
+        warn!("The {} lock was poisoned by a panic in another thread. Recovering it.", name);
+        error.into_inner()
+    })
+}
+
+/// This function locks the provided lock for writing, recovering the guard if the lock is poisoned.
+///
+/// The name is only used to know which lock was poisoned in the logs.
+pub fn write_lock<'a, T>(lock: &'a RwLock<T>, name: &str) -> RwLockWriteGuard<'a, T> {
+    lock.write().unwrap_or_else(|error| {
+        warn!("The {} lock was poisoned by a panic in another thread. Recovering it.", name);
+        error.into_inner()
+    })
+}
+
+/// This function replaces the value of the provided lock with a single write, returning the old value.
+///
+/// The new value must be fully built before calling this, so readers either see the old value or the new one, never something in between.
+pub fn replace_lock<T>(lock: &RwLock<T>, name: &str, value: T) -> T {
+    std::mem::replace(&mut *write_lock(lock, name), value)
+}
+
+//-------------------------------------------------------------------------------//
+//                             Global accessors
+//-------------------------------------------------------------------------------//
+
+/// This function returns the game currently selected, locked for reading.
+pub fn read_game_selected() -> RwLockReadGuard<'static, &'static GameInfo> {
+    read_lock(&GAME_SELECTED, "GAME_SELECTED")
+}
+
+/// This function returns the game currently selected, locked for writing.
+pub fn write_game_selected() -> RwLockWriteGuard<'static, &'static GameInfo> {
+    write_lock(&GAME_SELECTED, "GAME_SELECTED")
+}
+
+/// This function returns the currently loaded schema, locked for reading.
+pub fn read_schema() -> RwLockReadGuard<'static, Option<Schema>> {
+    read_lock(&SCHEMA, "SCHEMA")
+}
+
+/// This function returns the currently loaded schema, locked for writing.
+///
+/// Use [replace_schema] to load a new schema instead, so it's swapped in one go.
+pub fn write_schema() -> RwLockWriteGuard<'static, Option<Schema>> {
+    write_lock(&SCHEMA, "SCHEMA")
+}
+
+/// This function replaces the currently loaded schema with the provided one, returning the old one.
+pub fn replace_schema(schema: Option<Schema>) -> Option<Schema> {
+    replace_lock(&SCHEMA, "SCHEMA", schema)
+}
+
+/// This function returns the Sentry client guard, locked for reading.
+pub fn read_sentry_guard() -> RwLockReadGuard<'static, ClientInitGuard> {
+    read_lock(&SENTRY_GUARD, "SENTRY_GUARD")
+}
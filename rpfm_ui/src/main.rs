@@ -42,16 +42,21 @@ use qt_core::QString;
 use lazy_static::lazy_static;
 use time::format_description::{parse, FormatItem};
 
+use std::env::args;
 use std::path::PathBuf;
+use std::process::exit;
 use std::sync::{Arc, atomic::{AtomicBool, AtomicPtr}, RwLock};
-use std::thread;
+use std::thread::{self, JoinHandle};
 
 use rpfm_lib::games::{GameInfo, supported_games::{SupportedGames, KEY_WARHAMMER_3}};
 use rpfm_lib::integrations::log::*;
 use rpfm_lib::schema::Schema;
 
+use crate::app_ui::AppUI;
 use crate::communications::{CentralCommand, Command, Response};
+use crate::headless::{EXIT_CODE_INVALID_ARGUMENTS, Headless};
 use crate::locale::Locale;
+use crate::locks::read_sentry_guard;
 use crate::pack_tree::icons::Icons;
 use crate::settings_ui::backend::*;
 use crate::ui::*;
@@ -95,11 +100,15 @@ mod background_thread;
 mod communications;
 mod dependencies_ui;
 mod diagnostics_ui;
+mod file_info_ui;
 mod ffi;
 mod global_search_ui;
+mod headless;
 mod locale;
+mod locks;
 mod mymod_ui;
 mod network_thread;
+mod operations_ui;
 mod pack_tree;
 mod packfile_contents_ui;
 mod packedfile_views;
@@ -255,6 +264,9 @@ lazy_static! {
 
     /// Formatted date, so we can reuse it instead of re-parsing it on each use.
     static ref FULL_DATE_FORMAT: Vec<FormatItem<'static>> = parse("[year]-[month]-[day] [hour]:[minute]:[second]").unwrap();
+
+    /// Date format used to name the autosaves. No colons here, as Windows doesn't allow them in file names.
+    static ref AUTOSAVE_DATE_FORMAT: Vec<FormatItem<'static>> = parse("[year]-[month]-[day]T[hour]-[minute]-[second]").unwrap();
 }
 
 /// This constant gets RPFM's version from the `Cargo.toml` file, so we don't have to change it
@@ -271,21 +283,42 @@ const DISCORD_URL: &str = "https://discord.gg/moddingden";
 fn main() {
 
     // Access the guard to make sure it gets initialized.
-    if SENTRY_GUARD.read().unwrap().is_enabled() {
+    if read_sentry_guard().is_enabled() {
         info!("Sentry Logging support enabled. Starting...");
     } else {
         info!("Sentry Logging support disabled. Starting...");
     }
 
+    // If we got flags, we have to perform an operation without the UI. Check them before doing anything else.
+    let headless = match Headless::parse(&args().collect::<Vec<String>>()) {
+        Some(Ok(headless)) => Some(headless),
+        Some(Err(error)) => {
+            eprintln!("{}", error);
+            exit(EXIT_CODE_INVALID_ARGUMENTS);
+        }
+        None => None,
+    };
+
     //---------------------------------------------------------------------------------------//
     // Preparing the Program...
     //---------------------------------------------------------------------------------------//
 
+    // Register the decoders for file types not supported by the lib before anything tries to open a file.
+    rpfm_extensions::custom_files::register_all();
+
     // Create the background and network threads, where all the magic will happen.
     info!("Initializing threads...");
     let bac_handle = thread::spawn(|| { background_thread::background_loop(); });
     let net_handle = thread::spawn(|| { network_thread::network_loop(); });
 
+    // In headless mode, perform the operation and exit without ever creating the window.
+    if let Some(headless) = headless {
+        let exit_code = headless.run();
+
+        close_threads(bac_handle, net_handle);
+        exit(exit_code);
+    }
+
     // Create the application and start the loop.
     QApplication::init(|_app| {
         let ui = unsafe { UI::new() };
@@ -297,12 +330,11 @@ fn main() {
                     unsafe { QApplication::exec() }
                 } else { 0 };
 
-                // Close and rejoin the threads on exit, so we don't leave a rogue thread running.
-                CENTRAL_COMMAND.send_background(Command::Exit);
-                CENTRAL_COMMAND.send_network(Command::Exit);
+                // Remember the open files of the current Pack for the next time it's open.
+                unsafe { AppUI::save_tab_session(&ui.app_ui); }
 
-                let _ = bac_handle.join();
-                let _ = net_handle.join();
+                // Close and rejoin the threads on exit, so we don't leave a rogue thread running.
+                close_threads(bac_handle, net_handle);
 
                 exit_code
             }
@@ -310,11 +342,7 @@ fn main() {
                 error!("{}", error);
 
                 // Close and rejoin the threads on exit, so we don't leave a rogue thread running.
-                CENTRAL_COMMAND.send_background(Command::Exit);
-                CENTRAL_COMMAND.send_network(Command::Exit);
-
-                let _ = bac_handle.join();
-                let _ = net_handle.join();
+                close_threads(bac_handle, net_handle);
 
                 55
             }
@@ -322,3 +350,13 @@ fn main() {
     })
 }
 
+/// This function asks the background and network threads to exit, and waits until they do.
+///
+/// It takes the handles of the threads, so the exit command cannot be sent twice to them.
+fn close_threads(bac_handle: JoinHandle<()>, net_handle: JoinHandle<()>) {
+    CENTRAL_COMMAND.send_background(Command::Exit);
+    CENTRAL_COMMAND.send_network(Command::Exit);
+
+    let _ = bac_handle.join();
+    let _ = net_handle.join();
+}
@@ -25,6 +25,7 @@ use super::{MyModUI, slots::MyModUISlots};
 pub unsafe fn set_connections(ui: &MyModUI, slots: &MyModUISlots) {
     ui.name_line_edit().text_changed().connect(&slots.mymod_update_dialog);
     ui.game_combobox().current_text_changed().connect(&slots.mymod_update_dialog);
+    ui.game_combobox().current_text_changed().connect(&slots.mymod_update_skeleton);
 
     ui.gitignore_same_as_files_ignored_on_import_checkbox().state_changed().connect(&slots.mymod_update_dialog);
 
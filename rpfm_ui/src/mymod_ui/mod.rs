@@ -30,11 +30,12 @@ use qt_core::QPtr;
 use anyhow::Result;
 use getset::Getters;
 
+use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::app_ui::AppUI;
 use crate::ffi::*;
-use crate::GAME_SELECTED;
+use crate::locks::read_game_selected;
 use crate::locale::{qtr, tr};
 use crate::settings_ui::backend::*;
 use crate::SUPPORTED_GAMES;
@@ -64,6 +65,9 @@ pub struct MyModUI {
     gitignore_same_as_files_ignored_on_import_checkbox: QPtr<QCheckBox>,
     gitignore_contents_textedit: QPtr<QTextEdit>,
     pack_import_ignore_contents_textedit: QPtr<QTextEdit>,
+    skeleton_components_widget: QPtr<QWidget>,
+    skeleton_components_checkboxes: RefCell<Vec<(String, QPtr<QCheckBox>)>>,
+    skeleton_in_pack_checkbox: QPtr<QCheckBox>,
     game_combobox: QPtr<QComboBox>,
     name_line_edit: QPtr<QLineEdit>,
     message_widget: QPtr<QWidget>,
@@ -78,8 +82,8 @@ pub struct MyModUI {
 impl MyModUI {
 
     /// This function creates the entire "New Mod" dialog and executes it. It returns
-    /// the name of the mod and the folder_name of the game.
-    pub unsafe fn new(app_ui: &Rc<AppUI>) -> Result<Option<(String, String, bool, bool, String, Option<String>)>> {
+    /// the name of the mod and the folder_name of the game, the options chosen, and the components of the game's folder structure to create.
+    pub unsafe fn new(app_ui: &Rc<AppUI>) -> Result<Option<(String, String, bool, bool, String, Option<String>, Vec<String>, bool)>> {
 
         // Load the UI Template.
         let template_path = if cfg!(debug_assertions) { VIEW_DEBUG } else { VIEW_RELEASE };
@@ -96,6 +100,9 @@ impl MyModUI {
         let gitignore_contents_textedit: QPtr<QTextEdit> = find_widget(&main_widget.static_upcast(), "gitignore_contents_textedit")?;
         let pack_import_ignore_contents_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "pack_import_ignore_contents_label")?;
         let pack_import_ignore_contents_textedit: QPtr<QTextEdit> = find_widget(&main_widget.static_upcast(), "pack_import_ignore_contents_textedit")?;
+        let skeleton_groupbox: QPtr<QGroupBox> = find_widget(&main_widget.static_upcast(), "skeleton_groupbox")?;
+        let skeleton_components_widget: QPtr<QWidget> = find_widget(&main_widget.static_upcast(), "skeleton_components_widget")?;
+        let skeleton_in_pack_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "skeleton_in_pack_checkbox")?;
 
         let game_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "game_combobox")?;
         let name_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "name_label")?;
@@ -116,6 +123,8 @@ impl MyModUI {
         git_support_groupbox.set_title(&qtr("new_mymod_git_support"));
         sublime_support_checkbox.set_text(&qtr("new_mymod_sublime_support"));
         vscode_support_checkbox.set_text(&qtr("new_mymod_vscode_support"));
+        skeleton_groupbox.set_title(&qtr("new_mymod_skeleton"));
+        skeleton_in_pack_checkbox.set_text(&qtr("new_mymod_skeleton_in_pack"));
         gitignore_contents_label.set_text(&qtr("new_mymod_gitignore_contents"));
         gitignore_same_as_files_ignored_on_import_checkbox.set_text(&qtr("new_mymod_gitignore_same_as_files_ignored_on_import"));
         pack_import_ignore_contents_label.set_text(&qtr("new_mymod_pack_import_ignore_contents"));
@@ -130,7 +139,7 @@ impl MyModUI {
         // Add the games to the ComboBox.
         let mut selected_index = 0;
         let mut selected_index_counter = 0;
-        let game_selected = GAME_SELECTED.read().unwrap().game_key_name();
+        let game_selected = read_game_selected().game_key_name();
         for game in SUPPORTED_GAMES.games_sorted() {
             if game.supports_editing() {
                 game_combobox.add_item_q_string(&QString::from_std_str(game.display_name()));
@@ -155,12 +164,17 @@ impl MyModUI {
             gitignore_same_as_files_ignored_on_import_checkbox,
             gitignore_contents_textedit,
             pack_import_ignore_contents_textedit,
+            skeleton_components_widget,
+            skeleton_components_checkboxes: RefCell::new(vec![]),
+            skeleton_in_pack_checkbox,
             game_combobox,
             name_line_edit,
             message_widget,
             button_box,
         });
 
+        mymod_ui.load_skeleton_components();
+
         let mymod_slots = MyModUISlots::new(&mymod_ui);
         connections::set_connections(&mymod_ui, &mymod_slots);
 
@@ -203,13 +217,55 @@ impl MyModUI {
                 }
             } else { None };
 
-            Ok(Some((mod_name, mod_game, mymod_ui.sublime_support_checkbox.is_checked(), mymod_ui.vscode_support_checkbox.is_checked(), pack_import_ignore_paths, gitignore_contents)))
+            let skeleton_components = mymod_ui.skeleton_components_checkboxes.borrow().iter()
+                .filter(|(_, checkbox)| checkbox.is_checked())
+                .map(|(key, _)| key.to_owned())
+                .collect::<Vec<_>>();
+
+            Ok(Some((
+                mod_name,
+                mod_game,
+                mymod_ui.sublime_support_checkbox.is_checked(),
+                mymod_ui.vscode_support_checkbox.is_checked(),
+                pack_import_ignore_paths,
+                gitignore_contents,
+                skeleton_components,
+                mymod_ui.skeleton_in_pack_checkbox.is_checked()
+            )))
         }
 
         // If we cancelled/closed it, return `None`.
         else { Ok(None) }
     }
 
+    /// Function to load a checkbox for each component of the folder structure of the selected game, replacing the ones of the previous game.
+    unsafe fn load_skeleton_components(&self) {
+        for (_, checkbox) in self.skeleton_components_checkboxes.borrow_mut().drain(..) {
+            checkbox.delete_later();
+        }
+
+        let mut game = self.game_combobox.current_text().to_std_string();
+        if let Some(index) = game.find('&') { game.remove(index); }
+        let mod_game = game.replace(' ', "_").to_lowercase();
+
+        if let Some(game) = SUPPORTED_GAMES.game(&mod_game) {
+            let layout = self.skeleton_components_widget.layout();
+            for component in game.mymod_skeleton() {
+                let checkbox = QCheckBox::from_q_string_q_widget(&qtr(&format!("new_mymod_skeleton_{}", component.key())), &self.skeleton_components_widget);
+                checkbox.set_checked(true);
+
+                let mut paths = component.folders().iter().map(|folder| format!("{}/", folder)).collect::<Vec<_>>();
+                paths.extend(component.files().iter().map(|(path, _)| path.to_owned()));
+                checkbox.set_tool_tip(&QString::from_std_str(paths.join("\n")));
+
+                layout.add_widget(&checkbox);
+                self.skeleton_components_checkboxes.borrow_mut().push((component.key().to_owned(), checkbox.into_q_ptr()));
+            }
+        }
+
+        self.skeleton_in_pack_checkbox.set_enabled(!self.skeleton_components_checkboxes.borrow().is_empty());
+    }
+
     /// Function to update the dialog depending on options selected.
     unsafe fn update_dialog(&self) {
 
@@ -26,6 +26,7 @@ use crate::mymod_ui::MyModUI;
 /// This struct contains all the slots we need to respond to signals of the New MyMod Dialog.
 pub struct MyModUISlots {
     pub mymod_update_dialog: QBox<SlotNoArgs>,
+    pub mymod_update_skeleton: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -42,8 +43,14 @@ impl MyModUISlots {
             mymod_ui.update_dialog();
         }));
 
+        let mymod_update_skeleton = SlotNoArgs::new(&mymod_ui.dialog, clone!(
+            mymod_ui => move || {
+            mymod_ui.load_skeleton_components();
+        }));
+
         Self {
             mymod_update_dialog,
+            mymod_update_skeleton,
         }
     }
 }
@@ -0,0 +1,27 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `OperationsUI` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `OperationsUI` and `OperationsUISlots` structs.
+!*/
+
+use super::{OperationsUI, slots::OperationsUISlots};
+
+/// This function connects all the actions from the provided `OperationsUI` with their slots in `OperationsUISlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &OperationsUI, slots: &OperationsUISlots) {
+    ui.poll_timer.timeout().connect(&slots.poll_notifications);
+    ui.level_filter_combobox.current_index_changed().connect(&slots.filter_by_level);
+    ui.copy_all_button.released().connect(&slots.copy_all);
+}
@@ -0,0 +1,235 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to the `OperationsUI`.
+
+This panel shows a log of the significant actions done in the background thread, like opening or saving Packs.
+!*/
+
+use qt_widgets::QComboBox;
+use qt_widgets::QDockWidget;
+use qt_widgets::q_header_view::ResizeMode;
+use qt_widgets::QPushButton;
+use qt_widgets::QTableView;
+use qt_widgets::QWidget;
+
+use qt_gui::QGuiApplication;
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::{DockWidgetArea, Orientation};
+use qt_core::QBox;
+use qt_core::QPtr;
+use qt_core::QString;
+use qt_core::QTimer;
+use qt_core::QVariant;
+
+use anyhow::Result;
+use getset::Getters;
+use time::OffsetDateTime;
+
+use std::rc::Rc;
+
+use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::{NotificationLevel, Response};
+use crate::FULL_DATE_FORMAT;
+use crate::locale::qtr;
+use crate::utils::*;
+
+pub mod connections;
+pub mod slots;
+
+const VIEW_DEBUG: &str = "rpfm_ui/ui_templates/operations_dock_widget.ui";
+const VIEW_RELEASE: &str = "ui/operations_dock_widget.ui";
+
+/// Interval, in milliseconds, between checks for new notifications.
+const POLL_INTERVAL: i32 = 250;
+
+/// Data role used to store the level of each notification in the model.
+const LEVEL_ROLE: i32 = 40;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the pointers we need to access the widgets in the Operations panel.
+#[derive(Getters)]
+#[getset(get = "pub")]
+pub struct OperationsUI {
+
+    //-------------------------------------------------------------------------------//
+    // `Operations` Dock Widget.
+    //-------------------------------------------------------------------------------//
+    operations_dock_widget: QPtr<QDockWidget>,
+    operations_table_view: QPtr<QTableView>,
+    operations_table_model: QBox<QStandardItemModel>,
+    level_filter_combobox: QPtr<QComboBox>,
+    copy_all_button: QPtr<QPushButton>,
+    poll_timer: QBox<QTimer>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl OperationsUI {
+
+    /// This function creates an entire `OperationsUI` struct.
+    pub unsafe fn new(app_ui: &Rc<AppUI>) -> Result<Self> {
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { VIEW_DEBUG } else { VIEW_RELEASE };
+        let main_widget = load_template(app_ui.main_window(), template_path)?;
+
+        let operations_dock_widget: QPtr<QDockWidget> = main_widget.static_downcast();
+        let operations_dock_inner_widget: QPtr<QWidget> = find_widget(&main_widget.static_upcast(), "inner_widget")?;
+        let operations_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "operations_table_view")?;
+        let level_filter_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "level_filter_combobox")?;
+        let copy_all_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "copy_all_button")?;
+
+        app_ui.main_window().add_dock_widget_2a(DockWidgetArea::BottomDockWidgetArea, operations_dock_widget.as_ptr());
+        operations_dock_widget.set_window_title(&qtr("operations_title"));
+        operations_dock_widget.set_object_name(&QString::from_std_str("operations_dock"));
+
+        // The dock has no custom toggle action, so just use the one Qt provides.
+        let toggle_action = operations_dock_widget.toggle_view_action();
+        toggle_action.set_text(&qtr("view_toggle_operations_panel"));
+        app_ui.menu_bar_view().add_action(toggle_action.as_ptr());
+
+        // The order of the levels here must match the order of `NotificationLevel`.
+        level_filter_combobox.add_item_q_string(&qtr("operations_filter_info"));
+        level_filter_combobox.add_item_q_string(&qtr("operations_filter_warning"));
+        level_filter_combobox.add_item_q_string(&qtr("operations_filter_error"));
+        copy_all_button.set_text(&qtr("operations_copy_all"));
+
+        let operations_table_model = QStandardItemModel::new_1a(&operations_dock_inner_widget);
+        operations_table_view.set_model(&operations_table_model);
+        operations_table_model.set_column_count(3);
+        operations_table_model.set_header_data_3a(0, Orientation::Horizontal, &QVariant::from_q_string(&qtr("operations_time")));
+        operations_table_model.set_header_data_3a(1, Orientation::Horizontal, &QVariant::from_q_string(&qtr("operations_level")));
+        operations_table_model.set_header_data_3a(2, Orientation::Horizontal, &QVariant::from_q_string(&qtr("operations_message")));
+        operations_table_view.horizontal_header().set_stretch_last_section(true);
+
+        // Notifications are polled, so the background thread never has to wait for the UI.
+        let poll_timer = QTimer::new_1a(&operations_dock_widget);
+        poll_timer.set_interval(POLL_INTERVAL);
+        poll_timer.start_0a();
+
+        // Hide this widget by default.
+        operations_dock_widget.hide();
+
+        Ok(Self {
+
+            //-------------------------------------------------------------------------------//
+            // `Operations` Dock Widget.
+            //-------------------------------------------------------------------------------//
+            operations_dock_widget,
+            operations_table_view,
+            operations_table_model,
+            level_filter_combobox,
+            copy_all_button,
+            poll_timer,
+        })
+    }
+
+    /// This function adds to the panel all the notifications waiting in the notifications channel.
+    ///
+    /// Notifications that are not meant for the panel are returned, so the caller can handle them.
+    pub unsafe fn poll_notifications(&self) -> Vec<Response> {
+        let mut others = vec![];
+        let mut added = false;
+        for notification in CENTRAL_COMMAND.recv_notifications() {
+            match notification {
+                Response::Notification(level, message) => {
+                    self.add_notification(level, &message);
+                    added = true;
+                }
+                _ => others.push(notification),
+            }
+        }
+
+        if !added {
+            return others;
+        }
+
+        self.operations_table_view.horizontal_header().resize_sections(ResizeMode::ResizeToContents);
+        self.operations_table_view.horizontal_header().set_stretch_last_section(true);
+        self.operations_table_view.scroll_to_bottom();
+        others
+    }
+
+    /// This function adds a notification to the end of the panel, with the current time.
+    unsafe fn add_notification(&self, level: NotificationLevel, message: &str) {
+        let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+        let time = now.format(&FULL_DATE_FORMAT).unwrap_or_default();
+
+        let qlist_boi = QListOfQStandardItem::new();
+        let time_item = QStandardItem::from_q_string(&QString::from_std_str(time));
+        let level_item = QStandardItem::from_q_string(&qtr(Self::level_key(level)));
+        let message_item = QStandardItem::from_q_string(&QString::from_std_str(message));
+
+        level_item.set_data_2a(&QVariant::from_int(level as i32), LEVEL_ROLE);
+
+        time_item.set_editable(false);
+        level_item.set_editable(false);
+        message_item.set_editable(false);
+
+        qlist_boi.append_q_standard_item(&time_item.into_ptr().as_mut_raw_ptr());
+        qlist_boi.append_q_standard_item(&level_item.into_ptr().as_mut_raw_ptr());
+        qlist_boi.append_q_standard_item(&message_item.into_ptr().as_mut_raw_ptr());
+        self.operations_table_model.append_row_q_list_of_q_standard_item(qlist_boi.as_ref());
+
+        let row = self.operations_table_model.row_count_0a() - 1;
+        self.operations_table_view.set_row_hidden(row, !self.is_level_visible(level as i32));
+    }
+
+    /// This function hides the notifications below the level selected in the level filter.
+    pub unsafe fn filter_by_level(&self) {
+        for row in 0..self.operations_table_model.row_count_0a() {
+            let level = self.operations_table_model.item_2a(row, 1).data_1a(LEVEL_ROLE).to_int_0a();
+            self.operations_table_view.set_row_hidden(row, !self.is_level_visible(level));
+        }
+    }
+
+    /// This function copies all the visible notifications to the clipboard, one per line.
+    pub unsafe fn copy_all(&self) {
+        let mut text = String::new();
+        for row in 0..self.operations_table_model.row_count_0a() {
+            if self.operations_table_view.is_row_hidden(row) {
+                continue;
+            }
+
+            text.push_str(&format!("{} [{}] {}\n",
+                self.operations_table_model.item_2a(row, 0).text().to_std_string(),
+                self.operations_table_model.item_2a(row, 1).text().to_std_string(),
+                self.operations_table_model.item_2a(row, 2).text().to_std_string()
+            ));
+        }
+
+        QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(text));
+    }
+
+    /// This function returns if notifications of the provided level should be shown with the current filter.
+    unsafe fn is_level_visible(&self, level: i32) -> bool {
+        level >= self.level_filter_combobox.current_index()
+    }
+
+    /// This function returns the localisation key for the name of the provided level.
+    fn level_key(level: NotificationLevel) -> &'static str {
+        match level {
+            NotificationLevel::Info => "operations_level_info",
+            NotificationLevel::Warning => "operations_level_warning",
+            NotificationLevel::Error => "operations_level_error",
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code related to the main `OperationsUISlots`.
+!*/
+
+use qt_core::QBox;
+use qt_core::{SlotNoArgs, SlotOfInt};
+
+use std::rc::Rc;
+
+use crate::app_ui::AppUI;
+use crate::communications::Response;
+use crate::diagnostics_ui::DiagnosticsUI;
+use crate::global_search_ui::GlobalSearchUI;
+use crate::packfile_contents_ui::PackFileContentsUI;
+
+use super::OperationsUI;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains all the slots we need to respond to signals of the operations panel.
+pub struct OperationsUISlots {
+    pub poll_notifications: QBox<SlotNoArgs>,
+    pub filter_by_level: QBox<SlotOfInt>,
+    pub copy_all: QBox<SlotNoArgs>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation of `OperationsUISlots`.
+impl OperationsUISlots {
+
+    /// This function creates an entire `OperationsUISlots` struct.
+    pub unsafe fn new(
+        operations_ui: &Rc<OperationsUI>,
+        app_ui: &Rc<AppUI>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        global_search_ui: &Rc<GlobalSearchUI>,
+        diagnostics_ui: &Rc<DiagnosticsUI>,
+    ) -> Self {
+
+        // What happens when the poll timer times out.
+        let poll_notifications = SlotNoArgs::new(&operations_ui.operations_dock_widget, clone!(
+            operations_ui,
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui,
+            diagnostics_ui => move || {
+                let others = operations_ui.poll_notifications();
+
+                // A single write to the Pack can trigger multiple changes, so we only handle the last one.
+                let changed_path = others.into_iter()
+                    .filter_map(|notification| if let Response::PackFileChangedOnDisk(path) = notification { Some(path) } else { None })
+                    .last();
+
+                if let Some(path) = changed_path {
+
+                    // Stop polling while we handle it, so we don't ask again while the user is answering, and ignore the changes done meanwhile.
+                    operations_ui.poll_timer.stop();
+                    AppUI::pack_changed_on_disk(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &path);
+                    operations_ui.poll_notifications();
+                    operations_ui.poll_timer.start_0a();
+                }
+            }
+        ));
+
+        // What happens when we change the level filter.
+        let filter_by_level = SlotOfInt::new(&operations_ui.operations_dock_widget, clone!(
+            operations_ui => move |_| {
+                operations_ui.filter_by_level();
+            }
+        ));
+
+        // What happens when we want to copy the entire log.
+        let copy_all = SlotNoArgs::new(&operations_ui.operations_dock_widget, clone!(
+            operations_ui => move || {
+                operations_ui.copy_all();
+            }
+        ));
+
+        // And here... we return all the slots.
+        Self {
+            poll_notifications,
+            filter_by_level,
+            copy_all,
+        }
+    }
+}
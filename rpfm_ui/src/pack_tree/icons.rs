@@ -19,11 +19,14 @@ use qt_core::QString;
 
 use cpp_core::Ref;
 
+use std::collections::HashMap;
 use std::sync::atomic::AtomicPtr;
 
-use rpfm_lib::files::{animpack, anim_fragment, anims_table, esf, FileType, image, loc, matched_combat, pack, portrait_settings, rigidmodel, text, text::*, unit_variant, video};
+use rpfm_lib::files::{animpack, anim_fragment, anims_table, esf, FileType, image, loc, matched_combat, pack, portrait_settings, rigidmodel, speedtree, text, text::*, unit_variant, video};
 use rpfm_lib::{REGEX_DB, REGEX_PORTRAIT_SETTINGS};
 
+use crate::SUPPORTED_GAMES;
+use crate::locks::read_game_selected;
 use crate::pack_tree::{ROOT_NODE_TYPE_EDITABLE_PACKFILE, ROOT_NODE_TYPE};
 use crate::utils::{atomic_from_cpp_box, ref_from_atomic_ref};
 
@@ -79,8 +82,12 @@ pub struct Icons {
     pub text_css: AtomicPtr<QIcon>,
 
     pub rigid_model: AtomicPtr<QIcon>,
+    pub speedtree: AtomicPtr<QIcon>,
     pub unit_variant: AtomicPtr<QIcon>,
     pub video: AtomicPtr<QIcon>,
+
+    /// Icons used by the game-specific file types of all games, by name.
+    pub game_specific: HashMap<String, AtomicPtr<QIcon>>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -126,8 +133,18 @@ impl Icons {
             text_css: atomic_from_cpp_box(QIcon::from_theme_1a(&QString::from_std_str("text-css"))),
 
             rigid_model: atomic_from_cpp_box(QIcon::from_theme_1a(&QString::from_std_str("application-x-blender"))),
+            speedtree: atomic_from_cpp_box(QIcon::from_theme_1a(&QString::from_std_str("application-x-wavefront-obj"))),
             unit_variant: atomic_from_cpp_box(QIcon::from_theme_1a(&QString::from_std_str("application-vnd.openxmlformats-officedocument.spreadsheetml.sheet"))),
             video: atomic_from_cpp_box(QIcon::from_theme_1a(&QString::from_std_str("video-webm"))),
+
+            game_specific: SUPPORTED_GAMES.games().iter()
+                .flat_map(|game| game.file_type_overrides())
+                .filter_map(|file_type_override| file_type_override.icon().clone())
+                .map(|icon| {
+                    let qicon = atomic_from_cpp_box(QIcon::from_theme_1a(&QString::from_std_str(&icon)));
+                    (icon, qicon)
+                })
+                .collect(),
         }
     }
 
@@ -152,7 +169,11 @@ impl Icons {
                 //--GroupFormations,
                 //--UIC,
 
-                if path.ends_with(pack::EXTENSION) {
+                if let Some(icon) = self.game_specific_icon(&path) {
+                    icon
+                }
+
+                else if path.ends_with(pack::EXTENSION) {
                     &self.packfile_editable
                 }
 
@@ -168,6 +189,10 @@ impl Icons {
                     &self.animpack
                 }
 
+                else if speedtree::EXTENSIONS.iter().any(|x| path.ends_with(x)) {
+                    &self.speedtree
+                }
+
                 else if path.ends_with(video::EXTENSION) {
                     &self.video
                 }
@@ -183,17 +208,7 @@ impl Icons {
                 }
 
                 else if let Some((_, text_type)) = text::EXTENSIONS.iter().find(|(extension, _)| path.ends_with(extension)) {
-                    match text_type {
-                        TextFormat::Html => &self.text_html,
-                        TextFormat::Xml => &self.text_xml,
-                        TextFormat::Lua => &self.text_lua,
-                        TextFormat::Cpp => &self.text_cpp,
-                        TextFormat::Plain => &self.text_txt,
-                        TextFormat::Markdown => &self.text_md,
-                        TextFormat::Json => &self.text_json,
-                        TextFormat::Css => &self.text_css,
-                        TextFormat::Js => &self.text_js,
-                    }
+                    self.text_icon(text_type)
                 }
 
                 else if path.ends_with(unit_variant::EXTENSION) {
@@ -244,6 +259,15 @@ impl Icons {
     }
 
     pub unsafe fn set_standard_item_icon(&self, item: &QStandardItem, file_type: Option<&FileType>) {
+
+        // Game-specific file types take priority over the generic icons.
+        if file_type.is_some() {
+            if let Some(icon) = self.game_specific_icon(&item.text().to_std_string()) {
+                item.set_icon(ref_from_atomic_ref(icon));
+                return;
+            }
+        }
+
          let icon = ref_from_atomic_ref(
             match file_type {
                 Some(file_type) => match file_type {
@@ -259,6 +283,7 @@ impl Icons {
                     FileType::AnimPack => &self.animpack,
                     FileType::AnimsTable => &self.anims_table,
                     FileType::CEO => &self.ceo,
+                    FileType::Custom => &self.file,
                     FileType::DB => &self.db,
                     FileType::ESF => &self.esf,
                     FileType::GroupFormations => &self.file,
@@ -277,22 +302,11 @@ impl Icons {
                     FileType::PortraitSettings => &self.portrait_settings,
                     FileType::RigidModel => &self.rigid_model,
                     FileType::Save => &self.save,
+                    FileType::SpeedTree => &self.speedtree,
                     FileType::Text => {
                         let name = item.text().to_std_string();
                         match text::EXTENSIONS.iter().find(|(extension, _)| name.ends_with(extension)) {
-                            Some((_, text_type)) => {
-                                match text_type {
-                                    TextFormat::Html => &self.text_html,
-                                    TextFormat::Xml => &self.text_xml,
-                                    TextFormat::Lua => &self.text_lua,
-                                    TextFormat::Cpp => &self.text_cpp,
-                                    TextFormat::Plain => &self.text_txt,
-                                    TextFormat::Markdown => &self.text_md,
-                                    TextFormat::Json => &self.text_json,
-                                    TextFormat::Css => &self.text_css,
-                                    TextFormat::Js => &self.text_js,
-                                }
-                            },
+                            Some((_, text_type)) => self.text_icon(text_type),
                             None => &self.text_generic,
                         }
                     },
@@ -307,4 +321,31 @@ impl Icons {
         );
         item.set_icon(icon);
     }
+
+    /// This function returns the icon for the provided text format.
+    fn text_icon(&self, text_format: &TextFormat) -> &AtomicPtr<QIcon> {
+        match text_format {
+            TextFormat::Html => &self.text_html,
+            TextFormat::Xml => &self.text_xml,
+            TextFormat::Lua => &self.text_lua,
+            TextFormat::Cpp => &self.text_cpp,
+            TextFormat::Plain => &self.text_txt,
+            TextFormat::Markdown => &self.text_md,
+            TextFormat::Json => &self.text_json,
+            TextFormat::Css => &self.text_css,
+            TextFormat::Js => &self.text_js,
+        }
+    }
+
+    /// This function returns the icon of the file type rule of the game selected matching the provided path, if any.
+    ///
+    /// Rules without their own icon use the icon of their text format, if they have one.
+    fn game_specific_icon(&self, path: &str) -> Option<&AtomicPtr<QIcon>> {
+        let game = read_game_selected();
+        let file_type_override = game.file_type_override(path)?;
+        match file_type_override.icon() {
+            Some(icon) => self.game_specific.get(icon),
+            None => file_type_override.text_format().as_ref().map(|text_format| self.text_icon(text_format)),
+        }
+    }
 }
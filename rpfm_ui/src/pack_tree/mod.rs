@@ -12,6 +12,7 @@
 This module contains code to make our live easier when dealing with `TreeViews`.
 !*/
 
+use qt_widgets::q_header_view::ResizeMode;
 use qt_widgets::QTreeView;
 
 use qt_gui::QStandardItem;
@@ -65,6 +66,21 @@ const ITEM_IS_FOREVER_MODIFIED: i32 = 22;
 /// This const is the key of the QVariant that holds what kind of Root Node we have. Only in root nodes.
 const ROOT_NODE_TYPE: i32 = 23;
 
+/// This const is the key of the QVariant that holds the name of the label assigned to an item, if any. Used for filtering.
+pub const ITEM_LABEL: i32 = 24;
+
+/// This const is the key of the QVariant that holds the colour of the label assigned to an item, if any. Used by the delegate.
+const ITEM_LABEL_COLOUR: i32 = 25;
+
+/// This const is the key of the QVariant that holds the last modified date of a file, if it has one.
+const ITEM_TIMESTAMP: i32 = 26;
+
+/// This const is the key of the QVariant that holds if the Pack keeps the last modified date of its files. Only in root nodes.
+const ROOT_NODE_HAS_TIMESTAMPS: i32 = 27;
+
+/// Column of the TreeView with the last modified date of the files.
+const COLUMN_MODIFIED: i32 = 1;
+
 /// This const is used to identify an editable PackFile.
 const ROOT_NODE_TYPE_EDITABLE_PACKFILE: i32 = 0;
 
@@ -184,6 +200,11 @@ pub trait PackTree {
     /// - Position 21: Status. 0 is untouched, 1 is added, 2 is modified.
     /// In case you don't realise, those are bitmasks.
     unsafe fn update_treeview(&self, has_filter: bool, operation: TreeViewOperation, source: DataSource);
+
+    /// This function shows or hides the "Modified" column of the TreeView, filling it with the last modified date of each file.
+    ///
+    /// It's only shown if it's enabled in the settings and the Pack keeps the timestamps of its files.
+    unsafe fn update_modified_column(&self, has_filter: bool);
 }
 
 /// This enum has the different possible operations we can do in a `TreeView`.
@@ -221,6 +242,12 @@ pub enum TreeViewOperation {
 
     /// Updates the tooltip of the PackedFiles with the provided info.
     UpdateTooltip(Vec<RFileInfo>),
+
+    /// Set the label of the provided files/folders. Requires the name and colour of the label, or `None` to remove it.
+    UpdateLabels(Vec<(ContainerPath, Option<(String, String)>)>),
+
+    /// Set if the Pack keeps the last modified date of its files.
+    UpdateHasTimestamps(bool),
 }
 
 /// This struct represents the data needed to build a TreeView.
@@ -292,8 +319,7 @@ impl PackTree for QPtr<QTreeView> {
 
         // If we have fileinfo, set the new tooltip for the item.
         if let Some(file_info) = file_info {
-            let tooltip = new_packed_file_tooltip(file_info);
-            row.value_1a(0).set_tool_tip(&QString::from_std_str(tooltip));
+            set_file_item_info(&row.value_1a(0), file_info);
         }
 
         // If there was an item with than name, remove it.
@@ -372,7 +398,8 @@ impl PackTree for QPtr<QTreeView> {
 
                 root_item?
             },
-            DataSource::ExternalFile => return None,
+            DataSource::ExternalFile |
+            DataSource::ComparedPackFile => return None,
         };
         let model_index = model.index_2a(0, 0);
         let filtered_index = filter.map_from_source(&model_index);
@@ -887,6 +914,9 @@ impl PackTree for QPtr<QTreeView> {
             // If we want to build a new TreeView...
             TreeViewOperation::Build(build_data) => {
 
+                // Labels are stored in the settings of the open Pack, so only its tree can have them.
+                let is_open_pack = source == DataSource::PackFile && build_data.editable && build_data.path.is_none() && build_data.data.is_none();
+
                 // Get the root node and the data to fill the rest.
                 let (big_parent, mut packed_files_data) = match source {
 
@@ -916,6 +946,7 @@ impl PackTree for QPtr<QTreeView> {
                         let tooltip = new_pack_file_tooltip(&pack_file_data);
                         big_parent.set_tool_tip(&QString::from_std_str(tooltip));
                         big_parent.set_editable(false);
+                        big_parent.set_data_2a(&QVariant::from_bool(pack_file_data.bitmask().contains(PFHFlags::HAS_INDEX_WITH_TIMESTAMPS)), ROOT_NODE_HAS_TIMESTAMPS);
                         big_parent.set_data_2a(&QVariant::from_int(ITEM_TYPE_PACKFILE), ITEM_TYPE);
                         big_parent.set_data_2a(&QVariant::from_int(ITEM_STATUS_PRISTINE), ITEM_STATUS);
 
@@ -1035,7 +1066,8 @@ impl PackTree for QPtr<QTreeView> {
                         }
                     },
 
-                    DataSource::ExternalFile => unimplemented!()
+                    DataSource::ExternalFile |
+                    DataSource::ComparedPackFile => unimplemented!()
                 };
 
                 // We sort the paths with this horrific monster I don't want to touch ever again, using the following format:
@@ -1073,10 +1105,9 @@ impl PackTree for QPtr<QTreeView> {
 
                         // If it's the last string in the file path, it's a file, so we add it to the model.
                         if index_in_path == count {
-                            let tooltip = new_packed_file_tooltip(packed_file);
                             let file = base_file_item.clone();
                             file.set_text(&name);
-                            file.set_tool_tip(&QString::from_std_str(tooltip));
+                            set_file_item_info(&file, packed_file);
 
                             TREEVIEW_ICONS.set_standard_item_icon(&file, Some(packed_file.file_type()));
 
@@ -1141,6 +1172,21 @@ impl PackTree for QPtr<QTreeView> {
 
                 // Delay adding the big parent as much as we can, as otherwise the signals triggered when adding a file can slow this down to a crawl.
                 model.append_row_q_standard_item(big_parent);
+
+                if is_open_pack {
+                    let receiver = CENTRAL_COMMAND.send_background(Command::GetPathLabels);
+                    let response = CentralCommand::recv(&receiver);
+                    if let Response::VecStringStringHashMapContainerPathString(definitions, labels) = response {
+                        for (path, label) in &labels {
+                            if let Some(item) = item_from_exact_path(path, &model) {
+                                let colour = definitions.iter().find(|(name, _)| name == label).map(|(_, colour)| colour.as_str());
+                                set_item_label(item, Some((label, colour.unwrap_or_default())));
+                            }
+                        }
+                    } else {
+                        panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response);
+                    }
+                }
             },
 
             // If we want to add a file/folder to the `TreeView`...
@@ -1235,8 +1281,7 @@ impl PackTree for QPtr<QTreeView> {
 
                                     if let Some(file_info) = files_info.par_iter().find_first(|x| x.path() == item_type.path_raw()) {
                                         TREEVIEW_ICONS.set_standard_item_icon(&item, Some(file_info.file_type()));
-                                        let tooltip = new_packed_file_tooltip(file_info);
-                                        item.set_tool_tip(&QString::from_std_str(tooltip));
+                                        set_file_item_info(&item, file_info);
                                     }
                                 }
 
@@ -1426,8 +1471,7 @@ impl PackTree for QPtr<QTreeView> {
                         let response = CentralCommand::recv(&receiver);
                         let packed_file_info = if let Response::OptionRFileInfo(data) = response { data } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response); };
                         if let Some(info) = packed_file_info {
-                            let tooltip = new_packed_file_tooltip(&info);
-                            item.set_tool_tip(&QString::from_std_str(tooltip));
+                            set_file_item_info(&item, &info);
                         }
                     }
 
@@ -1511,8 +1555,7 @@ impl PackTree for QPtr<QTreeView> {
                                     let response = CentralCommand::recv(&receiver);
                                     let packed_file_info = if let Response::OptionRFileInfo(data) = response { data } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response); };
                                     if let Some(info) = packed_file_info {
-                                        let tooltip = new_packed_file_tooltip(&info);
-                                        item.set_tool_tip(&QString::from_std_str(tooltip));
+                                        set_file_item_info(&item, &info);
                                     }
                                 }
 
@@ -1564,17 +1607,62 @@ impl PackTree for QPtr<QTreeView> {
             // If we want to get the tooltips of the PackedFiles updated...
             TreeViewOperation::UpdateTooltip(packed_files_info) => {
                 for packed_file_info in packed_files_info {
-                    let tooltip = QString::from_std_str(new_packed_file_tooltip(&packed_file_info));
                     let tree_path_type = ContainerPath::File(packed_file_info.path().to_owned());
                     let item = Self::item_from_path(&tree_path_type, &model);
-                    item.set_tool_tip(&tooltip);
+                    set_file_item_info(&item, &packed_file_info);
+                }
+            },
+
+            // If we want to set or remove the label of some items...
+            TreeViewOperation::UpdateLabels(labels) => {
+                for (path, label) in &labels {
+                    if let Some(item) = item_from_exact_path(path, &model) {
+                        set_item_label(item, label.as_ref().map(|(name, colour)| (name.as_str(), colour.as_str())));
+                    }
+                }
+            },
+
+            // If the Pack starts or stops keeping the last modified date of its files...
+            TreeViewOperation::UpdateHasTimestamps(has_timestamps) => {
+                let item = model.item_1a(0);
+                if !item.is_null() {
+                    item.set_data_2a(&QVariant::from_bool(has_timestamps), ROOT_NODE_HAS_TIMESTAMPS);
                 }
             },
         }
 
+        if source == DataSource::PackFile {
+            self.update_modified_column(has_filter);
+        }
+
         // Re-enable the view.
         self.set_updates_enabled(true);
     }
+
+    unsafe fn update_modified_column(&self, has_filter: bool) {
+        let filter: Option<QPtr<QSortFilterProxyModel>> = if has_filter { Some(self.model().static_downcast()) } else { None };
+        let model: QPtr<QStandardItemModel> = if let Some(ref filter) = filter { filter.source_model().static_downcast() } else { self.model().static_downcast() };
+        let header = self.header();
+
+        // Packs without timestamps don't need the column, so don't even bother filling it.
+        let root = model.item_1a(0);
+        let show = setting_bool("packfile_treeview_show_modified_column") &&
+            !root.is_null() &&
+            root.data_1a(ITEM_TYPE).to_int_0a() == ITEM_TYPE_PACKFILE &&
+            root.data_1a(ROOT_NODE_HAS_TIMESTAMPS).to_bool();
+
+        if show {
+            fill_modified_column(root);
+            header.set_stretch_last_section(false);
+            header.set_section_resize_mode_2a(0, ResizeMode::Stretch);
+            header.set_section_resize_mode_2a(COLUMN_MODIFIED, ResizeMode::ResizeToContents);
+            self.set_column_hidden(COLUMN_MODIFIED, false);
+        } else if model.column_count_0a() > COLUMN_MODIFIED {
+            self.set_column_hidden(COLUMN_MODIFIED, true);
+            header.set_section_resize_mode_2a(0, ResizeMode::Interactive);
+            header.set_stretch_last_section(true);
+        }
+    }
 }
 
 //----------------------------------------------------------------//
@@ -1600,11 +1688,12 @@ pub fn new_pack_file_tooltip(info: &ContainerInfo) -> String {
 
 /// This function is used to create the tooltip for each `PackedFile` item in the PackFile Content's TreeView.
 fn new_packed_file_tooltip(info: &RFileInfo) -> String {
-    let mut string = format!("File Info: \
-        <ul> \
-            <li><b>Original Pack:</b> <i>{}</i></li>",
-        info.packfile_name(),
-    );
+    let mut string = "File Info: <ul>".to_owned();
+
+    // Files loaded from more than one Pack (like when loading CA Packs) show which Pack they come from.
+    if !info.packfile_name().is_empty() {
+        string.push_str(&format!("<li><b>Original Pack:</b> <i>{}</i></li>", info.packfile_name()));
+    }
 
     if let Some(timestamp) = info.timestamp() {
         string.push_str(&format!("<li><b>Last Modified:</b> <i>{}</i></li>", OffsetDateTime::from_unix_timestamp(*timestamp as i64)
@@ -1617,7 +1706,82 @@ fn new_packed_file_tooltip(info: &RFileInfo) -> String {
     string
 }
 
+/// This function sets the tooltip and the last modified date of a file item from its info.
+unsafe fn set_file_item_info(item: &QStandardItem, info: &RFileInfo) {
+    item.set_tool_tip(&QString::from_std_str(new_packed_file_tooltip(info)));
+    match info.timestamp() {
+        Some(timestamp) => item.set_data_2a(&QVariant::from_u64(*timestamp), ITEM_TIMESTAMP),
+        None => item.set_data_2a(&QVariant::new(), ITEM_TIMESTAMP),
+    }
+}
+
+/// This function fills the "Modified" column of the children of the provided item, recursively, with the last modified date of each file.
+unsafe fn fill_modified_column(item: Ptr<QStandardItem>) {
+    for row in 0..item.row_count() {
+        let child = item.child_1a(row);
+        if child.data_1a(ITEM_TYPE).to_int_0a() == ITEM_TYPE_FOLDER {
+            fill_modified_column(child);
+            continue;
+        }
+
+        let timestamp = child.data_1a(ITEM_TIMESTAMP);
+        let date = if timestamp.is_valid() {
+            OffsetDateTime::from_unix_timestamp(timestamp.to_u_long_long_0a() as i64)
+                .ok()
+                .and_then(|date| date.format(&FULL_DATE_FORMAT).ok())
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        let modified_item = item.child_2a(row, COLUMN_MODIFIED);
+        if modified_item.is_null() {
+            let modified_item = QStandardItem::from_q_string(&QString::from_std_str(date));
+            modified_item.set_editable(false);
+            item.set_child_3a(row, COLUMN_MODIFIED, modified_item.into_ptr());
+        } else {
+            modified_item.set_text(&QString::from_std_str(date));
+        }
+    }
+}
+
 /// This function cleans the entire TreeView from colors. To be used when saving.
+/// This function returns the item at the provided path, or `None` if there's no item at that exact path.
+///
+/// Unlike [PackTree::item_from_path], this never returns a parent of the path, and it never returns the Pack item.
+unsafe fn item_from_exact_path(path: &ContainerPath, model: &QPtr<QStandardItemModel>) -> Option<Ptr<QStandardItem>> {
+    let mut item = model.item_1a(0);
+    let path_raw = path.path_raw();
+    if item.is_null() || path_raw.is_empty() {
+        return None;
+    }
+
+    let path_split = path_raw.split('/').collect::<Vec<_>>();
+    for (index, path_element) in path_split.iter().enumerate() {
+        let item_type = if index == path_split.len() - 1 && path.is_file() { ITEM_TYPE_FILE } else { ITEM_TYPE_FOLDER };
+        let path_element = QString::from_std_str(path_element);
+        item = (0..item.row_count())
+            .map(|row| item.child_1a(row))
+            .find(|child| child.data_1a(ITEM_TYPE).to_int_0a() == item_type && child.text().compare_q_string(&path_element) == 0)?;
+    }
+
+    Some(item)
+}
+
+/// This function sets the label of the provided item, as `(name, colour)`. `None` removes its label.
+unsafe fn set_item_label(item: Ptr<QStandardItem>, label: Option<(&str, &str)>) {
+    match label {
+        Some((name, colour)) => {
+            item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(name)), ITEM_LABEL);
+            item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(colour)), ITEM_LABEL_COLOUR);
+        }
+        None => {
+            item.set_data_2a(&QVariant::new(), ITEM_LABEL);
+            item.set_data_2a(&QVariant::new(), ITEM_LABEL_COLOUR);
+        }
+    }
+}
+
 unsafe fn clean_treeview(item: Option<Ptr<QStandardItem>>, model: &QStandardItemModel) {
 
     // Only do it if the model actually have something.
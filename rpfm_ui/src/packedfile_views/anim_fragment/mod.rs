@@ -15,30 +15,26 @@ Module with all the code for managing the view for AnimFragment PackedFiles.
 use getset::Getters;
 use qt_widgets::QSpinBox;
 use qt_widgets::QWidget;
-use qt_widgets::QLineEdit;
 use qt_widgets::QGridLayout;
 use qt_widgets::QLabel;
 
-use qt_gui::QStandardItemModel;
-
 use qt_core::QBox;
 use qt_core::QString;
-use qt_core::QSortFilterProxyModel;
 use qt_core::QPtr;
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 
 use std::rc::Rc;
 use std::sync::{Arc, RwLock};
 
-use rpfm_lib::files::{anim_fragment::AnimFragment, FileType, RFileDecoded, table::*};
-use rpfm_lib::games::supported_games::KEY_WARHAMMER_2;
+use rpfm_lib::files::{anim_fragment::{AnimFragment, VERSION_WARHAMMER_2}, FileType, RFileDecoded};
+use rpfm_lib::games::supported_games::{KEY_WARHAMMER_2, KEY_WARHAMMER_3};
 use rpfm_lib::schema::Definition;
 
 use crate::app_ui::AppUI;
 use crate::dependencies_ui::DependenciesUI;
 use crate::diagnostics_ui::DiagnosticsUI;
-use crate::GAME_SELECTED;
+use crate::locks::read_game_selected;
 use crate::global_search_ui::GlobalSearchUI;
 use crate::packedfile_views::{DataSource, PackedFileView, View, ViewType};
 use crate::packfile_contents_ui::PackFileContentsUI;
@@ -66,6 +62,10 @@ pub struct PackedFileAnimFragmentView {
     integer_1: QBox<QSpinBox>,
     integer_2: QBox<QSpinBox>,
 
+    /// Copy of the fragment as it was loaded, so we can keep the header fields not shown in the view when saving.
+    #[getset(skip)]
+    fragment: Arc<RwLock<AnimFragment>>,
+
     packed_file_path: Arc<RwLock<String>>,
 
     #[getset(skip)]
@@ -97,7 +97,8 @@ impl PackedFileAnimFragmentView {
     ) -> Result<()> {
 
         // For any other game, use the debug view.
-        if GAME_SELECTED.read().unwrap().game_key_name() != KEY_WARHAMMER_2 {
+        let game_key = read_game_selected().game_key_name();
+        if game_key != KEY_WARHAMMER_2 && game_key != KEY_WARHAMMER_3 {
 
             // For now just build a debug view.
             let debug_view = DebugView::new_view(
@@ -117,17 +118,16 @@ impl PackedFileAnimFragmentView {
             Ok(())
         }
 
-        // For Wh2, use the fancy view.
+        // For Wh2 and Wh3, use the fancy view.
         else {
             let layout: QPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast();
 
-            let i1_label = QLabel::from_q_string_q_widget(&QString::from_std_str(data.skeleton_1()), packed_file_view.get_mut_widget());
-            let i2_label = QLabel::from_q_string_q_widget(&QString::from_std_str(data.skeleton_2()), packed_file_view.get_mut_widget());
-
+            let i1_label = QLabel::new_q_widget(packed_file_view.get_mut_widget());
+            let i2_label = QLabel::new_q_widget(packed_file_view.get_mut_widget());
             let integer_1 = QSpinBox::new_1a(packed_file_view.get_mut_widget());
             let integer_2 = QSpinBox::new_1a(packed_file_view.get_mut_widget());
-            integer_1.set_value(*data.min_id());
-            integer_2.set_value(*data.max_id());
+            integer_1.set_range(i32::MIN, i32::MAX);
+            integer_2.set_range(i32::MIN, i32::MAX);
 
             let table = QWidget::new_1a(packed_file_view.get_mut_widget());
             let layout_1 = QGridLayout::new_1a(&table);
@@ -141,7 +141,7 @@ impl PackedFileAnimFragmentView {
 
             layout.add_widget_5a(&table, 0, 2, 2, 1);
 
-            let table_data = data.data()?.get(0).unwrap();
+            let fragment = Arc::new(RwLock::new(data.clone()));
             let table_view = TableView::new_view(
                 &table,
                 app_ui,
@@ -161,6 +161,7 @@ impl PackedFileAnimFragmentView {
                 integer_label_2: i2_label,
                 integer_1,
                 integer_2,
+                fragment,
 
                 packed_file_path: packed_file_view.get_path_raw(),
                 data_source: packed_file_view.data_source.clone(),
@@ -173,6 +174,8 @@ impl PackedFileAnimFragmentView {
                 diagnostics_ui
             );
 
+            packed_file_table_view.load_header(&packed_file_table_view.fragment.read().unwrap());
+
             connections::set_connections(&packed_file_table_view, &packed_file_anim_fragment_view_slots);
             packed_file_view.view = ViewType::Internal(View::AnimFragment(packed_file_table_view));
             packed_file_view.packed_file_type = FileType::AnimFragment;
@@ -184,54 +187,49 @@ impl PackedFileAnimFragmentView {
 
     /// Function to reload the data of the view without having to delete the view itself.
     pub unsafe fn reload_view(&self, data: AnimFragment) -> Result<()> {
-
-        // Update the stored definition.
-        //let definition = data.definition();
-        //*self.definition.write().unwrap() = definition.clone();
-
-        // Load the data to the view itself.
-        self.load_data(&data)
+        self.load_data(&data)?;
+        *self.fragment.write().unwrap() = data;
+        Ok(())
     }
 
     /// This function takes care of loading the data into the AnimFragment View.
     pub unsafe fn load_data(&self, original_data: &AnimFragment) -> Result<()> {
-        self.integer_label_1.set_text(&QString::from_std_str(original_data.skeleton_1()));
-        self.integer_label_2.set_text(&QString::from_std_str(original_data.skeleton_2()));
-
-        self.integer_1.set_value(*original_data.min_id());
-        self.integer_2.set_value(*original_data.max_id());
-
-        // Each table view, we just load them.
-        //if let Some(DecodedData::SequenceU32(data)) = data.get(0) {
-        //    self.table_view_1.reload_view(TableType::AnimFragment(From::from(data.clone())));
-        //}
-
-        //if let Some(DecodedData::SequenceU32(data)) = data.get(3) {
-        //    self.table_view_2.reload_view(TableType::AnimFragment(From::from(data.clone())));
-        //}
-
+        self.load_header(original_data);
+        self.table_view.reload_view(TableType::AnimFragment(original_data.clone()));
         Ok(())
     }
 
+    /// This function loads the header of the provided AnimFragment into the view.
+    ///
+    /// Only Wh2 fragments have editable ids. For Wh3 ones we just show their name and skeleton.
+    unsafe fn load_header(&self, data: &AnimFragment) {
+        if *data.definition().version() == VERSION_WARHAMMER_2 {
+            self.integer_label_1.set_text(&QString::from_std_str(data.skeleton_1()));
+            self.integer_label_2.set_text(&QString::from_std_str(data.skeleton_2()));
+            self.integer_1.set_value(*data.min_id());
+            self.integer_2.set_value(*data.max_id());
+            self.integer_1.set_visible(true);
+            self.integer_2.set_visible(true);
+        } else {
+            self.integer_label_1.set_text(&QString::from_std_str(data.name()));
+            self.integer_label_2.set_text(&QString::from_std_str(data.skeleton_name()));
+            self.integer_1.set_visible(false);
+            self.integer_2.set_visible(false);
+        }
+    }
+
     /// This function takes care of building a RFileDecoded from the view's data.
     pub unsafe fn save_data(&self) -> Result<RFileDecoded> {
-        let mut table = AnimFragment::new(&self.definition());
-        let mut data = vec![];
-        let i1 = DecodedData::I32(self.integer_1.text().to_std_string().parse::<i32>()?);
-        let i2 = DecodedData::I32(self.integer_2.text().to_std_string().parse::<i32>()?);
-
-        let filter: QPtr<QSortFilterProxyModel> = self.table_view.table_view_ptr().model().static_downcast();
-        let table_model: QPtr<QStandardItemModel> = filter.source_model().static_downcast();
-        let data_1 = get_table_from_view(&table_model, &self.table_view.table_definition())?;
-
-        //data.push(DecodedData::SequenceU32(Box::new(data_1)));
-        data.push(i1);
-        data.push(i2);
-        //data.push(DecodedData::SequenceU32(Box::new(data_2)));
-
-        let data = vec![data; 1];
-        //table.set_table_data(&data)?;
-        Ok(RFileDecoded::AnimFragment(table))
+        let mut fragment = self.fragment.read().unwrap().clone();
+        if *fragment.definition().version() == VERSION_WARHAMMER_2 {
+            fragment.set_min_id(self.integer_1.value());
+            fragment.set_max_id(self.integer_2.value());
+        }
+
+        let table = get_table_from_view(&self.table_view.table_model_ptr().static_upcast(), &self.table_view.table_definition())?;
+        fragment.set_table(table);
+
+        Ok(RFileDecoded::AnimFragment(fragment))
     }
 
     /// This function returns a copy of the definition of this AnimFragment.
@@ -65,6 +65,7 @@ pub unsafe fn set_connections(ui: &PackedFileDecoderView, slots: &PackedFileDeco
     ui.table_view_old_versions_context_menu_delete().triggered().connect(&slots.table_view_old_versions_context_menu_delete);
 
     ui.import_from_assembly_kit_button().released().connect(&slots.import_from_assembly_kit);
+    ui.guess_definition_button().released().connect(&slots.guess_definition);
     ui.test_definition_button().released().connect(&slots.test_definition);
     ui.clear_definition_button().released().connect(&slots.remove_all_fields);
     ui.save_button().released().connect(&slots.save_definition);
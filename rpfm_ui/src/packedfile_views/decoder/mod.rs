@@ -12,6 +12,7 @@
 
 use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionMode};
 use qt_widgets::q_header_view::ResizeMode;
+use qt_widgets::QDialog;
 use qt_widgets::QFrame;
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
@@ -70,10 +71,9 @@ use crate::CENTRAL_COMMAND;
 use crate::communications::*;
 use crate::ffi::{new_combobox_item_delegate_safe, new_spinbox_item_delegate_safe, new_qstring_item_delegate_safe};
 use crate::FONT_MONOSPACE;
-use crate::GAME_SELECTED;
+use crate::locks::{read_game_selected, read_schema};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::{PackedFileView, View, ViewType};
-use crate::SCHEMA;
 use crate::setting_bool;
 use crate::utils::*;
 
@@ -150,6 +150,7 @@ pub struct PackedFileDecoderView {
     table_view_old_versions_context_menu_delete: QPtr<QAction>,
 
     import_from_assembly_kit_button: QBox<QPushButton>,
+    guess_definition_button: QBox<QPushButton>,
     test_definition_button: QBox<QPushButton>,
     clear_definition_button: QBox<QPushButton>,
     save_button: QBox<QPushButton>,
@@ -405,15 +406,17 @@ impl PackedFileDecoderView {
 
         // Create the bottom Buttons.
         let import_from_assembly_kit_button = QPushButton::from_q_string_q_widget(&QString::from_std_str("Import from Assembly Kit"), &button_box);
+        let guess_definition_button = QPushButton::from_q_string_q_widget(&QString::from_std_str("Guess Definition"), &button_box);
         let test_definition_button = QPushButton::from_q_string_q_widget(&QString::from_std_str("Test Definition"), &button_box);
         let clear_definition_button = QPushButton::from_q_string_q_widget(&QString::from_std_str("Remove all fields"), &button_box);
         let save_button = QPushButton::from_q_string_q_widget(&QString::from_std_str("Finish it!"), &button_box);
 
         // Add them to the Dialog.
         button_box_layout.add_widget_5a(&import_from_assembly_kit_button, 0, 0, 1, 1);
-        button_box_layout.add_widget_5a(&test_definition_button, 0, 1, 1, 1);
-        button_box_layout.add_widget_5a(&clear_definition_button, 0, 2, 1, 1);
-        button_box_layout.add_widget_5a(&save_button, 0, 3, 1, 1);
+        button_box_layout.add_widget_5a(&guess_definition_button, 0, 1, 1, 1);
+        button_box_layout.add_widget_5a(&test_definition_button, 0, 2, 1, 1);
+        button_box_layout.add_widget_5a(&clear_definition_button, 0, 3, 1, 1);
+        button_box_layout.add_widget_5a(&save_button, 0, 4, 1, 1);
 
         layout.add_widget_5a(&button_box, 4, 1, 1, 2);
 
@@ -481,6 +484,7 @@ impl PackedFileDecoderView {
             table_view_old_versions_context_menu_delete,
 
             import_from_assembly_kit_button,
+            guess_definition_button,
             test_definition_button,
             clear_definition_button,
             save_button,
@@ -1182,7 +1186,7 @@ impl PackedFileDecoderView {
     /// This function is used to update the list of "Versions" of the currently open table decoded.
     unsafe fn load_versions_list(&self) {
         self.table_model_old_versions.clear();
-        if let Some(ref schema) = *SCHEMA.read().unwrap() {
+        if let Some(ref schema) = *read_schema() {
             if let Some(definitions) = schema.definitions_by_table_name(&self.table_name) {
                 definitions.iter().for_each(|definition| {
                     let item = QStandardItem::from_q_string(&QString::from_std_str(&definition.version().to_string()));
@@ -1313,7 +1317,7 @@ impl PackedFileDecoderView {
 
     /// This function adds the definition currently in the view to a temporal schema, and returns it.
     unsafe fn add_definition_to_schema(&self) -> Schema {
-        let mut schema = SCHEMA.read().unwrap().clone().unwrap();
+        let mut schema = read_schema().clone().unwrap();
         let mut definition = Definition::new(self.version);
         *definition.fields_mut() = self.get_fields_from_view(None);
         schema.add_definition(&self.table_name, &definition);
@@ -1331,7 +1335,7 @@ impl PackedFileDecoderView {
     pub fn import_from_assembly_kit(&self) -> Result<Vec<Vec<Field>>> {
 
         // Get the raw data ready.
-        let game = GAME_SELECTED.read().unwrap();
+        let game = read_game_selected();
         let raw_db_version = game.raw_db_version();
         let raw_db_path = assembly_kit_path()?;
 
@@ -1627,9 +1631,80 @@ impl PackedFileDecoderView {
         }).collect::<Vec<Vec<Field>>>())*/
     }
 
+    /// This function creates the "Guess Definition" dialog, listing the definitions that can decode the table with a preview
+    /// of the first rows decoded with each one of them. It returns the fields of the definition accepted, or None.
+    pub unsafe fn create_guess_definition_dialog(&self) -> Result<Option<Vec<Field>>> {
+        let receiver = CENTRAL_COMMAND.send_background(Command::GuessTableDefinition(self.packed_file_path.to_owned()));
+        let response = CentralCommand::recv(&receiver);
+        let guesses = match response {
+            Response::VecGuessedDefinition(guesses) => guesses,
+            Response::Error(error) => return Err(error),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        if guesses.is_empty() {
+            return Err(anyhow!("No valid definitions found."));
+        }
+
+        // Create and configure the dialog.
+        let dialog = QDialog::new_1a(&self.table_view);
+        dialog.set_window_title(&QString::from_std_str("Guessed Definitions"));
+        dialog.set_modal(true);
+        dialog.resize_2a(800, 500);
+        let main_grid = create_grid_layout(dialog.static_upcast());
+
+        let instructions_label = QLabel::from_q_string_q_widget(&QString::from_std_str("These are the definitions that can decode the entire table, from most to least likely. Expand one to see the first rows decoded with it."), &dialog);
+        instructions_label.set_word_wrap(true);
+
+        // Each candidate goes in its own row, with the preview rows as its children.
+        let candidates_view = QTreeView::new_1a(&dialog);
+        let candidates_model = QStandardItemModel::new_1a(&candidates_view);
+        candidates_view.set_model(&candidates_model);
+        candidates_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        candidates_view.set_selection_mode(SelectionMode::SingleSelection);
+
+        for (index, guess) in guesses.iter().enumerate() {
+            let field_types = guess.definition().fields().iter().map(|field| field.field_type().to_string()).collect::<Vec<_>>().join(", ");
+            let candidate = QStandardItem::from_q_string(&QString::from_std_str(format!("{}. {} fields: {}", index + 1, guess.definition().fields().len(), field_types)));
+            for row in guess.preview() {
+                let row = row.iter().map(|data| data.data_to_string()).collect::<Vec<_>>().join(" | ");
+                candidate.append_row_q_standard_item(QStandardItem::from_q_string(&QString::from_std_str(row)).into_ptr());
+            }
+
+            candidates_model.append_row_q_standard_item(candidate.into_ptr());
+        }
+
+        candidates_model.set_header_data_3a(0, Orientation::Horizontal, &QVariant::from_q_string(&QString::from_std_str("Candidates")));
+        candidates_view.set_current_index(&candidates_model.index_2a(0, 0));
+        candidates_view.expand(&candidates_model.index_2a(0, 0));
+
+        let accept_button = QPushButton::from_q_string_q_widget(&QString::from_std_str("Use this"), &dialog);
+
+        main_grid.add_widget_5a(&instructions_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&candidates_view, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 2, 0, 1, 1);
+
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 {
+
+            // If a preview row is selected, use the candidate it belongs to.
+            let mut index = candidates_view.current_index();
+            if index.parent().is_valid() {
+                index = index.parent();
+            }
+
+            if index.is_valid() {
+                return Ok(guesses.get(index.row() as usize).map(|guess| guess.definition().fields().to_vec()));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// This function returns the definition corresponding to the decoded Packedfile, if exists.
     fn definition(&self) -> Option<Definition> {
-        if let Some(ref schema) = *SCHEMA.read().unwrap() {
+        if let Some(ref schema) = *read_schema() {
             return schema.definition_by_name_and_version(&self.table_name, self.version).cloned();
         }
         None
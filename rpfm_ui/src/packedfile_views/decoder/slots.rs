@@ -34,7 +34,7 @@ use crate::CENTRAL_COMMAND;
 use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::packedfile_views::DataSource;
 use crate::packfile_contents_ui::PackFileContentsUI;
-use crate::SCHEMA;
+use crate::locks::write_schema;
 use crate::UI_STATE;
 use crate::utils::*;
 
@@ -85,6 +85,7 @@ pub struct PackedFileDecoderViewSlots {
     pub table_view_old_versions_context_menu_delete: QBox<SlotOfBool>,
 
     pub import_from_assembly_kit: QBox<SlotNoArgs>,
+    pub guess_definition: QBox<SlotNoArgs>,
     pub test_definition: QBox<SlotNoArgs>,
     pub remove_all_fields: QBox<SlotNoArgs>,
     pub save_definition: QBox<SlotNoArgs>,
@@ -481,7 +482,7 @@ impl PackedFileDecoderViewSlots {
                     let model_index = indexes.at(0);
                     let version = view.table_model_old_versions.item_from_index(model_index).text().to_std_string().parse::<i32>().unwrap();
 
-                    if let Some(ref mut schema) = *SCHEMA.write().unwrap() {
+                    if let Some(ref mut schema) = *write_schema() {
                         schema.remove_definition(view.table_name(), version);
                     }
 
@@ -517,6 +518,22 @@ impl PackedFileDecoderViewSlots {
             }
         ));
 
+        // Slot for the "Guess Definition" button.
+        let guess_definition = SlotNoArgs::new(&view.table_view, clone!(
+            mut view => move || {
+                match view.create_guess_definition_dialog() {
+                    Ok(Some(fields)) => {
+                        view.table_model.clear();
+                        let _ = view.data.write().unwrap().seek(SeekFrom::Start(view.header_size));
+                        let _ = view.update_view(&fields, true);
+                        let _ = view.update_rows_decoded(None, None);
+                    }
+                    Ok(None) => {}
+                    Err(error) => show_dialog(&view.table_view, error, false),
+                }
+            }
+        ));
+
         // Slot for the "Test Definition" button.
         let test_definition = SlotNoArgs::new(&view.table_view, clone!(
             app_ui,
@@ -632,6 +649,7 @@ impl PackedFileDecoderViewSlots {
             table_view_old_versions_context_menu_delete,
 
             import_from_assembly_kit,
+            guess_definition,
             test_definition,
             remove_all_fields,
             save_definition,
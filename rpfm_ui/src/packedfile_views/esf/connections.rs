@@ -32,4 +32,12 @@ pub unsafe fn set_connections(ui: &Arc<PackedFileESFView>, slots: &PackedFileESF
     ui.filter_line_edit.text_changed().connect(&slots.filter_check_regex);
 
      ui.tree_view.selection_model().selection_changed().connect(&slots.open_node);
+
+    ui.search_line_edit.return_pressed().connect(&slots.search);
+    ui.search_button.released().connect(&slots.search);
+    ui.search_results_list_view.clicked().connect(&slots.open_search_result);
+
+    ui.bookmarks_combobox.activated().connect(&slots.open_bookmark);
+    ui.bookmark_add_button.released().connect(&slots.add_bookmark);
+    ui.bookmark_remove_button.released().connect(&slots.remove_bookmark);
 }
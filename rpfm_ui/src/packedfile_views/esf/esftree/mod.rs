@@ -15,6 +15,7 @@ It's similar to the PackTree, but modified for the requirements of the ESF files
 !*/
 
 use qt_widgets::QTreeView;
+use qt_widgets::q_abstract_item_view::ScrollHint;
 use qt_widgets::q_header_view::ResizeMode;
 
 use qt_gui::QStandardItem;
@@ -22,6 +23,7 @@ use qt_gui::QStandardItemModel;
 use qt_gui::QListOfQStandardItem;
 
 use qt_core::QModelIndex;
+use qt_core::q_item_selection_model::SelectionFlag;
 use qt_core::QVariant;
 use qt_core::QBox;
 use qt_core::ItemFlag;
@@ -34,7 +36,7 @@ use cpp_core::CppBox;
 use cpp_core::Ptr;
 use cpp_core::Ref;
 
-use rpfm_lib::files::esf::{ESF, NodeType, RecordNodeFlags};
+use rpfm_lib::files::esf::{ESF, ESFNodePath, NodeType, RecordNodeFlags};
 
 const ESF_DATA: i32 = 40;
 const CHILDLESS_NODE: i32 = 41;
@@ -69,6 +71,17 @@ pub(crate) trait ESFTree {
     /// This function gives you the item corresponding to an specific path.
     unsafe fn get_item_from_path(path: &[String], model: &QPtr<QStandardItemModel>) -> Ptr<QStandardItem>;
 
+    /// This function gives you the node path of a specific Item in a StandardItemModel.
+    unsafe fn get_node_path_from_item(item: Ptr<QStandardItem>, model: &QPtr<QStandardItemModel>) -> ESFNodePath;
+
+    /// This function gives you the item corresponding to an specific node path, if it exists.
+    unsafe fn get_item_from_node_path(path: &ESFNodePath, model: &QPtr<QStandardItemModel>) -> Option<Ptr<QStandardItem>>;
+
+    /// This function selects the item corresponding to the provided node path, expanding only its parents and scrolling to it.
+    ///
+    /// Returns false if the item doesn't exist, or if it's hidden by the filter.
+    unsafe fn select_node_path(&self, path: &ESFNodePath) -> bool;
+
     /// This function takes care of EVERY operation that manipulates the provided TreeView.
     /// It does one thing or another, depending on the operation we provide it.
     unsafe fn update_treeview(&self, has_filter: bool, operation: ESFTreeViewOperation);
@@ -183,6 +196,64 @@ impl ESFTree for QBox<QTreeView> {
         item
     }
 
+    unsafe fn get_node_path_from_item(item: Ptr<QStandardItem>, model: &QPtr<QStandardItemModel>) -> ESFNodePath {
+        let mut indexes = vec![];
+        let mut index = item.index();
+        while index.parent().is_valid() {
+            indexes.push(index.row() as usize);
+            index = index.parent();
+        }
+
+        indexes.reverse();
+
+        let mut names = Self::get_path_from_item(item, model);
+        names.insert(0, model.item_1a(0).data_1a(RECORD_NODE_NAME).to_string().to_std_string());
+        ESFNodePath::new(indexes, names)
+    }
+
+    unsafe fn get_item_from_node_path(path: &ESFNodePath, model: &QPtr<QStandardItemModel>) -> Option<Ptr<QStandardItem>> {
+        let mut item = model.item_1a(0);
+        if item.is_null() {
+            return None;
+        }
+
+        for index in path.indexes() {
+            if *index >= item.row_count() as usize {
+                return None;
+            }
+
+            item = item.child_1a(*index as i32);
+        }
+
+        Some(item)
+    }
+
+    unsafe fn select_node_path(&self, path: &ESFNodePath) -> bool {
+        let filter: QPtr<QSortFilterProxyModel> = self.model().static_downcast();
+        let model: QPtr<QStandardItemModel> = filter.source_model().static_downcast();
+
+        let item = match Self::get_item_from_node_path(path, &model) {
+            Some(item) => item,
+            None => return false,
+        };
+
+        let index = filter.map_from_source(&item.index());
+        if !index.is_valid() {
+            return false;
+        }
+
+        // Only expand the parents of the item, as expanding big trees is slow.
+        let mut parent = index.parent();
+        while parent.is_valid() {
+            self.expand(&parent);
+            parent = parent.parent();
+        }
+
+        self.selection_model().select_q_model_index_q_flags_selection_flag(&index, QFlags::from(SelectionFlag::ClearAndSelect));
+        self.scroll_to_2a(&index, ScrollHint::PositionAtCenter);
+        true
+    }
+
     unsafe fn update_treeview(&self, has_filter: bool, operation: ESFTreeViewOperation) {
         let filter: Option<QPtr<QSortFilterProxyModel>> = if has_filter { Some(self.model().static_downcast()) } else { None };
         let model: QPtr<QStandardItemModel> = if let Some(ref filter) = filter { filter.source_model().static_downcast() } else { self.model().static_downcast() };
@@ -269,11 +340,7 @@ unsafe fn load_node_to_view(parent: &CppBox<QStandardItem>, child: &NodeType, bl
         // Prepare the data in a way or another, depending if we have nested blocks or not.
         if node.record_flags().contains(RecordNodeFlags::HAS_NESTED_BLOCKS) {
             for (index, node_group) in node.children().iter().enumerate() {
-                let node_group_name = if node_group.len() == 2 {
-                    if let NodeType::Ascii(ref key) = node_group[0] {
-                        key.to_owned()
-                    } else { format!("{}_{}", node.name(), index) }
-                } else { format!("{}_{}", node.name(), index) };
+                let node_group_name = node.block_name(index);
                 let node_group_item = QStandardItem::from_q_string(&QString::from_std_str(&node_group_name));
                 let node_group_state_item = QStandardItem::new();
                 node_group_item.set_editable(false);
@@ -12,22 +12,27 @@
 Module with all the code for managing the ESF Views.
 !*/
 
-use qt_widgets::q_abstract_item_view::SelectionMode;
+use qt_widgets::q_abstract_item_view::{EditTrigger, SelectionMode};
+use qt_widgets::QComboBox;
 use qt_widgets::QLineEdit;
+use qt_widgets::QListView;
 use qt_widgets::QPushButton;
 use qt_widgets::QGridLayout;
 use qt_widgets::QSplitter;
 use qt_widgets::QTreeView;
 use qt_widgets::QWidget;
 
+use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 
 use qt_core::CaseSensitivity;
 use qt_core::ContextMenuPolicy;
 use qt_core::QBox;
+use qt_core::QFlags;
 use qt_core::QPtr;
 use qt_core::QRegExp;
 use qt_core::QSortFilterProxyModel;
+use qt_core::QString;
 use qt_core::QTimer;
 
 use std::rc::Rc;
@@ -35,9 +40,11 @@ use std::sync::{Arc, RwLock};
 
 use anyhow::Result;
 
-use rpfm_lib::files::{esf::ESF, FileType};
+use rpfm_lib::files::{esf::{ESF, ESFNodePath}, FileType};
 
 use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::dependencies_ui::DependenciesUI;
 use crate::diagnostics_ui::DiagnosticsUI;
 use crate::ffi::*;
@@ -48,7 +55,8 @@ use crate::packedfile_views::esf::slots::PackedFileESFViewSlots;
 use crate::packedfile_views::PackedFileView;
 use crate::packedfile_views::PackFileContentsUI;
 use crate::references_ui::ReferencesUI;
-use crate::utils::create_grid_layout;
+use crate::UI_STATE;
+use crate::utils::{create_grid_layout, show_dialog};
 
 use self::esf_detailed_view::ESFDetailedView;
 
@@ -74,11 +82,22 @@ pub struct PackedFileESFView {
     filter_case_sensitive_button: QBox<QPushButton>,
     filter_timer_delayed_updates: QBox<QTimer>,
 
+    search_line_edit: QBox<QLineEdit>,
+    search_button: QBox<QPushButton>,
+    search_results_list_view: QBox<QListView>,
+    search_results_model: QBox<QStandardItemModel>,
+    search_results: Arc<RwLock<Vec<ESFNodePath>>>,
+
+    bookmarks_combobox: QBox<QComboBox>,
+    bookmark_add_button: QBox<QPushButton>,
+    bookmark_remove_button: QBox<QPushButton>,
+    bookmarks: Arc<RwLock<Vec<ESFNodePath>>>,
+
     node_data_panel: QBox<QWidget>,
 
     detailed_view: Arc<RwLock<ESFDetailedView>>,
 
-    _path: Arc<RwLock<String>>,
+    path: Arc<RwLock<String>>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -138,6 +157,29 @@ impl PackedFileESFView {
         tree_layout.add_widget_5a(&filter_autoexpand_matches_button, 2, 0, 1, 1);
         tree_layout.add_widget_5a(&filter_case_sensitive_button, 2, 1, 1, 1);
 
+        // Create the widgets to search nodes by name or value, and to bookmark them.
+        let search_line_edit = QLineEdit::from_q_widget(&tree_panel);
+        let search_button = QPushButton::from_q_string_q_widget(&qtr("esf_search"), &tree_panel);
+        let search_results_list_view = QListView::new_1a(&tree_panel);
+        let search_results_model = QStandardItemModel::new_1a(&search_results_list_view);
+        search_line_edit.set_placeholder_text(&qtr("esf_search_placeholder"));
+        search_line_edit.set_clear_button_enabled(true);
+        search_results_list_view.set_model(&search_results_model);
+        search_results_list_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        search_results_list_view.set_maximum_height(150);
+
+        let bookmarks_combobox = QComboBox::new_1a(&tree_panel);
+        let bookmark_add_button = QPushButton::from_q_string_q_widget(&qtr("esf_bookmark_add"), &tree_panel);
+        let bookmark_remove_button = QPushButton::from_q_string_q_widget(&qtr("esf_bookmark_remove"), &tree_panel);
+        bookmarks_combobox.set_tool_tip(&qtr("esf_bookmarks_tooltip"));
+
+        tree_layout.add_widget_5a(&search_line_edit, 3, 0, 1, 1);
+        tree_layout.add_widget_5a(&search_button, 3, 1, 1, 1);
+        tree_layout.add_widget_5a(&search_results_list_view, 4, 0, 1, 2);
+        tree_layout.add_widget_5a(&bookmarks_combobox, 5, 0, 1, 2);
+        tree_layout.add_widget_5a(&bookmark_add_button, 6, 0, 1, 1);
+        tree_layout.add_widget_5a(&bookmark_remove_button, 6, 1, 1, 1);
+
         let node_data_panel = QWidget::new_1a(&splitter);
         let node_data_layout = create_grid_layout(node_data_panel.static_upcast());
         node_data_layout.set_row_stretch(1000, 100);
@@ -157,15 +199,36 @@ impl PackedFileESFView {
             filter_case_sensitive_button,
             filter_timer_delayed_updates,
 
+            search_line_edit,
+            search_button,
+            search_results_list_view,
+            search_results_model,
+            search_results: Arc::new(RwLock::new(vec![])),
+
+            bookmarks_combobox,
+            bookmark_add_button,
+            bookmark_remove_button,
+            bookmarks: Arc::new(RwLock::new(vec![])),
+
             node_data_panel,
 
             detailed_view: Arc::new(RwLock::new(ESFDetailedView::default())),
 
-            _path: packed_file_view.get_path_raw()
+            path: packed_file_view.get_path_raw()
         });
 
         view.tree_view.update_treeview(true, ESFTreeViewOperation::Build(data));
 
+        // Load the bookmarks of this file from the Pack Settings.
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetEsfBookmarks(view.path.read().unwrap().to_owned()));
+        let response = CentralCommand::recv(&receiver);
+        match response {
+            Response::VecESFNodePath(bookmarks) => *view.bookmarks.write().unwrap() = bookmarks,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        view.load_bookmarks();
+
         let slots = PackedFileESFViewSlots::new(
             &view,
             app_ui,
@@ -222,4 +285,113 @@ impl PackedFileESFView {
         view.filter_timer_delayed_updates.set_interval(500);
         view.filter_timer_delayed_updates.start_0a();
     }
+
+    /// This function searches the nodes of the file matching the text in the search bar, and loads them into the results list.
+    ///
+    /// The search is done over the file as it's in the backend, so unsaved edits in the view are not taken into account.
+    pub unsafe fn search(view: &Arc<Self>) {
+        view.search_results_model.clear();
+        view.search_results.write().unwrap().clear();
+
+        let query = view.search_line_edit.text().to_std_string();
+        if query.is_empty() {
+            return;
+        }
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::SearchEsfNodes(view.path.read().unwrap().to_owned(), query));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::VecESFNodePath(matches) => {
+                if matches.is_empty() {
+                    let item = QStandardItem::from_q_string(&qtr("esf_search_no_matches"));
+                    item.set_enabled(false);
+                    view.search_results_model.append_row_q_standard_item(item.into_ptr());
+                }
+
+                for node_path in &matches {
+                    let item = QStandardItem::from_q_string(&QString::from_std_str(node_path.to_string()));
+                    view.search_results_model.append_row_q_standard_item(item.into_ptr());
+                }
+
+                *view.search_results.write().unwrap() = matches;
+            }
+            Response::Error(error) => show_dialog(&view.tree_view, error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function selects the node of the provided path in the TreeView, clearing the filter if it hides the node.
+    pub unsafe fn go_to_node_path(view: &Arc<Self>, node_path: &ESFNodePath) {
+        if !view.tree_view.select_node_path(node_path) && !view.filter_line_edit.text().is_empty() {
+            view.filter_line_edit.clear();
+            Self::filter_files(view);
+            view.tree_view.select_node_path(node_path);
+        }
+    }
+
+    /// This function opens the search result at the provided row.
+    pub unsafe fn open_search_result(view: &Arc<Self>, row: i32) {
+        let node_path = view.search_results.read().unwrap().get(row as usize).cloned();
+        if let Some(node_path) = node_path {
+            Self::go_to_node_path(view, &node_path);
+        }
+    }
+
+    /// This function opens the bookmark at the provided index of the bookmarks combo.
+    pub unsafe fn open_bookmark(view: &Arc<Self>, index: i32) {
+        let node_path = view.bookmarks.read().unwrap().get(index as usize).cloned();
+        if let Some(node_path) = node_path {
+            Self::go_to_node_path(view, &node_path);
+        }
+    }
+
+    /// This function bookmarks the node currently selected in the TreeView.
+    pub unsafe fn add_bookmark(view: &Arc<Self>, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
+        let items = view.tree_view.get_items_from_selection(true);
+        if items.len() != 1 {
+            return;
+        }
+
+        let model: QPtr<QStandardItemModel> = view.tree_filter.source_model().static_downcast();
+        let node_path = <QBox<QTreeView> as ESFTree>::get_node_path_from_item(items[0], &model);
+        if view.bookmarks.read().unwrap().contains(&node_path) {
+            return;
+        }
+
+        view.bookmarks.write().unwrap().push(node_path);
+        view.save_bookmarks(app_ui, pack_file_contents_ui);
+        view.bookmarks_combobox.set_current_index(view.bookmarks_combobox.count() - 1);
+    }
+
+    /// This function removes the bookmark currently selected in the bookmarks combo.
+    pub unsafe fn remove_bookmark(view: &Arc<Self>, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
+        let index = view.bookmarks_combobox.current_index();
+        if index < 0 || index as usize >= view.bookmarks.read().unwrap().len() {
+            return;
+        }
+
+        view.bookmarks.write().unwrap().remove(index as usize);
+        view.save_bookmarks(app_ui, pack_file_contents_ui);
+    }
+
+    /// This function saves the bookmarks of this view to the Pack Settings, and reloads them into the bookmarks combo.
+    unsafe fn save_bookmarks(&self, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
+        let bookmarks = self.bookmarks.read().unwrap().to_vec();
+        let _ = CENTRAL_COMMAND.send_background(Command::SetEsfBookmarks(self.path.read().unwrap().to_owned(), bookmarks));
+        UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+
+        self.load_bookmarks();
+    }
+
+    /// This function loads the bookmarks of this view into the bookmarks combo.
+    unsafe fn load_bookmarks(&self) {
+        self.bookmarks_combobox.block_signals(true);
+        self.bookmarks_combobox.clear();
+        for bookmark in self.bookmarks.read().unwrap().iter() {
+            self.bookmarks_combobox.add_item_q_string(&QString::from_std_str(bookmark.to_string()));
+        }
+        self.bookmarks_combobox.block_signals(false);
+
+        self.bookmark_remove_button.set_enabled(self.bookmarks_combobox.count() > 0);
+    }
 }
@@ -16,6 +16,8 @@ use qt_widgets::QTreeView;
 
 use qt_core::QBox;
 use qt_core::SlotNoArgs;
+use qt_core::SlotOfInt;
+use qt_core::SlotOfQModelIndex;
 use qt_core::SlotOfQString;
 use qt_core::SlotOfBool;
 
@@ -46,6 +48,12 @@ pub struct PackedFileESFViewSlots {
     pub filter_check_regex: QBox<SlotOfQString>,
 
     pub open_node: QBox<SlotNoArgs>,
+
+    pub search: QBox<SlotNoArgs>,
+    pub open_search_result: QBox<SlotOfQModelIndex>,
+    pub open_bookmark: QBox<SlotOfInt>,
+    pub add_bookmark: QBox<SlotNoArgs>,
+    pub remove_bookmark: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -118,6 +126,42 @@ impl PackedFileESFViewSlots {
             }
         ));
 
+        // Slots for searching nodes and going to them.
+        let search = SlotNoArgs::new(&view.tree_view, clone!(
+            view => move || {
+                PackedFileESFView::search(&view);
+            }
+        ));
+
+        let open_search_result = SlotOfQModelIndex::new(&view.tree_view, clone!(
+            view => move |index| {
+                PackedFileESFView::open_search_result(&view, index.row());
+            }
+        ));
+
+        // Slots for managing the bookmarks of the file.
+        let open_bookmark = SlotOfInt::new(&view.tree_view, clone!(
+            view => move |index| {
+                PackedFileESFView::open_bookmark(&view, index);
+            }
+        ));
+
+        let add_bookmark = SlotNoArgs::new(&view.tree_view, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            view => move || {
+                PackedFileESFView::add_bookmark(&view, &app_ui, &pack_file_contents_ui);
+            }
+        ));
+
+        let remove_bookmark = SlotNoArgs::new(&view.tree_view, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            view => move || {
+                PackedFileESFView::remove_bookmark(&view, &app_ui, &pack_file_contents_ui);
+            }
+        ));
+
         // Return the slots, so we can keep them alive for the duration of the view.
         Self {
             filter_trigger,
@@ -127,6 +171,12 @@ impl PackedFileESFViewSlots {
             filter_check_regex,
 
             open_node,
+
+            search,
+            open_search_result,
+            open_bookmark,
+            add_bookmark,
+            remove_bookmark,
         }
     }
 }
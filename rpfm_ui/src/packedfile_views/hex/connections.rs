@@ -0,0 +1,33 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `PackedFileHexView` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `PackedFileHexView` and `PackedFileHexViewSlots` structs.
+!*/
+
+use std::sync::Arc;
+
+use super::{PackedFileHexView, slots::PackedFileHexViewSlots};
+
+/// This function connects all the actions from the provided `PackedFileHexView` with their slots in `PackedFileHexViewSlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &Arc<PackedFileHexView>, slots: &PackedFileHexViewSlots) {
+    ui.hex_view().vertical_scroll_bar().value_changed().connect(&slots.load_more);
+
+    ui.goto_button().released().connect(&slots.go_to_offset);
+    ui.goto_line_edit().return_pressed().connect(&slots.go_to_offset);
+
+    ui.search_button().released().connect(&slots.find_next);
+    ui.search_line_edit().return_pressed().connect(&slots.find_next);
+}
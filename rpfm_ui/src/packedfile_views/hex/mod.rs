@@ -0,0 +1,339 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code for managing the read-only hex view.
+
+This view is used as a fallback for files we either don't know how to decode, or failed to decode.
+As these files can be huge, the data is only formatted and loaded into the view in chunks, as the user scrolls through it.
+!*/
+
+use qt_widgets::QCheckBox;
+use qt_widgets::QGridLayout;
+use qt_widgets::QLabel;
+use qt_widgets::QLineEdit;
+use qt_widgets::q_plain_text_edit::LineWrapMode;
+use qt_widgets::QPlainTextEdit;
+use qt_widgets::QPushButton;
+
+use qt_gui::q_text_cursor::{MoveMode, MoveOperation};
+
+use qt_core::QBox;
+use qt_core::QObject;
+use qt_core::QPtr;
+use qt_core::QSignalBlocker;
+use qt_core::QString;
+
+use getset::Getters;
+
+use std::ops::Range;
+use std::sync::{Arc, RwLock};
+
+use rpfm_lib::files::FileType;
+
+use crate::FONT_MONOSPACE;
+use crate::locale::{qtr, tre};
+use crate::packedfile_views::{PackedFileView, View, ViewType};
+use crate::utils::ref_from_atomic;
+
+use self::slots::PackedFileHexViewSlots;
+
+mod connections;
+mod slots;
+
+/// Amount of bytes we format and load into the view at once.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Amount of bytes shown per line.
+const BYTES_PER_LINE: usize = 16;
+
+/// Amount of lines each full chunk takes in the view.
+const LINES_PER_CHUNK: usize = CHUNK_SIZE / BYTES_PER_LINE;
+
+/// Amount of characters used by the offset gutter, including the separation with the hex column.
+const GUTTER_LENGTH: usize = 10;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the view of a file shown as raw hex data.
+#[derive(Getters)]
+#[getset(get = "pub")]
+pub struct PackedFileHexView {
+    hex_view: QBox<QPlainTextEdit>,
+    goto_line_edit: QBox<QLineEdit>,
+    goto_button: QBox<QPushButton>,
+    search_line_edit: QBox<QLineEdit>,
+    search_bytes_checkbox: QBox<QCheckBox>,
+    search_button: QBox<QPushButton>,
+    status_label: QBox<QLabel>,
+
+    data: Arc<RwLock<Vec<u8>>>,
+
+    /// Range of chunks currently loaded into the view.
+    chunks_loaded: Arc<RwLock<Range<usize>>>,
+
+    /// Offset from where the next search will start.
+    search_start: Arc<RwLock<usize>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileHexView`.
+impl PackedFileHexView {
+
+    /// This function creates a new Hex View, and sets up his slots and connections.
+    pub unsafe fn new_view(
+        packed_file_view: &mut PackedFileView,
+        data: Vec<u8>,
+    ) {
+        let layout: QPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast();
+
+        let hex_view = QPlainTextEdit::from_q_widget(packed_file_view.get_mut_widget());
+        hex_view.set_read_only(true);
+        hex_view.set_line_wrap_mode(LineWrapMode::NoWrap);
+        hex_view.set_font(ref_from_atomic(&*FONT_MONOSPACE));
+
+        let goto_line_edit = QLineEdit::from_q_widget(packed_file_view.get_mut_widget());
+        let goto_button = QPushButton::from_q_string_q_widget(&qtr("hex_view_goto"), packed_file_view.get_mut_widget());
+        let search_line_edit = QLineEdit::from_q_widget(packed_file_view.get_mut_widget());
+        let search_bytes_checkbox = QCheckBox::from_q_string_q_widget(&qtr("hex_view_search_bytes"), packed_file_view.get_mut_widget());
+        let search_button = QPushButton::from_q_string_q_widget(&qtr("hex_view_find_next"), packed_file_view.get_mut_widget());
+        let status_label = QLabel::from_q_widget(packed_file_view.get_mut_widget());
+
+        goto_line_edit.set_placeholder_text(&qtr("hex_view_goto_placeholder"));
+        search_line_edit.set_placeholder_text(&qtr("hex_view_search_placeholder"));
+
+        layout.add_widget_5a(&hex_view, 0, 0, 1, 7);
+        layout.add_widget_5a(&goto_line_edit, 1, 0, 1, 1);
+        layout.add_widget_5a(&goto_button, 1, 1, 1, 1);
+        layout.add_widget_5a(&search_line_edit, 1, 2, 1, 1);
+        layout.add_widget_5a(&search_bytes_checkbox, 1, 3, 1, 1);
+        layout.add_widget_5a(&search_button, 1, 4, 1, 1);
+        layout.add_widget_5a(&status_label, 1, 5, 1, 2);
+        layout.set_row_stretch(0, 99);
+        layout.set_column_stretch(2, 99);
+
+        let view = Arc::new(Self {
+            hex_view,
+            goto_line_edit,
+            goto_button,
+            search_line_edit,
+            search_bytes_checkbox,
+            search_button,
+            status_label,
+            data: Arc::new(RwLock::new(vec![])),
+            chunks_loaded: Arc::new(RwLock::new(0..0)),
+            search_start: Arc::new(RwLock::new(0)),
+        });
+
+        view.reload_view(data);
+
+        let slots = PackedFileHexViewSlots::new(&view);
+        connections::set_connections(&view, &slots);
+
+        packed_file_view.packed_file_type = FileType::Unknown;
+        packed_file_view.view = ViewType::Internal(View::Hex(view));
+    }
+
+    /// Function to reload the data of the view without having to delete the view itself.
+    pub unsafe fn reload_view(&self, data: Vec<u8>) {
+        *self.data.write().unwrap() = data;
+        *self.search_start.write().unwrap() = 0;
+        self.load_chunk(0);
+        self.show_size();
+    }
+
+    /// This function resets the status label to show the size of the file.
+    pub unsafe fn show_size(&self) {
+        let size = self.data.read().unwrap().len().to_string();
+        self.status_label.set_text(&QString::from_std_str(tre("hex_view_size", &[&size])));
+    }
+
+    /// This function replaces whatever is in the view with the provided chunk.
+    unsafe fn load_chunk(&self, chunk: usize) {
+        let text = Self::format_chunk(&self.data.read().unwrap(), chunk);
+        *self.chunks_loaded.write().unwrap() = chunk..chunk + 1;
+
+        let _blocker = QSignalBlocker::from_q_object(self.hex_view.vertical_scroll_bar().static_upcast::<QObject>());
+        self.hex_view.set_plain_text(&QString::from_std_str(text));
+    }
+
+    /// This function appends the chunk after the last loaded one to the view, if there is one.
+    pub unsafe fn load_next_chunk(&self) {
+        let text = {
+            let data = self.data.read().unwrap();
+            let mut chunks_loaded = self.chunks_loaded.write().unwrap();
+            if chunks_loaded.end * CHUNK_SIZE >= data.len() {
+                return;
+            }
+
+            chunks_loaded.end += 1;
+            Self::format_chunk(&data, chunks_loaded.end - 1)
+        };
+
+        let _blocker = QSignalBlocker::from_q_object(self.hex_view.vertical_scroll_bar().static_upcast::<QObject>());
+        self.hex_view.append_plain_text(&QString::from_std_str(text));
+    }
+
+    /// This function prepends the chunk before the first loaded one to the view, if there is one.
+    ///
+    /// The scroll is moved so what the user was seeing stays in place.
+    pub unsafe fn load_previous_chunk(&self) {
+        let mut text = {
+            let data = self.data.read().unwrap();
+            let mut chunks_loaded = self.chunks_loaded.write().unwrap();
+            if chunks_loaded.start == 0 {
+                return;
+            }
+
+            chunks_loaded.start -= 1;
+            Self::format_chunk(&data, chunks_loaded.start)
+        };
+        text.push('\n');
+
+        let scroll_bar = self.hex_view.vertical_scroll_bar();
+        let _blocker = QSignalBlocker::from_q_object(scroll_bar.static_upcast::<QObject>());
+        let value = scroll_bar.value();
+
+        let cursor = self.hex_view.text_cursor();
+        cursor.move_position_1a(MoveOperation::Start);
+        cursor.insert_text_1a(&QString::from_std_str(text));
+
+        scroll_bar.set_value(value + LINES_PER_CHUNK as i32);
+    }
+
+    /// This function moves the view to the provided offset, selecting `len` bytes from it.
+    ///
+    /// It returns false if the offset is out of bounds.
+    pub unsafe fn go_to_offset(&self, offset: usize, len: usize) -> bool {
+        let data_len = self.data.read().unwrap().len();
+        if offset >= data_len {
+            return false;
+        }
+
+        let chunk = offset / CHUNK_SIZE;
+        if !self.chunks_loaded.read().unwrap().contains(&chunk) {
+            self.load_chunk(chunk);
+        }
+
+        // Make sure the entire selection is loaded.
+        let last_offset = (offset + len.max(1) - 1).min(data_len - 1);
+        while self.chunks_loaded.read().unwrap().end <= last_offset / CHUNK_SIZE {
+            self.load_next_chunk();
+        }
+
+        let first_loaded_offset = self.chunks_loaded.read().unwrap().start * CHUNK_SIZE;
+        let start = self.text_position(offset - first_loaded_offset);
+        let end = self.text_position(last_offset - first_loaded_offset) + 2;
+
+        let cursor = self.hex_view.text_cursor();
+        cursor.set_position_1a(start);
+        cursor.set_position_2a(end, MoveMode::KeepAnchor);
+        self.hex_view.set_text_cursor(&cursor);
+        self.hex_view.center_cursor();
+
+        true
+    }
+
+    /// This function searches the next ocurrence of the provided pattern, starting after the last match.
+    ///
+    /// If the end of the file is reached, it starts again from the beginning. It returns false if there are no matches.
+    pub unsafe fn find_next(&self, pattern: &[u8]) -> bool {
+        if pattern.is_empty() {
+            return false;
+        }
+
+        let position = {
+            let data = self.data.read().unwrap();
+            let start = (*self.search_start.read().unwrap()).min(data.len());
+            data[start..].windows(pattern.len())
+                .position(|window| window == pattern)
+                .map(|position| position + start)
+                .or_else(|| data.windows(pattern.len()).position(|window| window == pattern))
+        };
+
+        match position {
+            Some(position) => {
+                *self.search_start.write().unwrap() = position + 1;
+                self.go_to_offset(position, pattern.len())
+            }
+            None => false,
+        }
+    }
+
+    /// This function returns the position in the view's text of the byte at the provided offset, relative to the first loaded chunk.
+    unsafe fn text_position(&self, relative_offset: usize) -> i32 {
+        let line = relative_offset / BYTES_PER_LINE;
+        let column = relative_offset % BYTES_PER_LINE;
+        let block = self.hex_view.document().find_block_by_number(line as i32);
+        block.position() + Self::hex_column(column) as i32
+    }
+
+    /// This function returns the position within a line of the hex representation of the byte in the provided column.
+    fn hex_column(column: usize) -> usize {
+        GUTTER_LENGTH + column * 3 + if column >= BYTES_PER_LINE / 2 { 1 } else { 0 }
+    }
+
+    /// This function formats the provided chunk of data as lines of offset, hex and ASCII columns.
+    fn format_chunk(data: &[u8], chunk: usize) -> String {
+        let start = (chunk * CHUNK_SIZE).min(data.len());
+        let end = (start + CHUNK_SIZE).min(data.len());
+
+        data[start..end].chunks(BYTES_PER_LINE)
+            .enumerate()
+            .map(|(index, line)| {
+                let mut hex = String::with_capacity(BYTES_PER_LINE * 3 + 1);
+                let mut ascii = String::with_capacity(BYTES_PER_LINE);
+                for column in 0..BYTES_PER_LINE {
+                    if column == BYTES_PER_LINE / 2 {
+                        hex.push(' ');
+                    }
+
+                    match line.get(column) {
+                        Some(byte) => {
+                            hex.push_str(&format!("{byte:02X} "));
+                            ascii.push(if byte.is_ascii_graphic() || *byte == b' ' { *byte as char } else { '.' });
+                        }
+                        None => hex.push_str("   "),
+                    }
+                }
+
+                format!("{:08X}  {} {}", start + index * BYTES_PER_LINE, hex, ascii)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// This function parses an offset, either in decimal or in hex if it starts with `0x`.
+    pub fn parse_offset(text: &str) -> Option<usize> {
+        let text = text.trim();
+        match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+            Some(hex) => usize::from_str_radix(hex, 16).ok(),
+            None => text.parse().ok(),
+        }
+    }
+
+    /// This function parses a byte sequence written as hex, like `DE AD BE EF`.
+    pub fn parse_byte_sequence(text: &str) -> Option<Vec<u8>> {
+        let text = text.chars().filter(|char| !char.is_whitespace()).collect::<String>();
+        if text.is_empty() || text.len() % 2 != 0 {
+            return None;
+        }
+
+        (0..text.len())
+            .step_by(2)
+            .map(|index| text.get(index..index + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+            .collect()
+    }
+}
@@ -0,0 +1,99 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the slots for Hex Views.
+!*/
+
+use qt_core::QBox;
+use qt_core::{SlotNoArgs, SlotOfInt};
+
+use std::sync::Arc;
+
+use rpfm_lib::integrations::log::*;
+
+use crate::locale::qtr;
+use crate::packedfile_views::hex::PackedFileHexView;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the slots of the view of a file shown as hex.
+pub struct PackedFileHexViewSlots {
+    pub load_more: QBox<SlotOfInt>,
+    pub go_to_offset: QBox<SlotNoArgs>,
+    pub find_next: QBox<SlotNoArgs>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileHexViewSlots`.
+impl PackedFileHexViewSlots {
+
+    /// This function creates the entire slot pack for Hex Views.
+    pub unsafe fn new(view: &Arc<PackedFileHexView>) -> Self {
+
+        // Slot to load more data when we reach one of the ends of what's loaded.
+        let load_more = SlotOfInt::new(view.hex_view(), clone!(
+            view => move |value| {
+                let scroll_bar = view.hex_view().vertical_scroll_bar();
+                if value == scroll_bar.maximum() {
+                    view.load_next_chunk();
+                } else if value == scroll_bar.minimum() {
+                    view.load_previous_chunk();
+                }
+            }
+        ));
+
+        let go_to_offset = SlotNoArgs::new(view.hex_view(), clone!(
+            view => move || {
+                info!("Triggering `Go To Offset (Hex View)` By Slot");
+
+                let text = view.goto_line_edit().text().to_std_string();
+                match PackedFileHexView::parse_offset(&text) {
+                    Some(offset) if view.go_to_offset(offset, 1) => view.show_size(),
+                    _ => view.status_label().set_text(&qtr("hex_view_invalid_offset")),
+                }
+            }
+        ));
+
+        let find_next = SlotNoArgs::new(view.hex_view(), clone!(
+            view => move || {
+                info!("Triggering `Find Next (Hex View)` By Slot");
+
+                let text = view.search_line_edit().text().to_std_string();
+                let pattern = if view.search_bytes_checkbox().is_checked() {
+                    match PackedFileHexView::parse_byte_sequence(&text) {
+                        Some(pattern) => pattern,
+                        None => return view.status_label().set_text(&qtr("hex_view_invalid_byte_sequence")),
+                    }
+                } else {
+                    text.as_bytes().to_vec()
+                };
+
+                if view.find_next(&pattern) {
+                    view.show_size();
+                } else {
+                    view.status_label().set_text(&qtr("hex_view_not_found"));
+                }
+            }
+        ));
+
+        // Return the slots, so we can keep them alive for the duration of the view.
+        Self {
+            load_more,
+            go_to_offset,
+            find_next,
+        }
+    }
+}
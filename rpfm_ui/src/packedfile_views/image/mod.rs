@@ -14,6 +14,7 @@ Module with all the code for managing the view for Images.
 
 use qt_widgets::QGridLayout;
 use qt_widgets::QLabel;
+use qt_widgets::QPushButton;
 
 use qt_gui::QPixmap;
 
@@ -21,17 +22,28 @@ use cpp_core::CppBox;
 
 use qt_core::QFlags;
 use qt_core::AlignmentFlag;
+use qt_core::QBox;
 use qt_core::QByteArray;
 use qt_core::QPtr;
+use qt_core::QString;
 
 use anyhow::{anyhow, Result};
-use rpfm_lib::files::{FileType, image::Image};
+
+use std::sync::{Arc, RwLock};
+
+use rpfm_lib::files::{FileType, image::{Image, ImageHeader}};
 
 #[cfg(feature = "support_modern_dds")]
 use crate::ffi::get_dds_qimage;
 use crate::ffi::{new_resizable_label_safe, set_pixmap_on_resizable_label_safe};
+use crate::locale::{qtr, tre};
 use crate::packedfile_views::{PackedFileView, View, ViewType};
 
+use self::slots::PackedFileImageViewSlots;
+
+mod connections;
+mod slots;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -40,6 +52,9 @@ use crate::packedfile_views::{PackedFileView, View, ViewType};
 pub struct PackedFileImageView {
     label: QPtr<QLabel>,
     image: CppBox<QPixmap>,
+    metadata_label: QBox<QLabel>,
+    export_png_button: QBox<QPushButton>,
+    path: Arc<RwLock<String>>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -52,7 +67,8 @@ impl PackedFileImageView {
     /// This function creates a new Image View, and sets up his slots and connections.
     pub unsafe fn new_view(
         packed_file_view: &mut PackedFileView,
-        data: &Image
+        data: &Image,
+        header: &Option<ImageHeader>,
     ) -> Result<()> {
 
         // Create the image in the UI.
@@ -89,21 +105,72 @@ impl PackedFileImageView {
         let layout: QPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast();
         let label = new_resizable_label_safe(&packed_file_view.get_mut_widget().as_ptr(), &image.as_ptr());
         label.set_alignment(QFlags::from(AlignmentFlag::AlignCenter));
-        layout.add_widget_5a(&label, 0, 0, 1, 1);
 
-        packed_file_view.packed_file_type = FileType::Image;
-        packed_file_view.view = ViewType::Internal(View::Image(Self {
+        let metadata_label = QLabel::from_q_string_q_widget(&QString::from_std_str(Self::metadata(data, header)), packed_file_view.get_mut_widget());
+        let export_png_button = QPushButton::from_q_string_q_widget(&qtr("image_export_png"), packed_file_view.get_mut_widget());
+
+        layout.add_widget_5a(&label, 0, 0, 1, 2);
+        layout.add_widget_5a(&metadata_label, 1, 0, 1, 1);
+        layout.add_widget_5a(&export_png_button, 1, 1, 1, 1);
+        layout.set_row_stretch(0, 99);
+        layout.set_column_stretch(0, 99);
+
+        let view = Arc::new(Self {
             label,
-            image
-        }));
+            image,
+            metadata_label,
+            export_png_button,
+            path: packed_file_view.get_path_raw(),
+        });
+
+        let slots = PackedFileImageViewSlots::new(&view);
+        connections::set_connections(&view, &slots);
+
+        packed_file_view.packed_file_type = FileType::Image;
+        packed_file_view.view = ViewType::Internal(View::Image(view));
 
         Ok(())
     }
 
     /// Function to reload the data of the view without having to delete the view itself.
-    pub unsafe fn reload_view(&self, data: &Image) {
+    pub unsafe fn reload_view(&self, data: &Image, header: &Option<ImageHeader>) {
         let byte_array = QByteArray::from_slice(data.data());
         self.image.load_from_data_q_byte_array(byte_array.into_ptr().as_ref().unwrap());
         set_pixmap_on_resizable_label_safe(&self.label.as_ptr(), &self.image.as_ptr());
+        self.metadata_label.set_text(&QString::from_std_str(Self::metadata(data, header)));
+    }
+
+    /// This function returns the text with the metadata of the image shown under it.
+    fn metadata(data: &Image, header: &Option<ImageHeader>) -> String {
+        let size = data.data().len().to_string();
+
+        // DDS images get their full header shown.
+        if let Some(header) = data.dds_header() {
+            let width = header.width().to_string();
+            let height = header.height().to_string();
+            return tre("image_metadata_dds", &[&width, &height, &size, &header.format(), &header.mipmap_count().to_string(), &header.array_size().to_string()]);
+        }
+
+        match header {
+            Some(header) => {
+                let width = header.width().to_string();
+                let height = header.height().to_string();
+                match header.fourcc() {
+                    Some(fourcc) => tre("image_metadata_fourcc", &[&width, &height, &size, fourcc]),
+                    None => tre("image_metadata", &[&width, &height, &size]),
+                }
+            }
+            None => tre("image_metadata_size_only", &[&size]),
+        }
+    }
+
+    /// This function returns a pointer to the pixmap with the image being shown, at full resolution.
+    pub fn get_ref_image(&self) -> &CppBox<QPixmap> {
+        &self.image
+    }
+
+    /// This function returns a pointer to the `Export as PNG` button.
+    pub fn get_mut_ptr_export_png_button(&self) -> &QBox<QPushButton> {
+        &self.export_png_button
     }
 }
@@ -0,0 +1,86 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the slots for Image Views.
+!*/
+
+use qt_widgets::QFileDialog;
+use qt_widgets::q_file_dialog::AcceptMode;
+
+use qt_core::QBox;
+use qt_core::QString;
+use qt_core::SlotNoArgs;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use rpfm_lib::integrations::log::*;
+
+use crate::locale::{qtr, tre};
+use crate::packedfile_views::image::PackedFileImageView;
+use crate::utils::show_dialog;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the slots of the view of an Image PackedFile.
+pub struct PackedFileImageViewSlots {
+    pub export_png: QBox<SlotNoArgs>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileImageViewSlots`.
+impl PackedFileImageViewSlots {
+
+    /// This function creates the entire slot pack for Image PackedFile Views.
+    pub unsafe fn new(view: &Arc<PackedFileImageView>) -> Self {
+
+        // Slot to export the image being shown as a PNG. We export the pixmap, not the label, so it's at full resolution.
+        let export_png = SlotNoArgs::new(&view.export_png_button, clone!(
+            view => move || {
+                info!("Triggering `Export as PNG` By Slot");
+
+                let file_dialog = QFileDialog::from_q_widget_q_string(
+                    &view.export_png_button,
+                    &qtr("image_export_png_title")
+                );
+
+                // By default, use the name of the image with the png extension.
+                let file_name = PathBuf::from(&*view.path.read().unwrap()).with_extension("png");
+                if let Some(file_name) = file_name.file_name() {
+                    file_dialog.select_file(&QString::from_std_str(file_name.to_string_lossy()));
+                }
+
+                file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+                file_dialog.set_confirm_overwrite(true);
+                file_dialog.set_name_filter(&QString::from_std_str("PNG (*.png)"));
+                file_dialog.set_default_suffix(&QString::from_std_str("png"));
+
+                // The format is deduced from the extension, which the dialog forces to be png.
+                if file_dialog.exec() == 1 {
+                    let path = file_dialog.selected_files().at(0);
+                    if !view.get_ref_image().save_1a(path) {
+                        show_dialog(&view.export_png_button, tre("image_export_png_error", &[&path.to_std_string()]), false);
+                    }
+                }
+            }
+        ));
+
+        // Return the slots, so we can keep them alive for the duration of the view.
+        Self {
+            export_png,
+        }
+    }
+}
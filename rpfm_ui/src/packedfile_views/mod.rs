@@ -19,14 +19,17 @@ use qt_widgets::QWidget;
 use qt_core::QBox;
 
 use anyhow::{anyhow, Result};
+use serde_derive::{Serialize, Deserialize};
 
 use std::{fmt, fmt::Display};
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock, RwLockReadGuard};
 
+use rpfm_extensions::search::SearchSource;
+
 use rpfm_lib::integrations::log::*;
-use rpfm_lib::files::{anims_table::AnimsTable, ContainerPath, db::DB, loc::Loc, FileType, matched_combat::MatchedCombat, RFileDecoded, text::Text};
+use rpfm_lib::files::{anims_table::AnimsTable, ContainerPath, db::DB, loc::Loc, FileType, matched_combat::MatchedCombat, pack::RESERVED_NAME_NOTES, RFileDecoded, text::Text};
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
@@ -46,11 +49,13 @@ use self::esf::PackedFileESFView;
 use self::decoder::PackedFileDecoderView;
 use self::dependencies_manager::DependenciesManagerView;
 use self::external::PackedFileExternalView;
+use self::hex::PackedFileHexView;
 use self::image::PackedFileImageView;
 use self::table::PackedFileTableView;
 use self::text::PackedFileTextView;
 use self::packfile::PackFileExtraView;
 use self::packfile_settings::PackFileSettingsView;
+use self::speedtree::PackedFileSpeedTreeView;
 //use self::tips::TipsView;
 use self::video::PackedFileVideoView;
 
@@ -67,12 +72,14 @@ pub mod decoder;
 pub mod dependencies_manager;
 pub mod esf;
 pub mod external;
+pub mod hex;
 pub mod image;
 pub mod packfile;
 pub mod packfile_settings;
 
 #[cfg(feature = "support_rigidmodel")]
 pub mod rigidmodel;
+pub mod speedtree;
 pub mod table;
 pub mod text;
 //pub mod tips;
@@ -114,7 +121,7 @@ pub enum ViewType {
 }
 
 /// This enum represents the source of the data in the view.
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Ord, PartialOrd)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Ord, PartialOrd, Serialize, Deserialize)]
 pub enum DataSource {
 
     /// This means the data is from somewhere in our PackFile.
@@ -131,6 +138,9 @@ pub enum DataSource {
 
     /// This means the data comes from an external file.
     ExternalFile,
+
+    /// This means the data comes from the Pack the open one is being compared against.
+    ComparedPackFile,
 }
 
 /// This enum is used to hold in a common way all the view types we have.
@@ -141,12 +151,14 @@ pub enum View {
     Decoder(Arc<PackedFileDecoderView>),
     DependenciesManager(Arc<DependenciesManagerView>),
     ESF(Arc<PackedFileESFView>),
-    Image(PackedFileImageView),
+    Hex(Arc<PackedFileHexView>),
+    Image(Arc<PackedFileImageView>),
     PackFile(Arc<PackFileExtraView>),
     PackSettings(Arc<PackFileSettingsView>),
 
     #[cfg(feature = "support_rigidmodel")]
     RigidModel(Arc<PackedFileRigidModelView>),
+    SpeedTree(Arc<PackedFileSpeedTreeView>),
     Table(Arc<PackedFileTableView>),
     Text(Arc<PackedFileTextView>),
 /*
@@ -291,6 +303,58 @@ impl PackedFileView {
 
     /// This function allows you to save a `PackedFileView` to his corresponding `PackedFile`.
     pub unsafe fn save(&self, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) -> Result<()> {
+        match self.save_data(app_ui, pack_file_contents_ui)? {
+            Some(data) => {
+
+                // Save the PackedFile, and trigger the stuff that needs to be triggered after a save.
+                // Notes are not a file in the Pack, so they're saved directly to it.
+                let command = match data {
+                    RFileDecoded::Text(text) if self.get_path() == RESERVED_NAME_NOTES => Command::SetPackNotes(text.contents().to_owned()),
+                    data => Command::SavePackedFileFromView(self.get_path(), data),
+                };
+
+                let receiver = CENTRAL_COMMAND.send_background(command);
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::Success => {
+
+                        // Path lists are validated on save, so the problems shown match what's in the Pack.
+                        if let ViewType::Internal(View::Text(view)) = self.get_view() {
+                            view.update_path_list_validation();
+                            view.set_lua_syntax_errors(&[]);
+                        }
+                    },
+
+                    // Lua scripts with syntax errors are still saved, we just show the errors in the view.
+                    Response::VecLuaSyntaxError(errors) => {
+                        if let ViewType::Internal(View::Text(view)) = self.get_view() {
+                            view.set_lua_syntax_errors(&errors);
+                        }
+                    },
+                    Response::Error(error) => return Err(error),
+
+                    // In ANY other situation, it's a message problem.
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+
+                // Saving changes updates the last modified date of the file, so update it in the TreeView.
+                let receiver = CENTRAL_COMMAND.send_background(Command::GetRFileInfo(self.get_path()));
+                let response = CentralCommand::recv(&receiver);
+                if let Response::OptionRFileInfo(Some(info)) = response {
+                    pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateTooltip(vec![info]), DataSource::PackFile);
+                }
+
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// This function returns the decoded data of a `PackedFileView`, so it can be saved to his corresponding `PackedFile`.
+    ///
+    /// Views which are not saved through their decoded data (read-only views, external views, or views with custom save logic)
+    /// save themselves here and return `None`.
+    pub unsafe fn save_data(&self, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) -> Result<Option<RFileDecoded>> {
 
         // Only save non-read-only, local files.
         if let DataSource::PackFile = self.get_data_source() {
@@ -300,9 +364,9 @@ impl PackedFileView {
 
                         let data = match view {
                             View::AnimFragment(view) => view.save_data()?,
-                            View::AnimFragmentDebug(_) => return Ok(()),
-                            View::AnimPack(_) => return Ok(()),
-                            View::Decoder(_) => return Ok(()),
+                            View::AnimFragmentDebug(_) => return Ok(None),
+                            View::AnimPack(_) => return Ok(None),
+                            View::Decoder(_) => return Ok(None),
                             View::DependenciesManager(view) => {
                                 let mut entries = vec![];
                                 let model = view.get_ref_table().table_model_ptr();
@@ -351,14 +415,18 @@ impl PackedFileView {
                                     pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::MarkAlwaysModified(vec![ContainerPath::Folder(String::new())]), DataSource::PackFile);
                                     UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
                                 }
-                                return Ok(())
+                                return Ok(None)
                             },
                             View::ESF(view) => RFileDecoded::ESF(view.save_view()),
-                            View::Image(_) => return Ok(()),
-                            View::PackFile(_) => return Ok(()),
+                            View::Hex(_) => return Ok(None),
+                            View::Image(_) => return Ok(None),
+                            View::PackFile(_) => return Ok(None),
                             View::PackSettings(view) => {
                                 let _ = CENTRAL_COMMAND.send_background(Command::SetPackSettings(view.save_view()));
-                                return Ok(())
+                                if let Some(authoring_tool) = view.save_authoring_tool() {
+                                    let _ = CENTRAL_COMMAND.send_background(Command::SetPackAuthoringTool(authoring_tool));
+                                }
+                                return Ok(None)
                             },
 
                             #[cfg(feature = "support_rigidmodel")]
@@ -399,32 +467,24 @@ impl PackedFileView {
                                 let widget = view.get_mut_editor();
                                 let string = get_text_safe(widget).to_std_string();
                                 text.set_contents(string);
+                                text.set_encoding_checked(view.get_current_encoding())?;
                                 RFileDecoded::Text(text)
                             },
                             #[cfg(feature = "support_uic")]
                             View::UIC(view) => {
                                 RFileDecoded::UIC(view.save_view())
                             },
-                            View::UnitVariant(_) => return Ok(()),
+                            View::SpeedTree(_) => return Ok(None),
+                            View::UnitVariant(view) => view.save_view()?,
                             View::Video(view) => {
                                 let _ = CENTRAL_COMMAND.send_background(Command::SetVideoFormat(self.get_path(), view.get_current_format()));
-                                return Ok(());
+                                return Ok(None);
                             }
 
                             View::None => todo!(),
                         };
 
-                        // Save the PackedFile, and trigger the stuff that needs to be triggered after a save.
-                        let receiver = CENTRAL_COMMAND.send_background(Command::SavePackedFileFromView(self.get_path(), data));
-                        let response = CENTRAL_COMMAND.recv_try(&receiver);
-                        match response {
-                            Response::Success => {
-                                Ok(())
-                            }
-
-                            // In ANY other situation, it's a message problem.
-                            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
-                        }
+                        Ok(Some(data))
                     },
                     ViewType::External(view) => {
                         let receiver = CENTRAL_COMMAND.send_background(Command::SavePackedFileFromExternalView(self.get_path(), view.get_external_path()));
@@ -435,14 +495,14 @@ impl PackedFileView {
                             _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
                         }
 
-                        Ok(())
+                        Ok(None)
                     }
                 }
             } else {
-                Ok(())
+                Ok(None)
             }
         } else {
-            Ok(())
+            Ok(None)
         }
     }
 
@@ -458,6 +518,20 @@ impl PackedFileView {
             match self.get_ref_mut_view() {
                 ViewType::Internal(view) => {
 
+                    // Hex views show the raw data, so there's nothing to decode.
+                    if let View::Hex(old_hex) = view {
+                        let receiver = CENTRAL_COMMAND.send_background(Command::GetPackedFileRawData(path.to_owned()));
+                        let response = CentralCommand::recv(&receiver);
+                        return match response {
+                            Response::VecU8(data) => {
+                                old_hex.reload_view(data);
+                                Ok(())
+                            }
+                            Response::Error(error) => Err(error),
+                            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                        };
+                    }
+
                     let receiver = CENTRAL_COMMAND.send_background(Command::DecodePackedFile(path.to_owned(), data_source));
                     let response = CentralCommand::recv(&receiver);
 
@@ -521,9 +595,9 @@ impl PackedFileView {
                             }
                         },
 
-                        Response::ImageRFileInfo(image, packed_file_info) => {
+                        Response::ImageRFileInfo(image, header, packed_file_info) => {
                             if let View::Image(old_image) = view {
-                                old_image.reload_view(&image);
+                                old_image.reload_view(&image, &header);
                                 pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]), DataSource::PackFile);
                             }
                             else {
@@ -591,6 +665,16 @@ impl PackedFileView {
                                         return Err(anyhow!(RFILE_RELOAD_ERROR));
                                     }
                                 }
+                                RFileDecoded::SpeedTree(speedtree) => {
+                                    if let View::SpeedTree(old_speedtree) = view {
+                                        old_speedtree.reload_view(&speedtree);
+                                        pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateTooltip(vec![packed_file_info;1]), DataSource::PackFile);
+
+                                    }
+                                    else {
+                                        return Err(anyhow!(RFILE_RELOAD_ERROR));
+                                    }
+                                }
                                 RFileDecoded::ESF(esf) => {
                                     if let View::ESF(old_esf) = view {
                                         old_esf.reload_view(&esf);
@@ -652,6 +736,7 @@ impl Display for DataSource {
             Self::ParentFiles => "ParentFiles",
             Self::AssKitFiles => "AssKitFiles",
             Self::ExternalFile => "ExternalFile",
+            Self::ComparedPackFile => "ComparedPackFile",
         }, f)
     }
 }
@@ -664,7 +749,22 @@ impl From<&str> for DataSource {
             "ParentFiles" => Self::ParentFiles,
             "AssKitFiles" => Self::AssKitFiles,
             "ExternalFile" => Self::ExternalFile,
+            "ComparedPackFile" => Self::ComparedPackFile,
             _ => unreachable!()
         }
     }
 }
+
+impl From<&SearchSource> for DataSource {
+    fn from(value: &SearchSource) -> Self {
+        match value {
+            SearchSource::Pack => Self::PackFile,
+            SearchSource::ParentFiles => Self::ParentFiles,
+            SearchSource::GameFiles => Self::GameFiles,
+            SearchSource::AssKitFiles => Self::AssKitFiles,
+
+            // Matches are always tagged with the specific source they come from.
+            SearchSource::All => unreachable!(),
+        }
+    }
+}
@@ -24,6 +24,8 @@ use super::{PackFileExtraView, slots::PackFileExtraViewSlots};
 /// to not pollute the other modules with a ton of connections.
 pub unsafe fn set_connections(ui: &Arc<PackFileExtraView>, slots: &PackFileExtraViewSlots) {
     ui.tree_view().double_clicked().connect(&slots.import);
+    ui.add_to_pack().triggered().connect(&slots.add_to_pack);
+    ui.copy().triggered().connect(&slots.copy);
     ui.filter_line_edit().text_changed().connect(&slots.filter_change_text);
 
     ui.filter_autoexpand_matches_button().toggled().connect(&slots.filter_change_autoexpand_matches);
@@ -21,6 +21,7 @@ use qt_widgets::QLineEdit;
 use qt_widgets::QMenu;
 use qt_widgets::QToolButton;
 use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
 
 use qt_gui::QStandardItemModel;
 
@@ -73,6 +74,8 @@ pub struct PackFileExtraView {
     filter_case_sensitive_button: QPtr<QToolButton>,
 
     context_menu: QBox<QMenu>,
+    copy: QPtr<QAction>,
+    add_to_pack: QPtr<QAction>,
     expand: QPtr<QAction>,
     collapse: QPtr<QAction>,
 }
@@ -91,6 +94,22 @@ impl PackFileExtraView {
         pack_file_contents_ui: &Rc<PackFileContentsUI>,
         pack_file_path: PathBuf,
     ) -> Result<()> {
+        let view = Self::new_widget(pack_file_view.get_mut_widget(), app_ui, pack_file_contents_ui, pack_file_path)?;
+        pack_file_view.view = ViewType::Internal(View::PackFile(view));
+
+        // Return success.
+        Ok(())
+    }
+
+    /// This function loads the extra PackFile to memory and builds its view inside the provided widget.
+    ///
+    /// The widget must have a `QGridLayout`. This is used by both, the tab view and the side pane of the PackFile Contents panel.
+    pub unsafe fn new_widget(
+        parent: &QBox<QWidget>,
+        app_ui: &Rc<AppUI>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        pack_file_path: PathBuf,
+    ) -> Result<Arc<Self>> {
 
         // Load the extra PackFile to memory.
         // Ignore the response, we don't need it yet.
@@ -105,10 +124,10 @@ impl PackFileExtraView {
 
         // Load the UI Template.
         let template_path = if cfg!(debug_assertions) { VIEW_DEBUG } else { VIEW_RELEASE };
-        let main_widget = load_template(pack_file_view.get_mut_widget(), &template_path)?;
+        let main_widget = load_template(parent, template_path)?;
 
         // Add everything to the main widget's Layout.
-        let layout: QPtr<QGridLayout> = pack_file_view.get_mut_widget().layout().static_downcast();
+        let layout: QPtr<QGridLayout> = parent.layout().static_downcast();
         layout.add_widget_5a(&main_widget, 0, 0, 1, 1);
 
         let tree_view: QPtr<QTreeView> = find_widget(&main_widget.static_upcast(), "tree_view")?;
@@ -117,8 +136,8 @@ impl PackFileExtraView {
         let filter_case_sensitive_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_case_sensitive_button")?;
 
         // Create and configure the `TreeView` itself.
-        let tree_model = QStandardItemModel::new_1a(pack_file_view.get_mut_widget());
-        let tree_model_filter = new_treeview_filter_safe(pack_file_view.get_mut_widget().static_upcast());
+        let tree_model = QStandardItemModel::new_1a(&main_widget);
+        let tree_model_filter = new_treeview_filter_safe(main_widget.static_upcast());
         tree_model_filter.set_source_model(&tree_model);
         tree_view.set_model(&tree_model_filter);
         tree_view.set_expands_on_double_click(false);
@@ -130,9 +149,12 @@ impl PackFileExtraView {
         tree_view.update_treeview(true, TreeViewOperation::Build(build_data), DataSource::PackFile);
 
         // Create the extra actions for the TreeView.
-        let context_menu = QMenu::from_q_widget(pack_file_view.get_mut_widget());
-        let expand = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "secondary_pack_tree_context_menu", "expand", "treeview_expand_all", Some(pack_file_view.get_mut_widget().static_upcast::<qt_widgets::QWidget>()));
-        let collapse = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "secondary_pack_tree_context_menu", "collapse", "treeview_collapse_all", Some(pack_file_view.get_mut_widget().static_upcast::<qt_widgets::QWidget>()));
+        let context_menu = QMenu::from_q_widget(&main_widget);
+        let copy = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "secondary_pack_tree_context_menu", "copy", "extra_pack_copy", Some(tree_view.static_upcast::<QWidget>()));
+        let add_to_pack = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "secondary_pack_tree_context_menu", "add_to_pack", "extra_pack_add_to_pack", Some(tree_view.static_upcast::<QWidget>()));
+        let expand = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "secondary_pack_tree_context_menu", "expand", "treeview_expand_all", Some(main_widget.static_upcast::<QWidget>()));
+        let collapse = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "secondary_pack_tree_context_menu", "collapse", "treeview_collapse_all", Some(main_widget.static_upcast::<QWidget>()));
+        context_menu.insert_separator(&expand);
 
         // Build the slots and set up the shortcuts/connections/tip.
         let view = Arc::new(PackFileExtraView{
@@ -145,17 +167,16 @@ impl PackFileExtraView {
             filter_case_sensitive_button,
 
             context_menu,
+            copy,
+            add_to_pack,
             expand,
             collapse,
         });
 
         let slots = PackFileExtraViewSlots::new(app_ui, pack_file_contents_ui, &view);
-
         connections::set_connections(&view, &slots);
-        pack_file_view.view = ViewType::Internal(View::PackFile(view));
 
-        // Return success.
-        Ok(())
+        Ok(view)
     }
 
     // Function to filter the contents of the TreeView.
@@ -22,17 +22,10 @@ use qt_core::QBox;
 use std::sync::Arc;
 use std::rc::Rc;
 
-use rpfm_lib::files::ContainerPath;
-
 use crate::app_ui::AppUI;
-use crate::CENTRAL_COMMAND;
-use crate::communications::*;
-use crate::packedfile_views::DataSource;
 use crate::packfile_contents_ui::PackFileContentsUI;
-use crate::pack_tree::{PackTree, TreeViewOperation};
-use crate::utils::show_dialog;
+use crate::pack_tree::PackTree;
 use super::PackFileExtraView;
-use crate::UI_STATE;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -41,6 +34,8 @@ use crate::UI_STATE;
 /// This struct contains the slots of the view of the extra PackFile.
 pub struct PackFileExtraViewSlots {
     pub import: QBox<SlotOfQModelIndex>,
+    pub add_to_pack: QBox<SlotOfBool>,
+    pub copy: QBox<SlotOfBool>,
 
     pub filter_change_text: QBox<SlotOfQString>,
     pub filter_change_autoexpand_matches: QBox<SlotOfBool>,
@@ -65,48 +60,40 @@ impl PackFileExtraViewSlots {
         pack_file_view: &Arc<PackFileExtraView>
     ) -> Self {
 
-        // When we want to import the selected PackedFile...
+        // When we want to import the selected PackedFiles...
         let import = SlotOfQModelIndex::new(&pack_file_view.tree_view, clone!(
             app_ui,
             pack_file_contents_ui,
             pack_file_view => move |_| {
+                let item_types = pack_file_view.tree_view.get_item_types_from_selection_filtered();
+                let pack_file_path = pack_file_view.pack_file_path.read().unwrap().to_path_buf();
+                PackFileContentsUI::add_files_from_extra_pack(&app_ui, &pack_file_contents_ui, &pack_file_path, &item_types);
+                pack_file_view.tree_view.set_focus_0a();
+            }
+        ));
+
+        let add_to_pack = SlotOfBool::new(&pack_file_view.tree_view, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            pack_file_view => move |_| {
+                let item_types = pack_file_view.tree_view.get_item_types_from_selection_filtered();
+                let pack_file_path = pack_file_view.pack_file_path.read().unwrap().to_path_buf();
+                PackFileContentsUI::add_files_from_extra_pack(&app_ui, &pack_file_contents_ui, &pack_file_path, &item_types);
+                pack_file_view.tree_view.set_focus_0a();
+            }
+        ));
+
+        // When we want to copy the selected paths, so they can be pasted later into the main PackFile.
+        let copy = SlotOfBool::new(&pack_file_view.tree_view, clone!(
+            pack_file_contents_ui,
+            pack_file_view => move |_| {
+                let item_types = pack_file_view.tree_view.get_item_types_from_selection_filtered();
+                if !item_types.is_empty() {
+                    let pack_file_path = pack_file_view.pack_file_path.read().unwrap().to_path_buf();
+                    *pack_file_contents_ui.extra_pack_clipboard().write().unwrap() = Some((pack_file_path, item_types));
 
-                // Get the file to get from the TreeView.
-                let selection_file_to_move = pack_file_view.tree_view.selection_model().selection();
-                if selection_file_to_move.count_0a() == 1 {
-                    let item_types = pack_file_view.tree_view.get_item_types_from_selection_filtered();
-
-                    // Ask the Background Thread to move the files, and send him the path.
-                    app_ui.toggle_main_window(false);
-                    let receiver = CENTRAL_COMMAND.send_background(Command::AddPackedFilesFromPackFile(((&pack_file_view.pack_file_path.read().unwrap()).to_path_buf(), item_types)));
-                    let response = CentralCommand::recv(&receiver);
-                    match response {
-                        Response::VecContainerPath(paths_ok) => {
-
-                            // If any of the PackedFiles was already open (and we overwrote them) remove his view.
-                            for path in &paths_ok {
-                                if let ContainerPath::File(path) = path {
-                                    let mut open_packedfiles = UI_STATE.set_open_packedfiles();
-                                    if let Some(packed_file_view) = open_packedfiles.iter_mut().find(|x| *x.get_ref_path() == *path && x.get_data_source() == DataSource::PackFile) {
-                                        if packed_file_view.reload(path, &pack_file_contents_ui).is_err() {
-                                            let _ = AppUI::purge_that_one_specifically(&app_ui, &pack_file_contents_ui, path, DataSource::PackFile, false);
-                                        }
-                                    }
-                                }
-                            }
-
-                            // Update the TreeView.
-                            pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Add(paths_ok.to_vec()), DataSource::PackFile);
-                            pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths_ok.to_vec()), DataSource::PackFile);
-                            UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
-                        },
-                        Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
-                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
-                    }
-
-                    // Re-enable the Main Window.
-                    app_ui.toggle_main_window(true);
-                    pack_file_view.tree_view.set_focus_0a();
+                    // Enable pasting right away, so the shortcut works without having to refresh the selection first.
+                    pack_file_contents_ui.context_menu_paste_from_side_pane().set_enabled(pack_file_contents_ui.context_menu_add_from_packfile().is_enabled());
                 }
             }
         ));
@@ -135,6 +122,8 @@ impl PackFileExtraViewSlots {
         // Return the slots, so we can keep them alive for the duration of the view.
         Self {
             import,
+            add_to_pack,
+            copy,
 
             filter_change_text,
             filter_change_autoexpand_matches,
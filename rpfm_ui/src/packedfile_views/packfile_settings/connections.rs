@@ -24,4 +24,5 @@ use super::{PackFileSettingsView, slots::PackFileSettingsSlots};
 /// to not pollute the other modules with a ton of connections.
 pub unsafe fn set_connections(ui: &Arc<PackFileSettingsView>, slots: &PackFileSettingsSlots) {
     ui.get_ref_apply_button().released().connect(&slots.apply);
+    ui.get_ref_diagnostics_ignore_rules_remove_button().released().connect(&slots.remove_diagnostics_ignore_rules);
 }
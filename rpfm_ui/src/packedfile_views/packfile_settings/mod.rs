@@ -12,6 +12,7 @@
 Module with all the code for managing the PackFile-Specific settings.
 !*/
 
+use qt_widgets::q_abstract_item_view::SelectionBehavior;
 use qt_widgets::QCheckBox;
 use qt_widgets::QGridLayout;
 use qt_widgets::QLabel;
@@ -19,8 +20,13 @@ use qt_widgets::QLineEdit;
 use qt_widgets::QSpinBox;
 use qt_widgets::QPlainTextEdit;
 use qt_widgets::QPushButton;
+use qt_widgets::QTableView;
 use qt_widgets::QWidget;
 
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
 use qt_core::QBox;
 use qt_core::QPtr;
 use qt_core::QString;
@@ -31,7 +37,8 @@ use std::collections::BTreeMap;
 use std::rc::Rc;
 use std::sync::Arc;
 
-use rpfm_lib::files::pack::PackSettings;
+use rpfm_lib::files::pack::{DiagnosticIgnoreRule, PackSettings};
+use rpfm_lib::games::pfh_version::PFHVersion;
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
@@ -55,6 +62,10 @@ pub struct PackFileSettingsView {
     settings_text_single_line: BTreeMap<String, QBox<QLineEdit>>,
     settings_bool: BTreeMap<String, QBox<QCheckBox>>,
     settings_number: BTreeMap<String, QBox<QSpinBox>>,
+    authoring_tool: Option<QBox<QLineEdit>>,
+    diagnostics_ignore_rules_view: QBox<QTableView>,
+    diagnostics_ignore_rules_model: QBox<QStandardItemModel>,
+    diagnostics_ignore_rules_remove_button: QBox<QPushButton>,
     settings_apply_button: QBox<QPushButton>,
 }
 
@@ -82,6 +93,13 @@ impl PackFileSettingsView {
             _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
         };
 
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetPackHeader);
+        let response = CentralCommand::recv(&receiver);
+        let header = match response {
+            Response::PackHeader(header) => header,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
         let layout: QPtr<QGridLayout> = pack_file_view.get_mut_widget().layout().static_downcast();
 
         let mut settings_text_multi_line = BTreeMap::new();
@@ -90,6 +108,32 @@ impl PackFileSettingsView {
         let mut settings_bool = BTreeMap::new();
 
         let mut row = 0;
+
+        // Only PFH6 Packs have a subheader. Its values are kept as they were read, except the authoring tool, which can be edited.
+        let authoring_tool = if *header.pfh_version() == PFHVersion::PFH6 {
+            let header_label = QLabel::from_q_string_q_widget(&qtr("pfs_header_label"), pack_file_view.get_mut_widget());
+            let game_version_label = QLabel::from_q_string_q_widget(&qtr("pfs_header_game_version"), pack_file_view.get_mut_widget());
+            let game_version = header.game_version().to_be_bytes();
+            let game_version_value = QLabel::from_q_string_q_widget(&QString::from_std_str(format!("{}.{}.{}.{}", game_version[0], game_version[1], game_version[2], game_version[3])), pack_file_view.get_mut_widget());
+            let build_number_label = QLabel::from_q_string_q_widget(&qtr("pfs_header_build_number"), pack_file_view.get_mut_widget());
+            let build_number_value = QLabel::from_q_string_q_widget(&QString::from_std_str(header.build_number().to_string()), pack_file_view.get_mut_widget());
+            let authoring_tool_label = QLabel::from_q_string_q_widget(&qtr("pfs_header_authoring_tool"), pack_file_view.get_mut_widget());
+            let authoring_tool = QLineEdit::from_q_string_q_widget(&QString::from_std_str(header.authoring_tool()), pack_file_view.get_mut_widget());
+            authoring_tool.set_max_length(8);
+
+            layout.add_widget_5a(&header_label, row, 0, 1, 2);
+            layout.add_widget_5a(&game_version_label, row + 1, 0, 1, 1);
+            layout.add_widget_5a(&game_version_value, row + 1, 1, 1, 1);
+            layout.add_widget_5a(&build_number_label, row + 2, 0, 1, 1);
+            layout.add_widget_5a(&build_number_value, row + 2, 1, 1, 1);
+            layout.add_widget_5a(&authoring_tool_label, row + 3, 0, 1, 1);
+            layout.add_widget_5a(&authoring_tool, row + 3, 1, 1, 1);
+
+            row += 4;
+            Some(authoring_tool)
+        } else {
+            None
+        };
         for (key, setting) in settings.settings_text() {
             let label = QLabel::from_q_string_q_widget(&qtr(&format!("pfs_{}_label", key)), pack_file_view.get_mut_widget());
             let description_label = QLabel::from_q_string_q_widget(&qtr(&format!("pfs_{}_description_label", key)), pack_file_view.get_mut_widget());
@@ -134,6 +178,7 @@ impl PackFileSettingsView {
             let label = QLabel::from_q_string_q_widget(&qtr(&format!("pfs_{}_label", key)), pack_file_view.get_mut_widget());
             let _description_label = QLabel::from_q_string_q_widget(&qtr(&format!("pfs_{}_description_label", key)), pack_file_view.get_mut_widget());
             let edit = QSpinBox::new_1a(pack_file_view.get_mut_widget());
+            edit.set_maximum(i32::MAX);
             edit.set_value(*setting);
 
             layout.add_widget_5a(&label, row, 0, 1, 1);
@@ -143,6 +188,39 @@ impl PackFileSettingsView {
             row += 1;
         }
 
+        // Diagnostics ignore rules are not free-form settings, so they get their own table.
+        let rules_label = QLabel::from_q_string_q_widget(&qtr("pfs_diagnostics_ignore_rules_label"), pack_file_view.get_mut_widget());
+        let rules_description_label = QLabel::from_q_string_q_widget(&qtr("pfs_diagnostics_ignore_rules_description_label"), pack_file_view.get_mut_widget());
+        let rules_view = QTableView::new_1a(pack_file_view.get_mut_widget());
+        let rules_model = QStandardItemModel::new_1a(&rules_view);
+        let rules_remove_button = QPushButton::from_q_string_q_widget(&qtr("pfs_diagnostics_ignore_rules_remove"), pack_file_view.get_mut_widget());
+        rules_description_label.set_word_wrap(true);
+        rules_view.set_model(&rules_model);
+        rules_view.set_selection_behavior(SelectionBehavior::SelectRows);
+        rules_view.horizontal_header().set_stretch_last_section(true);
+
+        rules_model.set_column_count(3);
+        rules_model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("pfs_diagnostics_ignore_rules_path")).into_ptr());
+        rules_model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("pfs_diagnostics_ignore_rules_diagnostic")).into_ptr());
+        rules_model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("pfs_diagnostics_ignore_rules_columns")).into_ptr());
+
+        for rule in settings.diagnostics_ignore_rules() {
+            let qlist = QListOfQStandardItem::new();
+            qlist.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(rule.path())).into_ptr().as_mut_raw_ptr());
+            qlist.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(rule.diagnostic().as_deref().unwrap_or_default())).into_ptr().as_mut_raw_ptr());
+            qlist.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(rule.columns().join(", "))).into_ptr().as_mut_raw_ptr());
+            rules_model.append_row_q_list_of_q_standard_item(qlist.as_ref());
+        }
+
+        rules_view.resize_columns_to_contents();
+
+        layout.add_widget_5a(&rules_label, row, 0, 1, 1);
+        layout.add_widget_5a(&rules_description_label, row + 1, 0, 1, 1);
+        layout.add_widget_5a(&rules_view, row, 1, 2, 1);
+        layout.add_widget_5a(&rules_remove_button, row + 2, 1, 1, 1);
+        layout.set_row_stretch(row + 1, 100);
+        row += 3;
+
         let padding_widget = QWidget::new_1a(pack_file_view.get_mut_widget());
         layout.add_widget_5a(&padding_widget, row, 0, 1, 3);
 
@@ -161,6 +239,10 @@ impl PackFileSettingsView {
             settings_text_single_line,
             settings_bool,
             settings_number,
+            authoring_tool,
+            diagnostics_ignore_rules_view: rules_view,
+            diagnostics_ignore_rules_model: rules_model,
+            diagnostics_ignore_rules_remove_button: rules_remove_button,
             settings_apply_button: button_box_apply,
         });
 
@@ -183,9 +265,47 @@ impl PackFileSettingsView {
         self.settings_bool.iter().for_each(|(key, widget)| { settings.settings_bool_mut().insert(key.to_owned(), widget.is_checked()); });
         self.settings_number.iter().for_each(|(key, widget)| { settings.settings_number_mut().insert(key.to_owned(), widget.value()); });
 
+        for row in 0..self.diagnostics_ignore_rules_model.row_count_0a() {
+            let path = self.diagnostics_ignore_rules_model.item_2a(row, 0).text().to_std_string();
+            let diagnostic = self.diagnostics_ignore_rules_model.item_2a(row, 1).text().to_std_string();
+            let columns = self.diagnostics_ignore_rules_model.item_2a(row, 2).text().to_std_string()
+                .split(',')
+                .map(|column| column.trim().to_owned())
+                .collect::<Vec<_>>();
+
+            if path.trim().is_empty() {
+                continue;
+            }
+
+            let diagnostic = if diagnostic.trim().is_empty() { None } else { Some(diagnostic.trim()) };
+            settings.add_diagnostics_ignore_rule(DiagnosticIgnoreRule::new(path.trim(), diagnostic, &columns));
+        }
+
         settings
     }
 
+    /// This function returns the authoring tool to save in the header of the Pack, if the Pack has a subheader.
+    pub unsafe fn save_authoring_tool(&self) -> Option<String> {
+        self.authoring_tool.as_ref().map(|authoring_tool| authoring_tool.text().to_std_string())
+    }
+
+    /// This function removes the selected rows from the diagnostics ignore rules table.
+    pub unsafe fn remove_selected_diagnostics_ignore_rules(&self) {
+        let indexes = self.diagnostics_ignore_rules_view.selection_model().selected_rows_0a();
+        let mut rows = (0..indexes.count_0a()).map(|index| indexes.at(index).row()).collect::<Vec<_>>();
+        rows.sort_unstable();
+        rows.dedup();
+
+        for row in rows.iter().rev() {
+            self.diagnostics_ignore_rules_model.remove_row_1a(*row);
+        }
+    }
+
+    /// This function returns a reference to the button to remove diagnostics ignore rules.
+    pub fn get_ref_diagnostics_ignore_rules_remove_button(&self) -> &QBox<QPushButton> {
+        &self.diagnostics_ignore_rules_remove_button
+    }
+
     /// This function returns a reference to the apply button.
     pub fn get_ref_apply_button(&self) -> &QBox<QPushButton> {
         &self.settings_apply_button
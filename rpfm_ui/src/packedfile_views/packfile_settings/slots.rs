@@ -31,6 +31,7 @@ use super::PackFileSettingsView;
 /// This struct contains the slots of the view of a PackFile Settings.
 pub struct PackFileSettingsSlots {
     pub apply: QBox<SlotNoArgs>,
+    pub remove_diagnostics_ignore_rules: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -60,9 +61,17 @@ impl PackFileSettingsSlots {
             }
         ));
 
+        // Slot to remove the selected diagnostics ignore rules.
+        let remove_diagnostics_ignore_rules = SlotNoArgs::new(view.get_ref_diagnostics_ignore_rules_remove_button(), clone!(
+            view => move || {
+                view.remove_selected_diagnostics_ignore_rules();
+            }
+        ));
+
         // Return the slots, so we can keep them alive for the duration of the view.
         Self {
             apply,
+            remove_diagnostics_ignore_rules,
         }
     }
 }
@@ -0,0 +1,71 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code for managing the SpeedTree Views.
+
+SpeedTrees are read-only, so this view only shows the data we know of their header.
+!*/
+
+use anyhow::Result;
+
+use std::sync::Arc;
+
+use rpfm_lib::files::{FileType, RFileDecoded, speedtree::SpeedTree};
+
+use crate::views::debug::DebugView;
+
+use crate::packedfile_views::PackedFileView;
+
+use super::{ViewType, View};
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the view of a SpeedTree.
+pub struct PackedFileSpeedTreeView {
+    debug_view: Arc<DebugView>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// Implementation for `PackedFileSpeedTreeView`.
+impl PackedFileSpeedTreeView {
+
+    /// This function creates a new PackedFileSpeedTreeView, and sets up his slots and connections.
+    pub unsafe fn new_view(
+        packed_file_view: &mut PackedFileView,
+        data: RFileDecoded
+    ) -> Result<()> {
+        let debug_view = DebugView::new_view(
+            packed_file_view.get_mut_widget(),
+            data,
+            packed_file_view.get_path_raw(),
+        )?;
+
+        let packed_file_speedtree_view = Self {
+            debug_view,
+        };
+
+        packed_file_view.view = ViewType::Internal(View::SpeedTree(Arc::new(packed_file_speedtree_view)));
+        packed_file_view.packed_file_type = FileType::SpeedTree;
+
+        Ok(())
+    }
+
+    /// This function tries to reload the current view with the provided data.
+    pub unsafe fn reload_view(&self, data: &SpeedTree) {
+        let text = serde_json::to_string_pretty(&data).unwrap();
+        self.debug_view.reload_view(&text);
+    }
+}
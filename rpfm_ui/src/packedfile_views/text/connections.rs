@@ -25,4 +25,13 @@ use super::{PackedFileTextView, slots::PackedFileTextViewSlots};
 /// to not pollute the other modules with a ton of connections.
 pub unsafe fn set_connections(ui: &Arc<PackedFileTextView>, slots: &PackedFileTextViewSlots) {
     get_text_changed_dummy_widget_safe(&ui.editor.as_ptr()).text_changed().connect(&slots.modified);
+
+    if ui.markdown_preview.is_some() {
+        get_text_changed_dummy_widget_safe(&ui.editor.as_ptr()).text_changed().connect(&slots.update_markdown_preview);
+    }
+    ui.encoding_combobox.current_index_changed().connect(&slots.modified);
+
+    if let Some(ref validate_references) = ui.validate_references {
+        validate_references.triggered().connect(&slots.validate_references);
+    }
 }
@@ -0,0 +1,259 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with a minimal Markdown to HTML converter, used for previewing Markdown files.
+
+It only supports the basics: headers, paragraphs, lists, quotes, code blocks, rules,
+bold/italic/inline code and links. Anything else is shown as plain text.
+!*/
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Block we're currently building while converting.
+#[derive(PartialEq)]
+enum Block {
+    None,
+    Paragraph,
+    UnorderedList,
+    OrderedList,
+    Quote,
+    Code,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Extra functions
+//-------------------------------------------------------------------------------//
+
+/// This function converts the provided Markdown text to HTML.
+pub fn markdown_to_html(markdown: &str) -> String {
+    let mut html = String::new();
+    let mut block = Block::None;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+
+        // Code blocks are copied as they are until they're closed.
+        if block == Block::Code {
+            if trimmed.starts_with("```") {
+                html.push_str("</code></pre>\n");
+                block = Block::None;
+            } else {
+                html.push_str(&escape(line));
+                html.push('\n');
+            }
+            continue;
+        }
+
+        if trimmed.starts_with("```") {
+            close_block(&mut html, &mut block);
+            html.push_str("<pre><code>");
+            block = Block::Code;
+        }
+
+        else if trimmed.is_empty() {
+            close_block(&mut html, &mut block);
+        }
+
+        else if let Some((level, text)) = header(trimmed) {
+            close_block(&mut html, &mut block);
+            html.push_str(&format!("<h{0}>{1}</h{0}>\n", level, inline(text)));
+        }
+
+        else if is_rule(trimmed) {
+            close_block(&mut html, &mut block);
+            html.push_str("<hr/>\n");
+        }
+
+        else if let Some(text) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")).or_else(|| trimmed.strip_prefix("+ ")) {
+            open_block(&mut html, &mut block, Block::UnorderedList);
+            html.push_str(&format!("<li>{}</li>\n", inline(text)));
+        }
+
+        else if let Some(text) = ordered_item(trimmed) {
+            open_block(&mut html, &mut block, Block::OrderedList);
+            html.push_str(&format!("<li>{}</li>\n", inline(text)));
+        }
+
+        else if let Some(text) = trimmed.strip_prefix('>') {
+            open_block(&mut html, &mut block, Block::Quote);
+            html.push_str(&inline(text.trim_start()));
+            html.push('\n');
+        }
+
+        // Lines that are not a new block continue the current one. Lists and quotes end on them.
+        else {
+            if block != Block::Paragraph {
+                open_block(&mut html, &mut block, Block::Paragraph);
+            } else {
+                html.push('\n');
+            }
+            html.push_str(&inline(trimmed));
+        }
+    }
+
+    close_block(&mut html, &mut block);
+    html
+}
+
+/// This function opens a new block, closing the current one if it's of a different kind.
+fn open_block(html: &mut String, block: &mut Block, new_block: Block) {
+    if *block == new_block {
+        return;
+    }
+
+    close_block(html, block);
+    html.push_str(match new_block {
+        Block::Paragraph => "<p>",
+        Block::UnorderedList => "<ul>\n",
+        Block::OrderedList => "<ol>\n",
+        Block::Quote => "<blockquote>\n",
+        Block::Code => "<pre><code>",
+        Block::None => "",
+    });
+
+    *block = new_block;
+}
+
+/// This function closes the current block, if any.
+fn close_block(html: &mut String, block: &mut Block) {
+    html.push_str(match block {
+        Block::Paragraph => "</p>\n",
+        Block::UnorderedList => "</ul>\n",
+        Block::OrderedList => "</ol>\n",
+        Block::Quote => "</blockquote>\n",
+        Block::Code => "</code></pre>\n",
+        Block::None => "",
+    });
+
+    *block = Block::None;
+}
+
+/// This function returns the level and text of a header line, if the line is a header.
+fn header(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|x| *x == '#').count();
+    if (1..=6).contains(&level) {
+        line[level..].strip_prefix(' ').map(|text| (level, text.trim_end_matches('#').trim()))
+    } else {
+        None
+    }
+}
+
+/// This function checks if a line is a horizontal rule (`---`, `***` or `___`).
+fn is_rule(line: &str) -> bool {
+    let line = line.replace(' ', "");
+    line.len() >= 3 && ["-", "*", "_"].iter().any(|x| line.chars().all(|y| y.to_string() == *x))
+}
+
+/// This function returns the text of an ordered list item (`1. text`), if the line is one.
+fn ordered_item(line: &str) -> Option<&str> {
+    let digits = line.chars().take_while(|x| x.is_ascii_digit()).count();
+    if digits > 0 {
+        line[digits..].strip_prefix(". ")
+    } else {
+        None
+    }
+}
+
+/// This function escapes the characters HTML cares about.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// This function converts the inline elements of a line: inline code, links, bold and italic.
+fn inline(text: &str) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut html = String::new();
+    let mut index = 0;
+    let mut bold = false;
+    let mut italic = false;
+
+    while index < chars.len() {
+        match chars[index] {
+
+            // Inline code is not parsed further.
+            '`' => match find(&chars, index + 1, "`") {
+                Some(end) => {
+                    html.push_str(&format!("<code>{}</code>", escape(&chars[index + 1..end].iter().collect::<String>())));
+                    index = end + 1;
+                }
+                None => {
+                    html.push('`');
+                    index += 1;
+                }
+            },
+
+            // Links: [text](url).
+            '[' => match find(&chars, index + 1, "](") {
+                Some(text_end) => match find(&chars, text_end + 2, ")") {
+                    Some(url_end) => {
+                        let text = chars[index + 1..text_end].iter().collect::<String>();
+                        let url = chars[text_end + 2..url_end].iter().collect::<String>();
+                        html.push_str(&format!("<a href=\"{}\">{}</a>", escape(url.trim()), inline(&text)));
+                        index = url_end + 1;
+                    }
+                    None => {
+                        html.push('[');
+                        index += 1;
+                    }
+                },
+                None => {
+                    html.push('[');
+                    index += 1;
+                }
+            },
+
+            // Underscores within words (like in table names) are not emphasis.
+            '_' if index > 0 && chars[index - 1].is_alphanumeric() && chars.get(index + 1).map_or(false, |x| x.is_alphanumeric()) => {
+                html.push('_');
+                index += 1;
+            }
+
+            '*' | '_' if chars.get(index + 1) == Some(&chars[index]) => {
+                html.push_str(if bold { "</b>" } else { "<b>" });
+                bold = !bold;
+                index += 2;
+            }
+
+            '*' | '_' => {
+                html.push_str(if italic { "</i>" } else { "<i>" });
+                italic = !italic;
+                index += 1;
+            }
+
+            character => {
+                html.push_str(&escape(&character.to_string()));
+                index += 1;
+            }
+        }
+    }
+
+    // Close any unbalanced tag, so they don't leak to the rest of the document.
+    if italic {
+        html.push_str("</i>");
+    }
+
+    if bold {
+        html.push_str("</b>");
+    }
+
+    html
+}
+
+/// This function returns the position of the first match of `pattern` in `chars`, starting from `start`.
+fn find(chars: &[char], start: usize, pattern: &str) -> Option<usize> {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    (start..chars.len()).find(|index| chars[*index..].starts_with(&pattern))
+}
@@ -0,0 +1,48 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the Markdown to HTML converter.
+
+use super::markdown::markdown_to_html;
+
+#[test]
+fn test_markdown_to_html_blocks() {
+    let markdown = "# Changelog\n\n## v1.2 ##\nFirst line\nsecond line\n\n- One\n* Two\n\n1. First\n2. Second\n\n> Quoted\n---\n```\nlet x = <a>;\n```";
+    let html = "<h1>Changelog</h1>\n\
+        <h2>v1.2</h2>\n\
+        <p>First line\nsecond line</p>\n\
+        <ul>\n<li>One</li>\n<li>Two</li>\n</ul>\n\
+        <ol>\n<li>First</li>\n<li>Second</li>\n</ol>\n\
+        <blockquote>\nQuoted\n</blockquote>\n\
+        <hr/>\n\
+        <pre><code>let x = &lt;a&gt;;\n</code></pre>\n";
+
+    assert_eq!(markdown_to_html(markdown), html);
+}
+
+#[test]
+fn test_markdown_to_html_inline() {
+    assert_eq!(markdown_to_html("**bold** and *italic* and __also bold__"), "<p><b>bold</b> and <i>italic</i> and <b>also bold</b></p>\n");
+    assert_eq!(markdown_to_html("Edit `units_tables` & [the wiki](https://example.com/a?b=1)"), "<p>Edit <code>units_tables</code> &amp; <a href=\"https://example.com/a?b=1\">the wiki</a></p>\n");
+
+    // Underscores within words are kept.
+    assert_eq!(markdown_to_html("land_units_tables"), "<p>land_units_tables</p>\n");
+
+    // Unbalanced or incomplete markup must not break the document.
+    assert_eq!(markdown_to_html("*never closed"), "<p><i>never closed</i></p>\n");
+    assert_eq!(markdown_to_html("[not a link] and `open"), "<p>[not a link] and `open</p>\n");
+    assert_eq!(markdown_to_html("#not a header"), "<p>#not a header</p>\n");
+    assert_eq!(markdown_to_html("<script>"), "<p>&lt;script&gt;</p>\n");
+}
+
+#[test]
+fn test_markdown_to_html_unclosed_code_block() {
+    assert_eq!(markdown_to_html("```\ncode"), "<pre><code>code\n</code></pre>\n");
+}
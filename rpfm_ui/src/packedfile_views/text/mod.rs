@@ -12,27 +12,47 @@
 Module with all the code for managing the view for Text PackedFiles.
 !*/
 
+use qt_widgets::QAction;
+use qt_widgets::QComboBox;
 use qt_widgets::QGridLayout;
+use qt_widgets::QLabel;
+use qt_widgets::QTextBrowser;
 use qt_widgets::QWidget;
 
 use qt_core::QBox;
+use qt_core::QFlags;
 use qt_core::QPtr;
 use qt_core::QString;
+use qt_core::TextInteractionFlag;
+
+use anyhow::Result;
 
 use std::rc::Rc;
 use std::sync::{Arc, RwLock};
 
+use rpfm_extensions::lua::LuaSyntaxError;
+
 use rpfm_lib::files::{FileType, text::*};
 
 use crate::app_ui::AppUI;
-use crate::ffi::{cursor_row_safe, new_text_editor_safe, scroll_to_row_safe, set_text_safe};
+use crate::CENTRAL_COMMAND;
+use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::ffi::{add_text_editor_context_menu_action_safe, cursor_row_safe, get_text_safe, new_text_editor_safe, scroll_to_row_safe, set_text_safe};
+use crate::locale::{qtr, tr};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::{DataSource, PackedFileView, View, ViewType};
 use crate::packedfile_views::text::slots::PackedFileTextViewSlots;
 
+use self::markdown::markdown_to_html;
+use self::search::TextSearchView;
+
 mod connections;
+pub mod markdown;
+mod search;
 mod slots;
 
+#[cfg(test)] mod markdown_test;
+
 const CPP: &str = "C++";
 const HTML: &str = "HTML";
 const LUA: &str = "Lua";
@@ -43,6 +63,16 @@ const JSON: &str = "JSON";
 const CSS: &str = "CSS";
 const JS: &str = "Javascript";
 
+/// List of encodings the user can choose to save a Text file with, in the order they're shown in the UI.
+const ENCODINGS: [(&str, Encoding); 6] = [
+    ("UTF-8", Encoding::Utf8),
+    ("UTF-8 (BOM)", Encoding::Utf8Bom),
+    ("UTF-16 LE", Encoding::Utf16Le),
+    ("UTF-16 BE", Encoding::Utf16Be),
+    ("ISO-8859-1", Encoding::Iso8859_1),
+    ("Windows-1252", Encoding::Windows1252),
+];
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -50,6 +80,12 @@ const JS: &str = "Javascript";
 /// This struct contains the view of a Text PackedFile.
 pub struct PackedFileTextView {
     editor: QBox<QWidget>,
+    encoding_combobox: QBox<QComboBox>,
+    markdown_preview: Option<QBox<QTextBrowser>>,
+    path_list_label: Option<QBox<QLabel>>,
+    lua_problems_label: Option<QBox<QLabel>>,
+    validate_references: Option<QBox<QAction>>,
+    references_problems_label: Option<QBox<QLabel>>,
     packed_file_path: Option<Arc<RwLock<String>>>,
     data_source: Arc<RwLock<DataSource>>,
 }
@@ -67,7 +103,7 @@ impl PackedFileTextView {
         app_ui: &Rc<AppUI>,
         pack_file_contents_ui: &Rc<PackFileContentsUI>,
         data: &Text,
-    ) {
+    ) -> Result<()> {
 
         let highlighting_mode = match data.format() {
             TextFormat::Cpp => QString::from_std_str(CPP),
@@ -83,21 +119,95 @@ impl PackedFileTextView {
 
         let editor = new_text_editor_safe(&packed_file_view.get_mut_widget().static_upcast());
         let layout: QPtr<QGridLayout> = packed_file_view.get_mut_widget().layout().static_downcast();
-        layout.add_widget_5a(&editor, 0, 0, 1, 1);
+        layout.add_widget_5a(&editor, 0, 0, 1, 3);
+
+        // The search bar is kept alive by its slots, so we don't need to keep it in the view.
+        let search_view = TextSearchView::new(&packed_file_view.get_mut_widget().static_upcast(), &editor.static_upcast(), app_ui)?;
+        layout.add_widget_5a(search_view.main_widget(), 1, 0, 1, 3);
+
+        let encoding_label = QLabel::from_q_string_q_widget(&qtr("text_encoding"), packed_file_view.get_mut_widget());
+        let encoding_combobox = QComboBox::new_1a(packed_file_view.get_mut_widget());
+        for (name, _) in &ENCODINGS {
+            encoding_combobox.add_item_q_string(&QString::from_std_str(name));
+        }
+
+        layout.add_widget_5a(&encoding_label, 2, 0, 1, 1);
+        layout.add_widget_5a(&encoding_combobox, 2, 1, 1, 1);
+        layout.set_column_stretch(2, 10);
+
+        // Markdown files get a rendered preview side-by-side with the editor.
+        let markdown_preview = if let TextFormat::Markdown = data.format() {
+            let markdown_preview = QTextBrowser::new_1a(packed_file_view.get_mut_widget());
+            markdown_preview.set_open_external_links(true);
+            markdown_preview.set_html(&QString::from_std_str(markdown_to_html(data.contents())));
+            layout.add_widget_5a(&markdown_preview, 0, 3, 3, 1);
+            layout.set_column_stretch(3, 10);
+            Some(markdown_preview)
+        } else {
+            None
+        };
+
+        // Path lists from the Pack get the problems with their paths shown under the editor.
+        let path_list_label = if packed_file_view.get_data_source() == DataSource::PackFile && PathListFormat::from_path(&packed_file_view.get_path()).is_some() {
+            let path_list_label = QLabel::from_q_widget(packed_file_view.get_mut_widget());
+            path_list_label.set_word_wrap(true);
+            path_list_label.set_text_interaction_flags(QFlags::from(TextInteractionFlag::TextSelectableByMouse));
+            layout.add_widget_5a(&path_list_label, 3, 0, 1, 3);
+            Some(path_list_label)
+        } else {
+            None
+        };
+
+        // Lua scripts from the Pack get their syntax errors shown under the editor when saved.
+        let lua_problems_label = if packed_file_view.get_data_source() == DataSource::PackFile && *data.format() == TextFormat::Lua {
+            let lua_problems_label = QLabel::from_q_widget(packed_file_view.get_mut_widget());
+            lua_problems_label.set_word_wrap(true);
+            lua_problems_label.set_text_interaction_flags(QFlags::from(TextInteractionFlag::TextSelectableByMouse));
+            lua_problems_label.set_visible(false);
+            layout.add_widget_5a(&lua_problems_label, 4, 0, 1, 3);
+            Some(lua_problems_label)
+        } else {
+            None
+        };
+
+        // VariantMeshDefinitions from the Pack can have their references validated on demand, from the context menu of the editor.
+        let (validate_references, references_problems_label) = if packed_file_view.get_data_source() == DataSource::PackFile && packed_file_view.get_path().to_lowercase().ends_with(".variantmeshdefinition") {
+            let validate_references = QAction::from_q_string_q_object(&qtr("text_validate_references"), &editor);
+            add_text_editor_context_menu_action_safe(&editor.as_ptr(), &validate_references.as_ptr());
+
+            let references_problems_label = QLabel::from_q_widget(packed_file_view.get_mut_widget());
+            references_problems_label.set_word_wrap(true);
+            references_problems_label.set_text_interaction_flags(QFlags::from(TextInteractionFlag::TextSelectableByMouse));
+            references_problems_label.set_visible(false);
+            layout.add_widget_5a(&references_problems_label, 5, 0, 1, 3);
+            (Some(validate_references), Some(references_problems_label))
+        } else {
+            (None, None)
+        };
 
         set_text_safe(&editor.static_upcast(), &QString::from_std_str(data.contents()).as_ptr(), &highlighting_mode.as_ptr());
 
         let view = Arc::new(PackedFileTextView {
             editor,
+            encoding_combobox,
+            markdown_preview,
+            path_list_label,
+            lua_problems_label,
+            validate_references,
+            references_problems_label,
             packed_file_path: Some(packed_file_view.get_path_raw()),
             data_source: Arc::new(RwLock::new(packed_file_view.get_data_source())),
         });
 
+        view.set_current_encoding(*data.encoding());
+        view.update_path_list_validation();
+
         let slots = PackedFileTextViewSlots::new(&view, app_ui, pack_file_contents_ui);
         connections::set_connections(&view, &slots);
 
         packed_file_view.packed_file_type = FileType::Text;
         packed_file_view.view = ViewType::Internal(View::Text(view));
+        Ok(())
     }
 
     /// This function returns a pointer to the editor widget.
@@ -105,6 +215,20 @@ impl PackedFileTextView {
         &self.editor
     }
 
+    /// This function returns the encoding currently selected in the view.
+    pub unsafe fn get_current_encoding(&self) -> Encoding {
+        ENCODINGS.get(self.encoding_combobox.current_index() as usize)
+            .map(|(_, encoding)| *encoding)
+            .unwrap_or_default()
+    }
+
+    /// This function selects the provided encoding in the view.
+    unsafe fn set_current_encoding(&self, encoding: Encoding) {
+        if let Some(index) = ENCODINGS.iter().position(|(_, x)| *x == encoding) {
+            self.encoding_combobox.set_current_index(index as i32);
+        }
+    }
+
     /// Function to reload the data of the view without having to delete the view itself.
     pub unsafe fn reload_view(&self, data: &Text) {
 
@@ -122,8 +246,81 @@ impl PackedFileTextView {
 
         let row_number = cursor_row_safe(&self.editor.as_ptr());
         set_text_safe(&self.editor.static_upcast(), &QString::from_std_str(data.contents()).as_ptr(), &highlighting_mode.as_ptr());
+        self.set_current_encoding(*data.encoding());
+        self.update_markdown_preview();
 
         // Try to scroll to the line we were before.
         scroll_to_row_safe(&self.editor.as_ptr(), row_number);
     }
+
+    /// This function shows the problems with the paths of the file, if the view is for a path list.
+    ///
+    /// The check is done over the file in the backend, so the view needs to be saved first to get the result for its current contents.
+    pub unsafe fn update_path_list_validation(&self) {
+        if let (Some(ref path_list_label), Some(ref packed_file_path)) = (&self.path_list_label, &self.packed_file_path) {
+            let receiver = CENTRAL_COMMAND.send_background(Command::CheckPathList(packed_file_path.read().unwrap().to_owned()));
+            let response = CentralCommand::recv(&receiver);
+            match response {
+                Response::VecString(messages) => {
+                    if messages.is_empty() {
+                        path_list_label.set_visible(false);
+                    } else {
+                        path_list_label.set_text(&QString::from_std_str(format!("{}\n{}", tr("path_list_problems"), messages.join("\n"))));
+                        path_list_label.set_visible(true);
+                    }
+                }
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }
+    }
+
+    /// This function shows the syntax errors found in the Lua script when it was last saved, if the view is for a Lua script.
+    ///
+    /// Passing an empty list hides the problems.
+    pub unsafe fn set_lua_syntax_errors(&self, errors: &[LuaSyntaxError]) {
+        if let Some(ref lua_problems_label) = self.lua_problems_label {
+            if errors.is_empty() {
+                lua_problems_label.set_visible(false);
+            } else {
+                let errors = errors.iter().map(|error| error.to_string()).collect::<Vec<_>>();
+                lua_problems_label.set_text(&QString::from_std_str(format!("{}\n{}", tr("lua_syntax_problems"), errors.join("\n"))));
+                lua_problems_label.set_visible(true);
+            }
+        }
+    }
+
+    /// This function shows the problems with the references of the file, if the view is for a VariantMeshDefinition.
+    ///
+    /// The check is done over the current contents of the editor, so the view doesn't need to be saved first.
+    pub unsafe fn validate_references(&self) {
+        if let Some(ref references_problems_label) = self.references_problems_label {
+            let contents = get_text_safe(&self.editor).to_std_string();
+            let receiver = CENTRAL_COMMAND.send_background(Command::CheckVariantMeshDefinition(contents));
+            let response = CentralCommand::recv(&receiver);
+            match response {
+                Response::VecString(messages) => {
+                    if messages.is_empty() {
+                        references_problems_label.set_text(&qtr("variant_mesh_no_problems"));
+                    } else {
+                        references_problems_label.set_text(&QString::from_std_str(format!("{}\n{}", tr("variant_mesh_problems"), messages.join("\n"))));
+                    }
+
+                    references_problems_label.set_visible(true);
+                }
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+        }
+    }
+
+    /// This function updates the Markdown preview with the current contents of the editor, if the view has a preview.
+    pub unsafe fn update_markdown_preview(&self) {
+        if let Some(ref markdown_preview) = self.markdown_preview {
+            let markdown = get_text_safe(&self.editor).to_std_string();
+
+            // Keep the scroll position, so the preview doesn't jump to the top on each keystroke.
+            let scroll = markdown_preview.vertical_scroll_bar().value();
+            markdown_preview.set_html(&QString::from_std_str(markdown_to_html(&markdown)));
+            markdown_preview.vertical_scroll_bar().set_value(scroll);
+        }
+    }
 }
@@ -0,0 +1,43 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with all the code to connect `TextSearchView` signals with their corresponding slots.
+
+This module is, and should stay, private, as it's only glue between the `TextSearchView` and `TextSearchViewSlots` structs.
+!*/
+
+use crate::ffi::get_text_changed_dummy_widget_safe;
+use super::{TextSearchView, slots::TextSearchViewSlots};
+
+/// This function connects all the actions from the provided `TextSearchView` with their slots in `TextSearchViewSlots`.
+///
+/// This function is just glue to trigger after initializing both, the actions and the slots. It's here
+/// to not pollute the other modules with a ton of connections.
+pub unsafe fn set_connections(ui: &TextSearchView, slots: &TextSearchViewSlots) {
+    ui.find_action().triggered().connect(&slots.find);
+    ui.find_next_action().triggered().connect(&slots.find_next);
+    ui.find_previous_action().triggered().connect(&slots.find_previous);
+
+    ui.search_line_edit().text_changed().connect(&slots.search);
+    ui.search_line_edit().return_pressed().connect(&slots.next_match);
+    ui.replace_line_edit().return_pressed().connect(&slots.replace);
+    ui.case_sensitive_button().toggled().connect(&slots.search);
+    ui.whole_word_button().toggled().connect(&slots.search);
+    ui.regex_button().toggled().connect(&slots.search);
+
+    ui.prev_match_button().released().connect(&slots.prev_match);
+    ui.next_match_button().released().connect(&slots.next_match);
+    ui.replace_button().released().connect(&slots.replace);
+    ui.replace_all_button().released().connect(&slots.replace_all);
+    ui.close_button().released().connect(&slots.close);
+
+    get_text_changed_dummy_widget_safe(&ui.editor().as_ptr()).text_changed().connect(&slots.update_matches);
+}
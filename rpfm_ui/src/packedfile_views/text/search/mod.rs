@@ -0,0 +1,264 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! TextView submodule to provide Search & Replace functionality.
+//!
+//! The search itself is done by the text editor, so here we only keep the UI and the options of it.
+
+use qt_widgets::QAction;
+use qt_widgets::QApplication;
+use qt_widgets::QLabel;
+use qt_widgets::QLineEdit;
+use qt_widgets::QToolButton;
+use qt_widgets::QWidget;
+
+use qt_core::QBox;
+use qt_core::QPtr;
+use qt_core::QString;
+
+use anyhow::Result;
+use getset::Getters;
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::app_ui::AppUI;
+use crate::ffi::*;
+use crate::locale::{qtr, qtre};
+use crate::utils::*;
+
+use self::slots::TextSearchViewSlots;
+
+mod connections;
+mod slots;
+
+const VIEW_DEBUG: &str = "rpfm_ui/ui_templates/text_search_widget.ui";
+const VIEW_RELEASE: &str = "ui/text_search_widget.ui";
+
+/// Name of the shortcut group with the search actions.
+const SHORTCUT_GROUP: &str = "text_search";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the search bar of a text view. There is one per view, hidden until the user searches something.
+#[derive(Getters)]
+#[getset(get = "pub")]
+pub struct TextSearchView {
+    main_widget: QBox<QWidget>,
+    search_line_edit: QPtr<QLineEdit>,
+    replace_line_edit: QPtr<QLineEdit>,
+    prev_match_button: QPtr<QToolButton>,
+    next_match_button: QPtr<QToolButton>,
+    replace_button: QPtr<QToolButton>,
+    replace_all_button: QPtr<QToolButton>,
+    case_sensitive_button: QPtr<QToolButton>,
+    whole_word_button: QPtr<QToolButton>,
+    regex_button: QPtr<QToolButton>,
+    close_button: QPtr<QToolButton>,
+    matches_label: QPtr<QLabel>,
+
+    find_action: QPtr<QAction>,
+    find_next_action: QPtr<QAction>,
+    find_previous_action: QPtr<QAction>,
+
+    /// Widget of the view, to know if the shortcuts were triggered from it.
+    view_widget: QPtr<QWidget>,
+
+    /// Text editor we search in.
+    editor: QPtr<QWidget>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl TextSearchView {
+
+    /// This function creates the search bar of a text view, and sets up its slots, connections and shortcuts.
+    ///
+    /// The bar is not added to any layout. That's up to the view.
+    pub unsafe fn new(view_widget: &QPtr<QWidget>, editor: &QPtr<QWidget>, app_ui: &Rc<AppUI>) -> Result<Arc<Self>> {
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { VIEW_DEBUG } else { VIEW_RELEASE };
+        let main_widget = load_template(view_widget, template_path)?;
+
+        let search_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "search_label")?;
+        let replace_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "replace_label")?;
+        let search_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "search_line_edit")?;
+        let replace_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "replace_line_edit")?;
+
+        let prev_match_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "prev_match_button")?;
+        let next_match_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "next_match_button")?;
+        let replace_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "replace_button")?;
+        let replace_all_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "replace_all_button")?;
+        let case_sensitive_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "case_sensitive_button")?;
+        let whole_word_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "whole_word_button")?;
+        let regex_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "regex_button")?;
+        let close_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "close_button")?;
+        let matches_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "matches_label")?;
+
+        search_label.set_text(&qtr("text_search_search_pattern"));
+        replace_label.set_text(&qtr("text_search_replace_pattern"));
+        search_line_edit.set_placeholder_text(&qtr("text_search_search_placeholder"));
+        replace_line_edit.set_placeholder_text(&qtr("text_search_replace_placeholder"));
+        matches_label.set_text(&QString::new());
+
+        prev_match_button.set_tool_tip(&qtr("text_search_prev_match"));
+        next_match_button.set_tool_tip(&qtr("text_search_next_match"));
+        replace_button.set_tool_tip(&qtr("text_search_replace_current"));
+        replace_all_button.set_tool_tip(&qtr("text_search_replace_all"));
+        case_sensitive_button.set_tool_tip(&qtr("text_search_case_sensitive"));
+        whole_word_button.set_tool_tip(&qtr("text_search_whole_word"));
+        regex_button.set_tool_tip(&qtr("text_search_regex"));
+        close_button.set_tool_tip(&qtr("text_search_close"));
+
+        // The shortcuts are shared between all the text views, so each view checks if it has the focus before reacting to them.
+        let find_action = shortcut_action_safe(app_ui.shortcuts().as_ptr(), QString::from_std_str(SHORTCUT_GROUP).into_ptr(), QString::from_std_str("find").into_ptr());
+        let find_next_action = shortcut_action_safe(app_ui.shortcuts().as_ptr(), QString::from_std_str(SHORTCUT_GROUP).into_ptr(), QString::from_std_str("find_next").into_ptr());
+        let find_previous_action = shortcut_action_safe(app_ui.shortcuts().as_ptr(), QString::from_std_str(SHORTCUT_GROUP).into_ptr(), QString::from_std_str("find_previous").into_ptr());
+        view_widget.add_action(find_action.as_ptr());
+        view_widget.add_action(find_next_action.as_ptr());
+        view_widget.add_action(find_previous_action.as_ptr());
+
+        main_widget.hide();
+
+        let search = Arc::new(Self {
+            main_widget,
+            search_line_edit,
+            replace_line_edit,
+            prev_match_button,
+            next_match_button,
+            replace_button,
+            replace_all_button,
+            case_sensitive_button,
+            whole_word_button,
+            regex_button,
+            close_button,
+            matches_label,
+            find_action,
+            find_next_action,
+            find_previous_action,
+            view_widget: view_widget.clone(),
+            editor: editor.clone(),
+        });
+
+        search.update_matches();
+
+        let slots = TextSearchViewSlots::new(&search);
+        connections::set_connections(&search, &slots);
+
+        Ok(search)
+    }
+
+    /// This function returns if the focus is currently within the view this search bar belongs to.
+    pub unsafe fn has_focus(&self) -> bool {
+        let focus_widget = QApplication::focus_widget();
+        !focus_widget.is_null() && self.view_widget.is_ancestor_of(&focus_widget)
+    }
+
+    /// This function returns the search flags for the text editor, from the options selected in the search bar.
+    unsafe fn flags(&self) -> i32 {
+        let mut flags = 0;
+        if self.case_sensitive_button.is_checked() {
+            flags |= TEXT_SEARCH_CASE_SENSITIVE;
+        }
+
+        if self.whole_word_button.is_checked() {
+            flags |= TEXT_SEARCH_WHOLE_WORDS;
+        }
+
+        if self.regex_button.is_checked() {
+            flags |= TEXT_SEARCH_REGEX;
+        }
+
+        flags
+    }
+
+    /// This function shows the search bar, ready to type a pattern in it.
+    pub unsafe fn show(&self) {
+        self.main_widget.show();
+        self.search_line_edit.set_focus_0a();
+        self.search_line_edit.select_all();
+        self.update_matches();
+    }
+
+    /// This function hides the search bar, returning the focus to the editor.
+    pub unsafe fn hide(&self) {
+        self.main_widget.hide();
+        self.editor.set_focus_0a();
+    }
+
+    /// This function selects the next (or previous) match in the editor.
+    ///
+    /// Incremental searches keep the current match selected if it still matches, so the selection doesn't jump while typing.
+    pub unsafe fn find(&self, backwards: bool, incremental: bool) {
+        let pattern = self.search_line_edit.text();
+        if !pattern.is_empty() {
+            text_search_find_safe(&self.editor.as_ptr(), &pattern.as_ptr(), self.flags(), backwards, incremental);
+        }
+    }
+
+    /// This function searches the pattern again after it or the search options changed.
+    pub unsafe fn search(&self) {
+        if self.regex_button.is_checked() {
+            check_regex(&self.search_line_edit.text().to_std_string(), self.search_line_edit.static_upcast());
+        } else {
+            self.search_line_edit.set_style_sheet(&QString::new());
+        }
+
+        self.find(false, true);
+        self.update_matches();
+    }
+
+    /// This function replaces the current match, and selects the next one.
+    pub unsafe fn replace_current(&self) {
+        let pattern = self.search_line_edit.text();
+        let replacement = self.replace_line_edit.text();
+        text_search_replace_safe(&self.editor.as_ptr(), &pattern.as_ptr(), &replacement.as_ptr(), self.flags());
+        self.update_matches();
+    }
+
+    /// This function replaces all the matches in the file, as a single undo step.
+    pub unsafe fn replace_all(&self) {
+        let pattern = self.search_line_edit.text();
+        let replacement = self.replace_line_edit.text();
+        let replaced = text_search_replace_all_safe(&self.editor.as_ptr(), &pattern.as_ptr(), &replacement.as_ptr(), self.flags());
+        self.update_matches();
+
+        if replaced > 0 {
+            self.matches_label.set_text(&qtre("text_search_replaced", &[&replaced.to_string()]));
+        }
+    }
+
+    /// This function updates the amount of matches shown in the search bar, and which buttons can be used.
+    pub unsafe fn update_matches(&self) {
+        let pattern = self.search_line_edit.text();
+        let matches = text_search_count_safe(&self.editor.as_ptr(), &pattern.as_ptr(), self.flags());
+
+        if pattern.is_empty() {
+            self.matches_label.set_text(&QString::new());
+        } else if matches < 0 {
+            self.matches_label.set_text(&qtr("text_search_invalid_regex"));
+        } else if matches == 0 {
+            self.matches_label.set_text(&qtr("text_search_no_matches"));
+        } else {
+            self.matches_label.set_text(&qtre("text_search_matches", &[&matches.to_string()]));
+        }
+
+        let has_matches = matches > 0;
+        self.prev_match_button.set_enabled(has_matches);
+        self.next_match_button.set_enabled(has_matches);
+        self.replace_button.set_enabled(has_matches);
+        self.replace_all_button.set_enabled(has_matches);
+    }
+}
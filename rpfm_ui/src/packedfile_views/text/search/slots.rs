@@ -0,0 +1,134 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+use qt_core::QBox;
+use qt_core::SlotNoArgs;
+
+use rpfm_lib::integrations::log::*;
+
+use super::*;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+pub struct TextSearchViewSlots {
+    pub find: QBox<SlotNoArgs>,
+    pub find_next: QBox<SlotNoArgs>,
+    pub find_previous: QBox<SlotNoArgs>,
+    pub search: QBox<SlotNoArgs>,
+    pub prev_match: QBox<SlotNoArgs>,
+    pub next_match: QBox<SlotNoArgs>,
+    pub replace: QBox<SlotNoArgs>,
+    pub replace_all: QBox<SlotNoArgs>,
+    pub update_matches: QBox<SlotNoArgs>,
+    pub close: QBox<SlotNoArgs>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl TextSearchViewSlots {
+
+    pub unsafe fn new(view: &Arc<TextSearchView>) -> Self {
+
+        // Shortcut slots. These are triggered on all the open text views, so only the focused one must react.
+        let find = SlotNoArgs::new(&view.main_widget, clone!(
+            view => move || {
+                if view.has_focus() {
+                    info!("Triggering `Text Find` By Slot");
+                    view.show();
+                }
+            }
+        ));
+
+        let find_next = SlotNoArgs::new(&view.main_widget, clone!(
+            view => move || {
+                if view.has_focus() {
+                    info!("Triggering `Text Find Next` By Slot");
+                    view.find(false, false);
+                }
+            }
+        ));
+
+        let find_previous = SlotNoArgs::new(&view.main_widget, clone!(
+            view => move || {
+                if view.has_focus() {
+                    info!("Triggering `Text Find Previous` By Slot");
+                    view.find(true, false);
+                }
+            }
+        ));
+
+        let search = SlotNoArgs::new(&view.main_widget, clone!(
+            view => move || {
+                view.search();
+            }
+        ));
+
+        let prev_match = SlotNoArgs::new(&view.main_widget, clone!(
+            view => move || {
+                info!("Triggering `Text Prev Match` By Slot");
+                view.find(true, false);
+            }
+        ));
+
+        let next_match = SlotNoArgs::new(&view.main_widget, clone!(
+            view => move || {
+                info!("Triggering `Text Next Match` By Slot");
+                view.find(false, false);
+            }
+        ));
+
+        let replace = SlotNoArgs::new(&view.main_widget, clone!(
+            view => move || {
+                info!("Triggering `Text Replace Current` By Slot");
+                view.replace_current();
+            }
+        ));
+
+        let replace_all = SlotNoArgs::new(&view.main_widget, clone!(
+            view => move || {
+                info!("Triggering `Text Replace All` By Slot");
+                view.replace_all();
+            }
+        ));
+
+        // Keep the amount of matches updated while the file is being edited.
+        let update_matches = SlotNoArgs::new(&view.main_widget, clone!(
+            view => move || {
+                if view.main_widget.is_visible() {
+                    view.update_matches();
+                }
+            }
+        ));
+
+        let close = SlotNoArgs::new(&view.main_widget, clone!(
+            view => move || {
+                view.hide();
+            }
+        ));
+
+        // Return the slots, so we can keep them alive for the duration of the view.
+        Self {
+            find,
+            find_next,
+            find_previous,
+            search,
+            prev_match,
+            next_match,
+            replace,
+            replace_all,
+            update_matches,
+            close,
+        }
+    }
+}
@@ -32,6 +32,8 @@ use super::PackedFileTextView;
 /// This struct contains the slots of the view of an Text PackedFile.
 pub struct PackedFileTextViewSlots {
     pub modified: QBox<SlotNoArgs>,
+    pub update_markdown_preview: QBox<SlotNoArgs>,
+    pub validate_references: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -59,9 +61,24 @@ impl PackedFileTextViewSlots {
             }
         ));
 
+        let update_markdown_preview = SlotNoArgs::new(&view.editor, clone!(
+            view => move || {
+                view.update_markdown_preview();
+            }
+        ));
+
+        let validate_references = SlotNoArgs::new(&view.editor, clone!(
+            view => move || {
+                info!("Triggering `Validate References` By Slot");
+                view.validate_references();
+            }
+        ));
+
         // Return the slots, so we can keep them alive for the duration of the view.
         Self {
             modified,
+            update_markdown_preview,
+            validate_references,
         }
     }
 }
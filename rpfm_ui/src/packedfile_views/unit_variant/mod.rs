@@ -164,12 +164,10 @@ impl PackedFileUnitVariantView {
         Ok(())
     }
 
-    // This function saves a PackFileSettingsView into a PackFileSetting.
-    //pub unsafe fn save_view(&self) -> UnitVariant {
-    //    let unit_variant = UnitVariant::default();
-    //
-    //    unit_variant
-    //}
+    /// This function saves the view into an UnitVariant.
+    pub fn save_view(&self) -> Result<RFileDecoded> {
+        self.debug_view.save_view()
+    }
 
     /// This function tries to reload the current view with the provided data.
     pub unsafe fn reload_view(&self, data: &UnitVariant) {
@@ -25,4 +25,6 @@ use super::{PackedFileVideoView, slots::PackedFileVideoViewSlots};
 pub unsafe fn set_connections(ui: &Arc<PackedFileVideoView>, slots: &PackedFileVideoViewSlots) {
     ui.get_mut_ptr_convert_to_camv_button().released().connect(&slots.convert_to_camv);
     ui.get_mut_ptr_convert_to_ivf_button().released().connect(&slots.convert_to_ivf);
+    ui.get_mut_ptr_export_ivf_button().released().connect(&slots.export_ivf);
+    ui.get_mut_ptr_import_ivf_button().released().connect(&slots.import_ivf);
 }
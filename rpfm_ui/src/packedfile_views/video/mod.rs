@@ -53,6 +53,8 @@ pub struct PackedFileVideoView {
 
     convert_to_camv_button: QBox<QPushButton>,
     convert_to_ivf_button: QBox<QPushButton>,
+    export_ivf_button: QBox<QPushButton>,
+    import_ivf_button: QBox<QPushButton>,
     current_format: Arc<Mutex<SupportedFormats>>,
     path: Arc<RwLock<String>>,
 }
@@ -94,6 +96,8 @@ impl PackedFileVideoView {
 
         let convert_to_camv_button = QPushButton::from_q_string_q_widget(&qtr("convert_to_camv"), packed_file_view.get_mut_widget());
         let convert_to_ivf_button = QPushButton::from_q_string_q_widget(&qtr("convert_to_ivf"), packed_file_view.get_mut_widget());
+        let export_ivf_button = QPushButton::from_q_string_q_widget(&qtr("export_ivf"), packed_file_view.get_mut_widget());
+        let import_ivf_button = QPushButton::from_q_string_q_widget(&qtr("import_ivf"), packed_file_view.get_mut_widget());
 
         let instructions_label = QLabel::from_q_string_q_widget(&qtr("instructions_ca_vp8"), packed_file_view.get_mut_widget());
 
@@ -102,6 +106,8 @@ impl PackedFileVideoView {
 
         layout.add_widget_5a(&convert_to_camv_button, 0, 0, 1, 1);
         layout.add_widget_5a(&convert_to_ivf_button, 0, 1, 1, 1);
+        layout.add_widget_5a(&export_ivf_button, 1, 0, 1, 1);
+        layout.add_widget_5a(&import_ivf_button, 1, 1, 1, 1);
 
         layout.add_widget_5a(&format_label, 2, 0, 1, 1);
         layout.add_widget_5a(&version_label, 3, 0, 1, 1);
@@ -137,6 +143,8 @@ impl PackedFileVideoView {
             framerate_data_label,
             convert_to_camv_button,
             convert_to_ivf_button,
+            export_ivf_button,
+            import_ivf_button,
             current_format: Arc::new(Mutex::new(*data.format())),
             path: packed_file_view.get_path_raw()
         });
@@ -217,4 +225,14 @@ impl PackedFileVideoView {
     pub fn get_mut_ptr_convert_to_ivf_button(&self) -> &QBox<QPushButton> {
         &self.convert_to_ivf_button
     }
+
+    /// This function returns a pointer to the `Export as IVF` button.
+    pub fn get_mut_ptr_export_ivf_button(&self) -> &QBox<QPushButton> {
+        &self.export_ivf_button
+    }
+
+    /// This function returns a pointer to the `Import from IVF` button.
+    pub fn get_mut_ptr_import_ivf_button(&self) -> &QBox<QPushButton> {
+        &self.import_ivf_button
+    }
 }
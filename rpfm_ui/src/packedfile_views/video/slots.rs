@@ -12,20 +12,32 @@
 Module with the slots for CA_VP8 Views.
 !*/
 
+use qt_widgets::q_file_dialog::AcceptMode;
+use qt_widgets::QFileDialog;
+
 use qt_core::QBox;
 use qt_core::QString;
 use qt_core::SlotNoArgs;
 
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
 
-use rpfm_lib::files::video::SupportedFormats;
+use rpfm_lib::files::{ContainerPath, video::SupportedFormats};
 
 use crate::app_ui::AppUI;
+use crate::CENTRAL_COMMAND;
+use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICATION_ERROR};
+use crate::locale::{qtr, tr};
+use crate::pack_tree::{PackTree, TreeViewOperation};
 use crate::packedfile_views::DataSource;
 use crate::packedfile_views::video::PackedFileVideoView;
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::UI_STATE;
+use crate::utils::show_dialog;
+
+/// Filter used by the file dialogs when exporting/importing IVF files.
+const IVF_FILTER: &str = "IVF Videos (*.ivf)";
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -35,6 +47,8 @@ use crate::UI_STATE;
 pub struct PackedFileVideoViewSlots {
     pub convert_to_camv: QBox<SlotNoArgs>,
     pub convert_to_ivf: QBox<SlotNoArgs>,
+    pub export_ivf: QBox<SlotNoArgs>,
+    pub import_ivf: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -81,10 +95,68 @@ impl PackedFileVideoViewSlots {
             }
         ));
 
+        // Slot to export the video as a standard IVF file.
+        let export_ivf = SlotNoArgs::new(&view.format_data_label, clone!(
+            view => move || {
+                let file_dialog = QFileDialog::from_q_widget_q_string(&view.format_data_label, &qtr("export_ivf_title"));
+                file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+                file_dialog.set_confirm_overwrite(true);
+                file_dialog.set_name_filter(&QString::from_std_str(IVF_FILTER));
+                file_dialog.set_default_suffix(&QString::from_std_str("ivf"));
+
+                if file_dialog.exec() == 1 {
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                    let receiver = CENTRAL_COMMAND.send_background(Command::ExportCaVp8(view.path.read().unwrap().to_owned(), path));
+                    let response = CentralCommand::recv(&receiver);
+                    match response {
+                        Response::Success => show_dialog(&view.format_data_label, tr("export_ivf_success"), true),
+                        Response::Error(error) => show_dialog(&view.format_data_label, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+            }
+        ));
+
+        // Slot to replace the video with an IVF file from disk.
+        let import_ivf = SlotNoArgs::new(&view.format_data_label, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            view => move || {
+                let file_dialog = QFileDialog::from_q_widget_q_string(&view.format_data_label, &qtr("import_ivf_title"));
+                file_dialog.set_name_filter(&QString::from_std_str(IVF_FILTER));
+
+                if file_dialog.exec() == 1 {
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                    let internal_path = view.path.read().unwrap().to_owned();
+                    let receiver = CENTRAL_COMMAND.send_background(Command::ImportCaVp8(internal_path.to_owned(), path));
+                    let response = CentralCommand::recv(&receiver);
+                    match response {
+                        Response::Success => {
+                            let paths = vec![ContainerPath::File(internal_path.to_owned())];
+                            pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Modify(paths.to_vec()), DataSource::PackFile);
+                            pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths), DataSource::PackFile);
+                            UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
+
+                            // Reload the view so it shows the data of the imported video.
+                            if let Some(packed_file_view) = UI_STATE.set_open_packedfiles().iter_mut().find(|x| *x.get_ref_path() == internal_path && x.get_data_source() == DataSource::PackFile) {
+                                if let Err(error) = packed_file_view.reload(&internal_path, &pack_file_contents_ui) {
+                                    show_dialog(&view.format_data_label, error, false);
+                                }
+                            }
+                        }
+                        Response::Error(error) => show_dialog(&view.format_data_label, error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+            }
+        ));
+
         // Return the slots, so we can keep them alive for the duration of the view.
         Self {
             convert_to_camv,
             convert_to_ivf,
+            export_ivf,
+            import_ivf,
         }
     }
 }
@@ -40,13 +40,26 @@ pub unsafe fn set_connections(ui: &PackFileContentsUI, slots: &PackFileContentsS
     ui.packfile_contents_tree_view.custom_context_menu_requested().connect(&slots.contextual_menu);
     ui.packfile_contents_tree_view.selection_model().selection_changed().connect(&slots.contextual_menu_enabler);
     ui.packfile_contents_tree_view_context_menu.about_to_show().connect(&slots.contextual_menu_enabler);
+    ui.context_menu_label.about_to_show().connect(&slots.contextual_menu_label);
+    ui.filter_label_menu.about_to_show().connect(&slots.filter_label_menu);
 
     ui.context_menu_add_file.triggered().connect(&slots.contextual_menu_add_file);
     ui.context_menu_add_folder.triggered().connect(&slots.contextual_menu_add_folder);
     ui.context_menu_add_from_packfile.triggered().connect(&slots.contextual_menu_add_from_packfile);
+    ui.context_menu_add_from_archive.triggered().connect(&slots.contextual_menu_add_from_archive);
+    ui.context_menu_open_pack_in_side_pane.triggered().connect(&slots.contextual_menu_open_pack_in_side_pane);
+    ui.context_menu_paste_from_side_pane.triggered().connect(&slots.contextual_menu_paste_from_side_pane);
+    ui.extra_pack_pane_close_button.released().connect(&slots.close_extra_pack_pane);
     ui.context_menu_delete.triggered().connect(&slots.contextual_menu_delete);
+    ui.context_menu_undo.triggered().connect(&slots.contextual_menu_undo);
+    ui.context_menu_redo.triggered().connect(&slots.contextual_menu_redo);
     ui.context_menu_extract.triggered().connect(&slots.contextual_menu_extract);
+    ui.context_menu_cut.triggered().connect(&slots.contextual_menu_cut);
+    ui.context_menu_copy.triggered().connect(&slots.contextual_menu_copy);
+    ui.context_menu_paste.triggered().connect(&slots.contextual_menu_paste);
+    ui.context_menu_duplicate_table.triggered().connect(&slots.contextual_menu_duplicate_table);
     ui.context_menu_rename.triggered().connect(&slots.contextual_menu_rename);
+    ui.context_menu_rename_by_pattern.triggered().connect(&slots.contextual_menu_rename_by_pattern);
     ui.context_menu_copy_path.triggered().connect(&slots.contextual_menu_copy_path);
 
     ui.context_menu_new_folder.triggered().connect(&slots.contextual_menu_new_folder);
@@ -54,10 +67,12 @@ pub unsafe fn set_connections(ui: &PackFileContentsUI, slots: &PackFileContentsS
     ui.context_menu_new_packed_file_db.triggered().connect(&slots.contextual_menu_new_packed_file_db);
     ui.context_menu_new_packed_file_loc.triggered().connect(&slots.contextual_menu_new_packed_file_loc);
     ui.context_menu_new_packed_file_text.triggered().connect(&slots.contextual_menu_new_packed_file_text);
+    ui.context_menu_new_from_template.triggered().connect(&slots.contextual_menu_new_from_template);
     ui.context_menu_new_queek_packed_file.triggered().connect(&slots.contextual_menu_new_queek_packed_file);
 
     ui.context_menu_open_decoder.triggered().connect(&slots.contextual_menu_open_decoder);
     ui.context_menu_open_dependency_manager.triggered().connect(&slots.contextual_menu_open_dependency_manager);
+    ui.context_menu_open_dependencies_editor.triggered().connect(&slots.contextual_menu_open_dependencies_editor);
     ui.context_menu_open_containing_folder.triggered().connect(&slots.contextual_menu_open_containing_folder);
     ui.context_menu_open_with_external_program.triggered().connect(&slots.contextual_menu_open_in_external_program);
     ui.context_menu_open_packfile_settings.triggered().connect(&slots.contextual_menu_open_packfile_settings);
@@ -69,6 +84,7 @@ pub unsafe fn set_connections(ui: &PackFileContentsUI, slots: &PackFileContentsS
 
     ui.packfile_contents_tree_view_expand_all.triggered().connect(&slots.packfile_contents_tree_view_expand_all);
     ui.packfile_contents_tree_view_collapse_all.triggered().connect(&slots.packfile_contents_tree_view_collapse_all);
+    ui.packfile_contents_tree_view_drop_notifier.text_changed().connect(&slots.packfile_contents_tree_view_drop_files);
 
     // Not yet working.
     //ui.packfile_contents_tree_view.expanded().connect(&slots.packfile_contents_resize);
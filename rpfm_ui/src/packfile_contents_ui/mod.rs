@@ -14,45 +14,68 @@ Module with all the code related to the main `PackFileContentsUI`.
 
 use qt_widgets::QAction;
 use qt_widgets::QCheckBox;
+use qt_widgets::QComboBox;
 use qt_widgets::QDialog;
 use qt_widgets::{q_dialog_button_box::StandardButton, QDialogButtonBox};
 use qt_widgets::QDockWidget;
+use qt_widgets::QGridLayout;
 use qt_widgets::QFileDialog;
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
 use qt_widgets::QMenu;
+use qt_widgets::{QMessageBox, q_message_box};
+use qt_widgets::QPushButton;
+use qt_widgets::QSplitter;
+use qt_widgets::QStackedWidget;
 use qt_widgets::QToolButton;
 use qt_widgets::QTreeView;
 use qt_widgets::QWidget;
+use qt_widgets::q_abstract_item_view::DragDropMode;
 
+use qt_gui::QColor;
+use qt_gui::QIcon;
+use qt_gui::QPixmap;
 use qt_gui::QStandardItemModel;
 
 use qt_core::QBox;
 use qt_core::CaseSensitivity;
 use qt_core::DockWidgetArea;
+use qt_core::DropAction;
+use qt_core::ItemDataRole;
+use qt_core::Orientation;
 use qt_core::QObject;
 use qt_core::QPtr;
 use qt_core::QRegExp;
 use qt_core::QSortFilterProxyModel;
 use qt_core::QString;
 use qt_core::QTimer;
+use qt_core::SlotOfBool;
+use qt_core::SlotOfQString;
 
-use anyhow::Result;
+use cpp_core::CppBox;
+
+use anyhow::{anyhow, Result};
 use getset::Getters;
 
 use std::cmp::Ordering;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+use rpfm_lib::files::{ContainerPath, ExtractionFormat, pack::RESERVED_NAME_EXTRA_PACKFILE};
+use rpfm_lib::utils::files_from_subdir;
 
-use rpfm_lib::files::ContainerPath;
+use rpfm_extensions::template::Template;
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::ffi::*;
-use crate::locale::qtr;
+use crate::locale::{qtr, qtre, tr, tre};
 use crate::packedfile_views::DataSource;
-use crate::pack_tree::{PackTree, TreeViewOperation};
+use crate::packedfile_views::packfile::PackFileExtraView;
+use crate::pack_tree::{get_color_correct, get_color_wrong, ITEM_LABEL, PackTree, TreeViewOperation};
 use crate::settings_ui::backend::*;
 use crate::utils::*;
 use crate::ui_state::OperationalMode;
@@ -85,11 +108,27 @@ pub struct PackFileContentsUI {
     packfile_contents_tree_view: QPtr<QTreeView>,
     packfile_contents_tree_model_filter: QBox<QSortFilterProxyModel>,
     packfile_contents_tree_model: QBox<QStandardItemModel>,
+    packfile_contents_tree_view_drop_notifier: QPtr<QLineEdit>,
     filter_line_edit: QPtr<QLineEdit>,
     filter_autoexpand_matches_button: QPtr<QToolButton>,
     filter_case_sensitive_button: QPtr<QToolButton>,
+    filter_label_button: QPtr<QToolButton>,
+    filter_label_menu: QBox<QMenu>,
+    filter_label: Arc<RwLock<Option<String>>>,
     filter_timer_delayed_updates: QBox<QTimer>,
 
+    extra_pack_pane_widget: QBox<QWidget>,
+    extra_pack_pane_label: QBox<QLabel>,
+    extra_pack_pane_close_button: QBox<QToolButton>,
+    extra_pack_pane_contents: QBox<QWidget>,
+    extra_pack_pane: Arc<RwLock<Option<Arc<PackFileExtraView>>>>,
+
+    /// Paths copied from an extra PackFile, with the path of the PackFile they were copied from.
+    extra_pack_clipboard: Arc<RwLock<Option<(PathBuf, Vec<ContainerPath>)>>>,
+
+    /// Paths cut or copied within the open PackFile, and if they were cut.
+    files_clipboard: Arc<RwLock<Option<(Vec<ContainerPath>, bool)>>>,
+
     //-------------------------------------------------------------------------------//
     // Contextual menu for the PackFile Contents TreeView.
     //-------------------------------------------------------------------------------//
@@ -97,18 +136,31 @@ pub struct PackFileContentsUI {
     context_menu_add_file: QPtr<QAction>,
     context_menu_add_folder: QPtr<QAction>,
     context_menu_add_from_packfile: QPtr<QAction>,
+    context_menu_add_from_archive: QPtr<QAction>,
+    context_menu_open_pack_in_side_pane: QPtr<QAction>,
+    context_menu_paste_from_side_pane: QPtr<QAction>,
     context_menu_new_folder: QPtr<QAction>,
     context_menu_new_packed_file_anim_pack: QPtr<QAction>,
     context_menu_new_packed_file_db: QPtr<QAction>,
     context_menu_new_packed_file_loc: QPtr<QAction>,
     context_menu_new_packed_file_text: QPtr<QAction>,
+    context_menu_new_from_template: QPtr<QAction>,
     context_menu_new_queek_packed_file: QPtr<QAction>,
+    context_menu_cut: QPtr<QAction>,
+    context_menu_copy: QPtr<QAction>,
+    context_menu_paste: QPtr<QAction>,
+    context_menu_duplicate_table: QPtr<QAction>,
     context_menu_rename: QPtr<QAction>,
+    context_menu_rename_by_pattern: QPtr<QAction>,
     context_menu_delete: QPtr<QAction>,
+    context_menu_undo: QPtr<QAction>,
+    context_menu_redo: QPtr<QAction>,
     context_menu_extract: QPtr<QAction>,
     context_menu_copy_path: QPtr<QAction>,
+    context_menu_label: QPtr<QMenu>,
     context_menu_open_decoder: QPtr<QAction>,
     context_menu_open_dependency_manager: QPtr<QAction>,
+    context_menu_open_dependencies_editor: QPtr<QAction>,
     context_menu_open_containing_folder: QPtr<QAction>,
     context_menu_open_packfile_settings: QPtr<QAction>,
     context_menu_open_with_external_program: QPtr<QAction>,
@@ -144,6 +196,7 @@ impl PackFileContentsUI {
         let filter_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "filter_line_edit")?;
         let filter_autoexpand_matches_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_autoexpand_matches_button")?;
         let filter_case_sensitive_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_case_sensitive_button")?;
+        let filter_label_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_label_button")?;
 
         //-----------------------------------------------//
         // `PackFile Contents` DockWidget.
@@ -160,6 +213,13 @@ impl PackFileContentsUI {
         packfile_contents_tree_model.set_parent(&packfile_contents_tree_view);
         packfile_contents_tree_view.set_model(&packfile_contents_tree_model_filter);
 
+        // Allow dropping files and folders from outside the program into the `TreeView`.
+        let packfile_contents_tree_view_drop_notifier = QPtr::new(enable_external_drops_safe(&packfile_contents_tree_model.as_ptr(), &packfile_contents_tree_view.static_upcast::<QWidget>().as_ptr()));
+        packfile_contents_tree_view.set_accept_drops(true);
+        packfile_contents_tree_view.set_drop_indicator_shown(true);
+        packfile_contents_tree_view.set_drag_drop_mode(DragDropMode::DropOnly);
+        packfile_contents_tree_view.set_default_drop_action(DropAction::CopyAction);
+
         // Apply the view's delegate.
         new_tree_item_delegate_safe(&packfile_contents_tree_view.static_upcast::<QObject>().as_ptr(), true);
 
@@ -180,6 +240,32 @@ impl PackFileContentsUI {
         filter_timer_delayed_updates.set_single_shot(true);
         filter_line_edit.set_placeholder_text(&qtr("packedfile_filter"));
 
+        // Put the TreeView in a splitter, so we can open another Pack in a read-only pane next to it.
+        let inner_layout: QPtr<QGridLayout> = packfile_contents_dock_inner_widget.layout().static_downcast();
+        let extra_pack_splitter = QSplitter::from_orientation_q_widget(Orientation::Horizontal, &packfile_contents_dock_inner_widget);
+        inner_layout.remove_widget(&packfile_contents_tree_view);
+        inner_layout.add_widget_5a(&extra_pack_splitter, 0, 0, 1, 4);
+        extra_pack_splitter.add_widget(&packfile_contents_tree_view);
+
+        let extra_pack_pane_widget = QWidget::new_1a(&extra_pack_splitter);
+        let extra_pack_pane_layout = create_grid_layout(extra_pack_pane_widget.static_upcast());
+        let extra_pack_pane_label = QLabel::from_q_widget(&extra_pack_pane_widget);
+        let extra_pack_pane_close_button = QToolButton::new_1a(&extra_pack_pane_widget);
+        let extra_pack_pane_contents = QWidget::new_1a(&extra_pack_pane_widget);
+        create_grid_layout(extra_pack_pane_contents.static_upcast());
+        extra_pack_pane_close_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("window-close")));
+        extra_pack_pane_close_button.set_tool_tip(&qtr("extra_pack_pane_close"));
+        extra_pack_pane_layout.add_widget_5a(&extra_pack_pane_label, 0, 0, 1, 1);
+        extra_pack_pane_layout.add_widget_5a(&extra_pack_pane_close_button, 0, 1, 1, 1);
+        extra_pack_pane_layout.add_widget_5a(&extra_pack_pane_contents, 1, 0, 1, 2);
+        extra_pack_splitter.add_widget(&extra_pack_pane_widget);
+        extra_pack_pane_widget.set_visible(false);
+
+        // The label filter menu is filled when shown, as the available labels depend on the open PackFile.
+        let filter_label_menu = QMenu::from_q_widget(&filter_label_button);
+        filter_label_button.set_menu(&filter_label_menu);
+        filter_label_button.set_tool_tip(&qtr("packedfile_filter_label"));
+
         //-------------------------------------------------------------------------------//
         // Contextual menu for the PackFile Contents TreeView.
         //-------------------------------------------------------------------------------//
@@ -193,18 +279,31 @@ impl PackFileContentsUI {
         let context_menu_add_file = add_action_to_menu(&menu_add.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "add_file", "context_menu_add_file", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_add_folder = add_action_to_menu(&menu_add.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "add_folder", "context_menu_add_folder", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_add_from_packfile = add_action_to_menu(&menu_add.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "add_from_pack", "context_menu_add_from_packfile", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_add_from_archive = add_action_to_menu(&menu_add.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "add_from_archive", "context_menu_add_from_archive", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_open_pack_in_side_pane = add_action_to_menu(&menu_add.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "open_pack_in_side_pane", "context_menu_open_pack_in_side_pane", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_paste_from_side_pane = add_action_to_menu(&menu_add.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "paste_from_side_pane", "context_menu_paste_from_side_pane", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_new_folder = add_action_to_menu(&menu_create.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "new_folder", "context_menu_new_folder", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_new_packed_file_anim_pack = add_action_to_menu(&menu_create.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "new_animpack", "context_menu_new_packed_file_anim_pack", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_new_packed_file_db = add_action_to_menu(&menu_create.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "new_db", "context_menu_new_packed_file_db", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_new_packed_file_loc = add_action_to_menu(&menu_create.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "new_loc", "context_menu_new_packed_file_loc", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_new_packed_file_text = add_action_to_menu(&menu_create.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "new_text", "context_menu_new_packed_file_text", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_new_from_template = add_action_to_menu(&menu_create.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "new_from_template", "context_menu_new_from_template", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_new_queek_packed_file = add_action_to_menu(&menu_create.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "new_quick_file", "context_menu_new_queek_packed_file", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_cut = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "cut", "context_menu_cut", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_copy = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "copy", "context_menu_copy", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_paste = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "paste", "context_menu_paste", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_duplicate_table = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "duplicate_table", "context_menu_duplicate_table", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_rename = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "rename", "context_menu_move", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_rename_by_pattern = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "rename_by_pattern", "context_menu_rename_by_pattern", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_delete = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "delete", "context_menu_delete", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_undo = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "undo", "context_menu_undo", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_redo = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "redo", "context_menu_redo", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_extract = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "extract", "context_menu_extract", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_copy_path = add_action_to_menu(&packfile_contents_tree_view_context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "copy_path", "context_menu_copy_path", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_label = packfile_contents_tree_view_context_menu.add_menu_q_string(&qtr("context_menu_label"));
         let context_menu_open_decoder = add_action_to_menu(&menu_open.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "open_in_decoder", "context_menu_open_decoder", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_open_dependency_manager = add_action_to_menu(&menu_open.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "open_dependency_manager", "context_menu_open_dependency_manager", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_open_dependencies_editor = add_action_to_menu(&menu_open.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "open_dependencies_editor", "context_menu_open_dependencies_editor", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_open_containing_folder = add_action_to_menu(&menu_open.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "open_containing_folder", "context_menu_open_containing_folder", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_open_packfile_settings = add_action_to_menu(&menu_open.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "open_pack_settings", "context_menu_open_packfile_settings", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_open_with_external_program = add_action_to_menu(&menu_open.static_upcast(), app_ui.shortcuts().as_ref(), "pack_tree_context_menu", "open_in_external_program", "context_menu_open_with_external_program", Some(packfile_contents_tree_view.static_upcast::<qt_widgets::QWidget>()));
@@ -218,6 +317,7 @@ impl PackFileContentsUI {
 
         // Configure the `Contextual Menu` for the `PackFile` TreeView.
         packfile_contents_tree_view_context_menu.insert_separator(&menu_open.menu_action());
+        packfile_contents_tree_view_context_menu.insert_separator(&context_menu_cut);
         packfile_contents_tree_view_context_menu.insert_separator(&context_menu_rename);
         packfile_contents_tree_view_context_menu.insert_separator(&context_menu_merge_tables);
 
@@ -225,18 +325,28 @@ impl PackFileContentsUI {
         context_menu_add_file.set_enabled(false);
         context_menu_add_folder.set_enabled(false);
         context_menu_add_from_packfile.set_enabled(false);
+        context_menu_add_from_archive.set_enabled(false);
+        context_menu_open_pack_in_side_pane.set_enabled(false);
+        context_menu_paste_from_side_pane.set_enabled(false);
         context_menu_new_folder.set_enabled(false);
         context_menu_new_packed_file_anim_pack.set_enabled(false);
         context_menu_new_packed_file_db.set_enabled(false);
         context_menu_new_packed_file_loc.set_enabled(false);
         context_menu_new_packed_file_text.set_enabled(false);
+        context_menu_new_from_template.set_enabled(false);
         context_menu_new_queek_packed_file.set_enabled(false);
         context_menu_delete.set_enabled(false);
+        context_menu_cut.set_enabled(false);
+        context_menu_copy.set_enabled(false);
+        context_menu_paste.set_enabled(false);
+        context_menu_duplicate_table.set_enabled(false);
         context_menu_rename.set_enabled(false);
+        context_menu_rename_by_pattern.set_enabled(false);
         context_menu_extract.set_enabled(false);
         context_menu_copy_path.set_enabled(false);
         context_menu_open_decoder.set_enabled(false);
         context_menu_open_dependency_manager.set_enabled(false);
+        context_menu_open_dependencies_editor.set_enabled(false);
         context_menu_open_containing_folder.set_enabled(false);
         context_menu_open_packfile_settings.set_enabled(false);
         context_menu_open_with_external_program.set_enabled(false);
@@ -252,11 +362,23 @@ impl PackFileContentsUI {
             packfile_contents_tree_view,
             packfile_contents_tree_model_filter,
             packfile_contents_tree_model,
+            packfile_contents_tree_view_drop_notifier,
             filter_line_edit,
             filter_autoexpand_matches_button,
             filter_case_sensitive_button,
+            filter_label_button,
+            filter_label_menu,
+            filter_label: Arc::new(RwLock::new(None)),
             filter_timer_delayed_updates,
 
+            extra_pack_pane_widget,
+            extra_pack_pane_label,
+            extra_pack_pane_close_button,
+            extra_pack_pane_contents,
+            extra_pack_pane: Arc::new(RwLock::new(None)),
+            extra_pack_clipboard: Arc::new(RwLock::new(None)),
+            files_clipboard: Arc::new(RwLock::new(None)),
+
             //-------------------------------------------------------------------------------//
             // Contextual menu for the PackFile Contents TreeView.
             //-------------------------------------------------------------------------------//
@@ -266,21 +388,34 @@ impl PackFileContentsUI {
             context_menu_add_file,
             context_menu_add_folder,
             context_menu_add_from_packfile,
+            context_menu_add_from_archive,
+            context_menu_open_pack_in_side_pane,
+            context_menu_paste_from_side_pane,
 
             context_menu_new_folder,
             context_menu_new_packed_file_anim_pack,
             context_menu_new_packed_file_db,
             context_menu_new_packed_file_loc,
             context_menu_new_packed_file_text,
+            context_menu_new_from_template,
             context_menu_new_queek_packed_file,
 
+            context_menu_cut,
+            context_menu_copy,
+            context_menu_paste,
+            context_menu_duplicate_table,
             context_menu_rename,
+            context_menu_rename_by_pattern,
             context_menu_delete,
+            context_menu_undo,
+            context_menu_redo,
             context_menu_extract,
             context_menu_copy_path,
+            context_menu_label,
 
             context_menu_open_decoder,
             context_menu_open_dependency_manager,
+            context_menu_open_dependencies_editor,
             context_menu_open_containing_folder,
             context_menu_open_packfile_settings,
             context_menu_open_with_external_program,
@@ -352,16 +487,452 @@ impl PackFileContentsUI {
         }
     }
 
+    /// This function is a helper to add the contents of an archive to the UI, keeping the UI updated.
+    pub unsafe fn add_files_from_archive(
+        app_ui: &Rc<AppUI>,
+        pack_file_contents_ui: &Rc<Self>,
+        archive_path: &Path,
+        base_path: &str,
+    ) {
+        let window_was_disabled = !app_ui.main_window().is_enabled();
+        if !window_was_disabled {
+            app_ui.toggle_main_window(false);
+        }
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::AddFilesFromArchive(archive_path.to_path_buf(), base_path.to_owned(), true));
+        let response1 = CentralCommand::recv(&receiver);
+        let response2 = CentralCommand::recv(&receiver);
+        match response1 {
+            Response::VecContainerPath(paths) => if !paths.is_empty() {
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths.to_vec()), DataSource::PackFile);
+
+                UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+
+                // Try to reload all open files which data we altered, and close those that failed.
+                let failed_paths = paths.iter().filter_map(|path| {
+                    let path = path.path_raw();
+                    if let Some(packed_file_view) = UI_STATE.set_open_packedfiles().iter_mut().find(|x| *x.get_ref_path() == *path && x.get_data_source() == DataSource::PackFile) {
+                        if packed_file_view.reload(path, pack_file_contents_ui).is_err() {
+                            Some(path.to_owned())
+                        } else { None }
+                    } else { None }
+                }).collect::<Vec<String>>();
+
+                for path in &failed_paths {
+                    let _ = AppUI::purge_that_one_specifically(app_ui, pack_file_contents_ui, path, DataSource::PackFile, false);
+                }
+            }
+
+            Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response1),
+        }
+
+        match response2 {
+            Response::Success => {},
+            Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response2),
+        }
+
+        // Re-enable the Main Window.
+        if !window_was_disabled {
+            app_ui.toggle_main_window(true);
+        }
+    }
+
+    /// This function is a helper to add files from an extra PackFile to the UI, keeping the UI updated.
+    ///
+    /// If any of the files already exists in our PackFile, the user is asked once for the entire batch
+    /// whether to overwrite them, skip them, or cancel the operation.
+    pub unsafe fn add_files_from_extra_pack(
+        app_ui: &Rc<AppUI>,
+        pack_file_contents_ui: &Rc<Self>,
+        pack_file_path: &Path,
+        paths: &[ContainerPath],
+    ) {
+        if paths.is_empty() {
+            return;
+        }
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetPackFileExtraConflicts((pack_file_path.to_path_buf(), paths.to_vec())));
+        let response = CentralCommand::recv(&receiver);
+        let conflicts = match response {
+            Response::VecContainerPath(conflicts) => conflicts,
+            Response::Error(error) => return show_dialog(app_ui.main_window(), error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        let overwrite = if conflicts.is_empty() {
+            true
+        } else {
+            let conflicts = conflicts.iter()
+                .map(|path| format!("<li>{}</li>", path.path_raw()))
+                .collect::<Vec<_>>();
+
+            let message = tre("add_from_extra_pack_conflicts", &[&conflicts.join("")]);
+            let dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+                q_message_box::Icon::Warning,
+                &qtr("rpfm_title"),
+                &QString::from_std_str(message),
+                q_message_box::StandardButton::Yes | q_message_box::StandardButton::No | q_message_box::StandardButton::Cancel,
+                app_ui.main_window(),
+            );
+
+            dialog.button(q_message_box::StandardButton::Yes).set_text(&qtr("add_from_extra_pack_overwrite"));
+            dialog.button(q_message_box::StandardButton::No).set_text(&qtr("add_from_extra_pack_skip"));
+            dialog.set_default_button_standard_button(q_message_box::StandardButton::Cancel);
+
+            let result = dialog.exec();
+            if result == q_message_box::StandardButton::Yes.to_int() {
+                true
+            } else if result == q_message_box::StandardButton::No.to_int() {
+                false
+            } else {
+                return;
+            }
+        };
+
+        let window_was_disabled = !app_ui.main_window().is_enabled();
+        if !window_was_disabled {
+            app_ui.toggle_main_window(false);
+        }
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::AddPackedFilesFromPackFile((pack_file_path.to_path_buf(), paths.to_vec(), overwrite)));
+        let response = CentralCommand::recv(&receiver);
+        match response {
+            Response::VecContainerPath(paths) => if !paths.is_empty() {
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths.to_vec()), DataSource::PackFile);
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths.to_vec()), DataSource::PackFile);
+
+                UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+
+                // Try to reload all open files which data we altered, and close those that failed.
+                let failed_paths = paths.iter().filter_map(|path| {
+                    let path = path.path_raw();
+                    if let Some(packed_file_view) = UI_STATE.set_open_packedfiles().iter_mut().find(|x| *x.get_ref_path() == *path && x.get_data_source() == DataSource::PackFile) {
+                        if packed_file_view.reload(path, pack_file_contents_ui).is_err() {
+                            Some(path.to_owned())
+                        } else { None }
+                    } else { None }
+                }).collect::<Vec<String>>();
+
+                for path in &failed_paths {
+                    let _ = AppUI::purge_that_one_specifically(app_ui, pack_file_contents_ui, path, DataSource::PackFile, false);
+                }
+            }
+
+            Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        // Re-enable the Main Window.
+        if !window_was_disabled {
+            app_ui.toggle_main_window(true);
+        }
+    }
+
+    /// This function asks the user for a template and its parameters, and creates the files of the template in the open Pack.
+    ///
+    /// If any of the files already exists in the Pack, the user is asked if they should be overwritten before doing anything.
+    pub unsafe fn add_files_from_template(app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<Self>) {
+        let (name, values) = match Self::create_template_dialog(app_ui) {
+            Ok(Some(data)) => data,
+            Ok(None) => return,
+            Err(error) => return show_dialog(app_ui.main_window(), error, false),
+        };
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetTemplateCollisions(name.to_owned(), values.clone()));
+        let response = CentralCommand::recv(&receiver);
+        let collisions = match response {
+            Response::VecString(collisions) => collisions,
+            Response::Error(error) => return show_dialog(app_ui.main_window(), error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        if !collisions.is_empty() {
+            let collisions = collisions.iter()
+                .map(|path| format!("<li>{}</li>", path))
+                .collect::<Vec<_>>();
+
+            let message = tre("template_collisions", &[&collisions.join("")]);
+            let dialog = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+                q_message_box::Icon::Warning,
+                &qtr("rpfm_title"),
+                &QString::from_std_str(message),
+                q_message_box::StandardButton::Yes | q_message_box::StandardButton::Cancel,
+                app_ui.main_window(),
+            );
+
+            dialog.button(q_message_box::StandardButton::Yes).set_text(&qtr("add_from_extra_pack_overwrite"));
+            dialog.set_default_button_standard_button(q_message_box::StandardButton::Cancel);
+            if dialog.exec() != q_message_box::StandardButton::Yes.to_int() {
+                return;
+            }
+        }
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::ApplyTemplate(name, values));
+        let response = CentralCommand::recv(&receiver);
+        match response {
+            Response::VecContainerPath(paths) => if !paths.is_empty() {
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(paths.to_vec()), DataSource::PackFile);
+                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths.to_vec()), DataSource::PackFile);
+
+                UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+
+                // Try to reload all open files we overwrote, and close those that failed.
+                let failed_paths = paths.iter().filter_map(|path| {
+                    let path = path.path_raw();
+                    if let Some(packed_file_view) = UI_STATE.set_open_packedfiles().iter_mut().find(|x| *x.get_ref_path() == *path && x.get_data_source() == DataSource::PackFile) {
+                        if packed_file_view.reload(path, pack_file_contents_ui).is_err() {
+                            Some(path.to_owned())
+                        } else { None }
+                    } else { None }
+                }).collect::<Vec<String>>();
+
+                for path in &failed_paths {
+                    let _ = AppUI::purge_that_one_specifically(app_ui, pack_file_contents_ui, path, DataSource::PackFile, false);
+                }
+            }
+
+            Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function opens the provided PackFile in the read-only side pane, replacing the one already there, if any.
+    pub unsafe fn open_extra_pack_pane(
+        app_ui: &Rc<AppUI>,
+        pack_file_contents_ui: &Rc<Self>,
+        pack_file_path: PathBuf,
+    ) -> Result<()> {
+        Self::close_extra_pack_pane(pack_file_contents_ui);
+
+        let file_name = pack_file_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        let view = PackFileExtraView::new_widget(&pack_file_contents_ui.extra_pack_pane_contents, app_ui, pack_file_contents_ui, pack_file_path.to_path_buf())?;
+
+        pack_file_contents_ui.extra_pack_pane_label.set_text(&QString::from_std_str(file_name));
+        pack_file_contents_ui.extra_pack_pane_label.set_tool_tip(&QString::from_std_str(pack_file_path.to_string_lossy()));
+        pack_file_contents_ui.extra_pack_pane_widget.set_visible(true);
+        *pack_file_contents_ui.extra_pack_pane.write().unwrap() = Some(view);
+
+        Ok(())
+    }
+
+    /// This function closes the side pane, freeing its PackFile from memory unless a tab is still using it.
+    pub unsafe fn close_extra_pack_pane(pack_file_contents_ui: &Rc<Self>) {
+        if let Some(view) = pack_file_contents_ui.extra_pack_pane.write().unwrap().take() {
+            let pack_file_path = view.pack_file_path().read().unwrap().to_path_buf();
+            let tab_path = RESERVED_NAME_EXTRA_PACKFILE.to_owned() + "/" + &pack_file_path.to_string_lossy();
+            if !UI_STATE.get_open_packedfiles().iter().any(|x| *x.get_ref_path() == tab_path) {
+                let _ = CENTRAL_COMMAND.send_background(Command::RemovePackFileExtra(pack_file_path.to_path_buf()));
+
+                // Pasting from a Pack that's no longer in memory will fail, so forget about it.
+                let mut clipboard = pack_file_contents_ui.extra_pack_clipboard.write().unwrap();
+                if clipboard.as_ref().map(|(path, _)| *path == pack_file_path).unwrap_or(false) {
+                    *clipboard = None;
+                }
+            }
+
+            clear_layout(&pack_file_contents_ui.extra_pack_pane_contents.static_upcast());
+        }
+
+        pack_file_contents_ui.extra_pack_pane_label.clear();
+        pack_file_contents_ui.extra_pack_pane_widget.set_visible(false);
+    }
+
+    /// This function pastes the files and folders cut or copied within the open Pack into the provided folder.
+    ///
+    /// Cut files are moved, and the open views pointing to them are updated like when renaming them. Copied files are duplicated.
+    pub unsafe fn paste_files(app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<Self>, destination_folder: &str) {
+        let clipboard = pack_file_contents_ui.files_clipboard.read().unwrap().clone();
+        let (paths, is_cut) = match clipboard {
+            Some(clipboard) => clipboard,
+            None => return,
+        };
+
+        // Make sure the copies get the latest changes of the open files.
+        if let Err(error) = AppUI::back_to_back_end_all(app_ui, pack_file_contents_ui) {
+            return show_dialog(app_ui.main_window(), error, false);
+        }
+
+        let paths = ContainerPath::dedup(&paths);
+        let command = if is_cut {
+            Command::MoveFiles(paths.to_vec(), destination_folder.to_owned())
+        } else {
+            Command::CopyFiles(paths.to_vec(), destination_folder.to_owned())
+        };
+
+        let receiver = CENTRAL_COMMAND.send_background(command);
+        let response = CentralCommand::recv(&receiver);
+        match response {
+            Response::VecContainerPathContainerPath(pasted_items) => {
+                if pasted_items.is_empty() {
+                    return;
+                }
+
+                if is_cut {
+                    Self::update_open_views_after_rename(app_ui, &pasted_items);
+
+                    // Only remove the folders that were actually moved, as pasting into the same folder moves nothing.
+                    let folders_to_move = paths.into_iter()
+                        .filter(|path| match path {
+                            ContainerPath::Folder(folder) => {
+                                let prefix = format!("{}/", folder);
+                                pasted_items.iter().any(|(old_path, _)| old_path.path_raw().starts_with(&prefix))
+                            },
+                            ContainerPath::File(_) => false,
+                        })
+                        .collect::<Vec<_>>();
+
+                    pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Move(pasted_items, folders_to_move), DataSource::PackFile);
+
+                    // Cut files can only be pasted once.
+                    *pack_file_contents_ui.files_clipboard.write().unwrap() = None;
+                } else {
+                    let new_paths = pasted_items.into_iter().map(|(_, new_path)| new_path).collect::<Vec<_>>();
+                    pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(new_paths), DataSource::PackFile);
+                }
+
+                UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+            },
+            Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function adds the files and folders dropped from outside the program into the provided folder of the open Pack.
+    ///
+    /// Folders are added with all their contents, keeping their name. The MyMod's ignore list is respected, and
+    /// the user is asked before overwriting any file already in the Pack.
+    pub unsafe fn add_dropped_files(
+        app_ui: &Rc<AppUI>,
+        pack_file_contents_ui: &Rc<Self>,
+        folder: &str,
+        dropped_paths: &[PathBuf],
+    ) {
+
+        // If there is no Pack open, there's nothing to add the files to.
+        if pack_file_contents_ui.packfile_contents_tree_model.row_count_0a() == 0 {
+            return;
+        }
+
+        let mut paths = vec![];
+        let mut paths_in_container = vec![];
+        for dropped_path in dropped_paths {
+            let base_path = match dropped_path.file_name() {
+                Some(name) if folder.is_empty() => name.to_string_lossy().to_string(),
+                Some(name) => format!("{}/{}", folder, name.to_string_lossy()),
+                None => continue,
+            };
+
+            if dropped_path.is_dir() {
+                match files_from_subdir(dropped_path, true) {
+                    Ok(files) => for file in files {
+                        if let Ok(relative_path) = file.strip_prefix(dropped_path) {
+                            paths_in_container.push(ContainerPath::File(format!("{}/{}", base_path, relative_path.to_string_lossy().replace('\\', "/"))));
+                            paths.push(file);
+                        }
+                    },
+                    Err(error) => return show_dialog(app_ui.main_window(), error, false),
+                }
+            } else if dropped_path.is_file() {
+                paths_in_container.push(ContainerPath::File(base_path));
+                paths.push(dropped_path.to_path_buf());
+            }
+        }
+
+        // On MyMods, the files the MyMod is set to ignore on import are also ignored here.
+        let paths_to_ignore = if let OperationalMode::MyMod(ref game_folder_name, ref mod_name) = UI_STATE.get_operational_mode() {
+            let mut assets_folder = setting_path("mymods_base_path");
+            assets_folder.push(game_folder_name);
+            assets_folder.push(Path::new(&mod_name).file_stem().unwrap().to_string_lossy().as_ref());
+
+            let receiver = CENTRAL_COMMAND.send_background(Command::GetPackSettings);
+            let response = CentralCommand::recv(&receiver);
+            let settings = match response {
+                Response::PackSettings(settings) => settings,
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            };
+
+            settings.setting_text("import_files_to_ignore").map(|files_to_ignore| {
+                files_to_ignore.split('\n')
+                    .filter(|x| !x.is_empty())
+                    .map(|x| assets_folder.join(x))
+                    .collect::<Vec<PathBuf>>()
+            })
+        } else { None };
+
+        if let Some(ref paths_to_ignore) = paths_to_ignore {
+            let (paths_kept, paths_in_container_kept) = paths.into_iter()
+                .zip(paths_in_container.into_iter())
+                .filter(|(path, _)| !paths_to_ignore.iter().any(|x| path.starts_with(x)))
+                .unzip();
+            paths = paths_kept;
+            paths_in_container = paths_in_container_kept;
+        }
+
+        if paths.is_empty() {
+            return;
+        }
+
+        // Ask before overwriting files already in the Pack.
+        let conflicts = paths_in_container.iter()
+            .filter(|path| {
+                let receiver = CENTRAL_COMMAND.send_background(Command::PackedFileExists(path.path_raw().to_owned()));
+                let response = CentralCommand::recv(&receiver);
+                match response {
+                    Response::Bool(exists) => exists,
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
+            })
+            .map(|path| format!("<li>{}</li>", path.path_raw()))
+            .collect::<Vec<_>>();
+
+        if !conflicts.is_empty() {
+            let message = tre("add_files_overwrite_confirm", &[&conflicts.join("")]);
+            let accepted = QMessageBox::from_2_q_string_icon3_int_q_widget(
+                &qtr("rpfm_title"),
+                &QString::from_std_str(message),
+                q_message_box::Icon::Warning,
+                65536, // No
+                16384, // Yes
+                1, // By default, select yes.
+                app_ui.main_window(),
+            ).exec() == 3;
+
+            if !accepted {
+                return;
+            }
+        }
+
+        // Tsv files are imported as tables by the background thread, same as when adding them from the menu.
+        Self::add_files(app_ui, pack_file_contents_ui, &paths, &paths_in_container, paths_to_ignore);
+    }
+
     /// Function to filter the PackFile Contents TreeView.
     pub unsafe fn filter_files(pack_file_contents_ui: &Rc<Self>) {
 
-        // Set the pattern to search.
-        let pattern = QRegExp::new_1a(&pack_file_contents_ui.filter_line_edit.text());
+        // If we're filtering by label, we filter by the label of the items instead of by their name.
+        let pattern = match *pack_file_contents_ui.filter_label.read().unwrap() {
+            Some(ref label) => {
+                let pattern = QRegExp::new_1a(&QString::from_std_str(format!("^{}$", QRegExp::escape(&QString::from_std_str(label)).to_std_string())));
+                pattern.set_case_sensitivity(CaseSensitivity::CaseSensitive);
+                pack_file_contents_ui.packfile_contents_tree_model_filter.set_filter_role(ITEM_LABEL);
+                pattern
+            }
+            None => {
+
+                // Set the pattern to search.
+                let pattern = QRegExp::new_1a(&pack_file_contents_ui.filter_line_edit.text());
+
+                // Check if the filter should be "Case Sensitive".
+                let case_sensitive = pack_file_contents_ui.filter_case_sensitive_button.is_checked();
+                if case_sensitive { pattern.set_case_sensitivity(CaseSensitivity::CaseSensitive); }
+                else { pattern.set_case_sensitivity(CaseSensitivity::CaseInsensitive); }
 
-        // Check if the filter should be "Case Sensitive".
-        let case_sensitive = pack_file_contents_ui.filter_case_sensitive_button.is_checked();
-        if case_sensitive { pattern.set_case_sensitivity(CaseSensitivity::CaseSensitive); }
-        else { pattern.set_case_sensitivity(CaseSensitivity::CaseInsensitive); }
+                pack_file_contents_ui.packfile_contents_tree_model_filter.set_filter_role(ItemDataRole::DisplayRole.to_int());
+                pattern
+            }
+        };
 
         // Filter whatever it's in that column by the text we got.
         trigger_treeview_filter_safe(&pack_file_contents_ui.packfile_contents_tree_model_filter, &pattern.as_ptr());
@@ -372,6 +943,240 @@ impl PackFileContentsUI {
         }
     }
 
+    /// This function rebuilds the "Label" submenu of the contextual menu with the labels available in the open PackFile.
+    pub unsafe fn build_label_menu(app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<Self>) {
+        pack_file_contents_ui.context_menu_label.clear();
+
+        for (name, colour) in Self::path_label_definitions() {
+            let action = pack_file_contents_ui.context_menu_label.add_action_q_icon_q_string(&label_icon(&colour), &QString::from_std_str(&name));
+            let slot = SlotOfBool::new(&action, clone!(
+                app_ui,
+                pack_file_contents_ui,
+                name,
+                colour => move |_| {
+                Self::set_path_labels(&app_ui, &pack_file_contents_ui, Some((name.to_owned(), colour.to_owned())));
+            }));
+
+            action.triggered().connect(&slot);
+        }
+
+        pack_file_contents_ui.context_menu_label.add_separator();
+        let action = pack_file_contents_ui.context_menu_label.add_action_q_string(&qtr("context_menu_label_remove"));
+        let slot = SlotOfBool::new(&action, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+            Self::set_path_labels(&app_ui, &pack_file_contents_ui, None);
+        }));
+
+        action.triggered().connect(&slot);
+    }
+
+    /// This function rebuilds the menu of the label filter button with the labels available in the open PackFile.
+    pub unsafe fn build_label_filter_menu(pack_file_contents_ui: &Rc<Self>) {
+        pack_file_contents_ui.filter_label_menu.clear();
+        let current_label = pack_file_contents_ui.filter_label.read().unwrap().clone();
+
+        let action = pack_file_contents_ui.filter_label_menu.add_action_q_string(&qtr("packedfile_filter_label_none"));
+        action.set_checkable(true);
+        action.set_checked(current_label.is_none());
+
+        let slot = SlotOfBool::new(&action, clone!(
+            pack_file_contents_ui => move |_| {
+            Self::set_label_filter(&pack_file_contents_ui, None);
+        }));
+
+        action.triggered().connect(&slot);
+        pack_file_contents_ui.filter_label_menu.add_separator();
+
+        for (name, colour) in Self::path_label_definitions() {
+            let action = pack_file_contents_ui.filter_label_menu.add_action_q_icon_q_string(&label_icon(&colour), &QString::from_std_str(&name));
+            action.set_checkable(true);
+            action.set_checked(current_label.as_ref() == Some(&name));
+
+            let slot = SlotOfBool::new(&action, clone!(
+                pack_file_contents_ui,
+                name,
+                colour => move |_| {
+                Self::set_label_filter(&pack_file_contents_ui, Some((name.to_owned(), colour.to_owned())));
+            }));
+
+            action.triggered().connect(&slot);
+        }
+    }
+
+    /// This function sets the label of the selected files and folders. `None` removes their label.
+    ///
+    /// The label is passed as `(name, colour)`.
+    pub unsafe fn set_path_labels(app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<Self>, label: Option<(String, String)>) {
+        let paths = pack_file_contents_ui.packfile_contents_tree_view.get_item_types_from_selection(true)
+            .into_iter()
+            .filter(|path| !path.path_raw().is_empty())
+            .collect::<Vec<_>>();
+
+        if paths.is_empty() {
+            return;
+        }
+
+        for path in &paths {
+            let _ = CENTRAL_COMMAND.send_background(Command::SetPathLabel(path.clone(), label.as_ref().map(|(name, _)| name.to_owned())));
+        }
+
+        let labels = paths.into_iter().map(|path| (path, label.clone())).collect();
+        pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::UpdateLabels(labels), DataSource::PackFile);
+        UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+
+        // Relabelled items may no longer match the label filter.
+        if pack_file_contents_ui.filter_label.read().unwrap().is_some() {
+            Self::filter_files(pack_file_contents_ui);
+        }
+    }
+
+    /// This function sets the label to filter the TreeView by, as `(name, colour)`. `None` goes back to filtering by name.
+    pub unsafe fn set_label_filter(pack_file_contents_ui: &Rc<Self>, label: Option<(String, String)>) {
+        match label {
+            Some((name, colour)) => {
+                pack_file_contents_ui.filter_label_button.set_icon(&label_icon(&colour));
+                *pack_file_contents_ui.filter_label.write().unwrap() = Some(name);
+            }
+            None => {
+                pack_file_contents_ui.filter_label_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("tag")));
+                *pack_file_contents_ui.filter_label.write().unwrap() = None;
+            }
+        }
+
+        // Both filters cannot be used at the same time, so disable the name filter while filtering by label.
+        let is_name_filter = pack_file_contents_ui.filter_label.read().unwrap().is_none();
+        pack_file_contents_ui.filter_line_edit.set_enabled(is_name_filter);
+        pack_file_contents_ui.filter_case_sensitive_button.set_enabled(is_name_filter);
+
+        Self::filter_files(pack_file_contents_ui);
+    }
+
+    /// This function returns the labels available in the open PackFile, as `(name, colour)`.
+    unsafe fn path_label_definitions() -> Vec<(String, String)> {
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetPathLabels);
+        let response = CentralCommand::recv(&receiver);
+        match response {
+            Response::VecStringStringHashMapContainerPathString(definitions, _) => definitions,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function creates the "Generate Missing Loc Data" dialog.
+    ///
+    /// It returns the path of the target loc, the value template and if we should also generate keys already in the parent mods, or `None` if the dialog is canceled or closed.
+    pub unsafe fn create_missing_loc_data_dialog(app_ui: &Rc<AppUI>, selected_items: &[ContainerPath]) -> Option<(String, String, bool)> {
+
+        let dialog = QDialog::new_1a(app_ui.main_window());
+        dialog.set_window_title(&qtr("context_menu_generate_missing_loc_data"));
+        dialog.set_modal(true);
+        dialog.resize_2a(600, 20);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let loc_path_label = QLabel::from_q_string_q_widget(&qtr("missing_loc_data_target"), &dialog);
+        let loc_path_line_edit = QLineEdit::from_q_widget(&dialog);
+        let value_template_label = QLabel::from_q_string_q_widget(&qtr("missing_loc_data_template"), &dialog);
+        let value_template_line_edit = QLineEdit::from_q_widget(&dialog);
+        let include_parent_keys_checkbox = QCheckBox::from_q_string_q_widget(&qtr("missing_loc_data_include_parent_keys"), &dialog);
+        let accept_button = QPushButton::from_q_string_q_widget(&qtr("gen_loc_accept"), &dialog);
+
+        // If we have a loc selected, use it as target by default.
+        let loc_path = match selected_items {
+            [ContainerPath::File(path)] if path.to_lowercase().ends_with(".loc") => path.to_owned(),
+            _ => "text/db/missing_locs.loc".to_owned(),
+        };
+
+        loc_path_line_edit.set_text(&QString::from_std_str(loc_path));
+        value_template_line_edit.set_text(&QString::from_std_str("PLACEHOLDER: {key}"));
+
+        main_grid.add_widget_5a(&loc_path_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&loc_path_line_edit, 0, 1, 1, 1);
+        main_grid.add_widget_5a(&value_template_label, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&value_template_line_edit, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&include_parent_keys_checkbox, 2, 0, 1, 2);
+        main_grid.add_widget_5a(&accept_button, 3, 0, 1, 2);
+
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 {
+            let loc_path = loc_path_line_edit.text().to_std_string();
+            if loc_path.is_empty() { None }
+            else { Some((loc_path, value_template_line_edit.text().to_std_string(), include_parent_keys_checkbox.is_checked())) }
+        } else { None }
+    }
+
+    /// This function creates the "New from Template" dialog, with a form for the parameters of each template in the templates folder.
+    ///
+    /// It returns the name of the chosen template and the values of its parameters, or `None` if the dialog is canceled or closed.
+    pub unsafe fn create_template_dialog(app_ui: &Rc<AppUI>) -> Result<Option<(String, HashMap<String, String>)>> {
+        let templates = Template::templates(&templates_path()?)?;
+        if templates.is_empty() {
+            return Err(anyhow!(tr("template_none_found")));
+        }
+
+        let dialog = QDialog::new_1a(app_ui.main_window());
+        dialog.set_window_title(&qtr("context_menu_new_from_template"));
+        dialog.set_modal(true);
+        dialog.resize_2a(600, 20);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let template_label = QLabel::from_q_string_q_widget(&qtr("template_template"), &dialog);
+        let template_combobox = QComboBox::new_1a(&dialog);
+        let forms_stack = QStackedWidget::new_1a(&dialog);
+        let accept_button = QPushButton::from_q_string_q_widget(&qtr("gen_loc_accept"), &dialog);
+
+        // One page per template, so switching templates is just switching pages.
+        let mut forms = vec![];
+        for (key, template) in &templates {
+            template_combobox.add_item_q_string(&QString::from_std_str(template.name()));
+
+            let page = QWidget::new_1a(&forms_stack);
+            let page_grid = create_grid_layout(page.static_upcast());
+            let description_label = QLabel::from_q_string_q_widget(&QString::from_std_str(template.description()), &page);
+            description_label.set_word_wrap(true);
+            page_grid.add_widget_5a(&description_label, 0, 0, 1, 2);
+
+            let mut line_edits = vec![];
+            for (index, parameter) in template.parameters().iter().enumerate() {
+                let label = QLabel::from_q_string_q_widget(&QString::from_std_str(parameter.name()), &page);
+                let line_edit = QLineEdit::from_q_widget(&page);
+                label.set_tool_tip(&QString::from_std_str(parameter.description()));
+                line_edit.set_tool_tip(&QString::from_std_str(parameter.description()));
+                match parameter.default_value() {
+                    Some(default_value) => line_edit.set_text(&QString::from_std_str(default_value)),
+                    None => line_edit.set_placeholder_text(&qtr("template_parameter_required")),
+                }
+
+                page_grid.add_widget_5a(&label, index as i32 + 1, 0, 1, 1);
+                page_grid.add_widget_5a(&line_edit, index as i32 + 1, 1, 1, 1);
+                line_edits.push((parameter.key().to_owned(), line_edit));
+            }
+
+            page_grid.set_row_stretch(template.parameters().len() as i32 + 1, 10);
+            forms_stack.add_widget(&page);
+            forms.push((key.to_owned(), line_edits));
+        }
+
+        main_grid.add_widget_5a(&template_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&template_combobox, 0, 1, 1, 1);
+        main_grid.add_widget_5a(&forms_stack, 1, 0, 1, 2);
+        main_grid.add_widget_5a(&accept_button, 2, 0, 1, 2);
+
+        template_combobox.current_index_changed().connect(forms_stack.slot_set_current_index());
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 {
+            let (key, line_edits) = &forms[template_combobox.current_index() as usize];
+            let values = line_edits.iter()
+                .map(|(key, line_edit)| (key.to_owned(), line_edit.text().to_std_string()))
+                .collect();
+
+            Ok(Some((key.to_owned(), values)))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// This function creates the entire "Rename" dialog.
     ///
     ///It returns the new name of the Item, or `None` if the dialog is canceled or closed.
@@ -438,11 +1243,207 @@ impl PackFileContentsUI {
         )
     }
 
+    /// This function creates the "Rename by Pattern" dialog. It returns the pattern, the replacement, and if the pattern is case-insensitive.
+    pub unsafe fn create_rename_by_pattern_dialog(app_ui: &Rc<AppUI>) -> Option<(String, String, bool)> {
+        let dialog = QDialog::new_1a(app_ui.main_window());
+        dialog.set_window_title(&qtr("rename_by_pattern_title"));
+        dialog.set_modal(true);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let instructions_label = QLabel::from_q_string_q_widget(&qtr("rename_by_pattern_instructions"), &dialog);
+        let pattern_line_edit = QLineEdit::from_q_widget(&dialog);
+        let replacement_line_edit = QLineEdit::from_q_widget(&dialog);
+        let case_insensitive_checkbox = QCheckBox::from_q_string_q_widget(&qtr("rename_by_pattern_case_insensitive"), &dialog);
+        let accept_button = QPushButton::from_q_string_q_widget(&qtr("gen_loc_accept"), &dialog);
+
+        instructions_label.set_word_wrap(true);
+        pattern_line_edit.set_placeholder_text(&qtr("rename_by_pattern_pattern"));
+        replacement_line_edit.set_placeholder_text(&qtr("rename_by_pattern_replacement"));
+
+        main_grid.add_widget_5a(&instructions_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&pattern_line_edit, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&replacement_line_edit, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&case_insensitive_checkbox, 3, 0, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 4, 0, 1, 1);
+
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 {
+            let pattern = pattern_line_edit.text().to_std_string();
+            if !pattern.is_empty() {
+                Some((pattern, replacement_line_edit.text().to_std_string(), case_insensitive_checkbox.is_checked()))
+            } else { None }
+        } else { None }
+    }
+
+    /// This function creates the "Duplicate Table" dialog, checking the name of the copy while it's being written.
+    ///
+    /// It returns the name for the copy of the table, or `None` if the dialog is canceled or closed.
+    pub unsafe fn create_duplicate_table_dialog(app_ui: &Rc<AppUI>, source_path: &str) -> Option<String> {
+        let dialog = QDialog::new_1a(app_ui.main_window());
+        dialog.set_window_title(&qtr("duplicate_table_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(500, 20);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let instructions_label = QLabel::from_q_string_q_widget(&qtre("duplicate_table_instructions", &[source_path]), &dialog);
+        let name_line_edit = QLineEdit::from_q_widget(&dialog);
+        let new_path_label = QLabel::from_q_widget(&dialog);
+        let accept_button = QPushButton::from_q_string_q_widget(&qtr("gen_loc_accept"), &dialog);
+        instructions_label.set_word_wrap(true);
+        new_path_label.set_word_wrap(true);
+
+        main_grid.add_widget_5a(&instructions_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&name_line_edit, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&new_path_label, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 3, 0, 1, 1);
+
+        // Show where the copy will end up, or why it can't be there, as the name is written.
+        let source = ContainerPath::File(source_path.to_owned());
+        let name_line_edit_ptr = name_line_edit.as_ptr();
+        let new_path_label_ptr = new_path_label.as_ptr();
+        let accept_button_ptr = accept_button.as_ptr();
+        let check_name = SlotOfQString::new(&dialog, move |name| {
+            let (text, is_valid) = match source.duplicate_table_path(&name.to_std_string()) {
+                Ok(new_path) => (new_path, true),
+                Err(error) => (error.to_string(), false),
+            };
+
+            let color = if is_valid { get_color_correct() } else { get_color_wrong() };
+            new_path_label_ptr.set_text(&QString::from_std_str(text));
+            name_line_edit_ptr.set_style_sheet(&QString::from_std_str(format!("background-color: {}", color)));
+            accept_button_ptr.set_enabled(is_valid);
+        });
+
+        name_line_edit.text_changed().connect(&check_name);
+        accept_button.released().connect(dialog.slot_accept());
+
+        // Pre-fill it with a valid name, keeping the extension of Loc files at the end.
+        let file_name = source_path.rsplit('/').next().unwrap_or_default();
+        let default_name = match file_name.strip_suffix(".loc") {
+            Some(name) => format!("{}_copy.loc", name),
+            None => format!("{}_copy", file_name),
+        };
+        name_line_edit.set_text(&QString::from_std_str(default_name));
+
+        if dialog.exec() == 1 {
+            Some(name_line_edit.text().to_std_string())
+        } else { None }
+    }
+
+    /// This function creates the "Extraction Format" dialog. It returns the format chosen for the tables, remembering it for the next time.
+    pub unsafe fn create_extraction_format_dialog(app_ui: &Rc<AppUI>) -> Option<ExtractionFormat> {
+        let dialog = QDialog::new_1a(app_ui.main_window());
+        dialog.set_window_title(&qtr("extraction_format_title"));
+        dialog.set_modal(true);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let instructions_label = QLabel::from_q_string_q_widget(&qtr("extraction_format_instructions"), &dialog);
+        let format_combobox = QComboBox::new_1a(&dialog);
+        let accept_button = QPushButton::from_q_string_q_widget(&qtr("gen_loc_accept"), &dialog);
+
+        instructions_label.set_word_wrap(true);
+        format_combobox.add_item_q_string(&qtr("extraction_format_raw"));
+        format_combobox.add_item_q_string(&qtr("extraction_format_tsv"));
+        format_combobox.add_item_q_string(&qtr("extraction_format_ass_kit_xml"));
+        format_combobox.set_current_index(setting_int("extraction_format"));
+
+        main_grid.add_widget_5a(&instructions_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&format_combobox, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 2, 0, 1, 1);
+
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 {
+            let format = match format_combobox.current_index() {
+                1 => ExtractionFormat::Tsv,
+                2 => ExtractionFormat::AssKitXml,
+                _ => ExtractionFormat::Raw,
+            };
+
+            set_setting_int("extraction_format", format_combobox.current_index());
+            Some(format)
+        } else { None }
+    }
+
+    /// This function updates the paths of the open views of renamed files, so they keep pointing to their files.
+    /// This function undoes or redoes the last operation done over the structure of the open Pack, updating the TreeView and the open files.
+    pub unsafe fn undo_redo_container_op(app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<Self>, redo: bool) {
+        let command = if redo { Command::RedoContainerOp } else { Command::UndoContainerOp };
+        let receiver = CENTRAL_COMMAND.send_background(command);
+        let response = CentralCommand::recv(&receiver);
+        let changes = match response {
+            Response::OptionJournalChanges(Some(changes)) => changes,
+            Response::OptionJournalChanges(None) => return log_to_status_bar(&tr(if redo { "container_redo_nothing" } else { "container_undo_nothing" })),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        // Close the removed and replaced files, as their views no longer match what's in the Pack.
+        for path in changes.removed().iter().chain(changes.modified().iter()) {
+            let _ = AppUI::purge_that_one_specifically(app_ui, pack_file_contents_ui, path.path_raw(), DataSource::PackFile, false);
+        }
+
+        // Apply the changes in the same order the backend did.
+        if !changes.removed().is_empty() {
+            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Delete(changes.removed().to_vec()), DataSource::PackFile);
+            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(changes.removed().to_vec()), DataSource::PackFile);
+        }
+
+        if !changes.moved().is_empty() {
+            Self::update_open_views_after_rename(app_ui, changes.moved());
+            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Move(changes.moved().to_vec(), vec![]), DataSource::PackFile);
+        }
+
+        if !changes.added().is_empty() {
+            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(changes.added().to_vec()), DataSource::PackFile);
+        }
+
+        if !changes.modified().is_empty() {
+            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Modify(changes.modified().to_vec()), DataSource::PackFile);
+        }
+
+        UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+    }
+
+    pub unsafe fn update_open_views_after_rename(app_ui: &Rc<AppUI>, renamed_items: &[(ContainerPath, ContainerPath)]) {
+        let mut path_changes = vec![];
+
+        // TODO: Filter out reserved files with some generic logic.
+        for path in UI_STATE.get_open_packedfiles().iter().filter(|x| x.get_data_source() == DataSource::PackFile).map(|x| x.get_ref_path()) {
+            if !path.is_empty() {
+                for (old_path, new_path) in renamed_items {
+
+                    // No need to check for path type here, as we can only get file paths.
+                    if old_path.path_raw() == *path {
+                        path_changes.push((old_path.path_raw(), new_path.path_raw()));
+                    }
+                }
+            }
+        }
+
+        let mut open_packedfiles = UI_STATE.set_open_packedfiles();
+        for (path_before, path_after) in &path_changes {
+            let position = open_packedfiles.iter().position(|x| *x.get_ref_path() == *path_before && x.get_data_source() == DataSource::PackFile).unwrap();
+            let data = open_packedfiles.remove(position);
+            let widget = data.get_mut_widget();
+            let index = app_ui.tab_bar_packed_file().index_of(widget);
+            let path_split_before = path_before.split('/').collect::<Vec<_>>();
+            let path_split_after = path_after.split('/').collect::<Vec<_>>();
+            let old_name = path_split_before.last().unwrap();
+            let new_name = path_split_after.last().unwrap();
+            if old_name != new_name {
+                app_ui.tab_bar_packed_file().set_tab_text(index, &QString::from_std_str(new_name));
+            }
+
+            data.set_path(path_after);
+            open_packedfiles.push(data);
+        }
+    }
+
     pub unsafe fn extract_packed_files(
         app_ui: &Rc<AppUI>,
         pack_file_contents_ui: &Rc<Self>,
         paths_to_extract: Option<Vec<ContainerPath>>,
-        extract_tables_as_tsv: bool,
     ) {
 
         // Get the currently selected paths (and visible) paths, or the ones received from the function.
@@ -451,7 +1452,7 @@ impl PackFileContentsUI {
             None => <QPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(pack_file_contents_ui),
         };
 
-        let extraction_path = match UI_STATE.get_operational_mode() {
+        let (extraction_path, extraction_format) = match UI_STATE.get_operational_mode() {
 
             // In MyMod mode we extract directly to the folder of the selected MyMod, keeping the folder structure.
             OperationalMode::MyMod(ref game_folder_name, ref mod_name) => {
@@ -469,7 +1470,7 @@ impl PackFileContentsUI {
                     let mut assets_folder = mymods_base_path;
                     assets_folder.push(game_folder_name);
                     assets_folder.push(&mod_name);
-                    assets_folder
+                    (assets_folder, ExtractionFormat::Raw)
                 }
 
                 // If there is no MyMod path configured, report it.
@@ -478,14 +1479,19 @@ impl PackFileContentsUI {
                 }
             }
 
-            // In normal mode, we ask the user to provide us with a path.
+            // In normal mode, we ask the user to provide us with a format for the tables and a path.
             OperationalMode::Normal => {
+                let extraction_format = match Self::create_extraction_format_dialog(app_ui) {
+                    Some(extraction_format) => extraction_format,
+                    None => return,
+                };
+
                 let extraction_path = QFileDialog::get_existing_directory_2a(
                     app_ui.main_window(),
                     &qtr("context_menu_extract_packfile"),
                 );
 
-                if !extraction_path.is_empty() { PathBuf::from(extraction_path.to_std_string()) }
+                if !extraction_path.is_empty() { (PathBuf::from(extraction_path.to_std_string()), extraction_format) }
                 else { return }
             }
         };
@@ -499,7 +1505,7 @@ impl PackFileContentsUI {
         }
 
         else {
-            let receiver = CENTRAL_COMMAND.send_background(Command::ExtractPackedFiles(items_to_extract, extraction_path, extract_tables_as_tsv));
+            let receiver = CENTRAL_COMMAND.send_background(Command::ExtractPackedFiles(items_to_extract, extraction_path, extraction_format));
             app_ui.toggle_main_window(false);
             let response = CENTRAL_COMMAND.recv_try(&receiver);
             match response {
@@ -516,3 +1522,10 @@ impl PackFileContentsUI {
         pack_file_contents_ui.filter_timer_delayed_updates.start_0a();
     }
 }
+
+/// This function creates an icon filled with the colour of a label.
+unsafe fn label_icon(colour: &str) -> CppBox<QIcon> {
+    let pixmap = QPixmap::from_2_int(16, 16);
+    pixmap.fill_1a(&QColor::from_q_string(&QString::from_std_str(colour)));
+    QIcon::from_q_pixmap(&pixmap)
+}
@@ -13,6 +13,7 @@ Module with all the code related to the main `PackFileContentsSlots`.
 !*/
 
 use qt_widgets::{QFileDialog, q_file_dialog::FileMode};
+use qt_widgets::{QMessageBox, q_message_box};
 use qt_widgets::SlotOfQPoint;
 use qt_widgets::QTreeView;
 
@@ -38,12 +39,12 @@ use crate::dependencies_ui::DependenciesUI;
 use crate::diagnostics_ui::DiagnosticsUI;
 use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICATION_ERROR};
 use crate::global_search_ui::GlobalSearchUI;
-use crate::locale::{qtr, tre};
+use crate::locale::{qtr, tr, tre};
 use crate::pack_tree::{PackTree, TreeViewOperation};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::{DataSource, SpecialView};
 use crate::references_ui::ReferencesUI;
-use crate::SCHEMA;
+use crate::locks::read_schema;
 use crate::settings_ui::backend::*;
 use crate::utils::{show_dialog, check_regex};
 use crate::UI_STATE;
@@ -66,24 +67,39 @@ pub struct PackFileContentsSlots {
 
     pub contextual_menu: QBox<SlotOfQPoint>,
     pub contextual_menu_enabler: QBox<SlotNoArgs>,
+    pub contextual_menu_label: QBox<SlotNoArgs>,
+    pub filter_label_menu: QBox<SlotNoArgs>,
 
     pub contextual_menu_add_file: QBox<SlotOfBool>,
     pub contextual_menu_add_folder: QBox<SlotOfBool>,
     pub contextual_menu_add_from_packfile: QBox<SlotOfBool>,
+    pub contextual_menu_add_from_archive: QBox<SlotOfBool>,
+    pub contextual_menu_open_pack_in_side_pane: QBox<SlotOfBool>,
+    pub contextual_menu_paste_from_side_pane: QBox<SlotOfBool>,
+    pub close_extra_pack_pane: QBox<SlotNoArgs>,
     pub contextual_menu_delete: QBox<SlotOfBool>,
+    pub contextual_menu_undo: QBox<SlotOfBool>,
+    pub contextual_menu_redo: QBox<SlotOfBool>,
     pub contextual_menu_extract: QBox<SlotOfBool>,
     pub contextual_menu_rename: QBox<SlotOfBool>,
+    pub contextual_menu_rename_by_pattern: QBox<SlotOfBool>,
+    pub contextual_menu_cut: QBox<SlotOfBool>,
+    pub contextual_menu_copy: QBox<SlotOfBool>,
+    pub contextual_menu_paste: QBox<SlotOfBool>,
+    pub contextual_menu_duplicate_table: QBox<SlotOfBool>,
     pub contextual_menu_copy_path: QBox<SlotOfBool>,
 
     pub contextual_menu_new_packed_file_anim_pack: QBox<SlotOfBool>,
     pub contextual_menu_new_packed_file_db: QBox<SlotOfBool>,
     pub contextual_menu_new_packed_file_loc: QBox<SlotOfBool>,
     pub contextual_menu_new_packed_file_text: QBox<SlotOfBool>,
+    pub contextual_menu_new_from_template: QBox<SlotOfBool>,
     pub contextual_menu_new_folder: QBox<SlotOfBool>,
     pub contextual_menu_new_queek_packed_file: QBox<SlotOfBool>,
 
     pub contextual_menu_open_decoder: QBox<SlotOfBool>,
     pub contextual_menu_open_dependency_manager: QBox<SlotOfBool>,
+    pub contextual_menu_open_dependencies_editor: QBox<SlotOfBool>,
     pub contextual_menu_open_containing_folder: QBox<SlotOfBool>,
     pub contextual_menu_open_in_external_program: QBox<SlotOfBool>,
     pub contextual_menu_open_packfile_settings: QBox<SlotOfBool>,
@@ -95,6 +111,7 @@ pub struct PackFileContentsSlots {
 
     pub packfile_contents_tree_view_expand_all: QBox<SlotNoArgs>,
     pub packfile_contents_tree_view_collapse_all: QBox<SlotNoArgs>,
+    pub packfile_contents_tree_view_drop_files: QBox<SlotOfQString>,
 
     pub packfile_contents_resize: QBox<SlotNoArgs>,
 }
@@ -178,6 +195,18 @@ impl PackFileContentsSlots {
             pack_file_contents_ui.packfile_contents_tree_view_context_menu.exec_1a_mut(&QCursor::pos_0a());
         }));
 
+        // Slots to rebuild the label menus when shown, as the available labels may have changed.
+        let contextual_menu_label = SlotNoArgs::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move || {
+            PackFileContentsUI::build_label_menu(&app_ui, &pack_file_contents_ui);
+        }));
+
+        let filter_label_menu = SlotNoArgs::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            pack_file_contents_ui => move || {
+            PackFileContentsUI::build_label_filter_menu(&pack_file_contents_ui);
+        }));
+
         // Slot to enable/disable contextual actions depending on the selected item.
         let contextual_menu_enabler = SlotNoArgs::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
             pack_file_contents_ui => move || {
@@ -190,16 +219,20 @@ impl PackFileContentsSlots {
                         // These options are valid for 1 or more files.
                         pack_file_contents_ui.context_menu_add_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_folder.set_enabled(false);
+                        pack_file_contents_ui.context_menu_add_from_archive.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_from_packfile.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_anim_pack.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_db.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_packed_file_loc.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_text.set_enabled(false);
+                        pack_file_contents_ui.context_menu_new_from_template.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(true);
+                        pack_file_contents_ui.context_menu_rename_by_pattern.set_enabled(true);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
+                        pack_file_contents_ui.context_menu_open_dependencies_editor.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_packfile_settings.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_notes.set_enabled(true);
@@ -228,22 +261,26 @@ impl PackFileContentsSlots {
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(true);
+                        pack_file_contents_ui.context_menu_rename_by_pattern.set_enabled(true);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
+                        pack_file_contents_ui.context_menu_open_dependencies_editor.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_packfile_settings.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_notes.set_enabled(true);
-                        pack_file_contents_ui.context_menu_update_table.set_enabled(false);
 
                         // These options are limited to only 1 folder selected.
                         let enabled = folders == 1;
+                        pack_file_contents_ui.context_menu_update_table.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_add_file.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_add_folder.set_enabled(enabled);
+                        pack_file_contents_ui.context_menu_add_from_archive.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_new_folder.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_new_packed_file_anim_pack.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_new_packed_file_loc.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_new_packed_file_text.set_enabled(enabled);
+                        pack_file_contents_ui.context_menu_new_from_template.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_new_queek_packed_file.set_enabled(enabled);
                         pack_file_contents_ui.context_menu_copy_path.set_enabled(enabled);
                     },
@@ -252,20 +289,24 @@ impl PackFileContentsSlots {
                     3 => {
                         pack_file_contents_ui.context_menu_add_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_folder.set_enabled(false);
+                        pack_file_contents_ui.context_menu_add_from_archive.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_from_packfile.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_anim_pack.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_db.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_packed_file_loc.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_text.set_enabled(false);
+                        pack_file_contents_ui.context_menu_new_from_template.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_queek_packed_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_rename_by_pattern.set_enabled(false);
                         pack_file_contents_ui.context_menu_copy_path.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
+                        pack_file_contents_ui.context_menu_open_dependencies_editor.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_packfile_settings.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
@@ -277,20 +318,24 @@ impl PackFileContentsSlots {
                     4 => {
                         pack_file_contents_ui.context_menu_add_file.set_enabled(true);
                         pack_file_contents_ui.context_menu_add_folder.set_enabled(true);
+                        pack_file_contents_ui.context_menu_add_from_archive.set_enabled(true);
                         pack_file_contents_ui.context_menu_add_from_packfile.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_folder.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_packed_file_anim_pack.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_packed_file_db.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_packed_file_loc.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_packed_file_text.set_enabled(true);
+                        pack_file_contents_ui.context_menu_new_from_template.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_queek_packed_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_rename_by_pattern.set_enabled(false);
                         pack_file_contents_ui.context_menu_copy_path.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(true);
+                        pack_file_contents_ui.context_menu_open_dependencies_editor.set_enabled(true);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(true);
                         pack_file_contents_ui.context_menu_open_packfile_settings.set_enabled(true);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
@@ -302,20 +347,24 @@ impl PackFileContentsSlots {
                     5 => {
                         pack_file_contents_ui.context_menu_add_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_folder.set_enabled(false);
+                        pack_file_contents_ui.context_menu_add_from_archive.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_from_packfile.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_anim_pack.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_db.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_packed_file_loc.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_text.set_enabled(false);
+                        pack_file_contents_ui.context_menu_new_from_template.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_queek_packed_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_rename_by_pattern.set_enabled(false);
                         pack_file_contents_ui.context_menu_copy_path.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
+                        pack_file_contents_ui.context_menu_open_dependencies_editor.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_packfile_settings.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
@@ -327,19 +376,23 @@ impl PackFileContentsSlots {
                     6 => {
                         pack_file_contents_ui.context_menu_add_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_folder.set_enabled(false);
+                        pack_file_contents_ui.context_menu_add_from_archive.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_from_packfile.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_anim_pack.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_db.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_packed_file_loc.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_text.set_enabled(false);
+                        pack_file_contents_ui.context_menu_new_from_template.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_queek_packed_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_rename_by_pattern.set_enabled(false);
                         pack_file_contents_ui.context_menu_copy_path.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
+                        pack_file_contents_ui.context_menu_open_dependencies_editor.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_packfile_settings.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
@@ -351,20 +404,24 @@ impl PackFileContentsSlots {
                     7 => {
                         pack_file_contents_ui.context_menu_add_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_folder.set_enabled(false);
+                        pack_file_contents_ui.context_menu_add_from_archive.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_from_packfile.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_anim_pack.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_db.set_enabled(true);
                         pack_file_contents_ui.context_menu_new_packed_file_loc.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_text.set_enabled(false);
+                        pack_file_contents_ui.context_menu_new_from_template.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_queek_packed_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(true);
                         pack_file_contents_ui.context_menu_extract.set_enabled(true);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_rename_by_pattern.set_enabled(false);
                         pack_file_contents_ui.context_menu_copy_path.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
+                        pack_file_contents_ui.context_menu_open_dependencies_editor.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_packfile_settings.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
@@ -376,20 +433,24 @@ impl PackFileContentsSlots {
                     0 | 8..=255 => {
                         pack_file_contents_ui.context_menu_add_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_folder.set_enabled(false);
+                        pack_file_contents_ui.context_menu_add_from_archive.set_enabled(false);
                         pack_file_contents_ui.context_menu_add_from_packfile.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_anim_pack.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_db.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_loc.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_packed_file_text.set_enabled(false);
+                        pack_file_contents_ui.context_menu_new_from_template.set_enabled(false);
                         pack_file_contents_ui.context_menu_new_queek_packed_file.set_enabled(false);
                         pack_file_contents_ui.context_menu_merge_tables.set_enabled(false);
                         pack_file_contents_ui.context_menu_delete.set_enabled(false);
                         pack_file_contents_ui.context_menu_extract.set_enabled(false);
                         pack_file_contents_ui.context_menu_rename.set_enabled(false);
+                        pack_file_contents_ui.context_menu_rename_by_pattern.set_enabled(false);
                         pack_file_contents_ui.context_menu_copy_path.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_decoder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_dependency_manager.set_enabled(false);
+                        pack_file_contents_ui.context_menu_open_dependencies_editor.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_containing_folder.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_packfile_settings.set_enabled(false);
                         pack_file_contents_ui.context_menu_open_with_external_program.set_enabled(false);
@@ -398,6 +459,25 @@ impl PackFileContentsSlots {
                     },
                 }
 
+                // The side pane actions are available wherever adding from another PackFile is.
+                let can_add_from_pack = pack_file_contents_ui.context_menu_add_from_packfile.is_enabled();
+                pack_file_contents_ui.context_menu_open_pack_in_side_pane.set_enabled(can_add_from_pack);
+                pack_file_contents_ui.context_menu_paste_from_side_pane.set_enabled(can_add_from_pack && pack_file_contents_ui.extra_pack_clipboard.read().unwrap().is_some());
+
+                // Files and folders can be cut or copied, then pasted into a single folder, the folder of a single file, or the PackFile itself.
+                let can_cut_or_copy = (files > 0 || folders > 0) && contents < 4;
+                let can_paste = (contents == 1 && files == 1) || (contents == 2 && folders == 1) || contents == 4;
+                pack_file_contents_ui.context_menu_cut.set_enabled(can_cut_or_copy);
+                pack_file_contents_ui.context_menu_copy.set_enabled(can_cut_or_copy);
+                pack_file_contents_ui.context_menu_paste.set_enabled(can_paste && pack_file_contents_ui.files_clipboard.read().unwrap().is_some());
+
+                // Only a single DB or Loc table can be duplicated at a time.
+                let can_duplicate = contents == 1 && files == 1 && <QPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui)
+                    .first()
+                    .map(|path| path.duplicate_table_path("copy").is_ok())
+                    .unwrap_or(false);
+                pack_file_contents_ui.context_menu_duplicate_table.set_enabled(can_duplicate);
+
                 // If there is anything selected, we can generate missing loc data.
                 if files > 0 || folders > 0 {
                     pack_file_contents_ui.context_menu_generate_missing_loc_data.set_enabled(true);
@@ -405,6 +485,9 @@ impl PackFileContentsSlots {
                     pack_file_contents_ui.context_menu_generate_missing_loc_data.set_enabled(false);
                 }
 
+                // Labels can be assigned to any file or folder, but not to the PackFile itself.
+                pack_file_contents_ui.context_menu_label.menu_action().set_enabled(files > 0 || folders > 0);
+
                 // Ask the other thread if there is a Dependency Database and a Schema loaded.
                 let receiver = CENTRAL_COMMAND.send_background(Command::IsThereADependencyDatabase(false));
                 let response = CentralCommand::recv(&receiver);
@@ -414,7 +497,7 @@ impl PackFileContentsSlots {
                 };
 
                 // If there is no dependency_database or schema for our GameSelected, ALWAYS disable creating new DB Tables and exporting them.
-                if !is_there_a_dependency_database || SCHEMA.read().unwrap().is_none() {
+                if !is_there_a_dependency_database || read_schema().is_none() {
                     pack_file_contents_ui.context_menu_update_table.set_enabled(false);
                 }
             }
@@ -625,6 +708,34 @@ impl PackFileContentsSlots {
             }
         ));
 
+        // What happens when we trigger the "Add from Archive" action in the Contextual Menu.
+        let contextual_menu_add_from_archive = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Add from Archive` By Slot");
+
+                let file_dialog = QFileDialog::from_q_widget_q_string(
+                    app_ui.main_window(),
+                    &qtr("context_menu_select_archive"),
+                );
+
+                if cfg!(feature = "support_7z") {
+                    file_dialog.set_name_filter(&QString::from_std_str("Archives (*.zip *.7z)"));
+                } else {
+                    file_dialog.set_name_filter(&QString::from_std_str("Archives (*.zip)"));
+                }
+
+                if file_dialog.exec() == 1 {
+                    let archive_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+
+                    // The archive contents go into the selected folder, like with Add Folder.
+                    if let Some(selection) = pack_file_contents_ui.packfile_contents_tree_view.get_path_from_selection().get(0) {
+                        PackFileContentsUI::add_files_from_archive(&app_ui, &pack_file_contents_ui, &archive_path, selection);
+                    }
+                }
+            }
+        ));
+
         // What happens when we trigger the "Add From PackFile" action in the Contextual Menu.
         let contextual_menu_add_from_packfile = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
             app_ui,
@@ -666,6 +777,60 @@ impl PackFileContentsSlots {
             }
         ));
 
+        let contextual_menu_open_pack_in_side_pane = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Open Pack In Side Pane` By Slot");
+
+                let file_dialog = QFileDialog::from_q_widget_q_string(
+                    app_ui.main_window(),
+                    &qtr("context_menu_select_packfile"),
+                );
+
+                file_dialog.set_name_filter(&QString::from_std_str("PackFiles (*.pack)"));
+                if file_dialog.exec() == 1 {
+                    let path_str = file_dialog.selected_files().at(0).to_std_string();
+
+                    // Same as with the tab, we cannot add files from the PackFile we're adding them to.
+                    let receiver = CENTRAL_COMMAND.send_background(Command::GetPackFileDataForTreeView);
+                    let response = CentralCommand::recv(&receiver);
+                    match response {
+                        Response::ContainerInfoVecRFileInfo((pack_file_info, _)) => {
+                            if pack_file_info.file_path() == &path_str {
+                                 return show_dialog(app_ui.main_window(), "You cannot add PackedFile to the same PackFile you're adding from. It's like putting a bag of holding into a bag of holding.", false);
+                            }
+                        },
+                        Response::Error(error) => return show_dialog(app_ui.main_window(), error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+
+                    app_ui.toggle_main_window(false);
+                    if let Err(error) = PackFileContentsUI::open_extra_pack_pane(&app_ui, &pack_file_contents_ui, PathBuf::from(path_str)) {
+                        show_dialog(app_ui.main_window(), error, false);
+                    }
+                    app_ui.toggle_main_window(true);
+                }
+            }
+        ));
+
+        let contextual_menu_paste_from_side_pane = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Paste From Side Pane` By Slot");
+
+                let clipboard = pack_file_contents_ui.extra_pack_clipboard.read().unwrap().clone();
+                if let Some((pack_file_path, paths)) = clipboard {
+                    PackFileContentsUI::add_files_from_extra_pack(&app_ui, &pack_file_contents_ui, &pack_file_path, &paths);
+                }
+            }
+        ));
+
+        let close_extra_pack_pane = SlotNoArgs::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            pack_file_contents_ui => move || {
+                PackFileContentsUI::close_extra_pack_pane(&pack_file_contents_ui);
+            }
+        ));
+
         // What happens when we trigger the "Delete" action in the Contextual Menu.
         let contextual_menu_delete = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
             app_ui,
@@ -712,12 +877,30 @@ impl PackFileContentsSlots {
             }
         ));
 
+        // What happens when we trigger the "Undo" action in the Contextual Menu.
+        let contextual_menu_undo = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Undo` By Slot");
+                PackFileContentsUI::undo_redo_container_op(&app_ui, &pack_file_contents_ui, false);
+            }
+        ));
+
+        // What happens when we trigger the "Redo" action in the Contextual Menu.
+        let contextual_menu_redo = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Redo` By Slot");
+                PackFileContentsUI::undo_redo_container_op(&app_ui, &pack_file_contents_ui, true);
+            }
+        ));
+
         // What happens when we trigger the "Extract" action in the Contextual Menu.
         let contextual_menu_extract = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
             app_ui,
             pack_file_contents_ui => move |_| {
                 info!("Triggering `Extract` By Slot");
-                PackFileContentsUI::extract_packed_files(&app_ui, &pack_file_contents_ui, None, false);
+                PackFileContentsUI::extract_packed_files(&app_ui, &pack_file_contents_ui, None);
             }
         ));
 
@@ -775,40 +958,7 @@ impl PackFileContentsSlots {
                             let response = CentralCommand::recv(&receiver);
                             match response {
                                 Response::VecContainerPathContainerPath(renamed_items) => {
-                                    let mut path_changes = vec![];
-
-                                    // TODO: Filter out reserved files with some generic logic.
-                                    for path in UI_STATE.get_open_packedfiles().iter().filter(|x| x.get_data_source() == DataSource::PackFile).map(|x| x.get_ref_path()) {
-                                        if !path.is_empty() {
-                                            for (old_path, new_path) in &renamed_items {
-
-                                                // No need to check for path type here, as we can only get file paths.
-                                                if old_path.path_raw() == *path {
-                                                    path_changes.push((old_path.path_raw(), new_path.path_raw()));
-                                                }
-                                            }
-                                        }
-                                    }
-
-                                    {
-                                        let mut open_packedfiles = UI_STATE.set_open_packedfiles();
-                                        for (path_before, path_after) in &path_changes {
-                                            let position = open_packedfiles.iter().position(|x| *x.get_ref_path() == *path_before && x.get_data_source() == DataSource::PackFile).unwrap();
-                                            let data = open_packedfiles.remove(position);
-                                            let widget = data.get_mut_widget();
-                                            let index = app_ui.tab_bar_packed_file().index_of(widget);
-                                            let path_split_before = path_before.split('/').collect::<Vec<_>>();
-                                            let path_split_after = path_after.split('/').collect::<Vec<_>>();
-                                            let old_name = path_split_before.last().unwrap();
-                                            let new_name = path_split_after.last().unwrap();
-                                            if old_name != new_name {
-                                                app_ui.tab_bar_packed_file().set_tab_text(index, &QString::from_std_str(new_name));
-                                            }
-
-                                            data.set_path(path_after);
-                                            open_packedfiles.push(data);
-                                        }
-                                    }
+                                    PackFileContentsUI::update_open_views_after_rename(&app_ui, &renamed_items);
 
                                     // Move the items on the UI and mark the currently open Pack as modified.
                                     let folders_to_move = selected_items.into_iter()
@@ -829,6 +979,113 @@ impl PackFileContentsSlots {
             }
         ));
 
+        // What happens when we trigger the "Rename by Pattern" Action.
+        let contextual_menu_rename_by_pattern = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Rename by Pattern` By Slot");
+
+                let selected_items = <QPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui);
+                if selected_items.is_empty() {
+                    return;
+                }
+
+                if let Some((pattern, replacement, case_insensitive)) = PackFileContentsUI::create_rename_by_pattern_dialog(&app_ui) {
+                    let receiver = CENTRAL_COMMAND.send_background(Command::RenamePackedFilesByPattern(selected_items, pattern, replacement, case_insensitive));
+                    let response = CentralCommand::recv(&receiver);
+                    match response {
+                        Response::VecContainerPathContainerPath(renamed_items) => {
+                            if renamed_items.is_empty() {
+                                return show_dialog(app_ui.main_window(), tr("rename_by_pattern_no_matches"), false);
+                            }
+
+                            PackFileContentsUI::update_open_views_after_rename(&app_ui, &renamed_items);
+
+                            // Folders are not moved as a whole here, as not all their files may have matched the pattern.
+                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Move(renamed_items, vec![]), DataSource::PackFile);
+                            UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
+                        },
+                        Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+            }
+        ));
+
+        let contextual_menu_cut = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Cut` By Slot");
+
+                let selected_items = <QPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui);
+                if !selected_items.is_empty() {
+                    *pack_file_contents_ui.files_clipboard.write().unwrap() = Some((selected_items, true));
+                }
+            }
+        ));
+
+        let contextual_menu_copy = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Copy` By Slot");
+
+                let selected_items = <QPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui);
+                if !selected_items.is_empty() {
+                    *pack_file_contents_ui.files_clipboard.write().unwrap() = Some((selected_items, false));
+                }
+            }
+        ));
+
+        let contextual_menu_paste = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Paste` By Slot");
+
+                // Files are pasted into their own folder.
+                let selected_items = <QPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui);
+                let destination_folder = match selected_items.first() {
+                    Some(ContainerPath::File(_)) => selected_items[0].parent_path(),
+                    Some(ContainerPath::Folder(path)) => path.to_owned(),
+                    None => return,
+                };
+
+                PackFileContentsUI::paste_files(&app_ui, &pack_file_contents_ui, &destination_folder);
+            }
+        ));
+
+        // What happens when we trigger the "Duplicate Table" Action.
+        let contextual_menu_duplicate_table = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+                info!("Triggering `Duplicate Table` By Slot");
+
+                let selected_items = <QPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui);
+                let source_path = match selected_items.first() {
+                    Some(ContainerPath::File(path)) if selected_items.len() == 1 => path.to_owned(),
+                    _ => return,
+                };
+
+                if let Some(new_name) = PackFileContentsUI::create_duplicate_table_dialog(&app_ui, &source_path) {
+
+                    // If the table is open, make sure its latest changes are the ones being duplicated.
+                    if let Some(file_view) = UI_STATE.get_open_packedfiles().iter().find(|file_view| file_view.get_data_source() == DataSource::PackFile && *file_view.get_ref_path() == source_path) {
+                        if let Err(error) = file_view.save(&app_ui, &pack_file_contents_ui) {
+                            return show_dialog(app_ui.main_window(), error, false);
+                        }
+                    }
+
+                    let receiver = CENTRAL_COMMAND.send_background(Command::DuplicateTable(source_path, new_name));
+                    let response = CentralCommand::recv(&receiver);
+                    match response {
+                        Response::OptionRFileInfo(Some(info)) => {
+                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(vec![ContainerPath::File(info.path().to_owned())]), DataSource::PackFile);
+                            UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
+                        },
+                        Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+            }
+        ));
+
         let contextual_menu_copy_path = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
             pack_file_contents_ui => move |_| {
             let selected_paths = pack_file_contents_ui.packfile_contents_tree_view.get_path_from_selection();
@@ -869,6 +1126,14 @@ impl PackFileContentsSlots {
             AppUI::new_packed_file(&app_ui, &pack_file_contents_ui, FileType::Text);
         }));
 
+        // What happens when we trigger the "New from Template" Action.
+        let contextual_menu_new_from_template = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+            info!("Triggering `New from Template` By Slot");
+            PackFileContentsUI::add_files_from_template(&app_ui, &pack_file_contents_ui);
+        }));
+
         // What happens when we trigger the "New Folder" Action.
         let contextual_menu_new_folder = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
             app_ui,
@@ -939,10 +1204,18 @@ impl PackFileContentsSlots {
             AppUI::open_special_view(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui, SpecialView::PackDependencies);
         }));
 
+        // What happens when we trigger the "Open Dependencies Editor" Action.
+        let contextual_menu_open_dependencies_editor = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |_| {
+            info!("Triggering `Open Dependencies Editor` By Slot");
+            AppUI::dependencies_editor_dialog(&app_ui, &pack_file_contents_ui);
+        }));
+
         // What happens when we trigger the "Open Containing Folder" Action.
         let contextual_menu_open_containing_folder = SlotOfBool::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
             app_ui => move |_| {
-            let receiver = CENTRAL_COMMAND.send_background(Command::OpenContainingFolder);
+            let receiver = CENTRAL_COMMAND.send_background(Command::OpenContainingFolder(None));
             let response = CentralCommand::recv(&receiver);
             match response {
                 Response::Success => {}
@@ -1037,7 +1310,7 @@ impl PackFileContentsSlots {
             if (loc_pass || db_pass) && !(loc_pass && db_pass) {
 
                 // Get the info for the merged file.
-                if let Some((mut name, delete_source_files)) = AppUI::merge_tables_dialog(&app_ui) {
+                if let Some((mut name, delete_source_files, upgrade_before_merge)) = AppUI::merge_tables_dialog(&app_ui) {
 
                     // If it's a loc file and the name doesn't end in a ".loc" termination, call it ".loc".
                     if loc_pass && !name.to_lowercase().ends_with(".loc") {
@@ -1065,10 +1338,52 @@ impl PackFileContentsSlots {
                     path_to_add.push('/');
                     path_to_add.push_str(&name);
 
-                    let receiver = CENTRAL_COMMAND.send_background(Command::MergeFiles(paths_to_close.to_vec(), path_to_add, delete_source_files));
-                    let response = CentralCommand::recv(&receiver);
+                    // First try without conflicts. If there are conflicts, ask the user before merging, and before deleting the source files.
+                    let paths_to_merge = selected_paths.iter().map(|x| ContainerPath::File(x.to_owned())).collect::<Vec<_>>();
+                    let mut ignore_conflicts = false;
+                    let response = loop {
+                        let receiver = CENTRAL_COMMAND.send_background(Command::MergeFiles(paths_to_merge.to_vec(), path_to_add.to_owned(), delete_source_files, upgrade_before_merge, ignore_conflicts));
+                        let response = CentralCommand::recv(&receiver);
+                        match response {
+                            Response::VecMergeConflictVecContainerPath(conflicts, updated_paths) => {
+
+                                // If the tables got updated before finding the conflicts, they're still updated even if we cancel the merge.
+                                if !updated_paths.is_empty() {
+                                    pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Modify(updated_paths.to_vec()), DataSource::PackFile);
+                                    pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(updated_paths), DataSource::PackFile);
+                                    UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
+                                }
+
+                                let list = conflicts.iter()
+                                    .map(|conflict| format!("<li><b>{}</b>: {}</li>", conflict.key(), conflict.rows().iter().map(|(path, _)| path.to_owned()).collect::<Vec<_>>().join(", ")))
+                                    .collect::<String>();
+                                let message = tre("merge_tables_conflicts", &[&conflicts.len().to_string(), &list]);
+                                let accepted = QMessageBox::from_2_q_string_icon3_int_q_widget(
+                                    &qtr("rpfm_title"),
+                                    &QString::from_std_str(message),
+                                    q_message_box::Icon::Warning,
+                                    65536, // No
+                                    16384, // Yes
+                                    1, // By default, select yes.
+                                    app_ui.main_window(),
+                                ).exec() == 3;
+
+                                if !accepted {
+                                    return;
+                                }
+
+                                ignore_conflicts = true;
+                            }
+                            _ => break response,
+                        }
+                    };
+
                     match response {
-                        Response::String(path_to_add) => {
+                        Response::StringVecContainerPath(path_to_add, updated_paths) => {
+                            if !updated_paths.is_empty() && !delete_source_files {
+                                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Modify(updated_paths.to_vec()), DataSource::PackFile);
+                                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(updated_paths), DataSource::PackFile);
+                            }
 
                             // If we want to delete the sources, do it now. Oh, and close them manually first, or the autocleanup will try to save them and fail miserably.
                             if delete_source_files {
@@ -1101,37 +1416,68 @@ impl PackFileContentsSlots {
 
             let selected_items = <QPtr<QTreeView> as PackTree>::get_item_types_from_main_treeview_selection(&pack_file_contents_ui);
             let item_type = if selected_items.len() == 1 { &selected_items[0] } else { return };
-            match item_type {
-                ContainerPath::File(path) => {
 
-                    // First, if the PackedFile is open, save it.
-                    let close_path = UI_STATE.get_open_packedfiles().iter().filter(|x| x.get_data_source() == DataSource::PackFile).any(|packed_file_view| {
-                        packed_file_view.get_path() == *path
-                    });
+            // Get what can be updated first, so we can ask the user before doing anything.
+            let receiver = CENTRAL_COMMAND.send_background(Command::GetOutdatedTables(item_type.clone()));
+            let response = CentralCommand::recv(&receiver);
+            let (outdated, skipped) = match response {
+                Response::TableUpdates(data) => data,
+                Response::Error(error) => return show_dialog(app_ui.main_window(), error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            };
 
-                    if close_path {
-                        if let Err(error) = AppUI::purge_that_one_specifically(&app_ui, &pack_file_contents_ui, path, DataSource::PackFile, true) {
-                            return show_dialog(app_ui.main_window(), error, false);
-                        }
-                    }
+            if outdated.is_empty() {
+                return show_dialog(app_ui.main_window(), tr("update_tables_nothing_to_update"), false);
+            }
 
-                    let receiver = CENTRAL_COMMAND.send_background(Command::UpdateTable(item_type.clone()));
-                    let response = CentralCommand::recv(&receiver);
-                    match response {
-                        Response::I32I32(old_version, new_version) => {
-                            let message = tre("update_table_success", &[&old_version.to_string(), &new_version.to_string()]);
-                            show_dialog(app_ui.main_window(), message, true);
+            let list = outdated.iter()
+                .map(|(path, old_version, new_version)| format!("<li>{}: {} => {}</li>", path, old_version, new_version))
+                .collect::<String>();
+            let skipped_list = skipped.iter()
+                .map(|path| format!("<li>{}</li>", path))
+                .collect::<String>();
+            let message = tre("update_tables_confirm", &[&list, &skipped_list]);
+            let accepted = QMessageBox::from_2_q_string_icon3_int_q_widget(
+                &qtr("rpfm_title"),
+                &QString::from_std_str(message),
+                q_message_box::Icon::Warning,
+                65536, // No
+                16384, // Yes
+                1, // By default, select yes.
+                app_ui.main_window(),
+            ).exec() == 3;
+
+            if !accepted {
+                return;
+            }
 
-                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Modify(vec![item_type.clone(); 1]), DataSource::PackFile);
-                            pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(vec![item_type.clone(); 1]), DataSource::PackFile);
-                            UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
-                        }
+            // If any of the tables is open, close it before updating it.
+            for (path, _, _) in &outdated {
+                let close_path = UI_STATE.get_open_packedfiles().iter().filter(|x| x.get_data_source() == DataSource::PackFile).any(|packed_file_view| {
+                    packed_file_view.get_path() == *path
+                });
 
-                        Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
-                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                if close_path {
+                    if let Err(error) = AppUI::purge_that_one_specifically(&app_ui, &pack_file_contents_ui, path, DataSource::PackFile, true) {
+                        return show_dialog(app_ui.main_window(), error, false);
                     }
                 }
-                _ => unimplemented!()
+            }
+
+            let receiver = CENTRAL_COMMAND.send_background(Command::UpdateTable(item_type.clone()));
+            let response = CentralCommand::recv(&receiver);
+            match response {
+                Response::TableUpdates((updated, _)) => {
+                    let paths = updated.iter().map(|(path, _, _)| ContainerPath::File(path.to_owned())).collect::<Vec<_>>();
+                    pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Modify(paths.to_vec()), DataSource::PackFile);
+                    pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::MarkAlwaysModified(paths), DataSource::PackFile);
+                    UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
+
+                    show_dialog(app_ui.main_window(), tre("update_tables_success", &[&updated.len().to_string()]), true);
+                }
+
+                Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
             }
         }));
 
@@ -1141,18 +1487,42 @@ impl PackFileContentsSlots {
             pack_file_contents_ui => move |_| {
             info!("Triggering `Generate Loc Data` By Slot");
 
-            let receiver = CENTRAL_COMMAND.send_background(Command::GenerateMissingLocData);
-            let response = CentralCommand::recv(&receiver);
-            match response {
-                Response::OptionContainerPath(path_to_add) => {
-                    if let Some(path_to_add) = path_to_add {
-                        pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(vec![path_to_add; 1]), DataSource::PackFile);
-                        UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
+            let selected_items = pack_file_contents_ui.packfile_contents_tree_view().get_item_types_from_selection(true);
+            if let Some((loc_path, value_template, include_parent_keys)) = PackFileContentsUI::create_missing_loc_data_dialog(&app_ui, &selected_items) {
+
+                // Check if the loc already exists, so we know if we have to add it to the tree or just update it.
+                let receiver = CENTRAL_COMMAND.send_background(Command::GetRFileInfo(loc_path.to_owned()));
+                let response = CentralCommand::recv(&receiver);
+                let loc_exists = if let Response::OptionRFileInfo(info) = response { info.is_some() } else { panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response); };
+
+                let receiver = CENTRAL_COMMAND.send_background(Command::GenerateMissingLocData(loc_path, value_template, include_parent_keys));
+                let response = CentralCommand::recv(&receiver);
+                match response {
+                    Response::UsizeOptionRFileInfo(amount, info) => {
+                        if let Some(info) = info {
+                            let path = ContainerPath::File(info.path().to_owned());
+                            if loc_exists {
+                                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Modify(vec![path]), DataSource::PackFile);
+
+                                // If the loc is open, reload it so it shows the new entries.
+                                if let Some(file_view) = UI_STATE.set_open_packedfiles().iter_mut().find(|x| *x.get_ref_path() == *info.path() && x.get_data_source() == DataSource::PackFile) {
+                                    if let Err(error) = file_view.reload(info.path(), &pack_file_contents_ui) {
+                                        show_dialog(app_ui.main_window(), error, false);
+                                    }
+                                }
+                            } else {
+                                pack_file_contents_ui.packfile_contents_tree_view.update_treeview(true, TreeViewOperation::Add(vec![path]), DataSource::PackFile);
+                            }
+
+                            UI_STATE.set_is_modified(true, &app_ui, &pack_file_contents_ui);
+                        }
+
+                        show_dialog(app_ui.main_window(), tre("missing_loc_data_generated", &[&amount.to_string()]), true);
                     }
-                }
 
-                Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
-                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    Response::Error(error) => show_dialog(app_ui.main_window(), error, false),
+                    _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                }
             }
         }));
 
@@ -1167,6 +1537,24 @@ impl PackFileContentsSlots {
             }
         ));
 
+        // What happens when we drop files or folders from outside the program into the TreeView.
+        let packfile_contents_tree_view_drop_files = SlotOfQString::new(&pack_file_contents_ui.packfile_contents_dock_widget, clone!(
+            app_ui,
+            pack_file_contents_ui => move |payload| {
+                info!("Triggering `Drop Files` By Slot");
+
+                // First line is the folder where the files were dropped, the rest are the dropped files.
+                let payload = payload.to_std_string();
+                let mut lines = payload.split('\n');
+                let folder = lines.next().unwrap_or_default().to_owned();
+                let dropped_paths = lines.filter(|x| !x.is_empty()).map(PathBuf::from).collect::<Vec<_>>();
+
+                app_ui.toggle_main_window(false);
+                PackFileContentsUI::add_dropped_files(&app_ui, &pack_file_contents_ui, &folder, &dropped_paths);
+                app_ui.toggle_main_window(true);
+            }
+        ));
+
         let packfile_contents_resize = SlotNoArgs::new(&pack_file_contents_ui.packfile_contents_dock_widget, move || {
 
             // Not yet working.
@@ -1189,24 +1577,39 @@ impl PackFileContentsSlots {
 
             contextual_menu,
             contextual_menu_enabler,
+            contextual_menu_label,
+            filter_label_menu,
 
             contextual_menu_add_file,
             contextual_menu_add_folder,
             contextual_menu_add_from_packfile,
+            contextual_menu_add_from_archive,
+            contextual_menu_open_pack_in_side_pane,
+            contextual_menu_paste_from_side_pane,
+            close_extra_pack_pane,
             contextual_menu_delete,
+            contextual_menu_undo,
+            contextual_menu_redo,
             contextual_menu_extract,
             contextual_menu_rename,
+            contextual_menu_rename_by_pattern,
+            contextual_menu_cut,
+            contextual_menu_copy,
+            contextual_menu_paste,
+            contextual_menu_duplicate_table,
             contextual_menu_copy_path,
 
             contextual_menu_new_packed_file_anim_pack,
             contextual_menu_new_packed_file_db,
             contextual_menu_new_packed_file_loc,
             contextual_menu_new_packed_file_text,
+            contextual_menu_new_from_template,
             contextual_menu_new_folder,
             contextual_menu_new_queek_packed_file,
 
             contextual_menu_open_decoder,
             contextual_menu_open_dependency_manager,
+            contextual_menu_open_dependencies_editor,
             contextual_menu_open_containing_folder,
             contextual_menu_open_in_external_program,
             contextual_menu_open_packfile_settings,
@@ -1218,6 +1621,7 @@ impl PackFileContentsSlots {
 
             packfile_contents_tree_view_expand_all,
             packfile_contents_tree_view_collapse_all,
+            packfile_contents_tree_view_drop_files,
 
             packfile_contents_resize
 		}
@@ -179,9 +179,9 @@ impl ReferencesUI {
             self.references_table_model.set_header_data_3a(3, Orientation::Horizontal, &QVariant::from_q_string(&qtr("reference_search_column_number")));
             self.references_table_model.set_header_data_3a(4, Orientation::Horizontal, &QVariant::from_q_string(&qtr("reference_search_row_number")));
 
-            // Hide the column number column for tables.
+            // Hide the column number column for tables, and keep the order of the results, as they come grouped by source.
             self.references_table_view.hide_column(3);
-            self.references_table_view.sort_by_column_2a(1, SortOrder::AscendingOrder);
+            self.references_table_view.sort_by_column_2a(-1, SortOrder::AscendingOrder);
 
             self.references_table_view.horizontal_header().set_stretch_last_section(true);
             self.references_table_view.horizontal_header().resize_sections(ResizeMode::ResizeToContents);
@@ -232,7 +232,8 @@ impl ReferencesUI {
                     }
                 }
             },
-            DataSource::ExternalFile => {},
+            DataSource::ExternalFile |
+            DataSource::ComparedPackFile => {},
         }
 
         // Open the table and select the cell.
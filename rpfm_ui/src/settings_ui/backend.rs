@@ -29,16 +29,17 @@ use anyhow::{anyhow, Result};
 use directories::ProjectDirs;
 use qt_widgets::QApplication;
 
-use std::fs::{DirBuilder, File};
+use std::fs::DirBuilder;
 use std::path::{Path, PathBuf};
 
 use rpfm_lib::error::RLibError;
 use rpfm_lib::games::{*, supported_games::*};
 use rpfm_lib::schema::SCHEMA_FOLDER;
 use rpfm_lib::tips::TIPS_REMOTE_FOLDER;
+use rpfm_lib::utils::files_from_subdir;
 
 use crate::app_ui::AppUI;
-use crate::GAME_SELECTED;
+use crate::locks::read_game_selected;
 use crate::SUPPORTED_GAMES;
 use crate::updater::STABLE;
 
@@ -88,8 +89,10 @@ pub unsafe fn init_settings(app_ui: &AppUI) {
     set_setting_if_new_string(&q_settings, "default_game", KEY_WARHAMMER_3);
     set_setting_if_new_string(&q_settings, "language", "English_en");
     set_setting_if_new_string(&q_settings, "update_channel", STABLE);
-    set_setting_if_new_int(&q_settings, "autosave_amount", 10);
+    set_setting_if_new_int(&q_settings, "autosave_slots", 10);
+    set_setting_if_new_int(&q_settings, "autosave_size_warning_threshold", 500);
     set_setting_if_new_int(&q_settings, "autosave_interval", 5);
+    set_setting_if_new_int(&q_settings, "extraction_format", 0);
 
     let font = QApplication::font();
     let font_name = font.family().to_std_string();
@@ -112,9 +115,17 @@ pub unsafe fn init_settings(app_ui: &AppUI) {
     set_setting_if_new_bool(&q_settings, "optimize_not_renamed_packedfiles", false);
     set_setting_if_new_bool(&q_settings, "disable_uuid_regeneration_on_db_tables", true);
     set_setting_if_new_bool(&q_settings, "packfile_treeview_resize_to_fit", false);
+    set_setting_if_new_bool(&q_settings, "packfile_treeview_show_modified_column", false);
     set_setting_if_new_bool(&q_settings, "expand_treeview_when_adding_items", true);
     set_setting_if_new_bool(&q_settings, "use_right_size_markers", false);
     set_setting_if_new_bool(&q_settings, "disable_file_previews", false);
+    set_setting_if_new_bool(&q_settings, "restore_tab_sessions", true);
+    set_setting_if_new_int(&q_settings, "recent_packs_limit", 10);
+    set_setting_if_new_bool(&q_settings, "recent_packs_show_all_games", false);
+    set_setting_if_new_int(&q_settings, "container_undo_memory_cap", 512);
+    set_setting_if_new_bool(&q_settings, "container_undo_clear_on_save", true);
+    set_setting_if_new_int(&q_settings, "memory_ceiling_mb", 0);
+    set_setting_if_new_bool(&q_settings, "watch_open_pack", true);
 
     // Table Settings.
     set_setting_if_new_bool(&q_settings, "adjust_columns_to_content", true);
@@ -353,16 +364,6 @@ pub fn init_config_path() -> Result<()> {
     DirBuilder::new().recursive(true).create(tips_local_path)?;
     DirBuilder::new().recursive(true).create(tips_remote_path)?;
 
-    // Init autosave files if they're not yet initialized. Minimum 1.
-    let mut max_autosaves = setting_int("autosave_amount");
-    if max_autosaves < 1 { max_autosaves = 1; }
-    (1..=max_autosaves).for_each(|x| {
-        let path = autosaves_path.join(format!("autosave_{:02?}.pack", x));
-        if !path.is_file() {
-            let _ = File::create(path);
-        }
-    });
-
     Ok(())
 }
 
@@ -406,11 +407,57 @@ pub fn remote_tips_path() -> Result<PathBuf> {
     Ok(config_path()?.join(TIPS_REMOTE_FOLDER))
 }
 
+/// This function returns the path of the key used to sign Packs. Its public key is next to it, with the `.pub` extension.
+pub fn pack_signing_key_path() -> Result<PathBuf> {
+    Ok(config_path()?.join("pack_signing.key"))
+}
+
 /// This function returns the autosave path.
 pub fn backup_autosave_path() -> Result<PathBuf> {
     Ok(config_path()?.join("autosaves"))
 }
 
+/// This function returns the autosave path of a specific Pack.
+pub fn pack_autosave_path(pack_name: &str) -> Result<PathBuf> {
+    Ok(backup_autosave_path()?.join(pack_name))
+}
+
+/// This function returns the autosaves of a specific Pack, sorted from newest to oldest.
+///
+/// As autosaves are named after the time they were done, sorting them by name is enough.
+pub fn pack_autosaves(pack_name: &str) -> Result<Vec<PathBuf>> {
+    let path = pack_autosave_path(pack_name)?;
+    if !path.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut autosaves = files_from_subdir(&path, false)?;
+    autosaves.retain(|path| path.extension().map(|extension| extension == "pack").unwrap_or(false));
+    autosaves.sort();
+    autosaves.reverse();
+    Ok(autosaves)
+}
+
+/// This function returns the path of the file where the open tabs of each Pack are stored between sessions.
+pub fn tab_sessions_path() -> Result<PathBuf> {
+    Ok(config_path()?.join("tab_sessions.json"))
+}
+
+/// This function returns the path of the file where the list of recently opened Packs is stored.
+pub fn recent_packs_path() -> Result<PathBuf> {
+    Ok(config_path()?.join("recent_packs.json"))
+}
+
+/// This function returns the path of the file where the column presets of table views are stored.
+pub fn column_presets_path() -> Result<PathBuf> {
+    Ok(config_path()?.join("column_presets.json"))
+}
+
+/// This function returns the path of the folder where the templates for new files are stored.
+pub fn templates_path() -> Result<PathBuf> {
+    Ok(config_path()?.join("templates"))
+}
+
 /// This function returns the dependencies path.
 pub fn dependencies_cache_path() -> Result<PathBuf> {
     Ok(config_path()?.join(DEPENDENCIES_FOLDER))
@@ -418,7 +465,7 @@ pub fn dependencies_cache_path() -> Result<PathBuf> {
 
 /// This function returns the dependencies path.
 pub fn assembly_kit_path() -> Result<PathBuf> {
-    let game_selected = GAME_SELECTED.read().unwrap();
+    let game_selected = read_game_selected();
     let mut base_path = setting_path(&format!("{}_assembly_kit", game_selected.game_key_name()));
     let version = game_selected.raw_db_version();
     match version {
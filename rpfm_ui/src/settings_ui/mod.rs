@@ -58,9 +58,12 @@ use self::slots::SettingsUISlots;
 
 pub mod backend;
 mod connections;
+pub mod recent_packs;
 mod slots;
 mod tips;
 
+#[cfg(test)] mod recent_packs_test;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -95,6 +98,7 @@ pub struct SettingsUI {
     // `General` section of the `Settings` dialog.
     //-------------------------------------------------------------------------------//
     extra_packfile_autosave_amount_label: QBox<QLabel>,
+    extra_packfile_autosave_size_warning_threshold_label: QBox<QLabel>,
     extra_network_check_updates_on_start_label: QBox<QLabel>,
     extra_network_check_schema_updates_on_start_label: QBox<QLabel>,
     extra_packfile_allow_editing_of_ca_packfiles_label: QBox<QLabel>,
@@ -102,6 +106,12 @@ pub struct SettingsUI {
     extra_packfile_use_lazy_loading_label: QBox<QLabel>,
     extra_packfile_disable_uuid_regeneration_on_db_tables_label: QBox<QLabel>,
     extra_packfile_disable_file_previews_label: QBox<QLabel>,
+    extra_packfile_restore_tab_sessions_label: QBox<QLabel>,
+    extra_packfile_recent_packs_limit_label: QBox<QLabel>,
+    extra_packfile_container_undo_memory_cap_label: QBox<QLabel>,
+    extra_packfile_container_undo_clear_on_save_label: QBox<QLabel>,
+    extra_packfile_memory_ceiling_label: QBox<QLabel>,
+    extra_packfile_watch_open_pack_label: QBox<QLabel>,
     ui_global_use_dark_theme_label: QBox<QLabel>,
     ui_window_start_maximized_label: QBox<QLabel>,
     general_packfile_treeview_expand_treeview_when_adding_items_label: QBox<QLabel>,
@@ -111,6 +121,7 @@ pub struct SettingsUI {
     extra_network_update_channel_combobox: QBox<QComboBox>,
     extra_packfile_autosave_interval_spinbox: QBox<QSpinBox>,
     extra_packfile_autosave_amount_spinbox: QBox<QSpinBox>,
+    extra_packfile_autosave_size_warning_threshold_spinbox: QBox<QSpinBox>,
     extra_network_check_updates_on_start_checkbox: QBox<QCheckBox>,
     extra_network_check_schema_updates_on_start_checkbox: QBox<QCheckBox>,
     extra_network_check_message_updates_on_start_checkbox: QBox<QCheckBox>,
@@ -120,6 +131,12 @@ pub struct SettingsUI {
     extra_packfile_use_lazy_loading_checkbox: QBox<QCheckBox>,
     extra_packfile_disable_uuid_regeneration_on_db_tables_checkbox: QBox<QCheckBox>,
     extra_packfile_disable_file_previews_checkbox: QBox<QCheckBox>,
+    extra_packfile_restore_tab_sessions_checkbox: QBox<QCheckBox>,
+    extra_packfile_recent_packs_limit_spinbox: QBox<QSpinBox>,
+    extra_packfile_container_undo_memory_cap_spinbox: QBox<QSpinBox>,
+    extra_packfile_container_undo_clear_on_save_checkbox: QBox<QCheckBox>,
+    extra_packfile_memory_ceiling_spinbox: QBox<QSpinBox>,
+    extra_packfile_watch_open_pack_checkbox: QBox<QCheckBox>,
     ui_global_use_dark_theme_checkbox: QBox<QCheckBox>,
     ui_window_start_maximized_checkbox: QBox<QCheckBox>,
     ui_window_hide_background_icon_checkbox: QBox<QCheckBox>,
@@ -390,6 +407,9 @@ impl SettingsUI {
         let extra_packfile_autosave_amount_label = QLabel::from_q_string_q_widget(&qtr("settings_autosave_amount"), &general_frame);
         let extra_packfile_autosave_interval_spinbox = QSpinBox::new_1a(&general_frame);
         let extra_packfile_autosave_amount_spinbox = QSpinBox::new_1a(&general_frame);
+        let extra_packfile_autosave_size_warning_threshold_label = QLabel::from_q_string_q_widget(&qtr("settings_autosave_size_warning_threshold"), &general_frame);
+        let extra_packfile_autosave_size_warning_threshold_spinbox = QSpinBox::new_1a(&general_frame);
+        extra_packfile_autosave_size_warning_threshold_spinbox.set_maximum(100_000);
 
         // Update checkers.
         let extra_network_check_updates_on_start_label = QLabel::from_q_string_q_widget(&qtr("settings_check_updates_on_start"), &general_frame);
@@ -411,6 +431,27 @@ impl SettingsUI {
         let extra_packfile_disable_file_previews_label = QLabel::from_q_string_q_widget(&qtr("settings_disable_file_previews"), &general_frame);
         let extra_packfile_disable_file_previews_checkbox = QCheckBox::from_q_widget(&general_frame);
 
+        let extra_packfile_restore_tab_sessions_label = QLabel::from_q_string_q_widget(&qtr("settings_restore_tab_sessions"), &general_frame);
+        let extra_packfile_restore_tab_sessions_checkbox = QCheckBox::from_q_widget(&general_frame);
+
+        let extra_packfile_recent_packs_limit_label = QLabel::from_q_string_q_widget(&qtr("settings_recent_packs_limit"), &general_frame);
+        let extra_packfile_recent_packs_limit_spinbox = QSpinBox::new_1a(&general_frame);
+        extra_packfile_recent_packs_limit_spinbox.set_minimum(1);
+
+        let extra_packfile_container_undo_memory_cap_label = QLabel::from_q_string_q_widget(&qtr("settings_container_undo_memory_cap"), &general_frame);
+        let extra_packfile_container_undo_memory_cap_spinbox = QSpinBox::new_1a(&general_frame);
+        extra_packfile_container_undo_memory_cap_spinbox.set_maximum(100_000);
+
+        let extra_packfile_container_undo_clear_on_save_label = QLabel::from_q_string_q_widget(&qtr("settings_container_undo_clear_on_save"), &general_frame);
+        let extra_packfile_container_undo_clear_on_save_checkbox = QCheckBox::from_q_widget(&general_frame);
+
+        let extra_packfile_memory_ceiling_label = QLabel::from_q_string_q_widget(&qtr("settings_memory_ceiling"), &general_frame);
+        let extra_packfile_memory_ceiling_spinbox = QSpinBox::new_1a(&general_frame);
+        extra_packfile_memory_ceiling_spinbox.set_maximum(1_000_000);
+
+        let extra_packfile_watch_open_pack_label = QLabel::from_q_string_q_widget(&qtr("settings_watch_open_pack"), &general_frame);
+        let extra_packfile_watch_open_pack_checkbox = QCheckBox::from_q_widget(&general_frame);
+
         let ui_global_use_dark_theme_label = QLabel::from_q_string_q_widget(&qtr("settings_ui_dark_theme"), &general_frame);
         let ui_global_use_dark_theme_checkbox = QCheckBox::from_q_widget(&general_frame);
 
@@ -465,20 +506,41 @@ impl SettingsUI {
         general_grid.add_widget_5a(&extra_packfile_disable_file_previews_label, 11, 0, 1, 1);
         general_grid.add_widget_5a(&extra_packfile_disable_file_previews_checkbox, 11, 1, 1, 1);
 
-        general_grid.add_widget_5a(&ui_global_use_dark_theme_label, 14, 0, 1, 1);
-        general_grid.add_widget_5a(&ui_global_use_dark_theme_checkbox, 14, 1, 1, 1);
+        general_grid.add_widget_5a(&extra_packfile_autosave_size_warning_threshold_label, 12, 0, 1, 1);
+        general_grid.add_widget_5a(&extra_packfile_autosave_size_warning_threshold_spinbox, 12, 1, 1, 1);
 
-        general_grid.add_widget_5a(&ui_window_start_maximized_label, 15, 0, 1, 1);
-        general_grid.add_widget_5a(&ui_window_start_maximized_checkbox, 15, 1, 1, 1);
+        general_grid.add_widget_5a(&extra_packfile_restore_tab_sessions_label, 13, 0, 1, 1);
+        general_grid.add_widget_5a(&extra_packfile_restore_tab_sessions_checkbox, 13, 1, 1, 1);
 
-        general_grid.add_widget_5a(&ui_window_hide_background_icon_label, 16, 0, 1, 1);
-        general_grid.add_widget_5a(&ui_window_hide_background_icon_checkbox, 16, 1, 1, 1);
+        general_grid.add_widget_5a(&extra_packfile_recent_packs_limit_label, 14, 0, 1, 1);
+        general_grid.add_widget_5a(&extra_packfile_recent_packs_limit_spinbox, 14, 1, 1, 1);
+
+        general_grid.add_widget_5a(&ui_global_use_dark_theme_label, 15, 0, 1, 1);
+        general_grid.add_widget_5a(&ui_global_use_dark_theme_checkbox, 15, 1, 1, 1);
+
+        general_grid.add_widget_5a(&ui_window_start_maximized_label, 16, 0, 1, 1);
+        general_grid.add_widget_5a(&ui_window_start_maximized_checkbox, 16, 1, 1, 1);
+
+        general_grid.add_widget_5a(&ui_window_hide_background_icon_label, 17, 0, 1, 1);
+        general_grid.add_widget_5a(&ui_window_hide_background_icon_checkbox, 17, 1, 1, 1);
 
         //general_grid.add_widget_5a(&general_packfile_treeview_resize_to_fit_label, 14, 0, 1, 1);
         //general_grid.add_widget_5a(&general_packfile_treeview_resize_to_fit_checkbox, 14, 1, 1, 1);
 
-        general_grid.add_widget_5a(&general_packfile_treeview_expand_treeview_when_adding_items_label, 17, 0, 1, 1);
-        general_grid.add_widget_5a(&general_packfile_treeview_expand_treeview_when_adding_items_checkbox, 17, 1, 1, 1);
+        general_grid.add_widget_5a(&general_packfile_treeview_expand_treeview_when_adding_items_label, 18, 0, 1, 1);
+        general_grid.add_widget_5a(&general_packfile_treeview_expand_treeview_when_adding_items_checkbox, 18, 1, 1, 1);
+
+        general_grid.add_widget_5a(&extra_packfile_container_undo_memory_cap_label, 19, 0, 1, 1);
+        general_grid.add_widget_5a(&extra_packfile_container_undo_memory_cap_spinbox, 19, 1, 1, 1);
+
+        general_grid.add_widget_5a(&extra_packfile_container_undo_clear_on_save_label, 20, 0, 1, 1);
+        general_grid.add_widget_5a(&extra_packfile_container_undo_clear_on_save_checkbox, 20, 1, 1, 1);
+
+        general_grid.add_widget_5a(&extra_packfile_memory_ceiling_label, 21, 0, 1, 1);
+        general_grid.add_widget_5a(&extra_packfile_memory_ceiling_spinbox, 21, 1, 1, 1);
+
+        general_grid.add_widget_5a(&extra_packfile_watch_open_pack_label, 22, 0, 1, 1);
+        general_grid.add_widget_5a(&extra_packfile_watch_open_pack_checkbox, 22, 1, 1, 1);
 
         settings_grid.add_widget_5a(&general_frame, 2, 0, 2, 1);
 
@@ -761,6 +823,7 @@ impl SettingsUI {
             // `General` section of the `Settings` dialog.
             //-------------------------------------------------------------------------------//
             extra_packfile_autosave_amount_label,
+            extra_packfile_autosave_size_warning_threshold_label,
             extra_network_check_updates_on_start_label,
             extra_network_check_schema_updates_on_start_label,
             extra_packfile_allow_editing_of_ca_packfiles_label,
@@ -768,6 +831,12 @@ impl SettingsUI {
             extra_packfile_use_lazy_loading_label,
             extra_packfile_disable_uuid_regeneration_on_db_tables_label,
             extra_packfile_disable_file_previews_label,
+            extra_packfile_restore_tab_sessions_label,
+            extra_packfile_recent_packs_limit_label,
+            extra_packfile_container_undo_memory_cap_label,
+            extra_packfile_container_undo_clear_on_save_label,
+            extra_packfile_memory_ceiling_label,
+            extra_packfile_watch_open_pack_label,
             ui_global_use_dark_theme_label,
             ui_window_start_maximized_label,
             general_packfile_treeview_expand_treeview_when_adding_items_label,
@@ -776,6 +845,7 @@ impl SettingsUI {
             extra_global_default_game_combobox,
             extra_network_update_channel_combobox,
             extra_packfile_autosave_amount_spinbox,
+            extra_packfile_autosave_size_warning_threshold_spinbox,
             extra_packfile_autosave_interval_spinbox,
             extra_network_check_updates_on_start_checkbox,
             extra_network_check_schema_updates_on_start_checkbox,
@@ -786,6 +856,12 @@ impl SettingsUI {
             extra_packfile_use_lazy_loading_checkbox,
             extra_packfile_disable_uuid_regeneration_on_db_tables_checkbox,
             extra_packfile_disable_file_previews_checkbox,
+            extra_packfile_restore_tab_sessions_checkbox,
+            extra_packfile_recent_packs_limit_spinbox,
+            extra_packfile_container_undo_memory_cap_spinbox,
+            extra_packfile_container_undo_clear_on_save_checkbox,
+            extra_packfile_memory_ceiling_spinbox,
+            extra_packfile_watch_open_pack_checkbox,
             ui_global_use_dark_theme_checkbox,
             ui_window_start_maximized_checkbox,
             ui_window_hide_background_icon_checkbox,
@@ -920,7 +996,8 @@ impl SettingsUI {
         *self.font_data.borrow_mut() = (setting_string("font_name"), setting_int("font_size"));
 
         // Load the General Stuff.
-        self.extra_packfile_autosave_amount_spinbox.set_value(setting_int("autosave_amount"));
+        self.extra_packfile_autosave_amount_spinbox.set_value(setting_int("autosave_slots"));
+        self.extra_packfile_autosave_size_warning_threshold_spinbox.set_value(setting_int("autosave_size_warning_threshold"));
         self.extra_packfile_autosave_interval_spinbox.set_value(setting_int("autosave_interval"));
         self.ui_global_use_dark_theme_checkbox.set_checked(setting_bool("use_dark_theme"));
         self.ui_window_start_maximized_checkbox.set_checked(setting_bool("start_maximized"));
@@ -934,6 +1011,12 @@ impl SettingsUI {
         self.extra_packfile_use_lazy_loading_checkbox.set_checked(setting_bool("use_lazy_loading"));
         self.extra_packfile_disable_uuid_regeneration_on_db_tables_checkbox.set_checked(setting_bool("disable_uuid_regeneration_on_db_tables"));
         self.extra_packfile_disable_file_previews_checkbox.set_checked(setting_bool("disable_file_previews"));
+        self.extra_packfile_restore_tab_sessions_checkbox.set_checked(setting_bool("restore_tab_sessions"));
+        self.extra_packfile_recent_packs_limit_spinbox.set_value(setting_int("recent_packs_limit"));
+        self.extra_packfile_container_undo_memory_cap_spinbox.set_value(setting_int("container_undo_memory_cap"));
+        self.extra_packfile_container_undo_clear_on_save_checkbox.set_checked(setting_bool("container_undo_clear_on_save"));
+        self.extra_packfile_memory_ceiling_spinbox.set_value(setting_int("memory_ceiling_mb"));
+        self.extra_packfile_watch_open_pack_checkbox.set_checked(setting_bool("watch_open_pack"));
         self.general_packfile_treeview_resize_to_fit_checkbox.set_checked(setting_bool("packfile_treeview_resize_to_fit"));
         self.general_packfile_treeview_expand_treeview_when_adding_items_checkbox.set_checked(setting_bool("expand_treeview_when_adding_items"));
 
@@ -1046,7 +1129,8 @@ impl SettingsUI {
         set_setting_int_to_q_setting(&q_settings, "font_size", self.font_data.borrow().1);
 
         // Get the General Settings.
-        set_setting_int_to_q_setting(&q_settings, "autosave_amount", self.extra_packfile_autosave_amount_spinbox.value());
+        set_setting_int_to_q_setting(&q_settings, "autosave_slots", self.extra_packfile_autosave_amount_spinbox.value());
+        set_setting_int_to_q_setting(&q_settings, "autosave_size_warning_threshold", self.extra_packfile_autosave_size_warning_threshold_spinbox.value());
         set_setting_int_to_q_setting(&q_settings, "autosave_interval", self.extra_packfile_autosave_interval_spinbox.value());
         set_setting_bool_to_q_setting(&q_settings, "use_dark_theme", self.ui_global_use_dark_theme_checkbox.is_checked());
         set_setting_bool_to_q_setting(&q_settings, "start_maximized", self.ui_window_start_maximized_checkbox.is_checked());
@@ -1060,6 +1144,12 @@ impl SettingsUI {
         set_setting_bool_to_q_setting(&q_settings, "use_lazy_loading", self.extra_packfile_use_lazy_loading_checkbox.is_checked());
         set_setting_bool_to_q_setting(&q_settings, "disable_uuid_regeneration_on_db_tables", self.extra_packfile_disable_uuid_regeneration_on_db_tables_checkbox.is_checked());
         set_setting_bool_to_q_setting(&q_settings, "disable_file_previews", self.extra_packfile_disable_file_previews_checkbox.is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "restore_tab_sessions", self.extra_packfile_restore_tab_sessions_checkbox.is_checked());
+        set_setting_int_to_q_setting(&q_settings, "recent_packs_limit", self.extra_packfile_recent_packs_limit_spinbox.value());
+        set_setting_int_to_q_setting(&q_settings, "container_undo_memory_cap", self.extra_packfile_container_undo_memory_cap_spinbox.value());
+        set_setting_bool_to_q_setting(&q_settings, "container_undo_clear_on_save", self.extra_packfile_container_undo_clear_on_save_checkbox.is_checked());
+        set_setting_int_to_q_setting(&q_settings, "memory_ceiling_mb", self.extra_packfile_memory_ceiling_spinbox.value());
+        set_setting_bool_to_q_setting(&q_settings, "watch_open_pack", self.extra_packfile_watch_open_pack_checkbox.is_checked());
         set_setting_bool_to_q_setting(&q_settings, "packfile_treeview_resize_to_fit", self.general_packfile_treeview_resize_to_fit_checkbox.is_checked());
         set_setting_bool_to_q_setting(&q_settings, "expand_treeview_when_adding_items", self.general_packfile_treeview_expand_treeview_when_adding_items_checkbox.is_checked());
 
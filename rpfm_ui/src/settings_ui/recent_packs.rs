@@ -0,0 +1,155 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to remember the Packs opened recently.
+
+The list is stored in a json file in the config folder, with the most recent Pack first.
+Each Pack remembers the game it was opened under, so the list can be filtered by the Game Selected.
+!*/
+
+use qt_core::QString;
+
+use anyhow::Result;
+use getset::Getters;
+use serde_derive::{Serialize, Deserialize};
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::locks::read_game_selected;
+use crate::settings_ui::backend::{recent_packs_path, settings};
+
+/// Key of the setting where older versions stored the list of recent Packs.
+const OLD_RECENT_PACKS_KEY: &str = "recentFileList";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the list of recently opened Packs.
+#[derive(Clone, Debug, Default, PartialEq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct RecentPacks {
+
+    /// Recently opened Packs, from the most recent to the oldest.
+    packs: Vec<RecentPack>,
+}
+
+/// This struct contains the info of a recently opened Pack.
+#[derive(Clone, Debug, PartialEq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct RecentPack {
+    path: PathBuf,
+
+    /// Key of the game the Pack was opened under.
+    game: String,
+
+    /// Pinned Packs are never removed from the list automatically.
+    pinned: bool,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl RecentPacks {
+
+    /// This function loads the list of recent Packs.
+    ///
+    /// If there is no list stored, the one from older versions is imported. As it didn't store the game of each Pack,
+    /// all its Packs are assumed to be from the Game Selected.
+    pub fn load() -> Result<Self> {
+        let path = recent_packs_path()?;
+        if !path.is_file() {
+            let q_settings = settings();
+            let mut recent_packs = Self::default();
+            if q_settings.contains(&QString::from_std_str(OLD_RECENT_PACKS_KEY)) {
+                let paths = q_settings.value_1a(&QString::from_std_str(OLD_RECENT_PACKS_KEY)).to_string_list();
+                let game = read_game_selected().game_key_name();
+                for index in 0..paths.count_0a() {
+                    recent_packs.packs.push(RecentPack::new(&PathBuf::from(paths.at(index).to_std_string()), &game, false));
+                }
+            }
+
+            return Ok(recent_packs);
+        }
+
+        let file = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    /// This function saves the list of recent Packs, replacing the stored one.
+    pub fn save(&self) -> Result<()> {
+        let mut file = BufWriter::new(File::create(recent_packs_path()?)?);
+        file.write_all(serde_json::to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function adds a Pack to the top of the list, or moves it there if it was already in it.
+    ///
+    /// Once added, the oldest unpinned Packs of the same game are removed, so the game keeps at most `max_unpinned` of them.
+    pub fn add(&mut self, path: &Path, game: &str, max_unpinned: usize) {
+        let pinned = match self.packs.iter().position(|pack| pack.path == path) {
+            Some(index) => self.packs.remove(index).pinned,
+            None => false,
+        };
+
+        self.packs.insert(0, RecentPack::new(path, game, pinned));
+
+        let mut unpinned = 0;
+        self.packs.retain(|pack| {
+            if pack.pinned || pack.game != game {
+                true
+            } else {
+                unpinned += 1;
+                unpinned <= max_unpinned
+            }
+        });
+    }
+
+    /// This function removes a Pack from the list, pinned or not.
+    pub fn remove(&mut self, path: &Path) {
+        self.packs.retain(|pack| pack.path != path);
+    }
+
+    /// This function pins or unpins a Pack of the list.
+    pub fn set_pinned(&mut self, path: &Path, pinned: bool) {
+        if let Some(pack) = self.packs.iter_mut().find(|pack| pack.path == path) {
+            pack.pinned = pinned;
+        }
+    }
+
+    /// This function returns the Packs that should be shown in the list, pinned ones first.
+    ///
+    /// If a game is provided, only the Packs opened under that game are returned.
+    pub fn visible(&self, game: Option<&str>) -> Vec<&RecentPack> {
+        let mut packs = self.packs.iter()
+            .filter(|pack| game.map(|game| pack.game == game).unwrap_or(true))
+            .collect::<Vec<_>>();
+
+        // Stable sort, so the order between Packs is kept within each group.
+        packs.sort_by_key(|pack| !pack.pinned);
+        packs
+    }
+}
+
+impl RecentPack {
+
+    /// This function creates a new recent Pack entry.
+    pub fn new(path: &Path, game: &str, pinned: bool) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            game: game.to_owned(),
+            pinned,
+        }
+    }
+}
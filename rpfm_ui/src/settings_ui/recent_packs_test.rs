@@ -0,0 +1,59 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the list of recent Packs.
+
+use std::path::{Path, PathBuf};
+
+use super::recent_packs::RecentPacks;
+
+fn paths(recent_packs: &RecentPacks, game: Option<&str>) -> Vec<PathBuf> {
+    recent_packs.visible(game).iter().map(|pack| pack.path().to_path_buf()).collect()
+}
+
+#[test]
+fn test_add() {
+    let mut recent_packs = RecentPacks::default();
+    recent_packs.add(Path::new("a.pack"), "warhammer_3", 2);
+    recent_packs.add(Path::new("b.pack"), "warhammer_3", 2);
+    recent_packs.add(Path::new("a.pack"), "warhammer_3", 2);
+    assert_eq!(paths(&recent_packs, None), vec![PathBuf::from("a.pack"), PathBuf::from("b.pack")]);
+
+    // Once over the limit, the oldest Pack gets removed.
+    recent_packs.add(Path::new("c.pack"), "warhammer_3", 2);
+    assert_eq!(paths(&recent_packs, None), vec![PathBuf::from("c.pack"), PathBuf::from("a.pack")]);
+
+    // Packs from other games don't count for the limit.
+    recent_packs.add(Path::new("d.pack"), "troy", 2);
+    assert_eq!(paths(&recent_packs, Some("warhammer_3")), vec![PathBuf::from("c.pack"), PathBuf::from("a.pack")]);
+    assert_eq!(paths(&recent_packs, Some("troy")), vec![PathBuf::from("d.pack")]);
+    assert_eq!(recent_packs.packs().len(), 3);
+}
+
+#[test]
+fn test_pinned() {
+    let mut recent_packs = RecentPacks::default();
+    recent_packs.add(Path::new("a.pack"), "warhammer_3", 1);
+    recent_packs.set_pinned(Path::new("a.pack"), true);
+
+    // Pinned Packs are shown first, and never rotate out.
+    recent_packs.add(Path::new("b.pack"), "warhammer_3", 1);
+    recent_packs.add(Path::new("c.pack"), "warhammer_3", 1);
+    assert_eq!(paths(&recent_packs, None), vec![PathBuf::from("a.pack"), PathBuf::from("c.pack")]);
+
+    // Opening a pinned Pack again keeps it pinned.
+    recent_packs.add(Path::new("a.pack"), "warhammer_3", 1);
+    assert!(*recent_packs.packs()[0].pinned());
+
+    recent_packs.set_pinned(Path::new("a.pack"), false);
+    recent_packs.remove(Path::new("c.pack"));
+    assert_eq!(paths(&recent_packs, None), vec![PathBuf::from("a.pack")]);
+    assert!(!*recent_packs.packs()[0].pinned());
+}
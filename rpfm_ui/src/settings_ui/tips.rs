@@ -56,6 +56,7 @@ pub unsafe fn set_tips(settings_ui: &Rc<SettingsUI>) {
     //-----------------------------------------------//
 
     let autosave_amount_tip = qtr("tt_settings_autosave_amount");
+    let autosave_size_warning_threshold_tip = qtr("tt_settings_autosave_size_warning_threshold");
     let extra_network_check_updates_on_start_tip = qtr("tt_extra_network_check_updates_on_start_tip");
     let extra_network_check_schema_updates_on_start_tip = qtr("tt_extra_network_check_schema_updates_on_start_tip");
     let extra_packfile_allow_editing_of_ca_packfiles_tip = qtr("tt_extra_packfile_allow_editing_of_ca_packfiles_tip");
@@ -63,9 +64,17 @@ pub unsafe fn set_tips(settings_ui: &Rc<SettingsUI>) {
     let extra_packfile_use_lazy_loading_tip = qtr("tt_extra_packfile_use_lazy_loading_tip");
     let extra_disable_uuid_regeneration_on_db_tables_label_tip = qtr("tt_extra_disable_uuid_regeneration_on_db_tables_label_tip");
     let extra_packfile_disable_file_previews_tip = qtr("tt_settings_disable_file_previews_tip");
+    let extra_packfile_restore_tab_sessions_tip = qtr("tt_settings_restore_tab_sessions_tip");
+    let extra_packfile_recent_packs_limit_tip = qtr("tt_settings_recent_packs_limit");
+    let extra_packfile_container_undo_memory_cap_tip = qtr("tt_settings_container_undo_memory_cap");
+    let extra_packfile_container_undo_clear_on_save_tip = qtr("tt_settings_container_undo_clear_on_save");
+    let extra_packfile_memory_ceiling_tip = qtr("tt_settings_memory_ceiling");
+    let extra_packfile_watch_open_pack_tip = qtr("tt_settings_watch_open_pack");
 
     settings_ui.extra_packfile_autosave_amount_label.set_tool_tip(&autosave_amount_tip);
     settings_ui.extra_packfile_autosave_amount_spinbox.set_tool_tip(&autosave_amount_tip);
+    settings_ui.extra_packfile_autosave_size_warning_threshold_label.set_tool_tip(&autosave_size_warning_threshold_tip);
+    settings_ui.extra_packfile_autosave_size_warning_threshold_spinbox.set_tool_tip(&autosave_size_warning_threshold_tip);
 
     settings_ui.extra_network_check_updates_on_start_label.set_tool_tip(&extra_network_check_updates_on_start_tip);
     settings_ui.extra_network_check_updates_on_start_checkbox.set_tool_tip(&extra_network_check_updates_on_start_tip);
@@ -81,6 +90,18 @@ pub unsafe fn set_tips(settings_ui: &Rc<SettingsUI>) {
     settings_ui.extra_packfile_disable_uuid_regeneration_on_db_tables_checkbox.set_tool_tip(&extra_disable_uuid_regeneration_on_db_tables_label_tip);
     settings_ui.extra_packfile_disable_file_previews_label.set_tool_tip(&extra_packfile_disable_file_previews_tip);
     settings_ui.extra_packfile_disable_file_previews_checkbox.set_tool_tip(&extra_packfile_disable_file_previews_tip);
+    settings_ui.extra_packfile_restore_tab_sessions_label.set_tool_tip(&extra_packfile_restore_tab_sessions_tip);
+    settings_ui.extra_packfile_restore_tab_sessions_checkbox.set_tool_tip(&extra_packfile_restore_tab_sessions_tip);
+    settings_ui.extra_packfile_recent_packs_limit_label.set_tool_tip(&extra_packfile_recent_packs_limit_tip);
+    settings_ui.extra_packfile_recent_packs_limit_spinbox.set_tool_tip(&extra_packfile_recent_packs_limit_tip);
+    settings_ui.extra_packfile_container_undo_memory_cap_label.set_tool_tip(&extra_packfile_container_undo_memory_cap_tip);
+    settings_ui.extra_packfile_container_undo_memory_cap_spinbox.set_tool_tip(&extra_packfile_container_undo_memory_cap_tip);
+    settings_ui.extra_packfile_container_undo_clear_on_save_label.set_tool_tip(&extra_packfile_container_undo_clear_on_save_tip);
+    settings_ui.extra_packfile_container_undo_clear_on_save_checkbox.set_tool_tip(&extra_packfile_container_undo_clear_on_save_tip);
+    settings_ui.extra_packfile_memory_ceiling_label.set_tool_tip(&extra_packfile_memory_ceiling_tip);
+    settings_ui.extra_packfile_memory_ceiling_spinbox.set_tool_tip(&extra_packfile_memory_ceiling_tip);
+    settings_ui.extra_packfile_watch_open_pack_label.set_tool_tip(&extra_packfile_watch_open_pack_tip);
+    settings_ui.extra_packfile_watch_open_pack_checkbox.set_tool_tip(&extra_packfile_watch_open_pack_tip);
 
     //-----------------------------------------------//
     // `Debug` tips.
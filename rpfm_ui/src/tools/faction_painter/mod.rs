@@ -145,7 +145,7 @@ impl ToolFactionPainter {
 
         // Initialize a Tool. This also performs some common checks to ensure we can actually use the tool.
         // TODO: Move this to a tool var.
-        let paths = match &*GAME_SELECTED.read().unwrap().game_key_name() {
+        let paths = match &*read_game_selected().game_key_name() {
             KEY_WARHAMMER_3 => vec![
                 ContainerPath::Folder("db/factions_tables".to_owned()),
                 ContainerPath::Folder("text".to_owned()),
@@ -378,7 +378,7 @@ impl ToolFactionPainter {
 
         // We have to save the data to the last entry of the keys in out list, so if any of the other fields is edited on it, that edition is kept.
         let mut files_to_save = vec![];
-        match &*GAME_SELECTED.read().unwrap().game_key_name() {
+        match &*read_game_selected().game_key_name() {
             KEY_WARHAMMER_3 => {
                 files_to_save.push(self.save_factions_data(&data_to_save)?);
             }
@@ -592,15 +592,15 @@ impl ToolFactionPainter {
 
     /// This function gets the data needed for the tool from the factions table.
     unsafe fn get_faction_data(&self, data: &mut HashMap<String, RFile>, processed_data: &mut HashMap<String, HashMap<String, String>>, data_source: DataSource) -> Result<()> {
-        let row_key = GAME_SELECTED.read().unwrap().tool_var("faction_painter_factions_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_factions_row_key".to_owned()))?;
+        let row_key = read_game_selected().tool_var("faction_painter_factions_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_factions_row_key".to_owned()))?;
 
-        let banner_primary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_primary_colour_column_name".to_owned()))?;
-        let banner_secondary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_secondary_colour_column_name".to_owned()))?;
-        let banner_tertiary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_tertiary_colour_column_name".to_owned()))?;
+        let banner_primary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_primary_colour_column_name".to_owned()))?;
+        let banner_secondary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_secondary_colour_column_name".to_owned()))?;
+        let banner_tertiary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_tertiary_colour_column_name".to_owned()))?;
 
-        let uniform_primary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_primary_colour_column_name".to_owned()))?;
-        let uniform_secondary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_secondary_colour_column_name".to_owned()))?;
-        let uniform_tertiary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_tertiary_colour_column_name".to_owned()))?;
+        let uniform_primary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_primary_colour_column_name".to_owned()))?;
+        let uniform_secondary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_secondary_colour_column_name".to_owned()))?;
+        let uniform_tertiary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_tertiary_colour_column_name".to_owned()))?;
 
         // First, get the keys, names and flags from the factions tables.
         for (path, packed_file) in data.iter_mut() {
@@ -612,13 +612,13 @@ impl ToolFactionPainter {
                     let flag_path_column = table.column_position_by_name("flags_path").ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), "flags_path".to_string()))?;
 
                     // Only used for WH3.
-                    let banner_primary_colour_column = if GAME_SELECTED.read().unwrap().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(banner_primary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), banner_primary_colour_column_name.to_string()))? } else { 0 };
-                    let banner_secondary_colour_column = if GAME_SELECTED.read().unwrap().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(banner_secondary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), banner_secondary_colour_column_name.to_string()))? } else { 0 };
-                    let banner_tertiary_colour_column = if GAME_SELECTED.read().unwrap().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(banner_tertiary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), banner_tertiary_colour_column_name.to_string()))? } else { 0 };
+                    let banner_primary_colour_column = if read_game_selected().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(banner_primary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), banner_primary_colour_column_name.to_string()))? } else { 0 };
+                    let banner_secondary_colour_column = if read_game_selected().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(banner_secondary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), banner_secondary_colour_column_name.to_string()))? } else { 0 };
+                    let banner_tertiary_colour_column = if read_game_selected().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(banner_tertiary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), banner_tertiary_colour_column_name.to_string()))? } else { 0 };
 
-                    let uniform_primary_colour_column = if GAME_SELECTED.read().unwrap().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(uniform_primary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), uniform_primary_colour_column_name.to_string()))? } else { 0 };
-                    let uniform_secondary_colour_column = if GAME_SELECTED.read().unwrap().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(uniform_secondary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), uniform_secondary_colour_column_name.to_string()))? } else { 0 };
-                    let uniform_tertiary_colour_column = if GAME_SELECTED.read().unwrap().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(uniform_tertiary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), uniform_tertiary_colour_column_name.to_string()))? } else { 0 };
+                    let uniform_primary_colour_column = if read_game_selected().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(uniform_primary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), uniform_primary_colour_column_name.to_string()))? } else { 0 };
+                    let uniform_secondary_colour_column = if read_game_selected().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(uniform_secondary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), uniform_secondary_colour_column_name.to_string()))? } else { 0 };
+                    let uniform_tertiary_colour_column = if read_game_selected().game_key_name() == KEY_WARHAMMER_3 { table.column_position_by_name(uniform_tertiary_colour_column_name).ok_or_else(|| ToolsError::MissingColumnInTable(table.table_name().to_string(), uniform_tertiary_colour_column_name.to_string()))? } else { 0 };
 
                     let definition = serde_json::to_string(table.definition())?;
                     for row in table.data(&None)?.iter() {
@@ -651,7 +651,7 @@ impl ToolFactionPainter {
                         }
 
                         // In WH3 the 3 tables were merged into factions, so we have to check here for their data
-                        if GAME_SELECTED.read().unwrap().game_key_name() == KEY_WARHAMMER_3 {
+                        if read_game_selected().game_key_name() == KEY_WARHAMMER_3 {
                             let banner_primary_row_by_column = Tool::get_row_by_column_index(row, banner_primary_colour_column)?;
                             let banner_secondary_row_by_column = Tool::get_row_by_column_index(row, banner_secondary_colour_column)?;
                             let banner_tertiary_row_by_column = Tool::get_row_by_column_index(row, banner_tertiary_colour_column)?;
@@ -765,13 +765,13 @@ impl ToolFactionPainter {
     /// This function gets the data needed for the tool from the faction_banners table.
     unsafe fn get_faction_banner_data(&self, data: &mut HashMap<String, RFile>, processed_data: &mut HashMap<String, HashMap<String, String>>, data_source: DataSource) -> Result<()> {
 
-        let table_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_table_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_table_name".to_owned()))?;
-        let table_definition_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_table_definition").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_table_definition".to_owned()))?;
-        let key_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_key_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_key_column_name".to_owned()))?;
-        let primary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_primary_colour_column_name".to_owned()))?;
-        let secondary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_secondary_colour_column_name".to_owned()))?;
-        let tertiary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_tertiary_colour_column_name".to_owned()))?;
-        let row_key = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_row_key".to_owned()))?;
+        let table_name = read_game_selected().tool_var("faction_painter_banner_table_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_table_name".to_owned()))?;
+        let table_definition_name = read_game_selected().tool_var("faction_painter_banner_table_definition").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_table_definition".to_owned()))?;
+        let key_column_name = read_game_selected().tool_var("faction_painter_banner_key_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_key_column_name".to_owned()))?;
+        let primary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_primary_colour_column_name".to_owned()))?;
+        let secondary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_secondary_colour_column_name".to_owned()))?;
+        let tertiary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_tertiary_colour_column_name".to_owned()))?;
+        let row_key = read_game_selected().tool_var("faction_painter_banner_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_row_key".to_owned()))?;
 
         for (path, packed_file) in data.iter_mut() {
             if path.to_lowercase().starts_with(&format!("db/{}/", table_name)) {
@@ -849,13 +849,13 @@ impl ToolFactionPainter {
     /// This function gets the data needed for the tool from the faction_uniform_colours table.
     unsafe fn get_faction_uniform_data(&self, data: &mut HashMap<String, RFile>, processed_data: &mut HashMap<String, HashMap<String, String>>, data_source: DataSource) -> Result<()> {
 
-        let table_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_table_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_table_name".to_owned()))?;
-        let table_definition_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_table_definition").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_table_definition".to_owned()))?;
-        let key_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_key_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_key_column_name".to_owned()))?;
-        let primary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_primary_colour_column_name".to_owned()))?;
-        let secondary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_secondary_colour_column_name".to_owned()))?;
-        let tertiary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_tertiary_colour_column_name".to_owned()))?;
-        let row_key = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_row_key".to_owned()))?;
+        let table_name = read_game_selected().tool_var("faction_painter_uniform_table_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_table_name".to_owned()))?;
+        let table_definition_name = read_game_selected().tool_var("faction_painter_uniform_table_definition").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_table_definition".to_owned()))?;
+        let key_column_name = read_game_selected().tool_var("faction_painter_uniform_key_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_key_column_name".to_owned()))?;
+        let primary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_primary_colour_column_name".to_owned()))?;
+        let secondary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_secondary_colour_column_name".to_owned()))?;
+        let tertiary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_tertiary_colour_column_name".to_owned()))?;
+        let row_key = read_game_selected().tool_var("faction_painter_uniform_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_row_key".to_owned()))?;
 
         for (path, packed_file) in data.iter_mut() {
             if path.to_lowercase().starts_with(&format!("db/{}/", table_name)) {
@@ -932,17 +932,17 @@ impl ToolFactionPainter {
 
     /// This function takes care of saving the factions's data into a RFile.
     unsafe fn save_factions_data(&self, data: &[HashMap<String, String>]) -> Result<RFile> {
-        let table_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_factions_table_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_factions_table_name".to_owned()))?;
-        let table_definition_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_factions_table_definition").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_factions_table_definition".to_owned()))?;
-        let row_key = GAME_SELECTED.read().unwrap().tool_var("faction_painter_factions_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_factions_row_key".to_owned()))?;
+        let table_name = read_game_selected().tool_var("faction_painter_factions_table_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_factions_table_name".to_owned()))?;
+        let table_definition_name = read_game_selected().tool_var("faction_painter_factions_table_definition").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_factions_table_definition".to_owned()))?;
+        let row_key = read_game_selected().tool_var("faction_painter_factions_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_factions_row_key".to_owned()))?;
 
-        let banner_primary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_primary_colour_column_name".to_owned()))?;
-        let banner_secondary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_secondary_colour_column_name".to_owned()))?;
-        let banner_tertiary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_tertiary_colour_column_name".to_owned()))?;
+        let banner_primary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_primary_colour_column_name".to_owned()))?;
+        let banner_secondary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_secondary_colour_column_name".to_owned()))?;
+        let banner_tertiary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_tertiary_colour_column_name".to_owned()))?;
 
-        let uniform_primary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_primary_colour_column_name".to_owned()))?;
-        let uniform_secondary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_secondary_colour_column_name".to_owned()))?;
-        let uniform_tertiary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_tertiary_colour_column_name".to_owned()))?;
+        let uniform_primary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_primary_colour_column_name".to_owned()))?;
+        let uniform_secondary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_secondary_colour_column_name".to_owned()))?;
+        let uniform_tertiary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_tertiary_colour_column_name".to_owned()))?;
 
         if let Some(first) = data.iter().next() {
             if let Some(definition) = first.get(table_definition_name) {
@@ -1001,14 +1001,14 @@ impl ToolFactionPainter {
     /// This function takes care of saving the banner's data into a RFile.
     unsafe fn save_faction_banner_data(&self, data: &[HashMap<String, String>]) -> Result<RFile> {
 
-        let table_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_table_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_table_name".to_owned()))?;
-        let table_definition_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_table_definition").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_table_definition".to_owned()))?;
-        let primary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_primary_colour_column_name".to_owned()))?;
-        let secondary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_secondary_colour_column_name".to_owned()))?;
-        let tertiary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_tertiary_colour_column_name".to_owned()))?;
-        let row_key = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_row_key".to_owned()))?;
+        let table_name = read_game_selected().tool_var("faction_painter_banner_table_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_table_name".to_owned()))?;
+        let table_definition_name = read_game_selected().tool_var("faction_painter_banner_table_definition").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_table_definition".to_owned()))?;
+        let primary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_primary_colour_column_name".to_owned()))?;
+        let secondary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_secondary_colour_column_name".to_owned()))?;
+        let tertiary_colour_column_name = read_game_selected().tool_var("faction_painter_banner_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_tertiary_colour_column_name".to_owned()))?;
+        let row_key = read_game_selected().tool_var("faction_painter_banner_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_row_key".to_owned()))?;
 
-        let key_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_banner_key_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_key_column_name".to_owned()))?;
+        let key_column_name = read_game_selected().tool_var("faction_painter_banner_key_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_banner_key_column_name".to_owned()))?;
 
         if let Some(first) = data.iter().next() {
             if let Some(definition) = first.get(table_definition_name) {
@@ -1066,14 +1066,14 @@ impl ToolFactionPainter {
     /// This function takes care of saving the banner's data into a RFile.
     unsafe fn save_faction_uniform_data(&self, data: &[HashMap<String, String>]) -> Result<RFile> {
 
-        let table_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_table_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_table_name".to_owned()))?;
-        let table_definition_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_table_definition").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_table_definition".to_owned()))?;
-        let primary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_primary_colour_column_name".to_owned()))?;
-        let secondary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_secondary_colour_column_name".to_owned()))?;
-        let tertiary_colour_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_tertiary_colour_column_name".to_owned()))?;
-        let row_key = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_row_key".to_owned()))?;
+        let table_name = read_game_selected().tool_var("faction_painter_uniform_table_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_table_name".to_owned()))?;
+        let table_definition_name = read_game_selected().tool_var("faction_painter_uniform_table_definition").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_table_definition".to_owned()))?;
+        let primary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_primary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_primary_colour_column_name".to_owned()))?;
+        let secondary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_secondary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_secondary_colour_column_name".to_owned()))?;
+        let tertiary_colour_column_name = read_game_selected().tool_var("faction_painter_uniform_tertiary_colour_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_tertiary_colour_column_name".to_owned()))?;
+        let row_key = read_game_selected().tool_var("faction_painter_uniform_row_key").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_row_key".to_owned()))?;
 
-        let key_column_name = GAME_SELECTED.read().unwrap().tool_var("faction_painter_uniform_key_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_key_column_name".to_owned()))?;
+        let key_column_name = read_game_selected().tool_var("faction_painter_uniform_key_column_name").ok_or_else(|| ToolsError::ToolVarNotFoundForGame("faction_painter_uniform_key_column_name".to_owned()))?;
 
         if let Some(first) = data.iter().next() {
             if let Some(definition) = first.get(table_definition_name) {
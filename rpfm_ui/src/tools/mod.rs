@@ -54,12 +54,11 @@ use crate::communications::{CentralCommand, Command, Response, THREADS_COMMUNICA
 use crate::dependencies_ui::DependenciesUI;
 use crate::diagnostics_ui::DiagnosticsUI;
 use crate::ffi::*;
-use crate::GAME_SELECTED;
+use crate::locks::{read_game_selected, read_schema};
 use crate::global_search_ui::GlobalSearchUI;
 use crate::packedfile_views::DataSource;
 use crate::pack_tree::{PackTree, TreeViewOperation};
 use crate::packfile_contents_ui::PackFileContentsUI;
-use crate::SCHEMA;
 use crate::utils::*;
 use crate::UI_STATE;
 use crate::views::table::utils::clean_column_names;
@@ -138,11 +137,11 @@ impl Tool {
         // - Dependencies cache generated and up-to-date.
         //
         // These requirements are common for all tools, so they're checked here.
-        if tool_supported_games.iter().all(|x| *x != GAME_SELECTED.read().unwrap().game_key_name()) {
+        if tool_supported_games.iter().all(|x| *x != read_game_selected().game_key_name()) {
             return Err(ToolsError::GameSelectedNotSupportedForTool.into());
         }
 
-        if SCHEMA.read().unwrap().is_none() {
+        if read_schema().is_none() {
             return Err(ToolsError::SchemaNotFound.into());
         }
 
@@ -55,11 +55,17 @@ use crate::dependencies_ui::slots::DependenciesUISlots;
 use crate::diagnostics_ui;
 use crate::diagnostics_ui::DiagnosticsUI;
 use crate::diagnostics_ui::slots::DiagnosticsUISlots;
-use crate::GAME_SELECTED;
+use crate::file_info_ui;
+use crate::file_info_ui::FileInfoUI;
+use crate::file_info_ui::slots::FileInfoUISlots;
+use crate::locks::read_game_selected;
 use crate::GAME_SELECTED_ICONS;
 use crate::global_search_ui;
 use crate::global_search_ui::GlobalSearchUI;
 use crate::global_search_ui::slots::GlobalSearchSlots;
+use crate::operations_ui;
+use crate::operations_ui::OperationsUI;
+use crate::operations_ui::slots::OperationsUISlots;
 use crate::references_ui;
 use crate::references_ui::ReferencesUI;
 use crate::references_ui::slots::ReferencesUISlots;
@@ -124,6 +130,8 @@ impl UI {
         let diagnostics_ui = Rc::new(DiagnosticsUI::new(app_ui.main_window())?);
         let dependencies_ui = Rc::new(DependenciesUI::new(&app_ui)?);
         let references_ui = Rc::new(ReferencesUI::new(app_ui.main_window())?);
+        let operations_ui = Rc::new(OperationsUI::new(&app_ui)?);
+        let file_info_ui = Rc::new(FileInfoUI::new(&app_ui)?);
 
         AppUITempSlots::build(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui);
 
@@ -133,6 +141,8 @@ impl UI {
         let diagnostics_slots = DiagnosticsUISlots::new(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui);
         let dependencies_slots = DependenciesUISlots::new(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui);
         let references_slots = ReferencesUISlots::new(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui);
+        let operations_slots = OperationsUISlots::new(&operations_ui, &app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui);
+        let file_info_slots = FileInfoUISlots::new(&file_info_ui, &pack_file_contents_ui, &dependencies_ui);
 
         app_ui::connections::set_connections(&app_ui, &app_slots);
         app_ui::tips::set_tips(&app_ui);
@@ -148,6 +158,8 @@ impl UI {
 
         diagnostics_ui::connections::set_connections(&diagnostics_ui, &diagnostics_slots);
         references_ui::connections::set_connections(&references_ui, &references_slots);
+        operations_ui::connections::set_connections(&operations_ui, &operations_slots);
+        file_info_ui::connections::set_connections(&file_info_ui, &file_info_slots, &pack_file_contents_ui, &dependencies_ui);
 
         // Initialize settings.
         init_settings(&app_ui);
@@ -209,9 +221,9 @@ dbg!(t.elapsed().unwrap());
 
             info!("Directly opening Pack/s {:?}.", paths);
             if let Err(error) = AppUI::open_packfile(&app_ui, &pack_file_contents_ui, &global_search_ui, &paths, "") {
-                show_dialog(app_ui.main_window(), error, false);
+                AppUI::show_error_dialog(&app_ui, &error);
             } else if setting_bool("diagnostics_trigger_on_open") {
-                DiagnosticsUI::check(&app_ui, &diagnostics_ui);
+                DiagnosticsUI::check_when_ready(&app_ui, &diagnostics_ui);
             }
         }
 dbg!(t.elapsed().unwrap());
@@ -295,7 +307,7 @@ impl GameSelectedIcons {
 
     /// This function sets the main window icon according to the currently selected game.
     pub unsafe fn set_game_selected_icon(app_ui: &Rc<AppUI>) {
-        let (icon, big_icon) = match &*GAME_SELECTED.read().unwrap().game_key_name() {
+        let (icon, big_icon) = match &*read_game_selected().game_key_name() {
             KEY_WARHAMMER_3 => &GAME_SELECTED_ICONS.warhammer_3,
             KEY_TROY => &GAME_SELECTED_ICONS.troy,
             KEY_THREE_KINGDOMS => &GAME_SELECTED_ICONS.three_kingdoms,
@@ -16,6 +16,9 @@ This module contains the code needed to keep track of the current state of the U
 
 use qt_core::QEventLoop;
 
+use crossbeam::channel::Receiver;
+
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -24,10 +27,17 @@ use std::rc::Rc;
 use rpfm_extensions::diagnostics::Diagnostics;
 use rpfm_extensions::search::GlobalSearch;
 
+use rpfm_lib::files::pack::{RESERVED_NAME_DEPENDENCIES_MANAGER, RESERVED_RFILE_NAMES};
+
 use crate::app_ui::AppUI;
-use crate::packedfile_views::PackedFileView;
+use crate::communications::Response;
+use crate::packedfile_views::{DataSource, decoder::DECODER_EXTENSION, PackedFileView, ViewType};
 use crate::packfile_contents_ui::PackFileContentsUI;
 
+use self::tab_session::{TabSession, TabSessionEntry};
+
+pub mod tab_session;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -38,12 +48,18 @@ pub struct UIState {
     /// This stores the current state of the PackFile.
     is_modified: AtomicBool,
 
+    /// This stores if the PackFile has been modified since it was last autosaved.
+    is_modified_since_autosave: AtomicBool,
+
     /// This stores if we have put the `PackFile Contents` view in read-only mode.
     packfile_contents_read_only: AtomicBool,
 
     /// This stores the list to all the widgets of the open PackedFiles.
     open_packedfiles: Arc<RwLock<Vec<PackedFileView>>>,
 
+    /// This stores the paths of the PackedFiles edited since the open PackFile was last saved.
+    modified_paths: Arc<RwLock<HashSet<String>>>,
+
     /// This stores the current operational mode of the application.
     operational_mode: Arc<RwLock<OperationalMode>>,
 
@@ -52,6 +68,13 @@ pub struct UIState {
 
     /// This stores the current `Diagnostics`.
     diagnostics: Arc<RwLock<Diagnostics>>,
+
+    /// This stores the receiver of the dependencies rebuild still running in the background, if any,
+    /// and if the game selected changed with it.
+    pending_dependencies: Arc<RwLock<Option<(Receiver<Response>, bool)>>>,
+
+    /// This stores if a diagnostics check is waiting for the dependencies to finish rebuilding.
+    diagnostics_queued: AtomicBool,
 }
 
 /// This enum represent the current ***Operational Mode*** for RPFM.
@@ -76,11 +99,15 @@ impl Default for UIState {
     fn default() -> Self {
         Self {
             is_modified: AtomicBool::new(false),
+            is_modified_since_autosave: AtomicBool::new(false),
             packfile_contents_read_only: AtomicBool::new(false),
             open_packedfiles: Arc::new(RwLock::new(vec![])),
+            modified_paths: Arc::new(RwLock::new(HashSet::new())),
             operational_mode: Arc::new(RwLock::new(OperationalMode::Normal)),
             global_search: Arc::new(RwLock::new(GlobalSearch::default())),
             diagnostics: Arc::new(RwLock::new(Diagnostics::default())),
+            pending_dependencies: Arc::new(RwLock::new(None)),
+            diagnostics_queued: AtomicBool::new(false),
         }
     }
 }
@@ -94,11 +121,45 @@ impl UIState {
     }
 
     /// This function sets the flag that stores if the open PackFile has been modified or not.
+    ///
+    /// Setting it to false also forgets the PackedFiles edited until now.
     pub unsafe fn set_is_modified(&self, is_modified: bool, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
         self.is_modified.store(is_modified, Ordering::SeqCst);
+        self.is_modified_since_autosave.store(is_modified, Ordering::SeqCst);
+        if !is_modified {
+            self.modified_paths.write().unwrap().clear();
+
+            // Once the Pack is saved or reloaded, the warning about it changing on disk no longer applies.
+            app_ui.pack_changed_on_disk_banner().hide();
+        }
+
         AppUI::update_window_title(app_ui, pack_file_contents_ui);
     }
 
+    /// This function returns if the open PackFile has been modified since it was last autosaved, and resets the flag.
+    ///
+    /// Only the edits done after calling this count for the next autosave.
+    pub fn take_is_modified_since_autosave(&self) -> bool {
+        self.is_modified_since_autosave.swap(false, Ordering::SeqCst)
+    }
+
+    /// This function marks the PackedFile with the provided path as edited since the open PackFile was last saved.
+    pub fn add_modified_path(&self, path: &str) {
+        self.modified_paths.write().unwrap().insert(path.to_owned());
+    }
+
+    /// This function returns the sorted paths of the open PackedFiles edited since the open PackFile was last saved.
+    pub fn get_unsaved_open_packedfiles(&self) -> Vec<String> {
+        let modified_paths = self.modified_paths.read().unwrap();
+        let mut paths = self.get_open_packedfiles().iter()
+            .filter(|view| view.get_data_source() == DataSource::PackFile && modified_paths.contains(&view.get_path()))
+            .map(|view| view.get_path())
+            .collect::<Vec<_>>();
+
+        paths.sort();
+        paths
+    }
+
     /// This function gets if the `PackFile Contents` TreeView is in read-only mode or not.
     pub fn get_packfile_contents_read_only(&self) -> bool {
         self.packfile_contents_read_only.load(Ordering::SeqCst)
@@ -135,6 +196,37 @@ impl UIState {
         }
     }
 
+    /// This function returns the session of the currently visible file views, in the same order they're in the tab bar.
+    ///
+    /// Views that cannot be reopened from a path, like external views or decoders, are not part of the session.
+    pub unsafe fn get_tab_session(&self, app_ui: &Rc<AppUI>) -> TabSession {
+        let tab_bar = app_ui.tab_bar_packed_file();
+        let mut tabs = self.get_open_packedfiles().iter()
+            .filter_map(|view| {
+                let index = tab_bar.index_of(view.get_mut_widget());
+                let path = view.get_ref_path();
+                let restorable = index != -1 &&
+                    !matches!(view.get_view(), ViewType::External(_)) &&
+                    !matches!(view.get_data_source(), DataSource::ExternalFile | DataSource::ComparedPackFile) &&
+                    !path.ends_with(DECODER_EXTENSION) &&
+                    *path != RESERVED_NAME_DEPENDENCIES_MANAGER &&
+                    !RESERVED_RFILE_NAMES.contains(&&**path);
+
+                if restorable {
+                    Some((index, TabSessionEntry::new(&path, view.get_data_source(), view.get_is_preview())))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        tabs.sort_by_key(|(index, _)| *index);
+
+        let current_index = tab_bar.current_index();
+        let active_tab = tabs.iter().position(|(index, _)| *index == current_index);
+        TabSession::new(tabs.into_iter().map(|(_, entry)| entry).collect(), active_tab)
+    }
+
     /// This function returns a reference to the current `Operational Mode`.
     pub fn get_operational_mode(&self) -> OperationalMode {
         self.operational_mode.read().unwrap().clone()
@@ -192,4 +284,29 @@ impl UIState {
     pub fn set_diagnostics(&self, diagnostics: &Diagnostics) {
         *self.diagnostics.write().unwrap() = diagnostics.clone();
     }
+
+    /// This function returns if the dependencies are still being rebuilt in the background.
+    pub fn get_dependencies_pending(&self) -> bool {
+        self.pending_dependencies.read().unwrap().is_some()
+    }
+
+    /// This function returns the receiver of the pending dependencies rebuild, and if the game selected changed with it.
+    pub fn get_pending_dependencies(&self) -> Option<(Receiver<Response>, bool)> {
+        self.pending_dependencies.read().unwrap().clone()
+    }
+
+    /// This function sets the receiver of the pending dependencies rebuild. Set it to `None` once the rebuild is done.
+    pub fn set_pending_dependencies(&self, pending_dependencies: Option<(Receiver<Response>, bool)>) {
+        *self.pending_dependencies.write().unwrap() = pending_dependencies;
+    }
+
+    /// This function returns if a diagnostics check is waiting for the dependencies, clearing the flag.
+    pub fn take_diagnostics_queued(&self) -> bool {
+        self.diagnostics_queued.swap(false, Ordering::SeqCst)
+    }
+
+    /// This function sets if a diagnostics check has to be done once the dependencies finish rebuilding.
+    pub fn set_diagnostics_queued(&self, diagnostics_queued: bool) {
+        self.diagnostics_queued.store(diagnostics_queued, Ordering::SeqCst);
+    }
 }
@@ -0,0 +1,115 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to remember the open files of a Pack between sessions.
+
+Sessions are stored per Pack path, in a json file in the config folder.
+!*/
+
+use anyhow::Result;
+use getset::Getters;
+use serde_derive::{Serialize, Deserialize};
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::packedfile_views::DataSource;
+use crate::settings_ui::backend::tab_sessions_path;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the list of file views open in a Pack, so they can be reopened later.
+#[derive(Clone, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct TabSession {
+
+    /// Open file views, in the same order they had in the tab bar.
+    tabs: Vec<TabSessionEntry>,
+
+    /// Position in `tabs` of the view that was active when the session got saved.
+    active_tab: Option<usize>,
+}
+
+/// This struct contains the info needed to reopen a file view.
+#[derive(Clone, Debug, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct TabSessionEntry {
+    path: String,
+    data_source: DataSource,
+    is_preview: bool,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl TabSession {
+
+    /// This function creates a new session with the provided views.
+    pub fn new(tabs: Vec<TabSessionEntry>, active_tab: Option<usize>) -> Self {
+        Self {
+            tabs,
+            active_tab,
+        }
+    }
+
+    /// This function loads the session of the Pack at the provided path, if there's one.
+    pub fn load(pack_path: &Path) -> Result<Option<Self>> {
+        let mut sessions = Self::load_all()?;
+        Ok(sessions.remove(&pack_path.to_string_lossy().to_string()))
+    }
+
+    /// This function saves this session as the session of the Pack at the provided path.
+    ///
+    /// Empty sessions remove the stored session of the Pack, if any.
+    pub fn save(&self, pack_path: &Path) -> Result<()> {
+        let mut sessions = Self::load_all()?;
+        let key = pack_path.to_string_lossy().to_string();
+        if self.tabs.is_empty() {
+            if sessions.remove(&key).is_none() {
+                return Ok(());
+            }
+        } else {
+            sessions.insert(key, self.clone());
+        }
+
+        let mut file = BufWriter::new(File::create(tab_sessions_path()?)?);
+        file.write_all(serde_json::to_string_pretty(&sessions)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function loads all the stored sessions.
+    fn load_all() -> Result<BTreeMap<String, Self>> {
+        let path = tab_sessions_path()?;
+        if !path.is_file() {
+            return Ok(BTreeMap::new());
+        }
+
+        let file = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(file)?)
+    }
+}
+
+impl TabSessionEntry {
+
+    /// This function creates a new session entry.
+    pub fn new(path: &str, data_source: DataSource, is_preview: bool) -> Self {
+        Self {
+            path: path.to_owned(),
+            data_source,
+            is_preview,
+        }
+    }
+}
@@ -19,6 +19,7 @@ use qt_widgets::QGridLayout;
 use qt_widgets::QLabel;
 use qt_widgets::QMenu;
 use qt_widgets::{QMessageBox, q_message_box::{Icon, StandardButton}};
+use qt_widgets::QProgressDialog;
 use qt_widgets::QPushButton;
 use qt_widgets::QWidget;
 use qt_widgets::QMainWindow;
@@ -46,16 +47,21 @@ use cpp_core::StaticUpcast;
 use anyhow::{anyhow, Result};
 use regex::Regex;
 
-use rpfm_lib::files::pack::PackSettings;
+use rpfm_lib::files::pack::{DEFAULT_PATH_LABEL_DEFINITIONS, PackSettings, PADDING_SIZE_SETTING};
 use rpfm_lib::integrations::log::*;
 
 use std::convert::AsRef;
 use std::fmt::Display;
 use std::fs::File;
 use std::io::{BufReader, Read};
-use std::sync::atomic::{AtomicPtr, Ordering};
-
-use crate::{ASSETS_PATH, DARK_PALETTE, GAME_SELECTED, LIGHT_PALETTE, LIGHT_STYLE_SHEET, SENTRY_GUARD};
+use std::path::Path;
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+use std::process::Command;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+
+use crate::{ASSETS_PATH, DARK_PALETTE, LIGHT_PALETTE, LIGHT_STYLE_SHEET};
+use crate::locks::{read_game_selected, read_sentry_guard};
 use crate::ffi::*;
 use crate::locale::{qtr, qtre};
 use crate::setting_bool;
@@ -178,6 +184,51 @@ pub unsafe fn show_dialog<T: Display>(parent: impl cpp_core::CastInto<Ptr<QWidge
     message_box.exec();
 }
 
+/// This function creates a modal progress dialog without cancel button, to be updated with the progress reported by a background command.
+///
+/// It requires:
+/// - text: something that dereferences to `str`, to put in the dialog.
+pub unsafe fn new_progress_dialog<T: AsRef<str>>(parent: impl cpp_core::CastInto<Ptr<QWidget>>, text: T) -> QBox<QProgressDialog> {
+    let dialog = QProgressDialog::new_1a(parent);
+    dialog.set_window_title(&qtr("rpfm_title"));
+    dialog.set_label_text(&QString::from_std_str(text.as_ref()));
+    dialog.set_cancel_button(cpp_core::NullPtr);
+    dialog.set_minimum_duration(0);
+    dialog.set_modal(true);
+    dialog.set_range(0, 0);
+    dialog.show();
+    dialog
+}
+
+/// This function creates a modal progress dialog like `new_progress_dialog`, but with a cancel button that sets the provided cancellation token.
+///
+/// The dialog is not closed when cancelled, as the command may take a bit to stop, or even finish normally before noticing it.
+pub unsafe fn new_cancellable_progress_dialog<T: AsRef<str>>(parent: impl cpp_core::CastInto<Ptr<QWidget>>, text: T, cancel: &Arc<AtomicBool>) -> QBox<QProgressDialog> {
+    let dialog = new_progress_dialog(parent, text);
+    dialog.set_auto_close(false);
+    dialog.set_auto_reset(false);
+    dialog.set_cancel_button_text(&qtr("cancel"));
+
+    let cancel = cancel.clone();
+    let dialog_ptr = dialog.as_ptr();
+    let slot_cancel = SlotNoArgs::new(&dialog, move || {
+        cancel.store(true, Ordering::SeqCst);
+        dialog_ptr.set_label_text(&qtr("operation_cancelling"));
+    });
+
+    dialog.canceled().connect(&slot_cancel);
+    dialog
+}
+
+/// This function updates a progress dialog created with `new_progress_dialog` with the progress reported by a background command.
+///
+/// A total of 0 turns the dialog into a busy indicator.
+pub unsafe fn update_progress_dialog(dialog: &QBox<QProgressDialog>, text: &str, current: u32, total: u32, description: &str) {
+    dialog.set_maximum(total as i32);
+    dialog.set_value(current as i32);
+    dialog.set_label_text(&QString::from_std_str(format!("{}\n\n{}", text, description)));
+}
+
 /// This function creates a non-modal dialog, for debugging purpouses.
 ///
 /// It requires:
@@ -254,7 +305,7 @@ pub unsafe fn show_undecoded_table_report_dialog(parent: Ptr<QWidget>, table_nam
     dialog.resize_2a(400, 50);
 
     let main_grid = create_grid_layout(dialog.static_upcast());
-    let explanation_label = QLabel::from_q_string_q_widget(&qtre("send_table_for_decoding_explanation", &[&GAME_SELECTED.read().unwrap().game_key_name(), &table_name]), &dialog);
+    let explanation_label = QLabel::from_q_string_q_widget(&qtre("send_table_for_decoding_explanation", &[&read_game_selected().game_key_name(), &table_name]), &dialog);
     let cancel_button = QPushButton::from_q_string(&qtr("cancel"));
     let accept_button = QPushButton::from_q_string(&qtr("send"));
 
@@ -263,8 +314,8 @@ pub unsafe fn show_undecoded_table_report_dialog(parent: Ptr<QWidget>, table_nam
     main_grid.add_widget_5a(&accept_button, 6, 1, 1, 1);
 
     let send_table_slot = SlotNoArgs::new(&dialog, move || {
-        let message = format!("{} - Request for table decoding: {}", GAME_SELECTED.read().unwrap().display_name(), table_name);
-        if let Err(error) = Logger::send_event(&SENTRY_GUARD.read().unwrap(), Level::Info, &message, Some((&table_name, &table_data))) {
+        let message = format!("{} - Request for table decoding: {}", read_game_selected().display_name(), table_name);
+        if let Err(error) = Logger::send_event(&read_sentry_guard(), Level::Info, &message, Some((&table_name, &table_data))) {
             show_dialog(parent, error, false)
         }
     });
@@ -352,6 +403,108 @@ pub fn get_feature_flags() -> String {
     feature_flags
 }
 
+/// This function opens the folder containing the provided path in the default file manager, selecting the path if possible.
+///
+/// On Linux the selection is done through the `org.freedesktop.FileManager1` DBus interface, and on Windows through explorer.
+/// If the file manager doesn't support selecting files, we fall back to just opening the folder.
+pub fn show_in_file_manager(path: &Path) -> Result<()> {
+    let path = path.canonicalize()?;
+
+    #[cfg(target_os = "linux")] {
+        let uri = format!("array:string:file://{}", percent_encode_path(&path.to_string_lossy()));
+        let result = Command::new("dbus-send")
+            .args(["--session", "--print-reply", "--dest=org.freedesktop.FileManager1", "--type=method_call", "/org/freedesktop/FileManager1", "org.freedesktop.FileManager1.ShowItems", &uri, "string:"])
+            .output();
+
+        match result {
+            Ok(output) if output.status.success() => return Ok(()),
+            Ok(output) => warn!("Failed to select {} in the file manager: {}", path.to_string_lossy(), String::from_utf8_lossy(&output.stderr)),
+            Err(error) => warn!("Failed to select {} in the file manager: {}", path.to_string_lossy(), error),
+        }
+    }
+
+    #[cfg(target_os = "windows")] {
+        use std::os::windows::process::CommandExt;
+
+        // Explorer returns an error code even when it works, so we can only check if it launched.
+        // Canonicalized paths on windows have a verbatim prefix explorer doesn't understand.
+        let path_str = path.to_string_lossy();
+        let path_str = path_str.strip_prefix(r"\\?\").unwrap_or(&path_str);
+        match Command::new("explorer").raw_arg(format!("/select,\"{}\"", path_str)).spawn() {
+            Ok(_) => return Ok(()),
+            Err(error) => warn!("Failed to select {} in the file manager: {}", path_str, error),
+        }
+    }
+
+    // Fallback: just open the folder.
+    let folder = if path.is_dir() { &path } else { path.parent().unwrap_or(&path) };
+    open::that(folder).map_err(From::from)
+}
+
+/// This function percent-encodes a path so it can be used in a `file://` uri.
+#[cfg(target_os = "linux")]
+fn percent_encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// This function returns the amount of physical memory used by RPFM, in bytes, or None if it cannot be checked.
+pub fn process_memory_usage() -> Option<u64> {
+
+    // The second value of statm is the resident set size, in pages.
+    #[cfg(target_os = "linux")] {
+        let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+        let pages = statm.split_whitespace().nth(1)?.parse::<u64>().ok()?;
+        Some(pages * 4096)
+    }
+
+    #[cfg(target_os = "windows")] {
+
+        // Layout of PROCESS_MEMORY_COUNTERS, from psapi.h.
+        #[repr(C)]
+        #[derive(Default)]
+        struct ProcessMemoryCounters {
+            cb: u32,
+            page_fault_count: u32,
+            peak_working_set_size: usize,
+            working_set_size: usize,
+            quota_peak_paged_pool_usage: usize,
+            quota_paged_pool_usage: usize,
+            quota_peak_non_paged_pool_usage: usize,
+            quota_non_paged_pool_usage: usize,
+            pagefile_usage: usize,
+            peak_pagefile_usage: usize,
+        }
+
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn GetCurrentProcess() -> isize;
+            fn K32GetProcessMemoryInfo(process: isize, counters: *mut ProcessMemoryCounters, cb: u32) -> i32;
+        }
+
+        let mut counters = ProcessMemoryCounters {
+            cb: std::mem::size_of::<ProcessMemoryCounters>() as u32,
+            ..Default::default()
+        };
+
+        if unsafe { K32GetProcessMemoryInfo(GetCurrentProcess(), &mut counters, counters.cb) } != 0 {
+            Some(counters.working_set_size as u64)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))] {
+        None
+    }
+}
+
 /// This function creates the stylesheet used for the dark theme in windows.
 pub fn dark_stylesheet() -> Result<String> {
     let mut file = File::open(ASSETS_PATH.join("dark-theme.qss"))?;
@@ -436,8 +589,13 @@ pub unsafe fn load_template(parent: impl CastInto<Ptr<QWidget>>, path: &str) ->
 
 pub fn initialize_pack_settings() -> PackSettings {
     let mut pack_settings = PackSettings::default();
-    pack_settings.settings_text_mut().insert("diagnostics_files_to_ignore".to_owned(), "".to_owned());
     pack_settings.settings_text_mut().insert("import_files_to_ignore".to_owned(), "".to_owned());
+    pack_settings.settings_text_mut().insert("compression_files_to_ignore".to_owned(), ".dds\n.ca_vp8\n.mp3".to_owned());
+    pack_settings.settings_text_mut().insert("mymod_export_rules".to_owned(), "".to_owned());
+    pack_settings.settings_text_mut().insert("path_label_definitions".to_owned(), DEFAULT_PATH_LABEL_DEFINITIONS.to_owned());
+    pack_settings.settings_text_mut().insert("translation_report_keys_to_ignore".to_owned(), "".to_owned());
     pack_settings.settings_bool_mut().insert("disable_autosaves".to_owned(), false);
+    pack_settings.settings_bool_mut().insert("generate_manifest_on_save".to_owned(), false);
+    pack_settings.settings_number_mut().insert(PADDING_SIZE_SETTING.to_owned(), 0);
     pack_settings
 }
@@ -73,9 +73,15 @@ impl DebugView {
             RFileDecoded::AnimFragment(data) => (FileType::AnimFragment, serde_json::to_string_pretty(&data)?),
             RFileDecoded::UnitVariant(data) => (FileType::UnitVariant, serde_json::to_string_pretty(&data)?),
             RFileDecoded::ESF(data) => (FileType::ESF, serde_json::to_string_pretty(&data)?),
+            RFileDecoded::SpeedTree(data) => (FileType::SpeedTree, serde_json::to_string_pretty(&data)?),
             _ => unimplemented!(),
         };
 
+        // SpeedTrees are read-only, as we only know their header.
+        if packed_file_type == FileType::SpeedTree {
+            save_button.set_visible(false);
+        }
+
         set_text_safe(&editor.static_upcast(), &QString::from_std_str(text).as_ptr(), &QString::from_std_str(JSON).as_ptr());
 
         let packed_file_debug_view = Arc::new(Self {
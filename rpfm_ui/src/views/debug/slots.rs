@@ -52,7 +52,7 @@ impl DebugViewSlots {
                     let receiver = CENTRAL_COMMAND.send_background(Command::SavePackedFileFromView(view.get_path(), decoded_packed_file));
                     let response = CENTRAL_COMMAND.recv_try(&receiver);
                     match response {
-                        Response::Success => log_to_status_bar(&tr("debug_view_save_success")),
+                        Response::Success | Response::VecLuaSyntaxError(_) => log_to_status_bar(&tr("debug_view_save_success")),
                         Response::Error(error) => show_dialog(&view.editor, error, false),
 
                         // In ANY other situation, it's a message problem.
@@ -0,0 +1,209 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+Module with the code to store column layouts (order, width and visibility) of table views.
+
+Presets are stored per table name (or file type, for tables without name) in a json file in the config folder.
+Columns are stored by field name, so presets survive schema updates that add or remove columns.
+!*/
+
+use anyhow::Result;
+use getset::Getters;
+use serde_derive::{Serialize, Deserialize};
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::settings_ui::backend::column_presets_path;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// This struct contains the column presets of a specific table.
+#[derive(Clone, Debug, Default, PartialEq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct TableColumnPresets {
+
+    /// Name of the preset to apply when opening the table, if any.
+    active: Option<String>,
+
+    /// Presets of the table, by name.
+    presets: BTreeMap<String, ColumnPreset>,
+}
+
+/// This struct contains a column layout, with the columns in the order they should be shown.
+#[derive(Clone, Debug, Default, PartialEq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct ColumnPreset {
+    columns: Vec<ColumnPresetEntry>,
+}
+
+/// This struct contains the layout of a specific column.
+#[derive(Clone, Debug, Default, PartialEq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct ColumnPresetEntry {
+
+    /// Name of the field of the column.
+    name: String,
+    hidden: bool,
+
+    /// Width of the column. 0 means the default width is used.
+    width: i32,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl TableColumnPresets {
+
+    /// This function loads the presets of the table with the provided key.
+    pub fn load(key: &str) -> Result<Self> {
+        let mut presets = Self::load_all()?;
+        Ok(presets.remove(key).unwrap_or_default())
+    }
+
+    /// This function saves these presets as the presets of the table with the provided key.
+    ///
+    /// If there are no presets, the stored presets of the table are removed.
+    pub fn save(&self, key: &str) -> Result<()> {
+        let mut presets = Self::load_all()?;
+        if self.presets.is_empty() {
+            if presets.remove(key).is_none() {
+                return Ok(());
+            }
+        } else {
+            presets.insert(key.to_owned(), self.clone());
+        }
+
+        Self::save_all(&presets)
+    }
+
+    /// This function exports the presets of all tables to the provided json file.
+    pub fn export(path: &Path) -> Result<()> {
+        let presets = Self::load_all()?;
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(serde_json::to_string_pretty(&presets)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function imports the presets from the provided json file, merging them with the stored ones.
+    ///
+    /// Imported presets replace stored presets with the same name.
+    pub fn import(path: &Path) -> Result<()> {
+        let file = BufReader::new(File::open(path)?);
+        let imported: BTreeMap<String, Self> = serde_json::from_reader(file)?;
+
+        let mut presets = Self::load_all()?;
+        for (key, table_presets) in imported {
+            presets.entry(key).or_default().merge(table_presets);
+        }
+
+        Self::save_all(&presets)
+    }
+
+    /// This function merges the provided presets into these ones.
+    ///
+    /// The active preset is only taken from the provided presets if we don't have one.
+    pub fn merge(&mut self, other: Self) {
+        if self.active.is_none() {
+            self.active = other.active;
+        }
+
+        self.presets.extend(other.presets);
+    }
+
+    /// This function returns the active preset, if any.
+    pub fn active_preset(&self) -> Option<&ColumnPreset> {
+        self.active.as_ref().and_then(|name| self.presets.get(name))
+    }
+
+    /// This function adds a preset, replacing any preset with the same name, and makes it the active one.
+    pub fn add_preset(&mut self, name: &str, preset: ColumnPreset) {
+        self.presets.insert(name.to_owned(), preset);
+        self.active = Some(name.to_owned());
+    }
+
+    /// This function removes the preset with the provided name. If it was the active one, no preset will be active.
+    pub fn remove_preset(&mut self, name: &str) {
+        self.presets.remove(name);
+        if self.active.as_deref() == Some(name) {
+            self.active = None;
+        }
+    }
+
+    /// This function sets the active preset. Passing a name without preset clears the active one.
+    pub fn set_active(&mut self, name: Option<&str>) {
+        self.active = name.filter(|name| self.presets.contains_key(*name)).map(|name| name.to_owned());
+    }
+
+    /// This function loads all the stored presets.
+    fn load_all() -> Result<BTreeMap<String, Self>> {
+        let path = column_presets_path()?;
+        if !path.is_file() {
+            return Ok(BTreeMap::new());
+        }
+
+        let file = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    /// This function saves all the provided presets, replacing the stored ones.
+    fn save_all(presets: &BTreeMap<String, Self>) -> Result<()> {
+        let mut file = BufWriter::new(File::create(column_presets_path()?)?);
+        file.write_all(serde_json::to_string_pretty(presets)?.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl ColumnPreset {
+
+    /// This function creates a new preset with the provided columns, in visual order.
+    pub fn new(columns: Vec<ColumnPresetEntry>) -> Self {
+        Self {
+            columns
+        }
+    }
+
+    /// This function returns the layout of this preset for a table with the provided fields.
+    ///
+    /// It returns the logical index of each column in the order they should be shown, with their stored layout.
+    /// Columns are matched by name. Columns not in the preset are shown after the ones in it, and columns
+    /// in the preset that the table no longer has are ignored.
+    pub fn layout(&self, field_names: &[&str]) -> Vec<(usize, Option<&ColumnPresetEntry>)> {
+        let mut layout = self.columns.iter()
+            .filter_map(|entry| field_names.iter().position(|name| *name == entry.name).map(|index| (index, Some(entry))))
+            .collect::<Vec<_>>();
+
+        for index in 0..field_names.len() {
+            if !layout.iter().any(|(logical_index, _)| *logical_index == index) {
+                layout.push((index, None));
+            }
+        }
+
+        layout
+    }
+}
+
+impl ColumnPresetEntry {
+
+    /// This function creates a new column entry.
+    pub fn new(name: &str, hidden: bool, width: i32) -> Self {
+        Self {
+            name: name.to_owned(),
+            hidden,
+            width,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the column presets of table views.
+
+use super::column_presets::{ColumnPreset, ColumnPresetEntry, TableColumnPresets};
+
+fn preset() -> ColumnPreset {
+    ColumnPreset::new(vec![
+        ColumnPresetEntry::new("c", false, 200),
+        ColumnPresetEntry::new("a", true, 0),
+        ColumnPresetEntry::new("removed", true, 0),
+        ColumnPresetEntry::new("b", false, 100),
+    ])
+}
+
+#[test]
+fn test_layout() {
+    let preset = preset();
+    let layout = preset.layout(&["a", "b", "c"]);
+    let layout = layout.iter().map(|(index, entry)| (*index, entry.map(|entry| (*entry.hidden(), *entry.width())))).collect::<Vec<_>>();
+    assert_eq!(layout, vec![(2, Some((false, 200))), (0, Some((true, 0))), (1, Some((false, 100)))]);
+}
+
+#[test]
+fn test_layout_schema_changed() {
+    let preset = preset();
+
+    // New columns go after the ones in the preset, in schema order. Removed columns are ignored.
+    let layout = preset.layout(&["new_1", "b", "c", "new_2"]);
+    let layout = layout.iter().map(|(index, entry)| (*index, entry.is_some())).collect::<Vec<_>>();
+    assert_eq!(layout, vec![(2, true), (1, true), (0, false), (3, false)]);
+
+    assert!(preset.layout(&[]).is_empty());
+}
+
+#[test]
+fn test_presets() {
+    let mut presets = TableColumnPresets::default();
+    presets.add_preset("first", preset());
+    presets.add_preset("second", ColumnPreset::default());
+    assert_eq!(presets.active().as_deref(), Some("second"));
+    assert_eq!(presets.active_preset(), Some(&ColumnPreset::default()));
+
+    presets.set_active(Some("missing"));
+    assert!(presets.active().is_none());
+
+    presets.set_active(Some("first"));
+    presets.remove_preset("second");
+    assert_eq!(presets.active_preset(), Some(&preset()));
+
+    presets.remove_preset("first");
+    assert!(presets.active().is_none());
+    assert!(presets.presets().is_empty());
+}
+
+#[test]
+fn test_merge() {
+    let mut presets = TableColumnPresets::default();
+    presets.add_preset("mine", preset());
+    presets.add_preset("shared", preset());
+
+    let mut imported = TableColumnPresets::default();
+    imported.add_preset("theirs", ColumnPreset::default());
+    imported.add_preset("shared", ColumnPreset::default());
+
+    // The active preset is kept, and imported presets replace the ones with the same name.
+    presets.merge(imported.clone());
+    assert_eq!(presets.active().as_deref(), Some("shared"));
+    assert_eq!(presets.presets().keys().collect::<Vec<_>>(), vec!["mine", "shared", "theirs"]);
+    assert_eq!(presets.presets()["shared"], ColumnPreset::default());
+
+    let mut empty = TableColumnPresets::default();
+    empty.merge(imported);
+    assert_eq!(empty.active().as_deref(), Some("shared"));
+}
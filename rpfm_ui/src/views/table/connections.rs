@@ -50,13 +50,25 @@ pub unsafe fn set_connections(ui: &Arc<TableView>, slots: &TableViewSlots) {
     ui.context_menu_import_tsv().triggered().connect(&slots.import_tsv);
     ui.context_menu_export_tsv().triggered().connect(&slots.export_tsv);
     ui.context_menu_resize_columns().triggered().connect(&slots.resize_columns);
+    ui.context_menu_column_presets().about_to_show().connect(&slots.column_presets_menu);
+    ui.context_menu_column_presets_save().triggered().connect(&slots.column_presets_save);
+    ui.context_menu_column_presets_delete().triggered().connect(&slots.column_presets_delete);
+    ui.context_menu_column_presets_reset().triggered().connect(&slots.column_presets_reset);
+    ui.context_menu_column_presets_export().triggered().connect(&slots.column_presets_export);
+    ui.context_menu_column_presets_import().triggered().connect(&slots.column_presets_import);
     ui.context_menu_sidebar().triggered().connect(&slots.sidebar);
     ui.context_menu_search().triggered().connect(&slots.search);
     ui.context_menu_cascade_edition().triggered().connect(&slots.cascade_edition);
+    ui.context_menu_deep_clone().triggered().connect(&slots.deep_clone);
     ui.context_menu_find_references().triggered().connect(&slots.find_references);
     ui.context_menu_patch_column().triggered().connect(&slots.patch_column);
     ui.context_menu_go_to_definition().triggered().connect(&slots.go_to_definition);
+    ui.context_menu_open_related_loc_entries().triggered().connect(&slots.open_related_loc_entries);
+    ui.context_menu_open_defining_table_row().triggered().connect(&slots.open_defining_table_row);
     ui.context_menu_smart_delete().triggered().connect(&slots.smart_delete);
+    ui.context_menu_diff_against_dependencies().toggled().connect(&slots.diff_against_dependencies);
+    ui.context_menu_edit_row_annotation().triggered().connect(&slots.edit_row_annotation);
+    ui.diff_show_only_changed_checkbox().state_changed().connect(&slots.diff_show_only_changed);
 
     ui.context_menu_go_to_loc().iter()
         .zip(slots.go_to_loc.iter())
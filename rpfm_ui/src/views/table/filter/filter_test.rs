@@ -0,0 +1,66 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for parsing typed table filters.
+
+use rpfm_lib::schema::FieldType;
+
+use super::{FilterType, parse_filter_pattern};
+
+#[test]
+fn test_parse_filter_pattern_integer() {
+    assert_eq!(parse_filter_pattern(&FieldType::I32, ">500"), (FilterType::Integer, "(500,)".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I32, ">= 500"), (FilterType::Integer, "[500,)".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I16, "<-5"), (FilterType::Integer, "(,-5)".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::OptionalI64, "<=9000000000"), (FilterType::Integer, "(,9000000000]".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I64, "=42"), (FilterType::Integer, "[42,42]".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I32, "10..20"), (FilterType::Integer, "[10,20]".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I32, "-20..-10"), (FilterType::Integer, "[-20,-10]".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I32, "10.."), (FilterType::Integer, "[10,)".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I32, "..20"), (FilterType::Integer, "(,20]".to_owned()));
+}
+
+#[test]
+fn test_parse_filter_pattern_float() {
+    assert_eq!(parse_filter_pattern(&FieldType::F32, ">0.5"), (FilterType::Float, "(0.5,)".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::F64, "<=-1.25"), (FilterType::Float, "(,-1.25]".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::F32, "=2"), (FilterType::Float, "[2,2]".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::F64, "0.5..1.5"), (FilterType::Float, "[0.5,1.5]".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::F32, "..1.5"), (FilterType::Float, "(,1.5]".to_owned()));
+}
+
+#[test]
+fn test_parse_filter_pattern_colour() {
+    assert_eq!(parse_filter_pattern(&FieldType::ColourRGB, "ff00aa"), (FilterType::Colour, "FF00AA".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::ColourRGB, "#Ff00aA"), (FilterType::Colour, "FF00AA".to_owned()));
+}
+
+#[test]
+fn test_parse_filter_pattern_fallback() {
+
+    // Strings and booleans always use the text filter.
+    assert_eq!(parse_filter_pattern(&FieldType::StringU8, ">500"), (FilterType::Text, ">500".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::Boolean, "true"), (FilterType::Text, "true".to_owned()));
+
+    // Numbers without operators keep the old substring/regex behavior.
+    assert_eq!(parse_filter_pattern(&FieldType::I32, "50"), (FilterType::Text, "50".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I32, "^5.*"), (FilterType::Text, "^5.*".to_owned()));
+
+    // Invalid numbers and ranges.
+    assert_eq!(parse_filter_pattern(&FieldType::I32, ">abc"), (FilterType::Text, ">abc".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I32, ">0.5"), (FilterType::Text, ">0.5".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I32, ".."), (FilterType::Text, "..".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::I32, "1..b"), (FilterType::Text, "1..b".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::F32, ">NaN"), (FilterType::Text, ">NaN".to_owned()));
+
+    // Partial or invalid colours.
+    assert_eq!(parse_filter_pattern(&FieldType::ColourRGB, "ff"), (FilterType::Text, "ff".to_owned()));
+    assert_eq!(parse_filter_pattern(&FieldType::ColourRGB, "gg0000"), (FilterType::Text, "gg0000".to_owned()));
+}
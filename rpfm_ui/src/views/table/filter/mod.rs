@@ -26,8 +26,13 @@ use qt_core::QTimer;
 use anyhow::Result;
 use getset::Getters;
 
+use std::fmt::Display;
+use std::ops::Bound;
+use std::str::FromStr;
 use std::sync::Arc;
 
+use rpfm_lib::schema::FieldType;
+
 use crate::locale::*;
 use crate::views::table::clean_column_names;
 use crate::utils::*;
@@ -38,6 +43,8 @@ use super::TableView;
 mod connections;
 mod slots;
 
+#[cfg(test)] mod filter_test;
+
 const VIEW_DEBUG: &str = "rpfm_ui/ui_templates/table_filter_groupbox.ui";
 const VIEW_RELEASE: &str = "ui/table_filter_groupbox.ui";
 
@@ -60,6 +67,17 @@ pub struct FilterView {
     remove_button: QPtr<QToolButton>,
 }
 
+/// This enum represents the kind of comparison the table filter does for a column.
+///
+/// Its values are shared with the C++ side of the filter, so they must be kept in sync with `tableview_filter.h`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterType {
+    Text = 0,
+    Integer = 1,
+    Float = 2,
+    Colour = 3,
+}
+
 //-------------------------------------------------------------------------------//
 //                             Implementations
 //-------------------------------------------------------------------------------//
@@ -85,6 +103,7 @@ impl FilterView {
 
         filter_line_edit.set_placeholder_text(&qtr("table_filter"));
         filter_line_edit.set_clear_button_enabled(true);
+        filter_line_edit.set_tool_tip(&qtr("table_filter_typed"));
         show_blank_cells_button.set_tool_tip(&qtr("table_filter_show_blank_cells"));
         case_sensitive_button.set_tool_tip(&qtr("table_filter_case_sensitive"));
         timer_delayed_updates.set_single_shot(true);
@@ -150,3 +169,105 @@ impl FilterView {
         }
     }
 }
+
+impl From<&FieldType> for FilterType {
+    fn from(field_type: &FieldType) -> Self {
+        match field_type {
+            FieldType::I16 |
+            FieldType::I32 |
+            FieldType::I64 |
+            FieldType::OptionalI16 |
+            FieldType::OptionalI32 |
+            FieldType::OptionalI64 => Self::Integer,
+            FieldType::F32 |
+            FieldType::F64 => Self::Float,
+            FieldType::ColourRGB => Self::Colour,
+            _ => Self::Text,
+        }
+    }
+}
+
+/// This function turns the text of a filter into a pattern the C++ side of the filter understands for the provided column type.
+///
+/// Numeric columns accept `>`, `>=`, `<`, `<=` and `=` followed by a number, or an inclusive range like `a..b` (either end can be omitted),
+/// and get turned into an interval like `[a,b]`. Colour columns accept an RGB value in hex, with or without `#`.
+///
+/// Anything else (or anything we fail to parse) falls back to a text filter with the original pattern.
+pub fn parse_filter_pattern(field_type: &FieldType, pattern: &str) -> (FilterType, String) {
+    let filter_type = FilterType::from(field_type);
+    let typed_pattern = match filter_type {
+        FilterType::Integer => parse_numeric_range::<i64>(pattern),
+        FilterType::Float => parse_numeric_range::<f64>(pattern),
+        FilterType::Colour => parse_colour(pattern),
+        FilterType::Text => None,
+    };
+
+    match typed_pattern {
+        Some(typed_pattern) => (filter_type, typed_pattern),
+        None => (FilterType::Text, pattern.to_owned()),
+    }
+}
+
+/// This function parses a numeric comparison or range into an interval, with empty ends being unbounded.
+fn parse_numeric_range<T: FromStr + PartialOrd + Display>(pattern: &str) -> Option<String> {
+    let pattern = pattern.trim();
+    let (start, end) = if let Some(value) = pattern.strip_prefix(">=") {
+        (Bound::Included(parse_number::<T>(value)?), Bound::Unbounded)
+    } else if let Some(value) = pattern.strip_prefix("<=") {
+        (Bound::Unbounded, Bound::Included(parse_number::<T>(value)?))
+    } else if let Some(value) = pattern.strip_prefix('>') {
+        (Bound::Excluded(parse_number::<T>(value)?), Bound::Unbounded)
+    } else if let Some(value) = pattern.strip_prefix('<') {
+        (Bound::Unbounded, Bound::Excluded(parse_number::<T>(value)?))
+    } else if let Some(value) = pattern.strip_prefix('=') {
+        let value = parse_number::<T>(value)?;
+        (Bound::Included(value.clone()), Bound::Included(value))
+    } else if let Some((start, end)) = pattern.split_once("..") {
+        let start = if start.trim().is_empty() { Bound::Unbounded } else { Bound::Included(parse_number::<T>(start)?) };
+        let end = if end.trim().is_empty() { Bound::Unbounded } else { Bound::Included(parse_number::<T>(end)?) };
+
+        // A range without ends is not a filter.
+        if start == Bound::Unbounded && end == Bound::Unbounded {
+            return None;
+        }
+
+        (start, end)
+    } else {
+        return None;
+    };
+
+    let (start_char, start) = match start {
+        Bound::Included(value) => ('[', value),
+        Bound::Excluded(value) => ('(', value),
+        Bound::Unbounded => ('(', String::new()),
+    };
+
+    let (end_char, end) = match end {
+        Bound::Included(value) => (']', value),
+        Bound::Excluded(value) => (')', value),
+        Bound::Unbounded => (')', String::new()),
+    };
+
+    Some(format!("{start_char}{start},{end}{end_char}"))
+}
+
+/// This function parses a number, returning it normalized as text. Values that cannot be compared, like NaN, are rejected.
+fn parse_number<T: FromStr + PartialOrd + Display>(value: &str) -> Option<String> {
+    let value = value.trim().parse::<T>().ok()?;
+    if value.partial_cmp(&value).is_some() {
+        Some(value.to_string())
+    } else {
+        None
+    }
+}
+
+/// This function parses a RRGGBB colour, returning it in uppercase and without `#`.
+fn parse_colour(pattern: &str) -> Option<String> {
+    let pattern = pattern.trim();
+    let pattern = pattern.strip_prefix('#').unwrap_or(pattern);
+    if pattern.len() == 6 && pattern.chars().all(|x| x.is_ascii_hexdigit()) {
+        Some(pattern.to_uppercase())
+    } else {
+        None
+    }
+}
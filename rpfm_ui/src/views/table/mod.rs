@@ -12,7 +12,7 @@
 Module with all the code for managing the view for Tables.
 !*/
 
-use qt_widgets::q_abstract_item_view::ScrollHint;
+use qt_widgets::q_abstract_item_view::{EditTrigger, ScrollHint};
 use qt_widgets::QAction;
 use qt_widgets::QCheckBox;
 use qt_widgets::QComboBox;
@@ -25,14 +25,20 @@ use qt_widgets::QGroupBox;
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
 use qt_widgets::QMenu;
+use qt_widgets::QMessageBox;
+use qt_widgets::q_message_box;
 use qt_widgets::QPushButton;
 use qt_widgets::QTableView;
 use qt_widgets::QTextEdit;
+use qt_widgets::QTreeView;
 use qt_widgets::QScrollArea;
 use qt_widgets::QSpinBox;
 use qt_widgets::QWidget;
 
+use qt_gui::QBrush;
+use qt_gui::QColor;
 use qt_gui::QGuiApplication;
+use qt_gui::QIcon;
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
@@ -40,6 +46,7 @@ use qt_gui::QStandardItemModel;
 use qt_core::AlignmentFlag;
 use qt_core::CaseSensitivity;
 use qt_core::CheckState;
+use qt_core::ItemDataRole;
 use qt_core::Orientation;
 use qt_core::QBox;
 use qt_core::QFlags;
@@ -73,9 +80,13 @@ use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
 use std::rc::Rc;
 
+use rpfm_extensions::cascade_edition::CascadeEdition;
+use rpfm_extensions::deep_clone::ClonedRow;
 use rpfm_extensions::dependencies::TableReferences;
+use rpfm_extensions::table_diff::{RowDiffStatus, TableDataDiff};
 
-use rpfm_lib::files::{anim_fragment::AnimFragment, anims_table::AnimsTable, FileType, db::DB, loc::Loc, matched_combat::MatchedCombat, table::*};
+use rpfm_lib::files::{anim_fragment::AnimFragment, anims_table::AnimsTable, ContainerPath, FileType, db::DB, loc::Loc, matched_combat::MatchedCombat, table::*};
+use rpfm_lib::integrations::log::warn;
 use rpfm_lib::schema::{Definition, DefinitionPatch, Field, FieldType, Schema};
 
 use crate::ASSETS_PATH;
@@ -84,30 +95,36 @@ use crate::CENTRAL_COMMAND;
 use crate::communications::*;
 use crate::dependencies_ui::DependenciesUI;
 use crate::diagnostics_ui::DiagnosticsUI;
-use crate::GAME_SELECTED;
+use crate::locks::{read_game_selected, read_schema};
 use crate::ffi::*;
 use crate::global_search_ui::GlobalSearchUI;
-use crate::locale::{qtr, qtre, tr};
+use crate::locale::{qtr, qtre, tr, tre};
 use crate::packfile_contents_ui::PackFileContentsUI;
 use crate::packedfile_views::{DataSource, utils::set_modified, View, ViewType};
 use crate::pack_tree::*;
 use crate::references_ui::ReferencesUI;
 use crate::settings_ui::backend::*;
-use crate::SCHEMA;
 use crate::UI_STATE;
 use crate::utils::*;
 
+use self::column_presets::*;
 use self::filter::*;
+use self::paste::*;
 use self::search::*;
 use self::slots::*;
 use self::utils::*;
 
+pub mod column_presets;
 mod connections;
-mod filter;
+pub mod filter;
+mod paste;
 mod search;
 pub mod slots;
 pub mod utils;
 
+#[cfg(test)] mod column_presets_test;
+#[cfg(test)] mod paste_test;
+
 // Column default sizes.
 pub static COLUMN_SIZE_BOOLEAN: i32 = 100;
 pub static COLUMN_SIZE_NUMBER: i32 = 140;
@@ -123,6 +140,12 @@ pub static ITEM_HAS_SOURCE_VALUE: i32 = 30;
 pub static ITEM_SOURCE_VALUE: i32 = 31;
 pub static ITEM_IS_SEQUENCE: i32 = 35;
 pub static ITEM_SEQUENCE_DATA: i32 = 36;
+pub static ITEM_DIFF_STATUS: i32 = 40;
+
+// Values of the ITEM_DIFF_STATUS role.
+const DIFF_STATUS_UNCHANGED: i32 = 0;
+const DIFF_STATUS_ADDED: i32 = 1;
+const DIFF_STATUS_MODIFIED: i32 = 2;
 
 const PATCH_COLUMN_VIEW_DEBUG: &str = "rpfm_ui/ui_templates/new_schema_patch_dialog.ui";
 const PATCH_COLUMN_VIEW_RELEASE: &str = "ui/new_schema_patch_dialog.ui";
@@ -204,12 +227,30 @@ pub struct TableView {
     context_menu_search: QPtr<QAction>,
     context_menu_find_references: QPtr<QAction>,
     context_menu_cascade_edition: QPtr<QAction>,
+    context_menu_deep_clone: QPtr<QAction>,
     context_menu_patch_column: QPtr<QAction>,
     context_menu_smart_delete: QBox<QAction>,
 
+    context_menu_column_presets: QBox<QMenu>,
+    context_menu_column_presets_switch: QBox<QMenu>,
+    context_menu_column_presets_save: QPtr<QAction>,
+    context_menu_column_presets_delete: QPtr<QAction>,
+    context_menu_column_presets_reset: QPtr<QAction>,
+    context_menu_column_presets_export: QPtr<QAction>,
+    context_menu_column_presets_import: QPtr<QAction>,
+
     _context_menu_go_to: QBox<QMenu>,
     context_menu_go_to_definition: QPtr<QAction>,
     context_menu_go_to_loc: Vec<QPtr<QAction>>,
+    context_menu_open_related_loc_entries: QPtr<QAction>,
+    context_menu_open_defining_table_row: QPtr<QAction>,
+    context_menu_diff_against_dependencies: QPtr<QAction>,
+    context_menu_edit_row_annotation: QPtr<QAction>,
+
+    diff_widget: QBox<QWidget>,
+    diff_show_only_changed_checkbox: QBox<QCheckBox>,
+    diff_removed_rows_tree_view: QBox<QTreeView>,
+    diff_removed_rows_tree_model: QBox<QStandardItemModel>,
 
     sidebar_scroll_area: QBox<QScrollArea>,
 
@@ -220,6 +261,7 @@ pub struct TableView {
 
     _table_status_bar: QBox<QWidget>,
     table_status_bar_line_counter_label: QBox<QLabel>,
+    table_status_bar_column_description_label: QBox<QLabel>,
 
     #[getset(skip)]
     search_view: Arc<RwLock<Option<Arc<SearchView>>>>,
@@ -245,6 +287,9 @@ pub struct TableView {
     #[getset(skip)]
     reference_map: Arc<HashMap<String, HashMap<String, Vec<String>>>>,
 
+    #[getset(skip)]
+    row_annotations: Arc<RwLock<BTreeMap<String, String>>>,
+
     save_lock: Arc<AtomicBool>,
     undo_lock: Arc<AtomicBool>,
 
@@ -284,7 +329,7 @@ impl TableView {
         let t = std::time::SystemTime::now();
         let (table_definition, patches, table_name, table_uuid, packed_file_type) = match table_data {
             //TableType::DependencyManager(_) => {
-            //    if let Some(schema) = &*SCHEMA.read().unwrap() {
+            //    if let Some(schema) = &*read_schema() {
             //        (schema.get_ref_versioned_file_dep_manager()?.get_version_list()[0].clone(), None, None, FileType::DependencyPackFilesList)
             //    } else {
             //        return Err(anyhow!("There is no Schema for the Game Selected."));
@@ -342,7 +387,7 @@ impl TableView {
         //    layout.add_widget_5a(&warning_message, 0, 0, 1, 4);
         //} else if let FileType::DB = packed_file_type {
         if let FileType::DB = packed_file_type {
-            banned_table = GAME_SELECTED.read().unwrap().is_file_banned(&format!("db/{}", &table_name_for_ref));
+            banned_table = read_game_selected().is_file_banned(&format!("db/{}", &table_name_for_ref));
             if banned_table {
                 let warning_message = QLabel::from_q_string_q_widget(&qtr("banned_tables_warning"), parent);
                 layout.add_widget_5a(&warning_message, 0, 0, 1, 4);
@@ -352,12 +397,30 @@ impl TableView {
         let table_status_bar = QWidget::new_1a(parent);
         let table_status_bar_grid = create_grid_layout(table_status_bar.static_upcast());
         let table_status_bar_line_counter_label = QLabel::from_q_string_q_widget(&qtre("line_counter", &["0", "0"]), &table_status_bar);
+        let table_status_bar_column_description_label = QLabel::from_q_widget(&table_status_bar);
         table_status_bar_grid.add_widget_5a(&table_status_bar_line_counter_label, 0, 0, 1, 1);
+        table_status_bar_grid.add_widget_5a(&table_status_bar_column_description_label, 0, 1, 1, 1);
+        table_status_bar_grid.set_column_stretch(1, 10);
 
         layout.add_widget_5a(&table_view, 1, 0, 1, 1);
         layout.add_widget_5a(&table_status_bar, 2, 0, 1, 2);
         layout.add_widget_5a(&filter_base_widget, 4, 0, 1, 2);
 
+        // Widgets for the diff against dependencies. Removed rows go in a tree, so they can be collapsed.
+        let diff_widget = QWidget::new_1a(parent);
+        let diff_grid = create_grid_layout(diff_widget.static_upcast());
+        let diff_show_only_changed_checkbox = QCheckBox::from_q_string_q_widget(&qtr("table_diff_show_only_changed"), &diff_widget);
+        let diff_removed_rows_tree_view = QTreeView::new_1a(&diff_widget);
+        let diff_removed_rows_tree_model = QStandardItemModel::new_1a(&diff_removed_rows_tree_view);
+        diff_removed_rows_tree_view.set_model(&diff_removed_rows_tree_model);
+        diff_removed_rows_tree_view.set_edit_triggers(QFlags::from(EditTrigger::NoEditTriggers));
+        diff_removed_rows_tree_view.set_maximum_height(200);
+
+        diff_grid.add_widget_5a(&diff_show_only_changed_checkbox, 0, 0, 1, 1);
+        diff_grid.add_widget_5a(&diff_removed_rows_tree_view, 1, 0, 1, 1);
+        layout.add_widget_5a(&diff_widget, 5, 0, 1, 2);
+        diff_widget.hide();
+
         // Action to make the delete button delete contents.
         let context_menu_smart_delete = QAction::from_q_object(&table_view);
 
@@ -387,6 +450,7 @@ impl TableView {
         let context_menu_sidebar = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "table_editor", "sidebar", "context_menu_sidebar", Some(table_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_find_references = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "table_editor", "find_references", "context_menu_find_references", Some(table_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_cascade_edition = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "table_editor", "rename_references", "context_menu_cascade_edition", Some(table_view.static_upcast::<qt_widgets::QWidget>()));
+        let context_menu_deep_clone = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "table_editor", "deep_clone", "context_menu_deep_clone", Some(table_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_patch_column = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "table_editor", "patch_columns", "context_menu_patch_column", Some(table_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_undo = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "table_editor", "undo", "context_menu_undo", Some(table_view.static_upcast::<qt_widgets::QWidget>()));
         let context_menu_redo = add_action_to_menu(&context_menu.static_upcast(), app_ui.shortcuts().as_ref(), "table_editor", "redo", "context_menu_redo", Some(table_view.static_upcast::<qt_widgets::QWidget>()));
@@ -400,14 +464,35 @@ impl TableView {
             context_menu_go_to_loc.push(context_menu_go_to_loc_action)
         }
 
+        let context_menu_open_related_loc_entries = context_menu_go_to.add_action_q_string(&qtr("context_menu_open_related_loc_entries"));
+        let context_menu_open_defining_table_row = context_menu_go_to.add_action_q_string(&qtr("context_menu_open_defining_table_row"));
+        context_menu_go_to.insert_separator(&context_menu_open_related_loc_entries);
+
+        let context_menu_diff_against_dependencies = context_menu.add_action_q_string(&qtr("context_menu_diff_against_dependencies"));
+        context_menu_diff_against_dependencies.set_checkable(true);
+        let context_menu_edit_row_annotation = context_menu.add_action_q_string(&qtr("context_menu_edit_row_annotation"));
+
+        // Column presets submenu. The list of presets is filled when the menu is shown.
+        let context_menu_column_presets = QMenu::from_q_string_q_widget(&qtr("context_menu_column_presets"), &table_view);
+        let context_menu_column_presets_switch = QMenu::from_q_string_q_widget(&qtr("context_menu_column_presets_switch"), &table_view);
+        context_menu_column_presets.add_menu_q_menu(&context_menu_column_presets_switch);
+        let context_menu_column_presets_save = context_menu_column_presets.add_action_q_string(&qtr("context_menu_column_presets_save"));
+        let context_menu_column_presets_delete = context_menu_column_presets.add_action_q_string(&qtr("context_menu_column_presets_delete"));
+        let context_menu_column_presets_reset = context_menu_column_presets.add_action_q_string(&qtr("context_menu_column_presets_reset"));
+        context_menu_column_presets.add_separator();
+        let context_menu_column_presets_export = context_menu_column_presets.add_action_q_string(&qtr("context_menu_column_presets_export"));
+        let context_menu_column_presets_import = context_menu_column_presets.add_action_q_string(&qtr("context_menu_column_presets_import"));
+
         // Insert some separators to space the menu, and the paste submenu.
         context_menu.insert_menu(&context_menu_paste, &context_menu_clone_submenu);
         context_menu.insert_menu(&context_menu_paste, &context_menu_copy_submenu);
         context_menu.insert_menu(&context_menu_paste, &context_menu_go_to);
+        context_menu.insert_menu(&context_menu_import_tsv, &context_menu_column_presets);
         context_menu.insert_separator(&context_menu_rewrite_selection);
         context_menu.insert_separator(&context_menu_import_tsv);
         context_menu.insert_separator(&context_menu_search);
         context_menu.insert_separator(&context_menu_undo);
+        context_menu.insert_separator(&context_menu_diff_against_dependencies);
 
         //--------------------------------------------------//
         // Search Section.
@@ -535,7 +620,7 @@ dbg!(t.elapsed().unwrap());
         timer_delayed_updates.set_single_shot(true);
 
         // Get the reference data for this table, to speedup reference searching.
-        let reference_map = if let Some(schema) = &*SCHEMA.read().unwrap() {
+        let reference_map = if let Some(schema) = &*read_schema() {
             if let Some(table_name) = table_name {
                 schema.referencing_columns_for_table(table_name, table_definition)
             } else {
@@ -580,12 +665,30 @@ dbg!(t.elapsed().unwrap());
             context_menu_search,
             context_menu_find_references,
             context_menu_cascade_edition,
+            context_menu_deep_clone,
             context_menu_patch_column,
             context_menu_smart_delete,
 
+            context_menu_column_presets,
+            context_menu_column_presets_switch,
+            context_menu_column_presets_save,
+            context_menu_column_presets_delete,
+            context_menu_column_presets_reset,
+            context_menu_column_presets_export,
+            context_menu_column_presets_import,
+
             _context_menu_go_to: context_menu_go_to,
             context_menu_go_to_definition,
             context_menu_go_to_loc,
+            context_menu_open_related_loc_entries,
+            context_menu_open_defining_table_row,
+            context_menu_diff_against_dependencies,
+            context_menu_edit_row_annotation,
+
+            diff_widget,
+            diff_show_only_changed_checkbox,
+            diff_removed_rows_tree_view,
+            diff_removed_rows_tree_model,
 
             sidebar_hide_checkboxes,
             sidebar_hide_checkboxes_all,
@@ -596,6 +699,7 @@ dbg!(t.elapsed().unwrap());
 
             _table_status_bar: table_status_bar,
             table_status_bar_line_counter_label,
+            table_status_bar_column_description_label,
 
             search_view: Arc::new(RwLock::new(None)),
 
@@ -609,6 +713,7 @@ dbg!(t.elapsed().unwrap());
             packed_file_type: Arc::new(packed_file_type),
             banned_table,
             reference_map: Arc::new(reference_map),
+            row_annotations: Arc::new(RwLock::new(BTreeMap::new())),
 
             undo_lock,
             save_lock,
@@ -653,16 +758,23 @@ dbg!(t.elapsed().unwrap());
         build_columns(
             &packed_file_table_view.table_view_ptr(),
             &packed_file_table_view.table_definition.read().unwrap(),
-            packed_file_table_view.table_name.as_deref()
+            packed_file_table_view.table_name.as_deref(),
+            Some(&packed_file_table_view.patches()),
         );
 dbg!(t.elapsed().unwrap());
         // Set the connections and return success.
         connections::set_connections(&packed_file_table_view, &packed_file_table_view_slots);
 
+        // Apply the column preset of the table, if any. This needs the connections, as hiding columns is done through the sidebar.
+        packed_file_table_view.apply_active_column_preset();
+
 dbg!(t.elapsed().unwrap());
         // Update the line counter.
         packed_file_table_view.update_line_counter();
 
+        // Pin the rows with notes.
+        packed_file_table_view.update_row_annotations();
+
         // This fixes some weird issues on first click.
         packed_file_table_view.context_menu_update();
 dbg!(t.elapsed().unwrap());
@@ -716,9 +828,13 @@ dbg!(t.elapsed().unwrap());
         build_columns(
             table_view,
             &self.table_definition(),
-            self.table_name.as_deref()
+            self.table_name.as_deref(),
+            Some(&self.patches()),
         );
 
+        // Reloading clears the row headers, so pin the rows with notes again.
+        self.update_row_annotations();
+
         // Rebuild the column list of the filter and search panels, just in case the definition changed.
         // NOTE: We need to lock the signals for the column selector so it doesn't try to trigger in the middle of the rebuild, causing a deadlock.
         for filter in self.filters_mut().iter() {
@@ -813,6 +929,30 @@ dbg!(t.elapsed().unwrap());
         self.table_status_bar_line_counter_label.set_text(&qtre("line_counter", &[&rows_on_filter, &rows_on_model]));
     }
 
+    /// This function shows the description of the column of the current cell in the status bar, so it's visible without hovering the header.
+    pub unsafe fn update_column_description(&self) {
+        let index = self.table_filter.map_to_source(&self.table_view.selection_model().current_index());
+        let description = if index.is_valid() {
+            self.table_definition().fields_processed().get(index.column() as usize)
+                .map(|field| (utils::clean_column_names(field.name()), field.description_with_patches(Some(&self.patches()))))
+                .filter(|(_, description)| !description.is_empty())
+                .map(|(name, description)| qtre("column_description", &[&name, &description.replace('\n', " ")]))
+        } else {
+            None
+        };
+
+        match description {
+            Some(description) => {
+                self.table_status_bar_column_description_label.set_tool_tip(&description);
+                self.table_status_bar_column_description_label.set_text(&description);
+            }
+            None => {
+                self.table_status_bar_column_description_label.set_tool_tip(&QString::new());
+                self.table_status_bar_column_description_label.set_text(&QString::new());
+            }
+        }
+    }
+
     //----------------------------------------------------------------//
     //----------------------------------------------------------------//
     //----------------------------------------------------------------//
@@ -836,9 +976,13 @@ dbg!(t.elapsed().unwrap());
         self.context_menu_import_tsv.set_enabled(false);
         self.context_menu_find_references.set_enabled(false);
         self.context_menu_cascade_edition.set_enabled(false);
+        self.context_menu_deep_clone.set_enabled(false);
         self.context_menu_patch_column.set_enabled(true);
         self.context_menu_smart_delete.set_enabled(false);
 
+        // Diffs are done against the data in the backend, so they only work for DB/Loc tables in our Pack.
+        self.context_menu_diff_against_dependencies.set_enabled(self.get_data_source() == DataSource::PackFile && [FileType::DB, FileType::Loc].contains(&self.packed_file_type));
+
         // Turns out that this slot doesn't give the the amount of selected items, so we have to get them ourselves.
         let indexes = self.table_filter.map_selection_to_source(&self.table_view.selection_model().selection()).indexes();
 
@@ -854,11 +998,26 @@ dbg!(t.elapsed().unwrap());
                 self.context_menu_go_to_loc.iter().for_each(|x| x.set_enabled(false));
             }
 
+            // Definitions may be in the dependencies, so we cannot go to them until they're loaded.
             if [FileType::DB, FileType::Loc].contains(&self.packed_file_type) {
-                self.context_menu_go_to_definition.set_enabled(true);
+                let dependencies_pending = UI_STATE.get_dependencies_pending();
+                self.context_menu_go_to_definition.set_enabled(!dependencies_pending);
+                self.context_menu_go_to_definition.set_tool_tip(&if dependencies_pending { qtr("dependencies_pending") } else { QString::new() });
             } else {
                 self.context_menu_go_to_definition.set_enabled(false);
             }
+
+            // Related loc entries are only available from the key columns of tables with localised fields.
+            let is_key_column = self.table_definition().fields_processed().get(indexes.at(0).column() as usize).map(|field| field.is_key()).unwrap_or(false);
+            self.context_menu_open_related_loc_entries.set_enabled(*self.packed_file_type == FileType::DB && is_key_column && !self.table_definition().localised_fields().is_empty());
+            self.context_menu_open_defining_table_row.set_enabled(*self.packed_file_type == FileType::Loc);
+
+            // Notes are stored by row key in the Pack Settings, so they only work for DB/Loc tables with keys in our Pack.
+            self.context_menu_edit_row_annotation.set_enabled(
+                self.get_data_source() == DataSource::PackFile &&
+                [FileType::DB, FileType::Loc].contains(&self.packed_file_type) &&
+                self.table_definition().fields_processed().iter().any(|field| field.is_key())
+            );
         }
 
         // Otherwise, disable them.
@@ -867,6 +1026,9 @@ dbg!(t.elapsed().unwrap());
             self.context_menu_copy_as_lua_table.set_enabled(false);
             self.context_menu_go_to_definition.set_enabled(false);
             self.context_menu_go_to_loc.iter().for_each(|x| x.set_enabled(false));
+            self.context_menu_open_related_loc_entries.set_enabled(false);
+            self.context_menu_open_defining_table_row.set_enabled(false);
+            self.context_menu_edit_row_annotation.set_enabled(false);
         }
 
         // Only enable editing if the table is ours and not banned.
@@ -890,6 +1052,10 @@ dbg!(t.elapsed().unwrap());
                     self.context_menu_rewrite_selection.set_enabled(true);
                     self.context_menu_generate_ids.set_enabled(true);
                     self.context_menu_cascade_edition.set_enabled(true);
+
+                    if *self.packed_file_type == FileType::DB {
+                        self.context_menu_deep_clone.set_enabled(true);
+                    }
                 }
 
                 if !self.undo_lock.load(Ordering::SeqCst) {
@@ -907,21 +1073,32 @@ dbg!(t.elapsed().unwrap());
         let mut sensitivity = vec![];
         let mut show_blank_cells = vec![];
         let mut match_groups = vec![];
+        let mut filter_types = vec![];
 
+        let fields_processed = self.table_definition().fields_processed();
         let filters = self.filters.read().unwrap();
         for filter in filters.iter() {
 
             // Ignore empty filters.
-            if !filter.filter_line_edit().text().to_std_string().is_empty() {
+            let pattern = filter.filter_line_edit().text().to_std_string();
+            if !pattern.is_empty() {
 
                 let column_name = filter.column_combobox().current_text();
+                let mut column_index = None;
                 for column in 0..self.table_model.column_count_0a() {
                     if self.table_model.header_data_2a(column, Orientation::Horizontal).to_string().compare_q_string_case_sensitivity(&column_name, CaseSensitivity::CaseSensitive) == 0 {
                         columns.push(column);
+                        column_index = Some(column);
                         break;
                     }
                 }
 
+                // Numeric and colour columns support typed comparisons, so get the pattern for their type.
+                let (filter_type, pattern) = match column_index.and_then(|column| fields_processed.get(column as usize)) {
+                    Some(field) => parse_filter_pattern(field.field_type(), &pattern),
+                    None => (FilterType::Text, pattern),
+                };
+
                 // Check if the filter should be "Case Sensitive".
                 let case_sensitive = filter.case_sensitive_button().is_checked();
                 if case_sensitive { sensitivity.push(CaseSensitivity::CaseSensitive); }
@@ -930,18 +1107,124 @@ dbg!(t.elapsed().unwrap());
                 // Check if we should filter out blank cells or not.
                 show_blank_cells.push(filter.show_blank_cells_button().is_checked());
 
-                patterns.push(filter.filter_line_edit().text().into_ptr());
+                patterns.push(QString::from_std_str(pattern).into_ptr());
                 match_groups.push(filter.group_combobox().current_index());
+                filter_types.push(filter_type as i32);
             }
         }
 
         // Filter whatever it's in that column by the text we got.
-        trigger_tableview_filter_safe(&self.table_filter, &columns, patterns, &sensitivity, &show_blank_cells, &match_groups);
+        trigger_tableview_filter_safe(&self.table_filter, &columns, patterns, &sensitivity, &show_blank_cells, &match_groups, &filter_types);
+        self.filter_table_diff();
 
         // Update the line count.
         self.update_line_counter();
     }
 
+    /// This function diffs the table against the vanilla and parent data of the same table, and marks the results in the view.
+    ///
+    /// The diff is done against the data in the backend, so make sure the view is saved before calling this. If the diff is disabled, the marks are cleared.
+    pub unsafe fn update_table_diff(&self) -> Result<()> {
+        let diff = if self.context_menu_diff_against_dependencies.is_checked() {
+            match self.get_packed_file_path() {
+                Some(path) => {
+                    let receiver = CENTRAL_COMMAND.send_background(Command::GetTableDiff(path));
+                    let response = CENTRAL_COMMAND.recv_try(&receiver);
+                    match response {
+                        Response::TableDataDiff(diff) => Some(diff),
+                        Response::Error(error) => {
+                            self.context_menu_diff_against_dependencies.set_checked(false);
+                            self.paint_table_diff(None);
+                            return Err(error);
+                        }
+                        _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+                    }
+                }
+                None => None,
+            }
+        } else { None };
+
+        self.paint_table_diff(diff.as_ref());
+        Ok(())
+    }
+
+    /// This function marks the rows and cells of the provided diff in the view, or clears the marks if there is no diff.
+    unsafe fn paint_table_diff(&self, diff: Option<&TableDataDiff>) {
+        let (colour_added, colour_modified) = if setting_bool("use_dark_theme") {
+            (setting_string("colour_dark_table_added"), setting_string("colour_dark_table_modified"))
+        } else {
+            (setting_string("colour_light_table_added"), setting_string("colour_light_table_modified"))
+        };
+
+        let brush_added = QBrush::from_q_color(&QColor::from_q_string(&QString::from_std_str(colour_added)));
+        let brush_modified = QBrush::from_q_color(&QColor::from_q_string(&QString::from_std_str(colour_modified)));
+
+        // Block the signals, so this is not detected as an edition.
+        let blocker = QSignalBlocker::from_q_object(&self.table_model);
+        for row in 0..self.table_model.row_count_0a() {
+            let status = diff.and_then(|diff| diff.statuses().get(row as usize));
+            for column in 0..self.table_model.column_count_0a() {
+                let item = self.table_model.item_2a(row, column);
+                match status {
+                    Some(RowDiffStatus::Added) => item.set_background(&brush_added),
+                    Some(RowDiffStatus::Modified(columns)) if columns.contains(&(column as usize)) => item.set_background(&brush_modified),
+                    _ => item.set_data_2a(&QVariant::new(), ItemDataRole::BackgroundRole.to_int()),
+                }
+            }
+
+            let status = match status {
+                Some(RowDiffStatus::Added) => DIFF_STATUS_ADDED,
+                Some(RowDiffStatus::Modified(_)) => DIFF_STATUS_MODIFIED,
+                _ => DIFF_STATUS_UNCHANGED,
+            };
+
+            if self.table_model.column_count_0a() > 0 {
+                self.table_model.item_2a(row, 0).set_data_2a(&QVariant::from_int(status), ITEM_DIFF_STATUS);
+            }
+        }
+        blocker.unblock();
+
+        // Removed rows are shown as children of a single collapsible item.
+        self.diff_removed_rows_tree_model.clear();
+        match diff {
+            Some(diff) => {
+                let fields = self.table_definition().fields_processed();
+                let headers = QStringList::new();
+                fields.iter().for_each(|field| headers.append_q_string(&QString::from_std_str(utils::clean_column_names(field.name()))));
+                self.diff_removed_rows_tree_model.set_horizontal_header_labels(&headers);
+
+                let removed_rows = QStandardItem::from_q_string(&qtre("table_diff_removed_rows", &[&diff.removed_rows().len().to_string()]));
+                for row in diff.removed_rows() {
+                    let items = QListOfQStandardItem::new();
+                    for data in row {
+                        let item = QStandardItem::from_q_string(&QString::from_std_str(data.data_to_string()));
+                        item.set_background(&brush_added);
+                        items.append_q_standard_item(&item.into_ptr().as_mut_raw_ptr());
+                    }
+                    removed_rows.append_row_q_list_of_q_standard_item(&items);
+                }
+
+                self.diff_removed_rows_tree_model.append_row_q_standard_item(removed_rows.into_ptr());
+                self.diff_removed_rows_tree_view.set_visible(!diff.removed_rows().is_empty());
+                self.diff_widget.show();
+            }
+            None => self.diff_widget.hide(),
+        }
+
+        self.filter_table_diff();
+    }
+
+    /// This function hides the rows not changed against the vanilla and parent data, if the diff is enabled and we only want to see the changed rows.
+    ///
+    /// This is done on the view, not on the filter, so it has to be redone every time the filter changes.
+    pub unsafe fn filter_table_diff(&self) {
+        let only_changed = self.context_menu_diff_against_dependencies.is_checked() && self.diff_show_only_changed_checkbox.is_checked();
+        for row in 0..self.table_filter.row_count_0a() {
+            let hidden = only_changed && self.table_filter.index_2a(row, 0).data_1a(ITEM_DIFF_STATUS).to_int_0a() == DIFF_STATUS_UNCHANGED;
+            self.table_view.set_row_hidden(row, hidden);
+        }
+    }
+
     /// This function enables/disables showing the lookup values instead of the real ones in the columns that support it.
     pub unsafe fn toggle_lookups(&self) {
         /*
@@ -1238,10 +1521,7 @@ dbg!(t.elapsed().unwrap());
 
     /// This function allow us to paste the contents of the clipboard into new rows at the end of the table, if the content is compatible with them.
     pub unsafe fn paste_as_new_row(&self, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
-        let mut text = QGuiApplication::clipboard().text().to_std_string();
-        if text.ends_with('\n') { text.pop(); }
-        let rows = text.split('\n').collect::<Vec<&str>>();
-        let rows = rows.iter().map(|x| x.split('\t').collect::<Vec<&str>>()).collect::<Vec<Vec<&str>>>();
+        let rows = parse_clipboard(&QGuiApplication::clipboard().text().to_std_string());
 
         // Then paste the data as it fits. If no indexes are provided, the data is pasted in new rows.
         self.paste_as_it_fits(&rows, &[], app_ui, pack_file_contents_ui);
@@ -1254,11 +1534,7 @@ dbg!(t.elapsed().unwrap());
     pub unsafe fn paste(&self, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
 
         // Get the current selection. We treat it like a TSV, for compatibility with table editors.
-        // Also, if the text ends in \n, remove it. Excel things.
-        let mut text = QGuiApplication::clipboard().text().to_std_string();
-        if text.ends_with('\n') { text.pop(); }
-        let rows = text.split('\n').collect::<Vec<&str>>();
-        let rows = rows.iter().map(|x| x.split('\t').collect::<Vec<&str>>()).collect::<Vec<Vec<&str>>>();
+        let rows = parse_clipboard(&QGuiApplication::clipboard().text().to_std_string());
 
         // Get the current selection and his, visually speaking, first item (top-left).
         let indexes = self.table_view.selection_model().selection().indexes();
@@ -1306,7 +1582,7 @@ dbg!(t.elapsed().unwrap());
         rows_selected.dedup();
 
         if rows.len() == 1 && rows[0].len() == 1 {
-            self.paste_one_for_all(rows[0][0], &indexes_sorted, app_ui, pack_file_contents_ui);
+            self.paste_one_for_all(&rows[0][0], &indexes_sorted, app_ui, pack_file_contents_ui);
         }
 
         else if rows.len() == 1 && same_amount_of_cells_selected_per_row && rows_selected.len() > 1 {
@@ -1320,32 +1596,44 @@ dbg!(t.elapsed().unwrap());
 
     /// This function pastes the value in the clipboard in every selected Cell.
     unsafe fn paste_one_for_all(&self, text: &str, indexes: &[Ref<QModelIndex>], app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
+        let fields_processed = self.table_definition().fields_processed();
+        let mut errors = vec![];
 
-        let real_cells = indexes.iter().map(|index| {
-            (self.table_filter.map_to_source(*index), text)
-        }).collect::<Vec<(CppBox<QModelIndex>, &str)>>();
+        let real_cells = indexes.iter().filter_map(|index| {
+            let real_cell = self.table_filter.map_to_source(*index);
+            self.coerce_pasted_value(text, real_cell.column(), index.row(), &fields_processed, &mut errors).map(|text| (real_cell, text))
+        }).collect::<Vec<(CppBox<QModelIndex>, String)>>();
 
-        let fields_processed = self.table_definition().fields_processed();
+        let real_cells = real_cells.iter().map(|(index, text)| (QModelIndex::new_copy(index), text.as_str())).collect::<Vec<(CppBox<QModelIndex>, &str)>>();
         self.set_data_on_cells(&real_cells, 0, &[], &fields_processed, app_ui, pack_file_contents_ui);
+        self.show_paste_errors(&errors);
     }
 
     /// This function pastes the row in the clipboard in every selected row that has the same amount of items selected as items in the clipboard we have.
-    unsafe fn paste_same_row_for_all(&self, text: &[&str], indexes: &[Ref<QModelIndex>], app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
+    unsafe fn paste_same_row_for_all(&self, text: &[String], indexes: &[Ref<QModelIndex>], app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
+        let fields_processed = self.table_definition().fields_processed();
+        let mut errors = vec![];
 
         let real_cells = indexes.iter().filter_map(|index| {
             if index.column() == -1 {
                 None
             } else {
-                text.get(index.column() as usize).map(|text| (self.table_filter.map_to_source(*index), *text))
+                let real_cell = self.table_filter.map_to_source(*index);
+                text.get(index.column() as usize)
+                    .and_then(|text| self.coerce_pasted_value(text, real_cell.column(), index.row(), &fields_processed, &mut errors))
+                    .map(|text| (real_cell, text))
             }
-        }).collect::<Vec<(CppBox<QModelIndex>, &str)>>();
+        }).collect::<Vec<(CppBox<QModelIndex>, String)>>();
 
-        let fields_processed = self.table_definition().fields_processed();
+        let real_cells = real_cells.iter().map(|(index, text)| (QModelIndex::new_copy(index), text.as_str())).collect::<Vec<(CppBox<QModelIndex>, &str)>>();
         self.set_data_on_cells(&real_cells, 0, &[], &fields_processed, app_ui, pack_file_contents_ui);
+        self.show_paste_errors(&errors);
     }
 
     /// This function pastes the provided text into the table as it fits, following a square strategy starting in the first selected index.
-    unsafe fn paste_as_it_fits(&self, text: &[Vec<&str>], indexes: &[Ref<QModelIndex>], app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
+    ///
+    /// If the text doesn't fit in the existing rows, new rows are added. Unless we're pasting as new rows, the user is asked first.
+    unsafe fn paste_as_it_fits(&self, text: &[Vec<String>], indexes: &[Ref<QModelIndex>], app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
 
         // We're going to try and check in square mode. That means, start in the selected cell, then right
         // until we reach a \n, then return to the initial column. Due to how sorting works, we have to do
@@ -1363,66 +1651,63 @@ dbg!(t.elapsed().unwrap());
         let definition = self.table_definition();
         let fields_processed = definition.fields_processed();
 
-        let mut real_cells = vec![];
-        let mut added_rows = 0;
+        // Cells to paste, by row. Rows that don't exist yet have no real row.
+        let mut pending_rows = vec![];
+        let mut errors = vec![];
         for row in text {
             let mut visual_column = match base_index_visual {
                 Some(base_index_visual) => horizontal_header.visual_index(base_index_visual.column()),
                 None => 0,
             };
 
-            let mut real_row = self.table_filter.map_to_source(&self.table_filter.index_2a(visual_row, visual_column)).row();
+            let real_row = self.table_filter.map_to_source(&self.table_filter.index_2a(visual_row, visual_column)).row();
+            let mut cells = vec![];
 
             for text in row {
 
-                // Depending on the column, we try to encode the data in one format or another, or we just skip it.
+                // Depending on the column, we try to convert the data to one format or another, or we just skip it.
                 let real_column = horizontal_header.logical_index(visual_column);
+                if let Some(text) = self.coerce_pasted_value(text, real_column, visual_row, &fields_processed, &mut errors) {
+                    cells.push((real_column, text));
+                }
 
-                if let Some(field) = fields_processed.get(real_column as usize) {
-
-                    // Check if, according to the definition, we have a valid value for the type.
-                    let is_valid_data = match field.field_type() {
-                        FieldType::Boolean => !(text.to_lowercase() != "true" && text.to_lowercase() != "false" && text != &"1" && text != &"0"),
-                        FieldType::F32 => text.parse::<f32>().is_ok(),
-                        FieldType::F64 => text.parse::<f64>().is_ok(),
-                        FieldType::I16 => text.parse::<i16>().is_ok() || text.parse::<f32>().is_ok(),
-                        FieldType::I32 => text.parse::<i32>().is_ok() || text.parse::<f32>().is_ok(),
-                        FieldType::I64 => text.parse::<i64>().is_ok() || text.parse::<f32>().is_ok(),
-                        FieldType::OptionalI16 => text.parse::<i16>().is_ok() || text.parse::<f32>().is_ok(),
-                        FieldType::OptionalI32 => text.parse::<i32>().is_ok() || text.parse::<f32>().is_ok(),
-                        FieldType::OptionalI64 => text.parse::<i64>().is_ok() || text.parse::<f32>().is_ok(),
-                        FieldType::ColourRGB => u32::from_str_radix(text, 16).is_ok(),
-
-                        // All these are Strings, so we can skip their checks....
-                        FieldType::StringU8 |
-                        FieldType::StringU16 |
-                        FieldType::OptionalStringU8 |
-                        FieldType::OptionalStringU16 => true,
-
-                        // Ignore sequences.
-                        FieldType::SequenceU16(_) | FieldType::SequenceU32(_) => false,
-                    };
+                visual_column += 1;
+            }
 
-                    // If it's valid, add it to the real_cells list.
-                    if is_valid_data {
+            if !cells.is_empty() {
+                pending_rows.push((if real_row == -1 { None } else { Some(real_row) }, cells));
+            }
 
-                        // If real_row is -1 (invalid), then we need to add an empty row to the model (NOT TO THE FILTER)
-                        // because that means we have no row for that position, and we need one.
-                        if real_row == -1 {
-                            let row = get_new_row(&self.table_definition(), Some(&self.patches()));
-                            for index in 0..row.count_0a() {
-                                row.value_1a(index).set_data_2a(&QVariant::from_bool(true), ITEM_IS_ADDED);
-                            }
-                            self.table_model.append_row_q_list_of_q_standard_item(&row);
-                            real_row = self.table_model.row_count_0a() - 1;
-                            added_rows += 1;
-                        }
-                        real_cells.push((self.table_model.index_2a(real_row, real_column), *text));
+            visual_row += 1;
+        }
+
+        // If we need new rows to fit what we're pasting, make sure the user wants them.
+        if !indexes.is_empty() && pending_rows.iter().any(|(real_row, _)| real_row.is_none()) && !AppUI::are_you_sure_edition(app_ui, "paste_add_rows_are_you_sure") {
+            pending_rows.retain(|(real_row, _)| real_row.is_some());
+        }
+
+        let mut real_cells = vec![];
+        let mut added_rows = 0;
+        for (real_row, cells) in &pending_rows {
+
+            // If there is no real row, then we need to add an empty row to the model (NOT TO THE FILTER)
+            // because that means we have no row for that position, and we need one.
+            let real_row = match real_row {
+                Some(real_row) => *real_row,
+                None => {
+                    let row = get_new_row(&self.table_definition(), Some(&self.patches()));
+                    for index in 0..row.count_0a() {
+                        row.value_1a(index).set_data_2a(&QVariant::from_bool(true), ITEM_IS_ADDED);
                     }
+                    self.table_model.append_row_q_list_of_q_standard_item(&row);
+                    added_rows += 1;
+                    self.table_model.row_count_0a() - 1
                 }
-                visual_column += 1;
+            };
+
+            for (real_column, text) in cells {
+                real_cells.push((self.table_model.index_2a(real_row, *real_column), text.as_str()));
             }
-            visual_row += 1;
         }
 
         // We need to update the undo model here, because otherwise it'll start triggering crashes
@@ -1434,6 +1719,36 @@ dbg!(t.elapsed().unwrap());
         }
 
         self.set_data_on_cells(&real_cells, added_rows, &[], &fields_processed, app_ui, pack_file_contents_ui);
+        self.show_paste_errors(&errors);
+    }
+
+    /// This function converts a pasted value to the type of the column it's being pasted into.
+    ///
+    /// If the value is not valid for the column, it's added to the provided error list. Values pasted outside the table are just ignored.
+    unsafe fn coerce_pasted_value(&self, text: &str, real_column: i32, visual_row: i32, fields: &[Field], errors: &mut Vec<String>) -> Option<String> {
+        let field = fields.get(usize::try_from(real_column).ok()?)?;
+        let value = coerce_value(text, field.field_type());
+        if value.is_none() {
+            errors.push(tre("paste_invalid_value", &[&(visual_row + 1).to_string(), field.name(), text, &field.field_type().to_string()]));
+        }
+
+        value
+    }
+
+    /// This function shows a report with the values that couldn't be pasted, if any.
+    unsafe fn show_paste_errors(&self, errors: &[String]) {
+        if !errors.is_empty() {
+            let message_box = QMessageBox::from_icon2_q_string_q_flags_standard_button_q_widget(
+                q_message_box::Icon::Warning,
+                &qtr("rpfm_title"),
+                &qtre("paste_invalid_values", &[&errors.len().to_string()]),
+                QFlags::from(q_message_box::StandardButton::Ok),
+                &self.table_view,
+            );
+
+            message_box.set_detailed_text(&QString::from_std_str(errors.join("\n")));
+            message_box.exec();
+        }
     }
 
     /// Function to undo/redo an operation in the table.
@@ -2305,6 +2620,152 @@ dbg!(t.elapsed().unwrap());
         self.table_view.viewport().repaint();
     }
 
+    /// This function reloads the notes of the rows of this table from the Pack Settings, and pins the rows that have one.
+    pub unsafe fn update_row_annotations(&self) {
+        let path = match self.get_packed_file_path() {
+            Some(path) if self.get_data_source() == DataSource::PackFile && [FileType::DB, FileType::Loc].contains(&self.packed_file_type) => path,
+            _ => return,
+        };
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetRowAnnotations(path));
+        let response = CentralCommand::recv(&receiver);
+        match response {
+            Response::BTreeMapStringString(annotations) => *self.row_annotations.write().unwrap() = annotations,
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+
+        self.paint_row_annotations();
+    }
+
+    /// This function shows a pin in the header of the rows with notes, with the note as tooltip, and removes it from the rest.
+    pub unsafe fn paint_row_annotations(&self) {
+        let annotations = self.row_annotations.read().unwrap();
+        let fields_processed = self.table_definition().fields_processed();
+        let key_fields = get_key_fields(&fields_processed);
+        let pin_icon = QIcon::from_theme_1a(&QString::from_std_str("pin"));
+
+        for row in 0..self.table_model.row_count_0a() {
+            let header_item = self.table_model.vertical_header_item(row);
+            let note = if annotations.is_empty() {
+                None
+            } else {
+                get_row_key_from_view(&self.table_model_ptr(), row, &key_fields).and_then(|key| annotations.get(&key))
+            };
+
+            // Header items replace the default row numbers, so they have to show them too.
+            match note {
+                Some(note) => {
+                    let header_item = if header_item.is_null() {
+                        let item = QStandardItem::new().into_ptr();
+                        self.table_model.set_vertical_header_item(row, item);
+                        item
+                    } else {
+                        header_item
+                    };
+
+                    header_item.set_text(&QString::from_std_str((row + 1).to_string()));
+                    header_item.set_icon(&pin_icon);
+                    header_item.set_tool_tip(&QString::from_std_str(note));
+                }
+                None => if !header_item.is_null() {
+                    header_item.set_text(&QString::from_std_str((row + 1).to_string()));
+                    header_item.set_icon(&QIcon::new());
+                    header_item.set_tool_tip(&QString::new());
+                }
+            }
+        }
+    }
+
+    /// This function opens a dialog to edit the note of the selected rows. Leaving the note empty removes it.
+    pub unsafe fn edit_row_annotation(&self, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
+        let path = if let Some(path) = self.get_packed_file_path() { path } else { return };
+        let fields_processed = self.table_definition().fields_processed();
+        let key_fields = get_key_fields(&fields_processed);
+
+        let indexes = get_real_indexes_from_visible_selection_sorted(&self.table_view_ptr(), &self.table_view_filter_ptr());
+        let keys = indexes.iter()
+            .map(|index| index.row())
+            .dedup()
+            .filter_map(|row| get_row_key_from_view(&self.table_model_ptr(), row, &key_fields))
+            .unique()
+            .collect::<Vec<_>>();
+
+        if keys.is_empty() {
+            return;
+        }
+
+        // Only prefill the note if all the selected rows share it.
+        let current_note = {
+            let annotations = self.row_annotations.read().unwrap();
+            let notes = keys.iter().map(|key| annotations.get(key).cloned().unwrap_or_default()).collect::<Vec<_>>();
+            if notes.iter().all(|note| *note == notes[0]) { notes[0].to_owned() } else { String::new() }
+        };
+
+        // Create and configure the dialog.
+        let dialog = QDialog::new_1a(&self.table_view);
+        dialog.set_window_title(&qtr("row_annotation_dialog"));
+        dialog.set_modal(true);
+        dialog.resize_2a(500, 250);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtre("row_annotation_explanation", &[&keys.len().to_string()]), &dialog);
+        let note_text_edit = QTextEdit::from_q_widget(&dialog);
+        let button_box = QDialogButtonBox::from_q_widget(&dialog);
+        let cancel_button = button_box.add_button_standard_button(StandardButton::Cancel);
+        let accept_button = button_box.add_button_standard_button(StandardButton::Ok);
+
+        explanation_label.set_word_wrap(true);
+        note_text_edit.set_accept_rich_text(false);
+        note_text_edit.set_plain_text(&QString::from_std_str(&current_note));
+
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&note_text_edit, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&button_box, 2, 0, 1, 1);
+
+        cancel_button.released().connect(dialog.slot_close());
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() != 1 {
+            return;
+        }
+
+        let note = note_text_edit.to_plain_text().to_std_string();
+        let note = if note.trim().is_empty() { None } else { Some(note) };
+        {
+            let mut annotations = self.row_annotations.write().unwrap();
+            for key in keys {
+                let _ = CENTRAL_COMMAND.send_background(Command::SetRowAnnotation(path.to_owned(), key.to_owned(), note.clone()));
+                match note {
+                    Some(ref note) => annotations.insert(key, note.to_owned()),
+                    None => annotations.remove(&key),
+                };
+            }
+        }
+
+        self.paint_row_annotations();
+        UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+    }
+
+    /// This function selects the row with the provided key, if it's found in the table.
+    ///
+    /// Rows hidden by the filters cannot be selected, so this returns if the row was selected.
+    pub unsafe fn select_row_by_key(&self, key: &str) -> bool {
+        let fields_processed = self.table_definition().fields_processed();
+        let key_fields = get_key_fields(&fields_processed);
+
+        let row = (0..self.table_model.row_count_0a()).find(|row| get_row_key_from_view(&self.table_model_ptr(), *row, &key_fields).as_deref() == Some(key));
+        if let Some(row) = row {
+            let index_filtered = self.table_filter.map_from_source(&self.table_model.index_2a(row, key_fields[0].0));
+            if index_filtered.is_valid() {
+                self.table_view.scroll_to_2a(index_filtered.as_ref(), ScrollHint::EnsureVisible);
+                self.table_view.selection_model().select_q_model_index_q_flags_selection_flag(index_filtered.as_ref(), QFlags::from(SelectionFlag::ClearAndSelect));
+                return true;
+            }
+        }
+
+        false
+    }
+
     /// This function triggers a cascade edition through the entire program of the selected cells.
     pub unsafe fn cascade_edition(&self, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) {
 
@@ -2320,38 +2781,83 @@ dbg!(t.elapsed().unwrap());
 
         // Ask the dialog to get the data needed for the replacing.
         if let Some(editions) = self.cascade_edition_dialog(&indexes) {
-            app_ui.toggle_main_window(false);
 
-            // Trigger editions in our own table.
-            let real_cells = editions.iter()
-                .map(|(_, new_value, row, column)| (self.table_model.index_2a(*row, *column), &**new_value))
-                .collect::<Vec<(CppBox<QModelIndex>, &str)>>();
+            // Save all views of referencing files, so the backend has the latest data of every table.
+            let _ = AppUI::back_to_back_end_all(app_ui, pack_file_contents_ui);
 
             let fields_processed = self.table_definition().fields_processed();
-            self.set_data_on_cells(&real_cells, 0, &[], &fields_processed, app_ui, pack_file_contents_ui);
+            let changes = editions.iter().map(|(value_before, value_after, _, column)|
+                (fields_processed[*column as usize].name().to_owned(), value_before.to_string(), value_after.to_string()))
+                .collect::<Vec<_>>();
 
-            // Stop the timer again.
-            self.timer_delayed_updates.stop();
+            // Before editing anything, show the user what is going to be changed.
+            let receiver = CENTRAL_COMMAND.send_background(Command::CascadeEditionPreview(table_name.to_owned(), changes.to_vec()));
+            let response = CentralCommand::recv(&receiver);
+            let cascade_edition = match response {
+                Response::CascadeEdition(cascade_edition) => cascade_edition,
+                Response::Error(error) => {
+                    show_dialog(&self.table_view, error, false);
+                    self.start_delayed_updates_timer();
+                    return;
+                }
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            };
 
-            // Now that we know what to edit, save all views of referencing files, so we only have to deal with them in the background.
-            let _ = AppUI::back_to_back_end_all(app_ui, pack_file_contents_ui);
+            if !self.cascade_edition_preview_dialog(&cascade_edition) {
+                self.start_delayed_updates_timer();
+                return;
+            }
 
-            // Then ask the backend to do the heavy work.
-            let definition = self.table_definition().clone();
-            let fields_processed = definition.fields_processed();
-            let changes = editions.iter().map(|(value_before, value_after, _, column)|
-                (fields_processed[*column as usize].clone(), value_before.to_string(), value_after.to_string()))
-                .collect::<Vec<_>>();
+            app_ui.toggle_main_window(false);
 
-            let receiver = CENTRAL_COMMAND.send_background(Command::CascadeEdition(table_name, definition, changes));
+            // Then ask the backend to do the heavy work. If it fails, no file is edited, so we don't touch our own table either.
+            let receiver = CENTRAL_COMMAND.send_background(Command::CascadeEdition(table_name, changes));
             let response = CentralCommand::recv(&receiver);
             match response {
-                Response::VecContainerPathVecRFileInfo(edited_paths, packed_files_info) => {
+                Response::VecCascadeEditVecRFileInfo(edits, packed_files_info) => {
+
+                    // Trigger editions in our own table.
+                    let real_cells = editions.iter()
+                        .map(|(_, new_value, row, column)| (self.table_model.index_2a(*row, *column), &**new_value))
+                        .collect::<Vec<(CppBox<QModelIndex>, &str)>>();
+
+                    let key_fields = get_key_fields(&fields_processed);
+                    let edited_rows = editions.iter().map(|(_, _, row, _)| *row).sorted().dedup().collect::<Vec<_>>();
+                    let keys_before = edited_rows.iter().map(|row| get_row_key_from_view(&self.table_model_ptr(), *row, &key_fields)).collect::<Vec<_>>();
+
+                    self.set_data_on_cells(&real_cells, 0, &[], &fields_processed, app_ui, pack_file_contents_ui);
+
+                    // The backend moves the notes of the rows it edited, but our own rows are edited here, so we have to move their notes ourselves.
+                    if let Some(path) = self.get_packed_file_path() {
+                        let changes = edited_rows.iter()
+                            .zip(keys_before)
+                            .filter_map(|(row, key_before)| Some((key_before?, get_row_key_from_view(&self.table_model_ptr(), *row, &key_fields)?)))
+                            .filter(|(key_before, key_after)| key_before != key_after)
+                            .collect::<Vec<_>>();
+
+                        if !changes.is_empty() {
+                            let _ = CENTRAL_COMMAND.send_background(Command::RekeyRowAnnotations(path, changes));
+                        }
+
+                        self.update_row_annotations();
+                    }
+
+                    // Stop the timer again.
+                    self.timer_delayed_updates.stop();
 
                     // If it worked, get the list of edited PackedFiles and update the TreeView to reflect the change.
-                    pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Modify(edited_paths.to_vec()), DataSource::PackFile);
-                    pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::MarkAlwaysModified(edited_paths.to_vec()), DataSource::PackFile);
-                    pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateTooltip(packed_files_info), DataSource::PackFile);
+                    let edited_paths = edits.iter()
+                        .map(|edit| edit.path().to_owned())
+                        .sorted()
+                        .dedup()
+                        .map(ContainerPath::File)
+                        .collect::<Vec<_>>();
+
+                    if !edited_paths.is_empty() {
+                        pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Modify(edited_paths.to_vec()), DataSource::PackFile);
+                        pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::MarkAlwaysModified(edited_paths.to_vec()), DataSource::PackFile);
+                        pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::UpdateTooltip(packed_files_info), DataSource::PackFile);
+                    }
 
                     // Before finishing, reload all edited views.
                     let mut open_packedfiles = UI_STATE.set_open_packedfiles();
@@ -2362,14 +2868,15 @@ dbg!(t.elapsed().unwrap());
                             }
                         }
                     });
-
-                    app_ui.toggle_main_window(true);
-
-                    // Now it's safe to trigger the timer.
-                    self.start_delayed_updates_timer();
                 }
+                Response::Error(error) => show_dialog(&self.table_view, error, false),
                 _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
             }
+
+            app_ui.toggle_main_window(true);
+
+            // Now it's safe to trigger the timer.
+            self.start_delayed_updates_timer();
         }
 
         // If we didn't do anything, but we cut a timer, continue it.
@@ -2419,6 +2926,197 @@ dbg!(t.elapsed().unwrap());
         } else { None }
     }
 
+    /// This function clones the key of the selected row into a new key, along with all the rows of other tables referencing it.
+    pub unsafe fn deep_clone(&self, app_ui: &Rc<AppUI>, pack_file_contents_ui: &Rc<PackFileContentsUI>) -> Result<()> {
+
+        // We only want to do this for tables we can identify.
+        let table_name = match self.table_name() {
+            Some(table_name) => table_name.to_lowercase(),
+            None => return Err(anyhow!("This is either not a DB Table, or it's a DB Table but it's corrupted.")),
+        };
+
+        // Only works with a single row selected.
+        let indexes = get_real_indexes_from_visible_selection_sorted(&self.table_view_ptr(), &self.table_view_filter_ptr());
+        let rows: Vec<i32> = indexes.iter().map(|x| x.row()).sorted().dedup().collect();
+        if rows.len() != 1 {
+            return Err(anyhow!("Either 0 or more than 1 row selected. This only works with 1 row selected."));
+        }
+
+        // The key we clone is the one in the first key column, same as the backend does.
+        let fields_processed = self.table_definition().fields_processed();
+        let key_column = fields_processed.iter().position(|field| field.is_key()).unwrap_or(0);
+        let key = self.table_model.index_2a(rows[0], key_column as i32).data_0a().to_string().to_std_string();
+
+        let (new_key, include_vanilla) = match self.deep_clone_dialog(&key) {
+            Some(data) => data,
+            None => return Ok(()),
+        };
+
+        // Save all views, so the backend has the latest data of every table.
+        self.timer_delayed_updates.stop();
+        let _ = AppUI::back_to_back_end_all(app_ui, pack_file_contents_ui);
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::DeepCloneKeyPreview(table_name.to_owned(), key.to_owned(), new_key.to_owned(), include_vanilla));
+        let response = CentralCommand::recv(&receiver);
+        let deep_clone = match response {
+            Response::DeepClone(deep_clone) => deep_clone,
+            Response::Error(error) => {
+                self.start_delayed_updates_timer();
+                return Err(error);
+            }
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        if !self.deep_clone_preview_dialog(deep_clone.rows()) {
+            self.start_delayed_updates_timer();
+            return Ok(());
+        }
+
+        app_ui.toggle_main_window(false);
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::DeepCloneKey(table_name, key, new_key, include_vanilla));
+        let response = CentralCommand::recv(&receiver);
+        let result = match response {
+            Response::VecClonedRowVecContainerPathVecContainerPath(rows, modified_paths, added_paths) => {
+                if !added_paths.is_empty() {
+                    pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Add(added_paths.to_vec()), DataSource::PackFile);
+                }
+
+                if !modified_paths.is_empty() {
+                    pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Modify(modified_paths.to_vec()), DataSource::PackFile);
+                }
+
+                // Reload all edited views, so they show the new rows.
+                let mut open_packedfiles = UI_STATE.set_open_packedfiles();
+                modified_paths.iter().for_each(|path| {
+                    if let Some(packed_file_view) = open_packedfiles.iter_mut().find(|x| *x.get_ref_path() == path.path_raw() && x.get_data_source() == DataSource::PackFile) {
+                        if packed_file_view.reload(path.path_raw(), pack_file_contents_ui).is_err() {
+                            let _ = AppUI::purge_that_one_specifically(app_ui, pack_file_contents_ui, path.path_raw(), DataSource::PackFile, false);
+                        }
+                    }
+                });
+
+                UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+                log_to_status_bar(&tre("deep_clone_success", &[&rows.len().to_string(), &(modified_paths.len() + added_paths.len()).to_string()]));
+                Ok(())
+            }
+            Response::Error(error) => Err(error),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        app_ui.toggle_main_window(true);
+        self.start_delayed_updates_timer();
+        result
+    }
+
+    /// This function creates the "Deep Clone" dialog.
+    ///
+    /// It returns the new key and if vanilla rows should be cloned too, or `None` if the dialog is canceled or closed.
+    pub unsafe fn deep_clone_dialog(&self, key: &str) -> Option<(String, bool)> {
+
+        // Create and configure the dialog.
+        let dialog = QDialog::new_1a(&self.table_view);
+        dialog.set_window_title(&qtr("deep_clone_dialog"));
+        dialog.set_modal(true);
+        dialog.resize_2a(600, 50);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let instructions_label = QLabel::from_q_string_q_widget(&qtr("deep_clone_instructions"), &dialog);
+        let key_label = QLabel::from_q_string_q_widget(&qtr("deep_clone_key"), &dialog);
+        let key_line_edit = QLineEdit::from_q_string_q_widget(&QString::from_std_str(key), &dialog);
+        let new_key_label = QLabel::from_q_string_q_widget(&qtr("deep_clone_new_key"), &dialog);
+        let new_key_line_edit = QLineEdit::from_q_string_q_widget(&QString::from_std_str(key), &dialog);
+        let include_vanilla_checkbox = QCheckBox::from_q_string_q_widget(&qtr("deep_clone_include_vanilla"), &dialog);
+        let accept_button = QPushButton::from_q_string(&qtr("gen_loc_accept"));
+
+        instructions_label.set_word_wrap(true);
+        key_line_edit.set_enabled(false);
+        include_vanilla_checkbox.set_checked(true);
+
+        main_grid.add_widget_5a(&instructions_label, 0, 0, 1, 2);
+        main_grid.add_widget_5a(&key_label, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&key_line_edit, 1, 1, 1, 1);
+        main_grid.add_widget_5a(&new_key_label, 2, 0, 1, 1);
+        main_grid.add_widget_5a(&new_key_line_edit, 2, 1, 1, 1);
+        main_grid.add_widget_5a(&include_vanilla_checkbox, 3, 0, 1, 2);
+        main_grid.add_widget_5a(&accept_button, 4, 0, 1, 2);
+
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 {
+            let new_key = new_key_line_edit.text().to_std_string();
+            if new_key.is_empty() || new_key == key { None } else { Some((new_key, include_vanilla_checkbox.is_checked())) }
+        } else { None }
+    }
+
+    /// This function shows the rows a deep clone is going to create, and asks for confirmation.
+    pub unsafe fn deep_clone_preview_dialog(&self, rows: &[ClonedRow]) -> bool {
+
+        // Create and configure the dialog.
+        let dialog = QDialog::new_1a(&self.table_view);
+        dialog.set_window_title(&qtr("deep_clone_preview_dialog"));
+        dialog.set_modal(true);
+        dialog.resize_2a(900, 500);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtre("deep_clone_preview_explanation", &[&rows.len().to_string()]), &dialog);
+        let rows_text_edit = QTextEdit::from_q_widget(&dialog);
+        let button_box = QDialogButtonBox::from_q_widget(&dialog);
+        let cancel_button = button_box.add_button_standard_button(StandardButton::Cancel);
+        let accept_button = button_box.add_button_standard_button(StandardButton::Ok);
+
+        let text = rows.iter()
+            .map(|row| format!("{} ({}): {}", row.path(), row.column_name(), row.row().iter().map(|data| data.data_to_string()).join(" | ")))
+            .join("\n");
+
+        explanation_label.set_word_wrap(true);
+        rows_text_edit.set_read_only(true);
+        rows_text_edit.set_plain_text(&QString::from_std_str(text));
+
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&rows_text_edit, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&button_box, 2, 0, 1, 1);
+
+        cancel_button.released().connect(dialog.slot_close());
+        accept_button.released().connect(dialog.slot_accept());
+
+        dialog.exec() == 1
+    }
+
+    /// This function shows the cells a cascade edition is going to change, and asks for confirmation.
+    pub unsafe fn cascade_edition_preview_dialog(&self, cascade_edition: &CascadeEdition) -> bool {
+
+        // Create and configure the dialog.
+        let dialog = QDialog::new_1a(&self.table_view);
+        dialog.set_window_title(&qtr("cascade_edition_preview_dialog"));
+        dialog.set_modal(true);
+        dialog.resize_2a(900, 500);
+
+        let main_grid = create_grid_layout(dialog.static_upcast());
+        let explanation_label = QLabel::from_q_string_q_widget(&qtre("cascade_edition_preview_explanation", &[&cascade_edition.edits().len().to_string(), &cascade_edition.files_count().to_string()]), &dialog);
+        let edits_text_edit = QTextEdit::from_q_widget(&dialog);
+        let button_box = QDialogButtonBox::from_q_widget(&dialog);
+        let cancel_button = button_box.add_button_standard_button(StandardButton::Cancel);
+        let accept_button = button_box.add_button_standard_button(StandardButton::Ok);
+
+        let text = cascade_edition.edits().iter()
+            .map(|edit| format!("{} (row {}, {}): {} -> {}", edit.path(), edit.row() + 1, edit.column_name(), edit.value_before(), edit.value_after()))
+            .join("\n");
+
+        explanation_label.set_word_wrap(true);
+        edits_text_edit.set_read_only(true);
+        edits_text_edit.set_plain_text(&QString::from_std_str(text));
+
+        main_grid.add_widget_5a(&explanation_label, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&edits_text_edit, 1, 0, 1, 1);
+        main_grid.add_widget_5a(&button_box, 2, 0, 1, 1);
+
+        cancel_button.released().connect(dialog.slot_close());
+        accept_button.released().connect(dialog.slot_accept());
+
+        dialog.exec() == 1
+    }
+
     /// This function creates the "Patch Column" dialog and submits a patch of accepted.
     pub unsafe fn patch_column(&self) -> Result<()> {
 
@@ -2586,7 +3284,8 @@ dbg!(t.elapsed().unwrap());
                                     }
                                 }
                             },
-                            DataSource::ExternalFile => {},
+                            DataSource::ExternalFile |
+                            DataSource::ComparedPackFile => {},
                         }
 
                         // Set the current file as non-preview, so it doesn't close when opening the source one.
@@ -2698,7 +3397,8 @@ dbg!(t.elapsed().unwrap());
                                     }
                                 }
                             },
-                            DataSource::ExternalFile => {},
+                            DataSource::ExternalFile |
+                            DataSource::ComparedPackFile => {},
                         }
 
                         // Set the current file as non-preview, so it doesn't close when opening the source one.
@@ -2738,6 +3438,218 @@ dbg!(t.elapsed().unwrap());
         else { Some(error_message) }
     }
 
+    /// This function opens the loc entries related with the currently selected row, selecting them.
+    ///
+    /// The loc keys are generated from the localised fields of the table. Entries are searched in the open Pack first, then in the parent files,
+    /// then in the vanilla files. If none of them are found, it offers to create them in the open Pack.
+    pub unsafe fn open_related_loc_entries(
+        &self,
+        app_ui: &Rc<AppUI>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        global_search_ui: &Rc<GlobalSearchUI>,
+        diagnostics_ui: &Rc<DiagnosticsUI>,
+        dependencies_ui: &Rc<DependenciesUI>,
+        references_ui: &Rc<ReferencesUI>,
+    ) -> Option<String> {
+
+        // This is only for DB Tables with localised fields, and we need to have something selected.
+        let indexes = self.table_view.selection_model().selection().indexes();
+        if indexes.count_0a() == 0 || *self.packed_file_type != FileType::DB {
+            return None;
+        }
+
+        let table_definition = self.table_definition();
+        let table_name = match self.table_name {
+            Some(ref table_name) if !table_definition.localised_fields().is_empty() => table_name.strip_suffix("_tables").unwrap_or(table_name).to_owned(),
+            _ => return Some(tr("loc_key_not_found")),
+        };
+
+        // Get the keys of the selected row, IN THE ORDER THEY ARE IN THE BINARY FILE, to build the loc keys.
+        let key_field_names = table_definition.fields().iter().filter_map(|field| if field.is_key() { Some(field.name()) } else { None }).collect::<Vec<&str>>();
+        let key_field_positions = key_field_names.iter().filter_map(|name| table_definition.fields_processed().iter().position(|field| field.name() == *name)).collect::<Vec<usize>>();
+        let row = self.table_filter.map_to_source(indexes.at(0)).row();
+        let key = key_field_positions.iter().map(|column| self.table_model.index_2a(row, *column as i32).data_0a().to_string().to_std_string()).join("");
+        let loc_keys = table_definition.localised_fields().iter().map(|field| format!("{}_{}_{}", table_name, field.name(), key)).collect::<Vec<_>>();
+
+        // Save the currently open locs, to ensure the backend has the most up-to-date data.
+        UI_STATE.get_open_packedfiles().iter().filter(|x| x.get_data_source() == DataSource::PackFile).for_each(|packed_file_view| {
+            if let FileType::Loc = packed_file_view.get_packed_file_type() {
+                let _ = packed_file_view.save(app_ui, pack_file_contents_ui);
+            }
+        });
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetLocEntries(loc_keys));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        let (mut entries, missing_keys) = match response {
+            Response::VecDataSourceStringUsizeVecString(entries, missing_keys) => (entries, missing_keys),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        };
+
+        // If there are no entries for the row, offer to create them.
+        if entries.is_empty() {
+            if !AppUI::are_you_sure_edition(app_ui, "related_loc_entries_not_found_create") {
+                return None;
+            }
+
+            let receiver = CENTRAL_COMMAND.send_background(Command::AddLocEntries(missing_keys.to_vec()));
+            let response = CENTRAL_COMMAND.recv_try(&receiver);
+            match response {
+                Response::VecContainerPathVecContainerPath(modified_paths, added_paths) => {
+                    if !added_paths.is_empty() {
+                        pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Add(added_paths.to_vec()), DataSource::PackFile);
+                    }
+
+                    if !modified_paths.is_empty() {
+                        pack_file_contents_ui.packfile_contents_tree_view().update_treeview(true, TreeViewOperation::Modify(modified_paths.to_vec()), DataSource::PackFile);
+                    }
+
+                    // Reload the edited loc if it's open, so it shows the new entries.
+                    let mut open_packedfiles = UI_STATE.set_open_packedfiles();
+                    modified_paths.iter().for_each(|path| {
+                        if let Some(packed_file_view) = open_packedfiles.iter_mut().find(|x| *x.get_ref_path() == path.path_raw() && x.get_data_source() == DataSource::PackFile) {
+                            if packed_file_view.reload(path.path_raw(), pack_file_contents_ui).is_err() {
+                                let _ = AppUI::purge_that_one_specifically(app_ui, pack_file_contents_ui, path.path_raw(), DataSource::PackFile, false);
+                            }
+                        }
+                    });
+
+                    drop(open_packedfiles);
+                    UI_STATE.set_is_modified(true, app_ui, pack_file_contents_ui);
+                }
+                Response::Error(error) => return Some(error.to_string()),
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            }
+
+            let receiver = CENTRAL_COMMAND.send_background(Command::GetLocEntries(missing_keys));
+            let response = CENTRAL_COMMAND.recv_try(&receiver);
+            entries = match response {
+                Response::VecDataSourceStringUsizeVecString(entries, _) => entries,
+                _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+            };
+        }
+
+        self.open_and_select_rows(app_ui, pack_file_contents_ui, global_search_ui, diagnostics_ui, dependencies_ui, references_ui, &entries);
+        None
+    }
+
+    /// This function opens the table row where the loc entry of the currently selected row comes from, selecting it.
+    ///
+    /// If the row it's not found, it does nothing.
+    pub unsafe fn open_defining_table_row(
+        &self,
+        app_ui: &Rc<AppUI>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        global_search_ui: &Rc<GlobalSearchUI>,
+        diagnostics_ui: &Rc<DiagnosticsUI>,
+        dependencies_ui: &Rc<DependenciesUI>,
+        references_ui: &Rc<ReferencesUI>,
+    ) -> Option<String> {
+
+        // This is only for Locs, and we need to have something selected.
+        let indexes = self.table_view.selection_model().selection().indexes();
+        if indexes.count_0a() == 0 || *self.packed_file_type != FileType::Loc {
+            return None;
+        }
+
+        // Save the currently open tables, to ensure the backend has the most up-to-date data.
+        UI_STATE.get_open_packedfiles().iter().filter(|x| x.get_data_source() == DataSource::PackFile).for_each(|packed_file_view| {
+            if let FileType::DB = packed_file_view.get_packed_file_type() {
+                let _ = packed_file_view.save(app_ui, pack_file_contents_ui);
+            }
+        });
+
+        // For Locs, we use the column 0 of the row with the selected item.
+        let row = self.table_filter.map_to_source(indexes.at(0)).row();
+        let key = self.table_model.index_2a(row, 0).data_0a().to_string().to_std_string();
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetLocKeySourceRow(key));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::OptionDataSourceStringUsize(Some(entry)) => {
+                self.open_and_select_rows(app_ui, pack_file_contents_ui, global_search_ui, diagnostics_ui, dependencies_ui, references_ui, &[entry]);
+                None
+            }
+            Response::OptionDataSourceStringUsize(None) => Some(tr("source_data_for_field_not_found")),
+            _ => panic!("{}{:?}", THREADS_COMMUNICATION_ERROR, response),
+        }
+    }
+
+    /// This function opens the tables of the provided source/path/row entries, selecting the full rows of each one.
+    ///
+    /// If there is more than one table to open, they're not opened as preview, so they don't replace each other.
+    unsafe fn open_and_select_rows(
+        &self,
+        app_ui: &Rc<AppUI>,
+        pack_file_contents_ui: &Rc<PackFileContentsUI>,
+        global_search_ui: &Rc<GlobalSearchUI>,
+        diagnostics_ui: &Rc<DiagnosticsUI>,
+        dependencies_ui: &Rc<DependenciesUI>,
+        references_ui: &Rc<ReferencesUI>,
+        entries: &[(DataSource, String, usize)],
+    ) {
+
+        // Set the current file as non-preview, so it doesn't close when opening the other ones.
+        if let Some(packed_file_path) = self.get_packed_file_path() {
+            if let Some(packed_file_view) = UI_STATE.get_open_packedfiles().iter().find(|x| *x.get_ref_path() == *packed_file_path && x.get_data_source() == self.get_data_source()) {
+                packed_file_view.set_is_preview(false);
+            }
+        }
+
+        let files = entries.iter().map(|(data_source, path, _)| (*data_source, path.to_owned())).unique().collect::<Vec<_>>();
+        for (data_source, path) in &files {
+            match data_source {
+                DataSource::PackFile => {
+                    let tree_index = pack_file_contents_ui.packfile_contents_tree_view().expand_treeview_to_item(path, *data_source);
+                    if let Some(ref tree_index) = tree_index {
+                        if tree_index.is_valid() {
+                            let _blocker = QSignalBlocker::from_q_object(pack_file_contents_ui.packfile_contents_tree_view().static_upcast::<QObject>());
+                            pack_file_contents_ui.packfile_contents_tree_view().scroll_to_1a(tree_index.as_ref().unwrap());
+                            pack_file_contents_ui.packfile_contents_tree_view().selection_model().select_q_model_index_q_flags_selection_flag(tree_index.as_ref().unwrap(), QFlags::from(SelectionFlag::ClearAndSelect));
+                        }
+                    }
+                },
+                DataSource::ParentFiles |
+                DataSource::AssKitFiles |
+                DataSource::GameFiles => {
+                    let tree_index = dependencies_ui.dependencies_tree_view().expand_treeview_to_item(path, DataSource::GameFiles);
+                    if let Some(ref tree_index) = tree_index {
+                        if tree_index.is_valid() {
+                            let _blocker = QSignalBlocker::from_q_object(dependencies_ui.dependencies_tree_view().static_upcast::<QObject>());
+                            dependencies_ui.dependencies_tree_view().scroll_to_1a(tree_index.as_ref().unwrap());
+                            dependencies_ui.dependencies_tree_view().selection_model().select_q_model_index_q_flags_selection_flag(tree_index.as_ref().unwrap(), QFlags::from(SelectionFlag::ClearAndSelect));
+                        }
+                    }
+                },
+                DataSource::ExternalFile |
+                DataSource::ComparedPackFile => {},
+            }
+
+            // Open the table and select the rows, scrolling to the first one.
+            AppUI::open_packedfile(app_ui, pack_file_contents_ui, global_search_ui, diagnostics_ui, dependencies_ui, references_ui, Some(path.to_owned()), files.len() == 1, false, *data_source);
+            if let Some(packed_file_view) = UI_STATE.get_open_packedfiles().iter().find(|x| x.get_ref_path() == path && x.get_data_source() == *data_source) {
+                if let ViewType::Internal(View::Table(view)) = packed_file_view.get_view() {
+                    let table_view = view.get_ref_table();
+                    let table_view = table_view.table_view_ptr();
+                    let table_filter: QPtr<QSortFilterProxyModel> = table_view.model().static_downcast();
+                    let table_model: QPtr<QStandardItemModel> = table_filter.source_model().static_downcast();
+                    let table_selection_model = table_view.selection_model();
+                    table_selection_model.clear();
+
+                    let mut scrolled = false;
+                    for (_, _, row) in entries.iter().filter(|(entry_data_source, entry_path, _)| entry_data_source == data_source && entry_path == path) {
+                        let table_model_index_filtered = table_filter.map_from_source(&table_model.index_2a(*row as i32, 0));
+                        if table_model_index_filtered.is_valid() {
+                            table_selection_model.select_q_model_index_q_flags_selection_flag(table_model_index_filtered.as_ref(), SelectionFlag::Select | SelectionFlag::Rows);
+                            if !scrolled {
+                                table_view.scroll_to_2a(table_model_index_filtered.as_ref(), ScrollHint::EnsureVisible);
+                                scrolled = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// This function clears the markings for added/modified cells.
     pub unsafe fn clear_markings(&self) {
         let table_view = self.table_view_ptr();
@@ -2761,6 +3673,115 @@ dbg!(t.elapsed().unwrap());
         blocker.unblock();
         table_view.viewport().repaint();
     }
+
+    /// This function returns the key used to store the column presets of this table.
+    ///
+    /// DB Tables use their table name, so all the files of the same table share presets. Other tables use their file type.
+    pub fn column_presets_key(&self) -> String {
+        match self.table_name {
+            Some(ref table_name) => table_name.to_owned(),
+            None => self.packed_file_type.to_string(),
+        }
+    }
+
+    /// This function returns the current column layout of the table as a preset.
+    pub unsafe fn column_preset(&self) -> ColumnPreset {
+        let header = self.table_view.horizontal_header();
+        let fields_processed = self.table_definition().fields_processed();
+
+        // Hidden sections report a size of 0, which means their width is not stored.
+        let columns = (0..header.count())
+            .filter_map(|visual_index| {
+                let logical_index = header.logical_index(visual_index);
+                fields_processed.get(logical_index as usize).map(|field| ColumnPresetEntry::new(
+                    field.name(),
+                    header.is_section_hidden(logical_index),
+                    header.section_size(logical_index)
+                ))
+            })
+            .collect();
+
+        ColumnPreset::new(columns)
+    }
+
+    /// This function applies the provided column preset to the table.
+    ///
+    /// Columns are matched by field name, so presets made with older versions of the table's definition still work.
+    pub unsafe fn apply_column_preset(&self, preset: &ColumnPreset) {
+        let header = self.table_view.horizontal_header();
+        let definition = self.table_definition();
+        let fields_processed = definition.fields_processed();
+        let fields_sorted = definition.fields_processed_sorted(setting_bool("tables_use_old_column_order"));
+        let field_names = fields_processed.iter().map(|field| field.name()).collect::<Vec<_>>();
+
+        for (visual_index, (logical_index, entry)) in preset.layout(&field_names).iter().enumerate() {
+            header.move_section(header.visual_index(*logical_index as i32), visual_index as i32);
+
+            if let Some(entry) = entry {
+                if *entry.width() > 0 {
+                    header.resize_section(*logical_index as i32, *entry.width());
+                }
+            }
+
+            // Columns are hidden through the sidebar, so its checkboxes reflect the state of the columns.
+            let hidden = entry.map(|entry| *entry.hidden()).unwrap_or(false);
+            if let Some(checkbox_index) = fields_sorted.iter().position(|field| field.name() == field_names[*logical_index]) {
+                self.sidebar_hide_checkboxes[checkbox_index].set_checked(hidden);
+            }
+        }
+    }
+
+    /// This function applies the active column preset of the table, if it has one.
+    pub unsafe fn apply_active_column_preset(&self) {
+        match TableColumnPresets::load(&self.column_presets_key()) {
+            Ok(presets) => if let Some(preset) = presets.active_preset() {
+                self.apply_column_preset(preset);
+            }
+            Err(error) => warn!("Failed to load the column presets: {}", error),
+        }
+    }
+
+    /// This function resets the column layout to the one from the table's definition, showing all columns.
+    pub unsafe fn reset_column_layout(&self) {
+        let header = self.table_view.horizontal_header();
+        for logical_index in 0..header.count() {
+            header.move_section(header.visual_index(logical_index), logical_index);
+        }
+
+        self.sidebar_hide_checkboxes.iter().for_each(|checkbox| checkbox.set_checked(false));
+
+        build_columns(
+            &self.table_view_ptr(),
+            &self.table_definition(),
+            self.table_name.as_deref(),
+            Some(&self.patches()),
+        );
+    }
+
+    /// This function creates the dialog to name a column preset. It returns the name, or None.
+    pub unsafe fn create_column_preset_name_dialog(&self, name: &str) -> Option<String> {
+
+        // Create and configure the dialog.
+        let dialog = QDialog::new_1a(&self.table_view);
+        dialog.set_window_title(&qtr("column_presets_save_title"));
+        dialog.set_modal(true);
+        dialog.resize_2a(400, 50);
+        let main_grid = create_grid_layout(dialog.static_upcast());
+
+        let name_line_edit = QLineEdit::from_q_string_q_widget(&QString::from_std_str(name), &dialog);
+        name_line_edit.set_placeholder_text(&qtr("column_presets_save_placeholder"));
+        let accept_button = QPushButton::from_q_string(&qtr("gen_loc_accept"));
+
+        main_grid.add_widget_5a(&name_line_edit, 0, 0, 1, 1);
+        main_grid.add_widget_5a(&accept_button, 0, 1, 1, 1);
+
+        accept_button.released().connect(dialog.slot_accept());
+
+        if dialog.exec() == 1 {
+            let name = name_line_edit.text().to_std_string().trim().to_owned();
+            if name.is_empty() { None } else { Some(name) }
+        } else { None }
+    }
 }
 
 //----------------------------------------------------------------//
@@ -0,0 +1,183 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+/*!
+In this file are the functions to turn what spreadsheet programs (Excel, LibreOffice,...) put in the
+clipboard into values we can paste into a table.
+!*/
+
+use rpfm_lib::schema::FieldType;
+
+//-------------------------------------------------------------------------------//
+//                             Extra functions
+//-------------------------------------------------------------------------------//
+
+/// This function parses the text of the clipboard as a block of cells, in TSV format.
+///
+/// Spreadsheet programs quote the cells containing tabs, line jumps or quotes, so we unquote them here.
+/// The line jump at the end of the block, and the carriage returns of Windows line jumps, are ignored.
+pub fn parse_clipboard(text: &str) -> Vec<Vec<String>> {
+    let text = text.replace("\r\n", "\n");
+    let text = text.strip_suffix('\n').unwrap_or(&text);
+
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut cell = String::new();
+    let mut chars = text.chars().peekable();
+
+    // Quotes only have a meaning at the start of a cell.
+    let mut quoted = chars.peek() == Some(&'"');
+    if quoted {
+        chars.next();
+    }
+
+    while let Some(character) = chars.next() {
+        if quoted {
+            if character == '"' {
+                if chars.peek() == Some(&'"') {
+                    cell.push('"');
+                    chars.next();
+                } else {
+                    quoted = false;
+                }
+            } else {
+                cell.push(character);
+            }
+        }
+
+        else if character == '\t' || character == '\n' {
+            row.push(cell);
+            cell = String::new();
+
+            if character == '\n' {
+                rows.push(row);
+                row = vec![];
+            }
+
+            quoted = chars.peek() == Some(&'"');
+            if quoted {
+                chars.next();
+            }
+        }
+
+        else {
+            cell.push(character);
+        }
+    }
+
+    row.push(cell);
+    rows.push(row);
+    rows
+}
+
+/// This function converts the provided text to the format the table expects for the provided type of field.
+///
+/// Booleans can be `true/false`, `1/0` or `yes/no`, and numbers can use either a dot or a comma as decimal separator.
+/// If the text is not a valid value for the type, it returns `None`.
+pub fn coerce_value(text: &str, field_type: &FieldType) -> Option<String> {
+    match field_type {
+        FieldType::Boolean => match &*text.trim().to_lowercase() {
+            "true" | "1" | "yes" => Some("true".to_owned()),
+            "false" | "0" | "no" => Some("false".to_owned()),
+            _ => None,
+        },
+
+        FieldType::F32 => normalize_number(text).filter(|value| matches!(value.parse::<f32>(), Ok(value) if value.is_finite())),
+        FieldType::F64 => normalize_number(text).filter(|value| matches!(value.parse::<f64>(), Ok(value) if value.is_finite())),
+
+        FieldType::I16 | FieldType::OptionalI16 => coerce_integer(text, i16::MIN as f64, i16::MAX as f64),
+        FieldType::I32 | FieldType::OptionalI32 => coerce_integer(text, i32::MIN as f64, i32::MAX as f64),
+        FieldType::I64 | FieldType::OptionalI64 => coerce_integer(text, i64::MIN as f64, i64::MAX as f64),
+
+        FieldType::ColourRGB => {
+            let text = text.trim();
+            let text = text.strip_prefix('#').unwrap_or(text);
+            if !text.is_empty() && text.len() <= 6 && u32::from_str_radix(text, 16).is_ok() {
+                Some(text.to_owned())
+            } else {
+                None
+            }
+        }
+
+        // Strings are pasted as they are.
+        FieldType::StringU8 |
+        FieldType::StringU16 |
+        FieldType::OptionalStringU8 |
+        FieldType::OptionalStringU16 => Some(text.to_owned()),
+
+        // Sequences cannot be pasted.
+        FieldType::SequenceU16(_) | FieldType::SequenceU32(_) => None,
+    }
+}
+
+/// This function normalizes a number to use a dot as decimal separator, removing any thousands separator.
+///
+/// If both a dot and a comma are used, the last one is considered the decimal separator.
+fn normalize_number(text: &str) -> Option<String> {
+    let text = text.trim();
+    let decimal_separator = match (text.rfind('.'), text.rfind(',')) {
+        (Some(dot), Some(comma)) => if dot > comma { '.' } else { ',' },
+        (None, Some(_)) => ',',
+        _ => '.',
+    };
+
+    // A number cannot have more than one decimal separator.
+    if text.matches(decimal_separator).count() > 1 {
+        return None;
+    }
+
+    let number = text.chars()
+        .filter(|character| (*character != ',' && *character != '.') || *character == decimal_separator)
+        .map(|character| if character == decimal_separator { '.' } else { character })
+        .collect::<String>();
+
+    if number.is_empty() {
+        None
+    } else {
+        Some(number)
+    }
+}
+
+/// This function converts the provided text to an integer within the provided range.
+///
+/// Commas followed by groups of exactly 3 digits are thousands separators. Numbers with a zero fractional part
+/// are accepted, as spreadsheets like to turn integers into floats, but numbers with decimals are rejected.
+fn coerce_integer(text: &str, min: f64, max: f64) -> Option<String> {
+    let text = text.trim();
+    let number = if has_thousands_separators(text) {
+        text.replace(',', "")
+    } else {
+        normalize_number(text)?
+    };
+
+    if let Ok(value) = number.parse::<i64>() {
+        if (min..=max).contains(&(value as f64)) {
+            return Some(value.to_string());
+        }
+    }
+
+    match number.parse::<f64>() {
+        Ok(value) if value.is_finite() && value.fract() == 0.0 && (min..=max).contains(&value) => Some((value as i64).to_string()),
+        _ => None,
+    }
+}
+
+/// This function checks if the integer part of the provided number uses commas as thousands separators, like `1,000,000`.
+fn has_thousands_separators(text: &str) -> bool {
+    let integer_part = text.split('.').next().unwrap_or_default();
+    let integer_part = integer_part.strip_prefix(['-', '+']).unwrap_or(integer_part);
+    let mut groups = integer_part.split(',');
+    let first = groups.next().unwrap_or_default();
+
+    integer_part.contains(',') &&
+        (1..=3).contains(&first.len()) &&
+        first.chars().all(|character| character.is_ascii_digit()) &&
+        groups.all(|group| group.len() == 3 && group.chars().all(|character| character.is_ascii_digit()))
+}
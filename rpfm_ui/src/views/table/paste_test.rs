@@ -0,0 +1,62 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2023 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted PackFile Manager (RPFM) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing tests for the parsing and conversion of pasted cells.
+
+use rpfm_lib::schema::FieldType;
+
+use super::paste::{coerce_value, parse_clipboard};
+
+#[test]
+fn test_parse_clipboard() {
+    assert_eq!(parse_clipboard("a\tb\r\nc\td\r\n"), vec![vec!["a", "b"], vec!["c", "d"]]);
+    assert_eq!(parse_clipboard("a\t\tb"), vec![vec!["a", "", "b"]]);
+    assert_eq!(parse_clipboard(""), vec![vec![""]]);
+
+    // Quoted cells can contain tabs, line jumps and quotes.
+    assert_eq!(parse_clipboard("\"multi\nline\"\t\"tab\there\"\n\"say \"\"hi\"\"\"\tx"), vec![vec!["multi\nline", "tab\there"], vec!["say \"hi\"", "x"]]);
+
+    // Quotes not at the start of a cell are just text.
+    assert_eq!(parse_clipboard("5\" barrel\tb"), vec![vec!["5\" barrel", "b"]]);
+}
+
+#[test]
+fn test_coerce_value() {
+    assert_eq!(coerce_value("TRUE", &FieldType::Boolean), Some("true".to_owned()));
+    assert_eq!(coerce_value("0", &FieldType::Boolean), Some("false".to_owned()));
+    assert_eq!(coerce_value(" yes ", &FieldType::Boolean), Some("true".to_owned()));
+    assert_eq!(coerce_value("maybe", &FieldType::Boolean), None);
+
+    // Both decimal separators are accepted, with or without thousands separators.
+    assert_eq!(coerce_value("1,5", &FieldType::F32), Some("1.5".to_owned()));
+    assert_eq!(coerce_value("1.234,5", &FieldType::F64), Some("1234.5".to_owned()));
+    assert_eq!(coerce_value("1,234.5", &FieldType::F64), Some("1234.5".to_owned()));
+    assert_eq!(coerce_value("1,2,3", &FieldType::F32), None);
+    assert_eq!(coerce_value("abc", &FieldType::F32), None);
+
+    // Integers are checked against the range of the type, and cannot have decimals.
+    assert_eq!(coerce_value("42", &FieldType::I32), Some("42".to_owned()));
+    assert_eq!(coerce_value("5.0", &FieldType::I32), Some("5".to_owned()));
+    assert_eq!(coerce_value("2,6", &FieldType::OptionalI16), None);
+    assert_eq!(coerce_value("2.6", &FieldType::I64), None);
+
+    // Commas followed by groups of 3 digits are thousands separators.
+    assert_eq!(coerce_value("1,000", &FieldType::I32), Some("1000".to_owned()));
+    assert_eq!(coerce_value("-1,000,000", &FieldType::I64), Some("-1000000".to_owned()));
+    assert_eq!(coerce_value("1,000.5", &FieldType::I32), None);
+    assert_eq!(coerce_value("1,50", &FieldType::I32), None);
+    assert_eq!(coerce_value("40000", &FieldType::I16), None);
+    assert_eq!(coerce_value("", &FieldType::I64), None);
+
+    assert_eq!(coerce_value("#FF00aa", &FieldType::ColourRGB), Some("FF00aa".to_owned()));
+    assert_eq!(coerce_value("GG0000", &FieldType::ColourRGB), None);
+
+    assert_eq!(coerce_value(" keep spaces ", &FieldType::StringU8), Some(" keep spaces ".to_owned()));
+}
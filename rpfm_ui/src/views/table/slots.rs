@@ -47,6 +47,14 @@ use crate::UI_STATE;
 use super::utils::*;
 use super::*;
 
+/// Name filters for the files tables can be exported to.
+const TSV_FILTER: &str = "TSV Files (*.tsv)";
+const CSV_COMMA_FILTER: &str = "CSV Files, comma-separated (*.csv)";
+const CSV_SEMICOLON_FILTER: &str = "CSV Files, semicolon-separated (*.csv)";
+
+/// Name filter for the files column presets can be exported to.
+const JSON_FILTER: &str = "JSON Files (*.json)";
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -80,13 +88,25 @@ pub struct TableViewSlots {
     pub export_tsv: QBox<SlotOfBool>,
     pub smart_delete: QBox<SlotNoArgs>,
     pub resize_columns: QBox<SlotNoArgs>,
+    pub column_presets_menu: QBox<SlotNoArgs>,
+    pub column_presets_save: QBox<SlotOfBool>,
+    pub column_presets_delete: QBox<SlotOfBool>,
+    pub column_presets_reset: QBox<SlotOfBool>,
+    pub column_presets_export: QBox<SlotOfBool>,
+    pub column_presets_import: QBox<SlotOfBool>,
     pub sidebar: QBox<SlotOfBool>,
     pub search: QBox<SlotOfBool>,
     pub cascade_edition: QBox<SlotNoArgs>,
+    pub deep_clone: QBox<SlotNoArgs>,
     pub patch_column: QBox<SlotNoArgs>,
     pub find_references: QBox<SlotNoArgs>,
     pub go_to_definition: QBox<SlotNoArgs>,
     pub go_to_loc: Vec<QBox<SlotNoArgs>>,
+    pub open_related_loc_entries: QBox<SlotNoArgs>,
+    pub open_defining_table_row: QBox<SlotNoArgs>,
+    pub diff_against_dependencies: QBox<SlotOfBool>,
+    pub edit_row_annotation: QBox<SlotNoArgs>,
+    pub diff_show_only_changed: QBox<SlotOfInt>,
     pub hide_show_columns: Vec<QBox<SlotOfInt>>,
     pub hide_show_columns_all: QBox<SlotOfInt>,
     pub freeze_columns: Vec<QBox<SlotOfInt>>,
@@ -130,6 +150,13 @@ impl TableViewSlots {
                             show_dialog(&view.table_view, error, false);
                         } else if let Some(path) = view.get_packed_file_path() {
                             paths_to_check.push(path);
+
+                            // Keep the diff against the dependencies up to date with the edits.
+                            if view.context_menu_diff_against_dependencies.is_checked() {
+                                if let Err(error) = view.update_table_diff() {
+                                    log_to_status_bar(&error.to_string());
+                                }
+                            }
                         }
                     }
 
@@ -141,6 +168,9 @@ impl TableViewSlots {
                     }
                 }
             }
+
+            // Edits can change the keys of rows or move them around, so the pins of the rows with notes need to be redone.
+            view.paint_row_annotations();
         }));
 
         // When we want to toggle the lookups on and off.
@@ -167,6 +197,7 @@ impl TableViewSlots {
             view => move |_,_| {
             info!("Triggering `Update Context Menu for Table` By Slot");
             view.context_menu_update();
+            view.update_column_description();
         }));
 
         // When we want to respond to a change in one item in the model.
@@ -431,13 +462,21 @@ impl TableViewSlots {
                         &qtr("tsv_select_title"),
                     );
 
-                    file_dialog.set_name_filter(&QString::from_std_str("TSV Files (*.tsv)"));
+                    file_dialog.set_name_filter(&QString::from_std_str("TSV Files (*.tsv);;CSV Files (*.csv)"));
 
                     // Run it and, if we receive 1 (Accept), try to import the TSV file.
                     if file_dialog.exec() == 1 {
                         let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
 
-                        let receiver = CENTRAL_COMMAND.send_background(Command::ImportTSV(packed_file_path.read().unwrap().to_owned(), path));
+                        // CSV files may use any delimiter, so let the backend guess it.
+                        let is_csv = path.extension().map(|extension| extension.eq_ignore_ascii_case("csv")).unwrap_or(false);
+                        let command = if is_csv {
+                            Command::ImportCSV(packed_file_path.read().unwrap().to_owned(), path, None)
+                        } else {
+                            Command::ImportTSV(packed_file_path.read().unwrap().to_owned(), path)
+                        };
+
+                        let receiver = CENTRAL_COMMAND.send_background(command);
                         let response = CENTRAL_COMMAND.recv_try(&receiver);
                         match response {
                             Response::RFileDecoded(data) => {
@@ -470,7 +509,8 @@ impl TableViewSlots {
                                 build_columns(
                                     &view.table_view_ptr(),
                                     &view.table_definition(),
-                                    table_name.as_deref()
+                                    table_name.as_deref(),
+                                    Some(&view.patches()),
                                 );
 
                                 view.undo_lock.store(false, Ordering::SeqCst);
@@ -511,7 +551,7 @@ impl TableViewSlots {
 
                         file_dialog.set_accept_mode(AcceptMode::AcceptSave);
                         file_dialog.set_confirm_overwrite(true);
-                        file_dialog.set_name_filter(&QString::from_std_str("TSV Files (*.tsv)"));
+                        file_dialog.set_name_filter(&QString::from_std_str(&format!("{};;{};;{}", TSV_FILTER, CSV_COMMA_FILTER, CSV_SEMICOLON_FILTER)));
                         file_dialog.set_default_suffix(&QString::from_std_str("tsv"));
 
                         // Run it and, if we receive 1 (Accept), export the DB Table, saving it's contents first.
@@ -524,7 +564,13 @@ impl TableViewSlots {
                                 }
                             }
 
-                            let receiver = CENTRAL_COMMAND.send_background(Command::ExportTSV(packed_file_path.read().unwrap().to_string(), path));
+                            let command = match &*file_dialog.selected_name_filter().to_std_string() {
+                                CSV_COMMA_FILTER => Command::ExportCSV(packed_file_path.read().unwrap().to_string(), path.with_extension("csv"), b','),
+                                CSV_SEMICOLON_FILTER => Command::ExportCSV(packed_file_path.read().unwrap().to_string(), path.with_extension("csv"), b';'),
+                                _ => Command::ExportTSV(packed_file_path.read().unwrap().to_string(), path),
+                            };
+
+                            let receiver = CENTRAL_COMMAND.send_background(command);
                             let response = CENTRAL_COMMAND.recv_try(&receiver);
                             match response {
                                 Response::Success => (),
@@ -546,6 +592,148 @@ impl TableViewSlots {
             }
         }));
 
+        // When the column presets menu is about to be shown, fill the list of presets of the table.
+        let column_presets_menu = SlotNoArgs::new(&view.table_view, clone!(
+            view => move || {
+                view.context_menu_column_presets_switch.clear();
+
+                let presets = match TableColumnPresets::load(&view.column_presets_key()) {
+                    Ok(presets) => presets,
+                    Err(error) => return show_dialog(&view.table_view, error, false),
+                };
+
+                for name in presets.presets().keys() {
+                    let name = name.to_owned();
+                    let action = view.context_menu_column_presets_switch.add_action_q_string(&QString::from_std_str(name));
+                    action.set_checkable(true);
+                    action.set_checked(presets.active().as_ref() == Some(&name));
+
+                    // Parent the slot to the action, so it gets deleted along with it when the menu is cleared.
+                    let slot = SlotOfBool::new(&action, clone!(
+                        name,
+                        view => move |_| {
+                            info!("Triggering `Switch Column Preset` By Slot");
+                            let key = view.column_presets_key();
+                            match TableColumnPresets::load(&key) {
+                                Ok(mut presets) => {
+                                    presets.set_active(Some(&name));
+                                    if let Some(preset) = presets.active_preset() {
+                                        view.apply_column_preset(preset);
+                                    }
+
+                                    if let Err(error) = presets.save(&key) {
+                                        show_dialog(&view.table_view, error, false);
+                                    }
+                                }
+                                Err(error) => show_dialog(&view.table_view, error, false),
+                            }
+                        }
+                    ));
+                    action.triggered().connect(&slot);
+                }
+
+                view.context_menu_column_presets_switch.set_enabled(!presets.presets().is_empty());
+                view.context_menu_column_presets_delete.set_enabled(presets.active().is_some());
+            }
+        ));
+
+        let column_presets_save = SlotOfBool::new(&view.table_view, clone!(
+            view => move |_| {
+                info!("Triggering `Save Column Preset` By Slot");
+                let key = view.column_presets_key();
+                let mut presets = match TableColumnPresets::load(&key) {
+                    Ok(presets) => presets,
+                    Err(error) => return show_dialog(&view.table_view, error, false),
+                };
+
+                let name = presets.active().clone().unwrap_or_default();
+                if let Some(name) = view.create_column_preset_name_dialog(&name) {
+                    presets.add_preset(&name, view.column_preset());
+                    if let Err(error) = presets.save(&key) {
+                        show_dialog(&view.table_view, error, false);
+                    }
+                }
+            }
+        ));
+
+        let column_presets_delete = SlotOfBool::new(&view.table_view, clone!(
+            view => move |_| {
+                info!("Triggering `Delete Column Preset` By Slot");
+                let key = view.column_presets_key();
+                match TableColumnPresets::load(&key) {
+                    Ok(mut presets) => {
+                        if let Some(name) = presets.active().clone() {
+                            presets.remove_preset(&name);
+                            if let Err(error) = presets.save(&key) {
+                                show_dialog(&view.table_view, error, false);
+                            }
+                        }
+                    }
+                    Err(error) => show_dialog(&view.table_view, error, false),
+                }
+            }
+        ));
+
+        let column_presets_reset = SlotOfBool::new(&view.table_view, clone!(
+            view => move |_| {
+                info!("Triggering `Reset Column Layout` By Slot");
+                let key = view.column_presets_key();
+                match TableColumnPresets::load(&key) {
+                    Ok(mut presets) => {
+                        presets.set_active(None);
+                        if let Err(error) = presets.save(&key) {
+                            show_dialog(&view.table_view, error, false);
+                        }
+                    }
+                    Err(error) => show_dialog(&view.table_view, error, false),
+                }
+
+                view.reset_column_layout();
+            }
+        ));
+
+        let column_presets_export = SlotOfBool::new(&view.table_view, clone!(
+            view => move |_| {
+                info!("Triggering `Export Column Presets` By Slot");
+                let file_dialog = QFileDialog::from_q_widget_q_string(
+                    &view.table_view,
+                    &qtr("column_presets_export_title")
+                );
+
+                file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+                file_dialog.set_confirm_overwrite(true);
+                file_dialog.set_name_filter(&QString::from_std_str(JSON_FILTER));
+                file_dialog.set_default_suffix(&QString::from_std_str("json"));
+
+                if file_dialog.exec() == 1 {
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                    if let Err(error) = TableColumnPresets::export(&path) {
+                        show_dialog(&view.table_view, error, false);
+                    }
+                }
+            }
+        ));
+
+        let column_presets_import = SlotOfBool::new(&view.table_view, clone!(
+            view => move |_| {
+                info!("Triggering `Import Column Presets` By Slot");
+                let file_dialog = QFileDialog::from_q_widget_q_string(
+                    &view.table_view,
+                    &qtr("column_presets_import_title")
+                );
+
+                file_dialog.set_name_filter(&QString::from_std_str(JSON_FILTER));
+
+                if file_dialog.exec() == 1 {
+                    let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+                    match TableColumnPresets::import(&path) {
+                        Ok(_) => show_dialog(&view.table_view, tr("column_presets_import_success"), true),
+                        Err(error) => show_dialog(&view.table_view, error, false),
+                    }
+                }
+            }
+        ));
+
         // When you want to use the "Smart Delete" feature...
         let smart_delete = SlotNoArgs::new(&view.table_view, clone!(
             app_ui,
@@ -587,6 +775,17 @@ impl TableViewSlots {
             }
         ));
 
+        let deep_clone = SlotNoArgs::new(&view.table_view, clone!(
+            view,
+            app_ui,
+            pack_file_contents_ui => move || {
+                info!("Triggering `Deep Clone` By Slot");
+                if let Err(error) = view.deep_clone(&app_ui, &pack_file_contents_ui) {
+                    show_dialog(&view.table_view, error, false);
+                }
+            }
+        ));
+
         let patch_column = SlotNoArgs::new(&view.table_view, clone!(
             view => move || {
                 info!("Triggering `Patch Column` By Slot");
@@ -669,6 +868,73 @@ impl TableViewSlots {
             go_to_loc.push(slot);
         }
 
+        let open_related_loc_entries = SlotNoArgs::new(&view.table_view, clone!(
+            view,
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui,
+            diagnostics_ui,
+            dependencies_ui,
+            references_ui => move || {
+                info!("Triggering `Open Related Loc Entries` By Slot");
+                if let Some(error) = view.open_related_loc_entries(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui) {
+                    log_to_status_bar(&error);
+                }
+            }
+        ));
+
+        let open_defining_table_row = SlotNoArgs::new(&view.table_view, clone!(
+            view,
+            app_ui,
+            pack_file_contents_ui,
+            global_search_ui,
+            diagnostics_ui,
+            dependencies_ui,
+            references_ui => move || {
+                info!("Triggering `Open Defining Table Row` By Slot");
+                if let Some(error) = view.open_defining_table_row(&app_ui, &pack_file_contents_ui, &global_search_ui, &diagnostics_ui, &dependencies_ui, &references_ui) {
+                    log_to_status_bar(&error);
+                }
+            }
+        ));
+
+        // When we want to diff the table against the vanilla/parent data of the same table.
+        let edit_row_annotation = SlotNoArgs::new(&view.table_view, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            view => move || {
+                info!("Triggering `Edit Row Note` By Slot");
+                view.edit_row_annotation(&app_ui, &pack_file_contents_ui);
+            }
+        ));
+
+        let diff_against_dependencies = SlotOfBool::new(&view.table_view, clone!(
+            app_ui,
+            pack_file_contents_ui,
+            view => move |_| {
+                info!("Triggering `Diff Against Dependencies` By Slot");
+
+                // Save the table first, so the backend diffs the data we see.
+                if let Some(packed_file_path) = view.get_packed_file_path() {
+                    if let Some(packed_file) = UI_STATE.get_open_packedfiles().iter().find(|x| *x.get_ref_path() == packed_file_path && x.get_data_source() == DataSource::PackFile) {
+                        if let Err(error) = packed_file.save(&app_ui, &pack_file_contents_ui) {
+                            return show_dialog(&view.table_view, error, false);
+                        }
+                    }
+                }
+
+                if let Err(error) = view.update_table_diff() {
+                    show_dialog(&view.table_view, error, false);
+                }
+            }
+        ));
+
+        let diff_show_only_changed = SlotOfInt::new(&view.table_view, clone!(
+            view => move |_| {
+                view.filter_table_diff();
+            }
+        ));
+
         let mut hide_show_columns = vec![];
         let mut freeze_columns = vec![];
 
@@ -777,13 +1043,25 @@ impl TableViewSlots {
             export_tsv,
             smart_delete,
             resize_columns,
+            column_presets_menu,
+            column_presets_save,
+            column_presets_delete,
+            column_presets_reset,
+            column_presets_export,
+            column_presets_import,
             sidebar,
             search,
             cascade_edition,
+            deep_clone,
             patch_column,
             find_references,
             go_to_definition,
             go_to_loc,
+            open_related_loc_entries,
+            open_defining_table_row,
+            diff_against_dependencies,
+            edit_row_annotation,
+            diff_show_only_changed,
             hide_show_columns,
             hide_show_columns_all,
             freeze_columns,
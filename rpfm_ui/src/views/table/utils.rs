@@ -464,7 +464,7 @@ pub unsafe fn load_data(
         for (row, entry) in data.iter().enumerate() {
             let qlist = QListOfQStandardItem::new();
             for (column, field) in entry.iter().enumerate() {
-                let item = get_item_from_decoded_data(field, &keys, column);
+                let item = get_item_from_decoded_data(field, &fields_processed[column], &keys, column);
 
                 if data_source != DataSource::PackFile {
                     item.set_editable(false);
@@ -499,7 +499,7 @@ pub unsafe fn load_data(
 }
 
 /// This function generates a StandardItem for the provided DecodedData.
-pub unsafe fn get_item_from_decoded_data(data: &DecodedData, keys: &[i32], column: usize) -> CppBox<QStandardItem> {
+pub unsafe fn get_item_from_decoded_data(data: &DecodedData, field: &Field, keys: &[i32], column: usize) -> CppBox<QStandardItem> {
     let item = match *data {
 
         // This one needs a couple of changes before turning it into an item in the table.
@@ -609,7 +609,13 @@ pub unsafe fn get_item_from_decoded_data(data: &DecodedData, keys: &[i32], colum
             item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(data)), ITEM_SOURCE_VALUE);
             item
         },
-        DecodedData::SequenceU16(ref table) | DecodedData::SequenceU32(ref table) => {
+        // Sequences are shown as nested tables, so we decode them with the definition of their field.
+        DecodedData::SequenceU16(_) | DecodedData::SequenceU32(_) => {
+            let table = match field.field_type() {
+                FieldType::SequenceU16(ref definition) | FieldType::SequenceU32(ref definition) => Table::from_sequence(data, definition).unwrap(),
+                _ => unreachable!(),
+            };
+
             let table = QString::from_std_str(serde_json::to_string(&table).unwrap());
             let item = QStandardItem::from_q_string(&qtr("packedfile_editable_sequence"));
             item.set_editable(false);
@@ -633,15 +639,20 @@ pub unsafe fn build_columns(
     table_view: &QPtr<QTableView>,
     definition: &Definition,
     table_name: Option<&str>,
+    patches: Option<&DefinitionPatch>,
 ) {
     let filter: QPtr<QSortFilterProxyModel> = table_view.model().static_downcast();
     let model: QPtr<QStandardItemModel> = filter.source_model().static_downcast();
-    let schema = SCHEMA.read().unwrap();
+    let schema = read_schema();
     let mut do_we_have_ca_order = false;
     let mut keys = vec![];
 
     let fields_processed = definition.fields_processed();
-    let tooltips = get_column_tooltips(&schema, &fields_processed, table_name);
+    let tooltips = get_column_tooltips(&schema, &fields_processed, table_name, patches);
+
+    // Let the filter know the type of each column, so numeric and colour columns are sorted by value.
+    let column_types = fields_processed.iter().map(|field| FilterType::from(field.field_type()) as i32).collect::<Vec<_>>();
+    set_tableview_filter_column_types_safe(&filter, &column_types);
 
     for (index, field) in fields_processed.iter().enumerate() {
 
@@ -716,6 +727,7 @@ pub unsafe fn get_column_tooltips(
     schema: &Option<Schema>,
     fields: &[Field],
     table_name: Option<&str>,
+    patches: Option<&DefinitionPatch>,
 ) -> Vec<String> {
 
     let mut tooltips = vec![];
@@ -730,8 +742,9 @@ pub unsafe fn get_column_tooltips(
             let ref_definitions = schema.definitions();
             tooltips = fields.par_iter().map(|field| {
                 let mut tooltip_text = String::new();
-                if !field.description().is_empty() {
-                    tooltip_text.push_str(&format!("<p>{}</p>", field.description()));
+                let description = field.description_with_patches(patches);
+                if !description.is_empty() {
+                    tooltip_text.push_str(&format!("<p>{}</p>", description.replace('\n', "<br/>")));
                 }
 
                 if field.is_filename() {
@@ -803,7 +816,7 @@ pub unsafe fn get_reference_data(file_type: FileType, table_name: &str, definiti
         FileType::DB => {
 
             // Call the backend passing it the files we have open (so we don't get them from the backend too), and get the frontend data while we wait for it to finish.
-            let receiver = CENTRAL_COMMAND.send_background(Command::GetReferenceDataFromDefinition(table_name.to_owned(), definition.clone()));
+            let receiver = CENTRAL_COMMAND.send_background(Command::GetReferenceDataCached(table_name.to_owned(), definition.clone()));
 
             let reference_data = definition.reference_data();
             let mut dependency_data_visual = BTreeMap::new();
@@ -954,43 +967,14 @@ pub unsafe fn get_table_from_view(
     definition: &Definition
 ) -> Result<Table> {
     let mut entries = vec![];
+    let fields_processed = definition.fields_processed();
 
     for row in 0..model.row_count_0a() {
         let mut new_row: Vec<DecodedData> = vec![];
 
         // Bitwise columns can span across multiple columns. That means we have to keep track of the column ourselves.
-        for (column, field) in definition.fields_processed().iter().enumerate() {
-
-            // Create a new Item.
-            let item = match field.field_type() {
-
-                // This one needs a couple of changes before turning it into an item in the table.
-                FieldType::Boolean => DecodedData::Boolean(model.item_2a(row, column as i32).check_state() == CheckState::Checked),
-
-                // Numbers need parsing, and this can fail.
-                FieldType::F32 => DecodedData::F32(model.item_2a(row, column as i32).data_1a(2).to_float_0a()),
-                FieldType::F64 => DecodedData::F64(model.item_2a(row, column as i32).data_1a(2).to_double_0a()),
-                FieldType::I16 => DecodedData::I16(model.item_2a(row, column as i32).data_1a(2).to_int_0a() as i16),
-                FieldType::I32 => DecodedData::I32(model.item_2a(row, column as i32).data_1a(2).to_int_0a()),
-                FieldType::I64 => DecodedData::I64(model.item_2a(row, column as i32).data_1a(2).to_long_long_0a()),
-                FieldType::OptionalI16 => DecodedData::OptionalI16(model.item_2a(row, column as i32).data_1a(2).to_int_0a() as i16),
-                FieldType::OptionalI32 => DecodedData::OptionalI32(model.item_2a(row, column as i32).data_1a(2).to_int_0a()),
-                FieldType::OptionalI64 => DecodedData::OptionalI64(model.item_2a(row, column as i32).data_1a(2).to_long_long_0a()),
-
-                // Colours need parsing to turn them into integers.
-                FieldType::ColourRGB => DecodedData::ColourRGB(QString::to_std_string(&model.item_2a(row, column as i32).text())),
-
-                // All these are just normal Strings.
-                FieldType::StringU8 => DecodedData::StringU8(QString::to_std_string(&model.item_2a(row, column as i32).text())),
-                FieldType::StringU16 => DecodedData::StringU16(QString::to_std_string(&model.item_2a(row, column as i32).text())),
-                FieldType::OptionalStringU8 => DecodedData::OptionalStringU8(QString::to_std_string(&model.item_2a(row, column as i32).text())),
-                FieldType::OptionalStringU16 => DecodedData::OptionalStringU16(QString::to_std_string(&model.item_2a(row, column as i32).text())),
-
-                // Sequences in the UI are not yet supported.
-                FieldType::SequenceU16(_) => DecodedData::SequenceU16(serde_json::from_str(&model.item_2a(row, column as i32).data_1a(ITEM_SEQUENCE_DATA).to_string().to_std_string()).unwrap()),
-                FieldType::SequenceU32(_) => DecodedData::SequenceU32(serde_json::from_str(&model.item_2a(row, column as i32).data_1a(ITEM_SEQUENCE_DATA).to_string().to_std_string()).unwrap()),
-            };
-            new_row.push(item);
+        for (column, field) in fields_processed.iter().enumerate() {
+            new_row.push(get_decoded_data_from_view(model, row, column as i32, field)?);
         }
         entries.push(new_row);
     }
@@ -1000,6 +984,71 @@ pub unsafe fn get_table_from_view(
     Ok(table)
 }
 
+/// This function returns the data of the provided cell of a TableView, as DecodedData of the type of the provided field.
+pub unsafe fn get_decoded_data_from_view(
+    model: &QPtr<QStandardItemModel>,
+    row: i32,
+    column: i32,
+    field: &Field
+) -> Result<DecodedData> {
+    let item = model.item_2a(row, column);
+    Ok(match field.field_type() {
+
+        // This one needs a couple of changes before turning it into an item in the table.
+        FieldType::Boolean => DecodedData::Boolean(item.check_state() == CheckState::Checked),
+
+        // Numbers need parsing, and this can fail.
+        FieldType::F32 => DecodedData::F32(item.data_1a(2).to_float_0a()),
+        FieldType::F64 => DecodedData::F64(item.data_1a(2).to_double_0a()),
+        FieldType::I16 => DecodedData::I16(item.data_1a(2).to_int_0a() as i16),
+        FieldType::I32 => DecodedData::I32(item.data_1a(2).to_int_0a()),
+        FieldType::I64 => DecodedData::I64(item.data_1a(2).to_long_long_0a()),
+        FieldType::OptionalI16 => DecodedData::OptionalI16(item.data_1a(2).to_int_0a() as i16),
+        FieldType::OptionalI32 => DecodedData::OptionalI32(item.data_1a(2).to_int_0a()),
+        FieldType::OptionalI64 => DecodedData::OptionalI64(item.data_1a(2).to_long_long_0a()),
+
+        // Colours need parsing to turn them into integers.
+        FieldType::ColourRGB => DecodedData::ColourRGB(QString::to_std_string(&item.text())),
+
+        // All these are just normal Strings.
+        FieldType::StringU8 => DecodedData::StringU8(QString::to_std_string(&item.text())),
+        FieldType::StringU16 => DecodedData::StringU16(QString::to_std_string(&item.text())),
+        FieldType::OptionalStringU8 => DecodedData::OptionalStringU8(QString::to_std_string(&item.text())),
+        FieldType::OptionalStringU16 => DecodedData::OptionalStringU16(QString::to_std_string(&item.text())),
+
+        // Sequences are stored as nested tables, so we need to encode them back.
+        FieldType::SequenceU16(_) |
+        FieldType::SequenceU32(_) => {
+            let table: Table = serde_json::from_str(&item.data_1a(ITEM_SEQUENCE_DATA).to_string().to_std_string())?;
+            table.to_sequence(field.field_type())?
+        }
+    })
+}
+
+/// This function returns the key columns of the provided fields, as `(column, field)`.
+pub fn get_key_fields(fields: &[Field]) -> Vec<(i32, &Field)> {
+    fields.iter()
+        .enumerate()
+        .filter(|(_, field)| field.is_key())
+        .map(|(column, field)| (column as i32, field))
+        .collect()
+}
+
+/// This function returns the key of the provided row of a TableView, the same way [Table::row_key] does.
+///
+/// Key columns are passed as `(column, field)`. Returns `None` if there are no key columns.
+pub unsafe fn get_row_key_from_view(model: &QPtr<QStandardItemModel>, row: i32, key_fields: &[(i32, &Field)]) -> Option<String> {
+    if key_fields.is_empty() {
+        return None;
+    }
+
+    key_fields.iter()
+        .map(|(column, field)| get_decoded_data_from_view(model, row, *column, field).map(|data| data.data_to_string().to_string()))
+        .collect::<Result<Vec<_>>>()
+        .ok()
+        .map(|key| key.join(ROW_KEY_SEPARATOR))
+}
+
 /// This function creates a new subtable from the current table.
 pub unsafe fn open_subtable(
     parent: QPtr<QWidget>,